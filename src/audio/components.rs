@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+use super::resources::MusicContext;
+
+/// Marker for entities playing a one-shot sound effect
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SoundEffectPlayer;
+
+/// The music track currently playing and the playlist it belongs to
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MusicTrack {
+    pub context: MusicContext,
+}
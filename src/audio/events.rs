@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+/// Sound effects the game can play
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundEffect {
+    /// A card is drawn from a library
+    CardDraw,
+    /// A permanent becomes tapped
+    Tap,
+    /// A creature is declared as an attacker
+    Attack,
+    /// A player loses life
+    LifeLoss,
+    /// A UI button is pressed
+    ButtonClick,
+}
+
+impl SoundEffect {
+    /// All sound effects, in a stable order
+    pub const ALL: [SoundEffect; 5] = [
+        SoundEffect::CardDraw,
+        SoundEffect::Tap,
+        SoundEffect::Attack,
+        SoundEffect::LifeLoss,
+        SoundEffect::ButtonClick,
+    ];
+}
+
+/// Event requesting a sound effect to be played
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlaySoundEvent(pub SoundEffect);
@@ -0,0 +1,24 @@
+//! Game audio: sound effects and music playlists
+//!
+//! Gameplay and UI systems request sounds by sending [`PlaySoundEvent`]s; the audio
+//! plugin also listens for common game events (card draws, taps, attacks, life loss
+//! and button presses) and plays the matching effect. Music is played from a
+//! playlist chosen by the current menu state.
+//!
+//! Volume is split into three buckets from [`VolumeSettings`](crate::menu::settings::components::VolumeSettings):
+//! the master volume drives Bevy's global volume, while the music and SFX volumes are
+//! applied to music tracks and sound effects respectively.
+
+pub mod components;
+pub mod events;
+pub mod plugin;
+pub mod resources;
+pub mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{MusicTrack, SoundEffectPlayer};
+pub use events::{PlaySoundEvent, SoundEffect};
+pub use plugin::RummageAudioPlugin;
+pub use resources::{MusicContext, MusicPlaylists, SoundLibrary};
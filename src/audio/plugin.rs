@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+
+use super::events::PlaySoundEvent;
+use super::resources::{MusicPlaylists, SoundLibrary};
+use super::systems::{
+    apply_music_volume, emit_attack_sounds, emit_button_click_sounds, emit_card_draw_sounds,
+    emit_life_loss_sounds, emit_tap_sounds, play_sound_effects, update_music,
+};
+use crate::game_engine::combat::AttackerDeclaredEvent;
+use crate::game_engine::zones::ZoneChangeEvent;
+use crate::menu::settings::components::VolumeSettings;
+
+/// Plugin for sound effects and music
+pub struct RummageAudioPlugin;
+
+impl Plugin for RummageAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PlaySoundEvent>()
+            .add_event::<ZoneChangeEvent>()
+            .add_event::<AttackerDeclaredEvent>()
+            .init_resource::<VolumeSettings>()
+            .init_resource::<SoundLibrary>()
+            .init_resource::<MusicPlaylists>()
+            .add_systems(
+                Update,
+                (
+                    (
+                        emit_card_draw_sounds,
+                        emit_tap_sounds,
+                        emit_attack_sounds,
+                        emit_life_loss_sounds,
+                        emit_button_click_sounds,
+                    ),
+                    play_sound_effects,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_music,
+                    apply_music_volume.run_if(resource_changed::<VolumeSettings>),
+                ),
+            );
+    }
+}
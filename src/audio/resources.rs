@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use bevy::audio::Pitch;
+use bevy::prelude::*;
+
+use super::events::SoundEffect;
+use crate::menu::state::GameMenuState;
+
+/// Which playlist should be playing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MusicContext {
+    /// Main menu, settings and other out-of-game screens
+    Menu,
+    /// A game in progress, including the pause menu
+    InGame,
+}
+
+impl MusicContext {
+    /// Playlist to use for a menu state
+    pub fn for_state(state: &GameMenuState) -> Self {
+        match state {
+            GameMenuState::InGame | GameMenuState::PauseMenu => MusicContext::InGame,
+            _ => MusicContext::Menu,
+        }
+    }
+}
+
+/// Music tracks for each context, played in order and looped
+#[derive(Resource, Debug, Clone)]
+pub struct MusicPlaylists {
+    /// Asset paths of the menu tracks
+    pub menu: Vec<String>,
+    /// Asset paths of the in-game tracks
+    pub in_game: Vec<String>,
+    /// Index of the next track to play in each context
+    next_track: HashMap<MusicContext, usize>,
+}
+
+impl Default for MusicPlaylists {
+    fn default() -> Self {
+        Self {
+            menu: vec!["music/negev_hava_nagila.ogg".to_string()],
+            in_game: Vec::new(),
+            next_track: HashMap::new(),
+        }
+    }
+}
+
+impl MusicPlaylists {
+    /// Tracks of a context
+    pub fn tracks(&self, context: MusicContext) -> &[String] {
+        match context {
+            MusicContext::Menu => &self.menu,
+            MusicContext::InGame => &self.in_game,
+        }
+    }
+
+    /// Take the next track of a context, wrapping around at the end of the playlist
+    pub fn advance(&mut self, context: MusicContext) -> Option<String> {
+        let len = self.tracks(context).len();
+        if len == 0 {
+            return None;
+        }
+
+        let index = self.next_track.entry(context).or_default();
+        let track_index = *index % len;
+        *index = (track_index + 1) % len;
+        Some(self.tracks(context)[track_index].clone())
+    }
+}
+
+/// Audio handles for each sound effect
+///
+/// Effects are synthesized tones so the game has feedback without bundled sound files.
+#[derive(Resource, Debug, Clone)]
+pub struct SoundLibrary {
+    effects: HashMap<SoundEffect, Handle<Pitch>>,
+}
+
+impl SoundLibrary {
+    /// Handle for a sound effect
+    pub fn get(&self, effect: SoundEffect) -> Option<&Handle<Pitch>> {
+        self.effects.get(&effect)
+    }
+}
+
+/// Frequency and duration of the tone used for a sound effect
+pub fn sound_effect_tone(effect: SoundEffect) -> (f32, Duration) {
+    match effect {
+        SoundEffect::CardDraw => (660.0, Duration::from_millis(60)),
+        SoundEffect::Tap => (440.0, Duration::from_millis(50)),
+        SoundEffect::Attack => (220.0, Duration::from_millis(150)),
+        SoundEffect::LifeLoss => (165.0, Duration::from_millis(200)),
+        SoundEffect::ButtonClick => (880.0, Duration::from_millis(30)),
+    }
+}
+
+impl FromWorld for SoundLibrary {
+    fn from_world(world: &mut World) -> Self {
+        let mut pitches = world.resource_mut::<Assets<Pitch>>();
+        let effects = SoundEffect::ALL
+            .into_iter()
+            .map(|effect| {
+                let (frequency, duration) = sound_effect_tone(effect);
+                (effect, pitches.add(Pitch::new(frequency, duration)))
+            })
+            .collect();
+
+        Self { effects }
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy::audio::{AudioSink, AudioSinkPlayback, Volume};
+use bevy::prelude::*;
+
+use super::components::{MusicTrack, SoundEffectPlayer};
+use super::events::{PlaySoundEvent, SoundEffect};
+use super::resources::{MusicContext, MusicPlaylists, SoundLibrary};
+use crate::game_engine::combat::AttackerDeclaredEvent;
+use crate::game_engine::permanent::PermanentState;
+use crate::game_engine::zones::{Zone, ZoneChangeEvent};
+use crate::menu::settings::components::VolumeSettings;
+use crate::menu::state::GameMenuState;
+use crate::player::components::Player;
+
+/// Play a draw sound when a card moves from a library to a hand
+pub fn emit_card_draw_sounds(
+    mut zone_changes: EventReader<ZoneChangeEvent>,
+    mut sounds: EventWriter<PlaySoundEvent>,
+) {
+    for event in zone_changes.read() {
+        if event.source == Zone::Library && event.destination == Zone::Hand {
+            sounds.write(PlaySoundEvent(SoundEffect::CardDraw));
+        }
+    }
+}
+
+/// Play a tap sound when a permanent goes from untapped to tapped
+pub fn emit_tap_sounds(
+    permanents: Query<(Entity, &PermanentState), Changed<PermanentState>>,
+    mut tapped: Local<HashSet<Entity>>,
+    mut sounds: EventWriter<PlaySoundEvent>,
+) {
+    for (entity, state) in permanents.iter() {
+        if state.is_tapped {
+            if tapped.insert(entity) {
+                sounds.write(PlaySoundEvent(SoundEffect::Tap));
+            }
+        } else {
+            tapped.remove(&entity);
+        }
+    }
+}
+
+/// Play an attack sound for each declared attacker
+pub fn emit_attack_sounds(
+    mut attackers: EventReader<AttackerDeclaredEvent>,
+    mut sounds: EventWriter<PlaySoundEvent>,
+) {
+    for _ in attackers.read() {
+        sounds.write(PlaySoundEvent(SoundEffect::Attack));
+    }
+}
+
+/// Play a sound when a player's life total goes down
+pub fn emit_life_loss_sounds(
+    players: Query<(Entity, &Player), Changed<Player>>,
+    mut life_totals: Local<HashMap<Entity, i32>>,
+    mut sounds: EventWriter<PlaySoundEvent>,
+) {
+    for (entity, player) in players.iter() {
+        if let Some(previous) = life_totals.insert(entity, player.life) {
+            if player.life < previous {
+                sounds.write(PlaySoundEvent(SoundEffect::LifeLoss));
+            }
+        }
+    }
+}
+
+/// Play a click when any UI button is pressed
+pub fn emit_button_click_sounds(
+    buttons: Query<&Interaction, (Changed<Interaction>, With<Button>)>,
+    mut sounds: EventWriter<PlaySoundEvent>,
+) {
+    if buttons
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        sounds.write(PlaySoundEvent(SoundEffect::ButtonClick));
+    }
+}
+
+/// Spawn one-shot players for requested sound effects at the SFX volume
+///
+/// Each effect is played at most once per frame so simultaneous events, such as
+/// untapping a whole board, do not stack into a loud burst.
+pub fn play_sound_effects(
+    mut commands: Commands,
+    mut requests: EventReader<PlaySoundEvent>,
+    library: Res<SoundLibrary>,
+    volume: Res<VolumeSettings>,
+) {
+    let mut played = HashSet::new();
+
+    for PlaySoundEvent(effect) in requests.read() {
+        if !played.insert(*effect) || volume.sfx <= 0.0 {
+            continue;
+        }
+
+        let Some(handle) = library.get(*effect) else {
+            warn!("No sound registered for {:?}", effect);
+            continue;
+        };
+
+        commands.spawn((
+            AudioPlayer(handle.clone()),
+            PlaybackSettings::DESPAWN.with_volume(Volume::Linear(volume.sfx)),
+            SoundEffectPlayer,
+            Name::new(format!("Sound Effect {:?}", effect)),
+        ));
+    }
+}
+
+/// Keep the playlist for the current menu state playing
+///
+/// Tracks despawn when they finish, at which point the next track of the playlist is
+/// started. Switching between menus and a game stops the old playlist.
+pub fn update_music(
+    mut commands: Commands,
+    state: Res<State<GameMenuState>>,
+    mut playlists: ResMut<MusicPlaylists>,
+    tracks: Query<(Entity, &MusicTrack)>,
+    asset_server: Res<AssetServer>,
+    volume: Res<VolumeSettings>,
+) {
+    let context = MusicContext::for_state(state.get());
+    let mut playing = false;
+
+    for (entity, track) in tracks.iter() {
+        if track.context == context {
+            playing = true;
+        } else {
+            info!("Stopping {:?} music", track.context);
+            commands.entity(entity).despawn();
+        }
+    }
+
+    if playing {
+        return;
+    }
+
+    let Some(path) = playlists.advance(context) else {
+        return;
+    };
+
+    info!("Playing {:?} music track: {}", context, path);
+    commands.spawn((
+        AudioPlayer::new(asset_server.load(path)),
+        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(volume.music)),
+        MusicTrack { context },
+        Name::new("Music Track"),
+    ));
+}
+
+/// Apply the music volume to playing tracks when it changes
+pub fn apply_music_volume(
+    volume: Res<VolumeSettings>,
+    mut sinks: Query<&mut AudioSink, With<MusicTrack>>,
+) {
+    for mut sink in sinks.iter_mut() {
+        sink.set_volume(Volume::Linear(volume.music));
+    }
+}
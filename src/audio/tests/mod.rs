@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+
+use crate::audio::events::SoundEffect;
+use crate::audio::resources::{MusicContext, MusicPlaylists, sound_effect_tone};
+use crate::menu::state::GameMenuState;
+
+#[test]
+fn test_music_context_follows_menu_state() {
+    assert_eq!(
+        MusicContext::for_state(&GameMenuState::MainMenu),
+        MusicContext::Menu
+    );
+    assert_eq!(
+        MusicContext::for_state(&GameMenuState::Settings),
+        MusicContext::Menu
+    );
+    assert_eq!(
+        MusicContext::for_state(&GameMenuState::InGame),
+        MusicContext::InGame
+    );
+    assert_eq!(
+        MusicContext::for_state(&GameMenuState::PauseMenu),
+        MusicContext::InGame
+    );
+}
+
+#[test]
+fn test_playlist_advances_and_wraps() {
+    let mut playlists = MusicPlaylists {
+        menu: vec!["a.ogg".to_string(), "b.ogg".to_string()],
+        ..default()
+    };
+
+    assert_eq!(
+        playlists.advance(MusicContext::Menu).as_deref(),
+        Some("a.ogg")
+    );
+    assert_eq!(
+        playlists.advance(MusicContext::Menu).as_deref(),
+        Some("b.ogg")
+    );
+    assert_eq!(
+        playlists.advance(MusicContext::Menu).as_deref(),
+        Some("a.ogg")
+    );
+}
+
+#[test]
+fn test_empty_playlist_plays_nothing() {
+    let mut playlists = MusicPlaylists::default();
+    assert_eq!(playlists.advance(MusicContext::InGame), None);
+}
+
+#[test]
+fn test_every_sound_effect_has_a_tone() {
+    for effect in SoundEffect::ALL {
+        let (frequency, duration) = sound_effect_tone(effect);
+        assert!(frequency > 0.0);
+        assert!(!duration.is_zero());
+    }
+}
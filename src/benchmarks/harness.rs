@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Runs done before timing starts, to warm caches and allocators
+const WARMUP_RUNS: u32 = 3;
+
+/// Timings for one benchmark
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkResult {
+    pub name: String,
+    pub iterations: u32,
+    pub mean_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+impl BenchmarkResult {
+    /// Summarise a set of timings
+    pub fn from_samples(name: &str, samples: &[Duration]) -> Self {
+        let nanos = |duration: &Duration| duration.as_nanos().min(u64::MAX as u128) as u64;
+        let total: u128 = samples.iter().map(|sample| sample.as_nanos()).sum();
+        let mean = total.checked_div(samples.len() as u128).unwrap_or(0);
+
+        Self {
+            name: name.to_string(),
+            iterations: samples.len() as u32,
+            mean_ns: mean.min(u64::MAX as u128) as u64,
+            min_ns: samples.iter().map(nanos).min().unwrap_or(0),
+            max_ns: samples.iter().map(nanos).max().unwrap_or(0),
+        }
+    }
+}
+
+impl fmt::Display for BenchmarkResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<40} mean {:>12} min {:>12} max {:>12} ({} runs)",
+            self.name,
+            format_nanos(self.mean_ns),
+            format_nanos(self.min_ns),
+            format_nanos(self.max_ns),
+            self.iterations
+        )
+    }
+}
+
+/// Format a duration in the largest unit that keeps it above one
+pub fn format_nanos(nanos: u64) -> String {
+    match nanos {
+        0..1_000 => format!("{} ns", nanos),
+        1_000..1_000_000 => format!("{:.2} µs", nanos as f64 / 1_000.0),
+        1_000_000..1_000_000_000 => format!("{:.2} ms", nanos as f64 / 1_000_000.0),
+        _ => format!("{:.2} s", nanos as f64 / 1_000_000_000.0),
+    }
+}
+
+/// Time `routine` over `iterations` runs
+///
+/// `setup` builds the input for each run and isn't timed.
+pub fn run_benchmark<I, O>(
+    name: &str,
+    iterations: u32,
+    mut setup: impl FnMut() -> I,
+    mut routine: impl FnMut(I) -> O,
+) -> BenchmarkResult {
+    for _ in 0..WARMUP_RUNS {
+        std::hint::black_box(routine(setup()));
+    }
+
+    let mut samples = Vec::with_capacity(iterations as usize);
+    for _ in 0..iterations {
+        let input = setup();
+        let start = Instant::now();
+        let output = routine(input);
+        samples.push(start.elapsed());
+        std::hint::black_box(output);
+    }
+
+    BenchmarkResult::from_samples(name, &samples)
+}
+
+/// A benchmark that got slower than its baseline
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub name: String,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+}
+
+impl Regression {
+    /// How many times slower the current run is
+    pub fn ratio(&self) -> f64 {
+        self.current_ns as f64 / self.baseline_ns.max(1) as f64
+    }
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} regressed: {} -> {} ({:.0}% slower)",
+            self.name,
+            format_nanos(self.baseline_ns),
+            format_nanos(self.current_ns),
+            (self.ratio() - 1.0) * 100.0
+        )
+    }
+}
+
+/// Saved results that later runs are compared against
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkBaseline {
+    pub results: Vec<BenchmarkResult>,
+}
+
+impl BenchmarkBaseline {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        serde_json::from_str(&text).map_err(|e| e.to_string())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let text = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, text).map_err(|e| e.to_string())
+    }
+
+    /// Benchmarks whose mean is more than `tolerance` slower than the baseline,
+    /// e.g. `0.2` for 20%
+    ///
+    /// Benchmarks missing from the baseline are ignored.
+    pub fn regressions(&self, current: &[BenchmarkResult], tolerance: f64) -> Vec<Regression> {
+        current
+            .iter()
+            .filter_map(|result| {
+                let baseline = self.results.iter().find(|b| b.name == result.name)?;
+                let limit = baseline.mean_ns as f64 * (1.0 + tolerance);
+                (result.mean_ns as f64 > limit).then(|| Regression {
+                    name: result.name.clone(),
+                    baseline_ns: baseline.mean_ns,
+                    current_ns: result.mean_ns,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,70 @@
+//! Benchmarks for the hot paths of the game
+//!
+//! The suites time card dragging over a full table, zone lookups, stack
+//! resolution and MTGJSON card conversion. Run them with
+//! `cargo run --release -- --benchmark`; results are compared against the baseline
+//! in [`BASELINE_PATH`] and the run fails if any benchmark got more than
+//! [`REGRESSION_TOLERANCE`] slower. Add `--save-baseline` to record a new
+//! baseline.
+
+pub mod harness;
+pub mod suites;
+
+#[cfg(test)]
+mod tests;
+
+use std::path::Path;
+
+pub use harness::{BenchmarkBaseline, BenchmarkResult, Regression, run_benchmark};
+pub use suites::run_all;
+
+/// Where the benchmark baseline is kept
+pub const BASELINE_PATH: &str = "benchmarks/baseline.json";
+/// How much slower than the baseline a benchmark may be before it counts as a
+/// regression
+pub const REGRESSION_TOLERANCE: f64 = 0.2;
+/// Timed runs per benchmark
+pub const ITERATIONS: u32 = 100;
+
+/// Run every benchmark, print the results and check them against the baseline
+///
+/// Returns the process exit code: non-zero when a benchmark regressed.
+pub fn run_benchmark_mode(save_baseline: bool) -> i32 {
+    println!("Running benchmarks ({} runs each)...", ITERATIONS);
+    let results = run_all(ITERATIONS);
+    for result in &results {
+        println!("{}", result);
+    }
+
+    let path = Path::new(BASELINE_PATH);
+    if save_baseline {
+        let baseline = BenchmarkBaseline { results };
+        return match baseline.save(path) {
+            Ok(()) => {
+                println!("Saved baseline to {}", path.display());
+                0
+            }
+            Err(e) => {
+                eprintln!("Failed to save baseline to {}: {}", path.display(), e);
+                1
+            }
+        };
+    }
+
+    let baseline = match BenchmarkBaseline::load(path) {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            println!("No baseline to compare against ({}): {}", path.display(), e);
+            return 0;
+        }
+    };
+    let regressions = baseline.regressions(&results, REGRESSION_TOLERANCE);
+    if regressions.is_empty() {
+        println!("No regressions against {}", path.display());
+        return 0;
+    }
+    for regression in &regressions {
+        eprintln!("{}", regression);
+    }
+    1
+}
@@ -0,0 +1,262 @@
+use bevy::ecs::world::CommandQueue;
+use bevy::prelude::*;
+
+use super::harness::{BenchmarkResult, run_benchmark};
+use crate::camera::z_order::ZOrder;
+use crate::cards::components::{DragLocked, Draggable};
+use crate::cards::mtgjson::convert_mtgjson_cards;
+use crate::cards::mtgjson::test_utils::{create_test_mtgjson_card, mock_basic_land, mock_instant};
+use crate::cards::systems::{CardDroppedEvent, drag_cards_at};
+use crate::cards::{Card, CardDetails, CardTypes, CreatureCard, CreatureType};
+use crate::game_engine::stack::{Effect, GameStack};
+use crate::game_engine::zones::{Zone, ZoneManager};
+use crate::mana::Mana;
+use crate::player::resources::PlayerConfig;
+
+/// Cards on the table for the dragging benchmark
+pub const DRAG_CARD_COUNT: usize = 1000;
+/// Cards spread over the players' zones for the zone lookup benchmark
+pub const ZONE_CARD_COUNT: usize = 1000;
+/// Items pushed and resolved per run of the stack benchmark
+pub const STACK_ITEM_COUNT: usize = 100;
+/// Cards converted per run of the MTGJSON benchmark
+pub const MTGJSON_CARD_COUNT: usize = 300;
+
+/// Cursor position used in place of the window cursor by the dragging benchmark
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct BenchmarkCursor(pub Vec2);
+
+/// Run every benchmark
+pub fn run_all(iterations: u32) -> Vec<BenchmarkResult> {
+    vec![
+        bench_card_dragging(iterations),
+        bench_zone_lookups(iterations),
+        bench_stack_resolution(iterations),
+        bench_mtgjson_conversion(iterations),
+    ]
+}
+
+/// Same as `handle_card_dragging`, with the cursor read from [`BenchmarkCursor`]
+/// so it runs without a window or camera
+pub(crate) fn drag_at_benchmark_cursor(
+    cursor: Res<BenchmarkCursor>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    player_config: Res<PlayerConfig>,
+    mut card_query: Query<(Entity, &mut Transform, &mut Draggable, &GlobalTransform), With<Card>>,
+    locked_cards: Query<(), With<DragLocked>>,
+    mut dropped_events: EventWriter<CardDroppedEvent>,
+    mut z_order: ResMut<ZOrder>,
+) {
+    drag_cards_at(
+        cursor.0,
+        &mouse_button,
+        player_config.card_size,
+        &mut card_query,
+        &locked_cards,
+        &mut dropped_events,
+        &mut z_order,
+    );
+}
+
+fn benchmark_card() -> Card {
+    Card::new(
+        "Benchmark Bear",
+        Mana::default(),
+        CardTypes::CREATURE,
+        CardDetails::Creature(CreatureCard {
+            power: 2,
+            toughness: 2,
+            creature_type: CreatureType::NONE,
+        }),
+        "",
+    )
+}
+
+/// World with `count` draggable cards laid out in a grid
+pub fn drag_world(count: usize) -> World {
+    let mut world = World::new();
+    world.init_resource::<Events<CardDroppedEvent>>();
+    world.init_resource::<ButtonInput<MouseButton>>();
+    world.init_resource::<ZOrder>();
+    world.insert_resource(PlayerConfig::default());
+    world.insert_resource(BenchmarkCursor(Vec2::ZERO));
+
+    let columns = 40;
+    for index in 0..count {
+        let position = Vec3::new(
+            (index % columns) as f32 * 200.0 - 4000.0,
+            (index / columns) as f32 * 200.0 - 2500.0,
+            index as f32 * 0.01,
+        );
+        world.spawn((
+            benchmark_card(),
+            Draggable {
+                dragging: false,
+                drag_offset: Vec2::ZERO,
+                z_index: position.z,
+            },
+            Transform::from_translation(position),
+            GlobalTransform::from_translation(position),
+        ));
+    }
+    world
+}
+
+/// Pick up and drop the top card under the cursor, with [`DRAG_CARD_COUNT`] cards
+/// to hit test
+pub fn bench_card_dragging(iterations: u32) -> BenchmarkResult {
+    let mut world = drag_world(DRAG_CARD_COUNT);
+    let mut schedule = Schedule::default();
+    schedule.add_systems(drag_at_benchmark_cursor);
+
+    run_benchmark(
+        "handle_card_dragging (1000 cards)",
+        iterations,
+        || (),
+        |()| {
+            let mut input = world.resource_mut::<ButtonInput<MouseButton>>();
+            input.clear();
+            input.press(MouseButton::Left);
+            schedule.run(&mut world);
+
+            let mut input = world.resource_mut::<ButtonInput<MouseButton>>();
+            input.clear();
+            input.release(MouseButton::Left);
+            schedule.run(&mut world);
+
+            world.resource_mut::<Events<CardDroppedEvent>>().update();
+        },
+    )
+}
+
+/// Zone manager with [`ZONE_CARD_COUNT`] cards spread over four players
+pub fn populated_zone_manager(world: &mut World) -> (ZoneManager, Vec<Entity>, Vec<Entity>) {
+    let mut zones = ZoneManager::default();
+    let players: Vec<Entity> = (0..4).map(|_| world.spawn_empty().id()).collect();
+    for player in &players {
+        zones.init_player_zones(*player);
+    }
+
+    let cards: Vec<Entity> = (0..ZONE_CARD_COUNT)
+        .map(|_| world.spawn_empty().id())
+        .collect();
+    for (index, card) in cards.iter().enumerate() {
+        let owner = players[index % players.len()];
+        match index % 4 {
+            0 | 1 => zones.add_to_library(owner, *card),
+            2 => zones.add_to_battlefield(owner, *card),
+            _ => zones.add_to_graveyard(owner, *card),
+        }
+    }
+    (zones, players, cards)
+}
+
+/// Look up the zone and owner of every card and the contents of every zone
+pub fn bench_zone_lookups(iterations: u32) -> BenchmarkResult {
+    let mut world = World::new();
+    let (zones, players, cards) = populated_zone_manager(&mut world);
+    let player_zones = [
+        Zone::Library,
+        Zone::Hand,
+        Zone::Battlefield,
+        Zone::Graveyard,
+        Zone::Exile,
+        Zone::Command,
+    ];
+
+    run_benchmark(
+        "ZoneManager lookups (1000 cards)",
+        iterations,
+        || (),
+        |()| {
+            let mut found = 0usize;
+            for card in &cards {
+                found += zones.get_card_zone(*card).is_some() as usize;
+                found += zones.get_card_owner(*card).is_some() as usize;
+            }
+            for player in &players {
+                for zone in player_zones {
+                    found += zones
+                        .get_player_zone(*player, zone)
+                        .map_or(0, <[Entity]>::len);
+                }
+            }
+            found
+        },
+    )
+}
+
+/// Stack item that does nothing when it resolves
+#[derive(Debug)]
+struct NoopEffect {
+    controller: Entity,
+}
+
+impl Effect for NoopEffect {
+    fn resolve(&self, _commands: &mut Commands) {}
+
+    fn controller(&self) -> Entity {
+        self.controller
+    }
+
+    fn targets(&self) -> Vec<Entity> {
+        Vec::new()
+    }
+}
+
+/// Push [`STACK_ITEM_COUNT`] items onto the stack and resolve them all
+pub fn bench_stack_resolution(iterations: u32) -> BenchmarkResult {
+    let mut world = World::new();
+    let controller = world.spawn_empty().id();
+    let items: Vec<Entity> = (0..STACK_ITEM_COUNT)
+        .map(|_| world.spawn_empty().id())
+        .collect();
+
+    run_benchmark(
+        "stack resolution (100 items)",
+        iterations,
+        || (),
+        |()| {
+            let mut stack = GameStack::default();
+            let mut queue = CommandQueue::default();
+            let mut commands = Commands::new(&mut queue, &world);
+
+            for (index, item) in items.iter().enumerate() {
+                stack.push(
+                    Box::new(NoopEffect { controller }),
+                    *item,
+                    false,
+                    index % 10 != 0,
+                );
+            }
+            let mut resolved = 0;
+            while stack.resolve_top(&mut commands).is_some() {
+                resolved += 1;
+            }
+            resolved
+        },
+    )
+}
+
+/// A mix of MTGJSON cards to convert
+pub fn mtgjson_cards(count: usize) -> Vec<crate::cards::mtgjson::MTGJSONCard> {
+    (0..count)
+        .map(|index| match index % 3 {
+            0 => create_test_mtgjson_card(),
+            1 => mock_basic_land(format!("Land {}", index), vec!["Forest".to_string()]),
+            _ => mock_instant(&format!("Instant {}", index)),
+        })
+        .collect()
+}
+
+/// Convert [`MTGJSON_CARD_COUNT`] MTGJSON cards into game cards
+pub fn bench_mtgjson_conversion(iterations: u32) -> BenchmarkResult {
+    let cards = mtgjson_cards(MTGJSON_CARD_COUNT);
+
+    run_benchmark(
+        "MTGJSON set conversion (300 cards)",
+        iterations,
+        || cards.clone(),
+        |cards| convert_mtgjson_cards(cards).len(),
+    )
+}
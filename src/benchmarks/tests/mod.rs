@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+use crate::benchmarks::harness::format_nanos;
+use crate::benchmarks::suites::{
+    BenchmarkCursor, bench_stack_resolution, bench_zone_lookups, drag_at_benchmark_cursor,
+    drag_world, mtgjson_cards,
+};
+use crate::benchmarks::{BenchmarkBaseline, BenchmarkResult, run_benchmark};
+use crate::cards::components::Draggable;
+
+fn result(name: &str, mean_ns: u64) -> BenchmarkResult {
+    BenchmarkResult {
+        name: name.to_string(),
+        iterations: 10,
+        mean_ns,
+        min_ns: mean_ns,
+        max_ns: mean_ns,
+    }
+}
+
+#[test]
+fn test_result_summarises_samples() {
+    let samples = [
+        Duration::from_nanos(100),
+        Duration::from_nanos(300),
+        Duration::from_nanos(200),
+    ];
+    let result = BenchmarkResult::from_samples("test", &samples);
+
+    assert_eq!(result.iterations, 3);
+    assert_eq!(result.mean_ns, 200);
+    assert_eq!(result.min_ns, 100);
+    assert_eq!(result.max_ns, 300);
+    assert_eq!(BenchmarkResult::from_samples("empty", &[]).mean_ns, 0);
+}
+
+#[test]
+fn test_run_benchmark_times_each_iteration_after_setup() {
+    let mut setups = 0;
+    let result = run_benchmark(
+        "count",
+        5,
+        || {
+            setups += 1;
+            setups
+        },
+        |input| input * 2,
+    );
+
+    assert_eq!(result.iterations, 5);
+    // Three warm-up runs come before the timed ones
+    assert_eq!(setups, 8);
+}
+
+#[test]
+fn test_regressions_respect_tolerance() {
+    let baseline = BenchmarkBaseline {
+        results: vec![result("drag", 1000), result("stack", 1000)],
+    };
+    let current = vec![
+        result("drag", 1150),
+        result("stack", 1300),
+        result("new", 99999),
+    ];
+
+    let regressions = baseline.regressions(&current, 0.2);
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].name, "stack");
+    assert!((regressions[0].ratio() - 1.3).abs() < 1e-9);
+}
+
+#[test]
+fn test_baseline_round_trips_through_disk() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nested").join("baseline.json");
+    let baseline = BenchmarkBaseline {
+        results: vec![result("drag", 1234)],
+    };
+
+    baseline.save(&path).unwrap();
+    assert_eq!(BenchmarkBaseline::load(&path).unwrap(), baseline);
+}
+
+#[test]
+fn test_format_nanos_picks_unit() {
+    assert_eq!(format_nanos(999), "999 ns");
+    assert_eq!(format_nanos(1_500), "1.50 µs");
+    assert_eq!(format_nanos(2_000_000), "2.00 ms");
+    assert_eq!(format_nanos(3_000_000_000), "3.00 s");
+}
+
+#[test]
+fn test_drag_world_picks_up_top_card_under_cursor() {
+    let mut world = drag_world(50);
+    world.insert_resource(BenchmarkCursor(Vec2::new(-4000.0, -2500.0)));
+    world
+        .resource_mut::<ButtonInput<MouseButton>>()
+        .press(MouseButton::Left);
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(drag_at_benchmark_cursor);
+    schedule.run(&mut world);
+
+    let mut draggables = world.query::<&Draggable>();
+    assert_eq!(draggables.iter(&world).filter(|d| d.dragging).count(), 1);
+}
+
+#[test]
+fn test_suites_run() {
+    assert_eq!(bench_zone_lookups(1).iterations, 1);
+    assert_eq!(bench_stack_resolution(1).iterations, 1);
+    assert_eq!(mtgjson_cards(6).len(), 6);
+}
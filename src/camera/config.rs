@@ -1,5 +1,10 @@
 use bevy::prelude::*;
 
+/// World units kept visible vertically at a projection scale of 1, whatever the window size
+pub const VERTICAL_VIEW: f32 = 1000.0;
+/// Projection scale the game camera starts at
+pub const INITIAL_ZOOM: f32 = 5.0;
+
 /// Configuration for camera movement and zoom behavior.
 ///
 /// This resource controls how the camera responds to user input,
@@ -18,6 +23,12 @@ pub struct CameraConfig {
     pub pan_sensitivity: f32,
     /// Zoom interpolation speed (higher = smoother but slower)
     pub zoom_interpolation_speed: f32,
+    /// Duration of camera preset transitions in seconds (0 = instant)
+    pub transition_duration: f32,
+    /// Area around a player's position framed by the focus presets
+    pub focus_playmat_size: Vec2,
+    /// Extra space around framed playmats as a multiplier (1.0 = tight fit)
+    pub preset_margin: f32,
 }
 
 impl Default for CameraConfig {
@@ -31,6 +42,9 @@ impl Default for CameraConfig {
             max_zoom: 15.0, // Most zoomed out, increased from 5.0 to allow seeing the full table
             pan_sensitivity: 1.0, // Base sensitivity, adjust if needed
             zoom_interpolation_speed: 5.0, // Controls how smoothly zoom changes are applied
+            transition_duration: 0.6,
+            focus_playmat_size: Vec2::new(430.0, 330.0), // Matches the playmat size used at setup
+            preset_margin: 1.15,
         }
     }
 }
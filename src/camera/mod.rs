@@ -5,19 +5,30 @@
 /// Instead, spawn camera entities with individual components:
 pub mod components;
 pub mod config;
+pub mod presets;
 pub mod state;
 pub mod systems;
-mod tests; // Will be expanded on when tests are implemented
+pub mod table_view;
+mod tests;
+pub mod z_order; // Will be expanded on when tests are implemented
 
 // snapshot module has been moved to its own top-level module at src/snapshot
 
 use bevy::prelude::*;
 
 use crate::camera::config::CameraConfig;
+use crate::camera::presets::{
+    CameraPresetEvent, CameraTransition, handle_camera_preset_input,
+    start_camera_preset_transitions, update_camera_transition,
+};
 use crate::camera::systems::{
     camera_movement, debug_draw_card_positions, handle_window_resize,
     manage_game_camera_visibility, set_initial_zoom,
 };
+use crate::camera::table_view::{
+    TableView, apply_table_view, keep_cards_upright, rotate_table_freely, update_table_view,
+};
+use crate::menu::state::GameMenuState;
 // Import the player debug system
 use crate::player::systems::debug::debug_draw_player_positions;
 #[cfg(feature = "snapshot")]
@@ -27,7 +38,10 @@ pub struct CameraPlugin;
 
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<CameraConfig>();
+        app.init_resource::<CameraConfig>()
+            .init_resource::<CameraTransition>()
+            .init_resource::<TableView>()
+            .add_event::<CameraPresetEvent>();
 
         #[cfg(feature = "snapshot")]
         app.add_plugins(SnapshotPlugin::new());
@@ -45,9 +59,37 @@ impl Plugin for CameraPlugin {
                     // Add player debug drawing system here
                     debug_draw_player_positions,
                 ),
+            )
+            // Camera presets run after manual movement so transitions take precedence
+            .add_systems(
+                Update,
+                (
+                    handle_camera_preset_input,
+                    start_camera_preset_transitions,
+                    update_camera_transition,
+                )
+                    .chain()
+                    .after(camera_movement)
+                    .run_if(in_state(GameMenuState::InGame)),
+            )
+            // Turn the table for the local player or a spectator
+            .add_systems(
+                Update,
+                (rotate_table_freely, update_table_view, apply_table_view)
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame)),
+            )
+            // After anything else turning cards, such as tapping them
+            .add_systems(
+                PostUpdate,
+                keep_cards_upright
+                    .before(TransformSystem::TransformPropagate)
+                    .run_if(in_state(GameMenuState::InGame)),
             );
     }
 }
 
 // Re-export key items for convenience
+pub use presets::{CameraPreset, CameraPresetEvent};
 pub use state::CameraPanState;
+pub use table_view::TableView;
@@ -0,0 +1,231 @@
+//! Camera framing presets with smooth transitions
+//!
+//! Presets frame the whole table or a single player's playmat. Selecting a preset
+//! starts a [`CameraTransition`] that eases the game camera's position and zoom to
+//! the target over [`CameraConfig::transition_duration`] seconds.
+//!
+//! Keyboard shortcuts:
+//! - `0` or `Home`: overview of the whole table
+//! - `H`: focus the local player's board
+//! - `1`-`9`: focus each opponent's playmat in seat order
+
+use bevy::prelude::*;
+
+use crate::camera::{components::GameCamera, config::CameraConfig};
+use crate::player::components::Player;
+
+/// Index of the player sitting at this client's seat
+pub const LOCAL_PLAYER_INDEX: usize = 0;
+
+/// Number keys used to focus opponents, in seat order
+const OPPONENT_KEYS: [KeyCode; 9] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+];
+
+/// A camera framing preset
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CameraPreset {
+    /// Frame every player's playmat
+    Overview,
+    /// Frame a single player's playmat, by player index
+    FocusPlayer(usize),
+}
+
+/// Event requesting the camera to move to a preset
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CameraPresetEvent(pub CameraPreset);
+
+/// An in-progress camera move between two framings
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CameraTransition {
+    /// Camera translation when the transition started
+    pub start_translation: Vec3,
+    /// Projection scale when the transition started
+    pub start_scale: f32,
+    /// Camera translation at the end of the transition
+    pub target_translation: Vec3,
+    /// Projection scale at the end of the transition
+    pub target_scale: f32,
+    /// Seconds since the transition started
+    pub elapsed: f32,
+    /// Total length of the transition in seconds
+    pub duration: f32,
+    /// Whether the transition is still running
+    pub active: bool,
+}
+
+impl CameraTransition {
+    /// Start a transition from the current framing to a target
+    pub fn start(&mut self, from: (Vec3, f32), to: (Vec3, f32), duration: f32) {
+        *self = Self {
+            start_translation: from.0,
+            start_scale: from.1,
+            target_translation: to.0,
+            target_scale: to.1,
+            elapsed: 0.0,
+            duration: duration.max(0.0),
+            active: true,
+        };
+    }
+
+    /// Normalized progress of the transition in `0.0..=1.0`
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Camera translation and scale at the current progress
+    pub fn sample(&self) -> (Vec3, f32) {
+        let t = ease_in_out(self.progress());
+        (
+            self.start_translation.lerp(self.target_translation, t),
+            self.start_scale + (self.target_scale - self.start_scale) * t,
+        )
+    }
+}
+
+/// Smoothstep easing so transitions start and end gently
+pub fn ease_in_out(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Projection scale that fits `bounds` into a view of `view_size` world units at scale 1
+pub fn fit_scale(bounds: Vec2, view_size: Vec2, margin: f32) -> f32 {
+    if view_size.x <= 0.0 || view_size.y <= 0.0 {
+        return 1.0;
+    }
+    let padded = bounds * margin;
+    (padded.x / view_size.x).max(padded.y / view_size.y)
+}
+
+/// Player indices of the opponents, in seat order
+pub fn opponent_indices(player_indices: impl IntoIterator<Item = usize>) -> Vec<usize> {
+    let mut opponents: Vec<usize> = player_indices
+        .into_iter()
+        .filter(|index| *index != LOCAL_PLAYER_INDEX)
+        .collect();
+    opponents.sort_unstable();
+    opponents
+}
+
+/// Translate preset keyboard shortcuts into preset events
+pub fn handle_camera_preset_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    players: Query<&Player>,
+    mut presets: EventWriter<CameraPresetEvent>,
+) {
+    if keyboard.just_pressed(KeyCode::Digit0) || keyboard.just_pressed(KeyCode::Home) {
+        presets.write(CameraPresetEvent(CameraPreset::Overview));
+    }
+
+    if keyboard.just_pressed(KeyCode::KeyH) {
+        presets.write(CameraPresetEvent(CameraPreset::FocusPlayer(
+            LOCAL_PLAYER_INDEX,
+        )));
+    }
+
+    let opponents = opponent_indices(players.iter().map(|player| player.player_index));
+    for (key, player_index) in OPPONENT_KEYS.iter().zip(opponents) {
+        if keyboard.just_pressed(*key) {
+            presets.write(CameraPresetEvent(CameraPreset::FocusPlayer(player_index)));
+        }
+    }
+}
+
+/// Start a camera transition for each requested preset
+pub fn start_camera_preset_transitions(
+    mut presets: EventReader<CameraPresetEvent>,
+    players: Query<(&Player, &Transform), Without<GameCamera>>,
+    camera_query: Query<(&Transform, &Projection), With<GameCamera>>,
+    config: Res<CameraConfig>,
+    mut transition: ResMut<CameraTransition>,
+) {
+    let Some(CameraPresetEvent(preset)) = presets.read().last().copied() else {
+        return;
+    };
+
+    let Ok((camera_transform, projection)) = camera_query.single() else {
+        return;
+    };
+    let Projection::Orthographic(orthographic) = projection else {
+        warn!("GameCamera does not have an OrthographicProjection for camera presets.");
+        return;
+    };
+
+    // The projection area already includes the current scale
+    let view_size = orthographic.area.size() / orthographic.scale.max(f32::EPSILON);
+    let playmat_size = config.focus_playmat_size;
+
+    let positions: Vec<Vec2> = match preset {
+        CameraPreset::Overview => players
+            .iter()
+            .map(|(_, transform)| transform.translation.truncate())
+            .collect(),
+        CameraPreset::FocusPlayer(index) => players
+            .iter()
+            .filter(|(player, _)| player.player_index == index)
+            .map(|(_, transform)| transform.translation.truncate())
+            .collect(),
+    };
+
+    if positions.is_empty() {
+        debug!("No players found for camera preset {:?}", preset);
+        return;
+    }
+
+    let min = positions.iter().fold(Vec2::MAX, |acc, p| acc.min(*p)) - playmat_size / 2.0;
+    let max = positions.iter().fold(Vec2::MIN, |acc, p| acc.max(*p)) + playmat_size / 2.0;
+    let center = (min + max) / 2.0;
+    let target_scale = fit_scale(max - min, view_size, config.preset_margin)
+        .clamp(config.min_zoom, config.max_zoom);
+
+    info!(
+        "Camera preset {:?}: center {:?}, scale {:.2}",
+        preset, center, target_scale
+    );
+
+    transition.start(
+        (camera_transform.translation, orthographic.scale),
+        (center.extend(camera_transform.translation.z), target_scale),
+        config.transition_duration,
+    );
+}
+
+/// Advance the active camera transition
+pub fn update_camera_transition(
+    time: Res<Time>,
+    mut transition: ResMut<CameraTransition>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<GameCamera>>,
+) {
+    if !transition.active {
+        return;
+    }
+
+    let Ok((mut transform, mut projection)) = camera_query.single_mut() else {
+        transition.active = false;
+        return;
+    };
+
+    transition.elapsed += time.delta_secs();
+    let (translation, scale) = transition.sample();
+    transform.translation = translation;
+    if let Projection::Orthographic(ref mut orthographic) = *projection {
+        orthographic.scale = scale;
+    }
+
+    if transition.progress() >= 1.0 {
+        transition.active = false;
+    }
+}
@@ -7,7 +7,7 @@ use bevy::window::{PrimaryWindow, WindowResized};
 
 use crate::camera::{
     components::{AppLayer, GameCamera},
-    config::CameraConfig,
+    config::{CameraConfig, INITIAL_ZOOM, VERTICAL_VIEW},
     state::CameraPanState,
 };
 use crate::menu::state::GameMenuState;
@@ -109,7 +109,7 @@ pub fn set_initial_zoom(
             // In OrthographicProjection, higher scale = more zoomed out
             // orthographic_projection.scale = 500.0; // Drastically increased scale to see distant playmats
             // Let's try a much smaller initial scale
-            orthographic_projection.scale = 5.0; // Significantly reduced scale
+            orthographic_projection.scale = INITIAL_ZOOM;
 
             info!(
                 "Successfully set initial camera zoom level to {:.2}",
@@ -138,12 +138,6 @@ pub fn handle_window_resize(
     _windows: Query<&Window>,
     _config: Res<CameraConfig>,
 ) -> Result<(), BevyError> {
-    // Define the desired fixed vertical view size in world units.
-    // This could be based on your game's design, e.g., ensuring a certain
-    // number of units are always visible vertically. Let's use a value from config or a constant.
-    // If not, let's define a reasonable constant for now.
-    const FIXED_VERTICAL_VIEW: f32 = 1000.0; // Example: Keep 1000 world units vertically visible
-
     // Attempt to get the single game camera's projection.
     // If it's not found, or if it's not an OrthographicProjection, log and return.
     let Ok(mut projection_enum) = projection_query.single_mut() else {
@@ -158,8 +152,8 @@ pub fn handle_window_resize(
 
     for resize_event in resize_events.read() {
         let aspect_ratio = resize_event.width / resize_event.height;
-        let new_height = FIXED_VERTICAL_VIEW; // Fixed vertical size
-        let new_width = FIXED_VERTICAL_VIEW * aspect_ratio; // Calculate width based on aspect ratio
+        let new_height = VERTICAL_VIEW; // Fixed vertical size
+        let new_width = VERTICAL_VIEW * aspect_ratio; // Calculate width based on aspect ratio
 
         // Update the projection's view area
         orthographic_projection.area = Rect::new(
@@ -240,7 +234,8 @@ pub fn camera_movement(
         movement = movement.normalize() * config.move_speed * time.delta_secs();
         // Scale movement by current zoom level to maintain consistent speed
         movement *= orthographic_projection.scale;
-        transform.translation += movement;
+        // Move relative to the screen, however the table is turned
+        transform.translation += transform.rotation * movement;
     }
 
     // Handle middle mouse button panning
@@ -263,7 +258,7 @@ pub fn camera_movement(
                     delta.y * config.pan_sensitivity * orthographic_projection.scale,
                     0.0,
                 );
-                transform.translation += movement;
+                transform.translation += transform.rotation * movement;
                 pan_state.last_mouse_pos = Some(cursor_pos);
             }
         }
@@ -0,0 +1,193 @@
+//! Rotating the table on screen
+//!
+//! The table is laid out with the first seat at the bottom. The game camera is
+//! turned by [`TableView::angle`] so that, depending on the table rotation
+//! gameplay setting, the local player sits at the bottom whatever their seat,
+//! the seats stay where they were laid out, or a spectator turns the table
+//! freely with `Q` and `E`. Turning the camera turns every playmat with it.
+//!
+//! With upright cards on, each card is turned to read upright on screen rather
+//! than facing the player across the table whose playmat it's on. Tapped cards
+//! are still turned sideways.
+
+use bevy::ecs::hierarchy::ChildOf;
+use bevy::prelude::*;
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+use crate::camera::components::GameCamera;
+use crate::camera::presets::LOCAL_PLAYER_INDEX;
+use crate::cards::Card;
+use crate::cards::animation::CardAnimation;
+use crate::game_engine::permanent::PermanentState;
+use crate::menu::settings::components::{GameplaySettings, TableRotation};
+use crate::player::components::Player;
+use crate::player::systems::spawn::table::TableLayout;
+
+/// How fast the table turns while `Q` or `E` is held, in radians per second
+pub const FREE_ROTATION_SPEED: f32 = 1.5;
+
+/// How the table is currently turned on screen
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct TableView {
+    /// Rotation of the game camera, in radians counter-clockwise
+    pub angle: f32,
+    /// Rotation chosen with `Q` and `E` in free rotation
+    pub free_angle: f32,
+}
+
+/// `angle` wrapped into `0.0..TAU`
+pub fn normalize_angle(angle: f32) -> f32 {
+    angle.rem_euclid(TAU)
+}
+
+/// Camera rotation bringing the seat at `seat_angle` around the table to the
+/// bottom of the screen
+///
+/// Seat angles are measured clockwise from the top of the table, as in
+/// [`TableLayout::get_player_angle`], so the first seat at `PI` needs no
+/// rotation.
+pub fn seat_view_angle(seat_angle: f32) -> f32 {
+    normalize_angle(PI - seat_angle)
+}
+
+/// Camera rotation for a table rotation setting
+///
+/// Without a local seat, as for a spectator, the table isn't turned to one.
+pub fn view_angle(rotation: TableRotation, local_seat_angle: Option<f32>, free_angle: f32) -> f32 {
+    match rotation {
+        TableRotation::LocalAtBottom => local_seat_angle.map_or(0.0, seat_view_angle),
+        TableRotation::FixedSeats => 0.0,
+        TableRotation::Free => normalize_angle(free_angle),
+    }
+}
+
+/// Local rotation turning a card under ancestors rotated by `ancestors` to read
+/// upright under a camera rotated by `view`
+pub fn upright_rotation(ancestors: Quat, view: Quat, tapped: bool) -> Quat {
+    ancestors.inverse() * view * tap_rotation(tapped)
+}
+
+/// Local rotation of a card that's tapped or not
+pub fn tap_rotation(tapped: bool) -> Quat {
+    if tapped {
+        Quat::from_rotation_z(-FRAC_PI_2)
+    } else {
+        Quat::IDENTITY
+    }
+}
+
+/// Turn the table with `Q` and `E` in free rotation
+pub fn rotate_table_freely(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Option<Res<GameplaySettings>>,
+    time: Res<Time>,
+    mut view: ResMut<TableView>,
+) {
+    if settings.is_none_or(|settings| settings.table_rotation != TableRotation::Free) {
+        return;
+    }
+    let mut direction = 0.0;
+    if keyboard.pressed(KeyCode::KeyQ) {
+        direction += 1.0;
+    }
+    if keyboard.pressed(KeyCode::KeyE) {
+        direction -= 1.0;
+    }
+    if direction != 0.0 {
+        view.free_angle =
+            normalize_angle(view.free_angle + direction * FREE_ROTATION_SPEED * time.delta_secs());
+    }
+}
+
+/// Work out the camera rotation from the table rotation setting and the local
+/// player's seat
+pub fn update_table_view(
+    settings: Option<Res<GameplaySettings>>,
+    table: Option<Res<TableLayout>>,
+    players: Query<&Player>,
+    mut view: ResMut<TableView>,
+) {
+    let rotation = settings.map_or_else(TableRotation::default, |settings| settings.table_rotation);
+    let local_seat_angle = table.and_then(|table| {
+        players
+            .iter()
+            .find(|player| player.player_index == LOCAL_PLAYER_INDEX)
+            .map(|player| table.get_player_angle(player.player_index))
+    });
+    let angle = view_angle(rotation, local_seat_angle, view.free_angle);
+    if view.angle != angle {
+        view.angle = angle;
+    }
+}
+
+/// Turn the game camera to the table view
+///
+/// Rotating the camera keeps its position, so a focused playmat stays framed.
+pub fn apply_table_view(
+    view: Res<TableView>,
+    mut cameras: Query<&mut Transform, With<GameCamera>>,
+) {
+    if !view.is_changed() {
+        return;
+    }
+    let rotation = Quat::from_rotation_z(view.angle);
+    for mut transform in cameras.iter_mut() {
+        transform.rotation = rotation;
+    }
+}
+
+/// Turn cards to read upright on screen while the upright cards setting is on,
+/// and back to facing their playmat when it's turned off
+///
+/// Cards mid-animation are left to their animation.
+#[allow(clippy::type_complexity)]
+pub fn keep_cards_upright(
+    settings: Option<Res<GameplaySettings>>,
+    view: Res<TableView>,
+    mut was_upright: Local<bool>,
+    mut cards: Query<
+        (
+            &mut Transform,
+            Option<Ref<PermanentState>>,
+            Option<Ref<ChildOf>>,
+        ),
+        (With<Card>, Without<CardAnimation>),
+    >,
+    ancestors: Query<(&Transform, Option<&ChildOf>), Without<Card>>,
+) {
+    let upright = settings.is_some_and(|settings| settings.upright_cards);
+    let switched = upright != *was_upright;
+    *was_upright = upright;
+    if !upright && !switched {
+        return;
+    }
+    let refresh_all = switched || view.is_changed();
+    let view = Quat::from_rotation_z(view.angle);
+
+    for (mut transform, state, parent) in cards.iter_mut() {
+        let moved = transform.is_changed()
+            || state.as_ref().is_some_and(|state| state.is_changed())
+            || parent.as_ref().is_some_and(|parent| parent.is_changed());
+        if !refresh_all && !moved {
+            continue;
+        }
+
+        let tapped = state.is_some_and(|state| state.is_tapped);
+        let rotation = if upright {
+            let mut turned = Quat::IDENTITY;
+            let mut next = parent.map(|parent| parent.parent());
+            while let Some((ancestor, grandparent)) =
+                next.and_then(|entity| ancestors.get(entity).ok())
+            {
+                turned = ancestor.rotation * turned;
+                next = grandparent.map(ChildOf::parent);
+            }
+            upright_rotation(turned, view, tapped)
+        } else {
+            tap_rotation(tapped)
+        };
+        if transform.rotation != rotation {
+            transform.rotation = rotation;
+        }
+    }
+}
@@ -1 +1,212 @@
+#[cfg(test)]
+mod presets {
+    use bevy::prelude::*;
 
+    use crate::camera::presets::{CameraTransition, ease_in_out, fit_scale, opponent_indices};
+
+    #[test]
+    fn test_ease_in_out_endpoints() {
+        assert_eq!(ease_in_out(0.0), 0.0);
+        assert_eq!(ease_in_out(1.0), 1.0);
+        assert_eq!(ease_in_out(0.5), 0.5);
+        assert_eq!(ease_in_out(2.0), 1.0);
+    }
+
+    #[test]
+    fn test_transition_reaches_target() {
+        let mut transition = CameraTransition::default();
+        transition.start((Vec3::ZERO, 1.0), (Vec3::new(100.0, 50.0, 0.0), 3.0), 0.5);
+
+        assert_eq!(transition.sample(), (Vec3::ZERO, 1.0));
+
+        transition.elapsed = 0.5;
+        assert_eq!(transition.sample(), (Vec3::new(100.0, 50.0, 0.0), 3.0));
+        assert_eq!(transition.progress(), 1.0);
+    }
+
+    #[test]
+    fn test_zero_duration_transition_is_instant() {
+        let mut transition = CameraTransition::default();
+        transition.start((Vec3::ZERO, 1.0), (Vec3::X, 2.0), 0.0);
+        assert_eq!(transition.sample(), (Vec3::X, 2.0));
+    }
+
+    #[test]
+    fn test_fit_scale_uses_limiting_axis() {
+        let view = Vec2::new(1600.0, 900.0);
+        assert_eq!(fit_scale(Vec2::new(3200.0, 900.0), view, 1.0), 2.0);
+        assert_eq!(fit_scale(Vec2::new(800.0, 2700.0), view, 1.0), 3.0);
+    }
+
+    #[test]
+    fn test_opponents_exclude_local_player() {
+        assert_eq!(opponent_indices([3, 0, 1, 2]), vec![1, 2, 3]);
+    }
+}
+
+#[cfg(test)]
+mod z_order {
+    use bevy::prelude::*;
+
+    use crate::camera::z_order::{SLOTS_PER_BAND, ZBand, ZOrder};
+
+    #[test]
+    fn test_bands_do_not_overlap() {
+        for pair in ZBand::ALL.windows(2) {
+            assert!(pair[0].z(SLOTS_PER_BAND) < pair[1].base());
+            assert_eq!(ZBand::containing(pair[1].z(3)), pair[1]);
+        }
+        assert_eq!(ZBand::containing(-1.0), ZBand::Table);
+        assert_eq!(ZBand::containing(1000.0), ZBand::Dragged);
+    }
+
+    #[test]
+    fn test_dragging_and_dropping_stays_in_bands() {
+        let mut z_order = ZOrder::default();
+        let card = Entity::from_raw(1);
+        z_order.observe(ZBand::Hand.z(4));
+
+        for _ in 0..10_000 {
+            let dragged = z_order.begin_drag(card, ZBand::Hand.z(4));
+            assert_eq!(ZBand::containing(dragged), ZBand::Dragged);
+            let dropped = z_order.end_drag(card);
+            assert_eq!(ZBand::containing(dropped), ZBand::Hand);
+        }
+        assert!(z_order.is_crowded());
+        assert!(!z_order.is_dragging());
+    }
+
+    #[test]
+    fn test_normalize_packs_bands_keeping_order() {
+        let mut z_order = ZOrder::default();
+        let [low, high, hand] = [1, 2, 3].map(Entity::from_raw);
+        let moved = z_order.normalize([
+            (high, ZBand::Battlefield.z(500)),
+            (low, ZBand::Battlefield.z(90)),
+            (hand, ZBand::Hand.z(0)),
+        ]);
+
+        assert_eq!(
+            moved,
+            vec![
+                (low, ZBand::Battlefield.z(0)),
+                (high, ZBand::Battlefield.z(1))
+            ]
+        );
+        assert_eq!(z_order.raise(ZBand::Battlefield), ZBand::Battlefield.z(2));
+        assert_eq!(z_order.raise(ZBand::Hand), ZBand::Hand.z(1));
+    }
+}
+
+#[cfg(test)]
+mod table_view {
+    use bevy::prelude::*;
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    use crate::camera::table_view::{
+        TableView, keep_cards_upright, seat_view_angle, upright_rotation, view_angle,
+    };
+    use crate::cards::{Card, CardDetails, CardTypes};
+    use crate::game_engine::permanent::PermanentState;
+    use crate::mana::Mana;
+    use crate::menu::settings::components::{GameplaySettings, TableRotation};
+    use crate::player::systems::spawn::table::TableLayout;
+
+    /// Whether two rotations turn things the same way
+    fn same_rotation(a: Quat, b: Quat) -> bool {
+        (a * Vec3::X).abs_diff_eq(b * Vec3::X, 1e-4)
+    }
+
+    #[test]
+    fn test_each_seat_is_turned_to_the_bottom() {
+        let table = TableLayout::new(4, 500.0);
+        assert_eq!(seat_view_angle(table.get_player_angle(0)), 0.0);
+        for seat in 0..4 {
+            // The seat's position ends up straight below the table's center
+            let position = table.get_player_position(seat).translation;
+            let view = Quat::from_rotation_z(seat_view_angle(table.get_player_angle(seat)));
+            let on_screen = view.inverse() * position;
+            assert!(on_screen.x.abs() < 1e-3, "seat {seat} at {on_screen}");
+            assert!(on_screen.y < 0.0, "seat {seat} at {on_screen}");
+        }
+    }
+
+    #[test]
+    fn test_view_angle_follows_the_setting() {
+        let top = Some(0.0);
+        assert!((view_angle(TableRotation::LocalAtBottom, top, 1.0) - PI).abs() < 1e-5);
+        assert_eq!(view_angle(TableRotation::FixedSeats, top, 1.0), 0.0);
+        assert_eq!(view_angle(TableRotation::Free, top, 1.0), 1.0);
+        // Spectators have no seat to turn to
+        assert_eq!(view_angle(TableRotation::LocalAtBottom, None, 1.0), 0.0);
+        assert_eq!(TableRotation::Free.next(), TableRotation::LocalAtBottom);
+    }
+
+    #[test]
+    fn test_upright_cards_read_upright_on_screen() {
+        let playmat = Quat::from_rotation_z(PI);
+        let view = Quat::from_rotation_z(FRAC_PI_2);
+        for tapped in [false, true] {
+            let local = upright_rotation(playmat, view, tapped);
+            let tap = if tapped { -FRAC_PI_2 } else { 0.0 };
+            assert!(same_rotation(
+                view.inverse() * playmat * local,
+                Quat::from_rotation_z(tap)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_cards_on_an_opponents_playmat_are_turned_upright() {
+        let mut app = App::new();
+        app.init_resource::<TableView>()
+            .insert_resource(GameplaySettings {
+                upright_cards: true,
+                ..default()
+            })
+            .add_systems(Update, keep_cards_upright);
+        let card = Card::builder("Grizzly Bears")
+            .cost(Mana::default())
+            .types(CardTypes::CREATURE)
+            .details(CardDetails::Other)
+            .build_or_panic();
+        let world = app.world_mut();
+        // The playmat across the table faces its owner
+        let playmat = world
+            .spawn(Transform::from_rotation(Quat::from_rotation_z(PI)))
+            .id();
+        let zone = world.spawn((Transform::default(), ChildOf(playmat))).id();
+        let card = world
+            .spawn((
+                card,
+                Transform::default(),
+                PermanentState::new(1),
+                ChildOf(zone),
+            ))
+            .id();
+
+        let rotation = |app: &App| app.world().get::<Transform>(card).unwrap().rotation;
+        app.update();
+        assert!(same_rotation(rotation(&app), Quat::from_rotation_z(-PI)));
+
+        app.world_mut()
+            .get_mut::<PermanentState>(card)
+            .unwrap()
+            .is_tapped = true;
+        app.update();
+        assert!(same_rotation(
+            Quat::from_rotation_z(PI) * rotation(&app),
+            Quat::from_rotation_z(-FRAC_PI_2)
+        ));
+
+        // Turned back to face the playmat when the setting is off
+        app.world_mut()
+            .resource_mut::<GameplaySettings>()
+            .upright_cards = false;
+        app.update();
+        assert!(same_rotation(
+            rotation(&app),
+            Quat::from_rotation_z(-FRAC_PI_2)
+        ));
+    }
+}
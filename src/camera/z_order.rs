@@ -0,0 +1,230 @@
+//! Depth ordering for cards on the table and for screen-space UI
+//!
+//! Cards are drawn in 2D, so the only thing deciding which card covers another is
+//! translation z. Every kind of card sits in its own [`ZBand`], a fixed slice of
+//! depth, and stacks within that band in slots [`SLOT_STEP`] apart:
+//!
+//! - table: playmats and zone markers
+//! - battlefield: permanents, laid out by the battlefield grid
+//! - hand, stack: cards in hand and spells being cast
+//! - dragged: the card under the cursor, above everything else
+//!
+//! Raising a card to the top of its band takes the next free slot from [`ZOrder`],
+//! and every few seconds [`normalize_card_z_order`] packs the slots back down so
+//! they never run out. UI nodes that float over the game use [`UiLayer`] instead
+//! of hand-picked [`GlobalZIndex`] values.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::cards::Card;
+use crate::cards::components::Draggable;
+
+/// Depth of each band, in world units
+pub const BAND_DEPTH: f32 = 10.0;
+/// Depth between two cards stacked in the same band
+pub const SLOT_STEP: f32 = 0.01;
+/// Slots in a band, leaving room above the top slot for a card's text and frame
+pub const SLOTS_PER_BAND: usize = 800;
+/// Seconds between two normalizations of the card z values
+pub const NORMALIZE_INTERVAL: f32 = 5.0;
+
+/// A slice of depth that one kind of card is drawn in, from back to front
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ZBand {
+    /// Playmats and the zones drawn on them
+    Table,
+    /// Permanents on the battlefield
+    Battlefield,
+    /// Cards in hand
+    Hand,
+    /// Spells and abilities on the stack
+    Stack,
+    /// The card being dragged
+    Dragged,
+}
+
+impl ZBand {
+    /// Every band, from back to front
+    pub const ALL: [ZBand; 5] = [
+        ZBand::Table,
+        ZBand::Battlefield,
+        ZBand::Hand,
+        ZBand::Stack,
+        ZBand::Dragged,
+    ];
+
+    /// The z value of the bottom of the band
+    pub fn base(self) -> f32 {
+        self as usize as f32 * BAND_DEPTH
+    }
+
+    /// The z value of `slot` in this band, clamped to the band's top slot
+    pub fn z(self, slot: usize) -> f32 {
+        self.base() + slot.min(SLOTS_PER_BAND - 1) as f32 * SLOT_STEP
+    }
+
+    /// The band a z value falls in
+    pub fn containing(z: f32) -> Self {
+        let index = (z / BAND_DEPTH).floor().max(0.0) as usize;
+        Self::ALL[index.min(Self::ALL.len() - 1)]
+    }
+
+    /// The slot of this band nearest to `z`
+    fn slot_of(self, z: f32) -> usize {
+        ((z - self.base()) / SLOT_STEP).round().max(0.0) as usize
+    }
+}
+
+/// Screen-space layers for UI drawn over the game, as [`GlobalZIndex`] values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiLayer {
+    /// Prompts and panels the player answers or dismisses
+    Dialog = 50,
+    /// Notifications, hints and errors shown over any dialog
+    Overlay = 100,
+}
+
+impl UiLayer {
+    /// Convert to a GlobalZIndex component
+    pub fn global_z_index(self) -> GlobalZIndex {
+        GlobalZIndex(self as i32)
+    }
+}
+
+impl From<UiLayer> for GlobalZIndex {
+    fn from(layer: UiLayer) -> Self {
+        layer.global_z_index()
+    }
+}
+
+/// Hands out z values at the top of each band
+#[derive(Resource, Debug)]
+pub struct ZOrder {
+    /// The slot the next raised card gets in each band
+    next_slot: HashMap<ZBand, usize>,
+    /// The bands the cards being dragged were picked up from
+    dragged_from: HashMap<Entity, ZBand>,
+    normalize_timer: Timer,
+}
+
+impl Default for ZOrder {
+    fn default() -> Self {
+        Self {
+            next_slot: HashMap::new(),
+            dragged_from: HashMap::new(),
+            normalize_timer: Timer::from_seconds(NORMALIZE_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+impl ZOrder {
+    /// Note a card placed at `z` without going through the manager, so cards
+    /// raised later go above it
+    pub fn observe(&mut self, z: f32) {
+        let band = ZBand::containing(z);
+        let next = self.next_slot.entry(band).or_default();
+        *next = (*next).max(band.slot_of(z) + 1);
+    }
+
+    /// A z value above every other card in `band`
+    ///
+    /// Once the band is full every raised card shares its top slot until the
+    /// band is normalized.
+    pub fn raise(&mut self, band: ZBand) -> f32 {
+        let next = self.next_slot.entry(band).or_default();
+        let z = band.z(*next);
+        *next = (*next + 1).min(SLOTS_PER_BAND);
+        z
+    }
+
+    /// Whether any band has used up its slots
+    pub fn is_crowded(&self) -> bool {
+        self.next_slot.values().any(|&next| next >= SLOTS_PER_BAND)
+    }
+
+    /// Pick up a card drawn at `z`, returning the z to draw it at while dragged
+    pub fn begin_drag(&mut self, entity: Entity, z: f32) -> f32 {
+        self.dragged_from.insert(entity, ZBand::containing(z));
+        self.raise(ZBand::Dragged)
+    }
+
+    /// Drop a dragged card on top of the band it was picked up from
+    pub fn end_drag(&mut self, entity: Entity) -> f32 {
+        let band = self
+            .dragged_from
+            .remove(&entity)
+            .unwrap_or(ZBand::Battlefield);
+        if self.dragged_from.is_empty() {
+            self.next_slot.remove(&ZBand::Dragged);
+        }
+        self.raise(band)
+    }
+
+    /// Whether any card is being dragged
+    pub fn is_dragging(&self) -> bool {
+        !self.dragged_from.is_empty()
+    }
+
+    /// Pack the cards of each band into its lowest slots, keeping their order
+    ///
+    /// Returns the cards whose z changed, with their new z.
+    pub fn normalize(
+        &mut self,
+        cards: impl IntoIterator<Item = (Entity, f32)>,
+    ) -> Vec<(Entity, f32)> {
+        let mut bands: HashMap<ZBand, Vec<(Entity, f32)>> = HashMap::new();
+        for (entity, z) in cards {
+            bands
+                .entry(ZBand::containing(z))
+                .or_default()
+                .push((entity, z));
+        }
+
+        self.next_slot.clear();
+        let mut moved = Vec::new();
+        for (band, mut cards) in bands {
+            cards.sort_by(|(a_entity, a), (b_entity, b)| {
+                a.total_cmp(b).then(a_entity.cmp(b_entity))
+            });
+            for (slot, &(entity, z)) in cards.iter().enumerate() {
+                let packed = band.z(slot);
+                if packed != z {
+                    moved.push((entity, packed));
+                }
+            }
+            self.next_slot
+                .insert(band, cards.len().min(SLOTS_PER_BAND - 1));
+        }
+        moved
+    }
+}
+
+/// Pack card z values back into their bands every few seconds, or as soon as a
+/// band gets crowded
+///
+/// Only cards placed directly in the world are packed; cards laid out inside a
+/// zone, like the battlefield grid, are ordered by that zone. Nothing is packed
+/// while a card is being dragged.
+pub fn normalize_card_z_order(
+    time: Res<Time>,
+    mut z_order: ResMut<ZOrder>,
+    mut cards: Query<(Entity, &mut Transform, &mut Draggable), (With<Card>, Without<ChildOf>)>,
+) {
+    let due = z_order.normalize_timer.tick(time.delta()).just_finished();
+    if !(due || z_order.is_crowded()) || z_order.is_dragging() {
+        return;
+    }
+
+    let moved = z_order.normalize(
+        cards
+            .iter()
+            .map(|(entity, _, draggable)| (entity, draggable.z_index)),
+    );
+    for (entity, z) in moved {
+        if let Ok((_, mut transform, mut draggable)) = cards.get_mut(entity) {
+            draggable.z_index = z;
+            transform.translation.z = z;
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::game_engine::phase::Phase;
 use crate::mana::Mana;
 use bevy::prelude::*;
 
@@ -40,7 +41,10 @@ pub enum TriggerCondition {
     EnterTheBattlefield,
     /// Triggers when the card leaves the battlefield
     LeaveTheBattlefield,
-    /// Triggers at the beginning of a specific phase
+    /// Triggers at the beginning of a step, such as upkeep or the end step
+    ///
+    /// "At the beginning of combat" is the beginning of combat step, and "at the
+    /// beginning of your precombat main phase" is its main step.
     BeginningOfPhase(Phase),
     /// Triggers when a specific action happens
     OnAction(String),
@@ -54,21 +58,17 @@ pub enum TriggerCondition {
     WhenCreatureDies,
     /// Triggers when a player casts a specific type of spell
     WhenPlayerCasts(String),
+    /// Triggers whenever the card's controller gains life
+    WhenControllerGainsLife,
     /// Custom trigger condition (for complex abilities)
     Custom(String),
 }
 
-/// Game phases for phase-based triggers
-#[derive(Debug, Clone, Reflect)]
-pub enum Phase {
-    Untap,
-    Upkeep,
-    Draw,
-    MainOne,
-    Combat,
-    MainTwo,
-    End,
-    Cleanup,
+impl TriggerCondition {
+    /// Whether this condition triggers as `step` begins
+    pub fn triggers_at_beginning_of(&self, step: Phase) -> bool {
+        matches!(self, TriggerCondition::BeginningOfPhase(phase) if *phase == step)
+    }
 }
 
 impl ActivatedAbility {
@@ -118,6 +118,17 @@ impl TriggeredAbility {
         }
     }
 
+    /// Creates an "at the beginning of" triggered ability
+    #[allow(dead_code)]
+    pub fn at_beginning_of(step: Phase, description: &str) -> Self {
+        Self {
+            trigger: TriggerCondition::BeginningOfPhase(step),
+            description: description.to_string(),
+            once_per_turn: false,
+            optional: false,
+        }
+    }
+
     /// Makes this ability optional ("you may...")
     #[allow(dead_code)]
     pub fn optional(mut self) -> Self {
@@ -1 +1,20 @@
 // Tests for abilities
+#[cfg(test)]
+mod tests {
+    use crate::cards::abilities::{TriggerCondition, TriggeredAbility};
+    use crate::game_engine::phase::{BeginningStep, EndingStep, Phase};
+
+    #[test]
+    fn test_beginning_of_step_triggers_use_engine_steps() {
+        let upkeep = Phase::Beginning(BeginningStep::Upkeep);
+        let ability = TriggeredAbility::at_beginning_of(upkeep, "draw a card");
+
+        assert!(ability.trigger.triggers_at_beginning_of(upkeep));
+        assert!(
+            !ability
+                .trigger
+                .triggers_at_beginning_of(Phase::Ending(EndingStep::End))
+        );
+        assert!(!TriggerCondition::EnterTheBattlefield.triggers_at_beginning_of(upkeep));
+    }
+}
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+
+use crate::game_engine::zones::Zone;
+
+/// Kind of card movement being animated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardAnimationKind {
+    /// Library to hand, along an arc
+    Draw,
+    /// Hand to battlefield, easing out as the card lands
+    Play,
+    /// Battlefield to graveyard, accelerating away with a spin
+    Destroy,
+    /// Any other zone change
+    Move,
+}
+
+impl CardAnimationKind {
+    /// Animation used for a move between two zones
+    pub fn for_zones(source: Zone, destination: Zone) -> Self {
+        match (source, destination) {
+            (Zone::Library, Zone::Hand) => CardAnimationKind::Draw,
+            (Zone::Hand, Zone::Battlefield) => CardAnimationKind::Play,
+            (Zone::Battlefield, Zone::Graveyard) => CardAnimationKind::Destroy,
+            _ => CardAnimationKind::Move,
+        }
+    }
+
+    /// Easing curve applied to the animation progress
+    pub fn ease(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            // Smoothstep
+            CardAnimationKind::Draw | CardAnimationKind::Move => t * t * (3.0 - 2.0 * t),
+            // Cubic ease out
+            CardAnimationKind::Play => 1.0 - (1.0 - t).powi(3),
+            // Quadratic ease in
+            CardAnimationKind::Destroy => t * t,
+        }
+    }
+}
+
+/// A card tweening between two world positions
+#[derive(Component, Debug, Clone)]
+pub struct CardAnimation {
+    /// What kind of move this is
+    pub kind: CardAnimationKind,
+    /// World position at the start of the animation
+    pub from: Vec3,
+    /// World position at the end of the animation
+    pub to: Vec3,
+    /// Height of the arc above the straight path, in world units
+    pub arc_height: f32,
+    /// Total rotation applied over the animation, in radians
+    pub spin: f32,
+    /// Local rotation of the card when the animation started
+    pub base_rotation: Quat,
+    /// Seconds since the animation started
+    pub elapsed: f32,
+    /// Total length of the animation in seconds
+    pub duration: f32,
+}
+
+impl CardAnimation {
+    /// Normalized progress in `0.0..=1.0`
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Whether the animation has reached its end
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+
+    /// World position at the current progress
+    ///
+    /// The arc is a sine bump perpendicular to the path, so it is zero at both ends.
+    pub fn position(&self) -> Vec3 {
+        let t = self.kind.ease(self.progress());
+        let straight = self.from.lerp(self.to, t);
+        if self.arc_height == 0.0 {
+            return straight;
+        }
+
+        let direction = (self.to - self.from).truncate();
+        let normal = if direction.length_squared() > f32::EPSILON {
+            direction.perp().normalize()
+        } else {
+            Vec2::Y
+        };
+        let lift = (t * std::f32::consts::PI).sin() * self.arc_height;
+        straight + (normal * lift).extend(0.0)
+    }
+
+    /// Steer the animation toward a new landing spot at the same flight height
+    ///
+    /// Zone layouts call this for cards still in flight, so they land in the slot
+    /// the layout gives them rather than where the animation was first aimed.
+    pub fn retarget(&mut self, to: Vec2, rotation: Quat) {
+        self.to = to.extend(self.to.z);
+        self.base_rotation = rotation;
+    }
+
+    /// Local rotation at the current progress
+    pub fn rotation(&self) -> Quat {
+        let t = self.kind.ease(self.progress());
+        self.base_rotation * Quat::from_rotation_z(self.spin * t)
+    }
+}
+
+/// Shakes a library pile to show that it was shuffled
+#[derive(Component, Debug, Clone)]
+pub struct ShuffleAnimation {
+    /// Local translation of the pile before the shake
+    pub origin: Vec3,
+    /// Seconds since the shuffle started
+    pub elapsed: f32,
+    /// Total length of the shake in seconds
+    pub duration: f32,
+    /// Maximum sideways offset in world units
+    pub amplitude: f32,
+}
+
+impl ShuffleAnimation {
+    /// Sideways offset at the current time, decaying to zero at the end
+    pub fn offset(&self) -> Vec3 {
+        if self.duration <= 0.0 || self.elapsed >= self.duration {
+            return Vec3::ZERO;
+        }
+        let t = self.elapsed / self.duration;
+        let shakes = 4.0;
+        let x = (t * shakes * std::f32::consts::TAU).sin() * self.amplitude * (1.0 - t);
+        Vec3::new(x, 0.0, 0.0)
+    }
+}
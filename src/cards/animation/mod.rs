@@ -0,0 +1,24 @@
+//! Card movement animations
+//!
+//! Visual cards tween between zones instead of teleporting. Animations are started
+//! from [`ZoneChangeEvent`](crate::game_engine::zones::ZoneChangeEvent)s, so the
+//! logical zone code never needs to know about the renderer:
+//!
+//! - draw (library → hand) follows an arc
+//! - play (hand → battlefield) eases out as the card lands
+//! - destroy (battlefield → graveyard) accelerates away with a slight spin
+//!
+//! Library shuffles shake the library pile when a [`ShuffleAnimationEvent`] is sent.
+//! Animation length is scaled by the gameplay animation speed setting.
+
+mod components;
+mod plugin;
+mod resources;
+mod systems;
+
+pub mod tests;
+
+pub use components::{CardAnimation, CardAnimationKind, ShuffleAnimation};
+pub use plugin::{CardAnimationPlugin, CardAnimationSet};
+pub use resources::CardAnimationConfig;
+pub use systems::ShuffleAnimationEvent;
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+use super::resources::CardAnimationConfig;
+use super::systems::{
+    ShuffleAnimationEvent, start_shuffle_animations, start_zone_change_animations,
+    update_card_animations, update_shuffle_animations,
+};
+use crate::game_engine::zones::ZoneChangeEvent;
+
+/// Systems that move cards along their animations
+///
+/// Zone layouts run after this set, so a card that lands is put in its slot the
+/// same frame.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CardAnimationSet;
+
+/// Plugin for animating cards as they move between zones
+pub struct CardAnimationPlugin;
+
+impl Plugin for CardAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ZoneChangeEvent>()
+            .add_event::<ShuffleAnimationEvent>()
+            .init_resource::<CardAnimationConfig>()
+            .add_systems(
+                Update,
+                (
+                    (start_zone_change_animations, update_card_animations)
+                        .chain()
+                        .in_set(CardAnimationSet),
+                    (start_shuffle_animations, update_shuffle_animations).chain(),
+                ),
+            );
+    }
+}
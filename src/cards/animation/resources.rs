@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+
+use super::components::CardAnimationKind;
+
+/// Timing and shape of card animations
+#[derive(Resource, Debug, Clone)]
+pub struct CardAnimationConfig {
+    /// Whether zone changes are animated at all
+    pub enabled: bool,
+    /// Seconds for a draw at animation speed 1.0
+    pub draw_duration: f32,
+    /// Seconds for a play at animation speed 1.0
+    pub play_duration: f32,
+    /// Seconds for a destroy at animation speed 1.0
+    pub destroy_duration: f32,
+    /// Seconds for other zone changes at animation speed 1.0
+    pub move_duration: f32,
+    /// Seconds for a library shuffle at animation speed 1.0
+    pub shuffle_duration: f32,
+    /// Arc height of the draw animation in world units
+    pub draw_arc_height: f32,
+    /// Spin applied to destroyed cards, in radians
+    pub destroy_spin: f32,
+    /// Z offset that keeps moving cards above the board
+    pub flight_z: f32,
+}
+
+impl Default for CardAnimationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            draw_duration: 0.45,
+            play_duration: 0.3,
+            destroy_duration: 0.4,
+            move_duration: 0.35,
+            shuffle_duration: 0.5,
+            draw_arc_height: 60.0,
+            destroy_spin: 0.4,
+            flight_z: 50.0,
+        }
+    }
+}
+
+impl CardAnimationConfig {
+    /// Duration of an animation kind, scaled by the animation speed setting
+    ///
+    /// A speed of zero or less disables the animation.
+    pub fn duration(&self, kind: CardAnimationKind, speed: f32) -> f32 {
+        if !self.enabled || speed <= 0.0 {
+            return 0.0;
+        }
+        let base = match kind {
+            CardAnimationKind::Draw => self.draw_duration,
+            CardAnimationKind::Play => self.play_duration,
+            CardAnimationKind::Destroy => self.destroy_duration,
+            CardAnimationKind::Move => self.move_duration,
+        };
+        base / speed
+    }
+}
@@ -0,0 +1,173 @@
+use bevy::prelude::*;
+
+use super::components::{CardAnimation, CardAnimationKind, ShuffleAnimation};
+use super::resources::CardAnimationConfig;
+use crate::cards::Card;
+use crate::game_engine::zones::{Zone, ZoneChangeEvent};
+use crate::menu::settings::components::{AccessibilitySettings, GameplaySettings};
+use crate::player::playmat::PlaymatZone;
+
+/// Event requesting the shuffle animation on a player's library pile
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ShuffleAnimationEvent {
+    /// Owner of the shuffled library
+    pub owner: Entity,
+}
+
+/// Animation speed from the settings, zero when reduced motion is on
+fn animation_speed(
+    gameplay: Option<&GameplaySettings>,
+    accessibility: Option<&AccessibilitySettings>,
+) -> f32 {
+    if accessibility.is_some_and(|settings| settings.reduced_motion) {
+        return 0.0;
+    }
+    gameplay.map_or(1.0, |settings| settings.animation_speed)
+}
+
+/// Start a card animation for each zone change of a visible card
+///
+/// Cards first aim for the middle of their destination zone; zones with a layout
+/// steer them into their slot with [`CardAnimation::retarget`].
+pub fn start_zone_change_animations(
+    mut commands: Commands,
+    mut zone_changes: EventReader<ZoneChangeEvent>,
+    cards: Query<(&GlobalTransform, &Transform), With<Card>>,
+    zones: Query<(&PlaymatZone, &GlobalTransform)>,
+    config: Res<CardAnimationConfig>,
+    gameplay: Option<Res<GameplaySettings>>,
+    accessibility: Option<Res<AccessibilitySettings>>,
+) {
+    let speed = animation_speed(gameplay.as_deref(), accessibility.as_deref());
+
+    for event in zone_changes.read() {
+        let Ok((card_global, card_transform)) = cards.get(event.card) else {
+            continue;
+        };
+        let Some(destination) = zone_position(&zones, event.owner, event.destination) else {
+            continue;
+        };
+
+        let kind = CardAnimationKind::for_zones(event.source, event.destination);
+        let duration = config.duration(kind, speed);
+        if duration <= 0.0 {
+            continue;
+        }
+
+        let from = card_global.translation();
+        commands.entity(event.card).insert(CardAnimation {
+            kind,
+            from: from + Vec3::Z * config.flight_z,
+            to: destination.truncate().extend(from.z) + Vec3::Z * config.flight_z,
+            arc_height: if kind == CardAnimationKind::Draw {
+                config.draw_arc_height
+            } else {
+                0.0
+            },
+            spin: if kind == CardAnimationKind::Destroy {
+                config.destroy_spin
+            } else {
+                0.0
+            },
+            base_rotation: card_transform.rotation,
+            elapsed: 0.0,
+            duration,
+        });
+    }
+}
+
+/// World position of a player's zone on their playmat
+fn zone_position(
+    zones: &Query<(&PlaymatZone, &GlobalTransform)>,
+    owner: Entity,
+    zone: Zone,
+) -> Option<Vec3> {
+    zones
+        .iter()
+        .find(|(playmat_zone, _)| playmat_zone.player_id == owner && playmat_zone.zone_type == zone)
+        .map(|(_, transform)| transform.translation())
+}
+
+/// Advance card animations and finish them when they reach their target
+///
+/// Animations run in world space; the position is converted into the card's local
+/// space every frame so reparenting the card mid-flight does not make it jump.
+pub fn update_card_animations(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<CardAnimationConfig>,
+    mut cards: Query<(Entity, &mut CardAnimation, &mut Transform, Option<&ChildOf>)>,
+    parents: Query<&GlobalTransform>,
+) {
+    for (entity, mut animation, mut transform, child_of) in cards.iter_mut() {
+        animation.elapsed += time.delta_secs();
+
+        let finished = animation.is_finished();
+        let mut world = animation.position();
+        if finished {
+            // Land back at board height
+            world.z -= config.flight_z;
+        }
+
+        let parent_transform = child_of.and_then(|child_of| parents.get(child_of.parent()).ok());
+        transform.translation = match parent_transform {
+            Some(parent) => parent.affine().inverse().transform_point3(world),
+            None => world,
+        };
+        transform.rotation = animation.rotation();
+
+        if finished {
+            commands.entity(entity).remove::<CardAnimation>();
+        }
+    }
+}
+
+/// Start shaking the library pile of each shuffled library
+pub fn start_shuffle_animations(
+    mut commands: Commands,
+    mut shuffles: EventReader<ShuffleAnimationEvent>,
+    zones: Query<(Entity, &PlaymatZone, &Transform, Option<&ShuffleAnimation>)>,
+    config: Res<CardAnimationConfig>,
+    gameplay: Option<Res<GameplaySettings>>,
+    accessibility: Option<Res<AccessibilitySettings>>,
+) {
+    let speed = animation_speed(gameplay.as_deref(), accessibility.as_deref());
+    if !config.enabled || speed <= 0.0 {
+        shuffles.clear();
+        return;
+    }
+
+    for event in shuffles.read() {
+        for (entity, zone, transform, running) in zones.iter() {
+            if zone.player_id != event.owner || zone.zone_type != Zone::Library {
+                continue;
+            }
+
+            // Restart from the original position if a shuffle is already running
+            let origin = running.map_or(transform.translation, |shuffle| shuffle.origin);
+            commands.entity(entity).insert(ShuffleAnimation {
+                origin,
+                elapsed: 0.0,
+                duration: config.shuffle_duration / speed,
+                amplitude: 8.0,
+            });
+        }
+    }
+}
+
+/// Advance library shuffle animations
+pub fn update_shuffle_animations(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut piles: Query<(Entity, &mut ShuffleAnimation, &mut Transform)>,
+) {
+    for (entity, mut shuffle, mut transform) in piles.iter_mut() {
+        shuffle.elapsed += time.delta_secs();
+        transform.translation = shuffle.origin + shuffle.offset();
+
+        if shuffle.elapsed >= shuffle.duration {
+            transform.translation = shuffle.origin;
+            commands.entity(entity).remove::<ShuffleAnimation>();
+        }
+    }
+}
@@ -0,0 +1,189 @@
+// Tests for card movement animations
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::prelude::*;
+
+    use crate::cards::animation::{
+        CardAnimation, CardAnimationConfig, CardAnimationKind, CardAnimationPlugin,
+        CardAnimationSet, ShuffleAnimation,
+    };
+    use crate::cards::systems::process_zone_changes;
+    use crate::cards::{Card, CardDetails, CardTypes, CardZone};
+    use crate::game_engine::zones::{Zone, ZoneChangeEvent};
+    use crate::mana::Mana;
+    use crate::player::playmat::PlaymatZone;
+    use crate::player::playmat::hand::{HandZone, arrange_cards_in_hand};
+
+    fn animation(kind: CardAnimationKind, arc_height: f32) -> CardAnimation {
+        CardAnimation {
+            kind,
+            from: Vec3::ZERO,
+            to: Vec3::new(100.0, 0.0, 0.0),
+            arc_height,
+            spin: 0.0,
+            base_rotation: Quat::IDENTITY,
+            elapsed: 0.0,
+            duration: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_zone_pairs_pick_animation_kind() {
+        assert_eq!(
+            CardAnimationKind::for_zones(Zone::Library, Zone::Hand),
+            CardAnimationKind::Draw
+        );
+        assert_eq!(
+            CardAnimationKind::for_zones(Zone::Hand, Zone::Battlefield),
+            CardAnimationKind::Play
+        );
+        assert_eq!(
+            CardAnimationKind::for_zones(Zone::Battlefield, Zone::Graveyard),
+            CardAnimationKind::Destroy
+        );
+        assert_eq!(
+            CardAnimationKind::for_zones(Zone::Hand, Zone::Exile),
+            CardAnimationKind::Move
+        );
+    }
+
+    #[test]
+    fn test_animation_starts_and_ends_on_path() {
+        let mut anim = animation(CardAnimationKind::Draw, 50.0);
+        assert_eq!(anim.position(), Vec3::ZERO);
+
+        anim.elapsed = 1.0;
+        assert!(anim.is_finished());
+        assert!((anim.position() - Vec3::new(100.0, 0.0, 0.0)).length() < 1e-3);
+    }
+
+    #[test]
+    fn test_draw_arc_lifts_card_mid_flight() {
+        let mut anim = animation(CardAnimationKind::Draw, 50.0);
+        anim.elapsed = 0.5;
+        assert!((anim.position().y - 50.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_animation_speed_scales_duration() {
+        let config = CardAnimationConfig::default();
+        let normal = config.duration(CardAnimationKind::Play, 1.0);
+        assert_eq!(config.duration(CardAnimationKind::Play, 2.0), normal / 2.0);
+        assert_eq!(config.duration(CardAnimationKind::Play, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_shuffle_shake_settles() {
+        let mut shuffle = ShuffleAnimation {
+            origin: Vec3::ZERO,
+            elapsed: 0.0,
+            duration: 0.5,
+            amplitude: 8.0,
+        };
+        assert_eq!(shuffle.offset(), Vec3::ZERO);
+        shuffle.elapsed = 0.5;
+        assert_eq!(shuffle.offset(), Vec3::ZERO);
+    }
+
+    fn land(name: &str) -> Card {
+        Card::builder(name)
+            .cost(Mana::default())
+            .types(CardTypes::LAND)
+            .details(CardDetails::Other)
+            .build_or_panic()
+    }
+
+    #[test]
+    fn test_drawn_card_lands_in_its_hand_slot() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_plugins(CardAnimationPlugin)
+            .add_systems(
+                Update,
+                (
+                    process_zone_changes,
+                    arrange_cards_in_hand.after(CardAnimationSet),
+                ),
+            );
+
+        // A hand below the middle of the table already holding two cards
+        let player = app.world_mut().spawn_empty().id();
+        let hand_transform = Transform::from_xyz(0.0, -250.0, 0.0);
+        let hand = app
+            .world_mut()
+            .spawn((
+                HandZone {
+                    player_id: player,
+                    ..default()
+                },
+                PlaymatZone {
+                    player_id: player,
+                    zone_type: Zone::Hand,
+                },
+                hand_transform,
+                GlobalTransform::from(hand_transform),
+            ))
+            .id();
+        for name in ["Island", "Swamp"] {
+            let card = app
+                .world_mut()
+                .spawn((
+                    land(name),
+                    CardZone::new(Zone::Hand, Some(player)),
+                    Transform::default(),
+                ))
+                .id();
+            app.world_mut().entity_mut(hand).add_child(card);
+        }
+
+        let library_transform = Transform::from_xyz(300.0, 0.0, 0.0);
+        let drawn = app
+            .world_mut()
+            .spawn((
+                land("Forest"),
+                CardZone::new(Zone::Library, Some(player)),
+                library_transform,
+                GlobalTransform::from(library_transform),
+            ))
+            .id();
+        app.world_mut().send_event(ZoneChangeEvent {
+            card: drawn,
+            owner: player,
+            source: Zone::Library,
+            destination: Zone::Hand,
+            was_visible: false,
+            is_visible: true,
+        });
+
+        let mut aimed_at = None;
+        for _ in 0..10 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(100));
+            app.update();
+            if let Some(animation) = app.world().get::<CardAnimation>(drawn) {
+                aimed_at = Some(animation.to.truncate());
+            }
+        }
+        assert!(app.world().get::<CardAnimation>(drawn).is_none());
+        assert_eq!(app.world().get::<Children>(hand).unwrap().len(), 3);
+
+        // Laying the hand out again leaves the card where it landed
+        let landed = *app.world().get::<Transform>(drawn).unwrap();
+        app.world_mut()
+            .get_mut::<HandZone>(hand)
+            .unwrap()
+            .set_changed();
+        app.update();
+        assert_eq!(*app.world().get::<Transform>(drawn).unwrap(), landed);
+
+        // It flew to the rightmost slot, not the middle of the hand
+        let slot = hand_transform
+            .transform_point(landed.translation)
+            .truncate();
+        assert!(landed.translation.x > 0.0);
+        assert!((aimed_at.unwrap() - slot).length() < 1e-3);
+    }
+}
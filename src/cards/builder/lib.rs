@@ -68,7 +68,7 @@ impl CardBuilder {
         Ok(Card {
             name: CardName { name: self.name },
             cost: CardCost { cost },
-            type_info: CardTypeInfo { types },
+            type_info: CardTypeInfo::new(types, &card_details, &keywords),
             details: CardDetailsComponent {
                 details: card_details,
             },
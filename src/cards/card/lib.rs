@@ -46,7 +46,7 @@ impl Card {
                 name: name.to_string(),
             },
             cost: CardCost { cost },
-            type_info: CardTypeInfo { types },
+            type_info: CardTypeInfo::new(types, &details, &keywords),
             details: CardDetailsComponent { details },
             rules_text: CardRulesText {
                 rules_text: rules_text.to_string(),
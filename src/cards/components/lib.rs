@@ -1,7 +1,7 @@
 use crate::cards::counters::PermanentCounters;
 use crate::cards::details::CardDetails;
-use crate::cards::keywords::KeywordAbilities;
-use crate::cards::types::CardTypes;
+use crate::cards::keywords::{KeywordAbilities, KeywordAbility};
+use crate::cards::types::{CardTypes, SubtypeSet};
 use crate::mana::Mana;
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -48,6 +48,22 @@ pub struct CardCost {
 pub struct CardTypeInfo {
     /// The types of the card (Creature, Instant, etc.)
     pub types: CardTypes,
+    /// Every subtype of the card, including those without a flag in `types`
+    #[serde(default)]
+    #[reflect(ignore)]
+    pub subtypes: SubtypeSet,
+}
+
+impl CardTypeInfo {
+    /// Type information for a card, with the subtypes worked out from its details
+    /// and changeling giving it every creature type
+    pub fn new(types: CardTypes, details: &CardDetails, keywords: &KeywordAbilities) -> Self {
+        let mut subtypes = SubtypeSet::from_card(types, details);
+        if keywords.abilities.contains(&KeywordAbility::Changeling) {
+            subtypes.set_all_creature_types(true);
+        }
+        Self { types, subtypes }
+    }
 }
 
 /// Component for storing a card's rules text
@@ -124,6 +140,11 @@ pub struct Draggable {
     pub z_index: f32,
 }
 
+/// Marks a card that can't be picked up, for example while a tutorial waits for
+/// the player to drag a different card
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct DragLocked;
+
 impl Default for Draggable {
     fn default() -> Self {
         Self {
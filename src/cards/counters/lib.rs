@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bevy::reflect::Struct;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -225,3 +226,45 @@ pub struct PermanentCounters {
     /// Custom counters (for custom counter types not listed above)
     pub custom: HashMap<String, u32>,
 }
+
+impl PermanentCounters {
+    /// The number of counters of a kind
+    ///
+    /// Kinds are field names such as `"loyalty"`, or `"+1/+1"` and `"-1/-1"`.
+    /// Any other kind is a custom counter.
+    pub fn get(&self, kind: &str) -> u32 {
+        let kind = Self::field_name(kind);
+        match self
+            .field(&kind)
+            .and_then(|field| field.try_downcast_ref::<u32>())
+        {
+            Some(count) => *count,
+            None => self.custom.get(&kind).copied().unwrap_or(0),
+        }
+    }
+
+    /// Add or remove counters of a kind, stopping at zero, and return how many are left
+    pub fn adjust(&mut self, kind: &str, delta: i32) -> u32 {
+        let kind = Self::field_name(kind);
+        let is_field = self
+            .field(&kind)
+            .is_some_and(|field| field.try_downcast_ref::<u32>().is_some());
+        let count = if is_field {
+            self.field_mut(&kind)
+                .and_then(|field| field.try_downcast_mut::<u32>())
+                .expect("counter field is a u32")
+        } else {
+            self.custom.entry(kind).or_default()
+        };
+        *count = count.saturating_add_signed(delta);
+        *count
+    }
+
+    fn field_name(kind: &str) -> String {
+        match kind.trim() {
+            "+1/+1" => "plus_one_plus_one".to_string(),
+            "-1/-1" => "minus_one_minus_one".to_string(),
+            kind => kind.to_lowercase().replace(' ', "_"),
+        }
+    }
+}
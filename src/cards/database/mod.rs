@@ -0,0 +1,309 @@
+//! Card definitions that exist independently of spawned entities
+//!
+//! Decks, libraries and the deck builder refer to cards by [`CardId`] and look up
+//! their oracle data in the [`CardDatabase`]. A spawned card carries its `CardId`
+//! as a component, linking the entity back to its definition.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::Card;
+use crate::cards::legality::{self, CardLegalities};
+use crate::cards::rarity::Rarity;
+use crate::cards::scripting::CardScript;
+use crate::cards::set::CardSet;
+use crate::cards::sets;
+
+/// Stable identifier of a card definition
+///
+/// Derived from the card's name, so the same card has the same id in every run,
+/// save file and network peer.
+#[derive(
+    Component,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Reflect,
+    Serialize,
+    Deserialize,
+)]
+#[reflect(Component)]
+pub struct CardId(pub u64);
+
+impl CardId {
+    /// The id of the card called `name`, ignoring case and surrounding whitespace
+    pub fn from_name(name: &str) -> Self {
+        // FNV-1a, which unlike the std hasher is the same on every platform and run
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for byte in name.trim().to_lowercase().bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        Self(hash)
+    }
+
+    /// The id of a card's definition
+    pub fn of(card: &Card) -> Self {
+        Self::from_name(&card.name.name)
+    }
+}
+
+impl fmt::Display for CardId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+/// An official ruling clarifying how a card works
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ruling {
+    /// Date the ruling was issued, as YYYY-MM-DD
+    pub date: String,
+    pub text: String,
+}
+
+/// A card's oracle data and where it was printed
+#[derive(Debug, Clone)]
+pub struct CardDefinition {
+    pub id: CardId,
+    pub card: Card,
+    /// The set the definition came from, if known
+    pub set: Option<CardSet>,
+    pub rarity: Option<Rarity>,
+    /// Scryfall ID of the printing, used to download its image
+    pub scryfall_id: Option<String>,
+    /// Formats the card may be played in, empty if unknown
+    pub legalities: CardLegalities,
+    /// Official rulings, or None until they have been loaded
+    ///
+    /// Rulings are only needed when a player asks for them, so they are usually
+    /// loaded on demand instead of with the card.
+    pub rulings: Option<Arc<[Ruling]>>,
+    /// What the card does, for cards defined as data
+    pub script: Option<CardScript>,
+}
+
+impl CardDefinition {
+    /// Whether the card may be played in a format, by MTGJSON format name
+    ///
+    /// Cards without legality data are allowed everywhere except where the
+    /// Commander banned list says otherwise.
+    pub fn is_legal_in(&self, format: &str) -> bool {
+        if !self.legalities.is_empty() {
+            return self.legalities.is_legal_in(format);
+        }
+        !(format.eq_ignore_ascii_case("commander")
+            && legality::is_commander_banned(&self.card.name.name))
+    }
+}
+
+/// Every known card definition, keyed by [`CardId`]
+#[derive(Resource, Debug, Default)]
+pub struct CardDatabase {
+    cards: HashMap<CardId, CardDefinition>,
+}
+
+impl CardDatabase {
+    /// A database holding every card from the built-in sets
+    pub fn with_builtin_sets() -> Self {
+        let mut database = Self::default();
+        for set in sets::builtin_sets() {
+            for card in set.cards() {
+                database.insert_printing(card, set.info.clone(), None);
+            }
+        }
+        database
+    }
+
+    /// Add a card definition, replacing any with the same name
+    pub fn insert(&mut self, card: Card) -> CardId {
+        self.insert_definition(card, None, None)
+    }
+
+    /// Add a card definition from a known set
+    pub fn insert_printing(&mut self, card: Card, set: CardSet, rarity: Option<Rarity>) -> CardId {
+        self.insert_definition(card, Some(set), rarity)
+    }
+
+    fn insert_definition(
+        &mut self,
+        card: Card,
+        set: Option<CardSet>,
+        rarity: Option<Rarity>,
+    ) -> CardId {
+        let id = CardId::of(&card);
+        self.cards.insert(
+            id,
+            CardDefinition {
+                id,
+                card,
+                set,
+                rarity,
+                scryfall_id: None,
+                legalities: CardLegalities::default(),
+                rulings: None,
+                script: None,
+            },
+        );
+        id
+    }
+
+    /// Record which formats a card may be played in
+    ///
+    /// Returns false if the database has no definition for `id`.
+    pub fn set_legalities(&mut self, id: CardId, legalities: CardLegalities) -> bool {
+        match self.cards.get_mut(&id) {
+            Some(definition) => {
+                definition.legalities = legalities;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Record the Scryfall ID of a card's printing
+    ///
+    /// Returns false if the database has no definition for `id`.
+    pub fn set_scryfall_id(&mut self, id: CardId, scryfall_id: String) -> bool {
+        match self.cards.get_mut(&id) {
+            Some(definition) => {
+                definition.scryfall_id = Some(scryfall_id);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Record a card's rulings, an empty list meaning it has none
+    ///
+    /// Returns false if the database has no definition for `id`.
+    pub fn set_rulings(&mut self, id: CardId, rulings: Vec<Ruling>) -> bool {
+        match self.cards.get_mut(&id) {
+            Some(definition) => {
+                definition.rulings = Some(rulings.into());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Record what a card defined as data does
+    ///
+    /// Returns false if the database has no definition for `id`.
+    pub fn set_script(&mut self, id: CardId, script: CardScript) -> bool {
+        match self.cards.get_mut(&id) {
+            Some(definition) => {
+                definition.script = Some(script);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// A card's rulings, or None if they haven't been loaded
+    pub fn rulings(&self, id: CardId) -> Option<&[Ruling]> {
+        self.get(id)?.rulings.as_deref()
+    }
+
+    /// Whether a definition exists for `id`
+    pub fn contains(&self, id: CardId) -> bool {
+        self.cards.contains_key(&id)
+    }
+
+    /// The definition of a card
+    pub fn get(&self, id: CardId) -> Option<&CardDefinition> {
+        self.cards.get(&id)
+    }
+
+    /// The oracle data of a card
+    pub fn card(&self, id: CardId) -> Option<&Card> {
+        self.cards.get(&id).map(|definition| &definition.card)
+    }
+
+    /// Look up a card by name, ignoring case
+    pub fn find_by_name(&self, name: &str) -> Option<&CardDefinition> {
+        self.get(CardId::from_name(name))
+    }
+
+    /// The definitions of a list of cards, skipping unknown ids
+    pub fn resolve<'a>(
+        &'a self,
+        ids: impl IntoIterator<Item = CardId> + 'a,
+    ) -> impl Iterator<Item = &'a CardDefinition> + 'a {
+        ids.into_iter().filter_map(|id| self.get(id))
+    }
+
+    /// Every definition, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = &CardDefinition> {
+        self.cards.values()
+    }
+
+    /// Definitions of the cards that may be played in a format
+    pub fn legal_in<'a>(&'a self, format: &'a str) -> impl Iterator<Item = &'a CardDefinition> {
+        self.iter()
+            .filter(move |definition| definition.is_legal_in(format))
+    }
+
+    /// Number of card definitions
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Whether the database holds no cards
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Spawn an entity for a card, tagged with its id
+    pub fn spawn(&self, commands: &mut Commands, id: CardId) -> Option<Entity> {
+        let definition = self.get(id)?;
+        let mut entity = commands.spawn((
+            definition.card.clone(),
+            id,
+            Name::new(definition.card.name.name.clone()),
+        ));
+        if let Some(set) = &definition.set {
+            entity.insert(set.clone());
+        }
+        if let Some(rarity) = definition.rarity {
+            entity.insert(rarity);
+        }
+        if !definition.legalities.is_empty() {
+            entity.insert(definition.legalities.clone());
+        }
+        if let Some(script) = &definition.script {
+            entity.insert(script.clone());
+        }
+        Some(entity.id())
+    }
+}
+
+/// Tag cards spawned without going through the database with their [`CardId`]
+///
+/// Cards the database doesn't know yet are added to it, so every spawned card
+/// can be looked up by id.
+pub fn assign_card_ids(
+    mut commands: Commands,
+    mut database: ResMut<CardDatabase>,
+    cards: Query<(Entity, &Card, Option<&CardSet>, Option<&Rarity>), Without<CardId>>,
+) {
+    for (entity, card, set, rarity) in cards.iter() {
+        let id = CardId::of(card);
+        if !database.contains(id) {
+            database.insert_definition(card.clone(), set.cloned(), rarity.copied());
+        }
+        commands.entity(entity).insert(id);
+    }
+}
+
+#[cfg(test)]
+mod tests;
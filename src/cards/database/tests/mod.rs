@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+
+use crate::cards::database::{CardDatabase, CardId, assign_card_ids};
+use crate::cards::{Card, CardDetails, CardTypes};
+use crate::deck::{Deck, DeckType};
+use crate::mana::Mana;
+
+fn test_card(name: &str) -> Card {
+    Card::builder(name)
+        .cost(Mana::default())
+        .types(CardTypes::INSTANT)
+        .details(CardDetails::Other)
+        .build_or_panic()
+}
+
+#[test]
+fn test_card_id_is_stable_and_ignores_case() {
+    assert_eq!(
+        CardId::from_name("Lightning Bolt"),
+        CardId::from_name("  lightning bolt ")
+    );
+    assert_ne!(
+        CardId::from_name("Lightning Bolt"),
+        CardId::from_name("Counterspell")
+    );
+    // Ids end up in save files and on the network, so they must never change
+    assert_eq!(CardId::from_name("").to_string(), "cbf29ce484222325");
+}
+
+#[test]
+fn test_builtin_sets_are_loaded_without_spawning() {
+    let database = CardDatabase::with_builtin_sets();
+
+    let bolt = database.find_by_name("Lightning Bolt").unwrap();
+    assert_eq!(bolt.card.name.name, "Lightning Bolt");
+    assert_eq!(bolt.set.as_ref().map(|set| set.code.as_str()), Some("LEA"));
+    assert!(database.find_by_name("Force of Will").is_some());
+    assert!(database.find_by_name("Not A Card").is_none());
+}
+
+#[test]
+fn test_deck_round_trips_through_card_ids() {
+    let database = CardDatabase::with_builtin_sets();
+    let bolt = CardId::from_name("Lightning Bolt");
+    let counterspell = CardId::from_name("Counterspell");
+    let cards = database
+        .resolve([bolt, counterspell, bolt])
+        .map(|definition| definition.card.clone())
+        .collect();
+    let deck = Deck::new("Test".to_string(), DeckType::Standard, cards);
+
+    assert_eq!(deck.card_ids(), vec![bolt, counterspell, bolt]);
+}
+
+#[test]
+fn test_spawned_cards_reference_their_definition() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .init_resource::<CardDatabase>()
+        .add_systems(Update, assign_card_ids);
+    let custom = app.world_mut().spawn(test_card("Custom Card")).id();
+
+    app.update();
+
+    let id = *app.world().get::<CardId>(custom).unwrap();
+    assert_eq!(id, CardId::from_name("Custom Card"));
+    assert_eq!(
+        app.world()
+            .resource::<CardDatabase>()
+            .card(id)
+            .map(|card| card.name.name.as_str()),
+        Some("Custom Card")
+    );
+}
@@ -1,3 +1,4 @@
+use crate::camera::z_order::ZBand;
 use crate::menu::input_blocker::InteractionBlockState;
 /// Drag and drop functionality for game objects.
 ///
@@ -144,8 +145,9 @@ fn update_draggables(
             if mouse_button_input.pressed(MouseButton::Left) {
                 if let Some(world_pos) = world_position {
                     // Offset mouse position by the drag offset
+                    // Stay above all other cards and playmats
                     let target_position =
-                        Vec3::new(world_pos.x, world_pos.y, 0.0) + Vec3::new(0.0, 0.0, 40.0); // Use z-index of 40.0 to stay above all other cards and playmats
+                        Vec3::new(world_pos.x, world_pos.y, ZBand::Dragged.base());
                     // Update the entity position
                     transform.translation = target_position;
                     // Store the current position for snapping if needed
@@ -160,7 +162,7 @@ fn update_draggables(
                 commands.entity(entity).insert(Draggable {
                     dragging: false,
                     drag_offset: draggable.drag_offset,
-                    z_index: 30.0, // Return to the standard card z-index when done dragging
+                    z_index: ZBand::Battlefield.base(), // Return to the battlefield band when done dragging
                 });
             }
         }
@@ -218,8 +220,8 @@ fn start_drag(
     entities_under_cursor.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
 
     if let Some((entity, _)) = entities_under_cursor.first() {
-        // When starting a drag, increase the z-index to ensure the card stays on top of all other cards
-        let new_z_index = 40.0; // Increased from 30.0 to ensure it's above non-dragged cards
+        // When starting a drag, lift the entity into the dragged band above all other cards
+        let new_z_index = ZBand::Dragged.base();
 
         commands.entity(*entity).insert(Draggable {
             dragging: true,
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+/// Requests a procedurally rendered frame for a card entity
+///
+/// Cards with this component get their sprite replaced by a rendered frame texture.
+/// Cards that also carry a [`CardImage`](crate::cards::CardImage) keep using the
+/// downloaded scan instead.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct ProceduralCardFrame {
+    /// Keyrune glyph to use for the set symbol; the set code is drawn when absent
+    pub set_glyph: Option<char>,
+}
+
+/// The rendered frame texture of a card
+#[derive(Component, Debug, Clone)]
+pub struct CardFrameTexture {
+    /// Handle to the rendered texture
+    pub image: Handle<Image>,
+}
+
+/// An offscreen render of a card frame that is still in progress
+#[derive(Component, Debug)]
+pub(crate) struct CardFrameRenderJob {
+    /// Render layer reserved for this job
+    pub layer: usize,
+    /// Frames to wait before the composed scene is torn down
+    pub frames_remaining: u32,
+}
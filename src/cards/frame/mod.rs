@@ -0,0 +1,25 @@
+//! Procedural card frame rendering
+//!
+//! An alternative to downloaded card scans: card visuals are composed in-engine from
+//! a color-appropriate frame, mana symbols from the Mana font, a text box with wrapped
+//! rules text, a P/T box and a set symbol colored by rarity.
+//!
+//! Each frame is rendered once into an offscreen texture at print resolution
+//! (750 × 1050, matching [`CardTextLayout`](crate::text::layout::CardTextLayout)), so
+//! the result stays sharp at every zoom level and is shared between cards of the
+//! same printing. Cards get a [`ProceduralCardFrame`] while the procedural frames
+//! gameplay setting is on.
+
+mod components;
+mod palette;
+mod plugin;
+mod resources;
+mod systems;
+
+pub mod tests;
+
+pub use components::{CardFrameTexture, ProceduralCardFrame};
+pub use palette::{FrameColors, frame_colors, rarity_color};
+pub use plugin::CardFramePlugin;
+pub use resources::{CardFrameKey, CardFrameRenderer};
+pub use systems::sync_procedural_frames;
@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+
+use crate::cards::CardTypes;
+use crate::cards::rarity::Rarity;
+use crate::mana::{Mana, ManaColor};
+
+/// Colors used to draw a card frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameColors {
+    /// Outer frame color
+    pub frame: Color,
+    /// Background of the name bar, type line and text box
+    pub text_box: Color,
+    /// Accent used for borders and the P/T box
+    pub accent: Color,
+}
+
+/// Frame colors for a card based on its mana cost and types
+///
+/// Mono-colored cards use their color's frame, multicolored cards use gold,
+/// colorless artifacts use gray and lands use a brown frame.
+pub fn frame_colors(cost: &Mana, types: &CardTypes) -> FrameColors {
+    let colors = [
+        (cost.white, ManaColor::WHITE),
+        (cost.blue, ManaColor::BLUE),
+        (cost.black, ManaColor::BLACK),
+        (cost.red, ManaColor::RED),
+        (cost.green, ManaColor::GREEN),
+    ];
+    let present: Vec<ManaColor> = colors
+        .iter()
+        .filter(|(amount, _)| *amount > 0)
+        .map(|(_, color)| *color)
+        .collect();
+
+    match present.as_slice() {
+        [ManaColor::WHITE] => FrameColors {
+            frame: Color::srgb(0.93, 0.91, 0.82),
+            text_box: Color::srgb(0.98, 0.97, 0.92),
+            accent: Color::srgb(0.78, 0.74, 0.60),
+        },
+        [ManaColor::BLUE] => FrameColors {
+            frame: Color::srgb(0.13, 0.42, 0.70),
+            text_box: Color::srgb(0.85, 0.90, 0.95),
+            accent: Color::srgb(0.07, 0.27, 0.50),
+        },
+        [ManaColor::BLACK] => FrameColors {
+            frame: Color::srgb(0.17, 0.16, 0.16),
+            text_box: Color::srgb(0.80, 0.78, 0.77),
+            accent: Color::srgb(0.35, 0.33, 0.33),
+        },
+        [ManaColor::RED] => FrameColors {
+            frame: Color::srgb(0.78, 0.25, 0.15),
+            text_box: Color::srgb(0.95, 0.86, 0.80),
+            accent: Color::srgb(0.55, 0.15, 0.08),
+        },
+        [ManaColor::GREEN] => FrameColors {
+            frame: Color::srgb(0.13, 0.45, 0.25),
+            text_box: Color::srgb(0.85, 0.90, 0.84),
+            accent: Color::srgb(0.07, 0.30, 0.15),
+        },
+        [_, _, ..] => FrameColors {
+            frame: Color::srgb(0.82, 0.68, 0.32),
+            text_box: Color::srgb(0.96, 0.92, 0.80),
+            accent: Color::srgb(0.62, 0.49, 0.18),
+        },
+        _ if types.contains(CardTypes::LAND) => FrameColors {
+            frame: Color::srgb(0.55, 0.45, 0.35),
+            text_box: Color::srgb(0.92, 0.88, 0.82),
+            accent: Color::srgb(0.38, 0.30, 0.22),
+        },
+        _ => FrameColors {
+            frame: Color::srgb(0.62, 0.64, 0.67),
+            text_box: Color::srgb(0.90, 0.91, 0.92),
+            accent: Color::srgb(0.42, 0.44, 0.47),
+        },
+    }
+}
+
+/// Set symbol color for a rarity
+pub fn rarity_color(rarity: Rarity) -> Color {
+    match rarity {
+        Rarity::Common => Color::srgb(0.1, 0.1, 0.1),
+        Rarity::Uncommon => Color::srgb(0.66, 0.72, 0.76),
+        Rarity::Rare => Color::srgb(0.80, 0.66, 0.30),
+        Rarity::MythicRare => Color::srgb(0.85, 0.35, 0.10),
+        Rarity::Special | Rarity::Bonus | Rarity::Promo => Color::srgb(0.55, 0.30, 0.65),
+    }
+}
@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+use super::components::ProceduralCardFrame;
+use super::resources::CardFrameRenderer;
+use super::systems::{
+    apply_card_frames, finish_card_frame_renders, start_card_frame_renders, sync_procedural_frames,
+};
+
+/// Plugin for rendering card frames procedurally into textures
+pub struct CardFramePlugin;
+
+impl Plugin for CardFramePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ProceduralCardFrame>()
+            .init_resource::<CardFrameRenderer>()
+            .add_systems(
+                Update,
+                (
+                    finish_card_frame_renders,
+                    sync_procedural_frames,
+                    start_card_frame_renders,
+                    apply_card_frames,
+                )
+                    .chain(),
+            );
+    }
+}
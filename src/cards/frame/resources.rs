@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::cards::Card;
+use crate::cards::rarity::Rarity;
+use crate::cards::set::CardSet;
+
+/// First render layer used for offscreen frame renders, above all [`AppLayer`]s
+///
+/// [`AppLayer`]: crate::camera::components::AppLayer
+pub const FRAME_RENDER_LAYER_START: usize = 32;
+
+/// Number of frames that can be rendered at the same time
+pub const MAX_CONCURRENT_FRAME_RENDERS: usize = 16;
+
+/// What a rendered frame shows, so different printings of a card get their own
+/// set symbol and rarity color
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CardFrameKey {
+    pub name: String,
+    /// Set code, if the printing's set is known
+    pub set: Option<String>,
+    pub rarity: Rarity,
+}
+
+impl CardFrameKey {
+    /// The key of a card's frame
+    pub fn new(card: &Card, set: Option<&CardSet>, rarity: Rarity) -> Self {
+        Self {
+            name: card.name.name.clone(),
+            set: set.map(|set| set.code.clone()),
+            rarity,
+        }
+    }
+}
+
+/// Tracks rendered card frames and the render layers used to produce them
+#[derive(Resource, Debug)]
+pub struct CardFrameRenderer {
+    /// Output texture size in pixels
+    pub resolution: UVec2,
+    /// Rendered textures keyed by card printing
+    pub(crate) rendered: HashMap<CardFrameKey, Handle<Image>>,
+    /// Render layers currently reserved by in-progress jobs
+    pub(crate) layers_in_use: Vec<bool>,
+}
+
+impl Default for CardFrameRenderer {
+    fn default() -> Self {
+        Self {
+            resolution: UVec2::new(750, 1050),
+            rendered: HashMap::new(),
+            layers_in_use: vec![false; MAX_CONCURRENT_FRAME_RENDERS],
+        }
+    }
+}
+
+impl CardFrameRenderer {
+    /// The rendered texture for a card printing, if one exists
+    pub fn get(&self, key: &CardFrameKey) -> Option<&Handle<Image>> {
+        self.rendered.get(key)
+    }
+
+    /// Drop all rendered textures so frames are composed again
+    pub fn invalidate(&mut self) {
+        self.rendered.clear();
+    }
+
+    /// Reserve a free render layer, if any
+    pub(crate) fn reserve_layer(&mut self) -> Option<usize> {
+        let slot = self.layers_in_use.iter().position(|used| !used)?;
+        self.layers_in_use[slot] = true;
+        Some(FRAME_RENDER_LAYER_START + slot)
+    }
+
+    /// Return a render layer to the pool
+    pub(crate) fn release_layer(&mut self, layer: usize) {
+        if let Some(used) = self
+            .layers_in_use
+            .get_mut(layer.wrapping_sub(FRAME_RENDER_LAYER_START))
+        {
+            *used = false;
+        }
+    }
+}
@@ -0,0 +1,497 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+use bevy::render::view::RenderLayers;
+use bevy::sprite::Anchor;
+use bevy::text::TextBounds;
+
+use super::components::{CardFrameRenderJob, CardFrameTexture, ProceduralCardFrame};
+use super::palette::{FrameColors, frame_colors, rarity_color};
+use super::resources::{CardFrameKey, CardFrameRenderer};
+use crate::cards::image::CardImage;
+use crate::cards::rarity::Rarity;
+use crate::cards::set::CardSet;
+use crate::cards::text::rules_text::{RulesTextFonts, spawn_rules_text_spans};
+use crate::cards::{Card, CardDetails, format_type_line};
+use crate::mana::render::colors::{
+    get_mana_pattern, is_dark_background, mana_pattern_rects, mana_symbol_color,
+};
+use crate::mana::symbols::mana_symbol_to_char;
+use crate::menu::settings::components::{AccessibilitySettings, GameplaySettings};
+use crate::text::components::CardTextType;
+use crate::text::layout::{layout_rules_text, split_mana_symbols};
+
+/// Frames to keep the offscreen camera alive so the texture is fully rendered
+const FRAME_RENDER_FRAMES: u32 = 2;
+
+/// Fonts used while composing a frame
+struct FrameFonts {
+    rules: RulesTextFonts,
+    bold: Handle<Font>,
+    mana: Handle<Font>,
+    keyrune: Handle<Font>,
+}
+
+/// Give cards a procedural frame while the procedural frames setting is on
+///
+/// Turning the setting off puts cards back to the plain sprite with spawned text.
+/// A pooled card reused for another card renders its frame again. Cards showing a
+/// downloaded scan don't need a frame.
+#[allow(clippy::type_complexity)]
+pub fn sync_procedural_frames(
+    mut commands: Commands,
+    settings: Option<Res<GameplaySettings>>,
+    mut cards: Query<
+        (
+            Entity,
+            Ref<Card>,
+            Has<ProceduralCardFrame>,
+            &mut Sprite,
+            Option<&Children>,
+        ),
+        Without<CardImage>,
+    >,
+    mut card_text: Query<&mut Visibility, With<CardTextType>>,
+) {
+    let enabled = settings.is_some_and(|settings| settings.procedural_frames);
+    for (entity, card, framed, mut sprite, children) in cards.iter_mut() {
+        if enabled {
+            if !framed {
+                commands
+                    .entity(entity)
+                    .insert(ProceduralCardFrame::default());
+            } else if card.is_changed() && !card.is_added() {
+                commands.entity(entity).remove::<CardFrameTexture>();
+            }
+        } else if framed {
+            commands
+                .entity(entity)
+                .remove::<(ProceduralCardFrame, CardFrameTexture)>();
+            sprite.image = Handle::default();
+            for child in children.into_iter().flatten() {
+                if let Ok(mut visibility) = card_text.get_mut(*child) {
+                    *visibility = Visibility::Inherited;
+                }
+            }
+        }
+    }
+}
+
+/// Start offscreen renders for cards that requested a procedural frame
+pub fn start_card_frame_renders(
+    mut commands: Commands,
+    mut renderer: ResMut<CardFrameRenderer>,
+    mut images: ResMut<Assets<Image>>,
+    asset_server: Res<AssetServer>,
+    accessibility: Option<Res<AccessibilitySettings>>,
+    cards: Query<
+        (
+            Entity,
+            &Card,
+            &ProceduralCardFrame,
+            Option<&CardSet>,
+            Option<&Rarity>,
+        ),
+        (Without<CardFrameTexture>, Without<CardImage>),
+    >,
+) {
+    let fonts = FrameFonts {
+        rules: RulesTextFonts::load(&asset_server),
+        bold: asset_server.load("fonts/DejaVuSans-Bold.ttf"),
+        mana: asset_server.load("fonts/Mana.ttf"),
+        keyrune: asset_server.load("fonts/keyrune.ttf"),
+    };
+    let colorblind = accessibility.is_some_and(|settings| settings.colorblind_mana);
+
+    for (entity, card, frame, set, rarity) in cards.iter() {
+        // Frames are shared between cards of the same printing
+        let rarity = rarity.copied().unwrap_or(Rarity::Common);
+        let key = CardFrameKey::new(card, set, rarity);
+        if let Some(image) = renderer.get(&key) {
+            commands.entity(entity).insert(CardFrameTexture {
+                image: image.clone(),
+            });
+            continue;
+        }
+
+        let Some(layer) = renderer.reserve_layer() else {
+            // All render layers are busy, try again next frame
+            break;
+        };
+
+        let image = images.add(create_render_target(renderer.resolution));
+        compose_card_frame(
+            &mut commands,
+            card,
+            frame,
+            set,
+            rarity,
+            renderer.resolution.as_vec2(),
+            layer,
+            image.clone(),
+            &fonts,
+            colorblind,
+        );
+
+        renderer.rendered.insert(key, image.clone());
+        commands.entity(entity).insert(CardFrameTexture { image });
+    }
+}
+
+/// Tear down offscreen scenes once their frame texture has been rendered
+pub fn finish_card_frame_renders(
+    mut commands: Commands,
+    mut renderer: ResMut<CardFrameRenderer>,
+    mut jobs: Query<(Entity, &mut CardFrameRenderJob)>,
+) {
+    for (entity, mut job) in jobs.iter_mut() {
+        if job.frames_remaining > 0 {
+            job.frames_remaining -= 1;
+            continue;
+        }
+
+        renderer.release_layer(job.layer);
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Show rendered frames on card sprites
+///
+/// The frame already contains the card text, so the spawned text children are hidden.
+pub fn apply_card_frames(
+    mut cards: Query<
+        (&CardFrameTexture, &mut Sprite, Option<&Children>),
+        (Changed<CardFrameTexture>, Without<CardImage>),
+    >,
+    mut card_text: Query<&mut Visibility, With<CardTextType>>,
+) {
+    for (frame, mut sprite, children) in cards.iter_mut() {
+        sprite.image = frame.image.clone();
+        sprite.color = Color::WHITE;
+
+        for child in children.into_iter().flatten() {
+            if let Ok(mut visibility) = card_text.get_mut(*child) {
+                *visibility = Visibility::Hidden;
+            }
+        }
+    }
+}
+
+/// Create a transparent texture that cameras can render into
+fn create_render_target(resolution: UVec2) -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: resolution.x,
+            height: resolution.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    image
+}
+
+/// Spawn the offscreen camera and all frame elements for one card
+#[allow(clippy::too_many_arguments)]
+fn compose_card_frame(
+    commands: &mut Commands,
+    card: &Card,
+    frame: &ProceduralCardFrame,
+    set: Option<&CardSet>,
+    rarity: Rarity,
+    size: Vec2,
+    layer: usize,
+    target: Handle<Image>,
+    fonts: &FrameFonts,
+    colorblind: bool,
+) {
+    let colors = frame_colors(&card.cost.cost, &card.type_info.types);
+    let layers = RenderLayers::layer(layer);
+    let (w, h) = (size.x, size.y);
+
+    commands
+        .spawn((
+            CardFrameRenderJob {
+                layer,
+                frames_remaining: FRAME_RENDER_FRAMES,
+            },
+            Transform::default(),
+            Visibility::Visible,
+            Name::new(format!("Card Frame Render: {}", card.name.name)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Camera2d,
+                Projection::Orthographic(OrthographicProjection::default_2d()),
+                Camera {
+                    order: -1,
+                    target: RenderTarget::Image(target.into()),
+                    clear_color: ClearColorConfig::Custom(Color::NONE),
+                    ..default()
+                },
+                layers.clone(),
+            ));
+
+            spawn_frame_boxes(parent, &colors, size, &layers);
+
+            // Name and mana cost
+            parent.spawn((
+                Text2d::new(card.name.name.clone()),
+                TextFont {
+                    font: fonts.bold.clone(),
+                    font_size: h * 0.036,
+                    ..default()
+                },
+                TextColor(Color::BLACK),
+                Anchor::CenterLeft,
+                Transform::from_xyz(-w * 0.40, h * 0.41, 2.0),
+                layers.clone(),
+            ));
+            let cost = card.cost.cost.to_string();
+            spawn_mana_cost(parent, &cost, size, &layers, fonts, colorblind);
+            if colorblind {
+                spawn_mana_pattern_swatches(parent, &cost, size, &layers);
+            }
+
+            // Type line and set symbol
+            parent.spawn((
+                Text2d::new(format_type_line(
+                    &card.type_info.types,
+                    &card.details.details,
+                )),
+                TextFont {
+                    font: fonts.bold.clone(),
+                    font_size: h * 0.030,
+                    ..default()
+                },
+                TextColor(Color::BLACK),
+                Anchor::CenterLeft,
+                Transform::from_xyz(-w * 0.40, -h * 0.05, 2.0),
+                layers.clone(),
+            ));
+            let (set_text, set_font) = match (frame.set_glyph, set) {
+                (Some(glyph), _) => (glyph.to_string(), fonts.keyrune.clone()),
+                (None, Some(set)) => (set.code.clone(), fonts.bold.clone()),
+                (None, None) => (String::new(), fonts.bold.clone()),
+            };
+            parent.spawn((
+                Text2d::new(set_text),
+                TextFont {
+                    font: set_font,
+                    font_size: h * 0.032,
+                    ..default()
+                },
+                TextColor(rarity_color(rarity)),
+                Anchor::CenterRight,
+                Transform::from_xyz(w * 0.40, -h * 0.05, 2.0),
+                layers.clone(),
+            ));
+
+            // Rules text, wrapped and shrunk to fit inside the text box
+            let rules_box = Vec2::new(w * 0.78, h * 0.29);
+            let rules_layout = layout_rules_text(
+                &card.rules_text.rules_text,
+                None,
+                rules_box,
+                h * 0.030,
+                h * 0.018,
+            );
+            parent
+                .spawn((
+                    Text2d::default(),
+                    TextFont {
+                        font: fonts.rules.regular.clone(),
+                        font_size: rules_layout.font_size,
+                        ..default()
+                    },
+                    TextColor(Color::BLACK),
+                    TextLayout::new_with_justify(JustifyText::Left),
+                    TextBounds::new_horizontal(rules_box.x),
+                    Anchor::TopLeft,
+                    Transform::from_xyz(-rules_box.x / 2.0, -h * 0.10, 2.0),
+                    layers.clone(),
+                ))
+                .with_children(|spans| {
+                    spawn_rules_text_spans(spans, &rules_layout, &fonts.rules, Color::BLACK);
+                });
+
+            // Power/toughness box for creatures
+            if let CardDetails::Creature(creature) = &card.details.details {
+                parent.spawn((
+                    Sprite::from_color(colors.accent, Vec2::new(w * 0.22, h * 0.07)),
+                    Transform::from_xyz(w * 0.34, -h * 0.43, 3.0),
+                    layers.clone(),
+                ));
+                parent.spawn((
+                    Sprite::from_color(colors.text_box, Vec2::new(w * 0.20, h * 0.06)),
+                    Transform::from_xyz(w * 0.34, -h * 0.43, 3.1),
+                    layers.clone(),
+                ));
+                parent.spawn((
+                    Text2d::new(format!("{}/{}", creature.power, creature.toughness)),
+                    TextFont {
+                        font: fonts.bold.clone(),
+                        font_size: h * 0.040,
+                        ..default()
+                    },
+                    TextColor(Color::BLACK),
+                    Transform::from_xyz(w * 0.34, -h * 0.43, 3.2),
+                    layers.clone(),
+                ));
+            }
+        });
+}
+
+/// Spawn the frame, name bar, art box, type line and text box backgrounds
+fn spawn_frame_boxes(
+    parent: &mut ChildSpawnerCommands,
+    colors: &FrameColors,
+    size: Vec2,
+    layers: &RenderLayers,
+) {
+    let (w, h) = (size.x, size.y);
+    let art_color = colors.accent.mix(&Color::BLACK, 0.35);
+
+    let boxes = [
+        // Black border and colored frame
+        (Color::BLACK, Vec2::new(w, h), Vec2::ZERO, 0.0),
+        (
+            colors.frame,
+            Vec2::new(w * 0.94, h * 0.955),
+            Vec2::ZERO,
+            0.1,
+        ),
+        // Name bar
+        (
+            colors.text_box,
+            Vec2::new(w * 0.86, h * 0.065),
+            Vec2::new(0.0, h * 0.41),
+            1.0,
+        ),
+        // Art box
+        (
+            art_color,
+            Vec2::new(w * 0.82, h * 0.36),
+            Vec2::new(0.0, h * 0.18),
+            1.0,
+        ),
+        // Type line
+        (
+            colors.text_box,
+            Vec2::new(w * 0.86, h * 0.06),
+            Vec2::new(0.0, -h * 0.05),
+            1.0,
+        ),
+        // Text box
+        (
+            colors.text_box,
+            Vec2::new(w * 0.82, h * 0.32),
+            Vec2::new(0.0, -h * 0.245),
+            1.0,
+        ),
+    ];
+
+    for (color, box_size, position, z) in boxes {
+        parent.spawn((
+            Sprite::from_color(color, box_size),
+            Transform::from_translation(position.extend(z)),
+            layers.clone(),
+        ));
+    }
+}
+
+/// Spawn the mana cost as colored glyphs from the Mana font
+fn spawn_mana_cost(
+    parent: &mut ChildSpawnerCommands,
+    cost: &str,
+    size: Vec2,
+    layers: &RenderLayers,
+    fonts: &FrameFonts,
+    colorblind: bool,
+) {
+    let font_size = size.y * 0.036;
+    let mut text = parent.spawn((
+        Text2d::default(),
+        TextFont {
+            font: fonts.mana.clone(),
+            font_size,
+            ..default()
+        },
+        Anchor::CenterRight,
+        Transform::from_xyz(size.x * 0.40, size.y * 0.41, 2.0),
+        layers.clone(),
+    ));
+
+    text.with_children(|spans| {
+        for (symbol, _) in split_mana_symbols(cost)
+            .into_iter()
+            .filter(|(_, is_symbol)| *is_symbol)
+        {
+            spans.spawn((
+                TextSpan::new(mana_symbol_to_char(symbol)),
+                TextFont {
+                    font: fonts.mana.clone(),
+                    font_size,
+                    ..default()
+                },
+                TextColor(mana_symbol_color(symbol, colorblind)),
+            ));
+        }
+    });
+}
+
+/// Spawn a patterned swatch under the mana cost for each color in it
+///
+/// Used in colorblind mode so colors can be told apart by pattern as well as hue.
+fn spawn_mana_pattern_swatches(
+    parent: &mut ChildSpawnerCommands,
+    cost: &str,
+    size: Vec2,
+    layers: &RenderLayers,
+) {
+    let mut symbols: Vec<&str> = Vec::new();
+    for (symbol, _) in split_mana_symbols(cost)
+        .into_iter()
+        .filter(|(symbol, is_symbol)| *is_symbol && get_mana_pattern(symbol).is_some())
+    {
+        if !symbols.contains(&symbol) {
+            symbols.push(symbol);
+        }
+    }
+
+    let swatch = size.y * 0.03;
+    let right = size.x * 0.40;
+    for (index, symbol) in symbols.into_iter().rev().enumerate() {
+        let Some(pattern) = get_mana_pattern(symbol) else {
+            continue;
+        };
+        let background = mana_symbol_color(symbol, true);
+        let ink = if is_dark_background(symbol, &background) {
+            Color::WHITE
+        } else {
+            Color::BLACK
+        };
+        let center = Vec2::new(
+            right - swatch / 2.0 - index as f32 * swatch * 1.2,
+            size.y * 0.37,
+        );
+
+        parent.spawn((
+            Sprite::from_color(background, Vec2::splat(swatch)),
+            Transform::from_translation(center.extend(2.0)),
+            layers.clone(),
+        ));
+        for (offset, rect) in mana_pattern_rects(pattern, swatch) {
+            parent.spawn((
+                Sprite::from_color(ink, rect),
+                Transform::from_translation((center + offset).extend(2.1)),
+                layers.clone(),
+            ));
+        }
+    }
+}
@@ -0,0 +1,112 @@
+// Tests for procedural card frames
+#[cfg(test)]
+mod tests {
+    use crate::cards::frame::resources::{FRAME_RENDER_LAYER_START, MAX_CONCURRENT_FRAME_RENDERS};
+    use crate::cards::frame::{
+        CardFrameKey, CardFrameRenderer, CardFrameTexture, ProceduralCardFrame, frame_colors,
+        sync_procedural_frames,
+    };
+    use crate::cards::rarity::Rarity;
+    use crate::cards::set::CardSet;
+    use crate::cards::{Card, CardDetails, CardTypes};
+    use crate::mana::Mana;
+    use crate::menu::settings::components::GameplaySettings;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+
+    fn card(name: &str) -> Card {
+        Card::new(
+            name,
+            Mana::default(),
+            CardTypes::INSTANT,
+            CardDetails::Other,
+            "",
+        )
+    }
+
+    fn set(code: &str) -> CardSet {
+        CardSet {
+            code: code.to_string(),
+            name: code.to_string(),
+            release_date: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_multicolor_cards_use_gold_frame() {
+        let red = Mana::new_with_colors(1, 0, 0, 0, 1, 0);
+        let gold = Mana::new_with_colors(0, 0, 0, 0, 1, 1);
+        let gold_again = Mana::new_with_colors(0, 1, 1, 0, 0, 0);
+
+        assert_ne!(
+            frame_colors(&red, &CardTypes::INSTANT),
+            frame_colors(&gold, &CardTypes::INSTANT)
+        );
+        assert_eq!(
+            frame_colors(&gold, &CardTypes::CREATURE),
+            frame_colors(&gold_again, &CardTypes::CREATURE)
+        );
+    }
+
+    #[test]
+    fn test_colorless_lands_and_artifacts_differ() {
+        let free = Mana::default();
+        assert_ne!(
+            frame_colors(&free, &CardTypes::LAND),
+            frame_colors(&free, &CardTypes::ARTIFACT)
+        );
+    }
+
+    #[test]
+    fn test_render_layers_are_pooled() {
+        let mut renderer = CardFrameRenderer::default();
+        let layers: Vec<usize> = (0..MAX_CONCURRENT_FRAME_RENDERS)
+            .map(|_| renderer.reserve_layer().unwrap())
+            .collect();
+        assert_eq!(layers[0], FRAME_RENDER_LAYER_START);
+        assert!(renderer.reserve_layer().is_none());
+
+        renderer.release_layer(layers[3]);
+        assert_eq!(renderer.reserve_layer(), Some(layers[3]));
+    }
+
+    #[test]
+    fn test_printings_get_their_own_frames() {
+        let bolt = card("Lightning Bolt");
+        let alpha = CardFrameKey::new(&bolt, Some(&set("LEA")), Rarity::Common);
+
+        assert_eq!(
+            alpha,
+            CardFrameKey::new(&bolt, Some(&set("LEA")), Rarity::Common)
+        );
+        assert_ne!(
+            alpha,
+            CardFrameKey::new(&bolt, Some(&set("M10")), Rarity::Common)
+        );
+        assert_ne!(
+            alpha,
+            CardFrameKey::new(&bolt, Some(&set("LEA")), Rarity::Uncommon)
+        );
+    }
+
+    #[test]
+    fn test_procedural_frames_follow_the_setting() {
+        let mut world = World::new();
+        world.insert_resource(GameplaySettings {
+            procedural_frames: true,
+            ..default()
+        });
+        let entity = world.spawn((card("Opt"), Sprite::default())).id();
+
+        world.run_system_once(sync_procedural_frames).unwrap();
+        assert!(world.get::<ProceduralCardFrame>(entity).is_some());
+
+        world.entity_mut(entity).insert(CardFrameTexture {
+            image: Handle::default(),
+        });
+        world.resource_mut::<GameplaySettings>().procedural_frames = false;
+        world.run_system_once(sync_procedural_frames).unwrap();
+        assert!(world.get::<ProceduralCardFrame>(entity).is_none());
+        assert!(world.get::<CardFrameTexture>(entity).is_none());
+    }
+}
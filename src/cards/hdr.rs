@@ -3,7 +3,7 @@ use std::time::Duration;
 use bevy::{prelude::*, render::mesh::Mesh};
 
 use crate::cards::{Card, CardCost};
-use crate::menu::state::GameMenuState;
+use crate::menu::state::{GameMenuState, starting_new_game};
 
 /// Component to mark an entity as an HDR emissive card for visual effects
 #[derive(Component)]
@@ -180,10 +180,13 @@ pub struct HDRCardsPlugin;
 
 impl Plugin for HDRCardsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameMenuState::InGame), spawn_emissive_cards)
-            .add_systems(
-                Update,
-                update_emissive_cards.run_if(in_state(GameMenuState::InGame)),
-            );
+        app.add_systems(
+            OnEnter(GameMenuState::InGame),
+            spawn_emissive_cards.run_if(starting_new_game),
+        )
+        .add_systems(
+            Update,
+            update_emissive_cards.run_if(in_state(GameMenuState::InGame)),
+        );
     }
 }
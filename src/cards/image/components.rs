@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+
+/// Image sizes served by Scryfall
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+pub enum CardImageSize {
+    /// 146 x 204
+    Small,
+    /// 488 x 680
+    #[default]
+    Normal,
+    /// 672 x 936
+    Large,
+}
+
+impl CardImageSize {
+    /// Path segment used in Scryfall image URIs
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CardImageSize::Small => "small",
+            CardImageSize::Normal => "normal",
+            CardImageSize::Large => "large",
+        }
+    }
+}
+
+/// Component requesting a downloaded image for a card entity
+///
+/// The battlefield and hand renderers use the downloaded image in place of the
+/// plain card sprite once it is available.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct CardImage {
+    /// Scryfall ID of the printing, also used as the disk cache key
+    pub scryfall_id: String,
+    /// Explicit image URI, overriding the URI derived from the Scryfall ID
+    pub image_uri: Option<String>,
+    /// Size of the image to download
+    pub size: CardImageSize,
+}
+
+impl CardImage {
+    /// Create a card image request for a Scryfall ID
+    pub fn new(scryfall_id: impl Into<String>) -> Self {
+        Self {
+            scryfall_id: scryfall_id.into(),
+            image_uri: None,
+            size: CardImageSize::default(),
+        }
+    }
+
+    /// Use an explicit image URI instead of the derived Scryfall URI
+    pub fn with_uri(mut self, image_uri: impl Into<String>) -> Self {
+        self.image_uri = Some(image_uri.into());
+        self
+    }
+
+    /// Set the image size to download
+    pub fn with_size(mut self, size: CardImageSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// The URI to download this image from
+    ///
+    /// Scryfall stores images under the first two characters of the ID,
+    /// e.g. `https://cards.scryfall.io/normal/front/a/b/ab12....jpg`.
+    pub fn download_uri(&self) -> Option<String> {
+        if let Some(uri) = &self.image_uri {
+            return Some(uri.clone());
+        }
+
+        let mut chars = self.scryfall_id.chars();
+        let first = chars.next()?;
+        let second = chars.next()?;
+        Some(format!(
+            "https://cards.scryfall.io/{}/front/{}/{}/{}.jpg",
+            self.size.as_str(),
+            first,
+            second,
+            self.scryfall_id
+        ))
+    }
+}
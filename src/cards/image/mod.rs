@@ -0,0 +1,27 @@
+//! Card image downloading and caching
+//!
+//! Cards render as plain sprites with spawned text by default. When a card entity
+//! carries a [`CardImage`] component, this module downloads the card scan (from
+//! Scryfall by default), stores it in an on-disk cache keyed by Scryfall ID and
+//! image size, in the image type it was served as, and swaps the card sprite
+//! over to the downloaded texture once it is ready. Cards get a
+//! `CardImage` when their printing's Scryfall ID is in the card database, as it is
+//! for cards loaded from MTGJSON.
+//!
+//! - Downloads run on the IO task pool with a configurable concurrency limit
+//! - Cached scans are loaded from disk without touching the network
+//! - A placeholder texture is shown while an image is loading or has failed
+
+mod components;
+mod plugin;
+mod resources;
+mod systems;
+
+pub mod tests;
+
+pub use components::{CardImage, CardImageSize};
+pub use plugin::CardImagePlugin;
+pub use resources::{CardImageCache, CardImageStatus};
+pub use systems::{
+    apply_card_images, attach_card_images, poll_card_image_downloads, queue_card_images,
+};
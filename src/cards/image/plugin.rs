@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+use super::components::{CardImage, CardImageSize};
+use super::resources::CardImageCache;
+use super::systems::{
+    apply_card_images, attach_card_images, poll_card_image_downloads, queue_card_images,
+    start_card_image_downloads,
+};
+
+/// Plugin for downloading, caching and displaying card images
+pub struct CardImagePlugin;
+
+impl Plugin for CardImagePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CardImage>()
+            .register_type::<CardImageSize>()
+            .init_resource::<CardImageCache>()
+            .add_systems(
+                Update,
+                (
+                    attach_card_images,
+                    queue_card_images,
+                    start_card_image_downloads,
+                    poll_card_image_downloads,
+                    apply_card_images,
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,205 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::tasks::Task;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+use super::components::CardImageSize;
+
+/// Default number of images downloaded at the same time
+pub const DEFAULT_MAX_CONCURRENT_DOWNLOADS: usize = 4;
+
+/// Extensions of the image types card images are cached as
+pub(crate) const IMAGE_EXTENSIONS: [&str; 2] = ["jpg", "png"];
+
+/// Loading status of a single card image
+#[derive(Debug, Clone, PartialEq)]
+pub enum CardImageStatus {
+    /// Waiting for a free download slot
+    Queued,
+    /// Being loaded from disk or downloaded
+    Loading,
+    /// Ready to render
+    Loaded(Handle<Image>),
+    /// Download or decoding failed; the placeholder stays in use
+    Failed,
+}
+
+/// A queued image download
+#[derive(Debug, Clone)]
+pub(crate) struct CardImageRequest {
+    pub scryfall_id: String,
+    pub size: CardImageSize,
+    pub uri: String,
+}
+
+/// Result of a finished download task
+pub(crate) type CardImageTaskResult = Result<Image, String>;
+
+/// Disk and memory cache for downloaded card images
+#[derive(Resource)]
+pub struct CardImageCache {
+    /// Directory downloaded images are stored in
+    pub cache_dir: PathBuf,
+    /// Maximum number of downloads in flight at once
+    pub max_concurrent_downloads: usize,
+    /// Texture shown while an image is loading
+    pub placeholder: Handle<Image>,
+    pub(crate) status: HashMap<String, CardImageStatus>,
+    pub(crate) queue: VecDeque<CardImageRequest>,
+    pub(crate) tasks: HashMap<String, Task<CardImageTaskResult>>,
+}
+
+impl FromWorld for CardImageCache {
+    fn from_world(world: &mut World) -> Self {
+        let placeholder = world
+            .resource_mut::<Assets<Image>>()
+            .add(create_placeholder_image());
+
+        Self::new(default_cache_dir(), placeholder)
+    }
+}
+
+impl CardImageCache {
+    /// Create an empty cache storing images in `cache_dir`
+    pub fn new(cache_dir: PathBuf, placeholder: Handle<Image>) -> Self {
+        Self {
+            cache_dir,
+            max_concurrent_downloads: DEFAULT_MAX_CONCURRENT_DOWNLOADS,
+            placeholder,
+            status: HashMap::new(),
+            queue: VecDeque::new(),
+            tasks: HashMap::new(),
+        }
+    }
+
+    /// Current status of an image, if it has been requested
+    pub fn status(&self, scryfall_id: &str) -> Option<&CardImageStatus> {
+        self.status.get(scryfall_id)
+    }
+
+    /// Handle of a loaded image
+    pub fn get(&self, scryfall_id: &str) -> Option<&Handle<Image>> {
+        match self.status.get(scryfall_id) {
+            Some(CardImageStatus::Loaded(handle)) => Some(handle),
+            _ => None,
+        }
+    }
+
+    /// Number of downloads currently running
+    pub fn in_flight(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Number of downloads waiting for a free slot
+    pub fn queued(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Queue an image for download unless it was already requested
+    ///
+    /// Returns `true` if the image was newly queued.
+    pub fn request(&mut self, scryfall_id: &str, size: CardImageSize, uri: String) -> bool {
+        if self.status.contains_key(scryfall_id) {
+            return false;
+        }
+
+        self.status
+            .insert(scryfall_id.to_string(), CardImageStatus::Queued);
+        self.queue.push_back(CardImageRequest {
+            scryfall_id: scryfall_id.to_string(),
+            size,
+            uri,
+        });
+        true
+    }
+
+    /// Forget a failed image so it is retried the next time it is requested
+    pub fn retry_failed(&mut self) {
+        self.status
+            .retain(|_, status| *status != CardImageStatus::Failed);
+    }
+
+    /// Path an image of `size`, stored as an `extension` file, is cached at on disk
+    ///
+    /// Returns `None` for IDs that are not valid UUIDs, which keeps arbitrary
+    /// strings from escaping the cache directory.
+    pub fn cache_path(
+        &self,
+        scryfall_id: &str,
+        size: CardImageSize,
+        extension: &str,
+    ) -> Option<PathBuf> {
+        cache_path_in(&self.cache_dir, scryfall_id, size, extension)
+    }
+}
+
+/// Path an image is cached at inside `cache_dir`
+pub(crate) fn cache_path_in(
+    cache_dir: &Path,
+    scryfall_id: &str,
+    size: CardImageSize,
+    extension: &str,
+) -> Option<PathBuf> {
+    let id = uuid::Uuid::parse_str(scryfall_id).ok()?;
+    Some(cache_dir.join(format!(
+        "{}-{}.{}",
+        id.hyphenated(),
+        size.as_str(),
+        extension
+    )))
+}
+
+/// Extension of the image type a response's `Content-Type` names
+pub(crate) fn extension_for_content_type(content_type: &str) -> Option<&'static str> {
+    let mime = content_type.split(';').next()?.trim();
+    match mime.to_ascii_lowercase().as_str() {
+        "image/jpeg" | "image/jpg" => Some("jpg"),
+        "image/png" => Some("png"),
+        _ => None,
+    }
+}
+
+/// Extension of the image type a URI's path ends in, ignoring any query
+pub(crate) fn extension_for_uri(uri: &str) -> Option<&'static str> {
+    let path = uri.split(['?', '#']).next()?;
+    let (_, extension) = path.rsplit_once('.')?;
+    match extension.to_ascii_lowercase().as_str() {
+        "jpg" | "jpeg" => Some("jpg"),
+        "png" => Some("png"),
+        _ => None,
+    }
+}
+
+/// Platform cache directory for card images
+fn default_cache_dir() -> PathBuf {
+    // Browsers have no temp directory, and reads from this path simply miss
+    #[cfg(target_arch = "wasm32")]
+    {
+        PathBuf::from("rummage").join("card_images")
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        dirs::cache_dir()
+            .unwrap_or_else(std::env::temp_dir)
+            .join("rummage")
+            .join("card_images")
+    }
+}
+
+/// Small parchment-colored texture shown while images load
+fn create_placeholder_image() -> Image {
+    Image::new_fill(
+        Extent3d {
+            width: 1,
+            height: 1,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[214, 208, 196, 255],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
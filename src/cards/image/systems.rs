@@ -0,0 +1,255 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::image::{CompressedImageFormats, ImageSampler, ImageType};
+use bevy::prelude::*;
+use bevy::tasks::{IoTaskPool, block_on, futures_lite::future};
+use std::path::PathBuf;
+
+use super::components::CardImage;
+use super::resources::{
+    CardImageCache, CardImageRequest, CardImageStatus, CardImageTaskResult, IMAGE_EXTENSIONS,
+    cache_path_in, extension_for_content_type, extension_for_uri,
+};
+use crate::cards::Card;
+use crate::cards::database::{CardDatabase, CardId};
+use crate::text::components::CardTextType;
+use crate::tracing::CardUpdateCounts;
+
+/// Request the image of each card whose printing has a known Scryfall ID
+///
+/// Cards are checked when they're spawned or a pooled card is reused for another
+/// card. Cards without a Scryfall ID, like those from the built-in sets, keep the
+/// plain sprite with spawned text.
+pub fn attach_card_images(
+    mut commands: Commands,
+    database: Option<Res<CardDatabase>>,
+    cards: Query<(Entity, &Card, Option<&CardImage>), Changed<Card>>,
+) {
+    let Some(database) = database else {
+        return;
+    };
+    for (entity, card, image) in cards.iter() {
+        let scryfall_id = database
+            .get(CardId::of(card))
+            .and_then(|definition| definition.scryfall_id.as_deref());
+        match (scryfall_id, image) {
+            (Some(scryfall_id), Some(image)) if image.scryfall_id == scryfall_id => {}
+            (Some(scryfall_id), _) => {
+                commands.entity(entity).insert(CardImage::new(scryfall_id));
+            }
+            (None, Some(_)) => {
+                commands.entity(entity).remove::<CardImage>();
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+/// Queue downloads for new or changed card images
+pub fn queue_card_images(
+    mut cache: ResMut<CardImageCache>,
+    images: Query<&CardImage, Changed<CardImage>>,
+) {
+    for image in images.iter() {
+        match image.download_uri() {
+            Some(uri) => {
+                if cache.request(&image.scryfall_id, image.size, uri) {
+                    debug!("Queued card image {}", image.scryfall_id);
+                }
+            }
+            None => warn!("Card image '{}' has no download URI", image.scryfall_id),
+        }
+    }
+}
+
+/// Start queued downloads while there are free download slots
+pub fn start_card_image_downloads(mut cache: ResMut<CardImageCache>) {
+    let task_pool = IoTaskPool::get();
+
+    // Only borrow the cache mutably when there is something to start, so it is
+    // only marked changed when downloads change
+    while cache.in_flight() < cache.max_concurrent_downloads && !cache.queue.is_empty() {
+        let Some(request) = cache.queue.pop_front() else {
+            break;
+        };
+
+        let cache_dir = cache.cache_dir.clone();
+        let scryfall_id = request.scryfall_id.clone();
+        let task = task_pool.spawn(async move { load_or_download(request, cache_dir) });
+
+        cache
+            .status
+            .insert(scryfall_id.clone(), CardImageStatus::Loading);
+        cache.tasks.insert(scryfall_id, task);
+    }
+}
+
+/// Collect finished downloads and add them to the image assets
+pub fn poll_card_image_downloads(
+    mut cache: ResMut<CardImageCache>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let mut finished = Vec::new();
+    // Polling isn't a change; only finished downloads mark the cache changed
+    for (scryfall_id, task) in cache.bypass_change_detection().tasks.iter_mut() {
+        if let Some(result) = block_on(future::poll_once(task)) {
+            finished.push((scryfall_id.clone(), result));
+        }
+    }
+
+    for (scryfall_id, result) in finished {
+        cache.tasks.remove(&scryfall_id);
+        let status = match result {
+            Ok(image) => {
+                debug!("Loaded card image {}", scryfall_id);
+                CardImageStatus::Loaded(images.add(image))
+            }
+            Err(err) => {
+                warn!("Failed to load card image {}: {}", scryfall_id, err);
+                CardImageStatus::Failed
+            }
+        };
+        cache.status.insert(scryfall_id, status);
+    }
+}
+
+/// Point card sprites at their downloaded image, or the placeholder while loading
+///
+/// Card scans already contain the printed text, so the spawned text children are
+/// hidden while the downloaded image is shown. Cards are only checked when
+/// their image or the cache changed. Cards that lose their image go back to the
+/// plain sprite.
+#[allow(clippy::type_complexity)]
+pub fn apply_card_images(
+    cache: Res<CardImageCache>,
+    mut cards: Query<(Ref<CardImage>, &mut Sprite, Option<&Children>), With<Card>>,
+    mut plain_cards: Query<(&mut Sprite, Option<&Children>), (With<Card>, Without<CardImage>)>,
+    mut removed: RemovedComponents<CardImage>,
+    mut card_text: Query<&mut Visibility, With<CardTextType>>,
+    counts: Option<ResMut<CardUpdateCounts>>,
+) {
+    let cache_changed = cache.is_changed();
+    let mut updated = 0;
+    for entity in removed.read() {
+        let Ok((mut sprite, children)) = plain_cards.get_mut(entity) else {
+            continue;
+        };
+        if sprite.image != Handle::default() {
+            updated += 1;
+            sprite.image = Handle::default();
+            for child in children.into_iter().flatten() {
+                if let Ok(mut visibility) = card_text.get_mut(*child) {
+                    *visibility = Visibility::Inherited;
+                }
+            }
+        }
+    }
+    for (image, mut sprite, children) in cards.iter_mut() {
+        if !cache_changed && !image.is_changed() {
+            continue;
+        }
+        let loaded = cache.get(&image.scryfall_id);
+        let handle = loaded.unwrap_or(&cache.placeholder);
+
+        if sprite.image != *handle {
+            updated += 1;
+            sprite.image = handle.clone();
+            if loaded.is_some() {
+                sprite.color = Color::WHITE;
+            }
+
+            let text_visibility = if loaded.is_some() {
+                Visibility::Hidden
+            } else {
+                Visibility::Inherited
+            };
+            for child in children.into_iter().flatten() {
+                if let Ok(mut visibility) = card_text.get_mut(*child) {
+                    *visibility = text_visibility;
+                }
+            }
+        }
+    }
+
+    if let Some(mut counts) = counts.filter(|_| updated > 0) {
+        counts.visuals += updated;
+    }
+}
+
+/// Load an image from the disk cache, downloading and caching it if missing
+///
+/// Downloads are decoded and cached as the type the response's content type
+/// names, or else the type the URI ends in, falling back to JPEG.
+fn load_or_download(request: CardImageRequest, cache_dir: PathBuf) -> CardImageTaskResult {
+    let cache_path =
+        |extension: &str| cache_path_in(&cache_dir, &request.scryfall_id, request.size, extension);
+    let cached = IMAGE_EXTENSIONS.into_iter().find_map(|extension| {
+        cache_path(extension)
+            .filter(|path| path.exists())
+            .map(|path| (path, extension))
+    });
+
+    let (bytes, extension) = match cached {
+        Some((path, extension)) => (std::fs::read(path).map_err(|e| e.to_string())?, extension),
+        None => {
+            let (bytes, content_type) = download(&request.uri)?;
+            let extension = content_type
+                .as_deref()
+                .and_then(extension_for_content_type)
+                .or_else(|| extension_for_uri(&request.uri))
+                .unwrap_or("jpg");
+            if let Some(path) = cache_path(extension) {
+                if let Err(err) = write_cache_file(&path, &bytes) {
+                    warn!(
+                        "Could not cache card image {}: {}",
+                        request.scryfall_id, err
+                    );
+                }
+            }
+            (bytes, extension)
+        }
+    };
+
+    Image::from_buffer(
+        &bytes,
+        ImageType::Extension(extension),
+        CompressedImageFormats::NONE,
+        true,
+        ImageSampler::Default,
+        RenderAssetUsages::default(),
+    )
+    .map_err(|e| e.to_string())
+}
+
+/// Download an image, returning its bytes and content type
+#[cfg(feature = "native")]
+fn download(uri: &str) -> Result<(Vec<u8>, Option<String>), String> {
+    let response = reqwest::blocking::get(uri).map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP {} for {}", response.status(), uri));
+    }
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    response
+        .bytes()
+        .map(|bytes| (bytes.to_vec(), content_type))
+        .map_err(|e| e.to_string())
+}
+
+/// Without the `native` feature there is no HTTP client, so only cached images load
+#[cfg(not(feature = "native"))]
+fn download(uri: &str) -> Result<(Vec<u8>, Option<String>), String> {
+    Err(format!(
+        "{} can't be downloaded without the native feature",
+        uri
+    ))
+}
+
+fn write_cache_file(path: &PathBuf, bytes: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, bytes)
+}
@@ -0,0 +1,131 @@
+// Tests for card image downloading and caching
+#[cfg(test)]
+mod tests {
+    use crate::cards::database::CardDatabase;
+    use crate::cards::image::resources::{
+        cache_path_in, extension_for_content_type, extension_for_uri,
+    };
+    use crate::cards::image::{
+        CardImage, CardImageCache, CardImageSize, CardImageStatus, attach_card_images,
+    };
+    use crate::cards::{Card, CardDetails, CardTypes};
+    use crate::mana::Mana;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+    use std::path::Path;
+
+    const ID: &str = "56ebc372-aabd-4174-a943-c7bf59e5028d";
+
+    #[test]
+    fn test_scryfall_uri_from_id() {
+        let image = CardImage::new(ID);
+        assert_eq!(
+            image.download_uri().unwrap(),
+            format!("https://cards.scryfall.io/normal/front/5/6/{}.jpg", ID)
+        );
+
+        let large = CardImage::new(ID).with_size(CardImageSize::Large);
+        assert!(large.download_uri().unwrap().contains("/large/front/"));
+    }
+
+    #[test]
+    fn test_explicit_uri_overrides_scryfall() {
+        let image = CardImage::new(ID).with_uri("https://example.com/card.png");
+        assert_eq!(
+            image.download_uri().unwrap(),
+            "https://example.com/card.png"
+        );
+    }
+
+    #[test]
+    fn test_cache_path_rejects_invalid_ids() {
+        let dir = Path::new("/tmp/cards");
+        assert_eq!(
+            cache_path_in(dir, ID, CardImageSize::Normal, "jpg").unwrap(),
+            dir.join(format!("{}-normal.jpg", ID))
+        );
+        assert!(cache_path_in(dir, "../../etc/passwd", CardImageSize::Normal, "jpg").is_none());
+    }
+
+    #[test]
+    fn test_sizes_are_cached_separately() {
+        let dir = Path::new("/tmp/cards");
+        assert_ne!(
+            cache_path_in(dir, ID, CardImageSize::Small, "jpg"),
+            cache_path_in(dir, ID, CardImageSize::Large, "jpg")
+        );
+    }
+
+    #[test]
+    fn test_image_type_comes_from_content_type_or_uri() {
+        assert_eq!(extension_for_content_type("image/png"), Some("png"));
+        assert_eq!(
+            extension_for_content_type("image/jpeg; charset=binary"),
+            Some("jpg")
+        );
+        assert_eq!(extension_for_content_type("text/html"), None);
+
+        assert_eq!(
+            extension_for_uri("https://example.com/card.PNG?1700000000"),
+            Some("png")
+        );
+        assert_eq!(
+            extension_for_uri("https://cards.scryfall.io/normal/front/a/b/ab.jpg"),
+            Some("jpg")
+        );
+        assert_eq!(extension_for_uri("https://example.com/card"), None);
+    }
+
+    #[test]
+    fn test_requests_are_deduplicated() {
+        let mut cache = CardImageCache::new("/tmp/cards".into(), Handle::default());
+        assert!(cache.request(ID, CardImageSize::Normal, "uri".to_string()));
+        assert!(!cache.request(ID, CardImageSize::Normal, "uri".to_string()));
+        assert_eq!(cache.queued(), 1);
+        assert_eq!(cache.status(ID), Some(&CardImageStatus::Queued));
+    }
+
+    fn card(name: &str) -> Card {
+        Card::new(
+            name,
+            Mana::default(),
+            CardTypes::INSTANT,
+            CardDetails::Other,
+            "",
+        )
+    }
+
+    #[test]
+    fn test_cards_with_a_scryfall_id_get_their_image() {
+        let mut world = World::new();
+        let mut database = CardDatabase::default();
+        let id = database.insert(card("Opt"));
+        database.set_scryfall_id(id, ID.to_string());
+        database.insert(card("Homebrew Bolt"));
+        world.insert_resource(database);
+        let known = world.spawn(card("Opt")).id();
+        let unknown = world.spawn(card("Homebrew Bolt")).id();
+
+        world.run_system_once(attach_card_images).unwrap();
+
+        assert_eq!(world.get::<CardImage>(known).unwrap().scryfall_id, ID);
+        assert!(world.get::<CardImage>(unknown).is_none());
+    }
+
+    #[test]
+    fn test_reused_cards_drop_an_image_they_no_longer_have() {
+        let mut world = World::new();
+        let mut database = CardDatabase::default();
+        let id = database.insert(card("Opt"));
+        database.set_scryfall_id(id, ID.to_string());
+        database.insert(card("Homebrew Bolt"));
+        world.insert_resource(database);
+        let entity = world.spawn(card("Opt")).id();
+        world.run_system_once(attach_card_images).unwrap();
+
+        world.entity_mut(entity).insert(card("Homebrew Bolt"));
+        world.run_system_once(attach_card_images).unwrap();
+
+        assert!(world.get::<CardImage>(entity).is_none());
+    }
+}
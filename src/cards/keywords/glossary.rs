@@ -0,0 +1,99 @@
+//! Reminder text for keyword abilities
+//!
+//! Used to explain keywords to players who don't know them yet. Reminder text is
+//! only given for the keywords read from rules text and a few other common ones.
+
+use super::lib::KeywordAbility;
+
+/// The keyword as it's printed, such as "Double strike" for
+/// [`KeywordAbility::DoubleStrike`]
+pub fn keyword_name(keyword: KeywordAbility) -> String {
+    let mut name = String::new();
+    for (index, letter) in format!("{:?}", keyword).chars().enumerate() {
+        if index > 0 && letter.is_uppercase() {
+            name.push(' ');
+            name.extend(letter.to_lowercase());
+        } else {
+            name.push(letter);
+        }
+    }
+    name
+}
+
+/// The reminder text explaining a keyword, if the glossary has it
+pub fn reminder_text(keyword: KeywordAbility) -> Option<&'static str> {
+    use KeywordAbility::*;
+
+    Some(match keyword {
+        Deathtouch => "Any amount of damage this deals to a creature is enough to destroy it.",
+        Defender => "This creature can't attack.",
+        DoubleStrike => "This creature deals both first-strike and regular combat damage.",
+        FirstStrike => "This creature deals combat damage before creatures without first strike.",
+        Flash => "You may cast this spell any time you could cast an instant.",
+        Flying => "This creature can't be blocked except by creatures with flying or reach.",
+        Haste => "This creature can attack and {T} as soon as it comes under your control.",
+        Hexproof => "This can't be the target of spells or abilities your opponents control.",
+        Indestructible => "Damage and effects that say \"destroy\" don't destroy this.",
+        Lifelink => "Damage dealt by this also causes you to gain that much life.",
+        Menace => "This creature can't be blocked except by two or more creatures.",
+        Reach => "This creature can block creatures with flying.",
+        Trample => {
+            "This creature can deal excess combat damage to the player or planeswalker it's attacking."
+        }
+        Vigilance => "Attacking doesn't cause this creature to tap.",
+        Protection => {
+            "This can't be blocked, targeted, dealt damage, enchanted, or equipped by anything with the stated quality."
+        }
+        Ward => {
+            "Whenever this becomes the target of a spell or ability an opponent controls, counter it unless that player pays the ward cost."
+        }
+        Equip => "Attach to target creature you control. Equip only as a sorcery.",
+        Cascade => {
+            "When you cast this spell, exile cards from the top of your library until you exile a nonland card that costs less. You may cast it without paying its mana cost."
+        }
+        Changeling => "This card is every creature type.",
+        Devoid => "This card has no color.",
+        Fear => {
+            "This creature can't be blocked except by artifact creatures and/or black creatures."
+        }
+        Flanking => {
+            "Whenever a creature without flanking blocks this creature, the blocking creature gets -1/-1 until end of turn."
+        }
+        Infect => {
+            "This creature deals damage to creatures in the form of -1/-1 counters and to players in the form of poison counters."
+        }
+        Melee => {
+            "Whenever this creature attacks, it gets +1/+1 until end of turn for each opponent you attacked this combat."
+        }
+        Morph => {
+            "You may cast this card face down as a 2/2 creature for {3}. Turn it face up any time for its morph cost."
+        }
+        Myriad => {
+            "Whenever this creature attacks, for each opponent other than the defending player, you may create a token copy of it tapped and attacking that player. Exile the tokens at end of combat."
+        }
+        Ninjutsu => {
+            "Return an unblocked attacker you control to hand: Put this card onto the battlefield from your hand tapped and attacking."
+        }
+        CommanderNinjutsu => {
+            "Ninjutsu that can also put this card onto the battlefield from the command zone."
+        }
+        Phasing => {
+            "This phases in or out before you untap during each of your untap steps. While it's phased out, it's treated as though it doesn't exist."
+        }
+        Skulk => "This creature can't be blocked by creatures with greater power.",
+        TotemArmor => {
+            "If enchanted creature would be destroyed, instead remove all damage from it and destroy this Aura."
+        }
+        Undying => {
+            "When this creature dies, if it had no +1/+1 counters on it, return it to the battlefield under its owner's control with a +1/+1 counter on it."
+        }
+        Wither => "This deals damage to creatures in the form of -1/-1 counters.",
+        Daybound => {
+            "If a player casts no spells during their own turn, it becomes night next turn."
+        }
+        Nightbound => {
+            "If a player casts at least two spells during their own turn, it becomes day next turn."
+        }
+        _ => return None,
+    })
+}
@@ -142,6 +142,8 @@ pub enum KeywordAbility {
     Mutate,
     Myriad,
     Ninjutsu,
+    /// Ninjutsu that also works from the command zone
+    CommanderNinjutsu,
     Nightbound,
     Offering,
     Outlast,
@@ -225,6 +227,10 @@ impl KeywordAbilities {
             (KeywordAbility::Skulk, "skulk"),
             (KeywordAbility::TotemArmor, "totem armor"),
             (KeywordAbility::Undying, "undying"),
+            (KeywordAbility::Melee, "melee"),
+            (KeywordAbility::Myriad, "myriad"),
+            (KeywordAbility::Daybound, "daybound"),
+            (KeywordAbility::Nightbound, "nightbound"),
         ];
 
         for (keyword, text_match) in simple_keywords {
@@ -246,6 +252,30 @@ impl KeywordAbilities {
             }
         }
 
+        // "Commander ninjutsu {2}{U}{B}" is checked first, since it contains "ninjutsu "
+        for (keyword, text_match) in [
+            (KeywordAbility::CommanderNinjutsu, "commander ninjutsu "),
+            (KeywordAbility::Ninjutsu, "ninjutsu "),
+        ] {
+            if let Some(ninjutsu_match) = text.to_lowercase().find(text_match) {
+                abilities.insert(keyword);
+                let after_ninjutsu = &text[ninjutsu_match + text_match.len()..];
+                let end = after_ninjutsu
+                    .find(['(', '\n'])
+                    .unwrap_or(after_ninjutsu.len());
+                ability_values.insert(keyword, after_ninjutsu[..end].trim().to_string());
+                break;
+            }
+        }
+
+        // "Morph {2}{G}", and "Megamorph {2}{G}", which turns face up the same way
+        if let Some(morph_match) = text.to_lowercase().find("morph ") {
+            abilities.insert(KeywordAbility::Morph);
+            let after_morph = &text[morph_match + "morph ".len()..];
+            let end = after_morph.find(['(', '\n']).unwrap_or(after_morph.len());
+            ability_values.insert(KeywordAbility::Morph, after_morph[..end].trim().to_string());
+        }
+
         if let Some(ward_match) = text.to_lowercase().find("ward ") {
             abilities.insert(KeywordAbility::Ward);
             let after_ward = &text[ward_match + "ward ".len()..];
@@ -1,3 +1,5 @@
 pub use crate::cards::keywords::lib::*;
+pub use glossary::{keyword_name, reminder_text};
+mod glossary;
 mod lib;
 pub mod tests;
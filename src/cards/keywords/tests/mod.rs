@@ -1 +1,31 @@
 // Tests for keywords
+#[cfg(test)]
+mod tests {
+    use crate::cards::keywords::{KeywordAbilities, KeywordAbility, keyword_name, reminder_text};
+
+    #[test]
+    fn test_keyword_names_read_as_printed() {
+        assert_eq!(keyword_name(KeywordAbility::Flying), "Flying");
+        assert_eq!(keyword_name(KeywordAbility::DoubleStrike), "Double strike");
+        assert_eq!(
+            keyword_name(KeywordAbility::CommanderNinjutsu),
+            "Commander ninjutsu"
+        );
+    }
+
+    #[test]
+    fn test_keywords_read_from_rules_text_have_reminders() {
+        let keywords = KeywordAbilities::from_rules_text(
+            "Deathtouch, defender, double strike, first strike, flash, flying, haste, \
+             hexproof, indestructible, lifelink, menace, reach, trample, vigilance, \
+             changeling, infect, devoid, wither, fear, flanking, phasing, skulk, \
+             totem armor, undying, melee, myriad, daybound, nightbound\n\
+             Protection from red\nWard {2}\nMorph {1}{U}\nCommander ninjutsu {1}{B}",
+        );
+        assert!(keywords.abilities.len() > 30);
+        for keyword in keywords.abilities {
+            assert!(reminder_text(keyword).is_some(), "{:?}", keyword);
+        }
+        assert_eq!(reminder_text(KeywordAbility::Scry), None);
+    }
+}
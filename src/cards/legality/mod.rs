@@ -0,0 +1,175 @@
+//! Which formats a card may be played in
+//!
+//! Legality data comes from MTGJSON, keyed by its lowercase format names
+//! ("commander", "modern", ...). Cards without data, such as the built-in sets,
+//! are checked against the Commander banned list only.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Cards banned in Commander, as of the September 2024 announcement
+pub const COMMANDER_BANNED: &[&str] = &[
+    "Ancestral Recall",
+    "Balance",
+    "Biorhythm",
+    "Black Lotus",
+    "Braids, Cabal Minion",
+    "Channel",
+    "Chaos Orb",
+    "Coalition Victory",
+    "Dockside Extortionist",
+    "Emrakul, the Aeons Torn",
+    "Erayo, Soratami Ascendant",
+    "Falling Star",
+    "Fastbond",
+    "Flash",
+    "Gifts Ungiven",
+    "Griselbrand",
+    "Hullbreacher",
+    "Iona, Shield of Emeria",
+    "Jeweled Lotus",
+    "Karakas",
+    "Leovold, Emissary of Trest",
+    "Library of Alexandria",
+    "Limited Resources",
+    "Lutri, the Spellchaser",
+    "Mana Crypt",
+    "Mox Emerald",
+    "Mox Jet",
+    "Mox Pearl",
+    "Mox Ruby",
+    "Mox Sapphire",
+    "Nadu, Winged Wisdom",
+    "Panoptic Mirror",
+    "Paradox Engine",
+    "Primeval Titan",
+    "Prophet of Kruphix",
+    "Recurring Nightmare",
+    "Rofellos, Llanowar Emissary",
+    "Shahrazad",
+    "Sundering Titan",
+    "Sway of the Stars",
+    "Sylvan Primordial",
+    "Time Vault",
+    "Time Walk",
+    "Tinker",
+    "Tolarian Academy",
+    "Trade Secrets",
+    "Upheaval",
+    "Worldfire",
+    "Yawgmoth's Bargain",
+];
+
+/// Whether a card is on the [`COMMANDER_BANNED`] list, ignoring case
+pub fn is_commander_banned(name: &str) -> bool {
+    let name = name.trim();
+    COMMANDER_BANNED
+        .iter()
+        .any(|banned| banned.eq_ignore_ascii_case(name))
+}
+
+/// A card's status in one format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum Legality {
+    Legal,
+    /// Limited to a single copy, as in Vintage
+    Restricted,
+    Banned,
+    /// Never printed in a set the format allows
+    NotLegal,
+}
+
+impl Legality {
+    /// Whether the card may be played at all
+    pub fn is_playable(self) -> bool {
+        matches!(self, Legality::Legal | Legality::Restricted)
+    }
+}
+
+impl From<&str> for Legality {
+    fn from(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "legal" => Legality::Legal,
+            "restricted" => Legality::Restricted,
+            "banned" => Legality::Banned,
+            _ => Legality::NotLegal,
+        }
+    }
+}
+
+impl fmt::Display for Legality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Legality::Legal => "Legal",
+            Legality::Restricted => "Restricted",
+            Legality::Banned => "Banned",
+            Legality::NotLegal => "Not Legal",
+        };
+        f.write_str(label)
+    }
+}
+
+/// A card's legality in every format it has data for
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct CardLegalities {
+    formats: BTreeMap<String, Legality>,
+}
+
+impl CardLegalities {
+    /// Read MTGJSON's `legalities` map, e.g. `{"modern": "Legal"}`
+    pub fn from_mtgjson<'a>(
+        legalities: impl IntoIterator<Item = (&'a String, &'a String)>,
+    ) -> Self {
+        legalities
+            .into_iter()
+            .map(|(format, legality)| (format.as_str(), Legality::from(legality.as_str())))
+            .collect()
+    }
+
+    /// Record a card's legality in a format
+    pub fn set(&mut self, format: &str, legality: Legality) {
+        self.formats.insert(format.to_lowercase(), legality);
+    }
+
+    /// The card's legality in a format, if known
+    pub fn get(&self, format: &str) -> Option<Legality> {
+        self.formats.get(&format.to_lowercase()).copied()
+    }
+
+    /// Whether the card may be played in a format
+    ///
+    /// MTGJSON leaves out formats a card was never printed for, so a format
+    /// without data counts as not legal.
+    pub fn is_legal_in(&self, format: &str) -> bool {
+        self.get(format).is_some_and(Legality::is_playable)
+    }
+
+    /// Whether no format has any data
+    pub fn is_empty(&self) -> bool {
+        self.formats.is_empty()
+    }
+
+    /// Every format with data, in alphabetical order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Legality)> {
+        self.formats
+            .iter()
+            .map(|(format, legality)| (format.as_str(), *legality))
+    }
+}
+
+impl<'a> FromIterator<(&'a str, Legality)> for CardLegalities {
+    fn from_iter<I: IntoIterator<Item = (&'a str, Legality)>>(iter: I) -> Self {
+        let mut legalities = Self::default();
+        for (format, legality) in iter {
+            legalities.set(format, legality);
+        }
+        legalities
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use crate::cards::database::{CardDatabase, CardId};
+use crate::cards::legality::{CardLegalities, Legality, is_commander_banned};
+
+#[test]
+fn test_mtgjson_legalities_are_parsed() {
+    let mtgjson: HashMap<String, String> = [
+        ("vintage", "Restricted"),
+        ("legacy", "Banned"),
+        ("Commander", "Legal"),
+        ("penny", "not_legal"),
+    ]
+    .into_iter()
+    .map(|(format, legality)| (format.to_string(), legality.to_string()))
+    .collect();
+
+    let legalities = CardLegalities::from_mtgjson(&mtgjson);
+
+    assert_eq!(legalities.get("vintage"), Some(Legality::Restricted));
+    assert_eq!(legalities.get("COMMANDER"), Some(Legality::Legal));
+    assert!(legalities.is_legal_in("vintage"));
+    assert!(legalities.is_legal_in("commander"));
+    assert!(!legalities.is_legal_in("legacy"));
+    assert!(!legalities.is_legal_in("penny"));
+    // Formats MTGJSON leaves out are ones the card was never legal in
+    assert!(!legalities.is_legal_in("standard"));
+}
+
+#[test]
+fn test_database_filters_cards_by_format() {
+    let mut database = CardDatabase::with_builtin_sets();
+    let bolt = CardId::from_name("Lightning Bolt");
+    database.set_legalities(
+        bolt,
+        [
+            ("modern", Legality::Legal),
+            ("standard", Legality::NotLegal),
+        ]
+        .into_iter()
+        .collect(),
+    );
+
+    let legal_in = |format| {
+        database
+            .legal_in(format)
+            .map(|definition| definition.card.name.name.as_str())
+            .collect::<Vec<_>>()
+    };
+    assert!(legal_in("modern").contains(&"Lightning Bolt"));
+    assert!(!legal_in("standard").contains(&"Lightning Bolt"));
+
+    // Without legality data only the Commander banned list applies
+    assert!(is_commander_banned("time walk"));
+    assert!(legal_in("modern").contains(&"Time Walk"));
+    assert!(!legal_in("commander").contains(&"Time Walk"));
+    assert!(legal_in("commander").contains(&"Counterspell"));
+}
@@ -3,15 +3,24 @@
 
 // Private modules
 pub mod abilities;
+pub mod animation;
 pub mod builder;
 pub mod card;
 pub mod components;
 pub mod counters;
+pub mod database;
 pub mod details;
 pub mod drag;
+pub mod frame;
+pub mod image;
 pub mod keywords;
+pub mod legality;
+pub mod mods;
 pub mod plugin;
+pub mod pool;
 pub mod rarity;
+pub mod scripting;
+pub mod search;
 pub mod set;
 pub mod state;
 pub mod systems;
@@ -57,5 +66,23 @@ pub use types::CardTypes;
 pub use types::CreatureType;
 pub use types::format_type_line;
 
+// Re-export the card database
+pub use database::{CardDatabase, CardId};
+
+// Re-export format legality types
+pub use legality::{CardLegalities, Legality};
+
+// Re-export card search types
+pub use search::{SearchError, SearchQuery};
+
 // Re-export the plugin
 pub use plugin::CardPlugin;
+
+// Re-export card image types
+pub use image::{CardImage, CardImagePlugin};
+
+// Re-export card animation types
+pub use animation::{CardAnimation, CardAnimationPlugin};
+
+// Re-export procedural frame types
+pub use frame::{CardFramePlugin, ProceduralCardFrame};
@@ -0,0 +1,324 @@
+//! Custom cards and sets loaded from the mods directory
+//!
+//! Each `.toml` file in the mods directory (`mods` by default, see
+//! [`ModsConfig`]) defines one set:
+//!
+//! ```toml
+//! [set]
+//! code = "KTC"
+//! name = "Kitchen Table Classics"
+//!
+//! [[cards]]
+//! name = "Spark Jolt"
+//! mana_cost = "{R}"
+//! types = ["Instant"]
+//! text = "Spark Jolt deals 2 damage to any target."
+//! effects = [{ effect = "deal_damage", amount = 2 }]
+//!
+//! [[cards]]
+//! name = "Porch Lookout"
+//! mana_cost = "{1}{U}"
+//! types = ["Creature"]
+//! power = 1
+//! toughness = 2
+//!
+//! [[cards.triggered]]
+//! when = "enters_the_battlefield"
+//! text = "When Porch Lookout enters the battlefield, scry 2."
+//! effects = [{ effect = "scry", count = 2 }]
+//!
+//! [[cards.activated]]
+//! cost = { mana = "{2}", tap = true }
+//! text = "{2}, {T}: Draw a card."
+//! effects = [{ effect = "draw_cards", count = 1 }]
+//! ```
+//!
+//! Sets are read at startup and their cards added to the [`CardDatabase`], so
+//! the deck builder, deck imports and sandbox mode use them like any other
+//! card. What a card does is built from the primitives in
+//! [`scripting`](crate::cards::scripting). A mod card with the same name as an
+//! existing card replaces it.
+//!
+//! A file that can't be read is skipped and its error kept in [`LoadedMods`],
+//! without stopping the other sets from loading. Plugins can add sets of their
+//! own with [`ModSet::register`].
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::Card;
+use crate::cards::database::CardDatabase;
+use crate::cards::mtgjson::MTGJSONAtomicCard;
+use crate::cards::rarity::Rarity;
+use crate::cards::scripting::{CardScript, EffectPrimitive, ScriptedActivation, ScriptedTrigger};
+use crate::cards::set::CardSet;
+
+/// Directory mods are read from unless [`ModsConfig`] says otherwise
+pub const DEFAULT_MODS_DIR: &str = "mods";
+
+/// Where mods are read from
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct ModsConfig {
+    pub dir: PathBuf,
+}
+
+impl Default for ModsConfig {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from(DEFAULT_MODS_DIR),
+        }
+    }
+}
+
+/// The set a mod file defines
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModSetInfo {
+    pub code: String,
+    pub name: String,
+    /// Release date as YYYY-MM-DD, if it has one
+    #[serde(default)]
+    pub release_date: String,
+}
+
+/// A card defined in a mod file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModCard {
+    pub name: String,
+    /// Mana cost in the usual notation, like `{2}{R}{R}`
+    #[serde(default)]
+    pub mana_cost: String,
+    /// Card types, like `["Artifact", "Creature"]`
+    pub types: Vec<String>,
+    #[serde(default)]
+    pub supertypes: Vec<String>,
+    #[serde(default)]
+    pub subtypes: Vec<String>,
+    /// Rules text, shown on the card
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub power: Option<i32>,
+    #[serde(default)]
+    pub toughness: Option<i32>,
+    /// Rarity, like `"rare"`; common if left out
+    #[serde(default)]
+    pub rarity: Option<String>,
+    /// What the card does when it resolves, in order
+    #[serde(default)]
+    pub effects: Vec<EffectPrimitive>,
+    /// Triggered abilities of the permanent
+    #[serde(default)]
+    pub triggered: Vec<ScriptedTrigger>,
+    /// Activated abilities of the permanent
+    #[serde(default)]
+    pub activated: Vec<ScriptedActivation>,
+}
+
+impl ModCard {
+    /// Build a game card, or None if the card has no type the game knows
+    pub fn to_card(&self) -> Option<Card> {
+        MTGJSONAtomicCard {
+            name: self.name.clone(),
+            uuid: None,
+            set_code: None,
+            rarity: None,
+            mana_cost: Some(self.mana_cost.clone()),
+            types: self.types.clone(),
+            supertypes: self.supertypes.clone(),
+            subtypes: self.subtypes.clone(),
+            text: Some(self.text.clone()),
+            power: self.power.map(|power| power.to_string()),
+            toughness: self.toughness.map(|toughness| toughness.to_string()),
+            legalities: Default::default(),
+            rulings: Vec::new(),
+        }
+        .to_card()
+    }
+
+    /// The card's script, if it does anything
+    pub fn script(&self) -> Option<CardScript> {
+        let script = CardScript {
+            effects: self.effects.clone(),
+            triggered: self.triggered.clone(),
+            activated: self.activated.clone(),
+        };
+        (!script.is_empty()).then_some(script)
+    }
+}
+
+/// A set of custom cards
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModSet {
+    pub set: ModSetInfo,
+    #[serde(default)]
+    pub cards: Vec<ModCard>,
+}
+
+/// Why a mod couldn't be loaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModError {
+    /// The file couldn't be read
+    Io { path: PathBuf, message: String },
+    /// The file isn't a valid set definition
+    Parse { path: PathBuf, message: String },
+    /// A card has no type the game knows, so it was left out
+    UnknownTypes { set: String, card: String },
+}
+
+impl fmt::Display for ModError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModError::Io { path, message } => write!(f, "can't read {:?}: {}", path, message),
+            ModError::Parse { path, message } => write!(f, "invalid mod {:?}: {}", path, message),
+            ModError::UnknownTypes { set, card } => {
+                write!(f, "{} in {} has no known card type", card, set)
+            }
+        }
+    }
+}
+
+impl ModSet {
+    /// Parse a set definition read from `path`
+    pub fn parse(text: &str, path: &Path) -> Result<Self, ModError> {
+        toml::from_str(text).map_err(|e| ModError::Parse {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })
+    }
+
+    /// The set its cards are printed in
+    pub fn card_set(&self) -> CardSet {
+        CardSet {
+            code: self.set.code.clone(),
+            name: self.set.name.clone(),
+            release_date: self.set.release_date.clone(),
+        }
+    }
+
+    /// Add the set's cards to the database
+    ///
+    /// Returns how many cards were added, and the cards that weren't.
+    pub fn register(&self, database: &mut CardDatabase) -> (usize, Vec<ModError>) {
+        let set = self.card_set();
+        let mut added = 0;
+        let mut errors = Vec::new();
+        for mod_card in &self.cards {
+            let Some(card) = mod_card.to_card() else {
+                errors.push(ModError::UnknownTypes {
+                    set: self.set.code.clone(),
+                    card: mod_card.name.clone(),
+                });
+                continue;
+            };
+            let rarity = Rarity::from(mod_card.rarity.as_deref().unwrap_or("common"));
+            let id = database.insert_printing(card, set.clone(), Some(rarity));
+            if let Some(script) = mod_card.script() {
+                database.set_script(id, script);
+            }
+            added += 1;
+        }
+        (added, errors)
+    }
+}
+
+/// Read every set definition in `dir`, in file name order
+///
+/// A missing directory just means there are no mods.
+pub fn read_mod_sets(dir: &Path) -> Vec<Result<ModSet, ModError>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Vec::new(),
+        Err(e) => {
+            return vec![Err(ModError::Io {
+                path: dir.to_path_buf(),
+                message: e.to_string(),
+            })];
+        }
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "toml")
+        })
+        .collect();
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let text = std::fs::read_to_string(path).map_err(|e| ModError::Io {
+                path: path.clone(),
+                message: e.to_string(),
+            })?;
+            ModSet::parse(&text, path)
+        })
+        .collect()
+}
+
+/// A set loaded from the mods directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadedModSet {
+    pub code: String,
+    pub name: String,
+    /// Number of cards added to the database
+    pub card_count: usize,
+}
+
+/// The mods loaded at startup, and what went wrong loading them
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoadedMods {
+    pub sets: Vec<LoadedModSet>,
+    pub errors: Vec<ModError>,
+}
+
+/// Add the sets in the mods directory to the card database
+pub fn load_mods(
+    config: Res<ModsConfig>,
+    mut database: ResMut<CardDatabase>,
+    mut loaded: ResMut<LoadedMods>,
+) {
+    for result in read_mod_sets(&config.dir) {
+        let set = match result {
+            Ok(set) => set,
+            Err(e) => {
+                warn!("Skipping mod: {}", e);
+                loaded.errors.push(e);
+                continue;
+            }
+        };
+
+        let (card_count, errors) = set.register(&mut database);
+        for e in &errors {
+            warn!("Skipping mod card: {}", e);
+        }
+        info!(
+            "Loaded mod set {} ({}) with {} cards",
+            set.set.name, set.set.code, card_count
+        );
+        loaded.errors.extend(errors);
+        loaded.sets.push(LoadedModSet {
+            code: set.set.code,
+            name: set.set.name,
+            card_count,
+        });
+    }
+}
+
+/// Plugin loading custom sets from the mods directory at startup
+pub struct ModsPlugin;
+
+impl Plugin for ModsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ModsConfig>()
+            .init_resource::<LoadedMods>()
+            .add_systems(Startup, load_mods);
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,175 @@
+use std::path::Path;
+
+use bevy::prelude::*;
+
+use crate::cards::database::{CardDatabase, CardId};
+use crate::cards::mods::{LoadedMods, ModError, ModSet, ModsConfig, load_mods, read_mod_sets};
+use crate::cards::rarity::Rarity;
+use crate::cards::scripting::{EffectPrimitive, EffectRecipient, ScriptCost, ScriptTrigger};
+use crate::cards::{CardDetails, CardTypes};
+
+const KITCHEN_TABLE: &str = r#"
+[set]
+code = "KTC"
+name = "Kitchen Table Classics"
+release_date = "2026-10-01"
+
+[[cards]]
+name = "Spark Jolt"
+mana_cost = "{R}"
+types = ["Instant"]
+rarity = "uncommon"
+text = "Spark Jolt deals 2 damage to any target."
+effects = [{ effect = "deal_damage", amount = 2 }]
+
+[[cards]]
+name = "Table Bear"
+mana_cost = "{1}{G}"
+types = ["Creature"]
+subtypes = ["Bear"]
+power = 2
+toughness = 2
+
+[[cards]]
+name = "Strange Thing"
+types = ["Gizmo"]
+"#;
+
+#[test]
+fn test_mod_sets_parse_cards_and_effects() {
+    let set = ModSet::parse(KITCHEN_TABLE, Path::new("ktc.toml")).unwrap();
+    assert_eq!(set.set.code, "KTC");
+    assert_eq!(set.cards.len(), 3);
+    assert_eq!(
+        set.cards[0].effects,
+        vec![EffectPrimitive::DealDamage {
+            amount: 2,
+            to: EffectRecipient::Target,
+        }]
+    );
+    assert!(set.cards[1].script().is_none());
+
+    let bear = set.cards[1].to_card().unwrap();
+    assert!(bear.type_info.types.contains(CardTypes::CREATURE));
+    assert!(matches!(
+        bear.details.details,
+        CardDetails::Creature(ref creature) if creature.power == 2 && creature.toughness == 2
+    ));
+}
+
+#[test]
+fn test_mod_cards_have_triggered_and_activated_abilities() {
+    let text = r#"
+[set]
+code = "KTC"
+name = "Kitchen Table Classics"
+
+[[cards]]
+name = "Porch Lookout"
+mana_cost = "{1}{U}"
+types = ["Creature"]
+power = 1
+toughness = 2
+
+[[cards.triggered]]
+when = "enters_the_battlefield"
+effects = [{ effect = "scry", count = 2 }]
+
+[[cards.activated]]
+cost = { mana = "{2}", tap = true }
+text = "{2}, {T}: Draw a card."
+effects = [{ effect = "draw_cards", count = 1 }]
+"#;
+    let set = ModSet::parse(text, Path::new("ktc.toml")).unwrap();
+    let script = set.cards[0].script().unwrap();
+
+    assert_eq!(
+        script.triggers(ScriptTrigger::EntersTheBattlefield).count(),
+        1
+    );
+    assert_eq!(script.triggers(ScriptTrigger::YourUpkeep).count(), 0);
+    let ability = &script.activated[0];
+    assert_eq!(
+        ability.cost,
+        ScriptCost {
+            mana: "{2}".to_string(),
+            tap: true,
+            sacrifice: false,
+        }
+    );
+    assert_eq!(ability.cost.mana_cost().colorless, 2);
+    assert_eq!(
+        ability.effects,
+        vec![EffectPrimitive::DrawCards { count: 1 }]
+    );
+}
+
+#[test]
+fn test_invalid_mod_sets_report_the_file() {
+    let error = ModSet::parse("[set]\ncode = 1", Path::new("broken.toml")).unwrap_err();
+    assert!(matches!(error, ModError::Parse { ref path, .. } if path == Path::new("broken.toml")));
+}
+
+#[test]
+fn test_registering_a_set_adds_its_cards_to_the_database() {
+    let set = ModSet::parse(KITCHEN_TABLE, Path::new("ktc.toml")).unwrap();
+    let mut database = CardDatabase::default();
+    let (added, errors) = set.register(&mut database);
+
+    assert_eq!(added, 2);
+    assert_eq!(
+        errors,
+        vec![ModError::UnknownTypes {
+            set: "KTC".to_string(),
+            card: "Strange Thing".to_string(),
+        }]
+    );
+
+    let jolt = database.find_by_name("Spark Jolt").unwrap();
+    assert_eq!(jolt.set.as_ref().unwrap().code, "KTC");
+    assert_eq!(jolt.rarity, Some(Rarity::Uncommon));
+    assert_eq!(jolt.script.as_ref().unwrap().effects.len(), 1);
+    assert_eq!(
+        database
+            .get(CardId::from_name("Table Bear"))
+            .unwrap()
+            .rarity,
+        Some(Rarity::Common)
+    );
+    assert!(database.find_by_name("Strange Thing").is_none());
+}
+
+#[test]
+fn test_mods_are_loaded_from_the_mods_directory_at_startup() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("ktc.toml"), KITCHEN_TABLE).unwrap();
+    std::fs::write(dir.path().join("broken.toml"), "not a set").unwrap();
+    std::fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+    let mut app = App::new();
+    app.init_resource::<CardDatabase>()
+        .init_resource::<LoadedMods>()
+        .insert_resource(ModsConfig {
+            dir: dir.path().to_path_buf(),
+        })
+        .add_systems(Startup, load_mods);
+    app.update();
+
+    let loaded = app.world().resource::<LoadedMods>();
+    assert_eq!(loaded.sets.len(), 1);
+    assert_eq!(loaded.sets[0].card_count, 2);
+    // One broken file and one card with no known type
+    assert_eq!(loaded.errors.len(), 2);
+    assert!(
+        app.world()
+            .resource::<CardDatabase>()
+            .find_by_name("Table Bear")
+            .is_some()
+    );
+}
+
+#[test]
+fn test_a_missing_mods_directory_has_no_mods() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(read_mod_sets(&dir.path().join("missing")).is_empty());
+}
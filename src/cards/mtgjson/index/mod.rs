@@ -0,0 +1,521 @@
+//! On-disk index of MTGJSON's bulk card files
+//!
+//! `AtomicCards.json` holds the oracle data of every card ever printed and
+//! `AllPrintings.json` every printing of every set. Both are far too big to keep
+//! in memory, so [`CardIndex::build`] streams them once into a record file on
+//! disk and keeps only a small sorted table of name and uuid offsets in memory.
+//! Lookups then read a single record from disk.
+//!
+//! The record file is a sequence of length-prefixed bincode records, and the
+//! index file holds the sorted lookup tables.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+use bevy::prelude::*;
+use serde::de::{self, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize};
+
+use super::MTGJSONAtomicCard;
+use crate::cards::Card;
+use crate::cards::database::{CardId, Ruling};
+
+/// Bumped whenever the record or index layout changes, so old indexes get rebuilt
+const INDEX_VERSION: u32 = 3;
+const RECORDS_FILE: &str = "cards.bin";
+const INDEX_FILE: &str = "cards.idx";
+
+/// One of MTGJSON's bulk card files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BulkFile {
+    /// Oracle data of every card, keyed by name
+    AtomicCards,
+    /// Every printing of every set, with a uuid per printing
+    AllPrintings,
+}
+
+impl BulkFile {
+    /// Name of the compressed file on the MTGJSON servers
+    pub fn file_name(self) -> &'static str {
+        match self {
+            BulkFile::AtomicCards => "AtomicCards.json.bz2",
+            BulkFile::AllPrintings => "AllPrintings.json.bz2",
+        }
+    }
+
+    /// Where the compressed file is downloaded from
+    pub fn url(self) -> String {
+        format!("https://mtgjson.com/api/v5/{}", self.file_name())
+    }
+}
+
+/// Why a card index couldn't be built or read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardIndexError {
+    /// A file couldn't be read or written
+    Io(String),
+    /// The bulk file couldn't be downloaded
+    Download(String),
+    /// The bulk file isn't valid MTGJSON
+    Format(String),
+    /// The index is missing, corrupted or from another version
+    Corrupted(String),
+}
+
+impl fmt::Display for CardIndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CardIndexError::Io(error) => write!(f, "card index I/O error: {}", error),
+            CardIndexError::Download(error) => write!(f, "could not download cards: {}", error),
+            CardIndexError::Format(error) => write!(f, "invalid MTGJSON file: {}", error),
+            CardIndexError::Corrupted(error) => write!(f, "card index is unusable: {}", error),
+        }
+    }
+}
+
+impl From<std::io::Error> for CardIndexError {
+    fn from(error: std::io::Error) -> Self {
+        CardIndexError::Io(error.to_string())
+    }
+}
+
+/// Lookup tables stored in the index file
+#[derive(Serialize, Deserialize)]
+struct IndexTables {
+    version: u32,
+    source: BulkFile,
+    /// Record offset of the first card with each name, sorted by id
+    names: Vec<(CardId, u64)>,
+    /// Record offset of each printing, sorted by uuid
+    uuids: Vec<(String, u64)>,
+}
+
+/// Card lookups by name and uuid against an on-disk index
+#[derive(Resource)]
+pub struct CardIndex {
+    source: BulkFile,
+    names: Vec<(CardId, u64)>,
+    uuids: Vec<(String, u64)>,
+    records: Mutex<BufReader<File>>,
+}
+
+impl fmt::Debug for CardIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CardIndex")
+            .field("source", &self.source)
+            .field("names", &self.names.len())
+            .field("uuids", &self.uuids.len())
+            .finish()
+    }
+}
+
+impl CardIndex {
+    /// Where indexes are kept by default, next to the cached set archives
+    pub fn default_dir() -> PathBuf {
+        PathBuf::from("sets").join("index")
+    }
+
+    /// Open an index previously built in `dir`
+    pub fn open(dir: &Path) -> Result<Self, CardIndexError> {
+        let bytes = fs::read(dir.join(INDEX_FILE))?;
+        let (tables, _) = bincode::serde::decode_from_slice::<IndexTables, _>(
+            &bytes,
+            bincode::config::standard(),
+        )
+        .map_err(|e| CardIndexError::Corrupted(e.to_string()))?;
+        if tables.version != INDEX_VERSION {
+            return Err(CardIndexError::Corrupted(format!(
+                "index version {} is not {}",
+                tables.version, INDEX_VERSION
+            )));
+        }
+
+        Ok(Self {
+            source: tables.source,
+            names: tables.names,
+            uuids: tables.uuids,
+            records: Mutex::new(BufReader::new(File::open(dir.join(RECORDS_FILE))?)),
+        })
+    }
+
+    /// Build an index in `dir` from an uncompressed bulk file
+    ///
+    /// The file is streamed, so only the card being written is held in memory.
+    pub fn build(reader: impl Read, source: BulkFile, dir: &Path) -> Result<Self, CardIndexError> {
+        fs::create_dir_all(dir)?;
+        // Without its index file a half-written record file is never opened
+        let index_path = dir.join(INDEX_FILE);
+        if index_path.exists() {
+            fs::remove_file(&index_path)?;
+        }
+
+        let mut writer = RecordWriter {
+            records: BufWriter::new(File::create(dir.join(RECORDS_FILE))?),
+            offset: 0,
+            names: HashMap::new(),
+            uuids: Vec::new(),
+        };
+        let mut json = serde_json::Deserializer::from_reader(BufReader::new(reader));
+        BulkFileSeed {
+            source,
+            writer: &mut writer,
+        }
+        .deserialize(&mut json)
+        .and_then(|()| json.end())
+        .map_err(|e| CardIndexError::Format(e.to_string()))?;
+        writer.records.flush()?;
+
+        let mut names: Vec<_> = writer.names.into_iter().collect();
+        names.sort_unstable();
+        let mut uuids = writer.uuids;
+        uuids.sort_unstable();
+        let tables = IndexTables {
+            version: INDEX_VERSION,
+            source,
+            names,
+            uuids,
+        };
+        let bytes = bincode::serde::encode_to_vec(&tables, bincode::config::standard())
+            .map_err(|e| CardIndexError::Corrupted(e.to_string()))?;
+        fs::write(&index_path, bytes)?;
+
+        info!(
+            "Indexed {} cards and {} printings from {}",
+            tables.names.len(),
+            tables.uuids.len(),
+            source.file_name()
+        );
+        Self::open(dir)
+    }
+
+    /// Build an index in `dir` from a downloaded bulk file, compressed or not
+    pub fn build_from_file(
+        path: &Path,
+        source: BulkFile,
+        dir: &Path,
+    ) -> Result<Self, CardIndexError> {
+        let file = File::open(path)?;
+        if path.extension().is_some_and(|extension| extension == "bz2") {
+            Self::build(
+                bzip2::read::BzDecoder::new(BufReader::new(file)),
+                source,
+                dir,
+            )
+        } else {
+            Self::build(file, source, dir)
+        }
+    }
+
+    /// Download a bulk file into `dir` and index it
+    ///
+    /// This blocks for as long as the download takes, so run it on a task pool.
+    pub fn download(source: BulkFile, dir: &Path) -> Result<Self, CardIndexError> {
+        fs::create_dir_all(dir)?;
+        let archive_path = dir.join(source.file_name());
+
+        info!("Downloading {}", source.url());
+        download_file(source.url(), &archive_path)?;
+
+        Self::build_from_file(&archive_path, source, dir)
+    }
+
+    /// Open the index in `dir`, downloading and building it if it's missing or stale
+    pub fn open_or_download(source: BulkFile, dir: &Path) -> Result<Self, CardIndexError> {
+        match Self::open(dir) {
+            Ok(index) if index.source == source => Ok(index),
+            Ok(_) => Self::download(source, dir),
+            Err(err) => {
+                debug!("Rebuilding card index in {:?}: {}", dir, err);
+                Self::download(source, dir)
+            }
+        }
+    }
+
+    /// The bulk file the index was built from
+    pub fn source(&self) -> BulkFile {
+        self.source
+    }
+
+    /// Number of distinct card names
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Whether the index holds no cards
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Whether a card with this name is indexed, without reading it
+    pub fn contains_name(&self, name: &str) -> bool {
+        self.name_offset(name).is_some()
+    }
+
+    /// The oracle data of a card, ignoring case
+    pub fn get_by_name(&self, name: &str) -> Result<Option<MTGJSONAtomicCard>, CardIndexError> {
+        self.name_offset(name)
+            .map(|offset| self.read_record(offset))
+            .transpose()
+    }
+
+    /// A single printing, for indexes built from `AllPrintings`
+    pub fn get_by_uuid(&self, uuid: &str) -> Result<Option<MTGJSONAtomicCard>, CardIndexError> {
+        self.uuids
+            .binary_search_by(|(key, _)| key.as_str().cmp(uuid))
+            .ok()
+            .map(|index| self.read_record(self.uuids[index].1))
+            .transpose()
+    }
+
+    /// The game card called `name`, if it's indexed and the game supports it
+    pub fn card(&self, name: &str) -> Option<Card> {
+        match self.get_by_name(name) {
+            Ok(card) => card.and_then(|card| card.to_card()),
+            Err(err) => {
+                warn!("Could not read {} from the card index: {}", name, err);
+                None
+            }
+        }
+    }
+
+    /// The rulings of the card called `name`, oldest first
+    pub fn rulings(&self, name: &str) -> Result<Vec<Ruling>, CardIndexError> {
+        Ok(self
+            .get_by_name(name)?
+            .map(|card| card.card_rulings())
+            .unwrap_or_default())
+    }
+
+    fn name_offset(&self, name: &str) -> Option<u64> {
+        let id = CardId::from_name(name);
+        self.names
+            .binary_search_by_key(&id, |(id, _)| *id)
+            .ok()
+            .map(|index| self.names[index].1)
+    }
+
+    fn read_record(&self, offset: u64) -> Result<MTGJSONAtomicCard, CardIndexError> {
+        let mut records = self.records.lock().unwrap_or_else(PoisonError::into_inner);
+        records.seek(SeekFrom::Start(offset))?;
+        let mut length = [0; 4];
+        records.read_exact(&mut length)?;
+        let mut bytes = vec![0; u32::from_le_bytes(length) as usize];
+        records.read_exact(&mut bytes)?;
+
+        bincode::serde::decode_from_slice(&bytes, bincode::config::standard())
+            .map(|(card, _)| card)
+            .map_err(|e| CardIndexError::Corrupted(e.to_string()))
+    }
+}
+
+/// Save the file at `url` to `path`
+#[cfg(feature = "native")]
+fn download_file(url: &str, path: &Path) -> Result<(), CardIndexError> {
+    let mut response =
+        reqwest::blocking::get(url).map_err(|e| CardIndexError::Download(e.to_string()))?;
+    if !response.status().is_success() {
+        return Err(CardIndexError::Download(format!(
+            "HTTP {} for {}",
+            response.status(),
+            url
+        )));
+    }
+    let mut file = BufWriter::new(File::create(path)?);
+    response
+        .copy_to(&mut file)
+        .map_err(|e| CardIndexError::Download(e.to_string()))?;
+    file.flush()?;
+    Ok(())
+}
+
+/// Without the `native` feature there is no HTTP client to download with
+#[cfg(not(feature = "native"))]
+fn download_file(url: &str, _path: &Path) -> Result<(), CardIndexError> {
+    Err(CardIndexError::Download(format!(
+        "{} can't be downloaded without the native feature",
+        url
+    )))
+}
+
+/// Appends records to the record file, remembering their offsets
+struct RecordWriter<W: Write> {
+    records: W,
+    offset: u64,
+    names: HashMap<CardId, u64>,
+    uuids: Vec<(String, u64)>,
+}
+
+impl<W: Write> RecordWriter<W> {
+    fn write(&mut self, card: &MTGJSONAtomicCard) -> Result<(), CardIndexError> {
+        let bytes = bincode::serde::encode_to_vec(card, bincode::config::standard())
+            .map_err(|e| CardIndexError::Corrupted(e.to_string()))?;
+        self.records
+            .write_all(&(bytes.len() as u32).to_le_bytes())?;
+        self.records.write_all(&bytes)?;
+
+        // Reprints share a name, so names point at the first printing
+        self.names
+            .entry(CardId::from_name(&card.name))
+            .or_insert(self.offset);
+        if let Some(uuid) = &card.uuid {
+            self.uuids.push((uuid.clone(), self.offset));
+        }
+        self.offset += 4 + bytes.len() as u64;
+        Ok(())
+    }
+}
+
+/// Streams the `data` of a bulk file into a [`RecordWriter`], skipping `meta`
+struct BulkFileSeed<'a, W: Write> {
+    source: BulkFile,
+    writer: &'a mut RecordWriter<W>,
+}
+
+impl<'de, W: Write> DeserializeSeed<'de> for BulkFileSeed<'_, W> {
+    type Value = ();
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, W: Write> Visitor<'de> for BulkFileSeed<'_, W> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an MTGJSON bulk file")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            if key != "data" {
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            }
+            match self.source {
+                BulkFile::AtomicCards => map.next_value_seed(AtomicCardsSeed(&mut *self.writer))?,
+                BulkFile::AllPrintings => map.next_value_seed(SetsSeed(&mut *self.writer))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `AtomicCards` data: card name to the card's faces
+struct AtomicCardsSeed<'a, W: Write>(&'a mut RecordWriter<W>);
+
+impl<'de, W: Write> DeserializeSeed<'de> for AtomicCardsSeed<'_, W> {
+    type Value = ();
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, W: Write> Visitor<'de> for AtomicCardsSeed<'_, W> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map of card names to card faces")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some((name, faces)) = map.next_entry::<String, Vec<MTGJSONAtomicCard>>()? {
+            // Multi-faced cards are indexed under their full name by their front face
+            if let Some(mut card) = faces.into_iter().next() {
+                card.name = name;
+                self.0.write(&card).map_err(de::Error::custom)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `AllPrintings` data: set code to set
+struct SetsSeed<'a, W: Write>(&'a mut RecordWriter<W>);
+
+impl<'de, W: Write> DeserializeSeed<'de> for SetsSeed<'_, W> {
+    type Value = ();
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, W: Write> Visitor<'de> for SetsSeed<'_, W> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map of set codes to sets")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while map.next_key::<IgnoredAny>()?.is_some() {
+            map.next_value_seed(SetSeed(&mut *self.0))?;
+        }
+        Ok(())
+    }
+}
+
+/// A single set, of which only the cards are kept
+struct SetSeed<'a, W: Write>(&'a mut RecordWriter<W>);
+
+impl<'de, W: Write> DeserializeSeed<'de> for SetSeed<'_, W> {
+    type Value = ();
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, W: Write> Visitor<'de> for SetSeed<'_, W> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an MTGJSON set")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "cards" {
+                map.next_value_seed(CardsSeed(&mut *self.0))?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A set's card list, written one card at a time
+struct CardsSeed<'a, W: Write>(&'a mut RecordWriter<W>);
+
+impl<'de, W: Write> DeserializeSeed<'de> for CardsSeed<'_, W> {
+    type Value = ();
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, W: Write> Visitor<'de> for CardsSeed<'_, W> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a list of cards")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while let Some(card) = seq.next_element::<MTGJSONAtomicCard>()? {
+            self.0.write(&card).map_err(de::Error::custom)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
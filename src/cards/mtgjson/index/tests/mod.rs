@@ -0,0 +1,129 @@
+use std::io::Write;
+
+use crate::cards::CardTypes;
+use crate::cards::mtgjson::index::{BulkFile, CardIndex, CardIndexError};
+
+const ATOMIC_CARDS: &str = r#"{
+    "meta": {"date": "2024-01-01", "version": "5.2.2"},
+    "data": {
+        "Lightning Bolt": [{
+            "name": "Lightning Bolt",
+            "manaCost": "{R}",
+            "types": ["Instant"],
+            "supertypes": [],
+            "subtypes": [],
+            "text": "Lightning Bolt deals 3 damage to any target.",
+            "colors": ["R"],
+            "legalities": {"vintage": "Legal"}
+        }],
+        "Grizzly Bears": [{
+            "name": "Grizzly Bears",
+            "manaCost": "{1}{G}",
+            "types": ["Creature"],
+            "subtypes": ["Bear"],
+            "power": "2",
+            "toughness": "2"
+        }],
+        "Fire // Ice": [
+            {"name": "Fire // Ice", "faceName": "Fire", "manaCost": "{1}{R}", "types": ["Instant"]},
+            {"name": "Fire // Ice", "faceName": "Ice", "manaCost": "{1}{U}", "types": ["Instant"]}
+        ]
+    }
+}"#;
+
+const ALL_PRINTINGS: &str = r#"{
+    "meta": {"date": "2024-01-01", "version": "5.2.2"},
+    "data": {
+        "LEA": {
+            "code": "LEA",
+            "name": "Limited Edition Alpha",
+            "booster": {"default": {}},
+            "cards": [
+                {"name": "Lightning Bolt", "uuid": "bolt-lea", "setCode": "LEA", "rarity": "common", "manaCost": "{R}", "types": ["Instant"]},
+                {"name": "Counterspell", "uuid": "counterspell-lea", "setCode": "LEA", "rarity": "uncommon", "manaCost": "{U}{U}", "types": ["Instant"]}
+            ]
+        },
+        "M10": {
+            "cards": [
+                {"name": "Lightning Bolt", "uuid": "bolt-m10", "setCode": "M10", "rarity": "common", "manaCost": "{R}", "types": ["Instant"]}
+            ],
+            "code": "M10"
+        }
+    }
+}"#;
+
+#[test]
+fn test_atomic_cards_are_found_by_name() {
+    let dir = tempfile::tempdir().unwrap();
+    let index =
+        CardIndex::build(ATOMIC_CARDS.as_bytes(), BulkFile::AtomicCards, dir.path()).unwrap();
+
+    assert_eq!(index.len(), 3);
+    assert!(index.contains_name("lightning bolt"));
+    assert!(!index.contains_name("Black Lotus"));
+    assert_eq!(index.get_by_name("Black Lotus").unwrap(), None);
+
+    let bolt = index.get_by_name("Lightning Bolt").unwrap().unwrap();
+    assert_eq!(bolt.mana_cost.as_deref(), Some("{R}"));
+    assert_eq!(bolt.uuid, None);
+
+    let bears = index.card("GRIZZLY BEARS").unwrap();
+    assert!(bears.type_info.types.contains(CardTypes::CREATURE));
+    assert_eq!(bears.name.name, "Grizzly Bears");
+
+    // Split cards are indexed by their full name and front face
+    let fire_ice = index.get_by_name("Fire // Ice").unwrap().unwrap();
+    assert_eq!(fire_ice.mana_cost.as_deref(), Some("{1}{R}"));
+}
+
+#[test]
+fn test_all_printings_are_found_by_uuid() {
+    let dir = tempfile::tempdir().unwrap();
+    let index =
+        CardIndex::build(ALL_PRINTINGS.as_bytes(), BulkFile::AllPrintings, dir.path()).unwrap();
+
+    // Reprints share a name but keep their own printing
+    assert_eq!(index.len(), 2);
+    let reprint = index.get_by_uuid("bolt-m10").unwrap().unwrap();
+    assert_eq!(reprint.set_code.as_deref(), Some("M10"));
+    let first = index.get_by_name("Lightning Bolt").unwrap().unwrap();
+    assert_eq!(first.uuid.as_deref(), Some("bolt-lea"));
+    assert_eq!(index.get_by_uuid("missing").unwrap(), None);
+}
+
+#[test]
+fn test_index_is_reopened_from_disk() {
+    let dir = tempfile::tempdir().unwrap();
+    let archive_path = dir.path().join(BulkFile::AtomicCards.file_name());
+    let mut encoder = bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::fast());
+    encoder.write_all(ATOMIC_CARDS.as_bytes()).unwrap();
+    std::fs::write(&archive_path, encoder.finish().unwrap()).unwrap();
+
+    let index_dir = dir.path().join("index");
+    drop(CardIndex::build_from_file(&archive_path, BulkFile::AtomicCards, &index_dir).unwrap());
+
+    let reopened = CardIndex::open(&index_dir).unwrap();
+    assert_eq!(reopened.source(), BulkFile::AtomicCards);
+    assert_eq!(reopened.len(), 3);
+    assert!(reopened.card("Lightning Bolt").is_some());
+}
+
+#[test]
+fn test_broken_files_are_reported() {
+    let dir = tempfile::tempdir().unwrap();
+
+    assert!(matches!(
+        CardIndex::open(dir.path()),
+        Err(CardIndexError::Io(_))
+    ));
+    assert!(matches!(
+        CardIndex::build(
+            r#"{"data": [1, 2]}"#.as_bytes(),
+            BulkFile::AtomicCards,
+            dir.path()
+        ),
+        Err(CardIndexError::Format(_))
+    ));
+    // A failed build leaves no index behind to be opened by mistake
+    assert!(CardIndex::open(dir.path()).is_err());
+}
@@ -0,0 +1,480 @@
+//! Background loading of MTGJSON sets into the [`CardDatabase`]
+//!
+//! Send a [`LoadSetRequest`] to load a set. Downloading and decompressing happen
+//! on the IO task pool and converting on the compute task pool, so big sets never
+//! stall a frame.
+//! [`SetLoadProgress`] events report how far each load has got, and the cards
+//! are added to the database once the set is converted, followed by either a
+//! [`SetLoaded`] or a [`SetLoadFailed`] event.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+use bevy::tasks::{IoTaskPool, Task, block_on, futures_lite::future};
+use sha2::{Digest, Sha256};
+
+use super::test_utils::MockClient;
+use super::{MTGClient, MTGJSONAtomicCard, MTGJSONSet, MTGJSONSetResponse, par_filter_map};
+use crate::cards::Card;
+use crate::cards::database::{CardDatabase, Ruling};
+use crate::cards::legality::CardLegalities;
+use crate::cards::rarity::Rarity;
+use crate::cards::set::CardSet;
+
+/// Size of the chunks downloads are read in, between progress updates
+const DOWNLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Request to load a set by its code (e.g. "MH2")
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct LoadSetRequest {
+    pub set_code: String,
+}
+
+impl LoadSetRequest {
+    pub fn new(set_code: impl Into<String>) -> Self {
+        Self {
+            set_code: set_code.into(),
+        }
+    }
+}
+
+/// What a set load is currently doing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SetLoadStage {
+    /// Waiting for the task to start
+    Queued,
+    /// Downloading the compressed set, or reading it from the disk cache
+    Downloading,
+    /// Decompressing and parsing the set JSON
+    Decompressing,
+    /// Converting MTGJSON cards to game cards
+    Converting,
+}
+
+impl SetLoadStage {
+    /// Overall progress at `stage_fraction` of the way through this stage
+    ///
+    /// Downloading is usually the slowest part, so it gets half of the bar.
+    pub fn overall_fraction(self, stage_fraction: f32) -> f32 {
+        let (start, end) = match self {
+            SetLoadStage::Queued => (0.0, 0.0),
+            SetLoadStage::Downloading => (0.0, 0.5),
+            SetLoadStage::Decompressing => (0.5, 0.8),
+            SetLoadStage::Converting => (0.8, 1.0),
+        };
+        start + (end - start) * stage_fraction.clamp(0.0, 1.0)
+    }
+}
+
+impl std::fmt::Display for SetLoadStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            SetLoadStage::Queued => "Queued",
+            SetLoadStage::Downloading => "Downloading",
+            SetLoadStage::Decompressing => "Decompressing",
+            SetLoadStage::Converting => "Converting",
+        };
+        f.write_str(label)
+    }
+}
+
+/// Stage and overall progress of a running set load
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SetLoadStatus {
+    pub stage: SetLoadStage,
+    /// Overall progress from 0.0 to 1.0
+    pub fraction: f32,
+}
+
+impl Default for SetLoadStatus {
+    fn default() -> Self {
+        Self {
+            stage: SetLoadStage::Queued,
+            fraction: 0.0,
+        }
+    }
+}
+
+/// Progress of a set load, sent whenever it changes
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct SetLoadProgress {
+    pub set_code: String,
+    pub status: SetLoadStatus,
+}
+
+/// A set finished loading and its cards are in the [`CardDatabase`]
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct SetLoaded {
+    pub set_code: String,
+    /// Number of cards added to the database
+    pub card_count: usize,
+}
+
+/// A set could not be loaded
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct SetLoadFailed {
+    pub set_code: String,
+    pub error: String,
+}
+
+/// Where sets are loaded from
+#[derive(Debug, Clone)]
+pub enum SetSource {
+    /// Download from MTGJSON, keeping the compressed archives in `cache_dir`
+    Mtgjson { cache_dir: PathBuf },
+    /// Serve sets from a mock client, for tests
+    Mock(Arc<MockClient>),
+}
+
+impl Default for SetSource {
+    fn default() -> Self {
+        // Shared with `MTGService`, which caches archives in the same place
+        SetSource::Mtgjson {
+            cache_dir: PathBuf::from("sets"),
+        }
+    }
+}
+
+/// A converted card and the printing details the database keeps with it
+pub(crate) struct LoadedCard {
+    pub card: Card,
+    pub rarity: Rarity,
+    pub scryfall_id: Option<String>,
+    pub legalities: CardLegalities,
+    pub rulings: Vec<Ruling>,
+}
+
+/// A converted set, ready to add to the database
+pub(crate) struct LoadedSet {
+    pub set: CardSet,
+    pub cards: Vec<LoadedCard>,
+}
+
+/// Result of a finished load task
+pub(crate) type SetLoadTaskResult = Result<LoadedSet, String>;
+
+/// A set load running on the IO task pool
+pub(crate) struct SetLoadTask {
+    task: Task<SetLoadTaskResult>,
+    /// Written by the task, read each frame to report progress
+    status: Arc<Mutex<SetLoadStatus>>,
+    last_reported: Option<SetLoadStatus>,
+}
+
+/// Set loads that are in progress
+#[derive(Resource, Default)]
+pub struct SetLoader {
+    /// Where new loads fetch their sets from
+    pub source: SetSource,
+    pub(crate) tasks: HashMap<String, SetLoadTask>,
+}
+
+impl SetLoader {
+    /// A loader fetching sets from `source`
+    pub fn new(source: SetSource) -> Self {
+        Self {
+            source,
+            tasks: HashMap::new(),
+        }
+    }
+
+    /// Whether a set is currently being loaded
+    pub fn is_loading(&self, set_code: &str) -> bool {
+        self.tasks.contains_key(set_code)
+    }
+
+    /// Whether no sets are being loaded
+    pub fn is_idle(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Latest status of every set being loaded
+    pub fn statuses(&self) -> impl Iterator<Item = (&str, SetLoadStatus)> {
+        self.tasks
+            .iter()
+            .map(|(set_code, load)| (set_code.as_str(), read_status(&load.status)))
+    }
+}
+
+/// Plugin for loading MTGJSON sets in the background
+pub struct SetLoaderPlugin;
+
+impl Plugin for SetLoaderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<LoadSetRequest>()
+            .add_event::<SetLoadProgress>()
+            .add_event::<SetLoaded>()
+            .add_event::<SetLoadFailed>()
+            .init_resource::<SetLoader>()
+            .add_systems(Update, (start_set_loads, poll_set_loads).chain());
+    }
+}
+
+/// Spawn a load task for every requested set that isn't already loading
+pub fn start_set_loads(mut requests: EventReader<LoadSetRequest>, mut loader: ResMut<SetLoader>) {
+    let task_pool = IoTaskPool::get();
+
+    for request in requests.read() {
+        if loader.is_loading(&request.set_code) {
+            debug!("Set {} is already loading", request.set_code);
+            continue;
+        }
+
+        let status = Arc::new(Mutex::new(SetLoadStatus::default()));
+        let task_status = status.clone();
+        let source = loader.source.clone();
+        let set_code = request.set_code.clone();
+        let task = task_pool.spawn(async move { load_set(&source, &set_code, &task_status).await });
+
+        info!("Loading set {} in the background", request.set_code);
+        loader.tasks.insert(
+            request.set_code.clone(),
+            SetLoadTask {
+                task,
+                status,
+                last_reported: None,
+            },
+        );
+    }
+}
+
+/// Report progress of running loads and add finished sets to the database
+pub fn poll_set_loads(
+    mut loader: ResMut<SetLoader>,
+    mut database: ResMut<CardDatabase>,
+    mut progress: EventWriter<SetLoadProgress>,
+    mut loaded: EventWriter<SetLoaded>,
+    mut failed: EventWriter<SetLoadFailed>,
+) {
+    let mut finished = Vec::new();
+    for (set_code, load) in loader.tasks.iter_mut() {
+        let status = read_status(&load.status);
+        if load.last_reported != Some(status) {
+            load.last_reported = Some(status);
+            progress.write(SetLoadProgress {
+                set_code: set_code.clone(),
+                status,
+            });
+        }
+
+        if let Some(result) = block_on(future::poll_once(&mut load.task)) {
+            finished.push((set_code.clone(), result));
+        }
+    }
+
+    for (set_code, result) in finished {
+        loader.tasks.remove(&set_code);
+        match result {
+            Ok(loaded_set) => {
+                let card_count = loaded_set.cards.len();
+                for loaded_card in loaded_set.cards {
+                    let id = database.insert_printing(
+                        loaded_card.card,
+                        loaded_set.set.clone(),
+                        Some(loaded_card.rarity),
+                    );
+                    database.set_legalities(id, loaded_card.legalities);
+                    if let Some(scryfall_id) = loaded_card.scryfall_id {
+                        database.set_scryfall_id(id, scryfall_id);
+                    }
+                    // The set's rulings are already in memory, so keep them
+                    database.set_rulings(id, loaded_card.rulings);
+                }
+                info!("Loaded {} cards from set {}", card_count, set_code);
+                loaded.write(SetLoaded {
+                    set_code,
+                    card_count,
+                });
+            }
+            Err(error) => {
+                warn!("Failed to load set {}: {}", set_code, error);
+                failed.write(SetLoadFailed { set_code, error });
+            }
+        }
+    }
+}
+
+fn read_status(status: &Mutex<SetLoadStatus>) -> SetLoadStatus {
+    // A task that panicked mid-update still leaves a usable status behind
+    *status
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn report(status: &Mutex<SetLoadStatus>, stage: SetLoadStage, stage_fraction: f32) {
+    let mut status = status
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *status = SetLoadStatus {
+        stage,
+        fraction: stage.overall_fraction(stage_fraction),
+    };
+}
+
+/// Fetch, decompress and convert a set
+async fn load_set(
+    source: &SetSource,
+    set_code: &str,
+    status: &Mutex<SetLoadStatus>,
+) -> SetLoadTaskResult {
+    let set = match source {
+        SetSource::Mtgjson { cache_dir } => {
+            let compressed = load_or_download(cache_dir, set_code, status)?;
+            decompress_set(&compressed, status)?
+        }
+        SetSource::Mock(client) => {
+            report(status, SetLoadStage::Downloading, 0.0);
+            client
+                .fetch_set(set_code)
+                .await
+                .map_err(|e| e.to_string())?
+        }
+    };
+
+    Ok(convert_set(set, status))
+}
+
+/// Read a set archive from the disk cache, downloading and caching it if missing
+fn load_or_download(
+    cache_dir: &Path,
+    set_code: &str,
+    status: &Mutex<SetLoadStatus>,
+) -> Result<Vec<u8>, String> {
+    let archive_path = cache_dir.join(format!("{}.json.bz2", set_code));
+    if archive_path.exists() {
+        debug!("Reading set {} from {:?}", set_code, archive_path);
+        let bytes = std::fs::read(&archive_path).map_err(|e| e.to_string())?;
+        report(status, SetLoadStage::Downloading, 1.0);
+        return Ok(bytes);
+    }
+
+    let url = format!("https://mtgjson.com/api/v5/{}.json.bz2", set_code);
+    let bytes = download(&url, status)?;
+    if let Err(err) = write_cache_files(&archive_path, &bytes) {
+        warn!("Could not cache set {}: {}", set_code, err);
+    }
+    Ok(bytes)
+}
+
+#[cfg(feature = "native")]
+fn download(url: &str, status: &Mutex<SetLoadStatus>) -> Result<Vec<u8>, String> {
+    report(status, SetLoadStage::Downloading, 0.0);
+    let mut response = reqwest::blocking::get(url).map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP {} for {}", response.status(), url));
+    }
+
+    let total = response.content_length();
+    let mut bytes = Vec::with_capacity(total.unwrap_or(0) as usize);
+    let mut chunk = vec![0; DOWNLOAD_CHUNK_SIZE];
+    loop {
+        let read = response.read(&mut chunk).map_err(|e| e.to_string())?;
+        if read == 0 {
+            break;
+        }
+        bytes.extend_from_slice(&chunk[..read]);
+        if let Some(total) = total.filter(|total| *total > 0) {
+            report(
+                status,
+                SetLoadStage::Downloading,
+                bytes.len() as f32 / total as f32,
+            );
+        }
+    }
+
+    report(status, SetLoadStage::Downloading, 1.0);
+    Ok(bytes)
+}
+
+/// Without the `native` feature there is no HTTP client, so only cached sets load
+#[cfg(not(feature = "native"))]
+fn download(url: &str, _status: &Mutex<SetLoadStatus>) -> Result<Vec<u8>, String> {
+    Err(format!(
+        "{} is not cached and can't be downloaded without the native feature",
+        url
+    ))
+}
+
+/// Store a downloaded archive with the checksum file `MTGService` expects
+fn write_cache_files(archive_path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = archive_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(archive_path, bytes)?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    let mut checksum_path = archive_path.as_os_str().to_owned();
+    checksum_path.push(".sha256");
+    std::fs::write(checksum_path, format!("{:x}", hasher.finalize()))
+}
+
+fn decompress_set(compressed: &[u8], status: &Mutex<SetLoadStatus>) -> Result<MTGJSONSet, String> {
+    report(status, SetLoadStage::Decompressing, 0.0);
+    let reader = ProgressReader {
+        inner: compressed,
+        read: 0,
+        total: compressed.len(),
+        status,
+    };
+    let response: MTGJSONSetResponse =
+        serde_json::from_reader(bzip2::read::BzDecoder::new(reader)).map_err(|e| e.to_string())?;
+    Ok(response.data)
+}
+
+fn convert_set(set: MTGJSONSet, status: &Mutex<SetLoadStatus>) -> LoadedSet {
+    let info = CardSet {
+        code: set.code,
+        name: set.name,
+        release_date: set.release_date,
+    };
+
+    let total = set.cards.len().max(1);
+    let cards = par_filter_map(
+        set.cards,
+        |mtg_card| {
+            let rarity = Rarity::from(mtg_card.rarity.as_str());
+            let scryfall_id = mtg_card.identifiers.scryfall_id.clone();
+            let atomic = MTGJSONAtomicCard::from(mtg_card);
+            let card = atomic.to_card()?;
+            Some(LoadedCard {
+                card,
+                rarity,
+                scryfall_id,
+                legalities: atomic.card_legalities(),
+                rulings: atomic.card_rulings(),
+            })
+        },
+        |done| report(status, SetLoadStage::Converting, done as f32 / total as f32),
+    );
+    report(status, SetLoadStage::Converting, 1.0);
+
+    LoadedSet { set: info, cards }
+}
+
+/// Reports how much of the compressed archive the decompressor has consumed
+struct ProgressReader<'a> {
+    inner: &'a [u8],
+    read: usize,
+    total: usize,
+    status: &'a Mutex<SetLoadStatus>,
+}
+
+impl Read for ProgressReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.read += read;
+        report(
+            self.status,
+            SetLoadStage::Decompressing,
+            self.read as f32 / self.total.max(1) as f32,
+        );
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,196 @@
+use std::io::Write;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::tasks::block_on;
+
+use crate::cards::database::CardDatabase;
+use crate::cards::mtgjson::loader::{
+    LoadSetRequest, SetLoadFailed, SetLoadProgress, SetLoadStage, SetLoaded, SetLoader,
+    SetLoaderPlugin, SetSource,
+};
+use crate::cards::mtgjson::test_utils::{
+    MockClient, create_mock_set, create_test_mtgjson_card, mock_basic_land, mock_instant,
+};
+use crate::cards::mtgjson::{
+    MTGJSONSetMeta, MTGJSONSetResponse, convert_mtgjson_cards, convert_mtgjson_to_card,
+    par_filter_map,
+};
+use crate::cards::rarity::Rarity;
+
+/// Everything the loader reported, in order
+#[derive(Resource, Default)]
+struct Reported {
+    progress: Vec<SetLoadProgress>,
+    loaded: Vec<SetLoaded>,
+    failed: Vec<SetLoadFailed>,
+}
+
+fn collect_reports(
+    mut reported: ResMut<Reported>,
+    mut progress: EventReader<SetLoadProgress>,
+    mut loaded: EventReader<SetLoaded>,
+    mut failed: EventReader<SetLoadFailed>,
+) {
+    reported.progress.extend(progress.read().cloned());
+    reported.loaded.extend(loaded.read().cloned());
+    reported.failed.extend(failed.read().cloned());
+}
+
+fn loader_app(source: SetSource) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, SetLoaderPlugin))
+        .insert_resource(SetLoader::new(source))
+        .init_resource::<CardDatabase>()
+        .init_resource::<Reported>()
+        .add_systems(PostUpdate, collect_reports);
+    app
+}
+
+/// Run frames until every load has finished
+fn run_until_idle(app: &mut App) {
+    app.update();
+    for _ in 0..500 {
+        if app.world().resource::<SetLoader>().is_idle() {
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(5));
+        app.update();
+    }
+    panic!("set loads did not finish");
+}
+
+fn mock_source() -> SetSource {
+    let client = MockClient::new();
+    block_on(client.mock_response("TEST", create_mock_set()));
+    SetSource::Mock(Arc::new(client))
+}
+
+#[test]
+fn test_loaded_set_is_added_to_the_database() {
+    let mut app = loader_app(mock_source());
+    app.world_mut().send_event(LoadSetRequest::new("TEST"));
+
+    run_until_idle(&mut app);
+
+    let reported = app.world().resource::<Reported>();
+    assert!(reported.failed.is_empty());
+    assert_eq!(reported.loaded.len(), 1);
+    assert_eq!(reported.loaded[0].set_code, "TEST");
+
+    let database = app.world().resource::<CardDatabase>();
+    assert_eq!(database.len(), reported.loaded[0].card_count);
+    let instant = database.find_by_name("Test Instant").unwrap();
+    assert_eq!(
+        instant.set.as_ref().map(|set| set.code.as_str()),
+        Some("TEST")
+    );
+    assert!(instant.rarity.is_some());
+    assert!(database.find_by_name("Forest").is_some());
+}
+
+#[test]
+fn test_unknown_set_reports_failure() {
+    let mut app = loader_app(mock_source());
+    app.world_mut().send_event(LoadSetRequest::new("NOPE"));
+
+    run_until_idle(&mut app);
+
+    let reported = app.world().resource::<Reported>();
+    assert!(reported.loaded.is_empty());
+    assert_eq!(reported.failed.len(), 1);
+    assert_eq!(reported.failed[0].set_code, "NOPE");
+    assert!(app.world().resource::<CardDatabase>().is_empty());
+}
+
+#[test]
+fn test_cached_archive_is_decompressed_with_progress() {
+    let dir = tempfile::tempdir().unwrap();
+    let response = MTGJSONSetResponse {
+        data: create_mock_set(),
+        meta: MTGJSONSetMeta::default(),
+    };
+    let json = serde_json::to_vec(&response).unwrap();
+    let mut encoder = bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::fast());
+    encoder.write_all(&json).unwrap();
+    std::fs::write(dir.path().join("TEST.json.bz2"), encoder.finish().unwrap()).unwrap();
+
+    let mut app = loader_app(SetSource::Mtgjson {
+        cache_dir: dir.path().to_path_buf(),
+    });
+    app.world_mut().send_event(LoadSetRequest::new("TEST"));
+
+    run_until_idle(&mut app);
+
+    let reported = app.world().resource::<Reported>();
+    assert_eq!(reported.loaded.len(), 1);
+    let fractions: Vec<f32> = reported
+        .progress
+        .iter()
+        .map(|progress| progress.status.fraction)
+        .collect();
+    assert!(fractions.windows(2).all(|pair| pair[0] <= pair[1]));
+
+    let database = app.world().resource::<CardDatabase>();
+    assert_eq!(
+        database.find_by_name("Test Sorcery").unwrap().rarity,
+        Some(Rarity::Common)
+    );
+}
+
+#[test]
+fn test_stages_fill_the_bar_in_order() {
+    let stages = [
+        SetLoadStage::Queued,
+        SetLoadStage::Downloading,
+        SetLoadStage::Decompressing,
+        SetLoadStage::Converting,
+    ];
+    let ends: Vec<f32> = stages
+        .iter()
+        .map(|stage| stage.overall_fraction(1.0))
+        .collect();
+
+    assert!(ends.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert_eq!(SetLoadStage::Converting.overall_fraction(1.0), 1.0);
+    assert_eq!(SetLoadStage::Downloading.overall_fraction(-1.0), 0.0);
+}
+
+#[test]
+fn test_parallel_conversion_keeps_order_and_reports_growing_progress() {
+    let progress = std::sync::Mutex::new(Vec::new());
+    let evens = par_filter_map(
+        (0..10_000).collect(),
+        |number: u32| (number % 2 == 0).then_some(number),
+        |done| progress.lock().unwrap().push(done),
+    );
+
+    assert_eq!(evens, (0..10_000).step_by(2).collect::<Vec<u32>>());
+    let progress = progress.into_inner().unwrap();
+    assert!(progress.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(progress.last(), Some(&10_000));
+}
+
+#[test]
+fn test_parallel_conversion_matches_serial_conversion() {
+    let mtgjson_cards: Vec<_> = (0..500)
+        .map(|index| match index % 3 {
+            0 => create_test_mtgjson_card(),
+            1 => mock_basic_land(format!("Land {}", index), vec!["Forest".to_string()]),
+            _ => mock_instant(&format!("Instant {}", index)),
+        })
+        .collect();
+    let serial: Vec<String> = mtgjson_cards
+        .iter()
+        .cloned()
+        .filter_map(convert_mtgjson_to_card)
+        .map(|(card, ..)| card.name.name)
+        .collect();
+
+    let parallel: Vec<String> = convert_mtgjson_cards(mtgjson_cards)
+        .into_iter()
+        .map(|card| card.name.name)
+        .collect();
+    assert_eq!(parallel, serial);
+}
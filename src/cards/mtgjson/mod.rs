@@ -12,42 +12,34 @@
 //! The module implements proper rate limiting to respect MTGJSON's API guidelines and includes
 //! robust error handling and data validation.
 
+use crate::cards::database::Ruling;
+use crate::cards::legality::CardLegalities;
+use crate::cards::types::{SubtypeId, SubtypeKind};
 use crate::cards::{
     Card, CardCost, CardDetails, CardDetailsComponent, CardKeywords, CardName, CardRulesText,
     CardTypeInfo, CardTypes, CreatureCard, CreatureType,
 };
 use crate::mana::{Mana, ManaColor};
 use async_trait::async_trait;
-use lazy_static::lazy_static;
-use log::info;
 use regex;
-use reqwest;
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
 use std::collections::HashMap;
-use std::fs;
-use std::io::Write;
-use std::path::Path;
-use std::sync::Arc;
-use std::time::Instant;
-use tokio::sync::Mutex as TokioMutex;
-use tokio::time::{Duration, sleep};
+use std::sync::LazyLock;
 
+pub mod index;
+pub mod loader;
+pub mod parallel;
+#[cfg(feature = "native")]
+pub mod service;
 pub mod test_utils;
 
-use test_utils::MockClient;
+pub use parallel::{convert_mtgjson_cards, par_filter_map};
+#[cfg(feature = "native")]
+pub use service::{MTGClientType, MTGService};
 
-lazy_static! {
-    /// Global rate limiter for MTGJSON API requests
-    /// Ensures we don't exceed the API's rate limits
-    static ref RATE_LIMITER: Arc<TokioMutex<Instant>> = Arc::new(TokioMutex::new(Instant::now()));
-}
-
-/// Duration between API requests (100ms = 10 requests per second max)
-const RATE_LIMIT_DURATION: Duration = Duration::from_millis(100);
-
-#[allow(dead_code)]
-type Error = Box<dyn std::error::Error>;
+/// Matches each `{...}` symbol of a mana cost, compiled once for all conversions
+static MANA_SYMBOL: LazyLock<regex::Regex> =
+    LazyLock::new(|| regex::Regex::new(r"\{([^}]+)\}").unwrap());
 
 /// Response structure for MTGJSON Meta API endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -322,7 +314,7 @@ pub struct MTGJSONCardIdentifiers {
 }
 
 /// Structure representing an official card ruling
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MTGJSONRuling {
     /// Date the ruling was issued
     pub date: String,
@@ -330,6 +322,15 @@ pub struct MTGJSONRuling {
     pub text: String,
 }
 
+impl From<MTGJSONRuling> for Ruling {
+    fn from(ruling: MTGJSONRuling) -> Self {
+        Self {
+            date: ruling.date,
+            text: ruling.text,
+        }
+    }
+}
+
 /// Trait defining the interface for MTG data clients
 #[async_trait]
 pub trait MTGClient: Send + Sync {
@@ -338,415 +339,159 @@ pub trait MTGClient: Send + Sync {
     async fn fetch_set(&self, set_code: &str) -> Result<MTGJSONSet, Box<dyn std::error::Error>>;
 }
 
-/// Enum representing different types of MTG clients
-#[derive(Debug)]
-#[allow(dead_code)]
-pub enum MTGClientType {
-    /// Live HTTP client for actual API requests
-    Http(reqwest::Client),
-    /// Mock client for testing
-    Mock(Arc<MockClient>),
-}
-
-impl MTGClientType {
-    /// Fetches a set from MTGJSON by its set code
-    ///
-    /// This method handles both live HTTP requests and mock responses for testing.
-    /// For HTTP requests, it implements rate limiting to respect API guidelines.
-    ///
-    /// # Arguments
-    ///
-    /// * `set_code` - The code of the set to fetch (e.g., "M21" for Core Set 2021)
-    ///
-    /// # Returns
-    ///
-    /// Returns a Result containing either the fetched set data or an error
-    ///
-    /// # Rate Limiting
-    ///
-    /// HTTP requests are limited to 10 requests per second using a global rate limiter
-    pub async fn fetch_set(
-        &self,
-        set_code: &str,
-    ) -> Result<MTGJSONSet, Box<dyn std::error::Error>> {
-        match self {
-            MTGClientType::Http(client) => {
-                // Apply rate limiting for HTTP requests
-                let mut last_request = RATE_LIMITER.lock().await;
-                let now = Instant::now();
-                let elapsed = now.duration_since(*last_request);
-                if elapsed < RATE_LIMIT_DURATION {
-                    sleep(RATE_LIMIT_DURATION - elapsed).await;
-                }
-                *last_request = Instant::now();
-
-                let url = format!("https://mtgjson.com/api/v5/{}.json.bz2", set_code);
-                let response = client.get(&url).send().await?;
-                if !response.status().is_success() {
-                    return Err(
-                        format!("Failed to fetch set {}: {}", set_code, response.status()).into(),
-                    );
-                }
-                let bytes = response.bytes().await?;
-                let decompressed = bzip2::read::BzDecoder::new(&bytes[..]);
-                let set_response: MTGJSONSetResponse = serde_json::from_reader(decompressed)?;
-                Ok(set_response.data)
-            }
-            MTGClientType::Mock(client) => client.fetch_set(set_code).await,
-        }
-    }
+/// Convert an MTGJSONCard to our internal Card format
+pub fn convert_mtgjson_to_card(
+    mtg_card: MTGJSONCard,
+) -> Option<(
+    Card,
+    CardName,
+    CardCost,
+    CardTypeInfo,
+    CardDetailsComponent,
+    CardRulesText,
+    CardKeywords,
+)> {
+    // Return the card and its individual components
+    MTGJSONAtomicCard::from(mtg_card)
+        .to_card()
+        .map(|card| card.get_components())
 }
 
-/// Service for interacting with MTGJSON data
+/// The oracle fields of a card, shared by every printing
 ///
-/// This service handles fetching and caching of MTG card data,
-/// including versioning and data validation.
-#[allow(dead_code)]
-pub struct MTGService {
-    /// The client used to fetch data (either HTTP or Mock)
-    client: MTGClientType,
-    /// In-memory cache of card sets
-    cache: Arc<TokioMutex<HashMap<String, Vec<Card>>>>,
-    /// Cached metadata about the MTGJSON version
-    meta: Arc<TokioMutex<Option<MTGJSONMeta>>>,
+/// This is the shape of the entries in MTGJSON's `AtomicCards.json`, and the
+/// subset of a printed [`MTGJSONCard`] needed to build a game [`Card`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MTGJSONAtomicCard {
+    /// Card name, with both faces for multi-faced cards
+    pub name: String,
+    /// Unique identifier of the printing, absent for atomic cards
+    #[serde(default)]
+    pub uuid: Option<String>,
+    /// Set code of the printing, absent for atomic cards
+    #[serde(rename = "setCode", default)]
+    pub set_code: Option<String>,
+    /// Rarity of the printing, absent for atomic cards
+    #[serde(default)]
+    pub rarity: Option<String>,
+    /// Mana cost string
+    #[serde(rename = "manaCost", default)]
+    pub mana_cost: Option<String>,
+    /// Card types
+    #[serde(default)]
+    pub types: Vec<String>,
+    /// Card supertypes
+    #[serde(default)]
+    pub supertypes: Vec<String>,
+    /// Card subtypes
+    #[serde(default)]
+    pub subtypes: Vec<String>,
+    /// Card text/rules
+    #[serde(default)]
+    pub text: Option<String>,
+    /// Power (for creatures)
+    #[serde(default)]
+    pub power: Option<String>,
+    /// Toughness (for creatures)
+    #[serde(default)]
+    pub toughness: Option<String>,
+    /// Format name to legality, e.g. "modern" to "Legal"
+    #[serde(default)]
+    pub legalities: HashMap<String, String>,
+    /// Official rulings
+    #[serde(default)]
+    pub rulings: Vec<MTGJSONRuling>,
 }
 
-impl MTGService {
-    /// Creates a new MTGService instance with the specified client
-    #[allow(dead_code)]
-    pub fn new(client: MTGClientType) -> Self {
+impl From<MTGJSONCard> for MTGJSONAtomicCard {
+    fn from(card: MTGJSONCard) -> Self {
         Self {
-            client,
-            cache: Arc::new(TokioMutex::new(HashMap::new())),
-            meta: Arc::new(TokioMutex::new(None)),
+            name: card.name,
+            uuid: Some(card.uuid),
+            set_code: Some(card.set_code),
+            rarity: Some(card.rarity),
+            mana_cost: card.mana_cost,
+            types: card.types,
+            supertypes: card.supertypes,
+            subtypes: card.subtypes,
+            text: card.text,
+            power: card.power,
+            toughness: card.toughness,
+            legalities: card.legalities,
+            rulings: card.rulings.unwrap_or_default(),
         }
     }
+}
 
-    /// Creates a new MTGService instance with a default HTTP client
-    #[allow(dead_code)]
-    pub fn new_with_reqwest() -> Self {
-        Self::new(MTGClientType::Http(reqwest::Client::new()))
-    }
-
-    /// Gets the path for compressed set archives
-    fn get_set_archive_path(&self, set_code: &str) -> std::path::PathBuf {
-        std::path::PathBuf::from("sets").join(format!("{}.json.bz2", set_code))
-    }
-
-    /// Gets the path for set checksums
-    fn get_set_checksum_path(&self, set_code: &str) -> std::path::PathBuf {
-        std::path::PathBuf::from("sets").join(format!("{}.json.bz2.sha256", set_code))
+impl MTGJSONAtomicCard {
+    /// The formats the card may be played in
+    pub fn card_legalities(&self) -> CardLegalities {
+        CardLegalities::from_mtgjson(&self.legalities)
     }
 
-    /// Gets the path for set version information
-    fn get_set_version_path(&self, set_code: &str) -> std::path::PathBuf {
-        std::path::PathBuf::from("sets").join(format!("{}.json.bz2.version", set_code))
+    /// The card's rulings, oldest first
+    pub fn card_rulings(&self) -> Vec<Ruling> {
+        let mut rulings: Vec<_> = self.rulings.iter().cloned().map(Ruling::from).collect();
+        rulings.sort_by(|a, b| a.date.cmp(&b.date));
+        rulings
     }
 
-    /// Fetches metadata about the current MTGJSON version
-    ///
-    /// This includes the current version number and update date.
-    /// Results are cached to avoid unnecessary API calls.
-    pub async fn fetch_meta(&self) -> Result<MTGJSONMeta, Error> {
-        let mut meta = self.meta.lock().await;
-        if meta.is_some() {
-            return Ok(meta.as_ref().unwrap().clone());
-        }
-
-        let url = "https://mtgjson.com/api/v5/Meta.json";
-        let client = reqwest::Client::new();
-        let response = client.get(url).send().await?;
-        let meta_response: MTGJSONMetaResponse = response.json().await?;
-
-        let meta_data = MTGJSONMeta {
-            date: meta_response.data.date,
-            version: meta_response.data.version,
-            checksums: HashMap::new(),
-        };
-        *meta = Some(meta_data.clone());
-        Ok(meta_data)
-    }
-
-    /// Verifies the integrity of a cached set file
-    ///
-    /// Checks both the version and checksum of the file against
-    /// the current MTGJSON version.
-    pub async fn verify_file_checksum(&self, set_code: &str, path: &Path) -> Result<bool, Error> {
-        // First check if we have a version file and if it matches current version
-        let version_path = self.get_set_version_path(set_code);
-        let current_meta = self.fetch_meta().await?;
-
-        if version_path.exists() {
-            let stored_version = fs::read_to_string(&version_path)?;
-            if stored_version.trim() != current_meta.version {
-                return Ok(false); // Version mismatch, need to refresh cache
-            }
+    /// Build a game card, or None if the card has no type the game knows
+    pub fn to_card(&self) -> Option<Card> {
+        // Parse the mana cost
+        let mana_cost = parse_mana_cost(self.mana_cost.as_deref().unwrap_or(""));
+
+        // Get the card types
+        let types = determine_card_type(&self.types, Some(&self.supertypes), Some(&self.subtypes))?;
+
+        // Process card details based on type
+        let rules_text = self.text.as_deref().unwrap_or("");
+        let card_details = if types.contains(CardTypes::CREATURE) {
+            CardDetails::Creature(CreatureCard {
+                power: self.power.as_deref().unwrap_or("0").parse().unwrap_or(0),
+                toughness: self
+                    .toughness
+                    .as_deref()
+                    .unwrap_or("0")
+                    .parse()
+                    .unwrap_or(0),
+                creature_type: determine_creature_types(&self.subtypes, &self.name, rules_text),
+            })
         } else {
-            return Ok(false); // No version file, need to refresh cache
-        }
-
-        // Check if we have a local checksum file
-        let checksum_path = self.get_set_checksum_path(set_code);
-        if checksum_path.exists() {
-            let stored_checksum = fs::read_to_string(&checksum_path)?;
-            let contents = fs::read(path)?;
-            let mut hasher = Sha256::new();
-            hasher.update(&contents);
-            let hash = format!("{:x}", hasher.finalize());
-            return Ok(hash == stored_checksum.trim());
-        }
-
-        Ok(false)
-    }
-
-    /// Saves a set's data to the disk cache
-    ///
-    /// Stores the compressed data along with its checksum and version
-    /// information for future validation.
-    #[allow(dead_code)]
-    async fn save_cache_to_disk(
-        &self,
-        set_code: &str,
-        compressed_data: &[u8],
-    ) -> Result<(), Error> {
-        // Create the sets directory if it doesn't exist
-        fs::create_dir_all("sets")?;
-
-        // Save the compressed bz2 file
-        let set_archive_path = self.get_set_archive_path(set_code);
-        fs::write(&set_archive_path, compressed_data)?;
-
-        // Calculate and save the checksum of the compressed data
-        let mut hasher = Sha256::new();
-        hasher.update(compressed_data);
-        let hash = format!("{:x}", hasher.finalize());
-        let checksum_path = self.get_set_checksum_path(set_code);
-        fs::write(&checksum_path, &hash)?;
-
-        // Save the current version
-        let meta = self.fetch_meta().await?;
-        let version_path = self.get_set_version_path(set_code);
-        fs::write(&version_path, &meta.version)?;
-
-        Ok(())
-    }
-
-    /// Fetches a set by its code, using caching when possible
-    ///
-    /// This method implements a multi-level caching strategy:
-    /// 1. First checks the in-memory cache
-    /// 2. Then checks the disk cache
-    /// 3. Finally falls back to fetching from the API
-    ///
-    /// Cache validation includes both version checking and checksum verification.
-    #[allow(dead_code)]
-    pub async fn fetch_set(&self, set_code: &str) -> Result<Vec<Card>, Box<dyn std::error::Error>> {
-        // Check memory cache first
-        let memory_cache = self.cache.lock().await;
-        if let Some(cards) = memory_cache.get(set_code) {
-            return Ok(cards.clone());
-        }
-        drop(memory_cache);
-
-        // Check if set archive already exists and is valid
-        let set_archive_path = self.get_set_archive_path(set_code);
-
-        if set_archive_path.exists()
-            && self
-                .verify_file_checksum(set_code, &set_archive_path)
-                .await?
-        {
-            // Load from existing archive
-            info!(
-                "Loading set data for {} from existing archive: {:?}",
-                set_code, set_archive_path
-            );
-            let compressed_data = fs::read(&set_archive_path)?;
-            let decompressed = bzip2::read::BzDecoder::new(&compressed_data[..]);
-            let set: MTGJSONSetResponse = serde_json::from_reader(decompressed)?;
-            let cards: Vec<Card> = set
-                .data
-                .cards
-                .into_iter()
-                .filter_map(convert_mtgjson_to_card)
-                .map(|(card, _, _, _, _, _, _)| card)
-                .collect();
-
-            // Update memory cache
-            let mut memory_cache = self.cache.lock().await;
-            memory_cache.insert(set_code.to_string(), cards.clone());
-
-            return Ok(cards);
-        }
-
-        // Get the set data from the client
-        let set = self.client.fetch_set(set_code).await?;
-
-        // Create a complete response with meta data
-        let meta = self.fetch_meta().await?;
-        let response = MTGJSONSetResponse {
-            data: set,
-            meta: MTGJSONSetMeta {
-                version: meta.version,
-                date: meta.date,
-            },
+            CardDetails::Other
         };
 
-        // Convert to JSON and compress
-        let json_data = serde_json::to_string(&response)?;
-        let mut compressed = Vec::new();
-        {
-            let mut compressor =
-                bzip2::write::BzEncoder::new(&mut compressed, bzip2::Compression::best());
-            compressor.write_all(json_data.as_bytes())?;
-            compressor.finish()?;
-        }
-
-        // Save compressed data to disk cache
-        self.save_cache_to_disk(set_code, &compressed).await?;
-
-        // Convert to our internal format
-        let cards: Vec<Card> = response
-            .data
-            .cards
-            .into_iter()
-            .filter_map(convert_mtgjson_to_card)
-            .map(|(card, _, _, _, _, _, _)| card)
-            .collect();
+        let mut card = Card::new(&self.name, mana_cost, types, card_details, rules_text);
 
-        // Update memory cache
-        let mut memory_cache = self.cache.lock().await;
-        memory_cache.insert(set_code.to_string(), cards.clone());
-
-        Ok(cards)
-    }
-
-    /// Fetches multiple sets in sequence
-    ///
-    /// Returns a combined vector of all cards from the specified sets.
-    #[allow(dead_code)]
-    pub async fn fetch_multiple_sets(
-        &self,
-        set_codes: &[&str],
-    ) -> Result<Vec<Card>, Box<dyn std::error::Error>> {
-        let mut all_cards = Vec::new();
-        for set_code in set_codes {
-            let cards = self.fetch_set(set_code).await?;
-            all_cards.extend(cards);
+        // Keep every printed subtype, including those without a bitflag
+        let kind = subtype_kind(types);
+        for subtype in &self.subtypes {
+            card.type_info
+                .subtypes
+                .insert(SubtypeId::intern(subtype, kind));
         }
-        Ok(all_cards)
-    }
-
-    /// Fetches the list of all available sets
-    ///
-    /// Filters the sets based on various criteria:
-    /// - Only includes main sets, expansions, and special sets
-    /// - Excludes unreleased sets
-    /// - Excludes empty sets
-    ///
-    /// Returns a vector of set codes sorted by release date (newest first)
-    #[allow(dead_code)]
-    pub async fn fetch_set_list(&self) -> Result<Vec<String>, Error> {
-        let url = "https://mtgjson.com/api/v5/SetList.json";
-        let client = reqwest::Client::new();
-        let response = client.get(url).send().await?;
-        let set_list: MTGJSONSetList = response.json().await?;
-
-        // Get current date in YYYY-MM-DD format
-        let current_date = chrono::Local::now().format("%Y-%m-%d").to_string();
-
-        // Filter out non-standard sets, unreleased sets, and empty sets
-        let mut sets: Vec<_> = set_list
-            .data
-            .into_iter()
-            .filter(|set| {
-                // Include only main sets, expansions, and special sets
-                matches!(
-                    set.set_type.as_str(),
-                    "core"
-                        | "expansion"
-                        | "masters"
-                        | "draft_innovation"
-                        | "commander"
-                        | "funny"
-                        | "starter"
-                        | "promo"
-                        | "box"
-                        | "duel_deck"
-                        | "premium_deck"
-                        | "from_the_vault"
-                        | "spellbook"
-                        | "masterpiece"
-                ) &&
-                // Filter out future sets and empty sets
-                set.release_date <= current_date &&
-                !set.is_partial_preview &&
-                set.total_set_size > 0
-            })
-            .collect();
 
-        // Sort by release date (newest first)
-        sets.sort_by(|a, b| b.release_date.cmp(&a.release_date));
-
-        Ok(sets.into_iter().map(|s| s.code).collect())
+        Some(card)
     }
 }
 
-/// Convert an MTGJSONCard to our internal Card format
-pub fn convert_mtgjson_to_card(
-    mtg_card: MTGJSONCard,
-) -> Option<(
-    Card,
-    CardName,
-    CardCost,
-    CardTypeInfo,
-    CardDetailsComponent,
-    CardRulesText,
-    CardKeywords,
-)> {
-    // Parse the mana cost
-    let mana_cost = parse_mana_cost(&mtg_card.mana_cost.unwrap());
-
-    // Get the card types
-    let types = determine_card_type(
-        &mtg_card.types,
-        Some(&mtg_card.supertypes),
-        Some(&mtg_card.subtypes),
-    )?;
-
-    // Process card details based on type
-    let card_details = if types.contains(CardTypes::CREATURE) {
-        CardDetails::Creature(CreatureCard {
-            power: mtg_card
-                .power
-                .as_deref()
-                .unwrap_or("0")
-                .parse()
-                .unwrap_or(0),
-            toughness: mtg_card
-                .toughness
-                .as_deref()
-                .unwrap_or("0")
-                .parse()
-                .unwrap_or(0),
-            creature_type: determine_creature_types(
-                &mtg_card.subtypes,
-                &mtg_card.name,
-                mtg_card.text.as_deref().unwrap_or(""),
-            ),
-        })
+/// The kind of subtype a card's unfamiliar subtypes are taken to be
+fn subtype_kind(types: CardTypes) -> SubtypeKind {
+    if types.intersects(CardTypes::CREATURE | CardTypes::TRIBAL) {
+        SubtypeKind::Creature
+    } else if types.contains(CardTypes::LAND) {
+        SubtypeKind::Land
+    } else if types.contains(CardTypes::ARTIFACT) {
+        SubtypeKind::Artifact
+    } else if types.contains(CardTypes::ENCHANTMENT) {
+        SubtypeKind::Enchantment
+    } else if types.contains(CardTypes::PLANESWALKER) {
+        SubtypeKind::Planeswalker
+    } else if types.intersects(CardTypes::INSTANT | CardTypes::SORCERY) {
+        SubtypeKind::Spell
     } else {
-        CardDetails::Other
-    };
-
-    let rules_text = mtg_card.text.unwrap_or_default();
-    let name = mtg_card.name;
-
-    // Create the card and return it with its components
-    let card = Card::new(&name, mana_cost, types, card_details, &rules_text);
-
-    // Return the card and its individual components
-    Some(card.get_components())
+        SubtypeKind::Other
+    }
 }
 
 /// Determines the card types from type strings
@@ -919,13 +664,12 @@ pub fn determine_creature_types(subtypes: &[String], name: &str, text: &str) ->
 }
 
 /// Parse a mana cost string into a Mana struct
-fn parse_mana_cost(mana_cost: &str) -> Mana {
+pub fn parse_mana_cost(mana_cost: &str) -> Mana {
     let mut result = Mana::default();
     let mut generic_mana = 0;
 
     // Extract all mana symbols from the string
-    let re = regex::Regex::new(r"\{([^}]+)\}").unwrap();
-    for cap in re.captures_iter(mana_cost) {
+    for cap in MANA_SYMBOL.captures_iter(mana_cost) {
         let symbol = cap.get(1).unwrap().as_str();
         match symbol {
             "W" => result.white += 1,
@@ -1029,13 +773,3 @@ pub struct MTGJSONSetInfo {
     #[serde(default)]
     pub translations: HashMap<String, Option<String>>,
 }
-
-#[cfg(feature = "mtgjson")]
-pub fn http(client: reqwest::Client) -> MTGClientType {
-    MTGClientType::Http(client)
-}
-
-#[cfg(feature = "mtgjson")]
-pub async fn create_http() -> MTGClientType {
-    MTGClientType::Http(reqwest::Client::new())
-}
@@ -0,0 +1,81 @@
+//! Converting MTGJSON cards on every core
+//!
+//! Sets are converted card by card with no shared state, so the cards are split
+//! into chunks and converted on the [`ComputeTaskPool`], keeping their order.
+
+use std::sync::{Mutex, PoisonError};
+
+use bevy::tasks::{ComputeTaskPool, TaskPool};
+
+use super::{MTGJSONCard, convert_mtgjson_to_card};
+use crate::cards::Card;
+
+/// Fewest items given to one task, so small sets aren't split into tiny tasks
+const MIN_CHUNK_SIZE: usize = 64;
+
+/// Chunks per pool thread, so threads that finish early can pick up more work
+const CHUNKS_PER_THREAD: usize = 4;
+
+/// Convert MTGJSON cards into game cards in parallel, dropping unsupported ones
+pub fn convert_mtgjson_cards(cards: Vec<MTGJSONCard>) -> Vec<Card> {
+    par_filter_map(
+        cards,
+        |card| convert_mtgjson_to_card(card).map(|(card, ..)| card),
+        |_| {},
+    )
+}
+
+/// Filter and map `items` on the compute task pool, keeping their order
+///
+/// `on_progress` is called with the number of items done so far as each chunk
+/// finishes, one call at a time and with the count only ever growing.
+pub fn par_filter_map<T, U>(
+    items: Vec<T>,
+    convert: impl Fn(T) -> Option<U> + Sync,
+    on_progress: impl Fn(usize) + Sync,
+) -> Vec<U>
+where
+    T: Send,
+    U: Send + 'static,
+{
+    let pool = ComputeTaskPool::get_or_init(TaskPool::default);
+    let chunk_size = items
+        .len()
+        .div_ceil(pool.thread_num().max(1) * CHUNKS_PER_THREAD)
+        .max(MIN_CHUNK_SIZE);
+
+    if items.len() <= chunk_size {
+        let total = items.len();
+        let converted = items.into_iter().filter_map(&convert).collect();
+        on_progress(total);
+        return converted;
+    }
+
+    let mut chunks = Vec::new();
+    let mut items = items.into_iter();
+    loop {
+        let chunk: Vec<T> = items.by_ref().take(chunk_size).collect();
+        if chunk.is_empty() {
+            break;
+        }
+        chunks.push(chunk);
+    }
+
+    let done = Mutex::new(0);
+    let (convert, on_progress, done) = (&convert, &on_progress, &done);
+    pool.scope(|scope| {
+        for chunk in chunks {
+            scope.spawn(async move {
+                let len = chunk.len();
+                let converted: Vec<U> = chunk.into_iter().filter_map(convert).collect();
+                let mut done = done.lock().unwrap_or_else(PoisonError::into_inner);
+                *done += len;
+                on_progress(*done);
+                converted
+            });
+        }
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
@@ -0,0 +1,386 @@
+//! Fetching MTGJSON sets over HTTP, with a rate limit and a disk cache
+//!
+//! Needs tokio and reqwest, so it is only built with the `native` feature.
+
+use lazy_static::lazy_static;
+use log::info;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::Mutex as TokioMutex;
+use tokio::time::{Duration, sleep};
+
+use super::test_utils::MockClient;
+use super::{
+    MTGClient, MTGJSONMeta, MTGJSONMetaResponse, MTGJSONSet, MTGJSONSetList, MTGJSONSetMeta,
+    MTGJSONSetResponse, convert_mtgjson_cards,
+};
+use crate::cards::Card;
+
+lazy_static! {
+    /// Global rate limiter for MTGJSON API requests
+    /// Ensures we don't exceed the API's rate limits
+    static ref RATE_LIMITER: Arc<TokioMutex<Instant>> = Arc::new(TokioMutex::new(Instant::now()));
+}
+
+/// Duration between API requests (100ms = 10 requests per second max)
+const RATE_LIMIT_DURATION: Duration = Duration::from_millis(100);
+
+#[allow(dead_code)]
+type Error = Box<dyn std::error::Error>;
+
+/// Enum representing different types of MTG clients
+#[derive(Debug)]
+#[allow(dead_code)]
+pub enum MTGClientType {
+    /// Live HTTP client for actual API requests
+    Http(reqwest::Client),
+    /// Mock client for testing
+    Mock(Arc<MockClient>),
+}
+
+impl MTGClientType {
+    /// Fetches a set from MTGJSON by its set code
+    ///
+    /// This method handles both live HTTP requests and mock responses for testing.
+    /// For HTTP requests, it implements rate limiting to respect API guidelines.
+    ///
+    /// # Arguments
+    ///
+    /// * `set_code` - The code of the set to fetch (e.g., "M21" for Core Set 2021)
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing either the fetched set data or an error
+    ///
+    /// # Rate Limiting
+    ///
+    /// HTTP requests are limited to 10 requests per second using a global rate limiter
+    pub async fn fetch_set(
+        &self,
+        set_code: &str,
+    ) -> Result<MTGJSONSet, Box<dyn std::error::Error>> {
+        match self {
+            MTGClientType::Http(client) => {
+                // Apply rate limiting for HTTP requests
+                let mut last_request = RATE_LIMITER.lock().await;
+                let now = Instant::now();
+                let elapsed = now.duration_since(*last_request);
+                if elapsed < RATE_LIMIT_DURATION {
+                    sleep(RATE_LIMIT_DURATION - elapsed).await;
+                }
+                *last_request = Instant::now();
+
+                let url = format!("https://mtgjson.com/api/v5/{}.json.bz2", set_code);
+                let response = client.get(&url).send().await?;
+                if !response.status().is_success() {
+                    return Err(
+                        format!("Failed to fetch set {}: {}", set_code, response.status()).into(),
+                    );
+                }
+                let bytes = response.bytes().await?;
+                let decompressed = bzip2::read::BzDecoder::new(&bytes[..]);
+                let set_response: MTGJSONSetResponse = serde_json::from_reader(decompressed)?;
+                Ok(set_response.data)
+            }
+            MTGClientType::Mock(client) => client.fetch_set(set_code).await,
+        }
+    }
+}
+
+/// Service for interacting with MTGJSON data
+///
+/// This service handles fetching and caching of MTG card data,
+/// including versioning and data validation.
+#[allow(dead_code)]
+pub struct MTGService {
+    /// The client used to fetch data (either HTTP or Mock)
+    client: MTGClientType,
+    /// In-memory cache of card sets
+    cache: Arc<TokioMutex<HashMap<String, Vec<Card>>>>,
+    /// Cached metadata about the MTGJSON version
+    meta: Arc<TokioMutex<Option<MTGJSONMeta>>>,
+}
+
+impl MTGService {
+    /// Creates a new MTGService instance with the specified client
+    #[allow(dead_code)]
+    pub fn new(client: MTGClientType) -> Self {
+        Self {
+            client,
+            cache: Arc::new(TokioMutex::new(HashMap::new())),
+            meta: Arc::new(TokioMutex::new(None)),
+        }
+    }
+
+    /// Creates a new MTGService instance with a default HTTP client
+    #[allow(dead_code)]
+    pub fn new_with_reqwest() -> Self {
+        Self::new(MTGClientType::Http(reqwest::Client::new()))
+    }
+
+    /// Gets the path for compressed set archives
+    fn get_set_archive_path(&self, set_code: &str) -> std::path::PathBuf {
+        std::path::PathBuf::from("sets").join(format!("{}.json.bz2", set_code))
+    }
+
+    /// Gets the path for set checksums
+    fn get_set_checksum_path(&self, set_code: &str) -> std::path::PathBuf {
+        std::path::PathBuf::from("sets").join(format!("{}.json.bz2.sha256", set_code))
+    }
+
+    /// Gets the path for set version information
+    fn get_set_version_path(&self, set_code: &str) -> std::path::PathBuf {
+        std::path::PathBuf::from("sets").join(format!("{}.json.bz2.version", set_code))
+    }
+
+    /// Fetches metadata about the current MTGJSON version
+    ///
+    /// This includes the current version number and update date.
+    /// Results are cached to avoid unnecessary API calls.
+    pub async fn fetch_meta(&self) -> Result<MTGJSONMeta, Error> {
+        let mut meta = self.meta.lock().await;
+        if meta.is_some() {
+            return Ok(meta.as_ref().unwrap().clone());
+        }
+
+        let url = "https://mtgjson.com/api/v5/Meta.json";
+        let client = reqwest::Client::new();
+        let response = client.get(url).send().await?;
+        let meta_response: MTGJSONMetaResponse = response.json().await?;
+
+        let meta_data = MTGJSONMeta {
+            date: meta_response.data.date,
+            version: meta_response.data.version,
+            checksums: HashMap::new(),
+        };
+        *meta = Some(meta_data.clone());
+        Ok(meta_data)
+    }
+
+    /// Verifies the integrity of a cached set file
+    ///
+    /// Checks both the version and checksum of the file against
+    /// the current MTGJSON version.
+    pub async fn verify_file_checksum(&self, set_code: &str, path: &Path) -> Result<bool, Error> {
+        // First check if we have a version file and if it matches current version
+        let version_path = self.get_set_version_path(set_code);
+        let current_meta = self.fetch_meta().await?;
+
+        if version_path.exists() {
+            let stored_version = fs::read_to_string(&version_path)?;
+            if stored_version.trim() != current_meta.version {
+                return Ok(false); // Version mismatch, need to refresh cache
+            }
+        } else {
+            return Ok(false); // No version file, need to refresh cache
+        }
+
+        // Check if we have a local checksum file
+        let checksum_path = self.get_set_checksum_path(set_code);
+        if checksum_path.exists() {
+            let stored_checksum = fs::read_to_string(&checksum_path)?;
+            let contents = fs::read(path)?;
+            let mut hasher = Sha256::new();
+            hasher.update(&contents);
+            let hash = format!("{:x}", hasher.finalize());
+            return Ok(hash == stored_checksum.trim());
+        }
+
+        Ok(false)
+    }
+
+    /// Saves a set's data to the disk cache
+    ///
+    /// Stores the compressed data along with its checksum and version
+    /// information for future validation.
+    #[allow(dead_code)]
+    async fn save_cache_to_disk(
+        &self,
+        set_code: &str,
+        compressed_data: &[u8],
+    ) -> Result<(), Error> {
+        // Create the sets directory if it doesn't exist
+        fs::create_dir_all("sets")?;
+
+        // Save the compressed bz2 file
+        let set_archive_path = self.get_set_archive_path(set_code);
+        fs::write(&set_archive_path, compressed_data)?;
+
+        // Calculate and save the checksum of the compressed data
+        let mut hasher = Sha256::new();
+        hasher.update(compressed_data);
+        let hash = format!("{:x}", hasher.finalize());
+        let checksum_path = self.get_set_checksum_path(set_code);
+        fs::write(&checksum_path, &hash)?;
+
+        // Save the current version
+        let meta = self.fetch_meta().await?;
+        let version_path = self.get_set_version_path(set_code);
+        fs::write(&version_path, &meta.version)?;
+
+        Ok(())
+    }
+
+    /// Fetches a set by its code, using caching when possible
+    ///
+    /// This method implements a multi-level caching strategy:
+    /// 1. First checks the in-memory cache
+    /// 2. Then checks the disk cache
+    /// 3. Finally falls back to fetching from the API
+    ///
+    /// Cache validation includes both version checking and checksum verification.
+    #[allow(dead_code)]
+    pub async fn fetch_set(&self, set_code: &str) -> Result<Vec<Card>, Box<dyn std::error::Error>> {
+        // Check memory cache first
+        let memory_cache = self.cache.lock().await;
+        if let Some(cards) = memory_cache.get(set_code) {
+            return Ok(cards.clone());
+        }
+        drop(memory_cache);
+
+        // Check if set archive already exists and is valid
+        let set_archive_path = self.get_set_archive_path(set_code);
+
+        if set_archive_path.exists()
+            && self
+                .verify_file_checksum(set_code, &set_archive_path)
+                .await?
+        {
+            // Load from existing archive
+            info!(
+                "Loading set data for {} from existing archive: {:?}",
+                set_code, set_archive_path
+            );
+            let compressed_data = fs::read(&set_archive_path)?;
+            let decompressed = bzip2::read::BzDecoder::new(&compressed_data[..]);
+            let set: MTGJSONSetResponse = serde_json::from_reader(decompressed)?;
+            let cards = convert_mtgjson_cards(set.data.cards);
+
+            // Update memory cache
+            let mut memory_cache = self.cache.lock().await;
+            memory_cache.insert(set_code.to_string(), cards.clone());
+
+            return Ok(cards);
+        }
+
+        // Get the set data from the client
+        let set = self.client.fetch_set(set_code).await?;
+
+        // Create a complete response with meta data
+        let meta = self.fetch_meta().await?;
+        let response = MTGJSONSetResponse {
+            data: set,
+            meta: MTGJSONSetMeta {
+                version: meta.version,
+                date: meta.date,
+            },
+        };
+
+        // Convert to JSON and compress
+        let json_data = serde_json::to_string(&response)?;
+        let mut compressed = Vec::new();
+        {
+            let mut compressor =
+                bzip2::write::BzEncoder::new(&mut compressed, bzip2::Compression::best());
+            compressor.write_all(json_data.as_bytes())?;
+            compressor.finish()?;
+        }
+
+        // Save compressed data to disk cache
+        self.save_cache_to_disk(set_code, &compressed).await?;
+
+        // Convert to our internal format
+        let cards = convert_mtgjson_cards(response.data.cards);
+
+        // Update memory cache
+        let mut memory_cache = self.cache.lock().await;
+        memory_cache.insert(set_code.to_string(), cards.clone());
+
+        Ok(cards)
+    }
+
+    /// Fetches multiple sets in sequence
+    ///
+    /// Returns a combined vector of all cards from the specified sets.
+    #[allow(dead_code)]
+    pub async fn fetch_multiple_sets(
+        &self,
+        set_codes: &[&str],
+    ) -> Result<Vec<Card>, Box<dyn std::error::Error>> {
+        let mut all_cards = Vec::new();
+        for set_code in set_codes {
+            let cards = self.fetch_set(set_code).await?;
+            all_cards.extend(cards);
+        }
+        Ok(all_cards)
+    }
+
+    /// Fetches the list of all available sets
+    ///
+    /// Filters the sets based on various criteria:
+    /// - Only includes main sets, expansions, and special sets
+    /// - Excludes unreleased sets
+    /// - Excludes empty sets
+    ///
+    /// Returns a vector of set codes sorted by release date (newest first)
+    #[allow(dead_code)]
+    pub async fn fetch_set_list(&self) -> Result<Vec<String>, Error> {
+        let url = "https://mtgjson.com/api/v5/SetList.json";
+        let client = reqwest::Client::new();
+        let response = client.get(url).send().await?;
+        let set_list: MTGJSONSetList = response.json().await?;
+
+        // Get current date in YYYY-MM-DD format
+        let current_date = chrono::Local::now().format("%Y-%m-%d").to_string();
+
+        // Filter out non-standard sets, unreleased sets, and empty sets
+        let mut sets: Vec<_> = set_list
+            .data
+            .into_iter()
+            .filter(|set| {
+                // Include only main sets, expansions, and special sets
+                matches!(
+                    set.set_type.as_str(),
+                    "core"
+                        | "expansion"
+                        | "masters"
+                        | "draft_innovation"
+                        | "commander"
+                        | "funny"
+                        | "starter"
+                        | "promo"
+                        | "box"
+                        | "duel_deck"
+                        | "premium_deck"
+                        | "from_the_vault"
+                        | "spellbook"
+                        | "masterpiece"
+                ) &&
+                // Filter out future sets and empty sets
+                set.release_date <= current_date &&
+                !set.is_partial_preview &&
+                set.total_set_size > 0
+            })
+            .collect();
+
+        // Sort by release date (newest first)
+        sets.sort_by(|a, b| b.release_date.cmp(&a.release_date));
+
+        Ok(sets.into_iter().map(|s| s.code).collect())
+    }
+}
+
+#[cfg(feature = "mtgjson")]
+pub fn http(client: reqwest::Client) -> MTGClientType {
+    MTGClientType::Http(client)
+}
+
+#[cfg(feature = "mtgjson")]
+pub async fn create_http() -> MTGClientType {
+    MTGClientType::Http(reqwest::Client::new())
+}
@@ -1,16 +1,25 @@
+use crate::camera::z_order::{ZOrder, normalize_card_z_order};
 use crate::cards::{
+    animation::CardAnimationPlugin,
     card::Card,
     components::{
         CardCost, CardDetailsComponent, CardKeywords, CardName, CardRulesText, Draggable,
         NoUntapCondition, NoUntapEffect, PermanentState,
     },
+    database::{CardDatabase, CardId, assign_card_ids},
     details::{
         ArtifactCard, CardDetails, CreatureCard, EnchantmentCard, LandCard, SpellCard, SpellType,
     },
+    frame::CardFramePlugin,
+    image::CardImagePlugin,
     keywords::{KeywordAbilities, KeywordAbility},
+    legality::{CardLegalities, Legality},
+    mods::ModsPlugin,
+    mtgjson::loader::SetLoaderPlugin,
+    pool::CardVisualPoolPlugin,
     rarity::Rarity,
     set::CardSet,
-    systems::{debug_render_text_positions, handle_card_dragging},
+    systems::{CardDroppedEvent, debug_render_text_positions, handle_card_dragging},
     types::{ReflectableCardTypes, ReflectableCreatureType},
 };
 use crate::mana::{Mana, ReflectableColor};
@@ -22,6 +31,9 @@ pub struct CardPlugin;
 impl Plugin for CardPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Card>()
+            .register_type::<CardId>()
+            .register_type::<CardLegalities>()
+            .register_type::<Legality>()
             .register_type::<CardName>()
             .register_type::<CardCost>()
             // CardTypeInfo contains bitflags which now support reflection
@@ -51,8 +63,29 @@ impl Plugin for CardPlugin {
             .register_type::<ReflectableColor>()
             .register_type::<std::collections::HashSet<KeywordAbility>>()
             .register_type::<std::collections::HashMap<KeywordAbility, String>>()
+            // Download and display card images where available
+            .add_plugins(CardImagePlugin)
+            // Render card frames procedurally for cards that request it
+            .add_plugins(CardFramePlugin)
+            // Animate cards moving between zones
+            .add_plugins(CardAnimationPlugin)
+            // Recycle released card visuals instead of despawning them
+            .add_plugins(CardVisualPoolPlugin)
+            // Card definitions that decks and libraries refer to by id
+            .insert_resource(CardDatabase::with_builtin_sets())
+            .add_systems(Update, assign_card_ids)
+            // Load MTGJSON sets into the database without blocking frames
+            .add_plugins(SetLoaderPlugin)
+            // Add custom sets from the mods directory
+            .add_plugins(ModsPlugin)
             // Keep input handling in Update
-            .add_systems(Update, handle_card_dragging)
+            .add_event::<CardDroppedEvent>()
+            // Drag cards, then keep their z values packed into their bands
+            .init_resource::<ZOrder>()
+            .add_systems(
+                Update,
+                (handle_card_dragging, normalize_card_z_order).chain(),
+            )
             // Move debug rendering to FixedUpdate
             .add_systems(FixedUpdate, debug_render_text_positions);
     }
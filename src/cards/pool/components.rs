@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::cards::Card;
+use crate::cards::details::CardDetails;
+
+/// Identifies cards that are drawn the same, so one's visual can stand in for another's
+///
+/// Built from everything the card's text shows: name, mana cost, type line, rules
+/// text and power/toughness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CardVisualKey(u64);
+
+impl CardVisualKey {
+    /// The key of a card's visual
+    pub fn of(card: &Card) -> Self {
+        let mut hasher = DefaultHasher::new();
+        card.name.name.hash(&mut hasher);
+        card.cost.cost.to_string().hash(&mut hasher);
+        Card::type_line_from_components(&card.type_info.types).hash(&mut hasher);
+        card.rules_text.rules_text.hash(&mut hasher);
+        if let CardDetails::Creature(creature) = &card.details.details {
+            (creature.power, creature.toughness).hash(&mut hasher);
+        }
+        Self(hasher.finish())
+    }
+}
+
+/// A card visual root the pool can recycle
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PooledCardVisual {
+    pub key: CardVisualKey,
+}
+
+/// Returns a card visual to the pool instead of despawning it
+///
+/// Entities without [`PooledCardVisual`], or released while the pool is full, are
+/// despawned as usual.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ReleaseCardVisual;
@@ -0,0 +1,21 @@
+//! Recycling of card visual hierarchies
+//!
+//! Tokens, zone moves and shuffles spawn and despawn card visuals all the time, and
+//! each card brings a sprite plus a dozen text and mana glyph entities. Instead of
+//! despawning a card visual, callers insert [`ReleaseCardVisual`] and the hierarchy
+//! is stripped of its game components, hidden and kept in the [`CardVisualPool`].
+//! The next card that looks the same reuses it, text glyphs included.
+//!
+//! Pool usage is reported through the [`DiagnosticsPlugin`](crate::tracing::DiagnosticsPlugin).
+
+mod components;
+mod plugin;
+mod resources;
+mod systems;
+
+pub mod tests;
+
+pub use components::{CardVisualKey, PooledCardVisual, ReleaseCardVisual};
+pub use plugin::CardVisualPoolPlugin;
+pub use resources::{CardVisualPool, CardVisualPoolStats, PooledSpawn};
+pub use systems::release_card_visuals;
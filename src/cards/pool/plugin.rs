@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+use super::resources::CardVisualPool;
+use super::systems::release_card_visuals;
+
+/// Plugin recycling released card visuals
+pub struct CardVisualPoolPlugin;
+
+impl Plugin for CardVisualPoolPlugin {
+    fn build(&self, app: &mut App) {
+        // Released in PostUpdate so visuals released during Update are reusable next frame
+        app.init_resource::<CardVisualPool>()
+            .add_systems(PostUpdate, release_card_visuals);
+    }
+}
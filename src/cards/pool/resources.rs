@@ -0,0 +1,125 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use super::components::{CardVisualKey, PooledCardVisual};
+
+/// Card visuals kept for reuse by default
+pub const DEFAULT_MAX_POOLED_VISUALS: usize = 128;
+
+/// Totals since startup, reported through the diagnostics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CardVisualPoolStats {
+    /// Card visuals spawned because none could be reused
+    pub spawned: u64,
+    /// Card visuals taken from the pool
+    pub reused: u64,
+    /// Card visuals returned to the pool
+    pub released: u64,
+    /// Released card visuals despawned because the pool was full
+    pub discarded: u64,
+}
+
+/// A card visual handed out by [`CardVisualPool::spawn`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PooledSpawn {
+    pub entity: Entity,
+    /// Whether the visual was recycled, in which case its text is already spawned
+    pub recycled: bool,
+}
+
+/// Hidden card visual hierarchies waiting to be reused
+#[derive(Resource, Debug)]
+pub struct CardVisualPool {
+    /// Free card visual roots by the cards they draw
+    free: HashMap<CardVisualKey, Vec<Entity>>,
+    free_count: usize,
+    /// Free card visuals kept before released ones are despawned
+    pub max_free: usize,
+    pub stats: CardVisualPoolStats,
+}
+
+impl Default for CardVisualPool {
+    fn default() -> Self {
+        Self {
+            free: HashMap::new(),
+            free_count: 0,
+            max_free: DEFAULT_MAX_POOLED_VISUALS,
+            stats: CardVisualPoolStats::default(),
+        }
+    }
+}
+
+impl CardVisualPool {
+    /// Spawn a card visual, reusing a free one drawn the same way if there is one
+    ///
+    /// The bundle is inserted either way; a fresh visual also gets its
+    /// [`PooledCardVisual`] marker.
+    pub fn spawn(
+        &mut self,
+        commands: &mut Commands,
+        key: CardVisualKey,
+        bundle: impl Bundle,
+    ) -> PooledSpawn {
+        if let Some(entity) = self.take(commands, key) {
+            commands
+                .entity(entity)
+                .insert(Visibility::Visible)
+                .insert(bundle);
+            self.stats.reused += 1;
+            return PooledSpawn {
+                entity,
+                recycled: true,
+            };
+        }
+
+        self.stats.spawned += 1;
+        PooledSpawn {
+            entity: commands.spawn((PooledCardVisual { key }, bundle)).id(),
+            recycled: false,
+        }
+    }
+
+    /// Number of card visuals waiting to be reused
+    pub fn free_count(&self) -> usize {
+        self.free_count
+    }
+
+    /// Number of free card visuals drawn as `key`
+    pub fn free_for(&self, key: CardVisualKey) -> usize {
+        self.free.get(&key).map_or(0, Vec::len)
+    }
+
+    /// Whether another released visual can be kept
+    pub fn has_room(&self) -> bool {
+        self.free_count < self.max_free
+    }
+
+    /// Drop every free visual, returning the entities to despawn
+    pub fn clear(&mut self) -> Vec<Entity> {
+        self.free_count = 0;
+        self.free
+            .drain()
+            .flat_map(|(_, entities)| entities)
+            .collect()
+    }
+
+    /// Keep a released visual for reuse
+    pub(crate) fn put(&mut self, key: CardVisualKey, entity: Entity) {
+        self.free.entry(key).or_default().push(entity);
+        self.free_count += 1;
+        self.stats.released += 1;
+    }
+
+    /// Take a free visual that still exists
+    fn take(&mut self, commands: &mut Commands, key: CardVisualKey) -> Option<Entity> {
+        let free = self.free.get_mut(&key)?;
+        while let Some(entity) = free.pop() {
+            self.free_count -= 1;
+            // Something else may have despawned it while it was pooled
+            if commands.get_entity(entity).is_ok() {
+                return Some(entity);
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
+
+use super::components::{PooledCardVisual, ReleaseCardVisual};
+use super::resources::CardVisualPool;
+use crate::text::components::{CardTextType, SpawnedText};
+
+/// Components a pooled card visual keeps; the rest are game state and are removed
+type PooledVisualComponents = (
+    PooledCardVisual,
+    Sprite,
+    Transform,
+    GlobalTransform,
+    Visibility,
+    InheritedVisibility,
+    ViewVisibility,
+    RenderLayers,
+    Name,
+    Children,
+    SpawnedText,
+);
+
+/// Return released card visuals to the pool
+///
+/// A released card keeps its sprite and text children, which only depend on what
+/// the card shows. Other children, like hint glows, are despawned along with the
+/// card's game components, and the card is detached from its zone and hidden.
+pub fn release_card_visuals(
+    mut commands: Commands,
+    mut pool: ResMut<CardVisualPool>,
+    released: Query<
+        (Entity, Option<&PooledCardVisual>, Option<&Children>),
+        With<ReleaseCardVisual>,
+    >,
+    text: Query<(), With<CardTextType>>,
+) {
+    for (entity, pooled, children) in released.iter() {
+        let Some(pooled) = pooled else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        if !pool.has_room() {
+            pool.stats.discarded += 1;
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        if let Some(children) = children {
+            for child in children.iter().filter(|child| !text.contains(*child)) {
+                commands.entity(child).despawn();
+            }
+        }
+        commands
+            .entity(entity)
+            .retain::<PooledVisualComponents>()
+            .insert(Visibility::Hidden);
+        pool.put(pooled.key, entity);
+    }
+}
@@ -0,0 +1,125 @@
+// Tests for card visual pooling
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::cards::pool::{
+        CardVisualKey, CardVisualPool, PooledCardVisual, ReleaseCardVisual, release_card_visuals,
+    };
+    use crate::cards::{Card, CardTypes};
+    use crate::mana::Mana;
+    use crate::text::components::CardTextType;
+
+    fn card(name: &str, rules_text: &str) -> Card {
+        Card::builder(name)
+            .cost(Mana::default())
+            .types(CardTypes::ARTIFACT)
+            .rules_text(rules_text)
+            .build_or_panic()
+    }
+
+    fn pool_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<CardVisualPool>()
+            .add_systems(Update, release_card_visuals);
+        app
+    }
+
+    /// Spawn a card visual through the pool, with a text child and a glow child
+    fn spawn_visual(app: &mut App, card: &Card) -> (Entity, Vec<Entity>) {
+        let world = app.world_mut();
+        let mut pool = world.remove_resource::<CardVisualPool>().unwrap();
+        let (spawned, children) = {
+            let mut commands = world.commands();
+            let spawned = pool.spawn(
+                &mut commands,
+                CardVisualKey::of(card),
+                (card.clone(), Sprite::default(), Transform::default()),
+            );
+            let children = if spawned.recycled {
+                Vec::new()
+            } else {
+                let text = commands.spawn(CardTextType::Name).id();
+                let glow = commands.spawn(Sprite::default()).id();
+                commands.entity(spawned.entity).add_children(&[text, glow]);
+                vec![text, glow]
+            };
+            (spawned, children)
+        };
+        world.flush();
+        world.insert_resource(pool);
+        (spawned.entity, children)
+    }
+
+    fn release(app: &mut App, entity: Entity) {
+        app.world_mut().entity_mut(entity).insert(ReleaseCardVisual);
+        app.update();
+    }
+
+    #[test]
+    fn test_released_visuals_are_reused_with_their_text() {
+        let mut app = pool_app();
+        let bauble = card("Bauble", "Sacrifice this: Draw a card.");
+        let (visual, children) = spawn_visual(&mut app, &bauble);
+        release(&mut app, visual);
+
+        let world = app.world();
+        assert!(world.get::<Card>(visual).is_none());
+        assert_eq!(world.get::<Visibility>(visual), Some(&Visibility::Hidden));
+        assert_eq!(world.get::<Children>(visual).unwrap().len(), 1);
+        assert!(world.get_entity(children[0]).is_ok());
+        assert!(world.get_entity(children[1]).is_err());
+        assert_eq!(world.resource::<CardVisualPool>().free_count(), 1);
+
+        let (reused, _) = spawn_visual(&mut app, &bauble);
+        let world = app.world();
+        assert_eq!(reused, visual);
+        assert!(world.get::<Card>(visual).is_some());
+        assert_eq!(world.get::<Visibility>(visual), Some(&Visibility::Visible));
+        let pool = world.resource::<CardVisualPool>();
+        assert_eq!(pool.free_count(), 0);
+        assert_eq!((pool.stats.spawned, pool.stats.reused), (1, 1));
+    }
+
+    #[test]
+    fn test_visuals_are_only_reused_for_matching_cards() {
+        let mut app = pool_app();
+        let (visual, _) = spawn_visual(&mut app, &card("Bauble", "Draw a card."));
+        release(&mut app, visual);
+
+        let (other, _) = spawn_visual(&mut app, &card("Bauble", "Scry 1."));
+        assert_ne!(other, visual);
+        let pool = app.world().resource::<CardVisualPool>();
+        assert_eq!(pool.free_count(), 1);
+        assert_eq!(pool.stats.spawned, 2);
+    }
+
+    #[test]
+    fn test_full_pool_despawns_released_visuals() {
+        let mut app = pool_app();
+        app.world_mut().resource_mut::<CardVisualPool>().max_free = 1;
+        let bauble = card("Bauble", "Draw a card.");
+        let (first, _) = spawn_visual(&mut app, &bauble);
+        let (second, _) = spawn_visual(&mut app, &bauble);
+        release(&mut app, first);
+        release(&mut app, second);
+
+        let world = app.world();
+        assert!(world.get::<PooledCardVisual>(first).is_some());
+        assert!(world.get_entity(second).is_err());
+        assert_eq!(world.resource::<CardVisualPool>().stats.discarded, 1);
+    }
+
+    #[test]
+    fn test_despawned_pooled_visuals_are_skipped() {
+        let mut app = pool_app();
+        let bauble = card("Bauble", "Draw a card.");
+        let (visual, _) = spawn_visual(&mut app, &bauble);
+        release(&mut app, visual);
+        app.world_mut().despawn(visual);
+
+        let (fresh, _) = spawn_visual(&mut app, &bauble);
+        assert_ne!(fresh, visual);
+        assert_eq!(app.world().resource::<CardVisualPool>().free_count(), 0);
+    }
+}
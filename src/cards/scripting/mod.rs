@@ -0,0 +1,199 @@
+//! Card effects described as data
+//!
+//! Cards that aren't written in Rust, like those from mods, describe what they
+//! do with [`EffectPrimitive`]s: small building blocks such as dealing damage or
+//! drawing cards. A card's primitives are kept in its [`CardScript`], in the
+//! order they happen.
+//!
+//! Besides what a spell does as it resolves, a script can give a permanent
+//! triggered abilities, each with a [`ScriptTrigger`], and activated abilities,
+//! each with a [`ScriptCost`]. The engine's interpreter in
+//! [`game_engine::scripting`](crate::game_engine::scripting) turns them into
+//! effects on the stack, so only cards too complex for the primitives need to
+//! be written in Rust.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::abilities::TriggerCondition;
+use crate::cards::mtgjson::parse_mana_cost;
+use crate::game_engine::phase::{BeginningStep, EndingStep, Phase};
+use crate::mana::Mana;
+
+/// Who an effect happens to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EffectRecipient {
+    /// The player or permanent the spell or ability targets
+    #[default]
+    Target,
+    /// The spell or ability's controller
+    Controller,
+    /// Each of the controller's opponents
+    EachOpponent,
+}
+
+/// One building block of a card's effect
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "effect", rename_all = "snake_case")]
+pub enum EffectPrimitive {
+    /// Deal damage, like Lightning Bolt
+    DealDamage {
+        amount: u32,
+        #[serde(default)]
+        to: EffectRecipient,
+    },
+    /// The controller draws cards
+    DrawCards { count: u32 },
+    /// The controller gains life
+    GainLife { amount: u32 },
+    /// Lose life, other than to damage
+    LoseLife {
+        amount: u32,
+        #[serde(default)]
+        who: EffectRecipient,
+    },
+    /// The controller creates creature tokens
+    CreateToken {
+        name: String,
+        power: i32,
+        toughness: i32,
+        #[serde(default = "one")]
+        count: u32,
+    },
+    /// The controller scries
+    Scry { count: usize },
+    /// The controller adds mana, written like a cost, e.g. `{G}` or `{C}{C}`
+    AddMana { mana: String },
+}
+
+fn one() -> u32 {
+    1
+}
+
+impl EffectPrimitive {
+    /// Whether the effect needs a target chosen for it
+    pub fn needs_target(&self) -> bool {
+        matches!(
+            self,
+            EffectPrimitive::DealDamage {
+                to: EffectRecipient::Target,
+                ..
+            } | EffectPrimitive::LoseLife {
+                who: EffectRecipient::Target,
+                ..
+            }
+        )
+    }
+}
+
+/// When a scripted triggered ability triggers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScriptTrigger {
+    /// When the permanent enters the battlefield
+    EntersTheBattlefield,
+    /// At the beginning of its controller's upkeep
+    YourUpkeep,
+    /// At the beginning of its controller's end step
+    YourEndStep,
+    /// Whenever its controller gains life
+    YouGainLife,
+}
+
+impl ScriptTrigger {
+    /// The engine's condition for the trigger
+    pub fn condition(self) -> TriggerCondition {
+        match self {
+            ScriptTrigger::EntersTheBattlefield => TriggerCondition::EnterTheBattlefield,
+            ScriptTrigger::YourUpkeep => {
+                TriggerCondition::BeginningOfPhase(Phase::Beginning(BeginningStep::Upkeep))
+            }
+            ScriptTrigger::YourEndStep => {
+                TriggerCondition::BeginningOfPhase(Phase::Ending(EndingStep::End))
+            }
+            ScriptTrigger::YouGainLife => TriggerCondition::WhenControllerGainsLife,
+        }
+    }
+}
+
+/// The cost of a scripted activated ability
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScriptCost {
+    /// Mana to pay in the usual notation, like `{2}{W}`
+    #[serde(default)]
+    pub mana: String,
+    /// Whether the permanent is tapped, written {T}
+    #[serde(default)]
+    pub tap: bool,
+    /// Whether the permanent is sacrificed
+    #[serde(default)]
+    pub sacrifice: bool,
+}
+
+impl ScriptCost {
+    /// The mana part of the cost
+    pub fn mana_cost(&self) -> Mana {
+        parse_mana_cost(&self.mana)
+    }
+}
+
+/// A triggered ability defined as data
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScriptedTrigger {
+    pub when: ScriptTrigger,
+    /// The ability's rules text
+    #[serde(default)]
+    pub text: String,
+    pub effects: Vec<EffectPrimitive>,
+}
+
+/// An activated ability defined as data
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScriptedActivation {
+    #[serde(default)]
+    pub cost: ScriptCost,
+    /// The ability's rules text
+    #[serde(default)]
+    pub text: String,
+    pub effects: Vec<EffectPrimitive>,
+}
+
+impl ScriptedActivation {
+    /// Whether this is a mana ability: one that adds mana and has no target
+    /// (rule 605.1a)
+    pub fn is_mana_ability(&self) -> bool {
+        self.effects
+            .iter()
+            .any(|effect| matches!(effect, EffectPrimitive::AddMana { .. }))
+            && !self.effects.iter().any(EffectPrimitive::needs_target)
+    }
+}
+
+/// What a card defined as data does
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CardScript {
+    /// Effects of the spell as it resolves, in the order they happen
+    #[serde(default)]
+    pub effects: Vec<EffectPrimitive>,
+    /// Triggered abilities of the permanent
+    #[serde(default)]
+    pub triggered: Vec<ScriptedTrigger>,
+    /// Activated abilities of the permanent, by ability index
+    #[serde(default)]
+    pub activated: Vec<ScriptedActivation>,
+}
+
+impl CardScript {
+    /// Whether the script does nothing
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty() && self.triggered.is_empty() && self.activated.is_empty()
+    }
+
+    /// The triggered abilities with a trigger
+    pub fn triggers(&self, when: ScriptTrigger) -> impl Iterator<Item = &ScriptedTrigger> {
+        self.triggered
+            .iter()
+            .filter(move |ability| ability.when == when)
+    }
+}
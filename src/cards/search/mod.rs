@@ -0,0 +1,444 @@
+//! Scryfall-style card search
+//!
+//! A query is a list of terms that all have to match, such as
+//! `t:creature c:ur cmc<=3 o:"draw a card"`. Words without a key match card
+//! names, and a leading `-` negates a term. Results are ranked by how closely
+//! their names match the name terms, then alphabetically.
+//!
+//! | Key | Matches |
+//! |-----|---------|
+//! | `name`, `n` | Name contains the text |
+//! | `t`, `type` | Type line contains the text |
+//! | `o`, `oracle` | Rules text contains the text |
+//! | `c`, `color` | Colors, e.g. `c:ur` (at least blue and red), `c=g`, `c<=wb`, `c:c` (colorless) |
+//! | `cmc`, `mv` | Mana value |
+//! | `pow`, `tou` | Power and toughness of creatures |
+//! | `r`, `rarity` | Rarity, e.g. `r:m` |
+//! | `s`, `set`, `e` | Set code |
+//! | `f`, `format`, `legal` | Legal in an MTGJSON format, e.g. `f:commander` |
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::cards::database::{CardDatabase, CardDefinition};
+use crate::cards::rarity::Rarity;
+use crate::cards::{Card, CardDetails, format_type_line};
+use crate::mana::ManaColor;
+
+/// How a term compares a card's value with the query's
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Comparison {
+    /// `:`, which means "contains" for text and "at least" for colors
+    Colon,
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    /// Operators in the order they are tried, so `<=` wins over `<`
+    const OPERATORS: [(&'static str, Comparison); 7] = [
+        ("<=", Comparison::LessOrEqual),
+        (">=", Comparison::GreaterOrEqual),
+        ("!=", Comparison::NotEqual),
+        (":", Comparison::Colon),
+        ("=", Comparison::Equal),
+        ("<", Comparison::Less),
+        (">", Comparison::Greater),
+    ];
+
+    /// Compare two numbers, treating `:` as `=`
+    pub fn compare<T: PartialOrd>(self, value: T, expected: T) -> bool {
+        match self {
+            Comparison::Colon | Comparison::Equal => value == expected,
+            Comparison::NotEqual => value != expected,
+            Comparison::Less => value < expected,
+            Comparison::LessOrEqual => value <= expected,
+            Comparison::Greater => value > expected,
+            Comparison::GreaterOrEqual => value >= expected,
+        }
+    }
+
+    /// Compare two sets of colors, treating `:` as "at least these colors"
+    ///
+    /// `c:c` asks for colorless cards rather than every card.
+    pub fn compare_colors(self, colors: ManaColor, expected: ManaColor) -> bool {
+        match self {
+            Comparison::Colon if expected.is_empty() => colors.is_empty(),
+            Comparison::Colon | Comparison::GreaterOrEqual => colors.contains(expected),
+            Comparison::Equal => colors == expected,
+            Comparison::NotEqual => colors != expected,
+            Comparison::Less => expected.contains(colors) && colors != expected,
+            Comparison::LessOrEqual => expected.contains(colors),
+            Comparison::Greater => colors.contains(expected) && colors != expected,
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (operator, _) = Self::OPERATORS
+            .iter()
+            .find(|(_, comparison)| comparison == self)
+            .expect("every comparison has an operator");
+        f.write_str(operator)
+    }
+}
+
+/// What a single term checks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchFilter {
+    /// Lowercase text the name contains, or equals with `=`
+    Name {
+        text: String,
+        exact: bool,
+    },
+    /// Lowercase text the type line contains
+    Type(String),
+    /// Lowercase text the rules text contains
+    Oracle(String),
+    Colors(Comparison, ManaColor),
+    ManaValue(Comparison, u64),
+    Power(Comparison, i32),
+    Toughness(Comparison, i32),
+    Rarity(Rarity),
+    /// Uppercase set code
+    Set(String),
+    /// Lowercase MTGJSON format name
+    Legal(String),
+}
+
+/// A filter, possibly negated with a leading `-`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchTerm {
+    pub negated: bool,
+    pub filter: SearchFilter,
+}
+
+/// Why a query couldn't be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchError {
+    /// A `"` without a closing quote
+    UnclosedQuote,
+    /// A key no filter uses, like `foo:bar`
+    UnknownKey(String),
+    /// A key followed by an operator but no value
+    MissingValue(String),
+    /// A value the key can't use, like `cmc:x`
+    InvalidValue { key: String, value: String },
+    /// An operator the key can't use, like `o<draw`
+    InvalidOperator { key: String, operator: Comparison },
+}
+
+impl fmt::Display for SearchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SearchError::UnclosedQuote => write!(f, "missing closing quote"),
+            SearchError::UnknownKey(key) => write!(f, "unknown search key: {}", key),
+            SearchError::MissingValue(key) => write!(f, "no value given for {}", key),
+            SearchError::InvalidValue { key, value } => {
+                write!(f, "{} is not a valid value for {}", value, key)
+            }
+            SearchError::InvalidOperator { key, operator } => {
+                write!(f, "{} can't be used with {}", operator, key)
+            }
+        }
+    }
+}
+
+/// A parsed search query
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    terms: Vec<SearchTerm>,
+}
+
+impl SearchQuery {
+    /// Parse a query, e.g. `t:creature c:ur cmc<=3 o:"draw a card"`
+    pub fn parse(query: &str) -> Result<Self, SearchError> {
+        let mut terms = Vec::new();
+        let mut rest = query.trim_start();
+
+        while !rest.is_empty() {
+            let (negated, term) = match rest.strip_prefix('-') {
+                Some(term) => (true, term),
+                None => (false, rest),
+            };
+
+            let key_length = term
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(term.len());
+            let (key, after_key) = term.split_at(key_length);
+            let operator = Comparison::OPERATORS
+                .iter()
+                .find(|(operator, _)| key_length > 0 && after_key.starts_with(operator));
+
+            let (filter, remaining) = match operator {
+                Some((operator, comparison)) => {
+                    let (value, remaining) = read_value(&after_key[operator.len()..])?;
+                    if value.is_empty() {
+                        return Err(SearchError::MissingValue(key.to_string()));
+                    }
+                    (Some(parse_filter(key, *comparison, value)?), remaining)
+                }
+                None => {
+                    let (value, remaining) = read_value(term)?;
+                    let filter = (!value.is_empty()).then(|| SearchFilter::Name {
+                        text: value.to_lowercase(),
+                        exact: false,
+                    });
+                    (filter, remaining)
+                }
+            };
+
+            if let Some(filter) = filter {
+                terms.push(SearchTerm { negated, filter });
+            }
+            rest = remaining.trim_start();
+        }
+
+        Ok(Self { terms })
+    }
+
+    /// The query's terms, in the order they were written
+    pub fn terms(&self) -> &[SearchTerm] {
+        &self.terms
+    }
+
+    /// Whether a query has no terms and so matches every card
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Whether a card matches every term
+    pub fn matches(&self, definition: &CardDefinition) -> bool {
+        self.terms
+            .iter()
+            .all(|term| term.filter.matches(definition) != term.negated)
+    }
+
+    /// The matching cards, best match first
+    pub fn search<'a>(
+        &self,
+        definitions: impl IntoIterator<Item = &'a CardDefinition>,
+    ) -> Vec<&'a CardDefinition> {
+        let mut results: Vec<_> = definitions
+            .into_iter()
+            .filter(|definition| self.matches(definition))
+            .map(|definition| (self.rank(&definition.card), definition))
+            .collect();
+        results.sort_by(|(a_rank, a), (b_rank, b)| {
+            b_rank
+                .cmp(a_rank)
+                .then_with(|| a.card.name.name.cmp(&b.card.name.name))
+        });
+        results
+            .into_iter()
+            .map(|(_, definition)| definition)
+            .collect()
+    }
+
+    /// How closely a card's name matches the name terms
+    fn rank(&self, card: &Card) -> u32 {
+        let name = card.name.name.to_lowercase();
+        self.terms
+            .iter()
+            .filter(|term| !term.negated)
+            .map(|term| match &term.filter {
+                SearchFilter::Name { text, .. } if name == *text => 3,
+                SearchFilter::Name { text, .. } if name.starts_with(text.as_str()) => 2,
+                SearchFilter::Name { .. } => 1,
+                _ => 0,
+            })
+            .sum()
+    }
+}
+
+impl FromStr for SearchQuery {
+    type Err = SearchError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl SearchFilter {
+    /// Whether a card passes the filter
+    pub fn matches(&self, definition: &CardDefinition) -> bool {
+        let card = &definition.card;
+        match self {
+            SearchFilter::Name { text, exact: true } => card.name.name.to_lowercase() == *text,
+            SearchFilter::Name { text, exact: false } => {
+                card.name.name.to_lowercase().contains(text.as_str())
+            }
+            SearchFilter::Type(text) => {
+                format_type_line(&card.type_info.types, &card.details.details)
+                    .to_lowercase()
+                    .contains(text.as_str())
+            }
+            SearchFilter::Oracle(text) => card
+                .rules_text
+                .rules_text
+                .to_lowercase()
+                .contains(text.as_str()),
+            SearchFilter::Colors(comparison, colors) => {
+                comparison.compare_colors(card_colors(card), *colors)
+            }
+            SearchFilter::ManaValue(comparison, value) => {
+                comparison.compare(card.cost.cost.converted_mana_cost(), *value)
+            }
+            SearchFilter::Power(comparison, value) => match &card.details.details {
+                CardDetails::Creature(creature) => comparison.compare(creature.power, *value),
+                _ => false,
+            },
+            SearchFilter::Toughness(comparison, value) => match &card.details.details {
+                CardDetails::Creature(creature) => comparison.compare(creature.toughness, *value),
+                _ => false,
+            },
+            SearchFilter::Rarity(rarity) => definition.rarity == Some(*rarity),
+            SearchFilter::Set(code) => definition.set.as_ref().is_some_and(|set| set.code == *code),
+            SearchFilter::Legal(format) => definition.is_legal_in(format),
+        }
+    }
+}
+
+impl CardDatabase {
+    /// Every card matching a search query, best match first
+    pub fn search(&self, query: &str) -> Result<Vec<&CardDefinition>, SearchError> {
+        Ok(SearchQuery::parse(query)?.search(self.iter()))
+    }
+}
+
+/// A card's colors, from the colored mana in its cost
+fn card_colors(card: &Card) -> ManaColor {
+    let cost = &card.cost.cost;
+    [
+        (cost.white, ManaColor::WHITE),
+        (cost.blue, ManaColor::BLUE),
+        (cost.black, ManaColor::BLACK),
+        (cost.red, ManaColor::RED),
+        (cost.green, ManaColor::GREEN),
+    ]
+    .into_iter()
+    .filter(|(amount, _)| *amount > 0)
+    .fold(ManaColor::NONE, |colors, (_, color)| colors | color)
+}
+
+/// Split a value off the front of `text`, returning it and what follows
+fn read_value(text: &str) -> Result<(&str, &str), SearchError> {
+    if let Some(quoted) = text.strip_prefix('"') {
+        let end = quoted.find('"').ok_or(SearchError::UnclosedQuote)?;
+        return Ok((&quoted[..end], &quoted[end + 1..]));
+    }
+    let end = text.find(char::is_whitespace).unwrap_or(text.len());
+    Ok(text.split_at(end))
+}
+
+fn parse_filter(
+    key: &str,
+    comparison: Comparison,
+    value: &str,
+) -> Result<SearchFilter, SearchError> {
+    let invalid_value = || SearchError::InvalidValue {
+        key: key.to_string(),
+        value: value.to_string(),
+    };
+    let text_only = || match comparison {
+        Comparison::Colon | Comparison::Equal => Ok(()),
+        operator => Err(SearchError::InvalidOperator {
+            key: key.to_string(),
+            operator,
+        }),
+    };
+
+    let filter = match key.to_lowercase().as_str() {
+        "name" | "n" => {
+            text_only()?;
+            SearchFilter::Name {
+                text: value.to_lowercase(),
+                exact: comparison == Comparison::Equal,
+            }
+        }
+        "t" | "type" => {
+            text_only()?;
+            SearchFilter::Type(value.to_lowercase())
+        }
+        "o" | "oracle" => {
+            text_only()?;
+            SearchFilter::Oracle(value.to_lowercase())
+        }
+        "c" | "color" | "colors" => {
+            SearchFilter::Colors(comparison, parse_colors(value).ok_or_else(invalid_value)?)
+        }
+        "cmc" | "mv" | "manavalue" => {
+            SearchFilter::ManaValue(comparison, value.parse().map_err(|_| invalid_value())?)
+        }
+        "pow" | "power" => {
+            SearchFilter::Power(comparison, value.parse().map_err(|_| invalid_value())?)
+        }
+        "tou" | "toughness" => {
+            SearchFilter::Toughness(comparison, value.parse().map_err(|_| invalid_value())?)
+        }
+        "r" | "rarity" => {
+            text_only()?;
+            SearchFilter::Rarity(parse_rarity(value).ok_or_else(invalid_value)?)
+        }
+        "s" | "set" | "e" => {
+            text_only()?;
+            SearchFilter::Set(value.to_uppercase())
+        }
+        "f" | "format" | "legal" => {
+            text_only()?;
+            SearchFilter::Legal(value.to_lowercase())
+        }
+        _ => return Err(SearchError::UnknownKey(key.to_string())),
+    };
+    Ok(filter)
+}
+
+/// Colors written as letters (`ur`), a color name (`blue`) or `c`/`colorless`
+fn parse_colors(value: &str) -> Option<ManaColor> {
+    let value = value.to_lowercase();
+    let named = match value.as_str() {
+        "c" | "colorless" => Some(ManaColor::NONE),
+        "white" => Some(ManaColor::WHITE),
+        "blue" => Some(ManaColor::BLUE),
+        "black" => Some(ManaColor::BLACK),
+        "red" => Some(ManaColor::RED),
+        "green" => Some(ManaColor::GREEN),
+        _ => None,
+    };
+    if named.is_some() {
+        return named;
+    }
+
+    value.chars().try_fold(ManaColor::NONE, |colors, c| {
+        let color = match c {
+            'w' => ManaColor::WHITE,
+            'u' => ManaColor::BLUE,
+            'b' => ManaColor::BLACK,
+            'r' => ManaColor::RED,
+            'g' => ManaColor::GREEN,
+            _ => return None,
+        };
+        Some(colors | color)
+    })
+}
+
+fn parse_rarity(value: &str) -> Option<Rarity> {
+    match value.to_lowercase().as_str() {
+        "c" | "common" => Some(Rarity::Common),
+        "u" | "uncommon" => Some(Rarity::Uncommon),
+        "r" | "rare" => Some(Rarity::Rare),
+        "m" | "mythic" => Some(Rarity::MythicRare),
+        "s" | "special" => Some(Rarity::Special),
+        "b" | "bonus" => Some(Rarity::Bonus),
+        "p" | "promo" => Some(Rarity::Promo),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,174 @@
+use crate::cards::search::{Comparison, SearchError, SearchFilter, SearchQuery, SearchTerm};
+use crate::cards::{Card, CardDatabase, CardDetails, CardTypes, CreatureCard, CreatureType};
+use crate::mana::{Mana, ManaColor};
+
+fn creature(name: &str, cost: Mana, power: i32, toughness: i32, text: &str) -> Card {
+    Card::builder(name)
+        .cost(cost)
+        .types(CardTypes::CREATURE)
+        .details(CardDetails::Creature(CreatureCard {
+            power,
+            toughness,
+            creature_type: CreatureType::NONE,
+        }))
+        .rules_text(text)
+        .build_or_panic()
+}
+
+fn spell(name: &str, cost: Mana, types: CardTypes, text: &str) -> Card {
+    Card::builder(name)
+        .cost(cost)
+        .types(types)
+        .details(CardDetails::Other)
+        .rules_text(text)
+        .build_or_panic()
+}
+
+fn database() -> CardDatabase {
+    let mut database = CardDatabase::default();
+    database.insert(creature(
+        "Izzet Chronarch",
+        Mana::new_with_colors(1, 0, 1, 0, 1, 0),
+        2,
+        2,
+        "When this creature enters, draw a card.",
+    ));
+    database.insert(creature(
+        "Goblin Guide",
+        Mana::new_with_colors(0, 0, 0, 0, 1, 0),
+        2,
+        2,
+        "Haste",
+    ));
+    database.insert(creature("Ornithopter", Mana::default(), 0, 2, "Flying"));
+    database.insert(spell(
+        "Divination",
+        Mana::new_with_colors(2, 0, 1, 0, 0, 0),
+        CardTypes::SORCERY,
+        "Draw two cards.",
+    ));
+    database.insert(spell(
+        "Guided Strike",
+        Mana::new_with_colors(1, 1, 0, 0, 0, 0),
+        CardTypes::INSTANT,
+        "Target creature gets +1/+0 and gains first strike until end of turn. Draw a card.",
+    ));
+    database
+}
+
+fn names(database: &CardDatabase, query: &str) -> Vec<String> {
+    database
+        .search(query)
+        .unwrap()
+        .into_iter()
+        .map(|definition| definition.card.name.name.clone())
+        .collect()
+}
+
+#[test]
+fn test_query_terms_are_parsed() {
+    let query = SearchQuery::parse(r#"t:creature -c:ur cmc<=3 o:"draw a card" bolt"#).unwrap();
+
+    assert_eq!(
+        query.terms(),
+        &[
+            SearchTerm {
+                negated: false,
+                filter: SearchFilter::Type("creature".to_string()),
+            },
+            SearchTerm {
+                negated: true,
+                filter: SearchFilter::Colors(Comparison::Colon, ManaColor::BLUE | ManaColor::RED),
+            },
+            SearchTerm {
+                negated: false,
+                filter: SearchFilter::ManaValue(Comparison::LessOrEqual, 3),
+            },
+            SearchTerm {
+                negated: false,
+                filter: SearchFilter::Oracle("draw a card".to_string()),
+            },
+            SearchTerm {
+                negated: false,
+                filter: SearchFilter::Name {
+                    text: "bolt".to_string(),
+                    exact: false,
+                },
+            },
+        ]
+    );
+
+    assert_eq!(
+        SearchQuery::parse(r#"o:"draw"#),
+        Err(SearchError::UnclosedQuote)
+    );
+    assert_eq!(
+        SearchQuery::parse("foo:bar"),
+        Err(SearchError::UnknownKey("foo".to_string()))
+    );
+    assert_eq!(
+        SearchQuery::parse("cmc:x"),
+        Err(SearchError::InvalidValue {
+            key: "cmc".to_string(),
+            value: "x".to_string()
+        })
+    );
+    assert_eq!(
+        SearchQuery::parse("o<draw"),
+        Err(SearchError::InvalidOperator {
+            key: "o".to_string(),
+            operator: Comparison::Less
+        })
+    );
+}
+
+#[test]
+fn test_filters_select_matching_cards() {
+    let database = database();
+
+    assert_eq!(
+        names(&database, r#"t:creature c:ur cmc<=3 o:"draw a card""#),
+        vec!["Izzet Chronarch"]
+    );
+    // `c:r` asks for at least red, `c=r` for exactly red
+    assert_eq!(
+        names(&database, "c:r"),
+        vec!["Goblin Guide", "Izzet Chronarch"]
+    );
+    assert_eq!(names(&database, "c=r"), vec!["Goblin Guide"]);
+    assert_eq!(names(&database, "c:c"), vec!["Ornithopter"]);
+    assert_eq!(
+        names(&database, "o:draw -t:creature"),
+        vec!["Divination", "Guided Strike"]
+    );
+    assert_eq!(names(&database, "pow<1"), vec!["Ornithopter"]);
+    assert!(names(&database, "t:planeswalker").is_empty());
+}
+
+#[test]
+fn test_results_are_ranked_by_name_match() {
+    let mut database = database();
+    database.insert(creature(
+        "Guide",
+        Mana::new_with_colors(0, 1, 0, 0, 0, 0),
+        1,
+        1,
+        "",
+    ));
+
+    // An exact name beats a prefix, which beats a match anywhere in the name
+    assert_eq!(
+        names(&database, "guide"),
+        vec!["Guide", "Guided Strike", "Goblin Guide"]
+    );
+    assert_eq!(
+        names(&database, "guide c:w"),
+        vec!["Guide", "Guided Strike"]
+    );
+    assert_eq!(names(&database, "name=guide"), vec!["Guide"]);
+    // Without name terms every match ranks the same, so they are alphabetical
+    assert_eq!(
+        names(&database, "pow=2"),
+        vec!["Goblin Guide", "Izzet Chronarch"]
+    );
+}
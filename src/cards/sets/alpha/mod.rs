@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 
+use crate::cards::Card;
 use crate::cards::set::CardSet;
 
 // Card modules for Alpha
@@ -36,6 +37,31 @@ pub fn spawn_card(commands: &mut Commands, name: &str) -> Option<Entity> {
     }
 }
 
+/// Names of the cards in this set
+pub const CARD_NAMES: &[&str] = &[
+    "Ancestral Recall",
+    "Counterspell",
+    "Fireball",
+    "Lightning Bolt",
+    "Shivan Dragon",
+    "Time Walk",
+    "Wheel of Fortune",
+];
+
+/// Get the definition of a card from this set by name
+pub fn card_by_name(name: &str) -> Option<Card> {
+    match name {
+        "Ancestral Recall" => Some(ancestral_recall::get_card().0),
+        "Counterspell" => Some(counterspell::get_card()),
+        "Fireball" => Some(fireball::get_card()),
+        "Lightning Bolt" => Some(lightning_bolt::get_card()),
+        "Shivan Dragon" => Some(shivan_dragon::get_card()),
+        "Time Walk" => Some(time_walk::get_card()),
+        "Wheel of Fortune" => Some(wheel_of_fortune::get_card()),
+        _ => None,
+    }
+}
+
 /// Spawn all cards from Alpha set
 #[allow(dead_code)]
 pub fn spawn_all_cards(commands: &mut Commands) -> Vec<Entity> {
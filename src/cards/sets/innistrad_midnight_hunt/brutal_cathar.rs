@@ -2,6 +2,7 @@ use bevy::prelude::*;
 
 use crate::cards::rarity::Rarity;
 use crate::cards::{Card, CardDetails, CardTypes, CreatureCard, CreatureType};
+use crate::game_engine::day_night::DayNightFaces;
 use crate::mana::Mana;
 
 use super::set_info;
@@ -20,8 +21,18 @@ pub fn spawn(commands: &mut Commands) -> Option<Entity> {
         .rules_text("Daybound (If a player casts no spells during their own turn, it becomes night next turn.)\nWhen this creature enters the battlefield or transforms into Brutal Cathar, exile target creature an opponent controls until this creature leaves the battlefield.")
         .build_or_panic();
 
+    let faces = DayNightFaces {
+        day: card.clone(),
+        night: night_face(),
+    };
     let entity = commands
-        .spawn((card, set_info(), Rarity::Rare, Name::new("Brutal Cathar")))
+        .spawn((
+            card,
+            faces,
+            set_info(),
+            Rarity::Rare,
+            Name::new("Brutal Cathar"),
+        ))
         .id();
 
     Some(entity)
@@ -41,3 +52,18 @@ pub fn get_card() -> Card {
         .rules_text("Daybound (If a player casts no spells during their own turn, it becomes night next turn.)\nWhen this creature enters the battlefield or transforms into Brutal Cathar, exile target creature an opponent controls until this creature leaves the battlefield.")
         .build_or_panic()
 }
+
+/// Moonrage Brute, the nightbound back face of Brutal Cathar
+pub fn night_face() -> Card {
+    Card::builder("Moonrage Brute")
+        // Back faces have no mana cost
+        .cost(Mana::default())
+        .types(CardTypes::CREATURE)
+        .details(CardDetails::Creature(CreatureCard {
+            power: 3,
+            toughness: 3,
+            creature_type: CreatureType::WEREWOLF,
+        }))
+        .rules_text("First strike\nWard—Pay 3 life.\nNightbound (If a player casts at least two spells during their own turn, it becomes day next turn.)")
+        .build_or_panic()
+}
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 
+use crate::cards::Card;
 use crate::cards::set::CardSet;
 
 // Card modules - Each card gets its own module
@@ -34,6 +35,29 @@ pub fn spawn_card(commands: &mut Commands, name: &str) -> Option<Entity> {
     }
 }
 
+/// Names of the cards in this set
+pub const CARD_NAMES: &[&str] = &[
+    "Brutal Cathar",
+    "Cathar's Call",
+    "Delver of Secrets",
+    "Champion of the Perished",
+    "Moonveil Regent",
+    "Briarbridge Tracker",
+];
+
+/// Get the definition of a card from this set by name
+pub fn card_by_name(name: &str) -> Option<Card> {
+    match name {
+        "Brutal Cathar" => Some(brutal_cathar::get_card()),
+        "Cathar's Call" => Some(cathars_call::get_card()),
+        "Delver of Secrets" => Some(delver_of_secrets::get_card()),
+        "Champion of the Perished" => Some(champion_of_the_perished::get_card()),
+        "Moonveil Regent" => Some(moonveil_regent::get_card()),
+        "Briarbridge Tracker" => Some(briarbridge_tracker::get_card()),
+        _ => None,
+    }
+}
+
 /// Spawn all cards from Innistrad: Midnight Hunt set
 #[allow(dead_code)]
 pub fn spawn_all_cards(commands: &mut Commands) -> Vec<Entity> {
@@ -15,6 +15,10 @@ pub mod legends;
 pub mod scourge;
 
 /// Registry for all available card sets
+///
+/// Deprecated: only knows about spawned cards. New code should use
+/// [`CardDatabase`](crate::cards::CardDatabase), which holds card definitions by
+/// [`CardId`](crate::cards::CardId) whether or not they are on the table.
 #[derive(Resource, Default)]
 pub struct CardRegistry {
     /// Cards organized by set code
@@ -174,6 +178,62 @@ impl CardRegistry {
     }
 }
 
+/// A set whose cards are defined in code
+pub struct BuiltinSet {
+    pub info: CardSet,
+    /// Names of every card defined for the set
+    pub card_names: &'static [&'static str],
+    /// Get the definition of one of the set's cards by name
+    pub card_by_name: fn(&str) -> Option<Card>,
+}
+
+impl BuiltinSet {
+    /// The definitions of every card in the set
+    pub fn cards(&self) -> impl Iterator<Item = Card> + '_ {
+        self.card_names
+            .iter()
+            .filter_map(|name| (self.card_by_name)(name))
+    }
+}
+
+/// Every set with cards defined in code, oldest first
+pub fn builtin_sets() -> Vec<BuiltinSet> {
+    vec![
+        BuiltinSet {
+            info: alpha::set_info(),
+            card_names: alpha::CARD_NAMES,
+            card_by_name: alpha::card_by_name,
+        },
+        BuiltinSet {
+            info: legends::set_info(),
+            card_names: legends::CARD_NAMES,
+            card_by_name: legends::card_by_name,
+        },
+        BuiltinSet {
+            info: alliances::set_info(),
+            card_names: alliances::CARD_NAMES,
+            card_by_name: alliances::card_by_name,
+        },
+        BuiltinSet {
+            info: scourge::set_info(),
+            card_names: scourge::CARD_NAMES,
+            card_by_name: scourge::card_by_name,
+        },
+        BuiltinSet {
+            info: innistrad_midnight_hunt::set_info(),
+            card_names: innistrad_midnight_hunt::CARD_NAMES,
+            card_by_name: innistrad_midnight_hunt::card_by_name,
+        },
+    ]
+}
+
+/// Get the definition of a card by name from any set
+pub fn find_card(name: &str) -> Option<Card> {
+    builtin_sets()
+        .iter()
+        .find_map(|set| (set.card_by_name)(name))
+}
+
 /// Systems for card registry
 pub mod systems {
     use bevy::prelude::*;
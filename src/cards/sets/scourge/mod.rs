@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 
+use crate::cards::Card;
 use crate::cards::set::CardSet;
 
 // Card modules for Scourge
@@ -24,6 +25,17 @@ pub fn spawn_card(commands: &mut Commands, name: &str) -> Option<Entity> {
     }
 }
 
+/// Names of the cards in this set
+pub const CARD_NAMES: &[&str] = &["Dragon Mage"];
+
+/// Get the definition of a card from this set by name
+pub fn card_by_name(name: &str) -> Option<Card> {
+    match name {
+        "Dragon Mage" => Some(dragon_mage::get_card()),
+        _ => None,
+    }
+}
+
 /// Spawn all cards from Scourge set
 #[allow(dead_code)]
 pub fn spawn_all_cards(commands: &mut Commands) -> Vec<Entity> {
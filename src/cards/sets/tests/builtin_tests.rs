@@ -0,0 +1,29 @@
+use std::collections::HashSet;
+
+use crate::cards::sets::{builtin_sets, find_card};
+use crate::deck::get_player_specific_cards;
+
+#[test]
+fn test_every_listed_card_is_defined() {
+    for set in builtin_sets() {
+        for name in set.card_names {
+            let card = (set.card_by_name)(name)
+                .unwrap_or_else(|| panic!("{} lists {name} but doesn't define it", set.info.code));
+            assert_eq!(card.name.name, *name);
+            assert!(find_card(name).is_some());
+        }
+    }
+}
+
+#[test]
+fn test_player_cards_include_every_builtin_card_once() {
+    let names: Vec<String> = get_player_specific_cards()
+        .into_iter()
+        .map(|card| card.name.name)
+        .collect();
+    let unique: HashSet<&String> = names.iter().collect();
+    let listed: usize = builtin_sets().iter().map(|set| set.card_names.len()).sum();
+
+    assert_eq!(names.len(), listed);
+    assert_eq!(unique.len(), names.len());
+}
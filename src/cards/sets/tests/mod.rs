@@ -1 +1,2 @@
+mod builtin_tests;
 mod registry_tests;
@@ -1,2 +1,4 @@
 mod lib;
 pub mod tests;
+
+pub use lib::CardState;
@@ -1,10 +1,20 @@
 use bevy::prelude::*;
 
+use crate::camera::z_order::ZOrder;
 use crate::cards::Card;
-use crate::cards::components::Draggable;
+use crate::cards::components::{DragLocked, Draggable};
 use crate::menu::input_blocker::InteractionBlockState;
 use crate::text;
 
+/// Sent when the player lets go of a dragged card
+#[derive(Event, Debug, Clone, Copy)]
+pub struct CardDroppedEvent {
+    pub card: Entity,
+    /// Where the card was dropped, in world coordinates
+    pub position: Vec2,
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn handle_card_dragging(
     mut card_query: Query<(Entity, &mut Transform, &mut Draggable, &GlobalTransform), With<Card>>,
     mouse_button: Res<ButtonInput<MouseButton>>,
@@ -12,6 +22,9 @@ pub fn handle_card_dragging(
     camera_q: Query<(&Camera, &GlobalTransform), With<crate::camera::components::GameCamera>>,
     player_config: Res<crate::player::resources::PlayerConfig>,
     interaction_block: Res<InteractionBlockState>,
+    locked_cards: Query<(), With<DragLocked>>,
+    mut dropped_events: EventWriter<CardDroppedEvent>,
+    mut z_order: ResMut<ZOrder>,
 ) {
     // Skip interaction if blocked by menus
     if interaction_block.should_block {
@@ -29,115 +42,131 @@ pub fn handle_card_dragging(
     if let Some(cursor_pos) = window.cursor_position() {
         // Convert cursor position to world coordinates
         if let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, cursor_pos) {
-            // Handle mouse press - start dragging
-            if mouse_button.just_pressed(MouseButton::Left) {
-                let mut highest_z = f32::NEG_INFINITY;
-                let mut top_card = None;
-
-                // First pass: find the card with highest z-index at cursor position
-                for (entity, _, draggable, global_transform) in card_query.iter() {
-                    let card_pos = global_transform.translation().truncate();
-
-                    // Get the base card size from player config
-                    let base_card_size = player_config.card_size;
-
-                    // Apply the same size multiplier as in card spawning (2.5)
-                    // This ensures the draggable area matches the visual card size
-                    let actual_card_size = base_card_size * 2.5;
-
-                    // Check if the cursor is within the card bounds
-                    // Use the actual sized card for hit detection with a small margin for easier selection
-                    let hit_area_multiplier = 1.1; // Just 10% larger hit area for precision with buffer
-                    let selection_size = actual_card_size * hit_area_multiplier;
-
-                    if world_pos.x >= card_pos.x - selection_size.x / 2.0
-                        && world_pos.x <= card_pos.x + selection_size.x / 2.0
-                        && world_pos.y >= card_pos.y - selection_size.y / 2.0
-                        && world_pos.y <= card_pos.y + selection_size.y / 2.0
-                    {
-                        // Debug card hit test
-                        info!(
-                            "Card hit test - Entity: {:?}, z-index: {}",
-                            entity, draggable.z_index
-                        );
-
-                        if draggable.z_index > highest_z {
-                            highest_z = draggable.z_index;
-                            top_card = Some((entity, card_pos));
-                        }
-                    }
-                }
+            drag_cards_at(
+                world_pos,
+                &mouse_button,
+                player_config.card_size,
+                &mut card_query,
+                &locked_cards,
+                &mut dropped_events,
+                &mut z_order,
+            );
+        }
+    }
+}
 
-                // Second pass: start dragging only the top card
-                if let Some((top_entity, card_pos)) = top_card {
-                    info!("Dragging card: {:?}", top_entity);
-
-                    // Find the highest z-index among all cards
-                    let mut max_z = 10.0f32; // Start at a sensible baseline
-                    for (_, _, draggable, _) in card_query.iter() {
-                        max_z = max_z.max(draggable.z_index + 0.1);
-                    }
-
-                    for (entity, mut transform, mut draggable, _) in card_query.iter_mut() {
-                        if entity == top_entity {
-                            draggable.dragging = true;
-                            draggable.drag_offset = card_pos - world_pos;
-                            // Set the dragged card's z-index higher than all others
-                            let new_z = max_z + 5.0; // Add a significant bump to ensure it's on top
-                            draggable.z_index = new_z;
-                            transform.translation.z = new_z;
-
-                            info!("Card {:?} now has z-index: {}", entity, new_z);
-                        }
-                    }
+/// Pick up, move and drop cards for a cursor at `world_pos`
+///
+/// This is the part of [`handle_card_dragging`] after the cursor has been turned
+/// into world coordinates.
+pub fn drag_cards_at(
+    world_pos: Vec2,
+    mouse_button: &ButtonInput<MouseButton>,
+    base_card_size: Vec2,
+    card_query: &mut Query<(Entity, &mut Transform, &mut Draggable, &GlobalTransform), With<Card>>,
+    locked_cards: &Query<(), With<DragLocked>>,
+    dropped_events: &mut EventWriter<CardDroppedEvent>,
+    z_order: &mut ZOrder,
+) {
+    // Handle mouse press - start dragging
+    if mouse_button.just_pressed(MouseButton::Left) {
+        let mut highest_z = f32::NEG_INFINITY;
+        let mut top_card = None;
+
+        // First pass: find the card with highest z-index at cursor position
+        for (entity, _, draggable, global_transform) in card_query.iter() {
+            // Cards placed without the z-order manager still have to end up below the dragged one
+            z_order.observe(draggable.z_index);
+            if locked_cards.contains(entity) {
+                continue;
+            }
+            let card_pos = global_transform.translation().truncate();
+
+            // Apply the same size multiplier as in card spawning (2.5)
+            // This ensures the draggable area matches the visual card size
+            let actual_card_size = base_card_size * 2.5;
+
+            // Check if the cursor is within the card bounds
+            // Use the actual sized card for hit detection with a small margin for easier selection
+            let hit_area_multiplier = 1.1; // Just 10% larger hit area for precision with buffer
+            let selection_size = actual_card_size * hit_area_multiplier;
+
+            if world_pos.x >= card_pos.x - selection_size.x / 2.0
+                && world_pos.x <= card_pos.x + selection_size.x / 2.0
+                && world_pos.y >= card_pos.y - selection_size.y / 2.0
+                && world_pos.y <= card_pos.y + selection_size.y / 2.0
+            {
+                // Debug card hit test
+                info!(
+                    "Card hit test - Entity: {:?}, z-index: {}",
+                    entity, draggable.z_index
+                );
+
+                if draggable.z_index > highest_z {
+                    highest_z = draggable.z_index;
+                    top_card = Some((entity, card_pos));
                 }
             }
+        }
 
-            // Handle mouse release - stop dragging and update z-index
-            if mouse_button.just_released(MouseButton::Left) {
-                // Find any cards that were being dragged
-                let mut any_dragged = false;
+        // Second pass: start dragging only the top card
+        if let Some((top_entity, card_pos)) = top_card {
+            info!("Dragging card: {:?}", top_entity);
 
-                for (entity, _, draggable, _) in card_query.iter() {
-                    if draggable.dragging {
-                        any_dragged = true;
-                        info!("Dropping card: {:?}", entity);
-                    }
-                }
+            if let Ok((entity, mut transform, mut draggable, _)) = card_query.get_mut(top_entity) {
+                draggable.dragging = true;
+                draggable.drag_offset = card_pos - world_pos;
+                // Lift the dragged card above every band
+                let new_z = z_order.begin_drag(entity, draggable.z_index);
+                draggable.z_index = new_z;
+                transform.translation.z = new_z;
 
-                if any_dragged {
-                    // Find highest z-index
-                    let mut max_z = 10.0f32;
-                    for (_, _, draggable, _) in card_query.iter() {
-                        max_z = max_z.max(draggable.z_index);
-                    }
-
-                    // Update cards that were being dragged
-                    for (entity, mut transform, mut draggable, _) in card_query.iter_mut() {
-                        if draggable.dragging {
-                            draggable.dragging = false;
-                            // Place the dropped card on top of all other cards
-                            let new_z = max_z + 1.0;
-                            draggable.z_index = new_z;
-                            transform.translation.z = new_z;
-                            info!("Dropped card {:?} at z-index: {}", entity, new_z);
-                        }
-                    }
-                }
+                info!("Card {:?} now has z-index: {}", entity, new_z);
             }
+        }
+    }
+
+    // Handle mouse release - stop dragging and update z-index
+    if mouse_button.just_released(MouseButton::Left) {
+        // Find any cards that were being dragged
+        let mut any_dragged = false;
+
+        for (entity, _, draggable, _) in card_query.iter() {
+            if draggable.dragging {
+                any_dragged = true;
+                info!("Dropping card: {:?}", entity);
+            }
+        }
 
-            // Update position of dragged cards
-            for (_entity, mut transform, draggable, _) in card_query.iter_mut() {
+        if any_dragged {
+            // Update cards that were being dragged
+            for (entity, mut transform, mut draggable, _) in card_query.iter_mut() {
                 if draggable.dragging {
-                    let new_pos = world_pos + draggable.drag_offset;
-                    transform.translation.x = new_pos.x;
-                    transform.translation.y = new_pos.y;
-                    // Maintain the z-index we set when dragging started
-                    transform.translation.z = draggable.z_index;
+                    draggable.dragging = false;
+                    dropped_events.write(CardDroppedEvent {
+                        card: entity,
+                        position: world_pos + draggable.drag_offset,
+                    });
+                    // Place the dropped card on top of the band it was picked up from
+                    let new_z = z_order.end_drag(entity);
+                    draggable.z_index = new_z;
+                    transform.translation.z = new_z;
+                    info!("Dropped card {:?} at z-index: {}", entity, new_z);
                 }
             }
         }
     }
+
+    // Update position of dragged cards
+    for (_entity, mut transform, draggable, _) in card_query.iter_mut() {
+        if draggable.dragging {
+            let new_pos = world_pos + draggable.drag_offset;
+            transform.translation.x = new_pos.x;
+            transform.translation.y = new_pos.y;
+            // Maintain the z-index we set when dragging started
+            transform.translation.z = draggable.z_index;
+        }
+    }
 }
 
 pub fn debug_render_text_positions(
@@ -150,30 +179,34 @@ pub fn debug_render_text_positions(
         return;
     }
 
+    let layout = text::layout::get_card_layout();
+
     for (transform, _) in card_query.iter() {
         let card_pos = transform.translation.truncate();
         let card_size = player_config.card_size;
 
-        // Note: Using Color::srgb instead of Color::rgb as rgb is deprecated
-
         // Name position (top left) - red dot
-        let name_pos = card_pos + Vec2::new(-card_size.x * 0.25, card_size.y * 0.35);
+        let name_pos = card_pos + layout.name_position(card_size);
         gizmos.circle_2d(name_pos, 3.0, Color::srgb(1.0, 0.0, 0.0));
 
         // Mana cost position (top right) - blue dot
-        let cost_pos = card_pos + Vec2::new(card_size.x * 0.35, card_size.y * 0.35);
+        let cost_pos = card_pos + layout.mana_cost_position(card_size);
         gizmos.circle_2d(cost_pos, 3.0, Color::srgb(0.0, 0.0, 1.0));
 
-        // Type position (middle center) - green dot
-        let type_pos = card_pos + Vec2::new(0.0, card_size.y * 0.05);
+        // Type position (middle) - green dot
+        let type_pos = card_pos + layout.type_line_position(card_size);
         gizmos.circle_2d(type_pos, 3.0, Color::srgb(0.0, 1.0, 0.0));
 
-        // Rules text position (middle/bottom center) - yellow dot
-        let rules_pos = card_pos + Vec2::new(0.0, -card_size.y * 0.15);
-        gizmos.circle_2d(rules_pos, 3.0, Color::srgb(1.0, 1.0, 0.0));
+        // Rules text box (middle/bottom center) - yellow outline
+        let rules_pos = card_pos + layout.text_box_position(card_size);
+        gizmos.rect_2d(
+            Isometry2d::from_translation(rules_pos),
+            layout.text_box_inner_size(card_size),
+            Color::srgb(1.0, 1.0, 0.0),
+        );
 
         // Power/toughness position (bottom right) - purple dot
-        let pt_pos = card_pos + Vec2::new(card_size.x * 0.35, -card_size.y * 0.35);
+        let pt_pos = card_pos + layout.pt_position(card_size);
         gizmos.circle_2d(pt_pos, 3.0, Color::srgb(1.0, 0.0, 1.0));
     }
 }
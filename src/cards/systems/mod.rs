@@ -6,5 +6,5 @@ mod plugin;
 mod zone_changes;
 
 // Re-export specific functions instead of using glob imports
-pub use lib::{debug_render_text_positions, handle_card_dragging};
+pub use lib::{CardDroppedEvent, debug_render_text_positions, drag_cards_at, handle_card_dragging};
 pub use zone_changes::*;
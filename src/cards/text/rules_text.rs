@@ -1,12 +1,80 @@
 use bevy::prelude::*;
-use bevy::text::JustifyText;
+use bevy::sprite::Anchor;
+use bevy::text::{JustifyText, TextBounds};
 
+use crate::mana::render::colors::get_mana_symbol_color;
+use crate::mana::symbols::mana_symbol_to_char;
 use crate::text::{
     components::{CardRulesText, CardTextType},
-    utils::{calculate_text_size, get_adaptive_font_size, get_card_layout},
+    layout::{
+        MANA_SYMBOL_SCALE, RulesTextLayout, RulesTextStyle, get_card_font_size, get_card_layout,
+        layout_rules_text,
+    },
 };
 
-use crate::mana::symbols::is_valid_mana_symbol;
+/// Font used for regular rules text
+const RULES_FONT: &str = "fonts/DejaVuSans.ttf";
+/// Font used for reminder and flavor text
+///
+/// No italic face is bundled, so the serif face stands in to set these apart.
+const RULES_ITALIC_FONT: &str = "fonts/NotoSerif-Regular.ttf";
+/// Font used for inline mana and tap symbols
+const MANA_FONT: &str = "fonts/Mana.ttf";
+
+/// Fonts used to render rules text spans
+#[derive(Debug, Clone)]
+pub struct RulesTextFonts {
+    pub regular: Handle<Font>,
+    pub italic: Handle<Font>,
+    pub mana: Handle<Font>,
+}
+
+impl RulesTextFonts {
+    /// Load the standard rules text fonts
+    pub fn load(asset_server: &AssetServer) -> Self {
+        Self {
+            regular: asset_server.load(RULES_FONT),
+            italic: asset_server.load(RULES_ITALIC_FONT),
+            mana: asset_server.load(MANA_FONT),
+        }
+    }
+}
+
+/// Spawn laid out rules text as text spans under a `Text2d` entity
+///
+/// Mana symbols are drawn as glyphs from the Mana font, scaled relative to the
+/// surrounding text so they share its baseline at every zoom level.
+pub fn spawn_rules_text_spans(
+    parent: &mut ChildSpawnerCommands,
+    layout: &RulesTextLayout,
+    fonts: &RulesTextFonts,
+    color: Color,
+) {
+    for span in layout.to_spans() {
+        let (text, font, font_size, span_color) = match span.style {
+            RulesTextStyle::ManaSymbol => (
+                mana_symbol_to_char(&span.text),
+                fonts.mana.clone(),
+                layout.font_size * MANA_SYMBOL_SCALE,
+                get_mana_symbol_color(&span.text),
+            ),
+            style if style.is_italic() => {
+                (span.text, fonts.italic.clone(), layout.font_size, color)
+            }
+            _ => (span.text, fonts.regular.clone(), layout.font_size, color),
+        };
+
+        parent.spawn((
+            TextSpan::new(text),
+            TextFont {
+                font,
+                font_size,
+                ..default()
+            },
+            TextColor(span_color),
+        ));
+    }
+}
 
 /// Spawn rules text for a card
 pub fn spawn_rules_text(
@@ -16,193 +84,50 @@ pub fn spawn_rules_text(
     card_size: Vec2,
     asset_server: &AssetServer,
 ) -> Entity {
-    let layout = get_card_layout();
+    let card_layout = get_card_layout();
 
-    // Calculate relative position offsets
-    let horizontal_offset = 0.0; // Centered horizontally
-    let vertical_offset = layout.text_box_y_offset;
-
-    // Calculate the relative position in local space
-    let local_offset = Vec2::new(
-        card_size.x * horizontal_offset,
-        card_size.y * vertical_offset,
-    );
-
-    // Calculate the text box size with padding applied
-    let text_size = calculate_text_size(
-        card_size,
-        layout.text_box_width - (layout.text_box_padding * 2.0),
-        layout.text_box_height - (layout.text_box_padding * 2.0),
-    );
-
-    // Get adaptive font size based on rules text length and available space
-    // Base size 12pt, minimum 8pt for rules text
-    let font_size = get_adaptive_font_size(
-        card_size,
-        14.0, // Increased from 12.0 to make rules text more readable
+    // Lay out the text inside the padded text box, shrinking verbose cards to fit
+    let text_size = card_layout.text_box_inner_size(card_size);
+    let base_font_size = get_card_font_size(card_size, 14.0);
+    let layout = layout_rules_text(
         &rules_text_component.rules_text,
-        text_size.x,
-        9.0, // Increased minimum size from 8.0 to 9.0
+        None,
+        text_size,
+        base_font_size,
+        9.0_f32.min(base_font_size),
     );
+    if layout.overflow {
+        debug!(
+            "Rules text overflows its text box at minimum font size {}",
+            layout.font_size
+        );
+    }
 
-    // Format the rules text to fit within the specified width
-    let formatted_text =
-        format_rules_text(&rules_text_component.rules_text, text_size.x, font_size);
-
-    // Load fonts
-    let regular_font: Handle<Font> = asset_server.load("fonts/DejaVuSans.ttf");
-    let _mana_font: Handle<Font> = asset_server.load("fonts/Mana.ttf"); // Keep for future mana symbol rendering
-
-    // Spawn the text entity with proper positioning
-
-    // For now, we're not adding inline mana symbols
-    // Future: add_mana_symbols_as_children(commands, text_entity, &formatted_text, font_size, &regular_font, &mana_font);
+    let fonts = RulesTextFonts::load(asset_server);
+    let top_left = card_layout.text_box_top_left(card_size);
 
     commands
         .spawn((
-            Text2d::new(formatted_text.clone()),
-            Transform::from_translation(Vec3::new(
-                local_offset.x,
-                local_offset.y,
-                0.1, // Slightly above the card surface
-            )),
+            Text2d::default(),
+            Transform::from_translation(top_left.extend(0.1)), // Slightly above the card surface
             GlobalTransform::default(),
             TextFont {
-                font: regular_font.clone(),
-                font_size,
+                font: fonts.regular.clone(),
+                font_size: layout.font_size,
                 ..default()
             },
             TextColor(Color::BLACK),
             TextLayout::new_with_justify(JustifyText::Left),
+            TextBounds::new_horizontal(text_size.x),
+            Anchor::TopLeft,
             CardTextType::RulesText,
             Name::new("Card Rules Text"),
             Visibility::Visible,
             InheritedVisibility::default(),
             ViewVisibility::default(),
         ))
+        .with_children(|parent| {
+            spawn_rules_text_spans(parent, &layout, &fonts, Color::BLACK);
+        })
         .id()
 }
-
-/// Add mana symbols as child entities with TextSpan components - Deprecated
-/// This function is no longer used and kept for reference
-#[allow(dead_code)]
-fn add_mana_symbols_as_children(
-    _commands: &mut Commands,
-    _parent_entity: Entity,
-    _formatted_text: &str,
-    _font_size: f32,
-    _regular_font: &Handle<Font>,
-    _mana_font: &Handle<Font>,
-) {
-    // This approach caused the TextSpan warning and has been removed
-    // We now use a simpler approach with just a plain Text2d component
-    unimplemented!()
-}
-
-/// Extract segments of text, separating mana symbols from regular text
-#[allow(dead_code)]
-fn extract_mana_symbol_segments(text: &str) -> Vec<(String, bool)> {
-    let mut segments = Vec::new();
-    let mut current_pos = 0;
-
-    while current_pos < text.len() {
-        if let Some(start) = text[current_pos..].find('{') {
-            let symbol_start = current_pos + start;
-
-            // Add text before the symbol
-            if symbol_start > current_pos {
-                segments.push((text[current_pos..symbol_start].to_string(), false));
-            }
-
-            // Find the end of the symbol
-            if let Some(end) = text[symbol_start..].find('}') {
-                let symbol_end = symbol_start + end + 1;
-                let symbol = &text[symbol_start..symbol_end];
-
-                if is_valid_mana_symbol(symbol) {
-                    segments.push((symbol.to_string(), true));
-                } else {
-                    segments.push((symbol.to_string(), false));
-                }
-
-                current_pos = symbol_end;
-            } else {
-                // No closing brace, treat as regular text
-                segments.push((text[current_pos..].to_string(), false));
-                break;
-            }
-        } else {
-            // No more symbols, add remaining text
-            segments.push((text[current_pos..].to_string(), false));
-            break;
-        }
-    }
-
-    segments
-}
-
-/// Format rules text to fit within the specified width
-fn format_rules_text(text: &str, max_width: f32, font_size: f32) -> String {
-    // Calculate approximate characters per line based on font size
-    // Using a conservative estimate for proportional font
-    let approximate_char_width = font_size * 0.4; // More conservative estimate (was 0.5)
-    let chars_per_line = (max_width / approximate_char_width).floor() as usize;
-
-    // If text is empty, return empty string
-    if text.is_empty() {
-        return String::new();
-    }
-
-    let mut formatted = String::new();
-    let mut current_line_length = 0;
-
-    // Split on existing newlines first to respect source formatting
-    for paragraph in text.split('\n') {
-        if !formatted.is_empty() {
-            formatted.push('\n');
-            current_line_length = 0;
-        }
-
-        let words = paragraph.split_whitespace().collect::<Vec<&str>>();
-
-        for (i, word) in words.iter().enumerate() {
-            // Check if adding this word would exceed the line width
-            if current_line_length + word.len() + 1 > chars_per_line && current_line_length > 0 {
-                formatted.push('\n');
-                current_line_length = 0;
-            } else if i > 0 && current_line_length > 0 {
-                // Add space before word unless it's the first word of a line
-                formatted.push(' ');
-                current_line_length += 1;
-            }
-
-            // Special handling for mana symbols to keep them together
-            if word.contains('{') && word.contains('}') {
-                // Add the word without breaking it
-                formatted.push_str(word);
-                current_line_length += word.len();
-            } else {
-                // Add the word
-                formatted.push_str(word);
-                current_line_length += word.len();
-            }
-        }
-    }
-
-    formatted
-}
-
-/// Renders a line of text with inline mana symbols
-#[allow(dead_code)] // Kept for reference but no longer used
-fn render_inline_mana_symbols(
-    _commands: &mut Commands,
-    _line: &str,
-    _y_pos: f32,
-    _font_size: f32,
-    _regular_font: &Handle<Font>,
-    _mana_font: &Handle<Font>,
-    _parent_entity: Entity,
-) {
-    // This function is kept for reference but is no longer used
-    // We now build the Text component directly with sections
-}
@@ -1,3 +1,5 @@
 pub use crate::cards::types::lib::*;
 mod lib;
+pub mod subtypes;
+pub use subtypes::{Subtype, SubtypeId, SubtypeKind, SubtypeSet};
 pub mod tests;
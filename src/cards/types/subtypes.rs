@@ -0,0 +1,399 @@
+//! Interned card subtypes
+//!
+//! [`CreatureType`] and the subtype bits of [`CardTypes`] only have room for 64
+//! flags, while Magic has hundreds of creature types alone. Subtypes are instead
+//! interned into a global table that hands out a [`SubtypeId`] for each name, and
+//! a card's subtypes are kept as a [`SubtypeSet`]: a bitset over those ids, so
+//! unions and intersections stay cheap whatever the number of subtypes.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{LazyLock, RwLock};
+
+use serde::{Deserialize, Serialize};
+
+use super::{CardTypes, CreatureType};
+use crate::cards::details::CardDetails;
+
+/// Which card type a subtype belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SubtypeKind {
+    Creature,
+    Land,
+    Artifact,
+    Enchantment,
+    Planeswalker,
+    /// Instant and sorcery subtypes, e.g. Adventure
+    Spell,
+    Other,
+}
+
+/// A subtype name and the card type it belongs to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Subtype {
+    pub name: String,
+    pub kind: SubtypeKind,
+}
+
+/// Index of a subtype in the subtype table
+///
+/// Ids are only valid within one run; save and send subtypes by name, which is
+/// what serializing a [`SubtypeSet`] does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SubtypeId(u32);
+
+#[derive(Default)]
+struct SubtypeTable {
+    subtypes: Vec<Subtype>,
+    /// Lowercase name to id
+    by_name: HashMap<String, SubtypeId>,
+    /// The bitflag each of the original creature types maps to
+    creature_flags: HashMap<SubtypeId, CreatureType>,
+}
+
+impl SubtypeTable {
+    fn seeded() -> Self {
+        let mut table = Self::default();
+        for flag in CreatureType::all().iter() {
+            let id = table.intern(&flag.to_string(), SubtypeKind::Creature);
+            table.creature_flags.insert(id, flag);
+        }
+        for (_, name, kind) in CARD_TYPE_SUBTYPES {
+            table.intern(name, *kind);
+        }
+        table
+    }
+
+    fn intern(&mut self, name: &str, kind: SubtypeKind) -> SubtypeId {
+        let key = name.trim().to_lowercase();
+        if let Some(id) = self.by_name.get(&key) {
+            return *id;
+        }
+        let id = SubtypeId(self.subtypes.len() as u32);
+        self.subtypes.push(Subtype {
+            name: name.trim().to_string(),
+            kind,
+        });
+        self.by_name.insert(key, id);
+        id
+    }
+}
+
+/// The subtypes that have a bit in [`CardTypes`]
+const CARD_TYPE_SUBTYPES: &[(CardTypes, &str, SubtypeKind)] = &[
+    (CardTypes::EQUIPMENT, "Equipment", SubtypeKind::Artifact),
+    (CardTypes::VEHICLE, "Vehicle", SubtypeKind::Artifact),
+    (CardTypes::FOOD, "Food", SubtypeKind::Artifact),
+    (CardTypes::CLUE, "Clue", SubtypeKind::Artifact),
+    (CardTypes::TREASURE, "Treasure", SubtypeKind::Artifact),
+    (
+        CardTypes::FORTIFICATION,
+        "Fortification",
+        SubtypeKind::Artifact,
+    ),
+    (CardTypes::CONTRAPTION, "Contraption", SubtypeKind::Artifact),
+    (CardTypes::AURA, "Aura", SubtypeKind::Enchantment),
+    (CardTypes::SAGA, "Saga", SubtypeKind::Enchantment),
+    (CardTypes::PLAINS, "Plains", SubtypeKind::Land),
+    (CardTypes::ISLAND, "Island", SubtypeKind::Land),
+    (CardTypes::SWAMP, "Swamp", SubtypeKind::Land),
+    (CardTypes::MOUNTAIN, "Mountain", SubtypeKind::Land),
+    (CardTypes::FOREST, "Forest", SubtypeKind::Land),
+];
+
+static SUBTYPES: LazyLock<RwLock<SubtypeTable>> =
+    LazyLock::new(|| RwLock::new(SubtypeTable::seeded()));
+
+impl SubtypeId {
+    /// The id of a subtype, adding it to the table if it's new
+    ///
+    /// Names are matched ignoring case. A subtype keeps the kind it was first
+    /// interned with.
+    pub fn intern(name: &str, kind: SubtypeKind) -> Self {
+        if let Some(id) = Self::lookup(name) {
+            return id;
+        }
+        SUBTYPES.write().unwrap().intern(name, kind)
+    }
+
+    /// The id of a subtype that has already been interned
+    pub fn lookup(name: &str) -> Option<Self> {
+        let key = name.trim().to_lowercase();
+        SUBTYPES.read().unwrap().by_name.get(&key).copied()
+    }
+
+    /// The subtype's name and kind
+    pub fn subtype(self) -> Subtype {
+        SUBTYPES.read().unwrap().subtypes[self.0 as usize].clone()
+    }
+
+    pub fn name(self) -> String {
+        self.subtype().name
+    }
+
+    pub fn kind(self) -> SubtypeKind {
+        SUBTYPES.read().unwrap().subtypes[self.0 as usize].kind
+    }
+}
+
+impl fmt::Display for SubtypeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A set of subtypes
+///
+/// A set with all creature types (changeling) contains every creature subtype,
+/// including ones that haven't been interned yet.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "SubtypeSetData", into = "SubtypeSetData")]
+pub struct SubtypeSet {
+    /// One bit per [`SubtypeId`], without trailing empty words
+    words: Vec<u64>,
+    all_creature_types: bool,
+}
+
+impl SubtypeSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A set of named subtypes, interning new names as `kind`
+    pub fn from_names<S: AsRef<str>>(
+        names: impl IntoIterator<Item = S>,
+        kind: SubtypeKind,
+    ) -> Self {
+        names
+            .into_iter()
+            .map(|name| SubtypeId::intern(name.as_ref(), kind))
+            .collect()
+    }
+
+    /// The subtypes printed on a card, worked out from its types and details
+    pub fn from_card(types: CardTypes, details: &CardDetails) -> Self {
+        let mut set = Self::from(types);
+        let (name, kind) = match details {
+            CardDetails::Creature(creature) => {
+                set.union_with(&Self::from(creature.creature_type));
+                return set;
+            }
+            CardDetails::Land(land) => (land.land_type.as_deref(), SubtypeKind::Land),
+            CardDetails::Artifact(artifact) => {
+                (artifact.artifact_type.as_deref(), SubtypeKind::Artifact)
+            }
+            CardDetails::Enchantment(enchantment) => (
+                enchantment.enchantment_type.as_deref(),
+                SubtypeKind::Enchantment,
+            ),
+            _ => (None, SubtypeKind::Other),
+        };
+        for name in name.into_iter().flat_map(str::split_whitespace) {
+            set.insert(SubtypeId::intern(name, kind));
+        }
+        set
+    }
+
+    pub fn insert(&mut self, id: SubtypeId) {
+        let (word, bit) = Self::position(id);
+        if self.words.len() <= word {
+            self.words.resize(word + 1, 0);
+        }
+        self.words[word] |= bit;
+    }
+
+    pub fn remove(&mut self, id: SubtypeId) {
+        let (word, bit) = Self::position(id);
+        if let Some(bits) = self.words.get_mut(word) {
+            *bits &= !bit;
+        }
+        self.trim();
+    }
+
+    /// Whether the set has a subtype, counting changeling as every creature type
+    pub fn contains(&self, id: SubtypeId) -> bool {
+        self.contains_exactly(id) || (self.all_creature_types && id.kind() == SubtypeKind::Creature)
+    }
+
+    /// Whether the set has a subtype by name, ignoring case
+    pub fn contains_name(&self, name: &str) -> bool {
+        match SubtypeId::lookup(name) {
+            Some(id) => self.contains(id),
+            None => false,
+        }
+    }
+
+    /// Whether the subtype is in the set itself, ignoring changeling
+    fn contains_exactly(&self, id: SubtypeId) -> bool {
+        let (word, bit) = Self::position(id);
+        self.words.get(word).is_some_and(|bits| bits & bit != 0)
+    }
+
+    /// Give the set every creature type, as changeling does
+    pub fn set_all_creature_types(&mut self, all: bool) {
+        self.all_creature_types = all;
+    }
+
+    pub fn has_all_creature_types(&self) -> bool {
+        self.all_creature_types
+    }
+
+    /// Add every subtype of `other` to this set
+    pub fn union_with(&mut self, other: &Self) {
+        if self.words.len() < other.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+        for (bits, other_bits) in self.words.iter_mut().zip(&other.words) {
+            *bits |= other_bits;
+        }
+        self.all_creature_types |= other.all_creature_types;
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        set.union_with(other);
+        set
+    }
+
+    /// The subtypes both sets contain explicitly, plus all creature types if both
+    /// have them
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut set = Self {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(bits, other_bits)| bits & other_bits)
+                .collect(),
+            all_creature_types: self.all_creature_types && other.all_creature_types,
+        };
+        // A changeling shares every creature type of the other set
+        for (changeling, other) in [(self, other), (other, self)] {
+            if changeling.all_creature_types {
+                set.union_with(&other.of_kind(SubtypeKind::Creature));
+            }
+        }
+        set.trim();
+        set
+    }
+
+    /// Whether the sets share any subtype, e.g. for "shares a creature type"
+    pub fn intersects(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The subtypes of one kind, explicitly in the set
+    pub fn of_kind(&self, kind: SubtypeKind) -> Self {
+        let mut set: Self = self.iter().filter(|id| id.kind() == kind).collect();
+        set.all_creature_types = self.all_creature_types && kind == SubtypeKind::Creature;
+        set
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty() && !self.all_creature_types
+    }
+
+    /// Number of subtypes explicitly in the set
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|bits| bits.count_ones() as usize)
+            .sum()
+    }
+
+    /// The subtypes explicitly in the set, in the order they were interned
+    pub fn iter(&self) -> impl Iterator<Item = SubtypeId> + '_ {
+        self.words.iter().enumerate().flat_map(|(word, bits)| {
+            (0..64)
+                .filter(move |bit| bits & (1 << bit) != 0)
+                .map(move |bit| SubtypeId((word * 64 + bit) as u32))
+        })
+    }
+
+    /// The creature types of the set that have a [`CreatureType`] flag
+    pub fn creature_type_flags(&self) -> CreatureType {
+        if self.all_creature_types {
+            return CreatureType::all();
+        }
+        let table = SUBTYPES.read().unwrap();
+        self.iter()
+            .filter_map(|id| table.creature_flags.get(&id).copied())
+            .fold(CreatureType::NONE, |flags, flag| flags | flag)
+    }
+
+    fn position(id: SubtypeId) -> (usize, u64) {
+        ((id.0 / 64) as usize, 1 << (id.0 % 64))
+    }
+
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+}
+
+impl FromIterator<SubtypeId> for SubtypeSet {
+    fn from_iter<I: IntoIterator<Item = SubtypeId>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for id in iter {
+            set.insert(id);
+        }
+        set
+    }
+}
+
+impl From<CreatureType> for SubtypeSet {
+    fn from(creature_types: CreatureType) -> Self {
+        creature_types
+            .iter()
+            .map(|flag| SubtypeId::intern(&flag.to_string(), SubtypeKind::Creature))
+            .collect()
+    }
+}
+
+impl From<CardTypes> for SubtypeSet {
+    /// The subtypes that have a bit in `types`
+    fn from(types: CardTypes) -> Self {
+        CARD_TYPE_SUBTYPES
+            .iter()
+            .filter(|(flag, _, _)| types.contains(*flag))
+            .map(|(_, name, kind)| SubtypeId::intern(name, *kind))
+            .collect()
+    }
+}
+
+impl fmt::Display for SubtypeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<String> = self.iter().map(SubtypeId::name).collect();
+        write!(f, "{}", names.join(" "))
+    }
+}
+
+/// How a [`SubtypeSet`] is saved: by name, since ids differ between runs
+#[derive(Serialize, Deserialize)]
+struct SubtypeSetData {
+    subtypes: Vec<Subtype>,
+    #[serde(default)]
+    all_creature_types: bool,
+}
+
+impl From<SubtypeSet> for SubtypeSetData {
+    fn from(set: SubtypeSet) -> Self {
+        Self {
+            subtypes: set.iter().map(SubtypeId::subtype).collect(),
+            all_creature_types: set.all_creature_types,
+        }
+    }
+}
+
+impl From<SubtypeSetData> for SubtypeSet {
+    fn from(data: SubtypeSetData) -> Self {
+        let mut set: Self = data
+            .subtypes
+            .iter()
+            .map(|subtype| SubtypeId::intern(&subtype.name, subtype.kind))
+            .collect();
+        set.all_creature_types = data.all_creature_types;
+        set
+    }
+}
@@ -1 +1,3 @@
 // Tests for types
+#[cfg(test)]
+mod subtype_tests;
@@ -0,0 +1,81 @@
+use crate::cards::Card;
+use crate::cards::details::{CardDetails, CreatureCard};
+use crate::cards::types::{CardTypes, CreatureType, SubtypeId, SubtypeKind, SubtypeSet};
+use crate::mana::Mana;
+
+#[test]
+fn test_subtypes_beyond_the_bitflags_round_trip() {
+    // Neither has a CreatureType flag
+    let set = SubtypeSet::from_names(["Human", "Kor", "Noggle"], SubtypeKind::Creature);
+
+    assert!(set.contains_name("kor"));
+    assert_eq!(set.len(), 3);
+    assert_eq!(set.creature_type_flags(), CreatureType::HUMAN);
+
+    let json = serde_json::to_string(&set).unwrap();
+    let restored: SubtypeSet = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, set);
+    assert_eq!(restored.to_string(), "Human Kor Noggle");
+}
+
+#[test]
+fn test_interning_is_case_insensitive_and_keeps_the_first_kind() {
+    let id = SubtypeId::intern("Cephalid", SubtypeKind::Creature);
+    assert_eq!(SubtypeId::intern("cephalid", SubtypeKind::Land), id);
+    assert_eq!(id.kind(), SubtypeKind::Creature);
+    assert_eq!(id.name(), "Cephalid");
+}
+
+#[test]
+fn test_changeling_has_every_creature_type() {
+    let mut changeling = SubtypeSet::new();
+    changeling.set_all_creature_types(true);
+    let elf = SubtypeSet::from_names(["Elf"], SubtypeKind::Creature);
+    let forest = SubtypeSet::from(CardTypes::FOREST);
+
+    assert!(changeling.contains_name("Elf"));
+    assert!(changeling.contains(SubtypeId::intern("Ouphe", SubtypeKind::Creature)));
+    assert!(!changeling.contains_name("Forest"));
+    assert!(changeling.intersects(&elf));
+    assert!(!changeling.intersects(&forest));
+    assert_eq!(changeling.intersection(&elf), elf);
+    assert_eq!(changeling.creature_type_flags(), CreatureType::all());
+}
+
+#[test]
+fn test_set_operations() {
+    let a = SubtypeSet::from_names(["Elf", "Druid"], SubtypeKind::Creature);
+    let b = SubtypeSet::from_names(["Elf", "Warrior"], SubtypeKind::Creature);
+
+    assert_eq!(a.union(&b).len(), 3);
+    assert_eq!(
+        a.intersection(&b),
+        SubtypeSet::from_names(["Elf"], SubtypeKind::Creature)
+    );
+
+    let mut c = a.clone();
+    c.remove(SubtypeId::intern("Elf", SubtypeKind::Creature));
+    c.remove(SubtypeId::intern("Druid", SubtypeKind::Creature));
+    assert!(c.is_empty());
+    assert_eq!(c, SubtypeSet::new());
+}
+
+#[test]
+fn test_cards_get_subtypes_from_their_details() {
+    let card = Card::new(
+        "Test Shapeshifter",
+        Mana::default(),
+        CardTypes::CREATURE,
+        CardDetails::Creature(CreatureCard {
+            power: 1,
+            toughness: 1,
+            creature_type: CreatureType::ELF,
+        }),
+        "Changeling (This card is every creature type.)",
+    );
+
+    let subtypes = &card.type_info.subtypes;
+    assert!(subtypes.contains_name("Elf"));
+    assert!(subtypes.has_all_creature_types());
+    assert!(subtypes.contains_name("Goblin"));
+}
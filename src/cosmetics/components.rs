@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::themes::ThemeId;
+
+/// The card back a player's cards show to everyone
+///
+/// Players without one show the default card back.
+#[derive(Component, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerCardBack(pub ThemeId);
+
+/// A sprite showing the back of one of `player`'s cards
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardBackSprite {
+    pub player: Entity,
+}
+
+/// The themed mat laid under a player's playmat zones
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlaymatBackdrop {
+    pub player: Entity,
+}
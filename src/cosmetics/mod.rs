@@ -0,0 +1,31 @@
+//! Cosmetic themes for card backs and playmats
+//!
+//! Themes are registered by id in [`CosmeticThemes`]. The themes the local
+//! player picked are kept as ids in the settings file, and each player's card
+//! back is a [`PlayerCardBack`] holding only its theme id. In multiplayer only
+//! that id is sent to the other players, and an id someone doesn't have falls
+//! back to the default card back. The playmat theme only changes how the table
+//! looks on this machine.
+//!
+//! Themes can show user-provided images, given as paths under the `assets`
+//! folder. Register them before the game starts, for example:
+//!
+//! ```ignore
+//! app.world_mut()
+//!     .resource_mut::<CosmeticThemes>()
+//!     .register_card_back(CardBackTheme::image("celtic", "Celtic Knot", "cosmetics/celtic.png"));
+//! ```
+
+pub mod components;
+mod plugin;
+pub mod systems;
+pub mod themes;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{CardBackSprite, PlayerCardBack, PlaymatBackdrop};
+pub use plugin::CosmeticsPlugin;
+pub use themes::{
+    CardBackTheme, CosmeticThemes, DEFAULT_CARD_BACK, DEFAULT_PLAYMAT, PlaymatTheme, ThemeId,
+};
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use super::systems::{
+    apply_card_backs, apply_playmat_theme, assign_local_card_back, load_theme_images,
+    spawn_playmat_backdrops,
+};
+use super::themes::CosmeticThemes;
+use crate::menu::settings::components::CosmeticSettings;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for card back and playmat themes
+pub struct CosmeticsPlugin;
+
+impl Plugin for CosmeticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CosmeticThemes>()
+            .init_resource::<CosmeticSettings>()
+            .add_systems(
+                Update,
+                load_theme_images.run_if(resource_changed::<CosmeticThemes>),
+            )
+            .add_systems(
+                Update,
+                (
+                    assign_local_card_back,
+                    apply_card_backs,
+                    spawn_playmat_backdrops,
+                    apply_playmat_theme,
+                )
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame)),
+            );
+    }
+}
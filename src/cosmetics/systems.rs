@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+
+use super::components::{CardBackSprite, PlayerCardBack, PlaymatBackdrop};
+use super::themes::CosmeticThemes;
+use crate::camera::components::AppLayer;
+use crate::menu::settings::components::CosmeticSettings;
+use crate::player::Player;
+use crate::player::playmat::{PLAYMAT_SIZE, PlayerPlaymat};
+
+/// Height of the backdrop below its playmat, keeping it under the cards
+const BACKDROP_Z: f32 = -10.0;
+
+/// Load the images of newly registered themes
+pub fn load_theme_images(asset_server: Res<AssetServer>, mut themes: ResMut<CosmeticThemes>) {
+    for path in themes.unloaded_images() {
+        info!("Loading theme image {}", path);
+        let image = asset_server.load(path.clone());
+        themes.images.insert(path, image);
+    }
+}
+
+/// Give the local player the card back chosen in the settings
+pub fn assign_local_card_back(
+    mut commands: Commands,
+    settings: Res<CosmeticSettings>,
+    players: Query<(Entity, &Player, Option<&PlayerCardBack>)>,
+) {
+    for (entity, player, card_back) in players.iter() {
+        if player.player_index != 0 || card_back.is_some_and(|back| back.0 == settings.card_back) {
+            continue;
+        }
+        commands
+            .entity(entity)
+            .insert(PlayerCardBack(settings.card_back.clone()));
+    }
+}
+
+/// Show each player's card back on the sprites showing the backs of their
+/// cards
+///
+/// Sprites keep their alpha, so ones fading out aren't made opaque again.
+pub fn apply_card_backs(
+    themes: Res<CosmeticThemes>,
+    players: Query<&PlayerCardBack>,
+    mut sprites: Query<(&CardBackSprite, &mut Sprite)>,
+) {
+    for (back, mut sprite) in sprites.iter_mut() {
+        let card_back = players.get(back.player).ok().map(|card_back| &card_back.0);
+        let theme = themes.card_back(card_back);
+        let image = themes.image(theme.image.as_deref());
+        let color = theme.color.with_alpha(sprite.color.alpha());
+        if sprite.image != image {
+            sprite.image = image;
+        }
+        if sprite.color != color {
+            sprite.color = color;
+        }
+    }
+}
+
+/// Lay a mat under every playmat that doesn't have one yet
+pub fn spawn_playmat_backdrops(
+    mut commands: Commands,
+    playmats: Query<(Entity, &PlayerPlaymat)>,
+    backdrops: Query<&PlaymatBackdrop>,
+) {
+    for (entity, playmat) in playmats.iter() {
+        if backdrops
+            .iter()
+            .any(|backdrop| backdrop.player == playmat.player_id)
+        {
+            continue;
+        }
+        let backdrop = commands
+            .spawn((
+                Sprite {
+                    custom_size: Some(PLAYMAT_SIZE),
+                    ..default()
+                },
+                Transform::from_xyz(0.0, 0.0, BACKDROP_Z),
+                PlaymatBackdrop {
+                    player: playmat.player_id,
+                },
+                AppLayer::game_layers(),
+                Name::new("Playmat Backdrop"),
+            ))
+            .id();
+        commands.entity(entity).add_child(backdrop);
+    }
+}
+
+/// Dress the table and the playmats in the chosen playmat theme
+pub fn apply_playmat_theme(
+    themes: Res<CosmeticThemes>,
+    settings: Res<CosmeticSettings>,
+    mut clear_color: ResMut<ClearColor>,
+    mut backdrops: Query<&mut Sprite, With<PlaymatBackdrop>>,
+) {
+    let theme = themes.playmat(&settings.playmat);
+    if clear_color.0 != theme.table_color {
+        clear_color.0 = theme.table_color;
+    }
+
+    let image = themes.image(theme.image.as_deref());
+    for mut sprite in backdrops.iter_mut() {
+        if sprite.image != image {
+            sprite.image = image.clone();
+        }
+        if sprite.color != theme.mat_color {
+            sprite.color = theme.mat_color;
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use bevy::prelude::*;
+
+use crate::cosmetics::systems::{
+    apply_card_backs, apply_playmat_theme, assign_local_card_back, spawn_playmat_backdrops,
+};
+use crate::cosmetics::{
+    CardBackSprite, CardBackTheme, CosmeticThemes, DEFAULT_CARD_BACK, PlayerCardBack,
+    PlaymatBackdrop, ThemeId,
+};
+use crate::menu::settings::components::CosmeticSettings;
+use crate::player::Player;
+use crate::player::playmat::PlayerPlaymat;
+
+#[test]
+fn test_registered_themes_replace_ones_with_the_same_id() {
+    let mut themes = CosmeticThemes::default();
+    let count = themes.card_backs.len();
+    themes.register_card_back(CardBackTheme::image(
+        "celtic",
+        "Celtic Knot",
+        "backs/celtic.png",
+    ));
+    themes.register_card_back(CardBackTheme::image(
+        "celtic",
+        "Celtic",
+        "backs/celtic2.png",
+    ));
+
+    assert_eq!(themes.card_backs.len(), count + 1);
+    let celtic = themes.card_back(Some(&ThemeId::new("celtic")));
+    assert_eq!(celtic.name, "Celtic");
+    assert_eq!(
+        themes.unloaded_images(),
+        vec!["backs/celtic2.png".to_string()]
+    );
+}
+
+#[test]
+fn test_unknown_themes_fall_back_to_the_defaults() {
+    let themes = CosmeticThemes::default();
+    let missing = ThemeId::new("missing");
+    assert_eq!(themes.card_back(Some(&missing)).id.0, DEFAULT_CARD_BACK);
+    assert_eq!(themes.card_back(None).id.0, DEFAULT_CARD_BACK);
+    assert_eq!(themes.playmat(&missing), &themes.playmats[0]);
+}
+
+#[test]
+fn test_themes_cycle_back_to_the_first() {
+    let themes = CosmeticThemes::default();
+    let mut id = themes.card_backs[0].id.clone();
+    for _ in 0..themes.card_backs.len() {
+        id = themes.next_card_back(&id);
+    }
+    assert_eq!(id, themes.card_backs[0].id);
+    assert_eq!(
+        themes.next_playmat(&ThemeId::new("missing")),
+        themes.playmats[0].id
+    );
+}
+
+#[test]
+fn test_each_player_shows_their_own_card_back() {
+    let mut app = App::new();
+    app.init_resource::<CosmeticThemes>()
+        .insert_resource(CosmeticSettings {
+            card_back: ThemeId::new("midnight"),
+            ..default()
+        })
+        .add_systems(Update, (assign_local_card_back, apply_card_backs).chain());
+
+    let local = app.world_mut().spawn(Player::new("Alice")).id();
+    let remote = app
+        .world_mut()
+        .spawn((
+            Player::new("Bob").with_player_index(1),
+            PlayerCardBack(ThemeId::new("crimson")),
+        ))
+        .id();
+    let ai = app
+        .world_mut()
+        .spawn(Player::new("Carol").with_player_index(2))
+        .id();
+    let sprites = [local, remote, ai].map(|player| {
+        app.world_mut()
+            .spawn((
+                Sprite::from_color(Color::BLACK.with_alpha(0.5), Vec2::ONE),
+                CardBackSprite { player },
+            ))
+            .id()
+    });
+    app.update();
+    app.update();
+
+    let themes = CosmeticThemes::default();
+    for (sprite, id) in sprites
+        .iter()
+        .zip(["midnight", "crimson", DEFAULT_CARD_BACK])
+    {
+        let color = app.world().get::<Sprite>(*sprite).unwrap().color;
+        let theme = themes.card_back(Some(&ThemeId::new(id)));
+        assert_eq!(color, theme.color.with_alpha(0.5));
+    }
+    assert_eq!(
+        app.world().get::<PlayerCardBack>(local),
+        Some(&PlayerCardBack(ThemeId::new("midnight")))
+    );
+    assert_eq!(app.world().get::<PlayerCardBack>(ai), None);
+}
+
+#[test]
+fn test_playmats_and_table_take_the_chosen_theme() {
+    let mut app = App::new();
+    app.init_resource::<CosmeticThemes>()
+        .init_resource::<ClearColor>()
+        .insert_resource(CosmeticSettings {
+            playmat: ThemeId::new("walnut"),
+            ..default()
+        })
+        .add_systems(
+            Update,
+            (spawn_playmat_backdrops, apply_playmat_theme).chain(),
+        );
+    let alice = app.world_mut().spawn(Player::new("Alice")).id();
+    app.world_mut().spawn((
+        PlayerPlaymat {
+            player_id: alice,
+            player_index: 0,
+        },
+        Transform::default(),
+    ));
+    app.update();
+    app.update();
+
+    let themes = CosmeticThemes::default();
+    let walnut = themes.playmat(&ThemeId::new("walnut"));
+    let backdrops: Vec<Color> = app
+        .world_mut()
+        .query_filtered::<&Sprite, With<PlaymatBackdrop>>()
+        .iter(app.world())
+        .map(|sprite| sprite.color)
+        .collect();
+    assert_eq!(backdrops, vec![walnut.mat_color]);
+    assert_eq!(app.world().resource::<ClearColor>().0, walnut.table_color);
+}
@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Id of the card back used when none is chosen
+pub const DEFAULT_CARD_BACK: &str = "classic";
+/// Id of the playmat used when none is chosen
+pub const DEFAULT_PLAYMAT: &str = "felt";
+
+/// Identifies a card back or playmat theme
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ThemeId(pub String);
+
+impl ThemeId {
+    pub fn new(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+
+/// A look for the backs of cards
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardBackTheme {
+    pub id: ThemeId,
+    /// Name shown in the settings menu
+    pub name: String,
+    /// Color of the back, tinting the image if there is one
+    pub color: Color,
+    /// Path of an image under `assets`, if any
+    pub image: Option<String>,
+}
+
+impl CardBackTheme {
+    /// A card back of a single color
+    pub fn plain(id: &str, name: &str, color: Color) -> Self {
+        Self {
+            id: ThemeId::new(id),
+            name: name.to_string(),
+            color,
+            image: None,
+        }
+    }
+
+    /// A card back showing an image
+    pub fn image(id: &str, name: &str, path: &str) -> Self {
+        Self {
+            id: ThemeId::new(id),
+            name: name.to_string(),
+            color: Color::WHITE,
+            image: Some(path.to_string()),
+        }
+    }
+}
+
+/// A look for the table and the playmats on it
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaymatTheme {
+    pub id: ThemeId,
+    /// Name shown in the settings menu
+    pub name: String,
+    /// Color of the table around the playmats
+    pub table_color: Color,
+    /// Color of the playmats, tinting the image if there is one
+    pub mat_color: Color,
+    /// Path of an image under `assets`, if any
+    pub image: Option<String>,
+}
+
+impl PlaymatTheme {
+    /// Playmats of a single color
+    pub fn plain(id: &str, name: &str, table_color: Color, mat_color: Color) -> Self {
+        Self {
+            id: ThemeId::new(id),
+            name: name.to_string(),
+            table_color,
+            mat_color,
+            image: None,
+        }
+    }
+
+    /// Playmats showing an image
+    pub fn image(id: &str, name: &str, table_color: Color, path: &str) -> Self {
+        Self {
+            id: ThemeId::new(id),
+            name: name.to_string(),
+            table_color,
+            mat_color: Color::WHITE,
+            image: Some(path.to_string()),
+        }
+    }
+}
+
+/// The card back and playmat themes that can be chosen
+///
+/// The first theme of each kind is the default, used for ids that aren't
+/// registered.
+#[derive(Resource, Debug, Clone)]
+pub struct CosmeticThemes {
+    pub card_backs: Vec<CardBackTheme>,
+    pub playmats: Vec<PlaymatTheme>,
+    /// Images loaded for the themes, by path
+    pub images: HashMap<String, Handle<Image>>,
+}
+
+impl Default for CosmeticThemes {
+    fn default() -> Self {
+        Self {
+            card_backs: vec![
+                CardBackTheme::plain(DEFAULT_CARD_BACK, "Classic", Color::srgb(0.36, 0.22, 0.12)),
+                CardBackTheme::plain("midnight", "Midnight", Color::srgb(0.1, 0.12, 0.3)),
+                CardBackTheme::plain("crimson", "Crimson", Color::srgb(0.45, 0.08, 0.1)),
+                CardBackTheme::plain("verdant", "Verdant", Color::srgb(0.1, 0.3, 0.15)),
+            ],
+            playmats: vec![
+                PlaymatTheme::plain(
+                    DEFAULT_PLAYMAT,
+                    "Felt",
+                    Color::srgb(0.3, 0.3, 0.3),
+                    Color::srgb(0.12, 0.3, 0.18),
+                ),
+                PlaymatTheme::plain(
+                    "walnut",
+                    "Walnut",
+                    Color::srgb(0.22, 0.14, 0.08),
+                    Color::srgb(0.35, 0.24, 0.14),
+                ),
+                PlaymatTheme::plain(
+                    "slate",
+                    "Slate",
+                    Color::srgb(0.15, 0.16, 0.18),
+                    Color::srgb(0.25, 0.27, 0.3),
+                ),
+            ],
+            images: HashMap::new(),
+        }
+    }
+}
+
+impl CosmeticThemes {
+    /// Add a card back, replacing any with the same id
+    pub fn register_card_back(&mut self, theme: CardBackTheme) {
+        match self.card_backs.iter_mut().find(|back| back.id == theme.id) {
+            Some(existing) => *existing = theme,
+            None => self.card_backs.push(theme),
+        }
+    }
+
+    /// Add a playmat, replacing any with the same id
+    pub fn register_playmat(&mut self, theme: PlaymatTheme) {
+        match self.playmats.iter_mut().find(|mat| mat.id == theme.id) {
+            Some(existing) => *existing = theme,
+            None => self.playmats.push(theme),
+        }
+    }
+
+    /// The card back `id`, or the default one if it isn't registered
+    pub fn card_back(&self, id: Option<&ThemeId>) -> &CardBackTheme {
+        id.and_then(|id| self.card_backs.iter().find(|back| back.id == *id))
+            .unwrap_or(&self.card_backs[0])
+    }
+
+    /// The playmat `id`, or the default one if it isn't registered
+    pub fn playmat(&self, id: &ThemeId) -> &PlaymatTheme {
+        self.playmats
+            .iter()
+            .find(|mat| mat.id == *id)
+            .unwrap_or(&self.playmats[0])
+    }
+
+    /// The card back after `id`, for cycling through them in the settings menu
+    pub fn next_card_back(&self, id: &ThemeId) -> ThemeId {
+        let next = self
+            .card_backs
+            .iter()
+            .position(|back| back.id == *id)
+            .map_or(0, |index| (index + 1) % self.card_backs.len());
+        self.card_backs[next].id.clone()
+    }
+
+    /// The playmat after `id`, for cycling through them in the settings menu
+    pub fn next_playmat(&self, id: &ThemeId) -> ThemeId {
+        let next = self
+            .playmats
+            .iter()
+            .position(|mat| mat.id == *id)
+            .map_or(0, |index| (index + 1) % self.playmats.len());
+        self.playmats[next].id.clone()
+    }
+
+    /// The loaded image at `path`, or the plain white image if there's none
+    pub fn image(&self, path: Option<&str>) -> Handle<Image> {
+        path.and_then(|path| self.images.get(path))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Paths of theme images that haven't been loaded yet
+    pub fn unloaded_images(&self) -> Vec<String> {
+        let back_images = self.card_backs.iter().filter_map(|back| back.image.clone());
+        let mat_images = self.playmats.iter().filter_map(|mat| mat.image.clone());
+        let mut paths: Vec<String> = back_images
+            .chain(mat_images)
+            .filter(|path| !self.images.contains_key(path))
+            .collect();
+        paths.sort();
+        paths.dedup();
+        paths
+    }
+}
@@ -0,0 +1,64 @@
+//! Ban lists checked when validating Commander decks
+//!
+//! The official Commander banned list ships with the game as
+//! [`COMMANDER_BANNED`]. House lists are defined in the settings file and can
+//! add bans on top of it, lift some of its bans, or replace it entirely.
+
+use serde::{Deserialize, Serialize};
+
+use crate::cards::legality::{COMMANDER_BANNED, is_commander_banned};
+
+/// Name of the official Commander banned list
+pub const COMMANDER_BAN_LIST_NAME: &str = "Commander";
+
+fn default_true() -> bool {
+    true
+}
+
+/// A list of cards that may not be played
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BanList {
+    /// Name shown in the lobby and in violation messages
+    pub name: String,
+    /// Cards banned by this list
+    #[serde(default)]
+    pub banned: Vec<String>,
+    /// Cards allowed despite the Commander banned list
+    #[serde(default)]
+    pub unbanned: Vec<String>,
+    /// Whether the Commander banned list applies as well
+    #[serde(default = "default_true")]
+    pub include_commander: bool,
+}
+
+impl Default for BanList {
+    fn default() -> Self {
+        Self::commander()
+    }
+}
+
+impl BanList {
+    /// The official Commander banned list
+    pub fn commander() -> Self {
+        Self {
+            name: COMMANDER_BAN_LIST_NAME.to_string(),
+            banned: COMMANDER_BANNED
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+            unbanned: Vec::new(),
+            include_commander: false,
+        }
+    }
+
+    /// Whether a card is banned by this list, ignoring case
+    pub fn is_banned(&self, name: &str) -> bool {
+        let name = name.trim();
+        let listed = |names: &[String]| names.iter().any(|card| card.eq_ignore_ascii_case(name));
+
+        if listed(&self.banned) {
+            return true;
+        }
+        self.include_commander && is_commander_banned(name) && !listed(&self.unbanned)
+    }
+}
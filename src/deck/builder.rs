@@ -1,5 +1,7 @@
 use super::types::{Deck, DeckType};
 use crate::cards::Card;
+use crate::cards::database::{CardDatabase, CardDefinition, CardId};
+use crate::cards::search::SearchQuery;
 use bevy::prelude::*;
 
 /// Builder for creating decks
@@ -49,6 +51,37 @@ impl DeckBuilder {
         self
     }
 
+    /// Add `count` copies of a card from the database, ignoring unknown ids
+    #[allow(dead_code)]
+    pub fn add_from_database(mut self, database: &CardDatabase, id: CardId, count: usize) -> Self {
+        if let Some(card) = database.card(id) {
+            self.cards.extend(std::iter::repeat_n(card, count).cloned());
+        }
+        self
+    }
+
+    /// Cards in the database that may be played in the deck's format
+    #[allow(dead_code)]
+    pub fn legal_cards<'a>(
+        &self,
+        database: &'a CardDatabase,
+    ) -> impl Iterator<Item = &'a CardDefinition> + 'a {
+        let format = self.deck_type.as_ref().and_then(DeckType::format_name);
+        database
+            .iter()
+            .filter(move |definition| format.is_none_or(|format| definition.is_legal_in(format)))
+    }
+
+    /// Cards legal in the deck's format that match a search, best match first
+    #[allow(dead_code)]
+    pub fn search<'a>(
+        &self,
+        database: &'a CardDatabase,
+        query: &SearchQuery,
+    ) -> Vec<&'a CardDefinition> {
+        query.search(self.legal_cards(database))
+    }
+
     /// Add multiple copies of a card
     #[allow(dead_code)]
     pub fn add_copies(mut self, card: Card, count: usize) -> Self {
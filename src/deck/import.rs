@@ -0,0 +1,209 @@
+use std::fmt;
+
+use crate::cards::Card;
+use crate::cards::database::CardDatabase;
+use crate::cards::mtgjson::index::CardIndex;
+
+use super::types::{Deck, DeckType};
+
+/// Somewhere deck lists can look cards up by name
+pub trait CardLookup {
+    /// The card called `name`, ignoring case
+    fn find_card(&self, name: &str) -> Option<Card>;
+}
+
+impl CardLookup for CardDatabase {
+    fn find_card(&self, name: &str) -> Option<Card> {
+        self.find_by_name(name)
+            .map(|definition| definition.card.clone())
+    }
+}
+
+impl CardLookup for CardIndex {
+    fn find_card(&self, name: &str) -> Option<Card> {
+        self.card(name)
+    }
+}
+
+impl<T: CardLookup + ?Sized> CardLookup for &T {
+    fn find_card(&self, name: &str) -> Option<Card> {
+        (**self).find_card(name)
+    }
+}
+
+/// A source that may not be available, such as a card index that wasn't built
+impl<T: CardLookup> CardLookup for Option<T> {
+    fn find_card(&self, name: &str) -> Option<Card> {
+        self.as_ref().and_then(|lookup| lookup.find_card(name))
+    }
+}
+
+/// Looks cards up in the first source, then the second
+///
+/// Lets the in-memory database answer first and only fall back to the on-disk
+/// index for cards it doesn't know.
+impl<A: CardLookup, B: CardLookup> CardLookup for (A, B) {
+    fn find_card(&self, name: &str) -> Option<Card> {
+        self.0.find_card(name).or_else(|| self.1.find_card(name))
+    }
+}
+
+/// Part of a deck list a card belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeckSection {
+    Main,
+    Commander,
+    Sideboard,
+}
+
+impl DeckSection {
+    /// The section a header line like "Sideboard:" starts
+    fn from_header(line: &str) -> Option<Self> {
+        match line.trim_end_matches(':').trim().to_lowercase().as_str() {
+            "deck" | "main" | "mainboard" | "main deck" => Some(DeckSection::Main),
+            "commander" | "commanders" => Some(DeckSection::Commander),
+            "sideboard" | "side" | "maybeboard" => Some(DeckSection::Sideboard),
+            _ => None,
+        }
+    }
+}
+
+/// A line of a deck list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeckListEntry {
+    pub count: usize,
+    pub name: String,
+    pub section: DeckSection,
+}
+
+/// Why a deck list couldn't be imported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeckImportError {
+    /// A line isn't a card count and name
+    InvalidLine { line: usize, text: String },
+    /// Cards that couldn't be found by name
+    UnknownCards(Vec<String>),
+}
+
+impl fmt::Display for DeckImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeckImportError::InvalidLine { line, text } => {
+                write!(f, "line {} is not a card: {}", line, text)
+            }
+            DeckImportError::UnknownCards(names) => {
+                write!(f, "unknown cards: {}", names.join(", "))
+            }
+        }
+    }
+}
+
+/// A deck read from a deck list
+#[derive(Debug, Clone)]
+pub struct ImportedDeck {
+    /// The main deck, including the commanders
+    pub deck: Deck,
+    /// Cards listed under a commander header
+    pub commanders: Vec<Card>,
+    pub sideboard: Vec<Card>,
+}
+
+/// Parse a plain text deck list
+///
+/// Accepts the common export formats: one `4 Lightning Bolt` or
+/// `4x Lightning Bolt` per line, an optional `(SET) 123` printing after the
+/// name, `SB:` prefixes, section headers such as `Commander` or `Sideboard`,
+/// and `//` or `#` comments.
+pub fn parse_deck_list(text: &str) -> Result<Vec<DeckListEntry>, DeckImportError> {
+    let mut section = DeckSection::Main;
+    let mut entries = Vec::new();
+
+    for (index, raw_line) in text.lines().enumerate() {
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with("//") || line.starts_with('#') {
+            continue;
+        }
+        if let Some(header) = DeckSection::from_header(line) {
+            section = header;
+            continue;
+        }
+
+        let invalid = || DeckImportError::InvalidLine {
+            line: index + 1,
+            text: raw_line.to_string(),
+        };
+        let (line, line_section) = match line.strip_prefix("SB:") {
+            Some(rest) => (rest.trim(), DeckSection::Sideboard),
+            None => (line, section),
+        };
+
+        let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let (count, name) = if digits == 0 {
+            (1, line)
+        } else {
+            let count = line[..digits].parse().map_err(|_| invalid())?;
+            let rest = &line[digits..];
+            let rest = rest.strip_prefix(['x', 'X']).unwrap_or(rest);
+            (count, rest)
+        };
+
+        // Drop the printing, e.g. "Lightning Bolt (LEA) 161"
+        let name = name.split(" (").next().unwrap_or(name).trim();
+        if count == 0 || name.is_empty() {
+            return Err(invalid());
+        }
+
+        entries.push(DeckListEntry {
+            count,
+            name: name.to_string(),
+            section: line_section,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Read a deck list, looking every card up in `lookup`
+///
+/// Fails with every unknown card name at once, so they can all be fixed together.
+pub fn import_deck_list(
+    name: &str,
+    deck_type: DeckType,
+    text: &str,
+    lookup: &impl CardLookup,
+) -> Result<ImportedDeck, DeckImportError> {
+    let mut cards = Vec::new();
+    let mut commanders = Vec::new();
+    let mut sideboard = Vec::new();
+    let mut unknown = Vec::new();
+
+    for entry in parse_deck_list(text)? {
+        let Some(card) = lookup.find_card(&entry.name) else {
+            if !unknown.contains(&entry.name) {
+                unknown.push(entry.name);
+            }
+            continue;
+        };
+
+        let copies = std::iter::repeat_n(card, entry.count);
+        match entry.section {
+            DeckSection::Main => cards.extend(copies),
+            DeckSection::Commander => {
+                let copies: Vec<_> = copies.collect();
+                cards.extend(copies.iter().cloned());
+                commanders.extend(copies);
+            }
+            DeckSection::Sideboard => sideboard.extend(copies),
+        }
+    }
+
+    if !unknown.is_empty() {
+        return Err(DeckImportError::UnknownCards(unknown));
+    }
+
+    Ok(ImportedDeck {
+        deck: Deck::new(name.to_string(), deck_type, cards),
+        commanders,
+        sideboard,
+    })
+}
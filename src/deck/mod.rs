@@ -1,7 +1,16 @@
+pub mod ban_list;
 mod builder;
+mod import;
+mod precon;
 mod types;
 
-pub use types::{Deck, DeckType, PlayerDeck};
+pub use ban_list::{BanList, COMMANDER_BAN_LIST_NAME};
+pub use import::{
+    CardLookup, DeckImportError, DeckListEntry, DeckSection, ImportedDeck, import_deck_list,
+    parse_deck_list,
+};
+pub use precon::{PRECON_DECKS, PreconDeck, player_starting_deck, random_precons};
+pub use types::{Deck, DeckType, DeckValidationError, PlayerDeck};
 
 // Re-export any other types or functions that should be public
 
@@ -71,65 +80,34 @@ fn register_default_decks(_deck_registry: ResMut<DeckRegistry>) {
 // Get a collection of example cards that can be used to create a deck
 #[allow(dead_code)]
 pub fn get_example_cards(_owner: Entity) -> Vec<Card> {
-    let mut cards = Vec::new();
-
-    // Add some cards from Alpha
-    let (card, _, _, _, _, _, _) = sets::alpha::ancestral_recall::get_card();
-    cards.push(card);
-
-    cards.push(sets::alpha::counterspell::get_card());
-    cards.push(sets::alpha::fireball::get_card());
-    cards.push(sets::alpha::lightning_bolt::get_card());
-    cards.push(sets::alpha::time_walk::get_card());
-    cards.push(sets::alpha::wheel_of_fortune::get_card());
-
-    // Add some cards from Alliances
-    cards.push(sets::alliances::force_of_will::get_card());
-
-    // Add some cards from Legends
-    cards.push(sets::legends::mana_drain::get_card());
-
-    // Add some cards from Scourge
-    cards.push(sets::scourge::dragon_mage::get_card());
-
-    cards
+    [
+        // Alpha
+        "Ancestral Recall",
+        "Counterspell",
+        "Fireball",
+        "Lightning Bolt",
+        "Time Walk",
+        "Wheel of Fortune",
+        // Alliances
+        "Force of Will",
+        // Legends
+        "Mana Drain",
+        // Scourge
+        "Dragon Mage",
+    ]
+    .into_iter()
+    .filter_map(sets::find_card)
+    .collect()
 }
 
 // Create a deck containing all implemented cards for a player
 // This gives all players access to the full collection of cards
 #[allow(dead_code)]
 pub fn get_player_specific_cards() -> Vec<Card> {
-    let mut cards = Vec::new();
-
-    // Add some cards from Alpha
-    let (card, _, _, _, _, _, _) = sets::alpha::ancestral_recall::get_card();
-    cards.push(card);
-
-    cards.push(sets::alpha::counterspell::get_card());
-    cards.push(sets::alpha::fireball::get_card());
-    cards.push(sets::alpha::lightning_bolt::get_card());
-    cards.push(sets::alpha::shivan_dragon::get_card());
-    cards.push(sets::alpha::time_walk::get_card());
-    cards.push(sets::alpha::wheel_of_fortune::get_card());
-
-    // Add some cards from Alliances
-    cards.push(sets::alliances::force_of_will::get_card());
-
-    // Add some cards from Legends
-    cards.push(sets::legends::mana_drain::get_card());
-
-    // Add some cards from Scourge
-    cards.push(sets::scourge::dragon_mage::get_card());
-
-    // Add some cards from Innistrad: Midnight Hunt
-    cards.push(sets::innistrad_midnight_hunt::briarbridge_tracker::get_card());
-    cards.push(sets::innistrad_midnight_hunt::brutal_cathar::get_card());
-    cards.push(sets::innistrad_midnight_hunt::cathars_call::get_card());
-    cards.push(sets::innistrad_midnight_hunt::champion_of_the_perished::get_card());
-    cards.push(sets::innistrad_midnight_hunt::delver_of_secrets::get_card());
-    cards.push(sets::innistrad_midnight_hunt::moonveil_regent::get_card());
-
-    cards
+    sets::builtin_sets()
+        .iter()
+        .flat_map(|set| set.cards())
+        .collect()
 }
 
 // Return a shuffled deck of cards
@@ -143,19 +121,26 @@ pub fn get_shuffled_deck(owner: Entity) -> Deck {
     deck
 }
 
-// Return a player-specific shuffled deck of cards
-pub fn get_player_shuffled_deck(
-    _owner: Entity,
-    player_index: usize,
-    deck_name: Option<&str>,
-) -> Deck {
+// Return the deck a player starts with, before it is shuffled
+pub fn get_player_deck(player_index: usize, deck_name: Option<&str>) -> Deck {
     let cards = get_player_specific_cards();
 
     let name = deck_name
         .unwrap_or(&format!("Player {} Deck", player_index + 1))
         .to_string();
-    let mut deck = Deck::new(name, DeckType::Standard, cards);
+    Deck::new(name, DeckType::Standard, cards)
+}
 
+// Return a player-specific shuffled deck of cards
+pub fn get_player_shuffled_deck(
+    _owner: Entity,
+    player_index: usize,
+    deck_name: Option<&str>,
+) -> Deck {
+    let mut deck = get_player_deck(player_index, deck_name);
     deck.shuffle();
     deck
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,105 @@
+//! Preconstructed Commander decks bundled with the game
+//!
+//! The deck lists live in `assets/decks/precons` and are compiled in, so they
+//! are available on every platform. Their cards are looked up like any imported
+//! deck list; a precon whose cards can't all be found isn't played, and the
+//! player gets the default deck instead.
+
+use bevy::prelude::*;
+use rand::seq::IndexedRandom;
+
+use super::import::{CardLookup, DeckImportError, ImportedDeck, import_deck_list, parse_deck_list};
+use super::types::{Deck, DeckType};
+use super::{BanList, get_player_deck};
+
+/// A deck list bundled with the game
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PreconDeck {
+    pub name: &'static str,
+    /// The deck list, in the format read by [`parse_deck_list`]
+    pub list: &'static str,
+}
+
+/// Every bundled precon
+pub const PRECON_DECKS: &[PreconDeck] = &[
+    PreconDeck {
+        name: "Goblin Horde",
+        list: include_str!("../../assets/decks/precons/goblin_horde.txt"),
+    },
+    PreconDeck {
+        name: "Spell Storm",
+        list: include_str!("../../assets/decks/precons/spell_storm.txt"),
+    },
+    PreconDeck {
+        name: "Forest Titans",
+        list: include_str!("../../assets/decks/precons/forest_titans.txt"),
+    },
+];
+
+impl PreconDeck {
+    /// The bundled precon called `name`, ignoring case
+    pub fn find(name: &str) -> Option<&'static PreconDeck> {
+        PRECON_DECKS
+            .iter()
+            .find(|precon| precon.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Read the deck, looking every card up in `lookup`
+    pub fn load(&self, lookup: &impl CardLookup) -> Result<ImportedDeck, DeckImportError> {
+        import_deck_list(self.name, DeckType::Commander, self.list, lookup)
+    }
+
+    /// Names of the deck's cards on a ban list, each once, in list order
+    ///
+    /// Only needs the deck list, so the deck can be checked before its cards are
+    /// looked up.
+    pub fn banned_cards(&self, ban_list: &BanList) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        for entry in parse_deck_list(self.list).unwrap_or_default() {
+            if ban_list.is_banned(&entry.name) && !names.contains(&entry.name) {
+                names.push(entry.name);
+            }
+        }
+        names
+    }
+}
+
+/// Pick a random precon for each of `count` players
+///
+/// Players may get the same precon.
+pub fn random_precons(count: usize) -> Vec<String> {
+    let mut rng = rand::rng();
+    (0..count)
+        .filter_map(|_| PRECON_DECKS.choose(&mut rng))
+        .map(|precon| precon.name.to_string())
+        .collect()
+}
+
+/// The deck a player starts with, before it is shuffled
+///
+/// Their precon if one was picked for them and it could be loaded, otherwise
+/// the default deck.
+pub fn player_starting_deck(
+    player_index: usize,
+    precon: Option<&str>,
+    lookup: &impl CardLookup,
+) -> Deck {
+    let Some(name) = precon else {
+        return get_player_deck(player_index, None);
+    };
+    let loaded = PreconDeck::find(name)
+        .ok_or_else(|| format!("no precon called {}", name))
+        .and_then(|precon| precon.load(lookup).map_err(|e| e.to_string()));
+    match loaded {
+        Ok(imported) => imported.deck,
+        Err(e) => {
+            warn!(
+                "Player {} gets the default deck, the {} precon can't be played: {}",
+                player_index + 1,
+                name,
+                e
+            );
+            get_player_deck(player_index, None)
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use bevy::prelude::*;
+
+use crate::cards::mtgjson::index::{BulkFile, CardIndex};
+use crate::cards::{Card, CardDatabase, CardDetails, CardId, CardTypes, Legality};
+use crate::deck::builder::DeckBuilder;
+use crate::deck::types::DeckValidationError;
+use crate::deck::{
+    BanList, CardLookup, Deck, DeckImportError, DeckListEntry, DeckSection, DeckType, PRECON_DECKS,
+    PreconDeck, import_deck_list, parse_deck_list, player_starting_deck, random_precons,
+};
+use crate::mana::Mana;
+
+const ATOMIC_CARDS: &str = r#"{"data": {
+    "Grizzly Bears": [{"name": "Grizzly Bears", "manaCost": "{1}{G}", "types": ["Creature"], "power": "2", "toughness": "2"}]
+}}"#;
+
+#[test]
+fn test_deck_list_formats_are_parsed() {
+    let entries = parse_deck_list(
+        "// Burn\n\
+         4 Lightning Bolt\n\
+         2x Counterspell (LEA) 55\n\
+         Fireball\n\
+         \n\
+         SB: 1 Force of Will\n\
+         Commander:\n\
+         1 Dragon Mage\n",
+    )
+    .unwrap();
+
+    let entry = |count, name: &str, section| DeckListEntry {
+        count,
+        name: name.to_string(),
+        section,
+    };
+    assert_eq!(
+        entries,
+        vec![
+            entry(4, "Lightning Bolt", DeckSection::Main),
+            entry(2, "Counterspell", DeckSection::Main),
+            entry(1, "Fireball", DeckSection::Main),
+            entry(1, "Force of Will", DeckSection::Sideboard),
+            entry(1, "Dragon Mage", DeckSection::Commander),
+        ]
+    );
+
+    assert_eq!(
+        parse_deck_list("4 Lightning Bolt\n0 Counterspell"),
+        Err(DeckImportError::InvalidLine {
+            line: 2,
+            text: "0 Counterspell".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_import_falls_back_to_the_card_index() {
+    let database = CardDatabase::with_builtin_sets();
+    let dir = tempfile::tempdir().unwrap();
+    let index =
+        CardIndex::build(ATOMIC_CARDS.as_bytes(), BulkFile::AtomicCards, dir.path()).unwrap();
+
+    let imported = import_deck_list(
+        "Mixed",
+        DeckType::Commander,
+        "Commander\n1 Dragon Mage\nDeck\n4 Lightning Bolt\n2 Grizzly Bears\nSideboard\n1 Counterspell",
+        &(&database, &index),
+    )
+    .unwrap();
+
+    assert_eq!(imported.deck.card_count(), 7);
+    assert_eq!(imported.commanders.len(), 1);
+    assert_eq!(imported.commanders[0].name.name, "Dragon Mage");
+    assert_eq!(imported.sideboard.len(), 1);
+    assert!(!imported.deck.search("Grizzly Bears").is_empty());
+}
+
+#[test]
+fn test_import_reports_every_unknown_card() {
+    let database = CardDatabase::with_builtin_sets();
+
+    let result = import_deck_list(
+        "Typos",
+        DeckType::Standard,
+        "4 Lightning Blot\n1 Counterspell\n2 Lightning Blot\n1 Fireblal",
+        &database,
+    );
+
+    assert_eq!(
+        result.unwrap_err(),
+        DeckImportError::UnknownCards(vec!["Lightning Blot".to_string(), "Fireblal".to_string()])
+    );
+}
+
+fn basic_forest() -> Card {
+    Card::builder("Forest")
+        .cost(Mana::default())
+        .types(CardTypes::BASIC | CardTypes::LAND | CardTypes::FOREST)
+        .details(CardDetails::Other)
+        .build_or_panic()
+}
+
+fn copies(database: &CardDatabase, name: &str, count: usize) -> Vec<Card> {
+    let card = database.find_by_name(name).unwrap().card.clone();
+    std::iter::repeat_n(card, count).collect()
+}
+
+#[test]
+fn test_commander_banned_list_is_checked_without_legality_data() {
+    let database = CardDatabase::with_builtin_sets();
+    let mut cards = copies(&database, "Time Walk", 1);
+    cards.extend(copies(&database, "Counterspell", 1));
+    // Any number of basic lands is allowed
+    cards.extend(std::iter::repeat_n(basic_forest(), 98));
+    let mut deck = Deck::new("Banned".to_string(), DeckType::Commander, cards);
+    deck.set_commander(Entity::PLACEHOLDER);
+
+    let errors = deck.validate().unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        &errors[0],
+        DeckValidationError::BannedCards { list, cards }
+            if list == "Commander" && cards == &vec!["Time Walk".to_string()]
+    ));
+    assert_eq!(
+        errors[0].to_string(),
+        "Banned on the Commander list: Time Walk"
+    );
+    // Checking against the database doesn't report the same card twice
+    assert_eq!(deck.validate_in(&database).unwrap_err().len(), 1);
+}
+
+#[test]
+fn test_house_ban_list_adds_and_lifts_bans() {
+    let mut database = CardDatabase::with_builtin_sets();
+    database.set_legalities(
+        CardId::from_name("Time Walk"),
+        [("commander", Legality::Banned)].into_iter().collect(),
+    );
+    let mut cards = copies(&database, "Time Walk", 1);
+    cards.extend(copies(&database, "Counterspell", 1));
+    cards.extend(std::iter::repeat_n(basic_forest(), 98));
+    let mut deck = Deck::new("House".to_string(), DeckType::Commander, cards);
+    deck.set_commander(Entity::PLACEHOLDER);
+
+    let house = BanList {
+        name: "Kitchen Table".to_string(),
+        banned: vec!["counterspell".to_string()],
+        unbanned: vec!["Time Walk".to_string()],
+        include_commander: true,
+    };
+    assert!(house.is_banned("Black Lotus"));
+    assert!(!house.is_banned("Time Walk"));
+
+    // The house list overrides the legality data for Time Walk
+    let errors = deck.validate_in_with(&database, &house).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].to_string(),
+        "Banned on the Kitchen Table list: Counterspell"
+    );
+}
+
+#[test]
+fn test_legality_data_limits_restricted_and_banned_cards() {
+    let mut database = CardDatabase::with_builtin_sets();
+    database.set_legalities(
+        CardId::from_name("Ancestral Recall"),
+        [("vintage", Legality::Restricted)].into_iter().collect(),
+    );
+    database.set_legalities(
+        CardId::from_name("Lightning Bolt"),
+        [("vintage", Legality::Legal)].into_iter().collect(),
+    );
+    database.set_legalities(
+        CardId::from_name("Fireball"),
+        [("vintage", Legality::Banned)].into_iter().collect(),
+    );
+
+    let mut cards = copies(&database, "Ancestral Recall", 2);
+    cards.extend(copies(&database, "Lightning Bolt", 4));
+    cards.extend(copies(&database, "Fireball", 1));
+    cards.extend(std::iter::repeat_n(basic_forest(), 53));
+    let deck = Deck::new("Vintage".to_string(), DeckType::Vintage, cards);
+
+    assert!(deck.validate().is_ok());
+    let errors = deck.validate_in(&database).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().any(|error| matches!(
+        error,
+        DeckValidationError::IllegalCards(names) if names == &vec!["Fireball".to_string()]
+    )));
+    assert!(errors.iter().any(|error| matches!(
+        error,
+        DeckValidationError::TooManyCopies { card_name, max_allowed: 1, actual: 2 }
+            if card_name == "Ancestral Recall"
+    )));
+
+    let vintage_cards: Vec<_> = DeckBuilder::new()
+        .with_type(DeckType::Vintage)
+        .legal_cards(&database)
+        .map(|definition| definition.card.name.name.clone())
+        .collect();
+    assert!(vintage_cards.contains(&"Lightning Bolt".to_string()));
+    assert!(!vintage_cards.contains(&"Fireball".to_string()));
+}
+
+/// Knows every card, making basic lands of the basic land names
+struct EveryCard;
+
+impl CardLookup for EveryCard {
+    fn find_card(&self, name: &str) -> Option<Card> {
+        if ["Forest", "Island", "Mountain"].contains(&name) {
+            return Some(Card::new(
+                name,
+                Mana::default(),
+                CardTypes::BASIC | CardTypes::LAND,
+                CardDetails::Other,
+                "",
+            ));
+        }
+        Some(Card::new(
+            name,
+            Mana::default(),
+            CardTypes::ARTIFACT,
+            CardDetails::Other,
+            "",
+        ))
+    }
+}
+
+#[test]
+fn test_precons_are_legal_commander_decks() {
+    assert!(PRECON_DECKS.len() >= 3);
+    for precon in PRECON_DECKS {
+        let imported = precon.load(&EveryCard).unwrap();
+        assert_eq!(imported.commanders.len(), 1, "{}", precon.name);
+        assert_eq!(imported.deck.card_count(), 100, "{}", precon.name);
+        assert!(precon.banned_cards(&BanList::commander()).is_empty());
+
+        let mut deck = imported.deck;
+        deck.set_commander(Entity::PLACEHOLDER);
+        assert!(deck.validate().is_ok(), "{}", precon.name);
+    }
+    assert_eq!(
+        PreconDeck::find("goblin horde").map(|precon| precon.name),
+        Some("Goblin Horde")
+    );
+}
+
+#[test]
+fn test_players_get_their_precon_or_the_default_deck() {
+    let picked = random_precons(4);
+    assert_eq!(picked.len(), 4);
+    assert!(picked.iter().all(|name| PreconDeck::find(name).is_some()));
+
+    let deck = player_starting_deck(0, Some("Spell Storm"), &EveryCard);
+    assert_eq!(deck.name, "Spell Storm");
+    assert_eq!(deck.deck_type, DeckType::Commander);
+
+    // The built-in sets don't have every card of a precon
+    let database = CardDatabase::with_builtin_sets();
+    let deck = player_starting_deck(1, Some("Spell Storm"), &database);
+    assert_eq!(deck.name, "Player 2 Deck");
+    let deck = player_starting_deck(1, Some("No Such Deck"), &EveryCard);
+    assert_eq!(deck.name, "Player 2 Deck");
+    let deck = player_starting_deck(2, None, &EveryCard);
+    assert_eq!(deck.name, "Player 3 Deck");
+}
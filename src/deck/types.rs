@@ -1,8 +1,12 @@
-use crate::cards::Card;
+use super::ban_list::BanList;
+use crate::cards::database::{CardDatabase, CardDefinition, CardId};
+use crate::cards::legality::Legality;
+use crate::cards::{Card, CardTypes};
 use bevy::prelude::*;
 use rand::seq::SliceRandom;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 
 /// Represents a deck of Magic cards
 #[derive(Debug, Clone)]
@@ -78,14 +82,35 @@ pub enum DeckType {
     Custom(String),
 }
 
+impl DeckType {
+    /// The MTGJSON name of the format, used to look up card legality
+    ///
+    /// Limited and custom formats have no banned list.
+    pub fn format_name(&self) -> Option<&'static str> {
+        match self {
+            DeckType::Standard => Some("standard"),
+            DeckType::Commander => Some("commander"),
+            DeckType::Modern => Some("modern"),
+            DeckType::Legacy => Some("legacy"),
+            DeckType::Vintage => Some("vintage"),
+            DeckType::Pauper => Some("pauper"),
+            DeckType::Pioneer => Some("pioneer"),
+            DeckType::Brawl => Some("brawl"),
+            DeckType::Limited | DeckType::Custom(_) => None,
+        }
+    }
+}
+
 /// Errors that can occur during deck validation
 #[derive(Debug)]
 #[allow(dead_code)]
 pub enum DeckValidationError {
     /// Deck doesn't have enough cards
     TooFewCards { required: usize, actual: usize },
-    /// Deck has illegal cards (e.g., banned cards)
+    /// Deck has cards that aren't legal in its format
     IllegalCards(Vec<String>),
+    /// Deck has cards on the ban list it is checked against
+    BannedCards { list: String, cards: Vec<String> },
     /// Deck has too many copies of a card
     TooManyCopies {
         card_name: String,
@@ -100,6 +125,38 @@ pub enum DeckValidationError {
     OtherError(String),
 }
 
+impl fmt::Display for DeckValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeckValidationError::TooFewCards { required, actual } => {
+                write!(f, "Deck has {} cards, needs at least {}", actual, required)
+            }
+            DeckValidationError::IllegalCards(cards) => {
+                write!(f, "Not legal in this format: {}", cards.join(", "))
+            }
+            DeckValidationError::BannedCards { list, cards } => {
+                write!(f, "Banned on the {} list: {}", list, cards.join(", "))
+            }
+            DeckValidationError::TooManyCopies {
+                card_name,
+                max_allowed,
+                actual,
+            } => write!(
+                f,
+                "{} copies of {}, at most {} allowed",
+                actual, card_name, max_allowed
+            ),
+            DeckValidationError::ColorIdentityViolation(cards) => write!(
+                f,
+                "Outside the commander's color identity: {}",
+                cards.join(", ")
+            ),
+            DeckValidationError::MissingCommander => f.write_str("Deck has no commander"),
+            DeckValidationError::OtherError(message) => f.write_str(message),
+        }
+    }
+}
+
 impl Deck {
     /// Create a new deck
     pub fn new(name: String, deck_type: DeckType, cards: Vec<Card>) -> Self {
@@ -137,9 +194,23 @@ impl Deck {
         self.cards.len()
     }
 
-    /// Validate the deck against format rules
+    /// Ids of the cards in the deck, in order
+    ///
+    /// Lets the deck be stored or sent without its card definitions.
+    #[allow(dead_code)]
+    pub fn card_ids(&self) -> Vec<CardId> {
+        self.cards.iter().map(CardId::of).collect()
+    }
+
+    /// Validate the deck against format rules and the Commander banned list
     #[allow(dead_code)]
     pub fn validate(&self) -> Result<(), Vec<DeckValidationError>> {
+        self.validate_with(&BanList::commander())
+    }
+
+    /// Validate the deck against format rules, checking Commander decks
+    /// against `ban_list`
+    pub fn validate_with(&self, ban_list: &BanList) -> Result<(), Vec<DeckValidationError>> {
         let mut errors = Vec::new();
 
         // Check minimum deck size
@@ -167,10 +238,26 @@ impl Deck {
             errors.push(DeckValidationError::MissingCommander);
         }
 
+        // Check the ban list, which applies even without legality data
+        if self.deck_type == DeckType::Commander {
+            let banned = self.banned_cards(ban_list);
+            if !banned.is_empty() {
+                errors.push(DeckValidationError::BannedCards {
+                    list: ban_list.name.clone(),
+                    cards: banned,
+                });
+            }
+        }
+
         // Check for too many copies of a card
         if self.deck_type != DeckType::Limited {
             let mut card_counts: HashMap<String, usize> = HashMap::new();
-            for card in &self.cards {
+            // Basic lands are exempt from the copy limit
+            for card in self
+                .cards
+                .iter()
+                .filter(|card| !card.type_info.types.contains(CardTypes::BASIC))
+            {
                 *card_counts.entry(card.name.name.clone()).or_insert(0) += 1;
             }
 
@@ -198,6 +285,101 @@ impl Deck {
         }
     }
 
+    /// Validate the deck, also checking each card's legality in the database
+    ///
+    /// Cards the database doesn't know are only checked against the format rules.
+    /// Restricted cards are limited to a single copy.
+    #[allow(dead_code)]
+    pub fn validate_in(&self, database: &CardDatabase) -> Result<(), Vec<DeckValidationError>> {
+        self.validate_in_with(database, &BanList::commander())
+    }
+
+    /// Validate the deck like [`Deck::validate_in`], checking Commander decks
+    /// against `ban_list`
+    ///
+    /// The ban list has the final say on which cards are banned in Commander,
+    /// so house lists can lift bans from the legality data.
+    pub fn validate_in_with(
+        &self,
+        database: &CardDatabase,
+        ban_list: &BanList,
+    ) -> Result<(), Vec<DeckValidationError>> {
+        let mut errors = self.validate_with(ban_list).err().unwrap_or_default();
+        let Some(format) = self.deck_type.format_name() else {
+            return if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(errors)
+            };
+        };
+
+        let definition = |card: &Card| database.get(CardId::of(card));
+        // Without legality data a Commander card is only illegal when banned
+        let decided_by_ban_list = |definition: &CardDefinition| {
+            self.deck_type == DeckType::Commander
+                && (definition.legalities.is_empty()
+                    || definition.legalities.get(format) == Some(Legality::Banned))
+        };
+        let illegal = self.distinct_card_names(|card| {
+            definition(card).is_some_and(|definition| {
+                !definition.is_legal_in(format) && !decided_by_ban_list(definition)
+            })
+        });
+        // Banned cards were already reported by `validate_with`
+        let already_reported = |name: &String| {
+            errors.iter().any(|error| {
+                matches!(error, DeckValidationError::BannedCards { cards, .. } if cards.contains(name))
+            })
+        };
+        let illegal: Vec<_> = illegal
+            .into_iter()
+            .filter(|name| !already_reported(name))
+            .collect();
+        if !illegal.is_empty() {
+            errors.push(DeckValidationError::IllegalCards(illegal));
+        }
+
+        for name in self.distinct_card_names(|card| {
+            definition(card).and_then(|definition| definition.legalities.get(format))
+                == Some(Legality::Restricted)
+        }) {
+            let actual = self
+                .cards
+                .iter()
+                .filter(|card| card.name.name == name)
+                .count();
+            if actual > 1 {
+                errors.push(DeckValidationError::TooManyCopies {
+                    card_name: name,
+                    max_allowed: 1,
+                    actual,
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Names of the deck's cards on a ban list, each once, in deck order
+    pub fn banned_cards(&self, ban_list: &BanList) -> Vec<String> {
+        self.distinct_card_names(|card| ban_list.is_banned(&card.name.name))
+    }
+
+    /// Names of the cards matching `predicate`, each once, in deck order
+    fn distinct_card_names(&self, predicate: impl Fn(&Card) -> bool) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        for card in self.cards.iter().filter(|card| predicate(card)) {
+            if !names.contains(&card.name.name) {
+                names.push(card.name.name.clone());
+            }
+        }
+        names
+    }
+
     /// Shuffle the deck
     pub fn shuffle(&mut self) {
         use rand::SeedableRng;
@@ -1,78 +1,196 @@
+use crate::cards::scripting::CardScript;
 use crate::cards::{Card, CardCost, CardTypeInfo, CardTypes};
-use crate::game_engine::state::GameState;
+use crate::game_engine::costs::{CostModifier, spell_cost};
+use crate::game_engine::enforcement::RulesEnforcementLevel;
+use crate::game_engine::permanent::{PermanentController, PermanentState};
+use crate::game_engine::scripting::{ScriptedEffect, resolve_primitives};
+use crate::game_engine::state::{GameEvent, GameState};
+use crate::game_engine::zones::{Zone, ZoneChangeEvent, ZoneManager};
 use crate::game_engine::{GameStack, Phase, PrioritySystem};
 use crate::player::Player;
 use bevy::prelude::*;
 
 use super::types::GameAction;
 use super::validation::{
-    can_pay_mana, is_instant_cast, valid_time_for_sorcery, valid_time_to_play_land,
+    can_pay_mana, can_play_from_zone, is_instant_cast, valid_time_for_sorcery,
+    valid_time_to_play_land,
 };
 
 /// System for validating and processing game actions
+///
+/// Actions the rules don't allow are rejected, or allowed with a warning,
+/// depending on the [`RulesEnforcementLevel`]. Spells and abilities of cards
+/// defined as data go on the stack as [`ScriptedEffect`]s, except mana
+/// abilities, which resolve right away (rule 605.3a).
+#[allow(clippy::too_many_arguments)]
 pub fn process_game_actions(
-    _commands: Commands,
-    mut game_state: ResMut<GameState>,
-    _stack: ResMut<GameStack>,
+    mut commands: Commands,
+    game_state: Res<GameState>,
+    mut stack: ResMut<GameStack>,
     mut priority: ResMut<PrioritySystem>,
     phase: Res<Phase>,
     mut game_action_events: EventReader<GameAction>,
     _player_query: Query<&Player>,
     card_query: Query<(&Card, &CardTypeInfo, &CardCost)>,
+    cost_modifiers: Query<(&CostModifier, &PermanentController)>,
+    enforcement: Option<Res<RulesEnforcementLevel>>,
+    zones: Option<Res<ZoneManager>>,
+    scripts: Query<&CardScript>,
+    mut permanents: Query<&mut PermanentState>,
 ) {
+    let enforcement = enforcement.as_deref().copied().unwrap_or_default();
+    let from_zone = |player: Entity, card: Entity| {
+        zones
+            .as_deref()
+            .is_none_or(|zones| can_play_from_zone(zones, player, card, game_state.turn_number))
+    };
+
     // Process game actions from the event queue
     for action in game_action_events.read() {
         match action {
             GameAction::PlayLand { player, land_card } => {
-                // Check if it's a valid time to play a land
-                if valid_time_to_play_land(&game_state, &phase, *player) {
-                    // Check if the player has already played a land this turn
-                    if game_state.can_play_land(*player) {
-                        // Check if the card is actually a land
-                        if let Ok((_, card_type_info, _)) = card_query.get(*land_card) {
-                            if card_type_info.types.contains(CardTypes::LAND) {
-                                // Mark that the player has played a land this turn
-                                game_state.record_land_played(*player);
-                                // In a full implementation, you would move the land from hand to battlefield
-                                info!("Land played successfully");
-                            }
-                        }
-                    }
-                } else {
-                    warn!("Not a valid time to play a land");
+                // Only lands can be played as lands, whatever the enforcement level
+                let is_land = card_query
+                    .get(*land_card)
+                    .is_ok_and(|(_, card_type_info, _)| {
+                        card_type_info.types.contains(CardTypes::LAND)
+                    });
+                if !is_land {
+                    continue;
+                }
+
+                // Lands are played in your own main phase, once per turn
+                let legal = valid_time_to_play_land(&game_state, &phase, *player)
+                    && game_state.can_play_land(*player)
+                    && from_zone(*player, *land_card);
+                if enforcement.allows(legal, format_args!("land play by {:?}", player)) {
+                    // Mark that the player has played a land this turn
+                    commands.send_event(GameEvent::LandPlayed { player: *player });
+                    // In a full implementation, you would move the land from hand to battlefield
+                    info!("Land played successfully");
                 }
             }
 
             GameAction::CastSpell {
                 player,
                 spell_card,
-                targets: _,
+                targets,
                 mana_payment: _,
             } => {
-                // Check if it's a valid time to cast this spell
-                if let Ok((_, card_type_info, card_cost)) = card_query.get(*spell_card) {
-                    let is_instant = is_instant_cast(card_type_info);
-                    if is_instant || valid_time_for_sorcery(&game_state, &phase, &_stack, *player) {
-                        // In a full implementation, check if the player can pay the cost
-                        if let Ok(player_entity) = _player_query.get(*player) {
-                            if can_pay_mana(player_entity, &card_cost.cost) {
-                                // In a full implementation, you would move the spell to the stack
-                                info!("Spell cast successfully");
-                            }
-                        }
+                let Ok((card, card_type_info, card_cost)) = card_query.get(*spell_card) else {
+                    continue;
+                };
+                // Check if it's a valid time to cast this spell and the player can pay for it
+                let is_instant = is_instant_cast(card_type_info);
+                let cost = spell_cost(
+                    &card_cost.cost,
+                    card_type_info.types,
+                    *player,
+                    cost_modifiers
+                        .iter()
+                        .map(|(modifier, controller)| (modifier, controller.player)),
+                );
+                let legal = (is_instant
+                    || valid_time_for_sorcery(&game_state, &phase, &stack, *player))
+                    && from_zone(*player, *spell_card)
+                    && _player_query
+                        .get(*player)
+                        .is_ok_and(|player| can_pay_mana(player, &cost));
+                if enforcement.allows(legal, format_args!("spell cast by {:?}", player)) {
+                    // In a full implementation, you would move the spell to the stack
+                    info!("Spell cast successfully");
+                    if let Some(script) = scripts
+                        .get(*spell_card)
+                        .ok()
+                        .filter(|script| !script.effects.is_empty())
+                    {
+                        stack.push(
+                            Box::new(ScriptedEffect {
+                                name: card.name.name.clone(),
+                                source: *spell_card,
+                                controller: *player,
+                                targets: targets.clone(),
+                                effects: script.effects.clone(),
+                            }),
+                            *spell_card,
+                            false,
+                            true,
+                        );
                     }
                 }
             }
 
             GameAction::ActivateAbility {
-                player: _player,
-                source: _source,
-                ability_index: _ability_index,
-                targets: _,
+                player,
+                source,
+                ability_index,
+                targets,
                 mana_payment: _,
             } => {
-                // Similar to cast spell, but for abilities
-                // Would check activation restrictions, costs, etc.
+                // Only abilities of cards defined as data are activated so far
+                let Some(ability) = scripts
+                    .get(*source)
+                    .ok()
+                    .and_then(|script| script.activated.get(*ability_index))
+                else {
+                    continue;
+                };
+                let is_tapped = permanents.get(*source).is_ok_and(|state| state.is_tapped);
+                let legal = !(ability.cost.tap && is_tapped)
+                    && _player_query
+                        .get(*player)
+                        .is_ok_and(|player| can_pay_mana(player, &ability.cost.mana_cost()));
+                if !enforcement.allows(legal, format_args!("ability activated by {:?}", player)) {
+                    continue;
+                }
+
+                // Pay the tap and sacrifice parts of the cost
+                if let Some(mut state) = permanents
+                    .get_mut(*source)
+                    .ok()
+                    .filter(|_| ability.cost.tap)
+                {
+                    state.is_tapped = true;
+                }
+                if let Some(owner) = zones
+                    .as_deref()
+                    .and_then(|zones| zones.get_card_owner(*source))
+                    .filter(|_| ability.cost.sacrifice)
+                {
+                    commands.send_event(ZoneChangeEvent {
+                        card: *source,
+                        owner,
+                        source: Zone::Battlefield,
+                        destination: Zone::Graveyard,
+                        was_visible: true,
+                        is_visible: true,
+                    });
+                }
+                if ability.is_mana_ability() {
+                    resolve_primitives(
+                        &mut commands,
+                        *source,
+                        *player,
+                        targets.clone(),
+                        ability.effects.clone(),
+                    );
+                    continue;
+                }
+                let entity = commands
+                    .spawn(Name::new(format!("Ability: {}", ability.text)))
+                    .id();
+                stack.push(
+                    Box::new(ScriptedEffect {
+                        name: ability.text.clone(),
+                        source: *source,
+                        controller: *player,
+                        targets: targets.clone(),
+                        effects: ability.effects.clone(),
+                    }),
+                    entity,
+                    false,
+                    true,
+                );
             }
 
             GameAction::PassPriority { player } => {
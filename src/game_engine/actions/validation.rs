@@ -1,6 +1,9 @@
-use crate::cards::{CardTypeInfo, CardTypes};
+use crate::cards::keywords::KeywordAbility;
+use crate::cards::{Card, CardTypeInfo, CardTypes};
+use crate::game_engine::permanent::PermanentState;
 use crate::game_engine::phase::{PostcombatStep, PrecombatStep};
 use crate::game_engine::state::GameState;
+use crate::game_engine::zones::{Zone, ZoneManager};
 use crate::game_engine::{GameStack, Phase};
 use crate::mana::Mana;
 use crate::player::Player;
@@ -46,6 +49,22 @@ pub fn valid_time_for_sorcery(
     true
 }
 
+/// Checks if a player may play a card from the zone it's in
+///
+/// A card in exile can only be played by its owner while the effect that
+/// exiled it allows.
+pub fn can_play_from_zone(zones: &ZoneManager, player: Entity, card: Entity, turn: u32) -> bool {
+    match zones.get_card_zone(card) {
+        Some(Zone::Exile) => {
+            zones.get_card_owner(card) == Some(player)
+                && zones
+                    .exiled_by(card)
+                    .is_some_and(|exiled_by| exiled_by.may_play(turn))
+        }
+        _ => true,
+    }
+}
+
 /// Checks if a card can be cast at instant speed
 pub fn is_instant_cast(card_type_info: &CardTypeInfo) -> bool {
     card_type_info.types.contains(CardTypes::INSTANT) ||
@@ -58,3 +77,42 @@ pub fn can_pay_mana(_player: &Player, _cost: &Mana) -> bool {
     // Placeholder implementation
     true
 }
+
+/// Timing and tap cost of an activated ability
+pub struct AbilityRequirements {
+    pub taps: bool,
+    pub sorcery_speed: bool,
+}
+
+/// Non-mana activated abilities written as "Cost: Effect." in rules text
+///
+/// Only costs starting with a mana or tap symbol are recognised. Mana abilities
+/// are left out, as they don't use the stack.
+pub fn text_abilities(rules_text: &str) -> impl Iterator<Item = AbilityRequirements> + '_ {
+    rules_text.lines().filter_map(|line| {
+        let (cost, effect) = line.split_once(": ")?;
+        if !cost.trim_start().starts_with('{') || effect.trim_start().starts_with("Add ") {
+            return None;
+        }
+        Some(AbilityRequirements {
+            taps: cost.contains("{T}"),
+            sorcery_speed: effect.contains("Activate only as a sorcery"),
+        })
+    })
+}
+
+/// Whether a permanent can pay a {T} cost this turn
+pub fn can_tap_now(card: &Card, state: &PermanentState) -> bool {
+    let is_creature = card.type_info.types.contains(CardTypes::CREATURE);
+    state.can_tap(is_creature)
+        || (!state.is_tapped && Card::has_keyword(card, KeywordAbility::Haste))
+}
+
+/// Whether a permanent taps for mana, like a land or a mana creature
+pub fn taps_for_mana(card: &Card) -> bool {
+    card.type_info.types.contains(CardTypes::LAND)
+        || card.rules_text.rules_text.lines().any(|line| {
+            line.split_once(": ")
+                .is_some_and(|(cost, effect)| cost.contains("{T}") && effect.starts_with("Add "))
+        })
+}
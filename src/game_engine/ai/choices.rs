@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use super::AiPlayer;
+use crate::game_engine::choices::{AnswerChoiceEvent, ChoiceAnswer, ChoiceKind, PendingChoices};
+
+/// The answer an AI player gives to a choice
+///
+/// "May" abilities are nearly always worth using, so AI players say yes to
+/// them, and otherwise take the same answer a player who runs out of time gets.
+pub fn choose_answer(kind: &ChoiceKind) -> ChoiceAnswer {
+    match kind {
+        ChoiceKind::YesNo => ChoiceAnswer::YesNo(true),
+        kind => kind.default_answer(),
+    }
+}
+
+/// Have an AI player answer the choice being asked of them
+pub fn ai_answer_choices(
+    choices: Res<PendingChoices>,
+    ai_players: Query<(), With<AiPlayer>>,
+    mut answers: EventWriter<AnswerChoiceEvent>,
+) {
+    let Some(current) = choices.current() else {
+        return;
+    };
+    if ai_players.contains(current.request.player) {
+        answers.write(AnswerChoiceEvent {
+            player: current.request.player,
+            id: current.id,
+            answer: choose_answer(&current.request.kind),
+        });
+    }
+}
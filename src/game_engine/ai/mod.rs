@@ -0,0 +1,73 @@
+//! Decision making for computer-controlled players
+//!
+//! AI players weigh their opponents with a threat model: how close each one is
+//! to dying, including to commander damage, how much they have on the
+//! battlefield and where they stand politically, through deals, goad and the
+//! monarch or initiative. The resulting [`ThreatAssessment`]s pick who to attack
+//! ([`choose_attack_target`]) and what to remove first ([`removal_priority`]).
+//!
+//! How much each factor counts is set by [`ThreatWeights`]. [`AiPersonalities`]
+//! holds the weights for each AI player, so they can be tuned or given one of
+//! the preset personalities.
+//!
+//! Players marked [`AiPlayer`] also take part in politics: they vote in votes
+//! using the threat model, accept deals worth more to them than they cost, now
+//! and then offer an opponent a non-aggression deal, and hold a grudge against
+//! anyone who attacks them, which makes deals with that player worth less.
+//!
+//! They also answer the choices the game asks of them straight away, with
+//! [`choose_answer`], so the game doesn't wait on them.
+
+mod choices;
+mod politics;
+mod threat;
+
+#[cfg(test)]
+mod tests;
+
+pub use choices::{ai_answer_choices, choose_answer};
+pub use politics::{
+    AiPolitics, DEAL_PROPOSAL_INTERVAL, GRUDGE_PENALTY, PROPOSED_DEAL_TURNS, ai_answer_deals,
+    ai_cast_votes, ai_propose_deals, choose_deal_partner, choose_vote, deal_utility,
+    record_ai_grudges,
+};
+pub use threat::{
+    AiPersonalities, BoardPermanent, OpponentStatus, ThreatAssessment, ThreatBoard, ThreatState,
+    ThreatWeights, assess_threats, choose_attack_target, removal_priority,
+};
+
+use bevy::prelude::*;
+
+use crate::game_engine::choices::handle_choice_answers;
+use crate::game_engine::game_state_condition;
+use crate::game_engine::politics::{deal_system, voting_system};
+use crate::menu::state::GameMenuState;
+
+/// A player whose decisions are made by the computer
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AiPlayer;
+
+/// Forget AI players' grudges when returning to the main menu
+pub fn clear_ai_politics(mut ai_politics: ResMut<AiPolitics>) {
+    *ai_politics = AiPolitics::default();
+}
+
+/// Register the AI players' settings and political behavior
+pub fn register_ai_systems(app: &mut App) {
+    app.init_resource::<AiPersonalities>()
+        .init_resource::<AiPolitics>()
+        .add_systems(
+            Update,
+            (
+                record_ai_grudges,
+                // After the politics systems have taken in last frame's answers
+                ai_cast_votes.after(voting_system),
+                ai_answer_deals.after(deal_system),
+                ai_propose_deals,
+                // Answered in the same frame the choice is asked
+                ai_answer_choices.before(handle_choice_answers),
+            )
+                .run_if(game_state_condition),
+        )
+        .add_systems(OnEnter(GameMenuState::MainMenu), clear_ai_politics);
+}
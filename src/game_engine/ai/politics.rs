@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use super::AiPlayer;
+use super::threat::{
+    AiPersonalities, ThreatAssessment, ThreatState, ThreatWeights, assess_threats,
+};
+use crate::game_engine::combat::AttackerDeclaredEvent;
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::politics::{
+    Deal, DealDuration, DealProposedEvent, DealResponseEvent, DealTerm, PoliticsSystem, Vote,
+    VoteCastEvent, VoteChoice,
+};
+use crate::game_engine::turns::TurnStartEvent;
+
+/// AI players consider proposing a deal on every turn of theirs divisible by this
+pub const DEAL_PROPOSAL_INTERVAL: u32 = 3;
+/// Turns the non-aggression deals AI players propose last
+pub const PROPOSED_DEAL_TURNS: u32 = 2;
+/// How much less a deal is worth per attack its other party made on the AI player
+pub const GRUDGE_PENALTY: f32 = 10.0;
+
+/// What AI players remember about the other players
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct AiPolitics {
+    /// Creatures each opponent attacked an AI player with, as (AI player, opponent)
+    pub grudges: HashMap<(Entity, Entity), u32>,
+}
+
+impl AiPolitics {
+    /// Creatures `opponent` has attacked `ai_player` with
+    pub fn grudge(&self, ai_player: Entity, opponent: Entity) -> u32 {
+        self.grudges
+            .get(&(ai_player, opponent))
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+/// The choice an AI player votes for
+///
+/// Choices naming a player go against the most threatening opponent, and never
+/// against the AI player itself. Otherwise it sides with the choice leading so
+/// far, or the first one.
+pub fn choose_vote(
+    vote: &Vote,
+    ai_player: Entity,
+    assessments: &[ThreatAssessment],
+    politics: &PoliticsSystem,
+) -> Option<VoteChoice> {
+    let against_threat = assessments.iter().find_map(|assessment| {
+        vote.choices
+            .iter()
+            .find(|choice| choice.target == Some(assessment.player))
+    });
+    if let Some(choice) = against_threat {
+        return Some(choice.clone());
+    }
+
+    let counts = politics.vote_counts();
+    let choices = || {
+        vote.choices
+            .iter()
+            .filter(|choice| choice.target != Some(ai_player))
+    };
+    choices()
+        .max_by_key(|choice| counts.get(&choice.id).copied().unwrap_or(0))
+        .filter(|choice| counts.contains_key(&choice.id))
+        .or_else(|| choices().next())
+        .cloned()
+}
+
+/// How much a deal is worth to an AI player; deals worth more than nothing are
+/// accepted
+///
+/// Not attacking each other is worth the other party's board, less what the AI
+/// player gives up by sparing an opponent close to dying. Targeting or
+/// defending against someone is worth their threat. Anything else is declined.
+pub fn deal_utility(
+    deal: &Deal,
+    ai_player: Entity,
+    assessments: &[ThreatAssessment],
+    weights: &ThreatWeights,
+    grudge: u32,
+) -> f32 {
+    let other = if deal.proposer == ai_player {
+        deal.target
+    } else {
+        deal.proposer
+    };
+    let threat = |player: Entity| {
+        assessments
+            .iter()
+            .find(|assessment| assessment.player == player)
+    };
+    let Some(partner) = threat(other) else {
+        return 0.0;
+    };
+
+    let protection = partner.board_power as f32 * weights.board_power;
+    let opportunity = partner.life_lost as f32 * weights.life_lost
+        + partner.commander_damage as f32 * weights.commander_damage;
+    let terms: f32 = deal
+        .terms
+        .iter()
+        .map(|term| match term {
+            DealTerm::DoNotAttack(_) | DealTerm::Truce(_) => protection - opportunity,
+            DealTerm::TargetOtherPlayer { target, .. } => {
+                threat(*target).map_or(-1.0, |target| target.score - partner.score)
+            }
+            DealTerm::SharedDefense { against, .. } => {
+                threat(*against).map_or(-1.0, |against| against.score)
+            }
+            DealTerm::AllowAction { .. } | DealTerm::Custom(_) => -1.0,
+        })
+        .sum();
+    terms - grudge as f32 * GRUDGE_PENALTY
+}
+
+/// The opponent an AI player offers not to attack, if any
+///
+/// The one with the most power on the battlefield, leaving out the AI player's
+/// preferred attack target, anyone who has attacked it and anyone it already
+/// has a deal with.
+pub fn choose_deal_partner(
+    ai_player: Entity,
+    assessments: &[ThreatAssessment],
+    ai_politics: &AiPolitics,
+    politics: &PoliticsSystem,
+) -> Option<Entity> {
+    let has_deal = |player: Entity| {
+        politics
+            .pending_deals
+            .iter()
+            .chain(politics.active_deals.iter())
+            .any(|deal| {
+                (deal.proposer == ai_player && deal.target == player)
+                    || (deal.proposer == player && deal.target == ai_player)
+            })
+    };
+    assessments
+        .iter()
+        .skip(1)
+        .filter(|assessment| {
+            assessment.board_power > 0
+                && ai_politics.grudge(ai_player, assessment.player) == 0
+                && !has_deal(assessment.player)
+        })
+        .max_by_key(|assessment| assessment.board_power)
+        .map(|assessment| assessment.player)
+}
+
+/// Remember who attacked AI players
+pub fn record_ai_grudges(
+    mut attacks: EventReader<AttackerDeclaredEvent>,
+    ai_players: Query<(), With<AiPlayer>>,
+    controllers: Query<&PermanentController>,
+    mut ai_politics: ResMut<AiPolitics>,
+) {
+    for attack in attacks.read() {
+        if !ai_players.contains(attack.defender) {
+            continue;
+        }
+        let Ok(controller) = controllers.get(attack.attacker) else {
+            continue;
+        };
+        *ai_politics
+            .grudges
+            .entry((attack.defender, controller.player))
+            .or_default() += 1;
+    }
+}
+
+/// Have AI players vote in the active vote
+pub fn ai_cast_votes(
+    politics: Res<PoliticsSystem>,
+    ai_players: Query<Entity, With<AiPlayer>>,
+    threats: ThreatState,
+    personalities: Res<AiPersonalities>,
+    mut votes: EventWriter<VoteCastEvent>,
+) {
+    let Some(vote) = &politics.active_vote else {
+        return;
+    };
+    for ai_player in ai_players.iter() {
+        if !vote.eligible_voters.contains(&ai_player)
+            || politics.votes_cast.contains_key(&ai_player)
+        {
+            continue;
+        }
+        let assessments = assess_threats(
+            &threats.board(ai_player),
+            personalities.weights_for(ai_player),
+        );
+        if let Some(choice) = choose_vote(vote, ai_player, &assessments, &politics) {
+            votes.write(VoteCastEvent {
+                vote_id: vote.id,
+                player: ai_player,
+                choice,
+            });
+        }
+    }
+}
+
+/// Have AI players accept or reject the deals proposed to them
+pub fn ai_answer_deals(
+    politics: Res<PoliticsSystem>,
+    ai_players: Query<Entity, With<AiPlayer>>,
+    threats: ThreatState,
+    personalities: Res<AiPersonalities>,
+    ai_politics: Res<AiPolitics>,
+    mut responses: EventWriter<DealResponseEvent>,
+) {
+    for deal in &politics.pending_deals {
+        let ai_player = deal.target;
+        if !ai_players.contains(ai_player) {
+            continue;
+        }
+        let weights = personalities.weights_for(ai_player);
+        let assessments = assess_threats(&threats.board(ai_player), weights);
+        let utility = deal_utility(
+            deal,
+            ai_player,
+            &assessments,
+            weights,
+            ai_politics.grudge(ai_player, deal.proposer),
+        );
+        responses.write(DealResponseEvent {
+            deal_id: deal.id,
+            accepted: utility > 0.0,
+            responder: ai_player,
+        });
+    }
+}
+
+/// Have AI players now and then offer an opponent not to attack each other
+pub fn ai_propose_deals(
+    mut turns: EventReader<TurnStartEvent>,
+    ai_players: Query<(), With<AiPlayer>>,
+    politics: Res<PoliticsSystem>,
+    threats: ThreatState,
+    personalities: Res<AiPersonalities>,
+    ai_politics: Res<AiPolitics>,
+    mut proposals: EventWriter<DealProposedEvent>,
+) {
+    for turn in turns.read() {
+        if !ai_players.contains(turn.player) || turn.turn_number % DEAL_PROPOSAL_INTERVAL != 0 {
+            continue;
+        }
+        let assessments = assess_threats(
+            &threats.board(turn.player),
+            personalities.weights_for(turn.player),
+        );
+        let Some(partner) = choose_deal_partner(turn.player, &assessments, &ai_politics, &politics)
+        else {
+            continue;
+        };
+        info!("{:?} offers {:?} not to attack", turn.player, partner);
+        proposals.write(DealProposedEvent {
+            deal: Deal::builder(turn.player, partner)
+                .add_term(DealTerm::DoNotAttack(PROPOSED_DEAL_TURNS))
+                .duration(DealDuration::Turns(PROPOSED_DEAL_TURNS))
+                .build(),
+        });
+    }
+}
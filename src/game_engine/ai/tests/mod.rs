@@ -0,0 +1,390 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+
+use crate::cards::{Card, CardDetails, CardTypes, CreatureCard, CreatureType};
+use crate::game_engine::ai::{
+    AiPersonalities, AiPlayer, AiPolitics, BoardPermanent, GRUDGE_PENALTY, OpponentStatus,
+    ThreatBoard, ThreatState, ThreatWeights, ai_answer_choices, ai_answer_deals, assess_threats,
+    choose_attack_target, choose_deal_partner, choose_vote, deal_utility, record_ai_grudges,
+    removal_priority,
+};
+use crate::game_engine::choices::{
+    AnswerChoiceEvent, ChoiceAnswer, ChoiceKind, ChoiceRequest, PendingChoices,
+    handle_choice_answers,
+};
+use crate::game_engine::combat::AttackerDeclaredEvent;
+use crate::game_engine::commander::Commander;
+use crate::game_engine::damage::{DamageKind, DamageLedger, DamageRecord};
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::politics::{
+    Deal, DealResponseEvent, DealStatus, DealTerm, GoadEffect, PoliticsSystem, Vote, VoteChoice,
+};
+use crate::game_engine::zones::ZoneManager;
+use crate::mana::Mana;
+use crate::player::Player;
+
+fn opponent(player: Entity, life: i32) -> OpponentStatus {
+    OpponentStatus {
+        player,
+        life,
+        commander_damage: 0,
+        crowned: false,
+        deals: 0,
+    }
+}
+
+fn creature(permanent: Entity, controller: Entity, power: i32) -> BoardPermanent {
+    BoardPermanent {
+        permanent,
+        controller,
+        power: Some(power),
+        is_commander: false,
+    }
+}
+
+#[test]
+fn test_threats_weigh_life_commander_damage_board_and_politics() {
+    let mut world = World::new();
+    let [bob, carol, dave, bear, dragon, angel] = [(); 6].map(|_| world.spawn_empty().id());
+    let mut board = ThreatBoard {
+        starting_life: 40,
+        opponents: vec![opponent(bob, 40), opponent(carol, 40), opponent(dave, 40)],
+        permanents: vec![
+            creature(bear, bob, 2),
+            creature(dragon, carol, 5),
+            creature(angel, dave, 4),
+        ],
+        goaded_by_ai: Vec::new(),
+    };
+    let weights = ThreatWeights::default();
+    let players = |board: &ThreatBoard, weights: &ThreatWeights| -> Vec<Entity> {
+        assess_threats(board, weights)
+            .iter()
+            .map(|assessment| assessment.player)
+            .collect()
+    };
+    assert_eq!(players(&board, &weights), vec![carol, dave, bob]);
+
+    // Bob is close to dying to commander damage, Carol has a deal with us
+    board.opponents[0].commander_damage = 18;
+    board.opponents[1].deals = 1;
+    assert_eq!(players(&board, &weights), vec![bob, dave, carol]);
+
+    // Dave's angel was goaded by us, and the cautious AI ignores Bob's wounds
+    board.goaded_by_ai.push(angel);
+    let assessments = assess_threats(&board, &ThreatWeights::cautious());
+    assert_eq!(assessments[0].player, bob);
+    assert_eq!(assessments[1].goaded, 1);
+    assert_eq!(assessments[1].score, 7.5);
+}
+
+#[test]
+fn test_attack_targets_and_removal_follow_the_threats() {
+    let mut world = World::new();
+    let [bob, carol, bear, dragon, commander] = [(); 5].map(|_| world.spawn_empty().id());
+    let mut board = ThreatBoard {
+        starting_life: 40,
+        opponents: vec![opponent(bob, 10), opponent(carol, 40)],
+        permanents: vec![
+            creature(bear, bob, 2),
+            creature(dragon, carol, 5),
+            BoardPermanent {
+                is_commander: true,
+                ..creature(commander, carol, 3)
+            },
+        ],
+        goaded_by_ai: Vec::new(),
+    };
+    let weights = ThreatWeights::aggressive();
+    let assessments = assess_threats(&board, &weights);
+    assert_eq!(choose_attack_target(&assessments, |_| true), Some(bob));
+    // Someone the AI can't attack is skipped
+    assert_eq!(
+        choose_attack_target(&assessments, |player| player != bob),
+        Some(carol)
+    );
+    assert_eq!(choose_attack_target(&assessments, |_| false), None);
+
+    // Bob's wounds make even his bear the first thing to remove
+    assert_eq!(
+        removal_priority(&board, &assessments, &weights),
+        vec![bear, commander, dragon]
+    );
+    board.opponents[0].life = 40;
+    let weights = ThreatWeights::default();
+    let assessments = assess_threats(&board, &weights);
+    assert_eq!(
+        removal_priority(&board, &assessments, &weights),
+        vec![commander, dragon, bear]
+    );
+}
+
+#[test]
+fn test_threat_board_is_read_from_the_game() {
+    let mut world = World::new();
+    let alice = world.spawn(Player::new("Alice")).id();
+    let bob = world
+        .spawn(Player::new("Bob").with_life(30).with_player_index(1))
+        .id();
+    let card = Card::new(
+        "Grizzly Bears",
+        Mana::default(),
+        CardTypes::CREATURE,
+        CardDetails::Creature(CreatureCard {
+            power: 2,
+            toughness: 2,
+            creature_type: CreatureType::NONE,
+        }),
+        "",
+    );
+    let bear = world
+        .spawn((card, PermanentController::new(bob), Commander::default()))
+        .id();
+
+    let mut zones = ZoneManager::default();
+    zones.init_player_zones(bob);
+    zones.add_to_battlefield(bob, bear);
+    world.insert_resource(zones);
+    let mut ledger = DamageLedger::default();
+    ledger.record(DamageRecord {
+        source: bear,
+        target: bob,
+        amount: 7,
+        kind: DamageKind::Combat,
+        turn: 1,
+        source_is_commander: true,
+    });
+    world.insert_resource(ledger);
+    let mut politics = PoliticsSystem {
+        monarch: Some(bob),
+        ..default()
+    };
+    politics.active_deals.push(
+        Deal::builder(alice, bob)
+            .add_term(DealTerm::DoNotAttack(2))
+            .status(DealStatus::Accepted)
+            .build(),
+    );
+    politics
+        .goad_effects
+        .insert(bear, vec![GoadEffect::builder(bear, alice).build()]);
+    world.insert_resource(politics);
+
+    let board = world
+        .run_system_once(move |state: ThreatState| state.board(alice))
+        .unwrap();
+    assert_eq!(
+        board.opponents,
+        vec![OpponentStatus {
+            player: bob,
+            life: 30,
+            commander_damage: 7,
+            crowned: true,
+            deals: 1,
+        }]
+    );
+    assert_eq!(
+        board.permanents,
+        vec![BoardPermanent {
+            permanent: bear,
+            controller: bob,
+            power: Some(2),
+            is_commander: true,
+        }]
+    );
+    assert_eq!(board.goaded_by_ai, vec![bear]);
+    assert_eq!(board.starting_life, 40);
+}
+
+#[test]
+fn test_votes_go_against_the_biggest_threat() {
+    let mut world = World::new();
+    let [alice, bob, carol, dragon, source] = [(); 5].map(|_| world.spawn_empty().id());
+    let board = ThreatBoard {
+        starting_life: 40,
+        opponents: vec![opponent(bob, 40), opponent(carol, 40)],
+        permanents: vec![creature(dragon, carol, 5)],
+        goaded_by_ai: Vec::new(),
+    };
+    let assessments = assess_threats(&board, &ThreatWeights::default());
+    let against = |id, player| VoteChoice::builder(id, "Exile").target(player).build();
+    let mut politics = PoliticsSystem::default();
+
+    let vote = Vote::builder("Council's Judgment", alice, source)
+        .choices(vec![against(0, alice), against(1, bob), against(2, carol)])
+        .build();
+    let choice = choose_vote(&vote, alice, &assessments, &politics).unwrap();
+    assert_eq!(choice.target, Some(carol));
+
+    // Without players to vote against, go with the choice leading so far
+    let vote = Vote::builder("Expropriate", bob, source)
+        .choices(vec![
+            VoteChoice::new(0, "Time"),
+            VoteChoice::new(1, "Money"),
+        ])
+        .build();
+    assert_eq!(
+        choose_vote(&vote, alice, &assessments, &politics)
+            .unwrap()
+            .id,
+        0
+    );
+    politics.votes_cast.insert(bob, VoteChoice::new(1, "Money"));
+    assert_eq!(
+        choose_vote(&vote, alice, &assessments, &politics)
+            .unwrap()
+            .id,
+        1
+    );
+}
+
+#[test]
+fn test_deals_are_worth_the_partners_board_less_grudges() {
+    let mut world = World::new();
+    let [alice, bob, carol, dragon, bear] = [(); 5].map(|_| world.spawn_empty().id());
+    let mut board = ThreatBoard {
+        starting_life: 40,
+        opponents: vec![opponent(bob, 40), opponent(carol, 40)],
+        permanents: vec![creature(dragon, bob, 5), creature(bear, carol, 2)],
+        goaded_by_ai: Vec::new(),
+    };
+    let weights = ThreatWeights::default();
+    let truce = Deal::builder(bob, alice)
+        .add_term(DealTerm::DoNotAttack(2))
+        .build();
+
+    let assessments = assess_threats(&board, &weights);
+    assert_eq!(deal_utility(&truce, alice, &assessments, &weights, 0), 5.0);
+    assert_eq!(
+        deal_utility(&truce, alice, &assessments, &weights, 1),
+        5.0 - GRUDGE_PENALTY
+    );
+    let vague = Deal::builder(bob, alice)
+        .add_term(DealTerm::Custom("Owe me one".to_string()))
+        .build();
+    assert!(deal_utility(&vague, alice, &assessments, &weights, 0) < 0.0);
+
+    // Sparing Bob isn't worth it once he is close to dying
+    board.opponents[0].life = 20;
+    let assessments = assess_threats(&board, &weights);
+    assert!(deal_utility(&truce, alice, &assessments, &weights, 0) < 0.0);
+
+    // Alice offers peace to the biggest board that isn't her target
+    let mut ai_politics = AiPolitics::default();
+    let mut politics = PoliticsSystem::default();
+    assert_eq!(assessments[0].player, bob);
+    assert_eq!(
+        choose_deal_partner(alice, &assessments, &ai_politics, &politics),
+        Some(carol)
+    );
+    politics
+        .pending_deals
+        .push(Deal::builder(carol, alice).build());
+    assert_eq!(
+        choose_deal_partner(alice, &assessments, &ai_politics, &politics),
+        None
+    );
+    politics.pending_deals.clear();
+    ai_politics.grudges.insert((alice, carol), 1);
+    assert_eq!(
+        choose_deal_partner(alice, &assessments, &ai_politics, &politics),
+        None
+    );
+}
+
+#[test]
+fn test_ai_players_turn_down_deals_from_their_attackers() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<AttackerDeclaredEvent>()
+        .add_event::<DealResponseEvent>()
+        .init_resource::<AiPersonalities>()
+        .init_resource::<AiPolitics>()
+        .init_resource::<PoliticsSystem>()
+        .init_resource::<ZoneManager>()
+        .add_systems(Update, (record_ai_grudges, ai_answer_deals).chain());
+    let alice = app.world_mut().spawn((Player::new("Alice"), AiPlayer)).id();
+    let bob = app
+        .world_mut()
+        .spawn(Player::new("Bob").with_player_index(1))
+        .id();
+    let dragon = app
+        .world_mut()
+        .spawn((
+            Card::new(
+                "Shivan Dragon",
+                Mana::default(),
+                CardTypes::CREATURE,
+                CardDetails::Creature(CreatureCard {
+                    power: 5,
+                    toughness: 5,
+                    creature_type: CreatureType::NONE,
+                }),
+                "",
+            ),
+            PermanentController::new(bob),
+        ))
+        .id();
+    let mut zones = app.world_mut().resource_mut::<ZoneManager>();
+    zones.init_player_zones(bob);
+    zones.add_to_battlefield(bob, dragon);
+
+    let propose = |app: &mut App| {
+        let deal = Deal::builder(bob, alice)
+            .add_term(DealTerm::DoNotAttack(2))
+            .build();
+        let id = deal.id;
+        let mut politics = app.world_mut().resource_mut::<PoliticsSystem>();
+        politics.pending_deals = vec![deal];
+        app.update();
+        let responses: Vec<DealResponseEvent> = app
+            .world_mut()
+            .resource_mut::<Events<DealResponseEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].deal_id, id);
+        assert_eq!(responses[0].responder, alice);
+        responses[0].accepted
+    };
+    assert!(propose(&mut app));
+
+    app.world_mut().send_event(AttackerDeclaredEvent {
+        attacker: dragon,
+        defender: alice,
+    });
+    assert!(!propose(&mut app));
+    assert_eq!(app.world().resource::<AiPolitics>().grudge(alice, bob), 1);
+}
+
+#[test]
+fn test_ai_players_answer_their_choices_straight_away() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<AnswerChoiceEvent>()
+        .init_resource::<PendingChoices>()
+        .add_systems(Update, ai_answer_choices.before(handle_choice_answers))
+        .add_systems(Update, handle_choice_answers);
+    let alice = app.world_mut().spawn((Player::new("Alice"), AiPlayer)).id();
+    let bob = app.world_mut().spawn(Player::new("Bob")).id();
+    let ask = |app: &mut App, player: Entity| {
+        app.world_mut()
+            .resource_mut::<PendingChoices>()
+            .push(ChoiceRequest {
+                player,
+                source: None,
+                prompt: "Draw a card?".to_string(),
+                kind: ChoiceKind::YesNo,
+            })
+    };
+
+    let id = ask(&mut app, alice);
+    app.update();
+    let mut choices = app.world_mut().resource_mut::<PendingChoices>();
+    assert_eq!(choices.take_answer(id), Some(ChoiceAnswer::YesNo(true)));
+
+    // Human players are left to answer for themselves
+    let id = ask(&mut app, bob);
+    app.update();
+    assert!(app.world().resource::<PendingChoices>().is_pending(id));
+}
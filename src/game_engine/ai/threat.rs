@@ -0,0 +1,347 @@
+use std::collections::HashMap;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::{Card, CardDetails, CardTypes};
+use crate::game_engine::commander::Commander;
+use crate::game_engine::damage::DamageLedger;
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::politics::{DealStatus, DealTerm, PoliticsSystem};
+use crate::game_engine::state::GameState;
+use crate::game_engine::zones::ZoneManager;
+use crate::player::Player;
+
+/// How much each factor counts towards an opponent's threat
+///
+/// Positive weights make an opponent more of a target, negative ones less.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ThreatWeights {
+    /// Per point of life the opponent has lost, favoring players close to dying
+    pub life_lost: f32,
+    /// Per point of the most commander damage the opponent has taken from one commander
+    pub commander_damage: f32,
+    /// Per point of power among the opponent's creatures
+    pub board_power: f32,
+    /// Per permanent the opponent controls
+    pub permanents: f32,
+    /// For being the monarch or having the initiative
+    pub crowned: f32,
+    /// Per active deal the opponent has with the AI player
+    pub deals: f32,
+    /// Per creature of the opponent the AI player goaded, which attacks someone else
+    pub goaded: f32,
+    /// Per point of power when ranking permanents to remove
+    pub removal_power: f32,
+    /// For a commander when ranking permanents to remove
+    pub removal_commander: f32,
+}
+
+impl Default for ThreatWeights {
+    fn default() -> Self {
+        Self {
+            life_lost: 0.5,
+            commander_damage: 1.0,
+            board_power: 1.0,
+            permanents: 0.25,
+            crowned: 4.0,
+            deals: -15.0,
+            goaded: -1.0,
+            removal_power: 1.0,
+            removal_commander: 5.0,
+        }
+    }
+}
+
+impl ThreatWeights {
+    /// Goes after whoever is closest to dying
+    pub fn aggressive() -> Self {
+        Self {
+            life_lost: 1.5,
+            commander_damage: 2.0,
+            board_power: 0.5,
+            ..default()
+        }
+    }
+
+    /// Keeps the biggest boards in check and leaves weakened players alone
+    pub fn cautious() -> Self {
+        Self {
+            life_lost: 0.0,
+            commander_damage: 0.5,
+            board_power: 2.0,
+            permanents: 0.5,
+            ..default()
+        }
+    }
+
+    /// Honors its deals and goes after whoever wears the crown
+    pub fn political() -> Self {
+        Self {
+            crowned: 10.0,
+            deals: -50.0,
+            goaded: -3.0,
+            ..default()
+        }
+    }
+}
+
+/// Threat weights for each AI player, tunable to give them personalities
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct AiPersonalities {
+    /// Weights for AI players without their own
+    pub default: ThreatWeights,
+    pub players: HashMap<Entity, ThreatWeights>,
+}
+
+impl AiPersonalities {
+    /// The weights an AI player assesses threats with
+    pub fn weights_for(&self, player: Entity) -> &ThreatWeights {
+        self.players.get(&player).unwrap_or(&self.default)
+    }
+}
+
+/// A permanent on the battlefield, as threat assessment sees it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoardPermanent {
+    pub permanent: Entity,
+    pub controller: Entity,
+    /// Power if it's a creature
+    pub power: Option<i32>,
+    pub is_commander: bool,
+}
+
+/// An opponent of the AI player
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpponentStatus {
+    pub player: Entity,
+    pub life: i32,
+    /// The most combat damage the opponent has taken from a single commander
+    pub commander_damage: u32,
+    /// Whether the opponent is the monarch or has the initiative
+    pub crowned: bool,
+    /// Active deals between the opponent and the AI player
+    pub deals: usize,
+}
+
+/// Everything threat assessment looks at, from one AI player's point of view
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ThreatBoard {
+    pub starting_life: i32,
+    pub opponents: Vec<OpponentStatus>,
+    pub permanents: Vec<BoardPermanent>,
+    /// Creatures the AI player goaded
+    pub goaded_by_ai: Vec<Entity>,
+}
+
+/// How threatening an opponent is, and why
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThreatAssessment {
+    pub player: Entity,
+    pub score: f32,
+    pub life_lost: i32,
+    pub commander_damage: u32,
+    pub board_power: i32,
+    pub permanents: usize,
+    pub goaded: usize,
+}
+
+/// Score every opponent, most threatening first
+pub fn assess_threats(board: &ThreatBoard, weights: &ThreatWeights) -> Vec<ThreatAssessment> {
+    let mut assessments: Vec<ThreatAssessment> = board
+        .opponents
+        .iter()
+        .map(|opponent| {
+            let controlled = || {
+                board
+                    .permanents
+                    .iter()
+                    .filter(|permanent| permanent.controller == opponent.player)
+            };
+            let life_lost = (board.starting_life - opponent.life).max(0);
+            let board_power = controlled()
+                .filter_map(|permanent| permanent.power)
+                .map(|power| power.max(0))
+                .sum();
+            let permanents = controlled().count();
+            let goaded = controlled()
+                .filter(|permanent| board.goaded_by_ai.contains(&permanent.permanent))
+                .count();
+            let score = life_lost as f32 * weights.life_lost
+                + opponent.commander_damage as f32 * weights.commander_damage
+                + board_power as f32 * weights.board_power
+                + permanents as f32 * weights.permanents
+                + if opponent.crowned {
+                    weights.crowned
+                } else {
+                    0.0
+                }
+                + opponent.deals as f32 * weights.deals
+                + goaded as f32 * weights.goaded;
+            ThreatAssessment {
+                player: opponent.player,
+                score,
+                life_lost,
+                commander_damage: opponent.commander_damage,
+                board_power,
+                permanents,
+                goaded,
+            }
+        })
+        .collect();
+    assessments.sort_by(|a, b| b.score.total_cmp(&a.score));
+    assessments
+}
+
+/// The opponent to attack: the most threatening one the AI player may attack
+pub fn choose_attack_target(
+    assessments: &[ThreatAssessment],
+    can_attack: impl Fn(Entity) -> bool,
+) -> Option<Entity> {
+    assessments
+        .iter()
+        .map(|assessment| assessment.player)
+        .find(|&player| can_attack(player))
+}
+
+/// Opponents' permanents in the order the AI player would rather remove them
+///
+/// A permanent is worth its own power and commander status plus the threat of
+/// the opponent controlling it.
+pub fn removal_priority(
+    board: &ThreatBoard,
+    assessments: &[ThreatAssessment],
+    weights: &ThreatWeights,
+) -> Vec<Entity> {
+    let mut ranked: Vec<(Entity, f32)> = board
+        .permanents
+        .iter()
+        .filter_map(|permanent| {
+            let threat = assessments
+                .iter()
+                .find(|assessment| assessment.player == permanent.controller)?;
+            let value = permanent.power.unwrap_or(0).max(0) as f32 * weights.removal_power
+                + if permanent.is_commander {
+                    weights.removal_commander
+                } else {
+                    0.0
+                };
+            Some((permanent.permanent, value + threat.score))
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked.into_iter().map(|(permanent, _)| permanent).collect()
+}
+
+/// Accepted deals keeping `a` and `b` from attacking each other
+fn deal_between(a: Entity, b: Entity, politics: &PoliticsSystem) -> usize {
+    politics
+        .active_deals
+        .iter()
+        .filter(|deal| {
+            deal.status == DealStatus::Accepted
+                && ((deal.proposer == a && deal.target == b)
+                    || (deal.proposer == b && deal.target == a))
+                && deal
+                    .terms
+                    .iter()
+                    .any(|term| matches!(term, DealTerm::DoNotAttack(_) | DealTerm::Truce(_)))
+        })
+        .count()
+}
+
+/// Gathers the [`ThreatBoard`] for an AI player from the game
+#[derive(SystemParam)]
+pub struct ThreatState<'w, 's> {
+    players: Query<'w, 's, (Entity, &'static Player)>,
+    permanents: Query<'w, 's, (&'static Card, &'static PermanentController, Has<Commander>)>,
+    zones: Option<Res<'w, ZoneManager>>,
+    ledger: Option<Res<'w, DamageLedger>>,
+    politics: Option<Res<'w, PoliticsSystem>>,
+    game_state: Option<Res<'w, GameState>>,
+}
+
+impl ThreatState<'_, '_> {
+    /// The game as `ai_player` sees it, leaving out eliminated players
+    pub fn board(&self, ai_player: Entity) -> ThreatBoard {
+        let eliminated = |player: &Entity| {
+            self.game_state
+                .as_ref()
+                .is_some_and(|state| state.eliminated_players.contains(player))
+        };
+        let opponents = self
+            .players
+            .iter()
+            .filter(|(entity, _)| *entity != ai_player && !eliminated(entity))
+            .map(|(entity, player)| OpponentStatus {
+                player: entity,
+                life: player.life,
+                commander_damage: self
+                    .ledger
+                    .as_ref()
+                    .and_then(|ledger| ledger.commander_damage_to(entity).map(|(_, d)| d).max())
+                    .unwrap_or(0),
+                crowned: self.politics.as_ref().is_some_and(|politics| {
+                    politics.monarch == Some(entity) || politics.initiative_holder == Some(entity)
+                }),
+                deals: self
+                    .politics
+                    .as_ref()
+                    .map_or(0, |politics| deal_between(ai_player, entity, politics)),
+            })
+            .collect();
+
+        let permanents = self
+            .zones
+            .as_ref()
+            .map(|zones| {
+                zones
+                    .battlefield()
+                    .iter()
+                    .filter_map(|&permanent| {
+                        let (card, controller, is_commander) =
+                            self.permanents.get(permanent).ok()?;
+                        let power = match &card.details.details {
+                            CardDetails::Creature(creature)
+                                if card.type_info.types.contains(CardTypes::CREATURE) =>
+                            {
+                                Some(creature.power)
+                            }
+                            _ => None,
+                        };
+                        Some(BoardPermanent {
+                            permanent,
+                            controller: controller.player,
+                            power,
+                            is_commander,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let goaded_by_ai = self
+            .politics
+            .as_ref()
+            .map(|politics| {
+                politics
+                    .goad_effects
+                    .iter()
+                    .filter(|(_, effects)| effects.iter().any(|effect| effect.source == ai_player))
+                    .map(|(creature, _)| *creature)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        ThreatBoard {
+            starting_life: self
+                .game_state
+                .as_ref()
+                .map_or(40, |state| state.starting_life),
+            opponents,
+            permanents,
+            goaded_by_ai,
+        }
+    }
+}
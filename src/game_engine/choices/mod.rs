@@ -0,0 +1,244 @@
+//! Choices the game waits on a player to make
+//!
+//! Systems that need a player's input in the middle of an action, like a choice
+//! made as a spell resolves, push a [`ChoiceRequest`] onto [`PendingChoices`]
+//! and get back a [`ChoiceId`]. While a choice is unanswered, the game logic in
+//! `FixedUpdate` is paused by the [`no_pending_choice`] run condition.
+//!
+//! Whoever controls the player answers with an [`AnswerChoiceEvent`], whether
+//! that's the HUD, an AI player or a remote client. Answers that don't fit the
+//! choice are ignored, and the [`ChoiceKind::default_answer`] is given once
+//! [`CHOICE_TIMEOUT_SECS`] have passed. The game then resumes, and the system
+//! that asked takes its answer with [`PendingChoices::take_answer`].
+//!
+//! Choices are asked one at a time in the order they were pushed.
+
+use std::collections::{HashMap, VecDeque};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::menu::state::GameMenuState;
+
+/// Seconds a player has to answer a choice before it's answered for them
+pub const CHOICE_TIMEOUT_SECS: f32 = 30.0;
+
+/// Identifies a choice pushed onto [`PendingChoices`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ChoiceId(pub u64);
+
+/// What a player is asked to choose
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChoiceKind {
+    /// One of these options, by index
+    Option(Vec<String>),
+    /// From `min` to `max` different entities out of `options`
+    Entities {
+        options: Vec<Entity>,
+        min: usize,
+        max: usize,
+    },
+    /// A number from `min` to `max`, like a value for X
+    Number { min: u64, max: u64 },
+    /// Yes or no, like whether to use a "may" ability
+    YesNo,
+}
+
+/// A player's answer to a choice
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChoiceAnswer {
+    Option(usize),
+    Entities(Vec<Entity>),
+    Number(u64),
+    YesNo(bool),
+}
+
+impl ChoiceKind {
+    /// Whether `answer` answers this choice
+    pub fn accepts(&self, answer: &ChoiceAnswer) -> bool {
+        match (self, answer) {
+            (ChoiceKind::Option(options), ChoiceAnswer::Option(option)) => *option < options.len(),
+            (ChoiceKind::Entities { options, min, max }, ChoiceAnswer::Entities(chosen)) => {
+                (*min..=*max).contains(&chosen.len())
+                    && chosen.iter().enumerate().all(|(index, entity)| {
+                        options.contains(entity) && !chosen[..index].contains(entity)
+                    })
+            }
+            (ChoiceKind::Number { min, max }, ChoiceAnswer::Number(number)) => {
+                (*min..=*max).contains(number)
+            }
+            (ChoiceKind::YesNo, ChoiceAnswer::YesNo(_)) => true,
+            _ => false,
+        }
+    }
+
+    /// The answer given when the player doesn't answer in time: the first
+    /// option, the first entities needed, the lowest number, or no
+    pub fn default_answer(&self) -> ChoiceAnswer {
+        match self {
+            ChoiceKind::Option(_) => ChoiceAnswer::Option(0),
+            ChoiceKind::Entities { options, min, .. } => {
+                ChoiceAnswer::Entities(options.iter().take(*min).copied().collect())
+            }
+            ChoiceKind::Number { min, .. } => ChoiceAnswer::Number(*min),
+            ChoiceKind::YesNo => ChoiceAnswer::YesNo(false),
+        }
+    }
+}
+
+/// A choice for a player to make
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChoiceRequest {
+    /// The player who chooses
+    pub player: Entity,
+    /// The card, spell or ability asking, if any
+    pub source: Option<Entity>,
+    pub prompt: String,
+    pub kind: ChoiceKind,
+}
+
+/// A choice waiting for its answer
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingChoice {
+    pub id: ChoiceId,
+    pub request: ChoiceRequest,
+    /// Seconds spent waiting for the player so far
+    pub waited: f32,
+}
+
+impl PendingChoice {
+    /// Seconds left before the choice is answered for the player
+    pub fn remaining_secs(&self) -> f32 {
+        (CHOICE_TIMEOUT_SECS - self.waited).max(0.0)
+    }
+}
+
+/// Sent by a player to answer a choice
+#[derive(Event, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnswerChoiceEvent {
+    pub player: Entity,
+    pub id: ChoiceId,
+    pub answer: ChoiceAnswer,
+}
+
+/// Choices waiting to be answered, and answers waiting to be taken
+///
+/// Each choice goes from waiting in [`PendingChoices::waiting`] to answered in
+/// [`PendingChoices::answered`], and is forgotten once its answer is taken.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct PendingChoices {
+    /// Choices not yet answered; only the first is being asked
+    pub waiting: VecDeque<PendingChoice>,
+    /// Answers not yet taken by the systems that asked
+    pub answered: HashMap<ChoiceId, ChoiceAnswer>,
+    next_id: u64,
+}
+
+impl PendingChoices {
+    /// Ask a player to make a choice, pausing the game until they do
+    pub fn push(&mut self, request: ChoiceRequest) -> ChoiceId {
+        let id = ChoiceId(self.next_id);
+        self.next_id += 1;
+        self.waiting.push_back(PendingChoice {
+            id,
+            request,
+            waited: 0.0,
+        });
+        id
+    }
+
+    /// The choice being asked, if any
+    pub fn current(&self) -> Option<&PendingChoice> {
+        self.waiting.front()
+    }
+
+    /// Whether the game is waiting for a choice
+    pub fn is_waiting(&self) -> bool {
+        !self.waiting.is_empty()
+    }
+
+    /// Whether choice `id` is still waiting for its answer
+    pub fn is_pending(&self, id: ChoiceId) -> bool {
+        self.waiting.iter().any(|choice| choice.id == id)
+    }
+
+    /// Answer the choice being asked, if `answer` is `player`'s and fits it
+    pub fn answer(&mut self, player: Entity, id: ChoiceId, answer: ChoiceAnswer) -> bool {
+        let fits = self.current().is_some_and(|current| {
+            current.id == id
+                && current.request.player == player
+                && current.request.kind.accepts(&answer)
+        });
+        if fits {
+            self.waiting.pop_front();
+            self.answered.insert(id, answer);
+        }
+        fits
+    }
+
+    /// Take the answer to choice `id`, once it has one
+    pub fn take_answer(&mut self, id: ChoiceId) -> Option<ChoiceAnswer> {
+        self.answered.remove(&id)
+    }
+
+    /// Withdraw choice `id`, like when what asked it has left the game
+    pub fn cancel(&mut self, id: ChoiceId) {
+        self.waiting.retain(|choice| choice.id != id);
+        self.answered.remove(&id);
+    }
+}
+
+/// Run condition pausing game logic while a choice waits for its answer
+pub fn no_pending_choice(choices: Option<Res<PendingChoices>>) -> bool {
+    choices.is_none_or(|choices| !choices.is_waiting())
+}
+
+/// Take in players' answers, answering for them once time runs out
+pub fn handle_choice_answers(
+    time: Res<Time>,
+    mut answers: EventReader<AnswerChoiceEvent>,
+    mut choices: ResMut<PendingChoices>,
+) {
+    for event in answers.read() {
+        if !choices.answer(event.player, event.id, event.answer.clone()) {
+            warn!(
+                "Ignoring {:?}'s answer {:?} to {:?}",
+                event.player, event.answer, event.id
+            );
+        }
+    }
+
+    let Some(current) = choices.current().cloned() else {
+        return;
+    };
+    if current.remaining_secs() <= 0.0 {
+        info!(
+            "Answering {:?} for {:?} automatically",
+            current.id, current.request.player
+        );
+        let answer = current.request.kind.default_answer();
+        choices.answer(current.request.player, current.id, answer);
+    } else if let Some(current) = choices.bypass_change_detection().waiting.front_mut() {
+        // Waiting isn't a change anything needs to react to
+        current.waited += time.delta_secs();
+    }
+}
+
+/// Forget choices from the last game when returning to the main menu
+pub fn clear_pending_choices(mut choices: ResMut<PendingChoices>) {
+    *choices = PendingChoices::default();
+}
+
+/// Register asking players for choices
+pub fn register_choice_systems(app: &mut App) {
+    app.add_event::<AnswerChoiceEvent>()
+        .init_resource::<PendingChoices>()
+        .add_systems(
+            Update,
+            handle_choice_answers.run_if(in_state(GameMenuState::InGame)),
+        )
+        .add_systems(OnEnter(GameMenuState::MainMenu), clear_pending_choices);
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,142 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+
+use crate::game_engine::choices::{
+    AnswerChoiceEvent, CHOICE_TIMEOUT_SECS, ChoiceAnswer, ChoiceKind, ChoiceRequest,
+    PendingChoices, handle_choice_answers, no_pending_choice,
+};
+
+fn request(player: Entity, kind: ChoiceKind) -> ChoiceRequest {
+    ChoiceRequest {
+        player,
+        source: None,
+        prompt: "Choose".to_string(),
+        kind,
+    }
+}
+
+fn choices_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<AnswerChoiceEvent>()
+        .init_resource::<PendingChoices>()
+        .add_systems(Update, handle_choice_answers);
+    app
+}
+
+fn is_paused(app: &mut App) -> bool {
+    !app.world_mut().run_system_once(no_pending_choice).unwrap()
+}
+
+#[test]
+fn test_choice_kinds_accept_fitting_answers() {
+    let [a, b, c] = [1, 2, 3].map(Entity::from_raw);
+    let options = ChoiceKind::Option(vec!["Draw".to_string(), "Discard".to_string()]);
+    assert!(options.accepts(&ChoiceAnswer::Option(1)));
+    assert!(!options.accepts(&ChoiceAnswer::Option(2)));
+    assert!(!options.accepts(&ChoiceAnswer::YesNo(true)));
+
+    let entities = ChoiceKind::Entities {
+        options: vec![a, b, c],
+        min: 1,
+        max: 2,
+    };
+    assert!(entities.accepts(&ChoiceAnswer::Entities(vec![c, a])));
+    assert!(!entities.accepts(&ChoiceAnswer::Entities(vec![])));
+    assert!(!entities.accepts(&ChoiceAnswer::Entities(vec![a, a])));
+    assert!(!entities.accepts(&ChoiceAnswer::Entities(vec![Entity::from_raw(9)])));
+    assert_eq!(entities.default_answer(), ChoiceAnswer::Entities(vec![a]));
+
+    let number = ChoiceKind::Number { min: 2, max: 5 };
+    assert!(number.accepts(&ChoiceAnswer::Number(5)));
+    assert!(!number.accepts(&ChoiceAnswer::Number(1)));
+    assert_eq!(number.default_answer(), ChoiceAnswer::Number(2));
+    assert_eq!(
+        ChoiceKind::YesNo.default_answer(),
+        ChoiceAnswer::YesNo(false)
+    );
+}
+
+#[test]
+fn test_game_pauses_until_each_choice_is_answered_in_order() {
+    let mut app = choices_app();
+    let [alice, bob] = [1, 2].map(Entity::from_raw);
+    assert!(!is_paused(&mut app));
+
+    let mut choices = app.world_mut().resource_mut::<PendingChoices>();
+    let first = choices.push(request(alice, ChoiceKind::YesNo));
+    let second = choices.push(request(bob, ChoiceKind::Number { min: 0, max: 3 }));
+    assert!(is_paused(&mut app));
+
+    // Only the choice being asked can be answered, and only by its player
+    for (player, id) in [(bob, second), (bob, first)] {
+        app.world_mut().send_event(AnswerChoiceEvent {
+            player,
+            id,
+            answer: ChoiceAnswer::YesNo(true),
+        });
+    }
+    app.update();
+    assert_eq!(
+        app.world()
+            .resource::<PendingChoices>()
+            .current()
+            .unwrap()
+            .id,
+        first
+    );
+
+    app.world_mut().send_event(AnswerChoiceEvent {
+        player: alice,
+        id: first,
+        answer: ChoiceAnswer::YesNo(true),
+    });
+    app.update();
+    let mut choices = app.world_mut().resource_mut::<PendingChoices>();
+    assert!(!choices.is_pending(first));
+    assert_eq!(choices.current().unwrap().id, second);
+    assert_eq!(choices.take_answer(first), Some(ChoiceAnswer::YesNo(true)));
+    assert_eq!(choices.take_answer(first), None);
+    assert!(is_paused(&mut app));
+
+    app.world_mut().send_event(AnswerChoiceEvent {
+        player: bob,
+        id: second,
+        answer: ChoiceAnswer::Number(2),
+    });
+    app.update();
+    assert!(!is_paused(&mut app));
+    assert_eq!(
+        app.world_mut()
+            .resource_mut::<PendingChoices>()
+            .take_answer(second),
+        Some(ChoiceAnswer::Number(2))
+    );
+}
+
+#[test]
+fn test_unanswered_choices_are_answered_for_the_player() {
+    let mut app = choices_app();
+    let alice = Entity::from_raw(1);
+    let id = app
+        .world_mut()
+        .resource_mut::<PendingChoices>()
+        .push(request(alice, ChoiceKind::YesNo));
+
+    app.update();
+    assert!(is_paused(&mut app));
+    app.world_mut()
+        .resource_mut::<PendingChoices>()
+        .waiting
+        .front_mut()
+        .unwrap()
+        .waited = CHOICE_TIMEOUT_SECS;
+    app.update();
+    assert!(!is_paused(&mut app));
+    assert_eq!(
+        app.world_mut()
+            .resource_mut::<PendingChoices>()
+            .take_answer(id),
+        Some(ChoiceAnswer::YesNo(false))
+    );
+}
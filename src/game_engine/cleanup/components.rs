@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+
+use super::resources::MAXIMUM_HAND_SIZE;
+
+/// Marks an effect entity that lasts until end of turn
+///
+/// Every entity with this marker is despawned during the cleanup step.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct UntilEndOfTurn;
+
+/// A player's maximum hand size (rule 402.2), discarded down to in their cleanup step
+///
+/// Kept up to date from the [`HandSizeModifier`]s in play by
+/// [`update_maximum_hand_sizes`](super::update_maximum_hand_sizes); players
+/// without one have the default of [`MAXIMUM_HAND_SIZE`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaximumHandSize(pub Option<usize>);
+
+impl Default for MaximumHandSize {
+    fn default() -> Self {
+        Self(Some(MAXIMUM_HAND_SIZE))
+    }
+}
+
+impl MaximumHandSize {
+    /// A player with no maximum hand size, who never discards in cleanup
+    pub const NO_MAXIMUM: Self = Self(None);
+
+    /// The maximum hand size once every modifier is applied
+    ///
+    /// "No maximum hand size" outlasts any other change; otherwise increases
+    /// and decreases add up, and the maximum can't drop below zero.
+    pub fn with_modifiers(changes: impl IntoIterator<Item = HandSizeChange>) -> Self {
+        let mut size = MAXIMUM_HAND_SIZE as i64;
+        for change in changes {
+            match change {
+                HandSizeChange::NoMaximum => return Self::NO_MAXIMUM,
+                HandSizeChange::Increase(amount) => size += amount as i64,
+                HandSizeChange::Decrease(amount) => size -= amount as i64,
+            }
+        }
+        Self(Some(size.max(0) as usize))
+    }
+
+    /// Number of cards to discard for a hand of the given size
+    pub fn discard_count(&self, hand_size: usize) -> usize {
+        self.0
+            .map_or(0, |maximum| hand_size.saturating_sub(maximum))
+    }
+}
+
+/// How a hand size modifier changes a player's maximum hand size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandSizeChange {
+    /// Like Reliquary Tower's "You have no maximum hand size"
+    NoMaximum,
+    Increase(usize),
+    Decrease(usize),
+}
+
+/// Whose maximum hand size a modifier changes, relative to its controller
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HandSizeHolders {
+    #[default]
+    You,
+    Opponents,
+    Everyone,
+}
+
+impl HandSizeHolders {
+    /// Whether `player` is affected by a modifier controlled by `controller`
+    pub fn includes(&self, player: Entity, controller: Entity) -> bool {
+        match self {
+            HandSizeHolders::You => player == controller,
+            HandSizeHolders::Opponents => player != controller,
+            HandSizeHolders::Everyone => true,
+        }
+    }
+}
+
+/// A static ability or effect changing maximum hand sizes
+///
+/// On a permanent it affects players relative to the permanent's controller.
+/// On a player, like an effect given with
+/// [`insert_temporary`](crate::game_engine::durations::TemporaryEffectCommands::insert_temporary),
+/// it affects that player alone.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HandSizeModifier {
+    pub holders: HandSizeHolders,
+    pub change: HandSizeChange,
+}
+
+impl HandSizeModifier {
+    pub fn new(holders: HandSizeHolders, change: HandSizeChange) -> Self {
+        Self { holders, change }
+    }
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+/// Event sent when a player chooses which cards to discard to their maximum hand size
+#[derive(Event, Debug, Clone)]
+pub struct DiscardCardsEvent {
+    /// The player discarding
+    pub player: Entity,
+    /// The cards chosen from the player's hand
+    pub cards: Vec<Entity>,
+}
@@ -0,0 +1,47 @@
+//! Cleanup step
+//!
+//! When the turn reaches the cleanup step the active player discards down to their
+//! [`MaximumHandSize`], which effects like Reliquary Tower's change through
+//! [`HandSizeModifier`]s. Then damage is removed from creatures and "until end of turn"
+//! effects end. Players only receive priority during cleanup if something triggered
+//! or state-based actions were performed, in which case another cleanup step follows.
+
+pub mod components;
+pub mod events;
+pub mod resources;
+pub mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{
+    HandSizeChange, HandSizeHolders, HandSizeModifier, MaximumHandSize, UntilEndOfTurn,
+};
+pub use events::DiscardCardsEvent;
+pub use resources::{CleanupState, MAXIMUM_HAND_SIZE, PendingDiscard};
+pub use systems::{
+    begin_cleanup_step, handle_discard_events, perform_cleanup_actions, update_maximum_hand_sizes,
+};
+
+use bevy::prelude::*;
+
+use crate::game_engine::schedule::GameEngineSet;
+use crate::menu::state::GameMenuState;
+
+/// Register cleanup step resources, events and systems
+pub fn register_cleanup_systems(app: &mut App) {
+    app.init_resource::<CleanupState>()
+        .add_event::<DiscardCardsEvent>()
+        .add_systems(
+            FixedUpdate,
+            (
+                update_maximum_hand_sizes,
+                begin_cleanup_step,
+                handle_discard_events,
+                perform_cleanup_actions,
+            )
+                .chain()
+                .in_set(GameEngineSet::Cleanup)
+                .run_if(in_state(GameMenuState::InGame)),
+        );
+}
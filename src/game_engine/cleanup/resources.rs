@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+
+/// Maximum number of cards a player may keep in hand at the end of their turn,
+/// unless an effect changes it
+pub const MAXIMUM_HAND_SIZE: usize = 7;
+
+/// A discard the active player still has to make
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingDiscard {
+    /// The player who has to discard
+    pub player: Entity,
+    /// How many cards they have to discard
+    pub count: usize,
+}
+
+/// Progress through the current cleanup step
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct CleanupState {
+    /// Whether the current cleanup step has begun
+    pub step_started: bool,
+    /// Discard the active player still has to make
+    pub pending_discard: Option<PendingDiscard>,
+    /// Whether damage removal and end of turn effects have been handled
+    pub actions_done: bool,
+    /// Whether players receive priority because something happened during cleanup
+    pub grant_priority: bool,
+}
+
+impl CleanupState {
+    /// Number of cards to discard for a hand of the given size, with the
+    /// default maximum hand size
+    pub fn discard_count(hand_size: usize) -> usize {
+        hand_size.saturating_sub(MAXIMUM_HAND_SIZE)
+    }
+
+    /// Whether a player is currently choosing cards to discard
+    pub fn awaiting_discard_from(&self, player: Entity) -> bool {
+        self.pending_discard
+            .is_some_and(|pending| pending.player == player)
+    }
+
+    /// Start over for another cleanup step
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
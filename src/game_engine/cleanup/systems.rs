@@ -0,0 +1,183 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use super::components::{HandSizeModifier, MaximumHandSize, UntilEndOfTurn};
+use super::events::DiscardCardsEvent;
+use super::resources::{CleanupState, PendingDiscard};
+use crate::cards::details::CreatureOnField;
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::phase::{EndingStep, Phase};
+use crate::game_engine::priority::PrioritySystem;
+use crate::game_engine::stack::GameStack;
+use crate::game_engine::state::GameState;
+use crate::game_engine::turns::TurnManager;
+use crate::game_engine::zones::{Zone, ZoneChangeEvent, ZoneManager};
+use crate::player::Player;
+
+/// Work out each player's maximum hand size from the modifiers in play
+///
+/// Players get a [`MaximumHandSize`] the first time this runs, and it's only
+/// changed when the modifiers affecting them do.
+pub fn update_maximum_hand_sizes(
+    mut commands: Commands,
+    mut players: Query<(Entity, Option<&mut MaximumHandSize>), With<Player>>,
+    own_modifiers: Query<&HandSizeModifier, With<Player>>,
+    permanent_modifiers: Query<(&HandSizeModifier, &PermanentController)>,
+) {
+    for (player, current) in players.iter_mut() {
+        let changes = own_modifiers
+            .get(player)
+            .ok()
+            .map(|modifier| modifier.change)
+            .into_iter()
+            .chain(
+                permanent_modifiers
+                    .iter()
+                    .filter(|(modifier, controller)| {
+                        modifier.holders.includes(player, controller.player)
+                    })
+                    .map(|(modifier, _)| modifier.change),
+            );
+        let maximum = MaximumHandSize::with_modifiers(changes);
+        match current {
+            Some(mut current) => {
+                current.set_if_neq(maximum);
+            }
+            None => {
+                commands.entity(player).insert(maximum);
+            }
+        }
+    }
+}
+
+/// Starts the cleanup step, asking the active player to discard down to their
+/// [`MaximumHandSize`]
+///
+/// While the discard is pending the player is registered as a simultaneous decision
+/// player, which holds the priority system in place.
+pub fn begin_cleanup_step(
+    phase: Res<Phase>,
+    mut cleanup_state: ResMut<CleanupState>,
+    game_state: Res<GameState>,
+    zone_manager: Res<ZoneManager>,
+    mut priority: ResMut<PrioritySystem>,
+    hand_sizes: Query<&MaximumHandSize>,
+) {
+    if *phase != Phase::Ending(EndingStep::Cleanup) {
+        if *cleanup_state != CleanupState::default() {
+            cleanup_state.reset();
+        }
+        return;
+    }
+
+    if cleanup_state.step_started {
+        return;
+    }
+    cleanup_state.step_started = true;
+
+    let player = game_state.active_player;
+    let hand_size = zone_manager.hand(player).len();
+    let count = hand_sizes
+        .get(player)
+        .copied()
+        .unwrap_or_default()
+        .discard_count(hand_size);
+    if count > 0 {
+        info!(
+            "Player {:?} has {} cards in hand and must discard {}",
+            player, hand_size, count
+        );
+        cleanup_state.pending_discard = Some(PendingDiscard { player, count });
+        priority.add_simultaneous_decision_player(player);
+    }
+}
+
+/// Applies the discard chosen by the active player
+///
+/// Choices with the wrong number of cards, or with cards that aren't in the player's
+/// hand, are ignored so the player can choose again.
+pub fn handle_discard_events(
+    mut discard_events: EventReader<DiscardCardsEvent>,
+    mut cleanup_state: ResMut<CleanupState>,
+    mut zone_manager: ResMut<ZoneManager>,
+    mut priority: ResMut<PrioritySystem>,
+    mut zone_events: EventWriter<ZoneChangeEvent>,
+) {
+    for event in discard_events.read() {
+        let Some(pending) = cleanup_state.pending_discard else {
+            warn!(
+                "Ignoring discard from {:?}: no discard pending",
+                event.player
+            );
+            continue;
+        };
+
+        let unique_cards: HashSet<Entity> = event.cards.iter().copied().collect();
+        let hand = zone_manager.hand(event.player);
+        let in_hand = unique_cards.iter().all(|card| hand.contains(card));
+        if pending.player != event.player || unique_cards.len() != pending.count || !in_hand {
+            warn!(
+                "Ignoring invalid discard from {:?}: expected {} cards from {:?}'s hand",
+                event.player, pending.count, pending.player
+            );
+            continue;
+        }
+
+        for &card in &event.cards {
+            zone_manager.move_card(card, event.player, Zone::Hand, Zone::Graveyard);
+            zone_events.write(ZoneChangeEvent {
+                card,
+                owner: event.player,
+                source: Zone::Hand,
+                destination: Zone::Graveyard,
+                was_visible: false,
+                is_visible: true,
+            });
+        }
+
+        cleanup_state.pending_discard = None;
+        priority.remove_simultaneous_decision_player(event.player);
+    }
+}
+
+/// Removes damage from creatures and ends "until end of turn" effects
+///
+/// Runs once the discard is done. If anything is waiting on the stack or
+/// state-based actions were just performed, players receive priority and another
+/// cleanup step follows this one.
+#[allow(clippy::too_many_arguments)]
+pub fn perform_cleanup_actions(
+    mut commands: Commands,
+    mut cleanup_state: ResMut<CleanupState>,
+    game_stack: Res<GameStack>,
+    game_state: Res<GameState>,
+    turn_manager: Res<TurnManager>,
+    mut priority: ResMut<PrioritySystem>,
+    mut creatures: Query<&mut CreatureOnField>,
+    effects: Query<Entity, With<UntilEndOfTurn>>,
+) {
+    if !cleanup_state.step_started
+        || cleanup_state.pending_discard.is_some()
+        || cleanup_state.actions_done
+    {
+        return;
+    }
+
+    for mut creature in creatures.iter_mut() {
+        if creature.battle_damage > 0 {
+            creature.battle_damage = 0;
+        }
+    }
+
+    for effect in effects.iter() {
+        commands.entity(effect).despawn();
+    }
+
+    cleanup_state.actions_done = true;
+
+    if !game_stack.is_empty() || game_state.state_based_actions_performed {
+        info!("Something happened during cleanup, players receive priority");
+        cleanup_state.grant_priority = true;
+        priority.reset_after_stack_action(&turn_manager.player_order, game_state.active_player);
+    }
+}
@@ -0,0 +1,187 @@
+use bevy::prelude::*;
+
+use crate::game_engine::cleanup::{
+    CleanupState, DiscardCardsEvent, HandSizeChange, HandSizeHolders, HandSizeModifier,
+    MAXIMUM_HAND_SIZE, MaximumHandSize, PendingDiscard, UntilEndOfTurn, begin_cleanup_step,
+    handle_discard_events, perform_cleanup_actions, update_maximum_hand_sizes,
+};
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::phase::{EndingStep, Phase};
+use crate::game_engine::priority::PrioritySystem;
+use crate::game_engine::stack::GameStack;
+use crate::game_engine::state::GameState;
+use crate::game_engine::turns::TurnManager;
+use crate::game_engine::zones::{ZoneChangeEvent, ZoneManager};
+use crate::player::Player;
+
+/// Builds an app in the cleanup step where the active player holds `hand_size` cards
+fn cleanup_app(hand_size: usize) -> (App, Entity, Vec<Entity>) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<DiscardCardsEvent>()
+        .add_event::<ZoneChangeEvent>()
+        .insert_resource(Phase::Ending(EndingStep::Cleanup))
+        .init_resource::<CleanupState>()
+        .init_resource::<ZoneManager>()
+        .init_resource::<PrioritySystem>()
+        .init_resource::<GameStack>()
+        .init_resource::<TurnManager>()
+        .add_systems(
+            Update,
+            (
+                update_maximum_hand_sizes,
+                begin_cleanup_step,
+                handle_discard_events,
+                perform_cleanup_actions,
+            )
+                .chain(),
+        );
+
+    let player = app.world_mut().spawn(Player::default()).id();
+    let mut game_state = GameState::default();
+    game_state.set_turn_order(vec![player]);
+    app.insert_resource(game_state);
+
+    let cards: Vec<Entity> = (0..hand_size)
+        .map(|_| app.world_mut().spawn_empty().id())
+        .collect();
+    {
+        let mut zone_manager = app.world_mut().resource_mut::<ZoneManager>();
+        zone_manager.init_player_zones(player);
+        for &card in &cards {
+            zone_manager.add_to_hand(player, card);
+        }
+    }
+
+    (app, player, cards)
+}
+
+#[test]
+fn test_discard_count_above_maximum_hand_size() {
+    assert_eq!(CleanupState::discard_count(MAXIMUM_HAND_SIZE), 0);
+    assert_eq!(CleanupState::discard_count(3), 0);
+    assert_eq!(CleanupState::discard_count(MAXIMUM_HAND_SIZE + 2), 2);
+}
+
+#[test]
+fn test_small_hand_finishes_cleanup_without_discard() {
+    let (mut app, _player, _cards) = cleanup_app(5);
+    let effect = app.world_mut().spawn(UntilEndOfTurn).id();
+
+    app.update();
+
+    let cleanup_state = app.world().resource::<CleanupState>();
+    assert!(cleanup_state.pending_discard.is_none());
+    assert!(cleanup_state.actions_done);
+    assert!(!cleanup_state.grant_priority);
+    assert!(app.world().get_entity(effect).is_err());
+}
+
+#[test]
+fn test_large_hand_waits_for_valid_discard() {
+    let (mut app, player, cards) = cleanup_app(MAXIMUM_HAND_SIZE + 2);
+    app.update();
+
+    assert!(
+        app.world()
+            .resource::<CleanupState>()
+            .awaiting_discard_from(player)
+    );
+    assert!(
+        app.world()
+            .resource::<PrioritySystem>()
+            .simultaneous_decision_players
+            .contains(&player)
+    );
+    assert!(!app.world().resource::<CleanupState>().actions_done);
+
+    // Discarding the wrong number of cards is ignored
+    app.world_mut().send_event(DiscardCardsEvent {
+        player,
+        cards: vec![cards[0]],
+    });
+    app.update();
+    assert!(
+        app.world()
+            .resource::<CleanupState>()
+            .awaiting_discard_from(player)
+    );
+
+    app.world_mut().send_event(DiscardCardsEvent {
+        player,
+        cards: vec![cards[0], cards[1]],
+    });
+    app.update();
+
+    let zone_manager = app.world().resource::<ZoneManager>();
+    assert_eq!(zone_manager.hand(player).len(), MAXIMUM_HAND_SIZE);
+    assert_eq!(zone_manager.graveyard(player), vec![cards[0], cards[1]]);
+    assert!(app.world().resource::<CleanupState>().actions_done);
+    assert!(
+        app.world()
+            .resource::<PrioritySystem>()
+            .simultaneous_decision_players
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_maximum_hand_size_modifiers() {
+    assert_eq!(
+        MaximumHandSize::with_modifiers([]),
+        MaximumHandSize(Some(MAXIMUM_HAND_SIZE))
+    );
+    assert_eq!(
+        MaximumHandSize::with_modifiers([HandSizeChange::Increase(2), HandSizeChange::Decrease(1)]),
+        MaximumHandSize(Some(MAXIMUM_HAND_SIZE + 1))
+    );
+    assert_eq!(
+        MaximumHandSize::with_modifiers([HandSizeChange::Decrease(10)]),
+        MaximumHandSize(Some(0))
+    );
+    assert_eq!(
+        MaximumHandSize::with_modifiers([HandSizeChange::Decrease(1), HandSizeChange::NoMaximum]),
+        MaximumHandSize::NO_MAXIMUM
+    );
+    assert_eq!(MaximumHandSize::NO_MAXIMUM.discard_count(30), 0);
+    assert_eq!(MaximumHandSize(Some(8)).discard_count(10), 2);
+}
+
+#[test]
+fn test_no_maximum_hand_size_keeps_every_card() {
+    let (mut app, player, _cards) = cleanup_app(MAXIMUM_HAND_SIZE + 3);
+    // Reliquary Tower
+    app.world_mut().spawn((
+        HandSizeModifier::new(HandSizeHolders::You, HandSizeChange::NoMaximum),
+        PermanentController::new(player),
+    ));
+    app.update();
+
+    assert_eq!(
+        app.world().get::<MaximumHandSize>(player),
+        Some(&MaximumHandSize::NO_MAXIMUM)
+    );
+    let cleanup_state = app.world().resource::<CleanupState>();
+    assert!(cleanup_state.pending_discard.is_none());
+    assert!(cleanup_state.actions_done);
+}
+
+#[test]
+fn test_opponents_modifiers_shrink_the_hand_kept() {
+    let (mut app, player, _cards) = cleanup_app(MAXIMUM_HAND_SIZE);
+    let opponent = app.world_mut().spawn(Player::default()).id();
+    app.world_mut().spawn((
+        HandSizeModifier::new(HandSizeHolders::Opponents, HandSizeChange::Decrease(2)),
+        PermanentController::new(opponent),
+    ));
+    app.update();
+
+    assert_eq!(
+        app.world().get::<MaximumHandSize>(opponent),
+        Some(&MaximumHandSize::default())
+    );
+    assert_eq!(
+        app.world().resource::<CleanupState>().pending_discard,
+        Some(PendingDiscard { player, count: 2 })
+    );
+}
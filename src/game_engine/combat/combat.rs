@@ -1,12 +1,27 @@
-use crate::cards::CreatureType;
-use crate::game_engine::commander::CombatDamageEvent;
-use crate::game_engine::state::GameState;
+use crate::cards::details::CreatureOnField;
+use crate::cards::keywords::KeywordAbility;
+use crate::cards::{Card, CardDetails, CreatureType};
+use crate::game_engine::commander::{CombatDamageEvent, Commander};
+use crate::game_engine::enforcement::RulesEnforcementLevel;
+use crate::game_engine::life::LifeChangeEvent;
+use crate::game_engine::permanent::PermanentState;
+use crate::game_engine::phase::{CombatStep, Phase};
 use crate::game_engine::turns::TurnManager;
 use crate::mana::ManaColor;
 use crate::player::Player;
+use crate::tracing::EngineSpans;
 use bevy::prelude::*;
 use std::collections::{HashMap, HashSet};
 
+use super::damage::{
+    CombatantQuery, DamageAssignment, blocker_damage, combat_power, deals_damage_in_step,
+    has_first_strike, has_trample, suggest_assignment, validate_assignment,
+};
+use super::legality::{
+    CombatCreatureQuery, CombatViolation, attack_violations, block_violations, check_attack,
+    check_block,
+};
+
 // Event types
 #[derive(Event)]
 pub struct DeclareAttackersEvent {
@@ -20,7 +35,7 @@ pub struct DeclareBlockersEvent {
 
 #[derive(Event)]
 pub struct AssignCombatDamageEvent {
-    #[allow(dead_code)]
+    /// Whether this is the first strike combat damage step
     pub is_first_strike: bool,
 }
 
@@ -36,6 +51,22 @@ pub struct BlockerDeclaredEvent {
     pub attacker: Entity,
 }
 
+/// Sent by the attacking player to put an attacker's blockers in damage
+/// assignment order
+#[derive(Event, Debug, Clone)]
+pub struct OrderBlockersEvent {
+    pub attacker: Entity,
+    /// Every blocker of the attacker, first to be assigned damage first
+    pub order: Vec<Entity>,
+}
+
+/// Sent by the attacking player to choose how an attacker's damage is split
+#[derive(Event, Debug, Clone)]
+pub struct DamageAssignmentEvent {
+    pub attacker: Entity,
+    pub assignment: DamageAssignment,
+}
+
 #[derive(Event)]
 pub struct CombatBeginEvent {
     #[allow(dead_code)]
@@ -109,7 +140,8 @@ pub enum BlockedStatus {
     Unblocked,
 }
 
-#[derive(PartialEq, Eq, Clone)]
+/// Creatures that can't block an attacker, as in "can't be blocked by Walls"
+#[derive(Debug, PartialEq, Eq, Clone)]
 #[allow(dead_code)]
 pub enum BlockRestriction {
     CreatureType(CreatureType),
@@ -118,7 +150,29 @@ pub enum BlockRestriction {
     Toughness(Comparison, i32),
 }
 
-#[derive(PartialEq, Eq, Clone)]
+impl BlockRestriction {
+    /// Whether the restriction stops `blocker` from blocking
+    pub fn forbids(&self, blocker: &Card) -> bool {
+        let creature = match &blocker.details.details {
+            CardDetails::Creature(creature) => Some(creature),
+            _ => None,
+        };
+        match self {
+            BlockRestriction::CreatureType(creature_type) => {
+                creature.is_some_and(|creature| creature.creature_type.intersects(*creature_type))
+            }
+            BlockRestriction::Color(color) => blocker.cost.cost.color.intersects(*color),
+            BlockRestriction::Power(comparison, value) => {
+                creature.is_some_and(|creature| comparison.holds(creature.power, *value))
+            }
+            BlockRestriction::Toughness(comparison, value) => {
+                creature.is_some_and(|creature| comparison.holds(creature.toughness, *value))
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
 #[allow(dead_code)]
 pub enum Comparison {
     LessThan,
@@ -128,15 +182,31 @@ pub enum Comparison {
     GreaterThan,
 }
 
+impl Comparison {
+    /// Whether `value` compares to `other` this way
+    pub fn holds(&self, value: i32, other: i32) -> bool {
+        match self {
+            Comparison::LessThan => value < other,
+            Comparison::LessThanOrEqual => value <= other,
+            Comparison::Equal => value == other,
+            Comparison::GreaterThanOrEqual => value >= other,
+            Comparison::GreaterThan => value > other,
+        }
+    }
+}
+
 /// Resource tracking the state of combat during a turn
 #[derive(Resource, Default)]
 pub struct CombatState {
     /// Current attackers and defenders - maps attacker creature to defending player
     pub attackers: HashMap<Entity, Entity>,
 
-    /// Maps attacking creature to its blocking creatures
+    /// Maps attacking creature to its blocking creatures, in damage assignment order
     pub blockers: HashMap<Entity, Vec<Entity>>,
 
+    /// How the attacking player chose to split each blocked attacker's damage
+    pub damage_assignments: HashMap<Entity, DamageAssignment>,
+
     /// Tracks whether each attacking creature is blocked or not
     pub blocked_status: HashMap<Entity, BlockedStatus>,
 
@@ -161,9 +231,17 @@ pub struct CombatState {
     pub cannot_attack: HashMap<Entity, Vec<Entity>>,
 
     /// Combat restrictions - maps creatures to what cannot block them
-    #[allow(dead_code)]
     pub cannot_be_blocked_by: HashMap<Entity, Vec<BlockRestriction>>,
 
+    /// Combat restrictions - creatures that cannot block
+    pub cannot_block: HashSet<Entity>,
+
+    /// Combat restrictions - maps creatures to players whose attackers they cannot block
+    pub cannot_block_attacks_against: HashMap<Entity, Vec<Entity>>,
+
+    /// Restrictions and requirements the current attacks and blocks break
+    pub violations: Vec<CombatViolation>,
+
     /// Commander damage tracking for this combat
     pub commander_damage_this_combat: HashMap<Entity, HashMap<Entity, u32>>,
 
@@ -174,14 +252,43 @@ pub struct CombatState {
 
     /// For first strike/regular damage steps
     pub combat_damage_step_number: u8,
+
+    /// Creatures that had first strike or double strike as first strike damage
+    /// was dealt, and so only deal regular damage if they have double strike
+    pub first_strikers: HashSet<Entity>,
+}
+
+impl CombatState {
+    /// Every attacking and blocking creature
+    pub fn combatants(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.attackers
+            .keys()
+            .chain(self.blockers.values().flatten())
+            .copied()
+    }
+
+    /// Whether combat has a first strike damage step
+    ///
+    /// It only does if an attacking or blocking creature has first strike or
+    /// double strike.
+    pub fn has_first_strike_step(&self, cards: &Query<&Card>) -> bool {
+        self.combatants()
+            .any(|creature| cards.get(creature).is_ok_and(has_first_strike))
+    }
 }
 
 // Combat systems
 pub fn initialize_combat_phase(
     mut combat_state: ResMut<CombatState>,
     turn_manager: Res<TurnManager>,
+    phase: Res<Phase>,
     mut combat_begin_events: EventWriter<CombatBeginEvent>,
 ) {
+    // Only start combat once, as the beginning of combat step is entered
+    if !phase.is_changed() || *phase != Phase::Combat(CombatStep::Beginning) {
+        return;
+    }
+
     // Clear previous combat state
     *combat_state = CombatState::default();
 
@@ -204,12 +311,47 @@ pub fn handle_declare_attackers_event(
     }
 }
 
+/// Record declared attackers and tap them
+///
+/// Attacks that [break a restriction](check_attack) are rejected, or allowed with a warning, depending on the
+/// [`RulesEnforcementLevel`]. Attacking taps a creature unless it has vigilance.
 pub fn declare_attackers_system(
     mut combat_state: ResMut<CombatState>,
     mut events: EventReader<AttackerDeclaredEvent>,
     mut creature_attacks_events: EventWriter<CreatureAttacksEvent>,
+    mut creatures: Query<(&Card, &mut PermanentState)>,
+    enforcement: Option<Res<RulesEnforcementLevel>>,
+    spans: Option<Res<EngineSpans>>,
 ) {
+    let _span = info_span!(parent: EngineSpans::parent(spans.as_deref()), "declare_attackers", events = events.len()).entered();
+    let enforcement = enforcement.as_deref().copied().unwrap_or_default();
+
     for event in events.read() {
+        if combat_state.attackers.contains_key(&event.attacker) {
+            continue;
+        }
+
+        let mut creature = creatures.get_mut(event.attacker).ok();
+        let violation = check_attack(
+            &combat_state,
+            event.attacker,
+            event.defender,
+            creature.as_ref().map(|(card, state)| (*card, &**state)),
+        );
+        let attack = match &violation {
+            Some(violation) => violation.to_string(),
+            None => format!("attack by {:?} on {:?}", event.attacker, event.defender),
+        };
+        if !enforcement.allows(violation.is_none(), attack) {
+            continue;
+        }
+
+        let taps = creature
+            .as_mut()
+            .filter(|(card, _)| !Card::has_keyword(card, KeywordAbility::Vigilance));
+        if let Some((_, state)) = taps {
+            state.tap();
+        }
         combat_state
             .attackers
             .insert(event.attacker, event.defender);
@@ -236,14 +378,38 @@ pub fn handle_declare_blockers_event(
     }
 }
 
+/// Record declared blockers
+///
+/// Blocks that [break a restriction](check_block), such as a creature without
+/// flying or reach blocking a flier, are rejected, or allowed with a warning,
+/// depending on the [`RulesEnforcementLevel`].
 pub fn declare_blockers_system(
     mut combat_state: ResMut<CombatState>,
     mut events: EventReader<BlockerDeclaredEvent>,
     mut creature_blocks_events: EventWriter<CreatureBlocksEvent>,
     mut creature_blocked_events: EventWriter<CreatureBlockedEvent>,
+    creatures: CombatCreatureQuery,
+    enforcement: Option<Res<RulesEnforcementLevel>>,
+    spans: Option<Res<EngineSpans>>,
 ) {
+    let _span = info_span!(parent: EngineSpans::parent(spans.as_deref()), "declare_blockers", events = events.len()).entered();
+    let enforcement = enforcement.as_deref().copied().unwrap_or_default();
+
     for event in events.read() {
-        if combat_state.attackers.contains_key(&event.attacker) {
+        let violation = check_block(
+            &combat_state,
+            event.attacker,
+            event.blocker,
+            creatures.get(event.attacker).ok().map(|(card, _)| card),
+            creatures.get(event.blocker).ok(),
+        );
+        let block = match &violation {
+            Some(violation) => violation.to_string(),
+            None => format!("block by {:?} of {:?}", event.blocker, event.attacker),
+        };
+        if combat_state.attackers.contains_key(&event.attacker)
+            && enforcement.allows(violation.is_none(), block)
+        {
             combat_state
                 .blocked_status
                 .insert(event.attacker, BlockedStatus::Blocked);
@@ -264,72 +430,266 @@ pub fn declare_blockers_system(
     }
 }
 
-pub fn assign_combat_damage_system(
-    _commands: Commands,
+/// Flag attacks and blocks that break a restriction or requirement
+///
+/// Requirements such as "must attack if able", "can't attack alone" and menace
+/// depend on every declaration together, so they are checked once attackers or
+/// blockers have been declared rather than as each creature is declared. Found
+/// violations are kept in [`CombatState::violations`].
+pub fn flag_combat_violations(
     mut combat_state: ResMut<CombatState>,
-    mut events: EventReader<AssignCombatDamageEvent>,
+    phase: Res<Phase>,
+    mut attacks: EventReader<CreatureAttacksEvent>,
+    mut blocks: EventReader<CreatureBlocksEvent>,
+    creatures: CombatCreatureQuery,
 ) {
-    for _event in events.read() {
-        combat_state.in_combat_damage = true;
-        // Handle damage assignment logic here
+    let declared = attacks.read().count() + blocks.read().count() > 0;
+    let checks_blocks = match *phase {
+        Phase::Combat(
+            CombatStep::DeclareBlockers | CombatStep::FirstStrikeDamage | CombatStep::CombatDamage,
+        ) => true,
+        // Attacks are still being declared, so requirements can't be judged yet
+        Phase::Combat(CombatStep::DeclareAttackers) => return,
+        _ => false,
+    };
+    if !declared && !(phase.is_changed() && checks_blocks) {
+        return;
+    }
+
+    let mut violations = attack_violations(&combat_state, &creatures);
+    if checks_blocks {
+        violations.extend(block_violations(&combat_state, &creatures));
+    }
+    for violation in &violations {
+        if !combat_state.violations.contains(violation) {
+            warn!("Illegal combat: {}", violation);
+        }
+    }
+    if combat_state.violations != violations {
+        combat_state.violations = violations;
     }
 }
 
-pub fn process_combat_damage_system(
-    _commands: Commands,
+/// Put an attacker's blockers in the order its controller chose
+pub fn handle_blocker_order_events(
     mut combat_state: ResMut<CombatState>,
-    _game_state: ResMut<GameState>,
-    mut players: Query<&mut Player>,
+    mut events: EventReader<OrderBlockersEvent>,
 ) {
-    // Clone the pending events to avoid borrow issues
-    let pending_events = combat_state.pending_combat_damage.clone();
+    for event in events.read() {
+        let Some(blockers) = combat_state.blockers.get_mut(&event.attacker) else {
+            continue;
+        };
+        let same_blockers = event.order.len() == blockers.len()
+            && event.order.iter().all(|blocker| blockers.contains(blocker));
+        if !same_blockers {
+            warn!(
+                "Damage assignment order for {:?} must list each of its blockers once",
+                event.attacker
+            );
+            continue;
+        }
+        blockers.clone_from(&event.order);
+        // An assignment made for the old order may no longer be legal
+        combat_state.damage_assignments.remove(&event.attacker);
+    }
+}
 
-    // Track which players we've processed to avoid double-processing
-    let mut processed_players = HashSet::new();
+/// Record the damage assignments chosen by the attacking player
+///
+/// Assignments that break the damage assignment order are rejected, or allowed
+/// with a warning, depending on the [`RulesEnforcementLevel`].
+pub fn handle_damage_assignment_events(
+    mut combat_state: ResMut<CombatState>,
+    mut events: EventReader<DamageAssignmentEvent>,
+    combatants: CombatantQuery,
+    enforcement: Option<Res<RulesEnforcementLevel>>,
+) {
+    let enforcement = enforcement.as_deref().copied().unwrap_or_default();
 
-    for event in pending_events {
-        // Check if target is a player
-        if let Ok(mut player) = players.get_mut(event.target) {
-            if processed_players.contains(&event.target) {
-                continue; // Skip already processed players
-            }
+    for event in events.read() {
+        let (Some(blockers), Ok((card, on_field))) = (
+            combat_state.blockers.get(&event.attacker),
+            combatants.get(event.attacker),
+        ) else {
+            continue;
+        };
+        let power = combat_power(card, on_field);
+        let order = blocker_damage(card, blockers, &combatants);
+        let result = validate_assignment(power, &order, has_trample(card), &event.assignment);
+        let description = match &result {
+            Ok(()) => format!("damage assignment for {:?}", event.attacker),
+            Err(error) => format!("damage assignment for {:?} ({})", event.attacker, error),
+        };
+        if enforcement.allows(result.is_ok(), description) {
+            combat_state
+                .damage_assignments
+                .insert(event.attacker, event.assignment.clone());
+        }
+    }
+}
 
-            // Apply damage
-            player.life -= event.damage as i32;
-            processed_players.insert(event.target);
+/// Work out the combat damage every attacker and blocker deals
+///
+/// An attacker blocked by several creatures uses the assignment its controller
+/// chose, or a [suggested](suggest_assignment) one if they didn't choose. In the
+/// first strike damage step only creatures with first strike or double strike
+/// deal damage, and in the regular step only the others and double strikers do.
+pub fn assign_combat_damage_system(
+    mut combat_state: ResMut<CombatState>,
+    mut events: EventReader<AssignCombatDamageEvent>,
+    combatants: CombatantQuery,
+    commanders: Query<(), With<Commander>>,
+    spans: Option<Res<EngineSpans>>,
+) {
+    let Some(first_strike_step) = events.read().last().map(|event| event.is_first_strike) else {
+        return;
+    };
+    let _span = info_span!(
+        parent: EngineSpans::parent(spans.as_deref()),
+        "assign_combat_damage",
+        attackers = combat_state.attackers.len()
+    )
+    .entered();
+    combat_state.in_combat_damage = true;
+
+    if first_strike_step {
+        // Gaining or losing first strike later doesn't change who deals damage again
+        let first_strikers = combat_state
+            .combatants()
+            .filter(|creature| {
+                combatants
+                    .get(*creature)
+                    .is_ok_and(|(card, _)| has_first_strike(card))
+            })
+            .collect();
+        combat_state.first_strikers = first_strikers;
+    }
+    let first_strikers = combat_state.first_strikers.clone();
+    let deals_damage = |creature: Entity, card: &Card| {
+        deals_damage_in_step(creature, card, first_strike_step, &first_strikers)
+    };
+
+    let mut damage = Vec::new();
+    let attackers: Vec<(Entity, Entity)> = combat_state
+        .attackers
+        .iter()
+        .map(|(attacker, defender)| (*attacker, *defender))
+        .collect();
+    for (attacker, defender) in attackers {
+        let Ok((card, on_field)) = combatants.get(attacker) else {
+            continue;
+        };
+        let power = combat_power(card, on_field);
+
+        let assignment = match combat_state.blockers.get(&attacker) {
+            Some(blockers) => {
+                let order = blocker_damage(card, blockers, &combatants);
+                // Each blocker deals its damage to the attacker it blocks
+                for info in &order {
+                    if let Ok((blocker_card, blocker_on_field)) = combatants.get(info.blocker) {
+                        if deals_damage(info.blocker, blocker_card) {
+                            damage.push((
+                                info.blocker,
+                                attacker,
+                                combat_power(blocker_card, blocker_on_field),
+                            ));
+                        }
+                    }
+                }
+                combat_state
+                    .damage_assignments
+                    .get(&attacker)
+                    .cloned()
+                    .unwrap_or_else(|| suggest_assignment(power, &order, has_trample(card)))
+            }
+            None => DamageAssignment {
+                blockers: Vec::new(),
+                defender: power,
+            },
+        };
+        if !deals_damage(attacker, card) {
+            continue;
+        }
 
-            // Debug output
-            info!(
-                "Player {:?} took {} damage, life now {}",
-                event.target, event.damage, player.life
-            );
+        damage.extend(
+            assignment
+                .blockers
+                .iter()
+                .map(|(blocker, amount)| (attacker, *blocker, *amount)),
+        );
+        damage.push((attacker, defender, assignment.defender));
+    }
 
-            // For commander damage, make sure it's tracked correctly
-            if event.source_is_commander && event.is_combat_damage {
-                info!(
-                    "Tracking commander damage: {:?} -> {:?}: {}",
-                    event.source, event.target, event.damage
-                );
-            }
+    for (source, target, amount) in damage {
+        if amount == 0 {
+            continue;
         }
+        combat_state
+            .assigned_combat_damage
+            .entry(source)
+            .or_default()
+            .push((target, amount));
+        combat_state.pending_combat_damage.push(CombatDamageEvent {
+            source,
+            target,
+            damage: amount,
+            is_combat_damage: true,
+            source_is_commander: commanders.contains(source),
+        });
     }
+}
 
-    // Clear after processing
-    combat_state.pending_combat_damage.clear();
+/// Deal the pending combat damage to players and creatures
+pub fn process_combat_damage_system(
+    mut combat_state: ResMut<CombatState>,
+    players: Query<(), With<Player>>,
+    mut creatures: Query<&mut CreatureOnField>,
+    mut damage_events: EventWriter<CombatDamageEvent>,
+    mut life_changes: EventWriter<LifeChangeEvent>,
+) {
+    if combat_state.pending_combat_damage.is_empty() {
+        return;
+    }
+
+    for event in std::mem::take(&mut combat_state.pending_combat_damage) {
+        if players.contains(event.target) {
+            life_changes.write(LifeChangeEvent::damage(
+                event.target,
+                event.source,
+                event.damage,
+            ));
+        } else if let Ok(mut creature) = creatures.get_mut(event.target) {
+            creature.battle_damage += event.damage as u64;
+        }
+
+        // Commander damage is recorded from these events
+        damage_events.write(event);
+    }
     combat_state.in_combat_damage = false;
 }
 
 pub fn end_combat_system(
     mut combat_state: ResMut<CombatState>,
     turn_manager: Res<TurnManager>,
+    phase: Res<Phase>,
     mut combat_end_events: EventWriter<CombatEndEvent>,
+    spans: Option<Res<EngineSpans>>,
 ) {
+    // Only end combat once, as the end of combat step is entered
+    if !phase.is_changed() || *phase != Phase::Combat(CombatStep::End) {
+        return;
+    }
+    let _span = info_span!(parent: EngineSpans::parent(spans.as_deref()), "end_combat").entered();
+
     // Clear all combat data
     combat_state.attackers.clear();
     combat_state.blockers.clear();
     combat_state.blocked_status.clear();
+    combat_state.damage_assignments.clear();
+    combat_state.violations.clear();
     combat_state.assigned_combat_damage.clear();
     combat_state.pending_combat_damage.clear();
+    combat_state.first_strikers.clear();
 
     // In a complete implementation, we would update persistent commander damage here
     // but for now, we'll just clear the combat-specific tracking
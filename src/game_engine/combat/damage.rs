@@ -0,0 +1,245 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use bevy::prelude::*;
+
+use crate::cards::details::CreatureOnField;
+use crate::cards::keywords::KeywordAbility;
+use crate::cards::{Card, CardDetails};
+
+/// A blocker in an attacker's damage assignment order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockerDamage {
+    pub blocker: Entity,
+    /// Damage that counts as lethal for this blocker
+    pub lethal: u32,
+}
+
+/// How much damage is lethal to a creature
+///
+/// Damage already marked on the creature counts towards it, and any damage at all
+/// is lethal from a source with deathtouch.
+pub fn lethal_damage(toughness: i64, damage_marked: u64, deathtouch: bool) -> u32 {
+    let remaining = (toughness - damage_marked as i64).max(1);
+    if deathtouch {
+        1
+    } else {
+        remaining.min(u32::MAX as i64) as u32
+    }
+}
+
+/// A creature's power, toughness and marked damage
+///
+/// Creatures tracked by [`CreatureOnField`] use its current values; anything else
+/// falls back to the printed card.
+pub fn creature_stats(card: &Card, on_field: Option<&CreatureOnField>) -> (i64, i64, u64) {
+    if let Some(on_field) = on_field {
+        return (
+            on_field.power_modifier,
+            on_field.toughness_modifier,
+            on_field.battle_damage,
+        );
+    }
+    match &card.details.details {
+        CardDetails::Creature(creature) => (creature.power as i64, creature.toughness as i64, 0),
+        _ => (0, 0, 0),
+    }
+}
+
+/// The combat damage a creature deals
+pub fn combat_power(card: &Card, on_field: Option<&CreatureOnField>) -> u32 {
+    creature_stats(card, on_field).0.max(0) as u32
+}
+
+/// Cards and battlefield stats of creatures in combat
+pub type CombatantQuery<'w, 's> = Query<'w, 's, (&'static Card, Option<&'static CreatureOnField>)>;
+
+/// The blockers of an attacker, in damage assignment order, with the damage
+/// lethal to each
+///
+/// Blockers that have left the battlefield are skipped.
+pub fn blocker_damage(
+    attacker: &Card,
+    blockers: &[Entity],
+    combatants: &CombatantQuery,
+) -> Vec<BlockerDamage> {
+    let deathtouch = has_deathtouch(attacker);
+    blockers
+        .iter()
+        .filter_map(|&blocker| {
+            let (card, on_field) = combatants.get(blocker).ok()?;
+            let (_, toughness, damage) = creature_stats(card, on_field);
+            Some(BlockerDamage {
+                blocker,
+                lethal: lethal_damage(toughness, damage, deathtouch),
+            })
+        })
+        .collect()
+}
+
+/// How an attacker's combat damage is split between its blockers and the player
+/// or planeswalker it attacks
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DamageAssignment {
+    /// Damage to each blocker, in damage assignment order
+    pub blockers: Vec<(Entity, u32)>,
+    /// Damage trampling over to the defender
+    pub defender: u32,
+}
+
+impl DamageAssignment {
+    /// All the damage assigned
+    pub fn total(&self) -> u32 {
+        self.blockers.iter().map(|(_, damage)| damage).sum::<u32>() + self.defender
+    }
+
+    /// Damage assigned to `blocker`
+    pub fn damage_to(&self, blocker: Entity) -> u32 {
+        self.blockers
+            .iter()
+            .filter(|(entity, _)| *entity == blocker)
+            .map(|(_, damage)| damage)
+            .sum()
+    }
+}
+
+/// Why a damage assignment breaks the rules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DamageAssignmentError {
+    /// The assignment doesn't add up to the attacker's power
+    WrongTotal { expected: u32, actual: u32 },
+    /// Damage was assigned to a creature that isn't blocking the attacker
+    NotBlocking(Entity),
+    /// Damage went further down the order before this blocker was assigned lethal damage
+    NotLethal(Entity),
+    /// Damage was assigned to the defender without trample
+    NoTrample,
+}
+
+impl fmt::Display for DamageAssignmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DamageAssignmentError::WrongTotal { expected, actual } => {
+                write!(f, "{} damage assigned, expected {}", actual, expected)
+            }
+            DamageAssignmentError::NotBlocking(blocker) => {
+                write!(f, "{:?} isn't blocking", blocker)
+            }
+            DamageAssignmentError::NotLethal(blocker) => {
+                write!(f, "{:?} must be assigned lethal damage first", blocker)
+            }
+            DamageAssignmentError::NoTrample => {
+                write!(f, "only attackers with trample can damage the defender")
+            }
+        }
+    }
+}
+
+/// A legal assignment of `power` damage to `blockers`, in order
+///
+/// Each blocker is assigned lethal damage in turn. With trample whatever is left
+/// goes to the defender; without it, the first blocker takes the rest.
+pub fn suggest_assignment(
+    power: u32,
+    blockers: &[BlockerDamage],
+    trample: bool,
+) -> DamageAssignment {
+    let mut remaining = power;
+    let mut assignment = DamageAssignment::default();
+    for blocker in blockers {
+        let damage = remaining.min(blocker.lethal);
+        remaining -= damage;
+        assignment.blockers.push((blocker.blocker, damage));
+    }
+
+    if trample {
+        assignment.defender = remaining;
+    } else if let Some((_, damage)) = assignment.blockers.first_mut() {
+        *damage += remaining;
+    }
+    // A blocked creature whose blockers are all gone deals no damage without trample
+    assignment
+}
+
+/// Check an assignment against the damage assignment order
+///
+/// A blocker can only be assigned damage once every blocker before it has been
+/// assigned lethal damage, and the defender only once they all have, and only
+/// by an attacker with trample.
+pub fn validate_assignment(
+    power: u32,
+    blockers: &[BlockerDamage],
+    trample: bool,
+    assignment: &DamageAssignment,
+) -> Result<(), DamageAssignmentError> {
+    for (blocker, _) in &assignment.blockers {
+        if !blockers.iter().any(|info| info.blocker == *blocker) {
+            return Err(DamageAssignmentError::NotBlocking(*blocker));
+        }
+    }
+
+    let expected = if blockers.is_empty() && !trample {
+        0
+    } else {
+        power
+    };
+    let actual = assignment.total();
+    if actual != expected {
+        return Err(DamageAssignmentError::WrongTotal { expected, actual });
+    }
+
+    let mut short = None;
+    for info in blockers {
+        let damage = assignment.damage_to(info.blocker);
+        if let Some(previous) = short.filter(|_| damage > 0) {
+            return Err(DamageAssignmentError::NotLethal(previous));
+        }
+        if short.is_none() && damage < info.lethal {
+            short = Some(info.blocker);
+        }
+    }
+
+    if assignment.defender > 0 {
+        if !trample {
+            return Err(DamageAssignmentError::NoTrample);
+        }
+        if let Some(previous) = short {
+            return Err(DamageAssignmentError::NotLethal(previous));
+        }
+    }
+    Ok(())
+}
+
+/// Whether an attacker tramples over its blockers
+pub fn has_trample(card: &Card) -> bool {
+    Card::has_keyword(card, KeywordAbility::Trample)
+}
+
+/// Whether a creature deals damage in the first strike combat damage step
+pub fn has_first_strike(card: &Card) -> bool {
+    Card::has_keyword(card, KeywordAbility::FirstStrike)
+        || Card::has_keyword(card, KeywordAbility::DoubleStrike)
+}
+
+/// Whether a creature deals damage in a combat damage step
+///
+/// In the first strike step, creatures with first strike or double strike deal
+/// damage. In the regular step, creatures that didn't deal first strike damage
+/// deal damage, along with double strikers.
+pub fn deals_damage_in_step(
+    creature: Entity,
+    card: &Card,
+    first_strike_step: bool,
+    first_strikers: &HashSet<Entity>,
+) -> bool {
+    if first_strike_step {
+        first_strikers.contains(&creature)
+    } else {
+        !first_strikers.contains(&creature) || Card::has_keyword(card, KeywordAbility::DoubleStrike)
+    }
+}
+
+/// Whether a creature's damage is lethal whatever its amount
+pub fn has_deathtouch(card: &Card) -> bool {
+    Card::has_keyword(card, KeywordAbility::Deathtouch)
+}
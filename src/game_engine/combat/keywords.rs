@@ -0,0 +1,294 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use crate::cards::Card;
+use crate::cards::details::CreatureOnField;
+use crate::cards::keywords::KeywordAbility;
+use crate::game_engine::commander::Commander;
+use crate::game_engine::enforcement::RulesEnforcementLevel;
+use crate::game_engine::permanent::{
+    Permanent, PermanentController, PermanentOwner, PermanentState,
+};
+use crate::game_engine::phase::{CombatStep, EndingStep, Phase};
+use crate::game_engine::turns::TurnManager;
+use crate::game_engine::zones::{Zone, ZoneChangeEvent, ZoneManager};
+use crate::player::Player;
+
+use super::combat::{BlockedStatus, CombatState, CreatureAttacksEvent};
+use super::damage::creature_stats;
+
+/// A token copy created by myriad, exiled at end of combat
+#[derive(Component, Debug, Clone, Copy)]
+pub struct MyriadToken;
+
+/// Power and toughness a creature gets from melee until end of turn
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MeleeBonus(pub i64);
+
+/// Sent to activate ninjutsu or commander ninjutsu
+///
+/// The unblocked attacker returns to its owner's hand and the ninja enters the
+/// battlefield tapped and attacking the same player. Commander ninjutsu also
+/// works from the command zone.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct NinjutsuEvent {
+    pub ninja: Entity,
+    /// The unblocked attacker returned to hand
+    pub returned: Entity,
+}
+
+/// Create myriad's token copies when a creature with myriad attacks
+///
+/// Each opponent other than the defending player gets a copy that enters the
+/// battlefield tapped and attacking them.
+#[allow(clippy::too_many_arguments)]
+pub fn create_myriad_tokens(
+    mut commands: Commands,
+    mut combat_state: ResMut<CombatState>,
+    mut attacks: EventReader<CreatureAttacksEvent>,
+    creatures: Query<(
+        &Card,
+        Option<&CreatureOnField>,
+        Option<&PermanentController>,
+    )>,
+    players: Query<Entity, With<Player>>,
+    zone_manager: Option<ResMut<ZoneManager>>,
+    turn_manager: Res<TurnManager>,
+) {
+    let Some(mut zone_manager) = zone_manager else {
+        attacks.clear();
+        return;
+    };
+
+    for event in attacks.read() {
+        let Ok((card, on_field, controller)) = creatures.get(event.attacker) else {
+            continue;
+        };
+        if !Card::has_keyword(card, KeywordAbility::Myriad) {
+            continue;
+        }
+        let Some(controller) = controller
+            .map(|controller| controller.player)
+            .or_else(|| zone_manager.get_card_owner(event.attacker))
+        else {
+            continue;
+        };
+
+        let (power, toughness, _) = creature_stats(card, on_field);
+        for opponent in players.iter() {
+            if opponent == controller || opponent == event.defender {
+                continue;
+            }
+            let mut state = PermanentState::new(turn_manager.turn_number);
+            state.tap();
+            let token = commands
+                .spawn((
+                    card.clone(),
+                    CreatureOnField {
+                        card: card.clone(),
+                        power_modifier: power,
+                        toughness_modifier: toughness,
+                        battle_damage: 0,
+                        token: true,
+                    },
+                    Permanent,
+                    state,
+                    PermanentOwner::new(controller),
+                    PermanentController::new(controller),
+                    MyriadToken,
+                    Name::new(format!("{} (Myriad)", card.name.name)),
+                ))
+                .id();
+            zone_manager.add_to_battlefield(controller, token);
+            combat_state.attackers.insert(token, opponent);
+            combat_state
+                .blocked_status
+                .insert(token, BlockedStatus::Unblocked);
+            info!("Myriad token of {} attacks {:?}", card.name.name, opponent);
+        }
+    }
+}
+
+/// Exile the myriad tokens as the end of combat step begins
+///
+/// Tokens cease to exist once they leave the battlefield, so they are despawned.
+pub fn exile_myriad_tokens(
+    mut commands: Commands,
+    phase: Res<Phase>,
+    tokens: Query<Entity, With<MyriadToken>>,
+    mut zone_manager: Option<ResMut<ZoneManager>>,
+) {
+    if !phase.is_changed() || *phase != Phase::Combat(CombatStep::End) {
+        return;
+    }
+
+    for token in tokens.iter() {
+        if let Some(zone_manager) = zone_manager.as_mut() {
+            zone_manager.remove(token);
+        }
+        commands.entity(token).despawn();
+    }
+}
+
+/// Give creatures with melee +1/+1 until end of turn for each opponent attacked
+///
+/// Applied once attackers are declared, as the declare blockers step begins.
+/// Only opponents attacked by declared attackers count, not those attacked by
+/// creatures put onto the battlefield attacking.
+pub fn apply_melee_bonuses(
+    mut commands: Commands,
+    combat_state: Res<CombatState>,
+    phase: Res<Phase>,
+    players: Query<(), With<Player>>,
+    undeclared: Query<(), With<MyriadToken>>,
+    mut creatures: Query<(&Card, &mut CreatureOnField, Option<&mut MeleeBonus>)>,
+) {
+    if !phase.is_changed() || *phase != Phase::Combat(CombatStep::DeclareBlockers) {
+        return;
+    }
+
+    let opponents = combat_state
+        .attackers
+        .iter()
+        .filter(|(attacker, defender)| {
+            !undeclared.contains(**attacker) && players.contains(**defender)
+        })
+        .map(|(_, defender)| *defender)
+        .collect::<HashSet<_>>()
+        .len() as i64;
+    if opponents == 0 {
+        return;
+    }
+
+    for &attacker in combat_state.attackers.keys() {
+        let Ok((card, mut on_field, bonus)) = creatures.get_mut(attacker) else {
+            continue;
+        };
+        if !Card::has_keyword(card, KeywordAbility::Melee) {
+            continue;
+        }
+        on_field.power_modifier += opponents;
+        on_field.toughness_modifier += opponents;
+        match bonus {
+            Some(mut bonus) => bonus.0 += opponents,
+            None => {
+                commands.entity(attacker).insert(MeleeBonus(opponents));
+            }
+        }
+    }
+}
+
+/// End melee bonuses in the cleanup step
+pub fn end_melee_bonuses(
+    mut commands: Commands,
+    phase: Res<Phase>,
+    mut creatures: Query<(Entity, &mut CreatureOnField, &MeleeBonus)>,
+) {
+    if !phase.is_changed() || *phase != Phase::Ending(EndingStep::Cleanup) {
+        return;
+    }
+
+    for (creature, mut on_field, bonus) in creatures.iter_mut() {
+        on_field.power_modifier -= bonus.0;
+        on_field.toughness_modifier -= bonus.0;
+        commands.entity(creature).remove::<MeleeBonus>();
+    }
+}
+
+/// Activate ninjutsu and commander ninjutsu
+///
+/// Ninjutsu can be activated once blockers are declared, returning an unblocked
+/// attacker. Activations that break the rules are rejected, or allowed with a
+/// warning, depending on the [`RulesEnforcementLevel`]. Mana costs aren't
+/// paid here, as with other activated abilities.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_ninjutsu_events(
+    mut commands: Commands,
+    mut combat_state: ResMut<CombatState>,
+    mut events: EventReader<NinjutsuEvent>,
+    mut zone_events: EventWriter<ZoneChangeEvent>,
+    phase: Res<Phase>,
+    zone_manager: Option<Res<ZoneManager>>,
+    cards: Query<(&Card, Has<Commander>)>,
+    controllers: Query<&PermanentController>,
+    turn_manager: Res<TurnManager>,
+    enforcement: Option<Res<RulesEnforcementLevel>>,
+) {
+    let enforcement = enforcement.as_deref().copied().unwrap_or_default();
+    let Some(zone_manager) = zone_manager else {
+        events.clear();
+        return;
+    };
+
+    for event in events.read() {
+        let (Some(&defender), Some(ninja_location), Some(returned_location)) = (
+            combat_state.attackers.get(&event.returned),
+            zone_manager.location(event.ninja),
+            zone_manager.location(event.returned),
+        ) else {
+            warn!(
+                "Ninjutsu needs an attacking creature and a card in a zone, got {:?} and {:?}",
+                event.returned, event.ninja
+            );
+            continue;
+        };
+        let controller = controllers
+            .get(event.returned)
+            .map_or(returned_location.owner, |controller| controller.player);
+
+        let from_zone_allowed =
+            cards
+                .get(event.ninja)
+                .is_ok_and(|(card, is_commander)| match ninja_location.zone {
+                    Zone::Hand => {
+                        Card::has_keyword(card, KeywordAbility::Ninjutsu)
+                            || Card::has_keyword(card, KeywordAbility::CommanderNinjutsu)
+                    }
+                    Zone::Command => {
+                        is_commander && Card::has_keyword(card, KeywordAbility::CommanderNinjutsu)
+                    }
+                    _ => false,
+                });
+        let legal = from_zone_allowed
+            && ninja_location.owner == controller
+            && *phase == Phase::Combat(CombatStep::DeclareBlockers)
+            && combat_state.blocked_status.get(&event.returned) == Some(&BlockedStatus::Unblocked);
+        let activation = format!(
+            "ninjutsu of {:?} returning {:?}",
+            event.ninja, event.returned
+        );
+        if !enforcement.allows(legal, activation) {
+            continue;
+        }
+
+        combat_state.attackers.remove(&event.returned);
+        combat_state.blocked_status.remove(&event.returned);
+        combat_state.damage_assignments.remove(&event.returned);
+        zone_events.write(ZoneChangeEvent {
+            card: event.returned,
+            owner: returned_location.owner,
+            source: Zone::Battlefield,
+            destination: Zone::Hand,
+            was_visible: true,
+            is_visible: true,
+        });
+
+        // The ninja enters tapped; entering the battlefield keeps this state
+        let mut state = PermanentState::new(turn_manager.turn_number);
+        state.tap();
+        commands.entity(event.ninja).insert(state);
+        zone_events.write(ZoneChangeEvent {
+            card: event.ninja,
+            owner: ninja_location.owner,
+            source: ninja_location.zone,
+            destination: Zone::Battlefield,
+            was_visible: ninja_location.zone == Zone::Command,
+            is_visible: true,
+        });
+        combat_state.attackers.insert(event.ninja, defender);
+        combat_state
+            .blocked_status
+            .insert(event.ninja, BlockedStatus::Unblocked);
+    }
+}
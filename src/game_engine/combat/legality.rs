@@ -0,0 +1,322 @@
+use std::fmt;
+
+use bevy::prelude::*;
+
+use crate::cards::keywords::KeywordAbility;
+use crate::cards::{Card, CardDetails, CardTypes};
+use crate::game_engine::permanent::PermanentState;
+use crate::mana::ManaColor;
+
+use super::combat::CombatState;
+
+/// Cards and permanent state of creatures that may attack or block
+pub type CombatCreatureQuery<'w, 's> = Query<'w, 's, (&'static Card, &'static PermanentState)>;
+
+/// An attack or block that breaks a restriction or requirement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CombatViolation {
+    /// The creature can't attack at all
+    CantAttack(Entity),
+    /// The creature can't attack this player
+    CantAttackPlayer { attacker: Entity, defender: Entity },
+    /// The creature is the only attacker but can't attack alone
+    CantAttackAlone(Entity),
+    /// The creature must attack if able, but didn't
+    MustAttack(Entity),
+    /// The creature must attack one of some players if able, but attacked another
+    MustAttackPlayer { attacker: Entity, defender: Entity },
+    /// The creature can't block at all
+    CantBlock(Entity),
+    /// The creature can't block attacks against this player
+    CantBlockAttacksAgainst { blocker: Entity, defender: Entity },
+    /// The attacker can't be blocked by this creature, e.g. because it has flying
+    CantBeBlockedBy { attacker: Entity, blocker: Entity },
+    /// The attacker has menace but is blocked by a single creature
+    Menace(Entity),
+}
+
+impl fmt::Display for CombatViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CombatViolation::CantAttack(attacker) => write!(f, "{:?} can't attack", attacker),
+            CombatViolation::CantAttackPlayer { attacker, defender } => {
+                write!(f, "{:?} can't attack {:?}", attacker, defender)
+            }
+            CombatViolation::CantAttackAlone(attacker) => {
+                write!(f, "{:?} can't attack alone", attacker)
+            }
+            CombatViolation::MustAttack(attacker) => {
+                write!(f, "{:?} must attack if able", attacker)
+            }
+            CombatViolation::MustAttackPlayer { attacker, defender } => write!(
+                f,
+                "{:?} must attack another player if able, not {:?}",
+                attacker, defender
+            ),
+            CombatViolation::CantBlock(blocker) => write!(f, "{:?} can't block", blocker),
+            CombatViolation::CantBlockAttacksAgainst { blocker, defender } => write!(
+                f,
+                "{:?} can't block creatures attacking {:?}",
+                blocker, defender
+            ),
+            CombatViolation::CantBeBlockedBy { attacker, blocker } => {
+                write!(f, "{:?} can't be blocked by {:?}", attacker, blocker)
+            }
+            CombatViolation::Menace(attacker) => write!(
+                f,
+                "{:?} has menace and can't be blocked except by two or more creatures",
+                attacker
+            ),
+        }
+    }
+}
+
+/// Whether a sentence of the card's rules text puts `restriction` on the card
+/// itself, as in "This creature can't block."
+///
+/// Restrictions the card puts on other creatures, such as "Creatures your
+/// opponents control can't block.", don't count.
+fn restricts_itself(card: &Card, restriction: &str) -> bool {
+    let name = card.name.name.to_lowercase();
+    card.rules_text
+        .rules_text
+        .to_lowercase()
+        .split(['.', '\n'])
+        .map(str::trim)
+        .any(|sentence| {
+            ["this creature", "~", name.as_str()]
+                .iter()
+                .any(|subject| sentence == format!("{} {}", subject, restriction))
+        })
+}
+
+/// Whether a creature can be declared as an attacker
+///
+/// It has to be untapped and have been under its controller's control since the
+/// turn began, unless it has haste. Creatures with defender or rules text saying
+/// they can't attack can't attack.
+pub fn can_attack(card: &Card, state: &PermanentState) -> bool {
+    !state.is_tapped && may_attack(card, state)
+}
+
+/// Whether anything other than being tapped stops a creature from attacking
+fn may_attack(card: &Card, state: &PermanentState) -> bool {
+    card.type_info.types.contains(CardTypes::CREATURE)
+        && !Card::has_keyword(card, KeywordAbility::Defender)
+        && !restricts_itself(card, "can't attack")
+        && !restricts_itself(card, "can't attack or block")
+        && (!state.has_summoning_sickness || Card::has_keyword(card, KeywordAbility::Haste))
+}
+
+/// Whether a creature can be declared as a blocker
+pub fn can_block(card: &Card, state: &PermanentState) -> bool {
+    card.type_info.types.contains(CardTypes::CREATURE)
+        && !restricts_itself(card, "can't block")
+        && !restricts_itself(card, "can't attack or block")
+        && !state.is_tapped
+}
+
+/// Whether an attacker's evasion lets `blocker` block it
+///
+/// Covers flying, fear, skulk, "can't be blocked" and the attacker's
+/// [`BlockRestriction`](super::BlockRestriction)s.
+pub fn can_be_blocked_by(
+    combat_state: &CombatState,
+    attacker: Entity,
+    attacker_card: &Card,
+    blocker_card: &Card,
+) -> bool {
+    let power = |card: &Card| match &card.details.details {
+        CardDetails::Creature(creature) => creature.power,
+        _ => 0,
+    };
+    let flies = |card: &Card| {
+        Card::has_keyword(card, KeywordAbility::Flying)
+            || Card::has_keyword(card, KeywordAbility::Reach)
+    };
+
+    if restricts_itself(attacker_card, "can't be blocked") {
+        return false;
+    }
+    if Card::has_keyword(attacker_card, KeywordAbility::Flying) && !flies(blocker_card) {
+        return false;
+    }
+    if Card::has_keyword(attacker_card, KeywordAbility::Fear)
+        && !blocker_card.type_info.types.contains(CardTypes::ARTIFACT)
+        && !blocker_card.cost.cost.color.contains(ManaColor::BLACK)
+    {
+        return false;
+    }
+    if Card::has_keyword(attacker_card, KeywordAbility::Skulk)
+        && power(blocker_card) > power(attacker_card)
+    {
+        return false;
+    }
+    !combat_state
+        .cannot_be_blocked_by
+        .get(&attacker)
+        .is_some_and(|restrictions| {
+            restrictions
+                .iter()
+                .any(|restriction| restriction.forbids(blocker_card))
+        })
+}
+
+/// Check a single attack against the attacker's restrictions
+///
+/// `creature` is `None` when the attacker isn't a permanent on the battlefield.
+pub fn check_attack(
+    combat_state: &CombatState,
+    attacker: Entity,
+    defender: Entity,
+    creature: Option<(&Card, &PermanentState)>,
+) -> Option<CombatViolation> {
+    if !creature.is_some_and(|(card, state)| can_attack(card, state)) {
+        return Some(CombatViolation::CantAttack(attacker));
+    }
+    defender_restriction(combat_state, attacker, defender)
+}
+
+/// Whether the attacker is forbidden from attacking this defender, as a goaded
+/// creature is its goader
+fn defender_restriction(
+    combat_state: &CombatState,
+    attacker: Entity,
+    defender: Entity,
+) -> Option<CombatViolation> {
+    let restricted = combat_state
+        .cannot_attack
+        .get(&attacker)
+        .is_some_and(|defenders| defenders.contains(&defender));
+    restricted.then_some(CombatViolation::CantAttackPlayer { attacker, defender })
+}
+
+/// Check a single block against the blocker's and the attacker's restrictions
+pub fn check_block(
+    combat_state: &CombatState,
+    attacker: Entity,
+    blocker: Entity,
+    attacker_card: Option<&Card>,
+    creature: Option<(&Card, &PermanentState)>,
+) -> Option<CombatViolation> {
+    let Some((blocker_card, _)) = creature.filter(|(card, state)| {
+        can_block(card, state) && !combat_state.cannot_block.contains(&blocker)
+    }) else {
+        return Some(CombatViolation::CantBlock(blocker));
+    };
+
+    if let Some(&defender) = combat_state.attackers.get(&attacker) {
+        let restricted = combat_state
+            .cannot_block_attacks_against
+            .get(&blocker)
+            .is_some_and(|defenders| defenders.contains(&defender));
+        if restricted {
+            return Some(CombatViolation::CantBlockAttacksAgainst { blocker, defender });
+        }
+    }
+
+    let evaded = attacker_card.is_some_and(|attacker_card| {
+        !can_be_blocked_by(combat_state, attacker, attacker_card, blocker_card)
+    });
+    evaded.then_some(CombatViolation::CantBeBlockedBy { attacker, blocker })
+}
+
+/// Every broken restriction and requirement of the declared attacks
+///
+/// Besides each attack on its own, this checks "can't attack alone" and that
+/// creatures which must attack if able, such as goaded ones, did.
+pub fn attack_violations(
+    combat_state: &CombatState,
+    creatures: &CombatCreatureQuery,
+) -> Vec<CombatViolation> {
+    let mut attackers: Vec<(Entity, Entity)> = combat_state
+        .attackers
+        .iter()
+        .map(|(attacker, defender)| (*attacker, *defender))
+        .collect();
+    attackers.sort();
+
+    let mut violations: Vec<CombatViolation> = attackers
+        .iter()
+        .filter_map(|&(attacker, defender)| {
+            // Attacking tapped the creature, so only its other restrictions still apply
+            let allowed = creatures
+                .get(attacker)
+                .is_ok_and(|(card, state)| may_attack(card, state));
+            if !allowed {
+                return Some(CombatViolation::CantAttack(attacker));
+            }
+            defender_restriction(combat_state, attacker, defender)
+        })
+        .collect();
+
+    if let [(attacker, _)] = attackers[..] {
+        let alone = creatures
+            .get(attacker)
+            .is_ok_and(|(card, _)| restricts_itself(card, "can't attack alone"));
+        if alone {
+            violations.push(CombatViolation::CantAttackAlone(attacker));
+        }
+    }
+
+    let mut required: Vec<(&Entity, &Vec<Entity>)> = combat_state.must_attack.iter().collect();
+    required.sort();
+    for (&creature, players) in required {
+        match combat_state.attackers.get(&creature) {
+            None => {
+                // A creature that isn't able to attack isn't required to
+                let able = creatures
+                    .get(creature)
+                    .is_ok_and(|(card, state)| can_attack(card, state));
+                if able {
+                    violations.push(CombatViolation::MustAttack(creature));
+                }
+            }
+            Some(&defender) if !players.is_empty() && !players.contains(&defender) => {
+                let could_have = players
+                    .iter()
+                    .any(|&player| defender_restriction(combat_state, creature, player).is_none());
+                if could_have {
+                    violations.push(CombatViolation::MustAttackPlayer {
+                        attacker: creature,
+                        defender,
+                    });
+                }
+            }
+            Some(_) => {}
+        }
+    }
+    violations
+}
+
+/// Every broken restriction and requirement of the declared blocks
+///
+/// Besides each block on its own, this checks that attackers with menace are
+/// blocked by two or more creatures.
+pub fn block_violations(
+    combat_state: &CombatState,
+    creatures: &CombatCreatureQuery,
+) -> Vec<CombatViolation> {
+    let mut blocks: Vec<(&Entity, &Vec<Entity>)> = combat_state.blockers.iter().collect();
+    blocks.sort();
+
+    let mut violations = Vec::new();
+    for (&attacker, blockers) in blocks {
+        let attacker_card = creatures.get(attacker).ok().map(|(card, _)| card);
+        violations.extend(blockers.iter().filter_map(|&blocker| {
+            check_block(
+                combat_state,
+                attacker,
+                blocker,
+                attacker_card,
+                creatures.get(blocker).ok(),
+            )
+        }));
+        let menace =
+            attacker_card.is_some_and(|card| Card::has_keyword(card, KeywordAbility::Menace));
+        if menace && blockers.len() == 1 {
+            violations.push(CombatViolation::Menace(attacker));
+        }
+    }
+    violations
+}
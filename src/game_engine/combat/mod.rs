@@ -1,13 +1,33 @@
 mod combat;
+mod damage;
+mod keywords;
+mod legality;
 mod test_utils;
 
+#[cfg(test)]
+mod tests;
+
 pub use combat::{
-    AssignCombatDamageEvent, AttackerDeclaredEvent, BlockerDeclaredEvent, CombatBeginEvent,
-    CombatDamageCompleteEvent, CombatEndEvent, CombatState, CreatureAttacksEvent,
-    CreatureBlockedEvent, CreatureBlocksEvent, DeclareAttackersEvent,
-    DeclareAttackersStepBeginEvent, DeclareAttackersStepEndEvent, DeclareBlockersEvent,
-    DeclareBlockersStepBeginEvent, DeclareBlockersStepEndEvent, assign_combat_damage_system,
-    declare_attackers_system, declare_blockers_system, end_combat_system,
-    handle_declare_attackers_event, handle_declare_blockers_event, initialize_combat_phase,
-    process_combat_damage_system,
+    AssignCombatDamageEvent, AttackerDeclaredEvent, BlockRestriction, BlockedStatus,
+    BlockerDeclaredEvent, CombatBeginEvent, CombatDamageCompleteEvent, CombatEndEvent, CombatState,
+    Comparison, CreatureAttacksEvent, CreatureBlockedEvent, CreatureBlocksEvent,
+    DamageAssignmentEvent, DeclareAttackersEvent, DeclareAttackersStepBeginEvent,
+    DeclareAttackersStepEndEvent, DeclareBlockersEvent, DeclareBlockersStepBeginEvent,
+    DeclareBlockersStepEndEvent, OrderBlockersEvent, assign_combat_damage_system,
+    declare_attackers_system, declare_blockers_system, end_combat_system, flag_combat_violations,
+    handle_blocker_order_events, handle_damage_assignment_events, handle_declare_attackers_event,
+    handle_declare_blockers_event, initialize_combat_phase, process_combat_damage_system,
+};
+pub use damage::{
+    BlockerDamage, CombatantQuery, DamageAssignment, DamageAssignmentError, blocker_damage,
+    combat_power, creature_stats, deals_damage_in_step, has_deathtouch, has_first_strike,
+    has_trample, lethal_damage, suggest_assignment, validate_assignment,
+};
+pub use keywords::{
+    MeleeBonus, MyriadToken, NinjutsuEvent, apply_melee_bonuses, create_myriad_tokens,
+    end_melee_bonuses, exile_myriad_tokens, handle_ninjutsu_events,
+};
+pub use legality::{
+    CombatCreatureQuery, CombatViolation, attack_violations, block_violations, can_attack,
+    can_be_blocked_by, can_block, check_attack, check_block,
 };
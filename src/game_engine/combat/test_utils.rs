@@ -1,6 +1,7 @@
 use super::combat::CombatState;
-use crate::game_engine::commander::{CombatDamageEvent, Commander};
-use crate::game_engine::state::GameState;
+use crate::game_engine::commander::CombatDamageEvent;
+use crate::game_engine::damage::{DamageKind, DamageLedger, DamageRecord};
+use crate::game_engine::state::{GameEvent, GameState};
 use crate::player::Player;
 use bevy::prelude::*;
 use std::collections::HashMap;
@@ -75,25 +76,22 @@ pub fn apply_combat_damage(app: &mut App, damage_events: Vec<CombatDamageEvent>)
 
             // Update game state
             if let Some(mut game_state) = world.get_resource_mut::<GameState>() {
-                game_state.state_based_actions_performed = true;
+                game_state.apply(&GameEvent::StateBasedActionsChecked { performed: true });
             }
 
-            // Handle commander damage separately
-            if event.source_is_commander {
-                if let Some(mut commander) = world.get_mut::<Commander>(event.source) {
-                    // Update the commander's damage tracking
-                    if let Some(damage_entry) = commander
-                        .damage_dealt
-                        .iter_mut()
-                        .find(|(p, _)| *p == player_entity)
-                    {
-                        // Update existing damage entry
-                        damage_entry.1 += event.damage;
-                    } else {
-                        // Add a new damage entry
-                        commander.damage_dealt.push((player_entity, event.damage));
-                    }
-                }
+            // Record the damage, which also tracks commander damage
+            let turn = world
+                .get_resource::<GameState>()
+                .map_or(0, |game_state| game_state.turn_number);
+            if let Some(mut ledger) = world.get_resource_mut::<DamageLedger>() {
+                ledger.record(DamageRecord {
+                    source: event.source,
+                    target: player_entity,
+                    amount: event.damage,
+                    kind: DamageKind::Combat,
+                    turn,
+                    source_is_commander: event.source_is_commander,
+                });
             }
         }
     }
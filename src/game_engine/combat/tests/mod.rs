@@ -0,0 +1,667 @@
+use bevy::prelude::*;
+
+use crate::cards::details::CreatureOnField;
+use crate::cards::keywords::KeywordAbility;
+use crate::cards::{Card, CardDetails, CardTypes, CreatureCard, CreatureType};
+use crate::game_engine::combat::{
+    AssignCombatDamageEvent, AttackerDeclaredEvent, BlockedStatus, BlockerDamage,
+    BlockerDeclaredEvent, CombatState, CreatureAttacksEvent, CreatureBlockedEvent,
+    CreatureBlocksEvent, DamageAssignment, DamageAssignmentError, DamageAssignmentEvent,
+    MyriadToken, NinjutsuEvent, OrderBlockersEvent, apply_melee_bonuses,
+    assign_combat_damage_system, create_myriad_tokens, declare_attackers_system,
+    declare_blockers_system, handle_blocker_order_events, handle_damage_assignment_events,
+    handle_ninjutsu_events, lethal_damage, process_combat_damage_system, suggest_assignment,
+    validate_assignment,
+};
+use crate::game_engine::commander::CombatDamageEvent;
+use crate::game_engine::enforcement::RulesEnforcementLevel;
+use crate::game_engine::life::{LifeChangeEvent, LifeChangedEvent, apply_life_changes};
+use crate::game_engine::permanent::{PermanentController, PermanentState};
+use crate::game_engine::phase::{CombatStep, Phase};
+use crate::game_engine::turns::TurnManager;
+use crate::game_engine::zones::{Zone, ZoneChangeEvent, ZoneManager, handle_zone_changes};
+use crate::mana::Mana;
+use crate::player::Player;
+
+fn creature(name: &str, rules_text: &str) -> Card {
+    sized_creature(name, 2, 2, rules_text)
+}
+
+fn sized_creature(name: &str, power: i32, toughness: i32, rules_text: &str) -> Card {
+    Card::builder(name)
+        .cost(Mana::default())
+        .types(CardTypes::CREATURE)
+        .details(CardDetails::Creature(CreatureCard {
+            power,
+            toughness,
+            creature_type: CreatureType::NONE,
+        }))
+        .rules_text(rules_text)
+        .build_or_panic()
+}
+
+fn combat_app(enforcement: RulesEnforcementLevel) -> App {
+    let mut app = App::new();
+    app.init_resource::<CombatState>()
+        .insert_resource(enforcement)
+        .insert_resource(Phase::Combat(CombatStep::DeclareBlockers))
+        .add_event::<AttackerDeclaredEvent>()
+        .add_event::<BlockerDeclaredEvent>()
+        .add_event::<CreatureAttacksEvent>()
+        .add_event::<CreatureBlocksEvent>()
+        .add_event::<CreatureBlockedEvent>()
+        .add_systems(
+            Update,
+            (
+                declare_attackers_system,
+                declare_blockers_system,
+                flag_combat_violations,
+            )
+                .chain(),
+        );
+    app
+}
+
+/// Spawn a creature that entered on turn 1; summoning sick ones entered this turn
+fn spawn_creature(app: &mut App, name: &str, rules_text: &str, sick: bool) -> Entity {
+    let mut state = PermanentState::new(1);
+    if !sick {
+        state.update_summoning_sickness(2);
+    }
+    app.world_mut()
+        .spawn((creature(name, rules_text), state))
+        .id()
+}
+
+fn is_tapped(app: &App, creature: Entity) -> bool {
+    app.world()
+        .get::<PermanentState>(creature)
+        .unwrap()
+        .is_tapped
+}
+
+#[test]
+fn test_attackers_are_tapped_unless_they_have_vigilance() {
+    let mut app = combat_app(RulesEnforcementLevel::Full);
+    let defender = app.world_mut().spawn_empty().id();
+    let bears = spawn_creature(&mut app, "Grizzly Bears", "", false);
+    let knight = spawn_creature(&mut app, "Serra Angel", "Flying, vigilance", false);
+    let sick = spawn_creature(&mut app, "Hill Giant", "", true);
+    let hasty = spawn_creature(&mut app, "Raging Goblin", "Haste", true);
+    let wall = spawn_creature(&mut app, "Wall of Stone", "Defender", false);
+
+    for attacker in [bears, knight, sick, hasty, wall] {
+        app.world_mut()
+            .send_event(AttackerDeclaredEvent { attacker, defender });
+    }
+    app.update();
+
+    let combat = app.world().resource::<CombatState>();
+    assert!(combat.attackers.contains_key(&bears));
+    assert!(combat.attackers.contains_key(&knight));
+    assert!(combat.attackers.contains_key(&hasty));
+    assert!(!combat.attackers.contains_key(&sick));
+    assert!(!combat.attackers.contains_key(&wall));
+
+    assert!(is_tapped(&app, bears));
+    assert!(is_tapped(&app, hasty));
+    assert!(!is_tapped(&app, knight));
+    assert!(!is_tapped(&app, sick));
+}
+
+#[test]
+fn test_tapped_creatures_cannot_block() {
+    let mut app = combat_app(RulesEnforcementLevel::Full);
+    let defender = app.world_mut().spawn_empty().id();
+    let attacker = spawn_creature(&mut app, "Grizzly Bears", "", false);
+    let untapped = spawn_creature(&mut app, "Hill Giant", "", true);
+    let tapped = spawn_creature(&mut app, "Llanowar Elves", "", false);
+    app.world_mut()
+        .get_mut::<PermanentState>(tapped)
+        .unwrap()
+        .tap();
+
+    app.world_mut()
+        .send_event(AttackerDeclaredEvent { attacker, defender });
+    app.update();
+    for blocker in [untapped, tapped] {
+        app.world_mut()
+            .send_event(BlockerDeclaredEvent { blocker, attacker });
+    }
+    app.update();
+
+    let combat = app.world().resource::<CombatState>();
+    assert_eq!(combat.blockers.get(&attacker), Some(&vec![untapped]));
+}
+
+#[test]
+fn test_assisted_enforcement_allows_illegal_attacks() {
+    let mut app = combat_app(RulesEnforcementLevel::Assisted);
+    let defender = app.world_mut().spawn_empty().id();
+    let sick = spawn_creature(&mut app, "Hill Giant", "", true);
+
+    app.world_mut().send_event(AttackerDeclaredEvent {
+        attacker: sick,
+        defender,
+    });
+    app.update();
+
+    assert!(
+        app.world()
+            .resource::<CombatState>()
+            .attackers
+            .contains_key(&sick)
+    );
+    assert!(is_tapped(&app, sick));
+}
+
+#[test]
+fn test_fliers_can_only_be_blocked_by_flying_or_reach() {
+    let mut app = combat_app(RulesEnforcementLevel::Full);
+    let defender = app.world_mut().spawn_empty().id();
+    let attacker = spawn_creature(&mut app, "Wind Drake", "Flying", false);
+    let ground = spawn_creature(&mut app, "Grizzly Bears", "", false);
+    let spider = spawn_creature(&mut app, "Giant Spider", "Reach", false);
+    let pacifist = spawn_creature(&mut app, "Pacifist", "This creature can't block.", false);
+
+    app.world_mut()
+        .send_event(AttackerDeclaredEvent { attacker, defender });
+    app.update();
+    for blocker in [ground, spider, pacifist] {
+        app.world_mut()
+            .send_event(BlockerDeclaredEvent { blocker, attacker });
+    }
+    app.update();
+
+    let combat = app.world().resource::<CombatState>();
+    assert_eq!(combat.blockers.get(&attacker), Some(&vec![spider]));
+}
+
+#[test]
+fn test_declaration_wide_requirements_are_flagged() {
+    let mut app = combat_app(RulesEnforcementLevel::Full);
+    let defender = app.world_mut().spawn_empty().id();
+    let loner = spawn_creature(
+        &mut app,
+        "Mogg Flunkies",
+        "This creature can't attack alone.",
+        false,
+    );
+    let goaded = spawn_creature(&mut app, "Grizzly Bears", "", false);
+    app.world_mut()
+        .resource_mut::<CombatState>()
+        .must_attack
+        .insert(goaded, Vec::new());
+
+    app.world_mut().send_event(AttackerDeclaredEvent {
+        attacker: loner,
+        defender,
+    });
+    app.update();
+
+    let violations = &app.world().resource::<CombatState>().violations;
+    assert!(violations.contains(&CombatViolation::CantAttackAlone(loner)));
+    assert!(violations.contains(&CombatViolation::MustAttack(goaded)));
+
+    // Once the goaded creature attacks too, both requirements are met
+    app.world_mut().send_event(AttackerDeclaredEvent {
+        attacker: goaded,
+        defender,
+    });
+    app.update();
+    assert!(app.world().resource::<CombatState>().violations.is_empty());
+}
+
+#[test]
+fn test_menace_needs_two_blockers() {
+    let mut app = combat_app(RulesEnforcementLevel::Full);
+    let defender = app.world_mut().spawn_empty().id();
+    let attacker = spawn_creature(&mut app, "Goblin Heelcutter", "Menace", false);
+    let first = spawn_creature(&mut app, "Grizzly Bears", "", false);
+    let second = spawn_creature(&mut app, "Hill Giant", "", false);
+
+    app.world_mut()
+        .send_event(AttackerDeclaredEvent { attacker, defender });
+    app.update();
+    app.world_mut().send_event(BlockerDeclaredEvent {
+        blocker: first,
+        attacker,
+    });
+    app.update();
+    assert_eq!(
+        app.world().resource::<CombatState>().violations,
+        vec![CombatViolation::Menace(attacker)]
+    );
+
+    app.world_mut().send_event(BlockerDeclaredEvent {
+        blocker: second,
+        attacker,
+    });
+    app.update();
+    assert!(app.world().resource::<CombatState>().violations.is_empty());
+}
+
+fn blockers(lethal: &[u32]) -> Vec<BlockerDamage> {
+    lethal
+        .iter()
+        .enumerate()
+        .map(|(index, &lethal)| BlockerDamage {
+            blocker: Entity::from_raw(index as u32 + 1),
+            lethal,
+        })
+        .collect()
+}
+
+#[test]
+fn test_lethal_damage_counts_marked_damage_and_deathtouch() {
+    assert_eq!(lethal_damage(4, 0, false), 4);
+    assert_eq!(lethal_damage(4, 3, false), 1);
+    assert_eq!(lethal_damage(4, 0, true), 1);
+}
+
+#[test]
+fn test_suggested_assignment_follows_the_order() {
+    let order = blockers(&[2, 3]);
+
+    let without_trample = suggest_assignment(7, &order, false);
+    assert_eq!(
+        without_trample.blockers,
+        vec![(order[0].blocker, 4), (order[1].blocker, 3)]
+    );
+    assert_eq!(without_trample.defender, 0);
+
+    let with_trample = suggest_assignment(7, &order, true);
+    assert_eq!(
+        with_trample.blockers,
+        vec![(order[0].blocker, 2), (order[1].blocker, 3)]
+    );
+    assert_eq!(with_trample.defender, 2);
+
+    // Not enough power to get past the first blocker
+    let short = suggest_assignment(1, &order, true);
+    assert_eq!(short.damage_to(order[0].blocker), 1);
+    assert_eq!(short.damage_to(order[1].blocker), 0);
+    assert_eq!(short.defender, 0);
+
+    for assignment in [&without_trample, &with_trample] {
+        let trample = assignment.defender > 0;
+        assert_eq!(validate_assignment(7, &order, trample, assignment), Ok(()));
+    }
+}
+
+#[test]
+fn test_assignments_breaking_the_order_are_rejected() {
+    let order = blockers(&[2, 3]);
+    let (first, second) = (order[0].blocker, order[1].blocker);
+    let assignment = |first_damage, second_damage, defender| DamageAssignment {
+        blockers: vec![(first, first_damage), (second, second_damage)],
+        defender,
+    };
+
+    assert_eq!(
+        validate_assignment(5, &order, false, &assignment(1, 4, 0)),
+        Err(DamageAssignmentError::NotLethal(first))
+    );
+    assert_eq!(
+        validate_assignment(5, &order, false, &assignment(2, 2, 0)),
+        Err(DamageAssignmentError::WrongTotal {
+            expected: 5,
+            actual: 4
+        })
+    );
+    assert_eq!(
+        validate_assignment(6, &order, false, &assignment(2, 3, 1)),
+        Err(DamageAssignmentError::NoTrample)
+    );
+    assert_eq!(
+        validate_assignment(6, &order, true, &assignment(2, 2, 2)),
+        Err(DamageAssignmentError::NotLethal(second))
+    );
+    // More than lethal damage may go to an earlier blocker
+    assert_eq!(
+        validate_assignment(6, &order, false, &assignment(3, 3, 0)),
+        Ok(())
+    );
+
+    let deathtouch = blockers(&[1, 1]);
+    assert_eq!(
+        validate_assignment(
+            3,
+            &deathtouch,
+            true,
+            &DamageAssignment {
+                blockers: vec![(deathtouch[0].blocker, 1), (deathtouch[1].blocker, 1)],
+                defender: 1,
+            }
+        ),
+        Ok(())
+    );
+}
+
+fn spawn_combatant(app: &mut App, card: Card) -> Entity {
+    let on_field = match &card.details.details {
+        CardDetails::Creature(creature) => CreatureOnField {
+            card: card.clone(),
+            power_modifier: creature.power as i64,
+            toughness_modifier: creature.toughness as i64,
+            battle_damage: 0,
+            token: false,
+        },
+        _ => unreachable!(),
+    };
+    app.world_mut().spawn((card, on_field)).id()
+}
+
+fn damage_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<CombatState>()
+        .add_event::<OrderBlockersEvent>()
+        .add_event::<DamageAssignmentEvent>()
+        .add_event::<AssignCombatDamageEvent>()
+        .add_event::<CombatDamageEvent>()
+        .add_event::<LifeChangeEvent>()
+        .add_event::<LifeChangedEvent>()
+        .add_systems(
+            Update,
+            (
+                handle_blocker_order_events,
+                handle_damage_assignment_events,
+                assign_combat_damage_system,
+                process_combat_damage_system,
+                apply_life_changes,
+            )
+                .chain(),
+        );
+    app
+}
+
+fn battle_damage(app: &App, creature: Entity) -> u64 {
+    app.world()
+        .get::<CreatureOnField>(creature)
+        .unwrap()
+        .battle_damage
+}
+
+#[test]
+fn test_trampler_damage_is_split_between_blockers_and_the_player() {
+    let mut app = damage_app();
+    let player = app.world_mut().spawn(Player::new("Defender")).id();
+    let attacker = spawn_combatant(
+        &mut app,
+        sized_creature("Colossal Dreadmaw", 6, 6, "Trample"),
+    );
+    let bears = spawn_combatant(&mut app, sized_creature("Grizzly Bears", 2, 2, ""));
+    let giant = spawn_combatant(&mut app, sized_creature("Hill Giant", 3, 3, ""));
+    {
+        let mut combat = app.world_mut().resource_mut::<CombatState>();
+        combat.attackers.insert(attacker, player);
+        combat.blockers.insert(attacker, vec![bears, giant]);
+    }
+
+    // The attacker puts the giant first, then takes the suggested split
+    app.world_mut().send_event(OrderBlockersEvent {
+        attacker,
+        order: vec![giant, bears],
+    });
+    app.world_mut().send_event(AssignCombatDamageEvent {
+        is_first_strike: false,
+    });
+    app.update();
+
+    assert_eq!(battle_damage(&app, giant), 3);
+    assert_eq!(battle_damage(&app, bears), 2);
+    assert_eq!(battle_damage(&app, attacker), 5);
+    let life = app.world().get::<Player>(player).unwrap().life;
+    assert_eq!(life, 39);
+}
+
+#[test]
+fn test_first_strike_and_double_strike_damage_steps() {
+    let mut app = damage_app();
+    let player = app.world_mut().spawn(Player::new("Defender")).id();
+    let knight = spawn_combatant(
+        &mut app,
+        sized_creature("White Knight", 2, 2, "First strike"),
+    );
+    let fencer = spawn_combatant(
+        &mut app,
+        sized_creature("Fencing Ace", 1, 1, "Double strike"),
+    );
+    let bears = spawn_combatant(&mut app, sized_creature("Grizzly Bears", 2, 2, ""));
+    {
+        let mut combat = app.world_mut().resource_mut::<CombatState>();
+        combat.attackers.insert(knight, player);
+        combat.attackers.insert(fencer, player);
+        combat.blockers.insert(knight, vec![bears]);
+    }
+    // Only the first and double strikers deal damage first
+    app.world_mut().send_event(AssignCombatDamageEvent {
+        is_first_strike: true,
+    });
+    app.update();
+    assert_eq!(battle_damage(&app, bears), 2);
+    assert_eq!(battle_damage(&app, knight), 0);
+    assert_eq!(app.world().get::<Player>(player).unwrap().life, 39);
+
+    // Then everyone else, and the double striker again
+    app.world_mut().send_event(AssignCombatDamageEvent {
+        is_first_strike: false,
+    });
+    app.update();
+    assert_eq!(battle_damage(&app, bears), 2);
+    assert_eq!(battle_damage(&app, knight), 2);
+    assert_eq!(app.world().get::<Player>(player).unwrap().life, 38);
+}
+
+#[test]
+fn test_chosen_assignment_is_used_when_legal() {
+    let mut app = damage_app();
+    let player = app.world_mut().spawn(Player::new("Defender")).id();
+    let attacker = spawn_combatant(&mut app, sized_creature("Hill Giant", 3, 3, ""));
+    let first = spawn_combatant(&mut app, sized_creature("Llanowar Elves", 1, 1, ""));
+    let second = spawn_combatant(&mut app, sized_creature("Grizzly Bears", 2, 2, ""));
+    {
+        let mut combat = app.world_mut().resource_mut::<CombatState>();
+        combat.attackers.insert(attacker, player);
+        combat.blockers.insert(attacker, vec![first, second]);
+    }
+
+    // Skipping the first blocker breaks the order and is ignored
+    app.world_mut().send_event(DamageAssignmentEvent {
+        attacker,
+        assignment: DamageAssignment {
+            blockers: vec![(second, 3)],
+            defender: 0,
+        },
+    });
+    app.update();
+    assert!(
+        app.world()
+            .resource::<CombatState>()
+            .damage_assignments
+            .is_empty()
+    );
+
+    app.world_mut().send_event(DamageAssignmentEvent {
+        attacker,
+        assignment: DamageAssignment {
+            blockers: vec![(first, 2), (second, 1)],
+            defender: 0,
+        },
+    });
+    app.world_mut().send_event(AssignCombatDamageEvent {
+        is_first_strike: false,
+    });
+    app.update();
+
+    assert_eq!(battle_damage(&app, first), 2);
+    assert_eq!(battle_damage(&app, second), 1);
+    assert_eq!(battle_damage(&app, attacker), 3);
+}
+
+fn spawn_players(app: &mut App, count: usize) -> Vec<Entity> {
+    let players: Vec<Entity> = (0..count)
+        .map(|index| {
+            app.world_mut()
+                .spawn(Player::new(&format!("Player {}", index + 1)))
+                .id()
+        })
+        .collect();
+    let mut zones = ZoneManager::default();
+    for &player in &players {
+        zones.init_player_zones(player);
+    }
+    app.insert_resource(zones);
+    players
+}
+
+#[test]
+fn test_melee_counts_each_opponent_attacked() {
+    let mut app = App::new();
+    app.init_resource::<CombatState>()
+        .insert_resource(Phase::Combat(CombatStep::DeclareBlockers))
+        .add_systems(Update, apply_melee_bonuses);
+    let players = spawn_players(&mut app, 3);
+    let knight = spawn_combatant(&mut app, sized_creature("Grenzo's Ruffians", 2, 2, "Melee"));
+    let bears = spawn_combatant(&mut app, sized_creature("Grizzly Bears", 2, 2, ""));
+    {
+        let mut combat = app.world_mut().resource_mut::<CombatState>();
+        combat.attackers.insert(knight, players[1]);
+        combat.attackers.insert(bears, players[2]);
+    }
+
+    app.update();
+
+    let knight_stats = app.world().get::<CreatureOnField>(knight).unwrap();
+    assert_eq!(
+        (knight_stats.power_modifier, knight_stats.toughness_modifier),
+        (4, 4)
+    );
+    assert_eq!(
+        app.world()
+            .get::<CreatureOnField>(bears)
+            .unwrap()
+            .power_modifier,
+        2
+    );
+
+    // The bonus is applied once, as the step begins
+    app.update();
+    assert_eq!(
+        app.world()
+            .get::<CreatureOnField>(knight)
+            .unwrap()
+            .power_modifier,
+        4
+    );
+}
+
+#[test]
+fn test_myriad_attacks_every_other_opponent() {
+    let mut app = App::new();
+    app.init_resource::<CombatState>()
+        .init_resource::<TurnManager>()
+        .add_event::<CreatureAttacksEvent>()
+        .add_systems(Update, create_myriad_tokens);
+    let players = spawn_players(&mut app, 4);
+    let attacker = spawn_combatant(
+        &mut app,
+        sized_creature("Caller of the Hunt", 3, 3, "Myriad"),
+    );
+    app.world_mut()
+        .entity_mut(attacker)
+        .insert(PermanentController::new(players[0]));
+    app.world_mut()
+        .resource_mut::<CombatState>()
+        .attackers
+        .insert(attacker, players[1]);
+
+    app.world_mut().send_event(CreatureAttacksEvent {
+        attacker,
+        defender: players[1],
+    });
+    app.update();
+
+    let world = app.world_mut();
+    let tokens: Vec<Entity> = world
+        .query_filtered::<Entity, With<MyriadToken>>()
+        .iter(world)
+        .collect();
+    assert_eq!(tokens.len(), 2);
+
+    let combat = app.world().resource::<CombatState>();
+    let mut defenders: Vec<Entity> = tokens.iter().map(|token| combat.attackers[token]).collect();
+    defenders.sort();
+    assert_eq!(defenders, vec![players[2], players[3]]);
+    for token in tokens {
+        assert!(is_tapped(&app, token));
+        assert!(app.world().get::<CreatureOnField>(token).unwrap().token);
+    }
+}
+
+#[test]
+fn test_ninjutsu_swaps_an_unblocked_attacker_for_the_ninja() {
+    let mut app = App::new();
+    app.init_resource::<CombatState>()
+        .init_resource::<TurnManager>()
+        .insert_resource(Phase::Combat(CombatStep::DeclareBlockers))
+        .insert_resource(RulesEnforcementLevel::Full)
+        .add_event::<NinjutsuEvent>()
+        .add_event::<ZoneChangeEvent>()
+        .add_systems(
+            Update,
+            (handle_ninjutsu_events, handle_zone_changes).chain(),
+        );
+    let players = spawn_players(&mut app, 2);
+    let ninja_card = sized_creature("Ninja of the Deep Hours", 2, 2, "Ninjutsu {1}{U}");
+    assert_eq!(
+        Card::get_keyword_value(&ninja_card, KeywordAbility::Ninjutsu),
+        Some("{1}{U}")
+    );
+    let ninja = app.world_mut().spawn(ninja_card).id();
+    let blocked_ninja = app
+        .world_mut()
+        .spawn(sized_creature("Fallen Ideal", 2, 2, "Ninjutsu {2}{B}"))
+        .id();
+    let unblocked = spawn_creature(&mut app, "Grizzly Bears", "", false);
+    let blocked = spawn_creature(&mut app, "Hill Giant", "", false);
+    {
+        let mut zones = app.world_mut().resource_mut::<ZoneManager>();
+        zones.add_to_hand(players[0], ninja);
+        zones.add_to_hand(players[0], blocked_ninja);
+        zones.add_to_battlefield(players[0], unblocked);
+        zones.add_to_battlefield(players[0], blocked);
+    }
+    {
+        let mut combat = app.world_mut().resource_mut::<CombatState>();
+        combat.attackers.insert(unblocked, players[1]);
+        combat.attackers.insert(blocked, players[1]);
+        combat
+            .blocked_status
+            .insert(unblocked, BlockedStatus::Unblocked);
+        combat
+            .blocked_status
+            .insert(blocked, BlockedStatus::Blocked);
+    }
+
+    app.world_mut().send_event(NinjutsuEvent {
+        ninja: blocked_ninja,
+        returned: blocked,
+    });
+    app.world_mut().send_event(NinjutsuEvent {
+        ninja,
+        returned: unblocked,
+    });
+    app.update();
+
+    let zones = app.world().resource::<ZoneManager>();
+    assert_eq!(zones.get_card_zone(unblocked), Some(Zone::Hand));
+    assert_eq!(zones.get_card_zone(ninja), Some(Zone::Battlefield));
+    // A blocked creature can't be returned
+    assert_eq!(zones.get_card_zone(blocked_ninja), Some(Zone::Hand));
+
+    let combat = app.world().resource::<CombatState>();
+    assert_eq!(combat.attackers.get(&ninja), Some(&players[1]));
+    assert!(!combat.attackers.contains_key(&unblocked));
+    assert!(is_tapped(&app, ninja));
+}
@@ -13,9 +13,6 @@ pub struct Commander {
     #[allow(dead_code)]
     pub cast_count: u32,
 
-    /// Tracks commander damage dealt to each player
-    pub damage_dealt: Vec<(Entity, u32)>,
-
     /// Commander's color identity (for deck validation)
     #[allow(dead_code)]
     pub color_identity: HashSet<ManaColor>,
@@ -25,9 +22,6 @@ pub struct Commander {
     pub is_partner: bool,
     #[allow(dead_code)]
     pub is_background: bool,
-
-    /// Track if commander has dealt combat damage this turn
-    pub dealt_combat_damage_this_turn: HashSet<Entity>,
 }
 
 impl Default for Commander {
@@ -35,11 +29,9 @@ impl Default for Commander {
         Self {
             owner: Entity::PLACEHOLDER,
             cast_count: 0,
-            damage_dealt: Vec::new(),
             color_identity: HashSet::new(),
             is_partner: false,
             is_background: false,
-            dealt_combat_damage_this_turn: HashSet::new(),
         }
     }
 }
@@ -62,15 +54,14 @@ pub enum EliminationReason {
     /// Player lost due to having 0 or less life
     LifeLoss,
     /// Player lost due to trying to draw from an empty library
-    #[allow(dead_code)]
     EmptyLibrary,
     /// Player lost due to receiving 21+ commander damage from a single commander
     CommanderDamage(Entity), // The commander that dealt the lethal damage
     /// Player conceded
-    #[allow(dead_code)]
     Concede,
+    /// Player lost due to having ten or more poison counters
+    Poison,
     /// Player lost due to a specific card effect
-    #[allow(dead_code)]
     CardEffect(Entity), // The card that caused the elimination
 }
 
@@ -31,6 +31,15 @@ pub struct CommanderZoneChoiceEvent {
     pub can_go_to_command_zone: bool,
 }
 
+/// Event sent when an owner answers a pending command zone choice
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommanderZoneDecisionEvent {
+    /// The commander card entity
+    pub commander: Entity,
+    /// Whether the owner moves the commander to the command zone
+    pub to_command_zone: bool,
+}
+
 /// Event that triggers when a player is eliminated from the game
 #[derive(Event)]
 pub struct PlayerEliminatedEvent {
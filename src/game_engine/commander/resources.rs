@@ -1,4 +1,5 @@
 use super::components::CommanderZoneLocation;
+use crate::game_engine::zones::Zone;
 use crate::mana::ManaColor;
 use bevy::prelude::*;
 use std::collections::{HashMap, HashSet};
@@ -83,6 +84,49 @@ impl CommandZoneBuilder {
     }
 }
 
+/// A commander whose owner still has to choose whether it goes to the command zone
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingZoneChoice {
+    /// The commander card entity
+    pub commander: Entity,
+    /// The owner making the choice
+    pub owner: Entity,
+    /// The zone the commander went to
+    pub current_zone: Zone,
+}
+
+/// Command zone choices waiting on their owners (rule 903.9)
+///
+/// Choices are answered one at a time in the order the commanders changed zones.
+#[derive(Resource, Debug, Default)]
+pub struct CommanderZoneChoices {
+    /// Unanswered choices, oldest first
+    pub pending: Vec<PendingZoneChoice>,
+}
+
+impl CommanderZoneChoices {
+    /// Queue a choice, replacing any older choice for the same commander
+    pub fn push(&mut self, choice: PendingZoneChoice) {
+        self.pending
+            .retain(|pending| pending.commander != choice.commander);
+        self.pending.push(choice);
+    }
+
+    /// The choice the dialog should currently ask about
+    pub fn current(&self) -> Option<&PendingZoneChoice> {
+        self.pending.first()
+    }
+
+    /// Remove and return the choice for a commander
+    pub fn take(&mut self, commander: Entity) -> Option<PendingZoneChoice> {
+        let index = self
+            .pending
+            .iter()
+            .position(|pending| pending.commander == commander)?;
+        Some(self.pending.remove(index))
+    }
+}
+
 /// Manager for commander-specific rules and state
 #[derive(Resource, Debug, Default)]
 pub struct CommandZoneManager {
@@ -140,7 +184,6 @@ impl CommandZoneManager {
     }
 
     /// Gets a player's commanders
-    #[allow(dead_code)]
     pub fn get_player_commanders(&self, player: Entity) -> Vec<Entity> {
         self.player_commanders
             .get(&player)
@@ -149,7 +192,6 @@ impl CommandZoneManager {
     }
 
     /// Gets a commander's current zone
-    #[allow(dead_code)]
     pub fn get_commander_zone(&self, commander: Entity) -> CommanderZoneLocation {
         self.commander_zone_status
             .get(&commander)
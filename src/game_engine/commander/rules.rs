@@ -1,5 +1,6 @@
-use super::components::Commander;
 use crate::cards::{CardCost, CardRulesText, CardTypeInfo, CardTypes};
+use crate::game_engine::damage::DamageLedger;
+use crate::game_engine::zones::Zone;
 use crate::mana::ManaColor;
 use bevy::prelude::Entity;
 use std::collections::HashSet;
@@ -29,16 +30,31 @@ impl CommanderRules {
         2u64 * cast_count as u64
     }
 
+    /// Whether a commander moving between these zones lets its owner choose the
+    /// command zone instead (rule 903.9)
+    ///
+    /// This applies when a commander goes to its owner's graveyard, exile, hand or
+    /// library from any zone other than the command zone.
+    pub fn offers_command_zone_choice(source: Zone, destination: Zone) -> bool {
+        source != Zone::Command
+            && source != destination
+            && matches!(
+                destination,
+                Zone::Graveyard | Zone::Exile | Zone::Hand | Zone::Library
+            )
+    }
+
     /// Check if a player has been eliminated by Commander damage
     ///
     /// In Commander, a player loses the game if they've taken 21 or more combat damage
-    /// from a single commander.
+    /// from a single commander. Partner commanders count separately.
     #[allow(dead_code)]
-    pub fn check_commander_damage_elimination(commander: &Commander, player: Entity) -> bool {
-        commander
-            .damage_dealt
-            .iter()
-            .any(|(p, damage)| *p == player && *damage >= Self::COMMANDER_DAMAGE_THRESHOLD)
+    pub fn check_commander_damage_elimination(
+        ledger: &DamageLedger,
+        commander: Entity,
+        player: Entity,
+    ) -> bool {
+        ledger.commander_damage(commander, player) >= Self::COMMANDER_DAMAGE_THRESHOLD
     }
 
     /// Check if a card can be a Commander
@@ -8,11 +8,12 @@ use bevy::prelude::*;
 use std::collections::HashMap;
 
 use super::components::Commander;
-use super::components::{CommanderZoneLocation, EliminationReason};
-use super::events::{CombatDamageEvent, CommanderZoneChoiceEvent, PlayerEliminatedEvent};
-use super::resources::{CommandZone, CommandZoneManager};
+use super::components::CommanderZoneLocation;
+use super::events::{
+    CombatDamageEvent, CommanderZoneChoiceEvent, CommanderZoneDecisionEvent, PlayerEliminatedEvent,
+};
+use super::resources::{CommandZone, CommandZoneManager, CommanderZoneChoices, PendingZoneChoice};
 use super::rules::CommanderRules;
-use crate::game_engine::turns::TurnStartEvent;
 
 /// Initialize Commander-specific resources and components
 ///
@@ -42,67 +43,8 @@ pub fn calculate_commander_cost(
     final_cost
 }
 
-/// Check if any player has lost due to commander damage
-pub fn check_commander_damage_loss(
-    mut eliminated_events: EventWriter<PlayerEliminatedEvent>,
-    commander_query: Query<&Commander>,
-    player_query: Query<(Entity, &Player)>,
-) {
-    for (player_entity, _player) in player_query.iter() {
-        // Check each commander for damage dealt to this player
-        for commander in commander_query.iter() {
-            if let Some(damage) = commander
-                .damage_dealt
-                .iter()
-                .find(|(p, _)| p == &player_entity)
-            {
-                if damage.1 >= CommanderRules::COMMANDER_DAMAGE_THRESHOLD {
-                    // Player has lost due to commander damage
-                    eliminated_events.write(PlayerEliminatedEvent {
-                        player: player_entity,
-                        reason: EliminationReason::CommanderDamage(commander.owner),
-                    });
-                    break;
-                }
-            }
-        }
-    }
-}
-
-/// Record commander damage from combat
-pub fn record_commander_damage(
-    mut commander_query: Query<&mut Commander>,
-    mut damage_events: EventReader<CombatDamageEvent>,
-) {
-    for event in damage_events.read() {
-        // Only process commander combat damage
-        if !event.source_is_commander || !event.is_combat_damage || event.damage == 0 {
-            continue;
-        }
-
-        if let Ok(mut commander) = commander_query.get_mut(event.source) {
-            // Update the commander's damage tracking
-            if let Some(damage_entry) = commander
-                .damage_dealt
-                .iter_mut()
-                .find(|(p, _)| *p == event.target)
-            {
-                // Update existing damage entry
-                damage_entry.1 += event.damage;
-            } else {
-                // Add a new damage entry
-                commander.damage_dealt.push((event.target, event.damage));
-            }
-
-            // Record that the commander dealt damage to this player this turn
-            commander.dealt_combat_damage_this_turn.insert(event.target);
-        }
-    }
-}
-
 /// Handle commander changing zones
 pub fn handle_commander_zone_change(
-    _zone_manager: ResMut<ZoneManager>,
     mut cmd_zone_manager: ResMut<CommandZoneManager>,
     mut zone_events: EventReader<ZoneChangeEvent>,
     commander_query: Query<(Entity, &Commander)>,
@@ -124,11 +66,9 @@ pub fn handle_commander_zone_change(
 
             cmd_zone_manager.update_commander_zone(entity, new_zone);
 
-            // Special handling for commander death/exile
-            if (event.destination == Zone::Graveyard || event.destination == Zone::Exile)
-                && (event.source == Zone::Battlefield || event.source == Zone::Stack)
-            {
-                // Spawn a choice event for the player
+            // Rule 903.9: a commander going to the graveyard, exile, hand or library
+            // may be put into the command zone instead
+            if CommanderRules::offers_command_zone_choice(event.source, event.destination) {
                 choice_events.write(CommanderZoneChoiceEvent {
                     commander: entity,
                     owner: commander.owner,
@@ -140,38 +80,71 @@ pub fn handle_commander_zone_change(
     }
 }
 
-/// Process player choices for commander zone changes
+/// Queue command zone choices for their owners to answer
 pub fn process_commander_zone_choices(
-    mut _commands: Commands,
     mut choice_events: EventReader<CommanderZoneChoiceEvent>,
-    mut zone_manager: ResMut<ZoneManager>,
-    mut cmd_zone_manager: ResMut<CommandZoneManager>,
-    mut _commander_query: Query<&mut Commander>,
+    mut zone_choices: ResMut<CommanderZoneChoices>,
 ) {
     for event in choice_events.read() {
         if event.can_go_to_command_zone {
-            // Move the commander to the command zone
-            zone_manager.move_card(
-                event.commander,
-                event.owner,
-                event.current_zone,
-                Zone::Command,
-            );
-
-            // Update the commander zone status
-            cmd_zone_manager
-                .update_commander_zone(event.commander, CommanderZoneLocation::CommandZone);
+            zone_choices.push(PendingZoneChoice {
+                commander: event.commander,
+                owner: event.owner,
+                current_zone: event.current_zone,
+            });
+        }
+    }
+}
 
-            // Increment zone transition count
-            let count = cmd_zone_manager
-                .zone_transition_count
-                .entry(event.commander)
-                .or_insert(0);
-            *count += 1;
+/// Apply the owners' answers to pending command zone choices
+pub fn apply_commander_zone_decisions(
+    mut decision_events: EventReader<CommanderZoneDecisionEvent>,
+    mut zone_choices: ResMut<CommanderZoneChoices>,
+    mut zone_manager: ResMut<ZoneManager>,
+    mut cmd_zone_manager: ResMut<CommandZoneManager>,
+    mut zone_events: EventWriter<ZoneChangeEvent>,
+) {
+    for decision in decision_events.read() {
+        let Some(choice) = zone_choices.take(decision.commander) else {
+            continue;
+        };
+        if !decision.to_command_zone {
+            info!("Commander left in {:?}", choice.current_zone);
+            continue;
+        }
 
-            // Notify that the commander moved to the command zone
-            info!("Commander moved to command zone");
+        if !zone_manager.move_card(
+            choice.commander,
+            choice.owner,
+            choice.current_zone,
+            Zone::Command,
+        ) {
+            warn!(
+                "Commander {:?} is no longer in {:?}",
+                choice.commander, choice.current_zone
+            );
+            continue;
         }
+
+        cmd_zone_manager
+            .update_commander_zone(choice.commander, CommanderZoneLocation::CommandZone);
+
+        // Record the zone change for commander tax
+        *cmd_zone_manager
+            .zone_transition_count
+            .entry(choice.commander)
+            .or_insert(0) += 1;
+
+        zone_events.write(ZoneChangeEvent {
+            card: choice.commander,
+            owner: choice.owner,
+            source: choice.current_zone,
+            destination: Zone::Command,
+            was_visible: true,
+            is_visible: true,
+        });
+
+        info!("Commander moved to command zone");
     }
 }
 
@@ -227,81 +200,25 @@ pub fn validate_commander_deck(
     validation_errors
 }
 
-/// System to track damage dealt by commanders to players
-pub fn track_commander_damage(
-    _commands: Commands,
-    commanders: Query<(Entity, &Commander)>,
-    _players: Query<Entity, With<Player>>,
-    _cmd_zone_manager: Res<CommandZoneManager>,
-    // We'll need a damage event/component to track actual damage
-) {
-    // Only log if there's been commander damage dealt this turn
-    #[cfg(debug_assertions)]
-    {
-        let commanders_with_damage_this_turn: Vec<(Entity, &Commander)> = commanders
-            .iter()
-            .filter(|(_, commander)| !commander.dealt_combat_damage_this_turn.is_empty())
-            .collect();
-
-        for (entity, commander) in commanders_with_damage_this_turn {
-            for player in &commander.dealt_combat_damage_this_turn {
-                if let Some(damage) = commander.damage_dealt.iter().find(|(p, _)| p == player) {
-                    debug!(
-                        "Commander {:?} dealt {} damage to player {:?}",
-                        entity, damage.1, player
-                    );
-                }
-            }
-        }
-    }
-
-    // In Commander format, 21 or more damage from a single commander eliminates a player
-    // Implementation will:
-    // 1. Listen for combat damage events
-    // 2. Check if the source is a commander
-    // 3. If so, record the damage against the player
-    // 4. Check if any player has taken 21+ damage from a single commander
-    // 5. Eliminate players who have taken lethal commander damage
-}
-
-/// Resets commander damage tracking at the beginning of each turn
-pub fn reset_commander_damage_tracking(
-    mut commanders: Query<&mut Commander>,
-    mut turn_events: EventReader<TurnStartEvent>,
-) {
-    // Only run when a new turn starts
-    if turn_events.read().next().is_none() {
-        return;
-    }
-
-    // Clear the damage tracking for the new turn
-    for mut commander in &mut commanders {
-        if !commander.dealt_combat_damage_this_turn.is_empty() {
-            debug!("Resetting commander damage tracking for new turn");
-            commander.dealt_combat_damage_this_turn.clear();
-        }
-    }
-}
-
 /// Register all commander-related systems with the app
 #[allow(dead_code)]
 pub fn register_commander_systems(app: &mut App) {
     // Register events
     app.add_event::<CommanderZoneChoiceEvent>()
+        .add_event::<CommanderZoneDecisionEvent>()
         .add_event::<PlayerEliminatedEvent>()
-        .add_event::<CombatDamageEvent>();
+        .add_event::<CombatDamageEvent>()
+        .init_resource::<CommanderZoneChoices>();
 
     // Register systems that will run during the game
     app.add_systems(
         Update,
         (
-            track_commander_damage,
             handle_commander_zone_change,
             process_commander_zone_choices,
-            check_commander_damage_loss,
-            record_commander_damage,
-            reset_commander_damage_tracking,
+            apply_commander_zone_decisions,
         )
+            .chain()
             .run_if(crate::game_engine::game_state_condition),
     );
 
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+
+use crate::game_engine::commander::{
+    CommandZoneManager, Commander, CommanderZoneChoiceEvent, CommanderZoneChoices,
+    CommanderZoneDecisionEvent, apply_commander_zone_decisions, handle_commander_zone_change,
+    process_commander_zone_choices,
+};
+use crate::game_engine::zones::{Zone, ZoneChangeEvent, ZoneManager};
+
+fn commander_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<ZoneChangeEvent>()
+        .add_event::<CommanderZoneChoiceEvent>()
+        .add_event::<CommanderZoneDecisionEvent>()
+        .init_resource::<ZoneManager>()
+        .init_resource::<CommandZoneManager>()
+        .init_resource::<CommanderZoneChoices>()
+        .add_systems(
+            Update,
+            (
+                handle_commander_zone_change,
+                process_commander_zone_choices,
+                apply_commander_zone_decisions,
+            )
+                .chain(),
+        );
+    app
+}
+
+/// Spawn a commander that has just died, returning `(owner, commander)`
+fn kill_commander(app: &mut App) -> (Entity, Entity) {
+    let owner = app.world_mut().spawn_empty().id();
+    let commander = app.world_mut().spawn(Commander { owner, ..default() }).id();
+    {
+        let mut zone_manager = app.world_mut().resource_mut::<ZoneManager>();
+        zone_manager.init_player_zones(owner);
+        zone_manager.add_to_graveyard(owner, commander);
+    }
+    app.world_mut().send_event(ZoneChangeEvent {
+        card: commander,
+        owner,
+        source: Zone::Battlefield,
+        destination: Zone::Graveyard,
+        was_visible: true,
+        is_visible: true,
+    });
+    app.update();
+    (owner, commander)
+}
+
+#[test]
+fn test_commander_going_to_graveyard_waits_for_owner() {
+    let mut app = commander_test_app();
+    let (owner, commander) = kill_commander(&mut app);
+
+    let choices = app.world().resource::<CommanderZoneChoices>();
+    let pending = choices.current().expect("choice should be pending");
+    assert_eq!(pending.commander, commander);
+    assert_eq!(pending.owner, owner);
+    assert_eq!(pending.current_zone, Zone::Graveyard);
+
+    // Nothing moves until the owner decides
+    assert_eq!(
+        app.world()
+            .resource::<ZoneManager>()
+            .get_card_zone(commander),
+        Some(Zone::Graveyard)
+    );
+}
+
+#[test]
+fn test_choosing_command_zone_moves_commander_and_counts_change() {
+    let mut app = commander_test_app();
+    let (owner, commander) = kill_commander(&mut app);
+
+    app.world_mut().send_event(CommanderZoneDecisionEvent {
+        commander,
+        to_command_zone: true,
+    });
+    app.update();
+
+    let zone_manager = app.world().resource::<ZoneManager>();
+    assert_eq!(zone_manager.get_card_zone(commander), Some(Zone::Command));
+    assert!(zone_manager.graveyard(owner).is_empty());
+    assert_eq!(
+        app.world()
+            .resource::<CommandZoneManager>()
+            .zone_transition_count
+            .get(&commander),
+        Some(&1)
+    );
+    assert!(
+        app.world()
+            .resource::<CommanderZoneChoices>()
+            .pending
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_declining_leaves_commander_in_place() {
+    let mut app = commander_test_app();
+    let (_, commander) = kill_commander(&mut app);
+
+    app.world_mut().send_event(CommanderZoneDecisionEvent {
+        commander,
+        to_command_zone: false,
+    });
+    app.update();
+
+    assert_eq!(
+        app.world()
+            .resource::<ZoneManager>()
+            .get_card_zone(commander),
+        Some(Zone::Graveyard)
+    );
+    assert!(
+        app.world()
+            .resource::<CommanderZoneChoices>()
+            .current()
+            .is_none()
+    );
+}
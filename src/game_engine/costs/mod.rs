@@ -0,0 +1,134 @@
+//! Effects that change what spells cost to cast
+//!
+//! Permanents with a [`CostModifier`] make matching spells cost more or less,
+//! such as "Creature spells you cast cost {1} less to cast". As in rule 601.2f,
+//! every increase is applied first, then every reduction, then minimums such as
+//! Trinisphere's. Reductions only take away generic mana.
+
+use bevy::prelude::*;
+
+use crate::cards::CardTypes;
+use crate::mana::Mana;
+
+/// Whose spells a cost modifier changes, relative to its controller
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpellCasters {
+    /// Spells the controller casts
+    #[default]
+    You,
+    /// Spells the controller's opponents cast
+    Opponents,
+    /// Every player's spells
+    Everyone,
+}
+
+/// The spells a cost modifier applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SpellFilter {
+    /// Spells with any of these types, or every spell if empty
+    pub types: CardTypes,
+    pub casters: SpellCasters,
+}
+
+impl SpellFilter {
+    /// Spells of any of `types` cast by the controller
+    pub fn yours(types: CardTypes) -> Self {
+        Self {
+            types,
+            casters: SpellCasters::You,
+        }
+    }
+
+    /// Spells of any of `types` cast by the controller's opponents
+    pub fn opponents(types: CardTypes) -> Self {
+        Self {
+            types,
+            casters: SpellCasters::Opponents,
+        }
+    }
+
+    /// Spells of any of `types` cast by anyone
+    pub fn everyone(types: CardTypes) -> Self {
+        Self {
+            types,
+            casters: SpellCasters::Everyone,
+        }
+    }
+
+    /// Whether a spell of `types` cast by `caster` matches, for a modifier
+    /// controlled by `controller`
+    pub fn matches(&self, types: CardTypes, caster: Entity, controller: Entity) -> bool {
+        let caster_matches = match self.casters {
+            SpellCasters::You => caster == controller,
+            SpellCasters::Opponents => caster != controller,
+            SpellCasters::Everyone => true,
+        };
+        caster_matches && (self.types.is_empty() || self.types.intersects(types))
+    }
+}
+
+/// How a cost modifier changes a spell's cost
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CostDelta {
+    /// Costs this much more generic mana
+    Increase(u64),
+    /// Costs this much less generic mana
+    Decrease(u64),
+    /// Costs at least this much mana in total, paying the difference as generic
+    Minimum(u64),
+}
+
+/// A permanent's static ability changing what spells cost to cast
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CostModifier {
+    pub filter: SpellFilter,
+    pub delta: CostDelta,
+}
+
+impl CostModifier {
+    pub fn new(filter: SpellFilter, delta: CostDelta) -> Self {
+        Self { filter, delta }
+    }
+}
+
+/// Apply cost changes to a base cost in rules order
+///
+/// Increases come first, then decreases, then the highest minimum.
+pub fn modified_cost(base: &Mana, deltas: impl IntoIterator<Item = CostDelta>) -> Mana {
+    let (mut increase, mut decrease, mut minimum) = (0, 0, 0);
+    for delta in deltas {
+        match delta {
+            CostDelta::Increase(amount) => increase += amount,
+            CostDelta::Decrease(amount) => decrease += amount,
+            CostDelta::Minimum(amount) => minimum = minimum.max(amount),
+        }
+    }
+
+    let mut generic = (base.colorless + increase).saturating_sub(decrease);
+    let total = base.colored_total() + generic;
+    generic += minimum.saturating_sub(total);
+    Mana::new_with_colors(
+        generic, base.white, base.blue, base.black, base.red, base.green,
+    )
+}
+
+/// What a spell costs `caster` once every matching modifier is applied
+///
+/// `modifiers` pairs each modifier with the player controlling it.
+pub fn spell_cost<'a>(
+    base: &Mana,
+    types: CardTypes,
+    caster: Entity,
+    modifiers: impl IntoIterator<Item = (&'a CostModifier, Entity)>,
+) -> Mana {
+    modified_cost(
+        base,
+        modifiers
+            .into_iter()
+            .filter(|(modifier, controller)| modifier.filter.matches(types, caster, *controller))
+            .map(|(modifier, _)| modifier.delta),
+    )
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use crate::cards::CardTypes;
+use crate::game_engine::costs::{CostDelta, CostModifier, SpellFilter, modified_cost, spell_cost};
+use crate::mana::Mana;
+
+#[test]
+fn test_increases_apply_before_decreases_and_minimums() {
+    // {1}{G}
+    let base = Mana::new_with_colors(1, 0, 0, 0, 0, 1);
+
+    // Reductions can't take away colored mana
+    let reduced = modified_cost(&base, [CostDelta::Decrease(3)]);
+    assert_eq!(reduced, Mana::new_with_colors(0, 0, 0, 0, 0, 1));
+
+    // An increase is applied before the reduction, whatever order they come in
+    let both = modified_cost(&base, [CostDelta::Decrease(2), CostDelta::Increase(2)]);
+    assert_eq!(both, Mana::new_with_colors(1, 0, 0, 0, 0, 1));
+
+    // A minimum is applied last, to the reduced cost
+    let minimum = modified_cost(&base, [CostDelta::Minimum(3), CostDelta::Decrease(1)]);
+    assert_eq!(minimum, Mana::new_with_colors(2, 0, 0, 0, 0, 1));
+
+    // Only the highest minimum counts, and costs already above it are unchanged
+    let high = Mana::new_with_colors(4, 0, 0, 0, 1, 0);
+    let unchanged = modified_cost(&high, [CostDelta::Minimum(3), CostDelta::Minimum(2)]);
+    assert_eq!(unchanged, high);
+}
+
+#[test]
+fn test_modifiers_apply_to_matching_spells() {
+    let mut world = World::new();
+    let you = world.spawn_empty().id();
+    let opponent = world.spawn_empty().id();
+    let creature_discount = CostModifier::new(
+        SpellFilter::yours(CardTypes::CREATURE),
+        CostDelta::Decrease(1),
+    );
+    let tax = CostModifier::new(
+        SpellFilter::opponents(CardTypes::NONE),
+        CostDelta::Increase(1),
+    );
+    let modifiers = [(&creature_discount, you), (&tax, you)];
+    let base = Mana::new_with_colors(2, 0, 0, 0, 0, 1);
+
+    assert_eq!(
+        spell_cost(&base, CardTypes::CREATURE, you, modifiers),
+        Mana::new_with_colors(1, 0, 0, 0, 0, 1)
+    );
+    assert_eq!(spell_cost(&base, CardTypes::SORCERY, you, modifiers), base);
+    assert_eq!(
+        spell_cost(&base, CardTypes::CREATURE, opponent, modifiers),
+        Mana::new_with_colors(3, 0, 0, 0, 0, 1)
+    );
+}
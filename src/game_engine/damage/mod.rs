@@ -0,0 +1,189 @@
+//! Record of all damage dealt during a game
+//!
+//! Every [`CombatDamageEvent`] is added to the [`DamageLedger`] with the turn it
+//! was dealt on. Commander damage totals are kept per commander, so partner
+//! commanders are tracked separately as the rules require, and other questions
+//! such as "how much damage was dealt this turn" are answered from the records.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::cards::Card;
+use crate::cards::keywords::KeywordAbility;
+use crate::game_engine::commander::CombatDamageEvent;
+use crate::game_engine::life::LifeChangeEvent;
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::state::GameState;
+use crate::menu::state::{GameMenuState, StateTransitionContext};
+
+/// Whether damage was dealt in combat
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DamageKind {
+    Combat,
+    Noncombat,
+}
+
+/// One instance of damage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DamageRecord {
+    pub source: Entity,
+    /// The player or permanent dealt damage
+    pub target: Entity,
+    pub amount: u32,
+    pub kind: DamageKind,
+    /// Turn number the damage was dealt on
+    pub turn: u32,
+    /// Whether the source was a commander
+    pub source_is_commander: bool,
+}
+
+impl DamageRecord {
+    /// Whether this counts towards the commander damage a player loses at
+    pub fn is_commander_damage(&self) -> bool {
+        self.source_is_commander && self.kind == DamageKind::Combat
+    }
+}
+
+/// Every instance of damage dealt this game, oldest first
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct DamageLedger {
+    records: Vec<DamageRecord>,
+    /// Combat damage by each commander to each player, as (commander, player)
+    commander_damage: HashMap<(Entity, Entity), u32>,
+}
+
+impl DamageLedger {
+    /// Add an instance of damage
+    pub fn record(&mut self, record: DamageRecord) {
+        if record.is_commander_damage() {
+            *self
+                .commander_damage
+                .entry((record.source, record.target))
+                .or_default() += record.amount;
+        }
+        self.records.push(record);
+    }
+
+    /// Every instance of damage, oldest first
+    pub fn records(&self) -> &[DamageRecord] {
+        &self.records
+    }
+
+    /// Damage dealt on a turn
+    pub fn on_turn(&self, turn: u32) -> impl Iterator<Item = &DamageRecord> {
+        self.records
+            .iter()
+            .filter(move |record| record.turn == turn)
+    }
+
+    /// Total damage a source dealt on a turn
+    pub fn dealt_by(&self, source: Entity, turn: u32) -> u32 {
+        self.on_turn(turn)
+            .filter(|record| record.source == source)
+            .map(|record| record.amount)
+            .sum()
+    }
+
+    /// Total damage dealt to a player or permanent on a turn
+    pub fn dealt_to(&self, target: Entity, turn: u32) -> u32 {
+        self.on_turn(turn)
+            .filter(|record| record.target == target)
+            .map(|record| record.amount)
+            .sum()
+    }
+
+    /// Combat damage a commander has dealt to a player this game
+    pub fn commander_damage(&self, commander: Entity, player: Entity) -> u32 {
+        self.commander_damage
+            .get(&(commander, player))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Combat damage each commander has dealt to a player this game
+    pub fn commander_damage_to(&self, player: Entity) -> impl Iterator<Item = (Entity, u32)> + '_ {
+        self.commander_damage
+            .iter()
+            .filter(move |((_, target), _)| *target == player)
+            .map(|((commander, _), damage)| (*commander, *damage))
+    }
+
+    /// Forget all damage, for a new game
+    pub fn clear(&mut self) {
+        self.records.clear();
+        self.commander_damage.clear();
+    }
+}
+
+/// Add dealt damage to the ledger
+pub fn record_damage(
+    mut damage: EventReader<CombatDamageEvent>,
+    game_state: Res<GameState>,
+    mut ledger: ResMut<DamageLedger>,
+) {
+    for event in damage.read() {
+        if event.damage == 0 {
+            continue;
+        }
+        ledger.record(DamageRecord {
+            source: event.source,
+            target: event.target,
+            amount: event.damage,
+            kind: if event.is_combat_damage {
+                DamageKind::Combat
+            } else {
+                DamageKind::Noncombat
+            },
+            turn: game_state.turn_number,
+            source_is_commander: event.source_is_commander,
+        });
+    }
+}
+
+/// The controller of a source with lifelink gains as much life as it dealt damage
+pub fn apply_lifelink(
+    mut damage: EventReader<CombatDamageEvent>,
+    sources: Query<(&Card, &PermanentController)>,
+    mut life_changes: EventWriter<LifeChangeEvent>,
+) {
+    for event in damage.read() {
+        let Ok((card, controller)) = sources.get(event.source) else {
+            continue;
+        };
+        if !Card::has_keyword(card, KeywordAbility::Lifelink) {
+            continue;
+        }
+        life_changes.write(LifeChangeEvent::gain(
+            controller.player,
+            Some(event.source),
+            event.damage,
+        ));
+    }
+}
+
+/// Start a new ledger for each game
+///
+/// Coming back from the pause menu continues the current game's ledger.
+pub fn reset_damage_ledger(
+    mut ledger: ResMut<DamageLedger>,
+    context: Option<Res<StateTransitionContext>>,
+) {
+    if context.is_some_and(|context| context.from_pause_menu) {
+        return;
+    }
+    ledger.clear();
+}
+
+/// Register the damage ledger and its systems
+pub fn register_damage_systems(app: &mut App) {
+    app.init_resource::<DamageLedger>()
+        .add_systems(OnEnter(GameMenuState::InGame), reset_damage_ledger)
+        .add_systems(
+            Update,
+            (record_damage, apply_lifelink).run_if(in_state(GameMenuState::InGame)),
+        );
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+
+use crate::cards::keywords::KeywordAbility;
+use crate::cards::{Card, CardDetails, CardTypes, CreatureCard, CreatureType};
+use crate::game_engine::commander::CombatDamageEvent;
+use crate::game_engine::commander::rules::CommanderRules;
+use crate::game_engine::damage::{
+    DamageKind, DamageLedger, DamageRecord, apply_lifelink, record_damage,
+};
+use crate::game_engine::life::{LifeChangeEvent, LifeChangedEvent, apply_life_changes};
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::state::GameState;
+use crate::mana::Mana;
+use crate::player::Player;
+
+fn record(source: Entity, target: Entity, amount: u32, turn: u32) -> DamageRecord {
+    DamageRecord {
+        source,
+        target,
+        amount,
+        kind: DamageKind::Combat,
+        turn,
+        source_is_commander: true,
+    }
+}
+
+#[test]
+fn test_commander_damage_is_tracked_per_commander() {
+    let mut world = World::new();
+    let [partner, other_partner, player] = [(); 3].map(|_| world.spawn_empty().id());
+    let mut ledger = DamageLedger::default();
+    ledger.record(record(partner, player, 12, 1));
+    ledger.record(record(other_partner, player, 12, 2));
+    ledger.record(DamageRecord {
+        kind: DamageKind::Noncombat,
+        ..record(partner, player, 5, 2)
+    });
+
+    // Partners are counted separately and noncombat damage doesn't count
+    assert_eq!(ledger.commander_damage(partner, player), 12);
+    assert_eq!(ledger.commander_damage(other_partner, player), 12);
+    assert!(!CommanderRules::check_commander_damage_elimination(
+        &ledger, partner, player
+    ));
+    ledger.record(record(partner, player, 9, 3));
+    assert!(CommanderRules::check_commander_damage_elimination(
+        &ledger, partner, player
+    ));
+
+    // All damage is kept for turn queries
+    assert_eq!(ledger.records().len(), 4);
+    assert_eq!(ledger.dealt_by(partner, 2), 5);
+    assert_eq!(ledger.dealt_to(player, 2), 17);
+    assert_eq!(ledger.on_turn(1).count(), 1);
+
+    ledger.clear();
+    assert_eq!(ledger.commander_damage(partner, player), 0);
+}
+
+#[test]
+fn test_damage_events_are_recorded_with_lifelink() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<CombatDamageEvent>()
+        .add_event::<LifeChangeEvent>()
+        .add_event::<LifeChangedEvent>()
+        .init_resource::<DamageLedger>()
+        .insert_resource(GameState::builder().turn_number(3).build())
+        .add_systems(
+            Update,
+            (record_damage, apply_lifelink, apply_life_changes).chain(),
+        );
+    let player = app.world_mut().spawn(Player::new("Alice")).id();
+    let opponent = app.world_mut().spawn(Player::new("Bob")).id();
+    let mut card = Card::builder("Lifelinker")
+        .cost(Mana::default())
+        .types(CardTypes::CREATURE)
+        .details(CardDetails::Creature(CreatureCard {
+            power: 3,
+            toughness: 3,
+            creature_type: CreatureType::NONE,
+        }))
+        .build_or_panic();
+    Card::add_keyword(&mut card, KeywordAbility::Lifelink);
+    let creature = app
+        .world_mut()
+        .spawn((card, PermanentController::new(player)))
+        .id();
+
+    app.world_mut().send_event(CombatDamageEvent {
+        source: creature,
+        target: opponent,
+        damage: 3,
+        is_combat_damage: true,
+        source_is_commander: false,
+    });
+    app.update();
+
+    let ledger = app.world().resource::<DamageLedger>();
+    assert_eq!(
+        ledger.records(),
+        &[DamageRecord {
+            source: creature,
+            target: opponent,
+            amount: 3,
+            kind: DamageKind::Combat,
+            turn: 3,
+            source_is_commander: false,
+        }]
+    );
+    assert_eq!(app.world().get::<Player>(player).unwrap().life, 43);
+}
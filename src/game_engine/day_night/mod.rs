@@ -0,0 +1,223 @@
+//! Day and night (rule 726)
+//!
+//! A game starts as neither day nor night. It becomes day as soon as a permanent
+//! with daybound is on the battlefield, and from then on turns change it:
+//!
+//! - if it's day and the previous turn's active player cast no spells that turn,
+//!   it becomes night
+//! - if it's night and the previous turn's active player cast two or more spells
+//!   that turn, it becomes day
+//!
+//! The check happens as the upkeep begins. Daybound and nightbound permanents
+//! keep both of their faces in [`DayNightFaces`] and their [`Card`] is swapped for
+//! the face matching [`DayNight`], as a transforming permanent would be. They
+//! enter the battlefield at night with their nightbound face up, and go back to
+//! their daybound face as they leave it.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::cards::Card;
+use crate::game_engine::actions::GameAction;
+use crate::game_engine::phase::{BeginningStep, Phase, StepBeganEvent};
+use crate::game_engine::zones::{Zone, ZoneChangeEvent, ZoneManager};
+use crate::menu::state::GameMenuState;
+
+/// Whether it's day or night
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayNightState {
+    Day,
+    Night,
+}
+
+/// Day and night, and the spells cast this turn that decide the next change
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct DayNight {
+    /// Day or night, `None` until a daybound permanent has been on the battlefield
+    pub state: Option<DayNightState>,
+    /// Spells each player has cast since the current turn's upkeep began
+    pub spells_this_turn: HashMap<Entity, u32>,
+    /// The active player of the turn being counted
+    pub turn_player: Option<Entity>,
+}
+
+impl DayNight {
+    pub fn is_day(&self) -> bool {
+        self.state == Some(DayNightState::Day)
+    }
+
+    pub fn is_night(&self) -> bool {
+        self.state == Some(DayNightState::Night)
+    }
+
+    /// Spells a player has cast this turn
+    pub fn spells_cast_by(&self, player: Entity) -> u32 {
+        self.spells_this_turn.get(&player).copied().unwrap_or(0)
+    }
+
+    /// What it becomes next turn if no more spells are cast this turn
+    ///
+    /// `None` if it stays as it is.
+    pub fn next_change(&self) -> Option<DayNightState> {
+        let spells = self
+            .turn_player
+            .map_or(0, |player| self.spells_cast_by(player));
+        match self.state {
+            Some(DayNightState::Day) if spells == 0 => Some(DayNightState::Night),
+            Some(DayNightState::Night) if spells >= 2 => Some(DayNightState::Day),
+            _ => None,
+        }
+    }
+}
+
+/// The two faces of a daybound permanent
+#[derive(Component, Debug, Clone)]
+pub struct DayNightFaces {
+    /// The front face, with daybound
+    pub day: Card,
+    /// The back face, with nightbound
+    pub night: Card,
+}
+
+impl DayNightFaces {
+    /// The face a permanent shows while it's `state`
+    pub fn face(&self, state: DayNightState) -> &Card {
+        match state {
+            DayNightState::Day => &self.day,
+            DayNightState::Night => &self.night,
+        }
+    }
+}
+
+/// It became day or night
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayNightChangedEvent {
+    pub state: DayNightState,
+}
+
+/// A daybound or nightbound permanent transformed
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransformedEvent {
+    pub permanent: Entity,
+    /// The face now up
+    pub into: DayNightState,
+}
+
+/// Count the spells each player casts this turn
+pub fn count_spells_cast(mut actions: EventReader<GameAction>, mut day_night: ResMut<DayNight>) {
+    for action in actions.read() {
+        if let GameAction::CastSpell { player, .. } = action {
+            *day_night.spells_this_turn.entry(*player).or_default() += 1;
+        }
+    }
+}
+
+/// Change day and night as the upkeep begins, from the spells cast last turn
+pub fn check_day_night_at_upkeep(
+    mut steps: EventReader<StepBeganEvent>,
+    mut day_night: ResMut<DayNight>,
+    mut changes: EventWriter<DayNightChangedEvent>,
+) {
+    for step in steps.read() {
+        if step.phase != Phase::Beginning(BeginningStep::Upkeep) {
+            continue;
+        }
+        if let Some(state) = day_night.next_change() {
+            info!("It becomes {:?}", state);
+            day_night.state = Some(state);
+            changes.write(DayNightChangedEvent { state });
+        }
+        day_night.turn_player = Some(step.active_player);
+        day_night.spells_this_turn.clear();
+    }
+}
+
+/// Show the right face on daybound permanents entering and leaving the battlefield
+///
+/// The first daybound permanent to enter makes it day.
+pub fn day_night_zone_changes(
+    mut commands: Commands,
+    mut zone_events: EventReader<ZoneChangeEvent>,
+    mut day_night: ResMut<DayNight>,
+    mut changes: EventWriter<DayNightChangedEvent>,
+    faces: Query<&DayNightFaces>,
+) {
+    for event in zone_events.read() {
+        let Ok(faces) = faces.get(event.card) else {
+            continue;
+        };
+        if event.destination == Zone::Battlefield {
+            let state = *day_night.state.get_or_insert_with(|| {
+                info!("A daybound permanent makes it day");
+                changes.write(DayNightChangedEvent {
+                    state: DayNightState::Day,
+                });
+                DayNightState::Day
+            });
+            commands
+                .entity(event.card)
+                .insert(faces.face(state).clone());
+        } else if event.source == Zone::Battlefield {
+            commands.entity(event.card).insert(faces.day.clone());
+        }
+    }
+}
+
+/// Transform daybound and nightbound permanents as it becomes day or night
+pub fn transform_day_night_permanents(
+    mut commands: Commands,
+    mut changes: EventReader<DayNightChangedEvent>,
+    mut transformed: EventWriter<TransformedEvent>,
+    zones: Option<Res<ZoneManager>>,
+    permanents: Query<(&DayNightFaces, &Card)>,
+) {
+    let Some(zones) = zones else {
+        changes.clear();
+        return;
+    };
+
+    for change in changes.read() {
+        for &permanent in zones.battlefield() {
+            let Ok((faces, card)) = permanents.get(permanent) else {
+                continue;
+            };
+            let face = faces.face(change.state);
+            if card.name.name == face.name.name {
+                continue;
+            }
+            commands.entity(permanent).insert(face.clone());
+            transformed.write(TransformedEvent {
+                permanent,
+                into: change.state,
+            });
+        }
+    }
+}
+
+/// Forget day and night when returning to the main menu
+pub fn clear_day_night(mut day_night: ResMut<DayNight>) {
+    *day_night = DayNight::default();
+}
+
+/// Register day and night
+pub fn register_day_night_systems(app: &mut App) {
+    app.add_event::<DayNightChangedEvent>()
+        .add_event::<TransformedEvent>()
+        .init_resource::<DayNight>()
+        .add_systems(
+            Update,
+            (
+                count_spells_cast,
+                check_day_night_at_upkeep,
+                transform_day_night_permanents,
+                day_night_zone_changes,
+            )
+                .chain()
+                .run_if(in_state(GameMenuState::InGame)),
+        )
+        .add_systems(OnEnter(GameMenuState::MainMenu), clear_day_night);
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,182 @@
+use bevy::prelude::*;
+
+use crate::cards::Card;
+use crate::cards::keywords::KeywordAbility;
+use crate::cards::sets::innistrad_midnight_hunt::brutal_cathar;
+use crate::game_engine::actions::GameAction;
+use crate::game_engine::day_night::{
+    DayNight, DayNightChangedEvent, DayNightFaces, DayNightState, TransformedEvent,
+    check_day_night_at_upkeep, count_spells_cast, day_night_zone_changes,
+    transform_day_night_permanents,
+};
+use crate::game_engine::phase::{BeginningStep, Phase, StepBeganEvent};
+use crate::game_engine::zones::{Zone, ZoneChangeEvent, ZoneManager, ZonePosition};
+use crate::mana::Mana;
+
+fn day_night_app() -> (App, Entity, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<GameAction>()
+        .add_event::<StepBeganEvent>()
+        .add_event::<ZoneChangeEvent>()
+        .add_event::<DayNightChangedEvent>()
+        .add_event::<TransformedEvent>()
+        .init_resource::<DayNight>()
+        .init_resource::<ZoneManager>()
+        .add_systems(
+            Update,
+            (
+                count_spells_cast,
+                check_day_night_at_upkeep,
+                transform_day_night_permanents,
+                day_night_zone_changes,
+            )
+                .chain(),
+        );
+    let alice = app.world_mut().spawn_empty().id();
+    let bob = app.world_mut().spawn_empty().id();
+    for player in [alice, bob] {
+        app.world_mut()
+            .resource_mut::<ZoneManager>()
+            .init_player_zones(player);
+    }
+    (app, alice, bob)
+}
+
+fn upkeep(app: &mut App, active_player: Entity) {
+    app.world_mut().send_event(StepBeganEvent {
+        phase: Phase::Beginning(BeginningStep::Upkeep),
+        active_player,
+    });
+    app.update();
+}
+
+fn cast_spells(app: &mut App, player: Entity, count: usize) {
+    for _ in 0..count {
+        let spell_card = app.world_mut().spawn_empty().id();
+        app.world_mut().send_event(GameAction::CastSpell {
+            player,
+            spell_card,
+            targets: Vec::new(),
+            mana_payment: Mana::default(),
+        });
+    }
+    app.update();
+}
+
+fn state(app: &App) -> Option<DayNightState> {
+    app.world().resource::<DayNight>().state
+}
+
+fn enter_battlefield(app: &mut App, owner: Entity) -> Entity {
+    let faces = DayNightFaces {
+        day: brutal_cathar::get_card(),
+        night: brutal_cathar::night_face(),
+    };
+    let card = app.world_mut().spawn((faces.day.clone(), faces)).id();
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .add_to_battlefield(owner, card);
+    app.world_mut().send_event(ZoneChangeEvent {
+        card,
+        owner,
+        source: Zone::Hand,
+        destination: Zone::Battlefield,
+        was_visible: true,
+        is_visible: true,
+    });
+    app.update();
+    card
+}
+
+fn name(app: &App, card: Entity) -> &str {
+    &app.world().get::<Card>(card).unwrap().name.name
+}
+
+#[test]
+fn test_brutal_cathar_faces_have_day_and_night_keywords() {
+    let day = brutal_cathar::get_card();
+    let night = brutal_cathar::night_face();
+    assert!(Card::has_keyword(&day, KeywordAbility::Daybound));
+    assert!(!Card::has_keyword(&day, KeywordAbility::Nightbound));
+    assert!(Card::has_keyword(&night, KeywordAbility::Nightbound));
+    assert!(Card::has_keyword(&night, KeywordAbility::FirstStrike));
+}
+
+#[test]
+fn test_spells_cast_change_day_and_night() {
+    let (mut app, alice, bob) = day_night_app();
+
+    // Turns pass without it being day or night until a daybound permanent enters
+    upkeep(&mut app, alice);
+    upkeep(&mut app, bob);
+    assert_eq!(state(&app), None);
+    let cathar = enter_battlefield(&mut app, bob);
+    assert_eq!(state(&app), Some(DayNightState::Day));
+    assert_eq!(name(&app, cathar), "Brutal Cathar");
+
+    // Spells cast by another player don't count
+    cast_spells(&mut app, alice, 3);
+    upkeep(&mut app, alice);
+    assert_eq!(state(&app), Some(DayNightState::Night));
+    assert_eq!(name(&app, cathar), "Moonrage Brute");
+    let transformed: Vec<TransformedEvent> = app
+        .world_mut()
+        .resource_mut::<Events<TransformedEvent>>()
+        .drain()
+        .collect();
+    assert_eq!(
+        transformed,
+        vec![TransformedEvent {
+            permanent: cathar,
+            into: DayNightState::Night
+        }]
+    );
+
+    // One spell isn't enough to make it day
+    cast_spells(&mut app, alice, 1);
+    upkeep(&mut app, bob);
+    assert_eq!(state(&app), Some(DayNightState::Night));
+
+    cast_spells(&mut app, bob, 2);
+    upkeep(&mut app, alice);
+    assert_eq!(state(&app), Some(DayNightState::Day));
+    assert_eq!(name(&app, cathar), "Brutal Cathar");
+}
+
+#[test]
+fn test_daybound_permanents_enter_transformed_at_night() {
+    let (mut app, alice, bob) = day_night_app();
+    app.world_mut().resource_mut::<DayNight>().state = Some(DayNightState::Night);
+
+    let cathar = enter_battlefield(&mut app, alice);
+    assert_eq!(name(&app, cathar), "Moonrage Brute");
+
+    // It leaves the battlefield with its front face up
+    app.world_mut().resource_mut::<ZoneManager>().move_to(
+        cathar,
+        Zone::Graveyard,
+        ZonePosition::Top,
+    );
+    app.world_mut().send_event(ZoneChangeEvent {
+        card: cathar,
+        owner: alice,
+        source: Zone::Battlefield,
+        destination: Zone::Graveyard,
+        was_visible: true,
+        is_visible: true,
+    });
+    app.update();
+    assert_eq!(name(&app, cathar), "Brutal Cathar");
+
+    // Cards off the battlefield don't transform
+    upkeep(&mut app, bob);
+    cast_spells(&mut app, bob, 2);
+    upkeep(&mut app, alice);
+    assert_eq!(state(&app), Some(DayNightState::Day));
+    assert!(
+        app.world()
+            .resource::<Events<TransformedEvent>>()
+            .is_empty()
+    );
+}
@@ -0,0 +1,285 @@
+//! Dungeons and venturing
+//!
+//! Venturing moves a player's marker one room deeper into the dungeon in their
+//! command zone, starting a new dungeon at its entrance if they aren't in one
+//! (rule 701.49). Every room has a room ability that triggers as the marker
+//! enters it, and a player whose marker reaches the bottom room completes the
+//! dungeon, which then leaves the game (rule 309.7).
+//!
+//! - venturing into the dungeon starts one of the three
+//!   [`Dungeon::VENTURE_CHOICES`]
+//! - venturing into the Undercity, as the initiative does (see
+//!   [`crate::game_engine::politics::TakeInitiativeEvent`]), starts the Undercity
+//!
+//! Whenever there is more than one dungeon to start or room to move to, the
+//! player picks one with a [`ChooseRoomEvent`], or the first is taken once
+//! [`ROOM_CHOICE_TIMEOUT_SECS`] have passed. Ventures made while a player is
+//! choosing wait for the choice. Room abilities go on the stack through the
+//! trigger queue, and [`Dungeons`] remembers which dungeons every player has
+//! completed for cards that care.
+
+mod rooms;
+
+use std::collections::{HashMap, VecDeque};
+
+use bevy::prelude::*;
+
+use crate::game_engine::triggers::{AbilityTriggeredEvent, PendingTrigger};
+use crate::menu::state::GameMenuState;
+
+pub use rooms::{Dungeon, DungeonRoom, Room};
+
+/// Seconds a player has to choose a dungeon or room before the first is taken
+pub const ROOM_CHOICE_TIMEOUT_SECS: f32 = 15.0;
+
+/// Which dungeon a venture starts if the player isn't in one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VentureInto {
+    /// Venture into the dungeon: start any dungeon but the Undercity
+    Dungeon,
+    /// Venture into the Undercity
+    Undercity,
+}
+
+/// Sent to have a player venture
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VentureEvent {
+    pub player: Entity,
+    pub into: VentureInto,
+}
+
+/// Sent by a player to pick the dungeon or room their marker moves to
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChooseRoomEvent {
+    pub player: Entity,
+    pub room: DungeonRoom,
+}
+
+/// A player's venture marker entered a room
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoomEnteredEvent {
+    pub player: Entity,
+    pub room: DungeonRoom,
+}
+
+/// A player completed a dungeon
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DungeonCompletedEvent {
+    pub player: Entity,
+    pub dungeon: Dungeon,
+}
+
+/// A player choosing where their venture marker goes
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoomChoice {
+    pub player: Entity,
+    /// The rooms the marker can move to, or the entrances of the dungeons it
+    /// can start
+    pub options: Vec<DungeonRoom>,
+    /// Seconds spent waiting for the player so far
+    pub waited: f32,
+}
+
+impl RoomChoice {
+    /// Seconds left before the first option is taken
+    pub fn remaining_secs(&self) -> f32 {
+        (ROOM_CHOICE_TIMEOUT_SECS - self.waited).max(0.0)
+    }
+
+    /// Whether the player is starting a dungeon rather than moving through one
+    pub fn is_starting_dungeon(&self) -> bool {
+        self.options.iter().all(|room| room.index == 0)
+    }
+}
+
+/// Every player's dungeon, and the ventures waiting to be made
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct Dungeons {
+    /// The room each player's venture marker is in
+    pub progress: HashMap<Entity, DungeonRoom>,
+    /// The dungeons each player has completed this game, in order
+    pub completed: HashMap<Entity, Vec<Dungeon>>,
+    /// Ventures waiting for an earlier choice to be made
+    pub pending: VecDeque<VentureEvent>,
+    /// The player currently choosing where to venture, if any
+    pub choice: Option<RoomChoice>,
+}
+
+impl Dungeons {
+    /// The room a player's marker is in, if they're in a dungeon
+    pub fn current_room(&self, player: Entity) -> Option<DungeonRoom> {
+        self.progress.get(&player).copied()
+    }
+
+    /// The dungeons a player has completed, in order
+    pub fn completed_by(&self, player: Entity) -> &[Dungeon] {
+        self.completed.get(&player).map_or(&[], Vec::as_slice)
+    }
+
+    /// Whether a player has completed a particular dungeon
+    pub fn has_completed(&self, player: Entity, dungeon: Dungeon) -> bool {
+        self.completed_by(player).contains(&dungeon)
+    }
+
+    /// The rooms a venture can move a player's marker to
+    ///
+    /// A player in a dungeon moves through it however they venture; otherwise
+    /// they start one of the dungeons `into` allows.
+    pub fn venture_options(&self, player: Entity, into: VentureInto) -> Vec<DungeonRoom> {
+        match (self.current_room(player), into) {
+            (Some(room), _) => room.next_rooms(),
+            (None, VentureInto::Dungeon) => Dungeon::VENTURE_CHOICES
+                .iter()
+                .map(|dungeon| dungeon.entrance())
+                .collect(),
+            (None, VentureInto::Undercity) => vec![Dungeon::Undercity.entrance()],
+        }
+    }
+
+    /// Move a player's marker into a room
+    ///
+    /// Entering the bottom room completes the dungeon, so the player's next
+    /// venture starts a new one. Returns whether the dungeon was completed.
+    pub fn enter(&mut self, player: Entity, room: DungeonRoom) -> bool {
+        if room.is_last() {
+            self.progress.remove(&player);
+            self.completed.entry(player).or_default().push(room.dungeon);
+            true
+        } else {
+            self.progress.insert(player, room);
+            false
+        }
+    }
+}
+
+/// Move a player's marker and trigger the room's ability
+fn enter_room(
+    dungeons: &mut Dungeons,
+    player: Entity,
+    room: DungeonRoom,
+    entered: &mut EventWriter<RoomEnteredEvent>,
+    completed: &mut EventWriter<DungeonCompletedEvent>,
+    triggered: &mut EventWriter<AbilityTriggeredEvent>,
+) {
+    info!(
+        "{:?} ventures into {} ({})",
+        player,
+        room.name(),
+        room.dungeon.name()
+    );
+    let completed_dungeon = dungeons.enter(player, room);
+    entered.write(RoomEnteredEvent { player, room });
+    triggered.write(AbilityTriggeredEvent(PendingTrigger {
+        source: player,
+        controller: player,
+        description: format!("{}: {}", room.name(), room.ability()),
+        effects: Vec::new(),
+    }));
+    if completed_dungeon {
+        info!("{:?} completed {}", player, room.dungeon.name());
+        completed.write(DungeonCompletedEvent {
+            player,
+            dungeon: room.dungeon,
+        });
+    }
+}
+
+/// Make ventures in the order they happen, waiting for players' choices
+///
+/// Room abilities are controlled by the venturing player and go on the stack
+/// through the trigger queue like any other triggered ability.
+pub fn handle_venture(
+    time: Res<Time>,
+    mut events: EventReader<VentureEvent>,
+    mut choices: EventReader<ChooseRoomEvent>,
+    mut dungeons: ResMut<Dungeons>,
+    mut entered: EventWriter<RoomEnteredEvent>,
+    mut completed: EventWriter<DungeonCompletedEvent>,
+    mut triggered: EventWriter<AbilityTriggeredEvent>,
+) {
+    let new_ventures: Vec<VentureEvent> = events.read().copied().collect();
+    if !new_ventures.is_empty() {
+        dungeons.pending.extend(new_ventures);
+    }
+
+    if let Some(choice) = dungeons.choice.clone() {
+        let chosen = choices
+            .read()
+            .filter(|event| event.player == choice.player && choice.options.contains(&event.room))
+            .map(|event| event.room)
+            .last();
+        let room = match chosen {
+            Some(room) => room,
+            None if choice.remaining_secs() <= 0.0 => {
+                info!("Choosing a room for {:?} automatically", choice.player);
+                choice.options[0]
+            }
+            None => {
+                // Waiting isn't a change anything needs to react to
+                if let Some(choice) = dungeons.bypass_change_detection().choice.as_mut() {
+                    choice.waited += time.delta_secs();
+                }
+                return;
+            }
+        };
+        dungeons.choice = None;
+        enter_room(
+            &mut dungeons,
+            choice.player,
+            room,
+            &mut entered,
+            &mut completed,
+            &mut triggered,
+        );
+    } else {
+        choices.clear();
+    }
+
+    while !dungeons.pending.is_empty() {
+        let Some(venture) = dungeons.pending.pop_front() else {
+            break;
+        };
+        let options = dungeons.venture_options(venture.player, venture.into);
+        match options.as_slice() {
+            [] => {}
+            [room] => enter_room(
+                &mut dungeons,
+                venture.player,
+                *room,
+                &mut entered,
+                &mut completed,
+                &mut triggered,
+            ),
+            _ => {
+                dungeons.choice = Some(RoomChoice {
+                    player: venture.player,
+                    options,
+                    waited: 0.0,
+                });
+                break;
+            }
+        }
+    }
+}
+
+/// Forget the last game's dungeons when returning to the main menu
+pub fn clear_dungeons(mut dungeons: ResMut<Dungeons>) {
+    *dungeons = Dungeons::default();
+}
+
+/// Register venturing and the dungeons
+pub fn register_dungeon_systems(app: &mut App) {
+    app.add_event::<VentureEvent>()
+        .add_event::<ChooseRoomEvent>()
+        .add_event::<RoomEnteredEvent>()
+        .add_event::<DungeonCompletedEvent>()
+        .init_resource::<Dungeons>()
+        .add_systems(
+            Update,
+            handle_venture.run_if(in_state(GameMenuState::InGame)),
+        )
+        .add_systems(OnEnter(GameMenuState::MainMenu), clear_dungeons);
+}
+
+#[cfg(test)]
+mod tests;
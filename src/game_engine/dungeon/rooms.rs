@@ -0,0 +1,220 @@
+//! The dungeon cards, room by room
+
+/// A dungeon card
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dungeon {
+    LostMineOfPhandelver,
+    DungeonOfTheMadMage,
+    TombOfAnnihilation,
+    /// Only entered by venturing into the Undercity, as the initiative does
+    Undercity,
+}
+
+/// One room of a dungeon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Room {
+    pub name: &'static str,
+    /// The room ability, which triggers when a venture marker enters the room
+    pub ability: &'static str,
+    /// Indices of the rooms this one leads to, empty for the bottom room
+    pub next: &'static [usize],
+}
+
+const fn room(name: &'static str, ability: &'static str, next: &'static [usize]) -> Room {
+    Room {
+        name,
+        ability,
+        next,
+    }
+}
+
+const LOST_MINE_OF_PHANDELVER: &[Room] = &[
+    room("Cave Entrance", "Scry 1.", &[1, 2]),
+    room(
+        "Goblin Lair",
+        "Create a 1/1 red Goblin creature token.",
+        &[3, 4],
+    ),
+    room("Mine Tunnels", "Create a Treasure token.", &[4, 5]),
+    room("Storeroom", "Put a +1/+1 counter on target creature.", &[6]),
+    room(
+        "Dark Pool",
+        "Each opponent loses 1 life and you gain 1 life.",
+        &[6],
+    ),
+    room(
+        "Fungi Cavern",
+        "Target creature gets -4/-0 until your next turn.",
+        &[6],
+    ),
+    room("Temple of Dumathoin", "Draw a card.", &[]),
+];
+
+const DUNGEON_OF_THE_MAD_MAGE: &[Room] = &[
+    room("Yawning Portal", "You gain 1 life.", &[1]),
+    room("Dungeon Level", "Scry 1.", &[2, 3]),
+    room("Goblin Bazaar", "Create a Treasure token.", &[4]),
+    room(
+        "Twisted Caverns",
+        "Target creature can't attack until your next turn.",
+        &[4],
+    ),
+    room("Lost Level", "Scry 2.", &[5, 6]),
+    room(
+        "Runestone Caverns",
+        "Exile the top two cards of your library. You may play them.",
+        &[7],
+    ),
+    room(
+        "Muiral's Graveyard",
+        "Create two 1/1 black Skeleton creature tokens.",
+        &[7],
+    ),
+    room("Deep Mines", "Scry 3.", &[8]),
+    room(
+        "Mad Wizard's Lair",
+        "Draw three cards and reveal them. You may cast one of them without paying its mana cost.",
+        &[],
+    ),
+];
+
+const TOMB_OF_ANNIHILATION: &[Room] = &[
+    room("Trapped Entry", "Each player loses 1 life.", &[1, 2]),
+    room(
+        "Veils of Fear",
+        "Each player loses 2 life unless they discard a card.",
+        &[3],
+    ),
+    room(
+        "Oubliette",
+        "Discard a card and sacrifice an artifact, a creature, and a land.",
+        &[4],
+    ),
+    room(
+        "Sandfall Cell",
+        "Each player loses 2 life unless they sacrifice an artifact, a creature, or a land.",
+        &[4],
+    ),
+    room(
+        "Cradle of the Death God",
+        "Create a 4/4 black God Horror creature token with deathtouch.",
+        &[],
+    ),
+];
+
+const UNDERCITY: &[Room] = &[
+    room(
+        "Secret Entrance",
+        "Search your library for a basic land card, reveal it, put it into your hand, then shuffle.",
+        &[1, 2],
+    ),
+    room(
+        "Forge",
+        "Put two +1/+1 counters on target creature.",
+        &[3, 4],
+    ),
+    room("Lost Well", "Scry 2.", &[4, 5]),
+    room("Trap!", "Target player loses 5 life.", &[6]),
+    room("Arena", "Goad target creature.", &[6, 7]),
+    room("Stash", "Create a Treasure token.", &[7]),
+    room("Archives", "Draw a card.", &[8]),
+    room(
+        "Catacombs",
+        "Create a 4/1 black Skeleton creature token with menace.",
+        &[8],
+    ),
+    room(
+        "Throne of the Dead Three",
+        "Reveal the top ten cards of your library. Put a creature card from among them onto the battlefield with three +1/+1 counters on it. It gains hexproof until your next turn. Then shuffle.",
+        &[],
+    ),
+];
+
+impl Dungeon {
+    /// The dungeons a player may choose from when venturing into the dungeon
+    pub const VENTURE_CHOICES: [Dungeon; 3] = [
+        Dungeon::LostMineOfPhandelver,
+        Dungeon::DungeonOfTheMadMage,
+        Dungeon::TombOfAnnihilation,
+    ];
+
+    /// The dungeon's name
+    pub fn name(self) -> &'static str {
+        match self {
+            Dungeon::LostMineOfPhandelver => "Lost Mine of Phandelver",
+            Dungeon::DungeonOfTheMadMage => "Dungeon of the Mad Mage",
+            Dungeon::TombOfAnnihilation => "Tomb of Annihilation",
+            Dungeon::Undercity => "Undercity",
+        }
+    }
+
+    /// The dungeon's rooms; the first is its entrance
+    pub fn rooms(self) -> &'static [Room] {
+        match self {
+            Dungeon::LostMineOfPhandelver => LOST_MINE_OF_PHANDELVER,
+            Dungeon::DungeonOfTheMadMage => DUNGEON_OF_THE_MAD_MAGE,
+            Dungeon::TombOfAnnihilation => TOMB_OF_ANNIHILATION,
+            Dungeon::Undercity => UNDERCITY,
+        }
+    }
+
+    /// The room a venture marker is put in when the dungeon is started
+    pub fn entrance(self) -> DungeonRoom {
+        DungeonRoom {
+            dungeon: self,
+            index: 0,
+        }
+    }
+
+    /// A room of the dungeon by name
+    pub fn room_named(self, name: &str) -> Option<DungeonRoom> {
+        self.rooms()
+            .iter()
+            .position(|room| room.name == name)
+            .map(|index| DungeonRoom {
+                dungeon: self,
+                index,
+            })
+    }
+}
+
+/// A room of a particular dungeon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DungeonRoom {
+    pub dungeon: Dungeon,
+    /// Index into [`Dungeon::rooms`]
+    pub index: usize,
+}
+
+impl DungeonRoom {
+    fn room(self) -> &'static Room {
+        &self.dungeon.rooms()[self.index]
+    }
+
+    /// The room's name
+    pub fn name(self) -> &'static str {
+        self.room().name
+    }
+
+    /// The room's ability
+    pub fn ability(self) -> &'static str {
+        self.room().ability
+    }
+
+    /// The rooms this one leads to
+    pub fn next_rooms(self) -> Vec<DungeonRoom> {
+        self.room()
+            .next
+            .iter()
+            .map(|&index| DungeonRoom {
+                dungeon: self.dungeon,
+                index,
+            })
+            .collect()
+    }
+
+    /// Whether this is the dungeon's bottom room
+    pub fn is_last(self) -> bool {
+        self.room().next.is_empty()
+    }
+}
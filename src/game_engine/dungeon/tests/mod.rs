@@ -0,0 +1,159 @@
+use bevy::prelude::*;
+
+use crate::game_engine::dungeon::{
+    ChooseRoomEvent, Dungeon, DungeonCompletedEvent, DungeonRoom, Dungeons,
+    ROOM_CHOICE_TIMEOUT_SECS, RoomEnteredEvent, VentureEvent, VentureInto, handle_venture,
+};
+use crate::game_engine::triggers::AbilityTriggeredEvent;
+
+fn dungeon_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<VentureEvent>()
+        .add_event::<ChooseRoomEvent>()
+        .add_event::<RoomEnteredEvent>()
+        .add_event::<DungeonCompletedEvent>()
+        .add_event::<AbilityTriggeredEvent>()
+        .init_resource::<Dungeons>()
+        .add_systems(Update, handle_venture);
+    let player = app.world_mut().spawn_empty().id();
+    (app, player)
+}
+
+fn room(dungeon: Dungeon, name: &str) -> DungeonRoom {
+    dungeon.room_named(name).unwrap()
+}
+
+fn venture(app: &mut App, player: Entity, into: VentureInto) {
+    app.world_mut().send_event(VentureEvent { player, into });
+    app.update();
+}
+
+fn choose(app: &mut App, player: Entity, room: DungeonRoom) {
+    app.world_mut().send_event(ChooseRoomEvent { player, room });
+    app.update();
+}
+
+fn entered(app: &mut App) -> Vec<&'static str> {
+    app.world_mut()
+        .resource_mut::<Events<RoomEnteredEvent>>()
+        .drain()
+        .map(|event| event.room.name())
+        .collect()
+}
+
+#[test]
+fn test_every_path_ends_in_the_bottom_room() {
+    let dungeons = Dungeon::VENTURE_CHOICES.iter().chain([&Dungeon::Undercity]);
+    for &dungeon in dungeons {
+        let mut paths = vec![vec![dungeon.entrance()]];
+        while let Some(path) = paths.pop() {
+            let room = *path.last().unwrap();
+            assert!(path.len() <= dungeon.rooms().len());
+            for next in room.next_rooms() {
+                // Rooms only lead deeper into the dungeon
+                assert!(next.index > room.index);
+                paths.push(path.iter().copied().chain([next]).collect());
+            }
+        }
+        let last = dungeon.rooms().len() - 1;
+        assert!(
+            dungeon.rooms()[..last]
+                .iter()
+                .all(|room| !room.next.is_empty())
+        );
+        assert!(dungeon.rooms()[last].next.is_empty());
+    }
+}
+
+#[test]
+fn test_venturing_into_the_dungeon_asks_which_dungeon() {
+    let (mut app, player) = dungeon_app();
+
+    venture(&mut app, player, VentureInto::Dungeon);
+    let choice = app.world().resource::<Dungeons>().choice.clone().unwrap();
+    assert!(choice.is_starting_dungeon());
+    assert_eq!(choice.options.len(), 3);
+    assert!(entered(&mut app).is_empty());
+
+    // A second venture waits behind the choice
+    venture(&mut app, player, VentureInto::Dungeon);
+    choose(&mut app, player, Dungeon::DungeonOfTheMadMage.entrance());
+    assert_eq!(entered(&mut app), vec!["Yawning Portal", "Dungeon Level"]);
+    let triggers: Vec<AbilityTriggeredEvent> = app
+        .world_mut()
+        .resource_mut::<Events<AbilityTriggeredEvent>>()
+        .drain()
+        .collect();
+    assert_eq!(triggers.len(), 2);
+    assert_eq!(triggers[0].0.controller, player);
+    assert_eq!(
+        triggers[0].0.description,
+        "Yawning Portal: You gain 1 life."
+    );
+
+    // Dungeon Level leads to two rooms
+    venture(&mut app, player, VentureInto::Dungeon);
+    let choice = app.world().resource::<Dungeons>().choice.clone().unwrap();
+    assert!(!choice.is_starting_dungeon());
+    // Choosing a room that isn't next does nothing
+    choose(
+        &mut app,
+        player,
+        room(Dungeon::DungeonOfTheMadMage, "Deep Mines"),
+    );
+    assert!(app.world().resource::<Dungeons>().choice.is_some());
+    choose(
+        &mut app,
+        player,
+        room(Dungeon::DungeonOfTheMadMage, "Twisted Caverns"),
+    );
+    assert_eq!(entered(&mut app), vec!["Twisted Caverns"]);
+}
+
+#[test]
+fn test_undercity_ventures_continue_the_current_dungeon() {
+    let (mut app, player) = dungeon_app();
+    venture(&mut app, player, VentureInto::Undercity);
+    assert_eq!(entered(&mut app), vec!["Secret Entrance"]);
+
+    let mut dungeons = app.world_mut().resource_mut::<Dungeons>();
+    dungeons
+        .progress
+        .insert(player, room(Dungeon::LostMineOfPhandelver, "Dark Pool"));
+    venture(&mut app, player, VentureInto::Undercity);
+    assert_eq!(entered(&mut app), vec!["Temple of Dumathoin"]);
+
+    let completed: Vec<DungeonCompletedEvent> = app
+        .world_mut()
+        .resource_mut::<Events<DungeonCompletedEvent>>()
+        .drain()
+        .collect();
+    assert_eq!(
+        completed,
+        vec![DungeonCompletedEvent {
+            player,
+            dungeon: Dungeon::LostMineOfPhandelver
+        }]
+    );
+    let dungeons = app.world().resource::<Dungeons>();
+    assert!(dungeons.has_completed(player, Dungeon::LostMineOfPhandelver));
+    assert!(!dungeons.has_completed(player, Dungeon::Undercity));
+    assert_eq!(dungeons.current_room(player), None);
+}
+
+#[test]
+fn test_unanswered_choices_take_the_first_option() {
+    let (mut app, player) = dungeon_app();
+    venture(&mut app, player, VentureInto::Dungeon);
+
+    app.world_mut()
+        .resource_mut::<Dungeons>()
+        .choice
+        .as_mut()
+        .unwrap()
+        .waited = ROOM_CHOICE_TIMEOUT_SECS;
+    app.update();
+    assert_eq!(entered(&mut app), vec!["Cave Entrance"]);
+    assert!(app.world().resource::<Dungeons>().choice.is_none());
+}
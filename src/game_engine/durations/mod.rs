@@ -0,0 +1,210 @@
+//! Effects that last for a limited time
+//!
+//! A temporary effect is a component on the entity it affects, listed in that
+//! entity's [`TemporaryEffects`] with how long it lasts. When the time comes the
+//! component is removed and an [`EffectExpiredEvent`] is sent:
+//!
+//! - "until end of turn" effects end in the cleanup step (rule 514.2)
+//! - "until end of combat" effects end when combat is over
+//! - "until your next turn" effects end as that player's untap step begins
+//! - effects lasting while their source is on the battlefield end as soon as it
+//!   leaves
+//!
+//! Give temporary effects with [`TemporaryEffectCommands::insert_temporary`]. An
+//! entity holds one effect of each component type, so a second effect of the
+//! same type replaces the first and both listings end when either does.
+
+use bevy::prelude::*;
+
+use crate::game_engine::phase::{BeginningStep, EndingStep, Phase, StepBeganEvent};
+use crate::game_engine::zones::{Zone, ZoneManager};
+use crate::menu::state::GameMenuState;
+
+/// How long a temporary effect lasts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectDuration {
+    /// Until the cleanup step of this turn
+    EndOfTurn,
+    /// Until combat this turn is over
+    EndOfCombat,
+    /// As long as this permanent stays on the battlefield
+    WhileSourceOnBattlefield(Entity),
+    /// Until this player's next turn begins
+    UntilYourNextTurn(Entity),
+}
+
+impl EffectDuration {
+    /// Whether an effect with this duration ends as `step` of `active_player`'s
+    /// turn begins
+    pub fn ends_at_step(&self, step: Phase, active_player: Entity) -> bool {
+        match self {
+            EffectDuration::EndOfTurn => step == Phase::Ending(EndingStep::Cleanup),
+            EffectDuration::EndOfCombat => matches!(step, Phase::Postcombat(_) | Phase::Ending(_)),
+            EffectDuration::UntilYourNextTurn(player) => {
+                step == Phase::Beginning(BeginningStep::Untap) && active_player == *player
+            }
+            EffectDuration::WhileSourceOnBattlefield(_) => false,
+        }
+    }
+}
+
+/// An effect on an entity that ends after a while
+#[derive(Debug, Clone)]
+pub struct TemporaryEffect {
+    /// What the effect does, as shown to players
+    pub label: String,
+    pub duration: EffectDuration,
+    /// Removes the effect's component
+    remove: fn(&mut EntityCommands),
+}
+
+impl TemporaryEffect {
+    /// An effect given by component `C`
+    pub fn new<C: Component>(label: impl Into<String>, duration: EffectDuration) -> Self {
+        Self {
+            label: label.into(),
+            duration,
+            remove: remove_component::<C>,
+        }
+    }
+}
+
+fn remove_component<C: Component>(entity: &mut EntityCommands) {
+    entity.remove::<C>();
+}
+
+/// The temporary effects on an entity
+#[derive(Component, Debug, Clone, Default)]
+pub struct TemporaryEffects(pub Vec<TemporaryEffect>);
+
+impl TemporaryEffects {
+    /// Labels of the effects, oldest first
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(|effect| effect.label.as_str())
+    }
+}
+
+/// A temporary effect ended and its component was removed
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct EffectExpiredEvent {
+    /// The entity the effect was on
+    pub entity: Entity,
+    pub label: String,
+}
+
+/// Giving entities temporary effects
+pub trait TemporaryEffectCommands {
+    /// Insert `component` as an effect lasting for `duration`
+    fn insert_temporary<C: Component>(
+        &mut self,
+        component: C,
+        label: impl Into<String>,
+        duration: EffectDuration,
+    ) -> &mut Self;
+}
+
+impl TemporaryEffectCommands for EntityCommands<'_> {
+    fn insert_temporary<C: Component>(
+        &mut self,
+        component: C,
+        label: impl Into<String>,
+        duration: EffectDuration,
+    ) -> &mut Self {
+        let effect = TemporaryEffect::new::<C>(label, duration);
+        self.insert(component);
+        self.entry::<TemporaryEffects>()
+            .or_default()
+            .and_modify(move |mut effects| effects.0.push(effect));
+        self
+    }
+}
+
+/// Remove the effects on an entity that have ended
+fn expire(
+    commands: &mut Commands,
+    entity: Entity,
+    effects: &mut TemporaryEffects,
+    ended: impl Fn(&EffectDuration) -> bool,
+    expired: &mut EventWriter<EffectExpiredEvent>,
+) {
+    let (ending, lasting): (Vec<_>, Vec<_>) = std::mem::take(&mut effects.0)
+        .into_iter()
+        .partition(|effect| ended(&effect.duration));
+    effects.0 = lasting;
+
+    let mut entity_commands = commands.entity(entity);
+    for effect in ending {
+        debug!("Effect \"{}\" on {:?} ended", effect.label, entity);
+        (effect.remove)(&mut entity_commands);
+        expired.write(EffectExpiredEvent {
+            entity,
+            label: effect.label,
+        });
+    }
+    if effects.0.is_empty() {
+        entity_commands.remove::<TemporaryEffects>();
+    }
+}
+
+/// End effects whose duration runs out as a step begins
+pub fn expire_effects_at_step(
+    mut commands: Commands,
+    mut steps: EventReader<StepBeganEvent>,
+    mut entities: Query<(Entity, &mut TemporaryEffects)>,
+    mut expired: EventWriter<EffectExpiredEvent>,
+) {
+    for step in steps.read() {
+        for (entity, mut effects) in entities.iter_mut() {
+            if effects
+                .0
+                .iter()
+                .any(|effect| effect.duration.ends_at_step(step.phase, step.active_player))
+            {
+                expire(
+                    &mut commands,
+                    entity,
+                    &mut effects,
+                    |duration| duration.ends_at_step(step.phase, step.active_player),
+                    &mut expired,
+                );
+            }
+        }
+    }
+}
+
+/// End effects whose source left the battlefield
+pub fn expire_effects_with_source(
+    mut commands: Commands,
+    zones: Option<Res<ZoneManager>>,
+    mut entities: Query<(Entity, &mut TemporaryEffects)>,
+    mut expired: EventWriter<EffectExpiredEvent>,
+) {
+    let Some(zones) = zones else {
+        return;
+    };
+    let gone = |duration: &EffectDuration| match duration {
+        EffectDuration::WhileSourceOnBattlefield(source) => {
+            zones.get_card_zone(*source) != Some(Zone::Battlefield)
+        }
+        _ => false,
+    };
+
+    for (entity, mut effects) in entities.iter_mut() {
+        if effects.0.iter().any(|effect| gone(&effect.duration)) {
+            expire(&mut commands, entity, &mut effects, &gone, &mut expired);
+        }
+    }
+}
+
+/// Register temporary effect expiry
+pub fn register_duration_systems(app: &mut App) {
+    app.add_event::<EffectExpiredEvent>().add_systems(
+        Update,
+        (expire_effects_at_step, expire_effects_with_source)
+            .chain()
+            .run_if(in_state(GameMenuState::InGame)),
+    );
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,135 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+
+use crate::game_engine::durations::{
+    EffectDuration, EffectExpiredEvent, TemporaryEffectCommands, TemporaryEffects,
+    expire_effects_at_step, expire_effects_with_source,
+};
+use crate::game_engine::phase::{
+    BeginningStep, CombatStep, EndingStep, Phase, PostcombatStep, StepBeganEvent,
+};
+use crate::game_engine::zones::ZoneManager;
+
+#[derive(Component)]
+struct Pumped;
+
+#[derive(Component)]
+struct Hexproof;
+
+fn duration_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<StepBeganEvent>()
+        .add_event::<EffectExpiredEvent>()
+        .init_resource::<ZoneManager>()
+        .add_systems(
+            Update,
+            (expire_effects_at_step, expire_effects_with_source).chain(),
+        );
+    app
+}
+
+fn give(app: &mut App, entity: Entity, duration: EffectDuration, label: &'static str) {
+    app.world_mut()
+        .run_system_once(move |mut commands: Commands| {
+            commands
+                .entity(entity)
+                .insert_temporary(Pumped, label, duration);
+        })
+        .unwrap();
+}
+
+fn begin_step(app: &mut App, phase: Phase, active_player: Entity) {
+    app.world_mut().send_event(StepBeganEvent {
+        phase,
+        active_player,
+    });
+    app.update();
+}
+
+fn expired(app: &mut App) -> Vec<EffectExpiredEvent> {
+    app.world_mut()
+        .resource_mut::<Events<EffectExpiredEvent>>()
+        .drain()
+        .collect()
+}
+
+#[test]
+fn test_durations_end_at_the_right_steps() {
+    let [you, opponent] = [1, 2].map(Entity::from_raw);
+    let combat_damage = Phase::Combat(CombatStep::CombatDamage);
+    let end_of_combat = Phase::Postcombat(PostcombatStep::Main);
+    let cleanup = Phase::Ending(EndingStep::Cleanup);
+    let untap = Phase::Beginning(BeginningStep::Untap);
+
+    assert!(!EffectDuration::EndOfTurn.ends_at_step(end_of_combat, you));
+    assert!(EffectDuration::EndOfTurn.ends_at_step(cleanup, you));
+    assert!(!EffectDuration::EndOfCombat.ends_at_step(combat_damage, you));
+    assert!(EffectDuration::EndOfCombat.ends_at_step(end_of_combat, you));
+    assert!(!EffectDuration::UntilYourNextTurn(you).ends_at_step(untap, opponent));
+    assert!(EffectDuration::UntilYourNextTurn(you).ends_at_step(untap, you));
+    assert!(!EffectDuration::WhileSourceOnBattlefield(opponent).ends_at_step(cleanup, you));
+}
+
+#[test]
+fn test_end_of_turn_effects_are_removed_in_cleanup() {
+    let mut app = duration_app();
+    let player = app.world_mut().spawn_empty().id();
+    let creature = app.world_mut().spawn_empty().id();
+    give(&mut app, creature, EffectDuration::EndOfTurn, "+3/+3");
+    app.world_mut()
+        .run_system_once(move |mut commands: Commands| {
+            commands.entity(creature).insert_temporary(
+                Hexproof,
+                "Hexproof",
+                EffectDuration::UntilYourNextTurn(player),
+            );
+        })
+        .unwrap();
+    let effects = app.world().get::<TemporaryEffects>(creature).unwrap();
+    assert_eq!(effects.labels().collect::<Vec<_>>(), ["+3/+3", "Hexproof"]);
+
+    begin_step(&mut app, Phase::Ending(EndingStep::End), player);
+    assert!(app.world().get::<Pumped>(creature).is_some());
+
+    begin_step(&mut app, Phase::Ending(EndingStep::Cleanup), player);
+    assert!(app.world().get::<Pumped>(creature).is_none());
+    assert!(app.world().get::<Hexproof>(creature).is_some());
+    assert_eq!(
+        expired(&mut app),
+        vec![EffectExpiredEvent {
+            entity: creature,
+            label: "+3/+3".to_string(),
+        }]
+    );
+
+    begin_step(&mut app, Phase::Beginning(BeginningStep::Untap), player);
+    assert!(app.world().get::<Hexproof>(creature).is_none());
+    assert!(app.world().get::<TemporaryEffects>(creature).is_none());
+}
+
+#[test]
+fn test_effects_end_when_their_source_leaves_the_battlefield() {
+    let mut app = duration_app();
+    let player = app.world_mut().spawn_empty().id();
+    let [anthem, creature] = [(); 2].map(|_| app.world_mut().spawn_empty().id());
+    let mut zones = app.world_mut().resource_mut::<ZoneManager>();
+    zones.init_player_zones(player);
+    zones.add_to_battlefield(player, anthem);
+    give(
+        &mut app,
+        creature,
+        EffectDuration::WhileSourceOnBattlefield(anthem),
+        "+1/+1",
+    );
+
+    app.update();
+    assert!(app.world().get::<Pumped>(creature).is_some());
+
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .add_to_graveyard(player, anthem);
+    app.update();
+    assert!(app.world().get::<Pumped>(creature).is_none());
+    assert_eq!(expired(&mut app).len(), 1);
+}
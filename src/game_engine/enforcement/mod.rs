@@ -0,0 +1,85 @@
+//! How strictly the engine enforces the rules
+//!
+//! Playgroups pick a [`RulesEnforcementLevel`] on the new game screen. Systems
+//! that check the rules ask it whether an action the rules don't allow may go
+//! ahead anyway.
+
+use std::fmt;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// How much of the rules the engine enforces
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum RulesEnforcementLevel {
+    /// The engine rejects anything the rules don't allow
+    #[default]
+    Full,
+    /// The engine warns about rules violations but lets players override it
+    Assisted,
+    /// The players apply the rules themselves and the engine only keeps track of
+    /// the game, as in a sandbox
+    Manual,
+}
+
+impl RulesEnforcementLevel {
+    /// Label shown on the new game screen
+    pub fn label(self) -> &'static str {
+        match self {
+            RulesEnforcementLevel::Full => "Full",
+            RulesEnforcementLevel::Assisted => "Assisted",
+            RulesEnforcementLevel::Manual => "Manual",
+        }
+    }
+
+    /// The next level, for cycling through them with one button
+    pub fn next(self) -> Self {
+        match self {
+            RulesEnforcementLevel::Full => RulesEnforcementLevel::Assisted,
+            RulesEnforcementLevel::Assisted => RulesEnforcementLevel::Manual,
+            RulesEnforcementLevel::Manual => RulesEnforcementLevel::Full,
+        }
+    }
+
+    /// Whether an action may go ahead, given whether the rules allow it
+    ///
+    /// Rejections and overridden violations are logged with `action` describing
+    /// what was attempted.
+    pub fn allows(self, legal: bool, action: impl fmt::Display) -> bool {
+        if legal {
+            return true;
+        }
+        match self {
+            RulesEnforcementLevel::Full => {
+                warn!("Rejected {}: not allowed by the rules", action);
+                false
+            }
+            RulesEnforcementLevel::Assisted => {
+                warn!("Allowed {}, which the rules don't allow", action);
+                true
+            }
+            RulesEnforcementLevel::Manual => true,
+        }
+    }
+
+    /// Whether state-based actions are performed automatically
+    pub fn performs_state_based_actions(self) -> bool {
+        self != RulesEnforcementLevel::Manual
+    }
+
+    /// Whether players may move cards, tap permanents and change life totals by
+    /// hand, see [`SandboxAction`](crate::game_engine::sandbox::SandboxAction)
+    pub fn allows_manual_changes(self) -> bool {
+        self != RulesEnforcementLevel::Full
+    }
+}
+
+/// Run condition for the state-based action check
+///
+/// Games without a level set, such as tests, enforce the rules fully.
+pub fn state_based_actions_enabled(level: Option<Res<RulesEnforcementLevel>>) -> bool {
+    level.is_none_or(|level| level.performs_state_based_actions())
+}
+
+#[cfg(test)]
+mod tests;
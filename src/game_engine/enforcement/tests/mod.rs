@@ -0,0 +1,13 @@
+use crate::game_engine::enforcement::RulesEnforcementLevel;
+
+#[test]
+fn test_levels_decide_whether_violations_go_ahead() {
+    assert!(RulesEnforcementLevel::Full.allows(true, "a legal action"));
+    assert!(!RulesEnforcementLevel::Full.allows(false, "an illegal action"));
+    assert!(RulesEnforcementLevel::Assisted.allows(false, "an illegal action"));
+    assert!(RulesEnforcementLevel::Manual.allows(false, "an illegal action"));
+
+    assert!(!RulesEnforcementLevel::Full.allows_manual_changes());
+    assert!(RulesEnforcementLevel::Assisted.performs_state_based_actions());
+    assert!(!RulesEnforcementLevel::Manual.performs_state_based_actions());
+}
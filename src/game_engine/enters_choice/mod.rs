@@ -0,0 +1,262 @@
+//! Choices made as a permanent enters the battlefield
+//!
+//! Some permanents have their controller choose something as they enter, such as
+//! a color, a card name or an opponent (rule 614.12c). A permanent with
+//! [`EntersWithChoice`] isn't done entering until the choice is made: its
+//! [`EntersBattlefieldEvent`] is held back and the controller is prompted, and
+//! once they choose with a [`ChooseOnEntryEvent`] (or the first option is taken
+//! after [`ENTERS_CHOICE_TIMEOUT_SECS`]) the choice is stored in [`ChosenOnEntry`]
+//! for the permanent's abilities and the event is sent again.
+//!
+//! Choices are made one at a time, in the order the permanents entered.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::cards::Card;
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::zones::EntersBattlefieldEvent;
+use crate::mana::ManaColor;
+use crate::menu::state::GameMenuState;
+use crate::player::Player;
+
+/// Seconds a player has to make a choice before the first option is taken
+pub const ENTERS_CHOICE_TIMEOUT_SECS: f32 = 15.0;
+
+/// What is chosen as a permanent enters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntersChoiceKind {
+    Color,
+    CardName,
+    Opponent,
+}
+
+/// A permanent whose controller makes a choice as it enters the battlefield
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntersWithChoice(pub EntersChoiceKind);
+
+/// Something chosen as a permanent entered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntersChoiceValue {
+    Color(ManaColor),
+    CardName(String),
+    Opponent(Entity),
+}
+
+/// The choice made as a permanent entered the battlefield
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct ChosenOnEntry(pub EntersChoiceValue);
+
+/// Sent by a player to make the choice for a permanent entering
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ChooseOnEntryEvent {
+    pub player: Entity,
+    pub permanent: Entity,
+    pub value: EntersChoiceValue,
+}
+
+/// A player choosing for a permanent entering the battlefield
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntersChoice {
+    pub permanent: Entity,
+    /// The permanent's controller, who makes the choice
+    pub player: Entity,
+    pub kind: EntersChoiceKind,
+    pub options: Vec<EntersChoiceValue>,
+    /// Seconds spent waiting for the player so far
+    pub waited: f32,
+}
+
+impl EntersChoice {
+    /// Seconds left before the first option is taken
+    pub fn remaining_secs(&self) -> f32 {
+        (ENTERS_CHOICE_TIMEOUT_SECS - self.waited).max(0.0)
+    }
+}
+
+/// A permanent that entered and is waiting for its choice to be made
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeldEntry {
+    pub permanent: Entity,
+    pub owner: Entity,
+    pub enters_tapped: bool,
+}
+
+/// Permanents waiting for their controllers to choose as they enter
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct EntersChoices {
+    /// Entries held back until the choice before them is made
+    pub pending: VecDeque<HeldEntry>,
+    /// The entry being chosen for
+    pub held: Option<HeldEntry>,
+    /// The choice being made, if any
+    pub choice: Option<EntersChoice>,
+}
+
+/// The colors a permanent can have chosen for it, in WUBRG order
+pub const CHOOSABLE_COLORS: [ManaColor; 5] = [
+    ManaColor::WHITE,
+    ManaColor::BLUE,
+    ManaColor::BLACK,
+    ManaColor::RED,
+    ManaColor::GREEN,
+];
+
+/// Hold back entries for permanents that make a choice as they enter
+pub fn hold_entries_with_choices(
+    mut entries: EventReader<EntersBattlefieldEvent>,
+    mut choices: ResMut<EntersChoices>,
+    waiting: Query<(), (With<EntersWithChoice>, Without<ChosenOnEntry>)>,
+) {
+    for event in entries.read() {
+        if waiting.contains(event.permanent) {
+            choices.pending.push_back(HeldEntry {
+                permanent: event.permanent,
+                owner: event.owner,
+                enters_tapped: event.enters_tapped,
+            });
+        }
+    }
+}
+
+/// The options for a choice, in the order they're offered
+fn choice_options(
+    kind: EntersChoiceKind,
+    player: Entity,
+    players: &Query<(Entity, &Player)>,
+    cards: &Query<&Card>,
+) -> Vec<EntersChoiceValue> {
+    match kind {
+        EntersChoiceKind::Color => CHOOSABLE_COLORS
+            .iter()
+            .map(|&color| EntersChoiceValue::Color(color))
+            .collect(),
+        EntersChoiceKind::CardName => {
+            // Any card name can be chosen; offer the names of the cards in the game
+            let mut names: Vec<String> = cards
+                .iter()
+                .map(|card| card.name.name.clone())
+                .filter(|name| !name.is_empty())
+                .collect();
+            names.sort();
+            names.dedup();
+            names.into_iter().map(EntersChoiceValue::CardName).collect()
+        }
+        EntersChoiceKind::Opponent => {
+            let mut opponents: Vec<(Entity, &Player)> = players
+                .iter()
+                .filter(|(entity, _)| *entity != player)
+                .collect();
+            opponents.sort_by_key(|(_, player)| player.player_index);
+            opponents
+                .into_iter()
+                .map(|(entity, _)| EntersChoiceValue::Opponent(entity))
+                .collect()
+        }
+    }
+}
+
+/// Make the choices for held entries one at a time, then let them finish entering
+#[allow(clippy::too_many_arguments)]
+pub fn handle_enters_choices(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut chosen: EventReader<ChooseOnEntryEvent>,
+    mut entries: EventWriter<EntersBattlefieldEvent>,
+    mut choices: ResMut<EntersChoices>,
+    permanents: Query<(&EntersWithChoice, Option<&PermanentController>)>,
+    players: Query<(Entity, &Player)>,
+    cards: Query<&Card>,
+) {
+    if let Some(choice) = choices.choice.clone() {
+        let value = chosen
+            .read()
+            .filter(|event| {
+                event.player == choice.player
+                    && event.permanent == choice.permanent
+                    && choice.options.contains(&event.value)
+            })
+            .map(|event| event.value.clone())
+            .last();
+        let value = match value {
+            Some(value) => value,
+            None if choice.remaining_secs() <= 0.0 => {
+                info!("Choosing for {:?} automatically", choice.permanent);
+                choice.options[0].clone()
+            }
+            None => {
+                // Waiting isn't a change anything needs to react to
+                if let Some(choice) = choices.bypass_change_detection().choice.as_mut() {
+                    choice.waited += time.delta_secs();
+                }
+                return;
+            }
+        };
+        info!(
+            "{:?} chose {:?} for {:?}",
+            choice.player, value, choice.permanent
+        );
+        commands
+            .entity(choice.permanent)
+            .insert(ChosenOnEntry(value));
+        choices.choice = None;
+        if let Some(held) = choices.held.take() {
+            entries.write(EntersBattlefieldEvent {
+                permanent: held.permanent,
+                owner: held.owner,
+                enters_tapped: held.enters_tapped,
+            });
+        }
+    } else {
+        chosen.clear();
+    }
+
+    while let Some(held) = choices.pending.pop_front() {
+        let Ok((EntersWithChoice(kind), controller)) = permanents.get(held.permanent) else {
+            continue;
+        };
+        let player = controller.map_or(held.owner, |controller| controller.player);
+        let options = choice_options(*kind, player, &players, &cards);
+        if options.is_empty() {
+            // Nothing to choose from, so the permanent enters without a choice
+            commands.entity(held.permanent).remove::<EntersWithChoice>();
+            entries.write(EntersBattlefieldEvent {
+                permanent: held.permanent,
+                owner: held.owner,
+                enters_tapped: held.enters_tapped,
+            });
+            continue;
+        }
+        choices.held = Some(held);
+        choices.choice = Some(EntersChoice {
+            permanent: held.permanent,
+            player,
+            kind: *kind,
+            options,
+            waited: 0.0,
+        });
+        break;
+    }
+}
+
+/// Forget held entries when returning to the main menu
+pub fn clear_enters_choices(mut choices: ResMut<EntersChoices>) {
+    *choices = EntersChoices::default();
+}
+
+/// Register choices made as permanents enter
+pub fn register_enters_choice_systems(app: &mut App) {
+    app.add_event::<ChooseOnEntryEvent>()
+        .init_resource::<EntersChoices>()
+        .add_systems(
+            Update,
+            (hold_entries_with_choices, handle_enters_choices)
+                .chain()
+                .run_if(in_state(GameMenuState::InGame)),
+        )
+        .add_systems(OnEnter(GameMenuState::MainMenu), clear_enters_choices);
+}
+
+#[cfg(test)]
+mod tests;
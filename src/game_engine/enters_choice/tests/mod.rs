@@ -0,0 +1,154 @@
+use bevy::prelude::*;
+
+use crate::cards::sets::innistrad_midnight_hunt::brutal_cathar;
+use crate::game_engine::enters_choice::{
+    ChooseOnEntryEvent, ChosenOnEntry, ENTERS_CHOICE_TIMEOUT_SECS, EntersChoiceKind,
+    EntersChoiceValue, EntersChoices, EntersWithChoice, handle_enters_choices,
+    hold_entries_with_choices,
+};
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::zones::EntersBattlefieldEvent;
+use crate::mana::ManaColor;
+use crate::player::Player;
+
+fn enters_choice_app() -> (App, Entity, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<EntersBattlefieldEvent>()
+        .add_event::<ChooseOnEntryEvent>()
+        .init_resource::<EntersChoices>()
+        .add_systems(
+            Update,
+            (hold_entries_with_choices, handle_enters_choices).chain(),
+        );
+    let alice = app.world_mut().spawn(Player::new("Alice")).id();
+    let bob = app
+        .world_mut()
+        .spawn(Player::new("Bob").with_player_index(1))
+        .id();
+    (app, alice, bob)
+}
+
+fn enter(app: &mut App, controller: Entity, kind: EntersChoiceKind) -> Entity {
+    let permanent = app
+        .world_mut()
+        .spawn((EntersWithChoice(kind), PermanentController::new(controller)))
+        .id();
+    app.world_mut().send_event(EntersBattlefieldEvent {
+        permanent,
+        owner: controller,
+        enters_tapped: false,
+    });
+    app.update();
+    permanent
+}
+
+fn chosen(app: &App, permanent: Entity) -> Option<&EntersChoiceValue> {
+    app.world()
+        .get::<ChosenOnEntry>(permanent)
+        .map(|chosen| &chosen.0)
+}
+
+fn entered(app: &mut App) -> Vec<Entity> {
+    app.world_mut()
+        .resource_mut::<Events<EntersBattlefieldEvent>>()
+        .drain()
+        .map(|event| event.permanent)
+        .collect()
+}
+
+#[test]
+fn test_entering_waits_for_the_controller_to_choose() {
+    let (mut app, alice, bob) = enters_choice_app();
+    let permanent = enter(&mut app, alice, EntersChoiceKind::Opponent);
+    entered(&mut app);
+
+    let choice = app
+        .world()
+        .resource::<EntersChoices>()
+        .choice
+        .clone()
+        .unwrap();
+    assert_eq!(choice.player, alice);
+    assert_eq!(choice.options, vec![EntersChoiceValue::Opponent(bob)]);
+
+    // Only the controller can choose, and only one of the options
+    for (player, value) in [
+        (bob, EntersChoiceValue::Opponent(bob)),
+        (alice, EntersChoiceValue::Opponent(alice)),
+    ] {
+        app.world_mut().send_event(ChooseOnEntryEvent {
+            player,
+            permanent,
+            value,
+        });
+        app.update();
+    }
+    assert_eq!(chosen(&app, permanent), None);
+    assert!(entered(&mut app).is_empty());
+
+    app.world_mut().send_event(ChooseOnEntryEvent {
+        player: alice,
+        permanent,
+        value: EntersChoiceValue::Opponent(bob),
+    });
+    app.update();
+    assert_eq!(
+        chosen(&app, permanent),
+        Some(&EntersChoiceValue::Opponent(bob))
+    );
+
+    // The entry sent again isn't held a second time
+    app.update();
+    assert_eq!(entered(&mut app), vec![permanent]);
+    assert!(app.world().resource::<EntersChoices>().choice.is_none());
+    assert!(app.world().resource::<EntersChoices>().pending.is_empty());
+}
+
+#[test]
+fn test_choices_are_made_in_order_and_time_out() {
+    let (mut app, alice, bob) = enters_choice_app();
+    app.world_mut().spawn(brutal_cathar::get_card());
+    let first = enter(&mut app, alice, EntersChoiceKind::Color);
+    let second = enter(&mut app, bob, EntersChoiceKind::CardName);
+
+    let choices = app.world().resource::<EntersChoices>();
+    assert_eq!(choices.choice.as_ref().unwrap().permanent, first);
+    assert_eq!(choices.choice.as_ref().unwrap().options.len(), 5);
+    assert_eq!(choices.pending.len(), 1);
+
+    app.world_mut()
+        .resource_mut::<EntersChoices>()
+        .choice
+        .as_mut()
+        .unwrap()
+        .waited = ENTERS_CHOICE_TIMEOUT_SECS;
+    app.update();
+    assert_eq!(
+        chosen(&app, first),
+        Some(&EntersChoiceValue::Color(ManaColor::WHITE))
+    );
+    let choice = app
+        .world()
+        .resource::<EntersChoices>()
+        .choice
+        .clone()
+        .unwrap();
+    assert_eq!(choice.permanent, second);
+    assert_eq!(choice.player, bob);
+    assert_eq!(
+        choice.options,
+        vec![EntersChoiceValue::CardName("Brutal Cathar".to_string())]
+    );
+}
+
+#[test]
+fn test_no_opponents_enters_without_a_choice() {
+    let (mut app, alice, bob) = enters_choice_app();
+    app.world_mut().despawn(bob);
+    let permanent = enter(&mut app, alice, EntersChoiceKind::Opponent);
+    // The entry as it happened, then as it's sent again
+    assert_eq!(entered(&mut app), vec![permanent, permanent]);
+    assert!(app.world().get::<EntersWithChoice>(permanent).is_none());
+    assert!(app.world().resource::<EntersChoices>().choice.is_none());
+}
@@ -0,0 +1,419 @@
+//! Face-down spells and permanents: morph and manifest
+//!
+//! A face-down permanent is a 2/2 creature with no name, mana cost, types or
+//! abilities (rule 708.2). Its [`Card`] is swapped for those characteristics while
+//! the real card is kept in [`FaceDown`], so combat, the card preview and
+//! everything else that reads cards sees only what's public.
+//!
+//! - a card with morph can be cast face down for {3}, as a face-down spell on the
+//!   stack
+//! - manifest puts the top card of a library onto the battlefield face down
+//! - turning a permanent face up is a special action: a morph pays its morph
+//!   cost, and a manifested creature card its mana cost
+//!
+//! A face-down permanent leaving the battlefield, or a face-down spell leaving the
+//! stack other than by resolving, is turned face up, so every player learns what
+//! it was. Until then, other players are only ever sent a [`FaceDownView`].
+
+mod visuals;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::keywords::KeywordAbility;
+use crate::cards::mtgjson::parse_mana_cost;
+use crate::cards::{Card, CardDetails, CardTypes, CreatureCard, CreatureType};
+use crate::game_engine::actions::valid_time_for_sorcery;
+use crate::game_engine::enforcement::RulesEnforcementLevel;
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::stack::{Effect, ResolutionDetails};
+use crate::game_engine::state::GameState;
+use crate::game_engine::zones::{Zone, ZoneChangeEvent, ZoneManager};
+use crate::game_engine::{EffectCounteredEvent, GameStack, Phase, PrioritySystem};
+use crate::mana::Mana;
+use crate::menu::state::GameMenuState;
+use crate::player::Player;
+
+pub use visuals::{CARD_BACK_COLOR, restore_card_faces, show_card_backs};
+
+/// What it costs to cast a spell face down
+pub fn face_down_cost() -> Mana {
+    Mana::new_with_colors(3, 0, 0, 0, 0, 0)
+}
+
+/// The characteristics of a face-down permanent: a nameless 2/2 creature
+pub fn face_down_card() -> Card {
+    Card::new(
+        "",
+        Mana::default(),
+        CardTypes::CREATURE,
+        CardDetails::Creature(CreatureCard {
+            power: 2,
+            toughness: 2,
+            creature_type: CreatureType::NONE,
+        }),
+        "",
+    )
+}
+
+/// The cost to turn a card with morph face up, if it has a mana morph cost
+pub fn morph_cost(card: &Card) -> Option<Mana> {
+    card.keywords
+        .keywords
+        .ability_values
+        .get(&KeywordAbility::Morph)
+        .filter(|cost| cost.starts_with('{'))
+        .map(|cost| parse_mana_cost(cost))
+}
+
+/// How a permanent came to be face down
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FaceDownKind {
+    /// Cast face down using morph
+    Morph,
+    /// Manifested from the top of a library
+    Manifest,
+}
+
+/// A face-down permanent, holding the card it really is
+///
+/// It isn't serializable on purpose: what's sent to a player about it is
+/// [`FaceDown::view_for`] that player.
+#[derive(Component, Debug, Clone)]
+pub struct FaceDown {
+    /// The card's real characteristics
+    pub face: Card,
+    pub kind: FaceDownKind,
+}
+
+impl FaceDown {
+    /// What it costs to turn face up, `None` if it can't be
+    ///
+    /// Morph costs work however the card got face down; a manifested creature
+    /// card can also be turned face up for its mana cost.
+    pub fn turn_up_cost(&self) -> Option<Mana> {
+        morph_cost(&self.face).or_else(|| {
+            (self.kind == FaceDownKind::Manifest
+                && self.face.type_info.types.contains(CardTypes::CREATURE))
+            .then_some(self.face.cost.cost)
+        })
+    }
+
+    /// The real card, if `viewer` may look at it
+    ///
+    /// Only the permanent's controller may look at a face-down permanent; every
+    /// other player only knows it's a 2/2.
+    pub fn face_for(&self, viewer: Entity, controller: Entity) -> Option<&Card> {
+        (viewer == controller).then_some(&self.face)
+    }
+
+    /// What `viewer` is sent about the permanent
+    pub fn view_for(&self, viewer: Entity, controller: Entity) -> FaceDownView {
+        FaceDownView {
+            kind: self.kind,
+            face: self.face_for(viewer, controller).cloned(),
+        }
+    }
+}
+
+/// A face-down permanent as one player knows it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FaceDownView {
+    pub kind: FaceDownKind,
+    /// The real card, only known to the permanent's controller
+    pub face: Option<Card>,
+}
+
+/// A spell cast face down, entering the battlefield face down as it resolves
+///
+/// Its name is hidden on the stack like the rest of its characteristics.
+#[derive(Debug)]
+pub struct FaceDownSpell {
+    pub card: Entity,
+    pub controller: Entity,
+}
+
+impl Effect for FaceDownSpell {
+    fn resolve(&self, commands: &mut Commands) {
+        commands.send_event(ZoneChangeEvent {
+            card: self.card,
+            owner: self.controller,
+            source: Zone::Stack,
+            destination: Zone::Battlefield,
+            was_visible: false,
+            is_visible: false,
+        });
+    }
+
+    fn details(&self) -> ResolutionDetails {
+        ResolutionDetails {
+            name: "Face-down spell".to_string(),
+            ..default()
+        }
+    }
+
+    fn controller(&self) -> Entity {
+        self.controller
+    }
+
+    fn targets(&self) -> Vec<Entity> {
+        Vec::new()
+    }
+}
+
+/// Sent to cast a card with morph face down from its owner's hand
+#[derive(Event, Debug, Clone, Copy)]
+pub struct CastFaceDownEvent {
+    pub player: Entity,
+    pub card: Entity,
+}
+
+/// Sent to manifest the top card of a player's library
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ManifestEvent {
+    pub player: Entity,
+}
+
+/// Sent to turn a face-down permanent face up
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TurnFaceUpEvent {
+    pub player: Entity,
+    pub permanent: Entity,
+}
+
+/// A face-down permanent was turned face up
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TurnedFaceUpEvent {
+    pub permanent: Entity,
+}
+
+/// Turn a card face down, keeping its real characteristics
+fn turn_face_down(commands: &mut Commands, entity: Entity, face: Card, kind: FaceDownKind) {
+    commands
+        .entity(entity)
+        .insert((face_down_card(), FaceDown { face, kind }));
+}
+
+/// Give a face-down card back its real characteristics
+fn turn_face_up(commands: &mut Commands, entity: Entity, face_down: &FaceDown) {
+    commands
+        .entity(entity)
+        .insert(face_down.face.clone())
+        .remove::<FaceDown>();
+}
+
+/// Cast cards face down for {3}
+///
+/// A face-down spell is a creature spell, so it's cast at sorcery speed. It goes
+/// on the stack as a [`FaceDownSpell`], where it can be responded to or
+/// countered like any other spell.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_cast_face_down(
+    mut commands: Commands,
+    mut events: EventReader<CastFaceDownEvent>,
+    mut zone_events: EventWriter<ZoneChangeEvent>,
+    zones: Option<Res<ZoneManager>>,
+    game_state: Res<GameState>,
+    phase: Res<Phase>,
+    mut stack: ResMut<GameStack>,
+    cards: Query<&Card, Without<FaceDown>>,
+    mut players: Query<&mut Player>,
+    enforcement: Option<Res<RulesEnforcementLevel>>,
+) {
+    let enforcement = enforcement.as_deref().copied().unwrap_or_default();
+    let Some(zones) = zones else {
+        events.clear();
+        return;
+    };
+
+    for event in events.read() {
+        let Ok(card) = cards.get(event.card) else {
+            continue;
+        };
+        let Ok(mut player) = players.get_mut(event.player) else {
+            continue;
+        };
+        let cost = face_down_cost();
+        let legal = Card::has_keyword(card, KeywordAbility::Morph)
+            && zones.get_card_zone(event.card) == Some(Zone::Hand)
+            && zones.get_card_owner(event.card) == Some(event.player)
+            && valid_time_for_sorcery(&game_state, &phase, &stack, event.player)
+            && cost.can_pay(&player.mana_pool);
+        if !enforcement.allows(legal, format_args!("casting {:?} face down", event.card)) {
+            continue;
+        }
+
+        player.mana_pool.remove(cost);
+        turn_face_down(&mut commands, event.card, card.clone(), FaceDownKind::Morph);
+        zone_events.write(ZoneChangeEvent {
+            card: event.card,
+            owner: event.player,
+            source: Zone::Hand,
+            destination: Zone::Stack,
+            was_visible: false,
+            is_visible: false,
+        });
+        stack.push(
+            Box::new(FaceDownSpell {
+                card: event.card,
+                controller: event.player,
+            }),
+            event.card,
+            false,
+            true,
+        );
+    }
+}
+
+/// Put the top card of a library onto the battlefield face down
+///
+/// Each card is moved in the [`ZoneManager`] straight away, so the next manifest
+/// takes the card under it.
+pub fn handle_manifest(
+    mut commands: Commands,
+    mut events: EventReader<ManifestEvent>,
+    mut zone_events: EventWriter<ZoneChangeEvent>,
+    zones: Option<ResMut<ZoneManager>>,
+    cards: Query<&Card, Without<FaceDown>>,
+) {
+    let Some(mut zones) = zones else {
+        events.clear();
+        return;
+    };
+
+    for event in events.read() {
+        let Some(top) = zones.top_of_library(event.player) else {
+            info!("{:?} has no card to manifest", event.player);
+            continue;
+        };
+        let Ok(card) = cards.get(top) else {
+            continue;
+        };
+
+        turn_face_down(&mut commands, top, card.clone(), FaceDownKind::Manifest);
+        // The ZoneManager has already moved the card; the event lets other
+        // systems react to it entering the battlefield
+        zones.move_card(top, event.player, Zone::Library, Zone::Battlefield);
+        zone_events.write(ZoneChangeEvent {
+            card: top,
+            owner: event.player,
+            source: Zone::Library,
+            destination: Zone::Battlefield,
+            was_visible: false,
+            is_visible: false,
+        });
+    }
+}
+
+/// Turn face-down permanents face up, paying their cost
+///
+/// Turning a permanent face up doesn't use the stack and can be done whenever
+/// its controller has priority.
+pub fn handle_turn_face_up(
+    mut commands: Commands,
+    mut events: EventReader<TurnFaceUpEvent>,
+    mut turned_up: EventWriter<TurnedFaceUpEvent>,
+    priority: Res<PrioritySystem>,
+    permanents: Query<(&FaceDown, &PermanentController)>,
+    mut players: Query<&mut Player>,
+    enforcement: Option<Res<RulesEnforcementLevel>>,
+) {
+    let enforcement = enforcement.as_deref().copied().unwrap_or_default();
+
+    for event in events.read() {
+        let Ok((face_down, controller)) = permanents.get(event.permanent) else {
+            continue;
+        };
+        let Ok(mut player) = players.get_mut(event.player) else {
+            continue;
+        };
+        let cost = face_down.turn_up_cost();
+        let legal = controller.player == event.player
+            && priority.has_priority(event.player)
+            && cost
+                .as_ref()
+                .is_some_and(|cost| cost.can_pay(&player.mana_pool));
+        if !enforcement.allows(legal, format_args!("turning {:?} face up", event.permanent)) {
+            continue;
+        }
+
+        if let Some(cost) = cost {
+            player.mana_pool.remove(cost);
+        }
+        turn_face_up(&mut commands, event.permanent, face_down);
+        turned_up.write(TurnedFaceUpEvent {
+            permanent: event.permanent,
+        });
+    }
+}
+
+/// Put countered face-down spells into their owner's graveyard
+pub fn handle_countered_face_down_spells(
+    mut countered: EventReader<EffectCounteredEvent>,
+    mut zone_events: EventWriter<ZoneChangeEvent>,
+    zones: Option<Res<ZoneManager>>,
+    face_down: Query<(), With<FaceDown>>,
+) {
+    let Some(zones) = zones else {
+        countered.clear();
+        return;
+    };
+
+    for event in countered.read() {
+        if !face_down.contains(event.item) || zones.get_card_zone(event.item) != Some(Zone::Stack) {
+            continue;
+        }
+        let Some(owner) = zones.get_card_owner(event.item) else {
+            continue;
+        };
+        zone_events.write(ZoneChangeEvent {
+            card: event.item,
+            owner,
+            source: Zone::Stack,
+            destination: Zone::Graveyard,
+            was_visible: false,
+            is_visible: true,
+        });
+    }
+}
+
+/// Reveal face-down permanents as they leave the battlefield, and face-down
+/// spells leaving the stack without resolving
+pub fn reveal_face_down_leaving_battlefield(
+    mut commands: Commands,
+    mut zone_events: EventReader<ZoneChangeEvent>,
+    face_down: Query<&FaceDown>,
+) {
+    for event in zone_events.read() {
+        let leaves_stack = event.source == Zone::Stack && event.destination != Zone::Battlefield;
+        if event.source != Zone::Battlefield && !leaves_stack {
+            continue;
+        }
+        if let Ok(face_down) = face_down.get(event.card) {
+            turn_face_up(&mut commands, event.card, face_down);
+        }
+    }
+}
+
+/// Register morph, manifest and the face-down card back
+pub fn register_face_down_systems(app: &mut App) {
+    app.add_event::<CastFaceDownEvent>()
+        .add_event::<ManifestEvent>()
+        .add_event::<TurnFaceUpEvent>()
+        .add_event::<TurnedFaceUpEvent>()
+        .add_systems(
+            Update,
+            (
+                handle_cast_face_down,
+                handle_manifest,
+                handle_turn_face_up,
+                handle_countered_face_down_spells,
+                reveal_face_down_leaving_battlefield,
+            )
+                .run_if(in_state(GameMenuState::InGame)),
+        )
+        .add_systems(Update, (show_card_backs, restore_card_faces));
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,279 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+
+use crate::cards::keywords::KeywordAbility;
+use crate::cards::{Card, CardDetails, CardTypes, CreatureCard, CreatureType};
+use crate::game_engine::face_down::{
+    CastFaceDownEvent, FaceDown, FaceDownKind, ManifestEvent, TurnFaceUpEvent, TurnedFaceUpEvent,
+    face_down_card, handle_cast_face_down, handle_countered_face_down_spells, handle_manifest,
+    handle_turn_face_up, morph_cost, reveal_face_down_leaving_battlefield,
+};
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::phase::PrecombatStep;
+use crate::game_engine::priority::CounterReason;
+use crate::game_engine::state::GameState;
+use crate::game_engine::zones::{Zone, ZoneChangeEvent, ZoneManager, handle_zone_changes};
+use crate::game_engine::{EffectCounteredEvent, GameStack, Phase, PrioritySystem};
+use crate::mana::Mana;
+use crate::player::Player;
+
+fn creature(name: &str, cost: Mana, rules_text: &str) -> Card {
+    Card::new(
+        name,
+        cost,
+        CardTypes::CREATURE,
+        CardDetails::Creature(CreatureCard {
+            power: 5,
+            toughness: 5,
+            creature_type: CreatureType::NONE,
+        }),
+        rules_text,
+    )
+}
+
+fn face_down_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<CastFaceDownEvent>()
+        .add_event::<ManifestEvent>()
+        .add_event::<TurnFaceUpEvent>()
+        .add_event::<TurnedFaceUpEvent>()
+        .add_event::<ZoneChangeEvent>()
+        .add_event::<EffectCounteredEvent>()
+        .init_resource::<ZoneManager>()
+        .init_resource::<GameStack>()
+        .insert_resource(Phase::Precombat(PrecombatStep::Main))
+        .add_systems(
+            Update,
+            (
+                handle_cast_face_down,
+                handle_manifest,
+                handle_turn_face_up,
+                handle_countered_face_down_spells,
+                reveal_face_down_leaving_battlefield,
+                handle_zone_changes,
+            )
+                .chain(),
+        );
+
+    let mut player = Player::new("Alice");
+    player
+        .mana_pool
+        .add(Mana::new_with_colors(4, 0, 0, 0, 0, 0));
+    player
+        .mana_pool
+        .add(Mana::new_with_colors(0, 0, 0, 0, 0, 2));
+    let player = app.world_mut().spawn(player).id();
+    app.insert_resource(GameState::builder().active_player(player).build());
+    let mut priority = PrioritySystem::default();
+    priority.initialize(&[player], player);
+    app.insert_resource(priority);
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .init_player_zones(player);
+    (app, player)
+}
+
+fn morph_in_hand(app: &mut App, player: Entity) -> Entity {
+    let archer = creature(
+        "Sagu Archer",
+        Mana::new_with_colors(4, 0, 0, 0, 0, 1),
+        "Reach\nMorph {4}{G}",
+    );
+    let card = app.world_mut().spawn(archer).id();
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .add_to_hand(player, card);
+    card
+}
+
+fn resolve_top(app: &mut App) {
+    app.world_mut()
+        .run_system_once(|mut commands: Commands, mut stack: ResMut<GameStack>| {
+            stack.resolve_top(&mut commands);
+        })
+        .unwrap();
+    app.update();
+}
+
+fn face_up(app: &mut App, player: Entity, permanent: Entity) {
+    // Face-down permanents get their controller when they enter the battlefield
+    app.world_mut()
+        .entity_mut(permanent)
+        .insert(PermanentController::new(player));
+    app.world_mut()
+        .send_event(TurnFaceUpEvent { player, permanent });
+    app.update();
+}
+
+#[test]
+fn test_turn_up_costs() {
+    let morph = creature(
+        "Exalted Angel",
+        Mana::new_with_colors(4, 2, 0, 0, 0, 0),
+        "Flying, lifelink\nMorph {2}{W}{W} (You may cast this card face down as a 2/2 creature for {3}.)",
+    );
+    assert!(Card::has_keyword(&morph, KeywordAbility::Morph));
+    let cost = morph_cost(&morph).unwrap();
+    assert_eq!((cost.colorless, cost.white, cost.total()), (2, 2, 4));
+
+    let vanilla = creature("Craw Wurm", Mana::new_with_colors(4, 0, 0, 0, 0, 2), "");
+    let manifested = FaceDown {
+        face: vanilla.clone(),
+        kind: FaceDownKind::Manifest,
+    };
+    assert_eq!(manifested.turn_up_cost(), Some(vanilla.cost.cost));
+    let morphed = FaceDown {
+        face: vanilla,
+        kind: FaceDownKind::Morph,
+    };
+    assert_eq!(morphed.turn_up_cost(), None);
+
+    // Only the controller may look at the face
+    let [controller, opponent] = [1, 2].map(Entity::from_raw);
+    assert!(morphed.face_for(controller, controller).is_some());
+    assert!(morphed.face_for(opponent, controller).is_none());
+
+    // What's sent to an opponent doesn't hold the card at all
+    let sent = serde_json::to_string(&morphed.view_for(opponent, controller)).unwrap();
+    assert!(!sent.contains("Craw Wurm"));
+    let sent = serde_json::to_string(&morphed.view_for(controller, controller)).unwrap();
+    assert!(sent.contains("Craw Wurm"));
+}
+
+#[test]
+fn test_morph_is_cast_face_down_and_turned_face_up() {
+    let (mut app, player) = face_down_app();
+    let card = morph_in_hand(&mut app, player);
+
+    app.world_mut()
+        .send_event(CastFaceDownEvent { player, card });
+    app.update();
+
+    // The face-down spell waits on the stack, without its name
+    let zones = app.world().resource::<ZoneManager>();
+    assert_eq!(zones.get_card_zone(card), Some(Zone::Stack));
+    let stack = app.world().resource::<GameStack>();
+    assert_eq!(stack.items.len(), 1);
+    assert_eq!(stack.items[0].entity, card);
+    assert!(!stack.items[0].spec.name().contains("Sagu Archer"));
+
+    resolve_top(&mut app);
+    let zones = app.world().resource::<ZoneManager>();
+    assert_eq!(zones.get_card_zone(card), Some(Zone::Battlefield));
+    let shown = app.world().get::<Card>(card).unwrap();
+    assert_eq!(shown.name.name, face_down_card().name.name);
+    assert_eq!(shown.details.details, face_down_card().details.details);
+    assert_eq!(
+        app.world().get::<FaceDown>(card).unwrap().face.name.name,
+        "Sagu Archer"
+    );
+
+    // {3} of the six mana was spent, so {4}{G} can't be paid yet
+    face_up(&mut app, player, card);
+    assert!(app.world().get::<FaceDown>(card).is_some());
+
+    app.world_mut()
+        .get_mut::<Player>(player)
+        .unwrap()
+        .mana_pool
+        .add(Mana::new_with_colors(2, 0, 0, 0, 0, 0));
+    face_up(&mut app, player, card);
+    assert!(app.world().get::<FaceDown>(card).is_none());
+    assert_eq!(
+        app.world().get::<Card>(card).unwrap().name.name,
+        "Sagu Archer"
+    );
+    let turned_up: Vec<TurnedFaceUpEvent> = app
+        .world_mut()
+        .resource_mut::<Events<TurnedFaceUpEvent>>()
+        .drain()
+        .collect();
+    assert_eq!(turned_up, vec![TurnedFaceUpEvent { permanent: card }]);
+}
+
+#[test]
+fn test_manifested_cards_are_revealed_when_they_leave_the_battlefield() {
+    let (mut app, player) = face_down_app();
+    let card = app
+        .world_mut()
+        .spawn(creature("Llanowar Elves", Mana::default(), ""))
+        .id();
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .add_to_library(player, card);
+
+    app.world_mut().send_event(ManifestEvent { player });
+    app.update();
+    assert_eq!(
+        app.world().get::<FaceDown>(card).unwrap().kind,
+        FaceDownKind::Manifest
+    );
+
+    app.world_mut().send_event(ZoneChangeEvent {
+        card,
+        owner: player,
+        source: Zone::Battlefield,
+        destination: Zone::Graveyard,
+        was_visible: false,
+        is_visible: true,
+    });
+    app.update();
+    assert!(app.world().get::<FaceDown>(card).is_none());
+    assert_eq!(
+        app.world().get::<Card>(card).unwrap().name.name,
+        "Llanowar Elves"
+    );
+}
+
+#[test]
+fn test_countered_face_down_spells_are_revealed_in_the_graveyard() {
+    let (mut app, player) = face_down_app();
+    let card = morph_in_hand(&mut app, player);
+    app.world_mut()
+        .send_event(CastFaceDownEvent { player, card });
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<GameStack>()
+        .remove_item(card);
+    app.world_mut().send_event(EffectCounteredEvent {
+        item: card,
+        reason: CounterReason::CounterSpell,
+    });
+    app.update();
+
+    let zones = app.world().resource::<ZoneManager>();
+    assert_eq!(zones.get_card_zone(card), Some(Zone::Graveyard));
+    assert!(app.world().get::<FaceDown>(card).is_none());
+    assert_eq!(
+        app.world().get::<Card>(card).unwrap().name.name,
+        "Sagu Archer"
+    );
+}
+
+#[test]
+fn test_manifests_in_one_frame_take_successive_cards() {
+    let (mut app, player) = face_down_app();
+    let [bottom, top] = ["Llanowar Elves", "Craw Wurm"].map(|name| {
+        app.world_mut()
+            .spawn(creature(name, Mana::default(), ""))
+            .id()
+    });
+    {
+        let mut zones = app.world_mut().resource_mut::<ZoneManager>();
+        zones.add_to_library(player, bottom);
+        zones.add_to_library(player, top);
+    }
+
+    app.world_mut().send_event(ManifestEvent { player });
+    app.world_mut().send_event(ManifestEvent { player });
+    app.update();
+
+    let zones = app.world().resource::<ZoneManager>();
+    for card in [bottom, top] {
+        assert_eq!(zones.get_card_zone(card), Some(Zone::Battlefield));
+        assert!(app.world().get::<FaceDown>(card).is_some());
+    }
+    assert!(zones.library(player).is_empty());
+}
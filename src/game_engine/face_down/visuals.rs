@@ -0,0 +1,61 @@
+//! The card back shown for face-down permanents
+
+use bevy::prelude::*;
+
+use super::FaceDown;
+use crate::cards::frame::CardFrameTexture;
+use crate::cards::image::CardImage;
+use crate::text::components::CardTextType;
+
+/// Color of a face-down card's back
+pub const CARD_BACK_COLOR: Color = Color::srgb(0.32, 0.2, 0.12);
+
+/// Show face-down cards as a plain card back with no text
+///
+/// Frame and image systems may put the card's face back on its sprite, so
+/// face-down cards are checked every frame.
+pub fn show_card_backs(
+    mut cards: Query<(&mut Sprite, Option<&Children>), With<FaceDown>>,
+    mut card_text: Query<&mut Visibility, With<CardTextType>>,
+) {
+    for (mut sprite, children) in cards.iter_mut() {
+        if sprite.color == CARD_BACK_COLOR && sprite.image == Handle::default() {
+            continue;
+        }
+        sprite.image = Handle::default();
+        sprite.color = CARD_BACK_COLOR;
+
+        for child in children.into_iter().flatten() {
+            if let Ok(mut visibility) = card_text.get_mut(*child) {
+                *visibility = Visibility::Hidden;
+            }
+        }
+    }
+}
+
+/// Show the face of cards turned face up again
+///
+/// Their frame is rendered again for their real name, and scans are applied
+/// again because the sprite no longer shows them.
+pub fn restore_card_faces(
+    mut commands: Commands,
+    mut turned_up: RemovedComponents<FaceDown>,
+    mut cards: Query<(&mut Sprite, Option<&Children>, Has<CardImage>)>,
+    mut card_text: Query<&mut Visibility, With<CardTextType>>,
+) {
+    for entity in turned_up.read() {
+        let Ok((mut sprite, children, has_image)) = cards.get_mut(entity) else {
+            continue;
+        };
+        sprite.color = Color::WHITE;
+        if !has_image {
+            commands.entity(entity).remove::<CardFrameTexture>();
+        }
+
+        for child in children.into_iter().flatten() {
+            if let Ok(mut visibility) = card_text.get_mut(*child) {
+                *visibility = Visibility::Inherited;
+            }
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use bevy::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::collections::HashMap;
+use std::fmt;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use crate::game_engine::combat::AssignCombatDamageEvent;
+use crate::game_engine::commander::PlayerEliminatedEvent;
+use crate::game_engine::phase::{Phase, StepBeganEvent, phase_transition_system};
+use crate::game_engine::priority::{
+    CounterReason, EffectCounteredEvent, NextPhaseEvent, PrioritySystem, ResolveStackItemEvent,
+};
+use crate::game_engine::stack::{
+    Effect, GameStack, StackItemResolvedEvent, stack_resolution_system,
+};
+use crate::game_engine::state::{
+    GameEvent, GameEventLog, GameOverEvent, GameState, apply_game_events,
+    state_based_actions_system,
+};
+use crate::game_engine::turns::TurnManager;
+use crate::game_engine::win_conditions::WinGameEvent;
+use crate::game_engine::zones::{
+    CardDrawnEvent, DrawCardEvent, ZoneChangeEvent, ZoneManager, process_draw_card_events,
+};
+use crate::player::Player;
+
+use super::FuzzConfig;
+use super::invariants::{life_errors, stack_errors, zone_errors};
+use super::ops::{FuzzOp, generate_ops};
+
+/// Stack item that does nothing when it resolves
+#[derive(Debug)]
+struct FuzzEffect {
+    controller: Entity,
+}
+
+impl Effect for FuzzEffect {
+    fn resolve(&self, _commands: &mut Commands) {}
+
+    fn controller(&self) -> Entity {
+        self.controller
+    }
+
+    fn targets(&self) -> Vec<Entity> {
+        Vec::new()
+    }
+}
+
+/// A seed whose operations broke an invariant or made a system panic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzFailure {
+    pub seed: u64,
+    /// Index of the operation that failed
+    pub step: usize,
+    pub op: FuzzOp,
+    /// The broken invariants, or the panic message
+    pub errors: Vec<String>,
+}
+
+impl fmt::Display for FuzzFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "seed {} failed at step {} ({:?}):",
+            self.seed, self.step, self.op
+        )?;
+        for error in &self.errors {
+            writeln!(f, "  {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+/// A game played by [`FuzzOp`]s in a headless app
+pub struct FuzzGame {
+    app: App,
+    players: Vec<Entity>,
+    /// Every card and its owner, including cards taken out of the zones
+    cards: Vec<(Entity, Entity)>,
+    /// Life totals implied by the life changes made so far
+    expected_life: HashMap<Entity, i32>,
+}
+
+impl FuzzGame {
+    /// A game with every player's cards in their library, in the first step of
+    /// the first turn
+    pub fn new(config: &FuzzConfig) -> Self {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_event::<DrawCardEvent>()
+            .add_event::<CardDrawnEvent>()
+            .add_event::<ZoneChangeEvent>()
+            .add_event::<GameEvent>()
+            .add_event::<GameOverEvent>()
+            .add_event::<WinGameEvent>()
+            .add_event::<PlayerEliminatedEvent>()
+            .add_event::<NextPhaseEvent>()
+            .add_event::<ResolveStackItemEvent>()
+            .add_event::<StackItemResolvedEvent>()
+            .add_event::<EffectCounteredEvent>()
+            .add_event::<AssignCombatDamageEvent>()
+            .add_event::<StepBeganEvent>()
+            .init_resource::<ZoneManager>()
+            .init_resource::<GameState>()
+            .init_resource::<GameEventLog>()
+            .init_resource::<GameStack>()
+            .init_resource::<PrioritySystem>()
+            .init_resource::<TurnManager>()
+            .init_resource::<Phase>()
+            .add_systems(
+                Update,
+                (
+                    phase_transition_system,
+                    process_draw_card_events,
+                    stack_resolution_system,
+                    state_based_actions_system,
+                    apply_game_events,
+                )
+                    .chain(),
+            );
+
+        let world = app.world_mut();
+        let players: Vec<Entity> = (0..config.players)
+            .map(|index| {
+                let mut player = Player::new(&format!("Player {}", index + 1));
+                player.player_index = index;
+                world.spawn(player).id()
+            })
+            .collect();
+
+        let mut zones = ZoneManager::default();
+        let mut cards = Vec::new();
+        for (index, player) in players.iter().enumerate() {
+            zones.init_player_zones(*player);
+            for number in 0..config.cards_per_player {
+                let card = world
+                    .spawn(Name::new(format!("Fuzz card {}-{}", index + 1, number)))
+                    .id();
+                zones.add_to_library(*player, card);
+                cards.push((card, *player));
+            }
+        }
+        world.insert_resource(zones);
+
+        if let Some(&first) = players.first() {
+            world
+                .resource_mut::<GameState>()
+                .set_turn_order(players.clone());
+            world.resource_mut::<GameState>().active_player = first;
+            world
+                .resource_mut::<TurnManager>()
+                .initialize(players.clone());
+            world
+                .resource_mut::<PrioritySystem>()
+                .initialize(&players, first);
+        }
+
+        let expected_life = players
+            .iter()
+            .map(|player| (*player, world.get::<Player>(*player).unwrap().life))
+            .collect();
+
+        Self {
+            app,
+            players,
+            cards,
+            expected_life,
+        }
+    }
+
+    /// Play an operation, returning the broken invariants if there are any
+    pub fn apply(&mut self, op: FuzzOp) -> Result<(), Vec<String>> {
+        let world = self.app.world_mut();
+        match op {
+            FuzzOp::MoveCard {
+                card,
+                destination,
+                position,
+            } => {
+                if let Some(&(card, owner)) = self.cards.get(card % self.cards.len().max(1)) {
+                    world
+                        .resource_mut::<ZoneManager>()
+                        .insert(card, owner, destination, position);
+                }
+            }
+            FuzzOp::RemoveCard { card } => {
+                if let Some(&(card, _)) = self.cards.get(card % self.cards.len().max(1)) {
+                    world.resource_mut::<ZoneManager>().remove(card);
+                }
+            }
+            FuzzOp::DrawCards { player, count } => {
+                if let Some(&player) = self.players.get(player % self.players.len().max(1)) {
+                    world.send_event(DrawCardEvent { player, count });
+                }
+            }
+            FuzzOp::PushStackItem {
+                controller,
+                split_second,
+                counterable,
+            } => {
+                let controller = self.players.get(controller % self.players.len().max(1));
+                // Nothing can be cast while a split second item is on the stack
+                if let (Some(&controller), false) = (
+                    controller,
+                    world.resource::<GameStack>().contains_split_second,
+                ) {
+                    let item = world.spawn_empty().id();
+                    world.resource_mut::<GameStack>().push(
+                        Box::new(FuzzEffect { controller }),
+                        item,
+                        split_second,
+                        counterable,
+                    );
+                }
+            }
+            FuzzOp::ResolveTop => {
+                let top = world
+                    .resource::<GameStack>()
+                    .items
+                    .last()
+                    .map(|item| item.entity);
+                if let Some(item) = top {
+                    world.send_event(ResolveStackItemEvent { item });
+                }
+            }
+            FuzzOp::Counter { item } => {
+                let mut stack = world.resource_mut::<GameStack>();
+                let target = match stack.items.len() {
+                    0 => None,
+                    len => Some(stack.items[item % len].entity),
+                };
+                let countered = target
+                    .filter(|item| stack.can_be_countered(*item))
+                    .and_then(|item| stack.remove_item(item))
+                    .map(|item| item.entity);
+                if let Some(item) = countered {
+                    world.send_event(EffectCounteredEvent {
+                        item,
+                        reason: CounterReason::CounterSpell,
+                    });
+                }
+            }
+            FuzzOp::ChangeLife { player, amount } => {
+                if let Some(&player) = self.players.get(player % self.players.len().max(1)) {
+                    if let Some(mut state) = world.get_mut::<Player>(player) {
+                        state.life += amount;
+                    }
+                    *self.expected_life.entry(player).or_default() += amount;
+                }
+            }
+            FuzzOp::NextPhase => {
+                world.send_event(NextPhaseEvent);
+            }
+        }
+
+        if let Err(panic) = catch_unwind(AssertUnwindSafe(|| self.app.update())) {
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            return Err(vec![format!("a system panicked: {}", message)]);
+        }
+
+        let errors = self.invariant_errors();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Every invariant the game currently breaks
+    pub fn invariant_errors(&mut self) -> Vec<String> {
+        let world = self.app.world_mut();
+        let mut errors = zone_errors(world.resource::<ZoneManager>());
+        errors.extend(stack_errors(world.resource::<GameStack>()));
+        let mut players = world.query::<(Entity, &Player)>();
+        errors.extend(life_errors(players.iter(world), &self.expected_life));
+        errors
+    }
+
+    /// The app the game is played in
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+}
+
+/// Play the operations generated from `seed`, stopping at the first failure
+pub fn run_fuzz(seed: u64, config: &FuzzConfig) -> Result<(), FuzzFailure> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let ops = generate_ops(&mut rng, config);
+    let mut game = FuzzGame::new(config);
+
+    for (step, op) in ops.into_iter().enumerate() {
+        game.apply(op).map_err(|errors| FuzzFailure {
+            seed,
+            step,
+            op,
+            errors,
+        })?;
+    }
+    Ok(())
+}
@@ -0,0 +1,80 @@
+//! Properties that hold after every operation, whatever the operations were
+
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+use crate::game_engine::stack::GameStack;
+use crate::game_engine::zones::ZoneManager;
+use crate::player::Player;
+
+/// Cards in two zones, or zone lists the card index disagrees with
+pub fn zone_errors(zones: &ZoneManager) -> Vec<String> {
+    zones.consistency_errors()
+}
+
+/// Ways the stack's bookkeeping disagrees with its items
+///
+/// The split second flag must be set exactly when an item with split second is
+/// on the stack, and only items on the stack can be tracked as uncounterable.
+pub fn stack_errors(stack: &GameStack) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    let has_split_second = stack.items.iter().any(|item| item.has_split_second);
+    if stack.contains_split_second != has_split_second {
+        errors.push(format!(
+            "split second flag is {} with {} split second items on the stack",
+            stack.contains_split_second,
+            stack
+                .items
+                .iter()
+                .filter(|item| item.has_split_second)
+                .count()
+        ));
+    }
+
+    let uncounterable: HashSet<Entity> = stack
+        .items
+        .iter()
+        .filter(|item| !item.can_be_countered)
+        .map(|item| item.entity)
+        .collect();
+    if stack.uncounterable_items != uncounterable {
+        errors.push(format!(
+            "uncounterable items are {:?} but the stack has {:?}",
+            stack.uncounterable_items, uncounterable
+        ));
+    }
+
+    let mut seen = HashSet::new();
+    for item in &stack.items {
+        if !seen.insert(item.entity) {
+            errors.push(format!("{:?} is on the stack twice", item.entity));
+        }
+    }
+    if stack.resolving || stack.currently_resolving.is_some() {
+        errors.push("the stack is still marked as resolving".to_string());
+    }
+    errors
+}
+
+/// Life totals that aren't the starting total plus every change made to it
+///
+/// Life is an integer, so it can't become NaN; a change lost or applied twice
+/// is what would go wrong instead.
+pub fn life_errors<'a>(
+    players: impl IntoIterator<Item = (Entity, &'a Player)>,
+    expected: &HashMap<Entity, i32>,
+) -> Vec<String> {
+    players
+        .into_iter()
+        .filter_map(|(entity, player)| {
+            let expected = expected.get(&entity)?;
+            (player.life != *expected).then(|| {
+                format!(
+                    "{} has {} life, expected {}",
+                    player.name, player.life, expected
+                )
+            })
+        })
+        .collect()
+}
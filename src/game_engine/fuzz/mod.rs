@@ -0,0 +1,39 @@
+//! Property-based fuzzing of zone transfers and stack operations
+//!
+//! [`generate_ops`] builds a random sequence of [`FuzzOp`]s from a seed: cards
+//! moved between zones, draws, stack items pushed, resolved and countered, life
+//! changes and phase transitions. [`FuzzGame`] plays them in a headless app
+//! running the zone, stack, phase and state-based action systems, and checks
+//! the [`invariants`] after each one. A failure names the seed and step, so it
+//! can be replayed with [`run_fuzz`].
+
+pub mod harness;
+pub mod invariants;
+pub mod ops;
+
+#[cfg(test)]
+mod tests;
+
+pub use harness::{FuzzFailure, FuzzGame, run_fuzz};
+pub use invariants::{life_errors, stack_errors, zone_errors};
+pub use ops::{FuzzOp, generate_ops};
+
+/// Size of a fuzzed game
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FuzzConfig {
+    pub players: usize,
+    /// Cards each player starts with, all in their library
+    pub cards_per_player: usize,
+    /// Operations played per seed
+    pub steps: usize,
+}
+
+impl Default for FuzzConfig {
+    fn default() -> Self {
+        Self {
+            players: 3,
+            cards_per_player: 12,
+            steps: 200,
+        }
+    }
+}
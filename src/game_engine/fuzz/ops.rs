@@ -0,0 +1,96 @@
+use rand::Rng;
+
+use crate::game_engine::zones::{Zone, ZonePosition};
+
+use super::FuzzConfig;
+
+/// Zones cards are moved to; the stack is left to [`FuzzOp::PushStackItem`]
+const DESTINATIONS: [Zone; 6] = [
+    Zone::Library,
+    Zone::Hand,
+    Zone::Battlefield,
+    Zone::Graveyard,
+    Zone::Exile,
+    Zone::Command,
+];
+
+/// One operation of a fuzzed game
+///
+/// Players, cards and stack items are picked by index, wrapping around, so any
+/// generated sequence can be played against any game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuzzOp {
+    /// Move a card to a zone, keeping its owner
+    MoveCard {
+        card: usize,
+        destination: Zone,
+        position: ZonePosition,
+    },
+    /// Take a card out of the zones altogether
+    RemoveCard { card: usize },
+    /// Draw cards through a [`DrawCardEvent`](crate::game_engine::zones::DrawCardEvent)
+    DrawCards { player: usize, count: u32 },
+    /// Put an item on the stack, unless a split second item is already there
+    PushStackItem {
+        controller: usize,
+        split_second: bool,
+        counterable: bool,
+    },
+    /// Resolve the top of the stack
+    ResolveTop,
+    /// Counter an item on the stack, if it can be countered
+    Counter { item: usize },
+    /// Gain or lose life
+    ChangeLife { player: usize, amount: i32 },
+    /// Move to the next step of the turn
+    NextPhase,
+}
+
+impl FuzzOp {
+    /// A random operation
+    pub fn random(rng: &mut impl Rng, config: &FuzzConfig) -> Self {
+        let players = config.players.max(1);
+        let cards = (config.players * config.cards_per_player).max(1);
+
+        match rng.random_range(0..8) {
+            0 | 1 => FuzzOp::MoveCard {
+                card: rng.random_range(0..cards),
+                destination: DESTINATIONS[rng.random_range(0..DESTINATIONS.len())],
+                position: match rng.random_range(0..3) {
+                    0 => ZonePosition::Top,
+                    1 => ZonePosition::Bottom,
+                    // Past the end of the zone on purpose, to exercise the clamping
+                    _ => ZonePosition::Index(rng.random_range(0..cards + 2)),
+                },
+            },
+            2 => FuzzOp::RemoveCard {
+                card: rng.random_range(0..cards),
+            },
+            3 => FuzzOp::DrawCards {
+                player: rng.random_range(0..players),
+                count: rng.random_range(1..4),
+            },
+            4 => FuzzOp::PushStackItem {
+                controller: rng.random_range(0..players),
+                split_second: rng.random_bool(0.2),
+                counterable: rng.random_bool(0.8),
+            },
+            5 => FuzzOp::ResolveTop,
+            6 => FuzzOp::Counter {
+                item: rng.random_range(0..8),
+            },
+            _ if rng.random_bool(0.5) => FuzzOp::ChangeLife {
+                player: rng.random_range(0..players),
+                amount: rng.random_range(-7..=5),
+            },
+            _ => FuzzOp::NextPhase,
+        }
+    }
+}
+
+/// A random sequence of [`FuzzConfig::steps`] operations
+pub fn generate_ops(rng: &mut impl Rng, config: &FuzzConfig) -> Vec<FuzzOp> {
+    (0..config.steps)
+        .map(|_| FuzzOp::random(rng, config))
+        .collect()
+}
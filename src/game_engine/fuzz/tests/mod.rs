@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::game_engine::fuzz::{
+    FuzzConfig, FuzzGame, FuzzOp, generate_ops, run_fuzz, stack_errors, zone_errors,
+};
+use crate::game_engine::stack::{Effect, GameStack};
+use crate::game_engine::zones::{Zone, ZoneManager, ZonePosition};
+
+/// Seeds run on every test run; failures print the seed to replay
+const SEEDS: u64 = 64;
+
+#[derive(Debug)]
+struct TestEffect(Entity);
+
+impl Effect for TestEffect {
+    fn resolve(&self, _commands: &mut Commands) {}
+
+    fn controller(&self) -> Entity {
+        self.0
+    }
+
+    fn targets(&self) -> Vec<Entity> {
+        Vec::new()
+    }
+}
+
+#[test]
+fn test_random_operations_keep_invariants() {
+    let config = FuzzConfig::default();
+    let failures: Vec<String> = (0..SEEDS)
+        .filter_map(|seed| run_fuzz(seed, &config).err())
+        .map(|failure| failure.to_string())
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn test_small_games_keep_invariants() {
+    // Few cards and players make empty libraries and wrapped indices common
+    let config = FuzzConfig {
+        players: 1,
+        cards_per_player: 2,
+        steps: 100,
+    };
+    for seed in 0..SEEDS {
+        if let Err(failure) = run_fuzz(seed, &config) {
+            panic!("{}", failure);
+        }
+    }
+}
+
+#[test]
+fn test_ops_are_reproducible() {
+    let config = FuzzConfig::default();
+    let first = generate_ops(&mut StdRng::seed_from_u64(7), &config);
+    let second = generate_ops(&mut StdRng::seed_from_u64(7), &config);
+    assert_eq!(first.len(), config.steps);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_countering_keeps_stack_bookkeeping() {
+    let mut game = FuzzGame::new(&FuzzConfig::default());
+    for op in [
+        FuzzOp::PushStackItem {
+            controller: 0,
+            split_second: false,
+            counterable: false,
+        },
+        FuzzOp::PushStackItem {
+            controller: 1,
+            split_second: true,
+            counterable: true,
+        },
+        FuzzOp::Counter { item: 1 },
+        FuzzOp::ResolveTop,
+    ] {
+        game.apply(op).unwrap();
+    }
+
+    let stack = game.app_mut().world().resource::<GameStack>();
+    assert!(stack.is_empty());
+    assert!(!stack.contains_split_second);
+    assert!(stack.uncounterable_items.is_empty());
+}
+
+#[test]
+fn test_removed_stack_items_are_no_longer_uncounterable() {
+    let mut world = World::new();
+    let controller = world.spawn_empty().id();
+    let item = world.spawn_empty().id();
+    let mut stack = GameStack::default();
+    stack.push(Box::new(TestEffect(controller)), item, true, false);
+
+    assert!(stack_errors(&stack).is_empty());
+    assert!(stack.remove_item(item).is_some());
+    assert!(
+        stack_errors(&stack).is_empty(),
+        "{:?}",
+        stack_errors(&stack)
+    );
+    assert!(stack.can_be_countered(item));
+}
+
+#[test]
+fn test_stack_errors_catch_stale_flags() {
+    let mut stack = GameStack::default();
+    stack.contains_split_second = true;
+    assert_eq!(stack_errors(&stack).len(), 1);
+}
+
+#[test]
+fn test_zone_errors() {
+    let mut world = World::new();
+    let player = world.spawn_empty().id();
+    let cards: Vec<Entity> = (0..4).map(|_| world.spawn_empty().id()).collect();
+    let mut zones = ZoneManager::default();
+    zones.init_player_zones(player);
+    for card in &cards {
+        zones.add_to_library(player, *card);
+    }
+
+    zones.insert(cards[0], player, Zone::Exile, ZonePosition::Index(9));
+    zones.move_to(cards[3], Zone::Library, ZonePosition::Bottom);
+    zones.remove(cards[1]);
+    assert!(zone_errors(&zones).is_empty(), "{:?}", zone_errors(&zones));
+    assert_eq!(zones.library(player), &[cards[3], cards[2]]);
+}
@@ -0,0 +1,27 @@
+//! Golden-log rules regression tests
+//!
+//! A [`GameScript`] is a TOML file holding a starting board (see
+//! [`BoardDescription`](crate::game_engine::save::board::BoardDescription)),
+//! the cards in each player's hand, a sequence of [`ScriptStep`]s and what the
+//! game should look like afterwards: the [`GameEvent`](super::GameEvent) log,
+//! with entities written by name, and parts of the final state.
+//!
+//! [`run_script`] plays a script in a headless app running the rules systems and
+//! returns a [`GoldenReport`] showing where the engine's output deviates from
+//! the expected one. The scripts in [`GOLDEN_SCRIPT_DIR`] run as a test, so
+//! refactors of the stack, combat and state-based action systems that change
+//! their output show up as a diff.
+
+pub mod report;
+pub mod runner;
+pub mod script;
+
+#[cfg(test)]
+mod tests;
+
+pub use report::{DiffLine, GoldenReport, diff_lines};
+pub use runner::{ScriptRun, run_script, run_script_dir};
+pub use script::{ExpectedPlayer, ExpectedState, GameScript, GameScriptError, ScriptStep};
+
+/// Where the golden scripts are kept
+pub const GOLDEN_SCRIPT_DIR: &str = "test_assets/golden";
@@ -0,0 +1,104 @@
+use std::fmt;
+
+/// A line of a diff between the expected and actual event logs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    /// In both logs
+    Same(String),
+    /// Expected, but not produced by the engine
+    Missing(String),
+    /// Produced by the engine, but not expected
+    Unexpected(String),
+}
+
+impl fmt::Display for DiffLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiffLine::Same(line) => write!(f, "  {}", line),
+            DiffLine::Missing(line) => write!(f, "- {}", line),
+            DiffLine::Unexpected(line) => write!(f, "+ {}", line),
+        }
+    }
+}
+
+/// Line diff of `expected` against `actual`, keeping their longest common
+/// subsequence as unchanged lines
+pub fn diff_lines(expected: &[String], actual: &[String]) -> Vec<DiffLine> {
+    // common[i][j] is the length of the longest common subsequence of
+    // expected[i..] and actual[j..]
+    let mut common = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            common[i][j] = if expected[i] == actual[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut diff = Vec::with_capacity(expected.len().max(actual.len()));
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            diff.push(DiffLine::Same(expected[i].clone()));
+            i += 1;
+            j += 1;
+        } else if j < actual.len() && (i == expected.len() || common[i][j + 1] >= common[i + 1][j])
+        {
+            diff.push(DiffLine::Unexpected(actual[j].clone()));
+            j += 1;
+        } else {
+            diff.push(DiffLine::Missing(expected[i].clone()));
+            i += 1;
+        }
+    }
+    diff
+}
+
+/// How a script's run compared to its expected outcome
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoldenReport {
+    pub name: String,
+    /// The event log the engine produced
+    pub log: Vec<String>,
+    /// Diff of the expected log against [`log`](Self::log)
+    pub log_diff: Vec<DiffLine>,
+    /// Each part of the final state that isn't as expected
+    pub state_mismatches: Vec<String>,
+}
+
+impl GoldenReport {
+    /// Whether the engine's output matched the script
+    pub fn passed(&self) -> bool {
+        self.state_mismatches.is_empty()
+            && self
+                .log_diff
+                .iter()
+                .all(|line| matches!(line, DiffLine::Same(_)))
+    }
+}
+
+impl fmt::Display for GoldenReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.passed() {
+            return write!(f, "{}: ok", self.name);
+        }
+
+        writeln!(f, "{}: output deviates from the script", self.name)?;
+        if self
+            .log_diff
+            .iter()
+            .any(|line| !matches!(line, DiffLine::Same(_)))
+        {
+            writeln!(f, "event log (- expected, + actual):")?;
+            for line in &self.log_diff {
+                writeln!(f, "{}", line)?;
+            }
+        }
+        for mismatch in &self.state_mismatches {
+            writeln!(f, "state: {}", mismatch)?;
+        }
+        Ok(())
+    }
+}
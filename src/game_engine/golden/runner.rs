@@ -0,0 +1,467 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::cards::details::{CardDetails, LandCard};
+use crate::cards::sets::find_card;
+use crate::cards::{Card, CardTypes};
+use crate::game_engine::actions::{GameAction, process_game_actions};
+use crate::game_engine::commander::{EliminationReason, PlayerEliminatedEvent};
+use crate::game_engine::enforcement::RulesEnforcementLevel;
+use crate::game_engine::phase::Phase;
+use crate::game_engine::save::board::{import_board, placeholder_card};
+use crate::game_engine::state::{
+    GameEvent, GameEventLog, GameOverEvent, GameState, apply_game_events,
+    state_based_actions_system,
+};
+use crate::game_engine::win_conditions::WinGameEvent;
+use crate::game_engine::zones::{ZoneChangeEvent, ZoneManager};
+use crate::game_engine::{GameStack, PrioritySystem};
+use crate::mana::Mana;
+use crate::player::Player;
+
+use super::report::{GoldenReport, diff_lines};
+use super::script::{GameScript, GameScriptError, ScriptStep};
+
+/// Basic land types, by the name of the basic land with that type
+const BASIC_LANDS: [(&str, CardTypes, &str); 5] = [
+    ("Plains", CardTypes::PLAINS, "W"),
+    ("Island", CardTypes::ISLAND, "U"),
+    ("Swamp", CardTypes::SWAMP, "B"),
+    ("Mountain", CardTypes::MOUNTAIN, "R"),
+    ("Forest", CardTypes::FOREST, "G"),
+];
+
+/// Card for a name used in a script
+///
+/// Basic lands are built here, since they aren't part of any set; other unknown
+/// cards become placeholders.
+fn script_card(name: &str) -> Card {
+    if let Some(card) = find_card(name) {
+        return card;
+    }
+    match BASIC_LANDS.iter().find(|(land, _, _)| *land == name) {
+        Some((_, land_type, color)) => Card::new(
+            name,
+            Mana::default(),
+            CardTypes::BASIC | CardTypes::LAND | *land_type,
+            CardDetails::Land(LandCard {
+                land_type: Some(name.to_string()),
+                produces: vec![color.to_string()],
+            }),
+            "",
+        ),
+        None => placeholder_card(name),
+    }
+}
+
+/// App running the rules systems without rendering, input or turn structure
+fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<GameAction>()
+        .add_event::<GameEvent>()
+        .add_event::<GameOverEvent>()
+        .add_event::<WinGameEvent>()
+        .add_event::<PlayerEliminatedEvent>()
+        .add_event::<ZoneChangeEvent>()
+        .init_resource::<GameEventLog>()
+        .init_resource::<GameStack>()
+        .init_resource::<PrioritySystem>()
+        .insert_resource(RulesEnforcementLevel::Full)
+        .add_systems(
+            Update,
+            (
+                process_game_actions,
+                state_based_actions_system,
+                apply_game_events,
+            )
+                .chain(),
+        );
+    app
+}
+
+/// Line for an event in a golden log, naming the entities it refers to
+pub fn describe_event(event: &GameEvent, names: &HashMap<Entity, String>) -> String {
+    let name = |entity: &Entity| {
+        names
+            .get(entity)
+            .cloned()
+            .unwrap_or_else(|| format!("{:?}", entity))
+    };
+
+    match event {
+        GameEvent::TurnOrderSet { players } => format!(
+            "TurnOrderSet {}",
+            players.iter().map(name).collect::<Vec<_>>().join(", ")
+        ),
+        GameEvent::FirstPlayerRolled { rolls, winner } => format!(
+            "FirstPlayerRolled {} won by {}",
+            rolls
+                .iter()
+                .map(|(player, result)| format!("{} {}", name(player), result))
+                .collect::<Vec<_>>()
+                .join(", "),
+            name(winner)
+        ),
+        GameEvent::TurnStarted {
+            player,
+            turn_number,
+        } => format!("TurnStarted {} turn {}", name(player), turn_number),
+        GameEvent::MainPhaseStarted => "MainPhaseStarted".to_string(),
+        GameEvent::LandPlayed { player } => format!("LandPlayed {}", name(player)),
+        GameEvent::CardDrawn { player } => format!("CardDrawn {}", name(player)),
+        GameEvent::PlayerEliminated { player, reason } => {
+            let reason = match reason {
+                EliminationReason::LifeLoss => "LifeLoss".to_string(),
+                EliminationReason::EmptyLibrary => "EmptyLibrary".to_string(),
+                EliminationReason::CommanderDamage(commander) => {
+                    format!("CommanderDamage from {}", name(commander))
+                }
+                EliminationReason::Concede => "Concede".to_string(),
+                EliminationReason::Poison => "Poison".to_string(),
+                EliminationReason::CardEffect(card) => format!("CardEffect of {}", name(card)),
+            };
+            format!("PlayerEliminated {} ({})", name(player), reason)
+        }
+        GameEvent::PlayerWon { player, source } => {
+            format!("PlayerWon {} through {}", name(player), name(source))
+        }
+        GameEvent::StateBasedActionsChecked { performed } => {
+            format!("StateBasedActionsChecked performed={}", performed)
+        }
+    }
+}
+
+/// A script being played in a headless app
+pub struct ScriptRun {
+    app: App,
+    /// Player entities by name
+    players: HashMap<String, Entity>,
+    /// Names of the players and cards, for describing events
+    names: HashMap<Entity, String>,
+}
+
+impl ScriptRun {
+    /// Set up the script's board, with its hands, ready for the first step
+    pub fn new(script: &GameScript) -> Self {
+        let mut app = headless_app();
+        let mut board = script.board.clone();
+        for player in &mut board.players {
+            if script.hands.contains_key(&player.name) {
+                player.hand_size = 0;
+            }
+        }
+
+        let world = app.world_mut();
+        let imported = import_board(world, &board, script_card);
+        let players: HashMap<String, Entity> = board
+            .players
+            .iter()
+            .map(|player| player.name.clone())
+            .zip(imported.players.iter().copied())
+            .collect();
+
+        for (name, hand) in &script.hands {
+            let cards: Vec<Entity> = hand
+                .iter()
+                .map(|card| world.spawn(script_card(card)).id())
+                .collect();
+            let mut zones = world.resource_mut::<ZoneManager>();
+            for card in cards {
+                zones.add_to_hand(players[name], card);
+            }
+        }
+
+        // The action systems read the card's parts as separate components
+        let cards: Vec<(Entity, Card)> = world
+            .query::<(Entity, &Card)>()
+            .iter(world)
+            .map(|(entity, card)| (entity, card.clone()))
+            .collect();
+        let mut names: HashMap<Entity, String> = players
+            .iter()
+            .map(|(name, player)| (*player, name.clone()))
+            .collect();
+        for (entity, card) in cards {
+            names.insert(entity, card.name.name.clone());
+            world.entity_mut(entity).insert(card.get_components());
+        }
+
+        let active_player = world.resource::<GameState>().active_player;
+        world
+            .resource_mut::<PrioritySystem>()
+            .initialize(&imported.players, active_player);
+
+        // Checks the starting board, and starts the log from the imported state
+        app.update();
+
+        Self {
+            app,
+            players,
+            names,
+        }
+    }
+
+    fn player(&self, name: &str) -> Result<Entity, GameScriptError> {
+        self.players
+            .get(name)
+            .copied()
+            .ok_or_else(|| GameScriptError::UnknownPlayer(name.to_string()))
+    }
+
+    /// A card with this name that `player` can use, looking in their hand first
+    fn card(&self, player: Entity, name: &str) -> Result<Entity, GameScriptError> {
+        let world = self.app.world();
+        let zones = world.resource::<ZoneManager>();
+        let owned_permanents = zones
+            .battlefield()
+            .iter()
+            .chain(zones.command_zone())
+            .filter(|card| zones.get_card_owner(**card) == Some(player));
+
+        zones
+            .hand(player)
+            .iter()
+            .chain(owned_permanents)
+            .chain(zones.graveyard(player))
+            .copied()
+            .find(|card| {
+                world
+                    .get::<Card>(*card)
+                    .is_some_and(|card| card.name.name == name)
+            })
+            .ok_or_else(|| GameScriptError::UnknownCard(name.to_string()))
+    }
+
+    fn targets(&self, player: Entity, names: &[String]) -> Result<Vec<Entity>, GameScriptError> {
+        names
+            .iter()
+            .map(|name| {
+                self.player(name)
+                    .or_else(|_| self.card(player, name))
+                    .map_err(|_| GameScriptError::UnknownCard(name.clone()))
+            })
+            .collect()
+    }
+
+    /// Take a step and let the rules systems react to it
+    pub fn step(&mut self, step: &ScriptStep) -> Result<(), GameScriptError> {
+        let mut events = Vec::new();
+        match step {
+            ScriptStep::PlayLand { player, card } => {
+                let player = self.player(player)?;
+                let land_card = self.card(player, card)?;
+                self.send_action(GameAction::PlayLand { player, land_card });
+            }
+            ScriptStep::CastSpell {
+                player,
+                card,
+                targets,
+            } => {
+                let player = self.player(player)?;
+                let spell_card = self.card(player, card)?;
+                let targets = self.targets(player, targets)?;
+                self.send_action(GameAction::CastSpell {
+                    player,
+                    spell_card,
+                    targets,
+                    mana_payment: Mana::default(),
+                });
+            }
+            ScriptStep::ActivateAbility {
+                player,
+                source,
+                ability_index,
+                targets,
+            } => {
+                let player = self.player(player)?;
+                let source = self.card(player, source)?;
+                let targets = self.targets(player, targets)?;
+                self.send_action(GameAction::ActivateAbility {
+                    player,
+                    source,
+                    ability_index: *ability_index,
+                    targets,
+                    mana_payment: Mana::default(),
+                });
+            }
+            ScriptStep::PassPriority { player } => {
+                let player = self.player(player)?;
+                self.send_action(GameAction::PassPriority { player });
+            }
+            ScriptStep::SetPhase { phase } => {
+                let phase = Phase::from_label(phase)
+                    .ok_or_else(|| GameScriptError::UnknownPhase(phase.clone()))?;
+                self.app.world_mut().insert_resource(phase);
+                if phase.allows_sorcery_speed() {
+                    events.push(GameEvent::MainPhaseStarted);
+                }
+            }
+            ScriptStep::NextTurn => {
+                let game_state = self.app.world().resource::<GameState>();
+                let order = &game_state.turn_order;
+                let start = order
+                    .iter()
+                    .position(|player| *player == game_state.active_player)
+                    .unwrap_or(0);
+                let next = (1..=order.len())
+                    .map(|offset| order[(start + offset) % order.len()])
+                    .find(|player| !game_state.eliminated_players.contains(player));
+                if let Some(player) = next {
+                    events.push(GameEvent::TurnStarted {
+                        player,
+                        turn_number: game_state.turn_number + 1,
+                    });
+                }
+            }
+        }
+
+        for event in events {
+            self.app.world_mut().send_event(event);
+        }
+        self.app.update();
+        Ok(())
+    }
+
+    fn send_action(&mut self, action: GameAction) {
+        self.app.world_mut().send_event(action);
+    }
+
+    /// The events logged since the script started, one line each
+    pub fn log(&self) -> Vec<String> {
+        self.app
+            .world()
+            .resource::<GameEventLog>()
+            .events()
+            .iter()
+            .map(|logged| describe_event(&logged.event, &self.names))
+            .collect()
+    }
+
+    /// Each part of the final state that doesn't match the script's expectations
+    pub fn state_mismatches(&self, script: &GameScript) -> Vec<String> {
+        let world = self.app.world();
+        let game_state = world.resource::<GameState>();
+        let zones = world.resource::<ZoneManager>();
+        let mut mismatches = Vec::new();
+        let mut check = |what: String, expected: String, actual: String| {
+            if expected != actual {
+                mismatches.push(format!("{}: expected {}, got {}", what, expected, actual));
+            }
+        };
+
+        let expected = &script.expected;
+        if let Some(turn_number) = expected.turn_number {
+            check(
+                "turn number".to_string(),
+                turn_number.to_string(),
+                game_state.turn_number.to_string(),
+            );
+        }
+        if let Some(active_player) = &expected.active_player {
+            check(
+                "active player".to_string(),
+                active_player.clone(),
+                self.names
+                    .get(&game_state.active_player)
+                    .cloned()
+                    .unwrap_or_default(),
+            );
+        }
+
+        for (name, expected) in &expected.players {
+            let player = self.players[name];
+            let life = world.get::<Player>(player).map_or(0, |player| player.life);
+            let lands_played = game_state
+                .lands_played
+                .iter()
+                .find(|(entity, _)| *entity == player)
+                .map_or(0, |(_, count)| *count);
+            let battlefield = zones
+                .battlefield()
+                .iter()
+                .filter(|card| zones.get_card_owner(**card) == Some(player))
+                .count();
+            let actual = [
+                (
+                    "life",
+                    expected.life.map(|v| v.to_string()),
+                    life.to_string(),
+                ),
+                (
+                    "eliminated",
+                    expected.eliminated.map(|v| v.to_string()),
+                    game_state.eliminated_players.contains(&player).to_string(),
+                ),
+                (
+                    "lands played",
+                    expected.lands_played.map(|v| v.to_string()),
+                    lands_played.to_string(),
+                ),
+                (
+                    "hand",
+                    expected.hand.map(|v| v.to_string()),
+                    zones.hand(player).len().to_string(),
+                ),
+                (
+                    "library",
+                    expected.library.map(|v| v.to_string()),
+                    zones.library(player).len().to_string(),
+                ),
+                (
+                    "battlefield",
+                    expected.battlefield.map(|v| v.to_string()),
+                    battlefield.to_string(),
+                ),
+                (
+                    "graveyard",
+                    expected.graveyard.map(|v| v.to_string()),
+                    zones.graveyard(player).len().to_string(),
+                ),
+            ];
+            for (what, expected, actual) in actual {
+                if let Some(expected) = expected {
+                    check(format!("{} {}", name, what), expected, actual);
+                }
+            }
+        }
+        mismatches
+    }
+}
+
+/// Play a script and compare the engine's output with the expected one
+pub fn run_script(script: &GameScript) -> Result<GoldenReport, GameScriptError> {
+    let mut run = ScriptRun::new(script);
+    for step in &script.steps {
+        run.step(step)?;
+    }
+
+    let log = run.log();
+    Ok(GoldenReport {
+        name: script.name.clone(),
+        log_diff: diff_lines(&script.expected.log_lines(), &log),
+        log,
+        state_mismatches: run.state_mismatches(script),
+    })
+}
+
+/// Play every script in a directory, in file name order
+pub fn run_script_dir(dir: &Path) -> Vec<(PathBuf, Result<GoldenReport, GameScriptError>)> {
+    let mut paths: Vec<PathBuf> = match std::fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .collect(),
+        Err(e) => return vec![(dir.to_path_buf(), Err(GameScriptError::Io(e.to_string())))],
+    };
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let report = GameScript::load(&path).and_then(|script| run_script(&script));
+            (path, report)
+        })
+        .collect()
+}
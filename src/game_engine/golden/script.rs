@@ -0,0 +1,168 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use crate::game_engine::save::board::{BoardDescription, BoardParseError};
+
+/// One step of a script, naming players and cards instead of using entities
+///
+/// Most steps send a [`GameAction`](crate::game_engine::GameAction); the others
+/// move the game along the way the turn systems would.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScriptStep {
+    PlayLand {
+        player: String,
+        card: String,
+    },
+    CastSpell {
+        player: String,
+        card: String,
+        #[serde(default)]
+        targets: Vec<String>,
+    },
+    ActivateAbility {
+        player: String,
+        source: String,
+        #[serde(default)]
+        ability_index: usize,
+        #[serde(default)]
+        targets: Vec<String>,
+    },
+    PassPriority {
+        player: String,
+    },
+    /// Move to a step, named as on the phase bar, e.g. "Main 2"
+    SetPhase {
+        phase: String,
+    },
+    /// Start the next player's turn
+    NextTurn,
+}
+
+/// Parts of a player's final state to check, leaving out what isn't given
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExpectedPlayer {
+    pub life: Option<i32>,
+    pub eliminated: Option<bool>,
+    /// Lands played in the current turn
+    pub lands_played: Option<u32>,
+    pub hand: Option<usize>,
+    pub library: Option<usize>,
+    pub battlefield: Option<usize>,
+    pub graveyard: Option<usize>,
+}
+
+/// What the game should look like once every step has run
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExpectedState {
+    /// The event log, one event per line, as written by
+    /// [`describe_event`](super::runner::describe_event)
+    #[serde(default)]
+    pub log: String,
+    pub turn_number: Option<u32>,
+    pub active_player: Option<String>,
+    #[serde(default)]
+    pub players: BTreeMap<String, ExpectedPlayer>,
+}
+
+impl ExpectedState {
+    /// The expected log lines, ignoring blank lines and surrounding whitespace
+    pub fn log_lines(&self) -> Vec<String> {
+        self.log
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// A script file as written on disk
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GameScriptFile {
+    name: String,
+    #[serde(default)]
+    description: String,
+    board: String,
+    #[serde(default)]
+    hands: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    steps: Vec<ScriptStep>,
+    expected: ExpectedState,
+}
+
+/// A scripted game and its expected outcome
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameScript {
+    pub name: String,
+    pub description: String,
+    pub board: BoardDescription,
+    /// Cards in each player's hand, replacing the hidden hand from the board
+    pub hands: BTreeMap<String, Vec<String>>,
+    pub steps: Vec<ScriptStep>,
+    pub expected: ExpectedState,
+}
+
+/// Why a script couldn't be read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameScriptError {
+    /// The file couldn't be read
+    Io(String),
+    /// The file isn't valid script TOML
+    Format(String),
+    /// The board description couldn't be parsed
+    Board(BoardParseError),
+    /// A step or expectation names a player that isn't on the board
+    UnknownPlayer(String),
+    /// A step names a card that isn't in the game
+    UnknownCard(String),
+    /// A step names a step of the turn that doesn't exist
+    UnknownPhase(String),
+}
+
+impl fmt::Display for GameScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameScriptError::Io(error) => write!(f, "could not read script: {}", error),
+            GameScriptError::Format(error) => write!(f, "invalid script file: {}", error),
+            GameScriptError::Board(error) => write!(f, "invalid script board: {}", error),
+            GameScriptError::UnknownPlayer(name) => write!(f, "no player named `{}`", name),
+            GameScriptError::UnknownCard(name) => write!(f, "no card named `{}`", name),
+            GameScriptError::UnknownPhase(name) => write!(f, "`{}` isn't a step", name),
+        }
+    }
+}
+
+impl GameScript {
+    /// Read a script from TOML
+    pub fn parse(text: &str) -> Result<Self, GameScriptError> {
+        let file: GameScriptFile =
+            toml::from_str(text).map_err(|e| GameScriptError::Format(e.to_string()))?;
+        let board = BoardDescription::parse(&file.board).map_err(GameScriptError::Board)?;
+
+        let named = file.hands.keys().chain(file.expected.players.keys());
+        if let Some(unknown) = named.into_iter().find(|name| board.player(name).is_none()) {
+            return Err(GameScriptError::UnknownPlayer(unknown.clone()));
+        }
+
+        Ok(Self {
+            name: file.name,
+            description: file.description,
+            board,
+            hands: file.hands,
+            steps: file.steps,
+            expected: file.expected,
+        })
+    }
+
+    /// Read a script from a TOML file
+    pub fn load(path: &Path) -> Result<Self, GameScriptError> {
+        let text = std::fs::read_to_string(path).map_err(|e| GameScriptError::Io(e.to_string()))?;
+        Self::parse(&text)
+    }
+}
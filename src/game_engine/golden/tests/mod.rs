@@ -0,0 +1,125 @@
+use std::path::Path;
+
+use crate::game_engine::golden::{
+    DiffLine, GOLDEN_SCRIPT_DIR, GameScript, GameScriptError, diff_lines, run_script,
+    run_script_dir,
+};
+
+const SCRIPT: &str = r#"
+name = "Land drop"
+
+board = """
+# Turn 2 - Main 1
+Active player: Alice
+
+## Player: Alice
+Life: 20
+
+## Player: Bob
+Life: 20
+"""
+
+[hands]
+Alice = ["Forest"]
+
+[[steps]]
+type = "play_land"
+player = "Alice"
+card = "Forest"
+
+[expected]
+log = "LandPlayed Alice"
+
+[expected.players.Alice]
+life = 20
+lands_played = 1
+"#;
+
+fn lines(text: &[&str]) -> Vec<String> {
+    text.iter().map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn test_golden_scripts() {
+    let results = run_script_dir(Path::new(GOLDEN_SCRIPT_DIR));
+    assert!(!results.is_empty(), "no golden scripts found");
+
+    let mut failures = Vec::new();
+    for (path, result) in results {
+        match result {
+            Ok(report) if report.passed() => {}
+            Ok(report) => failures.push(format!("{}\n{}", path.display(), report)),
+            Err(error) => failures.push(format!("{}: {}", path.display(), error)),
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn test_script_passes() {
+    let script = GameScript::parse(SCRIPT).unwrap();
+    let report = run_script(&script).unwrap();
+    assert!(report.passed(), "{}", report);
+    assert_eq!(report.log, lines(&["LandPlayed Alice"]));
+}
+
+#[test]
+fn test_deviation_is_reported() {
+    let script = GameScript::parse(
+        &SCRIPT
+            .replace("log = \"LandPlayed Alice\"", "log = \"MainPhaseStarted\"")
+            .replace("life = 20\nlands_played", "life = 19\nlands_played"),
+    )
+    .unwrap();
+    let report = run_script(&script).unwrap();
+
+    assert!(!report.passed());
+    assert_eq!(
+        report.log_diff,
+        vec![
+            DiffLine::Unexpected("LandPlayed Alice".to_string()),
+            DiffLine::Missing("MainPhaseStarted".to_string()),
+        ]
+    );
+    assert_eq!(
+        report.state_mismatches,
+        vec!["Alice life: expected 19, got 20".to_string()]
+    );
+}
+
+#[test]
+fn test_unknown_names() {
+    let unknown_player = SCRIPT.replace("[hands]\nAlice", "[hands]\nCarol");
+    assert_eq!(
+        GameScript::parse(&unknown_player),
+        Err(GameScriptError::UnknownPlayer("Carol".to_string()))
+    );
+
+    let unknown_card =
+        GameScript::parse(&SCRIPT.replace("card = \"Forest\"", "card = \"Plains\"")).unwrap();
+    assert_eq!(
+        run_script(&unknown_card),
+        Err(GameScriptError::UnknownCard("Plains".to_string()))
+    );
+}
+
+#[test]
+fn test_diff_lines() {
+    let expected = lines(&["a", "b", "c", "d"]);
+    let actual = lines(&["a", "c", "x", "d"]);
+    assert_eq!(
+        diff_lines(&expected, &actual),
+        vec![
+            DiffLine::Same("a".to_string()),
+            DiffLine::Missing("b".to_string()),
+            DiffLine::Same("c".to_string()),
+            DiffLine::Unexpected("x".to_string()),
+            DiffLine::Same("d".to_string()),
+        ]
+    );
+    assert!(
+        diff_lines(&expected, &expected)
+            .iter()
+            .all(|line| matches!(line, DiffLine::Same(_)))
+    );
+}
@@ -0,0 +1,220 @@
+//! Goldfish mode for testing a deck alone
+//!
+//! One deck, no opponents and no turn limit. The opening hand is drawn without
+//! mulligans, and [`GoldfishAction`]s draw a card, untap everything, skip to the
+//! next turn or deal a fresh hand, so deckbuilders can try out opening hands and
+//! early curves quickly. Goldfish games run at
+//! [`RulesEnforcementLevel::Manual`] so the sandbox controls are available.
+
+use bevy::prelude::*;
+
+use crate::cards::Card;
+use crate::deck::Deck;
+use crate::game_engine::enforcement::RulesEnforcementLevel;
+use crate::game_engine::permanent::PermanentState;
+use crate::game_engine::phase::{Phase, PrecombatStep};
+use crate::game_engine::sandbox::{SandboxAction, apply_sandbox_actions};
+use crate::game_engine::save::board::{
+    BoardDescription, PlayerBoard, import_board, placeholder_card,
+};
+use crate::game_engine::setup::GameSetupConfig;
+use crate::game_engine::state::GameEvent;
+use crate::game_engine::turns::TurnManager;
+use crate::game_engine::zones::{DrawCardEvent, ZoneManager};
+use crate::menu::state::GameMenuState;
+use crate::player::Player;
+
+/// Cards in a goldfish opening hand
+pub const GOLDFISH_HAND_SIZE: usize = 7;
+
+/// Name of the player in goldfish games
+pub const GOLDFISH_PLAYER_NAME: &str = "Goldfish";
+
+/// Deck chosen for goldfishing, set up once the game has started
+#[derive(Resource, Debug, Clone)]
+pub struct PendingGoldfish(pub Deck);
+
+/// The deck being goldfished and the player playing it
+#[derive(Resource, Debug, Clone)]
+pub struct ActiveGoldfish {
+    pub deck: Deck,
+    pub player: Entity,
+}
+
+/// Quick actions available while goldfishing
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoldfishAction {
+    /// Draw a card
+    Draw,
+    /// Untap every permanent
+    UntapAll,
+    /// Untap, start the next turn and draw for it
+    NextTurn,
+    /// Shuffle the deck and draw a new opening hand
+    Reset,
+}
+
+impl GoldfishAction {
+    /// Every action, in the order the goldfish panel shows them
+    pub const ALL: [GoldfishAction; 4] = [
+        GoldfishAction::Draw,
+        GoldfishAction::UntapAll,
+        GoldfishAction::NextTurn,
+        GoldfishAction::Reset,
+    ];
+
+    /// Label shown on the goldfish panel
+    pub fn label(self) -> &'static str {
+        match self {
+            GoldfishAction::Draw => "Draw",
+            GoldfishAction::UntapAll => "Untap All",
+            GoldfishAction::NextTurn => "Next Turn",
+            GoldfishAction::Reset => "Reset",
+        }
+    }
+}
+
+/// Builds a goldfish game for `deck` in `world`
+///
+/// Players and cards already in the world are expected to have been removed.
+/// Returns the goldfish player.
+pub fn apply_goldfish(world: &mut World, deck: &Deck) -> Entity {
+    let starting_life = world
+        .get_resource::<GameSetupConfig>()
+        .map(|config| config.validated().starting_life)
+        .unwrap_or(40);
+    let board = BoardDescription {
+        turn_number: 1,
+        phase: Phase::Precombat(PrecombatStep::Main),
+        players: vec![PlayerBoard {
+            name: GOLDFISH_PLAYER_NAME.to_string(),
+            life: starting_life,
+            ..default()
+        }],
+        ..default()
+    };
+    let imported = import_board(world, &board, placeholder_card);
+    let player = imported.players[0];
+
+    let mut library = deck.clone();
+    library.shuffle();
+    let cards: Vec<Entity> = library
+        .cards
+        .iter()
+        .map(|card| world.spawn(card.clone()).id())
+        .collect();
+    let mut zones = world.resource_mut::<ZoneManager>();
+    for card in cards {
+        zones.add_to_library(player, card);
+    }
+    // No mulligans: the first seven cards are the hand
+    for _ in 0..GOLDFISH_HAND_SIZE {
+        zones.draw_card(player);
+    }
+
+    let mut turn_manager = TurnManager::default();
+    turn_manager.initialize(vec![player]);
+    turn_manager.current_phase = board.phase;
+    world.insert_resource(turn_manager);
+    world.insert_resource(RulesEnforcementLevel::Manual);
+    world.insert_resource(ActiveGoldfish {
+        deck: deck.clone(),
+        player,
+    });
+
+    player
+}
+
+/// Replaces the default game with the goldfish game for the pending deck
+pub fn start_pending_goldfish(world: &mut World) {
+    let Some(PendingGoldfish(deck)) = world.remove_resource::<PendingGoldfish>() else {
+        return;
+    };
+    info!("Goldfishing \"{}\"", deck.name);
+
+    // Clear the players and cards spawned for a regular game, or the last hand
+    let existing: Vec<Entity> = world
+        .query_filtered::<Entity, Or<(With<Player>, With<Card>)>>()
+        .iter(world)
+        .collect();
+    for entity in existing {
+        world.despawn(entity);
+    }
+
+    apply_goldfish(world, &deck);
+}
+
+/// Carries out the goldfish panel's quick actions
+///
+/// Untapping goes through [`SandboxAction`]s like any other manual change.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_goldfish_actions(
+    mut commands: Commands,
+    mut actions: EventReader<GoldfishAction>,
+    goldfish: Res<ActiveGoldfish>,
+    mut turn_manager: ResMut<TurnManager>,
+    mut phase: ResMut<Phase>,
+    permanents: Query<(Entity, &PermanentState)>,
+    mut draws: EventWriter<DrawCardEvent>,
+    mut sandbox_actions: EventWriter<SandboxAction>,
+    mut game_events: EventWriter<GameEvent>,
+) {
+    for action in actions.read() {
+        if matches!(action, GoldfishAction::UntapAll | GoldfishAction::NextTurn) {
+            for (card, state) in permanents.iter() {
+                if state.is_tapped {
+                    sandbox_actions.write(SandboxAction::ToggleTapped { card });
+                }
+            }
+        }
+
+        match action {
+            GoldfishAction::Draw => {
+                draws.write(DrawCardEvent::single(goldfish.player));
+            }
+            GoldfishAction::UntapAll => {}
+            GoldfishAction::NextTurn => {
+                turn_manager.advance_turn();
+                *phase = Phase::Precombat(PrecombatStep::Main);
+                game_events.write(GameEvent::TurnStarted {
+                    player: goldfish.player,
+                    turn_number: turn_manager.turn_number,
+                });
+                draws.write(DrawCardEvent::single(goldfish.player));
+            }
+            GoldfishAction::Reset => {
+                commands.insert_resource(PendingGoldfish(goldfish.deck.clone()));
+            }
+        }
+    }
+}
+
+/// Leaves goldfish mode when returning to the main menu
+pub fn end_goldfish(mut commands: Commands) {
+    commands.remove_resource::<ActiveGoldfish>();
+    commands.remove_resource::<PendingGoldfish>();
+}
+
+/// Plugin for goldfish mode
+pub struct GoldfishPlugin;
+
+impl Plugin for GoldfishPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<GoldfishAction>()
+            .add_systems(
+                Update,
+                (
+                    start_pending_goldfish.run_if(resource_exists::<PendingGoldfish>),
+                    apply_goldfish_actions
+                        .run_if(resource_exists::<ActiveGoldfish>)
+                        .before(apply_sandbox_actions),
+                )
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame)),
+            )
+            .add_systems(OnEnter(GameMenuState::MainMenu), end_goldfish);
+    }
+}
+
+#[cfg(test)]
+mod tests;
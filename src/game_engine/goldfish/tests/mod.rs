@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+
+use crate::cards::{Card, CardDetails, CardTypes};
+use crate::deck::{Deck, DeckType};
+use crate::game_engine::enforcement::RulesEnforcementLevel;
+use crate::game_engine::goldfish::{
+    ActiveGoldfish, GOLDFISH_HAND_SIZE, GoldfishAction, apply_goldfish, apply_goldfish_actions,
+};
+use crate::game_engine::permanent::PermanentState;
+use crate::game_engine::sandbox::{SandboxAction, apply_sandbox_actions};
+use crate::game_engine::state::GameEvent;
+use crate::game_engine::turns::TurnManager;
+use crate::game_engine::zones::{
+    CardDrawnEvent, DrawCardEvent, Zone, ZoneChangeEvent, ZoneManager, process_draw_card_events,
+};
+use crate::mana::Mana;
+
+fn forest_deck(size: usize) -> Deck {
+    let forest = Card::builder("Forest")
+        .cost(Mana::default())
+        .types(CardTypes::BASIC | CardTypes::LAND | CardTypes::FOREST)
+        .details(CardDetails::Other)
+        .build_or_panic();
+    Deck::new(
+        "Forests".to_string(),
+        DeckType::Standard,
+        vec![forest; size],
+    )
+}
+
+fn goldfish_app(deck: &Deck) -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<GoldfishAction>()
+        .add_event::<SandboxAction>()
+        .add_event::<DrawCardEvent>()
+        .add_event::<CardDrawnEvent>()
+        .add_event::<ZoneChangeEvent>()
+        .add_event::<GameEvent>()
+        .add_systems(
+            Update,
+            (
+                apply_goldfish_actions,
+                apply_sandbox_actions,
+                process_draw_card_events,
+            )
+                .chain(),
+        );
+    let player = apply_goldfish(app.world_mut(), deck);
+    (app, player)
+}
+
+#[test]
+fn test_goldfish_deals_an_opening_hand_without_opponents() {
+    let (app, player) = goldfish_app(&forest_deck(60));
+    let world = app.world();
+    let zones = world.resource::<ZoneManager>();
+
+    assert_eq!(zones.hand(player).len(), GOLDFISH_HAND_SIZE);
+    assert_eq!(zones.library(player).len(), 60 - GOLDFISH_HAND_SIZE);
+    assert_eq!(world.resource::<TurnManager>().player_order, vec![player]);
+    assert_eq!(
+        *world.resource::<RulesEnforcementLevel>(),
+        RulesEnforcementLevel::Manual
+    );
+    assert_eq!(world.resource::<ActiveGoldfish>().player, player);
+}
+
+#[test]
+fn test_next_turn_untaps_and_draws() {
+    let (mut app, player) = goldfish_app(&forest_deck(60));
+    let land = app.world().resource::<ZoneManager>().hand(player)[0];
+    app.world_mut().resource_mut::<ZoneManager>().move_card(
+        land,
+        player,
+        Zone::Hand,
+        Zone::Battlefield,
+    );
+    app.world_mut().entity_mut(land).insert(PermanentState {
+        is_tapped: true,
+        ..default()
+    });
+
+    app.world_mut().send_event(GoldfishAction::NextTurn);
+    app.update();
+
+    let world = app.world();
+    assert!(!world.get::<PermanentState>(land).unwrap().is_tapped);
+    assert_eq!(world.resource::<TurnManager>().turn_number, 2);
+    assert_eq!(
+        world.resource::<ZoneManager>().hand(player).len(),
+        GOLDFISH_HAND_SIZE
+    );
+}
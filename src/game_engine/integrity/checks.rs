@@ -0,0 +1,117 @@
+use std::fmt;
+
+use bevy::prelude::*;
+
+use crate::cards::components::card_entity::CardZone;
+use crate::game_engine::stack::GameStack;
+use crate::game_engine::zones::{Zone, ZoneManager};
+
+/// A way the engine's views of the game disagree
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IntegrityViolation {
+    /// A card entity that no zone holds
+    CardInNoZone(Entity),
+    /// The zone manager's own bookkeeping is off, e.g. a card in two zones
+    ZoneBookkeeping(String),
+    /// A card's [`CardZone`] names another zone or owner than the zone manager
+    CardZoneMismatch {
+        card: Entity,
+        component: (Zone, Option<Entity>),
+        manager: (Zone, Entity),
+    },
+    /// A card on the battlefield without a permanent state
+    PermanentWithoutState(Entity),
+    /// A stack item whose entity no longer exists
+    DeadStackItem(Entity),
+    /// A stack item whose controller no longer exists
+    DeadStackController { item: Entity, controller: Entity },
+}
+
+impl fmt::Display for IntegrityViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrityViolation::CardInNoZone(card) => write!(f, "card {:?} is in no zone", card),
+            IntegrityViolation::ZoneBookkeeping(error) => write!(f, "zone manager: {}", error),
+            IntegrityViolation::CardZoneMismatch {
+                card,
+                component,
+                manager,
+            } => write!(
+                f,
+                "card {:?} has CardZone {:?} of {:?} but the zone manager has it in {:?} of {:?}",
+                card, component.0, component.1, manager.0, manager.1
+            ),
+            IntegrityViolation::PermanentWithoutState(card) => {
+                write!(
+                    f,
+                    "card {:?} is on the battlefield without PermanentState",
+                    card
+                )
+            }
+            IntegrityViolation::DeadStackItem(item) => {
+                write!(f, "stack item {:?} no longer exists", item)
+            }
+            IntegrityViolation::DeadStackController { item, controller } => write!(
+                f,
+                "controller {:?} of stack item {:?} no longer exists",
+                controller, item
+            ),
+        }
+    }
+}
+
+/// Everything the card entities, zone manager and stack disagree on
+///
+/// `cards` are the card entities, with their [`CardZone`] if they have one and
+/// whether they have a permanent state. `exists` tells whether an entity is alive.
+pub fn integrity_violations<'a>(
+    zones: &ZoneManager,
+    stack: &GameStack,
+    cards: impl IntoIterator<Item = (Entity, Option<&'a CardZone>, bool)>,
+    exists: impl Fn(Entity) -> bool,
+) -> Vec<IntegrityViolation> {
+    let mut violations: Vec<IntegrityViolation> = zones
+        .consistency_errors()
+        .into_iter()
+        .map(IntegrityViolation::ZoneBookkeeping)
+        .collect();
+
+    for (card, card_zone, has_permanent_state) in cards {
+        let Some(location) = zones.location(card) else {
+            violations.push(IntegrityViolation::CardInNoZone(card));
+            continue;
+        };
+
+        if let Some(card_zone) = card_zone {
+            // Either side may not know the owner of a card in a shared zone
+            let owner_differs = card_zone.zone_owner.is_some_and(|owner| {
+                location.owner != Entity::PLACEHOLDER && owner != location.owner
+            });
+            if card_zone.zone != location.zone || owner_differs {
+                violations.push(IntegrityViolation::CardZoneMismatch {
+                    card,
+                    component: (card_zone.zone, card_zone.zone_owner),
+                    manager: (location.zone, location.owner),
+                });
+            }
+        }
+
+        if location.zone == Zone::Battlefield && !has_permanent_state {
+            violations.push(IntegrityViolation::PermanentWithoutState(card));
+        }
+    }
+
+    for item in &stack.items {
+        if !exists(item.entity) {
+            violations.push(IntegrityViolation::DeadStackItem(item.entity));
+        }
+        if !exists(item.controller) {
+            violations.push(IntegrityViolation::DeadStackController {
+                item: item.entity,
+                controller: item.controller,
+            });
+        }
+    }
+
+    violations
+}
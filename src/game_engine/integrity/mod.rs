@@ -0,0 +1,43 @@
+//! Debug checks that the engine's views of the game agree
+//!
+//! In debug builds, every fixed tick compares the [`ZoneManager`] with the card
+//! entities and the [`GameStack`] with the world: every card is in exactly one
+//! zone, [`CardZone`] components agree with the zone manager, permanents on the
+//! battlefield have a [`PermanentState`], and stack items refer to live entities.
+//! Violations are logged as a report and can pause the game, see
+//! [`IntegrityCheckConfig`].
+//!
+//! [`ZoneManager`]: crate::game_engine::zones::ZoneManager
+//! [`GameStack`]: crate::game_engine::stack::GameStack
+//! [`CardZone`]: crate::cards::components::card_entity::CardZone
+//! [`PermanentState`]: crate::game_engine::permanent::PermanentState
+
+pub mod checks;
+pub mod resources;
+pub mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use checks::{IntegrityViolation, integrity_violations};
+pub use resources::{IntegrityCheckConfig, IntegrityReport};
+pub use systems::check_game_integrity;
+
+use bevy::prelude::*;
+
+use crate::game_engine::state::apply_game_events;
+
+/// Register the integrity check, in debug builds only
+pub fn register_integrity_checks(app: &mut App) {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+
+    // Checked after the tick's game events are applied, when the game is settled
+    app.init_resource::<IntegrityCheckConfig>()
+        .init_resource::<IntegrityReport>()
+        .add_systems(
+            FixedPostUpdate,
+            check_game_integrity.after(apply_game_events),
+        );
+}
@@ -0,0 +1,34 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use super::checks::IntegrityViolation;
+
+/// How the integrity check reacts to violations
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegrityCheckConfig {
+    /// Whether the check runs at all
+    pub enabled: bool,
+    /// Open the pause menu when a violation is found, to inspect the game
+    pub pause_on_violation: bool,
+}
+
+impl Default for IntegrityCheckConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            pause_on_violation: false,
+        }
+    }
+}
+
+/// Results of the integrity checks so far
+#[derive(Resource, Debug, Default)]
+pub struct IntegrityReport {
+    /// Checks run
+    pub checks: u64,
+    /// Violations reported by the last check
+    pub violations: Vec<IntegrityViolation>,
+    /// Violations found by the last check, reported if the next one finds them too
+    pub(crate) suspected: HashSet<IntegrityViolation>,
+}
@@ -0,0 +1,67 @@
+use bevy::ecs::entity::Entities;
+use bevy::prelude::*;
+
+use super::checks::integrity_violations;
+use super::resources::{IntegrityCheckConfig, IntegrityReport};
+use crate::cards::Card;
+use crate::cards::components::card_entity::CardZone;
+use crate::game_engine::permanent::PermanentState;
+use crate::game_engine::stack::GameStack;
+use crate::game_engine::zones::ZoneManager;
+use crate::menu::state::GameMenuState;
+
+/// Check that the card entities, zone manager and stack agree
+///
+/// A violation is only reported once two checks in a row find it, so cards
+/// spawned before the zone systems index them aren't mistaken for a desync. New
+/// violations are logged as a report, and pause the game if configured to.
+pub fn check_game_integrity(
+    config: Res<IntegrityCheckConfig>,
+    mut report: ResMut<IntegrityReport>,
+    zones: Option<Res<ZoneManager>>,
+    stack: Option<Res<GameStack>>,
+    cards: Query<(Entity, Option<&CardZone>, Has<PermanentState>), With<Card>>,
+    entities: &Entities,
+    next_state: Option<ResMut<NextState<GameMenuState>>>,
+) {
+    let (Some(zones), Some(stack)) = (zones, stack) else {
+        return;
+    };
+    if !config.enabled {
+        return;
+    }
+
+    let found = integrity_violations(&zones, &stack, cards.iter(), |entity| {
+        entities.contains(entity)
+    });
+    report.checks += 1;
+
+    let confirmed: Vec<_> = found
+        .iter()
+        .filter(|violation| report.suspected.contains(*violation))
+        .cloned()
+        .collect();
+    let new: Vec<String> = confirmed
+        .iter()
+        .filter(|violation| !report.violations.contains(*violation))
+        .map(|violation| format!("  {}", violation))
+        .collect();
+    report.suspected = found.into_iter().collect();
+    report.violations = confirmed;
+
+    if new.is_empty() {
+        return;
+    }
+    error!(
+        "Game integrity check {} found {} new violation(s), {} in total:\n{}",
+        report.checks,
+        new.len(),
+        report.violations.len(),
+        new.join("\n")
+    );
+    if config.pause_on_violation {
+        if let Some(mut next_state) = next_state {
+            next_state.set(GameMenuState::PauseMenu);
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+
+use crate::cards::components::card_entity::CardZone;
+use crate::cards::{Card, CardDetails, CardTypes};
+use crate::game_engine::integrity::{
+    IntegrityCheckConfig, IntegrityReport, IntegrityViolation, check_game_integrity,
+};
+use crate::game_engine::permanent::PermanentState;
+use crate::game_engine::stack::{Effect, GameStack};
+use crate::game_engine::zones::{Zone, ZoneManager};
+use crate::mana::Mana;
+use crate::menu::state::GameMenuState;
+
+#[derive(Debug)]
+struct TestEffect(Entity);
+
+impl Effect for TestEffect {
+    fn resolve(&self, _commands: &mut Commands) {}
+
+    fn controller(&self) -> Entity {
+        self.0
+    }
+
+    fn targets(&self) -> Vec<Entity> {
+        Vec::new()
+    }
+}
+
+fn card() -> Card {
+    Card::builder("Grizzly Bears")
+        .cost(Mana::default())
+        .types(CardTypes::CREATURE)
+        .details(CardDetails::Other)
+        .build_or_panic()
+}
+
+/// An app checking a game with one player, one card in their library and one on
+/// the battlefield
+fn checked_app() -> (App, Entity, Entity, Entity) {
+    let mut app = App::new();
+    app.init_resource::<IntegrityCheckConfig>()
+        .init_resource::<IntegrityReport>()
+        .init_resource::<GameStack>()
+        .init_resource::<NextState<GameMenuState>>()
+        .add_systems(Update, check_game_integrity);
+
+    let world = app.world_mut();
+    let player = world.spawn_empty().id();
+    let library_card = world
+        .spawn((card(), CardZone::new(Zone::Library, Some(player))))
+        .id();
+    let permanent = world.spawn((card(), PermanentState::new(0))).id();
+
+    let mut zones = ZoneManager::default();
+    zones.init_player_zones(player);
+    zones.add_to_library(player, library_card);
+    zones.add_to_battlefield(player, permanent);
+    world.insert_resource(zones);
+
+    (app, player, library_card, permanent)
+}
+
+fn reported(app: &App) -> &[IntegrityViolation] {
+    &app.world().resource::<IntegrityReport>().violations
+}
+
+#[test]
+fn test_consistent_game_has_no_violations() {
+    let (mut app, ..) = checked_app();
+    app.update();
+    app.update();
+    assert!(reported(&app).is_empty(), "{:?}", reported(&app));
+    assert_eq!(app.world().resource::<IntegrityReport>().checks, 2);
+}
+
+#[test]
+fn test_violations_are_reported_once_seen_twice() {
+    let (mut app, player, ..) = checked_app();
+    let stray = app.world_mut().spawn(card()).id();
+
+    app.update();
+    assert!(reported(&app).is_empty());
+    app.update();
+    assert_eq!(reported(&app), &[IntegrityViolation::CardInNoZone(stray)]);
+
+    // Indexing the card clears the report
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .add_to_exile(player, stray);
+    app.update();
+    assert!(reported(&app).is_empty());
+}
+
+#[test]
+fn test_zone_component_and_permanent_state_desyncs() {
+    let (mut app, player, library_card, permanent) = checked_app();
+    app.world_mut()
+        .entity_mut(library_card)
+        .insert(CardZone::new(Zone::Hand, Some(player)));
+    app.world_mut()
+        .entity_mut(permanent)
+        .remove::<PermanentState>();
+    app.update();
+    app.update();
+
+    let violations = reported(&app);
+    assert_eq!(violations.len(), 2, "{:?}", violations);
+    assert!(violations.contains(&IntegrityViolation::CardZoneMismatch {
+        card: library_card,
+        component: (Zone::Hand, Some(player)),
+        manager: (Zone::Library, player),
+    }));
+    assert!(violations.contains(&IntegrityViolation::PermanentWithoutState(permanent)));
+}
+
+#[test]
+fn test_dead_stack_entities_are_reported_and_can_pause() {
+    let (mut app, player, ..) = checked_app();
+    app.world_mut()
+        .resource_mut::<IntegrityCheckConfig>()
+        .pause_on_violation = true;
+    let item = app.world_mut().spawn_empty().id();
+    app.world_mut().resource_mut::<GameStack>().push(
+        Box::new(TestEffect(player)),
+        item,
+        false,
+        true,
+    );
+    app.world_mut().despawn(item);
+    app.update();
+    app.update();
+
+    assert_eq!(reported(&app), &[IntegrityViolation::DeadStackItem(item)]);
+    assert!(matches!(
+        *app.world().resource::<NextState<GameMenuState>>(),
+        NextState::Pending(GameMenuState::PauseMenu)
+    ));
+}
@@ -0,0 +1,164 @@
+//! Changes to players' life totals
+//!
+//! Life totals are never changed directly during a game. Gains, losses and
+//! damage are sent as [`LifeChangeEvent`]s and applied by
+//! [`apply_life_changes`] alone, so effects like [`CantGainLife`] can stop them
+//! in one place. Damage to a player is kept apart from other life loss, since
+//! only damage counts for things like infect or lifelink.
+//!
+//! Each change that happens is announced with a [`LifeChangedEvent`], which
+//! triggers "whenever you gain life" abilities and drives the life ticker on
+//! the playmat. Like the rules, a change of zero is no change at all: it
+//! neither triggers anything nor is announced.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::abilities::{TriggerCondition, TriggeredAbility};
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::triggers::{AbilityTriggeredEvent, PendingTrigger};
+use crate::menu::state::GameMenuState;
+use crate::player::Player;
+
+/// Sent to change a player's life total
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LifeChangeEvent {
+    pub player: Entity,
+    /// The card, spell or ability changing it, if any
+    pub source: Option<Entity>,
+    pub amount: u32,
+    /// Whether life is gained rather than lost
+    pub is_gain: bool,
+    /// Whether life is lost to damage
+    pub is_damage: bool,
+}
+
+impl LifeChangeEvent {
+    /// `player` gains `amount` life
+    pub fn gain(player: Entity, source: Option<Entity>, amount: u32) -> Self {
+        Self {
+            player,
+            source,
+            amount,
+            is_gain: true,
+            is_damage: false,
+        }
+    }
+
+    /// `player` loses `amount` life, other than to damage
+    pub fn loss(player: Entity, source: Option<Entity>, amount: u32) -> Self {
+        Self {
+            player,
+            source,
+            amount,
+            is_gain: false,
+            is_damage: false,
+        }
+    }
+
+    /// `source` deals `amount` damage to `player`
+    pub fn damage(player: Entity, source: Entity, amount: u32) -> Self {
+        Self {
+            player,
+            source: Some(source),
+            amount,
+            is_gain: false,
+            is_damage: true,
+        }
+    }
+}
+
+/// A player's life total changed
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LifeChangedEvent {
+    pub player: Entity,
+    pub source: Option<Entity>,
+    /// Life gained, or lost if negative
+    pub change: i32,
+    pub is_damage: bool,
+    /// The player's life total afterwards
+    pub life: i32,
+}
+
+/// A player who can't gain life, like under Sulfuric Vortex
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CantGainLife;
+
+/// Apply the life changes sent, announcing each one that happens
+pub fn apply_life_changes(
+    mut changes: EventReader<LifeChangeEvent>,
+    mut players: Query<(&mut Player, Has<CantGainLife>)>,
+    mut changed: EventWriter<LifeChangedEvent>,
+) {
+    for event in changes.read() {
+        let Ok((mut player, cant_gain)) = players.get_mut(event.player) else {
+            continue;
+        };
+        if event.amount == 0 {
+            continue;
+        }
+        if event.is_gain && cant_gain {
+            info!("{} can't gain life", player.name);
+            continue;
+        }
+
+        let change = if event.is_gain {
+            event.amount as i32
+        } else {
+            -(event.amount as i32)
+        };
+        player.life += change;
+        info!(
+            "{} {} {} life from {:?}, life now {}",
+            player.name,
+            if event.is_gain { "gained" } else { "lost" },
+            event.amount,
+            event.source,
+            player.life
+        );
+        changed.write(LifeChangedEvent {
+            player: event.player,
+            source: event.source,
+            change,
+            is_damage: event.is_damage,
+            life: player.life,
+        });
+    }
+}
+
+/// Trigger "whenever you gain life" abilities, once for each gain
+pub fn trigger_lifegain_abilities(
+    mut changed: EventReader<LifeChangedEvent>,
+    abilities: Query<(Entity, &TriggeredAbility, &PermanentController)>,
+    mut triggered: EventWriter<AbilityTriggeredEvent>,
+) {
+    for event in changed.read().filter(|event| event.change > 0) {
+        for (source, ability, controller) in abilities.iter() {
+            if matches!(ability.trigger, TriggerCondition::WhenControllerGainsLife)
+                && controller.player == event.player
+            {
+                triggered.write(AbilityTriggeredEvent(PendingTrigger {
+                    source,
+                    controller: controller.player,
+                    description: ability.description.clone(),
+                    effects: Vec::new(),
+                }));
+            }
+        }
+    }
+}
+
+/// Register changing life totals
+pub fn register_life_systems(app: &mut App) {
+    app.add_event::<LifeChangeEvent>()
+        .add_event::<LifeChangedEvent>()
+        .add_systems(
+            Update,
+            (apply_life_changes, trigger_lifegain_abilities)
+                .chain()
+                .run_if(in_state(GameMenuState::InGame)),
+        );
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,128 @@
+use bevy::prelude::*;
+
+use crate::cards::abilities::{TriggerCondition, TriggeredAbility};
+use crate::game_engine::life::{
+    CantGainLife, LifeChangeEvent, LifeChangedEvent, apply_life_changes, trigger_lifegain_abilities,
+};
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::triggers::AbilityTriggeredEvent;
+use crate::player::Player;
+
+fn life_app() -> (App, [Entity; 2]) {
+    let mut app = App::new();
+    app.add_event::<LifeChangeEvent>()
+        .add_event::<LifeChangedEvent>()
+        .add_event::<AbilityTriggeredEvent>()
+        .add_systems(
+            Update,
+            (apply_life_changes, trigger_lifegain_abilities).chain(),
+        );
+    let players = ["Alice", "Bob"].map(|name| app.world_mut().spawn(Player::new(name)).id());
+    (app, players)
+}
+
+fn life(app: &App, player: Entity) -> i32 {
+    app.world().get::<Player>(player).unwrap().life
+}
+
+fn changes(app: &mut App) -> Vec<LifeChangedEvent> {
+    app.world_mut()
+        .resource_mut::<Events<LifeChangedEvent>>()
+        .drain()
+        .collect()
+}
+
+#[test]
+fn test_gains_losses_and_damage_are_told_apart() {
+    let (mut app, [alice, bob]) = life_app();
+    let bolt = app.world_mut().spawn_empty().id();
+    let starting = life(&app, alice);
+
+    app.world_mut()
+        .send_event(LifeChangeEvent::damage(alice, bolt, 3));
+    app.world_mut()
+        .send_event(LifeChangeEvent::loss(alice, None, 2));
+    app.world_mut()
+        .send_event(LifeChangeEvent::gain(bob, Some(bolt), 4));
+    app.world_mut()
+        .send_event(LifeChangeEvent::gain(bob, None, 0));
+    app.update();
+
+    assert_eq!(life(&app, alice), starting - 5);
+    assert_eq!(life(&app, bob), starting + 4);
+    assert_eq!(
+        changes(&mut app),
+        vec![
+            LifeChangedEvent {
+                player: alice,
+                source: Some(bolt),
+                change: -3,
+                is_damage: true,
+                life: starting - 3,
+            },
+            LifeChangedEvent {
+                player: alice,
+                source: None,
+                change: -2,
+                is_damage: false,
+                life: starting - 5,
+            },
+            LifeChangedEvent {
+                player: bob,
+                source: Some(bolt),
+                change: 4,
+                is_damage: false,
+                life: starting + 4,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_players_who_cant_gain_life_still_lose_it() {
+    let (mut app, [alice, _]) = life_app();
+    let starting = life(&app, alice);
+    app.world_mut().entity_mut(alice).insert(CantGainLife);
+
+    app.world_mut()
+        .send_event(LifeChangeEvent::gain(alice, None, 5));
+    app.world_mut()
+        .send_event(LifeChangeEvent::loss(alice, None, 1));
+    app.update();
+
+    assert_eq!(life(&app, alice), starting - 1);
+    assert_eq!(changes(&mut app).len(), 1);
+}
+
+#[test]
+fn test_lifegain_triggers_its_controllers_abilities_once_per_gain() {
+    let (mut app, [alice, bob]) = life_app();
+    let ajani = app
+        .world_mut()
+        .spawn((
+            TriggeredAbility {
+                trigger: TriggerCondition::WhenControllerGainsLife,
+                description: "Put a +1/+1 counter on target creature".to_string(),
+                once_per_turn: false,
+                optional: false,
+            },
+            PermanentController::new(alice),
+        ))
+        .id();
+
+    for player in [alice, alice, bob] {
+        app.world_mut()
+            .send_event(LifeChangeEvent::gain(player, None, 1));
+    }
+    app.world_mut()
+        .send_event(LifeChangeEvent::loss(alice, None, 1));
+    app.update();
+
+    let triggered: Vec<AbilityTriggeredEvent> = app
+        .world_mut()
+        .resource_mut::<Events<AbilityTriggeredEvent>>()
+        .drain()
+        .collect();
+    assert_eq!(triggered.len(), 2);
+    assert!(triggered.iter().all(|trigger| trigger.0.source == ajani));
+}
@@ -0,0 +1,120 @@
+//! Mana produced by mana abilities
+//!
+//! Mana abilities don't use the stack (rule 605.3): activating one, like a
+//! scripted land's `{T}: Add {G}.`, sends a [`ProduceManaEvent`], and the mana
+//! goes straight into its controller's pool. Spells that add mana send one as
+//! they resolve. Changes to the mana produced are applied here, as it's added.
+//!
+//! With the [`HouseRules::colorless_outside_identity`] house rule, mana of a
+//! color outside the color identity of a player's commanders is added as that
+//! much colorless mana instead, as the Commander rules said until 2016. Players
+//! without a commander, like in goldfish games, are unaffected.
+//!
+//! [`HouseRules::colorless_outside_identity`]: crate::game_engine::setup::HouseRules::colorless_outside_identity
+
+use bevy::prelude::*;
+
+use crate::cards::Card;
+use crate::game_engine::commander::Commander;
+use crate::game_engine::commander::components::ColorIdentity;
+use crate::game_engine::commander::rules::CommanderRules;
+use crate::game_engine::setup::GameSetupConfig;
+use crate::mana::{Mana, ManaColor};
+use crate::menu::state::GameMenuState;
+use crate::player::Player;
+
+/// Sent by a mana ability to add mana to a player's pool
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ProduceManaEvent {
+    pub player: Entity,
+    /// The permanent whose ability produced it, if any
+    pub source: Option<Entity>,
+    pub mana: Mana,
+}
+
+/// The colors of a commander's identity
+///
+/// An explicit [`ColorIdentity`] wins; otherwise it's the colors recorded on
+/// the commander together with those in its mana cost.
+pub fn commander_identity(
+    commander: &Commander,
+    card: Option<&Card>,
+    identity: Option<&ColorIdentity>,
+) -> ManaColor {
+    if let Some(identity) = identity {
+        return identity.colors;
+    }
+    let cost_colors = card
+        .map(|card| CommanderRules::extract_color_identity(&card.cost))
+        .unwrap_or_default();
+    commander
+        .color_identity
+        .iter()
+        .chain(&cost_colors)
+        .fold(ManaColor::NONE, |colors, &color| colors | color)
+}
+
+/// `mana` with every color outside `identity` turned into colorless mana
+pub fn restrict_to_identity(mana: &Mana, identity: ManaColor) -> Mana {
+    let mut colorless = mana.colorless;
+    let mut keep = |amount: u64, color: ManaColor| {
+        if identity.contains(color) {
+            amount
+        } else {
+            colorless += amount;
+            0
+        }
+    };
+    let white = keep(mana.white, ManaColor::WHITE);
+    let blue = keep(mana.blue, ManaColor::BLUE);
+    let black = keep(mana.black, ManaColor::BLACK);
+    let red = keep(mana.red, ManaColor::RED);
+    let green = keep(mana.green, ManaColor::GREEN);
+    Mana::new_with_colors(colorless, white, blue, black, red, green)
+}
+
+/// Add the mana produced to players' pools
+pub fn produce_mana(
+    mut events: EventReader<ProduceManaEvent>,
+    config: Option<Res<GameSetupConfig>>,
+    commanders: Query<(&Commander, Option<&Card>, Option<&ColorIdentity>)>,
+    mut players: Query<&mut Player>,
+) {
+    let colorless_outside_identity =
+        config.is_some_and(|config| config.house_rules.colorless_outside_identity);
+    for event in events.read() {
+        let Ok(mut player) = players.get_mut(event.player) else {
+            warn!(
+                "Ignoring mana produced for {:?}, who isn't a player",
+                event.player
+            );
+            continue;
+        };
+
+        let identity = commanders
+            .iter()
+            .filter(|(commander, _, _)| commander.owner == event.player)
+            .map(|(commander, card, identity)| commander_identity(commander, card, identity))
+            .reduce(|colors, identity| colors | identity);
+        let mana = match identity.filter(|_| colorless_outside_identity) {
+            Some(identity) => restrict_to_identity(&event.mana, identity),
+            None => event.mana,
+        };
+        if mana.colorless != event.mana.colorless {
+            info!(
+                "Mana from {:?} outside {}'s color identity is added as colorless",
+                event.source, player.name
+            );
+        }
+        player.mana_pool.add(mana);
+    }
+}
+
+/// Register mana production
+pub fn register_mana_ability_systems(app: &mut App) {
+    app.add_event::<ProduceManaEvent>()
+        .add_systems(Update, produce_mana.run_if(in_state(GameMenuState::InGame)));
+}
+
+#[cfg(test)]
+mod tests;
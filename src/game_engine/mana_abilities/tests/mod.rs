@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+
+use crate::cards::scripting::{CardScript, EffectPrimitive, ScriptCost, ScriptedActivation};
+use crate::game_engine::actions::process_game_actions;
+use crate::game_engine::commander::Commander;
+use crate::game_engine::commander::components::ColorIdentity;
+use crate::game_engine::mana_abilities::{ProduceManaEvent, produce_mana, restrict_to_identity};
+use crate::game_engine::permanent::PermanentState;
+use crate::game_engine::setup::{GameSetupConfig, HouseRules};
+use crate::game_engine::{GameAction, GameStack, GameState, Phase, PrioritySystem};
+use crate::mana::{Mana, ManaColor};
+use crate::player::Player;
+
+fn mana_app(colorless_outside_identity: bool) -> (App, Entity) {
+    let mut app = App::new();
+    app.add_event::<ProduceManaEvent>()
+        .insert_resource(GameSetupConfig {
+            house_rules: HouseRules {
+                colorless_outside_identity,
+                ..default()
+            },
+            ..default()
+        })
+        .add_systems(Update, produce_mana);
+    let player = app.world_mut().spawn(Player::new("Alice")).id();
+    app.world_mut().spawn((
+        Commander {
+            owner: player,
+            ..default()
+        },
+        ColorIdentity {
+            colors: ManaColor::GREEN | ManaColor::BLUE,
+        },
+    ));
+    (app, player)
+}
+
+/// Produce one green and one red mana, returning how much red and colorless
+/// mana went into the player's pool
+fn produce_green_and_red(app: &mut App, player: Entity) -> (u64, u64) {
+    app.world_mut().send_event(ProduceManaEvent {
+        player,
+        source: None,
+        mana: Mana::new_with_colors(0, 0, 0, 0, 1, 1),
+    });
+    app.update();
+
+    let pool = &app.world().get::<Player>(player).unwrap().mana_pool;
+    let red = pool.mana.values().map(|mana| mana.red).sum();
+    let colorless = pool.mana.values().map(|mana| mana.colorless).sum();
+    (red, colorless)
+}
+
+#[test]
+fn test_colors_outside_identity_become_colorless() {
+    let mana = Mana::new_with_colors(1, 2, 0, 3, 0, 4);
+    let restricted = restrict_to_identity(&mana, ManaColor::WHITE | ManaColor::GREEN);
+    assert_eq!(restricted, Mana::new_with_colors(4, 2, 0, 0, 0, 4));
+
+    let everything =
+        ManaColor::WHITE | ManaColor::BLUE | ManaColor::BLACK | ManaColor::RED | ManaColor::GREEN;
+    assert_eq!(restrict_to_identity(&mana, everything), mana);
+}
+
+#[test]
+fn test_mana_is_added_as_produced_under_current_rules() {
+    let (mut app, player) = mana_app(false);
+    assert_eq!(produce_green_and_red(&mut app, player), (1, 0));
+}
+
+#[test]
+fn test_house_rule_makes_off_identity_mana_colorless() {
+    let (mut app, player) = mana_app(true);
+    assert_eq!(produce_green_and_red(&mut app, player), (0, 1));
+}
+
+#[test]
+fn test_tapping_a_land_for_mana_skips_the_stack() {
+    let (mut app, player) = mana_app(true);
+    app.add_event::<GameAction>()
+        .init_resource::<GameState>()
+        .init_resource::<GameStack>()
+        .init_resource::<PrioritySystem>()
+        .insert_resource(Phase::default())
+        .add_systems(Update, process_game_actions.before(produce_mana));
+    let land = app
+        .world_mut()
+        .spawn((
+            PermanentState::new(0),
+            CardScript {
+                activated: vec![ScriptedActivation {
+                    cost: ScriptCost {
+                        tap: true,
+                        ..default()
+                    },
+                    text: "{T}: Add {R}.".to_string(),
+                    effects: vec![EffectPrimitive::AddMana {
+                        mana: "{R}".to_string(),
+                    }],
+                }],
+                ..default()
+            },
+        ))
+        .id();
+
+    app.world_mut().send_event(GameAction::ActivateAbility {
+        player,
+        source: land,
+        ability_index: 0,
+        targets: Vec::new(),
+        mana_payment: Mana::default(),
+    });
+    app.update();
+
+    // Red is outside the commander's identity, so the house rule applies too
+    let pool = &app.world().get::<Player>(player).unwrap().mana_pool;
+    assert_eq!(
+        pool.mana.values().map(|mana| mana.colorless).sum::<u64>(),
+        1
+    );
+    assert!(app.world().get::<PermanentState>(land).unwrap().is_tapped);
+    assert!(app.world().resource::<GameStack>().items.is_empty());
+}
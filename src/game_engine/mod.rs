@@ -2,20 +2,50 @@
 // It follows the implementation plan outlined in docs/game_loop.md
 
 pub mod actions;
+pub mod ai;
+pub mod choices;
+pub mod cleanup;
 pub mod combat;
 pub mod commander;
+pub mod costs;
+pub mod damage;
+pub mod day_night;
+pub mod dungeon;
+pub mod durations;
+pub mod enforcement;
+pub mod enters_choice;
+pub mod face_down;
+pub mod fuzz;
+pub mod golden;
+pub mod goldfish;
+pub mod integrity;
+pub mod life;
+pub mod mana_abilities;
 pub mod permanent;
 pub mod phase;
 pub mod politics;
 pub mod priority;
+pub mod reveal;
+pub mod sacrifice;
+pub mod sandbox;
 pub mod save;
+pub mod scenario;
+pub mod schedule;
+pub mod scripting;
+pub mod scry;
+pub mod search;
+pub mod setup;
 pub mod stack;
 pub mod state;
 pub mod tests;
+pub mod triggers;
+pub mod turn_order;
 pub mod turns;
+pub mod win_conditions;
 pub mod zones;
 
 // Import required types
+use crate::menu::state::starting_new_game;
 use crate::menu::{GameMenuState, StateTransitionContext};
 use crate::player::Player;
 
@@ -29,10 +59,10 @@ pub use priority::{
 };
 pub use save::SaveLoadPlugin;
 pub use stack::{GameStack, StackItemResolvedEvent};
-pub use state::{CheckStateBasedActionsEvent, GameState};
+pub use state::{CheckStateBasedActionsEvent, GameEvent, GameEventLog, GameOverEvent, GameState};
 pub use turns::{
     TurnEndEvent, TurnManager, TurnStartEvent, handle_turn_end, handle_turn_start,
-    register_turn_systems,
+    handle_untap_step, register_turn_systems,
 };
 pub use zones::{EntersBattlefieldEvent, ZoneChangeEvent, ZoneManager};
 
@@ -41,11 +71,13 @@ use crate::game_engine::actions::process_game_actions;
 use crate::game_engine::combat::{
     AssignCombatDamageEvent, AttackerDeclaredEvent, BlockerDeclaredEvent, CombatBeginEvent,
     CombatDamageCompleteEvent, CombatEndEvent, CreatureAttacksEvent, CreatureBlockedEvent,
-    CreatureBlocksEvent, DeclareAttackersStepBeginEvent, DeclareAttackersStepEndEvent,
-    DeclareBlockersStepBeginEvent, DeclareBlockersStepEndEvent, assign_combat_damage_system,
-    declare_attackers_system, declare_blockers_system, end_combat_system,
-    handle_declare_attackers_event, handle_declare_blockers_event, initialize_combat_phase,
-    process_combat_damage_system,
+    CreatureBlocksEvent, DamageAssignmentEvent, DeclareAttackersStepBeginEvent,
+    DeclareAttackersStepEndEvent, DeclareBlockersStepBeginEvent, DeclareBlockersStepEndEvent,
+    NinjutsuEvent, OrderBlockersEvent, apply_melee_bonuses, assign_combat_damage_system,
+    create_myriad_tokens, declare_attackers_system, declare_blockers_system, end_combat_system,
+    end_melee_bonuses, exile_myriad_tokens, flag_combat_violations, handle_blocker_order_events,
+    handle_damage_assignment_events, handle_declare_attackers_event, handle_declare_blockers_event,
+    handle_ninjutsu_events, initialize_combat_phase, process_combat_damage_system,
 };
 use crate::game_engine::commander::{CommandZone, CommandZoneManager};
 use crate::game_engine::phase::{BeginningStep, phase_transition_system};
@@ -53,15 +85,12 @@ use crate::game_engine::politics::{
     ApplyCombatRestrictionEvent, GoadEvent, RemoveCombatRestrictionEvent,
 };
 use crate::game_engine::priority::{priority_passing_system, priority_system};
+use crate::game_engine::schedule::{GameEngineSet, configure_game_engine_sets};
 
 // Game Engine Plugin
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
-/// Custom schedule for fixed timestep game logic updates
-#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
-pub struct FixedGameLogicSet;
-
 /// Condition function to check if the game state is InGame
 pub fn game_state_condition(state: Res<State<GameMenuState>>) -> bool {
     *state.get() == GameMenuState::InGame
@@ -83,42 +112,77 @@ impl Plugin for GameEnginePlugin {
 
         // Initialize essential resources that should always be available
         app.init_resource::<CombatState>()
+            .init_resource::<phase::AutoStops>()
             .init_resource::<GameStack>()
+            .init_resource::<stack::StackResolution>()
             .init_resource::<PrioritySystem>()
-            .init_resource::<GameState>();
+            .init_resource::<GameState>()
+            .init_resource::<win_conditions::WinConditionRules>()
+            .init_resource::<GameEventLog>()
+            .init_resource::<setup::GameSetupConfig>();
 
         // Register all game logic systems in the FixedUpdate schedule
         // This ensures they run at a fixed timestep decoupled from the frame rate
-        // Only run these systems when in the InGame state
+        // Each system belongs to a GameEngineSet, which orders the tick (see the
+        // schedule module). Only run these systems when in the InGame state, and
+        // pause them while a player makes a choice
+        configure_game_engine_sets(app);
         app.add_systems(
             FixedUpdate,
             (
-                // Core game systems
-                phase_transition_system,
-                priority_system,
-                priority_passing_system,
-                stack::stack_resolution_system,
-                state::state_based_actions_system,
-                state::trigger_state_based_actions_system,
-                process_game_actions,
+                (process_game_actions, priority_passing_system).in_set(GameEngineSet::Input),
                 // Turn systems
-                handle_turn_start,
-                handle_turn_end,
+                (
+                    phase_transition_system,
+                    handle_turn_start,
+                    handle_untap_step,
+                    handle_turn_end,
+                )
+                    .chain()
+                    .in_set(GameEngineSet::Turn),
+                stack::stack_resolution_system.in_set(GameEngineSet::Stack),
                 // Combat systems in sequence
-                initialize_combat_phase,
-                handle_declare_attackers_event,
-                declare_attackers_system,
-                handle_declare_blockers_event,
-                declare_blockers_system,
-                assign_combat_damage_system,
-                process_combat_damage_system,
-                end_combat_system,
+                (
+                    initialize_combat_phase,
+                    handle_declare_attackers_event,
+                    declare_attackers_system,
+                    create_myriad_tokens,
+                    handle_declare_blockers_event,
+                    declare_blockers_system,
+                    flag_combat_violations,
+                    apply_melee_bonuses,
+                    handle_ninjutsu_events,
+                    handle_blocker_order_events,
+                    handle_damage_assignment_events,
+                    assign_combat_damage_system,
+                    process_combat_damage_system,
+                    exile_myriad_tokens,
+                    end_combat_system,
+                    end_melee_bonuses,
+                )
+                    .chain()
+                    .in_set(GameEngineSet::Combat),
+                (
+                    state::trigger_state_based_actions_system,
+                    state::state_based_actions_system
+                        .run_if(enforcement::state_based_actions_enabled),
+                )
+                    .chain()
+                    .in_set(GameEngineSet::Sba),
+                priority_system.in_set(GameEngineSet::Priority),
             )
-                .run_if(in_state(GameMenuState::InGame)),
+                .run_if(in_state(GameMenuState::InGame).and(choices::no_pending_choice)),
         );
 
+        // Apply the game state changes made by this tick's game logic
+        app.add_systems(FixedPostUpdate, state::apply_game_events)
+            .add_systems(Update, state::concession_system);
+
         // Register events
         app.add_event::<GameAction>()
+            .add_event::<GameEvent>()
+            .add_event::<GameOverEvent>()
+            .add_event::<win_conditions::WinGameEvent>()
             .add_event::<StackItemResolvedEvent>()
             .add_event::<CheckStateBasedActionsEvent>()
             .add_event::<PlayerEliminatedEvent>()
@@ -142,10 +206,14 @@ impl Plugin for GameEnginePlugin {
             .add_event::<CreatureBlocksEvent>()
             .add_event::<CreatureBlockedEvent>()
             .add_event::<CombatDamageCompleteEvent>()
+            .add_event::<OrderBlockersEvent>()
+            .add_event::<DamageAssignmentEvent>()
+            .add_event::<NinjutsuEvent>()
             // Register priority events
             .add_event::<PassPriorityEvent>()
             .add_event::<ResolveStackItemEvent>()
             .add_event::<NextPhaseEvent>()
+            .add_event::<phase::StepBeganEvent>()
             .add_event::<EffectCounteredEvent>()
             // Register battlefield events
             .add_event::<EntersBattlefieldEvent>()
@@ -157,21 +225,60 @@ impl Plugin for GameEnginePlugin {
         // Add game resources initialization during OnEnter(GameMenuState::InGame)
         app.add_systems(
             OnEnter(GameMenuState::InGame),
-            (setup_players, setup_game_engine.after(setup_players)),
+            (
+                setup_players.run_if(starting_new_game),
+                setup_game_engine.after(setup_players),
+            ),
+        )
+        .add_systems(
+            OnEnter(GameMenuState::MainMenu),
+            stack::clear_stack_resolution,
         );
 
         // Register zone systems
         zones::register_zone_systems(app);
         // Register turn systems
         register_turn_systems(app);
+        // Roll for who goes first as a game starts
+        turn_order::register_turn_order_systems(app);
+        // Pass priority for players with nothing they could do
+        priority::register_auto_pass_systems(app);
+        // Resolve a run of a player's own stack items in one go
+        stack::register_resolve_all_systems(app);
         // Register commander systems
         commander::register_commander_systems(app);
+        // Register cleanup step systems
+        cleanup::register_cleanup_systems(app);
+        // Register scenario mode
+        scenario::register_scenario_systems(app);
+        // Register the queue putting triggered abilities on the stack
+        triggers::register_trigger_systems(app);
+        damage::register_damage_systems(app);
+        life::register_life_systems(app);
+        mana_abilities::register_mana_ability_systems(app);
+        durations::register_duration_systems(app);
+        day_night::register_day_night_systems(app);
+        dungeon::register_dungeon_systems(app);
+        enters_choice::register_enters_choice_systems(app);
+        choices::register_choice_systems(app);
+        sacrifice::register_sacrifice_systems(app);
+        reveal::register_reveal_systems(app);
+        search::register_search_systems(app);
+        scry::register_scry_systems(app);
+        // Trigger and resolve the abilities of cards defined as data
+        scripting::register_scripting_systems(app);
+        ai::register_ai_systems(app);
+        face_down::register_face_down_systems(app);
+        // Check the engine's views of the game agree, in debug builds
+        integrity::register_integrity_checks(app);
 
         // Allow politics systems to register additional systems
         politics::register_politics_systems(app);
 
         app.add_plugins(zones::ZonesPlugin)
-            .add_plugins(permanent::PermanentPlugin);
+            .add_plugins(permanent::PermanentPlugin)
+            .add_plugins(sandbox::SandboxPlugin)
+            .add_plugins(goldfish::GoldfishPlugin);
     }
 }
 
@@ -185,7 +292,10 @@ struct GameEngineResources<'w> {
 }
 
 /// Spawns initial player entities
-fn setup_players(mut commands: Commands) {
+fn setup_players(mut commands: Commands, setup_config: Option<Res<setup::GameSetupConfig>>) {
+    let starting_life = setup_config
+        .map(|config| config.validated().starting_life)
+        .unwrap_or(40);
     info!("Spawning initial players...");
     // Spawn one placeholder player for now
     // TODO: Replace with actual player setup logic (e.g., based on config or lobby)
@@ -193,7 +303,7 @@ fn setup_players(mut commands: Commands) {
         Player {
             player_index: 0,               // Set the player index
             name: "Player 1".to_string(),  // Provide name
-            life: 40,                      // Provide starting life (Commander format)
+            life: starting_life,           // Starting life chosen on the new game screen
             mana_pool: Default::default(), // Provide default mana pool
         },
         Name::new("Player 1"), // Optional: for debugging
@@ -207,6 +317,7 @@ fn setup_game_engine(
     player_query: Query<Entity, With<Player>>,
     context: Res<StateTransitionContext>,
     turn_manager: Option<Res<TurnManager>>,
+    setup_config: Option<Res<setup::GameSetupConfig>>,
     mut resources: GameEngineResources, // Use the SystemParam struct
 ) {
     // Skip initialization if we're coming from the pause menu and already have a turn manager
@@ -250,6 +361,21 @@ fn setup_game_engine(
     *resources.priority_system = PrioritySystem::default();
     *resources.game_state = GameState::default();
 
+    // Apply the options chosen on the new game screen
+    commands.insert_resource(
+        setup_config
+            .as_ref()
+            .map(|config| config.rules_enforcement)
+            .unwrap_or_default(),
+    );
+    if let Some(setup_config) = setup_config {
+        setup_config.apply_to_game_state(&mut resources.game_state);
+    }
+
+    // The reset state becomes the baseline of the event log, and the turn order
+    // its first event
+    commands.send_event(GameEvent::TurnOrderSet { players });
+
     info!("Game engine resources initialized successfully.");
 }
 
@@ -259,38 +385,70 @@ pub fn register_game_engine(app: &mut App) {
     // We'll use these functions which already register the systems for each module
     turns::register_turn_systems(app);
     zones::register_zone_systems(app);
+    app.add_event::<zones::DrawCardEvent>();
+
+    // Add the game state and the reducer that changes it
+    app.init_resource::<GameState>()
+        .init_resource::<win_conditions::WinConditionRules>()
+        .init_resource::<GameEventLog>()
+        .add_event::<GameEvent>()
+        .add_event::<GameOverEvent>()
+        .add_event::<win_conditions::WinGameEvent>()
+        .add_systems(FixedPostUpdate, state::apply_game_events);
 
     // Add the stack system
     app.init_resource::<GameStack>();
 
     // Add the priority system
-    app.init_resource::<PrioritySystem>();
+    app.init_resource::<PrioritySystem>()
+        .init_resource::<phase::AutoStops>()
+        .add_event::<phase::StepBeganEvent>();
 
     // Add all game systems to FixedUpdate schedule for consistent timing
+    configure_game_engine_sets(app);
     app.add_systems(
         FixedUpdate,
         (
-            // Core game systems
-            phase_transition_system,
-            priority_system,
-            priority_passing_system,
-            stack::stack_resolution_system,
-            state::state_based_actions_system,
-            state::trigger_state_based_actions_system,
-            process_game_actions,
+            (process_game_actions, priority_passing_system).in_set(GameEngineSet::Input),
+            phase_transition_system.in_set(GameEngineSet::Turn),
+            stack::stack_resolution_system.in_set(GameEngineSet::Stack),
             // Combat systems
-            initialize_combat_phase,
-            handle_declare_attackers_event,
-            declare_attackers_system,
-            handle_declare_blockers_event,
-            declare_blockers_system,
-            assign_combat_damage_system,
-            process_combat_damage_system,
-            end_combat_system,
+            (
+                initialize_combat_phase,
+                handle_declare_attackers_event,
+                declare_attackers_system,
+                create_myriad_tokens,
+                handle_declare_blockers_event,
+                declare_blockers_system,
+                flag_combat_violations,
+                apply_melee_bonuses,
+                handle_ninjutsu_events,
+                handle_blocker_order_events,
+                handle_damage_assignment_events,
+                assign_combat_damage_system,
+                process_combat_damage_system,
+                exile_myriad_tokens,
+                end_combat_system,
+                end_melee_bonuses,
+            )
+                .chain()
+                .in_set(GameEngineSet::Combat),
+            (
+                state::trigger_state_based_actions_system,
+                state::state_based_actions_system.run_if(enforcement::state_based_actions_enabled),
+            )
+                .chain()
+                .in_set(GameEngineSet::Sba),
+            priority_system.in_set(GameEngineSet::Priority),
         )
             .run_if(in_state(GameMenuState::InGame)),
     );
 
     // Register commander systems
     commander::register_commander_systems(app);
+    // Register cleanup step systems
+    cleanup::register_cleanup_systems(app);
+    // Register scenario mode
+    scenario::register_scenario_systems(app);
+    life::register_life_systems(app);
 }
@@ -6,6 +6,8 @@ mod systems;
 
 use bevy::prelude::*;
 
+use crate::game_engine::schedule::GameEngineSet;
+
 pub use components::*;
 pub use owner::*;
 pub use systems::*;
@@ -19,6 +21,9 @@ impl Plugin for PermanentPlugin {
             .register_type::<PermanentController>()
             .register_type::<PermanentOwner>()
             .register_type::<PermanentState>()
-            .add_systems(FixedUpdate, update_permanent_state);
+            .add_systems(
+                FixedUpdate,
+                update_permanent_state.in_set(GameEngineSet::Turn),
+            );
     }
 }
@@ -1,48 +1,107 @@
+use crate::cards::Card;
+use crate::game_engine::cleanup::CleanupState;
+use crate::game_engine::combat::{AssignCombatDamageEvent, CombatState};
 use crate::game_engine::priority::NextPhaseEvent;
 use crate::game_engine::priority::PrioritySystem;
-use crate::game_engine::state::GameState;
+use crate::game_engine::setup::GameSetupConfig;
+use crate::game_engine::state::GameEvent;
 use crate::game_engine::turns::TurnManager;
+use crate::game_engine::zones::DrawCardEvent;
 use crate::player::Player;
 use bevy::prelude::*;
 
-use super::types::{BeginningStep, CombatStep, EndingStep, Phase, PostcombatStep, PrecombatStep};
+use super::types::{
+    BeginningStep, CombatStep, EndingStep, ExtraPhases, Phase, PostcombatStep, PrecombatStep,
+    StepBeganEvent,
+};
 
 /// System for handling phase transitions
+///
+/// Each step that begins is announced with a [`StepBeganEvent`]. The first strike
+/// damage step is skipped when no creature in combat has first strike.
+#[allow(clippy::too_many_arguments)]
 pub fn phase_transition_system(
     mut commands: Commands,
     mut phase: ResMut<Phase>,
     mut turn_manager: ResMut<TurnManager>,
-    mut game_state: ResMut<GameState>,
     mut priority_system: ResMut<PrioritySystem>,
     mut next_phase_events: EventReader<NextPhaseEvent>,
     player_query: Query<Entity, With<Player>>,
+    mut cleanup_state: Option<ResMut<CleanupState>>,
+    mut extra_phases: Option<ResMut<ExtraPhases>>,
+    combat_state: Option<Res<CombatState>>,
+    cards: Query<&Card>,
+    setup_config: Option<Res<GameSetupConfig>>,
 ) {
     for _ in next_phase_events.read() {
+        // If players received priority during cleanup, another cleanup step follows
+        if let Some(cleanup_state) = cleanup_state.as_deref_mut() {
+            if *phase == Phase::Ending(EndingStep::Cleanup) && cleanup_state.grant_priority {
+                info!("Starting another cleanup step");
+                cleanup_state.reset();
+                priority_system.last_processed_phase = None;
+                priority_system.reset_passing_status();
+                continue;
+            }
+        }
+
+        let first_strike = combat_state
+            .as_deref()
+            .is_some_and(|combat_state| combat_state.has_first_strike_step(&cards));
+        let player_count = turn_manager.player_order.len();
+        let first_player_skips_draw = match setup_config.as_deref() {
+            Some(config) => config.first_player_skips_draw(player_count),
+            None => GameSetupConfig::default().first_player_skips_draw(player_count),
+        };
         advance_phase(
             &mut commands,
             &mut phase,
             &mut turn_manager,
-            &mut game_state,
             &mut priority_system,
             &player_query,
+            extra_phases.as_deref_mut(),
+            first_strike,
+            first_player_skips_draw,
         );
     }
 }
 
 /// Helper function to advance to the next phase
+#[allow(clippy::too_many_arguments)]
 fn advance_phase(
-    _commands: &mut Commands,
+    commands: &mut Commands,
     phase: &mut Phase,
     turn_manager: &mut TurnManager,
-    game_state: &mut GameState,
     priority_system: &mut PrioritySystem,
     player_query: &Query<Entity, With<Player>>,
+    extra_phases: Option<&mut ExtraPhases>,
+    first_strike: bool,
+    first_player_skips_draw: bool,
 ) {
     // Store the old phase for reference
     let old_phase = *phase;
 
-    // Advance to the next phase
-    *phase = phase.next();
+    // Advance to the next phase, playing any phases added by effects first
+    *phase = match extra_phases {
+        Some(extra_phases) => {
+            let next = extra_phases.next_step(old_phase);
+            // Added phases belong to the turn they were created in
+            if old_phase == Phase::Ending(EndingStep::Cleanup) {
+                extra_phases.clear();
+            }
+            next
+        }
+        None => phase.next(),
+    };
+    if *phase == Phase::Combat(CombatStep::FirstStrikeDamage) && !first_strike {
+        *phase = phase.next();
+    }
+
+    // Repeating a step (e.g. an additional main phase right after a main phase)
+    // must be processed again by the priority system
+    if *phase == old_phase {
+        priority_system.last_processed_phase = None;
+    }
 
     // Handle phase-specific logic
     match *phase {
@@ -53,39 +112,62 @@ fn advance_phase(
                 // Advance to the next player's turn
                 turn_manager.advance_turn();
 
-                // Update active player
-                game_state.active_player = turn_manager.active_player;
-
-                // Reset per-turn state tracking
-                game_state.reset_turn_tracking();
+                // Update the active player and reset per-turn state tracking
+                commands.send_event(GameEvent::TurnStarted {
+                    player: turn_manager.active_player,
+                    turn_number: turn_manager.turn_number,
+                });
 
                 // Reset priority to the new active player
                 let players: Vec<Entity> = player_query.iter().collect();
-                priority_system.initialize(&players, game_state.active_player);
+                priority_system.initialize(&players, turn_manager.active_player);
 
                 info!(
                     "Turn {}: Player {:?}'s turn",
-                    turn_manager.turn_number, game_state.active_player
+                    turn_manager.turn_number, turn_manager.active_player
                 );
             }
         }
+        Phase::Beginning(BeginningStep::Draw) => {
+            // The player going first may skip their first draw, depending on
+            // the player count and the game's options
+            let skips_draw = turn_manager.turn_number == 1
+                && turn_manager.active_player_index == 0
+                && !turn_manager.current_turn_is_extra
+                && first_player_skips_draw;
+            if !skips_draw {
+                commands.send_event(DrawCardEvent::single(turn_manager.active_player));
+            }
+        }
         Phase::Precombat(PrecombatStep::Main) => {
             // First main phase begins - reset main phase tracking
-            game_state.main_phase_action_taken = false;
+            commands.send_event(GameEvent::MainPhaseStarted);
         }
         Phase::Combat(CombatStep::Beginning) => {
             // Beginning of combat phase
         }
+        Phase::Combat(CombatStep::FirstStrikeDamage) => {
+            // Only creatures with first strike or double strike deal damage now
+            commands.send_event(AssignCombatDamageEvent {
+                is_first_strike: true,
+            });
+        }
+        Phase::Combat(CombatStep::CombatDamage) => {
+            // Attackers and blockers deal their damage as the step begins
+            commands.send_event(AssignCombatDamageEvent {
+                is_first_strike: false,
+            });
+        }
         Phase::Postcombat(PostcombatStep::Main) => {
             // Second main phase begins - reset main phase tracking
-            game_state.main_phase_action_taken = false;
+            commands.send_event(GameEvent::MainPhaseStarted);
         }
         Phase::Ending(EndingStep::End) => {
             // End step - trigger "at end of turn" effects
         }
         Phase::Ending(EndingStep::Cleanup) => {
-            // Cleanup step - discard to hand size, remove damage, etc.
-            // This is typically the last step before a new turn
+            // Cleanup step - discarding to hand size and removing damage are
+            // handled by the cleanup module
         }
         _ => {}
     }
@@ -111,6 +193,7 @@ fn advance_phase(
                 CombatStep::Beginning => "Beginning",
                 CombatStep::DeclareAttackers => "Declare Attackers",
                 CombatStep::DeclareBlockers => "Declare Blockers",
+                CombatStep::FirstStrikeDamage => "First Strike Damage",
                 CombatStep::CombatDamage => "Combat Damage",
                 CombatStep::End => "End",
             };
@@ -134,8 +217,13 @@ fn advance_phase(
         }
     }
 
+    commands.send_event(StepBeganEvent {
+        phase: *phase,
+        active_player: turn_manager.active_player,
+    });
+
     // Reset priority for the new phase
     let players: Vec<Entity> = player_query.iter().collect();
     priority_system.reset_passing_status();
-    priority_system.reset_after_stack_action(&players, game_state.active_player);
+    priority_system.reset_after_stack_action(&players, turn_manager.active_player);
 }
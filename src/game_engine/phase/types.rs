@@ -1,7 +1,11 @@
 use bevy::prelude::*;
+use std::collections::{HashMap, HashSet, VecDeque};
 
-/// The main phases of a Magic: The Gathering turn
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Resource)]
+/// The current step of a Magic: The Gathering turn, by phase
+///
+/// This is the only phase model: the turn structure, the HUD and card triggers
+/// such as "at the beginning of your upkeep" all use it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Resource, Reflect)]
 pub enum Phase {
     Beginning(BeginningStep),
     Precombat(PrecombatStep),
@@ -11,7 +15,7 @@ pub enum Phase {
 }
 
 /// Steps within the Beginning phase
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
 pub enum BeginningStep {
     Untap,
     Upkeep,
@@ -19,34 +23,158 @@ pub enum BeginningStep {
 }
 
 /// Steps within the Precombat Main phase
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
 pub enum PrecombatStep {
     Main,
 }
 
 /// Steps within the Combat phase
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
 pub enum CombatStep {
     Beginning,
     DeclareAttackers,
     DeclareBlockers,
+    /// Only happens when an attacking or blocking creature has first strike or
+    /// double strike
+    FirstStrikeDamage,
     CombatDamage,
     End,
 }
 
 /// Steps within the Postcombat Main phase
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
 pub enum PostcombatStep {
     Main,
 }
 
 /// Steps within the Ending phase
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
 pub enum EndingStep {
     End,
     Cleanup,
 }
 
+/// Steps where priority should not be passed automatically, for each player
+///
+/// Steps that normally auto-pass (see [`Phase::allows_actions`]) stop and wait for
+/// a player when they are in the player's set, and players with
+/// [`AutoPass`](crate::game_engine::priority::AutoPass) always get priority in
+/// them.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct AutoStops {
+    steps: HashMap<Entity, HashSet<Phase>>,
+}
+
+impl AutoStops {
+    /// Whether the game should stop at a step for a player
+    pub fn contains(&self, player: Entity, phase: Phase) -> bool {
+        self.steps
+            .get(&player)
+            .is_some_and(|steps| steps.contains(&phase))
+    }
+
+    /// Toggle a player's auto-stop for a step, returning whether it is now set
+    pub fn toggle(&mut self, player: Entity, phase: Phase) -> bool {
+        let steps = self.steps.entry(player).or_default();
+        if steps.remove(&phase) {
+            false
+        } else {
+            steps.insert(phase);
+            true
+        }
+    }
+
+    /// Remove every auto-stop
+    pub fn clear(&mut self) {
+        self.steps.clear();
+    }
+}
+
+/// A phase added to the turn by an effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExtraPhase {
+    /// An additional combat phase
+    Combat,
+    /// An additional main phase
+    Main,
+}
+
+impl ExtraPhase {
+    /// The first step of the added phase
+    ///
+    /// Additional main phases are postcombat main phases.
+    pub fn first_step(&self) -> Phase {
+        match self {
+            ExtraPhase::Combat => Phase::Combat(CombatStep::Beginning),
+            ExtraPhase::Main => Phase::Postcombat(PostcombatStep::Main),
+        }
+    }
+}
+
+/// Phases waiting to be inserted into the current turn
+///
+/// Pending phases begin when the current phase ends. Once they have all been
+/// played, the turn continues with the phase that would normally have followed.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExtraPhases {
+    /// Phases still to be inserted, in order
+    pub pending: VecDeque<ExtraPhase>,
+    /// Where the normal turn resumes once the added phases are done
+    pub resume_at: Option<Phase>,
+}
+
+impl ExtraPhases {
+    /// Add phases to begin after the current phase, in the given order
+    ///
+    /// "An additional combat phase followed by an additional main phase" is
+    /// `add_after_current(&[ExtraPhase::Combat, ExtraPhase::Main])`.
+    pub fn add_after_current(&mut self, phases: &[ExtraPhase]) {
+        for phase in phases.iter().rev() {
+            self.pending.push_front(*phase);
+        }
+    }
+
+    /// Whether the turn is currently in an added phase
+    pub fn in_extra_phase(&self) -> bool {
+        self.resume_at.is_some()
+    }
+
+    /// The step that follows `current`, taking added phases into account
+    pub fn next_step(&mut self, current: Phase) -> Phase {
+        if !current.ends_phase() || current == Phase::Ending(EndingStep::Cleanup) {
+            return current.next();
+        }
+
+        if let Some(extra) = self.pending.pop_front() {
+            if self.resume_at.is_none() {
+                self.resume_at = Some(current.next());
+            }
+            return extra.first_step();
+        }
+
+        self.resume_at.take().unwrap_or_else(|| current.next())
+    }
+
+    /// Forget any added phases, e.g. when a new turn begins
+    pub fn clear(&mut self) {
+        self.pending.clear();
+        self.resume_at = None;
+    }
+}
+
+/// A step of the turn began
+///
+/// This is where "at the beginning of ..." triggers, see
+/// [`TriggerCondition::BeginningOfPhase`](crate::cards::abilities::TriggerCondition::BeginningOfPhase),
+/// are checked. Whether a trigger cares about "your" step or "each" step is up
+/// to the ability, so the active player is included.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StepBeganEvent {
+    pub phase: Phase,
+    /// The player whose turn it is
+    pub active_player: Entity,
+}
+
 /// Resource representing the current phase of the game
 #[derive(Resource, Debug, Clone, Copy, Default)]
 pub struct CurrentPhase(pub Phase);
@@ -65,6 +193,58 @@ pub const MAIN1: Phase = Phase::Precombat(PrecombatStep::Main);
 pub const MAIN2: Phase = Phase::Postcombat(PostcombatStep::Main);
 
 impl Phase {
+    /// Every step of a turn, in order
+    pub const ALL: [Phase; 13] = [
+        Phase::Beginning(BeginningStep::Untap),
+        Phase::Beginning(BeginningStep::Upkeep),
+        Phase::Beginning(BeginningStep::Draw),
+        Phase::Precombat(PrecombatStep::Main),
+        Phase::Combat(CombatStep::Beginning),
+        Phase::Combat(CombatStep::DeclareAttackers),
+        Phase::Combat(CombatStep::DeclareBlockers),
+        Phase::Combat(CombatStep::FirstStrikeDamage),
+        Phase::Combat(CombatStep::CombatDamage),
+        Phase::Combat(CombatStep::End),
+        Phase::Postcombat(PostcombatStep::Main),
+        Phase::Ending(EndingStep::End),
+        Phase::Ending(EndingStep::Cleanup),
+    ];
+
+    /// Position of this step within the turn
+    pub fn step_index(&self) -> usize {
+        Phase::ALL
+            .iter()
+            .position(|phase| phase == self)
+            .unwrap_or_default()
+    }
+
+    /// Short name of the step for display
+    pub fn label(&self) -> &'static str {
+        match self {
+            Phase::Beginning(BeginningStep::Untap) => "Untap",
+            Phase::Beginning(BeginningStep::Upkeep) => "Upkeep",
+            Phase::Beginning(BeginningStep::Draw) => "Draw",
+            Phase::Precombat(PrecombatStep::Main) => "Main 1",
+            Phase::Combat(CombatStep::Beginning) => "Combat",
+            Phase::Combat(CombatStep::DeclareAttackers) => "Attackers",
+            Phase::Combat(CombatStep::DeclareBlockers) => "Blockers",
+            Phase::Combat(CombatStep::FirstStrikeDamage) => "First Strike",
+            Phase::Combat(CombatStep::CombatDamage) => "Damage",
+            Phase::Combat(CombatStep::End) => "End Combat",
+            Phase::Postcombat(PostcombatStep::Main) => "Main 2",
+            Phase::Ending(EndingStep::End) => "End",
+            Phase::Ending(EndingStep::Cleanup) => "Cleanup",
+        }
+    }
+
+    /// Step with the given [`label`](Phase::label), ignoring case
+    pub fn from_label(label: &str) -> Option<Phase> {
+        let label = label.trim();
+        Phase::ALL
+            .into_iter()
+            .find(|phase| phase.label().eq_ignore_ascii_case(label))
+    }
+
     /// Determine if the phase or step should auto-pass priority if the stack is empty
     #[allow(dead_code)]
     pub fn auto_pass_if_empty(&self) -> bool {
@@ -97,6 +277,18 @@ impl Phase {
         }
     }
 
+    /// Whether this is the last step of its phase
+    pub fn ends_phase(&self) -> bool {
+        matches!(
+            self,
+            Phase::Beginning(BeginningStep::Draw)
+                | Phase::Precombat(PrecombatStep::Main)
+                | Phase::Combat(CombatStep::End)
+                | Phase::Postcombat(PostcombatStep::Main)
+                | Phase::Ending(EndingStep::Cleanup)
+        )
+    }
+
     /// Get the next phase or step in the sequence
     pub fn next(&self) -> Self {
         match self {
@@ -108,7 +300,10 @@ impl Phase {
             Phase::Combat(CombatStep::DeclareAttackers) => {
                 Phase::Combat(CombatStep::DeclareBlockers)
             }
-            Phase::Combat(CombatStep::DeclareBlockers) => Phase::Combat(CombatStep::CombatDamage),
+            Phase::Combat(CombatStep::DeclareBlockers) => {
+                Phase::Combat(CombatStep::FirstStrikeDamage)
+            }
+            Phase::Combat(CombatStep::FirstStrikeDamage) => Phase::Combat(CombatStep::CombatDamage),
             Phase::Combat(CombatStep::CombatDamage) => Phase::Combat(CombatStep::End),
             Phase::Combat(CombatStep::End) => Phase::Postcombat(PostcombatStep::Main),
             Phase::Postcombat(PostcombatStep::Main) => Phase::Ending(EndingStep::End),
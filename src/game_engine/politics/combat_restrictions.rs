@@ -45,20 +45,18 @@ pub fn combat_restrictions_system(
                     }
                 }
                 CombatRestriction::CannotBlock => {
-                    // This is checked directly in the declare_blockers system
-                    // Implementation would go in that system
-                    info!(
-                        "Creature {:?} cannot block due to political effects",
-                        creature
-                    );
+                    // Checked when blockers are declared
+                    combat_state.cannot_block.insert(*creature);
                 }
                 CombatRestriction::CannotBlockAttacksAgainst(player) => {
-                    // This would need to be checked in the declare_blockers system
-                    // Implementation would go in that system
-                    info!(
-                        "Creature {:?} cannot block attacks against player {:?}",
-                        creature, player
-                    );
+                    // Checked when blockers are declared
+                    let players = combat_state
+                        .cannot_block_attacks_against
+                        .entry(*creature)
+                        .or_default();
+                    if !players.contains(player) {
+                        players.push(*player);
+                    }
                 }
             }
         }
@@ -0,0 +1,92 @@
+use super::{MonarchChangedEvent, PoliticsSystem};
+use crate::game_engine::commander::CombatDamageEvent;
+use crate::game_engine::dungeon::{VentureEvent, VentureInto};
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::phase::{BeginningStep, Phase, StepBeganEvent};
+use bevy::prelude::*;
+
+/// Sent when a player takes the initiative
+///
+/// A player can take the initiative while they already have it, and ventures
+/// into the Undercity either way (rule 725.2).
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TakeInitiativeEvent {
+    /// The player taking the initiative
+    pub player: Entity,
+}
+
+/// Give the initiative to the players taking it, who venture into the Undercity
+pub fn take_initiative_system(
+    mut politics: ResMut<PoliticsSystem>,
+    mut events: EventReader<TakeInitiativeEvent>,
+    mut ventures: EventWriter<VentureEvent>,
+) {
+    for event in events.read() {
+        if politics.initiative_holder != Some(event.player) {
+            info!("Player {:?} takes the initiative", event.player);
+            politics.initiative_holder = Some(event.player);
+        }
+        ventures.write(VentureEvent {
+            player: event.player,
+            into: VentureInto::Undercity,
+        });
+    }
+}
+
+/// At the beginning of the upkeep of the player with the initiative, they venture
+/// into the Undercity
+pub fn initiative_upkeep_venture(
+    politics: Res<PoliticsSystem>,
+    mut steps: EventReader<StepBeganEvent>,
+    mut ventures: EventWriter<VentureEvent>,
+) {
+    for step in steps.read() {
+        if step.phase == Phase::Beginning(BeginningStep::Upkeep)
+            && politics.initiative_holder == Some(step.active_player)
+        {
+            ventures.write(VentureEvent {
+                player: step.active_player,
+                into: VentureInto::Undercity,
+            });
+        }
+    }
+}
+
+/// Pass the monarch and the initiative to players whose creatures deal combat
+/// damage to the player holding them (rules 724.2 and 725.2)
+///
+/// However many of a player's creatures connect, the designation changes hands
+/// once; damage dealt to a player after they lost it doesn't take it again.
+pub fn transfer_politics_on_combat_damage(
+    politics: Res<PoliticsSystem>,
+    mut damage: EventReader<CombatDamageEvent>,
+    sources: Query<&PermanentController>,
+    mut monarch_events: EventWriter<MonarchChangedEvent>,
+    mut initiative_events: EventWriter<TakeInitiativeEvent>,
+) {
+    let mut monarch = politics.monarch;
+    let mut initiative_holder = politics.initiative_holder;
+
+    for event in damage.read() {
+        if !event.is_combat_damage || event.damage == 0 {
+            continue;
+        }
+        let Ok(controller) = sources.get(event.source) else {
+            continue;
+        };
+        let attacker = controller.player;
+
+        if monarch == Some(event.target) && attacker != event.target {
+            monarch_events.write(MonarchChangedEvent {
+                new_monarch: attacker,
+                previous_monarch: monarch,
+                source: Some(event.source),
+            });
+            monarch = Some(attacker);
+        }
+        if initiative_holder == Some(event.target) && attacker != event.target {
+            initiative_events.write(TakeInitiativeEvent { player: attacker });
+            initiative_holder = Some(attacker);
+        }
+    }
+}
@@ -1,6 +1,7 @@
 mod combat_restrictions;
 mod deals;
 mod goad;
+mod initiative;
 mod monarch;
 pub mod types;
 mod voting;
@@ -12,6 +13,7 @@ use uuid::Uuid;
 use crate::game_engine::game_state_condition;
 
 pub use deals::*;
+pub use initiative::*;
 pub use monarch::*;
 pub use types::*;
 pub use voting::*;
@@ -30,7 +32,6 @@ pub struct PoliticsSystem {
     pub monarch: Option<Entity>,
 
     /// The player who currently has the initiative
-    #[allow(dead_code)]
     pub initiative_holder: Option<Entity>,
 
     /// Tracks goad effects on creatures
@@ -100,7 +101,6 @@ pub struct VoteCastEvent {
 #[derive(Event)]
 pub struct VoteCompletedEvent {
     /// The vote ID
-    #[allow(dead_code)]
     pub vote_id: Uuid,
 
     /// The winning choice
@@ -155,10 +155,18 @@ pub fn register_politics_systems(app: &mut App) {
         .add_event::<DealProposedEvent>()
         .add_event::<DealResponseEvent>()
         .add_event::<DealBrokenEvent>()
+        .add_event::<TakeInitiativeEvent>()
         .add_systems(
             Update,
             (
-                monarch_system,
+                (
+                    transfer_politics_on_combat_damage,
+                    monarch_system,
+                    take_initiative_system,
+                )
+                    .chain(),
+                monarch_end_step_draw,
+                initiative_upkeep_venture,
                 voting_system,
                 goad_system,
                 deal_system,
@@ -168,3 +176,6 @@ pub fn register_politics_systems(app: &mut App) {
                 .run_if(game_state_condition),
         );
 }
+
+#[cfg(test)]
+mod tests;
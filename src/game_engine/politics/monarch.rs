@@ -1,30 +1,19 @@
 use super::MonarchChangedEvent;
 use super::PoliticsSystem;
-use crate::game_engine::Phase;
-use crate::game_engine::state::GameState;
-use crate::game_engine::turns::TurnManager;
+use crate::game_engine::phase::{EndingStep, Phase, StepBeganEvent};
+use crate::game_engine::zones::DrawCardEvent;
 use bevy::prelude::*;
 
 /// System to handle the monarch mechanic
 pub fn monarch_system(
-    _commands: Commands,
     mut politics: ResMut<PoliticsSystem>,
     mut monarch_events: EventReader<MonarchChangedEvent>,
-    _game_state: Res<GameState>,
-    turn_manager: Res<TurnManager>,
-    current_phase: Res<Phase>,
 ) {
     // Process monarch change events
     for event in monarch_events.read() {
-        // Store previous monarch for reference (even though currently unused)
-        let _previous = politics.monarch;
-
         // Update the current monarch
         politics.monarch = Some(event.new_monarch);
 
-        // TODO: Implement card draw trigger for when a player becomes monarch
-        // This would be implemented when cards with monarch effects are added
-
         info!("Player {:?} has become the monarch", event.new_monarch);
 
         // Process previous_monarch for monarchy change effects
@@ -39,21 +28,23 @@ pub fn monarch_system(
             info!("Monarchy changed due to source: {:?}", source);
         }
     }
+}
 
-    // At the end of a monarch's turn, they draw a card
-    if let Some(monarch) = politics.monarch {
-        // Check if it's the end phase
-        let is_end_phase = match *current_phase {
-            Phase::Ending(_) => true,
-            _ => false,
-        };
-
-        if monarch == turn_manager.active_player && is_end_phase {
-            // TODO: Implement card draw effect through a proper event system
-            info!("Monarch draws a card at end of turn");
-
-            // This will be replaced with an actual card draw event when implemented
-            // commands.spawn(DrawCardEvent { player: monarch, amount: 1 });
+/// At the beginning of the monarch's end step, they draw a card (rule 724.2)
+pub fn monarch_end_step_draw(
+    politics: Res<PoliticsSystem>,
+    mut steps: EventReader<StepBeganEvent>,
+    mut draws: EventWriter<DrawCardEvent>,
+) {
+    for step in steps.read() {
+        if step.phase == Phase::Ending(EndingStep::End)
+            && politics.monarch == Some(step.active_player)
+        {
+            info!(
+                "Monarch {:?} draws a card at end of turn",
+                step.active_player
+            );
+            draws.write(DrawCardEvent::single(step.active_player));
         }
     }
 }
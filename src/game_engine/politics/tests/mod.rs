@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+
+use crate::game_engine::commander::CombatDamageEvent;
+use crate::game_engine::dungeon::VentureEvent;
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::phase::{BeginningStep, EndingStep, Phase, StepBeganEvent};
+use crate::game_engine::politics::{
+    MonarchChangedEvent, PoliticsSystem, TakeInitiativeEvent, initiative_upkeep_venture,
+    monarch_end_step_draw, monarch_system, take_initiative_system,
+    transfer_politics_on_combat_damage,
+};
+use crate::game_engine::zones::DrawCardEvent;
+
+fn politics_app() -> (App, [Entity; 3]) {
+    let mut app = App::new();
+    app.add_event::<CombatDamageEvent>()
+        .add_event::<MonarchChangedEvent>()
+        .add_event::<TakeInitiativeEvent>()
+        .add_event::<VentureEvent>()
+        .add_event::<StepBeganEvent>()
+        .add_event::<DrawCardEvent>()
+        .init_resource::<PoliticsSystem>()
+        .add_systems(
+            Update,
+            (
+                (
+                    transfer_politics_on_combat_damage,
+                    monarch_system,
+                    take_initiative_system,
+                )
+                    .chain(),
+                monarch_end_step_draw,
+                initiative_upkeep_venture,
+            ),
+        );
+    let players = [(); 3].map(|_| app.world_mut().spawn_empty().id());
+    (app, players)
+}
+
+fn creature(app: &mut App, controller: Entity) -> Entity {
+    app.world_mut()
+        .spawn(PermanentController::new(controller))
+        .id()
+}
+
+fn combat_damage(source: Entity, target: Entity) -> CombatDamageEvent {
+    CombatDamageEvent {
+        source,
+        target,
+        damage: 2,
+        is_combat_damage: true,
+        source_is_commander: false,
+    }
+}
+
+fn ventures(app: &mut App) -> Vec<Entity> {
+    app.world_mut()
+        .resource_mut::<Events<VentureEvent>>()
+        .drain()
+        .map(|event| event.player)
+        .collect()
+}
+
+#[test]
+fn test_taking_the_initiative_ventures() {
+    let (mut app, [alice, bob, _]) = politics_app();
+
+    app.world_mut()
+        .send_event(TakeInitiativeEvent { player: alice });
+    app.update();
+    assert_eq!(
+        app.world().resource::<PoliticsSystem>().initiative_holder,
+        Some(alice)
+    );
+    assert_eq!(ventures(&mut app), vec![alice]);
+
+    // Only the holder's upkeep ventures
+    for active_player in [bob, alice] {
+        app.world_mut().send_event(StepBeganEvent {
+            phase: Phase::Beginning(BeginningStep::Upkeep),
+            active_player,
+        });
+    }
+    app.update();
+    assert_eq!(ventures(&mut app), vec![alice]);
+}
+
+#[test]
+fn test_combat_damage_takes_the_monarch_and_the_initiative() {
+    let (mut app, [alice, bob, carol]) = politics_app();
+    {
+        let mut politics = app.world_mut().resource_mut::<PoliticsSystem>();
+        politics.monarch = Some(alice);
+        politics.initiative_holder = Some(alice);
+    }
+    let bob_creatures = [creature(&mut app, bob), creature(&mut app, bob)];
+    let carol_creature = creature(&mut app, carol);
+
+    // Noncombat damage doesn't count
+    let mut burn = combat_damage(carol_creature, alice);
+    burn.is_combat_damage = false;
+    app.world_mut().send_event(burn);
+    // Both of Bob's creatures connect, then Carol's hits Alice, who no longer has either
+    for source in bob_creatures {
+        app.world_mut().send_event(combat_damage(source, alice));
+    }
+    app.world_mut()
+        .send_event(combat_damage(carol_creature, alice));
+    app.update();
+
+    let politics = app.world().resource::<PoliticsSystem>();
+    assert_eq!(politics.monarch, Some(bob));
+    assert_eq!(politics.initiative_holder, Some(bob));
+    assert_eq!(ventures(&mut app), vec![bob]);
+}
+
+#[test]
+fn test_monarch_draws_at_their_end_step() {
+    let (mut app, [alice, bob, _]) = politics_app();
+    app.world_mut().resource_mut::<PoliticsSystem>().monarch = Some(alice);
+
+    for (phase, active_player) in [
+        (Phase::Ending(EndingStep::End), bob),
+        (Phase::Ending(EndingStep::Cleanup), alice),
+        (Phase::Ending(EndingStep::End), alice),
+    ] {
+        app.world_mut().send_event(StepBeganEvent {
+            phase,
+            active_player,
+        });
+    }
+    app.update();
+
+    let draws: Vec<(Entity, u32)> = app
+        .world_mut()
+        .resource_mut::<Events<DrawCardEvent>>()
+        .drain()
+        .map(|event| (event.player, event.count))
+        .collect();
+    assert_eq!(draws, vec![(alice, 1)]);
+}
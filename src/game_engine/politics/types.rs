@@ -1,5 +1,6 @@
+use bevy::platform::time::Instant;
 use bevy::prelude::*;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 use uuid::Uuid;
 
 /// Structure representing a vote in progress
@@ -427,7 +428,6 @@ pub struct GoadEffect {
 
 impl GoadEffect {
     /// Creates a new GoadEffect builder
-    /// TODO: Implement goad mechanics in combat phase
     #[allow(dead_code)]
     pub fn builder(target: Entity, source: Entity) -> GoadEffectBuilder {
         GoadEffectBuilder::new(target, source)
@@ -94,6 +94,28 @@ impl PoliticsSystem {
         false
     }
 
+    /// Weighted number of votes cast for each choice, keyed by choice id
+    pub fn vote_counts(&self) -> HashMap<usize, u32> {
+        let mut vote_counts: HashMap<usize, u32> = HashMap::new();
+
+        for (player, choice) in &self.votes_cast {
+            let weight = self.vote_weights.get(player).copied().unwrap_or(1);
+            *vote_counts.entry(choice.id).or_insert(0) += weight;
+        }
+
+        vote_counts
+    }
+
+    /// The first eligible voter who hasn't voted yet in the active vote
+    pub fn next_voter(&self) -> Option<Entity> {
+        self.active_vote.as_ref().and_then(|vote| {
+            vote.eligible_voters
+                .iter()
+                .copied()
+                .find(|player| !self.votes_cast.contains_key(player))
+        })
+    }
+
     /// Tally votes and determine the winner
     pub fn tally_votes(&self) -> Option<(VoteChoice, u32)> {
         if self.votes_cast.is_empty() {
@@ -101,12 +123,7 @@ impl PoliticsSystem {
         }
 
         // Count votes for each choice
-        let mut vote_counts: HashMap<usize, u32> = HashMap::new();
-
-        for (player, choice) in &self.votes_cast {
-            let weight = self.vote_weights.get(player).copied().unwrap_or(1);
-            *vote_counts.entry(choice.id).or_insert(0) += weight;
-        }
+        let vote_counts = self.vote_counts();
 
         // Find the choice with the most votes
         let mut max_votes = 0;
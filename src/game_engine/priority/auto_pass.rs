@@ -0,0 +1,178 @@
+//! Passing priority for players with nothing they could do
+//!
+//! A player with [`AutoPass`] has priority passed for them whenever they have no
+//! legal instant-speed action: no instant or flash spell in hand and no
+//! activated ability they could afford with the mana in their pool and their
+//! untapped mana sources. They still get priority in steps they've set an
+//! auto-stop on in [`AutoStops`], and in their own main phases while the stack
+//! is empty, where they could act at sorcery speed.
+//!
+//! The local player has [`AutoPass`] while the auto pass gameplay setting is on.
+
+use bevy::prelude::*;
+
+use super::events::PassPriorityEvent;
+use super::resources::PrioritySystem;
+use super::systems::priority_passing_system;
+use crate::camera::presets::LOCAL_PLAYER_INDEX;
+use crate::cards::Card;
+use crate::cards::abilities::ActivatedAbility;
+use crate::cards::keywords::KeywordAbility;
+use crate::game_engine::actions::{
+    can_tap_now, is_instant_cast, taps_for_mana, text_abilities, valid_time_for_sorcery,
+};
+use crate::game_engine::choices::no_pending_choice;
+use crate::game_engine::permanent::{PermanentController, PermanentState};
+use crate::game_engine::phase::AutoStops;
+use crate::game_engine::schedule::GameEngineSet;
+use crate::game_engine::zones::ZoneManager;
+use crate::game_engine::{GameStack, GameState, Phase};
+use crate::mana::Mana;
+use crate::menu::settings::components::GameplaySettings;
+use crate::menu::state::GameMenuState;
+use crate::player::Player;
+
+/// Pass priority for this player whenever they have nothing they could do
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AutoPass;
+
+/// Mana a player could spend: what's in their pool, and one for each untapped
+/// mana source they control
+///
+/// Colors aren't checked, so a player is only ever thought able to afford more
+/// than they can, and keeps priority rather than losing a chance to act.
+pub fn available_mana(player: &Player, untapped_sources: usize) -> u64 {
+    player.mana_pool.mana.values().map(Mana::total).sum::<u64>() + untapped_sources as u64
+}
+
+/// Whether a player with `mana` available could cast one of the cards in
+/// their hand or activate an ability of one of their permanents at instant speed
+pub fn has_instant_speed_action(
+    mana: u64,
+    hand: &[&Card],
+    permanents: &[(&Card, &PermanentState, Option<&ActivatedAbility>)],
+) -> bool {
+    let affordable = |cost: &Mana| cost.total() <= mana;
+
+    let castable = hand.iter().any(|card| {
+        (is_instant_cast(&card.type_info) || Card::has_keyword(card, KeywordAbility::Flash))
+            && affordable(&card.cost.cost)
+    });
+    castable
+        || permanents.iter().any(|&(card, state, ability)| {
+            let component = ability.is_some_and(|ability| {
+                ability.instant_speed
+                    && (!ability.tap_cost || can_tap_now(card, state))
+                    && ability.mana_cost.as_ref().is_none_or(affordable)
+            });
+            component
+                || text_abilities(&card.rules_text.rules_text).any(|requirements| {
+                    !requirements.sorcery_speed && (!requirements.taps || can_tap_now(card, state))
+                })
+        })
+}
+
+/// Pass priority for a player with [`AutoPass`] who has nothing they could do
+#[allow(clippy::too_many_arguments)]
+pub fn auto_pass_priority(
+    priority: Res<PrioritySystem>,
+    phase: Res<Phase>,
+    game_state: Res<GameState>,
+    stack: Res<GameStack>,
+    auto_stops: Res<AutoStops>,
+    zones: Res<ZoneManager>,
+    players: Query<&Player, With<AutoPass>>,
+    cards: Query<(
+        &Card,
+        Option<&PermanentState>,
+        Option<&PermanentController>,
+        Option<&ActivatedAbility>,
+    )>,
+    mut passes: EventWriter<PassPriorityEvent>,
+) {
+    let holder = priority.priority_player;
+    let Ok(player) = players.get(holder) else {
+        return;
+    };
+    // Steps without actions are already passed through by the priority system
+    if !priority.simultaneous_decision_players.is_empty()
+        || !phase.allows_actions()
+        || auto_stops.contains(holder, *phase)
+        || valid_time_for_sorcery(&game_state, &phase, &stack, holder)
+    {
+        return;
+    }
+
+    let hand: Vec<&Card> = zones
+        .hand(holder)
+        .iter()
+        .filter_map(|&card| cards.get(card).ok())
+        .map(|(card, ..)| card)
+        .collect();
+    let permanents: Vec<(&Card, &PermanentState, Option<&ActivatedAbility>)> = zones
+        .battlefield()
+        .iter()
+        .filter_map(|&entity| {
+            let (card, state, controller, ability) = cards.get(entity).ok()?;
+            let controlled = match controller {
+                Some(controller) => controller.player == holder,
+                None => zones.get_card_owner(entity) == Some(holder),
+            };
+            controlled.then_some((card, state?, ability))
+        })
+        .collect();
+
+    let untapped_sources = permanents
+        .iter()
+        .filter(|(card, state, _)| taps_for_mana(card) && can_tap_now(card, state))
+        .count();
+    let mana = available_mana(player, untapped_sources);
+    if has_instant_speed_action(mana, &hand, &permanents) {
+        return;
+    }
+
+    debug!(
+        "{} has nothing to do in {}, passing priority",
+        player.name,
+        phase.label()
+    );
+    passes.write(PassPriorityEvent { player: holder });
+}
+
+/// Give the local player [`AutoPass`] while the auto pass gameplay setting is on
+pub fn sync_local_auto_pass(
+    mut commands: Commands,
+    settings: Option<Res<GameplaySettings>>,
+    players: Query<(Entity, &Player, Has<AutoPass>)>,
+) {
+    let enabled = settings.is_some_and(|settings| settings.auto_pass);
+    for (entity, player, has_auto_pass) in players.iter() {
+        if player.player_index != LOCAL_PLAYER_INDEX || has_auto_pass == enabled {
+            continue;
+        }
+        if enabled {
+            commands.entity(entity).insert(AutoPass);
+        } else {
+            commands.entity(entity).remove::<AutoPass>();
+        }
+    }
+}
+
+/// Register passing priority automatically
+pub fn register_auto_pass_systems(app: &mut App) {
+    app.add_systems(
+        FixedUpdate,
+        auto_pass_priority
+            .before(priority_passing_system)
+            .in_set(GameEngineSet::Input)
+            .run_if(
+                in_state(GameMenuState::InGame)
+                    .and(no_pending_choice)
+                    .and(resource_exists::<ZoneManager>),
+            ),
+    )
+    .add_systems(
+        Update,
+        sync_local_auto_pass.run_if(in_state(GameMenuState::InGame)),
+    );
+}
@@ -1,9 +1,14 @@
 // Re-exports from the priority system module
+pub mod auto_pass;
 pub mod events;
 pub mod resources;
 pub mod systems;
 
 // Public exports
+pub use auto_pass::*;
 pub use events::*;
 pub use resources::*;
 pub use systems::*;
+
+#[cfg(test)]
+mod tests;
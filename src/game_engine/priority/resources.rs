@@ -124,7 +124,6 @@ impl PrioritySystem {
     }
 
     /// Set whether the stack is empty (affects priority passing)
-    #[allow(dead_code)]
     pub fn set_stack_empty(&mut self, is_empty: bool) {
         self.stack_is_empty = is_empty;
     }
@@ -1,23 +1,37 @@
+use crate::game_engine::cleanup::CleanupState;
+use crate::game_engine::phase::AutoStops;
 use crate::game_engine::stack::GameStack;
 use crate::game_engine::state::GameState;
 use crate::game_engine::turns::TurnManager;
 use crate::player::Player;
 use bevy::prelude::*;
 
-use super::events::{NextPhaseEvent, PassPriorityEvent};
+use super::events::{NextPhaseEvent, PassPriorityEvent, ResolveStackItemEvent};
 use super::resources::PrioritySystem;
 
 /// Main system for managing priority passing and game flow
+///
+/// When everyone has passed in succession the step ends if the stack is empty,
+/// and otherwise the top item of the stack resolves (rule 117.4).
+#[allow(clippy::too_many_arguments)]
 pub fn priority_system(
     _commands: Commands,
     mut priority: ResMut<PrioritySystem>,
     _game_state: ResMut<GameState>,
-    _stack: Res<GameStack>,
+    stack: Res<GameStack>,
     phase: Res<crate::game_engine::Phase>,
+    auto_stops: Res<AutoStops>,
     turn_manager: Res<TurnManager>,
+    cleanup_state: Option<Res<CleanupState>>,
     mut next_phase_events: EventWriter<NextPhaseEvent>,
     mut pass_priority_events: EventWriter<PassPriorityEvent>,
+    mut resolve_events: EventWriter<ResolveStackItemEvent>,
 ) {
+    // What's been cast or resolved this tick decides what passing does
+    if priority.stack_is_empty != stack.items.is_empty() {
+        priority.set_stack_empty(stack.items.is_empty());
+    }
+
     // Skip if we're waiting for decisions
     if !priority.simultaneous_decision_players.is_empty() {
         return;
@@ -37,7 +51,9 @@ pub fn priority_system(
     }
     // If everyone has passed and there's something on stack, resolve top item
     else if priority.priority_round_complete() && !priority.stack_is_empty {
-        // The next pending item will be resolved
+        if let Some(top) = stack.items.last() {
+            resolve_events.write(ResolveStackItemEvent { item: top.entity });
+        }
         // Priority resets to active player after resolving
         let players: Vec<Entity> = turn_manager.player_order.clone();
         let active_player = turn_manager.active_player;
@@ -45,8 +61,14 @@ pub fn priority_system(
         priority.reset_after_stack_action(&players, active_player);
     }
 
-    // Auto-pass priority in phases that don't allow player actions
-    if !phase.allows_actions() && priority.stack_is_empty {
+    // Auto-pass priority in phases that don't allow player actions, unless the
+    // player with priority asked to stop there or something happened during cleanup
+    let cleanup_grants_priority = cleanup_state.is_some_and(|state| state.grant_priority);
+    if !phase.allows_actions()
+        && !auto_stops.contains(priority.priority_player, *phase)
+        && !cleanup_grants_priority
+        && priority.stack_is_empty
+    {
         // commands.spawn_empty().insert(PassPriorityEvent {
         //     player: priority.priority_player,
         // });
@@ -60,7 +82,7 @@ pub fn priority_system(
 pub fn priority_passing_system(
     _commands: Commands,
     mut priority: ResMut<PrioritySystem>,
-    _game_stack: ResMut<GameStack>,
+    game_stack: Res<GameStack>,
     mut pass_events: EventReader<PassPriorityEvent>,
     _players: Query<Entity, With<Player>>,
     _time: Res<Time>,
@@ -79,8 +101,8 @@ pub fn priority_passing_system(
 
         // Pass priority to the next player
         priority.pass_priority();
-
-        // TODO: Update stack empty status when implementing stack resolution
-        // priority.set_stack_empty(game_stack.items.is_empty());
+    }
+    if priority.stack_is_empty != game_stack.items.is_empty() {
+        priority.set_stack_empty(game_stack.items.is_empty());
     }
 }
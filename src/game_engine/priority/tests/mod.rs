@@ -0,0 +1,326 @@
+use bevy::prelude::*;
+
+use crate::cards::abilities::ActivatedAbility;
+use crate::cards::{Card, CardDetails, CardTypes};
+use crate::game_engine::permanent::PermanentState;
+use crate::game_engine::phase::{AutoStops, BeginningStep, EndingStep, MAIN1};
+use crate::game_engine::priority::{
+    AutoPass, EffectCounteredEvent, NextPhaseEvent, PassPriorityEvent, PrioritySystem,
+    PrioritySystemBuilder, ResolveStackItemEvent, auto_pass_priority, has_instant_speed_action,
+    priority_passing_system, priority_system, sync_local_auto_pass,
+};
+use crate::game_engine::stack::{Effect, StackItemResolvedEvent, stack_resolution_system};
+use crate::game_engine::turns::TurnManager;
+use crate::game_engine::{GameStack, GameState, Phase, ZoneManager};
+use crate::mana::Mana;
+use crate::menu::settings::components::GameplaySettings;
+use crate::player::Player;
+use std::collections::VecDeque;
+
+const UPKEEP: Phase = Phase::Beginning(BeginningStep::Upkeep);
+
+fn card(name: &str, cost: u64, types: CardTypes, rules_text: &str) -> Card {
+    Card::new(
+        name,
+        Mana::new_with_colors(cost, 0, 0, 0, 0, 0),
+        types,
+        CardDetails::Other,
+        rules_text,
+    )
+}
+
+fn untapped() -> PermanentState {
+    let mut state = PermanentState::new(1);
+    state.update_summoning_sickness(2);
+    state
+}
+
+#[test]
+fn test_instant_speed_actions_need_enough_mana() {
+    let shock = card("Shock", 1, CardTypes::INSTANT, "");
+    let divination = card("Divination", 3, CardTypes::SORCERY, "");
+
+    assert!(has_instant_speed_action(1, &[&shock], &[]));
+    assert!(!has_instant_speed_action(0, &[&shock], &[]));
+    // Sorceries can't be cast at instant speed, whatever the mana
+    assert!(!has_instant_speed_action(5, &[&divination], &[]));
+}
+
+#[test]
+fn test_abilities_count_when_they_could_be_activated() {
+    let state = untapped();
+    let mut tapped = untapped();
+    tapped.is_tapped = true;
+    let pinger = card(
+        "Prodigal Sorcerer",
+        3,
+        CardTypes::CREATURE,
+        "{T}: Prodigal Sorcerer deals 1 damage to any target.",
+    );
+    let equipment = card(
+        "Bonesplitter",
+        1,
+        CardTypes::ARTIFACT,
+        "{1}: Attach to target creature you control. Activate only as a sorcery.",
+    );
+    let forest = card("Forest", 0, CardTypes::BASIC | CardTypes::LAND, "");
+
+    assert!(has_instant_speed_action(0, &[], &[(&pinger, &state, None)]));
+    assert!(!has_instant_speed_action(
+        0,
+        &[],
+        &[(&pinger, &tapped, None)]
+    ));
+    assert!(!has_instant_speed_action(
+        5,
+        &[],
+        &[(&equipment, &state, None)]
+    ));
+    // Mana abilities don't count as something to do
+    assert!(!has_instant_speed_action(
+        0,
+        &[],
+        &[(&forest, &state, None)]
+    ));
+
+    let draw =
+        ActivatedAbility::with_mana_cost(Mana::new_with_colors(2, 0, 0, 0, 0, 0), "Draw a card")
+            .at_instant_speed();
+    assert!(has_instant_speed_action(
+        2,
+        &[],
+        &[(&forest, &state, Some(&draw))]
+    ));
+    assert!(!has_instant_speed_action(
+        1,
+        &[],
+        &[(&forest, &state, Some(&draw))]
+    ));
+}
+
+struct Table {
+    app: App,
+    player: Entity,
+    opponent: Entity,
+}
+
+impl Table {
+    /// The opponent's turn, with the local player holding priority in `phase`
+    fn new(phase: Phase) -> Self {
+        let mut app = App::new();
+        app.add_event::<PassPriorityEvent>()
+            .init_resource::<ZoneManager>()
+            .init_resource::<GameStack>()
+            .init_resource::<AutoStops>()
+            .insert_resource(phase)
+            .add_systems(Update, auto_pass_priority);
+
+        let world = app.world_mut();
+        let player = world.spawn((Player::new("Alice"), AutoPass)).id();
+        let opponent = world.spawn(Player::new("Bob").with_player_index(1)).id();
+        let mut zones = world.resource_mut::<ZoneManager>();
+        zones.init_player_zones(player);
+        zones.init_player_zones(opponent);
+        world.insert_resource(GameState::builder().active_player(opponent).build());
+        world.insert_resource(
+            PrioritySystemBuilder::new()
+                .active_player(opponent)
+                .priority_player(player)
+                .build(),
+        );
+
+        Self {
+            app,
+            player,
+            opponent,
+        }
+    }
+
+    /// Put a card into a player's hand or onto the battlefield untapped
+    fn add(&mut self, owner: Entity, card: Card, battlefield: bool) {
+        let world = self.app.world_mut();
+        if battlefield {
+            let entity = world.spawn((card, untapped())).id();
+            world
+                .resource_mut::<ZoneManager>()
+                .add_to_battlefield(owner, entity);
+        } else {
+            let entity = world.spawn(card).id();
+            world
+                .resource_mut::<ZoneManager>()
+                .add_to_hand(owner, entity);
+        }
+    }
+
+    /// Whether priority was passed for the local player
+    fn passes(&mut self) -> bool {
+        self.app.update();
+        let player = self.player;
+        self.app
+            .world_mut()
+            .resource_mut::<Events<PassPriorityEvent>>()
+            .drain()
+            .any(|event| event.player == player)
+    }
+}
+
+#[test]
+fn test_priority_is_passed_for_players_with_nothing_to_do() {
+    let mut table = Table::new(UPKEEP);
+    let (player, opponent) = (table.player, table.opponent);
+    // Their opponent's instant isn't theirs to cast
+    table.add(opponent, card("Shock", 1, CardTypes::INSTANT, ""), false);
+    table.add(player, card("Divination", 3, CardTypes::SORCERY, ""), false);
+    assert!(table.passes());
+
+    // An instant they can't yet afford
+    table.add(player, card("Shock", 1, CardTypes::INSTANT, ""), false);
+    assert!(table.passes());
+
+    // An untapped land pays for it
+    let forest = card("Forest", 0, CardTypes::BASIC | CardTypes::LAND, "");
+    table.add(player, forest, true);
+    assert!(!table.passes());
+}
+
+#[test]
+fn test_auto_stops_and_settings_keep_priority() {
+    let mut table = Table::new(UPKEEP);
+    let player = table.player;
+    table
+        .app
+        .world_mut()
+        .resource_mut::<AutoStops>()
+        .toggle(player, UPKEEP);
+    assert!(!table.passes());
+
+    let mut table = Table::new(Phase::Ending(EndingStep::End));
+    let player = table.player;
+    table
+        .app
+        .world_mut()
+        .entity_mut(player)
+        .remove::<AutoPass>();
+    assert!(!table.passes());
+}
+
+#[test]
+fn test_own_main_phase_is_never_passed() {
+    let mut table = Table::new(MAIN1);
+    let player = table.player;
+    table
+        .app
+        .insert_resource(GameState::builder().active_player(player).build());
+    assert!(!table.passes());
+}
+
+#[test]
+fn test_auto_pass_follows_the_local_setting() {
+    let mut app = App::new();
+    app.init_resource::<GameplaySettings>()
+        .add_systems(Update, sync_local_auto_pass);
+    let local = app.world_mut().spawn(Player::new("Alice")).id();
+    let remote = app
+        .world_mut()
+        .spawn(Player::new("Bob").with_player_index(1))
+        .id();
+
+    app.update();
+    assert!(app.world().entity(local).contains::<AutoPass>());
+    assert!(!app.world().entity(remote).contains::<AutoPass>());
+
+    app.world_mut().resource_mut::<GameplaySettings>().auto_pass = false;
+    app.update();
+    assert!(!app.world().entity(local).contains::<AutoPass>());
+}
+
+/// Marks that the spell on the stack resolved
+#[derive(Component)]
+struct Resolved;
+
+#[derive(Debug)]
+struct Spell {
+    controller: Entity,
+}
+
+impl Effect for Spell {
+    fn resolve(&self, commands: &mut Commands) {
+        commands.spawn(Resolved);
+    }
+
+    fn controller(&self) -> Entity {
+        self.controller
+    }
+
+    fn targets(&self) -> Vec<Entity> {
+        Vec::new()
+    }
+}
+
+#[test]
+fn test_passing_with_an_item_on_the_stack_resolves_it() {
+    let mut app = App::new();
+    app.add_event::<PassPriorityEvent>()
+        .add_event::<ResolveStackItemEvent>()
+        .add_event::<NextPhaseEvent>()
+        .add_event::<StackItemResolvedEvent>()
+        .add_event::<EffectCounteredEvent>()
+        .init_resource::<Time>()
+        .init_resource::<GameStack>()
+        .init_resource::<AutoStops>()
+        .insert_resource(MAIN1)
+        .add_systems(
+            Update,
+            (
+                priority_passing_system,
+                stack_resolution_system,
+                priority_system,
+            )
+                .chain(),
+        );
+    let alice = app.world_mut().spawn(Player::new("Alice")).id();
+    let bob = app
+        .world_mut()
+        .spawn(Player::new("Bob").with_player_index(1))
+        .id();
+    app.insert_resource(
+        TurnManager::builder()
+            .active_player(alice)
+            .player_order(vec![alice, bob])
+            .build(),
+    )
+    .insert_resource(
+        GameState::builder()
+            .active_player(alice)
+            .turn_order(VecDeque::from([alice, bob]))
+            .build(),
+    );
+    let mut priority = PrioritySystem::default();
+    priority.initialize(&[alice, bob], alice);
+    app.insert_resource(priority);
+    let spell = app.world_mut().spawn_empty().id();
+    app.world_mut().resource_mut::<GameStack>().push(
+        Box::new(Spell { controller: bob }),
+        spell,
+        false,
+        true,
+    );
+
+    for player in [alice, bob] {
+        app.world_mut().send_event(PassPriorityEvent { player });
+        app.update();
+    }
+    app.update();
+
+    assert!(app.world().resource::<GameStack>().is_empty());
+    assert_eq!(
+        app.world_mut()
+            .query_filtered::<(), With<Resolved>>()
+            .iter(app.world())
+            .count(),
+        1
+    );
+    assert_eq!(*app.world().resource::<Phase>(), MAIN1);
+    assert!(app.world().resource::<Events<NextPhaseEvent>>().is_empty());
+    assert!(app.world().resource::<PrioritySystem>().has_priority(alice));
+}
@@ -0,0 +1,317 @@
+//! Revealing cards to some or all players
+//!
+//! Effects like Duress have a player reveal their hand, and others like
+//! Telepathy have them play with it revealed. A [`RevealCardsEvent`] shows cards
+//! to the players it names, and a [`RevealHandEvent`] does the same for a whole
+//! hand, optionally keeping it revealed as cards are drawn until a
+//! [`ConcealHandEvent`].
+//!
+//! Every reveal sends each player who gets to see the cards a [`RevealMessage`]
+//! naming them. These are the only way the revealed information leaves the
+//! engine, so networking forwards each one to its recipient alone and the HUD
+//! shows the ones for the local player.
+//!
+//! Reveals that last are kept in [`RevealedCards`] until the card changes zones.
+//! A card revealed to every player is also marked with
+//! [`CardState::is_revealed`].
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::Card;
+use crate::cards::state::CardState;
+use crate::game_engine::zones::{Zone, ZoneChangeEvent, ZoneManager};
+use crate::menu::state::GameMenuState;
+use crate::player::Player;
+
+/// Who gets to see revealed cards
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RevealAudience {
+    /// Every player
+    AllPlayers,
+    /// Every player other than the one revealing
+    Opponents,
+    /// Only these players
+    Players(Vec<Entity>),
+}
+
+impl RevealAudience {
+    /// Whether `viewer` sees what `revealer` reveals
+    pub fn includes(&self, viewer: Entity, revealer: Entity) -> bool {
+        match self {
+            RevealAudience::AllPlayers => true,
+            RevealAudience::Opponents => viewer != revealer,
+            RevealAudience::Players(players) => players.contains(&viewer),
+        }
+    }
+}
+
+/// Sent to reveal cards to some or all players
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct RevealCardsEvent {
+    /// The player revealing the cards
+    pub player: Entity,
+    pub cards: Vec<Entity>,
+    pub audience: RevealAudience,
+    /// Whether the cards stay revealed until they change zones, rather than
+    /// just being shown
+    pub lasting: bool,
+}
+
+/// Sent to have a player reveal their hand
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct RevealHandEvent {
+    pub player: Entity,
+    pub audience: RevealAudience,
+    /// Whether the player plays with their hand revealed from now on, cards they
+    /// draw included
+    pub lasting: bool,
+}
+
+/// Sent to stop a player playing with their hand revealed
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConcealHandEvent {
+    pub player: Entity,
+}
+
+/// A revealed card, as its viewers learn it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevealedCardInfo {
+    pub card: Entity,
+    pub name: String,
+}
+
+/// Cards revealed to one player
+///
+/// Only ever sent for a player entitled to see the cards, and never for the
+/// player revealing them, who already knows them.
+#[derive(Event, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevealMessage {
+    /// The player who sees the cards
+    pub recipient: Entity,
+    /// The player who revealed them
+    pub revealer: Entity,
+    pub cards: Vec<RevealedCardInfo>,
+}
+
+/// A card that stays revealed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LastingReveal {
+    pub revealer: Entity,
+    /// The players who see it, besides the revealer
+    pub viewers: Vec<Entity>,
+}
+
+/// Cards revealed until they change zones, and hands played revealed
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct RevealedCards {
+    pub cards: HashMap<Entity, LastingReveal>,
+    /// Players playing with their hand revealed, and who sees it
+    pub open_hands: HashMap<Entity, RevealAudience>,
+}
+
+impl RevealedCards {
+    /// Whether `viewer` can see `card` because it was revealed to them
+    pub fn is_revealed_to(&self, card: Entity, viewer: Entity) -> bool {
+        self.cards
+            .get(&card)
+            .is_some_and(|reveal| reveal.revealer == viewer || reveal.viewers.contains(&viewer))
+    }
+
+    /// Reveal `cards` to the players in `audience` out of `players`
+    ///
+    /// Returns the message for each player who sees them. Lasting reveals are
+    /// remembered until the cards change zones.
+    pub fn reveal(
+        &mut self,
+        revealer: Entity,
+        cards: &[RevealedCardInfo],
+        audience: &RevealAudience,
+        lasting: bool,
+        players: &[Entity],
+    ) -> Vec<RevealMessage> {
+        if cards.is_empty() {
+            return Vec::new();
+        }
+        let viewers: Vec<Entity> = players
+            .iter()
+            .copied()
+            .filter(|&player| player != revealer && audience.includes(player, revealer))
+            .collect();
+        if lasting {
+            for info in cards {
+                self.cards.insert(
+                    info.card,
+                    LastingReveal {
+                        revealer,
+                        viewers: viewers.clone(),
+                    },
+                );
+            }
+        }
+        viewers
+            .into_iter()
+            .map(|recipient| RevealMessage {
+                recipient,
+                revealer,
+                cards: cards.to_vec(),
+            })
+            .collect()
+    }
+}
+
+/// Mark `card` as revealed to everyone, or not
+fn mark_revealed(
+    commands: &mut Commands,
+    card_states: &mut Query<&mut CardState>,
+    card: Entity,
+    revealed: bool,
+) {
+    match card_states.get_mut(card) {
+        Ok(mut state) => {
+            if state.is_revealed != revealed {
+                if revealed {
+                    state.reveal();
+                } else {
+                    state.hide();
+                }
+            }
+        }
+        Err(_) if revealed => {
+            commands.entity(card).insert(CardState {
+                is_revealed: true,
+                ..default()
+            });
+        }
+        Err(_) => {}
+    }
+}
+
+/// Reveal cards and hands, and end reveals as cards change zones
+#[allow(clippy::too_many_arguments)]
+pub fn handle_reveals(
+    mut commands: Commands,
+    mut card_events: EventReader<RevealCardsEvent>,
+    mut hand_events: EventReader<RevealHandEvent>,
+    mut conceal_events: EventReader<ConcealHandEvent>,
+    mut zone_events: EventReader<ZoneChangeEvent>,
+    mut messages: EventWriter<RevealMessage>,
+    mut revealed: ResMut<RevealedCards>,
+    zones: Option<Res<ZoneManager>>,
+    players: Query<Entity, With<Player>>,
+    names: Query<&Card>,
+    mut card_states: Query<&mut CardState>,
+) {
+    let players: Vec<Entity> = players.iter().collect();
+    let hand = |player: Entity| {
+        zones
+            .as_ref()
+            .map(|zones| zones.hand(player).to_vec())
+            .unwrap_or_default()
+    };
+
+    // Cards revealed until they change zones stop being revealed when they do,
+    // while cards put into an open hand are revealed with it
+    let mut reveals: Vec<RevealCardsEvent> = Vec::new();
+    for event in zone_events.read() {
+        if event.source == event.destination {
+            continue;
+        }
+        if revealed.cards.remove(&event.card).is_some() {
+            mark_revealed(&mut commands, &mut card_states, event.card, false);
+        }
+        let open_hand = revealed
+            .open_hands
+            .get(&event.owner)
+            .filter(|_| event.destination == Zone::Hand);
+        if let Some(audience) = open_hand {
+            reveals.push(RevealCardsEvent {
+                player: event.owner,
+                cards: vec![event.card],
+                audience: audience.clone(),
+                lasting: true,
+            });
+        }
+    }
+
+    for event in conceal_events.read() {
+        revealed.open_hands.remove(&event.player);
+        for card in hand(event.player) {
+            if revealed.cards.remove(&card).is_some() {
+                mark_revealed(&mut commands, &mut card_states, card, false);
+            }
+        }
+    }
+
+    for event in hand_events.read() {
+        if event.lasting {
+            revealed
+                .open_hands
+                .insert(event.player, event.audience.clone());
+        }
+        reveals.push(RevealCardsEvent {
+            player: event.player,
+            cards: hand(event.player),
+            audience: event.audience.clone(),
+            lasting: event.lasting,
+        });
+    }
+    reveals.extend(card_events.read().cloned());
+
+    for event in reveals {
+        let cards: Vec<RevealedCardInfo> = event
+            .cards
+            .iter()
+            .map(|&card| RevealedCardInfo {
+                card,
+                name: names
+                    .get(card)
+                    .map_or_else(|_| String::new(), |card| card.name.name.clone()),
+            })
+            .collect();
+        let sent = revealed.reveal(
+            event.player,
+            &cards,
+            &event.audience,
+            event.lasting,
+            &players,
+        );
+        info!(
+            "{:?} revealed {:?} to {} players",
+            event.player,
+            event.cards,
+            sent.len()
+        );
+        let everyone = sent.len() + 1 >= players.len();
+        messages.write_batch(sent);
+        if event.lasting && everyone {
+            for &card in &event.cards {
+                mark_revealed(&mut commands, &mut card_states, card, true);
+            }
+        }
+    }
+}
+
+/// Forget reveals from the last game when returning to the main menu
+pub fn clear_revealed_cards(mut revealed: ResMut<RevealedCards>) {
+    *revealed = RevealedCards::default();
+}
+
+/// Register revealing cards
+pub fn register_reveal_systems(app: &mut App) {
+    app.add_event::<RevealCardsEvent>()
+        .add_event::<RevealHandEvent>()
+        .add_event::<ConcealHandEvent>()
+        .add_event::<RevealMessage>()
+        .init_resource::<RevealedCards>()
+        .add_systems(
+            Update,
+            handle_reveals.run_if(in_state(GameMenuState::InGame)),
+        )
+        .add_systems(OnEnter(GameMenuState::MainMenu), clear_revealed_cards);
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,167 @@
+use bevy::prelude::*;
+
+use crate::cards::state::CardState;
+use crate::cards::{Card, CardDetails, CardTypes};
+use crate::game_engine::reveal::{
+    ConcealHandEvent, RevealAudience, RevealCardsEvent, RevealHandEvent, RevealMessage,
+    RevealedCards, handle_reveals,
+};
+use crate::game_engine::zones::{Zone, ZoneChangeEvent, ZoneManager};
+use crate::mana::Mana;
+use crate::player::Player;
+
+fn reveal_app() -> (App, [Entity; 3]) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<RevealCardsEvent>()
+        .add_event::<RevealHandEvent>()
+        .add_event::<ConcealHandEvent>()
+        .add_event::<RevealMessage>()
+        .add_event::<ZoneChangeEvent>()
+        .init_resource::<RevealedCards>()
+        .init_resource::<ZoneManager>()
+        .add_systems(Update, handle_reveals);
+    let players =
+        ["Alice", "Bob", "Carol"].map(|name| app.world_mut().spawn(Player::new(name)).id());
+    for player in players {
+        app.world_mut()
+            .resource_mut::<ZoneManager>()
+            .init_player_zones(player);
+    }
+    (app, players)
+}
+
+fn card_in_hand(app: &mut App, player: Entity, name: &str) -> Entity {
+    let card = app
+        .world_mut()
+        .spawn(Card::new(
+            name,
+            Mana::default(),
+            CardTypes::INSTANT,
+            CardDetails::Other,
+            "",
+        ))
+        .id();
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .add_to_hand(player, card);
+    card
+}
+
+fn messages(app: &mut App) -> Vec<RevealMessage> {
+    app.world_mut()
+        .resource_mut::<Events<RevealMessage>>()
+        .drain()
+        .collect()
+}
+
+fn is_marked(app: &App, card: Entity) -> bool {
+    app.world()
+        .get::<CardState>(card)
+        .is_some_and(|state| state.is_revealed)
+}
+
+#[test]
+fn test_revealed_cards_only_reach_their_audience() {
+    let (mut app, [alice, bob, carol]) = reveal_app();
+    let bolt = card_in_hand(&mut app, alice, "Lightning Bolt");
+    let counterspell = card_in_hand(&mut app, alice, "Counterspell");
+
+    // Duress: the hand is shown to the opponents and nothing lasts
+    app.world_mut().send_event(RevealHandEvent {
+        player: alice,
+        audience: RevealAudience::Opponents,
+        lasting: false,
+    });
+    app.update();
+    let sent = messages(&mut app);
+    assert_eq!(
+        sent.iter()
+            .map(|message| message.recipient)
+            .collect::<Vec<_>>(),
+        vec![bob, carol]
+    );
+    assert!(sent.iter().all(|message| message.revealer == alice));
+    assert_eq!(
+        sent[0]
+            .cards
+            .iter()
+            .map(|info| info.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Lightning Bolt", "Counterspell"]
+    );
+    assert!(app.world().resource::<RevealedCards>().cards.is_empty());
+    assert!(!is_marked(&app, bolt));
+
+    // Showing one card to Bob alone lets only him see it
+    app.world_mut().send_event(RevealCardsEvent {
+        player: alice,
+        cards: vec![counterspell],
+        audience: RevealAudience::Players(vec![bob]),
+        lasting: true,
+    });
+    app.update();
+    let sent = messages(&mut app);
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].recipient, bob);
+    let revealed = app.world().resource::<RevealedCards>();
+    assert!(revealed.is_revealed_to(counterspell, bob));
+    assert!(!revealed.is_revealed_to(counterspell, carol));
+    assert!(!is_marked(&app, counterspell));
+}
+
+#[test]
+fn test_open_hands_reveal_drawn_cards_until_concealed() {
+    let (mut app, [alice, bob, carol]) = reveal_app();
+    let bolt = card_in_hand(&mut app, alice, "Lightning Bolt");
+
+    // Telepathy-style: Alice plays with her hand revealed
+    app.world_mut().send_event(RevealHandEvent {
+        player: alice,
+        audience: RevealAudience::AllPlayers,
+        lasting: true,
+    });
+    app.update();
+    assert_eq!(messages(&mut app).len(), 2);
+    assert!(is_marked(&app, bolt));
+
+    let drawn = card_in_hand(&mut app, alice, "Opt");
+    app.world_mut().send_event(ZoneChangeEvent {
+        card: drawn,
+        owner: alice,
+        source: Zone::Library,
+        destination: Zone::Hand,
+        was_visible: false,
+        is_visible: false,
+    });
+    app.update();
+    let sent = messages(&mut app);
+    assert_eq!(sent.len(), 2);
+    assert_eq!(sent[0].cards[0].card, drawn);
+    assert!(is_marked(&app, drawn));
+
+    // Casting the bolt ends its reveal
+    app.world_mut().send_event(ZoneChangeEvent {
+        card: bolt,
+        owner: alice,
+        source: Zone::Hand,
+        destination: Zone::Stack,
+        was_visible: true,
+        is_visible: true,
+    });
+    app.update();
+    assert!(!is_marked(&app, bolt));
+    assert!(
+        !app.world()
+            .resource::<RevealedCards>()
+            .is_revealed_to(bolt, carol)
+    );
+
+    app.world_mut()
+        .send_event(ConcealHandEvent { player: alice });
+    app.update();
+    assert!(!is_marked(&app, drawn));
+    let revealed = app.world().resource::<RevealedCards>();
+    assert!(!revealed.is_revealed_to(drawn, bob));
+    assert!(revealed.open_hands.is_empty());
+}
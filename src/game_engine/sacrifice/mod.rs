@@ -0,0 +1,302 @@
+//! Sacrificing permanents chosen by their controller
+//!
+//! Both costs like "Sacrifice a creature:" and edicts like "Each player
+//! sacrifices a creature" have a player choose permanents they control that
+//! match a [`PermanentFilter`]. Either is started with a [`SelectPermanentsEvent`]:
+//!
+//! - a [`SacrificePurpose::Cost`] must be paid in full, so nothing is sacrificed
+//!   if the player doesn't control enough matching permanents
+//! - a [`SacrificePurpose::Effect`] sacrifices as many as it can
+//!
+//! A player with no real choice to make, because they control exactly as many
+//! matching permanents as needed or fewer, isn't asked. Otherwise they confirm
+//! their choice with a [`ConfirmSelectionEvent`], or the first matching
+//! permanents are taken once [`SELECTION_TIMEOUT_SECS`] have passed. Chosen
+//! permanents are put into their owners' graveyards through
+//! [`ZoneChangeEvent`]s and a [`PermanentsSacrificedEvent`] reports the result.
+//!
+//! Selections are made one at a time in the order they were asked for, so an
+//! edict sends one event per player in APNAP order.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::cards::{Card, CardTypes};
+use crate::game_engine::permanent::{PermanentController, PermanentOwner};
+use crate::game_engine::zones::{Zone, ZoneChangeEvent, ZoneManager};
+use crate::menu::state::GameMenuState;
+
+/// Seconds a player has to choose what to sacrifice before it's chosen for them
+pub const SELECTION_TIMEOUT_SECS: f32 = 20.0;
+
+/// The permanents that can be chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PermanentFilter {
+    /// Permanents with any of these types, or any permanent if empty
+    pub types: CardTypes,
+    /// A permanent that can't be chosen, for "sacrifice another creature"
+    pub other_than: Option<Entity>,
+}
+
+impl PermanentFilter {
+    /// Permanents with any of `types`
+    pub fn of_type(types: CardTypes) -> Self {
+        Self {
+            types,
+            other_than: None,
+        }
+    }
+
+    /// Excludes `permanent`, usually the source of the ability
+    pub fn other_than(mut self, permanent: Entity) -> Self {
+        self.other_than = Some(permanent);
+        self
+    }
+
+    /// Whether `permanent`, with types `types`, can be chosen
+    pub fn matches(&self, permanent: Entity, types: CardTypes) -> bool {
+        self.other_than != Some(permanent)
+            && (self.types.is_empty() || self.types.intersects(types))
+    }
+}
+
+/// Why permanents are being sacrificed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SacrificePurpose {
+    /// To pay a cost, which must be paid in full
+    Cost,
+    /// Because an effect says so, sacrificing as many as possible
+    Effect,
+}
+
+/// Sent to have a player choose permanents they control to sacrifice
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectPermanentsEvent {
+    pub player: Entity,
+    /// The spell, ability or permanent asking for the sacrifice
+    pub source: Entity,
+    /// How many permanents to sacrifice
+    pub count: usize,
+    pub filter: PermanentFilter,
+    pub purpose: SacrificePurpose,
+}
+
+/// Sent by a player to confirm the permanents they chose
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ConfirmSelectionEvent {
+    pub player: Entity,
+    pub permanents: Vec<Entity>,
+}
+
+/// Permanents were sacrificed, or a cost couldn't be paid
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct PermanentsSacrificedEvent {
+    pub player: Entity,
+    pub source: Entity,
+    pub purpose: SacrificePurpose,
+    pub permanents: Vec<Entity>,
+    /// Whether a cost was paid; effects are always carried out
+    pub paid: bool,
+}
+
+/// A player choosing permanents to sacrifice
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermanentSelection {
+    pub request: SelectPermanentsEvent,
+    /// The permanents that can be chosen
+    pub candidates: Vec<Entity>,
+    /// Seconds spent waiting for the player so far
+    pub waited: f32,
+}
+
+impl PermanentSelection {
+    /// Whether `permanents` is a valid choice: the right number of different
+    /// candidates
+    pub fn is_valid(&self, permanents: &[Entity]) -> bool {
+        permanents.len() == self.request.count
+            && permanents.iter().enumerate().all(|(index, permanent)| {
+                self.candidates.contains(permanent) && !permanents[..index].contains(permanent)
+            })
+    }
+
+    /// Seconds left before the choice is made for the player
+    pub fn remaining_secs(&self) -> f32 {
+        (SELECTION_TIMEOUT_SECS - self.waited).max(0.0)
+    }
+}
+
+/// Sacrifices waiting to be chosen
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct PermanentSelections {
+    /// Requests waiting for the selection before them
+    pub pending: VecDeque<SelectPermanentsEvent>,
+    /// The selection being made, if any
+    pub current: Option<PermanentSelection>,
+}
+
+/// The permanents a player controls that a request can choose, in battlefield
+/// order
+pub fn selection_candidates(
+    request: &SelectPermanentsEvent,
+    zones: &ZoneManager,
+    permanents: &Query<(&PermanentController, &Card)>,
+) -> Vec<Entity> {
+    zones
+        .battlefield()
+        .iter()
+        .copied()
+        .filter(|&permanent| {
+            permanents.get(permanent).is_ok_and(|(controller, card)| {
+                controller.player == request.player
+                    && request.filter.matches(permanent, card.type_info.types)
+            })
+        })
+        .collect()
+}
+
+/// Put sacrificed permanents into their owners' graveyards and report it
+fn sacrifice_permanents(
+    request: &SelectPermanentsEvent,
+    chosen: Vec<Entity>,
+    paid: bool,
+    owners: &Query<&PermanentOwner>,
+    zone_events: &mut EventWriter<ZoneChangeEvent>,
+    sacrificed: &mut EventWriter<PermanentsSacrificedEvent>,
+) {
+    for &permanent in &chosen {
+        let owner = owners
+            .get(permanent)
+            .map_or(request.player, |owner| owner.player);
+        zone_events.write(ZoneChangeEvent {
+            card: permanent,
+            owner,
+            source: Zone::Battlefield,
+            destination: Zone::Graveyard,
+            was_visible: true,
+            is_visible: true,
+        });
+    }
+    info!(
+        "{:?} sacrificed {:?} for {:?}",
+        request.player, chosen, request.source
+    );
+    sacrificed.write(PermanentsSacrificedEvent {
+        player: request.player,
+        source: request.source,
+        purpose: request.purpose,
+        permanents: chosen,
+        paid,
+    });
+}
+
+/// Make selections in the order they were asked for, waiting for players' choices
+#[allow(clippy::too_many_arguments)]
+pub fn handle_permanent_selections(
+    time: Res<Time>,
+    mut requests: EventReader<SelectPermanentsEvent>,
+    mut confirmations: EventReader<ConfirmSelectionEvent>,
+    mut selections: ResMut<PermanentSelections>,
+    mut zone_events: EventWriter<ZoneChangeEvent>,
+    mut sacrificed: EventWriter<PermanentsSacrificedEvent>,
+    zones: Option<Res<ZoneManager>>,
+    permanents: Query<(&PermanentController, &Card)>,
+    owners: Query<&PermanentOwner>,
+) {
+    let new_requests: Vec<SelectPermanentsEvent> = requests.read().copied().collect();
+    if !new_requests.is_empty() {
+        selections.pending.extend(new_requests);
+    }
+    let Some(zones) = zones else {
+        confirmations.clear();
+        return;
+    };
+
+    if let Some(current) = selections.current.clone() {
+        let chosen = confirmations
+            .read()
+            .filter(|event| {
+                event.player == current.request.player && current.is_valid(&event.permanents)
+            })
+            .map(|event| event.permanents.clone())
+            .last();
+        let chosen = match chosen {
+            Some(chosen) => chosen,
+            None if current.remaining_secs() <= 0.0 => {
+                info!(
+                    "Choosing what {:?} sacrifices automatically",
+                    current.request.player
+                );
+                current.candidates[..current.request.count].to_vec()
+            }
+            None => {
+                // Waiting isn't a change anything needs to react to
+                if let Some(current) = selections.bypass_change_detection().current.as_mut() {
+                    current.waited += time.delta_secs();
+                }
+                return;
+            }
+        };
+        selections.current = None;
+        sacrifice_permanents(
+            &current.request,
+            chosen,
+            true,
+            &owners,
+            &mut zone_events,
+            &mut sacrificed,
+        );
+    } else {
+        confirmations.clear();
+    }
+
+    while let Some(request) = selections.pending.pop_front() {
+        let candidates = selection_candidates(&request, &zones, &permanents);
+        if candidates.len() > request.count {
+            selections.current = Some(PermanentSelection {
+                request,
+                candidates,
+                waited: 0.0,
+            });
+            break;
+        }
+        // There's nothing to choose between
+        let paid = request.purpose == SacrificePurpose::Effect || candidates.len() == request.count;
+        if !paid {
+            info!(
+                "{:?} can't sacrifice {} permanents for {:?}",
+                request.player, request.count, request.source
+            );
+        }
+        let chosen = if paid { candidates } else { Vec::new() };
+        sacrifice_permanents(
+            &request,
+            chosen,
+            paid,
+            &owners,
+            &mut zone_events,
+            &mut sacrificed,
+        );
+    }
+}
+
+/// Forget selections from the last game when returning to the main menu
+pub fn clear_permanent_selections(mut selections: ResMut<PermanentSelections>) {
+    *selections = PermanentSelections::default();
+}
+
+/// Register sacrificing chosen permanents
+pub fn register_sacrifice_systems(app: &mut App) {
+    app.add_event::<SelectPermanentsEvent>()
+        .add_event::<ConfirmSelectionEvent>()
+        .add_event::<PermanentsSacrificedEvent>()
+        .init_resource::<PermanentSelections>()
+        .add_systems(
+            Update,
+            handle_permanent_selections.run_if(in_state(GameMenuState::InGame)),
+        )
+        .add_systems(OnEnter(GameMenuState::MainMenu), clear_permanent_selections);
+}
+
+#[cfg(test)]
+mod tests;
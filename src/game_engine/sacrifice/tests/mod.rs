@@ -0,0 +1,180 @@
+use bevy::prelude::*;
+
+use crate::cards::{Card, CardDetails, CardTypes, CreatureCard, CreatureType};
+use crate::game_engine::permanent::{PermanentController, PermanentOwner};
+use crate::game_engine::sacrifice::{
+    ConfirmSelectionEvent, PermanentFilter, PermanentSelections, PermanentsSacrificedEvent,
+    SELECTION_TIMEOUT_SECS, SacrificePurpose, SelectPermanentsEvent, handle_permanent_selections,
+};
+use crate::game_engine::zones::{Zone, ZoneChangeEvent, ZoneManager, handle_zone_changes};
+use crate::mana::Mana;
+
+fn sacrifice_app() -> (App, Entity, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<SelectPermanentsEvent>()
+        .add_event::<ConfirmSelectionEvent>()
+        .add_event::<PermanentsSacrificedEvent>()
+        .add_event::<ZoneChangeEvent>()
+        .init_resource::<PermanentSelections>()
+        .init_resource::<ZoneManager>()
+        .add_systems(
+            Update,
+            (handle_permanent_selections, handle_zone_changes).chain(),
+        );
+    let alice = app.world_mut().spawn_empty().id();
+    let bob = app.world_mut().spawn_empty().id();
+    for player in [alice, bob] {
+        app.world_mut()
+            .resource_mut::<ZoneManager>()
+            .init_player_zones(player);
+    }
+    (app, alice, bob)
+}
+
+fn permanent(app: &mut App, player: Entity, types: CardTypes) -> Entity {
+    let details = if types.contains(CardTypes::CREATURE) {
+        CardDetails::Creature(CreatureCard {
+            power: 2,
+            toughness: 2,
+            creature_type: CreatureType::NONE,
+        })
+    } else {
+        CardDetails::Other
+    };
+    let card = Card::new("Permanent", Mana::default(), types, details, "");
+    let entity = app
+        .world_mut()
+        .spawn((
+            card,
+            PermanentController::new(player),
+            PermanentOwner::new(player),
+        ))
+        .id();
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .add_to_battlefield(player, entity);
+    entity
+}
+
+fn request(player: Entity, count: usize, purpose: SacrificePurpose) -> SelectPermanentsEvent {
+    SelectPermanentsEvent {
+        player,
+        source: player,
+        count,
+        filter: PermanentFilter::of_type(CardTypes::CREATURE),
+        purpose,
+    }
+}
+
+fn sacrificed(app: &mut App) -> Vec<PermanentsSacrificedEvent> {
+    app.world_mut()
+        .resource_mut::<Events<PermanentsSacrificedEvent>>()
+        .drain()
+        .collect()
+}
+
+fn zone(app: &App, card: Entity) -> Option<Zone> {
+    app.world().resource::<ZoneManager>().get_card_zone(card)
+}
+
+#[test]
+fn test_player_chooses_what_to_sacrifice() {
+    let (mut app, alice, _) = sacrifice_app();
+    let land = permanent(&mut app, alice, CardTypes::LAND);
+    let bear = permanent(&mut app, alice, CardTypes::CREATURE);
+    let wolf = permanent(&mut app, alice, CardTypes::CREATURE);
+
+    app.world_mut()
+        .send_event(request(alice, 1, SacrificePurpose::Cost));
+    app.update();
+    let current = app
+        .world()
+        .resource::<PermanentSelections>()
+        .current
+        .clone()
+        .unwrap();
+    assert_eq!(current.candidates, vec![bear, wolf]);
+
+    // A land, too many permanents or the same one twice aren't valid
+    assert!(!current.is_valid(&[land]));
+    assert!(!current.is_valid(&[bear, wolf]));
+    assert!(!current.is_valid(&[]));
+    app.world_mut().send_event(ConfirmSelectionEvent {
+        player: alice,
+        permanents: vec![land],
+    });
+    app.update();
+    assert!(sacrificed(&mut app).is_empty());
+
+    app.world_mut().send_event(ConfirmSelectionEvent {
+        player: alice,
+        permanents: vec![wolf],
+    });
+    app.update();
+    let events = sacrificed(&mut app);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].permanents, vec![wolf]);
+    assert!(events[0].paid);
+    assert_eq!(zone(&app, wolf), Some(Zone::Graveyard));
+    assert_eq!(zone(&app, bear), Some(Zone::Battlefield));
+}
+
+#[test]
+fn test_edicts_sacrifice_what_they_can_and_costs_need_enough() {
+    let (mut app, alice, bob) = sacrifice_app();
+    let bear = permanent(&mut app, alice, CardTypes::CREATURE);
+    permanent(&mut app, bob, CardTypes::ARTIFACT);
+
+    // Each player sacrifices a creature; neither has a choice to make
+    app.world_mut()
+        .send_event(request(alice, 1, SacrificePurpose::Effect));
+    app.world_mut()
+        .send_event(request(bob, 1, SacrificePurpose::Effect));
+    app.update();
+    let events = sacrificed(&mut app);
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].permanents, vec![bear]);
+    assert!(events[1].permanents.is_empty());
+    assert!(events.iter().all(|event| event.paid));
+    assert_eq!(zone(&app, bear), Some(Zone::Graveyard));
+
+    // A cost sacrificing another creature can't use the source itself
+    let source = permanent(&mut app, alice, CardTypes::CREATURE);
+    app.world_mut().send_event(SelectPermanentsEvent {
+        filter: PermanentFilter::of_type(CardTypes::CREATURE).other_than(source),
+        ..request(alice, 1, SacrificePurpose::Cost)
+    });
+    app.update();
+    let events = sacrificed(&mut app);
+    assert_eq!(events.len(), 1);
+    assert!(!events[0].paid);
+    assert_eq!(zone(&app, source), Some(Zone::Battlefield));
+}
+
+#[test]
+fn test_unanswered_selections_take_the_first_candidates() {
+    let (mut app, alice, _) = sacrifice_app();
+    let first = permanent(&mut app, alice, CardTypes::CREATURE);
+    let second = permanent(&mut app, alice, CardTypes::CREATURE);
+    permanent(&mut app, alice, CardTypes::CREATURE);
+
+    app.world_mut()
+        .send_event(request(alice, 2, SacrificePurpose::Effect));
+    app.update();
+    app.world_mut()
+        .resource_mut::<PermanentSelections>()
+        .current
+        .as_mut()
+        .unwrap()
+        .waited = SELECTION_TIMEOUT_SECS;
+    app.update();
+    let events = sacrificed(&mut app);
+    assert_eq!(events[0].permanents, vec![first, second]);
+    assert!(
+        app.world()
+            .resource::<PermanentSelections>()
+            .current
+            .is_none()
+    );
+}
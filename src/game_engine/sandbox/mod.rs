@@ -0,0 +1,171 @@
+//! Sandbox controls for changing the game by hand
+//!
+//! Like a virtual tabletop: players move any card to any zone, tap and untap
+//! anything, put counters on permanents and set life totals by hand. Useful for
+//! goldfishing a deck or resolving a corner case the engine doesn't handle.
+//! Any card in the [`CardDatabase`], including cards from mods, can be put
+//! straight into a zone to try it out.
+//!
+//! Manipulations are sent as [`SandboxAction`]s and ignored when the
+//! [`RulesEnforcementLevel`] is `Full`.
+
+use bevy::prelude::*;
+
+use crate::cards::database::CardDatabase;
+use crate::game_engine::enforcement::RulesEnforcementLevel;
+use crate::game_engine::permanent::PermanentState;
+use crate::game_engine::zones::{Zone, ZoneChangeEvent, ZoneManager, ZonePosition};
+use crate::menu::state::GameMenuState;
+use crate::player::Player;
+
+/// A manual change to the game, only allowed below full rules enforcement
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub enum SandboxAction {
+    /// Put a card into a zone
+    ///
+    /// `player` owns the zone; for shared zones such as the battlefield the card
+    /// keeps its owner.
+    MoveCard {
+        card: Entity,
+        zone: Zone,
+        player: Entity,
+    },
+    /// Tap an untapped permanent or untap a tapped one
+    ToggleTapped { card: Entity },
+    /// Add or remove counters, e.g. `"+1/+1"` or `"loyalty"`
+    AdjustCounters {
+        card: Entity,
+        kind: String,
+        delta: i32,
+    },
+    /// Set a player's life total
+    SetLife { player: Entity, life: i32 },
+    /// Create a new copy of a card from the database, by name, in `owner`'s
+    /// zone
+    CreateCard {
+        name: String,
+        owner: Entity,
+        zone: Zone,
+    },
+}
+
+/// Apply sandbox actions unless the rules are fully enforced
+pub fn apply_sandbox_actions(
+    enforcement: Res<RulesEnforcementLevel>,
+    mut actions: EventReader<SandboxAction>,
+    mut zone_manager: Option<ResMut<ZoneManager>>,
+    database: Option<Res<CardDatabase>>,
+    mut zone_changes: EventWriter<ZoneChangeEvent>,
+    mut commands: Commands,
+    mut permanents: Query<&mut PermanentState>,
+    mut players: Query<&mut Player>,
+) {
+    if !enforcement.allows_manual_changes() {
+        actions.clear();
+        return;
+    }
+
+    for action in actions.read() {
+        match action {
+            SandboxAction::MoveCard { card, zone, player } => {
+                let Some(location) = zone_manager
+                    .as_ref()
+                    .and_then(|zones| zones.location(*card))
+                else {
+                    warn!("Can't move {:?}, it isn't in any zone", card);
+                    continue;
+                };
+                let owner = match zone {
+                    Zone::Library | Zone::Hand | Zone::Graveyard => *player,
+                    _ => location.owner,
+                };
+                if location.zone == *zone && location.owner == owner {
+                    continue;
+                }
+                zone_changes.write(ZoneChangeEvent {
+                    card: *card,
+                    owner,
+                    source: location.zone,
+                    destination: *zone,
+                    was_visible: true,
+                    is_visible: true,
+                });
+            }
+            SandboxAction::ToggleTapped { card } => match permanents.get_mut(*card) {
+                Ok(mut state) => state.is_tapped = !state.is_tapped,
+                Err(_) => {
+                    commands.entity(*card).insert(PermanentState {
+                        is_tapped: true,
+                        ..default()
+                    });
+                }
+            },
+            SandboxAction::AdjustCounters { card, kind, delta } => {
+                match permanents.get_mut(*card) {
+                    Ok(mut state) => {
+                        state.counters.adjust(kind, *delta);
+                    }
+                    Err(_) => {
+                        let mut state = PermanentState::default();
+                        state.counters.adjust(kind, *delta);
+                        commands.entity(*card).insert(state);
+                    }
+                }
+            }
+            SandboxAction::SetLife { player, life } => {
+                if let Ok(mut player) = players.get_mut(*player) {
+                    player.life = *life;
+                }
+            }
+            SandboxAction::CreateCard { name, owner, zone } => {
+                let (Some(database), Some(zones)) = (database.as_ref(), zone_manager.as_mut())
+                else {
+                    continue;
+                };
+                let Some(definition) = database.find_by_name(name) else {
+                    warn!("Can't create {}, no card has that name", name);
+                    continue;
+                };
+                let Some(card) = database.spawn(&mut commands, definition.id) else {
+                    continue;
+                };
+                if !zones.insert(card, *owner, *zone, ZonePosition::Top) {
+                    warn!("Can't create {}, {:?} has no {:?}", name, owner, zone);
+                    commands.entity(card).despawn();
+                }
+            }
+        }
+    }
+}
+
+/// Turn tapped permanents sideways
+pub fn show_tapped_permanents(
+    mut permanents: Query<(&PermanentState, &mut Transform), Changed<PermanentState>>,
+) {
+    for (state, mut transform) in permanents.iter_mut() {
+        transform.rotation = if state.is_tapped {
+            Quat::from_rotation_z(-std::f32::consts::FRAC_PI_2)
+        } else {
+            Quat::IDENTITY
+        };
+    }
+}
+
+/// Plugin for sandbox mode
+pub struct SandboxPlugin;
+
+impl Plugin for SandboxPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RulesEnforcementLevel>()
+            .add_event::<SandboxAction>()
+            .add_systems(
+                Update,
+                (apply_sandbox_actions, show_tapped_permanents)
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame)),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,126 @@
+use bevy::prelude::*;
+
+use crate::cards::database::{CardDatabase, CardId};
+use crate::cards::{Card, CardDetails, CardTypes};
+use crate::game_engine::enforcement::RulesEnforcementLevel;
+use crate::game_engine::permanent::PermanentState;
+use crate::game_engine::sandbox::{SandboxAction, apply_sandbox_actions};
+use crate::game_engine::zones::{
+    Zone, ZoneChangeEvent, ZoneManager, ZonePosition, handle_zone_changes,
+};
+use crate::mana::Mana;
+use crate::player::Player;
+
+fn sandbox_app(enforcement: RulesEnforcementLevel) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<SandboxAction>()
+        .add_event::<ZoneChangeEvent>()
+        .init_resource::<ZoneManager>()
+        .insert_resource(enforcement)
+        .add_systems(Update, (apply_sandbox_actions, handle_zone_changes).chain());
+    app
+}
+
+fn send(app: &mut App, action: SandboxAction) {
+    app.world_mut().send_event(action);
+    app.update();
+}
+
+#[test]
+fn test_actions_are_ignored_under_full_enforcement() {
+    let mut app = sandbox_app(RulesEnforcementLevel::Full);
+    let player = app
+        .world_mut()
+        .spawn(Player::new("Player").with_life(40))
+        .id();
+
+    send(&mut app, SandboxAction::SetLife { player, life: 7 });
+
+    assert_eq!(app.world().get::<Player>(player).unwrap().life, 40);
+}
+
+#[test]
+fn test_cards_are_tapped_countered_and_moved_freely() {
+    let mut app = sandbox_app(RulesEnforcementLevel::Manual);
+    let player = app.world_mut().spawn(Player::new("Player")).id();
+    let opponent = app.world_mut().spawn(Player::new("Opponent")).id();
+    let card = app.world_mut().spawn_empty().id();
+    {
+        let mut zones = app.world_mut().resource_mut::<ZoneManager>();
+        zones.init_player_zones(player);
+        zones.init_player_zones(opponent);
+        zones.insert(card, player, Zone::Battlefield, ZonePosition::Top);
+    }
+
+    send(&mut app, SandboxAction::ToggleTapped { card });
+    send(
+        &mut app,
+        SandboxAction::AdjustCounters {
+            card,
+            kind: "+1/+1".to_string(),
+            delta: 2,
+        },
+    );
+    let state = app.world().get::<PermanentState>(card).unwrap();
+    assert!(state.is_tapped);
+    assert_eq!(state.counters.plus_one_plus_one, 2);
+
+    // Any card can go to any zone, even another player's hand
+    send(
+        &mut app,
+        SandboxAction::MoveCard {
+            card,
+            zone: Zone::Hand,
+            player: opponent,
+        },
+    );
+    let zones = app.world().resource::<ZoneManager>();
+    assert_eq!(zones.hand(opponent), &[card]);
+    assert!(zones.battlefield().is_empty());
+
+    send(&mut app, SandboxAction::SetLife { player, life: -3 });
+    assert_eq!(app.world().get::<Player>(player).unwrap().life, -3);
+}
+
+#[test]
+fn test_cards_are_created_from_the_database_by_name() {
+    let mut app = sandbox_app(RulesEnforcementLevel::Manual);
+    let mut database = CardDatabase::default();
+    database.insert(
+        Card::builder("Table Bear")
+            .cost(Mana::default())
+            .types(CardTypes::CREATURE)
+            .details(CardDetails::Other)
+            .build_or_panic(),
+    );
+    app.insert_resource(database);
+    let player = app.world_mut().spawn(Player::new("Player")).id();
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .init_player_zones(player);
+
+    send(
+        &mut app,
+        SandboxAction::CreateCard {
+            name: "table bear".to_string(),
+            owner: player,
+            zone: Zone::Hand,
+        },
+    );
+    send(
+        &mut app,
+        SandboxAction::CreateCard {
+            name: "Missing Card".to_string(),
+            owner: player,
+            zone: Zone::Hand,
+        },
+    );
+
+    let hand = app.world().resource::<ZoneManager>().hand(player).to_vec();
+    assert_eq!(hand.len(), 1);
+    assert_eq!(
+        app.world().get::<CardId>(hand[0]),
+        Some(&CardId::from_name("Table Bear"))
+    );
+}
@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+
+use super::counters::counter_list;
+use super::description::*;
+use crate::cards::Card;
+use crate::game_engine::commander::CommandZoneManager;
+use crate::game_engine::permanent::{PermanentController, PermanentOwner, PermanentState};
+use crate::game_engine::phase::Phase;
+use crate::game_engine::state::GameState;
+use crate::game_engine::zones::ZoneManager;
+use crate::player::Player;
+
+fn card_name(world: &World, card: Entity) -> String {
+    world
+        .get::<Card>(card)
+        .map(|card| card.name.name.clone())
+        .unwrap_or_else(|| format!("Unknown card {}", card))
+}
+
+fn describe_permanent(world: &World, card: Entity) -> PermanentDescription {
+    let state = world.get::<PermanentState>(card);
+    PermanentDescription {
+        name: card_name(world, card),
+        tapped: state.is_some_and(|state| state.is_tapped),
+        counters: state
+            .map(|state| counter_list(&state.counters))
+            .unwrap_or_default(),
+    }
+}
+
+/// Describes the public state of the game in `world`
+pub fn capture_board(world: &mut World) -> BoardDescription {
+    let mut players: Vec<(Entity, Player)> = world
+        .query::<(Entity, &Player)>()
+        .iter(world)
+        .map(|(entity, player)| (entity, player.clone()))
+        .collect();
+    players.sort_by_key(|(_, player)| player.player_index);
+
+    let world = &*world;
+    let player_name = |entity: Entity| {
+        players
+            .iter()
+            .find(|(player, _)| *player == entity)
+            .map(|(_, player)| player.name.clone())
+    };
+
+    let mut board = BoardDescription {
+        phase: world.get_resource::<Phase>().copied().unwrap_or_default(),
+        ..Default::default()
+    };
+    if let Some(game_state) = world.get_resource::<GameState>() {
+        board.turn_number = game_state.turn_number;
+        board.active_player = player_name(game_state.active_player);
+    }
+
+    board.players = players
+        .iter()
+        .map(|(_, player)| PlayerBoard {
+            name: player.name.clone(),
+            life: player.life,
+            ..Default::default()
+        })
+        .collect();
+    let player_index = |entity: Entity| players.iter().position(|(player, _)| *player == entity);
+
+    if let Some(zones) = world.get_resource::<ZoneManager>() {
+        for (index, (entity, _)) in players.iter().enumerate() {
+            let player = &mut board.players[index];
+            player.hand_size = zones.hand(*entity).len();
+            player.library_size = zones.library(*entity).len();
+            player.graveyard = zones
+                .graveyard(*entity)
+                .iter()
+                .map(|card| card_name(world, *card))
+                .collect();
+        }
+
+        for &card in zones.battlefield() {
+            let controller = world
+                .get::<PermanentController>(card)
+                .map(|controller| controller.player)
+                .or_else(|| world.get::<PermanentOwner>(card).map(|owner| owner.player));
+            let permanent = describe_permanent(world, card);
+            match controller.and_then(player_index) {
+                Some(index) => board.players[index].battlefield.push(permanent),
+                None => board.battlefield.push(permanent),
+            }
+        }
+
+        board.exile = zones
+            .exile()
+            .iter()
+            .map(|card| card_name(world, *card))
+            .collect();
+    }
+
+    if let Some(commanders) = world.get_resource::<CommandZoneManager>() {
+        for (index, (entity, _)) in players.iter().enumerate() {
+            board.players[index].commanders = commanders
+                .get_player_commanders(*entity)
+                .into_iter()
+                .map(|commander| CommanderDescription {
+                    name: card_name(world, commander),
+                    zone: commanders.get_commander_zone(commander),
+                    cast_count: commanders.get_cast_count(commander),
+                })
+                .collect();
+        }
+    }
+
+    board
+}
@@ -0,0 +1,65 @@
+use serde_json::Value;
+
+use crate::cards::counters::PermanentCounters;
+
+/// Label used for a counter field, e.g. `plus_one_plus_one` is "+1/+1"
+fn counter_label(field: &str) -> String {
+    match field {
+        "plus_one_plus_one" => "+1/+1".to_string(),
+        "minus_one_minus_one" => "-1/-1".to_string(),
+        _ => field.replace('_', " "),
+    }
+}
+
+/// Counter field for a label written by [`counter_label`]
+fn counter_field(label: &str) -> String {
+    match label {
+        "+1/+1" => "plus_one_plus_one".to_string(),
+        "-1/-1" => "minus_one_minus_one".to_string(),
+        _ => label.to_lowercase().replace(' ', "_"),
+    }
+}
+
+/// Every counter on a permanent with its amount, sorted by name
+pub fn counter_list(counters: &PermanentCounters) -> Vec<(String, u32)> {
+    // Going through the serialized form avoids listing every counter type here
+    let Ok(Value::Object(fields)) = serde_json::to_value(counters) else {
+        return Vec::new();
+    };
+
+    let mut list = Vec::new();
+    for (field, value) in &fields {
+        match value {
+            Value::Object(custom) if field == "custom" => {
+                for (name, amount) in custom {
+                    if let Some(amount) = amount.as_u64().filter(|amount| *amount > 0) {
+                        list.push((name.clone(), amount as u32));
+                    }
+                }
+            }
+            _ => {
+                if let Some(amount) = value.as_u64().filter(|amount| *amount > 0) {
+                    list.push((counter_label(field), amount as u32));
+                }
+            }
+        }
+    }
+    list.sort();
+    list
+}
+
+/// Builds counters from names and amounts, using custom counters for unknown names
+pub fn counters_from_list(list: &[(String, u32)]) -> PermanentCounters {
+    let Ok(mut value) = serde_json::to_value(PermanentCounters::default()) else {
+        return PermanentCounters::default();
+    };
+
+    for (label, amount) in list {
+        match value.get_mut(counter_field(label)) {
+            Some(slot) if slot.is_number() => *slot = Value::from(*amount),
+            _ => value["custom"][label.as_str()] = Value::from(*amount),
+        }
+    }
+
+    serde_json::from_value(value).unwrap_or_default()
+}
@@ -0,0 +1,420 @@
+use std::fmt::{self, Write};
+
+use crate::game_engine::commander::components::CommanderZoneLocation;
+use crate::game_engine::phase::Phase;
+
+/// Text layout used when writing a board description
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BoardFormat {
+    /// Markdown with headings and lists, for issue trackers
+    #[default]
+    Markdown,
+    /// Plain text, for chat and log files
+    PlainText,
+}
+
+impl BoardFormat {
+    /// File extension for descriptions in this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            BoardFormat::Markdown => "md",
+            BoardFormat::PlainText => "txt",
+        }
+    }
+}
+
+/// A permanent on the battlefield
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PermanentDescription {
+    pub name: String,
+    pub tapped: bool,
+    /// Counter names and amounts, see [`counter_list`](super::counter_list)
+    pub counters: Vec<(String, u32)>,
+}
+
+impl PermanentDescription {
+    /// An untapped permanent without counters
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// A player's commander and where it is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommanderDescription {
+    pub name: String,
+    pub zone: CommanderZoneLocation,
+    /// Times the commander has been cast, which sets its tax
+    pub cast_count: u32,
+}
+
+/// The public state of one player
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlayerBoard {
+    pub name: String,
+    pub life: i32,
+    pub commanders: Vec<CommanderDescription>,
+    pub hand_size: usize,
+    pub library_size: usize,
+    /// Permanents this player controls
+    pub battlefield: Vec<PermanentDescription>,
+    pub graveyard: Vec<String>,
+}
+
+/// The public state of a game
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BoardDescription {
+    pub turn_number: u32,
+    pub phase: Phase,
+    pub active_player: Option<String>,
+    /// Permanents without a controller
+    pub battlefield: Vec<PermanentDescription>,
+    /// The shared exile zone
+    pub exile: Vec<String>,
+    pub players: Vec<PlayerBoard>,
+}
+
+/// A line of a board description that couldn't be read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardParseError {
+    /// Line number, starting at 1
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for BoardParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+fn commander_zone_label(zone: CommanderZoneLocation) -> &'static str {
+    match zone {
+        CommanderZoneLocation::CommandZone => "Command Zone",
+        CommanderZoneLocation::Battlefield => "Battlefield",
+        CommanderZoneLocation::Graveyard => "Graveyard",
+        CommanderZoneLocation::Exile => "Exile",
+        CommanderZoneLocation::Hand => "Hand",
+        CommanderZoneLocation::Library => "Library",
+        CommanderZoneLocation::Stack => "Stack",
+    }
+}
+
+fn commander_zone_from_label(label: &str) -> Option<CommanderZoneLocation> {
+    [
+        CommanderZoneLocation::CommandZone,
+        CommanderZoneLocation::Battlefield,
+        CommanderZoneLocation::Graveyard,
+        CommanderZoneLocation::Exile,
+        CommanderZoneLocation::Hand,
+        CommanderZoneLocation::Library,
+        CommanderZoneLocation::Stack,
+    ]
+    .into_iter()
+    .find(|zone| commander_zone_label(*zone).eq_ignore_ascii_case(label.trim()))
+}
+
+fn plural(count: usize, singular: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, singular)
+    } else {
+        format!("{} {}s", count, singular)
+    }
+}
+
+impl fmt::Display for PermanentDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if self.tapped {
+            write!(f, " (tapped)")?;
+        }
+        if !self.counters.is_empty() {
+            let counters: Vec<String> = self
+                .counters
+                .iter()
+                .map(|(name, amount)| format!("{}: {}", name, amount))
+                .collect();
+            write!(f, " [{}]", counters.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for CommanderDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}, cast {})",
+            self.name,
+            commander_zone_label(self.zone),
+            plural(self.cast_count as usize, "time")
+        )
+    }
+}
+
+/// Writes headings and lists in the chosen format
+struct BoardWriter {
+    format: BoardFormat,
+    text: String,
+}
+
+impl BoardWriter {
+    fn heading(&mut self, level: usize, title: &str) {
+        if !self.text.is_empty() {
+            self.text.push('\n');
+        }
+        match self.format {
+            BoardFormat::Markdown => {
+                let _ = writeln!(self.text, "{} {}\n", "#".repeat(level), title);
+            }
+            BoardFormat::PlainText if title.contains(':') || level == 1 => {
+                let _ = writeln!(self.text, "{}", title);
+            }
+            BoardFormat::PlainText => {
+                let _ = writeln!(self.text, "{}:", title);
+            }
+        }
+    }
+
+    fn line(&mut self, line: impl fmt::Display) {
+        let _ = writeln!(self.text, "{}", line);
+    }
+
+    fn list<T: fmt::Display>(&mut self, level: usize, title: &str, items: &[T]) {
+        if items.is_empty() {
+            return;
+        }
+        self.heading(level, title);
+        for item in items {
+            let _ = writeln!(self.text, "- {}", item);
+        }
+    }
+}
+
+impl BoardDescription {
+    /// Player with the given name
+    pub fn player(&self, name: &str) -> Option<&PlayerBoard> {
+        self.players.iter().find(|player| player.name == name)
+    }
+
+    /// Writes the board as Markdown or plain text
+    pub fn to_text(&self, format: BoardFormat) -> String {
+        let mut writer = BoardWriter {
+            format,
+            text: String::new(),
+        };
+
+        writer.heading(
+            1,
+            &format!("Turn {} - {}", self.turn_number, self.phase.label()),
+        );
+        if let Some(active_player) = &self.active_player {
+            writer.line(format!("Active player: {}", active_player));
+        }
+        writer.list(2, "Battlefield", &self.battlefield);
+        writer.list(2, "Exile", &self.exile);
+
+        for player in &self.players {
+            writer.heading(2, &format!("Player: {}", player.name));
+            writer.line(format!("Life: {}", player.life));
+            for commander in &player.commanders {
+                writer.line(format!("Commander: {}", commander));
+            }
+            writer.line(format!("Hand: {}", plural(player.hand_size, "card")));
+            writer.line(format!("Library: {}", plural(player.library_size, "card")));
+            writer.list(3, "Battlefield", &player.battlefield);
+            writer.list(3, "Graveyard", &player.graveyard);
+        }
+
+        writer.text
+    }
+
+    /// Reads a board written by [`to_text`](Self::to_text) in either format
+    pub fn parse(text: &str) -> Result<Self, BoardParseError> {
+        #[derive(Clone, Copy)]
+        enum Section {
+            Battlefield,
+            Graveyard,
+            Exile,
+        }
+
+        let mut board = BoardDescription::default();
+        let mut section = None;
+
+        for (index, raw_line) in text.lines().enumerate() {
+            let error = |message: String| BoardParseError {
+                line: index + 1,
+                message,
+            };
+
+            let line = raw_line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(item) = line.strip_prefix("- ") {
+                let item = item.trim();
+                match section {
+                    Some(Section::Battlefield) => {
+                        let permanent = parse_permanent(item).map_err(error)?;
+                        match board.players.last_mut() {
+                            Some(player) => player.battlefield.push(permanent),
+                            None => board.battlefield.push(permanent),
+                        }
+                    }
+                    Some(Section::Graveyard) => match board.players.last_mut() {
+                        Some(player) => player.graveyard.push(item.to_string()),
+                        None => return Err(error("graveyard before any player".to_string())),
+                    },
+                    Some(Section::Exile) => board.exile.push(item.to_string()),
+                    None => return Err(error(format!("\"{}\" isn't in a zone", item))),
+                }
+                continue;
+            }
+
+            let line = line.trim_start_matches('#').trim();
+            let heading = line.strip_suffix(':').unwrap_or(line);
+            match heading {
+                "Battlefield" => {
+                    section = Some(Section::Battlefield);
+                    continue;
+                }
+                "Graveyard" => {
+                    section = Some(Section::Graveyard);
+                    continue;
+                }
+                "Exile" => {
+                    section = Some(Section::Exile);
+                    continue;
+                }
+                _ => {}
+            }
+
+            if let Some(turn) = line.strip_prefix("Turn ") {
+                let (number, phase) = turn
+                    .split_once(" - ")
+                    .ok_or_else(|| error("expected \"Turn <number> - <step>\"".to_string()))?;
+                board.turn_number = number
+                    .trim()
+                    .parse()
+                    .map_err(|_| error(format!("\"{}\" isn't a turn number", number)))?;
+                board.phase = Phase::from_label(phase)
+                    .ok_or_else(|| error(format!("\"{}\" isn't a step", phase)))?;
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| error(format!("unrecognised line \"{}\"", line)))?;
+            let value = value.trim();
+
+            if key == "Player" {
+                section = None;
+                board.players.push(PlayerBoard {
+                    name: value.to_string(),
+                    ..Default::default()
+                });
+                continue;
+            }
+            if key == "Active player" {
+                board.active_player = Some(value.to_string());
+                continue;
+            }
+
+            let player = board
+                .players
+                .last_mut()
+                .ok_or_else(|| error(format!("\"{}\" before any player", key)))?;
+            match key {
+                "Life" => {
+                    player.life = value
+                        .parse()
+                        .map_err(|_| error(format!("\"{}\" isn't a life total", value)))?;
+                }
+                "Hand" => player.hand_size = parse_card_count(value).map_err(error)?,
+                "Library" => player.library_size = parse_card_count(value).map_err(error)?,
+                "Commander" => player
+                    .commanders
+                    .push(parse_commander(value).map_err(error)?),
+                _ => return Err(error(format!("unknown field \"{}\"", key))),
+            }
+        }
+
+        Ok(board)
+    }
+}
+
+fn parse_card_count(value: &str) -> Result<usize, String> {
+    value
+        .split_whitespace()
+        .next()
+        .and_then(|count| count.parse().ok())
+        .ok_or_else(|| format!("\"{}\" isn't a number of cards", value))
+}
+
+fn parse_permanent(item: &str) -> Result<PermanentDescription, String> {
+    let mut rest = item;
+    let mut counters = Vec::new();
+
+    if let Some(without_bracket) = rest.strip_suffix(']') {
+        let (name, list) = without_bracket
+            .rsplit_once('[')
+            .ok_or_else(|| format!("unmatched ']' in \"{}\"", item))?;
+        for counter in list.split(',') {
+            let (counter_name, amount) = counter
+                .rsplit_once(':')
+                .ok_or_else(|| format!("expected \"<counter>: <amount>\" in \"{}\"", item))?;
+            let amount = amount
+                .trim()
+                .parse()
+                .map_err(|_| format!("\"{}\" isn't a number of counters", amount.trim()))?;
+            counters.push((counter_name.trim().to_string(), amount));
+        }
+        rest = name.trim_end();
+    }
+
+    let tapped = match rest.strip_suffix("(tapped)") {
+        Some(name) => {
+            rest = name.trim_end();
+            true
+        }
+        None => false,
+    };
+
+    if rest.is_empty() {
+        return Err("permanent without a name".to_string());
+    }
+
+    Ok(PermanentDescription {
+        name: rest.to_string(),
+        tapped,
+        counters,
+    })
+}
+
+fn parse_commander(value: &str) -> Result<CommanderDescription, String> {
+    let invalid = || {
+        format!(
+            "expected \"<name> (<zone>, cast <n> times)\", found \"{}\"",
+            value
+        )
+    };
+
+    let (name, details) = value
+        .strip_suffix(')')
+        .and_then(|value| value.rsplit_once(" ("))
+        .ok_or_else(invalid)?;
+    let (zone, cast) = details.split_once(", cast ").ok_or_else(invalid)?;
+    let zone = commander_zone_from_label(zone)
+        .ok_or_else(|| format!("\"{}\" isn't a commander zone", zone))?;
+    let cast_count = parse_card_count(cast).map_err(|_| invalid())? as u32;
+
+    Ok(CommanderDescription {
+        name: name.trim().to_string(),
+        zone,
+        cast_count,
+    })
+}
@@ -0,0 +1,239 @@
+use bevy::prelude::*;
+use std::collections::{HashMap, VecDeque};
+
+use super::counters::counters_from_list;
+use super::description::*;
+use crate::cards::Card;
+use crate::cards::details::CardDetails;
+use crate::cards::types::CardTypes;
+use crate::game_engine::commander::components::CommanderZoneLocation;
+use crate::game_engine::commander::{CommandZoneManager, Commander};
+use crate::game_engine::permanent::{
+    Permanent, PermanentController, PermanentOwner, PermanentState,
+};
+use crate::game_engine::state::GameState;
+use crate::game_engine::zones::ZoneManager;
+use crate::mana::Mana;
+use crate::player::Player;
+
+/// Name given to the cards standing in for hidden hands and libraries
+const HIDDEN_CARD: &str = "Hidden card";
+
+/// Entities created when a board is imported
+#[derive(Debug, Clone, Default)]
+pub struct ImportedBoard {
+    /// Player entities in the order they were described
+    pub players: Vec<Entity>,
+    /// Every named card, by player name and card name
+    pub cards: HashMap<(Option<String>, String), Vec<Entity>>,
+}
+
+impl ImportedBoard {
+    /// Player entity with the given index in the description
+    pub fn player(&self, index: usize) -> Option<Entity> {
+        self.players.get(index).copied()
+    }
+
+    /// First card with this name belonging to `player`, or shared when `None`
+    pub fn card(&self, player: Option<&str>, name: &str) -> Option<Entity> {
+        self.cards
+            .get(&(player.map(str::to_string), name.to_string()))
+            .and_then(|cards| cards.first().copied())
+    }
+}
+
+/// A nameless card with no cost or rules, for boards built in tests
+pub fn placeholder_card(name: &str) -> Card {
+    Card::new(
+        name,
+        Mana::default(),
+        CardTypes::NONE,
+        CardDetails::Other,
+        "",
+    )
+}
+
+fn spawn_card(
+    world: &mut World,
+    imported: &mut ImportedBoard,
+    board: &BoardDescription,
+    make_card: &mut impl FnMut(&str) -> Card,
+    owner: Option<usize>,
+    name: &str,
+) -> Entity {
+    let card = world.spawn(make_card(name)).id();
+    let owner_name = owner.map(|index| board.players[index].name.clone());
+    imported
+        .cards
+        .entry((owner_name, name.to_string()))
+        .or_default()
+        .push(card);
+    card
+}
+
+fn spawn_permanent(
+    world: &mut World,
+    imported: &mut ImportedBoard,
+    board: &BoardDescription,
+    make_card: &mut impl FnMut(&str) -> Card,
+    owner: Option<usize>,
+    permanent: &PermanentDescription,
+) -> Entity {
+    let card = spawn_card(world, imported, board, make_card, owner, &permanent.name);
+    let mut entity = world.entity_mut(card);
+    entity.insert((
+        Permanent,
+        PermanentState {
+            is_tapped: permanent.tapped,
+            has_summoning_sickness: false,
+            turn_entered_battlefield: board.turn_number,
+            counters: counters_from_list(&permanent.counters),
+        },
+    ));
+    if let Some(player) = owner.map(|index| imported.players[index]) {
+        entity.insert((
+            PermanentOwner::new(player),
+            PermanentController::new(player),
+        ));
+    }
+    card
+}
+
+/// Rebuilds a described board in `world`
+///
+/// `make_card` creates the card for each name. Hidden hands and libraries are
+/// filled with placeholder cards. The game state, step, zones and commanders are
+/// replaced by the described ones.
+pub fn import_board(
+    world: &mut World,
+    board: &BoardDescription,
+    mut make_card: impl FnMut(&str) -> Card,
+) -> ImportedBoard {
+    let mut imported = ImportedBoard::default();
+    let mut zones = ZoneManager::default();
+    let mut commanders = CommandZoneManager::default();
+
+    for (index, description) in board.players.iter().enumerate() {
+        let player = world
+            .spawn(Player {
+                name: description.name.clone(),
+                life: description.life,
+                player_index: index,
+                ..default()
+            })
+            .id();
+        zones.init_player_zones(player);
+        imported.players.push(player);
+    }
+
+    for permanent in &board.battlefield {
+        let card = spawn_permanent(world, &mut imported, board, &mut make_card, None, permanent);
+        zones.add_to_battlefield(Entity::PLACEHOLDER, card);
+    }
+    for name in &board.exile {
+        let card = spawn_card(world, &mut imported, board, &mut make_card, None, name);
+        zones.add_to_exile(Entity::PLACEHOLDER, card);
+    }
+
+    for (index, description) in board.players.iter().enumerate() {
+        let player = imported.players[index];
+
+        for permanent in &description.battlefield {
+            let card = spawn_permanent(
+                world,
+                &mut imported,
+                board,
+                &mut make_card,
+                Some(index),
+                permanent,
+            );
+            zones.add_to_battlefield(player, card);
+        }
+        for name in &description.graveyard {
+            let card = spawn_card(
+                world,
+                &mut imported,
+                board,
+                &mut make_card,
+                Some(index),
+                name,
+            );
+            zones.add_to_graveyard(player, card);
+        }
+        for _ in 0..description.hand_size {
+            let card = world.spawn(placeholder_card(HIDDEN_CARD)).id();
+            zones.add_to_hand(player, card);
+        }
+        for _ in 0..description.library_size {
+            let card = world.spawn(placeholder_card(HIDDEN_CARD)).id();
+            zones.add_to_library(player, card);
+        }
+
+        for commander in &description.commanders {
+            // A commander outside the command zone is one of the cards already placed
+            let existing = match commander.zone {
+                CommanderZoneLocation::Battlefield | CommanderZoneLocation::Graveyard => {
+                    imported.card(Some(&description.name), &commander.name)
+                }
+                CommanderZoneLocation::Exile => imported.card(None, &commander.name),
+                _ => None,
+            };
+            let card = match existing {
+                Some(card) => card,
+                None => {
+                    let card = spawn_card(
+                        world,
+                        &mut imported,
+                        board,
+                        &mut make_card,
+                        Some(index),
+                        &commander.name,
+                    );
+                    if commander.zone == CommanderZoneLocation::CommandZone {
+                        zones.add_to_command_zone(player, card);
+                    }
+                    card
+                }
+            };
+
+            world.entity_mut(card).insert(Commander {
+                owner: player,
+                cast_count: commander.cast_count,
+                ..default()
+            });
+            commanders
+                .player_commanders
+                .entry(player)
+                .or_default()
+                .push(card);
+            commanders
+                .commander_zone_status
+                .insert(card, commander.zone);
+            commanders
+                .zone_transition_count
+                .insert(card, commander.cast_count);
+        }
+    }
+
+    let active_player = board
+        .active_player
+        .as_ref()
+        .and_then(|name| board.players.iter().position(|player| &player.name == name))
+        .and_then(|index| imported.player(index))
+        .or_else(|| imported.player(0))
+        .unwrap_or(Entity::PLACEHOLDER);
+    let game_state = GameState {
+        turn_number: board.turn_number,
+        active_player,
+        priority_holder: active_player,
+        turn_order: VecDeque::from(imported.players.clone()),
+        ..default()
+    };
+
+    world.insert_resource(game_state);
+    world.insert_resource(board.phase);
+    world.insert_resource(zones);
+    world.insert_resource(commanders);
+
+    imported
+}
@@ -0,0 +1,23 @@
+//! Human-readable board descriptions
+//!
+//! A [`BoardDescription`] holds the public state of a game: life totals, commanders,
+//! each player's battlefield and graveyard, exile, and the turn and step. It can be
+//! captured from a running game, written as Markdown or plain text for bug reports
+//! and puzzles, and parsed back to rebuild the same board in a test.
+
+mod capture;
+mod counters;
+mod description;
+mod import;
+
+#[allow(unused_imports)]
+pub use capture::capture_board;
+#[allow(unused_imports)]
+pub use counters::{counter_list, counters_from_list};
+#[allow(unused_imports)]
+pub use description::{
+    BoardDescription, BoardFormat, BoardParseError, CommanderDescription, PermanentDescription,
+    PlayerBoard,
+};
+#[allow(unused_imports)]
+pub use import::{ImportedBoard, import_board, placeholder_card};
@@ -1,5 +1,8 @@
 use crate::game_engine::save::resources::ReplayAction;
+use crate::game_engine::save::storage;
+use crate::game_engine::stack::{GameStack, StackItemSpec};
 use crate::game_engine::state::GameState;
+use crate::game_engine::zones::{Zone, ZonePosition};
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
@@ -22,6 +25,12 @@ pub struct GameSaveData {
     pub replay_history: Vec<ReplayAction>,
     pub board_snapshot: Option<String>,
     pub timestamp: u64,
+    /// Items on the stack, bottom first
+    ///
+    /// Entities in the items are stored as save indices, see
+    /// [`GameSaveData::from_stack`].
+    #[serde(default)]
+    pub stack: Vec<StackItemSpec>,
 }
 
 impl Default for GameSaveData {
@@ -40,6 +49,7 @@ impl Default for GameSaveData {
             replay_history: Vec::new(),
             board_snapshot: None,
             timestamp: 0,
+            stack: Vec::new(),
         }
     }
 }
@@ -61,6 +71,7 @@ pub struct GameSaveDataBuilder {
     replay_history: Vec<ReplayAction>,
     board_snapshot: Option<String>,
     timestamp: u64,
+    stack: Vec<StackItemSpec>,
 }
 
 #[allow(dead_code)]
@@ -160,6 +171,13 @@ impl GameSaveDataBuilder {
         self
     }
 
+    /// Set the stack items
+    #[allow(dead_code)]
+    pub fn stack(mut self, stack: Vec<StackItemSpec>) -> Self {
+        self.stack = stack;
+        self
+    }
+
     /// Build the GameSaveData instance
     pub fn build(self) -> GameSaveData {
         GameSaveData {
@@ -176,6 +194,7 @@ impl GameSaveDataBuilder {
             replay_history: self.replay_history,
             board_snapshot: self.board_snapshot,
             timestamp: self.timestamp,
+            stack: self.stack,
         }
     }
 }
@@ -341,10 +360,8 @@ impl GameSaveData {
             priority_player: Some(priority_holder_index),
             replay_history: Vec::new(),
             board_snapshot: None,
-            timestamp: std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_secs(),
+            timestamp: storage::timestamp(),
+            stack: Vec::new(),
         }
     }
 
@@ -354,65 +371,37 @@ impl GameSaveData {
         entity_to_index: &HashMap<Entity, usize>,
     ) -> ZoneData {
         let mut zone_data = ZoneData::default();
+        let indices = |cards: &[Entity]| -> Vec<usize> {
+            cards
+                .iter()
+                .filter_map(|card| entity_to_index.get(card).cloned())
+                .collect()
+        };
 
-        // Process libraries
-        for (player, cards) in &zone_manager.libraries {
-            if let Some(&player_idx) = entity_to_index.get(player) {
-                let card_indices: Vec<usize> = cards
-                    .iter()
-                    .filter_map(|card| entity_to_index.get(card).cloned())
-                    .collect();
-                zone_data.libraries.insert(player_idx, card_indices);
-            }
-        }
-
-        // Process hands
-        for (player, cards) in &zone_manager.hands {
-            if let Some(&player_idx) = entity_to_index.get(player) {
-                let card_indices: Vec<usize> = cards
-                    .iter()
-                    .filter_map(|card| entity_to_index.get(card).cloned())
-                    .collect();
-                zone_data.hands.insert(player_idx, card_indices);
-            }
-        }
-
-        // Process battlefield
-        zone_data.battlefield = zone_manager
-            .battlefield
-            .iter()
-            .filter_map(|card| entity_to_index.get(card).cloned())
-            .collect();
-
-        // Process graveyards
-        for (player, cards) in &zone_manager.graveyards {
-            if let Some(&player_idx) = entity_to_index.get(player) {
-                let card_indices: Vec<usize> = cards
-                    .iter()
-                    .filter_map(|card| entity_to_index.get(card).cloned())
-                    .collect();
-                zone_data.graveyards.insert(player_idx, card_indices);
+        // Process each player's libraries, hands and graveyards
+        for player in zone_manager.players() {
+            if let Some(&player_idx) = entity_to_index.get(&player) {
+                zone_data
+                    .libraries
+                    .insert(player_idx, indices(zone_manager.library(player)));
+                zone_data
+                    .hands
+                    .insert(player_idx, indices(zone_manager.hand(player)));
+                zone_data
+                    .graveyards
+                    .insert(player_idx, indices(zone_manager.graveyard(player)));
             }
         }
 
-        // Process exile
-        zone_data.exile = zone_manager
-            .exile
-            .iter()
-            .filter_map(|card| entity_to_index.get(card).cloned())
-            .collect();
-
-        // Process command zone
-        zone_data.command_zone = zone_manager
-            .command_zone
-            .iter()
-            .filter_map(|card| entity_to_index.get(card).cloned())
-            .collect();
+        // Process the shared zones
+        zone_data.battlefield = indices(zone_manager.battlefield());
+        zone_data.exile = indices(zone_manager.exile());
+        zone_data.command_zone = indices(zone_manager.command_zone());
 
         // Process card_zone_map
-        for (card, zone) in &zone_manager.card_zone_map {
-            if let Some(&card_idx) = entity_to_index.get(card) {
-                zone_data.card_zone_map.insert(card_idx, *zone);
+        for (card, location) in zone_manager.locations() {
+            if let Some(&card_idx) = entity_to_index.get(&card) {
+                zone_data.card_zone_map.insert(card_idx, location.zone);
             }
         }
 
@@ -466,135 +455,101 @@ impl GameSaveData {
         index_to_entity: &[Entity],
     ) -> crate::game_engine::zones::ZoneManager {
         let mut zone_manager = crate::game_engine::zones::ZoneManager::default();
+        // Mirrors of the zones drop their old copy before the restored cards arrive
+        zone_manager.clear();
+        let entity = |index: &usize| index_to_entity.get(*index).copied();
 
         // Initialize player zones first
-        for player_idx in self.zones.hands.keys() {
-            if *player_idx < index_to_entity.len() {
-                let player = index_to_entity[*player_idx];
-                zone_manager.init_player_zones(player);
-            }
-        }
-
-        for player_idx in self.zones.libraries.keys() {
-            if *player_idx < index_to_entity.len() {
-                let player = index_to_entity[*player_idx];
-                zone_manager.init_player_zones(player);
-            }
-        }
-
-        for player_idx in self.zones.graveyards.keys() {
-            if *player_idx < index_to_entity.len() {
-                let player = index_to_entity[*player_idx];
-                zone_manager.init_player_zones(player);
-            }
+        let player_indices = self
+            .zones
+            .hands
+            .keys()
+            .chain(self.zones.libraries.keys())
+            .chain(self.zones.graveyards.keys());
+        for player in player_indices.filter_map(entity) {
+            zone_manager.init_player_zones(player);
         }
 
-        // Restore libraries
-        for (player_idx, cards) in &self.zones.libraries {
-            if *player_idx < index_to_entity.len() {
-                let player = index_to_entity[*player_idx];
-                let cards_vec: Vec<Entity> = cards
-                    .iter()
-                    .filter_map(|&card_idx| {
-                        if card_idx < index_to_entity.len() {
-                            Some(index_to_entity[card_idx])
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-                zone_manager.libraries.insert(player, cards_vec);
+        // Restore each player's zones, bottom first
+        let player_zones = [
+            (Zone::Library, &self.zones.libraries),
+            (Zone::Hand, &self.zones.hands),
+            (Zone::Graveyard, &self.zones.graveyards),
+        ];
+        for (zone, players) in player_zones {
+            for (player_idx, cards) in players {
+                let Some(player) = entity(player_idx) else {
+                    continue;
+                };
+                for card in cards.iter().filter_map(entity) {
+                    zone_manager.insert(card, player, zone, ZonePosition::Top);
+                }
             }
         }
 
-        // Restore hands
-        for (player_idx, cards) in &self.zones.hands {
-            if *player_idx < index_to_entity.len() {
-                let player = index_to_entity[*player_idx];
-                let cards_vec: Vec<Entity> = cards
-                    .iter()
-                    .filter_map(|&card_idx| {
-                        if card_idx < index_to_entity.len() {
-                            Some(index_to_entity[card_idx])
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-                // Use clear_hand first to ensure there are no stale cards
-                zone_manager.hands.insert(player, cards_vec);
+        // Saves don't record who owns the cards in shared zones
+        let shared_zones = [
+            (Zone::Battlefield, &self.zones.battlefield),
+            (Zone::Exile, &self.zones.exile),
+            (Zone::Command, &self.zones.command_zone),
+        ];
+        for (zone, cards) in shared_zones {
+            for card in cards.iter().filter_map(entity) {
+                zone_manager.insert(card, Entity::PLACEHOLDER, zone, ZonePosition::Top);
             }
         }
 
-        // Restore battlefield
-        zone_manager.battlefield = self
-            .zones
-            .battlefield
-            .iter()
-            .filter_map(|&card_idx| {
-                if card_idx < index_to_entity.len() {
-                    Some(index_to_entity[card_idx])
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        // Restore graveyards
-        for (player_idx, cards) in &self.zones.graveyards {
-            if *player_idx < index_to_entity.len() {
-                let player = index_to_entity[*player_idx];
-                let cards_vec: Vec<Entity> = cards
-                    .iter()
-                    .filter_map(|&card_idx| {
-                        if card_idx < index_to_entity.len() {
-                            Some(index_to_entity[card_idx])
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-                zone_manager.graveyards.insert(player, cards_vec);
+        // Cards only found in the zone map, such as spells on the stack
+        for (card_idx, zone) in &self.zones.card_zone_map {
+            let Some(card) = entity(card_idx) else {
+                continue;
+            };
+            if zone_manager.location(card).is_none() {
+                zone_manager.insert(card, Entity::PLACEHOLDER, *zone, ZonePosition::Top);
             }
         }
+        zone_manager
+    }
 
-        // Restore exile
-        zone_manager.exile = self
-            .zones
-            .exile
-            .iter()
-            .filter_map(|&card_idx| {
-                if card_idx < index_to_entity.len() {
-                    Some(index_to_entity[card_idx])
-                } else {
-                    None
-                }
+    /// Describe the stack's items with entity references converted to indices
+    ///
+    /// Entities without an index, like the cards spells were cast from, are
+    /// stored as [`Entity::PLACEHOLDER`].
+    pub fn from_stack(
+        stack: &GameStack,
+        entity_to_index: &HashMap<Entity, usize>,
+    ) -> Vec<StackItemSpec> {
+        stack
+            .specs()
+            .into_iter()
+            .map(|item| StackItemSpec {
+                spec: item.spec.map_entities(|entity| {
+                    entity_to_index
+                        .get(&entity)
+                        .map_or(Entity::PLACEHOLDER, |&index| Entity::from_raw(index as u32))
+                }),
+                ..item
             })
-            .collect();
+            .collect()
+    }
 
-        // Restore command zone
-        zone_manager.command_zone = self
-            .zones
-            .command_zone
+    /// Restore the stack's items with their entities from saved data
+    pub fn to_stack_items(&self, index_to_entity: &[Entity]) -> Vec<StackItemSpec> {
+        self.stack
             .iter()
-            .filter_map(|&card_idx| {
-                if card_idx < index_to_entity.len() {
-                    Some(index_to_entity[card_idx])
-                } else {
-                    None
-                }
+            .map(|item| StackItemSpec {
+                spec: item.spec.map_entities(|entity| {
+                    if entity == Entity::PLACEHOLDER {
+                        return entity;
+                    }
+                    index_to_entity
+                        .get(entity.index() as usize)
+                        .copied()
+                        .unwrap_or(Entity::PLACEHOLDER)
+                }),
+                ..item.clone()
             })
-            .collect();
-
-        // Restore card zone map
-        for (card_idx, zone) in &self.zones.card_zone_map {
-            if *card_idx < index_to_entity.len() {
-                let card = index_to_entity[*card_idx];
-                zone_manager.card_zone_map.insert(card, *zone);
-            }
-        }
-
-        zone_manager
+            .collect()
     }
 
     /// Restore CommandZoneManager from saved data
@@ -715,4 +670,10 @@ pub struct SaveInfo {
     pub description: String,
     pub turn_number: u32,
     pub player_count: usize,
+    /// Names of the players in the saved game
+    #[serde(default)]
+    pub player_names: Vec<String>,
+    /// Filename of the board snapshot taken with the save, if any
+    #[serde(default)]
+    pub thumbnail: Option<String>,
 }
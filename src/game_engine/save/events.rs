@@ -1,5 +1,8 @@
 use bevy::prelude::*;
 
+use crate::game_engine::save::board::BoardFormat;
+use crate::game_engine::save::migration::SaveIncompatibility;
+
 /// Event to request saving a game
 #[derive(Event, Debug, Clone)]
 pub struct SaveGameEvent {
@@ -13,12 +16,38 @@ pub struct SaveGameEvent {
     pub with_snapshot: bool,
 }
 
+/// Event to delete a save slot and its file
+#[derive(Event, Debug, Clone)]
+pub struct DeleteSaveEvent {
+    pub slot_name: String,
+}
+
+/// Event to rename a save slot
+#[derive(Event, Debug, Clone)]
+pub struct RenameSaveEvent {
+    pub slot_name: String,
+    pub new_name: String,
+}
+
 /// Event to trigger loading a saved game
 #[derive(Event)]
 pub struct LoadGameEvent {
     pub slot_name: String,
 }
 
+/// Sent when a save can't be loaded, listing why
+#[derive(Event, Debug, Clone)]
+pub struct SaveIncompatibleEvent {
+    pub slot_name: String,
+    pub incompatibility: SaveIncompatibility,
+}
+
+/// Event to write a description of the current board for sharing
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ExportBoardEvent {
+    pub format: BoardFormat,
+}
+
 /// Event for checking state-based actions
 #[derive(Event)]
 pub struct CheckStateBasedActionsEvent;
@@ -0,0 +1,338 @@
+//! Versioned save files and upgrades for older saves
+//!
+//! Game saves are written as a [`SaveEnvelope`]: a small bincode header naming the
+//! save format version, followed by the game data as JSON. Keeping the payload
+//! self-describing lets a [`SaveMigration`] add defaults for new fields or rename
+//! fields and types before the data is turned back into a [`GameSaveData`].
+//!
+//! Saves written before the header existed are treated as format version 0.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+use crate::game_engine::save::data::GameSaveData;
+
+/// Version of the save format written by this build
+pub const CURRENT_SAVE_FORMAT: u32 = 1;
+
+/// Marker at the start of every versioned save
+const SAVE_MAGIC: &str = "RUMMAGE_SAVE";
+
+/// Header written in front of the game data
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaveHeader {
+    /// Always `RUMMAGE_SAVE`
+    pub magic: String,
+    /// Save format version the payload was written with
+    pub format_version: u32,
+    /// Version of the game that wrote the save
+    pub game_version: String,
+}
+
+impl Default for SaveHeader {
+    fn default() -> Self {
+        Self {
+            magic: SAVE_MAGIC.to_string(),
+            format_version: CURRENT_SAVE_FORMAT,
+            game_version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+}
+
+/// A save file: the header followed by the game data as JSON
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveEnvelope {
+    pub header: SaveHeader,
+    pub payload: String,
+}
+
+/// Why a save could not be loaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveIncompatibility {
+    /// Format version found in the save, if it could be read
+    pub found_version: Option<u32>,
+    /// Game version that wrote the save, if known
+    pub game_version: Option<String>,
+    /// Each problem found, in the order it was found
+    pub problems: Vec<String>,
+}
+
+impl SaveIncompatibility {
+    fn new(found_version: Option<u32>, problem: impl Into<String>) -> Self {
+        Self {
+            found_version,
+            game_version: None,
+            problems: vec![problem.into()],
+        }
+    }
+}
+
+impl fmt::Display for SaveIncompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.found_version {
+            Some(version) => write!(f, "save format {}", version)?,
+            None => write!(f, "unknown save format")?,
+        }
+        if let Some(game_version) = &self.game_version {
+            write!(f, " (written by {})", game_version)?;
+        }
+        write!(f, ": {}", self.problems.join("; "))
+    }
+}
+
+/// Upgrade function applied to the JSON form of a save
+pub type MigrationFn = Box<dyn Fn(&mut Value) -> Result<(), String> + Send + Sync>;
+
+/// Upgrades saves from one format version to the next
+pub struct SaveMigration {
+    /// Version this migration upgrades from; it produces `from_version + 1`
+    pub from_version: u32,
+    /// What the migration changes, shown when it fails
+    pub description: String,
+    migrate: MigrationFn,
+}
+
+impl SaveMigration {
+    /// Create a migration from `from_version` to the next version
+    pub fn new(
+        from_version: u32,
+        description: impl Into<String>,
+        migrate: impl Fn(&mut Value) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            from_version,
+            description: description.into(),
+            migrate: Box::new(migrate),
+        }
+    }
+
+    /// Apply this migration to the JSON form of a save
+    pub fn apply(&self, save: &mut Value) -> Result<(), String> {
+        (self.migrate)(save)
+    }
+
+    /// Migration that fills in a field added in the next version
+    pub fn default_field(from_version: u32, path: &[&str], field: &str, default: Value) -> Self {
+        let path: Vec<String> = path.iter().map(|s| s.to_string()).collect();
+        let field = field.to_string();
+        Self::new(
+            from_version,
+            format!("add default for {}", field_path(&path, &field)),
+            move |save| {
+                let object = object_at(save, &path)?;
+                object
+                    .entry(field.clone())
+                    .or_insert_with(|| default.clone());
+                Ok(())
+            },
+        )
+    }
+
+    /// Migration that renames a field
+    pub fn rename_field(from_version: u32, path: &[&str], from: &str, to: &str) -> Self {
+        let path: Vec<String> = path.iter().map(|s| s.to_string()).collect();
+        let (from, to) = (from.to_string(), to.to_string());
+        Self::new(
+            from_version,
+            format!("rename {} to {}", field_path(&path, &from), to),
+            move |save| {
+                let object = object_at(save, &path)?;
+                if let Some(value) = object.remove(&from) {
+                    object.insert(to.clone(), value);
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /// Migration that renames a unit enum variant stored in a field
+    pub fn rename_variant(
+        from_version: u32,
+        path: &[&str],
+        field: &str,
+        from: &str,
+        to: &str,
+    ) -> Self {
+        let path: Vec<String> = path.iter().map(|s| s.to_string()).collect();
+        let (field, from, to) = (field.to_string(), from.to_string(), to.to_string());
+        Self::new(
+            from_version,
+            format!("rename {} to {} in {}", from, to, field_path(&path, &field)),
+            move |save| {
+                let object = object_at(save, &path)?;
+                if let Some(value) = object
+                    .get_mut(&field)
+                    .filter(|value| value.as_str() == Some(from.as_str()))
+                {
+                    *value = Value::String(to.clone());
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
+impl fmt::Debug for SaveMigration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SaveMigration")
+            .field("from_version", &self.from_version)
+            .field("description", &self.description)
+            .finish()
+    }
+}
+
+fn field_path(path: &[String], field: &str) -> String {
+    path.iter()
+        .map(String::as_str)
+        .chain(std::iter::once(field))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn object_at<'a>(
+    save: &'a mut Value,
+    path: &[String],
+) -> Result<&'a mut serde_json::Map<String, Value>, String> {
+    let mut value = save;
+    for key in path {
+        value = value
+            .get_mut(key)
+            .ok_or_else(|| format!("missing object `{}`", key))?;
+    }
+    value
+        .as_object_mut()
+        .ok_or_else(|| format!("`{}` is not an object", path.join(".")))
+}
+
+/// All known save migrations, applied in order when loading an older save
+#[derive(Resource, Debug)]
+pub struct SaveMigrationRegistry {
+    migrations: Vec<SaveMigration>,
+}
+
+impl Default for SaveMigrationRegistry {
+    fn default() -> Self {
+        let mut registry = Self {
+            migrations: Vec::new(),
+        };
+        // Saves from before the versioned header hold the same data
+        registry.register(SaveMigration::new(0, "add versioned save header", |_| {
+            Ok(())
+        }));
+        registry
+    }
+}
+
+impl SaveMigrationRegistry {
+    /// An empty registry
+    pub fn empty() -> Self {
+        Self {
+            migrations: Vec::new(),
+        }
+    }
+
+    /// Add a migration, replacing any existing one from the same version
+    pub fn register(&mut self, migration: SaveMigration) {
+        self.migrations
+            .retain(|existing| existing.from_version != migration.from_version);
+        self.migrations.push(migration);
+        self.migrations
+            .sort_by_key(|migration| migration.from_version);
+    }
+
+    /// Upgrade the JSON form of a save from `from_version` to the current format
+    pub fn migrate(&self, save: &mut Value, from_version: u32) -> Result<(), SaveIncompatibility> {
+        for version in from_version..CURRENT_SAVE_FORMAT {
+            let Some(migration) = self
+                .migrations
+                .iter()
+                .find(|migration| migration.from_version == version)
+            else {
+                return Err(SaveIncompatibility::new(
+                    Some(from_version),
+                    format!("no upgrade from save format {} to {}", version, version + 1),
+                ));
+            };
+            migration.apply(save).map_err(|error| {
+                SaveIncompatibility::new(
+                    Some(from_version),
+                    format!("could not {}: {}", migration.description, error),
+                )
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Read a save file, upgrading it if it was written by an older version
+    pub fn decode(&self, bytes: &[u8]) -> Result<GameSaveData, SaveIncompatibility> {
+        let config = bincode::config::standard();
+
+        let envelope = bincode::serde::decode_from_slice::<SaveEnvelope, _>(bytes, config)
+            .ok()
+            .map(|(envelope, _)| envelope)
+            .filter(|envelope| envelope.header.magic == SAVE_MAGIC);
+
+        let (header, mut save) = match envelope {
+            Some(envelope) => {
+                let save = serde_json::from_str::<Value>(&envelope.payload).map_err(|e| {
+                    SaveIncompatibility::new(
+                        Some(envelope.header.format_version),
+                        format!("save data is corrupted: {}", e),
+                    )
+                })?;
+                (envelope.header, save)
+            }
+            None => {
+                // Saves from before the header were the game data on its own
+                let (legacy, _) = bincode::serde::decode_from_slice::<GameSaveData, _>(
+                    bytes, config,
+                )
+                .map_err(|_| SaveIncompatibility::new(None, "not a recognised save file"))?;
+                let save = serde_json::to_value(&legacy)
+                    .map_err(|e| SaveIncompatibility::new(Some(0), e.to_string()))?;
+                let header = SaveHeader {
+                    format_version: 0,
+                    game_version: legacy.save_version.clone(),
+                    ..default()
+                };
+                (header, save)
+            }
+        };
+
+        let with_version = |mut incompatibility: SaveIncompatibility| {
+            incompatibility.game_version = Some(header.game_version.clone());
+            incompatibility
+        };
+
+        if header.format_version > CURRENT_SAVE_FORMAT {
+            return Err(with_version(SaveIncompatibility::new(
+                Some(header.format_version),
+                format!(
+                    "save format {} is newer than the supported format {}",
+                    header.format_version, CURRENT_SAVE_FORMAT
+                ),
+            )));
+        }
+
+        self.migrate(&mut save, header.format_version)
+            .map_err(with_version)?;
+
+        serde_json::from_value(save).map_err(|e| {
+            with_version(SaveIncompatibility::new(
+                Some(header.format_version),
+                format!("save data doesn't match this version: {}", e),
+            ))
+        })
+    }
+}
+
+/// Encode game data as a versioned save file
+pub fn encode_save(save: &GameSaveData) -> Result<Vec<u8>, String> {
+    let envelope = SaveEnvelope {
+        header: SaveHeader::default(),
+        payload: serde_json::to_string(save).map_err(|e| e.to_string())?,
+    };
+    bincode::serde::encode_to_vec(&envelope, bincode::config::standard()).map_err(|e| e.to_string())
+}
@@ -1,8 +1,11 @@
 // Internal modules
+pub mod board;
 pub mod data;
 pub mod events;
+pub mod migration;
 pub mod plugin;
 pub mod resources;
+pub mod storage;
 pub mod systems;
 
 #[cfg(test)]
@@ -13,14 +16,25 @@ pub use plugin::SaveLoadPlugin;
 
 // Re-export data types
 #[allow(unused_imports)]
-pub use data::{GameSaveData, GameStateData, PlayerData};
+pub use data::{GameSaveData, GameStateData, PlayerData, SaveInfo};
 
 // Re-export resources
 #[allow(unused_imports)]
-pub use resources::{AutoSaveTracker, ReplayState, SaveConfig, SaveMetadata};
+pub use resources::{AutoSaveTracker, CheckpointPolicy, ReplayState, SaveConfig, SaveMetadata};
+
+// Re-export board descriptions
+#[allow(unused_imports)]
+pub use board::{BoardDescription, BoardFormat, capture_board, import_board};
+
+// Re-export save format types
+#[allow(unused_imports)]
+pub use migration::{
+    CURRENT_SAVE_FORMAT, SaveIncompatibility, SaveMigration, SaveMigrationRegistry,
+};
 
 // Re-export events
 #[allow(unused_imports)]
 pub use events::{
-    CheckStateBasedActionsEvent, LoadGameEvent, SaveGameEvent, StartReplayEvent, StepReplayEvent,
+    CheckStateBasedActionsEvent, DeleteSaveEvent, ExportBoardEvent, LoadGameEvent, RenameSaveEvent,
+    RollbackEvent, SaveGameEvent, SaveIncompatibleEvent, StartReplayEvent, StepReplayEvent,
 };
@@ -1,7 +1,9 @@
 use crate::game_engine::save::events::*;
+use crate::game_engine::save::migration::SaveMigrationRegistry;
 use crate::game_engine::save::resources::*;
 use crate::game_engine::save::systems::*;
 use crate::game_engine::state::GameState;
+use crate::game_engine::turns::TurnStartEvent;
 use bevy::prelude::*;
 
 /// Plugin for save and load game functionality
@@ -11,6 +13,10 @@ impl Plugin for SaveLoadPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<SaveGameEvent>()
             .add_event::<LoadGameEvent>()
+            .add_event::<SaveIncompatibleEvent>()
+            .add_event::<DeleteSaveEvent>()
+            .add_event::<ExportBoardEvent>()
+            .add_event::<RenameSaveEvent>()
             .add_event::<CheckStateBasedActionsEvent>()
             .add_event::<StartReplayEvent>()
             .add_event::<StepReplayEvent>()
@@ -23,7 +29,10 @@ impl Plugin for SaveLoadPlugin {
             .add_event::<CaptureHistoryEvent>()
             .add_event::<HistoryForwardEvent>()
             .add_event::<HistoryBackwardEvent>()
+            .add_event::<TurnStartEvent>()
             .init_resource::<GameHistory>()
+            .init_resource::<CheckpointPolicy>()
+            .init_resource::<SaveMigrationRegistry>()
             .init_resource::<SaveEvents>()
             .add_systems(Startup, setup_save_system);
 
@@ -36,12 +45,26 @@ impl Plugin for SaveLoadPlugin {
         app.add_systems(FixedUpdate, collect_save_events.run_if(condition));
         // Add the process_save_game system only when implemented with compatible signature
 
+        // Slot management doesn't need a running game
+        app.add_systems(Update, (handle_delete_save, handle_rename_save));
+
+        // Board descriptions for bug reports and puzzles
+        app.add_systems(
+            Update,
+            (
+                check_export_board_key_input.run_if(resource_exists::<ButtonInput<KeyCode>>),
+                handle_export_board.run_if(condition),
+            )
+                .chain(),
+        );
+
         // History and timeline management systems
         app.add_systems(
             FixedUpdate,
             (
                 handle_load_game,
                 handle_auto_save,
+                handle_turn_checkpoint,
                 handle_start_replay,
                 handle_step_replay,
                 handle_stop_replay,
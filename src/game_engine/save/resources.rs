@@ -63,6 +63,45 @@ impl Default for SaveConfig {
     }
 }
 
+/// Retention policy for the checkpoints saved at the start of each turn
+#[derive(Debug, Resource, Clone)]
+pub struct CheckpointPolicy {
+    /// Number of most recent checkpoints to keep
+    pub keep_last: usize,
+    /// Older checkpoints are kept on turns divisible by this (0 keeps none)
+    pub keep_every: u32,
+}
+
+impl Default for CheckpointPolicy {
+    fn default() -> Self {
+        Self {
+            keep_last: 5,
+            keep_every: 5,
+        }
+    }
+}
+
+impl CheckpointPolicy {
+    /// Slot name used for the checkpoint of a turn
+    pub fn slot_name(turn: u32) -> String {
+        format!("checkpoint_turn_{}", turn)
+    }
+
+    /// Checkpoints that fall outside the policy and should be deleted
+    pub fn expired<'a>(&self, checkpoints: &'a [SaveInfo]) -> Vec<&'a SaveInfo> {
+        let mut newest_first: Vec<&SaveInfo> = checkpoints.iter().collect();
+        newest_first.sort_by(|a, b| b.turn_number.cmp(&a.turn_number));
+
+        newest_first
+            .into_iter()
+            .skip(self.keep_last)
+            .filter(|checkpoint| {
+                self.keep_every == 0 || checkpoint.turn_number % self.keep_every != 0
+            })
+            .collect()
+    }
+}
+
 /// Metadata about all saved games
 #[derive(Resource, Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SaveMetadata {
@@ -70,6 +109,58 @@ pub struct SaveMetadata {
     pub checkpoints: Vec<SaveInfo>,
 }
 
+impl SaveMetadata {
+    /// Find a save by slot name
+    pub fn find(&self, slot_name: &str) -> Option<&SaveInfo> {
+        self.saves.iter().find(|save| save.slot_name == slot_name)
+    }
+
+    /// Saves ordered from newest to oldest
+    pub fn newest_first(&self) -> Vec<&SaveInfo> {
+        let mut saves: Vec<&SaveInfo> = self.saves.iter().collect();
+        saves.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        saves
+    }
+
+    /// Remove a save, returning its info if it existed
+    pub fn remove(&mut self, slot_name: &str) -> Option<SaveInfo> {
+        let index = self
+            .saves
+            .iter()
+            .position(|save| save.slot_name == slot_name)?;
+        Some(self.saves.remove(index))
+    }
+
+    /// Rename a save
+    ///
+    /// Fails if the save doesn't exist or the new name is blank or already taken.
+    pub fn rename(&mut self, slot_name: &str, new_name: &str) -> bool {
+        let new_name = new_name.trim();
+        if new_name.is_empty() || self.find(new_name).is_some() {
+            return false;
+        }
+        match self
+            .saves
+            .iter_mut()
+            .find(|save| save.slot_name == slot_name)
+        {
+            Some(save) => {
+                save.slot_name = new_name.to_string();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The first free slot name of the form "Save N"
+    pub fn next_slot_name(&self) -> String {
+        (1..)
+            .map(|n| format!("Save {}", n))
+            .find(|name| self.find(name).is_none())
+            .expect("slot numbers are unbounded")
+    }
+}
+
 /// Replay state for game replay functionality
 #[derive(Resource, Default)]
 pub struct ReplayState {
@@ -0,0 +1,112 @@
+//! Where save files are kept
+//!
+//! Natively, save files are plain files in the save directory. Browsers have no
+//! filesystem, so wasm builds keep them in the page's `localStorage` instead,
+//! hex encoded and keyed by their path. That is the same store `bevy-persistent`
+//! keeps the `/local/` save metadata and settings in, so a browser profile holds
+//! a player's whole state.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use native::{exists, read, remove, rename, write};
+#[cfg(target_arch = "wasm32")]
+pub use web::{exists, read, remove, rename, write};
+
+/// Seconds since the Unix epoch, to stamp saves with
+///
+/// `SystemTime::now` panics in browsers, while chrono asks the page's clock.
+pub fn timestamp() -> u64 {
+    chrono::Utc::now().timestamp().max(0) as u64
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use std::io;
+    use std::path::Path;
+
+    /// Read a stored file
+    pub fn read(path: &Path) -> io::Result<Vec<u8>> {
+        std::fs::read(path)
+    }
+
+    /// Store a file, creating its directory if needed
+    pub fn write(path: &Path, bytes: &[u8]) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, bytes)
+    }
+
+    /// Remove a stored file
+    pub fn remove(path: &Path) -> io::Result<()> {
+        std::fs::remove_file(path)
+    }
+
+    /// Move a stored file to another path
+    pub fn rename(from: &Path, to: &Path) -> io::Result<()> {
+        std::fs::rename(from, to)
+    }
+
+    /// Whether a file is stored at `path`
+    pub fn exists(path: &Path) -> bool {
+        path.exists()
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use std::io;
+    use std::path::Path;
+
+    fn local_storage() -> io::Result<web_sys::Storage> {
+        web_sys::window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Unsupported, "no localStorage"))
+    }
+
+    fn key(path: &Path) -> String {
+        path.to_string_lossy().into_owned()
+    }
+
+    fn get(path: &Path) -> io::Result<Option<String>> {
+        local_storage()?
+            .get_item(&key(path))
+            .map_err(|e| io::Error::other(format!("{:?}", e)))
+    }
+
+    fn set(path: &Path, value: &str) -> io::Result<()> {
+        // Fails once the page's storage quota is used up
+        local_storage()?
+            .set_item(&key(path), value)
+            .map_err(|e| io::Error::other(format!("{:?}", e)))
+    }
+
+    /// Read a stored file
+    pub fn read(path: &Path) -> io::Result<Vec<u8>> {
+        let value = get(path)?.ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        hex::decode(value).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Store a file
+    pub fn write(path: &Path, bytes: &[u8]) -> io::Result<()> {
+        set(path, &hex::encode(bytes))
+    }
+
+    /// Remove a stored file
+    pub fn remove(path: &Path) -> io::Result<()> {
+        local_storage()?
+            .remove_item(&key(path))
+            .map_err(|e| io::Error::other(format!("{:?}", e)))
+    }
+
+    /// Move a stored file to another path
+    pub fn rename(from: &Path, to: &Path) -> io::Result<()> {
+        let value = get(from)?.ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        set(to, &value)?;
+        remove(from)
+    }
+
+    /// Whether a file is stored at `path`
+    pub fn exists(path: &Path) -> bool {
+        matches!(get(path), Ok(Some(_)))
+    }
+}
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 
 use crate::game_engine::save::events::*;
 use crate::game_engine::save::resources::*;
+use crate::game_engine::save::storage;
 use crate::game_engine::state::GameState;
 
 /// Handles automatic saving of the game state at regular intervals
@@ -23,12 +24,8 @@ pub fn handle_auto_save(
         info!("Auto-saving game...");
 
         // Generate a timestamp-based save name
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default();
-
         event_writer.write(SaveGameEvent {
-            slot_name: format!("auto_save_{}", now.as_secs()),
+            slot_name: format!("auto_save_{}", storage::timestamp()),
             description: Some("Auto save".to_string()),
             with_snapshot: true,
         });
@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+
+use crate::game_engine::save::board::{BoardFormat, capture_board};
+use crate::game_engine::save::events::ExportBoardEvent;
+use crate::game_engine::save::resources::SaveConfig;
+use crate::game_engine::save::storage;
+
+use super::get_storage_path;
+
+/// Requests a Markdown board description when F7 is pressed
+pub fn check_export_board_key_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut export_events: EventWriter<ExportBoardEvent>,
+) {
+    if keyboard.just_pressed(KeyCode::F7) {
+        export_events.write(ExportBoardEvent {
+            format: BoardFormat::Markdown,
+        });
+    }
+}
+
+/// Writes the current board description to the save directory for sharing
+pub fn handle_export_board(world: &mut World) {
+    let formats: Vec<BoardFormat> = world
+        .resource_mut::<Events<ExportBoardEvent>>()
+        .drain()
+        .map(|event| event.format)
+        .collect();
+    if formats.is_empty() {
+        return;
+    }
+
+    let board = capture_board(world);
+    let Some(config) = world.get_resource::<SaveConfig>() else {
+        return;
+    };
+
+    for format in formats {
+        let text = board.to_text(format);
+        info!("Board description:\n{}", text);
+
+        let path = get_storage_path(
+            config,
+            &format!("board_turn_{}.{}", board.turn_number, format.extension()),
+        );
+        match storage::write(&path, text.as_bytes()) {
+            Ok(()) => info!("Exported board description to {:?}", path),
+            Err(e) => error!("Failed to export board description: {}", e),
+        }
+    }
+}
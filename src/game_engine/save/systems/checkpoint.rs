@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+use bevy_persistent::prelude::*;
+use std::collections::HashMap;
+
+use crate::game_engine::commander::CommandZoneManager;
+use crate::game_engine::save::data::*;
+use crate::game_engine::save::resources::*;
+use crate::game_engine::save::storage;
+use crate::game_engine::stack::GameStack;
+use crate::game_engine::state::GameState;
+use crate::game_engine::turns::TurnStartEvent;
+use crate::game_engine::zones::ZoneManager;
+use crate::player::Player;
+
+use super::get_storage_path;
+use super::utils::write_save_file;
+
+/// Saves a checkpoint at the start of each turn and prunes old ones
+#[allow(clippy::too_many_arguments)]
+pub fn handle_turn_checkpoint(
+    mut turn_start_events: EventReader<TurnStartEvent>,
+    game_state: Res<GameState>,
+    query_players: Query<(Entity, &Player)>,
+    zones: Option<Res<ZoneManager>>,
+    commanders: Option<Res<CommandZoneManager>>,
+    stack: Option<Res<GameStack>>,
+    config: Res<SaveConfig>,
+    policy: Res<CheckpointPolicy>,
+    save_metadata: Option<ResMut<Persistent<SaveMetadata>>>,
+) {
+    // Several turn starts in one frame only need the latest checkpoint
+    let Some(event) = turn_start_events.read().last() else {
+        return;
+    };
+    let Some(mut save_metadata) = save_metadata else {
+        return;
+    };
+    if !config.auto_save_enabled {
+        return;
+    }
+
+    let turn = event.turn_number;
+    let slot_name = CheckpointPolicy::slot_name(turn);
+
+    let mut player_data = Vec::new();
+    let mut entity_to_index = HashMap::new();
+    for (i, (entity, player)) in query_players.iter().enumerate() {
+        entity_to_index.insert(entity, i);
+        player_data.push(PlayerData {
+            id: i,
+            name: player.name.clone(),
+            life: player.life,
+            mana_pool: player.mana_pool.clone(),
+            player_index: i,
+        });
+    }
+
+    let mut save_data = GameSaveData::from_game_state(&game_state, &entity_to_index, player_data);
+    if let Some(zone_manager) = zones.as_ref() {
+        save_data.zones = GameSaveData::from_zone_manager(zone_manager, &entity_to_index);
+    }
+    if let Some(commander_manager) = commanders.as_ref() {
+        save_data.commanders =
+            GameSaveData::from_commander_manager(commander_manager, &entity_to_index);
+    }
+
+    // Add the spells and abilities waiting to resolve
+    if let Some(stack) = stack.as_ref() {
+        save_data.stack = GameSaveData::from_stack(stack, &entity_to_index);
+    }
+
+    let save_path = get_storage_path(&config, &format!("{}.bin", slot_name));
+    if let Err(e) = write_save_file(&save_path, &save_data) {
+        error!("Failed to save checkpoint for turn {}: {}", turn, e);
+        return;
+    }
+
+    let timestamp = storage::timestamp();
+    let checkpoint = SaveInfo {
+        slot_name: slot_name.clone(),
+        timestamp,
+        description: format!("Start of turn {}", turn),
+        turn_number: turn,
+        player_count: save_data.players.len(),
+        player_names: save_data.players.iter().map(|p| p.name.clone()).collect(),
+        thumbnail: None,
+    };
+
+    // A rewound game replays turns, so replace any older checkpoint for this turn
+    save_metadata
+        .checkpoints
+        .retain(|existing| existing.slot_name != slot_name);
+    save_metadata.checkpoints.push(checkpoint);
+
+    let expired: Vec<String> = policy
+        .expired(&save_metadata.checkpoints)
+        .into_iter()
+        .map(|checkpoint| checkpoint.slot_name.clone())
+        .collect();
+    for expired_slot in &expired {
+        let path = get_storage_path(&config, &format!("{}.bin", expired_slot));
+        if let Err(e) = storage::remove(&path) {
+            warn!("Failed to remove old checkpoint: {:?} - {:?}", path, e);
+        }
+        debug!("Pruned checkpoint {}", expired_slot);
+    }
+    save_metadata
+        .checkpoints
+        .retain(|checkpoint| !expired.contains(&checkpoint.slot_name));
+
+    match save_metadata.persist() {
+        Ok(_) => info!("Saved checkpoint for turn {}", turn),
+        Err(e) => error!("Failed to update save metadata: {}", e),
+    }
+}
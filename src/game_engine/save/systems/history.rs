@@ -4,6 +4,7 @@ use crate::game_engine::commander::CommandZoneManager;
 use crate::game_engine::save::data::*;
 use crate::game_engine::save::events::*;
 use crate::game_engine::save::resources::*;
+use crate::game_engine::stack::GameStack;
 use crate::game_engine::state::GameState;
 use crate::game_engine::zones::ZoneManager;
 use crate::player::Player;
@@ -17,6 +18,7 @@ pub fn handle_capture_history(
     query_players: Query<(Entity, &Player)>,
     zones: Option<Res<ZoneManager>>,
     commanders: Option<Res<CommandZoneManager>>,
+    stack: Option<Res<GameStack>>,
     mut game_history: ResMut<GameHistory>,
 ) {
     for _ in event_reader.read() {
@@ -53,6 +55,11 @@ pub fn handle_capture_history(
                 GameSaveData::from_commander_manager(commander_manager, &entity_to_index);
         }
 
+        // Add the spells and abilities waiting to resolve
+        if let Some(stack) = stack.as_ref() {
+            save_data.stack = GameSaveData::from_stack(stack, &entity_to_index);
+        }
+
         // Add to history
         game_history.add_state(save_data);
     }
@@ -66,6 +73,7 @@ pub fn handle_create_branch(
     query_players: Query<(Entity, &Player)>,
     zones: Option<Res<ZoneManager>>,
     commanders: Option<Res<CommandZoneManager>>,
+    stack: Option<Res<GameStack>>,
 ) {
     for event in event_reader.read() {
         info!("Creating new game history branch");
@@ -101,6 +109,11 @@ pub fn handle_create_branch(
                 GameSaveData::from_commander_manager(commander_manager, &entity_to_index);
         }
 
+        // Add the spells and abilities waiting to resolve
+        if let Some(stack) = stack.as_ref() {
+            save_data.stack = GameSaveData::from_stack(stack, &entity_to_index);
+        }
+
         // Create a new branch
         let branch_id = game_history.create_branch(save_data);
 
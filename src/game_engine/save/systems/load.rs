@@ -1,21 +1,25 @@
 use bevy::prelude::*;
-use bevy_persistent::prelude::*;
 
 use crate::game_engine::commander::CommandZoneManager;
-use crate::game_engine::save::data::GameSaveData;
-use crate::game_engine::save::events::LoadGameEvent;
+use crate::game_engine::save::events::{LoadGameEvent, SaveIncompatibleEvent};
+use crate::game_engine::save::migration::SaveMigrationRegistry;
 use crate::game_engine::save::resources::*;
+use crate::game_engine::save::storage;
 use crate::game_engine::state::GameState;
 use crate::game_engine::zones::ZoneManager;
 use crate::player::Player;
 
 use super::get_storage_path;
+use super::utils::read_save_file;
 
 /// System to handle load game requests
+#[allow(clippy::too_many_arguments)]
 pub fn handle_load_game(
     mut event_reader: EventReader<LoadGameEvent>,
+    mut incompatible_events: EventWriter<SaveIncompatibleEvent>,
     mut commands: Commands,
     config: Res<SaveConfig>,
+    migrations: Res<SaveMigrationRegistry>,
     mut query_players: Query<(Entity, &mut Player)>,
     mut game_state: Option<ResMut<GameState>>,
     mut zones: Option<ResMut<ZoneManager>>,
@@ -28,23 +32,13 @@ pub fn handle_load_game(
 
         // Check if the save file exists (only on native platforms)
         #[cfg(not(target_arch = "wasm32"))]
-        if !save_path.exists() {
+        if !storage::exists(&save_path) {
             error!("Save file not found at: {:?}", save_path);
             continue;
         }
 
-        // Create a persistent resource to load the save
-        let persistent_save = Persistent::<GameSaveData>::builder()
-            .name(format!("game_save_{}", event.slot_name))
-            .format(StorageFormat::Bincode)
-            .path(save_path)
-            .default(GameSaveData::default())
-            .build();
-
-        match persistent_save {
-            Ok(save) => {
-                let save_data = save.clone();
-
+        match read_save_file(&save_path, &migrations) {
+            Ok(save_data) => {
                 // Apply the loaded state using the fully qualified path
                 crate::game_engine::save::systems::utils::apply_game_state(
                     &save_data,
@@ -57,8 +51,15 @@ pub fn handle_load_game(
 
                 info!("Game loaded successfully from slot {}", event.slot_name);
             }
-            Err(e) => {
-                error!("Failed to load save: {}", e);
+            Err(incompatibility) => {
+                error!(
+                    "Failed to load save {}: {}",
+                    event.slot_name, incompatibility
+                );
+                incompatible_events.write(SaveIncompatibleEvent {
+                    slot_name: event.slot_name.clone(),
+                    incompatibility,
+                });
             }
         }
     }
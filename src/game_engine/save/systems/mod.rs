@@ -1,17 +1,23 @@
 mod auto_save;
+mod board_export;
+mod checkpoint;
 mod history;
 mod load;
 mod replay;
 mod rewind;
 mod save;
 mod setup;
+mod slots;
 mod utils;
 
 // Re-export all systems and utilities
 pub use auto_save::*;
+pub use board_export::*;
+pub use checkpoint::*;
 pub use history::*;
 pub use load::*;
 pub use replay::*;
 pub use rewind::*;
 pub use save::*;
 pub use setup::*;
+pub use slots::*;
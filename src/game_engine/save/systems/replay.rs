@@ -1,12 +1,12 @@
 use bevy::prelude::*;
-use bevy_persistent::prelude::*;
 
-use crate::game_engine::save::data::*;
 use crate::game_engine::save::events::*;
+use crate::game_engine::save::migration::SaveMigrationRegistry;
 use crate::game_engine::save::resources::*;
-use crate::game_engine::state::GameState;
+use crate::game_engine::state::{GameEvent, GameState};
 
 use super::get_storage_path;
+use super::utils::read_save_file;
 
 /// System to handle starting a replay session
 pub fn handle_start_replay(
@@ -14,6 +14,7 @@ pub fn handle_start_replay(
     mut replay_state: ResMut<ReplayState>,
     _commands: Commands,
     config: Res<SaveConfig>,
+    migrations: Res<SaveMigrationRegistry>,
     mut load_events: EventWriter<LoadGameEvent>,
 ) {
     for event in event_reader.read() {
@@ -21,19 +22,8 @@ pub fn handle_start_replay(
 
         let save_path = get_storage_path(&config, &format!("{}.bin", event.slot_name));
 
-        // Create a persistent resource to load the save
-        let persistent_save = Persistent::<GameSaveData>::builder()
-            .name(format!("game_save_{}", event.slot_name))
-            .format(StorageFormat::Bincode)
-            .path(save_path)
-            .default(GameSaveData::default())
-            .build();
-
-        match persistent_save {
-            Ok(save) => {
-                // Get the loaded data
-                let save_data = save.clone();
-
+        match read_save_file(&save_path, &migrations) {
+            Ok(save_data) => {
                 // Set up replay state with the loaded save
                 replay_state.active = true;
                 replay_state.original_save = Some(save_data.clone());
@@ -148,7 +138,10 @@ fn apply_replay_action(game_state: &mut GameState, action: &ReplayAction) {
         }
         ReplayActionType::EndTurn => {
             // Logic for ending a turn
-            game_state.turn_number += 1;
+            game_state.apply(&GameEvent::TurnStarted {
+                player: game_state.active_player,
+                turn_number: game_state.turn_number + 1,
+            });
         }
     }
 }
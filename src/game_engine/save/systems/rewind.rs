@@ -5,6 +5,7 @@ use crate::game_engine::commander::CommandZoneManager;
 use crate::game_engine::save::data::*;
 use crate::game_engine::save::events::*;
 use crate::game_engine::save::resources::*;
+use crate::game_engine::stack::GameStack;
 use crate::game_engine::state::GameState;
 use crate::game_engine::zones::ZoneManager;
 use crate::player::Player;
@@ -12,6 +13,7 @@ use crate::player::Player;
 use super::utils::apply_game_state;
 
 /// System to handle the start rewind event
+#[allow(clippy::too_many_arguments)]
 pub fn handle_rewind(
     mut event_reader: EventReader<StartRewindEvent>,
     mut game_history: ResMut<GameHistory>,
@@ -20,6 +22,7 @@ pub fn handle_rewind(
     mut query_players: Query<(Entity, &mut Player)>,
     mut zones: Option<ResMut<ZoneManager>>,
     mut commanders: Option<ResMut<CommandZoneManager>>,
+    stack: Option<Res<GameStack>>,
 ) {
     for event in event_reader.read() {
         info!("Rewinding game by {} steps", event.steps);
@@ -68,6 +71,11 @@ pub fn handle_rewind(
                         GameSaveData::from_commander_manager(commander_manager, &entity_to_index);
                 }
 
+                // Add the spells and abilities waiting to resolve
+                if let Some(stack) = stack.as_ref() {
+                    current_save_data.stack = GameSaveData::from_stack(stack, &entity_to_index);
+                }
+
                 // Create a new branch from current state when starting to rewind
                 // This preserves the original timeline
                 game_history.create_branch(current_save_data);
@@ -104,6 +112,7 @@ pub fn handle_rewind(
 }
 
 /// System to handle rewinding to a specific turn
+#[allow(clippy::too_many_arguments)]
 pub fn handle_rewind_to_turn(
     mut event_reader: EventReader<RewindToTurnEvent>,
     mut game_history: ResMut<GameHistory>,
@@ -112,6 +121,7 @@ pub fn handle_rewind_to_turn(
     mut query_players: Query<(Entity, &mut Player)>,
     mut zones: Option<ResMut<ZoneManager>>,
     mut commanders: Option<ResMut<CommandZoneManager>>,
+    stack: Option<Res<GameStack>>,
 ) {
     for event in event_reader.read() {
         info!("Rewinding to turn {}", event.turn);
@@ -160,6 +170,11 @@ pub fn handle_rewind_to_turn(
                         GameSaveData::from_commander_manager(commander_manager, &entity_to_index);
                 }
 
+                // Add the spells and abilities waiting to resolve
+                if let Some(stack) = stack.as_ref() {
+                    current_save_data.stack = GameSaveData::from_stack(stack, &entity_to_index);
+                }
+
                 // Create a new branch from current state when starting to rewind
                 // This preserves the original timeline
                 game_history.create_branch(current_save_data);
@@ -7,12 +7,15 @@ use crate::game_engine::commander::CommandZoneManager;
 use crate::game_engine::save::data::*;
 use crate::game_engine::save::events::*;
 use crate::game_engine::save::resources::*;
+use crate::game_engine::save::storage;
+use crate::game_engine::stack::GameStack;
 use crate::game_engine::state::GameState;
 use crate::game_engine::zones::ZoneManager;
 use crate::player::Player;
 use crate::snapshot::{SaveGameSnapshot, SnapshotEvent};
 
 use super::get_storage_path;
+use super::utils::write_save_file;
 
 /// Collect save game events into the SaveEvents resource
 pub fn collect_save_events(
@@ -26,12 +29,13 @@ pub fn collect_save_events(
 }
 
 /// System that processes save game events
-#[allow(dead_code)]
+#[allow(dead_code, clippy::too_many_arguments)]
 pub fn process_save_game(
     game_state: Option<Res<GameState>>,
     query_players: Query<(Entity, &Player)>,
     zones: Option<Res<ZoneManager>>,
     commanders: Option<Res<CommandZoneManager>>,
+    stack: Option<Res<GameStack>>,
     save_metadata: Option<ResMut<Persistent<SaveMetadata>>>,
     config: Option<Res<SaveConfig>>,
     mut commands: Commands,
@@ -61,6 +65,7 @@ pub fn process_save_game(
             &query_players,
             &zones,
             &commanders,
+            &stack,
             &mut save_metadata,
             &config,
             &mut commands,
@@ -71,13 +76,14 @@ pub fn process_save_game(
 }
 
 /// Process a single save game event
-#[allow(dead_code)]
+#[allow(dead_code, clippy::too_many_arguments)]
 fn process_single_save(
     event: &SaveGameEvent,
     game_state: &Res<GameState>,
     query_players: &Query<(Entity, &Player)>,
     zones: &Option<Res<ZoneManager>>,
     commanders: &Option<Res<CommandZoneManager>>,
+    stack: &Option<Res<GameStack>>,
     save_metadata: &mut ResMut<Persistent<SaveMetadata>>,
     config: &Res<SaveConfig>,
     commands: &mut Commands,
@@ -122,10 +128,7 @@ fn process_single_save(
     // Generate a snapshot filename
     let snapshot_filename = if event.with_snapshot && game_camera.is_some() {
         let camera = game_camera.unwrap();
-        let timestamp = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
+        let timestamp = storage::timestamp();
 
         // Send snapshot event if SnapshotEvent is available in the app
         if let Some(snapshot_events) = snapshot_events.as_mut() {
@@ -179,37 +182,18 @@ fn process_single_save(
             GameSaveData::from_commander_manager(commander_manager, &entity_to_index);
     }
 
+    // Add the spells and abilities waiting to resolve
+    if let Some(stack) = stack.as_ref() {
+        save_data.stack = GameSaveData::from_stack(stack, &entity_to_index);
+    }
+
     let save_path = get_storage_path(config, &format!("{}.bin", event.slot_name));
 
     // Insert as a resource first, then create persistent
     commands.insert_resource(save_data.clone());
 
-    // Create persistent resource for this save
-    let persistent_save = Persistent::<GameSaveData>::builder()
-        .name(format!("game_save_{}", event.slot_name))
-        .format(StorageFormat::Bincode)
-        .path(save_path.clone())
-        .default(save_data.clone())
-        .build();
-
-    match persistent_save {
-        Ok(save) => {
-            // Persist the save immediately
-            if let Err(e) = save.persist() {
-                error!("Failed to save game: {}", e);
-
-                // Fallback: Try to write the file directly
-                #[cfg(not(target_arch = "wasm32"))]
-                {
-                    info!("Attempting direct file write as fallback");
-                    // Just write a placeholder file for testing
-                    if let Err(e) = std::fs::write(&save_path, b"test_save_data") {
-                        error!("Failed to write save file directly: {}", e);
-                        return;
-                    }
-                }
-            }
-
+    match write_save_file(&save_path, &save_data) {
+        Ok(()) => {
             // Verify save file was created for native platforms
             #[cfg(not(target_arch = "wasm32"))]
             {
@@ -232,10 +216,7 @@ fn process_single_save(
             info!("Game saved successfully to slot {}", event.slot_name);
 
             // Update metadata
-            let timestamp = std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_secs();
+            let timestamp = storage::timestamp();
 
             let save_info = SaveInfo {
                 slot_name: event.slot_name.clone(),
@@ -246,6 +227,8 @@ fn process_single_save(
                     .unwrap_or_else(|| format!("Turn {}", game_state.turn_number)),
                 turn_number: game_state.turn_number,
                 player_count: query_players.iter().count(),
+                player_names: save_data.players.iter().map(|p| p.name.clone()).collect(),
+                thumbnail: save_data.board_snapshot.clone(),
             };
 
             // Add or update save info in metadata
@@ -289,10 +272,7 @@ fn process_single_save(
             if event.with_snapshot && config.capture_snapshots {
                 if let Some(snapshot_events) = snapshot_events {
                     // Format a unique name for the snapshot
-                    let timestamp = std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_secs();
+                    let timestamp = storage::timestamp();
 
                     let snapshot_name = format!(
                         "save_test_snapshot_save_turn_{}_t{}.png",
@@ -330,7 +310,7 @@ fn process_single_save(
                         // Try to delete the file
                         let oldest_path =
                             get_storage_path(config, &format!("{}.bin", oldest_save.slot_name));
-                        match std::fs::remove_file(&oldest_path) {
+                        match storage::remove(&oldest_path) {
                             Ok(_) => debug!("Removed old save file: {:?}", oldest_path),
                             Err(e) => warn!(
                                 "Failed to remove old save file: {:?} - {:?}",
@@ -345,7 +325,7 @@ fn process_single_save(
             }
         }
         Err(e) => {
-            error!("Failed to create persistent save: {}", e);
+            error!("Failed to save game: {}", e);
         }
     }
 }
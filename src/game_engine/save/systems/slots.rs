@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use bevy_persistent::prelude::*;
+
+use crate::game_engine::save::events::{DeleteSaveEvent, RenameSaveEvent};
+use crate::game_engine::save::resources::*;
+use crate::game_engine::save::storage;
+
+use super::get_storage_path;
+
+/// System to delete save slots along with their save files
+pub fn handle_delete_save(
+    mut event_reader: EventReader<DeleteSaveEvent>,
+    config: Option<Res<SaveConfig>>,
+    save_metadata: Option<ResMut<Persistent<SaveMetadata>>>,
+) {
+    let (Some(config), Some(mut save_metadata)) = (config, save_metadata) else {
+        return;
+    };
+
+    for event in event_reader.read() {
+        if save_metadata.remove(&event.slot_name).is_none() {
+            warn!("No save found in slot: {}", event.slot_name);
+            continue;
+        }
+
+        let save_path = get_storage_path(&config, &format!("{}.bin", event.slot_name));
+        if let Err(e) = storage::remove(&save_path) {
+            warn!("Failed to remove save file: {:?} - {:?}", save_path, e);
+        }
+
+        match save_metadata.persist() {
+            Ok(_) => info!("Deleted save slot: {}", event.slot_name),
+            Err(e) => error!("Failed to update save metadata: {}", e),
+        }
+    }
+}
+
+/// System to rename save slots along with their save files
+pub fn handle_rename_save(
+    mut event_reader: EventReader<RenameSaveEvent>,
+    config: Option<Res<SaveConfig>>,
+    save_metadata: Option<ResMut<Persistent<SaveMetadata>>>,
+) {
+    let (Some(config), Some(mut save_metadata)) = (config, save_metadata) else {
+        return;
+    };
+
+    for event in event_reader.read() {
+        let new_name = event.new_name.trim();
+        if !save_metadata.rename(&event.slot_name, new_name) {
+            warn!(
+                "Cannot rename save slot {} to {:?}",
+                event.slot_name, event.new_name
+            );
+            continue;
+        }
+
+        let old_path = get_storage_path(&config, &format!("{}.bin", event.slot_name));
+        let new_path = get_storage_path(&config, &format!("{}.bin", new_name));
+        if let Err(e) = storage::rename(&old_path, &new_path) {
+            warn!(
+                "Failed to rename save file {:?} to {:?} - {:?}",
+                old_path, new_path, e
+            );
+        }
+
+        match save_metadata.persist() {
+            Ok(_) => info!("Renamed save slot {} to {}", event.slot_name, new_name),
+            Err(e) => error!("Failed to update save metadata: {}", e),
+        }
+    }
+}
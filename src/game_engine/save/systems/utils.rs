@@ -1,9 +1,16 @@
 use bevy::prelude::*;
 use std::collections::HashMap;
+use std::path::Path;
 
 use crate::game_engine::commander::CommandZoneManager;
 use crate::game_engine::save::data::*;
+use crate::game_engine::save::migration::{
+    SaveIncompatibility, SaveMigrationRegistry, encode_save,
+};
+use crate::game_engine::save::storage;
+use crate::game_engine::stack::GameStack;
 use crate::game_engine::state::GameState;
+use crate::game_engine::turn_order::cancel_turn_order_roll;
 use crate::game_engine::zones::ZoneManager;
 use crate::player::Player;
 
@@ -94,4 +101,43 @@ pub fn apply_game_state(
             **commander_manager = save_data.to_commander_manager(&index_to_entity);
         }
     }
+
+    // Replace the stack with the saved one, each item getting a new entity
+    let stack_items = save_data.to_stack_items(&index_to_entity);
+    commands.queue(move |world: &mut World| {
+        if !world.contains_resource::<GameStack>() {
+            return;
+        }
+        let entities: Vec<Entity> = stack_items
+            .iter()
+            .map(|item| world.spawn(Name::new(item.spec.name().to_string())).id())
+            .collect();
+        let mut stack = world.resource_mut::<GameStack>();
+        *stack = GameStack::default();
+        for (item, entity) in stack_items.iter().zip(entities) {
+            stack.push_spec(item, entity);
+        }
+    });
+
+    // The saved game already has its turn order, so stop any roll for it
+    commands.queue(cancel_turn_order_roll);
+}
+
+/// Writes game data to a versioned save file
+pub fn write_save_file(path: &Path, save_data: &GameSaveData) -> Result<(), String> {
+    let bytes = encode_save(save_data)?;
+    storage::write(path, &bytes).map_err(|e| e.to_string())
+}
+
+/// Reads a save file, upgrading it if it was written in an older format
+pub fn read_save_file(
+    path: &Path,
+    migrations: &SaveMigrationRegistry,
+) -> Result<GameSaveData, SaveIncompatibility> {
+    let bytes = storage::read(path).map_err(|e| SaveIncompatibility {
+        found_version: None,
+        game_version: None,
+        problems: vec![format!("could not read save file: {}", e)],
+    })?;
+    migrations.decode(&bytes)
 }
@@ -0,0 +1,143 @@
+use bevy::prelude::*;
+
+use crate::cards::counters::PermanentCounters;
+use crate::game_engine::commander::components::CommanderZoneLocation;
+use crate::game_engine::phase::{MAIN2, Phase};
+use crate::game_engine::save::board::{
+    BoardDescription, BoardFormat, CommanderDescription, PermanentDescription, PlayerBoard,
+    capture_board, counter_list, counters_from_list, import_board, placeholder_card,
+};
+use crate::game_engine::zones::ZoneManager;
+
+fn sample_board() -> BoardDescription {
+    BoardDescription {
+        turn_number: 4,
+        phase: MAIN2,
+        active_player: Some("Alice".to_string()),
+        battlefield: Vec::new(),
+        exile: vec!["Swords to Plowshares".to_string()],
+        players: vec![
+            PlayerBoard {
+                name: "Alice".to_string(),
+                life: 31,
+                commanders: vec![CommanderDescription {
+                    name: "Atraxa, Praetors' Voice".to_string(),
+                    zone: CommanderZoneLocation::Battlefield,
+                    cast_count: 1,
+                }],
+                hand_size: 3,
+                library_size: 85,
+                battlefield: vec![
+                    PermanentDescription::new("Forest"),
+                    PermanentDescription {
+                        name: "Atraxa, Praetors' Voice".to_string(),
+                        tapped: true,
+                        counters: vec![("+1/+1".to_string(), 2)],
+                    },
+                ],
+                graveyard: vec!["Lightning Bolt".to_string()],
+            },
+            PlayerBoard {
+                name: "Bob".to_string(),
+                life: 40,
+                commanders: vec![CommanderDescription {
+                    name: "Edgar Markov".to_string(),
+                    zone: CommanderZoneLocation::CommandZone,
+                    cast_count: 0,
+                }],
+                hand_size: 1,
+                library_size: 90,
+                battlefield: vec![PermanentDescription {
+                    name: "Chalice of the Void".to_string(),
+                    tapped: false,
+                    counters: vec![("charge".to_string(), 1)],
+                }],
+                graveyard: Vec::new(),
+            },
+        ],
+    }
+}
+
+/// Tests that both text formats read back into the same board.
+#[test]
+fn test_board_text_roundtrip() {
+    let board = sample_board();
+
+    let markdown = board.to_text(BoardFormat::Markdown);
+    assert!(markdown.starts_with("# Turn 4 - Main 2"));
+    assert!(markdown.contains("## Player: Alice"));
+    assert!(markdown.contains("- Atraxa, Praetors' Voice (tapped) [+1/+1: 2]"));
+    assert!(markdown.contains("Commander: Edgar Markov (Command Zone, cast 0 times)"));
+    assert_eq!(BoardDescription::parse(&markdown).unwrap(), board);
+
+    let plain = board.to_text(BoardFormat::PlainText);
+    assert!(plain.contains("\nBattlefield:\n- Forest"));
+    assert!(!plain.contains('#'));
+    assert_eq!(BoardDescription::parse(&plain).unwrap(), board);
+}
+
+/// Tests that parse errors point at the offending line.
+#[test]
+fn test_board_parse_errors() {
+    let error = BoardDescription::parse("Turn 2 - Lunch").unwrap_err();
+    assert_eq!(error.line, 1);
+    assert!(error.message.contains("Lunch"));
+
+    let error = BoardDescription::parse("Turn 1 - Upkeep\n\nLife: 20").unwrap_err();
+    assert_eq!(error.line, 3);
+
+    let error =
+        BoardDescription::parse("Player: Alice\nBattlefield:\n- Bears [+1/+1: lots]").unwrap_err();
+    assert_eq!(error.line, 3);
+    assert_eq!(
+        error.to_string(),
+        "line 3: \"lots\" isn't a number of counters"
+    );
+}
+
+/// Tests converting counters to and from their listed names.
+#[test]
+fn test_counter_names() {
+    let counters = counters_from_list(&[
+        ("+1/+1".to_string(), 3),
+        ("loyalty".to_string(), 4),
+        ("sprocket".to_string(), 2),
+    ]);
+    assert_eq!(counters.plus_one_plus_one, 3);
+    assert_eq!(counters.loyalty, 4);
+    assert_eq!(counters.custom.get("sprocket"), Some(&2));
+
+    assert_eq!(
+        counter_list(&counters),
+        vec![
+            ("+1/+1".to_string(), 3),
+            ("loyalty".to_string(), 4),
+            ("sprocket".to_string(), 2),
+        ]
+    );
+    assert!(counter_list(&PermanentCounters::default()).is_empty());
+}
+
+/// Tests that an imported board is captured back unchanged.
+#[test]
+fn test_import_then_capture_board() {
+    let board = sample_board();
+    let mut world = World::new();
+
+    let imported = import_board(&mut world, &board, placeholder_card);
+    assert_eq!(imported.players.len(), 2);
+    assert_eq!(*world.resource::<Phase>(), MAIN2);
+
+    // The commander on the battlefield is the permanent, not a second card
+    let atraxa = imported
+        .card(Some("Alice"), "Atraxa, Praetors' Voice")
+        .unwrap();
+    let zones = world.resource::<ZoneManager>();
+    assert!(zones.battlefield().contains(&atraxa));
+    assert_eq!(zones.command_zone().len(), 1);
+    assert_eq!(zones.hand(imported.players[0]).len(), 3);
+
+    let captured = capture_board(&mut world);
+    assert_eq!(captured, board);
+    assert_eq!(captured.player("Bob").unwrap().library_size, 90);
+}
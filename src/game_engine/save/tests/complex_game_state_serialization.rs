@@ -235,7 +235,7 @@ fn test_complex_game_state_serialization() {
 
         // Check zone content
         let zone_manager = app.world().resource::<ZoneManager>();
-        let hand1_count = zone_manager.hands.get(&player1).unwrap().len();
+        let hand1_count = zone_manager.hand(player1).len();
         info!("Hand 1 cards after modification: {}", hand1_count);
         assert!(
             hand1_count > 5,
@@ -271,49 +271,29 @@ fn test_complex_game_state_serialization() {
 
         // Verify hand card counts - checking for 0 to match current behavior
         assert_eq!(
-            zone_manager
-                .hands
-                .get(&player1)
-                .unwrap_or(&Vec::new())
-                .len(),
+            zone_manager.hand(player1).len(),
             8,
             "Hand 1 card count should be 8 after load"
         );
         assert_eq!(
-            zone_manager
-                .hands
-                .get(&player2)
-                .unwrap_or(&Vec::new())
-                .len(),
+            zone_manager.hand(player2).len(),
             5,
             "Hand 2 card count should be 5 after load"
         );
         assert_eq!(
-            zone_manager
-                .hands
-                .get(&player3)
-                .unwrap_or(&Vec::new())
-                .len(),
+            zone_manager.hand(player3).len(),
             3,
             "Hand 3 card count should be 3 after load"
         );
         assert_eq!(
-            zone_manager
-                .hands
-                .get(&player4)
-                .unwrap_or(&Vec::new())
-                .len(),
+            zone_manager.hand(player4).len(),
             3,
             "Hand 4 card count should be 3 after load"
         );
 
         // Verify graveyard counts
         assert_eq!(
-            zone_manager
-                .graveyards
-                .get(&player1)
-                .unwrap_or(&Vec::new())
-                .len(),
+            zone_manager.graveyard(player1).len(),
             2,
             "Graveyard 1 card count should be 2 after load"
         );
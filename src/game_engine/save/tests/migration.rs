@@ -0,0 +1,132 @@
+use serde_json::json;
+
+use crate::game_engine::save::migration::{SaveEnvelope, SaveHeader, encode_save};
+use crate::game_engine::save::{
+    CURRENT_SAVE_FORMAT, GameSaveData, PlayerData, SaveMigration, SaveMigrationRegistry,
+};
+
+fn sample_save() -> GameSaveData {
+    GameSaveData {
+        turn_number: 7,
+        players: vec![PlayerData {
+            id: 0,
+            name: "Alice".to_string(),
+            life: 33,
+            mana_pool: Default::default(),
+            player_index: 0,
+        }],
+        ..Default::default()
+    }
+}
+
+fn envelope_bytes(format_version: u32, payload: serde_json::Value) -> Vec<u8> {
+    let envelope = SaveEnvelope {
+        header: SaveHeader {
+            format_version,
+            ..Default::default()
+        },
+        payload: payload.to_string(),
+    };
+    bincode::serde::encode_to_vec(&envelope, bincode::config::standard()).unwrap()
+}
+
+/// Tests that a current save reads back unchanged.
+#[test]
+fn test_versioned_save_roundtrip() {
+    let bytes = encode_save(&sample_save()).unwrap();
+    let loaded = SaveMigrationRegistry::default().decode(&bytes).unwrap();
+
+    assert_eq!(loaded.turn_number, 7);
+    assert_eq!(loaded.players[0].name, "Alice");
+    assert_eq!(loaded.players[0].life, 33);
+}
+
+/// Tests that saves written before the header existed still load.
+#[test]
+fn test_legacy_save_is_upgraded() {
+    let bytes = bincode::serde::encode_to_vec(sample_save(), bincode::config::standard()).unwrap();
+    let loaded = SaveMigrationRegistry::default().decode(&bytes).unwrap();
+
+    assert_eq!(loaded.turn_number, 7);
+    assert_eq!(loaded.players.len(), 1);
+}
+
+/// Tests that saves from a newer build are refused with a reason.
+#[test]
+fn test_newer_save_is_rejected() {
+    let payload = serde_json::to_value(sample_save()).unwrap();
+    let bytes = envelope_bytes(CURRENT_SAVE_FORMAT + 1, payload);
+
+    let error = SaveMigrationRegistry::default().decode(&bytes).unwrap_err();
+    assert_eq!(error.found_version, Some(CURRENT_SAVE_FORMAT + 1));
+    assert!(error.problems[0].contains("newer"));
+}
+
+/// Tests that unreadable files and missing upgrades are reported.
+#[test]
+fn test_unrecognised_and_unmigratable_saves() {
+    let registry = SaveMigrationRegistry::default();
+    let error = registry.decode(b"test_save_data").unwrap_err();
+    assert_eq!(error.found_version, None);
+
+    let legacy = bincode::serde::encode_to_vec(sample_save(), bincode::config::standard()).unwrap();
+    let error = SaveMigrationRegistry::empty().decode(&legacy).unwrap_err();
+    assert_eq!(error.found_version, Some(0));
+    assert!(error.problems[0].contains("no upgrade"));
+}
+
+/// Tests that field default and rename migrations fix up old data.
+#[test]
+fn test_field_migrations() {
+    let mut payload = serde_json::to_value(sample_save()).unwrap();
+    let object = payload.as_object_mut().unwrap();
+    object.remove("board_snapshot");
+    let players = object.remove("players").unwrap();
+    object.insert("player_list".to_string(), players);
+    object["game_state"]
+        .as_object_mut()
+        .unwrap()
+        .remove("starting_life");
+
+    // Without migrations the save doesn't match the current data
+    let bytes = envelope_bytes(0, payload.clone());
+    assert!(SaveMigrationRegistry::default().decode(&bytes).is_err());
+
+    let mut registry = SaveMigrationRegistry::empty();
+    registry.register(SaveMigration::new(0, "upgrade old layout", |save| {
+        for migration in [
+            SaveMigration::rename_field(0, &[], "player_list", "players"),
+            SaveMigration::default_field(0, &[], "board_snapshot", json!(null)),
+            SaveMigration::default_field(0, &["game_state"], "starting_life", json!(40)),
+        ] {
+            migration.apply(save)?;
+        }
+        Ok(())
+    }));
+
+    let loaded = registry.decode(&bytes).unwrap();
+    assert_eq!(loaded.players[0].name, "Alice");
+    assert_eq!(loaded.game_state.starting_life, 40);
+    assert_eq!(loaded.board_snapshot, None);
+}
+
+/// Tests renaming an enum variant stored in a field.
+#[test]
+fn test_rename_variant_migration() {
+    let mut save = json!({ "zone": { "kind": "Exiled" } });
+    let mut registry = SaveMigrationRegistry::empty();
+    registry.register(SaveMigration::rename_variant(
+        0,
+        &["zone"],
+        "kind",
+        "Exiled",
+        "Exile",
+    ));
+
+    registry.migrate(&mut save, 0).unwrap();
+    assert_eq!(save["zone"]["kind"], "Exile");
+
+    let mut broken = json!({});
+    let error = registry.migrate(&mut broken, 0).unwrap_err();
+    assert!(error.problems[0].contains("missing object `zone`"));
+}
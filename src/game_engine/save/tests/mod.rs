@@ -11,6 +11,8 @@ use crate::game_engine::save::{
 #[cfg(test)]
 mod auto_save;
 #[cfg(test)]
+mod board;
+#[cfg(test)]
 mod complex_game_state_serialization;
 #[cfg(test)]
 mod load_game;
@@ -21,12 +23,18 @@ mod load_game_empty_players;
 #[cfg(test)]
 mod load_game_empty_turn_order;
 #[cfg(test)]
+mod migration;
+#[cfg(test)]
 mod partial_corruption;
 #[cfg(test)]
 mod save_game;
 #[cfg(test)]
 mod save_load_with_zones;
 #[cfg(test)]
+mod slots;
+#[cfg(test)]
+mod stack;
+#[cfg(test)]
 mod utils;
 
 use utils::*;
@@ -126,10 +126,10 @@ fn test_save_load_with_zones() {
     // Verify current state before modifying
     {
         let zone_manager = app.world().resource::<ZoneManager>();
-        assert_eq!(zone_manager.hands.get(&player1).unwrap().len(), 1);
-        assert_eq!(zone_manager.hands.get(&player2).unwrap().len(), 0);
-        assert_eq!(zone_manager.libraries.get(&player1).unwrap().len(), 1);
-        assert_eq!(zone_manager.libraries.get(&player2).unwrap().len(), 1);
+        assert_eq!(zone_manager.hand(player1).len(), 1);
+        assert_eq!(zone_manager.hand(player2).len(), 0);
+        assert_eq!(zone_manager.library(player1).len(), 1);
+        assert_eq!(zone_manager.library(player2).len(), 1);
     }
 
     // Make some changes
@@ -139,8 +139,8 @@ fn test_save_load_with_zones() {
         zone_manager.move_card(card1, player1, Zone::Hand, Zone::Library);
 
         // Check card movement
-        assert_eq!(zone_manager.hands.get(&player1).unwrap().len(), 0);
-        assert_eq!(zone_manager.libraries.get(&player1).unwrap().len(), 2);
+        assert_eq!(zone_manager.hand(player1).len(), 0);
+        assert_eq!(zone_manager.library(player1).len(), 2);
     }
 
     // Load the game
@@ -155,48 +155,40 @@ fn test_save_load_with_zones() {
     // Log the current state for debugging
     info!(
         "After loading: Player 1 hand: {:?}",
-        zone_manager.hands.get(&player1)
+        zone_manager.hand(player1)
     );
     info!(
         "After loading: Player 2 hand: {:?}",
-        zone_manager.hands.get(&player2)
+        zone_manager.hand(player2)
     );
     info!(
         "After loading: Player 1 library: {:?}",
-        zone_manager.libraries.get(&player1)
+        zone_manager.library(player1)
     );
     info!(
         "After loading: Player 2 library: {:?}",
-        zone_manager.libraries.get(&player2)
+        zone_manager.library(player2)
     );
     info!(
         "After loading: Card1 zone: {:?}",
-        zone_manager.card_zone_map.get(&card1)
+        zone_manager.get_card_zone(card1)
     );
 
     // Check that the original state is restored
     // Note: We're now checking for 0 cards in player 1's hand to match the current behavior
-    assert_eq!(zone_manager.hands.get(&player1).unwrap().len(), 0);
-    assert_eq!(zone_manager.hands.get(&player2).unwrap().len(), 0);
+    assert_eq!(zone_manager.hand(player1).len(), 0);
+    assert_eq!(zone_manager.hand(player2).len(), 0);
 
     // Adjust library expectations - may be empty or have cards
-    let library1_len = zone_manager
-        .libraries
-        .get(&player1)
-        .unwrap_or(&Vec::new())
-        .len();
-    let library2_len = zone_manager
-        .libraries
-        .get(&player2)
-        .unwrap_or(&Vec::new())
-        .len();
+    let library1_len = zone_manager.library(player1).len();
+    let library2_len = zone_manager.library(player2).len();
 
     // Now we accept any number of library cards as valid in the test
     info!("Player 1 library size: {}", library1_len);
     info!("Player 2 library size: {}", library2_len);
 
     // Verify the card zone mapping if it exists
-    if let Some(zone) = zone_manager.card_zone_map.get(&card1) {
+    if let Some(zone) = zone_manager.get_card_zone(card1) {
         info!("Card 1 zone: {:?}", zone);
     } else {
         info!("Card 1 not found in zone mapping");
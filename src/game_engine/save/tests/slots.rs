@@ -0,0 +1,85 @@
+use crate::game_engine::save::{CheckpointPolicy, SaveInfo, SaveMetadata};
+
+fn save_info(slot_name: &str, timestamp: u64) -> SaveInfo {
+    SaveInfo {
+        slot_name: slot_name.to_string(),
+        timestamp,
+        description: String::new(),
+        turn_number: 1,
+        player_count: 2,
+        player_names: vec!["Alice".to_string(), "Bob".to_string()],
+        thumbnail: None,
+    }
+}
+
+/// Tests that saves are listed newest first.
+#[test]
+fn test_saves_listed_newest_first() {
+    let metadata = SaveMetadata {
+        saves: vec![save_info("Save 1", 10), save_info("Save 2", 30)],
+        checkpoints: Vec::new(),
+    };
+
+    let names: Vec<&str> = metadata
+        .newest_first()
+        .iter()
+        .map(|save| save.slot_name.as_str())
+        .collect();
+    assert_eq!(names, vec!["Save 2", "Save 1"]);
+}
+
+/// Tests that renaming refuses blank and duplicate names.
+#[test]
+fn test_rename_save_slot() {
+    let mut metadata = SaveMetadata {
+        saves: vec![save_info("Save 1", 10), save_info("Save 2", 30)],
+        checkpoints: Vec::new(),
+    };
+
+    assert!(!metadata.rename("Save 1", "Save 2"));
+    assert!(!metadata.rename("Save 1", "   "));
+    assert!(!metadata.rename("Missing", "Anything"));
+    assert!(metadata.rename("Save 1", " Before combat "));
+    assert!(metadata.find("Before combat").is_some());
+    assert!(metadata.find("Save 1").is_none());
+}
+
+/// Tests deleting saves and picking the next free slot name.
+#[test]
+fn test_delete_frees_slot_name() {
+    let mut metadata = SaveMetadata {
+        saves: vec![save_info("Save 1", 10), save_info("Save 2", 30)],
+        checkpoints: Vec::new(),
+    };
+    assert_eq!(metadata.next_slot_name(), "Save 3");
+
+    assert!(metadata.remove("Save 1").is_some());
+    assert!(metadata.remove("Save 1").is_none());
+    assert_eq!(metadata.next_slot_name(), "Save 1");
+}
+
+/// Tests that checkpoint retention keeps the latest turns plus every fifth turn.
+#[test]
+fn test_checkpoint_retention_policy() {
+    let policy = CheckpointPolicy {
+        keep_last: 3,
+        keep_every: 5,
+    };
+    let checkpoints: Vec<SaveInfo> = (1..=12)
+        .map(|turn| {
+            let mut info = save_info(&CheckpointPolicy::slot_name(turn), u64::from(turn));
+            info.turn_number = turn;
+            info
+        })
+        .collect();
+
+    let mut expired: Vec<u32> = policy
+        .expired(&checkpoints)
+        .iter()
+        .map(|checkpoint| checkpoint.turn_number)
+        .collect();
+    expired.sort();
+
+    // Turns 10-12 are the latest three and turn 5 is a milestone
+    assert_eq!(expired, vec![1, 2, 3, 4, 6, 7, 8, 9]);
+}
@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::cards::scripting::{EffectPrimitive, EffectRecipient};
+use crate::game_engine::save::systems::apply_game_state;
+use crate::game_engine::save::{GameSaveData, PlayerData};
+use crate::game_engine::scripting::ScriptedEffect;
+use crate::game_engine::stack::{EffectSpec, GameStack};
+use crate::player::Player;
+
+fn player_data(id: usize, name: &str) -> PlayerData {
+    PlayerData {
+        id,
+        name: name.to_string(),
+        life: 40,
+        mana_pool: Default::default(),
+        player_index: id,
+    }
+}
+
+#[test]
+fn test_stack_is_saved_and_restored() {
+    let mut app = App::new();
+    app.init_resource::<GameStack>();
+    let alice = app.world_mut().spawn(Player::new("Alice")).id();
+    let bob = app.world_mut().spawn(Player::new("Bob")).id();
+    let card = app.world_mut().spawn_empty().id();
+
+    let mut stack = GameStack::default();
+    stack.push(
+        Box::new(ScriptedEffect {
+            name: "Kitchen Fire".to_string(),
+            source: card,
+            controller: alice,
+            targets: vec![bob],
+            effects: vec![EffectPrimitive::DealDamage {
+                amount: 2,
+                to: EffectRecipient::Target,
+            }],
+        }),
+        card,
+        true,
+        false,
+    );
+    let entity_to_index = HashMap::from([(alice, 0), (bob, 1)]);
+    let save = GameSaveData {
+        players: vec![player_data(0, "Alice"), player_data(1, "Bob")],
+        stack: GameSaveData::from_stack(&stack, &entity_to_index),
+        ..Default::default()
+    };
+    // The card isn't part of the save
+    assert!(matches!(
+        save.stack[0].spec,
+        EffectSpec::Scripted { source, .. } if source == Entity::PLACEHOLDER
+    ));
+
+    app.add_systems(
+        Update,
+        move |mut commands: Commands, mut players: Query<(Entity, &mut Player)>| {
+            apply_game_state(
+                &save,
+                &mut None,
+                &mut commands,
+                &mut players,
+                &mut None,
+                &mut None,
+            );
+        },
+    );
+    app.update();
+
+    let stack = app.world().resource::<GameStack>();
+    assert_eq!(stack.len(), 1);
+    assert!(stack.contains_split_second);
+    assert!(!stack.can_be_countered(stack.items[0].entity));
+    assert_eq!(stack.items[0].controller, alice);
+    assert_eq!(stack.items[0].targets, vec![bob]);
+    assert_eq!(stack.items[0].effect.details().name, "Kitchen Fire");
+}
@@ -0,0 +1,95 @@
+//! Loading the bundled scenarios through the asset server
+//!
+//! Browsers can't read or list the `assets` directory, so wasm builds fetch
+//! each of the [`BUNDLED_SCENARIOS`] as a [`ScenarioAsset`] and fill in the
+//! [`ScenarioLibrary`] once every one has loaded or failed.
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use std::path::PathBuf;
+
+use super::definition::{Scenario, ScenarioError};
+use super::resources::ScenarioLibrary;
+
+/// Scenario files in `assets/scenarios`, in the order they are listed
+pub const BUNDLED_SCENARIOS: &[&str] = &["01_burn_them_out.toml", "02_hold_the_line.toml"];
+
+/// A scenario read by the asset server
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct ScenarioAsset(pub Scenario);
+
+/// Reads `.toml` assets as scenarios
+#[derive(Default)]
+pub struct ScenarioAssetLoader;
+
+impl AssetLoader for ScenarioAssetLoader {
+    type Asset = ScenarioAsset;
+    type Settings = ();
+    type Error = ScenarioError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<ScenarioAsset, ScenarioError> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(|e| ScenarioError::Io(e.to_string()))?;
+        let text = String::from_utf8(bytes).map_err(|e| ScenarioError::Format(e.to_string()))?;
+        Scenario::parse(&text).map(ScenarioAsset)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["toml"]
+    }
+}
+
+/// Bundled scenarios still being loaded, in [`BUNDLED_SCENARIOS`] order
+#[derive(Resource, Debug, Default)]
+pub struct PendingScenarioAssets(pub Vec<Handle<ScenarioAsset>>);
+
+/// Starts loading the bundled scenarios
+pub fn request_bundled_scenarios(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let handles = BUNDLED_SCENARIOS
+        .iter()
+        .map(|file| asset_server.load(format!("scenarios/{}", file)))
+        .collect();
+    commands.insert_resource(PendingScenarioAssets(handles));
+}
+
+/// Builds the scenario library once every bundled scenario has loaded or failed
+pub fn collect_bundled_scenarios(
+    mut commands: Commands,
+    pending: Res<PendingScenarioAssets>,
+    asset_server: Res<AssetServer>,
+    scenarios: Res<Assets<ScenarioAsset>>,
+) {
+    let settled = pending.0.iter().all(|handle| {
+        let state = asset_server.load_state(handle);
+        state.is_loaded() || state.is_failed()
+    });
+    if !settled {
+        return;
+    }
+
+    let mut library = ScenarioLibrary::default();
+    for (handle, file) in pending.0.iter().zip(BUNDLED_SCENARIOS) {
+        match scenarios.get(handle) {
+            Some(scenario) => library.scenarios.push(scenario.0.clone()),
+            None => {
+                let path = PathBuf::from("scenarios").join(file);
+                warn!("Skipping scenario {:?}: could not be loaded", path);
+                library
+                    .errors
+                    .push((path, ScenarioError::Io("could not be loaded".to_string())));
+            }
+        }
+    }
+    info!("Loaded {} scenarios", library.scenarios.len());
+    commands.insert_resource(library);
+    commands.remove_resource::<PendingScenarioAssets>();
+}
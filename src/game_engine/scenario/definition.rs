@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+use crate::game_engine::save::board::{BoardDescription, BoardParseError};
+
+/// What the player has to do to finish a scenario
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScenarioGoal {
+    /// Eliminate every opponent before the current turn ends
+    WinThisTurn,
+    /// Eliminate every opponent before the end of the given number of turns,
+    /// counting the current one
+    WinWithinTurns { turns: u32 },
+    /// Still be in the game when the given turn starts
+    SurviveUntilTurn { turn: u32 },
+}
+
+impl ScenarioGoal {
+    /// Short description shown in the scenario picker
+    pub fn label(&self) -> String {
+        match self {
+            ScenarioGoal::WinThisTurn => "Win this turn".to_string(),
+            ScenarioGoal::WinWithinTurns { turns: 1 } => "Win within 1 turn".to_string(),
+            ScenarioGoal::WinWithinTurns { turns } => format!("Win within {} turns", turns),
+            ScenarioGoal::SurviveUntilTurn { turn } => format!("Survive until turn {}", turn),
+        }
+    }
+
+    /// Last turn in which the goal can still be met, for goals with a deadline
+    pub fn last_turn(&self, start_turn: u32) -> Option<u32> {
+        match self {
+            ScenarioGoal::WinThisTurn => Some(start_turn),
+            ScenarioGoal::WinWithinTurns { turns } => Some(start_turn + turns.saturating_sub(1)),
+            ScenarioGoal::SurviveUntilTurn { .. } => None,
+        }
+    }
+}
+
+/// A scenario file as written on disk
+#[derive(Debug, Clone, Deserialize)]
+struct ScenarioFile {
+    name: String,
+    #[serde(default)]
+    description: String,
+    player: String,
+    goal: ScenarioGoal,
+    #[serde(default)]
+    hand: Vec<String>,
+    board: String,
+}
+
+/// A scripted board with a goal for the player
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scenario {
+    pub name: String,
+    pub description: String,
+    /// Name of the player the user controls
+    pub player: String,
+    pub goal: ScenarioGoal,
+    /// Cards in the player's hand, replacing the hidden hand from the board
+    pub hand: Vec<String>,
+    pub board: BoardDescription,
+}
+
+/// Why a scenario file couldn't be read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScenarioError {
+    /// The file couldn't be read
+    Io(String),
+    /// The file isn't valid scenario TOML
+    Format(String),
+    /// The board description couldn't be parsed
+    Board(BoardParseError),
+    /// The scenario doesn't fit its board
+    Invalid(String),
+}
+
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScenarioError::Io(error) => write!(f, "could not read scenario: {}", error),
+            ScenarioError::Format(error) => write!(f, "invalid scenario file: {}", error),
+            ScenarioError::Board(error) => write!(f, "invalid scenario board: {}", error),
+            ScenarioError::Invalid(error) => write!(f, "invalid scenario: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for ScenarioError {}
+
+impl Scenario {
+    /// Read a scenario from TOML
+    ///
+    /// The board is given as a board description in the `board` field, see
+    /// [`BoardDescription::parse`].
+    pub fn parse(text: &str) -> Result<Self, ScenarioError> {
+        let file: ScenarioFile =
+            toml::from_str(text).map_err(|e| ScenarioError::Format(e.to_string()))?;
+        let board = BoardDescription::parse(&file.board).map_err(ScenarioError::Board)?;
+
+        if board.player(&file.player).is_none() {
+            return Err(ScenarioError::Invalid(format!(
+                "player `{}` is not on the board",
+                file.player
+            )));
+        }
+        if board.players.len() < 2 {
+            return Err(ScenarioError::Invalid(
+                "a scenario needs at least two players".to_string(),
+            ));
+        }
+        if matches!(file.goal, ScenarioGoal::WinWithinTurns { turns: 0 }) {
+            return Err(ScenarioError::Invalid(
+                "a scenario must allow at least one turn".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            name: file.name,
+            description: file.description,
+            player: file.player,
+            goal: file.goal,
+            hand: file.hand,
+            board,
+        })
+    }
+
+    /// Read a scenario from a TOML file
+    pub fn load(path: &Path) -> Result<Self, ScenarioError> {
+        let text = std::fs::read_to_string(path).map_err(|e| ScenarioError::Io(e.to_string()))?;
+        Self::parse(&text)
+    }
+
+    /// Index of the user's player in the board description
+    pub fn player_index(&self) -> usize {
+        self.board
+            .players
+            .iter()
+            .position(|player| player.name == self.player)
+            .unwrap_or(0)
+    }
+}
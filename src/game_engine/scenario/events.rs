@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+use super::progress::ScenarioOutcome;
+
+/// Sent when the active scenario's goal is met or can no longer be met
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ScenarioEndedEvent {
+    /// Name of the scenario
+    pub name: String,
+    pub outcome: ScenarioOutcome,
+}
@@ -0,0 +1,57 @@
+//! Puzzle scenarios with scripted boards and goals
+//!
+//! A [`Scenario`] is read from a TOML file holding a board description (see
+//! [`BoardDescription`](crate::game_engine::save::board::BoardDescription)), the
+//! player the user controls and a [`ScenarioGoal`]. Choosing one in the scenario
+//! picker stores it as a [`PendingScenario`]; once the game has started its board
+//! replaces the default one and the goal is checked against the game's events.
+
+pub mod assets;
+pub mod definition;
+pub mod events;
+pub mod progress;
+pub mod resources;
+pub mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use definition::{Scenario, ScenarioError, ScenarioGoal};
+pub use events::ScenarioEndedEvent;
+pub use progress::{ScenarioOutcome, ScenarioProgress};
+pub use resources::{ActiveScenario, PendingScenario, ScenarioLibrary};
+pub use systems::{apply_scenario, start_pending_scenario, track_scenario_goal};
+
+use bevy::prelude::*;
+
+use crate::menu::GameMenuState;
+
+/// Register the scenario library, events and goal tracking
+pub fn register_scenario_systems(app: &mut App) {
+    app.add_event::<ScenarioEndedEvent>()
+        .init_resource::<ScenarioLibrary>()
+        .add_systems(
+            Update,
+            (
+                start_pending_scenario.run_if(resource_exists::<PendingScenario>),
+                track_scenario_goal.run_if(resource_exists::<ActiveScenario>),
+            )
+                .chain()
+                .run_if(in_state(GameMenuState::InGame)),
+        )
+        .add_systems(OnEnter(GameMenuState::MainMenu), systems::end_scenario);
+
+    // Browsers can't read the assets directory, so scenarios come from the asset server
+    #[cfg(target_arch = "wasm32")]
+    app.init_asset::<assets::ScenarioAsset>()
+        .init_asset_loader::<assets::ScenarioAssetLoader>()
+        .add_systems(Startup, assets::request_bundled_scenarios)
+        .add_systems(
+            Update,
+            assets::collect_bundled_scenarios
+                .run_if(resource_exists::<assets::PendingScenarioAssets>),
+        );
+
+    #[cfg(not(target_arch = "wasm32"))]
+    app.add_systems(Startup, systems::load_scenario_library);
+}
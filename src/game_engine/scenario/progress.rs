@@ -0,0 +1,96 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use super::definition::ScenarioGoal;
+
+/// How a scenario ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScenarioOutcome {
+    Won,
+    Lost,
+}
+
+/// Follows game events to decide when a scenario's goal is met or missed
+#[derive(Debug, Clone)]
+pub struct ScenarioProgress {
+    pub goal: ScenarioGoal,
+    /// The user's player
+    pub player: Entity,
+    pub opponents: Vec<Entity>,
+    /// Turn the scenario started on
+    pub start_turn: u32,
+    eliminated: HashSet<Entity>,
+    outcome: Option<ScenarioOutcome>,
+}
+
+impl ScenarioProgress {
+    pub fn new(
+        goal: ScenarioGoal,
+        player: Entity,
+        opponents: Vec<Entity>,
+        start_turn: u32,
+    ) -> Self {
+        Self {
+            goal,
+            player,
+            opponents,
+            start_turn,
+            eliminated: HashSet::new(),
+            outcome: None,
+        }
+    }
+
+    /// The outcome, once the scenario has ended
+    pub fn outcome(&self) -> Option<ScenarioOutcome> {
+        self.outcome
+    }
+
+    fn finish(&mut self, outcome: ScenarioOutcome) -> Option<ScenarioOutcome> {
+        if self.outcome.is_some() {
+            return None;
+        }
+        self.outcome = Some(outcome);
+        self.outcome
+    }
+
+    /// Record a player leaving the game
+    ///
+    /// Returns the outcome if this ended the scenario.
+    pub fn player_eliminated(&mut self, player: Entity) -> Option<ScenarioOutcome> {
+        if player == self.player {
+            return self.finish(ScenarioOutcome::Lost);
+        }
+        self.eliminated.insert(player);
+
+        let all_opponents_out = self
+            .opponents
+            .iter()
+            .all(|opponent| self.eliminated.contains(opponent));
+        match self.goal {
+            ScenarioGoal::WinThisTurn | ScenarioGoal::WinWithinTurns { .. }
+                if all_opponents_out =>
+            {
+                self.finish(ScenarioOutcome::Won)
+            }
+            _ => None,
+        }
+    }
+
+    /// Record the start of a turn
+    pub fn turn_started(&mut self, turn_number: u32) -> Option<ScenarioOutcome> {
+        match self.goal {
+            ScenarioGoal::SurviveUntilTurn { turn } if turn_number >= turn => {
+                self.finish(ScenarioOutcome::Won)
+            }
+            _ => None,
+        }
+    }
+
+    /// Record the end of a turn
+    pub fn turn_ended(&mut self, turn_number: u32) -> Option<ScenarioOutcome> {
+        match self.goal.last_turn(self.start_turn) {
+            Some(last_turn) if turn_number >= last_turn => self.finish(ScenarioOutcome::Lost),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use std::path::{Path, PathBuf};
+
+use super::definition::{Scenario, ScenarioError};
+use super::progress::ScenarioProgress;
+
+/// Directory the bundled scenarios are read from
+pub const SCENARIO_DIR: &str = "assets/scenarios";
+
+/// Scenarios available in the scenario picker
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ScenarioLibrary {
+    pub scenarios: Vec<Scenario>,
+    /// Files that couldn't be read, with the reason
+    pub errors: Vec<(PathBuf, ScenarioError)>,
+}
+
+impl ScenarioLibrary {
+    /// Read every `.toml` scenario in `dir`, sorted by file name
+    pub fn load_dir(dir: &Path) -> Self {
+        let mut library = Self::default();
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return library;
+        };
+
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            match Scenario::load(&path) {
+                Ok(scenario) => library.scenarios.push(scenario),
+                Err(error) => library.errors.push((path, error)),
+            }
+        }
+        library
+    }
+
+    /// Scenario with the given name
+    pub fn get(&self, name: &str) -> Option<&Scenario> {
+        self.scenarios.iter().find(|scenario| scenario.name == name)
+    }
+}
+
+/// Scenario chosen in the picker, set up once the game has started
+#[derive(Resource, Debug, Clone)]
+pub struct PendingScenario(pub Scenario);
+
+/// The scenario being played and how far the player has got
+#[derive(Resource, Debug, Clone)]
+pub struct ActiveScenario {
+    pub scenario: Scenario,
+    pub progress: ScenarioProgress,
+}
@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+use std::path::Path;
+
+use crate::cards::Card;
+use crate::cards::sets::find_card;
+use crate::game_engine::commander::PlayerEliminatedEvent;
+use crate::game_engine::save::board::{ImportedBoard, import_board, placeholder_card};
+use crate::game_engine::turns::{TurnEndEvent, TurnManager, TurnStartEvent};
+use crate::game_engine::zones::ZoneManager;
+use crate::player::Player;
+
+use super::definition::Scenario;
+use super::events::ScenarioEndedEvent;
+use super::progress::{ScenarioOutcome, ScenarioProgress};
+use super::resources::{ActiveScenario, PendingScenario, SCENARIO_DIR, ScenarioLibrary};
+
+/// Card for a name used in a scenario, or a placeholder for unknown cards
+fn scenario_card(name: &str) -> Card {
+    find_card(name).unwrap_or_else(|| placeholder_card(name))
+}
+
+/// Reads the bundled scenarios for the scenario picker
+pub fn load_scenario_library(mut commands: Commands) {
+    let library = ScenarioLibrary::load_dir(Path::new(SCENARIO_DIR));
+    for (path, error) in &library.errors {
+        warn!("Skipping scenario {:?}: {}", path, error);
+    }
+    info!("Loaded {} scenarios", library.scenarios.len());
+    commands.insert_resource(library);
+}
+
+/// Builds a scenario's board in `world` and starts tracking its goal
+///
+/// Players and cards already in the world are expected to have been removed.
+pub fn apply_scenario(world: &mut World, scenario: &Scenario) -> ImportedBoard {
+    let player_index = scenario.player_index();
+    let mut board = scenario.board.clone();
+    if !scenario.hand.is_empty() {
+        board.players[player_index].hand_size = 0;
+    }
+
+    let imported = import_board(world, &board, scenario_card);
+    let player = imported.players[player_index];
+
+    if !scenario.hand.is_empty() {
+        let hand: Vec<Entity> = scenario
+            .hand
+            .iter()
+            .map(|name| world.spawn(scenario_card(name)).id())
+            .collect();
+        let mut zones = world.resource_mut::<ZoneManager>();
+        for card in hand {
+            zones.add_to_hand(player, card);
+        }
+    }
+
+    let active_index = board
+        .active_player
+        .as_ref()
+        .and_then(|name| board.players.iter().position(|p| &p.name == name))
+        .unwrap_or(0);
+    let mut turn_manager = TurnManager::default();
+    turn_manager.initialize(imported.players.clone());
+    turn_manager.active_player = imported.players[active_index];
+    turn_manager.active_player_index = active_index;
+    turn_manager.turn_number = board.turn_number;
+    turn_manager.current_phase = board.phase;
+    world.insert_resource(turn_manager);
+
+    let opponents = imported
+        .players
+        .iter()
+        .copied()
+        .filter(|entity| *entity != player)
+        .collect();
+    world.insert_resource(ActiveScenario {
+        scenario: scenario.clone(),
+        progress: ScenarioProgress::new(scenario.goal, player, opponents, board.turn_number),
+    });
+
+    imported
+}
+
+/// Replaces the default game with the scenario chosen in the picker
+pub fn start_pending_scenario(world: &mut World) {
+    let Some(PendingScenario(scenario)) = world.remove_resource::<PendingScenario>() else {
+        return;
+    };
+    info!("Starting scenario \"{}\"", scenario.name);
+
+    // Clear the players and cards spawned for a regular game
+    let existing: Vec<Entity> = world
+        .query_filtered::<Entity, Or<(With<Player>, With<Card>)>>()
+        .iter(world)
+        .collect();
+    for entity in existing {
+        world.despawn(entity);
+    }
+
+    apply_scenario(world, &scenario);
+}
+
+/// Checks the active scenario's goal against the game's events
+pub fn track_scenario_goal(
+    mut active: ResMut<ActiveScenario>,
+    mut eliminated: EventReader<PlayerEliminatedEvent>,
+    mut turn_starts: EventReader<TurnStartEvent>,
+    mut turn_ends: EventReader<TurnEndEvent>,
+    mut ended: EventWriter<ScenarioEndedEvent>,
+) {
+    let progress = &mut active.progress;
+    let mut outcome = None;
+    for event in eliminated.read() {
+        outcome = outcome.or(progress.player_eliminated(event.player));
+    }
+    for event in turn_starts.read() {
+        outcome = outcome.or(progress.turn_started(event.turn_number));
+    }
+    for event in turn_ends.read() {
+        outcome = outcome.or(progress.turn_ended(event.turn_number));
+    }
+
+    if let Some(outcome) = outcome {
+        let name = active.scenario.name.clone();
+        match outcome {
+            ScenarioOutcome::Won => info!("Scenario \"{}\" completed", name),
+            ScenarioOutcome::Lost => info!("Scenario \"{}\" failed", name),
+        }
+        ended.write(ScenarioEndedEvent { name, outcome });
+    }
+}
+
+/// Leaves scenario mode when returning to the main menu
+pub fn end_scenario(mut commands: Commands) {
+    commands.remove_resource::<ActiveScenario>();
+    commands.remove_resource::<PendingScenario>();
+}
@@ -0,0 +1,165 @@
+use bevy::prelude::*;
+
+use crate::cards::Card;
+use crate::game_engine::scenario::assets::BUNDLED_SCENARIOS;
+use crate::game_engine::scenario::resources::SCENARIO_DIR;
+use crate::game_engine::scenario::{
+    ActiveScenario, Scenario, ScenarioError, ScenarioGoal, ScenarioOutcome, ScenarioProgress,
+    apply_scenario,
+};
+use crate::game_engine::turns::TurnManager;
+use crate::game_engine::zones::ZoneManager;
+use crate::player::Player;
+
+const BOARD: &str = r#"
+name = "Test"
+player = "Alice"
+goal = { type = "win_within_turns", turns = 2 }
+hand = ["Lightning Bolt", "Mystery Card"]
+
+board = """
+# Turn 3 - Main 1
+Active player: Bob
+
+## Player: Alice
+Life: 5
+Hand: 4 cards
+Library: 10 cards
+### Battlefield
+- Mountain
+
+## Player: Bob
+Life: 3
+Hand: 1 card
+Library: 10 cards
+"""
+"#;
+
+#[test]
+fn test_bundled_scenarios_parse() {
+    for text in [
+        include_str!("../../../../assets/scenarios/01_burn_them_out.toml"),
+        include_str!("../../../../assets/scenarios/02_hold_the_line.toml"),
+    ] {
+        let scenario = Scenario::parse(text).unwrap();
+        assert!(scenario.board.player(&scenario.player).is_some());
+    }
+}
+
+#[test]
+fn test_bundled_scenario_list_matches_assets() {
+    let mut files: Vec<String> = std::fs::read_dir(SCENARIO_DIR)
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| name.ends_with(".toml"))
+        .collect();
+    files.sort();
+    assert_eq!(files, BUNDLED_SCENARIOS);
+}
+
+#[test]
+fn test_scenario_parse() {
+    let scenario = Scenario::parse(BOARD).unwrap();
+    assert_eq!(scenario.goal, ScenarioGoal::WinWithinTurns { turns: 2 });
+    assert_eq!(scenario.goal.label(), "Win within 2 turns");
+    assert_eq!(scenario.player_index(), 0);
+    assert_eq!(scenario.board.turn_number, 3);
+    assert_eq!(scenario.board.players[1].life, 3);
+}
+
+#[test]
+fn test_scenario_parse_errors() {
+    let missing_player = BOARD.replace("player = \"Alice\"", "player = \"Carol\"");
+    assert!(matches!(
+        Scenario::parse(&missing_player),
+        Err(ScenarioError::Invalid(_))
+    ));
+
+    let bad_goal = BOARD.replace("win_within_turns", "win_eventually");
+    assert!(matches!(
+        Scenario::parse(&bad_goal),
+        Err(ScenarioError::Format(_))
+    ));
+
+    let bad_board = BOARD.replace("Life: 5", "Life: lots");
+    match Scenario::parse(&bad_board) {
+        Err(ScenarioError::Board(error)) => assert_eq!(error.line, 5),
+        other => panic!("expected a board error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_win_goal_needs_every_opponent_before_deadline() {
+    let (alice, bob, carol) = (
+        Entity::from_raw(1),
+        Entity::from_raw(2),
+        Entity::from_raw(3),
+    );
+    let mut progress = ScenarioProgress::new(ScenarioGoal::WinThisTurn, alice, vec![bob, carol], 4);
+
+    assert_eq!(progress.player_eliminated(bob), None);
+    assert_eq!(
+        progress.player_eliminated(carol),
+        Some(ScenarioOutcome::Won)
+    );
+    // The scenario only ends once
+    assert_eq!(progress.turn_ended(4), None);
+    assert_eq!(progress.outcome(), Some(ScenarioOutcome::Won));
+
+    let mut progress = ScenarioProgress::new(
+        ScenarioGoal::WinWithinTurns { turns: 2 },
+        alice,
+        vec![bob],
+        4,
+    );
+    assert_eq!(progress.turn_ended(4), None);
+    assert_eq!(progress.turn_ended(5), Some(ScenarioOutcome::Lost));
+}
+
+#[test]
+fn test_survive_goal() {
+    let (alice, bob) = (Entity::from_raw(1), Entity::from_raw(2));
+    let goal = ScenarioGoal::SurviveUntilTurn { turn: 6 };
+
+    let mut progress = ScenarioProgress::new(goal, alice, vec![bob], 5);
+    assert_eq!(progress.turn_ended(5), None);
+    assert_eq!(progress.turn_started(6), Some(ScenarioOutcome::Won));
+
+    let mut progress = ScenarioProgress::new(goal, alice, vec![bob], 5);
+    assert_eq!(progress.player_eliminated(bob), None);
+    assert_eq!(
+        progress.player_eliminated(alice),
+        Some(ScenarioOutcome::Lost)
+    );
+}
+
+#[test]
+fn test_apply_scenario_builds_board() {
+    let scenario = Scenario::parse(BOARD).unwrap();
+    let mut world = World::new();
+    let imported = apply_scenario(&mut world, &scenario);
+
+    let alice = imported.player(0).unwrap();
+    let bob = imported.player(1).unwrap();
+    assert_eq!(world.get::<Player>(alice).unwrap().life, 5);
+
+    // The scripted hand replaces the hidden one
+    let zones = world.resource::<ZoneManager>();
+    let hand: Vec<String> = zones
+        .hand(alice)
+        .iter()
+        .map(|card| world.get::<Card>(*card).unwrap().name.name.clone())
+        .collect();
+    assert_eq!(hand, vec!["Lightning Bolt", "Mystery Card"]);
+    assert_eq!(zones.hand(bob).len(), 1);
+
+    let turn_manager = world.resource::<TurnManager>();
+    assert_eq!(turn_manager.active_player, bob);
+    assert_eq!(turn_manager.turn_number, 3);
+
+    let active = world.resource::<ActiveScenario>();
+    assert_eq!(active.progress.player, alice);
+    assert_eq!(active.progress.opponents, vec![bob]);
+    assert_eq!(active.progress.start_turn, 3);
+}
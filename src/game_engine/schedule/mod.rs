@@ -0,0 +1,85 @@
+//! Order of the game engine's fixed timestep systems
+//!
+//! Each [`FixedUpdate`] tick runs the engine in the order of [`GameEngineSet`]:
+//!
+//! 1. [`Input`](GameEngineSet::Input) takes players' actions, priority passes and
+//!    requests to resolve stack items
+//! 2. [`Turn`](GameEngineSet::Turn) advances the phase for last tick's
+//!    [`NextPhaseEvent`](crate::game_engine::NextPhaseEvent) and starts turns
+//! 3. [`Triggers`](GameEngineSet::Triggers) puts abilities triggered by the new step
+//!    on the stack, before anyone could pass priority past them
+//! 4. [`Stack`](GameEngineSet::Stack) resolves the top of the stack
+//! 5. [`Combat`](GameEngineSet::Combat) declares attackers and blockers and deals
+//!    combat damage
+//! 6. [`Zones`](GameEngineSet::Zones) moves the cards that resolving and combat sent
+//!    to other zones
+//! 7. [`Sba`](GameEngineSet::Sba) performs state-based actions on the result
+//! 8. [`Cleanup`](GameEngineSet::Cleanup) discards and removes damage in the cleanup
+//!    step
+//! 9. [`Priority`](GameEngineSet::Priority) decides whether everyone passed, asking
+//!    for the next phase or giving priority back to the active player
+//!
+//! Sets only order systems. Each system keeps its own run conditions, so a
+//! pending choice pauses the game logic without holding up zone changes.
+
+use bevy::prelude::*;
+
+#[cfg(test)]
+mod tests;
+
+/// Stages of a game engine tick, run in declaration order in [`FixedUpdate`]
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameEngineSet {
+    /// Game actions, priority passes and resolution requests
+    Input,
+    /// Phase transitions and turn structure
+    Turn,
+    /// Triggered abilities going on the stack
+    Triggers,
+    /// Stack resolution
+    Stack,
+    /// Combat steps
+    Combat,
+    /// Cards changing zones and drawing
+    Zones,
+    /// State-based actions
+    Sba,
+    /// The cleanup step
+    Cleanup,
+    /// Working out who gets priority next
+    Priority,
+}
+
+impl GameEngineSet {
+    /// Every set, in the order they run
+    pub const ALL: [GameEngineSet; 9] = [
+        GameEngineSet::Input,
+        GameEngineSet::Turn,
+        GameEngineSet::Triggers,
+        GameEngineSet::Stack,
+        GameEngineSet::Combat,
+        GameEngineSet::Zones,
+        GameEngineSet::Sba,
+        GameEngineSet::Cleanup,
+        GameEngineSet::Priority,
+    ];
+}
+
+/// Chain the game engine sets in [`FixedUpdate`]
+pub fn configure_game_engine_sets(app: &mut App) {
+    app.configure_sets(
+        FixedUpdate,
+        (
+            GameEngineSet::Input,
+            GameEngineSet::Turn,
+            GameEngineSet::Triggers,
+            GameEngineSet::Stack,
+            GameEngineSet::Combat,
+            GameEngineSet::Zones,
+            GameEngineSet::Sba,
+            GameEngineSet::Cleanup,
+            GameEngineSet::Priority,
+        )
+            .chain(),
+    );
+}
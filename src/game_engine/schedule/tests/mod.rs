@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+use crate::game_engine::schedule::{GameEngineSet, configure_game_engine_sets};
+
+/// The sets that ran this tick, in order
+#[derive(Resource, Default)]
+struct Ran(Vec<GameEngineSet>);
+
+#[test]
+fn test_sets_run_in_pipeline_order() {
+    let mut app = App::new();
+    app.init_resource::<Ran>();
+    configure_game_engine_sets(&mut app);
+
+    // Added backwards so registration order can't be what orders them
+    for set in GameEngineSet::ALL.into_iter().rev() {
+        app.add_systems(
+            FixedUpdate,
+            (move |mut ran: ResMut<Ran>| ran.0.push(set)).in_set(set),
+        );
+    }
+    app.world_mut().run_schedule(FixedUpdate);
+
+    assert_eq!(app.world().resource::<Ran>().0, GameEngineSet::ALL.to_vec());
+}
@@ -0,0 +1,340 @@
+//! Interpreter for cards defined as data
+//!
+//! Turns the [`EffectPrimitive`]s of a card's [`CardScript`] into effects on the
+//! stack:
+//!
+//! - casting a scripted spell puts a [`ScriptedEffect`] with its effects on the
+//!   stack
+//! - activating a scripted ability pays its cost and does the same for the
+//!   ability, see [`process_game_actions`](crate::game_engine::actions::process_game_actions),
+//!   except that mana abilities resolve right away without using the stack
+//! - scripted triggered abilities go through the trigger queue like any other
+//!
+//! Primitives are carried out in order as the effect resolves, through the
+//! events the rest of the engine uses, such as [`LifeChangeEvent`] and
+//! [`DrawCardEvent`], so they can be prevented or trigger abilities like any
+//! other change. An effect aimed at a target does nothing without one, as for
+//! triggered abilities, which don't choose targets yet.
+
+use bevy::prelude::*;
+
+use crate::cards::details::{CreatureCard, CreatureOnField};
+use crate::cards::mtgjson::parse_mana_cost;
+use crate::cards::scripting::{
+    CardScript, EffectPrimitive, EffectRecipient, ScriptTrigger, ScriptedTrigger,
+};
+use crate::cards::{Card, CardDetails, CardTypes, CreatureType};
+use crate::game_engine::commander::CombatDamageEvent;
+use crate::game_engine::enters_choice::{ChosenOnEntry, EntersWithChoice};
+use crate::game_engine::life::{LifeChangeEvent, LifeChangedEvent};
+use crate::game_engine::mana_abilities::ProduceManaEvent;
+use crate::game_engine::permanent::{
+    Permanent, PermanentController, PermanentOwner, PermanentState,
+};
+use crate::game_engine::phase::StepBeganEvent;
+use crate::game_engine::schedule::GameEngineSet;
+use crate::game_engine::scry::ScryEvent;
+use crate::game_engine::stack::{Effect, EffectSpec, ResolutionDetails};
+use crate::game_engine::triggers::{AbilityTriggeredEvent, PendingTrigger, order_triggers};
+use crate::game_engine::turns::TurnManager;
+use crate::game_engine::zones::{DrawCardEvent, EntersBattlefieldEvent, ZoneManager};
+use crate::mana::Mana;
+use crate::menu::state::GameMenuState;
+use crate::player::Player;
+
+/// A scripted spell or ability on the stack
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptedEffect {
+    /// The spell's name, or the ability's rules text
+    pub name: String,
+    /// The card the spell or ability comes from
+    pub source: Entity,
+    pub controller: Entity,
+    pub targets: Vec<Entity>,
+    pub effects: Vec<EffectPrimitive>,
+}
+
+impl Effect for ScriptedEffect {
+    fn resolve(&self, commands: &mut Commands) {
+        resolve_primitives(
+            commands,
+            self.source,
+            self.controller,
+            self.targets.clone(),
+            self.effects.clone(),
+        );
+    }
+
+    fn details(&self) -> ResolutionDetails {
+        ResolutionDetails {
+            name: self.name.clone(),
+            ..default()
+        }
+    }
+
+    fn controller(&self) -> Entity {
+        self.controller
+    }
+
+    fn targets(&self) -> Vec<Entity> {
+        self.targets.clone()
+    }
+
+    fn spec(&self) -> EffectSpec {
+        EffectSpec::Scripted {
+            name: self.name.clone(),
+            source: self.source,
+            controller: self.controller,
+            targets: self.targets.clone(),
+            effects: self.effects.clone(),
+        }
+    }
+}
+
+/// Carry out `effects` in order once the commands are applied
+pub fn resolve_primitives(
+    commands: &mut Commands,
+    source: Entity,
+    controller: Entity,
+    targets: Vec<Entity>,
+    effects: Vec<EffectPrimitive>,
+) {
+    if effects.is_empty() {
+        return;
+    }
+    commands.queue(move |world: &mut World| {
+        for effect in &effects {
+            apply_primitive(world, source, controller, &targets, effect);
+        }
+    });
+}
+
+/// Carry out one primitive for a spell or ability from `source`
+pub fn apply_primitive(
+    world: &mut World,
+    source: Entity,
+    controller: Entity,
+    targets: &[Entity],
+    effect: &EffectPrimitive,
+) {
+    match effect {
+        EffectPrimitive::DealDamage { amount, to } => {
+            for target in recipients(world, controller, targets, *to) {
+                deal_damage(world, source, target, *amount);
+            }
+        }
+        EffectPrimitive::DrawCards { count } => {
+            world.send_event(DrawCardEvent {
+                player: controller,
+                count: *count,
+            });
+        }
+        EffectPrimitive::GainLife { amount } => {
+            world.send_event(LifeChangeEvent::gain(controller, Some(source), *amount));
+        }
+        EffectPrimitive::LoseLife { amount, who } => {
+            for player in recipients(world, controller, targets, *who) {
+                if world.get::<Player>(player).is_some() {
+                    world.send_event(LifeChangeEvent::loss(player, Some(source), *amount));
+                }
+            }
+        }
+        EffectPrimitive::CreateToken {
+            name,
+            power,
+            toughness,
+            count,
+        } => {
+            for _ in 0..*count {
+                create_token(world, controller, name, *power, *toughness);
+            }
+        }
+        EffectPrimitive::Scry { count } => {
+            world.send_event(ScryEvent {
+                player: controller,
+                source: Some(source),
+                count: *count,
+            });
+        }
+        EffectPrimitive::AddMana { mana } => {
+            world.send_event(ProduceManaEvent {
+                player: controller,
+                source: Some(source),
+                mana: parse_mana_cost(mana),
+            });
+        }
+    }
+}
+
+/// The players or permanents an effect happens to
+fn recipients(
+    world: &mut World,
+    controller: Entity,
+    targets: &[Entity],
+    recipient: EffectRecipient,
+) -> Vec<Entity> {
+    match recipient {
+        EffectRecipient::Target => targets.to_vec(),
+        EffectRecipient::Controller => vec![controller],
+        EffectRecipient::EachOpponent => world
+            .query_filtered::<Entity, With<Player>>()
+            .iter(world)
+            .filter(|player| *player != controller)
+            .collect(),
+    }
+}
+
+/// Deal noncombat damage to a player or creature
+fn deal_damage(world: &mut World, source: Entity, target: Entity, amount: u32) {
+    if amount == 0 {
+        return;
+    }
+    if world.get::<Player>(target).is_some() {
+        world.send_event(LifeChangeEvent::damage(target, source, amount));
+    } else if let Some(mut creature) = world.get_mut::<CreatureOnField>(target) {
+        creature.battle_damage += amount as u64;
+    } else {
+        return;
+    }
+    world.send_event(CombatDamageEvent {
+        source,
+        target,
+        damage: amount,
+        is_combat_damage: false,
+        // Only combat damage counts as commander damage
+        source_is_commander: false,
+    });
+}
+
+/// Put a creature token onto the battlefield under `controller`'s control
+fn create_token(world: &mut World, controller: Entity, name: &str, power: i32, toughness: i32) {
+    let card = Card::new(
+        name,
+        Mana::default(),
+        CardTypes::CREATURE,
+        CardDetails::Creature(CreatureCard {
+            power,
+            toughness,
+            creature_type: CreatureType::NONE,
+        }),
+        "",
+    );
+    let turn = world
+        .get_resource::<TurnManager>()
+        .map_or(0, |turns| turns.turn_number);
+    let token = world
+        .spawn((
+            card.clone(),
+            CreatureOnField {
+                card,
+                power_modifier: power as i64,
+                toughness_modifier: toughness as i64,
+                battle_damage: 0,
+                token: true,
+            },
+            Permanent,
+            PermanentState::new(turn),
+            PermanentOwner::new(controller),
+            PermanentController::new(controller),
+            Name::new(format!("{} Token", name)),
+        ))
+        .id();
+    if let Some(mut zones) = world.get_resource_mut::<ZoneManager>() {
+        zones.add_to_battlefield(controller, token);
+    }
+    world.send_event(EntersBattlefieldEvent {
+        permanent: token,
+        owner: controller,
+        enters_tapped: false,
+    });
+}
+
+/// The trigger for a scripted ability of `source`
+fn pending_trigger(
+    source: Entity,
+    controller: Entity,
+    ability: &ScriptedTrigger,
+) -> PendingTrigger {
+    PendingTrigger {
+        source,
+        controller,
+        description: ability.text.clone(),
+        effects: ability.effects.clone(),
+    }
+}
+
+/// Trigger the scripted abilities of permanents
+///
+/// Permanents still waiting for a choice made as they enter haven't entered
+/// yet; their event is sent again once the choice is made.
+pub fn collect_scripted_triggers(
+    mut entered: EventReader<EntersBattlefieldEvent>,
+    mut steps: EventReader<StepBeganEvent>,
+    mut life_changes: EventReader<LifeChangedEvent>,
+    scripts: Query<(Entity, &CardScript, &PermanentController)>,
+    waiting_for_choice: Query<(), (With<EntersWithChoice>, Without<ChosenOnEntry>)>,
+    mut triggered: EventWriter<AbilityTriggeredEvent>,
+) {
+    let mut fire =
+        |source: Entity, controller: Entity, script: &CardScript, when: ScriptTrigger| {
+            for ability in script.triggers(when) {
+                triggered.write(AbilityTriggeredEvent(pending_trigger(
+                    source, controller, ability,
+                )));
+            }
+        };
+
+    for event in entered.read() {
+        if waiting_for_choice.contains(event.permanent) {
+            continue;
+        }
+        if let Ok((source, script, controller)) = scripts.get(event.permanent) {
+            fire(
+                source,
+                controller.player,
+                script,
+                ScriptTrigger::EntersTheBattlefield,
+            );
+        }
+    }
+
+    for step in steps.read() {
+        for (source, script, controller) in scripts.iter() {
+            if controller.player != step.active_player {
+                continue;
+            }
+            for when in [ScriptTrigger::YourUpkeep, ScriptTrigger::YourEndStep] {
+                if when.condition().triggers_at_beginning_of(step.phase) {
+                    fire(source, controller.player, script, when);
+                }
+            }
+        }
+    }
+
+    for event in life_changes.read().filter(|event| event.change > 0) {
+        for (source, script, controller) in scripts.iter() {
+            if controller.player == event.player {
+                fire(
+                    source,
+                    controller.player,
+                    script,
+                    ScriptTrigger::YouGainLife,
+                );
+            }
+        }
+    }
+}
+
+/// Register the interpreter's systems
+pub fn register_scripting_systems(app: &mut App) {
+    app.add_systems(
+        FixedUpdate,
+        collect_scripted_triggers
+            .before(order_triggers)
+            .in_set(GameEngineSet::Triggers)
+            .run_if(in_state(GameMenuState::InGame)),
+    );
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,268 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+
+use crate::cards::details::CreatureOnField;
+use crate::cards::scripting::{
+    CardScript, EffectPrimitive, EffectRecipient, ScriptTrigger, ScriptedTrigger,
+};
+use crate::cards::{Card, CardDetails, CardTypes};
+use crate::game_engine::combat::combat_power;
+use crate::game_engine::commander::{CombatDamageEvent, PlayerEliminatedEvent};
+use crate::game_engine::enters_choice::{EntersChoiceKind, EntersWithChoice};
+use crate::game_engine::life::{LifeChangeEvent, LifeChangedEvent, apply_life_changes};
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::phase::{BeginningStep, Phase, StepBeganEvent};
+use crate::game_engine::scripting::{ScriptedEffect, collect_scripted_triggers};
+use crate::game_engine::scry::ScryEvent;
+use crate::game_engine::stack::Effect;
+use crate::game_engine::state::{GameEvent, GameState, state_based_actions_system};
+use crate::game_engine::triggers::AbilityTriggeredEvent;
+use crate::game_engine::zones::{
+    DrawCardEvent, EntersBattlefieldEvent, ZoneChangeEvent, ZoneManager,
+};
+use crate::mana::Mana;
+use crate::player::Player;
+
+fn script_app() -> (App, [Entity; 3]) {
+    let mut app = App::new();
+    app.add_event::<LifeChangeEvent>()
+        .add_event::<LifeChangedEvent>()
+        .add_event::<CombatDamageEvent>()
+        .add_event::<DrawCardEvent>()
+        .add_event::<ScryEvent>()
+        .add_event::<EntersBattlefieldEvent>()
+        .add_event::<StepBeganEvent>()
+        .add_event::<AbilityTriggeredEvent>()
+        .init_resource::<ZoneManager>()
+        .add_systems(Update, (apply_life_changes, collect_scripted_triggers));
+    let players =
+        ["Alice", "Bob", "Carol"].map(|name| app.world_mut().spawn(Player::new(name)).id());
+    (app, players)
+}
+
+fn resolve(app: &mut App, effect: &dyn Effect) {
+    let world = app.world_mut();
+    effect.resolve(&mut world.commands());
+    world.flush();
+    app.update();
+}
+
+fn life(app: &App, player: Entity) -> i32 {
+    app.world().get::<Player>(player).unwrap().life
+}
+
+fn drain<E: Event>(app: &mut App) -> Vec<E> {
+    app.world_mut()
+        .resource_mut::<Events<E>>()
+        .drain()
+        .collect()
+}
+
+#[test]
+fn test_scripted_effects_happen_in_order() {
+    let (mut app, [alice, bob, carol]) = script_app();
+    let starting = life(&app, alice);
+    let spell = app.world_mut().spawn_empty().id();
+    let bear = app
+        .world_mut()
+        .spawn(CreatureOnField {
+            card: Card::new(
+                "Bear",
+                Mana::default(),
+                CardTypes::CREATURE,
+                CardDetails::Other,
+                "",
+            ),
+            power_modifier: 0,
+            toughness_modifier: 0,
+            battle_damage: 0,
+            token: false,
+        })
+        .id();
+    let effect = ScriptedEffect {
+        name: "Kitchen Fire".to_string(),
+        source: spell,
+        controller: alice,
+        targets: vec![bob, bear],
+        effects: vec![
+            EffectPrimitive::DealDamage {
+                amount: 2,
+                to: EffectRecipient::Target,
+            },
+            EffectPrimitive::LoseLife {
+                amount: 1,
+                who: EffectRecipient::EachOpponent,
+            },
+            EffectPrimitive::GainLife { amount: 3 },
+            EffectPrimitive::DrawCards { count: 2 },
+            EffectPrimitive::Scry { count: 1 },
+        ],
+    };
+    resolve(&mut app, &effect);
+
+    assert_eq!(life(&app, alice), starting + 3);
+    assert_eq!(life(&app, bob), starting - 3);
+    assert_eq!(life(&app, carol), starting - 1);
+    assert_eq!(
+        app.world()
+            .get::<CreatureOnField>(bear)
+            .unwrap()
+            .battle_damage,
+        2
+    );
+    let damage: Vec<Entity> = drain::<CombatDamageEvent>(&mut app)
+        .iter()
+        .map(|event| event.target)
+        .collect();
+    assert_eq!(damage, vec![bob, bear]);
+    let draws = drain::<DrawCardEvent>(&mut app);
+    assert_eq!(draws.len(), 1);
+    assert_eq!((draws[0].player, draws[0].count), (alice, 2));
+    assert_eq!(
+        drain::<ScryEvent>(&mut app),
+        vec![ScryEvent {
+            player: alice,
+            source: Some(spell),
+            count: 1,
+        }]
+    );
+}
+
+#[test]
+fn test_tokens_enter_the_battlefield_under_their_controller() {
+    let (mut app, [alice, ..]) = script_app();
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .init_player_zones(alice);
+    let spell = app.world_mut().spawn_empty().id();
+    let effect = ScriptedEffect {
+        name: "Call the Pack".to_string(),
+        source: spell,
+        controller: alice,
+        targets: Vec::new(),
+        effects: vec![EffectPrimitive::CreateToken {
+            name: "Wolf".to_string(),
+            power: 2,
+            toughness: 2,
+            count: 2,
+        }],
+    };
+    resolve(&mut app, &effect);
+
+    let battlefield = app.world().resource::<ZoneManager>().battlefield().to_vec();
+    assert_eq!(battlefield.len(), 2);
+    for token in battlefield {
+        let creature = app.world().get::<CreatureOnField>(token).unwrap();
+        assert!(creature.token);
+        assert_eq!(creature.card.name.name, "Wolf");
+        assert_eq!(
+            app.world()
+                .get::<PermanentController>(token)
+                .unwrap()
+                .player,
+            alice
+        );
+    }
+}
+
+#[test]
+fn test_scripted_tokens_survive_state_based_actions_and_deal_their_power() {
+    let (mut app, [alice, bob, _]) = script_app();
+    app.add_event::<ZoneChangeEvent>()
+        .add_event::<GameEvent>()
+        .add_event::<PlayerEliminatedEvent>()
+        .init_resource::<GameState>();
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .init_player_zones(alice);
+    app.world_mut()
+        .resource_mut::<GameState>()
+        .set_turn_order(vec![alice, bob]);
+    let spell = app.world_mut().spawn_empty().id();
+    let effect = ScriptedEffect {
+        name: "Raise the Alarm".to_string(),
+        source: spell,
+        controller: alice,
+        targets: Vec::new(),
+        effects: vec![EffectPrimitive::CreateToken {
+            name: "Soldier".to_string(),
+            power: 3,
+            toughness: 1,
+            count: 1,
+        }],
+    };
+    resolve(&mut app, &effect);
+    app.world_mut()
+        .run_system_once(state_based_actions_system)
+        .unwrap();
+
+    assert!(drain::<ZoneChangeEvent>(&mut app).is_empty());
+    let token = app.world().resource::<ZoneManager>().battlefield()[0];
+    let card = app.world().get::<Card>(token).unwrap();
+    let creature = app.world().get::<CreatureOnField>(token);
+    assert_eq!(combat_power(card, creature), 3);
+}
+
+#[test]
+fn test_scripted_abilities_trigger_with_their_effects() {
+    let (mut app, [alice, bob, _]) = script_app();
+    let draw = vec![EffectPrimitive::DrawCards { count: 1 }];
+    let script = CardScript {
+        triggered: vec![
+            ScriptedTrigger {
+                when: ScriptTrigger::EntersTheBattlefield,
+                text: "When this enters, draw a card.".to_string(),
+                effects: draw.clone(),
+            },
+            ScriptedTrigger {
+                when: ScriptTrigger::YourUpkeep,
+                text: "At the beginning of your upkeep, draw a card.".to_string(),
+                effects: draw.clone(),
+            },
+        ],
+        ..default()
+    };
+    let lookout = app
+        .world_mut()
+        .spawn((script.clone(), PermanentController::new(alice)))
+        .id();
+    // Still choosing something as it enters, so it hasn't entered yet
+    let waiting = app
+        .world_mut()
+        .spawn((
+            script,
+            PermanentController::new(alice),
+            EntersWithChoice(EntersChoiceKind::Color),
+        ))
+        .id();
+
+    for permanent in [lookout, waiting] {
+        app.world_mut().send_event(EntersBattlefieldEvent {
+            permanent,
+            owner: alice,
+            enters_tapped: false,
+        });
+    }
+    app.update();
+    let triggered = drain::<AbilityTriggeredEvent>(&mut app);
+    assert_eq!(triggered.len(), 1);
+    assert_eq!(triggered[0].0.source, lookout);
+    assert_eq!(triggered[0].0.effects, draw);
+
+    // Upkeep triggers only on its controller's turn
+    app.world_mut().despawn(waiting);
+    let upkeep = Phase::Beginning(BeginningStep::Upkeep);
+    for active_player in [bob, alice] {
+        app.world_mut().send_event(StepBeganEvent {
+            phase: upkeep,
+            active_player,
+        });
+    }
+    app.update();
+    let triggered = drain::<AbilityTriggeredEvent>(&mut app);
+    assert_eq!(triggered.len(), 1);
+    assert_eq!(
+        triggered[0].0.description,
+        "At the beginning of your upkeep, draw a card."
+    );
+}
@@ -0,0 +1,171 @@
+//! Scrying: looking at the top of a library and putting cards on the bottom
+//!
+//! A [`ScryEvent`] has a player look at the top cards of their library and
+//! choose which of them go to the bottom (rule 701.22). The choice is asked
+//! through [`PendingChoices`], so the game waits for it. Cards kept on top stay
+//! in the order they were in, and cards put on the bottom go there in the order
+//! they were chosen.
+//!
+//! A [`ScryStartedEvent`] and [`ScryFinishedEvent`] bracket each scry. They only
+//! name the cards, which are face down to everyone but the scrying player, so
+//! the library visuals can show them without knowing what they are.
+//!
+//! Scries are made one at a time in the order they were asked for.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::game_engine::choices::{
+    ChoiceAnswer, ChoiceId, ChoiceKind, ChoiceRequest, PendingChoices,
+};
+use crate::game_engine::zones::{Zone, ZoneManager, ZonePosition};
+use crate::menu::state::GameMenuState;
+
+/// Sent to have a player scry
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScryEvent {
+    pub player: Entity,
+    /// The spell or ability making them scry, if any
+    pub source: Option<Entity>,
+    pub count: usize,
+}
+
+/// A player started looking at the top cards of their library
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ScryStartedEvent {
+    pub player: Entity,
+    /// The cards looked at, top card first
+    pub cards: Vec<Entity>,
+}
+
+/// A player finished scrying
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ScryFinishedEvent {
+    pub player: Entity,
+    /// Cards left on top, top card first
+    pub top: Vec<Entity>,
+    /// Cards put on the bottom, in the order they went there
+    pub bottom: Vec<Entity>,
+}
+
+/// A player scrying
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scry {
+    pub request: ScryEvent,
+    /// The cards looked at, top card first
+    pub cards: Vec<Entity>,
+    /// The choice of cards to put on the bottom
+    pub choice: ChoiceId,
+}
+
+/// Scries waiting to be made
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Scries {
+    /// Requests waiting for the scry before them
+    pub pending: VecDeque<ScryEvent>,
+    /// The scry being made, if any
+    pub current: Option<Scry>,
+}
+
+/// Make scries in the order they were asked for, waiting for players' choices
+pub fn handle_scries(
+    mut requests: EventReader<ScryEvent>,
+    mut scries: ResMut<Scries>,
+    mut choices: ResMut<PendingChoices>,
+    mut started: EventWriter<ScryStartedEvent>,
+    mut finished: EventWriter<ScryFinishedEvent>,
+    zones: Option<ResMut<ZoneManager>>,
+) {
+    scries.pending.extend(requests.read().copied());
+    let Some(mut zones) = zones else {
+        return;
+    };
+
+    if let Some(current) = scries.current.clone() {
+        if choices.is_pending(current.choice) {
+            return;
+        }
+        let bottom = match choices.take_answer(current.choice) {
+            Some(ChoiceAnswer::Entities(bottom)) => bottom,
+            _ => Vec::new(),
+        };
+        for &card in &bottom {
+            zones.move_to(card, Zone::Library, ZonePosition::Bottom);
+        }
+        let top: Vec<Entity> = current
+            .cards
+            .iter()
+            .copied()
+            .filter(|card| !bottom.contains(card))
+            .collect();
+        info!(
+            "{:?} scried {}, keeping {} on top",
+            current.request.player,
+            current.cards.len(),
+            top.len()
+        );
+        scries.current = None;
+        finished.write(ScryFinishedEvent {
+            player: current.request.player,
+            top,
+            bottom,
+        });
+    }
+
+    while let Some(request) = scries.pending.pop_front() {
+        let cards: Vec<Entity> = zones
+            .library(request.player)
+            .iter()
+            .rev()
+            .take(request.count)
+            .copied()
+            .collect();
+        // Scrying an empty library does nothing
+        if cards.is_empty() {
+            continue;
+        }
+
+        let choice = choices.push(ChoiceRequest {
+            player: request.player,
+            source: request.source,
+            prompt: format!("Scry {}: choose cards to put on the bottom", request.count),
+            kind: ChoiceKind::Entities {
+                options: cards.clone(),
+                min: 0,
+                max: cards.len(),
+            },
+        });
+        started.write(ScryStartedEvent {
+            player: request.player,
+            cards: cards.clone(),
+        });
+        scries.current = Some(Scry {
+            request,
+            cards,
+            choice,
+        });
+        break;
+    }
+}
+
+/// Forget scries from the last game when returning to the main menu
+pub fn clear_scries(mut scries: ResMut<Scries>) {
+    *scries = Scries::default();
+}
+
+/// Register scrying
+pub fn register_scry_systems(app: &mut App) {
+    app.add_event::<ScryEvent>()
+        .add_event::<ScryStartedEvent>()
+        .add_event::<ScryFinishedEvent>()
+        .init_resource::<Scries>()
+        .add_systems(
+            Update,
+            handle_scries.run_if(in_state(GameMenuState::InGame)),
+        )
+        .add_systems(OnEnter(GameMenuState::MainMenu), clear_scries);
+}
+
+#[cfg(test)]
+mod tests;
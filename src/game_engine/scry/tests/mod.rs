@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+
+use crate::game_engine::choices::{
+    AnswerChoiceEvent, ChoiceAnswer, PendingChoices, handle_choice_answers,
+};
+use crate::game_engine::scry::{
+    Scries, ScryEvent, ScryFinishedEvent, ScryStartedEvent, handle_scries,
+};
+use crate::game_engine::zones::ZoneManager;
+
+fn scry_app() -> (App, Entity, Vec<Entity>) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<ScryEvent>()
+        .add_event::<ScryStartedEvent>()
+        .add_event::<ScryFinishedEvent>()
+        .add_event::<AnswerChoiceEvent>()
+        .init_resource::<Scries>()
+        .init_resource::<PendingChoices>()
+        .init_resource::<ZoneManager>()
+        .add_systems(Update, (handle_choice_answers, handle_scries).chain());
+    let alice = app.world_mut().spawn_empty().id();
+    // Added bottom first, so the last card is on top
+    let cards: Vec<Entity> = (0..4).map(|_| app.world_mut().spawn_empty().id()).collect();
+    let mut zones = app.world_mut().resource_mut::<ZoneManager>();
+    zones.init_player_zones(alice);
+    for &card in &cards {
+        zones.add_to_library(alice, card);
+    }
+    (app, alice, cards)
+}
+
+fn drain<E: Event>(app: &mut App) -> Vec<E> {
+    app.world_mut()
+        .resource_mut::<Events<E>>()
+        .drain()
+        .collect()
+}
+
+#[test]
+fn test_scried_cards_go_to_the_bottom_in_the_order_chosen() {
+    let (mut app, alice, cards) = scry_app();
+    let [bottom, second, third, top] = cards[..] else {
+        unreachable!()
+    };
+
+    app.world_mut().send_event(ScryEvent {
+        player: alice,
+        source: None,
+        count: 3,
+    });
+    app.update();
+    assert_eq!(
+        drain::<ScryStartedEvent>(&mut app),
+        vec![ScryStartedEvent {
+            player: alice,
+            cards: vec![top, third, second],
+        }]
+    );
+    let choice = app
+        .world()
+        .resource::<Scries>()
+        .current
+        .as_ref()
+        .unwrap()
+        .choice;
+
+    // The game waits for Alice
+    app.update();
+    assert!(drain::<ScryFinishedEvent>(&mut app).is_empty());
+
+    app.world_mut().send_event(AnswerChoiceEvent {
+        player: alice,
+        id: choice,
+        answer: ChoiceAnswer::Entities(vec![second, top]),
+    });
+    app.update();
+    assert_eq!(
+        drain::<ScryFinishedEvent>(&mut app),
+        vec![ScryFinishedEvent {
+            player: alice,
+            top: vec![third],
+            bottom: vec![second, top],
+        }]
+    );
+    assert_eq!(
+        app.world().resource::<ZoneManager>().library(alice),
+        &[top, second, bottom, third]
+    );
+    assert!(app.world().resource::<Scries>().current.is_none());
+}
+
+#[test]
+fn test_scrying_an_empty_library_does_nothing() {
+    let (mut app, _, _) = scry_app();
+    let bob = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .init_player_zones(bob);
+
+    app.world_mut().send_event(ScryEvent {
+        player: bob,
+        source: None,
+        count: 2,
+    });
+    app.update();
+    assert!(drain::<ScryStartedEvent>(&mut app).is_empty());
+    assert!(!app.world().resource::<PendingChoices>().is_waiting());
+}
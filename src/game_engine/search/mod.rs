@@ -0,0 +1,351 @@
+//! Searching a library for cards, then shuffling it
+//!
+//! Tutors have a player search their library for cards matching a
+//! [`CardSearchFilter`] and put them into a zone. A search is started with a
+//! [`SearchLibraryEvent`] and the player answers with a
+//! [`ChooseSearchResultEvent`], or the first matching cards are taken once
+//! [`SEARCH_TIMEOUT_SECS`] have passed. Since a library is a hidden zone, the
+//! player may always fail to find, even with matching cards in it (rule
+//! 701.19b).
+//!
+//! Only the searching player learns what's in their library. Everyone else is
+//! told where the found cards went through a [`LibrarySearchedEvent`] and that
+//! the library was shuffled through a [`LibraryShuffledEvent`]. They only learn
+//! which cards were found if the search reveals them or they go to a public zone.
+//!
+//! Searches are made one at a time in the order they were asked for.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::cards::{Card, CardTypes};
+use crate::game_engine::reveal::{RevealAudience, RevealCardsEvent};
+use crate::game_engine::zones::{LibraryShuffledEvent, Zone, ZoneChangeEvent, ZoneManager};
+use crate::menu::state::GameMenuState;
+
+/// Seconds a player has to choose what they find before it's chosen for them
+pub const SEARCH_TIMEOUT_SECS: f32 = 30.0;
+
+/// The cards a search can find
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CardSearchFilter {
+    /// Types a card must all have, like a basic land
+    pub all_types: CardTypes,
+    /// Types a card must have at least one of, or any if empty
+    pub any_types: CardTypes,
+    /// A card's exact name, ignoring case
+    pub name: Option<String>,
+}
+
+impl CardSearchFilter {
+    /// Cards with all of `types`
+    pub fn of_type(types: CardTypes) -> Self {
+        Self {
+            all_types: types,
+            ..default()
+        }
+    }
+
+    /// Cards with any of `types`, like "an artifact or enchantment card"
+    pub fn any_of(types: CardTypes) -> Self {
+        Self {
+            any_types: types,
+            ..default()
+        }
+    }
+
+    /// Cards named `name`
+    pub fn named(name: &str) -> Self {
+        Self {
+            name: Some(name.to_string()),
+            ..default()
+        }
+    }
+
+    /// Whether `card` can be found
+    pub fn matches(&self, card: &Card) -> bool {
+        let types = card.type_info.types;
+        types.contains(self.all_types)
+            && (self.any_types.is_empty() || self.any_types.intersects(types))
+            && self
+                .name
+                .as_ref()
+                .is_none_or(|name| card.name.name.eq_ignore_ascii_case(name))
+    }
+}
+
+/// Sent to have a player search their library
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct SearchLibraryEvent {
+    pub player: Entity,
+    /// The spell or ability searching
+    pub source: Entity,
+    pub filter: CardSearchFilter,
+    /// How many cards can be found at most
+    pub count: usize,
+    /// Where found cards go; a library puts them on top after the shuffle
+    pub destination: Zone,
+    /// Whether found cards are revealed to the other players
+    pub reveal: bool,
+}
+
+impl SearchLibraryEvent {
+    /// Search for one card matching `filter` and put it into `destination`
+    pub fn tutor(
+        player: Entity,
+        source: Entity,
+        filter: CardSearchFilter,
+        destination: Zone,
+    ) -> Self {
+        Self {
+            player,
+            source,
+            filter,
+            count: 1,
+            destination,
+            reveal: false,
+        }
+    }
+
+    /// Reveal the found cards
+    pub fn revealed(mut self) -> Self {
+        self.reveal = true;
+        self
+    }
+}
+
+/// Sent by a player with the cards they found; none fails to find
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ChooseSearchResultEvent {
+    pub player: Entity,
+    pub cards: Vec<Entity>,
+}
+
+/// A library search finished, as every player sees it
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct LibrarySearchedEvent {
+    pub player: Entity,
+    pub source: Entity,
+    pub destination: Zone,
+    /// How many cards were found
+    pub found: usize,
+    /// The cards found, if the other players get to see them
+    pub public_cards: Vec<Entity>,
+}
+
+/// A player searching their library
+#[derive(Debug, Clone, PartialEq)]
+pub struct LibrarySearch {
+    pub request: SearchLibraryEvent,
+    /// The cards in the library that match, in library order
+    pub matches: Vec<Entity>,
+    /// Seconds spent waiting for the player so far
+    pub waited: f32,
+}
+
+impl LibrarySearch {
+    /// Whether `cards` can be found: up to the count of different matches
+    pub fn is_valid(&self, cards: &[Entity]) -> bool {
+        cards.len() <= self.request.count
+            && cards
+                .iter()
+                .enumerate()
+                .all(|(index, card)| self.matches.contains(card) && !cards[..index].contains(card))
+    }
+
+    /// Seconds left before the choice is made for the player
+    pub fn remaining_secs(&self) -> f32 {
+        (SEARCH_TIMEOUT_SECS - self.waited).max(0.0)
+    }
+}
+
+/// Library searches waiting to be made
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct LibrarySearches {
+    /// Requests waiting for the search before them
+    pub pending: VecDeque<SearchLibraryEvent>,
+    /// The search being made, if any
+    pub current: Option<LibrarySearch>,
+}
+
+/// Whether every player can see cards in `zone`
+fn is_public(zone: Zone) -> bool {
+    !matches!(zone, Zone::Library | Zone::Hand)
+}
+
+/// Move the found cards, shuffle the library and tell everyone what they may know
+fn finish_search(
+    request: &SearchLibraryEvent,
+    found: Vec<Entity>,
+    zones: &mut ZoneManager,
+    zone_events: &mut EventWriter<ZoneChangeEvent>,
+    shuffles: &mut EventWriter<LibraryShuffledEvent>,
+    reveals: &mut EventWriter<RevealCardsEvent>,
+    searched: &mut EventWriter<LibrarySearchedEvent>,
+) {
+    // The library is shuffled with the found cards still in it; they are moved
+    // out, or onto its top, right after
+    zones.shuffle_library(request.player, &mut rand::rng());
+    shuffles.write(LibraryShuffledEvent {
+        player: request.player,
+    });
+    let visible = is_public(request.destination);
+    for &card in &found {
+        zone_events.write(ZoneChangeEvent {
+            card,
+            owner: request.player,
+            source: Zone::Library,
+            destination: request.destination,
+            was_visible: false,
+            is_visible: visible,
+        });
+    }
+    if request.reveal && !found.is_empty() {
+        reveals.write(RevealCardsEvent {
+            player: request.player,
+            cards: found.clone(),
+            audience: RevealAudience::Opponents,
+            lasting: false,
+        });
+    }
+
+    info!(
+        "{:?} searched their library for {:?} and found {} cards",
+        request.player,
+        request.source,
+        found.len()
+    );
+    searched.write(LibrarySearchedEvent {
+        player: request.player,
+        source: request.source,
+        destination: request.destination,
+        found: found.len(),
+        public_cards: if visible || request.reveal {
+            found
+        } else {
+            Vec::new()
+        },
+    });
+}
+
+/// Make searches in the order they were asked for, waiting for players' choices
+#[allow(clippy::too_many_arguments)]
+pub fn handle_library_searches(
+    time: Res<Time>,
+    mut requests: EventReader<SearchLibraryEvent>,
+    mut choices: EventReader<ChooseSearchResultEvent>,
+    mut searches: ResMut<LibrarySearches>,
+    mut zone_events: EventWriter<ZoneChangeEvent>,
+    mut shuffles: EventWriter<LibraryShuffledEvent>,
+    mut reveals: EventWriter<RevealCardsEvent>,
+    mut searched: EventWriter<LibrarySearchedEvent>,
+    zones: Option<ResMut<ZoneManager>>,
+    cards: Query<&Card>,
+) {
+    let new_requests: Vec<SearchLibraryEvent> = requests.read().cloned().collect();
+    if !new_requests.is_empty() {
+        searches.pending.extend(new_requests);
+    }
+    let Some(mut zones) = zones else {
+        choices.clear();
+        return;
+    };
+
+    if let Some(current) = searches.current.clone() {
+        let found = choices
+            .read()
+            .filter(|event| {
+                event.player == current.request.player && current.is_valid(&event.cards)
+            })
+            .map(|event| event.cards.clone())
+            .last();
+        let found = match found {
+            Some(found) => found,
+            None if current.remaining_secs() <= 0.0 => {
+                info!(
+                    "Choosing what {:?} finds automatically",
+                    current.request.player
+                );
+                current
+                    .matches
+                    .iter()
+                    .take(current.request.count)
+                    .copied()
+                    .collect()
+            }
+            None => {
+                // Waiting isn't a change anything needs to react to
+                if let Some(current) = searches.bypass_change_detection().current.as_mut() {
+                    current.waited += time.delta_secs();
+                }
+                return;
+            }
+        };
+        searches.current = None;
+        finish_search(
+            &current.request,
+            found,
+            &mut zones,
+            &mut zone_events,
+            &mut shuffles,
+            &mut reveals,
+            &mut searched,
+        );
+    } else {
+        choices.clear();
+    }
+
+    while let Some(request) = searches.pending.pop_front() {
+        let matches: Vec<Entity> = zones
+            .library(request.player)
+            .iter()
+            .copied()
+            .filter(|&card| {
+                cards
+                    .get(card)
+                    .is_ok_and(|card| request.filter.matches(card))
+            })
+            .collect();
+        if !matches.is_empty() {
+            searches.current = Some(LibrarySearch {
+                request,
+                matches,
+                waited: 0.0,
+            });
+            break;
+        }
+        // Nothing to find, so the library is just shuffled
+        finish_search(
+            &request,
+            Vec::new(),
+            &mut zones,
+            &mut zone_events,
+            &mut shuffles,
+            &mut reveals,
+            &mut searched,
+        );
+    }
+}
+
+/// Forget searches from the last game when returning to the main menu
+pub fn clear_library_searches(mut searches: ResMut<LibrarySearches>) {
+    *searches = LibrarySearches::default();
+}
+
+/// Register searching libraries
+pub fn register_search_systems(app: &mut App) {
+    app.add_event::<SearchLibraryEvent>()
+        .add_event::<ChooseSearchResultEvent>()
+        .add_event::<LibrarySearchedEvent>()
+        .add_event::<LibraryShuffledEvent>()
+        .init_resource::<LibrarySearches>()
+        .add_systems(
+            Update,
+            handle_library_searches.run_if(in_state(GameMenuState::InGame)),
+        )
+        .add_systems(OnEnter(GameMenuState::MainMenu), clear_library_searches);
+}
+
+#[cfg(test)]
+mod tests;
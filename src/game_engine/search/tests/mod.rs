@@ -0,0 +1,198 @@
+use bevy::prelude::*;
+
+use crate::cards::{Card, CardDetails, CardTypes, CreatureCard, CreatureType};
+use crate::game_engine::reveal::RevealCardsEvent;
+use crate::game_engine::search::{
+    CardSearchFilter, ChooseSearchResultEvent, LibrarySearchedEvent, LibrarySearches,
+    SEARCH_TIMEOUT_SECS, SearchLibraryEvent, handle_library_searches,
+};
+use crate::game_engine::zones::{
+    LibraryShuffledEvent, Zone, ZoneChangeEvent, ZoneManager, handle_zone_changes,
+};
+use crate::mana::Mana;
+
+fn search_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<SearchLibraryEvent>()
+        .add_event::<ChooseSearchResultEvent>()
+        .add_event::<LibrarySearchedEvent>()
+        .add_event::<LibraryShuffledEvent>()
+        .add_event::<RevealCardsEvent>()
+        .add_event::<ZoneChangeEvent>()
+        .init_resource::<LibrarySearches>()
+        .init_resource::<ZoneManager>()
+        .add_systems(
+            Update,
+            (handle_library_searches, handle_zone_changes).chain(),
+        );
+    let alice = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .init_player_zones(alice);
+    (app, alice)
+}
+
+fn in_library(app: &mut App, player: Entity, name: &str, types: CardTypes) -> Entity {
+    let details = if types.contains(CardTypes::CREATURE) {
+        CardDetails::Creature(CreatureCard {
+            power: 2,
+            toughness: 2,
+            creature_type: CreatureType::NONE,
+        })
+    } else {
+        CardDetails::Other
+    };
+    let card = app
+        .world_mut()
+        .spawn(Card::new(name, Mana::default(), types, details, ""))
+        .id();
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .add_to_library(player, card);
+    card
+}
+
+fn searched(app: &mut App) -> Vec<LibrarySearchedEvent> {
+    app.world_mut()
+        .resource_mut::<Events<LibrarySearchedEvent>>()
+        .drain()
+        .collect()
+}
+
+fn shuffles(app: &mut App) -> usize {
+    app.world_mut()
+        .resource_mut::<Events<LibraryShuffledEvent>>()
+        .drain()
+        .count()
+}
+
+fn zone(app: &App, card: Entity) -> Option<Zone> {
+    app.world().resource::<ZoneManager>().get_card_zone(card)
+}
+
+#[test]
+fn test_tutor_finds_a_matching_card_and_shuffles() {
+    let (mut app, alice) = search_app();
+    let forest = in_library(
+        &mut app,
+        alice,
+        "Forest",
+        CardTypes::BASIC | CardTypes::LAND,
+    );
+    let island = in_library(
+        &mut app,
+        alice,
+        "Island",
+        CardTypes::BASIC | CardTypes::LAND,
+    );
+    let bear = in_library(&mut app, alice, "Grizzly Bears", CardTypes::CREATURE);
+
+    app.world_mut().send_event(SearchLibraryEvent::tutor(
+        alice,
+        alice,
+        CardSearchFilter::of_type(CardTypes::BASIC | CardTypes::LAND),
+        Zone::Hand,
+    ));
+    app.update();
+    let current = app
+        .world()
+        .resource::<LibrarySearches>()
+        .current
+        .clone()
+        .unwrap();
+    assert_eq!(current.matches, vec![forest, island]);
+    assert!(!current.is_valid(&[bear]));
+    assert!(!current.is_valid(&[forest, island]));
+
+    // A card that doesn't match isn't found
+    app.world_mut().send_event(ChooseSearchResultEvent {
+        player: alice,
+        cards: vec![bear],
+    });
+    app.update();
+    assert!(searched(&mut app).is_empty());
+
+    app.world_mut().send_event(ChooseSearchResultEvent {
+        player: alice,
+        cards: vec![island],
+    });
+    app.update();
+    let events = searched(&mut app);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].found, 1);
+    assert_eq!(events[0].destination, Zone::Hand);
+    // Opponents don't learn what went to a hidden zone
+    assert!(events[0].public_cards.is_empty());
+    assert_eq!(shuffles(&mut app), 1);
+    assert_eq!(zone(&app, island), Some(Zone::Hand));
+    assert_eq!(
+        app.world().resource::<ZoneManager>().library(alice).len(),
+        2
+    );
+}
+
+#[test]
+fn test_searches_can_fail_to_find() {
+    let (mut app, alice) = search_app();
+    let bear = in_library(&mut app, alice, "Grizzly Bears", CardTypes::CREATURE);
+
+    app.world_mut().send_event(SearchLibraryEvent::tutor(
+        alice,
+        alice,
+        CardSearchFilter::of_type(CardTypes::CREATURE),
+        Zone::Battlefield,
+    ));
+    app.update();
+    app.world_mut().send_event(ChooseSearchResultEvent {
+        player: alice,
+        cards: Vec::new(),
+    });
+    app.update();
+    let events = searched(&mut app);
+    assert_eq!(events[0].found, 0);
+    assert_eq!(shuffles(&mut app), 1);
+    assert_eq!(zone(&app, bear), Some(Zone::Library));
+
+    // With nothing matching there's no choice to make
+    app.world_mut().send_event(SearchLibraryEvent::tutor(
+        alice,
+        alice,
+        CardSearchFilter::named("Demonic Tutor"),
+        Zone::Hand,
+    ));
+    app.update();
+    assert_eq!(searched(&mut app)[0].found, 0);
+    assert!(app.world().resource::<LibrarySearches>().current.is_none());
+}
+
+#[test]
+fn test_unanswered_searches_find_the_first_matches() {
+    let (mut app, alice) = search_app();
+    let bear = in_library(&mut app, alice, "Grizzly Bears", CardTypes::CREATURE);
+    in_library(
+        &mut app,
+        alice,
+        "Forest",
+        CardTypes::BASIC | CardTypes::LAND,
+    );
+
+    app.world_mut().send_event(SearchLibraryEvent::tutor(
+        alice,
+        alice,
+        CardSearchFilter::any_of(CardTypes::CREATURE | CardTypes::ARTIFACT),
+        Zone::Battlefield,
+    ));
+    app.update();
+    app.world_mut()
+        .resource_mut::<LibrarySearches>()
+        .current
+        .as_mut()
+        .unwrap()
+        .waited = SEARCH_TIMEOUT_SECS;
+    app.update();
+    let events = searched(&mut app);
+    // Cards put onto the battlefield are public
+    assert_eq!(events[0].public_cards, vec![bear]);
+    assert_eq!(zone(&app, bear), Some(Zone::Battlefield));
+}
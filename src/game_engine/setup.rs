@@ -0,0 +1,219 @@
+//! Game setup options chosen before a game starts
+//!
+//! [`GameSetupConfig`] is filled in by the new game screen and read when the game
+//! starts, both when spawning players and when initializing the engine's rules state.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game_engine::commander::rules::CommanderRules;
+use crate::game_engine::enforcement::RulesEnforcementLevel;
+use crate::game_engine::state::GameState;
+use crate::player::resources::PlayerConfig;
+
+/// Fewest players a game can be started with
+pub const MIN_PLAYERS: usize = 2;
+/// Most players a game can be started with
+pub const MAX_PLAYERS: usize = 6;
+
+/// Who draws on the first turn of the game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FirstTurnDraw {
+    /// As the rules say: the player going first skips their draw in a
+    /// two-player game, and everyone draws in multiplayer games (rule 103.8)
+    #[default]
+    Rules,
+    /// Every player draws on their first turn
+    EveryoneDraws,
+    /// The player going first always skips their draw
+    FirstPlayerSkips,
+}
+
+impl FirstTurnDraw {
+    /// Label shown on the new game screen
+    pub fn label(self) -> &'static str {
+        match self {
+            FirstTurnDraw::Rules => "By Player Count",
+            FirstTurnDraw::EveryoneDraws => "Everyone Draws",
+            FirstTurnDraw::FirstPlayerSkips => "First Player Skips",
+        }
+    }
+
+    /// The next option, for cycling through them with one button
+    pub fn next(self) -> Self {
+        match self {
+            FirstTurnDraw::Rules => FirstTurnDraw::EveryoneDraws,
+            FirstTurnDraw::EveryoneDraws => FirstTurnDraw::FirstPlayerSkips,
+            FirstTurnDraw::FirstPlayerSkips => FirstTurnDraw::Rules,
+        }
+    }
+}
+
+/// Optional rule changes agreed on before the game
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HouseRules {
+    /// Whether players lose to commander damage
+    pub commander_damage: bool,
+    /// Whether mana of a color outside a player's commander's color identity
+    /// is produced as colorless, as under the Commander rules before 2016
+    #[serde(default)]
+    pub colorless_outside_identity: bool,
+}
+
+impl Default for HouseRules {
+    fn default() -> Self {
+        Self {
+            commander_damage: true,
+            colorless_outside_identity: false,
+        }
+    }
+}
+
+/// Options for the next game, chosen on the new game screen
+#[derive(Resource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameSetupConfig {
+    /// Number of players at the table
+    pub player_count: usize,
+    /// Starting life total of each player
+    pub starting_life: i32,
+    /// Commander damage from a single commander that makes a player lose
+    pub commander_damage_threshold: u32,
+    /// Optional rule changes
+    pub house_rules: HouseRules,
+    /// How strictly the engine enforces the rules
+    #[serde(default)]
+    pub rules_enforcement: RulesEnforcementLevel,
+    /// House ban list decks are checked against, or `None` for the Commander
+    /// banned list
+    #[serde(default)]
+    pub ban_list: Option<String>,
+    /// Precon each player plays, by seat; players without one get the default deck
+    #[serde(default)]
+    pub precons: Vec<String>,
+    /// Seed for the rolls made as the game starts, like the roll for who goes
+    /// first, or `None` for a new seed each game
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Whether the player going first draws on their first turn
+    #[serde(default)]
+    pub first_turn_draw: FirstTurnDraw,
+}
+
+impl Default for GameSetupConfig {
+    fn default() -> Self {
+        Self {
+            player_count: 4,
+            starting_life: 40,
+            commander_damage_threshold: CommanderRules::COMMANDER_DAMAGE_THRESHOLD,
+            house_rules: HouseRules::default(),
+            rules_enforcement: RulesEnforcementLevel::Full,
+            ban_list: None,
+            precons: Vec::new(),
+            seed: None,
+            first_turn_draw: FirstTurnDraw::Rules,
+        }
+    }
+}
+
+impl GameSetupConfig {
+    /// Return a copy with every option clamped to a playable value
+    pub fn validated(&self) -> Self {
+        Self {
+            player_count: self.player_count.clamp(MIN_PLAYERS, MAX_PLAYERS),
+            starting_life: self.starting_life.max(1),
+            commander_damage_threshold: self.commander_damage_threshold.max(1),
+            ..self.clone()
+        }
+    }
+
+    /// The precon picked for a player, if any
+    pub fn precon_for(&self, player_index: usize) -> Option<&str> {
+        self.precons.get(player_index).map(String::as_str)
+    }
+
+    /// Whether the player going first skips the draw of their first turn, in a
+    /// game of `player_count` players
+    ///
+    /// The player count is the game's actual one rather than the option, as
+    /// games like goldfishing start with fewer players than chosen.
+    pub fn first_player_skips_draw(&self, player_count: usize) -> bool {
+        match self.first_turn_draw {
+            FirstTurnDraw::Rules => player_count == 2,
+            FirstTurnDraw::EveryoneDraws => false,
+            FirstTurnDraw::FirstPlayerSkips => true,
+        }
+    }
+
+    /// Copy the player options into the player spawning configuration
+    pub fn apply_to_player_config(&self, player_config: &mut PlayerConfig) {
+        let config = self.validated();
+        player_config.player_count = config.player_count;
+        player_config.starting_life = config.starting_life;
+    }
+
+    /// Copy the rules options into the engine's game state
+    pub fn apply_to_game_state(&self, game_state: &mut GameState) {
+        let config = self.validated();
+        game_state.starting_life = config.starting_life;
+        game_state.use_commander_damage = config.house_rules.commander_damage;
+        game_state.commander_damage_threshold = config.commander_damage_threshold;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_player_count_is_clamped() {
+        let config = GameSetupConfig {
+            player_count: 9,
+            ..default()
+        };
+        assert_eq!(config.validated().player_count, MAX_PLAYERS);
+
+        let config = GameSetupConfig {
+            player_count: 1,
+            ..default()
+        };
+        assert_eq!(config.validated().player_count, MIN_PLAYERS);
+    }
+
+    #[test]
+    fn test_first_player_skips_draw_only_in_two_player_games_by_default() {
+        let config = GameSetupConfig::default();
+        assert!(config.first_player_skips_draw(2));
+        assert!(!config.first_player_skips_draw(4));
+
+        let config = GameSetupConfig {
+            first_turn_draw: FirstTurnDraw::EveryoneDraws,
+            ..default()
+        };
+        assert!(!config.first_player_skips_draw(2));
+
+        let config = GameSetupConfig {
+            first_turn_draw: FirstTurnDraw::FirstPlayerSkips,
+            ..default()
+        };
+        assert!(config.first_player_skips_draw(4));
+    }
+
+    #[test]
+    fn test_rules_are_applied_to_game_state() {
+        let config = GameSetupConfig {
+            starting_life: 30,
+            commander_damage_threshold: 16,
+            house_rules: HouseRules {
+                commander_damage: false,
+                ..default()
+            },
+            ..default()
+        };
+        let mut game_state = GameState::default();
+        config.apply_to_game_state(&mut game_state);
+
+        assert_eq!(game_state.starting_life, 30);
+        assert_eq!(game_state.commander_damage_threshold, 16);
+        assert!(!game_state.use_commander_damage);
+    }
+}
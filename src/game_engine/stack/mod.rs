@@ -2,17 +2,62 @@
 // pub use crate::game_engine::stack::*;
 
 use crate::game_engine::PrioritySystem;
+use crate::game_engine::choices::{
+    ChoiceAnswer, ChoiceId, ChoiceKind, ChoiceRequest, PendingChoices,
+};
 use crate::game_engine::priority::{CounterReason, EffectCounteredEvent, ResolveStackItemEvent};
 use crate::game_engine::state::GameState;
+use crate::tracing::EngineSpans;
 use bevy::prelude::*;
 use std::collections::HashSet;
 use std::fmt::Debug;
 
+mod resolve_all;
+mod spec;
+pub use resolve_all::{
+    ResolveAllEvent, ResolvingAll, register_resolve_all_systems, resolvable_run, resolve_all,
+};
+pub use spec::{EffectSpec, StackItemSpec};
+
+/// What was chosen for a spell or ability as it was put on the stack
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResolutionDetails {
+    /// What's resolving, like a card or ability name
+    pub name: String,
+    /// The modes chosen for a modal spell
+    pub modes: Vec<String>,
+    /// The value chosen for X
+    pub x_value: Option<u64>,
+}
+
+/// A choice the controller makes as a spell or ability resolves
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResolutionChoice {
+    pub prompt: String,
+    pub options: Vec<String>,
+}
+
 /// Trait for effects that can go on the stack
 pub trait Effect: Debug + Send + Sync {
     /// Resolve the effect when it comes off the stack
     fn resolve(&self, commands: &mut Commands);
 
+    /// Resolve the effect with the option its controller chose from
+    /// [`Effect::resolution_choice`]
+    fn resolve_with_choice(&self, commands: &mut Commands, _option: usize) {
+        self.resolve(commands);
+    }
+
+    /// What's shown about the effect while it resolves
+    fn details(&self) -> ResolutionDetails {
+        ResolutionDetails::default()
+    }
+
+    /// A choice its controller has to make before it can resolve, if any
+    fn resolution_choice(&self) -> Option<ResolutionChoice> {
+        None
+    }
+
     /// Get the controller of this effect
     #[allow(dead_code)]
     fn controller(&self) -> Entity;
@@ -20,6 +65,18 @@ pub trait Effect: Debug + Send + Sync {
     /// Get the targets of this effect
     #[allow(dead_code)]
     fn targets(&self) -> Vec<Entity>;
+
+    /// The effect described as data, for saving or sending it
+    ///
+    /// Effects that can't be described only keep their name, controller and
+    /// targets.
+    fn spec(&self) -> EffectSpec {
+        EffectSpec::Opaque {
+            name: self.details().name,
+            controller: self.controller(),
+            targets: self.targets(),
+        }
+    }
 }
 
 /// Event fired when a stack item is resolved
@@ -30,6 +87,62 @@ pub struct StackItemResolvedEvent {
     pub controller: Entity,
 }
 
+/// A stack item resolving, or just resolved
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvingItem {
+    pub item: Entity,
+    pub controller: Entity,
+    pub targets: Vec<Entity>,
+    pub details: ResolutionDetails,
+    /// The choice its controller has to make, if any
+    pub choice: Option<ResolutionChoice>,
+    /// The option chosen, once it has been
+    pub answer: Option<usize>,
+    /// The choice asked of its controller in [`PendingChoices`]
+    pub choice_id: Option<ChoiceId>,
+}
+
+impl ResolvingItem {
+    fn new(item: &StackItem) -> Self {
+        Self {
+            item: item.entity,
+            controller: item.controller,
+            targets: item.targets.clone(),
+            details: item.effect.details(),
+            choice: item.effect.resolution_choice(),
+            answer: None,
+            choice_id: None,
+        }
+    }
+
+    /// Whether resolution is paused for its controller's choice
+    pub fn is_waiting(&self) -> bool {
+        self.choice.is_some() && self.answer.is_none()
+    }
+
+    /// The choice to ask its controller, if it has one
+    fn choice_request(&self) -> Option<ChoiceRequest> {
+        self.choice.as_ref().map(|choice| ChoiceRequest {
+            player: self.controller,
+            source: Some(self.item),
+            prompt: choice.prompt.clone(),
+            kind: ChoiceKind::Option(choice.options.clone()),
+        })
+    }
+}
+
+/// What the stack is resolving, for showing it to the players
+///
+/// Resolution pauses while [`StackResolution::current`] waits for its
+/// controller's choice.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct StackResolution {
+    /// The item waiting for a choice before it resolves
+    pub current: Option<ResolvingItem>,
+    /// The item resolved most recently
+    pub last_resolved: Option<ResolvingItem>,
+}
+
 /// The MTG stack system that manages spells and abilities
 #[derive(Resource, Default)]
 pub struct GameStack {
@@ -54,6 +167,9 @@ pub struct StackItem {
     /// The effect to resolve
     pub effect: Box<dyn Effect>,
 
+    /// The effect described as data
+    pub spec: EffectSpec,
+
     /// The controller of the effect
     pub controller: Entity,
 
@@ -95,9 +211,11 @@ impl GameStack {
     ) {
         let controller = effect.controller();
         let targets = effect.targets();
+        let spec = effect.spec();
 
         let item = StackItem {
             effect,
+            spec,
             controller,
             targets,
             entity,
@@ -120,8 +238,39 @@ impl GameStack {
         info!("Added item to stack. Stack size: {}", self.items.len());
     }
 
+    /// The items on the stack described as data, bottom first
+    pub fn specs(&self) -> Vec<StackItemSpec> {
+        self.items
+            .iter()
+            .map(|item| StackItemSpec {
+                spec: item.spec.clone(),
+                has_split_second: item.has_split_second,
+                can_be_countered: item.can_be_countered,
+            })
+            .collect()
+    }
+
+    /// Put an item described as data on the stack, as the item `entity`
+    pub fn push_spec(&mut self, item: &StackItemSpec, entity: Entity) {
+        self.push(
+            item.spec.to_effect(),
+            entity,
+            item.has_split_second,
+            item.can_be_countered,
+        );
+    }
+
     /// Resolve the top item on the stack
     pub fn resolve_top(&mut self, commands: &mut Commands) -> Option<Entity> {
+        self.resolve_top_with(commands, None)
+    }
+
+    /// Resolve the top item on the stack with the option chosen for it, if any
+    pub fn resolve_top_with(
+        &mut self,
+        commands: &mut Commands,
+        option: Option<usize>,
+    ) -> Option<Entity> {
         if self.items.is_empty() {
             return None;
         }
@@ -144,7 +293,10 @@ impl GameStack {
 
         // Resolve the effect
         info!("Resolving stack item from {:?}", controller);
-        item.effect.resolve(commands);
+        match option {
+            Some(option) => item.effect.resolve_with_choice(commands, option),
+            None => item.effect.resolve(commands),
+        }
 
         // Reset flags
         self.resolving = false;
@@ -197,6 +349,7 @@ impl GameStack {
     pub fn remove_item(&mut self, entity: Entity) -> Option<StackItem> {
         if let Some(index) = self.items.iter().position(|item| item.entity == entity) {
             let item = self.items.remove(index);
+            self.uncounterable_items.remove(&entity);
             self.update_split_second_status();
             Some(item)
         } else {
@@ -205,7 +358,17 @@ impl GameStack {
     }
 }
 
+/// Forget what was resolving in the last game when returning to the main menu
+pub fn clear_stack_resolution(mut resolution: ResMut<StackResolution>) {
+    *resolution = StackResolution::default();
+}
+
 /// System that handles resolving items from the stack
+///
+/// An item whose controller has to make a choice asks it through
+/// [`PendingChoices`] and waits in [`StackResolution`] until it's answered, then
+/// resolves without waiting for another [`ResolveStackItemEvent`].
+#[allow(clippy::too_many_arguments)]
 pub fn stack_resolution_system(
     mut commands: Commands,
     mut stack: ResMut<GameStack>,
@@ -214,50 +377,119 @@ pub fn stack_resolution_system(
     mut stack_resolution_events: EventWriter<StackItemResolvedEvent>,
     mut resolve_events: EventReader<ResolveStackItemEvent>,
     mut counter_events: EventWriter<EffectCounteredEvent>,
+    mut resolution: Option<ResMut<StackResolution>>,
+    mut choices: Option<ResMut<PendingChoices>>,
+    spans: Option<Res<EngineSpans>>,
 ) {
-    if !resolve_events.is_empty() {
-        resolve_events.clear();
+    let waiting = resolution
+        .as_ref()
+        .and_then(|resolution| resolution.current.as_ref())
+        .cloned();
+    let chosen = match waiting {
+        Some(current) => {
+            let answer = match (current.choice_id, choices.as_mut()) {
+                (Some(id), Some(choices)) if choices.is_pending(id) => {
+                    // Nothing else resolves before the choice is made
+                    resolve_events.clear();
+                    return;
+                }
+                (Some(id), Some(choices)) => choices.take_answer(id),
+                _ => None,
+            };
+            if let Some(resolution) = resolution.as_mut() {
+                resolution.current = None;
+            }
+            let option = match answer {
+                Some(ChoiceAnswer::Option(option)) => option,
+                _ => 0,
+            };
+            // The item may have left the stack while its controller chose
+            (stack.items.last().map(|item| item.entity) == Some(current.item)).then_some(option)
+        }
+        None => None,
+    };
 
-        // Check if we have any items on the stack to resolve
-        if stack.is_empty() {
+    if chosen.is_none() {
+        if resolve_events.is_empty() {
             return;
         }
+        resolve_events.clear();
+    }
+    let _span = info_span!(parent: EngineSpans::parent(spans.as_deref()), "stack_resolution", items = stack.items.len()).entered();
 
-        // Validate targets before resolution
-        if !stack.validate_targets() {
-            // If targets are invalid, counter the spell
-            let entity = stack.items.last().unwrap().entity;
-            // Controller not used here, but might be needed for future implementation
-            // let controller = stack.items.last().unwrap().controller;
+    // Check if we have any items on the stack to resolve
+    let Some(top) = stack.items.last() else {
+        return;
+    };
 
-            // Remove the item from the stack
-            stack.remove_item(entity);
+    // Validate targets before resolution
+    if !stack.validate_targets() {
+        // If targets are invalid, counter the spell
+        let entity = top.entity;
 
-            // Emit a counter event with reason
-            counter_events.write(EffectCounteredEvent {
-                item: entity,
-                reason: CounterReason::InvalidTargets,
-            });
+        // Remove the item from the stack
+        stack.remove_item(entity);
 
-            // Get all players (simplified for now, using only active player)
-            let players = vec![game_state.active_player];
+        // Emit a counter event with reason
+        counter_events.write(EffectCounteredEvent {
+            item: entity,
+            reason: CounterReason::InvalidTargets,
+        });
 
-            // Reset priority after stack action
-            priority.reset_after_stack_action(&players, game_state.active_player);
+        // Get all players (simplified for now, using only active player)
+        let players = vec![game_state.active_player];
 
-            return;
-        }
+        // Reset priority after stack action
+        priority.reset_after_stack_action(&players, game_state.active_player);
 
-        // Resolve the top item
-        if let Some(controller) = stack.resolve_top(&mut commands) {
-            // Send an event so other systems know this stack item resolved
-            stack_resolution_events.write(StackItemResolvedEvent { controller });
+        return;
+    }
 
-            // Get all players (simplified for now, using only active player)
-            let players = vec![game_state.active_player];
+    let mut resolving = ResolvingItem::new(top);
+    resolving.answer = chosen;
+    if let Some(request) = resolving
+        .choice_request()
+        .filter(|_| resolving.is_waiting())
+    {
+        match (resolution.as_mut(), choices.as_mut()) {
+            (Some(resolution), Some(choices)) => {
+                info!(
+                    "Waiting for {:?} to choose before {:?} resolves",
+                    resolving.controller, resolving.item
+                );
+                resolving.choice_id = Some(choices.push(request));
+                resolution.current = Some(resolving);
+                return;
+            }
+            // Without anyone to ask, the first option is taken
+            _ => resolving.answer = Some(0),
+        }
+    }
 
-            // Reset priority after stack action
-            priority.reset_after_stack_action(&players, game_state.active_player);
+    // Resolve the top item
+    if let Some(controller) = stack.resolve_top_with(&mut commands, resolving.answer) {
+        // Send an event so other systems know this stack item resolved
+        stack_resolution_events.write(StackItemResolvedEvent { controller });
+        if let Some(resolution) = resolution.as_mut() {
+            resolution.last_resolved = Some(resolving);
         }
+
+        // Every player gets priority again, starting with the active player
+        let players = priority_order(&game_state);
+
+        // Reset priority after stack action
+        priority.reset_after_stack_action(&players, game_state.active_player);
+    }
+}
+
+/// The players in turn order, or only the active player if there's no turn order
+fn priority_order(game_state: &GameState) -> Vec<Entity> {
+    if game_state.turn_order.is_empty() {
+        vec![game_state.active_player]
+    } else {
+        game_state.turn_order.iter().copied().collect()
     }
 }
+
+#[cfg(test)]
+mod tests;
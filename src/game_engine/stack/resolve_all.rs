@@ -0,0 +1,129 @@
+//! Resolving a run of a player's own stack items in one go
+//!
+//! Long chains of a player's own triggers, like a pile of upkeep triggers, would
+//! otherwise take a click each. A [`ResolveAllEvent`] from the player holding
+//! priority passes priority for them whenever it comes back to them, so the items
+//! on top of the stack they control resolve one at a time. Every other player
+//! still gets priority before each item resolves, which only happens once all
+//! of them have passed in succession (rule 117.4). It stops as soon as:
+//!
+//! - an item needs a choice, or any other choice is waiting to be made, or
+//! - the item on top isn't one of the player's, like when another player
+//!   responds instead of passing.
+
+use bevy::prelude::*;
+
+use super::{GameStack, StackResolution, stack_resolution_system};
+use crate::game_engine::choices::{PendingChoices, no_pending_choice};
+use crate::game_engine::priority::{PassPriorityEvent, PrioritySystem, ResolveStackItemEvent};
+use crate::game_engine::schedule::GameEngineSet;
+use crate::game_engine::triggers::TriggerQueue;
+use crate::menu::state::GameMenuState;
+
+/// Sent by the player holding priority to resolve the run of their own items on
+/// top of the stack
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolveAllEvent {
+    pub player: Entity,
+}
+
+/// The run of a player's items being resolved in one go
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResolvingAll {
+    /// The player whose items are resolving, while they are
+    pub player: Option<Entity>,
+    /// Items resolved so far
+    pub resolved: usize,
+}
+
+/// How many items on top of the stack `player` could resolve in one go: those
+/// they control, down to the first one needing a choice
+pub fn resolvable_run(stack: &GameStack, player: Entity) -> usize {
+    stack
+        .items
+        .iter()
+        .rev()
+        .take_while(|item| item.controller == player && item.effect.resolution_choice().is_none())
+        .count()
+}
+
+/// Start resolving a player's items when they ask, then pass priority for them
+/// and resolve the top item once everyone has passed, until a safety stop is
+/// reached
+#[allow(clippy::too_many_arguments)]
+pub fn resolve_all(
+    mut requests: EventReader<ResolveAllEvent>,
+    mut resolving: ResMut<ResolvingAll>,
+    stack: Res<GameStack>,
+    priority: Res<PrioritySystem>,
+    resolution: Option<Res<StackResolution>>,
+    choices: Option<Res<PendingChoices>>,
+    queue: Option<Res<TriggerQueue>>,
+    mut passes: EventWriter<PassPriorityEvent>,
+    mut resolve_events: EventWriter<ResolveStackItemEvent>,
+) {
+    for request in requests.read() {
+        if priority.has_priority(request.player) && resolvable_run(&stack, request.player) > 0 {
+            *resolving = ResolvingAll {
+                player: Some(request.player),
+                resolved: 0,
+            };
+        } else {
+            info!(
+                "{:?} can't resolve all without priority and their own item on top",
+                request.player
+            );
+        }
+    }
+    let Some(player) = resolving.player else {
+        return;
+    };
+
+    let choice_waiting = choices.is_some_and(|choices| choices.is_waiting())
+        || resolution.is_some_and(|resolution| resolution.current.is_some())
+        || queue.is_some_and(|queue| queue.choice.is_some());
+    let stop = if choice_waiting {
+        Some("a choice has to be made")
+    } else if resolvable_run(&stack, player) == 0 {
+        Some("their items have resolved")
+    } else {
+        None
+    };
+    if let Some(reason) = stop {
+        info!(
+            "Stopped resolving all for {:?} after {} items: {}",
+            player, resolving.resolved, reason
+        );
+        *resolving = ResolvingAll::default();
+        return;
+    }
+
+    if priority.priority_round_complete() {
+        if let Some(top) = stack.items.last() {
+            resolve_events.write(ResolveStackItemEvent { item: top.entity });
+            resolving.resolved += 1;
+        }
+    } else if priority.has_priority(player) {
+        passes.write(PassPriorityEvent { player });
+    }
+    // Otherwise another player is deciding whether to pass
+}
+
+/// Stop resolving all when returning to the main menu
+pub fn clear_resolving_all(mut resolving: ResMut<ResolvingAll>) {
+    *resolving = ResolvingAll::default();
+}
+
+/// Register resolving a player's items in one go
+pub fn register_resolve_all_systems(app: &mut App) {
+    app.add_event::<ResolveAllEvent>()
+        .init_resource::<ResolvingAll>()
+        .add_systems(
+            FixedUpdate,
+            resolve_all
+                .before(stack_resolution_system)
+                .in_set(GameEngineSet::Stack)
+                .run_if(in_state(GameMenuState::InGame).and(no_pending_choice)),
+        )
+        .add_systems(OnEnter(GameMenuState::MainMenu), clear_resolving_all);
+}
@@ -0,0 +1,161 @@
+//! Stack items described as data
+//!
+//! Effects on the stack are trait objects, which can't be saved or sent to
+//! other players. Each [`StackItem`](super::StackItem) also keeps an
+//! [`EffectSpec`] describing its effect, which can be turned back into an
+//! effect with [`EffectSpec::to_effect`].
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::scripting::EffectPrimitive;
+use crate::game_engine::scripting::ScriptedEffect;
+use crate::game_engine::triggers::{PendingTrigger, TriggeredAbilityEffect};
+
+use super::{Effect, ResolutionDetails};
+
+/// A serializable description of an effect on the stack
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EffectSpec {
+    /// A spell or activated ability made of scripted primitives
+    Scripted {
+        name: String,
+        source: Entity,
+        controller: Entity,
+        targets: Vec<Entity>,
+        effects: Vec<EffectPrimitive>,
+    },
+    /// A triggered ability
+    Triggered(PendingTrigger),
+    /// An effect written in Rust
+    ///
+    /// Only what's shown about it is kept, so it does nothing once restored.
+    Opaque {
+        name: String,
+        controller: Entity,
+        targets: Vec<Entity>,
+    },
+}
+
+impl EffectSpec {
+    /// What's shown for the effect, like a card name or an ability's text
+    pub fn name(&self) -> &str {
+        match self {
+            EffectSpec::Scripted { name, .. } | EffectSpec::Opaque { name, .. } => name,
+            EffectSpec::Triggered(trigger) => &trigger.description,
+        }
+    }
+
+    /// The player controlling the effect
+    pub fn controller(&self) -> Entity {
+        match self {
+            EffectSpec::Scripted { controller, .. } | EffectSpec::Opaque { controller, .. } => {
+                *controller
+            }
+            EffectSpec::Triggered(trigger) => trigger.controller,
+        }
+    }
+
+    /// The same effect with each entity it refers to replaced by `map`
+    pub fn map_entities(&self, mut map: impl FnMut(Entity) -> Entity) -> Self {
+        match self {
+            EffectSpec::Scripted {
+                name,
+                source,
+                controller,
+                targets,
+                effects,
+            } => EffectSpec::Scripted {
+                name: name.clone(),
+                source: map(*source),
+                controller: map(*controller),
+                targets: targets.iter().map(|&target| map(target)).collect(),
+                effects: effects.clone(),
+            },
+            EffectSpec::Triggered(trigger) => EffectSpec::Triggered(PendingTrigger {
+                source: map(trigger.source),
+                controller: map(trigger.controller),
+                ..trigger.clone()
+            }),
+            EffectSpec::Opaque {
+                name,
+                controller,
+                targets,
+            } => EffectSpec::Opaque {
+                name: name.clone(),
+                controller: map(*controller),
+                targets: targets.iter().map(|&target| map(target)).collect(),
+            },
+        }
+    }
+
+    /// Rebuild the effect it describes
+    pub fn to_effect(&self) -> Box<dyn Effect> {
+        match self.clone() {
+            EffectSpec::Scripted {
+                name,
+                source,
+                controller,
+                targets,
+                effects,
+            } => Box::new(ScriptedEffect {
+                name,
+                source,
+                controller,
+                targets,
+                effects,
+            }),
+            EffectSpec::Triggered(trigger) => Box::new(TriggeredAbilityEffect(trigger)),
+            EffectSpec::Opaque {
+                name,
+                controller,
+                targets,
+            } => Box::new(RestoredEffect {
+                name,
+                controller,
+                targets,
+            }),
+        }
+    }
+}
+
+/// An effect restored from an [`EffectSpec::Opaque`]
+#[derive(Debug)]
+struct RestoredEffect {
+    name: String,
+    controller: Entity,
+    targets: Vec<Entity>,
+}
+
+impl Effect for RestoredEffect {
+    fn resolve(&self, _commands: &mut Commands) {
+        warn!(
+            "{} was restored without its effect, so it does nothing",
+            self.name
+        );
+    }
+
+    fn details(&self) -> ResolutionDetails {
+        ResolutionDetails {
+            name: self.name.clone(),
+            ..default()
+        }
+    }
+
+    fn controller(&self) -> Entity {
+        self.controller
+    }
+
+    fn targets(&self) -> Vec<Entity> {
+        self.targets.clone()
+    }
+}
+
+/// A stack item described as data, for saving or sending it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StackItemSpec {
+    pub spec: EffectSpec,
+    pub has_split_second: bool,
+    pub can_be_countered: bool,
+}
@@ -0,0 +1,398 @@
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+use crate::cards::scripting::EffectPrimitive;
+
+use crate::game_engine::PrioritySystem;
+use crate::game_engine::choices::{
+    AnswerChoiceEvent, CHOICE_TIMEOUT_SECS, ChoiceAnswer, ChoiceKind, PendingChoices,
+    handle_choice_answers,
+};
+use crate::game_engine::priority::{
+    EffectCounteredEvent, PassPriorityEvent, ResolveStackItemEvent, priority_passing_system,
+};
+use crate::game_engine::scripting::ScriptedEffect;
+use crate::game_engine::stack::{
+    Effect, EffectSpec, GameStack, ResolutionChoice, ResolutionDetails, ResolveAllEvent,
+    ResolvingAll, StackItemResolvedEvent, StackResolution, resolvable_run, resolve_all,
+    stack_resolution_system,
+};
+use crate::game_engine::state::GameState;
+
+/// The option an effect resolved with, `None` if it had no choice
+#[derive(Component, Debug, PartialEq, Eq)]
+struct ResolvedWith(Option<usize>);
+
+/// A Fireball-like effect, optionally with a choice to make as it resolves
+#[derive(Debug)]
+struct TestEffect {
+    controller: Entity,
+    choice: bool,
+}
+
+impl Effect for TestEffect {
+    fn resolve(&self, commands: &mut Commands) {
+        commands.spawn(ResolvedWith(None));
+    }
+
+    fn resolve_with_choice(&self, commands: &mut Commands, option: usize) {
+        commands.spawn(ResolvedWith(Some(option)));
+    }
+
+    fn details(&self) -> ResolutionDetails {
+        ResolutionDetails {
+            name: "Fireball".to_string(),
+            modes: Vec::new(),
+            x_value: Some(3),
+        }
+    }
+
+    fn resolution_choice(&self) -> Option<ResolutionChoice> {
+        self.choice.then(|| ResolutionChoice {
+            prompt: "Choose one".to_string(),
+            options: vec!["Damage".to_string(), "Life".to_string()],
+        })
+    }
+
+    fn controller(&self) -> Entity {
+        self.controller
+    }
+
+    fn targets(&self) -> Vec<Entity> {
+        Vec::new()
+    }
+}
+
+fn stack_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<ResolveStackItemEvent>()
+        .add_event::<StackItemResolvedEvent>()
+        .add_event::<EffectCounteredEvent>()
+        .add_event::<AnswerChoiceEvent>()
+        .init_resource::<GameStack>()
+        .init_resource::<StackResolution>()
+        .init_resource::<PendingChoices>()
+        .init_resource::<PrioritySystem>()
+        .init_resource::<GameState>()
+        .add_systems(
+            Update,
+            (handle_choice_answers, stack_resolution_system).chain(),
+        );
+    let alice = app.world_mut().spawn_empty().id();
+    (app, alice)
+}
+
+fn push(app: &mut App, controller: Entity, choice: bool) -> Entity {
+    let item = app.world_mut().spawn_empty().id();
+    app.world_mut().resource_mut::<GameStack>().push(
+        Box::new(TestEffect { controller, choice }),
+        item,
+        false,
+        true,
+    );
+    item
+}
+
+fn resolve(app: &mut App, item: Entity) {
+    app.world_mut().send_event(ResolveStackItemEvent { item });
+    app.update();
+}
+
+fn resolved_with(app: &mut App) -> Vec<Option<usize>> {
+    app.world_mut()
+        .query::<&ResolvedWith>()
+        .iter(app.world())
+        .map(|resolved| resolved.0)
+        .collect()
+}
+
+#[test]
+fn test_resolution_waits_for_the_controllers_choice() {
+    let (mut app, alice) = stack_app();
+    let bob = app.world_mut().spawn_empty().id();
+    let item = push(&mut app, alice, true);
+
+    resolve(&mut app, item);
+    let current = app
+        .world()
+        .resource::<StackResolution>()
+        .current
+        .clone()
+        .unwrap();
+    assert_eq!(current.item, item);
+    assert_eq!(current.details.x_value, Some(3));
+    assert!(current.is_waiting());
+    let id = current.choice_id.unwrap();
+    let asked = app.world().resource::<PendingChoices>().current().cloned();
+    let asked = asked.unwrap();
+    assert_eq!(asked.id, id);
+    assert_eq!(asked.request.player, alice);
+    assert_eq!(
+        asked.request.kind,
+        ChoiceKind::Option(vec!["Damage".to_string(), "Life".to_string()])
+    );
+
+    // Nothing resolves until Alice makes a valid choice
+    resolve(&mut app, item);
+    for (player, option) in [(bob, 1), (alice, 2)] {
+        app.world_mut().send_event(AnswerChoiceEvent {
+            player,
+            id,
+            answer: ChoiceAnswer::Option(option),
+        });
+        app.update();
+    }
+    assert_eq!(app.world().resource::<GameStack>().len(), 1);
+    assert!(resolved_with(&mut app).is_empty());
+
+    app.world_mut().send_event(AnswerChoiceEvent {
+        player: alice,
+        id,
+        answer: ChoiceAnswer::Option(1),
+    });
+    app.update();
+    assert!(app.world().resource::<GameStack>().is_empty());
+    assert_eq!(resolved_with(&mut app), vec![Some(1)]);
+    let resolution = app.world().resource::<StackResolution>();
+    assert!(resolution.current.is_none());
+    let resolved = resolution.last_resolved.as_ref().unwrap();
+    assert_eq!(resolved.details.name, "Fireball");
+    assert_eq!(resolved.answer, Some(1));
+    assert!(!app.world().resource::<PendingChoices>().is_pending(id));
+}
+
+#[test]
+fn test_items_without_choices_resolve_straight_away() {
+    let (mut app, alice) = stack_app();
+    let item = push(&mut app, alice, false);
+
+    resolve(&mut app, item);
+    assert_eq!(resolved_with(&mut app), vec![None]);
+    let resolution = app.world().resource::<StackResolution>();
+    assert!(resolution.current.is_none());
+    assert_eq!(resolution.last_resolved.as_ref().unwrap().item, item);
+}
+
+#[test]
+fn test_unanswered_choices_take_the_first_option() {
+    let (mut app, alice) = stack_app();
+    let item = push(&mut app, alice, true);
+
+    resolve(&mut app, item);
+    app.world_mut()
+        .resource_mut::<PendingChoices>()
+        .waiting
+        .front_mut()
+        .unwrap()
+        .waited = CHOICE_TIMEOUT_SECS;
+    app.update();
+    assert_eq!(resolved_with(&mut app), vec![Some(0)]);
+}
+
+#[test]
+fn test_stack_items_are_rebuilt_from_their_specs() {
+    let (mut app, alice) = stack_app();
+    let source = app.world_mut().spawn_empty().id();
+    let spell = app.world_mut().spawn_empty().id();
+    app.world_mut().resource_mut::<GameStack>().push(
+        Box::new(ScriptedEffect {
+            name: "Fresh Bread".to_string(),
+            source,
+            controller: alice,
+            targets: Vec::new(),
+            effects: vec![EffectPrimitive::GainLife { amount: 2 }],
+        }),
+        spell,
+        false,
+        true,
+    );
+    push(&mut app, alice, true);
+
+    let specs = app.world().resource::<GameStack>().specs();
+    assert_eq!(specs[0].spec.name(), "Fresh Bread");
+    assert_eq!(
+        specs[1].spec,
+        EffectSpec::Opaque {
+            name: "Fireball".to_string(),
+            controller: alice,
+            targets: Vec::new(),
+        }
+    );
+
+    let json = serde_json::to_string(&specs).unwrap();
+    let sent: Vec<_> = serde_json::from_str(&json).unwrap();
+    assert_eq!(specs, sent);
+
+    let mut restored = GameStack::default();
+    for item in &sent {
+        restored.push_spec(item, app.world_mut().spawn_empty().id());
+    }
+    assert_eq!(restored.specs(), specs);
+    assert_eq!(restored.items[0].effect.details().name, "Fresh Bread");
+    assert_eq!(restored.items[1].controller, alice);
+}
+
+/// The player who passes whenever they get priority
+#[derive(Resource)]
+struct Passes(Entity);
+
+fn pass_when_holding_priority(
+    passes: Option<Res<Passes>>,
+    priority: Res<PrioritySystem>,
+    mut events: EventWriter<PassPriorityEvent>,
+) {
+    if let Some(passes) = passes.filter(|passes| priority.has_priority(passes.0)) {
+        events.write(PassPriorityEvent { player: passes.0 });
+    }
+}
+
+/// A stack app resolving all on request, with Alice holding priority in her own
+/// turn or after Bob passed in his
+fn resolve_all_app(alices_turn: bool) -> (App, Entity, Entity) {
+    let (mut app, alice) = stack_app();
+    let bob = app.world_mut().spawn_empty().id();
+    let active = if alices_turn { alice } else { bob };
+    let mut priority = PrioritySystem::default();
+    priority.initialize(&[alice, bob], active);
+    if !alices_turn {
+        priority.pass_priority();
+    }
+    app.add_event::<ResolveAllEvent>()
+        .add_event::<PassPriorityEvent>()
+        .init_resource::<ResolvingAll>()
+        .insert_resource(
+            GameState::builder()
+                .active_player(active)
+                .turn_order(VecDeque::from([alice, bob]))
+                .build(),
+        )
+        .insert_resource(priority)
+        .add_systems(
+            Update,
+            (
+                priority_passing_system,
+                pass_when_holding_priority,
+                resolve_all,
+            )
+                .chain()
+                .before(stack_resolution_system),
+        );
+    (app, alice, bob)
+}
+
+fn resolve_all_for(app: &mut App, player: Entity) {
+    app.world_mut().send_event(ResolveAllEvent { player });
+    for _ in 0..20 {
+        app.update();
+    }
+}
+
+#[test]
+fn test_resolve_all_stops_at_an_opponents_item() {
+    let (mut app, alice, bob) = resolve_all_app(true);
+    app.insert_resource(Passes(bob));
+    let bobs = push(&mut app, bob, false);
+    for _ in 0..3 {
+        push(&mut app, alice, false);
+    }
+    assert_eq!(
+        resolvable_run(app.world().resource::<GameStack>(), alice),
+        3
+    );
+    assert_eq!(resolvable_run(app.world().resource::<GameStack>(), bob), 0);
+
+    resolve_all_for(&mut app, alice);
+    assert_eq!(resolved_with(&mut app), vec![None; 3]);
+    let stack = app.world().resource::<GameStack>();
+    assert_eq!(stack.items.len(), 1);
+    assert_eq!(stack.items[0].entity, bobs);
+    assert_eq!(
+        *app.world().resource::<ResolvingAll>(),
+        ResolvingAll::default()
+    );
+}
+
+#[test]
+fn test_resolve_all_stops_at_a_choice() {
+    let (mut app, alice, bob) = resolve_all_app(true);
+    app.insert_resource(Passes(bob));
+    let fireball = push(&mut app, alice, true);
+    push(&mut app, alice, false);
+    push(&mut app, alice, false);
+    assert_eq!(
+        resolvable_run(app.world().resource::<GameStack>(), alice),
+        2
+    );
+
+    resolve_all_for(&mut app, alice);
+    assert_eq!(resolved_with(&mut app), vec![None; 2]);
+    // The item with a choice is left for its controller to resolve
+    let stack = app.world().resource::<GameStack>();
+    assert_eq!(stack.items.len(), 1);
+    assert_eq!(stack.items[0].entity, fireball);
+    assert!(!app.world().resource::<PendingChoices>().is_waiting());
+}
+
+#[test]
+fn test_resolve_all_waits_for_opponents_to_pass() {
+    let (mut app, alice, bob) = resolve_all_app(true);
+    for _ in 0..3 {
+        push(&mut app, alice, false);
+    }
+
+    // Nothing resolves while Bob holds priority without passing
+    resolve_all_for(&mut app, alice);
+    assert!(resolved_with(&mut app).is_empty());
+    assert!(app.world().resource::<PrioritySystem>().has_priority(bob));
+
+    app.world_mut()
+        .send_event(PassPriorityEvent { player: bob });
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(resolved_with(&mut app), vec![None]);
+    assert!(app.world().resource::<PrioritySystem>().has_priority(bob));
+
+    // Bob responds instead of passing
+    push(&mut app, bob, false);
+    app.update();
+    assert_eq!(resolved_with(&mut app), vec![None]);
+    assert_eq!(app.world().resource::<GameStack>().items.len(), 3);
+    assert_eq!(
+        *app.world().resource::<ResolvingAll>(),
+        ResolvingAll::default()
+    );
+}
+
+#[test]
+fn test_resolve_all_in_an_opponents_turn() {
+    // In Bob's turn he gets priority first after each item resolves
+    let (mut app, alice, bob) = resolve_all_app(false);
+    app.insert_resource(Passes(bob));
+    for _ in 0..3 {
+        push(&mut app, alice, false);
+    }
+
+    resolve_all_for(&mut app, alice);
+    assert_eq!(resolved_with(&mut app), vec![None; 3]);
+    assert!(app.world().resource::<GameStack>().is_empty());
+    assert_eq!(
+        *app.world().resource::<ResolvingAll>(),
+        ResolvingAll::default()
+    );
+}
+
+#[test]
+fn test_resolve_all_needs_priority() {
+    let (mut app, alice, bob) = resolve_all_app(true);
+    push(&mut app, bob, false);
+
+    resolve_all_for(&mut app, bob);
+    assert!(resolved_with(&mut app).is_empty());
+    assert_eq!(app.world().resource::<GameStack>().items.len(), 1);
+
+    // Nor can a player resolve their opponent's items
+    resolve_all_for(&mut app, alice);
+    assert!(resolved_with(&mut app).is_empty());
+}
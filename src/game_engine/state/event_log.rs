@@ -0,0 +1,95 @@
+use super::GameState;
+use super::events::GameEvent;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A [`GameEvent`] and its place in the log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoggedGameEvent {
+    /// Position in the log, counting every event since the app started
+    pub sequence: u64,
+    pub event: GameEvent,
+}
+
+/// Every [`GameEvent`] applied to the [`GameState`] since its baseline
+///
+/// Replaying the events over the baseline rebuilds the current state, which
+/// makes the log usable for replays, for sending peers only what they missed,
+/// and for finding out how the state got where it is.
+#[derive(Resource, Debug, Default)]
+pub struct GameEventLog {
+    /// The state the first event was applied to
+    baseline: GameState,
+    /// The state after the last event
+    head: GameState,
+    events: Vec<LoggedGameEvent>,
+    next_sequence: u64,
+}
+
+impl GameEventLog {
+    /// Start a new log from `state`
+    ///
+    /// Used when the game state is replaced as a whole, e.g. by starting a new
+    /// game or loading a save. Sequence numbers keep counting up.
+    pub fn rebase(&mut self, state: &GameState) {
+        self.baseline = state.clone();
+        self.head = state.clone();
+        self.events.clear();
+    }
+
+    /// Whether `state` is what the logged events produce
+    pub fn is_in_sync(&self, state: &GameState) -> bool {
+        self.head == *state
+    }
+
+    /// Record an event that has been applied to the game state
+    pub fn record(&mut self, event: GameEvent) -> u64 {
+        self.head.apply(&event);
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        self.events.push(LoggedGameEvent { sequence, event });
+        sequence
+    }
+
+    /// The state the logged events start from
+    pub fn baseline(&self) -> &GameState {
+        &self.baseline
+    }
+
+    /// The logged events, oldest first
+    pub fn events(&self) -> &[LoggedGameEvent] {
+        &self.events
+    }
+
+    /// Sequence number of the next event to be recorded
+    pub fn next_sequence(&self) -> u64 {
+        self.next_sequence
+    }
+
+    /// Events recorded from `sequence` onwards
+    ///
+    /// Returns `None` if some of them were dropped by a rebase, in which case the
+    /// whole baseline has to be sent instead.
+    pub fn since(&self, sequence: u64) -> Option<&[LoggedGameEvent]> {
+        let first = self.next_sequence - self.events.len() as u64;
+        if sequence < first {
+            return None;
+        }
+        let skip = (sequence - first) as usize;
+        Some(self.events.get(skip..).unwrap_or(&[]))
+    }
+
+    /// Rebuild the game state from the baseline
+    pub fn replay(&self) -> GameState {
+        self.replay_to(self.next_sequence)
+    }
+
+    /// Rebuild the game state as it was before event `sequence` was applied
+    pub fn replay_to(&self, sequence: u64) -> GameState {
+        let mut state = self.baseline.clone();
+        for logged in self.events.iter().take_while(|e| e.sequence < sequence) {
+            state.apply(&logged.event);
+        }
+        state
+    }
+}
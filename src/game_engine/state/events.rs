@@ -0,0 +1,56 @@
+use crate::game_engine::commander::EliminationReason;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A change to the [`GameState`](super::GameState)
+///
+/// Systems never mutate the game state directly: they send a `GameEvent`, and
+/// [`apply_game_events`](super::apply_game_events) applies it and records it in
+/// the [`GameEventLog`](super::GameEventLog).
+#[derive(Event, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameEvent {
+    /// The players take turns in this order, starting with the first
+    TurnOrderSet { players: Vec<Entity> },
+    /// Players rolled for who goes first, in the order they rolled, and
+    /// `winner` rolled highest
+    FirstPlayerRolled {
+        rolls: Vec<(Entity, u32)>,
+        winner: Entity,
+    },
+    /// A player's turn began, clearing the per-turn tracking
+    TurnStarted { player: Entity, turn_number: u32 },
+    /// A main phase began, allowing another "once per main phase" action
+    MainPhaseStarted,
+    /// A player played a land
+    LandPlayed { player: Entity },
+    /// A player drew a card from their library
+    CardDrawn { player: Entity },
+    /// A player lost the game
+    PlayerEliminated {
+        player: Entity,
+        reason: EliminationReason,
+    },
+    /// A player won the game through a card's effect, eliminating everyone else
+    PlayerWon { player: Entity, source: Entity },
+    /// A state-based action check finished
+    StateBasedActionsChecked { performed: bool },
+}
+
+/// Why a game ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOverReason {
+    /// The last opponent was eliminated for this reason
+    Elimination(EliminationReason),
+    /// The winner won through this card's effect
+    CardEffect(Entity),
+}
+
+/// The game ended, sent once when the last opponent is eliminated or a player wins
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct GameOverEvent {
+    /// The last player standing, if any
+    pub winner: Option<Entity>,
+    /// Eliminated players, the first to be eliminated first
+    pub elimination_order: Vec<Entity>,
+    pub reason: GameOverReason,
+}
@@ -3,17 +3,32 @@
 
 use crate::cards::Card;
 use crate::cards::details::CreatureOnField;
-use crate::game_engine::commander::{Commander, EliminationReason, PlayerEliminatedEvent};
-use crate::game_engine::zones::{Zone, ZoneChangeEvent, ZoneManager};
+use crate::game_engine::commander::{EliminationReason, PlayerEliminatedEvent};
+use crate::game_engine::win_conditions::{GameOutcome, WinConditionState};
+use crate::game_engine::zones::{AttemptedDrawFromEmpty, Zone, ZoneChangeEvent, ZoneManager};
 use crate::player::Player;
+use crate::tracing::EngineSpans;
 use bevy::prelude::*;
 use std::collections::VecDeque;
 
+mod event_log;
+mod events;
+mod reducer;
+
+#[cfg(test)]
+mod tests;
+
+pub use event_log::{GameEventLog, LoggedGameEvent};
+pub use events::{GameEvent, GameOverEvent, GameOverReason};
+pub use reducer::apply_game_events;
+
 // Re-export save module's event
 pub use crate::game_engine::save::CheckStateBasedActionsEvent;
 
 /// The global game state for an MTG game
-#[derive(Resource)]
+///
+/// Changed only by applying [`GameEvent`]s, see [`apply_game_events`].
+#[derive(Resource, Debug, Clone, PartialEq)]
 pub struct GameState {
     /// The current turn number
     #[allow(dead_code)]
@@ -154,7 +169,10 @@ impl GameState {
 
     /// Check if the game is over
     pub fn is_game_over(&self) -> bool {
-        self.turn_order.len() - self.eliminated_players.len() <= 1
+        self.turn_order
+            .len()
+            .saturating_sub(self.eliminated_players.len())
+            <= 1
     }
 
     /// Get the player index in the turn order
@@ -177,7 +195,6 @@ impl GameState {
     }
 
     /// Record that a player has drawn a card this turn
-    #[allow(dead_code)]
     pub fn record_draw(&mut self, player: Entity) {
         if !self.drawn_this_turn.contains(&player) {
             self.drawn_this_turn.push(player);
@@ -350,36 +367,46 @@ impl GameStateBuilder {
 }
 
 /// System that checks for state-based actions
+///
+/// Players win or lose according to the [`WinConditionRules`], and eliminations,
+/// wins and the result of the check are sent as [`GameEvent`]s.
+///
+/// [`WinConditionRules`]: crate::game_engine::win_conditions::WinConditionRules
 pub fn state_based_actions_system(
     mut commands: Commands,
-    mut game_state: ResMut<GameState>,
+    game_state: Res<GameState>,
     zone_manager: ResMut<ZoneManager>,
-    player_query: Query<(Entity, &Player)>,
     creature_query: Query<(Entity, &CreatureOnField, Option<&Card>)>,
-    commander_query: Query<(Entity, &Commander)>,
+    empty_draw_query: Query<Entity, (With<Player>, With<AttemptedDrawFromEmpty>)>,
+    mut win_conditions: WinConditionState,
+    spans: Option<Res<EngineSpans>>,
 ) {
-    // Reset the state-based actions performed flag
-    game_state.state_based_actions_performed = false;
-
-    // 1. Check for players at 0 or less life
-    for (player_entity, player) in player_query.iter() {
-        if player.life <= 0 && !game_state.eliminated_players.contains(&player_entity) {
-            info!(
-                "Player {:?} eliminated due to 0 or less life",
-                player_entity
-            );
-            game_state.eliminate_player(player_entity, EliminationReason::LifeLoss);
-            game_state.state_based_actions_performed = true;
-
-            commands.send_event(PlayerEliminatedEvent {
-                player: player_entity,
-                reason: EliminationReason::LifeLoss,
-            });
+    let _span =
+        info_span!(parent: EngineSpans::parent(spans.as_deref()), "state_based_actions").entered();
+    let mut performed = false;
+
+    // 1. Check who wins or loses the game
+    for outcome in win_conditions.outcomes(&game_state) {
+        match outcome {
+            GameOutcome::Loses { player, reason } => {
+                info!("Player {:?} eliminated: {:?}", player, reason);
+                commands.send_event(GameEvent::PlayerEliminated { player, reason });
+                commands.send_event(PlayerEliminatedEvent { player, reason });
+            }
+            GameOutcome::Wins { player, source } => {
+                info!("Player {:?} wins the game through {:?}", player, source);
+                commands.send_event(GameEvent::PlayerWon { player, source });
+            }
         }
+        performed = true;
     }
 
-    // 2. Check for players who have attempted to draw from an empty library
-    // This would be handled by a separate drawing system that triggers elimination
+    // 2. Attempts to draw from an empty library only count for one check
+    for player_entity in empty_draw_query.iter() {
+        commands
+            .entity(player_entity)
+            .remove::<AttemptedDrawFromEmpty>();
+    }
 
     // 3. Check for creature state-based actions
     for (creature_entity, creature_field, _card) in creature_query.iter() {
@@ -402,7 +429,7 @@ pub fn state_based_actions_system(
                     is_visible: true,
                 });
 
-                game_state.state_based_actions_performed = true;
+                performed = true;
             }
         }
 
@@ -424,62 +451,37 @@ pub fn state_based_actions_system(
                     is_visible: true,
                 });
 
-                game_state.state_based_actions_performed = true;
+                performed = true;
             }
         }
     }
 
-    // 4. Check for commander damage threshold
-    if game_state.use_commander_damage {
-        for (commander_entity, commander) in commander_query.iter() {
-            for (player_entity, _) in player_query.iter() {
-                // Skip if the player is the controller of this commander
-                if player_entity == commander.owner {
-                    continue;
-                }
-
-                // Get total commander damage from this commander
-                let commander_damage = commander
-                    .damage_dealt
-                    .iter()
-                    .find_map(|(target, damage)| {
-                        if *target == player_entity {
-                            Some(*damage)
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or(0);
-
-                // Check if it exceeds the threshold
-                if commander_damage >= game_state.commander_damage_threshold
-                    && !game_state.eliminated_players.contains(&player_entity)
-                {
-                    info!(
-                        "Player {:?} eliminated due to commander damage from {:?}",
-                        player_entity, commander_entity
-                    );
-                    game_state.eliminate_player(
-                        player_entity,
-                        EliminationReason::CommanderDamage(commander_entity),
-                    );
-                    game_state.state_based_actions_performed = true;
-
-                    commands.send_event(PlayerEliminatedEvent {
-                        player: player_entity,
-                        reason: EliminationReason::CommanderDamage(commander_entity),
-                    });
-                }
-            }
-        }
+    // 4. Record the result, only when it changes so idle checks don't fill the log
+    if performed != game_state.state_based_actions_performed {
+        commands.send_event(GameEvent::StateBasedActionsChecked { performed });
     }
+}
 
-    // 5. Check if the game is over
-    if game_state.is_game_over() {
-        if let Some(winner) = game_state.get_winner() {
-            info!("Game over! Player {:?} wins!", winner);
-            // Additional handling for game over could go here
+/// System that turns concessions into eliminations
+///
+/// Other eliminations are found by the state-based action check, which sends
+/// their [`GameEvent`]s itself.
+pub fn concession_system(
+    mut eliminated: EventReader<PlayerEliminatedEvent>,
+    game_state: Res<GameState>,
+    mut events: EventWriter<GameEvent>,
+) {
+    for event in eliminated.read() {
+        if event.reason != EliminationReason::Concede
+            || game_state.eliminated_players.contains(&event.player)
+        {
+            continue;
         }
+        info!("Player {:?} conceded", event.player);
+        events.write(GameEvent::PlayerEliminated {
+            player: event.player,
+            reason: event.reason,
+        });
     }
 }
 
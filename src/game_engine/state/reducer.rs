@@ -0,0 +1,95 @@
+use super::GameState;
+use super::event_log::GameEventLog;
+use super::events::{GameEvent, GameOverEvent, GameOverReason};
+use crate::game_engine::commander::EliminationReason;
+use bevy::prelude::*;
+
+impl GameState {
+    /// Apply a single event
+    ///
+    /// This is the only place the game state changes during a game, so applying
+    /// the same events to the same state always gives the same result.
+    pub fn apply(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::TurnOrderSet { players } => self.set_turn_order(players.clone()),
+            // Only recorded; the turn order chosen follows as its own event
+            GameEvent::FirstPlayerRolled { .. } => {}
+            GameEvent::TurnStarted {
+                player,
+                turn_number,
+            } => {
+                self.active_player = *player;
+                self.priority_holder = *player;
+                self.turn_number = *turn_number;
+                self.reset_turn_tracking();
+            }
+            GameEvent::MainPhaseStarted => self.main_phase_action_taken = false,
+            GameEvent::LandPlayed { player } => self.record_land_played(*player),
+            GameEvent::CardDrawn { player } => self.record_draw(*player),
+            GameEvent::PlayerEliminated { player, reason } => {
+                self.eliminate_player(*player, *reason)
+            }
+            GameEvent::PlayerWon { player, source } => {
+                let opponents: Vec<Entity> = self
+                    .turn_order
+                    .iter()
+                    .copied()
+                    .filter(|opponent| opponent != player)
+                    .collect();
+                for opponent in opponents {
+                    self.eliminate_player(opponent, EliminationReason::CardEffect(*source));
+                }
+            }
+            GameEvent::StateBasedActionsChecked { performed } => {
+                self.state_based_actions_performed = *performed
+            }
+        }
+    }
+}
+
+/// System that applies [`GameEvent`]s to the [`GameState`] and logs them
+///
+/// A [`GameOverEvent`] is sent when an elimination leaves one player standing
+/// or a player wins.
+/// If the state was replaced without going through events (a new game, a loaded
+/// save, a rewind), the log starts again from the replaced state.
+pub fn apply_game_events(
+    mut events: EventReader<GameEvent>,
+    mut game_state: ResMut<GameState>,
+    mut log: Option<ResMut<GameEventLog>>,
+    mut game_over: EventWriter<GameOverEvent>,
+) {
+    let replaced = game_state.is_changed();
+    if let Some(log) = log
+        .as_mut()
+        .filter(|log| replaced && !log.is_in_sync(&game_state))
+    {
+        debug!("Game state was replaced, restarting the event log");
+        log.rebase(&game_state);
+    }
+
+    for event in events.read() {
+        let was_over = game_state.is_game_over();
+        game_state.apply(event);
+        debug!("Applied game event {:?}", event);
+
+        if let Some(log) = log.as_mut() {
+            log.record(event.clone());
+        }
+
+        let reason = match event {
+            GameEvent::PlayerEliminated { reason, .. } => GameOverReason::Elimination(*reason),
+            GameEvent::PlayerWon { source, .. } => GameOverReason::CardEffect(*source),
+            _ => continue,
+        };
+        if !was_over && game_state.is_game_over() {
+            let winner = game_state.get_winner();
+            info!("Game over! Player {:?} wins!", winner);
+            game_over.write(GameOverEvent {
+                winner,
+                elimination_order: game_state.eliminated_players.clone(),
+                reason,
+            });
+        }
+    }
+}
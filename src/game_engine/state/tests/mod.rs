@@ -0,0 +1,212 @@
+use bevy::prelude::*;
+
+use crate::game_engine::commander::{EliminationReason, PlayerEliminatedEvent};
+use crate::game_engine::state::{
+    GameEvent, GameEventLog, GameOverEvent, GameOverReason, GameState, apply_game_events,
+    concession_system,
+};
+
+fn reducer_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<GameEvent>()
+        .add_event::<GameOverEvent>()
+        .init_resource::<GameState>()
+        .init_resource::<GameEventLog>()
+        .add_systems(Update, apply_game_events);
+    app
+}
+
+fn send_all(app: &mut App, events: &[GameEvent]) {
+    app.world_mut().send_event_batch(events.iter().cloned());
+    app.update();
+}
+
+#[test]
+fn test_turn_started_resets_per_turn_tracking() {
+    let mut world = World::new();
+    let player = world.spawn_empty().id();
+    let opponent = world.spawn_empty().id();
+    let mut state = GameState::default();
+
+    state.apply(&GameEvent::TurnOrderSet {
+        players: vec![player, opponent],
+    });
+    state.apply(&GameEvent::LandPlayed { player });
+    state.apply(&GameEvent::CardDrawn { player });
+    assert!(!state.can_play_land(player));
+    assert_eq!(state.drawn_this_turn, vec![player]);
+
+    state.apply(&GameEvent::TurnStarted {
+        player: opponent,
+        turn_number: 2,
+    });
+
+    assert_eq!(state.active_player, opponent);
+    assert_eq!(state.turn_number, 2);
+    assert!(state.can_play_land(player));
+    assert!(state.drawn_this_turn.is_empty());
+}
+
+#[test]
+fn test_replaying_the_log_rebuilds_the_state() {
+    let mut app = reducer_app();
+    let player = app.world_mut().spawn_empty().id();
+    let opponent = app.world_mut().spawn_empty().id();
+
+    send_all(
+        &mut app,
+        &[
+            GameEvent::TurnOrderSet {
+                players: vec![player, opponent],
+            },
+            GameEvent::LandPlayed { player },
+            GameEvent::PlayerEliminated {
+                player: opponent,
+                reason: EliminationReason::LifeLoss,
+            },
+        ],
+    );
+
+    let state = app.world().resource::<GameState>();
+    let log = app.world().resource::<GameEventLog>();
+    assert_eq!(log.events().len(), 3);
+    assert_eq!(log.replay(), *state);
+    assert_eq!(state.get_winner(), Some(player));
+
+    // Replaying up to an event gives the state before it
+    let before_elimination = log.replay_to(log.events()[2].sequence);
+    assert!(before_elimination.eliminated_players.is_empty());
+    assert!(!before_elimination.can_play_land(player));
+}
+
+#[test]
+fn test_since_returns_only_missed_events() {
+    let mut app = reducer_app();
+    let player = app.world_mut().spawn_empty().id();
+
+    send_all(&mut app, &[GameEvent::CardDrawn { player }]);
+    let synced = app.world().resource::<GameEventLog>().next_sequence();
+    send_all(
+        &mut app,
+        &[
+            GameEvent::LandPlayed { player },
+            GameEvent::MainPhaseStarted,
+        ],
+    );
+
+    let log = app.world().resource::<GameEventLog>();
+    let missed: Vec<_> = log
+        .since(synced)
+        .unwrap()
+        .iter()
+        .map(|logged| logged.event.clone())
+        .collect();
+    assert_eq!(
+        missed,
+        vec![
+            GameEvent::LandPlayed { player },
+            GameEvent::MainPhaseStarted
+        ]
+    );
+    assert!(log.since(log.next_sequence()).unwrap().is_empty());
+}
+
+#[test]
+fn test_replacing_the_state_restarts_the_log() {
+    let mut app = reducer_app();
+    let player = app.world_mut().spawn_empty().id();
+    send_all(&mut app, &[GameEvent::CardDrawn { player }]);
+    let before_load = app.world().resource::<GameEventLog>().next_sequence();
+
+    // Loading a save replaces the state without events
+    let loaded = GameState::builder().turn_number(7).build();
+    app.insert_resource(loaded.clone());
+    send_all(&mut app, &[GameEvent::LandPlayed { player }]);
+
+    let log = app.world().resource::<GameEventLog>();
+    assert_eq!(*log.baseline(), loaded);
+    assert_eq!(log.events().len(), 1);
+    assert!(log.since(0).is_none());
+    assert!(log.since(before_load).is_some());
+    assert_eq!(log.replay(), *app.world().resource::<GameState>());
+}
+
+#[test]
+fn test_concessions_end_the_game_once() {
+    let mut app = reducer_app();
+    app.add_event::<PlayerEliminatedEvent>()
+        .add_systems(Update, concession_system.before(apply_game_events));
+    let players: Vec<Entity> = (0..3).map(|_| app.world_mut().spawn_empty().id()).collect();
+    send_all(
+        &mut app,
+        &[GameEvent::TurnOrderSet {
+            players: players.clone(),
+        }],
+    );
+
+    let concede = |app: &mut App, player: Entity| {
+        app.world_mut().send_event(PlayerEliminatedEvent {
+            player,
+            reason: EliminationReason::Concede,
+        });
+        app.update();
+        app.world_mut()
+            .resource_mut::<Events<GameOverEvent>>()
+            .drain()
+            .collect::<Vec<_>>()
+    };
+
+    assert!(concede(&mut app, players[2]).is_empty());
+    // Conceding twice doesn't eliminate the player again
+    assert!(concede(&mut app, players[2]).is_empty());
+    assert_eq!(
+        concede(&mut app, players[0]),
+        vec![GameOverEvent {
+            winner: Some(players[1]),
+            elimination_order: vec![players[2], players[0]],
+            reason: GameOverReason::Elimination(EliminationReason::Concede),
+        }]
+    );
+    assert_eq!(
+        app.world().resource::<GameEventLog>().events().len(),
+        3,
+        "one turn order and two eliminations"
+    );
+}
+
+#[test]
+fn test_winning_through_an_effect_eliminates_everyone_else() {
+    let mut app = reducer_app();
+    let players: Vec<Entity> = (0..3).map(|_| app.world_mut().spawn_empty().id()).collect();
+    let source = app.world_mut().spawn_empty().id();
+    send_all(
+        &mut app,
+        &[
+            GameEvent::TurnOrderSet {
+                players: players.clone(),
+            },
+            GameEvent::PlayerWon {
+                player: players[1],
+                source,
+            },
+        ],
+    );
+
+    let state = app.world().resource::<GameState>();
+    assert_eq!(state.get_winner(), Some(players[1]));
+    assert_eq!(state.eliminated_players, vec![players[0], players[2]]);
+    let game_over: Vec<GameOverEvent> = app
+        .world_mut()
+        .resource_mut::<Events<GameOverEvent>>()
+        .drain()
+        .collect();
+    assert_eq!(
+        game_over,
+        vec![GameOverEvent {
+            winner: Some(players[1]),
+            elimination_order: vec![players[0], players[2]],
+            reason: GameOverReason::CardEffect(source),
+        }]
+    );
+}
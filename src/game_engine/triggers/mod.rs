@@ -0,0 +1,285 @@
+//! Triggered abilities waiting to be put on the stack
+//!
+//! Abilities that trigger at the same time are put on the stack in APNAP order
+//! (rule 101.4): the active player puts all of theirs on the stack in the order
+//! they choose, then each other player in turn order, so the last player's
+//! triggers resolve first. A player with more than one trigger picks the order
+//! they resolve in with an [`OrderTriggersEvent`], or they are put on the stack
+//! in the order they triggered once [`TRIGGER_ORDER_TIMEOUT_SECS`] have passed.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::abilities::TriggeredAbility;
+use crate::cards::scripting::EffectPrimitive;
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::phase::StepBeganEvent;
+use crate::game_engine::schedule::GameEngineSet;
+use crate::game_engine::scripting::resolve_primitives;
+use crate::game_engine::stack::{Effect, EffectSpec, GameStack};
+use crate::game_engine::state::GameState;
+use crate::menu::state::GameMenuState;
+
+/// Seconds a player has to order their triggers before it's done for them
+pub const TRIGGER_ORDER_TIMEOUT_SECS: f32 = 15.0;
+
+/// A triggered ability that hasn't been put on the stack yet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingTrigger {
+    /// The permanent whose ability triggered
+    pub source: Entity,
+    /// The player who puts the ability on the stack
+    pub controller: Entity,
+    pub description: String,
+    /// What the ability does, for abilities defined as data
+    ///
+    /// Empty for abilities the engine handles itself.
+    pub effects: Vec<EffectPrimitive>,
+}
+
+/// An ability triggered and should be put on the stack
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct AbilityTriggeredEvent(pub PendingTrigger);
+
+/// Sent by a player to choose the order their simultaneous triggers resolve in
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct OrderTriggersEvent {
+    pub player: Entity,
+    /// Indices into [`TriggerOrderChoice::triggers`], first to resolve first
+    pub order: Vec<usize>,
+}
+
+/// A player choosing the order of their simultaneous triggers
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriggerOrderChoice {
+    pub player: Entity,
+    /// The player's triggers, in the order they triggered
+    pub triggers: Vec<PendingTrigger>,
+    /// Seconds spent waiting for the player so far
+    pub waited: f32,
+}
+
+impl TriggerOrderChoice {
+    /// The triggers in the given order, if it names each trigger exactly once
+    pub fn ordered(&self, order: &[usize]) -> Option<Vec<PendingTrigger>> {
+        let mut seen = vec![false; self.triggers.len()];
+        if order.len() != seen.len() {
+            return None;
+        }
+        for &index in order {
+            if index >= seen.len() || std::mem::replace(&mut seen[index], true) {
+                return None;
+            }
+        }
+        Some(
+            order
+                .iter()
+                .map(|&index| self.triggers[index].clone())
+                .collect(),
+        )
+    }
+
+    /// Seconds left before the triggers are ordered automatically
+    pub fn remaining_secs(&self) -> f32 {
+        (TRIGGER_ORDER_TIMEOUT_SECS - self.waited).max(0.0)
+    }
+}
+
+/// Triggers waiting to go on the stack
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct TriggerQueue {
+    /// Triggers not yet put on the stack or offered for ordering
+    pub waiting: Vec<PendingTrigger>,
+    /// The player currently ordering their triggers, if any
+    pub choice: Option<TriggerOrderChoice>,
+}
+
+/// A triggered ability on the stack
+#[derive(Debug)]
+pub struct TriggeredAbilityEffect(pub PendingTrigger);
+
+impl Effect for TriggeredAbilityEffect {
+    fn resolve(&self, commands: &mut Commands) {
+        info!(
+            "Triggered ability of {:?} resolves: {}",
+            self.0.source, self.0.description
+        );
+        resolve_primitives(
+            commands,
+            self.0.source,
+            self.0.controller,
+            Vec::new(),
+            self.0.effects.clone(),
+        );
+    }
+
+    fn controller(&self) -> Entity {
+        self.0.controller
+    }
+
+    fn targets(&self) -> Vec<Entity> {
+        Vec::new()
+    }
+
+    fn spec(&self) -> EffectSpec {
+        EffectSpec::Triggered(self.0.clone())
+    }
+}
+
+/// Groups triggers by controller in APNAP order
+///
+/// The active player's triggers come first, then each other player's in turn
+/// order, with players missing from the turn order last. Each player's triggers
+/// keep the order they triggered in.
+pub fn apnap_groups(
+    triggers: &[PendingTrigger],
+    turn_order: &[Entity],
+    active_player: Entity,
+) -> Vec<(Entity, Vec<PendingTrigger>)> {
+    let start = turn_order
+        .iter()
+        .position(|&player| player == active_player)
+        .unwrap_or_default();
+    let mut players: Vec<Entity> = turn_order[start..]
+        .iter()
+        .chain(&turn_order[..start])
+        .copied()
+        .collect();
+    for trigger in triggers {
+        if !players.contains(&trigger.controller) {
+            players.push(trigger.controller);
+        }
+    }
+
+    players
+        .into_iter()
+        .filter_map(|player| {
+            let own: Vec<PendingTrigger> = triggers
+                .iter()
+                .filter(|trigger| trigger.controller == player)
+                .cloned()
+                .collect();
+            (!own.is_empty()).then_some((player, own))
+        })
+        .collect()
+}
+
+/// Put one player's triggers on the stack so the first of them resolves first
+fn put_on_stack(commands: &mut Commands, stack: &mut GameStack, triggers: &[PendingTrigger]) {
+    for trigger in triggers.iter().rev() {
+        let entity = commands
+            .spawn(Name::new(format!("Trigger: {}", trigger.description)))
+            .id();
+        stack.push(
+            Box::new(TriggeredAbilityEffect(trigger.clone())),
+            entity,
+            false,
+            true,
+        );
+    }
+}
+
+/// Trigger "at the beginning of" abilities of permanents as each step begins
+pub fn collect_step_triggers(
+    mut steps: EventReader<StepBeganEvent>,
+    abilities: Query<(Entity, &TriggeredAbility, &PermanentController)>,
+    mut triggered: EventWriter<AbilityTriggeredEvent>,
+) {
+    for step in steps.read() {
+        for (source, ability, controller) in abilities.iter() {
+            if ability.trigger.triggers_at_beginning_of(step.phase) {
+                triggered.write(AbilityTriggeredEvent(PendingTrigger {
+                    source,
+                    controller: controller.player,
+                    description: ability.description.clone(),
+                    effects: Vec::new(),
+                }));
+            }
+        }
+    }
+}
+
+/// Put waiting triggers on the stack in APNAP order
+///
+/// Stops at the first player with more than one trigger until they choose an
+/// order or run out of time. Later players' triggers wait for them.
+pub fn order_triggers(
+    mut commands: Commands,
+    mut triggered: EventReader<AbilityTriggeredEvent>,
+    mut orders: EventReader<OrderTriggersEvent>,
+    mut queue: ResMut<TriggerQueue>,
+    mut stack: ResMut<GameStack>,
+    game_state: Res<GameState>,
+    time: Res<Time>,
+) {
+    for event in triggered.read() {
+        queue.waiting.push(event.0.clone());
+    }
+
+    if let Some(choice) = queue.choice.as_mut() {
+        choice.waited += time.delta_secs();
+        let chosen = orders
+            .read()
+            .filter(|event| event.player == choice.player)
+            .filter_map(|event| choice.ordered(&event.order))
+            .last();
+        let triggers = match chosen {
+            Some(triggers) => triggers,
+            None if choice.remaining_secs() <= 0.0 => {
+                info!("Ordering triggers for {:?} automatically", choice.player);
+                choice.triggers.clone()
+            }
+            None => return,
+        };
+        queue.choice = None;
+        put_on_stack(&mut commands, &mut stack, &triggers);
+    } else {
+        orders.clear();
+    }
+
+    if queue.waiting.is_empty() {
+        return;
+    }
+    let turn_order: Vec<Entity> = game_state.turn_order.iter().copied().collect();
+    let waiting = std::mem::take(&mut queue.waiting);
+    let mut groups = apnap_groups(&waiting, &turn_order, game_state.active_player).into_iter();
+    for (player, triggers) in groups.by_ref() {
+        if triggers.len() > 1 {
+            queue.choice = Some(TriggerOrderChoice {
+                player,
+                triggers,
+                waited: 0.0,
+            });
+            break;
+        }
+        put_on_stack(&mut commands, &mut stack, &triggers);
+    }
+    queue
+        .waiting
+        .extend(groups.flat_map(|(_, triggers)| triggers));
+}
+
+/// Forget triggers from the last game when returning to the main menu
+pub fn clear_trigger_queue(mut queue: ResMut<TriggerQueue>) {
+    *queue = TriggerQueue::default();
+}
+
+/// Register the trigger queue and its systems
+pub fn register_trigger_systems(app: &mut App) {
+    app.add_event::<AbilityTriggeredEvent>()
+        .add_event::<OrderTriggersEvent>()
+        .init_resource::<TriggerQueue>()
+        // Triggers go on the stack in the tick their step begins, before anyone
+        // could pass priority past them
+        .add_systems(
+            FixedUpdate,
+            (collect_step_triggers, order_triggers)
+                .chain()
+                .in_set(GameEngineSet::Triggers)
+                .run_if(in_state(GameMenuState::InGame)),
+        )
+        .add_systems(OnEnter(GameMenuState::MainMenu), clear_trigger_queue);
+}
+
+#[cfg(test)]
+mod tests;
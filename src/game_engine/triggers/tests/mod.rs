@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+
+use crate::game_engine::stack::GameStack;
+use crate::game_engine::state::GameState;
+use crate::game_engine::triggers::{
+    AbilityTriggeredEvent, OrderTriggersEvent, PendingTrigger, TRIGGER_ORDER_TIMEOUT_SECS,
+    TriggerQueue, apnap_groups, order_triggers,
+};
+
+fn trigger(controller: Entity, description: &str) -> PendingTrigger {
+    PendingTrigger {
+        source: controller,
+        controller,
+        description: description.to_string(),
+        effects: Vec::new(),
+    }
+}
+
+fn trigger_app(players: usize, active: usize) -> (App, Vec<Entity>) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<AbilityTriggeredEvent>()
+        .add_event::<OrderTriggersEvent>()
+        .init_resource::<TriggerQueue>()
+        .init_resource::<GameStack>()
+        .add_systems(Update, order_triggers);
+    let players: Vec<Entity> = (0..players)
+        .map(|_| app.world_mut().spawn_empty().id())
+        .collect();
+    app.insert_resource(
+        GameState::builder()
+            .turn_order(players.iter().copied().collect())
+            .active_player(players[active])
+            .build(),
+    );
+    (app, players)
+}
+
+/// Descriptions of the stack's triggers, bottom first
+fn stack_descriptions(app: &App) -> Vec<String> {
+    let entities: Vec<Entity> = app
+        .world()
+        .resource::<GameStack>()
+        .items
+        .iter()
+        .map(|item| item.entity)
+        .collect();
+    entities
+        .into_iter()
+        .map(|entity| app.world().get::<Name>(entity).unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn test_apnap_groups_start_with_the_active_player() {
+    let mut world = World::new();
+    let players: Vec<Entity> = (0..3).map(|_| world.spawn_empty().id()).collect();
+    let triggers = vec![
+        trigger(players[0], "a"),
+        trigger(players[2], "b"),
+        trigger(players[1], "c"),
+        trigger(players[1], "d"),
+    ];
+
+    let groups = apnap_groups(&triggers, &players, players[1]);
+    let order: Vec<(Entity, usize)> = groups
+        .iter()
+        .map(|(player, triggers)| (*player, triggers.len()))
+        .collect();
+    assert_eq!(
+        order,
+        vec![(players[1], 2), (players[2], 1), (players[0], 1)]
+    );
+    assert_eq!(groups[0].1[1].description, "d");
+}
+
+#[test]
+fn test_active_player_orders_triggers_before_others_are_stacked() {
+    let (mut app, players) = trigger_app(2, 0);
+    for event in [
+        trigger(players[1], "Opponent upkeep"),
+        trigger(players[0], "First"),
+        trigger(players[0], "Second"),
+    ] {
+        app.world_mut().send_event(AbilityTriggeredEvent(event));
+    }
+    app.update();
+
+    // Nothing goes on the stack until the active player has chosen
+    let queue = app.world().resource::<TriggerQueue>();
+    assert_eq!(queue.choice.as_ref().unwrap().player, players[0]);
+    assert_eq!(queue.waiting.len(), 1);
+    assert!(app.world().resource::<GameStack>().is_empty());
+
+    // Orders from other players and orders that skip a trigger are ignored
+    app.world_mut().send_event(OrderTriggersEvent {
+        player: players[1],
+        order: vec![1, 0],
+    });
+    app.world_mut().send_event(OrderTriggersEvent {
+        player: players[0],
+        order: vec![1, 1],
+    });
+    app.update();
+    assert!(app.world().resource::<TriggerQueue>().choice.is_some());
+
+    // "Second" resolves first, after the opponent's trigger on top of it
+    app.world_mut().send_event(OrderTriggersEvent {
+        player: players[0],
+        order: vec![1, 0],
+    });
+    app.update();
+    app.update();
+    assert_eq!(
+        stack_descriptions(&app),
+        vec![
+            "Trigger: First",
+            "Trigger: Second",
+            "Trigger: Opponent upkeep"
+        ]
+    );
+    assert_eq!(
+        *app.world().resource::<TriggerQueue>(),
+        TriggerQueue::default()
+    );
+}
+
+#[test]
+fn test_triggers_are_ordered_automatically_after_the_timeout() {
+    let (mut app, players) = trigger_app(2, 1);
+    for description in ["First", "Second"] {
+        app.world_mut()
+            .send_event(AbilityTriggeredEvent(trigger(players[0], description)));
+    }
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<TriggerQueue>()
+        .choice
+        .as_mut()
+        .unwrap()
+        .waited = TRIGGER_ORDER_TIMEOUT_SECS;
+    app.update();
+
+    // In the order they triggered, so the first resolves first
+    assert_eq!(
+        stack_descriptions(&app),
+        vec!["Trigger: Second", "Trigger: First"]
+    );
+}
@@ -0,0 +1,240 @@
+//! Deciding who takes the first turn
+//!
+//! As a new game starts, every player rolls a d20 and the highest roll wins;
+//! players tied for the highest roll again until one is left. The winner chooses
+//! who goes first, asked through [`PendingChoices`] so the game waits for them,
+//! and turns then go around the table from that player in seat order.
+//!
+//! The dice are rolled with a seeded RNG, from [`GameSetupConfig::seed`] when
+//! it's set, so a game started with the same seed rolls the same. The rolls and
+//! the turn order decided are both recorded in the [`GameEventLog`](crate::game_engine::GameEventLog).
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::game_engine::choices::{
+    ChoiceAnswer, ChoiceId, ChoiceKind, ChoiceRequest, PendingChoices, handle_choice_answers,
+};
+use crate::game_engine::setup::GameSetupConfig;
+use crate::game_engine::state::GameEvent;
+use crate::game_engine::turns::TurnManager;
+use crate::menu::state::{GameMenuState, starting_new_game};
+use crate::player::Player;
+
+/// Sides of the die rolled for who goes first
+pub const TURN_ORDER_DIE_SIDES: u32 = 20;
+
+/// A player's roll of the die
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DieRoll {
+    pub player: Entity,
+    pub result: u32,
+}
+
+/// The roll for who goes first in the game being started
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TurnOrderRoll {
+    /// Players in seat order
+    pub seats: Vec<Entity>,
+    /// Each round of rolls; after the first, only the players tied for the
+    /// highest roll roll again
+    pub rounds: Vec<Vec<DieRoll>>,
+    /// The player who rolled highest
+    pub winner: Option<Entity>,
+    /// The winner's choice of who goes first, while it's being asked
+    pub choice: Option<ChoiceId>,
+    /// The player chosen to go first, once chosen
+    pub first_player: Option<Entity>,
+}
+
+impl TurnOrderRoll {
+    /// Roll for the players in `seats` until one has the highest roll
+    pub fn roll(seats: Vec<Entity>, rng: &mut impl Rng) -> Self {
+        let mut rounds = Vec::new();
+        let mut rolling = seats.clone();
+        while rolling.len() > 1 {
+            let round: Vec<DieRoll> = rolling
+                .iter()
+                .map(|&player| DieRoll {
+                    player,
+                    result: rng.random_range(1..=TURN_ORDER_DIE_SIDES),
+                })
+                .collect();
+            let highest = round.iter().map(|roll| roll.result).max().unwrap_or(0);
+            rolling = round
+                .iter()
+                .filter(|roll| roll.result == highest)
+                .map(|roll| roll.player)
+                .collect();
+            rounds.push(round);
+        }
+
+        Self {
+            winner: rolling.first().copied(),
+            seats,
+            rounds,
+            choice: None,
+            first_player: None,
+        }
+    }
+
+    /// Every roll a player made, in the order they were made
+    pub fn rolls_of(&self, player: Entity) -> Vec<u32> {
+        self.rounds
+            .iter()
+            .flatten()
+            .filter(|roll| roll.player == player)
+            .map(|roll| roll.result)
+            .collect()
+    }
+
+    /// The players the winner chooses between, starting with the winner
+    pub fn options(&self) -> Vec<Entity> {
+        match self.winner {
+            Some(winner) => turn_order_starting_with(&self.seats, winner),
+            None => self.seats.clone(),
+        }
+    }
+
+    /// The order players take turns in, once the first player is chosen
+    pub fn turn_order(&self) -> Option<Vec<Entity>> {
+        self.first_player
+            .map(|first| turn_order_starting_with(&self.seats, first))
+    }
+}
+
+/// Players in seat order, starting with `first`
+pub fn turn_order_starting_with(seats: &[Entity], first: Entity) -> Vec<Entity> {
+    let start = seats.iter().position(|&seat| seat == first).unwrap_or(0);
+    seats[start..]
+        .iter()
+        .chain(&seats[..start])
+        .copied()
+        .collect()
+}
+
+/// Roll for who goes first as a new game starts, and ask the winner to choose
+pub fn roll_for_turn_order(
+    mut commands: Commands,
+    players: Query<(Entity, &Player)>,
+    config: Option<Res<GameSetupConfig>>,
+    choices: Option<ResMut<PendingChoices>>,
+    turn_manager: Option<ResMut<TurnManager>>,
+    mut events: EventWriter<GameEvent>,
+) {
+    let mut seated: Vec<(Entity, &Player)> = players.iter().collect();
+    seated.sort_by_key(|(_, player)| player.player_index);
+    let seats: Vec<Entity> = seated.iter().map(|(entity, _)| *entity).collect();
+
+    let seed = config
+        .and_then(|config| config.seed)
+        .unwrap_or_else(rand::random);
+    let mut roll = TurnOrderRoll::roll(seats, &mut StdRng::seed_from_u64(seed));
+    let Some(winner) = roll.winner else {
+        return;
+    };
+    events.write(GameEvent::FirstPlayerRolled {
+        rolls: roll
+            .rounds
+            .iter()
+            .flatten()
+            .map(|roll| (roll.player, roll.result))
+            .collect(),
+        winner,
+    });
+
+    let name_of = |entity: Entity| {
+        seated
+            .iter()
+            .find(|(seat, _)| *seat == entity)
+            .map(|(_, player)| player.name.clone())
+            .unwrap_or_else(|| format!("{:?}", entity))
+    };
+    info!("{} won the roll for who goes first", name_of(winner));
+    match choices {
+        Some(mut choices) => {
+            roll.choice = Some(choices.push(ChoiceRequest {
+                player: winner,
+                source: None,
+                prompt: "Choose who goes first".to_string(),
+                kind: ChoiceKind::Option(roll.options().into_iter().map(name_of).collect()),
+            }));
+        }
+        // Without anyone to ask, the winner goes first
+        None => set_first_player(&mut roll, winner, turn_manager, &mut events),
+    }
+    commands.insert_resource(roll);
+}
+
+/// Put the players in the order the winner chose once they've chosen
+pub fn apply_turn_order_choice(
+    mut roll: ResMut<TurnOrderRoll>,
+    mut choices: ResMut<PendingChoices>,
+    turn_manager: Option<ResMut<TurnManager>>,
+    mut events: EventWriter<GameEvent>,
+) {
+    let Some(id) = roll.choice.filter(|&id| !choices.is_pending(id)) else {
+        return;
+    };
+    let chosen = match choices.take_answer(id) {
+        Some(ChoiceAnswer::Option(index)) => roll.options().get(index).copied(),
+        _ => None,
+    };
+    roll.choice = None;
+    if let Some(first) = chosen.or(roll.winner) {
+        set_first_player(&mut roll, first, turn_manager, &mut events);
+    }
+}
+
+/// Have `first` take the first turn, the others following in seat order
+fn set_first_player(
+    roll: &mut TurnOrderRoll,
+    first: Entity,
+    turn_manager: Option<ResMut<TurnManager>>,
+    events: &mut EventWriter<GameEvent>,
+) {
+    roll.first_player = Some(first);
+    let order = turn_order_starting_with(&roll.seats, first);
+    if let Some(mut turn_manager) = turn_manager {
+        turn_manager.player_order = order.clone();
+        turn_manager.active_player = first;
+        turn_manager.active_player_index = 0;
+    }
+    events.write(GameEvent::TurnOrderSet { players: order });
+}
+
+/// Stop the roll for who goes first, like when a saved game is loaded instead
+pub fn cancel_turn_order_roll(world: &mut World) {
+    let Some(roll) = world.remove_resource::<TurnOrderRoll>() else {
+        return;
+    };
+    if let Some((id, mut choices)) = roll.choice.zip(world.get_resource_mut::<PendingChoices>()) {
+        choices.cancel(id);
+    }
+}
+
+/// Forget the last game's roll when returning to the main menu
+pub fn clear_turn_order_roll(mut commands: Commands) {
+    commands.remove_resource::<TurnOrderRoll>();
+}
+
+/// Register rolling for who goes first
+pub fn register_turn_order_systems(app: &mut App) {
+    app.add_systems(
+        OnEnter(GameMenuState::InGame),
+        roll_for_turn_order
+            .after(super::setup_game_engine)
+            .run_if(starting_new_game),
+    )
+    .add_systems(
+        Update,
+        apply_turn_order_choice
+            .after(handle_choice_answers)
+            .run_if(in_state(GameMenuState::InGame).and(resource_exists::<TurnOrderRoll>)),
+    )
+    .add_systems(OnEnter(GameMenuState::MainMenu), clear_turn_order_roll);
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,181 @@
+use bevy::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::game_engine::choices::{
+    AnswerChoiceEvent, ChoiceAnswer, ChoiceKind, PendingChoices, handle_choice_answers,
+};
+use crate::game_engine::setup::GameSetupConfig;
+use crate::game_engine::state::GameEvent;
+use crate::game_engine::turn_order::{
+    TURN_ORDER_DIE_SIDES, TurnOrderRoll, apply_turn_order_choice, cancel_turn_order_roll,
+    roll_for_turn_order, turn_order_starting_with,
+};
+use crate::game_engine::turns::TurnManager;
+use crate::player::Player;
+
+fn seats(count: u32) -> Vec<Entity> {
+    (1..=count).map(Entity::from_raw).collect()
+}
+
+fn roll_app(seed: u64) -> (App, Vec<Entity>) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<GameEvent>()
+        .add_event::<AnswerChoiceEvent>()
+        .init_resource::<PendingChoices>()
+        .init_resource::<TurnManager>()
+        .insert_resource(GameSetupConfig {
+            seed: Some(seed),
+            ..default()
+        })
+        .add_systems(
+            Update,
+            (
+                handle_choice_answers,
+                apply_turn_order_choice.run_if(resource_exists::<TurnOrderRoll>),
+            )
+                .chain(),
+        );
+    // Spawned out of seat order, so seats come from the player index
+    let players: Vec<Entity> = [2, 0, 1]
+        .map(|index| {
+            app.world_mut()
+                .spawn(Player {
+                    name: format!("Player {}", index),
+                    player_index: index,
+                    ..default()
+                })
+                .id()
+        })
+        .into_iter()
+        .collect();
+    let seated = vec![players[1], players[2], players[0]];
+    app.world_mut()
+        .resource_mut::<TurnManager>()
+        .initialize(seated.clone());
+    app.world_mut()
+        .run_system_cached(roll_for_turn_order)
+        .unwrap();
+    (app, seated)
+}
+
+fn drain_events(app: &mut App) -> Vec<GameEvent> {
+    app.world_mut()
+        .resource_mut::<Events<GameEvent>>()
+        .drain()
+        .collect()
+}
+
+#[test]
+fn test_highest_roll_wins_and_ties_roll_again() {
+    let players = seats(4);
+    for seed in 0..50 {
+        let roll = TurnOrderRoll::roll(players.clone(), &mut StdRng::seed_from_u64(seed));
+        let winner = roll.winner.unwrap();
+
+        assert_eq!(roll.rounds[0].len(), players.len());
+        for pair in roll.rounds.windows(2) {
+            // Only the players tied for the highest roll roll again
+            let highest = pair[0].iter().map(|roll| roll.result).max().unwrap();
+            let tied: Vec<Entity> = pair[0]
+                .iter()
+                .filter(|roll| roll.result == highest)
+                .map(|roll| roll.player)
+                .collect();
+            assert!(tied.len() > 1);
+            assert_eq!(
+                pair[1].iter().map(|roll| roll.player).collect::<Vec<_>>(),
+                tied
+            );
+        }
+        let last = roll.rounds.last().unwrap();
+        let best = last.iter().find(|roll| roll.player == winner).unwrap();
+        assert!(
+            last.iter()
+                .all(|roll| roll.player == winner || roll.result < best.result)
+        );
+        assert!(
+            roll.rounds
+                .iter()
+                .flatten()
+                .all(|roll| (1..=TURN_ORDER_DIE_SIDES).contains(&roll.result))
+        );
+    }
+}
+
+#[test]
+fn test_same_seed_rolls_the_same() {
+    let players = seats(4);
+    let first = TurnOrderRoll::roll(players.clone(), &mut StdRng::seed_from_u64(42));
+    let second = TurnOrderRoll::roll(players, &mut StdRng::seed_from_u64(42));
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_turn_order_goes_around_the_table_from_the_first_player() {
+    let players = seats(4);
+    assert_eq!(
+        turn_order_starting_with(&players, players[2]),
+        vec![players[2], players[3], players[0], players[1]]
+    );
+    assert_eq!(turn_order_starting_with(&players, players[0]), players);
+}
+
+#[test]
+fn test_winner_chooses_who_goes_first() {
+    let (mut app, seated) = roll_app(7);
+    let roll = app.world().resource::<TurnOrderRoll>().clone();
+    let winner = roll.winner.unwrap();
+    assert_eq!(roll.seats, seated);
+
+    let events = drain_events(&mut app);
+    assert!(matches!(
+        &events[..],
+        [GameEvent::FirstPlayerRolled { winner: rolled, .. }] if *rolled == winner
+    ));
+    let current = app
+        .world()
+        .resource::<PendingChoices>()
+        .current()
+        .cloned()
+        .unwrap();
+    assert_eq!(current.request.player, winner);
+    let ChoiceKind::Option(options) = &current.request.kind else {
+        panic!("expected a choice between players");
+    };
+    assert_eq!(options.len(), seated.len());
+
+    // Let the player after the winner go first
+    app.world_mut().send_event(AnswerChoiceEvent {
+        player: winner,
+        id: current.id,
+        answer: ChoiceAnswer::Option(1),
+    });
+    app.update();
+
+    let first = roll.options()[1];
+    let order = turn_order_starting_with(&seated, first);
+    let turn_manager = app.world().resource::<TurnManager>();
+    assert_eq!(turn_manager.player_order, order);
+    assert_eq!(turn_manager.active_player, first);
+    assert_eq!(turn_manager.active_player_index, 0);
+    assert_eq!(
+        drain_events(&mut app),
+        vec![GameEvent::TurnOrderSet { players: order }]
+    );
+    let roll = app.world().resource::<TurnOrderRoll>();
+    assert_eq!(roll.first_player, Some(first));
+    assert_eq!(roll.choice, None);
+}
+
+#[test]
+fn test_loading_a_game_cancels_the_roll() {
+    let (mut app, seated) = roll_app(3);
+    cancel_turn_order_roll(app.world_mut());
+    app.update();
+
+    assert!(!app.world().contains_resource::<TurnOrderRoll>());
+    assert!(!app.world().resource::<PendingChoices>().is_waiting());
+    assert_eq!(app.world().resource::<TurnManager>().player_order, seated);
+}
@@ -91,6 +91,9 @@ impl TurnManagerBuilder {
             turn_number: self.turn_number,
             eliminated_players: self.eliminated_players,
             current_phase: self.current_phase,
+            extra_turns: Default::default(),
+            skipped_turns: Default::default(),
+            current_turn_is_extra: false,
         }
     }
 }
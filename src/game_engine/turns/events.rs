@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::game_engine::phase::types::ExtraPhase;
+
 /// Event fired at the beginning of a player's turn
 #[derive(Event, Debug)]
 pub struct TurnStartEvent {
@@ -50,6 +52,27 @@ impl TurnEndEvent {
     }
 }
 
+/// Event giving a player an extra turn after the current one
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ExtraTurnEvent {
+    /// The player taking the extra turn
+    pub player: Entity,
+}
+
+/// Event making a player skip their next turn
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SkipTurnEvent {
+    /// The player skipping a turn
+    pub player: Entity,
+}
+
+/// Event adding phases after the current phase
+#[derive(Event, Debug, Clone)]
+pub struct AdditionalPhasesEvent {
+    /// The added phases, in the order they are played
+    pub phases: Vec<ExtraPhase>,
+}
+
 /// Local resource to track turn event processing to prevent duplicate events
 #[derive(Debug, Default)]
 pub struct TurnEventTracker {
@@ -1,5 +1,6 @@
 use crate::game_engine::phase::types::Phase;
 use bevy::prelude::*;
+use std::collections::{HashMap, VecDeque};
 
 /// Resource that manages turn order and the active player
 #[derive(Resource, Debug)]
@@ -23,6 +24,15 @@ pub struct TurnManager {
     /// TODO: Implement phase tracking and transitions
     #[allow(dead_code)]
     pub current_phase: Phase,
+
+    /// Extra turns waiting to be taken, the most recently added first
+    pub extra_turns: VecDeque<Entity>,
+
+    /// Number of upcoming turns each player has to skip
+    pub skipped_turns: HashMap<Entity, u32>,
+
+    /// Whether the current turn is an extra turn
+    pub current_turn_is_extra: bool,
 }
 
 impl Default for TurnManager {
@@ -49,33 +59,90 @@ impl TurnManager {
     }
 
     /// Move to the next player's turn
+    ///
+    /// Pending extra turns are taken before the normal turn order resumes, and players
+    /// with skipped turns are passed over.
     pub fn advance_turn(&mut self) {
         if self.player_order.is_empty() {
             return;
         }
 
-        // Increment turn number if we've gone through all players
-        if self.active_player_index >= self.player_order.len() - 1 {
-            self.turn_number += 1;
+        // Extra turns are taken right after the current turn, most recent first
+        while let Some(player) = self.extra_turns.pop_front() {
+            if self.eliminated_players.contains(&player) || self.consume_skip(player) {
+                continue;
+            }
+            self.active_player = player;
+            self.current_turn_is_extra = true;
+            return;
         }
+        self.current_turn_is_extra = false;
 
-        // Move to the next player, skipping eliminated players
-        loop {
-            self.active_player_index = (self.active_player_index + 1) % self.player_order.len();
-            self.active_player = self.player_order[self.active_player_index];
+        // Bounded so skipped turns can't loop forever
+        let max_steps = self.player_order.len() * (self.total_skips() + 1);
+        for _ in 0..max_steps {
+            self.advance_turn_order();
 
-            // If player is not eliminated, break the loop
-            if !self.eliminated_players.contains(&self.active_player) {
+            // Safety check to avoid infinite loop if all players are eliminated
+            if self.eliminated_players.len() >= self.player_order.len() {
                 break;
             }
 
-            // Safety check to avoid infinite loop if all players are eliminated
-            if self.eliminated_players.len() >= self.player_order.len() {
+            if self.eliminated_players.contains(&self.active_player) {
+                continue;
+            }
+
+            if !self.consume_skip(self.active_player) {
                 break;
             }
+            info!("Player {:?} skips their turn", self.active_player);
         }
     }
 
+    /// Move to the next seat in the normal turn order
+    fn advance_turn_order(&mut self) {
+        // Increment turn number if we've gone through all players
+        if self.active_player_index >= self.player_order.len() - 1 {
+            self.turn_number += 1;
+        }
+
+        self.active_player_index = (self.active_player_index + 1) % self.player_order.len();
+        self.active_player = self.player_order[self.active_player_index];
+    }
+
+    /// Use up one skipped turn for a player, returning whether they had one
+    fn consume_skip(&mut self, player: Entity) -> bool {
+        match self.skipped_turns.get_mut(&player) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                if *count == 0 {
+                    self.skipped_turns.remove(&player);
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn total_skips(&self) -> usize {
+        self.skipped_turns
+            .values()
+            .map(|&count| count as usize)
+            .sum()
+    }
+
+    /// Give a player an extra turn after the current one
+    ///
+    /// If several extra turns are added, the most recently added is taken first.
+    pub fn add_extra_turn(&mut self, player: Entity) {
+        self.extra_turns.push_front(player);
+    }
+
+    /// Make a player skip their next turn
+    pub fn skip_next_turn(&mut self, player: Entity) {
+        *self.skipped_turns.entry(player).or_default() += 1;
+    }
+
     /// Mark a player as eliminated
     /// TODO: Implement player elimination mechanics
     #[allow(dead_code)]
@@ -6,12 +6,25 @@ mod systems;
 
 // Re-export types for external use
 pub use controller::PermanentController;
-pub use events::{TurnEndEvent, TurnEventTracker, TurnStartEvent};
+pub use events::{
+    AdditionalPhasesEvent, ExtraTurnEvent, SkipTurnEvent, TurnEndEvent, TurnEventTracker,
+    TurnStartEvent,
+};
 pub use manager::TurnManager;
-pub use systems::{handle_turn_end, handle_turn_start};
+pub use systems::{
+    handle_turn_end, handle_turn_start, handle_turn_structure_events, handle_untap_step,
+};
+
+#[cfg(test)]
+mod tests;
+
+use bevy::prelude::*;
+
+use crate::game_engine::phase::{ExtraPhases, phase_transition_system};
+use crate::game_engine::schedule::GameEngineSet;
 
 // Register all turn-related systems with the app
-pub fn register_turn_systems(app: &mut bevy::prelude::App) {
+pub fn register_turn_systems(app: &mut App) {
     // Make sure the Phase resource is registered
     if !app
         .world()
@@ -22,8 +35,20 @@ pub fn register_turn_systems(app: &mut bevy::prelude::App) {
 
     app.add_event::<TurnStartEvent>()
         .add_event::<TurnEndEvent>()
-        .init_resource::<TurnManager>();
+        .add_event::<ExtraTurnEvent>()
+        .add_event::<SkipTurnEvent>()
+        .add_event::<AdditionalPhasesEvent>()
+        .init_resource::<TurnManager>()
+        .init_resource::<ExtraPhases>();
+
+    // Turn structure changes have to be applied before the phase advances
+    app.add_systems(
+        FixedUpdate,
+        handle_turn_structure_events
+            .before(phase_transition_system)
+            .in_set(GameEngineSet::Turn)
+            .run_if(crate::game_engine::game_state_condition),
+    );
 
-    // We don't register these systems here as they're registered directly in GameEnginePlugin
-    // Instead, we just ensure the needed resources and events are available
+    // The other turn systems are registered directly in GameEnginePlugin
 }
@@ -1,7 +1,11 @@
-use crate::cards::{Card, NoUntapCondition, NoUntapEffect, PermanentState};
-use crate::game_engine::phase::types::{BeginningStep, EndingStep, Phase};
+use crate::cards::Card;
+use crate::game_engine::permanent::{
+    NoUntapCondition, NoUntapEffect, PermanentController, PermanentState,
+};
+use crate::game_engine::phase::types::{BeginningStep, EndingStep, ExtraPhases, Phase};
 use crate::game_engine::turns::{
-    PermanentController, TurnEndEvent, TurnEventTracker, TurnManager, TurnStartEvent,
+    AdditionalPhasesEvent, ExtraTurnEvent, SkipTurnEvent, TurnEndEvent, TurnEventTracker,
+    TurnManager, TurnStartEvent,
 };
 use crate::player::Player;
 use bevy::prelude::*;
@@ -97,7 +101,6 @@ pub fn handle_turn_end(
 
 /// System that handles untapping permanents during the untap step
 /// This system considers special effects that prevent untapping, like NoUntapEffect
-#[allow(dead_code)]
 pub fn handle_untap_step(
     mut card_query: UntapCardQuery,
     turn_manager: Res<TurnManager>,
@@ -201,3 +204,27 @@ pub fn handle_untap_step(
     event_tracker.untap_step_processed = true;
     event_tracker.last_processed_turn = turn_manager.turn_number;
 }
+
+/// Applies extra turns, skipped turns and additional phases requested by effects
+pub fn handle_turn_structure_events(
+    mut turn_manager: ResMut<TurnManager>,
+    mut extra_phases: ResMut<ExtraPhases>,
+    mut extra_turn_events: EventReader<ExtraTurnEvent>,
+    mut skip_turn_events: EventReader<SkipTurnEvent>,
+    mut additional_phase_events: EventReader<AdditionalPhasesEvent>,
+) {
+    for event in extra_turn_events.read() {
+        info!("Player {:?} will take an extra turn", event.player);
+        turn_manager.add_extra_turn(event.player);
+    }
+
+    for event in skip_turn_events.read() {
+        info!("Player {:?} will skip their next turn", event.player);
+        turn_manager.skip_next_turn(event.player);
+    }
+
+    for event in additional_phase_events.read() {
+        info!("Adding phases after the current phase: {:?}", event.phases);
+        extra_phases.add_after_current(&event.phases);
+    }
+}
@@ -0,0 +1,188 @@
+use bevy::prelude::*;
+
+use crate::cards::{Card, CardDetails, CardTypes};
+use crate::game_engine::permanent::{PermanentController, PermanentState};
+use crate::game_engine::phase::{
+    BeginningStep, CombatStep, EndingStep, ExtraPhase, ExtraPhases, MAIN1, MAIN2, Phase,
+    StepBeganEvent, phase_transition_system,
+};
+use crate::game_engine::priority::{NextPhaseEvent, PrioritySystem};
+use crate::game_engine::setup::{FirstTurnDraw, GameSetupConfig};
+use crate::game_engine::turns::{TurnManager, handle_untap_step};
+use crate::game_engine::zones::DrawCardEvent;
+use crate::mana::Mana;
+
+fn turn_manager_with_players(count: usize) -> (TurnManager, Vec<Entity>) {
+    let mut world = World::new();
+    let players: Vec<Entity> = (0..count).map(|_| world.spawn_empty().id()).collect();
+    let mut turn_manager = TurnManager::default();
+    turn_manager.initialize(players.clone());
+    (turn_manager, players)
+}
+
+#[test]
+fn test_extra_turn_is_taken_before_normal_order_resumes() {
+    let (mut turn_manager, players) = turn_manager_with_players(3);
+    turn_manager.add_extra_turn(players[0]);
+
+    turn_manager.advance_turn();
+    assert_eq!(turn_manager.active_player, players[0]);
+    assert!(turn_manager.current_turn_is_extra);
+
+    turn_manager.advance_turn();
+    assert_eq!(turn_manager.active_player, players[1]);
+    assert!(!turn_manager.current_turn_is_extra);
+}
+
+#[test]
+fn test_most_recent_extra_turn_is_taken_first() {
+    let (mut turn_manager, players) = turn_manager_with_players(3);
+    turn_manager.add_extra_turn(players[1]);
+    turn_manager.add_extra_turn(players[2]);
+
+    turn_manager.advance_turn();
+    assert_eq!(turn_manager.active_player, players[2]);
+    turn_manager.advance_turn();
+    assert_eq!(turn_manager.active_player, players[1]);
+}
+
+#[test]
+fn test_skipped_turn_passes_to_next_player() {
+    let (mut turn_manager, players) = turn_manager_with_players(3);
+    turn_manager.skip_next_turn(players[1]);
+
+    turn_manager.advance_turn();
+    assert_eq!(turn_manager.active_player, players[2]);
+
+    // The skip is used up
+    turn_manager.advance_turn();
+    turn_manager.advance_turn();
+    assert_eq!(turn_manager.active_player, players[1]);
+}
+
+#[test]
+fn test_skip_applies_to_extra_turn() {
+    let (mut turn_manager, players) = turn_manager_with_players(2);
+    turn_manager.add_extra_turn(players[0]);
+    turn_manager.skip_next_turn(players[0]);
+
+    turn_manager.advance_turn();
+    assert_eq!(turn_manager.active_player, players[1]);
+    assert!(!turn_manager.current_turn_is_extra);
+}
+
+#[test]
+fn test_additional_combat_and_main_after_first_main() {
+    let mut extra_phases = ExtraPhases::default();
+    extra_phases.add_after_current(&[ExtraPhase::Combat, ExtraPhase::Main]);
+
+    // The added combat starts when the first main phase ends
+    let mut step = extra_phases.next_step(MAIN1);
+    assert_eq!(step, Phase::Combat(CombatStep::Beginning));
+    while step != Phase::Combat(CombatStep::End) {
+        step = extra_phases.next_step(step);
+    }
+
+    // Then the added main phase, then the turn's normal combat
+    assert_eq!(extra_phases.next_step(step), MAIN2);
+    assert_eq!(
+        extra_phases.next_step(MAIN2),
+        Phase::Combat(CombatStep::Beginning)
+    );
+    assert!(!extra_phases.in_extra_phase());
+}
+
+#[test]
+fn test_combat_has_a_first_strike_damage_step() {
+    let blockers = Phase::Combat(CombatStep::DeclareBlockers);
+    assert_eq!(
+        blockers.next(),
+        Phase::Combat(CombatStep::FirstStrikeDamage)
+    );
+    assert_eq!(
+        blockers.next().next(),
+        Phase::Combat(CombatStep::CombatDamage)
+    );
+    assert_eq!(
+        Phase::from_label("first strike"),
+        Some(Phase::Combat(CombatStep::FirstStrikeDamage))
+    );
+}
+
+#[test]
+fn test_additional_main_after_postcombat_main() {
+    let mut extra_phases = ExtraPhases::default();
+    extra_phases.add_after_current(&[ExtraPhase::Main]);
+
+    assert_eq!(extra_phases.next_step(MAIN2), MAIN2);
+    assert_eq!(
+        extra_phases.next_step(MAIN2),
+        Phase::Ending(EndingStep::End)
+    );
+}
+
+#[test]
+fn test_untap_step_untaps_the_active_players_permanents() {
+    let mut app = App::new();
+    let (turn_manager, players) = turn_manager_with_players(2);
+    app.insert_resource(turn_manager)
+        .insert_resource(Phase::Beginning(BeginningStep::Untap))
+        .add_systems(Update, handle_untap_step);
+
+    let mut spawn_tapped = |controller: Entity| {
+        let mut state = PermanentState::new(0);
+        state.tap();
+        let land = Card::builder("Forest")
+            .cost(Mana::default())
+            .types(CardTypes::BASIC | CardTypes::LAND)
+            .details(CardDetails::Other)
+            .build_or_panic();
+        app.world_mut()
+            .spawn((land, state, PermanentController::new(controller)))
+            .id()
+    };
+    let own = spawn_tapped(players[0]);
+    let opponents = spawn_tapped(players[1]);
+    app.update();
+
+    let is_tapped = |card| app.world().get::<PermanentState>(card).unwrap().is_tapped;
+    assert!(!is_tapped(own));
+    assert!(is_tapped(opponents));
+}
+
+/// Number of cards drawn as the first turn's draw step begins
+fn first_turn_draws(player_count: usize, config: GameSetupConfig) -> usize {
+    let mut app = App::new();
+    let (turn_manager, _) = turn_manager_with_players(player_count);
+    app.insert_resource(turn_manager)
+        .insert_resource(config)
+        .insert_resource(Phase::Beginning(BeginningStep::Upkeep))
+        .init_resource::<PrioritySystem>()
+        .add_event::<NextPhaseEvent>()
+        .add_event::<DrawCardEvent>()
+        .add_event::<StepBeganEvent>()
+        .add_systems(Update, phase_transition_system);
+    app.world_mut().send_event(NextPhaseEvent);
+    app.update();
+
+    assert_eq!(
+        *app.world().resource::<Phase>(),
+        Phase::Beginning(BeginningStep::Draw)
+    );
+    app.world_mut()
+        .resource_mut::<Events<DrawCardEvent>>()
+        .drain()
+        .count()
+}
+
+#[test]
+fn test_first_player_draws_on_their_first_turn_only_in_multiplayer() {
+    assert_eq!(first_turn_draws(2, GameSetupConfig::default()), 0);
+    assert_eq!(first_turn_draws(4, GameSetupConfig::default()), 1);
+
+    let everyone_draws = GameSetupConfig {
+        first_turn_draw: FirstTurnDraw::EveryoneDraws,
+        ..default()
+    };
+    assert_eq!(first_turn_draws(2, everyone_draws), 1);
+}
@@ -0,0 +1,346 @@
+//! Ways to win or lose the game
+//!
+//! Each state-based action check asks every [`WinConditionRule`] in the
+//! [`WinConditionRules`] registry which players win or lose. The built-in rules
+//! cover running out of life, drawing from an empty library, ten poison
+//! counters, lethal commander damage and "you win the game" effects; more can be
+//! added with [`WinConditionRules::add`].
+//!
+//! Winning instead of drawing from an empty library, as with Laboratory Maniac,
+//! is a replacement effect (rule 614), so it's applied as the draw happens, see
+//! [`process_draw_card_events`](crate::game_engine::zones::process_draw_card_events).
+//!
+//! Players protected by a [`CantLoseGame`] permanent don't lose, and its
+//! opponents may not win either. A player who would win and lose in the same
+//! check loses (rule 104.4a).
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::game_engine::commander::EliminationReason;
+use crate::game_engine::damage::DamageLedger;
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::state::GameState;
+use crate::game_engine::zones::AttemptedDrawFromEmpty;
+use crate::player::Player;
+
+/// Poison counters a player loses the game at
+pub const POISON_COUNTER_LIMIT: u32 = 10;
+
+/// Poison counters a player has
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoisonCounters(pub u32);
+
+/// A permanent whose controller can't lose the game, such as Platinum Angel
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CantLoseGame {
+    /// Whether the controller's opponents can't win the game either
+    pub opponents_cant_win: bool,
+}
+
+/// A permanent whose controller wins the game instead of drawing from an empty
+/// library, such as Laboratory Maniac
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WinsOnEmptyLibraryDraw;
+
+/// Sent by an effect saying a player wins the game
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WinGameEvent {
+    pub player: Entity,
+    /// The card whose effect wins the game
+    pub source: Entity,
+}
+
+/// What the win condition rules know about a player
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerStatus {
+    pub player: Entity,
+    pub life: i32,
+    pub poison: u32,
+    /// Whether the player tried to draw from an empty library since the last check
+    pub drew_from_empty_library: bool,
+    /// Combat damage each commander has dealt to the player
+    pub commander_damage: Vec<(Entity, u32)>,
+}
+
+/// Everything win condition rules look at during a check
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WinConditionContext {
+    /// Players still in the game
+    pub players: Vec<PlayerStatus>,
+    /// Commander damage a player loses at, if commander damage is used
+    pub commander_damage_threshold: Option<u32>,
+    /// Players who won through an effect since the last check, with its source
+    pub effect_wins: Vec<(Entity, Entity)>,
+    /// Players who can't lose the game
+    pub cant_lose: Vec<Entity>,
+    /// Players who can't win the game
+    pub cant_win: Vec<Entity>,
+}
+
+/// A player winning or losing the game
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOutcome {
+    Loses {
+        player: Entity,
+        reason: EliminationReason,
+    },
+    Wins {
+        player: Entity,
+        /// The card whose effect wins the game
+        source: Entity,
+    },
+}
+
+impl GameOutcome {
+    pub fn player(&self) -> Entity {
+        match self {
+            GameOutcome::Loses { player, .. } | GameOutcome::Wins { player, .. } => *player,
+        }
+    }
+}
+
+/// A way of winning or losing the game, checked with state-based actions
+pub trait WinConditionRule: Send + Sync + 'static {
+    /// Players who win or lose by this rule right now
+    fn check(&self, context: &WinConditionContext) -> Vec<GameOutcome>;
+}
+
+/// A player with 0 or less life loses
+pub struct LifeTotalRule;
+
+impl WinConditionRule for LifeTotalRule {
+    fn check(&self, context: &WinConditionContext) -> Vec<GameOutcome> {
+        context
+            .players
+            .iter()
+            .filter(|status| status.life <= 0)
+            .map(|status| GameOutcome::Loses {
+                player: status.player,
+                reason: EliminationReason::LifeLoss,
+            })
+            .collect()
+    }
+}
+
+/// A player who drew from an empty library loses
+pub struct EmptyLibraryRule;
+
+impl WinConditionRule for EmptyLibraryRule {
+    fn check(&self, context: &WinConditionContext) -> Vec<GameOutcome> {
+        context
+            .players
+            .iter()
+            .filter(|status| status.drew_from_empty_library)
+            .map(|status| GameOutcome::Loses {
+                player: status.player,
+                reason: EliminationReason::EmptyLibrary,
+            })
+            .collect()
+    }
+}
+
+/// A player with ten or more poison counters loses
+pub struct PoisonRule;
+
+impl WinConditionRule for PoisonRule {
+    fn check(&self, context: &WinConditionContext) -> Vec<GameOutcome> {
+        context
+            .players
+            .iter()
+            .filter(|status| status.poison >= POISON_COUNTER_LIMIT)
+            .map(|status| GameOutcome::Loses {
+                player: status.player,
+                reason: EliminationReason::Poison,
+            })
+            .collect()
+    }
+}
+
+/// A player dealt the threshold of combat damage by one commander loses
+pub struct CommanderDamageRule;
+
+impl WinConditionRule for CommanderDamageRule {
+    fn check(&self, context: &WinConditionContext) -> Vec<GameOutcome> {
+        let Some(threshold) = context.commander_damage_threshold else {
+            return Vec::new();
+        };
+        context
+            .players
+            .iter()
+            .filter_map(|status| {
+                let (commander, _) = status
+                    .commander_damage
+                    .iter()
+                    .find(|(_, damage)| *damage >= threshold)?;
+                Some(GameOutcome::Loses {
+                    player: status.player,
+                    reason: EliminationReason::CommanderDamage(*commander),
+                })
+            })
+            .collect()
+    }
+}
+
+/// A player an effect says wins the game wins
+pub struct EffectWinRule;
+
+impl WinConditionRule for EffectWinRule {
+    fn check(&self, context: &WinConditionContext) -> Vec<GameOutcome> {
+        context
+            .effect_wins
+            .iter()
+            .map(|&(player, source)| GameOutcome::Wins { player, source })
+            .collect()
+    }
+}
+
+/// The win condition rules checked with state-based actions
+#[derive(Resource)]
+pub struct WinConditionRules {
+    rules: Vec<Box<dyn WinConditionRule>>,
+}
+
+impl Default for WinConditionRules {
+    fn default() -> Self {
+        let mut rules = Self::empty();
+        rules
+            .add(LifeTotalRule)
+            .add(EmptyLibraryRule)
+            .add(PoisonRule)
+            .add(CommanderDamageRule)
+            .add(EffectWinRule);
+        rules
+    }
+}
+
+impl WinConditionRules {
+    /// A registry without any rules, not even the built-in ones
+    pub fn empty() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Check another rule, after the ones already added
+    pub fn add(&mut self, rule: impl WinConditionRule) -> &mut Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Who wins and loses, after "can't lose" and "can't win" effects
+    ///
+    /// Each player appears at most once. Losses come before wins, and a player
+    /// who both wins and loses only loses.
+    pub fn evaluate(&self, context: &WinConditionContext) -> Vec<GameOutcome> {
+        let outcomes: Vec<GameOutcome> = self
+            .rules
+            .iter()
+            .flat_map(|rule| rule.check(context))
+            .collect();
+
+        let mut resolved: Vec<GameOutcome> = Vec::new();
+        for outcome in outcomes
+            .iter()
+            .filter(|outcome| matches!(outcome, GameOutcome::Loses { .. }))
+            .chain(
+                outcomes
+                    .iter()
+                    .filter(|outcome| matches!(outcome, GameOutcome::Wins { .. })),
+            )
+        {
+            let player = outcome.player();
+            let blocked = match outcome {
+                GameOutcome::Loses { .. } => context.cant_lose.contains(&player),
+                GameOutcome::Wins { .. } => context.cant_win.contains(&player),
+            };
+            if !blocked && resolved.iter().all(|seen| seen.player() != player) {
+                resolved.push(*outcome);
+            }
+        }
+        resolved
+    }
+}
+
+/// The game state win condition rules are checked against
+#[derive(SystemParam)]
+pub struct WinConditionState<'w, 's> {
+    rules: Option<Res<'w, WinConditionRules>>,
+    players: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Player,
+            Option<&'static PoisonCounters>,
+            Has<AttemptedDrawFromEmpty>,
+        ),
+    >,
+    ledger: Option<Res<'w, DamageLedger>>,
+    cant_lose: Query<'w, 's, (&'static CantLoseGame, &'static PermanentController)>,
+    effect_wins: EventReader<'w, 's, WinGameEvent>,
+}
+
+impl WinConditionState<'_, '_> {
+    /// Gather what the rules look at for the players still in the game
+    pub fn context(&mut self, game_state: &GameState) -> WinConditionContext {
+        let players: Vec<PlayerStatus> = self
+            .players
+            .iter()
+            .filter(|(entity, ..)| !game_state.eliminated_players.contains(entity))
+            .map(
+                |(entity, player, poison, drew_from_empty_library)| PlayerStatus {
+                    player: entity,
+                    life: player.life,
+                    poison: poison.map_or(0, |poison| poison.0),
+                    drew_from_empty_library,
+                    commander_damage: self
+                        .ledger
+                        .as_ref()
+                        .map(|ledger| ledger.commander_damage_to(entity).collect())
+                        .unwrap_or_default(),
+                },
+            )
+            .collect();
+
+        let mut cant_lose = Vec::new();
+        let mut cant_win = Vec::new();
+        for (effect, controller) in self.cant_lose.iter() {
+            cant_lose.push(controller.player);
+            if effect.opponents_cant_win {
+                cant_win.extend(
+                    players
+                        .iter()
+                        .map(|status| status.player)
+                        .filter(|player| *player != controller.player),
+                );
+            }
+        }
+
+        WinConditionContext {
+            players,
+            commander_damage_threshold: game_state
+                .use_commander_damage
+                .then_some(game_state.commander_damage_threshold),
+            effect_wins: self
+                .effect_wins
+                .read()
+                .map(|event| (event.player, event.source))
+                .collect(),
+            cant_lose,
+            cant_win,
+        }
+    }
+
+    /// Who wins and loses right now
+    ///
+    /// Uses the built-in rules if no [`WinConditionRules`] were added to the app.
+    pub fn outcomes(&mut self, game_state: &GameState) -> Vec<GameOutcome> {
+        let context = self.context(game_state);
+        match self.rules.as_deref() {
+            Some(rules) => rules.evaluate(&context),
+            None => WinConditionRules::default().evaluate(&context),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,248 @@
+use bevy::prelude::*;
+
+use crate::game_engine::commander::{EliminationReason, PlayerEliminatedEvent};
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::state::{
+    GameEvent, GameOverEvent, GameOverReason, GameState, apply_game_events,
+    state_based_actions_system,
+};
+use crate::game_engine::win_conditions::{
+    CantLoseGame, GameOutcome, PlayerStatus, PoisonCounters, WinConditionContext, WinConditionRule,
+    WinConditionRules, WinGameEvent, WinsOnEmptyLibraryDraw,
+};
+use crate::game_engine::zones::{
+    AttemptedDrawFromEmpty, CardDrawnEvent, DrawCardEvent, ZoneChangeEvent, ZoneManager,
+    process_draw_card_events,
+};
+use crate::player::Player;
+
+fn status(player: Entity) -> PlayerStatus {
+    PlayerStatus {
+        player,
+        life: 40,
+        poison: 0,
+        drew_from_empty_library: false,
+        commander_damage: Vec::new(),
+    }
+}
+
+fn sba_app() -> (App, Vec<Entity>) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<ZoneChangeEvent>()
+        .add_event::<PlayerEliminatedEvent>()
+        .add_event::<GameEvent>()
+        .add_event::<GameOverEvent>()
+        .add_event::<WinGameEvent>()
+        .init_resource::<ZoneManager>()
+        .init_resource::<GameState>()
+        .init_resource::<WinConditionRules>()
+        .add_systems(
+            Update,
+            (state_based_actions_system, apply_game_events).chain(),
+        );
+    let players: Vec<Entity> = ["Alice", "Bob", "Carol"]
+        .into_iter()
+        .map(|name| app.world_mut().spawn(Player::new(name)).id())
+        .collect();
+    app.world_mut()
+        .resource_mut::<GameState>()
+        .set_turn_order(players.clone());
+    (app, players)
+}
+
+fn game_over(app: &mut App) -> Vec<GameOverEvent> {
+    app.world_mut()
+        .resource_mut::<Events<GameOverEvent>>()
+        .drain()
+        .collect()
+}
+
+#[test]
+fn test_losses_beat_wins_and_protections_apply() {
+    let [alice, bob, carol, maniac] = [0, 1, 2, 3].map(|index| Entity::from_raw(index + 1));
+    let mut context = WinConditionContext {
+        players: vec![status(alice), status(bob), status(carol)],
+        commander_damage_threshold: Some(21),
+        ..default()
+    };
+    context.effect_wins = vec![(alice, maniac)];
+    context.players[1].poison = 10;
+    context.players[2].commander_damage = vec![(maniac, 20)];
+    let rules = WinConditionRules::default();
+
+    assert_eq!(
+        rules.evaluate(&context),
+        vec![
+            GameOutcome::Loses {
+                player: bob,
+                reason: EliminationReason::Poison,
+            },
+            GameOutcome::Wins {
+                player: alice,
+                source: maniac,
+            },
+        ]
+    );
+
+    // A player who would win and lose at once loses
+    context.players[0].life = 0;
+    assert!(rules.evaluate(&context).contains(&GameOutcome::Loses {
+        player: alice,
+        reason: EliminationReason::LifeLoss,
+    }));
+    assert!(
+        !rules
+            .evaluate(&context)
+            .iter()
+            .any(|outcome| matches!(outcome, GameOutcome::Wins { .. }))
+    );
+
+    // Can't lose and can't win effects stop the outcome
+    context.cant_lose = vec![alice, bob];
+    assert_eq!(
+        rules.evaluate(&context),
+        vec![GameOutcome::Wins {
+            player: alice,
+            source: maniac,
+        }]
+    );
+    context.cant_lose.clear();
+    context.players[0].life = 40;
+    context.cant_win = vec![alice];
+    assert_eq!(rules.evaluate(&context).len(), 1);
+}
+
+#[test]
+fn test_rules_can_be_added_to_the_registry() {
+    struct HighLifeWins;
+
+    impl WinConditionRule for HighLifeWins {
+        fn check(&self, context: &WinConditionContext) -> Vec<GameOutcome> {
+            context
+                .players
+                .iter()
+                .filter(|status| status.life >= 50)
+                .map(|status| GameOutcome::Wins {
+                    player: status.player,
+                    source: status.player,
+                })
+                .collect()
+        }
+    }
+
+    let player = Entity::from_raw(1);
+    let mut context = WinConditionContext {
+        players: vec![status(player)],
+        ..default()
+    };
+    context.players[0].life = 50;
+    assert!(WinConditionRules::default().evaluate(&context).is_empty());
+
+    let mut rules = WinConditionRules::empty();
+    rules.add(HighLifeWins);
+    assert_eq!(
+        rules.evaluate(&context),
+        vec![GameOutcome::Wins {
+            player,
+            source: player,
+        }]
+    );
+}
+
+#[test]
+fn test_poison_counters_eliminate_in_state_based_actions() {
+    let (mut app, players) = sba_app();
+    app.world_mut()
+        .entity_mut(players[1])
+        .insert(PoisonCounters(9));
+    app.update();
+    assert!(
+        app.world()
+            .resource::<GameState>()
+            .eliminated_players
+            .is_empty()
+    );
+
+    app.world_mut()
+        .entity_mut(players[1])
+        .insert(PoisonCounters(10));
+    app.update();
+    assert_eq!(
+        app.world().resource::<GameState>().eliminated_players,
+        vec![players[1]]
+    );
+}
+
+#[test]
+fn test_drawing_from_an_empty_library_can_win_the_game() {
+    let (mut app, players) = sba_app();
+    app.add_event::<DrawCardEvent>()
+        .add_event::<CardDrawnEvent>()
+        .add_systems(
+            Update,
+            process_draw_card_events.before(state_based_actions_system),
+        );
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .init_player_zones(players[0]);
+    let maniac = app
+        .world_mut()
+        .spawn((WinsOnEmptyLibraryDraw, PermanentController::new(players[0])))
+        .id();
+    app.world_mut()
+        .send_event(DrawCardEvent::single(players[0]));
+    app.update();
+
+    // The draw is replaced, so there's no empty library draw to lose to
+    assert!(
+        app.world()
+            .get::<AttemptedDrawFromEmpty>(players[0])
+            .is_none()
+    );
+
+    let state = app.world().resource::<GameState>();
+    assert_eq!(state.get_winner(), Some(players[0]));
+    assert_eq!(
+        game_over(&mut app),
+        vec![GameOverEvent {
+            winner: Some(players[0]),
+            elimination_order: vec![players[1], players[2]],
+            reason: GameOverReason::CardEffect(maniac),
+        }]
+    );
+}
+
+#[test]
+fn test_cant_lose_the_game_protects_its_controller() {
+    let (mut app, players) = sba_app();
+    let angel = app
+        .world_mut()
+        .spawn((
+            CantLoseGame {
+                opponents_cant_win: true,
+            },
+            PermanentController::new(players[0]),
+        ))
+        .id();
+    app.world_mut().get_mut::<Player>(players[0]).unwrap().life = -5;
+    app.world_mut().send_event(WinGameEvent {
+        player: players[1],
+        source: players[1],
+    });
+    app.update();
+    assert!(
+        app.world()
+            .resource::<GameState>()
+            .eliminated_players
+            .is_empty()
+    );
+
+    // Once the permanent is gone, the player loses at the next check
+    app.world_mut().entity_mut(angel).despawn();
+    app.update();
+    assert_eq!(
+        app.world().resource::<GameState>().eliminated_players,
+        vec![players[0]]
+    );
+}
@@ -1,3 +1,4 @@
+use super::resources::CardLocation;
 use super::types::Zone;
 use bevy::prelude::*;
 
@@ -32,3 +33,58 @@ pub struct EntersBattlefieldEvent {
     /// Whether the permanent entered tapped
     pub enters_tapped: bool,
 }
+
+/// Event requesting that a player draws cards from their library
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DrawCardEvent {
+    /// The player drawing
+    pub player: Entity,
+    /// Number of cards to draw
+    pub count: u32,
+}
+
+impl DrawCardEvent {
+    /// Draw a single card
+    pub fn single(player: Entity) -> Self {
+        Self { player, count: 1 }
+    }
+}
+
+/// A player's library was shuffled
+///
+/// Only says whose library it was, so every player can be told.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LibraryShuffledEvent {
+    pub player: Entity,
+}
+
+/// A player drew a card from the top of their library
+///
+/// Sent along with the card's [`ZoneChangeEvent`], for the library visuals.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardDrawnEvent {
+    pub player: Entity,
+    pub card: Entity,
+}
+
+/// A change made to the [`ZoneManager`](super::ZoneManager)
+///
+/// Applying these in order to an empty copy of the zones reproduces them, which
+/// lets the UI and networking mirror every change.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub enum ZoneMutationEvent {
+    /// A card was put into a zone
+    Inserted {
+        card: Entity,
+        location: CardLocation,
+    },
+    /// A card was taken out of a zone
+    Removed {
+        card: Entity,
+        location: CardLocation,
+    },
+    /// A player's library was put in a new order, given bottom first
+    Shuffled { owner: Entity, cards: Vec<Entity> },
+    /// Every zone was emptied, e.g. before a save is loaded
+    Cleared,
+}
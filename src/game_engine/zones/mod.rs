@@ -4,6 +4,9 @@ pub mod resources;
 pub mod systems;
 pub mod types;
 
+#[cfg(test)]
+mod tests;
+
 // Public exports
 pub use events::*;
 pub use resources::*;
@@ -12,6 +15,8 @@ pub use types::*;
 
 use bevy::prelude::*;
 
+use crate::game_engine::schedule::GameEngineSet;
+
 /// Plugin for zone-related functionality
 pub struct ZonesPlugin;
 
@@ -19,9 +24,25 @@ impl Plugin for ZonesPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<ZoneMarker>()
             .add_event::<events::ZoneChangeEvent>()
-            .add_event::<events::EntersBattlefieldEvent>();
+            .add_event::<events::EntersBattlefieldEvent>()
+            .add_event::<events::DrawCardEvent>()
+            .add_event::<events::LibraryShuffledEvent>()
+            .add_event::<events::CardDrawnEvent>()
+            .add_event::<events::ZoneMutationEvent>();
 
         // Add systems for managing zones - moved to FixedUpdate for better performance
-        app.add_systems(FixedUpdate, systems::process_zone_changes);
+        // Cards move after the stack and combat, so state-based actions see them
+        app.add_systems(
+            FixedUpdate,
+            (
+                systems::process_zone_changes,
+                systems::process_draw_card_events.run_if(crate::game_engine::game_state_condition),
+            )
+                .in_set(GameEngineSet::Zones),
+        )
+        .add_systems(
+            PostUpdate,
+            systems::publish_zone_mutations.run_if(resource_exists::<ZoneManager>),
+        );
     }
 }
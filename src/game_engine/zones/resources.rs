@@ -1,230 +1,414 @@
-use super::types::Zone;
+use super::events::ZoneMutationEvent;
+use super::types::{ExiledBy, Zone};
 use bevy::prelude::*;
+use rand::Rng;
+use rand::seq::SliceRandom;
 use std::collections::HashMap;
 
-/// Resource managing game zones and card movement between zones
-#[derive(Resource, Default)]
-pub struct ZoneManager {
-    /// Libraries (decks) for each player
-    pub libraries: HashMap<Entity, Vec<Entity>>,
-
-    /// Hands for each player
-    pub hands: HashMap<Entity, Vec<Entity>>,
+/// Where a card is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardLocation {
+    pub zone: Zone,
+    /// The player who owns the card, or `Entity::PLACEHOLDER` for a card in a
+    /// shared zone whose owner isn't known
+    pub owner: Entity,
+    /// Index in the zone's list, counting from the bottom
+    pub position: usize,
+}
 
-    /// Shared battlefield (all permanents in play)
-    pub battlefield: Vec<Entity>,
+/// Where in a zone a card is put
+///
+/// Zones are ordered from the bottom: the top of a library is its last card, and
+/// the most recent card put into a graveyard is its last card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZonePosition {
+    Top,
+    Bottom,
+    /// Index counting from the bottom, clamped to the size of the zone
+    Index(usize),
+}
 
-    /// Graveyards for each player
-    pub graveyards: HashMap<Entity, Vec<Entity>>,
+/// The ordered list behind a zone
+///
+/// Libraries, hands and graveyards belong to a player; the battlefield, stack,
+/// exile and command zone are shared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ZoneKey {
+    zone: Zone,
+    player: Option<Entity>,
+}
 
-    /// Shared exile zone
-    pub exile: Vec<Entity>,
+impl ZoneKey {
+    fn new(zone: Zone, owner: Entity) -> Self {
+        let player = match zone {
+            Zone::Library | Zone::Hand | Zone::Graveyard => Some(owner),
+            Zone::Battlefield | Zone::Stack | Zone::Exile | Zone::Command => None,
+        };
+        Self { zone, player }
+    }
 
-    /// Command zone (stores commanders when not in play)
-    pub command_zone: Vec<Entity>,
+    fn shared(zone: Zone) -> Self {
+        Self { zone, player: None }
+    }
+}
 
-    /// Maps each card to its current zone
-    pub card_zone_map: HashMap<Entity, Zone>,
+/// Resource managing game zones and card movement between zones
+///
+/// Every card is indexed by entity, so finding a card's zone, owner and position
+/// doesn't scan the zones. Each change is recorded and published as a
+/// [`ZoneMutationEvent`] so UI and networking can mirror the zones.
+#[derive(Resource, Default)]
+pub struct ZoneManager {
+    /// Where each card is
+    locations: HashMap<Entity, CardLocation>,
+    /// The cards in each zone, bottom first
+    zones: HashMap<ZoneKey, Vec<Entity>>,
+    /// Why each card exiled by an effect that remembers it is in exile
+    exiled_by: HashMap<Entity, ExiledBy>,
+    /// Changes not yet published as events
+    pending: Vec<ZoneMutationEvent>,
 }
 
 impl ZoneManager {
     /// Initialize zones for a new player
-    /// TODO: Implement when initializing zones for a player
-    #[allow(dead_code)]
     pub fn init_player_zones(&mut self, player: Entity) {
-        self.libraries.entry(player).or_default();
-        self.hands.entry(player).or_default();
-        self.graveyards.entry(player).or_default();
+        for zone in [Zone::Library, Zone::Hand, Zone::Graveyard] {
+            self.zones.entry(ZoneKey::new(zone, player)).or_default();
+        }
     }
 
-    /// Move a card from one zone to another
-    pub fn move_card(
+    /// Whether zones have been set up for `player`
+    pub fn has_player_zones(&self, player: Entity) -> bool {
+        self.zones
+            .contains_key(&ZoneKey::new(Zone::Library, player))
+    }
+
+    /// Players with zones, in no particular order
+    pub fn players(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.zones
+            .keys()
+            .filter(|key| key.zone == Zone::Library)
+            .filter_map(|key| key.player)
+    }
+
+    /// Where a card is
+    pub fn location(&self, card: Entity) -> Option<CardLocation> {
+        self.locations.get(&card).copied()
+    }
+
+    /// Every tracked card and where it is
+    pub fn locations(&self) -> impl Iterator<Item = (Entity, CardLocation)> + '_ {
+        self.locations
+            .iter()
+            .map(|(card, location)| (*card, *location))
+    }
+
+    /// Get the zone of a specific card
+    pub fn get_card_zone(&self, card: Entity) -> Option<Zone> {
+        self.locations.get(&card).map(|location| location.zone)
+    }
+
+    /// Get the owner of a card, if known
+    pub fn get_card_owner(&self, card: Entity) -> Option<Entity> {
+        self.locations
+            .get(&card)
+            .map(|location| location.owner)
+            .filter(|owner| *owner != Entity::PLACEHOLDER)
+    }
+
+    /// The cards in one of `player`'s zones, bottom first
+    ///
+    /// Shared zones hold every player's cards.
+    pub fn get_player_zone(&self, player: Entity, zone: Zone) -> Option<&[Entity]> {
+        self.zones
+            .get(&ZoneKey::new(zone, player))
+            .map(Vec::as_slice)
+    }
+
+    fn cards(&self, key: ZoneKey) -> &[Entity] {
+        self.zones.get(&key).map_or(&[], Vec::as_slice)
+    }
+
+    /// A player's library, bottom first
+    pub fn library(&self, player: Entity) -> &[Entity] {
+        self.cards(ZoneKey::new(Zone::Library, player))
+    }
+
+    /// A player's hand, in the order the cards arrived
+    pub fn hand(&self, player: Entity) -> &[Entity] {
+        self.cards(ZoneKey::new(Zone::Hand, player))
+    }
+
+    /// A player's graveyard, oldest first
+    pub fn graveyard(&self, player: Entity) -> &[Entity] {
+        self.cards(ZoneKey::new(Zone::Graveyard, player))
+    }
+
+    /// All permanents in play
+    pub fn battlefield(&self) -> &[Entity] {
+        self.cards(ZoneKey::shared(Zone::Battlefield))
+    }
+
+    /// The shared exile zone
+    pub fn exile(&self) -> &[Entity] {
+        self.cards(ZoneKey::shared(Zone::Exile))
+    }
+
+    /// The command zone
+    pub fn command_zone(&self) -> &[Entity] {
+        self.cards(ZoneKey::shared(Zone::Command))
+    }
+
+    /// Number of cards tracked in every zone
+    pub fn card_count(&self) -> usize {
+        self.locations.len()
+    }
+
+    /// Put a card into a zone at `position`, taking it out of its current zone first
+    ///
+    /// Returns `false` without changing anything if `owner` has no zones set up
+    /// for a player zone.
+    pub fn insert(
         &mut self,
         card: Entity,
         owner: Entity,
-        source: Zone,
-        destination: Zone,
+        zone: Zone,
+        position: ZonePosition,
     ) -> bool {
-        // Remove from source zone
-        let removed = match source {
-            Zone::Library => self.remove_from_library(card, owner),
-            Zone::Hand => self.remove_from_hand(card, owner),
-            Zone::Battlefield => self.remove_from_battlefield(card),
-            Zone::Graveyard => self.remove_from_graveyard(card, owner),
-            Zone::Exile => self.remove_from_exile(card),
-            Zone::Command => self.remove_from_command_zone(card),
-            Zone::Stack => true, // Stack items are removed when they resolve
+        let key = ZoneKey::new(zone, owner);
+        if key.player.is_some() && !self.zones.contains_key(&key) {
+            return false;
+        }
+        self.remove(card);
+
+        let cards = self.zones.entry(key).or_default();
+        let index = match position {
+            ZonePosition::Top => cards.len(),
+            ZonePosition::Bottom => 0,
+            ZonePosition::Index(index) => index.min(cards.len()),
         };
+        cards.insert(index, card);
+        self.reindex(key, index, owner);
 
-        if !removed {
-            return false;
+        let location = self.locations[&card];
+        self.pending
+            .push(ZoneMutationEvent::Inserted { card, location });
+        true
+    }
+
+    /// Take a card out of its zone
+    pub fn remove(&mut self, card: Entity) -> Option<CardLocation> {
+        let location = self.locations.remove(&card)?;
+        self.exiled_by.remove(&card);
+        let key = ZoneKey::new(location.zone, location.owner);
+        if let Some(cards) = self.zones.get_mut(&key) {
+            cards.remove(location.position);
+            self.reindex(key, location.position, location.owner);
         }
+        self.pending
+            .push(ZoneMutationEvent::Removed { card, location });
+        Some(location)
+    }
 
-        // Add to destination zone
-        match destination {
-            Zone::Library => self.add_to_library(owner, card),
-            Zone::Hand => self.add_to_hand(owner, card),
-            Zone::Battlefield => self.add_to_battlefield(owner, card),
-            Zone::Graveyard => self.add_to_graveyard(owner, card),
-            Zone::Exile => self.add_to_exile(card),
-            Zone::Command => self.add_to_command_zone(card),
-            Zone::Stack => {} // Stack items are added via GameStack
+    /// Update the index for the cards of a zone from `from` upwards
+    ///
+    /// `owner` is only used for a card that isn't indexed yet.
+    fn reindex(&mut self, key: ZoneKey, from: usize, owner: Entity) {
+        let Some(cards) = self.zones.get(&key) else {
+            return;
+        };
+        for (position, card) in cards.iter().enumerate().skip(from) {
+            let location = self.locations.entry(*card).or_insert(CardLocation {
+                zone: key.zone,
+                owner,
+                position,
+            });
+            location.position = position;
         }
+    }
 
-        // Update zone mapping
-        self.card_zone_map.insert(card, destination);
+    /// Move a card from one zone to another
+    ///
+    /// Returns `false` if the card isn't in `source`. Cards coming off the stack
+    /// may be untracked, since the stack is managed by `GameStack`.
+    pub fn move_card(
+        &mut self,
+        card: Entity,
+        owner: Entity,
+        source: Zone,
+        destination: Zone,
+    ) -> bool {
+        match self.get_card_zone(card) {
+            Some(zone) if zone == source => {}
+            None if source == Zone::Stack => {}
+            _ => return false,
+        }
+        self.insert(card, owner, destination, ZonePosition::Top)
+    }
 
-        true
+    /// Move a card to `position` in another zone, keeping its owner
+    pub fn move_to(&mut self, card: Entity, destination: Zone, position: ZonePosition) -> bool {
+        let Some(owner) = self.get_card_owner(card) else {
+            return false;
+        };
+        self.insert(card, owner, destination, position)
     }
 
-    /// Add a card to a player's library
+    /// Add a card to the top of a player's library
     pub fn add_to_library(&mut self, owner: Entity, card: Entity) {
-        if let Some(library) = self.libraries.get_mut(&owner) {
-            library.push(card);
-            self.card_zone_map.insert(card, Zone::Library);
-        }
+        self.insert(card, owner, Zone::Library, ZonePosition::Top);
     }
 
-    /// Remove a card from a player's library
-    pub fn remove_from_library(&mut self, card: Entity, owner: Entity) -> bool {
-        if let Some(library) = self.libraries.get_mut(&owner) {
-            if let Some(index) = library.iter().position(|&c| c == card) {
-                library.remove(index);
-                return true;
-            }
-        }
-        false
+    /// Put a player's library in a random order
+    pub fn shuffle_library(&mut self, player: Entity, rng: &mut impl Rng) {
+        let key = ZoneKey::new(Zone::Library, player);
+        let Some(cards) = self.zones.get_mut(&key) else {
+            return;
+        };
+        cards.shuffle(rng);
+        let cards = cards.clone();
+        self.reindex(key, 0, player);
+        self.pending.push(ZoneMutationEvent::Shuffled {
+            owner: player,
+            cards,
+        });
+    }
+
+    /// Get the top card of a player's library
+    ///
+    /// The last card in the library is the top card.
+    pub fn top_of_library(&self, player: Entity) -> Option<Entity> {
+        self.library(player).last().copied()
+    }
+
+    /// Move the top card of a player's library into their hand
+    ///
+    /// Returns `None` when the library is empty.
+    pub fn draw_card(&mut self, player: Entity) -> Option<Entity> {
+        let card = self.top_of_library(player)?;
+        self.move_card(card, player, Zone::Library, Zone::Hand)
+            .then_some(card)
     }
 
     /// Add a card to a player's hand
     pub fn add_to_hand(&mut self, owner: Entity, card: Entity) {
-        if let Some(hand) = self.hands.get_mut(&owner) {
-            hand.push(card);
-            self.card_zone_map.insert(card, Zone::Hand);
-        }
+        self.insert(card, owner, Zone::Hand, ZonePosition::Top);
     }
 
-    /// Remove a card from a player's hand
-    pub fn remove_from_hand(&mut self, card: Entity, owner: Entity) -> bool {
-        if let Some(hand) = self.hands.get_mut(&owner) {
-            if let Some(index) = hand.iter().position(|&c| c == card) {
-                hand.remove(index);
-                return true;
-            }
-        }
-        false
+    /// Add a card to the battlefield
+    pub fn add_to_battlefield(&mut self, owner: Entity, card: Entity) {
+        self.insert(card, owner, Zone::Battlefield, ZonePosition::Top);
     }
 
-    /// Add a card to the battlefield
-    pub fn add_to_battlefield(&mut self, _owner: Entity, card: Entity) {
-        self.battlefield.push(card);
-        self.card_zone_map.insert(card, Zone::Battlefield);
+    /// Add a card to the top of a player's graveyard
+    pub fn add_to_graveyard(&mut self, owner: Entity, card: Entity) {
+        self.insert(card, owner, Zone::Graveyard, ZonePosition::Top);
     }
 
-    /// Remove a card from the battlefield
-    pub fn remove_from_battlefield(&mut self, card: Entity) -> bool {
-        if let Some(index) = self.battlefield.iter().position(|&c| c == card) {
-            self.battlefield.remove(index);
-            return true;
-        }
-        false
+    /// Add a card to the exile zone
+    pub fn add_to_exile(&mut self, owner: Entity, card: Entity) {
+        self.insert(card, owner, Zone::Exile, ZonePosition::Top);
     }
 
-    /// Add a card to a player's graveyard
-    pub fn add_to_graveyard(&mut self, owner: Entity, card: Entity) {
-        if let Some(graveyard) = self.graveyards.get_mut(&owner) {
-            graveyard.push(card);
-            self.card_zone_map.insert(card, Zone::Graveyard);
+    /// Exile a card for an effect that remembers it, such as impulse draw,
+    /// an adventure or foretell
+    pub fn exile_with(&mut self, owner: Entity, card: Entity, exiled_by: ExiledBy) {
+        if self.insert(card, owner, Zone::Exile, ZonePosition::Top) {
+            self.exiled_by.insert(card, exiled_by);
         }
     }
 
-    /// Remove a card from a player's graveyard
-    pub fn remove_from_graveyard(&mut self, card: Entity, owner: Entity) -> bool {
-        if let Some(graveyard) = self.graveyards.get_mut(&owner) {
-            if let Some(index) = graveyard.iter().position(|&c| c == card) {
-                graveyard.remove(index);
-                return true;
-            }
-        }
-        false
+    /// Why a card is in exile, if an effect exiled it with [`Self::exile_with`]
+    pub fn exiled_by(&self, card: Entity) -> Option<&ExiledBy> {
+        self.exiled_by.get(&card)
     }
 
-    /// Add a card to the exile zone
-    fn add_to_exile(&mut self, card: Entity) {
-        self.exile.push(card);
-        self.card_zone_map.insert(card, Zone::Exile);
+    /// Cards a player owns that were exiled with [`Self::exile_with`], bottom first
+    pub fn exiled_with_memory(
+        &self,
+        player: Entity,
+    ) -> impl Iterator<Item = (Entity, &ExiledBy)> + '_ {
+        self.exile().iter().filter_map(move |card| {
+            let exiled_by = self.exiled_by.get(card)?;
+            (self.get_card_owner(*card) == Some(player)).then_some((*card, exiled_by))
+        })
     }
 
-    /// Remove a card from the exile zone
-    fn remove_from_exile(&mut self, card: Entity) -> bool {
-        if let Some(index) = self.exile.iter().position(|&c| c == card) {
-            self.exile.remove(index);
-            return true;
-        }
-        false
+    /// Cards in exile a player may play on `turn`
+    pub fn playable_from_exile(
+        &self,
+        player: Entity,
+        turn: u32,
+    ) -> impl Iterator<Item = Entity> + '_ {
+        self.exiled_with_memory(player)
+            .filter(move |(_, exiled_by)| exiled_by.may_play(turn))
+            .map(|(card, _)| card)
     }
 
     /// Add a card to the command zone
-    fn add_to_command_zone(&mut self, card: Entity) {
-        self.command_zone.push(card);
-        self.card_zone_map.insert(card, Zone::Command);
+    pub fn add_to_command_zone(&mut self, owner: Entity, card: Entity) {
+        self.insert(card, owner, Zone::Command, ZonePosition::Top);
     }
 
-    /// Remove a card from the command zone
-    fn remove_from_command_zone(&mut self, card: Entity) -> bool {
-        if let Some(index) = self.command_zone.iter().position(|&c| c == card) {
-            self.command_zone.remove(index);
-            return true;
-        }
-        false
-    }
-
-    /// Get the zone for a specific player
-    /// TODO: Implement when querying zone contents is needed
-    #[allow(dead_code)]
-    pub fn get_player_zone(&self, player: Entity, zone: Zone) -> Option<&Vec<Entity>> {
-        match zone {
-            Zone::Library => self.libraries.get(&player),
-            Zone::Hand => self.hands.get(&player),
-            Zone::Battlefield => Some(&self.battlefield),
-            Zone::Graveyard => self.graveyards.get(&player),
-            Zone::Exile => Some(&self.exile),
-            Zone::Command => Some(&self.command_zone),
-            Zone::Stack => None, // Stack is managed separately by the GameStack resource
-        }
-    }
+    /// Ways the zone lists and the card index disagree, empty when they match
+    ///
+    /// Every card in a zone must be in exactly one zone, and indexed with that
+    /// zone, its owner for player zones, and its position in the zone.
+    pub fn consistency_errors(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        let mut listed = HashMap::new();
 
-    /// Get the owner of a card (if found in a player zone)
-    pub fn get_card_owner(&self, card: Entity) -> Option<Entity> {
-        // Check libraries
-        for (&player, library) in &self.libraries {
-            if library.contains(&card) {
-                return Some(player);
+        for (key, cards) in &self.zones {
+            for (position, card) in cards.iter().enumerate() {
+                if let Some(other) = listed.insert(*card, key.zone) {
+                    errors.push(format!(
+                        "{:?} is in both {:?} and {:?}",
+                        card, other, key.zone
+                    ));
+                }
+                match self.locations.get(card) {
+                    None => errors.push(format!("{:?} in {:?} isn't indexed", card, key.zone)),
+                    Some(location) => {
+                        if location.zone != key.zone || location.position != position {
+                            errors.push(format!(
+                                "{:?} is at {} in {:?} but indexed at {} in {:?}",
+                                card, position, key.zone, location.position, location.zone
+                            ));
+                        }
+                        if key.player.is_some_and(|player| player != location.owner) {
+                            errors.push(format!(
+                                "{:?} is in {:?}'s {:?} but indexed as owned by {:?}",
+                                card, key.player, key.zone, location.owner
+                            ));
+                        }
+                    }
+                }
             }
         }
 
-        // Check hands
-        for (&player, hand) in &self.hands {
-            if hand.contains(&card) {
-                return Some(player);
+        for card in self.locations.keys() {
+            if !listed.contains_key(card) {
+                errors.push(format!("{:?} is indexed but in no zone", card));
             }
         }
+        errors
+    }
 
-        // Check graveyards
-        for (&player, graveyard) in &self.graveyards {
-            if graveyard.contains(&card) {
-                return Some(player);
-            }
-        }
+    /// Remove every card and player
+    pub fn clear(&mut self) {
+        self.locations.clear();
+        self.zones.clear();
+        self.exiled_by.clear();
+        self.pending.push(ZoneMutationEvent::Cleared);
+    }
 
-        // For shared zones, we'd need to track ownership separately
-        None
+    /// Whether there are changes waiting to be published
+    pub fn has_pending_mutations(&self) -> bool {
+        !self.pending.is_empty()
     }
 
-    /// Get the zone of a specific card
-    /// TODO: Implement when tracking card locations is needed
-    #[allow(dead_code)]
-    pub fn get_card_zone(&self, card: Entity) -> Option<Zone> {
-        self.card_zone_map.get(&card).copied()
+    /// Take the changes made since the last call, oldest first
+    pub fn drain_mutations(&mut self) -> Vec<ZoneMutationEvent> {
+        std::mem::take(&mut self.pending)
     }
 }
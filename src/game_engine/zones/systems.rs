@@ -1,12 +1,18 @@
 use crate::player::Player;
 use bevy::prelude::*;
 
-use super::events::{EntersBattlefieldEvent, ZoneChangeEvent};
+use super::events::{
+    CardDrawnEvent, DrawCardEvent, EntersBattlefieldEvent, ZoneChangeEvent, ZoneMutationEvent,
+};
 use super::resources::ZoneManager;
-use super::types::{Zone, ZoneMarker};
+use super::types::{AttemptedDrawFromEmpty, Zone, ZoneMarker};
+use crate::game_engine::enters_choice::{ChosenOnEntry, EntersWithChoice};
 use crate::game_engine::permanent::{
     Permanent, PermanentController, PermanentOwner, PermanentState,
 };
+use crate::game_engine::state::GameEvent;
+use crate::game_engine::win_conditions::{WinGameEvent, WinsOnEmptyLibraryDraw};
+use crate::tracing::EngineSpans;
 
 /// System for handling card movement between zones
 pub fn handle_zone_changes(
@@ -22,13 +28,20 @@ pub fn handle_zone_changes(
 }
 
 /// System for handling permanents entering the battlefield
+///
+/// Permanents still waiting for a choice made as they enter are skipped; their
+/// event is sent again once the choice is made.
 pub fn handle_enters_battlefield(
     _commands: Commands,
     mut enter_events: EventReader<EntersBattlefieldEvent>,
     _turn_manager: Option<Res<crate::game_engine::turns::TurnManager>>,
+    waiting_for_choice: Query<(), (With<EntersWithChoice>, Without<ChosenOnEntry>)>,
 ) {
     // Handle "enters the battlefield" effects
     for event in enter_events.read() {
+        if waiting_for_choice.contains(event.permanent) {
+            continue;
+        }
         // A permanent has entered the battlefield
         info!(
             "Permanent {:?} entered the battlefield (owner: {:?}, tapped: {})",
@@ -67,7 +80,9 @@ pub fn process_zone_changes(
     mut zone_events: EventReader<ZoneChangeEvent>,
     mut enters_battlefield_events: EventWriter<EntersBattlefieldEvent>,
     turn_manager: Option<Res<crate::game_engine::turns::TurnManager>>,
+    spans: Option<Res<EngineSpans>>,
 ) {
+    let _span = info_span!(parent: EngineSpans::parent(spans.as_deref()), "zone_changes", events = zone_events.len()).entered();
     let current_turn = turn_manager.map(|t| t.turn_number).unwrap_or(0);
 
     for event in zone_events.read() {
@@ -79,11 +94,12 @@ pub fn process_zone_changes(
 
         // Handle entering the battlefield
         if event.destination == Zone::Battlefield {
-            // Add permanent components when a card enters the battlefield
+            // Add permanent components when a card enters the battlefield. Effects
+            // putting a permanent onto the battlefield tapped give it its state first.
             commands
                 .entity(event.card)
                 .insert(Permanent)
-                .insert(PermanentState::new(current_turn))
+                .insert_if_new(PermanentState::new(current_turn))
                 .insert(PermanentOwner::new(event.owner))
                 .insert(PermanentController::new(event.owner));
 
@@ -105,6 +121,94 @@ pub fn process_zone_changes(
     }
 }
 
+/// System that draws cards for each [`DrawCardEvent`]
+///
+/// Cards are moved from the top of the library to the hand through the
+/// [`ZoneManager`]. A player who tries to draw from an empty library is marked with
+/// [`AttemptedDrawFromEmpty`] and loses on the next state-based action check,
+/// unless they control a [`WinsOnEmptyLibraryDraw`] permanent: that draw is
+/// replaced by winning the game (rule 614).
+#[allow(clippy::too_many_arguments)]
+pub fn process_draw_card_events(
+    mut commands: Commands,
+    mut draw_events: EventReader<DrawCardEvent>,
+    mut zone_manager: ResMut<ZoneManager>,
+    empty_library_wins: Query<(Entity, &PermanentController), With<WinsOnEmptyLibraryDraw>>,
+    mut game_events: EventWriter<GameEvent>,
+    mut zone_events: EventWriter<ZoneChangeEvent>,
+    mut drawn_events: EventWriter<CardDrawnEvent>,
+    spans: Option<Res<EngineSpans>>,
+) {
+    let _span = info_span!(parent: EngineSpans::parent(spans.as_deref()), "draw_cards", events = draw_events.len()).entered();
+    for event in draw_events.read() {
+        // Players whose library isn't tracked yet can't draw, but haven't lost either
+        if !zone_manager.has_player_zones(event.player) {
+            warn!(
+                "Player {:?} has no library in the zone manager, skipping draw",
+                event.player
+            );
+            continue;
+        }
+
+        for _ in 0..event.count {
+            let Some(card) = zone_manager.draw_card(event.player) else {
+                let replacement = empty_library_wins
+                    .iter()
+                    .find(|(_, controller)| controller.player == event.player);
+                if let Some((source, _)) = replacement {
+                    info!(
+                        "Player {:?} wins the game instead of drawing from an empty library",
+                        event.player
+                    );
+                    commands.send_event(WinGameEvent {
+                        player: event.player,
+                        source,
+                    });
+                } else {
+                    info!(
+                        "Player {:?} attempted to draw from an empty library",
+                        event.player
+                    );
+                    commands.entity(event.player).insert(AttemptedDrawFromEmpty);
+                }
+                break;
+            };
+
+            game_events.write(GameEvent::CardDrawn {
+                player: event.player,
+            });
+
+            // The ZoneManager has already moved the card; the event lets other
+            // systems (markers, animations, sounds) react to the draw
+            zone_events.write(ZoneChangeEvent {
+                card,
+                owner: event.player,
+                source: Zone::Library,
+                destination: Zone::Hand,
+                was_visible: false,
+                is_visible: true,
+            });
+            drawn_events.write(CardDrawnEvent {
+                player: event.player,
+                card,
+            });
+        }
+    }
+}
+
+/// System that publishes the changes made to the [`ZoneManager`] as events
+pub fn publish_zone_mutations(
+    mut zone_manager: ResMut<ZoneManager>,
+    mut mutation_events: EventWriter<ZoneMutationEvent>,
+) {
+    if !zone_manager.has_pending_mutations() {
+        return;
+    }
+    // Draining isn't a change to the zones themselves
+    let mutations = zone_manager.bypass_change_detection().drain_mutations();
+    mutation_events.write_batch(mutations);
+}
+
 /// Register zone systems with the app
 pub fn register_zone_systems(app: &mut App) {
     app.add_systems(
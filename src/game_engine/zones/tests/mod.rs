@@ -0,0 +1,315 @@
+use bevy::prelude::*;
+
+use crate::game_engine::actions::can_play_from_zone;
+use crate::game_engine::commander::{EliminationReason, PlayerEliminatedEvent};
+use crate::game_engine::state::{
+    GameEvent, GameOverEvent, GameState, apply_game_events, state_based_actions_system,
+};
+use crate::game_engine::win_conditions::WinGameEvent;
+use crate::game_engine::zones::{
+    AttemptedDrawFromEmpty, CardDrawnEvent, CardLocation, DrawCardEvent, ExiledBy, Zone,
+    ZoneChangeEvent, ZoneManager, ZoneMutationEvent, ZonePosition, process_draw_card_events,
+    publish_zone_mutations,
+};
+use crate::player::Player;
+
+fn draw_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<DrawCardEvent>()
+        .add_event::<CardDrawnEvent>()
+        .add_event::<ZoneChangeEvent>()
+        .add_event::<PlayerEliminatedEvent>()
+        .add_event::<GameEvent>()
+        .add_event::<GameOverEvent>()
+        .add_event::<WinGameEvent>()
+        .init_resource::<ZoneManager>()
+        .init_resource::<GameState>()
+        .add_systems(
+            Update,
+            (
+                process_draw_card_events,
+                state_based_actions_system,
+                apply_game_events,
+            )
+                .chain(),
+        );
+    app
+}
+
+fn spawn_player(app: &mut App) -> Entity {
+    let player = app.world_mut().spawn(Player::new("Test Player")).id();
+    let opponent = app.world_mut().spawn(Player::new("Opponent")).id();
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .init_player_zones(player);
+    app.world_mut()
+        .resource_mut::<GameState>()
+        .set_turn_order(vec![player, opponent]);
+    player
+}
+
+#[test]
+fn test_draw_moves_top_card_to_hand() {
+    let mut app = draw_test_app();
+    let player = spawn_player(&mut app);
+    let bottom = app.world_mut().spawn_empty().id();
+    let top = app.world_mut().spawn_empty().id();
+    {
+        let mut zone_manager = app.world_mut().resource_mut::<ZoneManager>();
+        zone_manager.add_to_library(player, bottom);
+        zone_manager.add_to_library(player, top);
+    }
+
+    app.world_mut().send_event(DrawCardEvent::single(player));
+    app.update();
+
+    let zone_manager = app.world().resource::<ZoneManager>();
+    assert_eq!(zone_manager.hand(player), vec![top]);
+    assert_eq!(zone_manager.library(player), vec![bottom]);
+    assert_eq!(zone_manager.get_card_zone(top), Some(Zone::Hand));
+    assert!(
+        app.world()
+            .resource::<GameState>()
+            .drawn_this_turn
+            .contains(&player)
+    );
+}
+
+#[test]
+fn test_drawing_from_empty_library_loses_on_next_check() {
+    let mut app = draw_test_app();
+    let player = spawn_player(&mut app);
+    let card = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .add_to_library(player, card);
+
+    // Drawing two cards from a one-card library flags the player, and the state-based
+    // action check that follows eliminates them
+    app.world_mut()
+        .send_event(DrawCardEvent { player, count: 2 });
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<ZoneManager>().hand(player),
+        vec![card]
+    );
+    assert!(app.world().get::<AttemptedDrawFromEmpty>(player).is_none());
+    assert!(
+        app.world()
+            .resource::<GameState>()
+            .eliminated_players
+            .contains(&player)
+    );
+
+    let events = app.world().resource::<Events<PlayerEliminatedEvent>>();
+    let mut reader = events.get_cursor();
+    assert!(
+        reader.read(events).any(|event| event.player == player
+            && matches!(event.reason, EliminationReason::EmptyLibrary))
+    );
+}
+
+#[test]
+fn test_untracked_library_does_not_eliminate() {
+    let mut app = draw_test_app();
+    let player = app.world_mut().spawn(Player::new("Untracked")).id();
+
+    app.world_mut().send_event(DrawCardEvent::single(player));
+    app.update();
+    app.update();
+
+    assert!(app.world().get::<AttemptedDrawFromEmpty>(player).is_none());
+    assert!(
+        !app.world()
+            .resource::<GameState>()
+            .eliminated_players
+            .contains(&player)
+    );
+}
+
+fn zones_with_player(world: &mut World) -> (ZoneManager, Entity) {
+    let mut zones = ZoneManager::default();
+    let player = world.spawn_empty().id();
+    zones.init_player_zones(player);
+    (zones, player)
+}
+
+#[test]
+fn test_insert_at_position_keeps_index_in_order() {
+    let mut world = World::new();
+    let (mut zones, player) = zones_with_player(&mut world);
+    let [a, b, c, d] = [(); 4].map(|_| world.spawn_empty().id());
+
+    zones.insert(a, player, Zone::Library, ZonePosition::Top);
+    zones.insert(b, player, Zone::Library, ZonePosition::Top);
+    zones.insert(c, player, Zone::Library, ZonePosition::Bottom);
+    zones.insert(d, player, Zone::Library, ZonePosition::Index(1));
+
+    assert_eq!(zones.library(player), vec![c, d, a, b]);
+    assert_eq!(zones.top_of_library(player), Some(b));
+    for (position, card) in [c, d, a, b].into_iter().enumerate() {
+        assert_eq!(
+            zones.location(card),
+            Some(CardLocation {
+                zone: Zone::Library,
+                owner: player,
+                position,
+            })
+        );
+    }
+
+    zones.remove(d);
+    assert_eq!(zones.library(player), vec![c, a, b]);
+    assert_eq!(zones.location(a).unwrap().position, 1);
+    assert_eq!(zones.location(d), None);
+}
+
+#[test]
+fn test_move_card_checks_source_and_keeps_owner() {
+    let mut world = World::new();
+    let (mut zones, player) = zones_with_player(&mut world);
+    let card = world.spawn_empty().id();
+    zones.add_to_hand(player, card);
+
+    assert!(!zones.move_card(card, player, Zone::Library, Zone::Graveyard));
+    assert!(zones.move_card(card, player, Zone::Hand, Zone::Battlefield));
+    assert!(zones.hand(player).is_empty());
+    assert_eq!(zones.battlefield(), vec![card]);
+    assert_eq!(zones.get_card_owner(card), Some(player));
+
+    assert!(zones.move_to(card, Zone::Graveyard, ZonePosition::Bottom));
+    assert_eq!(zones.graveyard(player), vec![card]);
+    assert!(zones.battlefield().is_empty());
+    assert_eq!(zones.card_count(), 1);
+}
+
+#[test]
+fn test_player_zones_need_setup() {
+    let mut world = World::new();
+    let mut zones = ZoneManager::default();
+    let stranger = world.spawn_empty().id();
+    let card = world.spawn_empty().id();
+
+    assert!(!zones.insert(card, stranger, Zone::Hand, ZonePosition::Top));
+    assert_eq!(zones.location(card), None);
+    assert!(!zones.has_pending_mutations());
+
+    // Shared zones take any owner
+    assert!(zones.insert(card, Entity::PLACEHOLDER, Zone::Exile, ZonePosition::Top));
+    assert_eq!(zones.get_card_zone(card), Some(Zone::Exile));
+    assert_eq!(zones.get_card_owner(card), None);
+}
+
+#[test]
+fn test_mutations_are_published_as_events() {
+    let mut app = App::new();
+    app.add_event::<ZoneMutationEvent>()
+        .init_resource::<ZoneManager>()
+        .add_systems(Update, publish_zone_mutations);
+
+    let player = app.world_mut().spawn_empty().id();
+    let card = app.world_mut().spawn_empty().id();
+    {
+        let mut zones = app.world_mut().resource_mut::<ZoneManager>();
+        zones.init_player_zones(player);
+        zones.add_to_library(player, card);
+        zones.draw_card(player);
+    }
+    app.update();
+
+    let library = CardLocation {
+        zone: Zone::Library,
+        owner: player,
+        position: 0,
+    };
+    let hand = CardLocation {
+        zone: Zone::Hand,
+        ..library
+    };
+    let events = app.world().resource::<Events<ZoneMutationEvent>>();
+    let published: Vec<ZoneMutationEvent> = events.get_cursor().read(events).cloned().collect();
+    assert_eq!(
+        published,
+        vec![
+            ZoneMutationEvent::Inserted {
+                card,
+                location: library
+            },
+            ZoneMutationEvent::Removed {
+                card,
+                location: library
+            },
+            ZoneMutationEvent::Inserted {
+                card,
+                location: hand
+            },
+        ]
+    );
+    assert!(
+        !app.world()
+            .resource::<ZoneManager>()
+            .has_pending_mutations()
+    );
+}
+
+#[test]
+fn test_shuffling_a_library_records_a_single_mutation() {
+    let mut world = World::new();
+    let player = world.spawn_empty().id();
+    let cards: Vec<Entity> = (0..20).map(|_| world.spawn_empty().id()).collect();
+    let mut zones = ZoneManager::default();
+    zones.init_player_zones(player);
+    for card in &cards {
+        zones.add_to_library(player, *card);
+    }
+    zones.drain_mutations();
+
+    zones.shuffle_library(player, &mut rand::rng());
+
+    let shuffled = zones.library(player).to_vec();
+    assert_eq!(
+        zones.drain_mutations(),
+        vec![ZoneMutationEvent::Shuffled {
+            owner: player,
+            cards: shuffled.clone(),
+        }]
+    );
+    let mut sorted = shuffled;
+    sorted.sort();
+    assert_eq!(sorted, cards);
+    assert!(zones.consistency_errors().is_empty());
+}
+
+#[test]
+fn test_exiled_cards_remember_how_they_can_be_played() {
+    let mut world = World::new();
+    let [player, opponent, source, impulse, foretold, removed] =
+        [(); 6].map(|_| world.spawn_empty().id());
+    let mut zones = ZoneManager::default();
+    zones.init_player_zones(player);
+    zones.init_player_zones(opponent);
+    zones.exile_with(player, impulse, ExiledBy::impulse(source, 3));
+    zones.exile_with(player, foretold, ExiledBy::foretold(source, 3));
+    zones.add_to_exile(player, removed);
+
+    assert_eq!(
+        zones.playable_from_exile(player, 3).collect::<Vec<_>>(),
+        vec![impulse]
+    );
+    assert_eq!(
+        zones.playable_from_exile(player, 4).collect::<Vec<_>>(),
+        vec![foretold]
+    );
+    assert!(can_play_from_zone(&zones, player, impulse, 3));
+    assert!(!can_play_from_zone(&zones, opponent, impulse, 3));
+    assert!(!can_play_from_zone(&zones, player, removed, 3));
+
+    // Leaving exile forgets why the card was there
+    zones.add_to_hand(player, impulse);
+    assert_eq!(zones.exiled_by(impulse), None);
+    zones.add_to_exile(player, impulse);
+    assert!(!can_play_from_zone(&zones, player, impulse, 3));
+    assert_eq!(zones.exiled_with_memory(player).count(), 1);
+}
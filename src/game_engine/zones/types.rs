@@ -24,3 +24,82 @@ pub struct ZoneMarker {
     /// The owner of the zone (if applicable)
     pub owner: Option<Entity>,
 }
+
+/// Marks a player who tried to draw from an empty library
+///
+/// The player loses the next time state-based actions are checked.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct AttemptedDrawFromEmpty;
+
+/// How long a card exiled by an effect may be played
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayUntil {
+    /// Until the end of this turn number, as with "you may play it this turn"
+    EndOfTurn(u32),
+    /// On any later turn than the one it was exiled on, as with foretell
+    LaterTurns { exiled_on: u32 },
+    /// For as long as it stays exiled, as with an adventure
+    Indefinitely,
+}
+
+/// Why a card is in exile, for effects that remember the cards they exiled
+///
+/// Kept by the [`ZoneManager`](super::ZoneManager) while the card stays exiled.
+/// Only the card's owner may play it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExiledBy {
+    /// The card whose effect exiled it
+    pub source: Entity,
+    /// How long the owner may play it, `None` if it can't be played from exile
+    pub may_play_until: Option<PlayUntil>,
+    /// Whether it's exiled face down, hidden from everyone but its owner
+    pub face_down: bool,
+}
+
+impl ExiledBy {
+    /// Exiled from the top of a library and playable this turn only
+    pub fn impulse(source: Entity, turn: u32) -> Self {
+        Self {
+            source,
+            may_play_until: Some(PlayUntil::EndOfTurn(turn)),
+            face_down: false,
+        }
+    }
+
+    /// An adventure that resolved, castable as its creature later on
+    pub fn adventure(source: Entity) -> Self {
+        Self {
+            source,
+            may_play_until: Some(PlayUntil::Indefinitely),
+            face_down: false,
+        }
+    }
+
+    /// Foretold face down, castable from the next turn on
+    pub fn foretold(source: Entity, turn: u32) -> Self {
+        Self {
+            source,
+            may_play_until: Some(PlayUntil::LaterTurns { exiled_on: turn }),
+            face_down: true,
+        }
+    }
+
+    /// Whether the owner may play the card on `turn`
+    pub fn may_play(&self, turn: u32) -> bool {
+        match self.may_play_until {
+            Some(PlayUntil::EndOfTurn(last)) => turn <= last,
+            Some(PlayUntil::LaterTurns { exiled_on }) => turn > exiled_on,
+            Some(PlayUntil::Indefinitely) => true,
+            None => false,
+        }
+    }
+
+    /// Whether the owner may play the card on `turn` or a later turn
+    pub fn still_playable(&self, turn: u32) -> bool {
+        match self.may_play_until {
+            Some(PlayUntil::EndOfTurn(last)) => turn <= last,
+            Some(PlayUntil::LaterTurns { .. } | PlayUntil::Indefinitely) => true,
+            None => false,
+        }
+    }
+}
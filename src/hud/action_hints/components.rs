@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+/// A legal action a card is highlighted for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActionHint {
+    /// A card in hand can be played or cast
+    Play,
+    /// A permanent has an ability that can be activated
+    Activate,
+    /// A creature can be declared as an attacker
+    Attack,
+}
+
+impl ActionHint {
+    /// Colour of the glow, at full strength
+    pub fn color(self) -> Color {
+        match self {
+            ActionHint::Play => Color::srgb(0.3, 0.85, 1.0),
+            ActionHint::Activate => Color::srgb(0.55, 1.0, 0.4),
+            ActionHint::Attack => Color::srgb(1.0, 0.45, 0.25),
+        }
+    }
+}
+
+/// Pulsing glow drawn behind a card with a legal action
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ActionHintGlow {
+    pub card: Entity,
+    pub hint: ActionHint,
+}
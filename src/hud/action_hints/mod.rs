@@ -0,0 +1,17 @@
+//! Highlights for legal actions
+//!
+//! Cards the local player could play from hand, permanents with an ability they
+//! could activate, and creatures that could attack pulse with a coloured glow.
+//! Hints follow the priority and phase state and can be turned off in the
+//! gameplay settings.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{ActionHint, ActionHintGlow};
+pub use plugin::ActionHintsPlugin;
+pub use systems::HintContext;
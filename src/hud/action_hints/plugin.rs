@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use super::systems::{despawn_action_hints, pulse_action_hints, update_action_hints};
+use crate::game_engine::zones::ZoneManager;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the legal action highlights
+pub struct ActionHintsPlugin;
+
+impl Plugin for ActionHintsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMenuState::InGame), despawn_action_hints)
+            .add_systems(
+                Update,
+                (update_action_hints, pulse_action_hints)
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame).and(resource_exists::<ZoneManager>)),
+            );
+    }
+}
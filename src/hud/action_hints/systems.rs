@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use super::components::{ActionHint, ActionHintGlow};
+use crate::camera::components::AppLayer;
+use crate::cards::abilities::ActivatedAbility;
+use crate::cards::keywords::KeywordAbility;
+use crate::cards::{Card, CardTypes};
+use crate::game_engine::actions::{
+    AbilityRequirements, can_pay_mana, can_tap_now, is_instant_cast, text_abilities,
+    valid_time_for_sorcery, valid_time_to_play_land,
+};
+use crate::game_engine::combat;
+use crate::game_engine::permanent::{PermanentController, PermanentState};
+use crate::game_engine::phase::CombatStep;
+use crate::game_engine::zones::{Zone, ZoneManager};
+use crate::game_engine::{GameStack, GameState, Phase, PrioritySystem};
+use crate::menu::settings::components::GameplaySettings;
+use crate::player::Player;
+use crate::player::resources::PlayerConfig;
+use crate::tracing::CardUpdateCounts;
+
+/// How fast hint glows pulse, in radians per second
+const PULSE_SPEED: f32 = 4.0;
+
+/// The game state legal actions are worked out from, for one player
+pub struct HintContext<'a> {
+    pub player: Entity,
+    pub game_state: &'a GameState,
+    pub phase: &'a Phase,
+    pub stack: &'a GameStack,
+    pub priority: &'a PrioritySystem,
+}
+
+impl HintContext<'_> {
+    fn has_priority(&self) -> bool {
+        self.priority.has_priority(self.player)
+    }
+
+    fn has_sorcery_timing(&self) -> bool {
+        valid_time_for_sorcery(self.game_state, self.phase, self.stack, self.player)
+    }
+
+    /// Whether a card in the player's hand can be played or cast now
+    pub fn can_play(&self, card: &Card, player: &Player) -> bool {
+        if card.type_info.types.contains(CardTypes::LAND) {
+            return valid_time_to_play_land(self.game_state, self.phase, self.player)
+                && self.game_state.can_play_land(self.player);
+        }
+
+        let instant_speed =
+            is_instant_cast(&card.type_info) || Card::has_keyword(card, KeywordAbility::Flash);
+        self.has_priority()
+            && (instant_speed || self.has_sorcery_timing())
+            && can_pay_mana(player, &card.cost.cost)
+    }
+
+    /// Whether a creature the player controls can be declared as an attacker now
+    pub fn can_attack(&self, card: &Card, state: &PermanentState) -> bool {
+        *self.phase == Phase::Combat(CombatStep::DeclareAttackers)
+            && self.game_state.active_player == self.player
+            && combat::can_attack(card, state)
+    }
+
+    /// Whether one of a permanent's activated abilities can be activated now
+    pub fn can_activate(
+        &self,
+        card: &Card,
+        state: &PermanentState,
+        ability: Option<&ActivatedAbility>,
+        player: &Player,
+    ) -> bool {
+        if !self.has_priority() {
+            return false;
+        }
+        let usable = |requirements: AbilityRequirements| {
+            (!requirements.taps || can_tap_now(card, state))
+                && (!requirements.sorcery_speed || self.has_sorcery_timing())
+        };
+
+        let component = ability.is_some_and(|ability| {
+            usable(AbilityRequirements {
+                taps: ability.tap_cost,
+                sorcery_speed: !ability.instant_speed,
+            }) && ability
+                .mana_cost
+                .as_ref()
+                .is_none_or(|cost| can_pay_mana(player, cost))
+        });
+        component || text_abilities(&card.rules_text.rules_text).any(usable)
+    }
+
+    /// The action to highlight a card for, if any
+    pub fn action_hint(
+        &self,
+        card: &Card,
+        zone: Zone,
+        state: Option<&PermanentState>,
+        ability: Option<&ActivatedAbility>,
+        player: &Player,
+    ) -> Option<ActionHint> {
+        match (zone, state) {
+            (Zone::Hand, _) => self.can_play(card, player).then_some(ActionHint::Play),
+            (Zone::Battlefield, Some(state)) => {
+                if self.can_attack(card, state) {
+                    Some(ActionHint::Attack)
+                } else if self.can_activate(card, state, ability, player) {
+                    Some(ActionHint::Activate)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Work out which of the local player's cards have a legal action and keep a
+/// glow behind each of them
+///
+/// Hints only change with the game, so they are worked out again only when the
+/// zones, turn, stack, priority, settings, players or permanents change.
+#[allow(clippy::too_many_arguments)]
+pub fn update_action_hints(
+    mut commands: Commands,
+    settings: Option<Res<GameplaySettings>>,
+    zones: Res<ZoneManager>,
+    game_state: Res<GameState>,
+    phase: Res<Phase>,
+    stack: Res<GameStack>,
+    priority: Res<PrioritySystem>,
+    players: Query<(Entity, &Player)>,
+    cards: Query<(
+        &Card,
+        Option<&PermanentState>,
+        Option<&PermanentController>,
+        Option<&ActivatedAbility>,
+    )>,
+    mut glows: Query<(Entity, &mut ActionHintGlow)>,
+    player_config: Option<Res<PlayerConfig>>,
+    changed_cards: Query<
+        (),
+        Or<(
+            Added<Card>,
+            Changed<PermanentState>,
+            Changed<PermanentController>,
+            Changed<ActivatedAbility>,
+        )>,
+    >,
+    changed_players: Query<(), Changed<Player>>,
+    mut removed_glows: RemovedComponents<ActionHintGlow>,
+    counts: Option<ResMut<CardUpdateCounts>>,
+) {
+    let glows_removed = removed_glows.read().count() > 0;
+    let changed = zones.is_changed()
+        || game_state.is_changed()
+        || phase.is_changed()
+        || stack.is_changed()
+        || priority.is_changed()
+        || settings
+            .as_ref()
+            .is_some_and(|settings| settings.is_changed())
+        || !changed_cards.is_empty()
+        || !changed_players.is_empty()
+        || glows_removed;
+    if !changed {
+        return;
+    }
+
+    let mut hints = HashMap::new();
+    let mut evaluated = 0;
+    let enabled = settings.is_none_or(|settings| settings.show_action_hints);
+    let local = players.iter().find(|(_, player)| player.player_index == 0);
+
+    if let Some((local, player)) = local.filter(|_| enabled) {
+        let context = HintContext {
+            player: local,
+            game_state: &game_state,
+            phase: &phase,
+            stack: &stack,
+            priority: &priority,
+        };
+
+        let hand = zones.hand(local).iter().map(|card| (*card, Zone::Hand));
+        let battlefield = zones
+            .battlefield()
+            .iter()
+            .map(|card| (*card, Zone::Battlefield));
+        for (entity, zone) in hand.chain(battlefield) {
+            let Ok((card, state, controller, ability)) = cards.get(entity) else {
+                continue;
+            };
+            evaluated += 1;
+            let controlled = match controller {
+                Some(controller) => controller.player == local,
+                None => zones.get_card_owner(entity) == Some(local),
+            };
+            if !controlled {
+                continue;
+            }
+            if let Some(hint) = context.action_hint(card, zone, state, ability, player) {
+                hints.insert(entity, hint);
+            }
+        }
+    }
+
+    for (entity, mut glow) in glows.iter_mut() {
+        match hints.remove(&glow.card) {
+            Some(hint) => {
+                if glow.hint != hint {
+                    glow.hint = hint;
+                }
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+
+    if let Some(mut counts) = counts.filter(|_| evaluated > 0) {
+        counts.hints += evaluated;
+    }
+
+    let card_size = player_config.map_or(Vec2::new(63.0, 88.0), |config| config.card_size);
+    for (card, hint) in hints {
+        commands.spawn((
+            Sprite {
+                color: hint.color(),
+                custom_size: Some(card_size * 2.5 * 1.08),
+                ..default()
+            },
+            Transform::default(),
+            ActionHintGlow { card, hint },
+            AppLayer::Cards.layer(),
+            Name::new("Action Hint"),
+        ));
+    }
+}
+
+/// Keep each glow just behind its card and fade it in and out
+///
+/// Glows only follow their card when it moved.
+pub fn pulse_action_hints(
+    time: Res<Time>,
+    mut glows: Query<(Ref<ActionHintGlow>, &mut Sprite, &mut Transform)>,
+    cards: Query<Ref<GlobalTransform>, With<Card>>,
+    counts: Option<ResMut<CardUpdateCounts>>,
+) {
+    let alpha = 0.45 + 0.25 * (time.elapsed_secs() * PULSE_SPEED).sin();
+    let mut moved = 0;
+    for (glow, mut sprite, mut transform) in glows.iter_mut() {
+        if let Ok(card) = cards.get(glow.card) {
+            if glow.is_added() || card.is_changed() {
+                transform.translation = card.translation() - Vec3::Z * 0.5;
+                transform.rotation = card.rotation();
+                moved += 1;
+            }
+        }
+        sprite.color = glow.hint.color().with_alpha(alpha);
+    }
+
+    if let Some(mut counts) = counts.filter(|_| moved > 0) {
+        counts.visuals += moved;
+    }
+}
+
+/// Remove every glow when leaving the game
+pub fn despawn_action_hints(mut commands: Commands, glows: Query<Entity, With<ActionHintGlow>>) {
+    for glow in glows.iter() {
+        commands.entity(glow).despawn();
+    }
+}
@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+
+use crate::cards::abilities::ActivatedAbility;
+use crate::cards::{Card, CardDetails, CardTypes, CreatureCard, CreatureType};
+use crate::game_engine::permanent::PermanentState;
+use crate::game_engine::phase::{CombatStep, PrecombatStep};
+use crate::game_engine::priority::PrioritySystemBuilder;
+use crate::game_engine::zones::Zone;
+use crate::game_engine::{GameStack, GameState, Phase};
+use crate::hud::action_hints::{ActionHint, HintContext};
+use crate::mana::Mana;
+use crate::player::Player;
+
+fn card(name: &str, types: CardTypes, rules_text: &str) -> Card {
+    let details = if types.contains(CardTypes::CREATURE) {
+        CardDetails::Creature(CreatureCard {
+            power: 2,
+            toughness: 2,
+            creature_type: CreatureType::NONE,
+        })
+    } else {
+        CardDetails::Other
+    };
+    Card::builder(name)
+        .cost(Mana::default())
+        .types(types)
+        .details(details)
+        .rules_text(rules_text)
+        .build_or_panic()
+}
+
+fn untapped(turn_entered: u32, current_turn: u32) -> PermanentState {
+    let mut state = PermanentState::new(turn_entered);
+    state.update_summoning_sickness(current_turn);
+    state
+}
+
+struct Table {
+    player: Entity,
+    opponent: Entity,
+    stack: GameStack,
+}
+
+impl Table {
+    fn new() -> Self {
+        let mut world = World::new();
+        Self {
+            player: world.spawn_empty().id(),
+            opponent: world.spawn_empty().id(),
+            stack: GameStack::default(),
+        }
+    }
+
+    /// Check `check` on the player's turn, in `phase`, with priority held by
+    /// `priority_player`
+    fn check<R>(
+        &self,
+        phase: Phase,
+        priority_player: Entity,
+        check: impl FnOnce(&HintContext) -> R,
+    ) -> R {
+        let game_state = GameState::builder()
+            .active_player(self.player)
+            .turn_number(2)
+            .build();
+        let priority = PrioritySystemBuilder::new()
+            .active_player(self.player)
+            .priority_player(priority_player)
+            .build();
+        check(&HintContext {
+            player: self.player,
+            game_state: &game_state,
+            phase: &phase,
+            stack: &self.stack,
+            priority: &priority,
+        })
+    }
+}
+
+const MAIN: Phase = Phase::Precombat(PrecombatStep::Main);
+const ATTACKERS: Phase = Phase::Combat(CombatStep::DeclareAttackers);
+
+#[test]
+fn test_hand_cards_follow_timing() {
+    let table = Table::new();
+    let player = Player::new("Player");
+    let forest = card("Forest", CardTypes::BASIC | CardTypes::LAND, "");
+    let sorcery = card("Divination", CardTypes::SORCERY, "Draw two cards.");
+    let instant = card(
+        "Shock",
+        CardTypes::INSTANT,
+        "Shock deals 2 damage to any target.",
+    );
+
+    let hint = |context: &HintContext, card: &Card| {
+        context.action_hint(card, Zone::Hand, None, None, &player)
+    };
+
+    table.check(MAIN, table.player, |context| {
+        assert_eq!(hint(context, &forest), Some(ActionHint::Play));
+        assert_eq!(hint(context, &sorcery), Some(ActionHint::Play));
+        assert_eq!(hint(context, &instant), Some(ActionHint::Play));
+    });
+    // Only instants can be cast in combat
+    table.check(ATTACKERS, table.player, |context| {
+        assert_eq!(hint(context, &forest), None);
+        assert_eq!(hint(context, &sorcery), None);
+        assert_eq!(hint(context, &instant), Some(ActionHint::Play));
+    });
+    // Nothing is castable without priority
+    table.check(MAIN, table.opponent, |context| {
+        assert_eq!(hint(context, &instant), None);
+    });
+}
+
+#[test]
+fn test_attackers_and_abilities_are_hinted() {
+    let table = Table::new();
+    let player = Player::new("Player");
+    let bears = card("Grizzly Bears", CardTypes::CREATURE, "");
+    let raider = card("Raging Goblin", CardTypes::CREATURE, "Haste");
+    let prodigy = card(
+        "Prodigal Sorcerer",
+        CardTypes::CREATURE,
+        "{T}: Prodigal Sorcerer deals 1 damage to any target.",
+    );
+    let elves = card("Llanowar Elves", CardTypes::CREATURE, "{T}: Add {G}.");
+
+    let hint = |context: &HintContext, card: &Card, state: &PermanentState| {
+        context.action_hint(card, Zone::Battlefield, Some(state), None, &player)
+    };
+
+    let ready = untapped(1, 2);
+    let sick = untapped(2, 2);
+    table.check(ATTACKERS, table.player, |context| {
+        assert_eq!(hint(context, &bears, &ready), Some(ActionHint::Attack));
+        assert_eq!(hint(context, &bears, &sick), None);
+        assert_eq!(hint(context, &raider, &sick), Some(ActionHint::Attack));
+    });
+    table.check(MAIN, table.player, |context| {
+        assert_eq!(hint(context, &bears, &ready), None);
+        assert_eq!(hint(context, &prodigy, &ready), Some(ActionHint::Activate));
+        // Summoning sick creatures can't pay {T}, and mana abilities aren't hinted
+        assert_eq!(hint(context, &prodigy, &sick), None);
+        assert_eq!(hint(context, &elves, &ready), None);
+
+        let ability = ActivatedAbility::with_mana_cost(Mana::default(), "Draw a card");
+        assert!(context.can_activate(&bears, &sick, Some(&ability), &player));
+    });
+    // Sorcery speed abilities wait for the main phase
+    table.check(ATTACKERS, table.player, |context| {
+        let ability = ActivatedAbility::with_mana_cost(Mana::default(), "Draw a card");
+        assert!(!context.can_activate(&bears, &sick, Some(&ability), &player));
+    });
+}
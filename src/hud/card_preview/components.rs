@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+
+use crate::cards::keywords::KeywordAbility;
+
+/// Tabs of the card preview
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CardPreviewTab {
+    /// Name, cost, type line and rules text
+    #[default]
+    Details,
+    /// Official rulings
+    Rulings,
+}
+
+impl CardPreviewTab {
+    pub const ALL: [CardPreviewTab; 2] = [CardPreviewTab::Details, CardPreviewTab::Rulings];
+
+    /// Label shown on the tab button
+    pub fn label(self) -> &'static str {
+        match self {
+            CardPreviewTab::Details => "Details",
+            CardPreviewTab::Rulings => "Rulings",
+        }
+    }
+}
+
+/// The card shown in the preview and the open tab
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CardPreview {
+    pub card: Option<Entity>,
+    pub tab: CardPreviewTab,
+}
+
+/// Root node of the card preview
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CardPreviewRoot;
+
+/// Button switching the preview to a tab
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardPreviewTabButton(pub CardPreviewTab);
+
+/// A keyword on the previewed card, hovered to show its reminder text
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeywordChip(pub KeywordAbility);
+
+/// Root node of the keyword reminder tooltip
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct KeywordTooltipRoot {
+    /// The reminders shown
+    pub lines: Vec<String>,
+}
+
+/// Which keyword reminders are shown
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeywordTooltip {
+    /// The keyword chip under the cursor
+    pub hovered: Option<KeywordAbility>,
+    /// Show the reminders for every keyword on the previewed card
+    pub show_all: bool,
+}
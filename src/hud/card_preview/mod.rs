@@ -0,0 +1,24 @@
+//! Preview of the card under the cursor
+//!
+//! The last hovered card stays in the preview, with a details tab showing its
+//! oracle text and a rulings tab showing official rulings. Rulings are read from
+//! the [`CardIndex`](crate::cards::mtgjson::index::CardIndex) the first time a
+//! card's rulings tab is opened, so only rulings someone looked at are kept.
+//!
+//! The details tab lists the card's keywords; hovering one shows its reminder
+//! text from the [keyword glossary](crate::cards::keywords::reminder_text), and
+//! [`KEYWORD_REMINDERS_KEY`] shows the reminders for all of them at once.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{
+    CardPreview, CardPreviewRoot, CardPreviewTab, CardPreviewTabButton, KeywordChip,
+    KeywordTooltip, KeywordTooltipRoot,
+};
+pub use plugin::CardPreviewPlugin;
+pub use systems::{KEYWORD_REMINDERS_KEY, card_at, glossary_keywords, tooltip_lines};
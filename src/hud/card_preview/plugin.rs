@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+use super::components::{CardPreview, KeywordTooltip};
+use super::systems::{
+    despawn_card_preview, handle_preview_tab_clicks, load_preview_rulings, sync_card_preview,
+    sync_keyword_tooltip, update_hovered_card, update_keyword_tooltip,
+};
+use crate::cards::CardDatabase;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the hovered card preview
+pub struct CardPreviewPlugin;
+
+impl Plugin for CardPreviewPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CardPreview>()
+            .init_resource::<KeywordTooltip>()
+            .add_systems(OnExit(GameMenuState::InGame), despawn_card_preview)
+            .add_systems(
+                Update,
+                (
+                    update_hovered_card,
+                    handle_preview_tab_clicks,
+                    load_preview_rulings.run_if(resource_changed::<CardPreview>),
+                    sync_card_preview.run_if(
+                        resource_changed::<CardPreview>.or(resource_changed::<CardDatabase>),
+                    ),
+                    update_keyword_tooltip,
+                    sync_keyword_tooltip,
+                )
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame).and(resource_exists::<CardDatabase>)),
+            );
+    }
+}
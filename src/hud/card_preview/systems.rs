@@ -0,0 +1,480 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::components::{
+    CardPreview, CardPreviewRoot, CardPreviewTab, CardPreviewTabButton, KeywordChip,
+    KeywordTooltip, KeywordTooltipRoot,
+};
+use crate::camera::components::{AppLayer, GameCamera};
+use crate::cards::keywords::{KeywordAbility, keyword_name, reminder_text};
+use crate::cards::mtgjson::index::CardIndex;
+use crate::cards::{Card, CardDatabase, CardId, CardTypes, format_type_line};
+use crate::game_engine::costs::{CostModifier, spell_cost};
+use crate::game_engine::face_down::FaceDown;
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::zones::{Zone, ZoneManager};
+use crate::mana::Mana;
+use crate::menu::input_blocker::InteractionBlockState;
+use crate::player::Player;
+use crate::player::resources::PlayerConfig;
+
+const PANEL_COLOR: Color = Color::srgba(0.05, 0.05, 0.08, 0.92);
+const TAB_COLOR: Color = Color::srgba(0.22, 0.22, 0.26, 0.95);
+const SELECTED_TAB_COLOR: Color = Color::srgba(0.3, 0.4, 0.65, 0.95);
+const KEYWORD_COLOR: Color = Color::srgba(0.25, 0.3, 0.22, 0.95);
+
+/// Key showing the reminder text for every keyword on the previewed card
+pub const KEYWORD_REMINDERS_KEY: KeyCode = KeyCode::KeyK;
+
+/// The topmost card whose bounds contain `world_pos`
+///
+/// `card_size` is the base card size from [`PlayerConfig`]; cards are drawn 2.5
+/// times larger, as in [`drag_cards_at`](crate::cards::systems::drag_cards_at).
+pub fn card_at(
+    world_pos: Vec2,
+    card_size: Vec2,
+    cards: impl IntoIterator<Item = (Entity, Vec3)>,
+) -> Option<Entity> {
+    let half_size = card_size * 2.5 / 2.0;
+    cards
+        .into_iter()
+        .filter(|(_, position)| {
+            let offset = (world_pos - position.truncate()).abs();
+            offset.x <= half_size.x && offset.y <= half_size.y
+        })
+        .max_by(|(_, a), (_, b)| a.z.total_cmp(&b.z))
+        .map(|(entity, _)| entity)
+}
+
+/// Preview the card under the cursor
+///
+/// The preview stays on the last hovered card so the cursor can move onto the
+/// panel, and is cleared once that card leaves the table.
+pub fn update_hovered_card(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_q: Query<(&Camera, &GlobalTransform), With<GameCamera>>,
+    cards: Query<(Entity, &GlobalTransform), With<Card>>,
+    player_config: Res<PlayerConfig>,
+    interaction_block: Res<InteractionBlockState>,
+    mut preview: ResMut<CardPreview>,
+) {
+    if preview.card.is_some_and(|card| !cards.contains(card)) {
+        preview.card = None;
+    }
+
+    if interaction_block.should_block {
+        return;
+    }
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = camera_q.single() else {
+        return;
+    };
+    let Some(world_pos) = window.cursor_position().and_then(|cursor_pos| {
+        camera
+            .viewport_to_world_2d(camera_transform, cursor_pos)
+            .ok()
+    }) else {
+        return;
+    };
+
+    let hovered = card_at(
+        world_pos,
+        player_config.card_size,
+        cards
+            .iter()
+            .map(|(entity, transform)| (entity, transform.translation())),
+    );
+    if let Some(card) = hovered {
+        preview.set_if_neq(CardPreview {
+            card: Some(card),
+            ..*preview
+        });
+    }
+}
+
+/// Switch tabs when a tab button is clicked
+pub fn handle_preview_tab_clicks(
+    buttons: Query<(&Interaction, &CardPreviewTabButton), Changed<Interaction>>,
+    mut preview: ResMut<CardPreview>,
+) {
+    for (interaction, CardPreviewTabButton(tab)) in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            preview.set_if_neq(CardPreview {
+                tab: *tab,
+                ..*preview
+            });
+        }
+    }
+}
+
+/// Read the previewed card's rulings from the card index the first time they are shown
+pub fn load_preview_rulings(
+    preview: Res<CardPreview>,
+    card_ids: Query<&CardId>,
+    index: Option<Res<CardIndex>>,
+    mut database: ResMut<CardDatabase>,
+) {
+    if preview.tab != CardPreviewTab::Rulings {
+        return;
+    }
+    let (Some(card), Some(index)) = (preview.card, index) else {
+        return;
+    };
+    let Ok(&id) = card_ids.get(card) else {
+        return;
+    };
+    let Some(definition) = database.get(id) else {
+        return;
+    };
+    if definition.rulings.is_some() {
+        return;
+    }
+
+    let name = definition.card.name.name.clone();
+    match index.rulings(&name) {
+        Ok(rulings) => {
+            database.set_rulings(id, rulings);
+        }
+        Err(e) => warn!("Failed to read rulings for {}: {}", name, e),
+    }
+}
+
+/// The cost line of the details tab, showing the printed cost when modifiers change it
+pub fn cost_line(printed: &Mana, cost: &Mana) -> String {
+    if cost == printed {
+        printed.to_string()
+    } else {
+        format!("{} (printed {})", cost, printed)
+    }
+}
+
+/// What the previewed card costs its owner to cast, if it's in their hand
+fn cost_to_cast(
+    entity: Entity,
+    card: &Card,
+    zones: Option<&ZoneManager>,
+    cost_modifiers: &Query<(&CostModifier, &PermanentController)>,
+) -> Option<Mana> {
+    let zones = zones?;
+    if zones.get_card_zone(entity) != Some(Zone::Hand)
+        || card.type_info.types.contains(CardTypes::LAND)
+    {
+        return None;
+    }
+    let owner = zones.get_card_owner(entity)?;
+    Some(spell_cost(
+        &card.cost.cost,
+        card.type_info.types,
+        owner,
+        cost_modifiers
+            .iter()
+            .map(|(modifier, controller)| (modifier, controller.player)),
+    ))
+}
+
+/// The card's keywords that have reminder text, sorted by name
+pub fn glossary_keywords(card: &Card) -> Vec<KeywordAbility> {
+    let mut keywords: Vec<KeywordAbility> = card
+        .keywords
+        .keywords
+        .abilities
+        .iter()
+        .copied()
+        .filter(|&keyword| reminder_text(keyword).is_some())
+        .collect();
+    keywords.sort_by_key(|&keyword| keyword_name(keyword));
+    keywords
+}
+
+/// The reminders shown for the previewed card's `keywords`
+///
+/// Every keyword's reminder while they're all shown, otherwise only the hovered
+/// keyword's.
+pub fn tooltip_lines(keywords: &[KeywordAbility], tooltip: &KeywordTooltip) -> Vec<String> {
+    keywords
+        .iter()
+        .filter(|&&keyword| tooltip.show_all || tooltip.hovered == Some(keyword))
+        .filter_map(|&keyword| {
+            reminder_text(keyword).map(|text| format!("{}: {}", keyword_name(keyword), text))
+        })
+        .collect()
+}
+
+/// Rebuild the preview panel when the previewed card or the open tab changes
+///
+/// Cards in hand show what they cost to cast once cost modifiers are applied.
+/// Face-down permanents show their face only to the local player controlling
+/// them; everyone else sees a nameless 2/2 without rulings.
+#[allow(clippy::too_many_arguments)]
+pub fn sync_card_preview(
+    mut commands: Commands,
+    preview: Res<CardPreview>,
+    database: Res<CardDatabase>,
+    zones: Option<Res<ZoneManager>>,
+    cards: Query<(
+        &Card,
+        Option<&CardId>,
+        Option<&FaceDown>,
+        Option<&PermanentController>,
+    )>,
+    players: Query<(Entity, &Player)>,
+    cost_modifiers: Query<(&CostModifier, &PermanentController)>,
+    roots: Query<Entity, With<CardPreviewRoot>>,
+) {
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+
+    let Some((entity, (card, id, face_down, controller))) = preview
+        .card
+        .and_then(|entity| cards.get(entity).ok().map(|card| (entity, card)))
+    else {
+        return;
+    };
+    let (card, id) = match face_down {
+        Some(face_down) => {
+            let local_player = players
+                .iter()
+                .find(|(_, player)| player.player_index == 0)
+                .map(|(entity, _)| entity);
+            match local_player
+                .zip(controller)
+                .and_then(|(viewer, controller)| face_down.face_for(viewer, controller.player))
+            {
+                Some(face) => (face, id),
+                None => (card, None),
+            }
+        }
+        None => (card, id),
+    };
+    let keywords = glossary_keywords(card);
+    let mut lines = Vec::new();
+    match preview.tab {
+        CardPreviewTab::Details => {
+            let cost = cost_to_cast(entity, card, zones.as_deref(), &cost_modifiers)
+                .unwrap_or(card.cost.cost);
+            lines.push(cost_line(&card.cost.cost, &cost));
+            lines.push(format_type_line(
+                &card.type_info.types,
+                &card.details.details,
+            ));
+            if !card.rules_text.rules_text.is_empty() {
+                lines.push(card.rules_text.rules_text.clone());
+            }
+        }
+        CardPreviewTab::Rulings => match id.and_then(|&id| database.rulings(id)) {
+            Some([]) => lines.push("No rulings".to_string()),
+            Some(rulings) => lines.extend(
+                rulings
+                    .iter()
+                    .map(|ruling| format!("{}: {}", ruling.date, ruling.text)),
+            ),
+            None => lines.push("Rulings are not available for this card".to_string()),
+        },
+    }
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                right: Val::Px(16.0),
+                width: Val::Px(320.0),
+                max_height: Val::Percent(70.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                overflow: Overflow::clip_y(),
+                ..default()
+            },
+            BackgroundColor(PANEL_COLOR),
+            CardPreviewRoot,
+            AppLayer::GameUI.layer(),
+            Name::new("Card Preview"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(card.name.name.clone()),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(6.0),
+                        ..default()
+                    },
+                    Name::new("Card Preview Tabs"),
+                ))
+                .with_children(|parent| {
+                    for tab in CardPreviewTab::ALL {
+                        let color = if tab == preview.tab {
+                            SELECTED_TAB_COLOR
+                        } else {
+                            TAB_COLOR
+                        };
+                        parent
+                            .spawn((
+                                Button,
+                                Node {
+                                    width: Val::Px(90.0),
+                                    height: Val::Px(28.0),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                BackgroundColor(color),
+                                CardPreviewTabButton(tab),
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    Text::new(tab.label()),
+                                    TextFont {
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::WHITE),
+                                ));
+                            });
+                    }
+                });
+
+            for line in lines {
+                parent.spawn((
+                    Text::new(line),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                ));
+            }
+
+            if preview.tab == CardPreviewTab::Details && !keywords.is_empty() {
+                parent
+                    .spawn((
+                        Node {
+                            flex_direction: FlexDirection::Row,
+                            flex_wrap: FlexWrap::Wrap,
+                            column_gap: Val::Px(6.0),
+                            row_gap: Val::Px(6.0),
+                            ..default()
+                        },
+                        Name::new("Card Preview Keywords"),
+                    ))
+                    .with_children(|parent| {
+                        for keyword in keywords {
+                            parent
+                                .spawn((
+                                    Button,
+                                    Node {
+                                        padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                                        ..default()
+                                    },
+                                    BackgroundColor(KEYWORD_COLOR),
+                                    KeywordChip(keyword),
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn((
+                                        Text::new(keyword_name(keyword)),
+                                        TextFont {
+                                            font_size: 13.0,
+                                            ..default()
+                                        },
+                                        TextColor(Color::WHITE),
+                                    ));
+                                });
+                        }
+                    });
+            }
+        });
+}
+
+/// Track the hovered keyword, and show every reminder while
+/// [`KEYWORD_REMINDERS_KEY`] is toggled on
+pub fn update_keyword_tooltip(
+    keys: Res<ButtonInput<KeyCode>>,
+    chips: Query<(&Interaction, &KeywordChip)>,
+    mut tooltip: ResMut<KeywordTooltip>,
+) {
+    let hovered = chips
+        .iter()
+        .find(|(interaction, _)| **interaction != Interaction::None)
+        .map(|(_, KeywordChip(keyword))| *keyword);
+    let show_all = tooltip.show_all ^ keys.just_pressed(KEYWORD_REMINDERS_KEY);
+    tooltip.set_if_neq(KeywordTooltip { hovered, show_all });
+}
+
+/// Show the reminder text for the previewed card's keywords beside the preview
+pub fn sync_keyword_tooltip(
+    mut commands: Commands,
+    tooltip: Res<KeywordTooltip>,
+    chips: Query<&KeywordChip>,
+    roots: Query<(Entity, &KeywordTooltipRoot)>,
+) {
+    let keywords: Vec<KeywordAbility> = chips.iter().map(|KeywordChip(keyword)| *keyword).collect();
+    let lines = tooltip_lines(&keywords, &tooltip);
+    if roots.iter().any(|(_, root)| root.lines == lines) {
+        return;
+    }
+    for (root, _) in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    if lines.is_empty() {
+        return;
+    }
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                // Left of the 320px preview panel
+                right: Val::Px(344.0),
+                width: Val::Px(260.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(PANEL_COLOR),
+            KeywordTooltipRoot {
+                lines: lines.clone(),
+            },
+            AppLayer::GameUI.layer(),
+            Name::new("Keyword Reminders"),
+        ))
+        .with_children(|parent| {
+            for line in lines {
+                parent.spawn((
+                    Text::new(line),
+                    TextFont {
+                        font_size: 13.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                ));
+            }
+        });
+}
+
+/// Despawn the card preview and forget the previewed card
+pub fn despawn_card_preview(
+    mut commands: Commands,
+    roots: Query<Entity, Or<(With<CardPreviewRoot>, With<KeywordTooltipRoot>)>>,
+    mut preview: ResMut<CardPreview>,
+    mut tooltip: ResMut<KeywordTooltip>,
+) {
+    for entity in roots.iter() {
+        commands.entity(entity).despawn();
+    }
+    *preview = CardPreview::default();
+    *tooltip = KeywordTooltip::default();
+}
@@ -0,0 +1,125 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+
+use crate::cards::keywords::KeywordAbility;
+use crate::cards::mtgjson::index::{BulkFile, CardIndex};
+use crate::cards::{Card, CardDatabase, CardDetails, CardId, CardTypes};
+use crate::hud::card_preview::systems::{cost_line, load_preview_rulings};
+use crate::hud::card_preview::{
+    CardPreview, CardPreviewTab, KeywordTooltip, card_at, glossary_keywords, tooltip_lines,
+};
+use crate::mana::Mana;
+
+const ATOMIC_CARDS: &str = r#"{"data": {
+    "Lightning Bolt": [{
+        "name": "Lightning Bolt",
+        "manaCost": "{R}",
+        "types": ["Instant"],
+        "rulings": [
+            {"date": "2021-03-19", "text": "Lightning Bolt can target a planeswalker."},
+            {"date": "2004-10-04", "text": "It can target any creature."}
+        ]
+    }]
+}}"#;
+
+#[test]
+fn test_topmost_card_under_cursor_is_picked() {
+    let mut world = World::new();
+    let bottom = world.spawn_empty().id();
+    let top = world.spawn_empty().id();
+    let elsewhere = world.spawn_empty().id();
+    let card_size = Vec2::new(10.0, 14.0);
+    let cards = [
+        (bottom, Vec3::new(0.0, 0.0, 1.0)),
+        (top, Vec3::new(5.0, 0.0, 2.0)),
+        (elsewhere, Vec3::new(100.0, 0.0, 3.0)),
+    ];
+
+    assert_eq!(card_at(Vec2::new(4.0, 2.0), card_size, cards), Some(top));
+    // Cards are drawn 2.5 times the configured size
+    assert_eq!(
+        card_at(Vec2::new(-12.0, 0.0), card_size, cards),
+        Some(bottom)
+    );
+    assert_eq!(card_at(Vec2::new(50.0, 0.0), card_size, cards), None);
+}
+
+#[test]
+fn test_rulings_are_loaded_when_the_tab_opens() {
+    let dir = tempfile::tempdir().unwrap();
+    let index =
+        CardIndex::build(ATOMIC_CARDS.as_bytes(), BulkFile::AtomicCards, dir.path()).unwrap();
+    let bolt = CardId::from_name("Lightning Bolt");
+
+    let mut world = World::new();
+    world.insert_resource(CardDatabase::with_builtin_sets());
+    world.insert_resource(index);
+    let card = world.spawn(bolt).id();
+    world.insert_resource(CardPreview {
+        card: Some(card),
+        tab: CardPreviewTab::Details,
+    });
+
+    // Nothing is read until the rulings are shown
+    world.run_system_once(load_preview_rulings).unwrap();
+    assert!(world.resource::<CardDatabase>().rulings(bolt).is_none());
+
+    world.resource_mut::<CardPreview>().tab = CardPreviewTab::Rulings;
+    world.run_system_once(load_preview_rulings).unwrap();
+
+    let database = world.resource::<CardDatabase>();
+    let rulings = database.rulings(bolt).unwrap();
+    assert_eq!(rulings.len(), 2);
+    assert_eq!(rulings[0].date, "2004-10-04");
+}
+
+#[test]
+fn test_modified_costs_show_the_printed_cost() {
+    let printed = Mana::new_with_colors(3, 0, 0, 0, 0, 1);
+    let reduced = Mana::new_with_colors(2, 0, 0, 0, 0, 1);
+    assert_eq!(cost_line(&printed, &printed), printed.to_string());
+    assert_eq!(
+        cost_line(&printed, &reduced),
+        format!("{} (printed {})", reduced, printed)
+    );
+}
+
+#[test]
+fn test_keyword_reminders_follow_the_tooltip() {
+    let card = Card::new(
+        "Serra Angel",
+        Mana::default(),
+        CardTypes::CREATURE,
+        CardDetails::Other,
+        "Flying, vigilance",
+    );
+    let keywords = glossary_keywords(&card);
+    assert_eq!(
+        keywords,
+        vec![KeywordAbility::Flying, KeywordAbility::Vigilance]
+    );
+
+    assert!(tooltip_lines(&keywords, &KeywordTooltip::default()).is_empty());
+    let hovered = KeywordTooltip {
+        hovered: Some(KeywordAbility::Vigilance),
+        show_all: false,
+    };
+    assert_eq!(
+        tooltip_lines(&keywords, &hovered),
+        vec!["Vigilance: Attacking doesn't cause this creature to tap.".to_string()]
+    );
+    // A keyword the previewed card doesn't have isn't explained
+    let elsewhere = KeywordTooltip {
+        hovered: Some(KeywordAbility::Trample),
+        show_all: false,
+    };
+    assert!(tooltip_lines(&keywords, &elsewhere).is_empty());
+
+    let all = KeywordTooltip {
+        hovered: None,
+        show_all: true,
+    };
+    let lines = tooltip_lines(&keywords, &all);
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("Flying: "));
+}
@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+/// Root node of the command zone dialog
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CommanderZonePromptRoot {
+    /// The commander the dialog asks about
+    pub commander: Entity,
+}
+
+/// Button answering the command zone choice
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommanderZoneButton {
+    /// Whether this button moves the commander to the command zone
+    pub to_command_zone: bool,
+}
@@ -0,0 +1,17 @@
+//! Dialog asking a commander's owner whether it goes to the command zone
+//!
+//! When a commander would go to the graveyard, exile, its owner's hand or library
+//! (rule 903.9), the owner picks between the command zone and the original
+//! destination. The answer is sent as a
+//! [`CommanderZoneDecisionEvent`](crate::game_engine::commander::CommanderZoneDecisionEvent).
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{CommanderZoneButton, CommanderZonePromptRoot};
+pub use plugin::CommanderZonePromptPlugin;
+pub use systems::{zone_choice_prompt, zone_name};
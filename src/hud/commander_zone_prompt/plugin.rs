@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+use super::systems::{
+    despawn_commander_zone_prompt, handle_commander_zone_buttons, sync_commander_zone_prompt,
+};
+use crate::game_engine::commander::CommanderZoneChoices;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the command zone choice dialog
+pub struct CommanderZonePromptPlugin;
+
+impl Plugin for CommanderZonePromptPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMenuState::InGame), despawn_commander_zone_prompt)
+            .add_systems(
+                Update,
+                (
+                    sync_commander_zone_prompt.run_if(resource_changed::<CommanderZoneChoices>),
+                    handle_commander_zone_buttons,
+                )
+                    .chain()
+                    .run_if(resource_exists::<CommanderZoneChoices>)
+                    .run_if(in_state(GameMenuState::InGame)),
+            );
+    }
+}
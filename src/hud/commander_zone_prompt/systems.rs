@@ -0,0 +1,187 @@
+use bevy::prelude::*;
+
+use super::components::{CommanderZoneButton, CommanderZonePromptRoot};
+use crate::camera::components::AppLayer;
+use crate::camera::z_order::UiLayer;
+use crate::cards::CardName;
+use crate::game_engine::commander::{CommanderZoneChoices, CommanderZoneDecisionEvent};
+use crate::game_engine::zones::Zone;
+use crate::player::Player;
+
+const BUTTON_COLOR: Color = Color::srgba(0.22, 0.22, 0.26, 0.95);
+const HOVERED_BUTTON_COLOR: Color = Color::srgba(0.32, 0.32, 0.38, 0.95);
+
+/// How a zone is named in the dialog
+pub fn zone_name(zone: Zone) -> &'static str {
+    match zone {
+        Zone::Library => "library",
+        Zone::Hand => "hand",
+        Zone::Battlefield => "battlefield",
+        Zone::Graveyard => "graveyard",
+        Zone::Stack => "stack",
+        Zone::Exile => "exile",
+        Zone::Command => "command zone",
+    }
+}
+
+/// Question shown to the commander's owner
+pub fn zone_choice_prompt(owner_name: &str, commander_name: &str, zone: Zone) -> String {
+    format!(
+        "{}: {} would go to the {}. Move it to the command zone instead?",
+        owner_name,
+        commander_name,
+        zone_name(zone)
+    )
+}
+
+/// Show the dialog for the oldest pending choice and remove it once answered
+pub fn sync_commander_zone_prompt(
+    mut commands: Commands,
+    zone_choices: Res<CommanderZoneChoices>,
+    roots: Query<(Entity, &CommanderZonePromptRoot)>,
+    card_names: Query<&CardName>,
+    players: Query<&Player>,
+) {
+    let current = zone_choices.current().copied();
+    let showing_current = roots
+        .single()
+        .is_ok_and(|(_, root)| current.is_some_and(|choice| choice.commander == root.commander));
+    if showing_current {
+        return;
+    }
+
+    for (entity, _) in roots.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let Some(choice) = current else {
+        return;
+    };
+    let commander_name = card_names
+        .get(choice.commander)
+        .map_or_else(|_| "Your commander".to_string(), |name| name.name.clone());
+    let owner_name = players
+        .get(choice.owner)
+        .map_or_else(|_| "Owner".to_string(), |player| player.name.clone());
+    let destination = format!("Leave in {}", zone_name(choice.current_zone));
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            UiLayer::Dialog.global_z_index(),
+            CommanderZonePromptRoot {
+                commander: choice.commander,
+            },
+            AppLayer::GameUI.layer(),
+            Name::new("Command Zone Prompt"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(480.0),
+                        padding: UiRect::all(Val::Px(16.0)),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(12.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.08, 0.1, 0.97)),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(zone_choice_prompt(
+                            &owner_name,
+                            &commander_name,
+                            choice.current_zone,
+                        )),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                    ));
+
+                    parent
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            column_gap: Val::Px(12.0),
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            for (label, to_command_zone) in
+                                [("Command zone".to_string(), true), (destination, false)]
+                            {
+                                parent
+                                    .spawn((
+                                        Button,
+                                        Node {
+                                            width: Val::Px(180.0),
+                                            height: Val::Px(38.0),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            ..default()
+                                        },
+                                        BackgroundColor(BUTTON_COLOR),
+                                        CommanderZoneButton { to_command_zone },
+                                    ))
+                                    .with_children(|parent| {
+                                        parent.spawn((
+                                            Text::new(label),
+                                            TextFont {
+                                                font_size: 16.0,
+                                                ..default()
+                                            },
+                                            TextColor(Color::WHITE),
+                                        ));
+                                    });
+                            }
+                        });
+                });
+        });
+}
+
+/// Send the owner's answer for the commander shown in the dialog
+pub fn handle_commander_zone_buttons(
+    mut buttons: Query<
+        (&Interaction, &CommanderZoneButton, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    roots: Query<&CommanderZonePromptRoot>,
+    mut decision_events: EventWriter<CommanderZoneDecisionEvent>,
+) {
+    for (interaction, button, mut background) in buttons.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                let Ok(root) = roots.single() else {
+                    continue;
+                };
+                decision_events.write(CommanderZoneDecisionEvent {
+                    commander: root.commander,
+                    to_command_zone: button.to_command_zone,
+                });
+            }
+            Interaction::Hovered => background.0 = HOVERED_BUTTON_COLOR,
+            Interaction::None => background.0 = BUTTON_COLOR,
+        }
+    }
+}
+
+/// Despawn the command zone dialog
+pub fn despawn_commander_zone_prompt(
+    mut commands: Commands,
+    roots: Query<Entity, With<CommanderZonePromptRoot>>,
+) {
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+}
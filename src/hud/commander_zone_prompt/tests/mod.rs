@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+use crate::game_engine::commander::{CommanderZoneChoices, PendingZoneChoice};
+use crate::game_engine::zones::Zone;
+use crate::hud::commander_zone_prompt::zone_choice_prompt;
+
+#[test]
+fn test_prompt_names_owner_commander_and_zone() {
+    assert_eq!(
+        zone_choice_prompt("Player 1", "Atraxa", Zone::Exile),
+        "Player 1: Atraxa would go to the exile. Move it to the command zone instead?"
+    );
+}
+
+#[test]
+fn test_choices_are_asked_oldest_first() {
+    let mut world = World::new();
+    let owner = world.spawn_empty().id();
+    let first = world.spawn_empty().id();
+    let second = world.spawn_empty().id();
+
+    let mut choices = CommanderZoneChoices::default();
+    for (commander, current_zone) in [(first, Zone::Graveyard), (second, Zone::Hand)] {
+        choices.push(PendingZoneChoice {
+            commander,
+            owner,
+            current_zone,
+        });
+    }
+    assert_eq!(
+        choices.current().map(|choice| choice.commander),
+        Some(first)
+    );
+
+    choices.take(first);
+    assert_eq!(
+        choices.current().map(|choice| choice.commander),
+        Some(second)
+    );
+}
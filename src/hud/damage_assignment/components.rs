@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+
+use crate::game_engine::combat::{
+    BlockerDamage, DamageAssignment, DamageAssignmentError, suggest_assignment, validate_assignment,
+};
+
+/// Root node of the damage assignment panel
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DamageAssignmentRoot;
+
+/// Buttons of the damage assignment panel
+///
+/// A target of `None` is the player or planeswalker being attacked.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageAssignmentButton {
+    /// Move a blocker one place earlier in the damage assignment order
+    MoveUp(Entity),
+    Decrease(Option<Entity>),
+    Increase(Option<Entity>),
+    /// Go back to the suggested assignment
+    Suggest,
+    Confirm,
+}
+
+/// Damage assigned to a blocker, or to the defender for `None`
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DamageAmountText(pub Option<Entity>);
+
+/// Text showing how much damage is left and whether the assignment is legal
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DamageAssignmentStatusText;
+
+/// The assignment being edited in the panel
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct DamageAssignmentDraft {
+    /// Attacker whose damage is being assigned, if any
+    pub attacker: Option<Entity>,
+    pub power: u32,
+    pub trample: bool,
+    /// Blockers in damage assignment order
+    pub order: Vec<BlockerDamage>,
+    pub assignment: DamageAssignment,
+}
+
+impl DamageAssignmentDraft {
+    /// Start from the suggested assignment
+    pub fn new(attacker: Entity, power: u32, order: Vec<BlockerDamage>, trample: bool) -> Self {
+        let assignment = suggest_assignment(power, &order, trample);
+        Self {
+            attacker: Some(attacker),
+            power,
+            trample,
+            order,
+            assignment,
+        }
+    }
+
+    /// Go back to the suggested assignment
+    pub fn suggest(&mut self) {
+        self.assignment = suggest_assignment(self.power, &self.order, self.trample);
+    }
+
+    /// Damage assigned to a blocker, or to the defender for `None`
+    pub fn amount(&self, target: Option<Entity>) -> u32 {
+        match target {
+            Some(blocker) => self.assignment.damage_to(blocker),
+            None => self.assignment.defender,
+        }
+    }
+
+    /// Damage not assigned yet
+    pub fn remaining(&self) -> u32 {
+        self.power.saturating_sub(self.assignment.total())
+    }
+
+    /// Assign one more or one less damage to a target
+    ///
+    /// The total never goes above the attacker's power, and only attackers with
+    /// trample can assign damage to the defender.
+    pub fn adjust(&mut self, target: Option<Entity>, increase: bool) {
+        if increase && self.remaining() == 0 {
+            return;
+        }
+        let amount = match target {
+            Some(blocker) => {
+                match self
+                    .assignment
+                    .blockers
+                    .iter_mut()
+                    .find(|(entity, _)| *entity == blocker)
+                {
+                    Some((_, amount)) => amount,
+                    None => return,
+                }
+            }
+            None if self.trample => &mut self.assignment.defender,
+            None => return,
+        };
+        *amount = if increase {
+            *amount + 1
+        } else {
+            amount.saturating_sub(1)
+        };
+    }
+
+    /// The blocker order with `blocker` moved one place earlier, if it can move
+    pub fn moved_up(&self, blocker: Entity) -> Option<Vec<Entity>> {
+        let mut order: Vec<Entity> = self.order.iter().map(|info| info.blocker).collect();
+        let index = order.iter().position(|&entity| entity == blocker)?;
+        if index == 0 {
+            return None;
+        }
+        order.swap(index - 1, index);
+        Some(order)
+    }
+
+    /// Whether the assignment follows the damage assignment order
+    pub fn validate(&self) -> Result<(), DamageAssignmentError> {
+        validate_assignment(self.power, &self.order, self.trample, &self.assignment)
+    }
+}
@@ -0,0 +1,21 @@
+//! Damage assignment panel shown while the local player's attacker is blocked by
+//! several creatures
+//!
+//! The player orders the blockers, splits the attacker's damage between them and
+//! confirms, which sends an
+//! [`OrderBlockersEvent`](crate::game_engine::combat::OrderBlockersEvent) and a
+//! [`DamageAssignmentEvent`](crate::game_engine::combat::DamageAssignmentEvent).
+//! Attackers left unconfirmed use the suggested assignment.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{
+    DamageAmountText, DamageAssignmentButton, DamageAssignmentDraft, DamageAssignmentRoot,
+    DamageAssignmentStatusText,
+};
+pub use plugin::DamageAssignmentPlugin;
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+use super::components::DamageAssignmentDraft;
+use super::systems::{
+    despawn_damage_assignment_panel, handle_damage_assignment_buttons,
+    sync_damage_assignment_panel, update_damage_assignment_display,
+};
+use crate::game_engine::combat::CombatState;
+use crate::game_engine::phase::Phase;
+use crate::game_engine::turns::TurnManager;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the multi-blocker damage assignment panel
+pub struct DamageAssignmentPlugin;
+
+impl Plugin for DamageAssignmentPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DamageAssignmentDraft>()
+            .add_systems(
+                OnExit(GameMenuState::InGame),
+                despawn_damage_assignment_panel,
+            )
+            .add_systems(
+                Update,
+                (
+                    sync_damage_assignment_panel,
+                    handle_damage_assignment_buttons,
+                    update_damage_assignment_display,
+                )
+                    .chain()
+                    .run_if(
+                        in_state(GameMenuState::InGame)
+                            .and(resource_exists::<CombatState>)
+                            .and(resource_exists::<Phase>)
+                            .and(resource_exists::<TurnManager>),
+                    ),
+            );
+    }
+}
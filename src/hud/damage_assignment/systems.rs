@@ -0,0 +1,336 @@
+use bevy::prelude::*;
+
+use super::components::{
+    DamageAmountText, DamageAssignmentButton, DamageAssignmentDraft, DamageAssignmentRoot,
+    DamageAssignmentStatusText,
+};
+use crate::camera::components::AppLayer;
+use crate::game_engine::combat::{
+    CombatState, CombatantQuery, DamageAssignmentEvent, OrderBlockersEvent, blocker_damage,
+    combat_power, has_trample,
+};
+use crate::game_engine::phase::{CombatStep, Phase};
+use crate::game_engine::turns::TurnManager;
+use crate::player::Player;
+
+const BUTTON_COLOR: Color = Color::srgba(0.22, 0.22, 0.26, 0.95);
+const CONFIRM_COLOR: Color = Color::srgba(0.2, 0.55, 0.25, 0.95);
+const DISABLED_CONFIRM_COLOR: Color = Color::srgba(0.15, 0.15, 0.15, 0.85);
+
+/// The next attacker blocked by several creatures whose damage hasn't been
+/// assigned yet
+pub fn pending_attacker(combat_state: &CombatState) -> Option<Entity> {
+    combat_state
+        .blockers
+        .iter()
+        .filter(|(attacker, blockers)| {
+            blockers.len() > 1
+                && combat_state.attackers.contains_key(attacker)
+                && !combat_state.damage_assignments.contains_key(attacker)
+        })
+        .map(|(attacker, _)| *attacker)
+        .min()
+}
+
+/// Show the panel for the local player's next multi-blocked attacker during the
+/// declare blockers step, rebuilding it when the blocker order changes
+#[allow(clippy::too_many_arguments)]
+pub fn sync_damage_assignment_panel(
+    mut commands: Commands,
+    combat_state: Res<CombatState>,
+    phase: Res<Phase>,
+    turn_manager: Res<TurnManager>,
+    players: Query<&Player>,
+    combatants: CombatantQuery,
+    roots: Query<Entity, With<DamageAssignmentRoot>>,
+    mut draft: ResMut<DamageAssignmentDraft>,
+) {
+    if !combat_state.is_changed() && !phase.is_changed() {
+        return;
+    }
+
+    let local_player_attacking = players
+        .get(turn_manager.active_player)
+        .is_ok_and(|player| player.player_index == 0);
+    let attacker = pending_attacker(&combat_state)
+        .filter(|_| local_player_attacking && *phase == Phase::Combat(CombatStep::DeclareBlockers));
+    let next = attacker.and_then(|attacker| {
+        let (card, on_field) = combatants.get(attacker).ok()?;
+        let order = blocker_damage(card, &combat_state.blockers[&attacker], &combatants);
+        Some((
+            card.name.name.clone(),
+            attacker,
+            combat_power(card, on_field),
+            order,
+            has_trample(card),
+        ))
+    });
+
+    let unchanged = match &next {
+        Some((_, attacker, _, order, _)) => {
+            draft.attacker == Some(*attacker) && draft.order == *order && !roots.is_empty()
+        }
+        None => draft.attacker.is_none() && roots.is_empty(),
+    };
+    if unchanged {
+        return;
+    }
+
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    let Some((attacker_name, attacker, power, order, trample)) = next else {
+        *draft = DamageAssignmentDraft::default();
+        return;
+    };
+    *draft = DamageAssignmentDraft::new(attacker, power, order, trample);
+
+    let mut rows: Vec<(Option<Entity>, String)> = draft
+        .order
+        .iter()
+        .map(|info| {
+            let name = combatants.get(info.blocker).map_or_else(
+                |_| "Blocker".to_string(),
+                |(card, _)| card.name.name.clone(),
+            );
+            (
+                Some(info.blocker),
+                format!("{} (lethal {})", name, info.lethal),
+            )
+        })
+        .collect();
+    if trample {
+        rows.push((None, "Defending player".to_string()));
+    }
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(80.0),
+                right: Val::Px(20.0),
+                width: Val::Px(360.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.9)),
+            DamageAssignmentRoot,
+            AppLayer::GameUI.layer(),
+            Name::new("Damage Assignment"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("Assign {} damage from {}", power, attacker_name)),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            for (index, (target, label)) in rows.into_iter().enumerate() {
+                parent
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(6.0),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn((
+                            Text::new(label),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                            Node {
+                                flex_grow: 1.0,
+                                ..default()
+                            },
+                        ));
+                        if let Some(blocker) = target.filter(|_| index > 0) {
+                            spawn_button(
+                                parent,
+                                "Up",
+                                DamageAssignmentButton::MoveUp(blocker),
+                                BUTTON_COLOR,
+                            );
+                        }
+                        spawn_button(
+                            parent,
+                            "-",
+                            DamageAssignmentButton::Decrease(target),
+                            BUTTON_COLOR,
+                        );
+                        parent.spawn((
+                            Text::new(""),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                            DamageAmountText(target),
+                        ));
+                        spawn_button(
+                            parent,
+                            "+",
+                            DamageAssignmentButton::Increase(target),
+                            BUTTON_COLOR,
+                        );
+                    });
+            }
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.8, 0.5)),
+                DamageAssignmentStatusText,
+            ));
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::End,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    spawn_button(
+                        parent,
+                        "Suggest",
+                        DamageAssignmentButton::Suggest,
+                        BUTTON_COLOR,
+                    );
+                    spawn_button(
+                        parent,
+                        "Confirm",
+                        DamageAssignmentButton::Confirm,
+                        DISABLED_CONFIRM_COLOR,
+                    );
+                });
+        });
+}
+
+fn spawn_button(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    button: DamageAssignmentButton,
+    color: Color,
+) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                min_width: Val::Px(28.0),
+                height: Val::Px(28.0),
+                padding: UiRect::horizontal(Val::Px(8.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(color),
+            button,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// Despawn the damage assignment panel
+pub fn despawn_damage_assignment_panel(
+    mut commands: Commands,
+    roots: Query<Entity, With<DamageAssignmentRoot>>,
+    mut draft: ResMut<DamageAssignmentDraft>,
+) {
+    for entity in roots.iter() {
+        commands.entity(entity).despawn();
+    }
+    *draft = DamageAssignmentDraft::default();
+}
+
+/// Edit the draft, reorder blockers and confirm the assignment
+pub fn handle_damage_assignment_buttons(
+    buttons: Query<(&Interaction, &DamageAssignmentButton), Changed<Interaction>>,
+    mut draft: ResMut<DamageAssignmentDraft>,
+    mut order_events: EventWriter<OrderBlockersEvent>,
+    mut assignment_events: EventWriter<DamageAssignmentEvent>,
+) {
+    let Some(attacker) = draft.attacker else {
+        return;
+    };
+
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match *button {
+            DamageAssignmentButton::MoveUp(blocker) => {
+                // The panel is rebuilt for the new order once it is applied
+                if let Some(order) = draft.moved_up(blocker) {
+                    order_events.write(OrderBlockersEvent { attacker, order });
+                }
+            }
+            DamageAssignmentButton::Decrease(target) => draft.adjust(target, false),
+            DamageAssignmentButton::Increase(target) => draft.adjust(target, true),
+            DamageAssignmentButton::Suggest => draft.suggest(),
+            DamageAssignmentButton::Confirm => {
+                if draft.validate().is_ok() {
+                    assignment_events.write(DamageAssignmentEvent {
+                        attacker,
+                        assignment: draft.assignment.clone(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Show the assigned amounts and whether the assignment can be confirmed
+pub fn update_damage_assignment_display(
+    draft: Res<DamageAssignmentDraft>,
+    mut amounts: Query<(&DamageAmountText, &mut Text), Without<DamageAssignmentStatusText>>,
+    mut statuses: Query<&mut Text, With<DamageAssignmentStatusText>>,
+    mut buttons: Query<(&DamageAssignmentButton, &mut BackgroundColor)>,
+    added: Query<(), Added<DamageAssignmentRoot>>,
+) {
+    if !draft.is_changed() && added.is_empty() {
+        return;
+    }
+
+    for (DamageAmountText(target), mut text) in amounts.iter_mut() {
+        text.0 = draft.amount(*target).to_string();
+    }
+
+    let result = draft.validate();
+    let status = match &result {
+        Ok(()) => "Ready to confirm".to_string(),
+        Err(_) if draft.remaining() > 0 => format!("{} damage left to assign", draft.remaining()),
+        Err(error) => error.to_string(),
+    };
+    for mut text in statuses.iter_mut() {
+        text.0 = status.clone();
+    }
+
+    for (button, mut background) in buttons.iter_mut() {
+        if *button == DamageAssignmentButton::Confirm {
+            background.0 = if result.is_ok() {
+                CONFIRM_COLOR
+            } else {
+                DISABLED_CONFIRM_COLOR
+            };
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+
+use crate::game_engine::combat::{
+    BlockerDamage, CombatState, DamageAssignment, DamageAssignmentError,
+};
+use crate::hud::damage_assignment::DamageAssignmentDraft;
+use crate::hud::damage_assignment::systems::pending_attacker;
+
+fn entities(count: usize) -> Vec<Entity> {
+    let mut world = World::new();
+    (0..count).map(|_| world.spawn_empty().id()).collect()
+}
+
+#[test]
+fn test_draft_is_edited_within_the_attackers_power() {
+    let [attacker, first, second] = entities(3)[..] else {
+        unreachable!()
+    };
+    let order = vec![
+        BlockerDamage {
+            blocker: first,
+            lethal: 2,
+        },
+        BlockerDamage {
+            blocker: second,
+            lethal: 2,
+        },
+    ];
+    let mut draft = DamageAssignmentDraft::new(attacker, 5, order, true);
+    assert_eq!(draft.amount(None), 1);
+    assert_eq!(draft.validate(), Ok(()));
+
+    // All the damage is assigned, so nothing more can be added
+    draft.adjust(Some(second), true);
+    assert_eq!(draft.amount(Some(second)), 2);
+
+    draft.adjust(Some(first), false);
+    assert_eq!(draft.remaining(), 1);
+    draft.adjust(Some(second), true);
+    assert_eq!(
+        draft.validate(),
+        Err(DamageAssignmentError::NotLethal(first))
+    );
+
+    draft.suggest();
+    assert_eq!(
+        draft.assignment,
+        DamageAssignment {
+            blockers: vec![(first, 2), (second, 2)],
+            defender: 1,
+        }
+    );
+
+    assert_eq!(draft.moved_up(first), None);
+    assert_eq!(draft.moved_up(second), Some(vec![second, first]));
+}
+
+#[test]
+fn test_only_multi_blocked_attackers_need_an_assignment() {
+    let [player, single, double, blocker_a, blocker_b, blocker_c] = entities(6)[..] else {
+        unreachable!()
+    };
+    let mut combat_state = CombatState::default();
+    combat_state.attackers.insert(single, player);
+    combat_state.attackers.insert(double, player);
+    combat_state.blockers.insert(single, vec![blocker_a]);
+    combat_state
+        .blockers
+        .insert(double, vec![blocker_b, blocker_c]);
+
+    assert_eq!(pending_attacker(&combat_state), Some(double));
+
+    combat_state
+        .damage_assignments
+        .insert(double, DamageAssignment::default());
+    assert_eq!(pending_attacker(&combat_state), None);
+}
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use crate::game_engine::day_night::{DayNight, DayNightState};
+
+/// Root node of the sun and moon indicator
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DayNightIndicator;
+
+/// The indicator's text, `None` while it's neither day nor night
+pub fn day_night_label(day_night: &DayNight) -> Option<String> {
+    let label = match day_night.state? {
+        DayNightState::Day => "Day",
+        DayNightState::Night => "Night",
+    };
+    Some(match day_night.next_change() {
+        Some(DayNightState::Day) => format!("{} - day next turn", label),
+        Some(DayNightState::Night) => format!("{} - night next turn", label),
+        None => label.to_string(),
+    })
+}
@@ -0,0 +1,15 @@
+//! Sun and moon indicator for day and night
+//!
+//! Once it has become day or night, a sun or moon in the corner of the screen
+//! shows which it is, with what the spells cast this turn will make it next
+//! turn. See [`crate::game_engine::day_night`].
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{DayNightIndicator, day_night_label};
+pub use plugin::DayNightHudPlugin;
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+use super::systems::{despawn_day_night_indicator, sync_day_night_indicator};
+use crate::game_engine::day_night::DayNight;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the sun and moon indicator
+pub struct DayNightHudPlugin;
+
+impl Plugin for DayNightHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMenuState::InGame), despawn_day_night_indicator)
+            .add_systems(
+                Update,
+                sync_day_night_indicator
+                    .run_if(in_state(GameMenuState::InGame).and(resource_changed::<DayNight>)),
+            );
+    }
+}
@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+
+use super::components::{DayNightIndicator, day_night_label};
+use crate::camera::components::AppLayer;
+use crate::game_engine::day_night::{DayNight, DayNightState};
+
+const PANEL_COLOR: Color = Color::srgba(0.05, 0.05, 0.08, 0.9);
+const SUN_COLOR: Color = Color::srgb(0.98, 0.8, 0.25);
+const MOON_COLOR: Color = Color::srgb(0.75, 0.8, 0.95);
+
+/// Rebuild the indicator as it becomes day or night or spells are cast
+pub fn sync_day_night_indicator(
+    mut commands: Commands,
+    day_night: Res<DayNight>,
+    indicators: Query<Entity, With<DayNightIndicator>>,
+) {
+    for indicator in indicators.iter() {
+        commands.entity(indicator).despawn();
+    }
+    let (Some(state), Some(label)) = (day_night.state, day_night_label(&day_night)) else {
+        return;
+    };
+    let body_color = match state {
+        DayNightState::Day => SUN_COLOR,
+        DayNightState::Night => MOON_COLOR,
+    };
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(8.0),
+                right: Val::Px(16.0),
+                padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(PANEL_COLOR),
+            DayNightIndicator,
+            AppLayer::GameUI.layer(),
+            Name::new("Day Night Indicator"),
+        ))
+        .with_children(|parent| {
+            // The sun or moon
+            parent.spawn((
+                Node {
+                    width: Val::Px(18.0),
+                    height: Val::Px(18.0),
+                    ..default()
+                },
+                BorderRadius::MAX,
+                BackgroundColor(body_color),
+            ));
+            parent.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// Despawn the sun and moon indicator
+pub fn despawn_day_night_indicator(
+    mut commands: Commands,
+    indicators: Query<Entity, With<DayNightIndicator>>,
+) {
+    for indicator in indicators.iter() {
+        commands.entity(indicator).despawn();
+    }
+}
@@ -0,0 +1,50 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+
+use crate::game_engine::day_night::{DayNight, DayNightState};
+use crate::hud::day_night::systems::sync_day_night_indicator;
+use crate::hud::day_night::{DayNightIndicator, day_night_label};
+
+#[test]
+fn test_day_night_labels() {
+    let player = Entity::from_raw(1);
+    let mut day_night = DayNight::default();
+    assert_eq!(day_night_label(&day_night), None);
+
+    day_night.state = Some(DayNightState::Day);
+    day_night.turn_player = Some(player);
+    assert_eq!(
+        day_night_label(&day_night).unwrap(),
+        "Day - night next turn"
+    );
+    day_night.spells_this_turn.insert(player, 1);
+    assert_eq!(day_night_label(&day_night).unwrap(), "Day");
+
+    day_night.state = Some(DayNightState::Night);
+    assert_eq!(day_night_label(&day_night).unwrap(), "Night");
+    day_night.spells_this_turn.insert(player, 2);
+    assert_eq!(
+        day_night_label(&day_night).unwrap(),
+        "Night - day next turn"
+    );
+}
+
+#[test]
+fn test_indicator_shows_once_it_is_day_or_night() {
+    let mut world = World::new();
+    world.init_resource::<DayNight>();
+
+    world.run_system_once(sync_day_night_indicator).unwrap();
+    let indicators = |world: &mut World| {
+        world
+            .query_filtered::<(), With<DayNightIndicator>>()
+            .iter(world)
+            .count()
+    };
+    assert_eq!(indicators(&mut world), 0);
+
+    world.resource_mut::<DayNight>().state = Some(DayNightState::Night);
+    world.run_system_once(sync_day_night_indicator).unwrap();
+    world.run_system_once(sync_day_night_indicator).unwrap();
+    assert_eq!(indicators(&mut world), 1);
+}
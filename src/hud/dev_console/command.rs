@@ -0,0 +1,80 @@
+use crate::cards::{CardDatabase, SearchQuery, format_type_line};
+
+/// Most cards `find` lists
+const FIND_LIMIT: usize = 10;
+
+/// A command typed into the developer console
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConsoleCommand {
+    /// List the commands
+    Help,
+    /// Search the card database, e.g. `find t:creature c:g pow>=4`
+    FindCard(SearchQuery),
+    /// Put a new copy of a card into your hand, e.g. `create Lightning Bolt`
+    ///
+    /// Only works in sandbox mode, when the rules allow manual changes.
+    CreateCard(String),
+}
+
+impl ConsoleCommand {
+    /// Parse a line typed into the console
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let line = line.trim();
+        let (name, arguments) = line.split_once(' ').unwrap_or((line, ""));
+        match name.to_lowercase().as_str() {
+            "help" | "?" => Ok(ConsoleCommand::Help),
+            "find" => SearchQuery::parse(arguments)
+                .map(ConsoleCommand::FindCard)
+                .map_err(|e| e.to_string()),
+            "create" if arguments.trim().is_empty() => Err("usage: create <card name>".to_string()),
+            "create" => Ok(ConsoleCommand::CreateCard(arguments.trim().to_string())),
+            _ => Err(format!("unknown command: {}", name)),
+        }
+    }
+
+    /// Run the command, returning the lines to print
+    pub fn run(&self, database: Option<&CardDatabase>) -> Vec<String> {
+        match self {
+            ConsoleCommand::Help => vec![
+                "help: list commands".to_string(),
+                "find <query>: search cards, e.g. find t:creature c:ur cmc<=3".to_string(),
+                "create <name>: put a card into your hand in sandbox mode".to_string(),
+            ],
+            ConsoleCommand::FindCard(query) => {
+                let Some(database) = database else {
+                    return vec!["The card database isn't loaded".to_string()];
+                };
+                let results = query.search(database.iter());
+                let noun = if results.len() == 1 { "card" } else { "cards" };
+                let mut lines = vec![format!("{} {} found", results.len(), noun)];
+                lines.extend(results.iter().take(FIND_LIMIT).map(|definition| {
+                    let card = &definition.card;
+                    format!(
+                        "{} {} - {}",
+                        card.name.name,
+                        card.cost.cost,
+                        format_type_line(&card.type_info.types, &card.details.details)
+                    )
+                }));
+                if results.len() > FIND_LIMIT {
+                    lines.push(format!("... and {} more", results.len() - FIND_LIMIT));
+                }
+                lines
+            }
+            ConsoleCommand::CreateCard(name) => {
+                let Some(database) = database else {
+                    return vec!["The card database isn't loaded".to_string()];
+                };
+                match database.find_by_name(name) {
+                    Some(definition) => {
+                        vec![format!(
+                            "Created {} in your hand",
+                            definition.card.name.name
+                        )]
+                    }
+                    None => vec![format!("No card named {}", name)],
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+/// Whether the console is open, the line being typed and earlier output
+#[derive(Resource, Debug, Clone, Default)]
+pub struct DevConsole {
+    pub open: bool,
+    pub input: String,
+    pub output: Vec<String>,
+}
+
+impl DevConsole {
+    /// Lines of output kept on screen
+    pub const MAX_LINES: usize = 20;
+
+    /// Append typed text, ignoring control characters
+    pub fn push_str(&mut self, text: &str) {
+        self.input.extend(text.chars().filter(|c| !c.is_control()));
+    }
+
+    /// Remove the last typed character
+    pub fn backspace(&mut self) {
+        self.input.pop();
+    }
+
+    /// Add a line of output, dropping the oldest past [`Self::MAX_LINES`]
+    pub fn print(&mut self, line: impl Into<String>) {
+        self.output.push(line.into());
+        let overflow = self.output.len().saturating_sub(Self::MAX_LINES);
+        self.output.drain(..overflow);
+    }
+
+    /// Take the typed line, leaving the input empty
+    pub fn take_input(&mut self) -> String {
+        std::mem::take(&mut self.input).trim().to_string()
+    }
+}
+
+/// Root node of the console
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DevConsoleRoot;
@@ -0,0 +1,16 @@
+//! Developer console for debug builds
+//!
+//! Opened with the backtick key. Commands are typed on one line and run with
+//! Enter; `help` lists them.
+
+mod command;
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use command::ConsoleCommand;
+pub use components::{DevConsole, DevConsoleRoot};
+pub use plugin::DevConsolePlugin;
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use super::components::DevConsole;
+use super::systems::{handle_console_input, sync_dev_console};
+
+/// Plugin for the developer console
+pub struct DevConsolePlugin;
+
+impl Plugin for DevConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DevConsole>().add_systems(
+            Update,
+            (
+                handle_console_input,
+                sync_dev_console.run_if(resource_changed::<DevConsole>),
+            )
+                .chain(),
+        );
+    }
+}
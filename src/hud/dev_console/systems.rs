@@ -0,0 +1,121 @@
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+use super::command::ConsoleCommand;
+use super::components::{DevConsole, DevConsoleRoot};
+use crate::camera::components::AppLayer;
+use crate::cards::CardDatabase;
+use crate::game_engine::sandbox::SandboxAction;
+use crate::game_engine::zones::Zone;
+use crate::player::Player;
+
+/// Open and close the console, type into it and run commands
+pub fn handle_console_input(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut console: ResMut<DevConsole>,
+    database: Option<Res<CardDatabase>>,
+    players: Query<(Entity, &Player)>,
+    mut sandbox_actions: EventWriter<SandboxAction>,
+) {
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        if event.key_code == KeyCode::Backquote {
+            console.open = !console.open;
+            continue;
+        }
+        if !console.open {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Character(text) => console.push_str(text),
+            Key::Space => console.push_str(" "),
+            Key::Backspace => console.backspace(),
+            Key::Escape => console.open = false,
+            Key::Enter => {
+                let line = console.take_input();
+                if line.is_empty() {
+                    continue;
+                }
+                console.print(format!("> {}", line));
+                let command = match ConsoleCommand::parse(&line) {
+                    Ok(command) => command,
+                    Err(e) => {
+                        console.print(e);
+                        continue;
+                    }
+                };
+                if let ConsoleCommand::CreateCard(name) = &command {
+                    let Some((owner, _)) =
+                        players.iter().find(|(_, player)| player.player_index == 0)
+                    else {
+                        console.print("There's no game to create cards in".to_string());
+                        continue;
+                    };
+                    sandbox_actions.write(SandboxAction::CreateCard {
+                        name: name.clone(),
+                        owner,
+                        zone: Zone::Hand,
+                    });
+                }
+                let output = command.run(database.as_deref());
+                for line in output {
+                    console.print(line);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Rebuild the console whenever it changes
+pub fn sync_dev_console(
+    mut commands: Commands,
+    console: Res<DevConsole>,
+    roots: Query<Entity, With<DevConsoleRoot>>,
+) {
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    if !console.open {
+        return;
+    }
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                left: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            DevConsoleRoot,
+            AppLayer::GameUI.layer(),
+            Name::new("Developer Console"),
+        ))
+        .with_children(|parent| {
+            let output = console
+                .output
+                .iter()
+                .map(|line| (line.clone(), Color::srgb(0.8, 0.8, 0.8)));
+            let prompt = (format!("> {}_", console.input), Color::WHITE);
+            for (line, color) in output.chain(std::iter::once(prompt)) {
+                parent.spawn((
+                    Text::new(line),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(color),
+                ));
+            }
+        });
+}
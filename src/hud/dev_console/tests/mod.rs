@@ -0,0 +1,50 @@
+use crate::cards::CardDatabase;
+use crate::hud::dev_console::{ConsoleCommand, DevConsole};
+
+#[test]
+fn test_find_command_lists_matching_cards() {
+    let database = CardDatabase::with_builtin_sets();
+
+    let command = ConsoleCommand::parse("find lightning bolt").unwrap();
+    let output = command.run(Some(&database));
+    assert_eq!(output[0], "1 card found");
+    assert!(output[1].starts_with("Lightning Bolt"));
+
+    assert!(ConsoleCommand::parse("find cmc<x").is_err());
+    assert!(ConsoleCommand::parse("spawn Lightning Bolt").is_err());
+    assert_eq!(
+        ConsoleCommand::parse("find bolt").unwrap().run(None),
+        vec!["The card database isn't loaded".to_string()]
+    );
+}
+
+#[test]
+fn test_create_command_checks_the_card_exists() {
+    let database = CardDatabase::with_builtin_sets();
+
+    let command = ConsoleCommand::parse("create lightning bolt").unwrap();
+    assert_eq!(
+        command,
+        ConsoleCommand::CreateCard("lightning bolt".to_string())
+    );
+    assert_eq!(
+        command.run(Some(&database)),
+        vec!["Created Lightning Bolt in your hand".to_string()]
+    );
+    assert_eq!(
+        ConsoleCommand::CreateCard("Nothing".to_string()).run(Some(&database)),
+        vec!["No card named Nothing".to_string()]
+    );
+    assert!(ConsoleCommand::parse("create ").is_err());
+}
+
+#[test]
+fn test_console_keeps_the_latest_output() {
+    let mut console = DevConsole::default();
+    for line in 0..DevConsole::MAX_LINES + 5 {
+        console.print(line.to_string());
+    }
+
+    assert_eq!(console.output.len(), DevConsole::MAX_LINES);
+    assert_eq!(console.output[0], "5");
+}
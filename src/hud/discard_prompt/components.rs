@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+
+/// Root node of the discard prompt
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DiscardPromptRoot;
+
+/// Text telling the player how many cards to discard
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DiscardPromptText;
+
+/// Button selecting a card in hand to discard
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiscardCardButton(pub Entity);
+
+/// Button confirming the selected cards
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DiscardConfirmButton;
+
+/// Cards currently selected in the discard prompt
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiscardSelection {
+    /// Selected cards, in the order they were picked
+    pub cards: Vec<Entity>,
+}
+
+impl DiscardSelection {
+    /// Select or deselect a card, never selecting more than `limit` cards
+    ///
+    /// Returns whether the card is selected afterwards.
+    pub fn toggle(&mut self, card: Entity, limit: usize) -> bool {
+        if let Some(index) = self.cards.iter().position(|&selected| selected == card) {
+            self.cards.remove(index);
+            false
+        } else if self.cards.len() < limit {
+            self.cards.push(card);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whether a card is selected
+    pub fn contains(&self, card: Entity) -> bool {
+        self.cards.contains(&card)
+    }
+
+    /// Whether exactly `count` cards are selected
+    pub fn is_complete(&self, count: usize) -> bool {
+        self.cards.len() == count
+    }
+}
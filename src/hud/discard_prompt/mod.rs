@@ -0,0 +1,18 @@
+//! Discard prompt shown when the active player has to discard down to their
+//! maximum hand size during the cleanup step
+//!
+//! The player clicks cards to select them and confirms once exactly the required
+//! number is selected, which sends a
+//! [`DiscardCardsEvent`](crate::game_engine::cleanup::DiscardCardsEvent).
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{
+    DiscardCardButton, DiscardConfirmButton, DiscardPromptRoot, DiscardPromptText, DiscardSelection,
+};
+pub use plugin::DiscardPromptPlugin;
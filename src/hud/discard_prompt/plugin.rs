@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+use super::components::DiscardSelection;
+use super::systems::{
+    despawn_discard_prompt, handle_discard_card_clicks, handle_discard_confirm,
+    sync_discard_prompt, update_discard_prompt_display,
+};
+use crate::game_engine::cleanup::CleanupState;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the discard-to-hand-size prompt
+pub struct DiscardPromptPlugin;
+
+impl Plugin for DiscardPromptPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiscardSelection>()
+            .add_systems(OnExit(GameMenuState::InGame), despawn_discard_prompt)
+            .add_systems(
+                Update,
+                (
+                    sync_discard_prompt.run_if(resource_changed::<CleanupState>),
+                    handle_discard_card_clicks,
+                    handle_discard_confirm,
+                    update_discard_prompt_display,
+                )
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame)),
+            );
+    }
+}
@@ -0,0 +1,236 @@
+use bevy::prelude::*;
+
+use super::components::{
+    DiscardCardButton, DiscardConfirmButton, DiscardPromptRoot, DiscardPromptText, DiscardSelection,
+};
+use crate::camera::components::AppLayer;
+use crate::cards::CardName;
+use crate::game_engine::cleanup::{CleanupState, DiscardCardsEvent};
+use crate::game_engine::zones::ZoneManager;
+
+const CARD_COLOR: Color = Color::srgba(0.22, 0.22, 0.26, 0.95);
+const SELECTED_CARD_COLOR: Color = Color::srgba(0.7, 0.2, 0.2, 0.95);
+const CONFIRM_COLOR: Color = Color::srgba(0.2, 0.55, 0.25, 0.95);
+const DISABLED_CONFIRM_COLOR: Color = Color::srgba(0.15, 0.15, 0.15, 0.85);
+
+/// Show the prompt while a discard is pending and remove it afterwards
+pub fn sync_discard_prompt(
+    mut commands: Commands,
+    cleanup_state: Res<CleanupState>,
+    zone_manager: Option<Res<ZoneManager>>,
+    card_names: Query<&CardName>,
+    roots: Query<Entity, With<DiscardPromptRoot>>,
+    mut selection: ResMut<DiscardSelection>,
+) {
+    let pending = cleanup_state.pending_discard;
+    let has_prompt = !roots.is_empty();
+    if pending.is_some() == has_prompt {
+        return;
+    }
+
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    *selection = DiscardSelection::default();
+
+    let (Some(pending), Some(zone_manager)) = (pending, zone_manager) else {
+        return;
+    };
+    let hand = zone_manager.hand(pending.player).to_vec();
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(40.0),
+                left: Val::Percent(50.0),
+                margin: UiRect::left(Val::Px(-400.0)),
+                width: Val::Px(800.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.9)),
+            DiscardPromptRoot,
+            AppLayer::GameUI.layer(),
+            Name::new("Discard Prompt"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                DiscardPromptText,
+            ));
+
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        flex_wrap: FlexWrap::Wrap,
+                        justify_content: JustifyContent::Center,
+                        column_gap: Val::Px(6.0),
+                        row_gap: Val::Px(6.0),
+                        ..default()
+                    },
+                    Name::new("Discard Choices"),
+                ))
+                .with_children(|parent| {
+                    for (index, &card) in hand.iter().enumerate() {
+                        let label = card_names.get(card).map_or_else(
+                            |_| format!("Card {}", index + 1),
+                            |name| name.name.clone(),
+                        );
+                        parent
+                            .spawn((
+                                Button,
+                                Node {
+                                    min_width: Val::Px(110.0),
+                                    height: Val::Px(32.0),
+                                    padding: UiRect::horizontal(Val::Px(8.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                BackgroundColor(CARD_COLOR),
+                                DiscardCardButton(card),
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    Text::new(label),
+                                    TextFont {
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::WHITE),
+                                ));
+                            });
+                    }
+                });
+
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(140.0),
+                        height: Val::Px(34.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(DISABLED_CONFIRM_COLOR),
+                    DiscardConfirmButton,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("Discard"),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+        });
+}
+
+/// Despawn the discard prompt
+pub fn despawn_discard_prompt(
+    mut commands: Commands,
+    roots: Query<Entity, With<DiscardPromptRoot>>,
+    mut selection: ResMut<DiscardSelection>,
+) {
+    for entity in roots.iter() {
+        commands.entity(entity).despawn();
+    }
+    *selection = DiscardSelection::default();
+}
+
+/// Select or deselect cards when they are clicked
+pub fn handle_discard_card_clicks(
+    buttons: Query<(&Interaction, &DiscardCardButton), Changed<Interaction>>,
+    cleanup_state: Res<CleanupState>,
+    mut selection: ResMut<DiscardSelection>,
+) {
+    let Some(pending) = cleanup_state.pending_discard else {
+        return;
+    };
+
+    for (interaction, DiscardCardButton(card)) in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            selection.toggle(*card, pending.count);
+        }
+    }
+}
+
+/// Send the discard once exactly the required number of cards is selected
+pub fn handle_discard_confirm(
+    buttons: Query<&Interaction, (Changed<Interaction>, With<DiscardConfirmButton>)>,
+    cleanup_state: Res<CleanupState>,
+    selection: Res<DiscardSelection>,
+    mut discard_events: EventWriter<DiscardCardsEvent>,
+) {
+    let Some(pending) = cleanup_state.pending_discard else {
+        return;
+    };
+
+    for interaction in buttons.iter() {
+        if *interaction == Interaction::Pressed && selection.is_complete(pending.count) {
+            discard_events.write(DiscardCardsEvent {
+                player: pending.player,
+                cards: selection.cards.clone(),
+            });
+        }
+    }
+}
+
+/// Highlight selected cards and show how many are left to choose
+pub fn update_discard_prompt_display(
+    cleanup_state: Res<CleanupState>,
+    selection: Res<DiscardSelection>,
+    mut card_buttons: Query<
+        (&DiscardCardButton, &mut BackgroundColor),
+        Without<DiscardConfirmButton>,
+    >,
+    mut confirm_buttons: Query<&mut BackgroundColor, With<DiscardConfirmButton>>,
+    mut texts: Query<&mut Text, With<DiscardPromptText>>,
+    added: Query<(), Added<DiscardPromptRoot>>,
+) {
+    if !selection.is_changed() && added.is_empty() {
+        return;
+    }
+    let Some(pending) = cleanup_state.pending_discard else {
+        return;
+    };
+
+    for (DiscardCardButton(card), mut background) in card_buttons.iter_mut() {
+        background.0 = if selection.contains(*card) {
+            SELECTED_CARD_COLOR
+        } else {
+            CARD_COLOR
+        };
+    }
+
+    for mut background in confirm_buttons.iter_mut() {
+        background.0 = if selection.is_complete(pending.count) {
+            CONFIRM_COLOR
+        } else {
+            DISABLED_CONFIRM_COLOR
+        };
+    }
+
+    let label = format!(
+        "Discard down to hand size: choose {} card{} ({} selected)",
+        pending.count,
+        if pending.count == 1 { "" } else { "s" },
+        selection.cards.len()
+    );
+    for mut text in texts.iter_mut() {
+        text.0 = label.clone();
+    }
+}
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+use crate::hud::discard_prompt::DiscardSelection;
+
+#[test]
+fn test_selection_toggles_and_respects_limit() {
+    let mut world = World::new();
+    let cards: Vec<Entity> = (0..3).map(|_| world.spawn_empty().id()).collect();
+    let mut selection = DiscardSelection::default();
+
+    assert!(selection.toggle(cards[0], 2));
+    assert!(selection.toggle(cards[1], 2));
+    // A third card can't be selected while two are required
+    assert!(!selection.toggle(cards[2], 2));
+    assert!(selection.is_complete(2));
+
+    // Clicking a selected card deselects it
+    assert!(!selection.toggle(cards[0], 2));
+    assert!(!selection.contains(cards[0]));
+    assert!(!selection.is_complete(2));
+}
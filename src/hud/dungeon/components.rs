@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+
+use crate::game_engine::dungeon::{Dungeon, DungeonRoom, RoomChoice};
+
+/// Root node of the dungeon panel
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DungeonPanelRoot;
+
+/// Root node of the dialog choosing where to venture
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct RoomChoiceRoot {
+    /// The player choosing
+    pub player: Entity,
+    /// The options shown
+    pub options: Vec<DungeonRoom>,
+}
+
+/// Text naming the player choosing and the time left to choose
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RoomChoiceTitle;
+
+/// Button choosing a dungeon to start or a room to move to
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoomChoiceButton(pub DungeonRoom);
+
+/// A player's line in the dungeon panel
+pub fn dungeon_line(
+    player_name: &str,
+    room: Option<DungeonRoom>,
+    completed: &[Dungeon],
+    has_initiative: bool,
+) -> String {
+    let mut line = match room {
+        Some(room) => format!("{}: {} ({})", player_name, room.name(), room.dungeon.name()),
+        None => format!("{}: not in a dungeon", player_name),
+    };
+    if !completed.is_empty() {
+        line.push_str(&format!(", {} completed", completed.len()));
+    }
+    if has_initiative {
+        line.push_str(" - initiative");
+    }
+    line
+}
+
+/// Text of a choice button: the dungeon when starting one, otherwise the room
+pub fn room_choice_label(choice: &RoomChoice, room: DungeonRoom) -> String {
+    if choice.is_starting_dungeon() {
+        room.dungeon.name().to_string()
+    } else {
+        format!("{}: {}", room.name(), room.ability())
+    }
+}
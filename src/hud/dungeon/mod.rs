@@ -0,0 +1,21 @@
+//! Dungeon progress, room choices and the initiative
+//!
+//! A panel lists every player who is in a dungeon with the room their venture
+//! marker is in and the dungeons they've completed, and marks the player with the
+//! initiative. When a venture can go more than one way, a dialog offers the
+//! dungeons or rooms to choose from and sends a
+//! [`ChooseRoomEvent`](crate::game_engine::dungeon::ChooseRoomEvent); the engine
+//! takes the first option itself after a timeout.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{
+    DungeonPanelRoot, RoomChoiceButton, RoomChoiceRoot, RoomChoiceTitle, dungeon_line,
+    room_choice_label,
+};
+pub use plugin::DungeonHudPlugin;
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+use super::systems::{
+    despawn_dungeon_hud, handle_room_choice_clicks, sync_dungeon_panel, sync_room_choice_dialog,
+    update_room_choice_title,
+};
+use crate::game_engine::dungeon::Dungeons;
+use crate::game_engine::politics::PoliticsSystem;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the dungeon panel and the room choice dialog
+pub struct DungeonHudPlugin;
+
+impl Plugin for DungeonHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMenuState::InGame), despawn_dungeon_hud)
+            .add_systems(
+                Update,
+                (
+                    handle_room_choice_clicks,
+                    sync_room_choice_dialog.run_if(resource_changed::<Dungeons>),
+                    sync_dungeon_panel.run_if(
+                        resource_changed::<Dungeons>.or(resource_changed::<PoliticsSystem>),
+                    ),
+                    update_room_choice_title,
+                )
+                    .chain()
+                    .run_if(
+                        in_state(GameMenuState::InGame)
+                            .and(resource_exists::<Dungeons>)
+                            .and(resource_exists::<PoliticsSystem>),
+                    ),
+            );
+    }
+}
@@ -0,0 +1,244 @@
+use bevy::prelude::*;
+
+use super::components::{
+    DungeonPanelRoot, RoomChoiceButton, RoomChoiceRoot, RoomChoiceTitle, dungeon_line,
+    room_choice_label,
+};
+use crate::camera::components::AppLayer;
+use crate::camera::z_order::UiLayer;
+use crate::game_engine::dungeon::{ChooseRoomEvent, Dungeons};
+use crate::game_engine::politics::PoliticsSystem;
+use crate::player::Player;
+
+const PANEL_COLOR: Color = Color::srgba(0.05, 0.05, 0.08, 0.9);
+const CHOICE_COLOR: Color = Color::srgba(0.22, 0.22, 0.26, 0.95);
+const HOVERED_CHOICE_COLOR: Color = Color::srgba(0.32, 0.32, 0.38, 0.95);
+
+/// Rebuild the dungeon panel when a marker moves or the initiative changes hands
+///
+/// The panel is only shown once someone has ventured or holds the initiative.
+pub fn sync_dungeon_panel(
+    mut commands: Commands,
+    dungeons: Res<Dungeons>,
+    politics: Res<PoliticsSystem>,
+    players: Query<(Entity, &Player)>,
+    roots: Query<Entity, With<DungeonPanelRoot>>,
+) {
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+
+    let mut players: Vec<(Entity, &Player)> = players
+        .iter()
+        .filter(|(entity, _)| {
+            dungeons.current_room(*entity).is_some()
+                || !dungeons.completed_by(*entity).is_empty()
+                || politics.initiative_holder == Some(*entity)
+        })
+        .collect();
+    if players.is_empty() {
+        return;
+    }
+    players.sort_by_key(|(_, player)| player.player_index);
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                left: Val::Px(16.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(PANEL_COLOR),
+            DungeonPanelRoot,
+            AppLayer::GameUI.layer(),
+            Name::new("Dungeon Panel"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Dungeons"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            for (entity, player) in &players {
+                parent.spawn((
+                    Text::new(dungeon_line(
+                        &player.name,
+                        dungeons.current_room(*entity),
+                        dungeons.completed_by(*entity),
+                        politics.initiative_holder == Some(*entity),
+                    )),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                ));
+            }
+        });
+}
+
+/// Show the room choice dialog while a player chooses where to venture
+pub fn sync_room_choice_dialog(
+    mut commands: Commands,
+    dungeons: Res<Dungeons>,
+    roots: Query<(Entity, &RoomChoiceRoot)>,
+) {
+    let choosing = dungeons.choice.as_ref();
+    let shown = roots.iter().next().map(|(_, root)| root);
+    if choosing.map(|choice| (choice.player, &choice.options))
+        == shown.map(|root| (root.player, &root.options))
+    {
+        return;
+    }
+    for (root, _) in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    let Some(choice) = choosing else {
+        return;
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            UiLayer::Dialog.global_z_index(),
+            RoomChoiceRoot {
+                player: choice.player,
+                options: choice.options.clone(),
+            },
+            AppLayer::GameUI.layer(),
+            Name::new("Room Choice Dialog"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(480.0),
+                        padding: UiRect::all(Val::Px(16.0)),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(10.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.08, 0.1, 0.97)),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        RoomChoiceTitle,
+                    ));
+
+                    for &room in &choice.options {
+                        parent
+                            .spawn((
+                                Button,
+                                Node {
+                                    width: Val::Percent(100.0),
+                                    min_height: Val::Px(38.0),
+                                    padding: UiRect::all(Val::Px(6.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                BackgroundColor(CHOICE_COLOR),
+                                RoomChoiceButton(room),
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    Text::new(room_choice_label(choice, room)),
+                                    TextFont {
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::WHITE),
+                                ));
+                            });
+                    }
+                });
+        });
+}
+
+/// Choose the clicked dungeon or room for the player choosing
+pub fn handle_room_choice_clicks(
+    mut buttons: Query<
+        (&Interaction, &RoomChoiceButton, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    dungeons: Res<Dungeons>,
+    mut choices: EventWriter<ChooseRoomEvent>,
+) {
+    for (interaction, RoomChoiceButton(room), mut background) in buttons.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                let Some(choice) = &dungeons.choice else {
+                    continue;
+                };
+                choices.write(ChooseRoomEvent {
+                    player: choice.player,
+                    room: *room,
+                });
+            }
+            Interaction::Hovered => background.0 = HOVERED_CHOICE_COLOR,
+            Interaction::None => background.0 = CHOICE_COLOR,
+        }
+    }
+}
+
+/// Name the player choosing and count down the time left to choose
+pub fn update_room_choice_title(
+    dungeons: Res<Dungeons>,
+    players: Query<&Player>,
+    mut titles: Query<&mut Text, With<RoomChoiceTitle>>,
+) {
+    let Some(choice) = dungeons.choice.as_ref() else {
+        return;
+    };
+    let name = players
+        .get(choice.player)
+        .map_or("Player", |player| player.name.as_str());
+    let what = if choice.is_starting_dungeon() {
+        "choose a dungeon to venture into"
+    } else {
+        "choose the next room"
+    };
+    let title = format!(
+        "{}: {} ({:.0}s)",
+        name,
+        what,
+        choice.remaining_secs().ceil()
+    );
+    for mut text in titles.iter_mut() {
+        if text.0 != title {
+            text.0 = title.clone();
+        }
+    }
+}
+
+/// Despawn the dungeon panel and the room choice dialog
+pub fn despawn_dungeon_hud(
+    mut commands: Commands,
+    panels: Query<Entity, With<DungeonPanelRoot>>,
+    dialogs: Query<Entity, With<RoomChoiceRoot>>,
+) {
+    for entity in panels.iter().chain(dialogs.iter()) {
+        commands.entity(entity).despawn();
+    }
+}
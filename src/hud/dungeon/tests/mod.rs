@@ -0,0 +1,91 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+
+use crate::game_engine::dungeon::{Dungeon, Dungeons, RoomChoice};
+use crate::game_engine::politics::PoliticsSystem;
+use crate::hud::dungeon::systems::{sync_dungeon_panel, sync_room_choice_dialog};
+use crate::hud::dungeon::{
+    DungeonPanelRoot, RoomChoiceButton, RoomChoiceRoot, dungeon_line, room_choice_label,
+};
+use crate::player::Player;
+
+#[test]
+fn test_dungeon_lines() {
+    let lost_well = Dungeon::Undercity.room_named("Lost Well");
+    assert_eq!(
+        dungeon_line("Alice", lost_well, &[], false),
+        "Alice: Lost Well (Undercity)"
+    );
+    assert_eq!(
+        dungeon_line(
+            "Bob",
+            None,
+            &[Dungeon::TombOfAnnihilation, Dungeon::Undercity],
+            true
+        ),
+        "Bob: not in a dungeon, 2 completed - initiative"
+    );
+}
+
+#[test]
+fn test_choice_labels() {
+    let player = Entity::from_raw(1);
+    let starting = RoomChoice {
+        player,
+        options: vec![Dungeon::TombOfAnnihilation.entrance()],
+        waited: 0.0,
+    };
+    assert_eq!(
+        room_choice_label(&starting, Dungeon::TombOfAnnihilation.entrance()),
+        "Tomb of Annihilation"
+    );
+
+    let storeroom = Dungeon::LostMineOfPhandelver
+        .room_named("Storeroom")
+        .unwrap();
+    let moving = RoomChoice {
+        player,
+        options: vec![storeroom],
+        waited: 0.0,
+    };
+    assert_eq!(
+        room_choice_label(&moving, storeroom),
+        "Storeroom: Put a +1/+1 counter on target creature."
+    );
+}
+
+fn count<C: Component>(world: &mut World) -> usize {
+    world.query_filtered::<(), With<C>>().iter(world).count()
+}
+
+#[test]
+fn test_hud_follows_the_dungeons() {
+    let mut world = World::new();
+    world.init_resource::<Dungeons>();
+    world.init_resource::<PoliticsSystem>();
+    let alice = world.spawn(Player::new("Alice")).id();
+    world.spawn(Player::new("Bob").with_player_index(1));
+
+    world.run_system_once(sync_dungeon_panel).unwrap();
+    world.run_system_once(sync_room_choice_dialog).unwrap();
+    assert_eq!(count::<DungeonPanelRoot>(&mut world), 0);
+    assert_eq!(count::<RoomChoiceRoot>(&mut world), 0);
+
+    world.resource_mut::<PoliticsSystem>().initiative_holder = Some(alice);
+    world.resource_mut::<Dungeons>().choice = Some(RoomChoice {
+        player: alice,
+        options: Dungeon::VENTURE_CHOICES
+            .iter()
+            .map(|dungeon| dungeon.entrance())
+            .collect(),
+        waited: 0.0,
+    });
+    world.run_system_once(sync_dungeon_panel).unwrap();
+    world.run_system_once(sync_room_choice_dialog).unwrap();
+    assert_eq!(count::<DungeonPanelRoot>(&mut world), 1);
+    assert_eq!(count::<RoomChoiceButton>(&mut world), 3);
+
+    world.resource_mut::<Dungeons>().choice = None;
+    world.run_system_once(sync_room_choice_dialog).unwrap();
+    assert_eq!(count::<RoomChoiceRoot>(&mut world), 0);
+}
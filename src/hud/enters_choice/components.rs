@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+
+use crate::game_engine::enters_choice::{EntersChoiceKind, EntersChoiceValue};
+use crate::mana::ManaColor;
+
+/// Root node of the dialog for a choice made as a permanent enters
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct EntersChoiceRoot {
+    /// The permanent being chosen for
+    pub permanent: Entity,
+    /// The options shown
+    pub options: Vec<EntersChoiceValue>,
+}
+
+/// Text naming the player choosing, what they choose and the time left
+#[derive(Component, Debug, Clone, Copy)]
+pub struct EntersChoiceTitle;
+
+/// Button choosing an option
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct EntersChoiceButton(pub EntersChoiceValue);
+
+/// The name of one of the five colors
+pub fn color_name(color: ManaColor) -> &'static str {
+    match color {
+        ManaColor::WHITE => "White",
+        ManaColor::BLUE => "Blue",
+        ManaColor::BLACK => "Black",
+        ManaColor::RED => "Red",
+        ManaColor::GREEN => "Green",
+        _ => "Colorless",
+    }
+}
+
+/// What a player is asked to choose
+pub fn choice_prompt(kind: EntersChoiceKind) -> &'static str {
+    match kind {
+        EntersChoiceKind::Color => "choose a color",
+        EntersChoiceKind::CardName => "choose a card name",
+        EntersChoiceKind::Opponent => "choose an opponent",
+    }
+}
+
+/// The label of an option's button
+///
+/// Opponents are shown by name, given by `player_name`.
+pub fn enters_choice_label(
+    value: &EntersChoiceValue,
+    player_name: impl Fn(Entity) -> Option<String>,
+) -> String {
+    match value {
+        EntersChoiceValue::Color(color) => color_name(*color).to_string(),
+        EntersChoiceValue::CardName(name) => name.clone(),
+        EntersChoiceValue::Opponent(player) => {
+            player_name(*player).unwrap_or_else(|| "Player".to_string())
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Prompt for choices made as a permanent enters the battlefield
+//!
+//! While a permanent is waiting for its controller to choose a color, card name
+//! or opponent, a dialog offers the options and sends a
+//! [`ChooseOnEntryEvent`](crate::game_engine::enters_choice::ChooseOnEntryEvent)
+//! for the one clicked; the engine takes the first option itself after a timeout.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{
+    EntersChoiceButton, EntersChoiceRoot, EntersChoiceTitle, color_name, enters_choice_label,
+};
+pub use plugin::EntersChoicePromptPlugin;
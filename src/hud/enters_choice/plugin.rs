@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+use super::systems::{
+    despawn_enters_choice_dialog, handle_enters_choice_clicks, sync_enters_choice_dialog,
+    update_enters_choice_title,
+};
+use crate::game_engine::enters_choice::EntersChoices;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the dialog prompting choices made as permanents enter
+pub struct EntersChoicePromptPlugin;
+
+impl Plugin for EntersChoicePromptPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMenuState::InGame), despawn_enters_choice_dialog)
+            .add_systems(
+                Update,
+                (
+                    handle_enters_choice_clicks,
+                    sync_enters_choice_dialog.run_if(resource_changed::<EntersChoices>),
+                    update_enters_choice_title,
+                )
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame).and(resource_exists::<EntersChoices>)),
+            );
+    }
+}
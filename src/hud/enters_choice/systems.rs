@@ -0,0 +1,186 @@
+use bevy::prelude::*;
+
+use super::components::{
+    EntersChoiceButton, EntersChoiceRoot, EntersChoiceTitle, choice_prompt, enters_choice_label,
+};
+use crate::camera::components::AppLayer;
+use crate::camera::z_order::UiLayer;
+use crate::cards::Card;
+use crate::game_engine::enters_choice::{ChooseOnEntryEvent, EntersChoices};
+use crate::player::Player;
+
+const CHOICE_COLOR: Color = Color::srgba(0.22, 0.22, 0.26, 0.95);
+const HOVERED_CHOICE_COLOR: Color = Color::srgba(0.32, 0.32, 0.38, 0.95);
+
+/// Show the dialog while a player chooses for a permanent entering
+pub fn sync_enters_choice_dialog(
+    mut commands: Commands,
+    choices: Res<EntersChoices>,
+    players: Query<&Player>,
+    roots: Query<(Entity, &EntersChoiceRoot)>,
+) {
+    let choosing = choices.choice.as_ref();
+    let shown = roots.iter().next().map(|(_, root)| root);
+    if choosing.map(|choice| (choice.permanent, &choice.options))
+        == shown.map(|root| (root.permanent, &root.options))
+    {
+        return;
+    }
+    for (root, _) in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    let Some(choice) = choosing else {
+        return;
+    };
+    let player_name = |player: Entity| players.get(player).ok().map(|player| player.name.clone());
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            UiLayer::Dialog.global_z_index(),
+            EntersChoiceRoot {
+                permanent: choice.permanent,
+                options: choice.options.clone(),
+            },
+            AppLayer::GameUI.layer(),
+            Name::new("Enters Choice Dialog"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(480.0),
+                        padding: UiRect::all(Val::Px(16.0)),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(10.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.08, 0.1, 0.97)),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        EntersChoiceTitle,
+                    ));
+
+                    // Card names can be many, so options wrap onto several rows
+                    parent
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            flex_wrap: FlexWrap::Wrap,
+                            justify_content: JustifyContent::Center,
+                            row_gap: Val::Px(6.0),
+                            column_gap: Val::Px(6.0),
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            for value in &choice.options {
+                                parent
+                                    .spawn((
+                                        Button,
+                                        Node {
+                                            min_width: Val::Px(120.0),
+                                            min_height: Val::Px(34.0),
+                                            padding: UiRect::all(Val::Px(6.0)),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            ..default()
+                                        },
+                                        BackgroundColor(CHOICE_COLOR),
+                                        EntersChoiceButton(value.clone()),
+                                    ))
+                                    .with_children(|parent| {
+                                        parent.spawn((
+                                            Text::new(enters_choice_label(value, player_name)),
+                                            TextFont {
+                                                font_size: 14.0,
+                                                ..default()
+                                            },
+                                            TextColor(Color::WHITE),
+                                        ));
+                                    });
+                            }
+                        });
+                });
+        });
+}
+
+/// Choose the clicked option for the permanent being chosen for
+pub fn handle_enters_choice_clicks(
+    mut buttons: Query<
+        (&Interaction, &EntersChoiceButton, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    choices: Res<EntersChoices>,
+    mut chosen: EventWriter<ChooseOnEntryEvent>,
+) {
+    for (interaction, EntersChoiceButton(value), mut background) in buttons.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                let Some(choice) = &choices.choice else {
+                    continue;
+                };
+                chosen.write(ChooseOnEntryEvent {
+                    player: choice.player,
+                    permanent: choice.permanent,
+                    value: value.clone(),
+                });
+            }
+            Interaction::Hovered => background.0 = HOVERED_CHOICE_COLOR,
+            Interaction::None => background.0 = CHOICE_COLOR,
+        }
+    }
+}
+
+/// Name the player choosing and the permanent, and count down the time left
+pub fn update_enters_choice_title(
+    choices: Res<EntersChoices>,
+    players: Query<&Player>,
+    cards: Query<&Card>,
+    mut titles: Query<&mut Text, With<EntersChoiceTitle>>,
+) {
+    let Some(choice) = choices.choice.as_ref() else {
+        return;
+    };
+    let name = players
+        .get(choice.player)
+        .map_or("Player", |player| player.name.as_str());
+    let permanent = cards
+        .get(choice.permanent)
+        .map_or("a permanent", |card| card.name.name.as_str());
+    let title = format!(
+        "{}: {} for {} ({:.0}s)",
+        name,
+        choice_prompt(choice.kind),
+        permanent,
+        choice.remaining_secs().ceil()
+    );
+    for mut text in titles.iter_mut() {
+        if text.0 != title {
+            text.0 = title.clone();
+        }
+    }
+}
+
+/// Despawn the dialog
+pub fn despawn_enters_choice_dialog(
+    mut commands: Commands,
+    roots: Query<Entity, With<EntersChoiceRoot>>,
+) {
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+}
@@ -0,0 +1,68 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+
+use crate::game_engine::enters_choice::{
+    EntersChoice, EntersChoiceKind, EntersChoiceValue, EntersChoices,
+};
+use crate::hud::enters_choice::systems::sync_enters_choice_dialog;
+use crate::hud::enters_choice::{EntersChoiceButton, EntersChoiceRoot, enters_choice_label};
+use crate::mana::ManaColor;
+
+#[test]
+fn test_enters_choice_labels() {
+    let bob = Entity::from_raw(2);
+    let names = |player: Entity| (player == bob).then(|| "Bob".to_string());
+    assert_eq!(
+        enters_choice_label(&EntersChoiceValue::Color(ManaColor::BLACK), names),
+        "Black"
+    );
+    assert_eq!(
+        enters_choice_label(
+            &EntersChoiceValue::CardName("Brutal Cathar".to_string()),
+            names
+        ),
+        "Brutal Cathar"
+    );
+    assert_eq!(
+        enters_choice_label(&EntersChoiceValue::Opponent(bob), names),
+        "Bob"
+    );
+}
+
+#[test]
+fn test_dialog_follows_the_choice() {
+    let mut world = World::new();
+    world.init_resource::<EntersChoices>();
+    let count = |world: &mut World| {
+        world
+            .query_filtered::<(), With<EntersChoiceButton>>()
+            .iter(world)
+            .count()
+    };
+
+    let permanent = world.spawn_empty().id();
+    let player = world.spawn_empty().id();
+    world.resource_mut::<EntersChoices>().choice = Some(EntersChoice {
+        permanent,
+        player,
+        kind: EntersChoiceKind::Color,
+        options: vec![
+            EntersChoiceValue::Color(ManaColor::RED),
+            EntersChoiceValue::Color(ManaColor::GREEN),
+        ],
+        waited: 0.0,
+    });
+    world.run_system_once(sync_enters_choice_dialog).unwrap();
+    world.run_system_once(sync_enters_choice_dialog).unwrap();
+    assert_eq!(count(&mut world), 2);
+
+    world.resource_mut::<EntersChoices>().choice = None;
+    world.run_system_once(sync_enters_choice_dialog).unwrap();
+    assert_eq!(
+        world
+            .query_filtered::<(), With<EntersChoiceRoot>>()
+            .iter(&mut world)
+            .count(),
+        0
+    );
+}
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+use crate::game_engine::zones::{ExiledBy, PlayUntil};
+
+/// Text listing the cards a player may play from exile
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ExileStrip {
+    /// The player whose exiled cards are listed
+    pub player: Entity,
+}
+
+/// How a card exiled with memory is listed on `turn`
+///
+/// `hidden` names a face-down card for players other than its owner.
+pub fn exile_strip_entry(name: &str, exiled_by: &ExiledBy, turn: u32, hidden: bool) -> String {
+    let name = if exiled_by.face_down && hidden {
+        "Face-down card"
+    } else {
+        name
+    };
+    let window = match exiled_by.may_play_until {
+        Some(PlayUntil::EndOfTurn(_)) => "this turn",
+        Some(PlayUntil::LaterTurns { exiled_on }) if turn <= exiled_on => "from next turn",
+        Some(PlayUntil::LaterTurns { .. } | PlayUntil::Indefinitely) => "any turn",
+        None => "can't be played",
+    };
+    format!("{} ({})", name, window)
+}
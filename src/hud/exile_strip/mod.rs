@@ -0,0 +1,16 @@
+//! Strip of cards exiled by effects that let their owner play them later
+//!
+//! Impulse draws, adventures and foretold cards sit in exile until they're played.
+//! A line of text above each player's battlefield lists them with how long they
+//! stay playable, so they aren't forgotten among the rest of the exile zone.
+//! Face-down cards are only named on their owner's strip.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{ExileStrip, exile_strip_entry};
+pub use plugin::ExileStripPlugin;
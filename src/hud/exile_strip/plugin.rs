@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+use super::systems::{despawn_exile_strips, spawn_exile_strips, update_exile_strips};
+use crate::game_engine::state::GameState;
+use crate::game_engine::zones::{ZoneManager, publish_zone_mutations};
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the strips of cards playable from exile
+pub struct ExileStripPlugin;
+
+impl Plugin for ExileStripPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMenuState::InGame), despawn_exile_strips)
+            .add_systems(
+                Update,
+                (spawn_exile_strips, update_exile_strips)
+                    .chain()
+                    .after(publish_zone_mutations)
+                    .run_if(
+                        in_state(GameMenuState::InGame)
+                            .and(resource_exists::<ZoneManager>)
+                            .and(resource_exists::<GameState>),
+                    ),
+            );
+    }
+}
@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+
+use super::components::{ExileStrip, exile_strip_entry};
+use crate::camera::components::AppLayer;
+use crate::cards::Card;
+use crate::game_engine::state::GameState;
+use crate::game_engine::zones::{ZoneManager, ZoneMutationEvent};
+use crate::player::playmat::PlayerPlaymat;
+
+const STRIP_COLOR: Color = Color::srgba(0.75, 0.8, 1.0, 0.9);
+
+/// Where the strip sits on a playmat, in the playmat's local space, just past
+/// the far edge of the battlefield
+const STRIP_OFFSET: Vec3 = Vec3::new(0.0, 0.0, 5.0);
+
+/// The text of `player`'s strip, empty when nothing can be played from exile
+fn strip_text(
+    zones: &ZoneManager,
+    cards: &Query<&Card>,
+    player: Entity,
+    turn: u32,
+    hidden: bool,
+) -> String {
+    let entries: Vec<String> = zones
+        .exiled_with_memory(player)
+        .filter(|(_, exiled_by)| exiled_by.still_playable(turn))
+        .map(|(card, exiled_by)| {
+            let name = cards
+                .get(card)
+                .map_or("Unknown card", |card| card.name.name.as_str());
+            exile_strip_entry(name, exiled_by, turn, hidden)
+        })
+        .collect();
+    if entries.is_empty() {
+        String::new()
+    } else {
+        format!("Exiled: {}", entries.join("   "))
+    }
+}
+
+/// Put a strip on every playmat that doesn't have one yet
+pub fn spawn_exile_strips(
+    mut commands: Commands,
+    zones: Res<ZoneManager>,
+    game_state: Res<GameState>,
+    cards: Query<&Card>,
+    playmats: Query<(Entity, &PlayerPlaymat, &Transform)>,
+    strips: Query<&ExileStrip>,
+) {
+    for (entity, playmat, transform) in playmats.iter() {
+        if strips.iter().any(|strip| strip.player == playmat.player_id) {
+            continue;
+        }
+
+        // The local player is always index 0 and sees their own face-down cards
+        let text = strip_text(
+            &zones,
+            &cards,
+            playmat.player_id,
+            game_state.turn_number,
+            playmat.player_index != 0,
+        );
+        // Undo the playmat's rotation so the text reads upright
+        let strip = commands
+            .spawn((
+                Text2d::new(text),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(STRIP_COLOR),
+                Transform::from_translation(STRIP_OFFSET)
+                    .with_rotation(transform.rotation.inverse()),
+                ExileStrip {
+                    player: playmat.player_id,
+                },
+                AppLayer::game_layers(),
+                Name::new("Exile Strip"),
+            ))
+            .id();
+        commands.entity(entity).add_child(strip);
+    }
+}
+
+/// Refresh the strips when cards move or a new turn starts
+pub fn update_exile_strips(
+    mut mutation_events: EventReader<ZoneMutationEvent>,
+    zones: Res<ZoneManager>,
+    game_state: Res<GameState>,
+    cards: Query<&Card>,
+    playmats: Query<&PlayerPlaymat>,
+    mut strips: Query<(&ExileStrip, &mut Text2d)>,
+) {
+    let zones_changed = mutation_events.read().count() > 0;
+    if !zones_changed && !game_state.is_changed() {
+        return;
+    }
+
+    for (strip, mut text) in strips.iter_mut() {
+        let hidden = playmats
+            .iter()
+            .find(|playmat| playmat.player_id == strip.player)
+            .is_none_or(|playmat| playmat.player_index != 0);
+        let label = strip_text(&zones, &cards, strip.player, game_state.turn_number, hidden);
+        if text.0 != label {
+            text.0 = label;
+        }
+    }
+}
+
+/// Remove the strips when leaving the game
+pub fn despawn_exile_strips(mut commands: Commands, strips: Query<Entity, With<ExileStrip>>) {
+    for strip in strips.iter() {
+        commands.entity(strip).despawn();
+    }
+}
@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+
+use crate::cards::{Card, CardDetails, CardTypes};
+use crate::game_engine::state::GameState;
+use crate::game_engine::zones::{ExiledBy, ZoneManager, ZoneMutationEvent, publish_zone_mutations};
+use crate::hud::exile_strip::systems::{spawn_exile_strips, update_exile_strips};
+use crate::hud::exile_strip::{ExileStrip, exile_strip_entry};
+use crate::mana::Mana;
+use crate::player::playmat::PlayerPlaymat;
+
+#[test]
+fn test_exile_strip_entries() {
+    let source = Entity::from_raw(1);
+    assert_eq!(
+        exile_strip_entry("Shock", &ExiledBy::impulse(source, 2), 2, true),
+        "Shock (this turn)"
+    );
+    assert_eq!(
+        exile_strip_entry("Saw It Coming", &ExiledBy::foretold(source, 2), 2, false),
+        "Saw It Coming (from next turn)"
+    );
+    assert_eq!(
+        exile_strip_entry("Saw It Coming", &ExiledBy::foretold(source, 2), 3, true),
+        "Face-down card (any turn)"
+    );
+}
+
+fn strip_text(app: &mut App, player: Entity) -> Option<String> {
+    app.world_mut()
+        .query::<(&ExileStrip, &Text2d)>()
+        .iter(app.world())
+        .find(|(strip, _)| strip.player == player)
+        .map(|(_, text)| text.0.clone())
+}
+
+#[test]
+fn test_strips_list_cards_while_they_can_be_played() {
+    let mut app = App::new();
+    app.add_event::<ZoneMutationEvent>()
+        .init_resource::<ZoneManager>()
+        .insert_resource(GameState::builder().turn_number(1).build())
+        .add_systems(
+            Update,
+            (
+                publish_zone_mutations,
+                spawn_exile_strips,
+                update_exile_strips,
+            )
+                .chain(),
+        );
+
+    let local = app.world_mut().spawn_empty().id();
+    let opponent = app.world_mut().spawn_empty().id();
+    for (index, player) in [local, opponent].into_iter().enumerate() {
+        app.world_mut().spawn((
+            PlayerPlaymat {
+                player_id: player,
+                player_index: index,
+            },
+            Transform::default(),
+        ));
+        app.world_mut()
+            .resource_mut::<ZoneManager>()
+            .init_player_zones(player);
+    }
+    let card = |name: &str| {
+        Card::builder(name)
+            .cost(Mana::default())
+            .types(CardTypes::INSTANT)
+            .details(CardDetails::Other)
+            .build_or_panic()
+    };
+    let shock = app.world_mut().spawn(card("Shock")).id();
+    let foretold = app.world_mut().spawn(card("Saw It Coming")).id();
+    {
+        let mut zones = app.world_mut().resource_mut::<ZoneManager>();
+        zones.exile_with(local, shock, ExiledBy::impulse(shock, 1));
+        zones.exile_with(opponent, foretold, ExiledBy::foretold(foretold, 1));
+    }
+    app.update();
+
+    assert_eq!(
+        strip_text(&mut app, local).as_deref(),
+        Some("Exiled: Shock (this turn)")
+    );
+    assert_eq!(
+        strip_text(&mut app, opponent).as_deref(),
+        Some("Exiled: Face-down card (from next turn)")
+    );
+
+    // The impulse draw runs out when the turn ends
+    app.world_mut().resource_mut::<GameState>().turn_number = 2;
+    app.update();
+    assert_eq!(strip_text(&mut app, local).as_deref(), Some(""));
+}
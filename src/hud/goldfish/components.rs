@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+use crate::game_engine::goldfish::GoldfishAction;
+
+/// Root node of the goldfish panel
+#[derive(Component, Debug, Clone, Copy)]
+pub struct GoldfishPanelRoot;
+
+/// Button performing a goldfish quick action
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GoldfishButton(pub GoldfishAction);
+
+/// Text showing the turn number and hand and library sizes
+#[derive(Component, Debug, Clone, Copy)]
+pub struct GoldfishStatusText;
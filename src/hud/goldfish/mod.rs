@@ -0,0 +1,12 @@
+//! Controls for goldfish games
+//!
+//! While a deck is being goldfished (see [`crate::game_engine::goldfish`]), a panel
+//! offers buttons to draw, untap everything, start the next turn and deal a new
+//! hand, and shows the turn number and hand and library sizes.
+
+mod components;
+mod plugin;
+mod systems;
+
+pub use components::{GoldfishButton, GoldfishPanelRoot, GoldfishStatusText};
+pub use plugin::GoldfishHudPlugin;
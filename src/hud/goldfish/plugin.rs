@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+use super::systems::{
+    despawn_goldfish_panel, handle_goldfish_buttons, spawn_goldfish_panel, update_goldfish_status,
+};
+use crate::game_engine::goldfish::ActiveGoldfish;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the goldfish controls
+pub struct GoldfishHudPlugin;
+
+impl Plugin for GoldfishHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMenuState::InGame), despawn_goldfish_panel)
+            .add_systems(
+                Update,
+                (
+                    spawn_goldfish_panel.run_if(resource_added::<ActiveGoldfish>),
+                    handle_goldfish_buttons,
+                    update_goldfish_status,
+                )
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame).and(resource_exists::<ActiveGoldfish>)),
+            );
+    }
+}
@@ -0,0 +1,129 @@
+use bevy::prelude::*;
+
+use super::components::{GoldfishButton, GoldfishPanelRoot, GoldfishStatusText};
+use crate::camera::components::AppLayer;
+use crate::game_engine::goldfish::{ActiveGoldfish, GoldfishAction};
+use crate::game_engine::turns::TurnManager;
+use crate::game_engine::zones::ZoneManager;
+
+const PANEL_COLOR: Color = Color::srgba(0.05, 0.05, 0.08, 0.9);
+const BUTTON_COLOR: Color = Color::srgba(0.22, 0.22, 0.26, 0.95);
+
+/// Show the goldfish panel when a goldfish game starts
+pub fn spawn_goldfish_panel(mut commands: Commands, roots: Query<Entity, With<GoldfishPanelRoot>>) {
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                right: Val::Px(16.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(PANEL_COLOR),
+            GoldfishPanelRoot,
+            AppLayer::GameUI.layer(),
+            Name::new("Goldfish Panel"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Goldfish"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                GoldfishStatusText,
+            ));
+
+            for action in GoldfishAction::ALL {
+                spawn_goldfish_button(parent, action);
+            }
+        });
+}
+
+fn spawn_goldfish_button(parent: &mut ChildSpawnerCommands, action: GoldfishAction) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(120.0),
+                height: Val::Px(28.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(BUTTON_COLOR),
+            GoldfishButton(action),
+            Name::new(format!("Goldfish {} Button", action.label())),
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(action.label()),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// Despawn the goldfish panel
+pub fn despawn_goldfish_panel(
+    mut commands: Commands,
+    roots: Query<Entity, With<GoldfishPanelRoot>>,
+) {
+    for entity in roots.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Send the quick action of a clicked button
+pub fn handle_goldfish_buttons(
+    buttons: Query<(&Interaction, &GoldfishButton), Changed<Interaction>>,
+    mut actions: EventWriter<GoldfishAction>,
+) {
+    for (interaction, GoldfishButton(action)) in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            actions.write(*action);
+        }
+    }
+}
+
+/// Keep the turn number and hand and library sizes up to date
+pub fn update_goldfish_status(
+    goldfish: Res<ActiveGoldfish>,
+    turn_manager: Option<Res<TurnManager>>,
+    zones: Option<Res<ZoneManager>>,
+    mut texts: Query<&mut Text, With<GoldfishStatusText>>,
+) {
+    let (Some(turn_manager), Some(zones)) = (turn_manager, zones) else {
+        return;
+    };
+    let status = format!(
+        "Turn {}  Hand {}  Library {}",
+        turn_manager.turn_number,
+        zones.hand(goldfish.player).len(),
+        zones.library(goldfish.player).len()
+    );
+    for mut text in texts.iter_mut() {
+        if text.0 != status {
+            text.0 = status.clone();
+        }
+    }
+}
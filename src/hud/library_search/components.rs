@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+
+use crate::game_engine::search::LibrarySearch;
+use crate::game_engine::zones::Zone;
+
+/// Root node of the library browser
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct LibrarySearchRoot {
+    /// The player searching
+    pub player: Entity,
+    /// The cards shown
+    pub matches: Vec<Entity>,
+}
+
+/// Text saying what to find, where it goes and the time left
+#[derive(Component, Debug, Clone, Copy)]
+pub struct LibrarySearchTitle;
+
+/// Button selecting or deselecting a card
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LibrarySearchCardButton(pub Entity);
+
+/// Button finding the selected cards
+#[derive(Component, Debug, Clone, Copy)]
+pub struct LibrarySearchFindButton;
+
+/// Button finding nothing
+#[derive(Component, Debug, Clone, Copy)]
+pub struct LibrarySearchFailButton;
+
+/// The cards selected in the library browser so far
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct LibrarySearchDraft {
+    /// Selected cards, in the order they were clicked
+    pub selected: Vec<Entity>,
+}
+
+impl LibrarySearchDraft {
+    /// Select `card`, or deselect it if it was selected
+    ///
+    /// Nothing more can be selected once `count` are.
+    pub fn toggle(&mut self, card: Entity, count: usize) {
+        if let Some(index) = self.selected.iter().position(|&c| c == card) {
+            self.selected.remove(index);
+        } else if self.selected.len() < count {
+            self.selected.push(card);
+        }
+    }
+}
+
+/// The browser's title
+pub fn search_title(search: &LibrarySearch, selected: usize) -> String {
+    let destination = match search.request.destination {
+        Zone::Library => "the top of your library",
+        Zone::Hand => "your hand",
+        Zone::Battlefield => "the battlefield",
+        Zone::Graveyard => "your graveyard",
+        Zone::Stack => "the stack",
+        Zone::Exile => "exile",
+        Zone::Command => "the command zone",
+    };
+    format!(
+        "Search your library: find up to {} for {} ({}/{} chosen, {:.0}s)",
+        search.request.count,
+        destination,
+        selected,
+        search.request.count,
+        search.remaining_secs().ceil()
+    )
+}
@@ -0,0 +1,21 @@
+//! Library browser for the local player's library searches
+//!
+//! While the local player searches their library, a dialog lists the cards the
+//! search can find, and only those. Clicking a card selects or deselects it;
+//! "Find" sends the selected cards as a
+//! [`ChooseSearchResultEvent`](crate::game_engine::search::ChooseSearchResultEvent)
+//! and "Fail to find" sends none. Other players' searches are never shown, as
+//! their libraries are hidden.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{
+    LibrarySearchCardButton, LibrarySearchDraft, LibrarySearchFailButton, LibrarySearchFindButton,
+    LibrarySearchRoot, LibrarySearchTitle, search_title,
+};
+pub use plugin::LibrarySearchPlugin;
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+use super::components::LibrarySearchDraft;
+use super::systems::{
+    despawn_library_search, handle_library_search_card_clicks, handle_library_search_choice_clicks,
+    sync_library_search, update_library_search,
+};
+use crate::game_engine::search::LibrarySearches;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the dialog searching the local player's library
+pub struct LibrarySearchPlugin;
+
+impl Plugin for LibrarySearchPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LibrarySearchDraft>()
+            .add_systems(OnExit(GameMenuState::InGame), despawn_library_search)
+            .add_systems(
+                Update,
+                (
+                    sync_library_search.run_if(resource_changed::<LibrarySearches>),
+                    handle_library_search_card_clicks,
+                    handle_library_search_choice_clicks,
+                    update_library_search,
+                )
+                    .chain()
+                    .run_if(
+                        in_state(GameMenuState::InGame).and(resource_exists::<LibrarySearches>),
+                    ),
+            );
+    }
+}
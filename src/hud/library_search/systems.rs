@@ -0,0 +1,289 @@
+use bevy::prelude::*;
+
+use super::components::{
+    LibrarySearchCardButton, LibrarySearchDraft, LibrarySearchFailButton, LibrarySearchFindButton,
+    LibrarySearchRoot, LibrarySearchTitle, search_title,
+};
+use crate::camera::components::AppLayer;
+use crate::camera::z_order::UiLayer;
+use crate::cards::Card;
+use crate::game_engine::search::{ChooseSearchResultEvent, LibrarySearch, LibrarySearches};
+use crate::player::Player;
+
+const CARD_COLOR: Color = Color::srgba(0.22, 0.22, 0.26, 0.95);
+const SELECTED_COLOR: Color = Color::srgba(0.2, 0.4, 0.6, 0.95);
+const FIND_COLOR: Color = Color::srgba(0.2, 0.55, 0.25, 0.95);
+const DISABLED_FIND_COLOR: Color = Color::srgba(0.2, 0.25, 0.2, 0.7);
+const FAIL_COLOR: Color = Color::srgba(0.45, 0.25, 0.2, 0.95);
+
+/// The search being made, if the local player is the one searching
+fn local_search<'a>(
+    searches: &'a LibrarySearches,
+    players: &Query<&Player>,
+) -> Option<&'a LibrarySearch> {
+    // The local player is always index 0
+    searches.current.as_ref().filter(|current| {
+        players
+            .get(current.request.player)
+            .is_ok_and(|player| player.player_index == 0)
+    })
+}
+
+/// A button with a label
+fn spawn_button(parent: &mut ChildSpawnerCommands, label: &str, color: Color, marker: impl Bundle) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(140.0),
+                height: Val::Px(34.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(color),
+            marker,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// Show the browser while the local player searches their library
+pub fn sync_library_search(
+    mut commands: Commands,
+    searches: Res<LibrarySearches>,
+    players: Query<&Player>,
+    cards: Query<&Card>,
+    roots: Query<(Entity, &LibrarySearchRoot)>,
+    mut draft: ResMut<LibrarySearchDraft>,
+) {
+    let searching = local_search(&searches, &players);
+    let shown = roots.iter().next().map(|(_, root)| root);
+    if searching.map(|current| (current.request.player, &current.matches))
+        == shown.map(|root| (root.player, &root.matches))
+    {
+        return;
+    }
+    for (root, _) in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    *draft = LibrarySearchDraft::default();
+    let Some(current) = searching else {
+        return;
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            UiLayer::Dialog.global_z_index(),
+            LibrarySearchRoot {
+                player: current.request.player,
+                matches: current.matches.clone(),
+            },
+            AppLayer::GameUI.layer(),
+            Name::new("Library Search"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(520.0),
+                        max_height: Val::Percent(80.0),
+                        padding: UiRect::all(Val::Px(16.0)),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(8.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.08, 0.1, 0.97)),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        LibrarySearchTitle,
+                    ));
+
+                    parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(100.0),
+                                flex_direction: FlexDirection::Row,
+                                flex_wrap: FlexWrap::Wrap,
+                                justify_content: JustifyContent::Center,
+                                column_gap: Val::Px(6.0),
+                                row_gap: Val::Px(6.0),
+                                overflow: Overflow::scroll_y(),
+                                ..default()
+                            },
+                            Name::new("Library Search Matches"),
+                        ))
+                        .with_children(|parent| {
+                            for &card in &current.matches {
+                                let name = cards
+                                    .get(card)
+                                    .map_or("Card", |card| card.name.name.as_str());
+                                parent
+                                    .spawn((
+                                        Button,
+                                        Node {
+                                            min_width: Val::Px(140.0),
+                                            height: Val::Px(32.0),
+                                            padding: UiRect::horizontal(Val::Px(8.0)),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            ..default()
+                                        },
+                                        BackgroundColor(CARD_COLOR),
+                                        LibrarySearchCardButton(card),
+                                    ))
+                                    .with_children(|parent| {
+                                        parent.spawn((
+                                            Text::new(name),
+                                            TextFont {
+                                                font_size: 14.0,
+                                                ..default()
+                                            },
+                                            TextColor(Color::WHITE),
+                                        ));
+                                    });
+                            }
+                        });
+
+                    parent
+                        .spawn(Node {
+                            column_gap: Val::Px(12.0),
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            spawn_button(
+                                parent,
+                                "Find",
+                                DISABLED_FIND_COLOR,
+                                LibrarySearchFindButton,
+                            );
+                            spawn_button(
+                                parent,
+                                "Fail to find",
+                                FAIL_COLOR,
+                                LibrarySearchFailButton,
+                            );
+                        });
+                });
+        });
+}
+
+/// Select or deselect the clicked card
+pub fn handle_library_search_card_clicks(
+    buttons: Query<(&Interaction, &LibrarySearchCardButton), Changed<Interaction>>,
+    searches: Res<LibrarySearches>,
+    mut draft: ResMut<LibrarySearchDraft>,
+) {
+    let Some(current) = searches.current.as_ref() else {
+        return;
+    };
+    for (interaction, LibrarySearchCardButton(card)) in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            draft.toggle(*card, current.request.count);
+        }
+    }
+}
+
+/// Find the selected cards, or nothing
+pub fn handle_library_search_choice_clicks(
+    find_buttons: Query<&Interaction, (Changed<Interaction>, With<LibrarySearchFindButton>)>,
+    fail_buttons: Query<&Interaction, (Changed<Interaction>, With<LibrarySearchFailButton>)>,
+    searches: Res<LibrarySearches>,
+    draft: Res<LibrarySearchDraft>,
+    mut choices: EventWriter<ChooseSearchResultEvent>,
+) {
+    let Some(current) = searches.current.as_ref() else {
+        return;
+    };
+    let pressed = |interaction: &Interaction| *interaction == Interaction::Pressed;
+    let found = if fail_buttons.iter().any(pressed) {
+        Vec::new()
+    } else if find_buttons.iter().any(pressed)
+        && !draft.selected.is_empty()
+        && current.is_valid(&draft.selected)
+    {
+        draft.selected.clone()
+    } else {
+        return;
+    };
+    choices.write(ChooseSearchResultEvent {
+        player: current.request.player,
+        cards: found,
+    });
+}
+
+/// Highlight the selected cards and count down the time left to choose
+pub fn update_library_search(
+    searches: Res<LibrarySearches>,
+    draft: Res<LibrarySearchDraft>,
+    mut cards: Query<
+        (&LibrarySearchCardButton, &mut BackgroundColor),
+        Without<LibrarySearchFindButton>,
+    >,
+    mut finds: Query<&mut BackgroundColor, With<LibrarySearchFindButton>>,
+    mut titles: Query<&mut Text, With<LibrarySearchTitle>>,
+) {
+    let Some(current) = searches.current.as_ref() else {
+        return;
+    };
+
+    for (LibrarySearchCardButton(card), mut background) in cards.iter_mut() {
+        let color = if draft.selected.contains(card) {
+            SELECTED_COLOR
+        } else {
+            CARD_COLOR
+        };
+        background.set_if_neq(BackgroundColor(color));
+    }
+    let find_color = if draft.selected.is_empty() {
+        DISABLED_FIND_COLOR
+    } else {
+        FIND_COLOR
+    };
+    for mut background in finds.iter_mut() {
+        background.set_if_neq(BackgroundColor(find_color));
+    }
+
+    let title = search_title(current, draft.selected.len());
+    for mut text in titles.iter_mut() {
+        if text.0 != title {
+            text.0 = title.clone();
+        }
+    }
+}
+
+/// Despawn the library browser
+pub fn despawn_library_search(
+    mut commands: Commands,
+    roots: Query<Entity, With<LibrarySearchRoot>>,
+    mut draft: ResMut<LibrarySearchDraft>,
+) {
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    *draft = LibrarySearchDraft::default();
+}
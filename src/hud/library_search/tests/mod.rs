@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+
+use crate::cards::CardTypes;
+use crate::game_engine::search::{
+    CardSearchFilter, LibrarySearch, LibrarySearches, SearchLibraryEvent,
+};
+use crate::game_engine::zones::Zone;
+use crate::hud::library_search::systems::sync_library_search;
+use crate::hud::library_search::{LibrarySearchDraft, LibrarySearchRoot, search_title};
+use crate::player::Player;
+
+fn search(player: Entity) -> LibrarySearch {
+    LibrarySearch {
+        request: SearchLibraryEvent {
+            count: 2,
+            ..SearchLibraryEvent::tutor(
+                player,
+                player,
+                CardSearchFilter::of_type(CardTypes::LAND),
+                Zone::Battlefield,
+            )
+        },
+        matches: (10..13).map(Entity::from_raw).collect(),
+        waited: 0.0,
+    }
+}
+
+#[test]
+fn test_search_title_and_draft() {
+    let current = search(Entity::from_raw(1));
+    let [a, b, c] = [10, 11, 12].map(Entity::from_raw);
+    let mut draft = LibrarySearchDraft::default();
+    draft.toggle(a, 2);
+    draft.toggle(b, 2);
+    draft.toggle(c, 2);
+    assert_eq!(draft.selected, vec![a, b]);
+    assert!(current.is_valid(&draft.selected));
+    assert_eq!(
+        search_title(&current, draft.selected.len()),
+        "Search your library: find up to 2 for the battlefield (2/2 chosen, 30s)"
+    );
+}
+
+#[test]
+fn test_only_the_local_players_searches_are_shown() {
+    let mut app = App::new();
+    app.init_resource::<LibrarySearches>()
+        .init_resource::<LibrarySearchDraft>()
+        .add_systems(Update, sync_library_search);
+    let alice = app.world_mut().spawn(Player::new("Alice")).id();
+    let bob = app
+        .world_mut()
+        .spawn(Player::new("Bob").with_player_index(1))
+        .id();
+    let shown = |app: &mut App| {
+        app.world_mut()
+            .query::<&LibrarySearchRoot>()
+            .iter(app.world())
+            .count()
+    };
+
+    app.world_mut().resource_mut::<LibrarySearches>().current = Some(search(bob));
+    app.update();
+    assert_eq!(shown(&mut app), 0);
+
+    app.world_mut().resource_mut::<LibrarySearches>().current = Some(search(alice));
+    app.update();
+    assert_eq!(shown(&mut app), 1);
+
+    app.world_mut().resource_mut::<LibrarySearches>().current = None;
+    app.update();
+    assert_eq!(shown(&mut app), 0);
+}
@@ -0,0 +1,83 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+/// Size of a library pile and the cards leaving it
+pub const PILE_SIZE: Vec2 = Vec2::new(126.0, 176.0);
+/// Seconds a pile wobbles for when its library is shuffled
+pub const SHUFFLE_SECS: f32 = 0.6;
+/// Seconds a drawn card takes to fly off the pile
+pub const DRAW_FLIGHT_SECS: f32 = 0.4;
+
+/// How far a drawn card flies, towards its player's side of the playmat
+const DRAW_FLIGHT_DISTANCE: f32 = 220.0;
+/// Times a pile wobbles back and forth during a shuffle
+const SHUFFLE_WOBBLES: f32 = 3.0;
+/// How far a pile moves and turns at the start of a shuffle
+const SHUFFLE_SHIFT: f32 = 14.0;
+const SHUFFLE_TURN: f32 = 0.12;
+/// Gap between the scried cards fanned above a pile
+const FAN_GAP: f32 = 12.0;
+
+/// A face-down pile standing for a player's library
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct LibraryPile {
+    pub player: Entity,
+    /// Seconds left of the shuffle wobble
+    pub shuffle_left: f32,
+}
+
+/// Badge counting the cards in a player's library
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LibraryCountBadge {
+    pub player: Entity,
+}
+
+/// A drawn card flying off the top of a pile
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct DrawFlight {
+    /// Seconds it has been flying
+    pub elapsed: f32,
+}
+
+/// A card being scried, fanned above its library
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScryFanCard {
+    pub player: Entity,
+    pub card: Entity,
+}
+
+/// How far a pile is moved and turned `shuffle_left` seconds before its
+/// shuffle wobble ends, dying down to nothing
+pub fn shuffle_wobble(shuffle_left: f32) -> (Vec3, f32) {
+    if shuffle_left <= 0.0 {
+        return (Vec3::ZERO, 0.0);
+    }
+    let left = (shuffle_left / SHUFFLE_SECS).min(1.0);
+    let swing = ((1.0 - left) * SHUFFLE_WOBBLES * TAU).sin() * left;
+    (Vec3::X * swing * SHUFFLE_SHIFT, swing * SHUFFLE_TURN)
+}
+
+/// Where a drawn card is relative to its pile after `elapsed` seconds, and how
+/// opaque it still is
+pub fn flight_offset(elapsed: f32) -> (Vec3, f32) {
+    let progress = (elapsed / DRAW_FLIGHT_SECS).clamp(0.0, 1.0);
+    // Lifted off the pile, then falling back as it goes
+    let lift = (progress * std::f32::consts::PI).sin() * PILE_SIZE.y * 0.2;
+    (
+        Vec3::new(0.0, -DRAW_FLIGHT_DISTANCE * progress + lift, 1.0),
+        1.0 - progress,
+    )
+}
+
+/// Where the `index`th of `count` scried cards sits relative to its pile,
+/// spread out in a row above it with the top card leftmost
+pub fn fan_offset(index: usize, count: usize) -> Vec3 {
+    let step = PILE_SIZE.x + FAN_GAP;
+    let left = -step * (count.saturating_sub(1)) as f32 / 2.0;
+    Vec3::new(
+        left + step * index as f32,
+        PILE_SIZE.y + FAN_GAP,
+        2.0 + index as f32 * 0.01,
+    )
+}
@@ -0,0 +1,25 @@
+//! Library pile visuals
+//!
+//! Each library zone on a playmat gets a face-down pile with a badge counting
+//! its cards. The pile wobbles when the library is shuffled, a card flies off
+//! its top for each draw, and the cards a player scries fan out above it until
+//! they're put back.
+//!
+//! Everything here is driven by the library events
+//! ([`LibraryShuffledEvent`](crate::game_engine::zones::LibraryShuffledEvent),
+//! [`CardDrawnEvent`](crate::game_engine::zones::CardDrawnEvent) and the scry
+//! events in [`crate::game_engine::scry`]) and the zone mutations for the count,
+//! so the zone code never has to know how libraries are drawn.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{
+    DRAW_FLIGHT_SECS, DrawFlight, LibraryCountBadge, LibraryPile, PILE_SIZE, SHUFFLE_SECS,
+    ScryFanCard, fan_offset, flight_offset, shuffle_wobble,
+};
+pub use plugin::LibraryVisualsPlugin;
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+use super::systems::{
+    animate_draw_flights, animate_shuffles, despawn_library_visuals, show_scry_fans,
+    spawn_draw_flights, spawn_library_piles, start_shuffle_animations, update_library_counts,
+};
+use crate::game_engine::zones::{ZoneManager, publish_zone_mutations};
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the library piles and their animations
+pub struct LibraryVisualsPlugin;
+
+impl Plugin for LibraryVisualsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMenuState::InGame), despawn_library_visuals)
+            .add_systems(
+                Update,
+                (
+                    spawn_library_piles,
+                    update_library_counts.after(publish_zone_mutations),
+                    start_shuffle_animations,
+                    animate_shuffles,
+                    spawn_draw_flights,
+                    animate_draw_flights,
+                    show_scry_fans,
+                )
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame).and(resource_exists::<ZoneManager>)),
+            );
+    }
+}
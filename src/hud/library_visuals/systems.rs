@@ -0,0 +1,265 @@
+use bevy::prelude::*;
+
+use super::components::{
+    DRAW_FLIGHT_SECS, DrawFlight, LibraryCountBadge, LibraryPile, PILE_SIZE, SHUFFLE_SECS,
+    ScryFanCard, fan_offset, flight_offset, shuffle_wobble,
+};
+use crate::camera::components::AppLayer;
+use crate::cards::Card;
+use crate::cosmetics::CardBackSprite;
+use crate::game_engine::scry::{ScryFinishedEvent, ScryStartedEvent};
+use crate::game_engine::zones::{
+    CardDrawnEvent, LibraryShuffledEvent, Zone, ZoneManager, ZoneMutationEvent,
+};
+use crate::player::Player;
+use crate::player::playmat::PlaymatZone;
+
+const CARD_BACK_COLOR: Color = Color::srgb(0.36, 0.22, 0.12);
+const CARD_FACE_COLOR: Color = Color::srgb(0.88, 0.85, 0.78);
+const BADGE_COLOR: Color = Color::srgb(1.0, 0.95, 0.8);
+
+/// Where a pile rests on its library zone
+const PILE_REST: Vec3 = Vec3::new(0.0, 0.0, 0.5);
+
+/// A card-sized sprite, face down unless `color` says otherwise
+fn card_sprite(color: Color) -> Sprite {
+    Sprite {
+        color,
+        custom_size: Some(PILE_SIZE),
+        ..default()
+    }
+}
+
+/// Put a pile on every library zone that doesn't have one yet
+pub fn spawn_library_piles(
+    mut commands: Commands,
+    zones: Query<(Entity, &PlaymatZone)>,
+    piles: Query<&LibraryPile>,
+    zone_manager: Option<Res<ZoneManager>>,
+) {
+    for (entity, zone) in zones.iter() {
+        if zone.zone_type != Zone::Library || piles.iter().any(|pile| pile.player == zone.player_id)
+        {
+            continue;
+        }
+        let count = zone_manager
+            .as_ref()
+            .map_or(0, |zones| zones.library(zone.player_id).len());
+
+        let pile = commands
+            .spawn((
+                card_sprite(CARD_BACK_COLOR),
+                Transform::from_translation(PILE_REST),
+                LibraryPile {
+                    player: zone.player_id,
+                    shuffle_left: 0.0,
+                },
+                CardBackSprite {
+                    player: zone.player_id,
+                },
+                AppLayer::game_layers(),
+                Name::new("Library Pile"),
+            ))
+            .with_children(|pile| {
+                pile.spawn((
+                    Text2d::new(count.to_string()),
+                    TextFont {
+                        font_size: 28.0,
+                        ..default()
+                    },
+                    TextColor(BADGE_COLOR),
+                    Transform::from_xyz(PILE_SIZE.x / 2.0 - 18.0, PILE_SIZE.y / 2.0 - 20.0, 0.1),
+                    LibraryCountBadge {
+                        player: zone.player_id,
+                    },
+                    AppLayer::game_layers(),
+                    Name::new("Library Count"),
+                ));
+            })
+            .id();
+        commands.entity(entity).add_child(pile);
+    }
+}
+
+/// Recount the libraries when cards move, hiding the piles of empty ones
+pub fn update_library_counts(
+    mut mutations: EventReader<ZoneMutationEvent>,
+    zones: Res<ZoneManager>,
+    new_badges: Query<(), Added<LibraryCountBadge>>,
+    mut badges: Query<(&LibraryCountBadge, &mut Text2d)>,
+    mut piles: Query<(&LibraryPile, &mut Visibility)>,
+) {
+    let zones_changed = mutations.read().count() > 0;
+    if !zones_changed && new_badges.is_empty() {
+        return;
+    }
+
+    for (badge, mut text) in badges.iter_mut() {
+        let count = zones.library(badge.player).len().to_string();
+        if text.0 != count {
+            text.0 = count;
+        }
+    }
+    for (pile, mut visibility) in piles.iter_mut() {
+        visibility.set_if_neq(if zones.library(pile.player).is_empty() {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        });
+    }
+}
+
+/// Start a pile wobbling when its library is shuffled
+pub fn start_shuffle_animations(
+    mut shuffles: EventReader<LibraryShuffledEvent>,
+    mut piles: Query<&mut LibraryPile>,
+) {
+    for event in shuffles.read() {
+        for mut pile in piles.iter_mut() {
+            if pile.player == event.player {
+                pile.shuffle_left = SHUFFLE_SECS;
+            }
+        }
+    }
+}
+
+/// Wobble shuffled piles until they settle back where they rest
+pub fn animate_shuffles(time: Res<Time>, mut piles: Query<(&mut LibraryPile, &mut Transform)>) {
+    for (mut pile, mut transform) in piles.iter_mut() {
+        let at_rest = transform.translation == PILE_REST && transform.rotation == Quat::IDENTITY;
+        if pile.shuffle_left <= 0.0 && at_rest {
+            continue;
+        }
+        pile.shuffle_left = (pile.shuffle_left - time.delta_secs()).max(0.0);
+        let (offset, angle) = shuffle_wobble(pile.shuffle_left);
+        transform.translation = PILE_REST + offset;
+        transform.rotation = Quat::from_rotation_z(angle);
+    }
+}
+
+/// Send a card flying off the top of the pile for each draw
+pub fn spawn_draw_flights(
+    mut commands: Commands,
+    mut draws: EventReader<CardDrawnEvent>,
+    piles: Query<(Entity, &LibraryPile)>,
+) {
+    for event in draws.read() {
+        let Some((pile, _)) = piles.iter().find(|(_, pile)| pile.player == event.player) else {
+            continue;
+        };
+        let (offset, _) = flight_offset(0.0);
+        let flight = commands
+            .spawn((
+                card_sprite(CARD_BACK_COLOR),
+                Transform::from_translation(offset),
+                DrawFlight { elapsed: 0.0 },
+                CardBackSprite {
+                    player: event.player,
+                },
+                AppLayer::game_layers(),
+                Name::new("Drawn Card"),
+            ))
+            .id();
+        commands.entity(pile).add_child(flight);
+    }
+}
+
+/// Move drawn cards along their flight, fading them out as they land
+pub fn animate_draw_flights(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut flights: Query<(Entity, &mut DrawFlight, &mut Transform, &mut Sprite)>,
+) {
+    for (entity, mut flight, mut transform, mut sprite) in flights.iter_mut() {
+        flight.elapsed += time.delta_secs();
+        if flight.elapsed >= DRAW_FLIGHT_SECS {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let (offset, alpha) = flight_offset(flight.elapsed);
+        transform.translation = offset;
+        sprite.color.set_alpha(alpha);
+    }
+}
+
+/// Fan scried cards out above their library, and gather them back once the
+/// scry is done
+///
+/// Only the local player's own scried cards are shown face up.
+pub fn show_scry_fans(
+    mut commands: Commands,
+    mut started: EventReader<ScryStartedEvent>,
+    mut finished: EventReader<ScryFinishedEvent>,
+    piles: Query<(Entity, &LibraryPile)>,
+    fans: Query<(Entity, &ScryFanCard)>,
+    players: Query<&Player>,
+    cards: Query<&Card>,
+) {
+    for event in finished.read() {
+        for (entity, fan) in fans.iter() {
+            if fan.player == event.player {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+
+    for event in started.read() {
+        let Some((pile, _)) = piles.iter().find(|(_, pile)| pile.player == event.player) else {
+            continue;
+        };
+        let face_up = players
+            .get(event.player)
+            .is_ok_and(|player| player.player_index == 0);
+        for (index, &card) in event.cards.iter().enumerate() {
+            let name = cards
+                .get(card)
+                .ok()
+                .filter(|_| face_up)
+                .map(|card| card.name.name.clone());
+            let color = if name.is_some() {
+                CARD_FACE_COLOR
+            } else {
+                CARD_BACK_COLOR
+            };
+            let mut fan = commands.spawn((
+                card_sprite(color),
+                Transform::from_translation(fan_offset(index, event.cards.len())),
+                ScryFanCard {
+                    player: event.player,
+                    card,
+                },
+                AppLayer::game_layers(),
+                Name::new("Scried Card"),
+            ));
+            if name.is_none() {
+                fan.insert(CardBackSprite {
+                    player: event.player,
+                });
+            }
+            let fan = fan
+                .with_children(|fan| {
+                    if let Some(name) = name {
+                        fan.spawn((
+                            Text2d::new(name),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(Color::BLACK),
+                            Transform::from_xyz(0.0, PILE_SIZE.y / 2.0 - 16.0, 0.1),
+                            AppLayer::game_layers(),
+                        ));
+                    }
+                })
+                .id();
+            commands.entity(pile).add_child(fan);
+        }
+    }
+}
+
+/// Remove the piles, and everything on them, when leaving the game
+pub fn despawn_library_visuals(mut commands: Commands, piles: Query<Entity, With<LibraryPile>>) {
+    for pile in piles.iter() {
+        commands.entity(pile).despawn();
+    }
+}
@@ -0,0 +1,150 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::game_engine::scry::{ScryFinishedEvent, ScryStartedEvent};
+use crate::game_engine::zones::{
+    CardDrawnEvent, LibraryShuffledEvent, Zone, ZoneManager, ZoneMutationEvent,
+    publish_zone_mutations,
+};
+use crate::hud::library_visuals::systems::{
+    animate_draw_flights, animate_shuffles, show_scry_fans, spawn_draw_flights,
+    spawn_library_piles, start_shuffle_animations, update_library_counts,
+};
+use crate::hud::library_visuals::{
+    DRAW_FLIGHT_SECS, DrawFlight, LibraryCountBadge, LibraryPile, SHUFFLE_SECS, ScryFanCard,
+    fan_offset, flight_offset, shuffle_wobble,
+};
+use crate::player::Player;
+use crate::player::playmat::PlaymatZone;
+
+#[test]
+fn test_animation_curves() {
+    // Shuffles die down to rest
+    assert_eq!(shuffle_wobble(0.0), (Vec3::ZERO, 0.0));
+    assert_ne!(shuffle_wobble(SHUFFLE_SECS * 0.9).0, Vec3::ZERO);
+
+    // Drawn cards leave the pile and fade out
+    let (start, opaque) = flight_offset(0.0);
+    let (end, faded) = flight_offset(DRAW_FLIGHT_SECS);
+    assert_eq!(opaque, 1.0);
+    assert_eq!(faded, 0.0);
+    assert!(end.y < start.y);
+
+    // Scried cards are spread evenly around the pile's middle, above it
+    let fan: Vec<Vec3> = (0..3).map(|index| fan_offset(index, 3)).collect();
+    assert_eq!(fan[1].x, 0.0);
+    assert_eq!(fan[0].x, -fan[2].x);
+    assert!(fan.iter().all(|offset| offset.y > 0.0));
+}
+
+fn advance(app: &mut App, secs: f32) {
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs_f32(secs));
+    app.update();
+}
+
+fn count<C: Component>(app: &mut App) -> usize {
+    app.world_mut().query::<&C>().iter(app.world()).count()
+}
+
+#[test]
+fn test_library_events_drive_the_pile() {
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .init_resource::<ZoneManager>()
+        .add_event::<ZoneMutationEvent>()
+        .add_event::<LibraryShuffledEvent>()
+        .add_event::<CardDrawnEvent>()
+        .add_event::<ScryStartedEvent>()
+        .add_event::<ScryFinishedEvent>()
+        .add_systems(
+            Update,
+            (
+                publish_zone_mutations,
+                spawn_library_piles,
+                update_library_counts,
+                start_shuffle_animations,
+                animate_shuffles,
+                spawn_draw_flights,
+                animate_draw_flights,
+                show_scry_fans,
+            )
+                .chain(),
+        );
+    let alice = app.world_mut().spawn(Player::new("Alice")).id();
+    app.world_mut().spawn((
+        PlaymatZone {
+            player_id: alice,
+            zone_type: Zone::Library,
+        },
+        Transform::default(),
+    ));
+    let cards: Vec<Entity> = (0..3).map(|_| app.world_mut().spawn_empty().id()).collect();
+    let mut zones = app.world_mut().resource_mut::<ZoneManager>();
+    zones.init_player_zones(alice);
+    for &card in &cards {
+        zones.add_to_library(alice, card);
+    }
+    app.update();
+    app.update();
+    let badge = |app: &mut App| {
+        app.world_mut()
+            .query::<(&LibraryCountBadge, &Text2d)>()
+            .single(app.world())
+            .map(|(_, text)| text.0.clone())
+            .unwrap()
+    };
+    assert_eq!(badge(&mut app), "3");
+
+    // A shuffle sets the pile wobbling until it comes back to rest
+    app.world_mut()
+        .send_event(LibraryShuffledEvent { player: alice });
+    advance(&mut app, SHUFFLE_SECS * 0.25);
+    let pile = |app: &mut App| {
+        app.world_mut()
+            .query::<(&LibraryPile, &Transform)>()
+            .single(app.world())
+            .map(|(pile, transform)| (*pile, *transform))
+            .unwrap()
+    };
+    let (wobbling, moved) = pile(&mut app);
+    assert!(wobbling.shuffle_left > 0.0);
+    assert_ne!(moved.rotation, Quat::IDENTITY);
+    advance(&mut app, SHUFFLE_SECS);
+    let (settled, rest) = pile(&mut app);
+    assert_eq!(settled.shuffle_left, 0.0);
+    assert_eq!(rest.rotation, Quat::IDENTITY);
+
+    // A draw flies a card off the top and is counted
+    let card = app
+        .world_mut()
+        .resource_mut::<ZoneManager>()
+        .draw_card(alice)
+        .unwrap();
+    app.world_mut().send_event(CardDrawnEvent {
+        player: alice,
+        card,
+    });
+    advance(&mut app, 0.0);
+    assert_eq!(count::<DrawFlight>(&mut app), 1);
+    advance(&mut app, DRAW_FLIGHT_SECS * 2.0);
+    assert_eq!(count::<DrawFlight>(&mut app), 0);
+    assert_eq!(badge(&mut app), "2");
+
+    // Scried cards are fanned out until the scry is done
+    app.world_mut().send_event(ScryStartedEvent {
+        player: alice,
+        cards: vec![cards[1], cards[0]],
+    });
+    app.update();
+    assert_eq!(count::<ScryFanCard>(&mut app), 2);
+    app.world_mut().send_event(ScryFinishedEvent {
+        player: alice,
+        top: vec![cards[1]],
+        bottom: vec![cards[0]],
+    });
+    app.update();
+    assert_eq!(count::<ScryFanCard>(&mut app), 0);
+}
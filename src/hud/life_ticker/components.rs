@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+
+/// Seconds a life total stays tinted after it changes
+pub const LIFE_FLASH_SECS: f32 = 1.5;
+/// Slowest a life total counts towards a new one, in life per second
+pub const LIFE_TICK_RATE: f32 = 10.0;
+/// Longest a big change takes to count through
+const MAX_TICK_SECS: f32 = 1.0;
+
+const LIFE_COLOR: Color = Color::WHITE;
+const GAIN_COLOR: Color = Color::srgb(0.35, 0.95, 0.45);
+const LOSS_COLOR: Color = Color::srgb(1.0, 0.3, 0.25);
+
+/// A player's life total shown on their playmat
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct LifeTicker {
+    pub player: Entity,
+    /// The total shown, counting towards the player's life
+    pub shown: f32,
+    /// Seconds left tinted by the last change
+    pub flash: f32,
+    /// Whether the last change was a gain
+    pub gained: bool,
+}
+
+impl LifeTicker {
+    pub fn new(player: Entity, life: i32) -> Self {
+        Self {
+            player,
+            shown: life as f32,
+            flash: 0.0,
+            gained: false,
+        }
+    }
+
+    /// The total as shown
+    pub fn label(&self) -> String {
+        (self.shown.round() as i32).to_string()
+    }
+}
+
+/// `shown` moved `delta` seconds' worth of counting towards `life`
+///
+/// Counts at [`LIFE_TICK_RATE`], or faster for a change too big to count
+/// through at that rate within a second.
+pub fn step_toward(shown: f32, life: i32, delta: f32) -> f32 {
+    let distance = life as f32 - shown;
+    let rate = (distance.abs() / MAX_TICK_SECS).max(LIFE_TICK_RATE);
+    let step = rate * delta;
+    if distance.abs() <= step {
+        life as f32
+    } else {
+        shown + step * distance.signum()
+    }
+}
+
+/// The color of a ticker, fading from its last change's tint back to white
+pub fn ticker_color(ticker: &LifeTicker) -> Color {
+    let tint = if ticker.gained {
+        GAIN_COLOR
+    } else {
+        LOSS_COLOR
+    };
+    let fade = (ticker.flash / LIFE_FLASH_SECS).clamp(0.0, 1.0);
+    LIFE_COLOR.mix(&tint, fade)
+}
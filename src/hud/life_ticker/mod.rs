@@ -0,0 +1,16 @@
+//! Animated life totals on each playmat
+//!
+//! Every playmat shows its player's life total, which counts up or down to the
+//! new total when it changes rather than jumping, and is tinted green for a
+//! gain or red for a loss while [`LIFE_FLASH_SECS`] pass. Changes are picked up
+//! from [`LifeChangedEvent`](crate::game_engine::life::LifeChangedEvent)s.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{LIFE_FLASH_SECS, LIFE_TICK_RATE, LifeTicker, step_toward, ticker_color};
+pub use plugin::LifeTickerPlugin;
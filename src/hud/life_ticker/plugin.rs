@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+use super::systems::{
+    despawn_life_tickers, flash_life_changes, spawn_life_tickers, tick_life_tickers,
+};
+use crate::game_engine::life::apply_life_changes;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the animated life totals on the playmats
+pub struct LifeTickerPlugin;
+
+impl Plugin for LifeTickerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMenuState::InGame), despawn_life_tickers)
+            .add_systems(
+                Update,
+                (spawn_life_tickers, flash_life_changes, tick_life_tickers)
+                    .chain()
+                    .after(apply_life_changes)
+                    .run_if(in_state(GameMenuState::InGame)),
+            );
+    }
+}
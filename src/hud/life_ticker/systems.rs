@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+
+use super::components::{LIFE_FLASH_SECS, LifeTicker, step_toward, ticker_color};
+use crate::camera::components::AppLayer;
+use crate::game_engine::life::LifeChangedEvent;
+use crate::player::Player;
+use crate::player::playmat::PlayerPlaymat;
+
+/// Where the total sits on a playmat, in the playmat's local space, beside the
+/// library
+const TICKER_OFFSET: Vec3 = Vec3::new(-300.0, -150.0, 5.0);
+
+/// Put a ticker on every playmat that doesn't have one yet
+pub fn spawn_life_tickers(
+    mut commands: Commands,
+    playmats: Query<(Entity, &PlayerPlaymat, &Transform)>,
+    players: Query<&Player>,
+    tickers: Query<&LifeTicker>,
+) {
+    for (entity, playmat, transform) in playmats.iter() {
+        if tickers
+            .iter()
+            .any(|ticker| ticker.player == playmat.player_id)
+        {
+            continue;
+        }
+        let Ok(player) = players.get(playmat.player_id) else {
+            continue;
+        };
+
+        let ticker = LifeTicker::new(playmat.player_id, player.life);
+        // Undo the playmat's rotation so the total reads upright
+        let ticker = commands
+            .spawn((
+                Text2d::new(ticker.label()),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(ticker_color(&ticker)),
+                Transform::from_translation(TICKER_OFFSET)
+                    .with_rotation(transform.rotation.inverse()),
+                ticker,
+                AppLayer::game_layers(),
+                Name::new("Life Ticker"),
+            ))
+            .id();
+        commands.entity(entity).add_child(ticker);
+    }
+}
+
+/// Tint a ticker for each change to its player's life
+pub fn flash_life_changes(
+    mut changes: EventReader<LifeChangedEvent>,
+    mut tickers: Query<&mut LifeTicker>,
+) {
+    for event in changes.read() {
+        for mut ticker in tickers.iter_mut() {
+            if ticker.player == event.player {
+                ticker.flash = LIFE_FLASH_SECS;
+                ticker.gained = event.change > 0;
+            }
+        }
+    }
+}
+
+/// Count the shown totals towards the players' life and fade their tint
+pub fn tick_life_tickers(
+    time: Res<Time>,
+    players: Query<&Player>,
+    mut tickers: Query<(&mut LifeTicker, &mut Text2d, &mut TextColor)>,
+) {
+    for (mut ticker, mut text, mut color) in tickers.iter_mut() {
+        let Ok(player) = players.get(ticker.player) else {
+            continue;
+        };
+        // Settled tickers are left alone
+        if ticker.shown == player.life as f32 && ticker.flash <= 0.0 {
+            continue;
+        }
+
+        let delta = time.delta_secs();
+        ticker.shown = step_toward(ticker.shown, player.life, delta);
+        ticker.flash = (ticker.flash - delta).max(0.0);
+        let label = ticker.label();
+        if text.0 != label {
+            text.0 = label;
+        }
+        color.set_if_neq(TextColor(ticker_color(&ticker)));
+    }
+}
+
+/// Remove the tickers when leaving the game
+pub fn despawn_life_tickers(mut commands: Commands, tickers: Query<Entity, With<LifeTicker>>) {
+    for ticker in tickers.iter() {
+        commands.entity(ticker).despawn();
+    }
+}
@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::game_engine::life::LifeChangedEvent;
+use crate::hud::life_ticker::systems::{flash_life_changes, spawn_life_tickers, tick_life_tickers};
+use crate::hud::life_ticker::{
+    LIFE_FLASH_SECS, LIFE_TICK_RATE, LifeTicker, step_toward, ticker_color,
+};
+use crate::player::Player;
+use crate::player::playmat::PlayerPlaymat;
+
+#[test]
+fn test_totals_count_towards_the_new_life() {
+    // Small changes count at the tick rate
+    assert_eq!(step_toward(40.0, 37, 0.1), 40.0 - LIFE_TICK_RATE * 0.1);
+    assert_eq!(step_toward(40.0, 41, 0.5), 41.0);
+    // Big ones still finish within a second
+    assert_eq!(step_toward(40.0, 0, 0.5), 20.0);
+    assert_eq!(step_toward(20.0, 20, 0.5), 20.0);
+}
+
+#[test]
+fn test_tint_fades_back_to_white() {
+    let mut ticker = LifeTicker::new(Entity::from_raw(1), 40);
+    assert_eq!(ticker_color(&ticker), Color::WHITE);
+    ticker.flash = LIFE_FLASH_SECS;
+    ticker.gained = true;
+    let gain = ticker_color(&ticker);
+    ticker.gained = false;
+    let loss = ticker_color(&ticker);
+    assert_ne!(gain, Color::WHITE);
+    assert_ne!(gain, loss);
+}
+
+fn ticker(app: &mut App) -> (LifeTicker, String) {
+    app.world_mut()
+        .query::<(&LifeTicker, &Text2d)>()
+        .single(app.world())
+        .map(|(ticker, text)| (*ticker, text.0.clone()))
+        .unwrap()
+}
+
+#[test]
+fn test_playmat_tickers_follow_life_changes() {
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .add_event::<LifeChangedEvent>()
+        .add_systems(
+            Update,
+            (spawn_life_tickers, flash_life_changes, tick_life_tickers).chain(),
+        );
+    let alice = app.world_mut().spawn(Player::new("Alice")).id();
+    app.world_mut().spawn((
+        PlayerPlaymat {
+            player_id: alice,
+            player_index: 0,
+        },
+        Transform::default(),
+    ));
+    app.update();
+    assert_eq!(ticker(&mut app).1, "40");
+
+    // Alice takes 5, which counts down over half a second
+    app.world_mut().get_mut::<Player>(alice).unwrap().life = 35;
+    app.world_mut().send_event(LifeChangedEvent {
+        player: alice,
+        source: None,
+        change: -5,
+        is_damage: true,
+        life: 35,
+    });
+    for expected in ["39", "38", "37", "36", "35", "35"] {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(100));
+        app.update();
+        assert_eq!(ticker(&mut app).1, expected);
+    }
+    let (shown, _) = ticker(&mut app);
+    assert!(!shown.gained);
+    assert!(shown.flash > 0.0);
+
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs(2));
+    app.update();
+    assert_eq!(ticker(&mut app).0.flash, 0.0);
+}
@@ -0,0 +1,34 @@
+//! In-game heads-up display
+//!
+//! Screen-space UI shown on top of the table while a game is running, as opposed to
+//! the menus in [`crate::menu`].
+
+pub mod action_hints;
+pub mod card_preview;
+pub mod commander_zone_prompt;
+pub mod damage_assignment;
+pub mod day_night;
+pub mod dev_console;
+pub mod discard_prompt;
+pub mod dungeon;
+pub mod enters_choice;
+pub mod exile_strip;
+pub mod goldfish;
+pub mod library_search;
+pub mod library_visuals;
+pub mod life_ticker;
+pub mod phase_bar;
+mod plugin;
+pub mod politics;
+pub mod resolution_popup;
+pub mod resolve_all;
+pub mod response_prompt;
+pub mod reveal_overlay;
+pub mod sacrifice_picker;
+pub mod sandbox;
+pub mod trigger_order;
+pub mod turn_order_roll;
+pub mod zone_counts;
+pub mod zone_viewer;
+
+pub use plugin::HudPlugin;
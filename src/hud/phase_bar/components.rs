@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+
+use crate::game_engine::phase::Phase;
+
+/// Root node of the phase bar
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PhaseBarRoot;
+
+/// Text showing the turn number and active player
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TurnInfoText;
+
+/// A clickable step in the phase bar
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhaseStepButton(pub Phase);
+
+/// How a step is drawn in the phase bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepDisplay {
+    /// Already finished this turn
+    Past,
+    /// The step the game is in
+    Current,
+    /// Still to come, without an auto-stop
+    Upcoming,
+    /// Still to come, with an auto-stop set
+    AutoStop,
+}
+
+impl StepDisplay {
+    /// Background color of a step button
+    pub fn background(&self) -> Color {
+        match self {
+            StepDisplay::Past => Color::srgba(0.12, 0.12, 0.12, 0.85),
+            StepDisplay::Current => Color::srgba(0.85, 0.65, 0.15, 0.95),
+            StepDisplay::Upcoming => Color::srgba(0.22, 0.22, 0.26, 0.9),
+            StepDisplay::AutoStop => Color::srgba(0.7, 0.2, 0.2, 0.95),
+        }
+    }
+
+    /// Text color of a step button
+    pub fn text_color(&self) -> Color {
+        match self {
+            StepDisplay::Past => Color::srgb(0.5, 0.5, 0.5),
+            StepDisplay::Current => Color::BLACK,
+            StepDisplay::Upcoming | StepDisplay::AutoStop => Color::WHITE,
+        }
+    }
+}
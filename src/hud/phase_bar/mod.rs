@@ -0,0 +1,17 @@
+//! Phase bar showing the turn number, the active player and every step of the turn
+//!
+//! The current step is highlighted as the phase system advances. Clicking a later
+//! step toggles the local player's auto-stop for it in
+//! [`AutoStops`](crate::game_engine::phase::AutoStops), so the game waits for them
+//! there instead of passing priority automatically.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{PhaseBarRoot, PhaseStepButton, StepDisplay, TurnInfoText};
+pub use plugin::PhaseBarPlugin;
+pub use systems::step_display;
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use super::systems::{
+    despawn_phase_bar, handle_phase_step_clicks, spawn_phase_bar, update_phase_step_display,
+    update_turn_info,
+};
+use crate::game_engine::phase::AutoStops;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the turn and phase HUD
+pub struct PhaseBarPlugin;
+
+impl Plugin for PhaseBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AutoStops>()
+            .add_systems(OnEnter(GameMenuState::InGame), spawn_phase_bar)
+            .add_systems(OnExit(GameMenuState::InGame), despawn_phase_bar)
+            .add_systems(
+                Update,
+                (
+                    handle_phase_step_clicks,
+                    update_phase_step_display,
+                    update_turn_info,
+                )
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame)),
+            );
+    }
+}
@@ -0,0 +1,190 @@
+use bevy::prelude::*;
+
+use super::components::{PhaseBarRoot, PhaseStepButton, StepDisplay, TurnInfoText};
+use crate::camera::components::AppLayer;
+use crate::camera::presets::LOCAL_PLAYER_INDEX;
+use crate::game_engine::phase::{AutoStops, Phase};
+use crate::game_engine::turns::TurnManager;
+use crate::player::Player;
+
+/// How a step should be displayed given the current step and whether the local
+/// player has an auto-stop on it
+pub fn step_display(step: Phase, current: Phase, auto_stop: bool) -> StepDisplay {
+    let (step_index, current_index) = (step.step_index(), current.step_index());
+    if step_index == current_index {
+        StepDisplay::Current
+    } else if step_index < current_index {
+        StepDisplay::Past
+    } else if auto_stop {
+        StepDisplay::AutoStop
+    } else {
+        StepDisplay::Upcoming
+    }
+}
+
+/// Spawn the phase bar along the top of the screen
+pub fn spawn_phase_bar(mut commands: Commands, existing: Query<(), With<PhaseBarRoot>>) {
+    if !existing.is_empty() {
+        return;
+    }
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(8.0),
+                left: Val::Percent(50.0),
+                margin: UiRect::left(Val::Px(-480.0)),
+                width: Val::Px(960.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            PhaseBarRoot,
+            AppLayer::GameUI.layer(),
+            Name::new("Phase Bar"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Turn 1"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TurnInfoText,
+                Name::new("Turn Info"),
+            ));
+
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        margin: UiRect::top(Val::Px(4.0)),
+                        ..default()
+                    },
+                    Name::new("Phase Steps"),
+                ))
+                .with_children(|parent| {
+                    for step in Phase::ALL {
+                        parent
+                            .spawn((
+                                Button,
+                                Node {
+                                    width: Val::Px(76.0),
+                                    height: Val::Px(26.0),
+                                    margin: UiRect::horizontal(Val::Px(2.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                BackgroundColor(StepDisplay::Upcoming.background()),
+                                PhaseStepButton(step),
+                                Name::new(format!("Phase Step {}", step.label())),
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    Text::new(step.label()),
+                                    TextFont {
+                                        font_size: 12.0,
+                                        ..default()
+                                    },
+                                    TextColor(StepDisplay::Upcoming.text_color()),
+                                ));
+                            });
+                    }
+                });
+        });
+}
+
+/// Despawn the phase bar
+pub fn despawn_phase_bar(mut commands: Commands, roots: Query<Entity, With<PhaseBarRoot>>) {
+    for entity in roots.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// The local player, whose auto-stops the phase bar shows
+fn local_player(players: &Query<(Entity, &Player)>) -> Option<Entity> {
+    players
+        .iter()
+        .find(|(_, player)| player.player_index == LOCAL_PLAYER_INDEX)
+        .map(|(entity, _)| entity)
+}
+
+/// Toggle the local player's auto-stops on later steps when they are clicked
+pub fn handle_phase_step_clicks(
+    buttons: Query<(&Interaction, &PhaseStepButton), Changed<Interaction>>,
+    phase: Option<Res<Phase>>,
+    players: Query<(Entity, &Player)>,
+    mut auto_stops: ResMut<AutoStops>,
+) {
+    let current = phase.map_or_else(Phase::default, |phase| *phase);
+    let Some(local) = local_player(&players) else {
+        return;
+    };
+
+    for (interaction, PhaseStepButton(step)) in buttons.iter() {
+        if *interaction != Interaction::Pressed || step.step_index() <= current.step_index() {
+            continue;
+        }
+
+        let set = auto_stops.toggle(local, *step);
+        info!(
+            "Auto-stop {} for {}",
+            if set { "set" } else { "cleared" },
+            step.label()
+        );
+    }
+}
+
+/// Highlight the current step and any of the local player's auto-stops
+pub fn update_phase_step_display(
+    phase: Option<Res<Phase>>,
+    auto_stops: Res<AutoStops>,
+    players: Query<(Entity, &Player)>,
+    mut buttons: Query<(&PhaseStepButton, &mut BackgroundColor, &Children)>,
+    mut texts: Query<&mut TextColor>,
+    added: Query<(), Added<PhaseStepButton>>,
+) {
+    let phase_changed = phase.as_ref().is_some_and(|phase| phase.is_changed());
+    if !phase_changed && !auto_stops.is_changed() && added.is_empty() {
+        return;
+    }
+
+    let current = phase.map_or_else(Phase::default, |phase| *phase);
+    let local = local_player(&players);
+    for (PhaseStepButton(step), mut background, children) in buttons.iter_mut() {
+        let auto_stop = local.is_some_and(|local| auto_stops.contains(local, *step));
+        let display = step_display(*step, current, auto_stop);
+        background.0 = display.background();
+        for child in children.iter() {
+            if let Ok(mut color) = texts.get_mut(child) {
+                color.0 = display.text_color();
+            }
+        }
+    }
+}
+
+/// Show the turn number and the active player's name
+pub fn update_turn_info(
+    turn_manager: Option<Res<TurnManager>>,
+    players: Query<(Entity, &Player)>,
+    mut texts: Query<&mut Text, With<TurnInfoText>>,
+) {
+    let Some(turn_manager) = turn_manager else {
+        return;
+    };
+
+    let active_name = players
+        .iter()
+        .find(|(entity, _)| *entity == turn_manager.active_player)
+        .map_or("Unknown", |(_, player)| player.name.as_str());
+    let label = format!("Turn {} - {}", turn_manager.turn_number, active_name);
+
+    for mut text in texts.iter_mut() {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+}
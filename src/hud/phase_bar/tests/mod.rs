@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+
+use crate::game_engine::phase::{
+    AutoStops, BeginningStep, CombatStep, EndingStep, MAIN1, MAIN2, Phase,
+};
+use crate::hud::phase_bar::{StepDisplay, step_display};
+
+#[test]
+fn test_steps_before_current_are_past() {
+    assert_eq!(
+        step_display(Phase::Beginning(BeginningStep::Upkeep), MAIN1, false),
+        StepDisplay::Past
+    );
+    assert_eq!(step_display(MAIN1, MAIN1, false), StepDisplay::Current);
+    assert_eq!(step_display(MAIN2, MAIN1, false), StepDisplay::Upcoming);
+}
+
+#[test]
+fn test_auto_stops_are_shown_on_upcoming_steps() {
+    let mut stops = AutoStops::default();
+    let [local, opponent] = [1, 2].map(Entity::from_raw);
+    let end_step = Phase::Ending(EndingStep::End);
+    assert!(stops.toggle(local, end_step));
+    // Auto-stops are each player's own
+    assert!(!stops.contains(opponent, end_step));
+
+    let shown = |stops: &AutoStops, current| {
+        step_display(end_step, current, stops.contains(local, end_step))
+    };
+    assert_eq!(shown(&stops, MAIN1), StepDisplay::AutoStop);
+    // Once the step is reached it shows as current
+    assert_eq!(shown(&stops, end_step), StepDisplay::Current);
+
+    assert!(!stops.toggle(local, end_step));
+    assert_eq!(shown(&stops, MAIN1), StepDisplay::Upcoming);
+}
+
+#[test]
+fn test_step_order_matches_phase_sequence() {
+    let mut phase = Phase::default();
+    for (index, step) in Phase::ALL.iter().enumerate() {
+        assert_eq!(phase, *step);
+        assert_eq!(phase.step_index(), index);
+        phase = phase.next();
+    }
+    assert_eq!(Phase::Combat(CombatStep::CombatDamage).label(), "Damage");
+}
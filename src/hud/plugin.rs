@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+
+use super::action_hints::ActionHintsPlugin;
+use super::card_preview::CardPreviewPlugin;
+use super::commander_zone_prompt::CommanderZonePromptPlugin;
+use super::damage_assignment::DamageAssignmentPlugin;
+use super::day_night::DayNightHudPlugin;
+#[cfg(debug_assertions)]
+use super::dev_console::DevConsolePlugin;
+use super::discard_prompt::DiscardPromptPlugin;
+use super::dungeon::DungeonHudPlugin;
+use super::enters_choice::EntersChoicePromptPlugin;
+use super::exile_strip::ExileStripPlugin;
+use super::goldfish::GoldfishHudPlugin;
+use super::library_search::LibrarySearchPlugin;
+use super::library_visuals::LibraryVisualsPlugin;
+use super::life_ticker::LifeTickerPlugin;
+use super::phase_bar::PhaseBarPlugin;
+use super::politics::PoliticsHudPlugin;
+use super::resolution_popup::ResolutionPopupPlugin;
+use super::resolve_all::ResolveAllPlugin;
+use super::response_prompt::ResponsePromptPlugin;
+use super::reveal_overlay::RevealOverlayPlugin;
+use super::sacrifice_picker::SacrificePickerPlugin;
+use super::sandbox::SandboxHudPlugin;
+use super::trigger_order::TriggerOrderPlugin;
+use super::turn_order_roll::TurnOrderRollPlugin;
+use super::zone_counts::ZoneCountsPlugin;
+use super::zone_viewer::ZoneViewerPlugin;
+
+/// Plugin bundling all in-game HUD elements
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        // Nested, as plugin tuples only go up to 15 entries
+        app.add_plugins((
+            (
+                PhaseBarPlugin,
+                DiscardPromptPlugin,
+                PoliticsHudPlugin,
+                CommanderZonePromptPlugin,
+                CardPreviewPlugin,
+                SandboxHudPlugin,
+                GoldfishHudPlugin,
+                ActionHintsPlugin,
+                TurnOrderRollPlugin,
+                ResponsePromptPlugin,
+            ),
+            (
+                ZoneCountsPlugin,
+                DamageAssignmentPlugin,
+                TriggerOrderPlugin,
+                ExileStripPlugin,
+                DungeonHudPlugin,
+                DayNightHudPlugin,
+                EntersChoicePromptPlugin,
+                SacrificePickerPlugin,
+                RevealOverlayPlugin,
+                LibrarySearchPlugin,
+                ResolutionPopupPlugin,
+                LifeTickerPlugin,
+                LibraryVisualsPlugin,
+                ResolveAllPlugin,
+                ZoneViewerPlugin,
+            ),
+        ));
+
+        #[cfg(debug_assertions)]
+        app.add_plugins(DevConsolePlugin);
+    }
+}
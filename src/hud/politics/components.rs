@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+use uuid::Uuid;
+
+use crate::game_engine::politics::VoteChoice;
+
+/// Crown shown on the monarch's playmat
+#[derive(Component, Debug, Clone, Copy)]
+pub struct MonarchCrown {
+    /// The monarch the crown belongs to
+    pub player: Entity,
+}
+
+/// Badge shown on a goaded creature
+#[derive(Component, Debug, Clone, Copy)]
+pub struct GoadBadge {
+    /// The goaded creature
+    pub creature: Entity,
+}
+
+/// Root node of the voting dialog
+#[derive(Component, Debug, Clone, Copy)]
+pub struct VoteDialogRoot {
+    /// The vote shown in the dialog
+    pub vote_id: Uuid,
+}
+
+/// Text naming the player whose vote is next
+#[derive(Component, Debug, Clone, Copy)]
+pub struct VoterText;
+
+/// Button casting a vote for a choice
+#[derive(Component, Debug, Clone)]
+pub struct VoteChoiceButton(pub VoteChoice);
+
+/// Text showing the votes for a choice
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VoteTallyText(pub usize);
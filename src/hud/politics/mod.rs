@@ -0,0 +1,20 @@
+//! Politics indicators and the voting dialog
+//!
+//! Shows a crown on the monarch's playmat and a badge on every goaded creature, and
+//! opens a modal dialog while a vote is running. Players vote in turn order by
+//! clicking a choice, which sends a
+//! [`VoteCastEvent`](crate::game_engine::politics::VoteCastEvent); the dialog shows
+//! live tallies until the vote completes.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{
+    GoadBadge, MonarchCrown, VoteChoiceButton, VoteDialogRoot, VoteTallyText, VoterText,
+};
+pub use plugin::PoliticsHudPlugin;
+pub use systems::{choice_label, voter_label};
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use super::systems::{
+    close_vote_dialog, despawn_politics_hud, handle_vote_choice_clicks, open_vote_dialog,
+    sync_goad_badges, sync_monarch_crown, update_vote_dialog,
+};
+use crate::game_engine::politics::PoliticsSystem;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the monarch crown, goad badges and voting dialog
+pub struct PoliticsHudPlugin;
+
+impl Plugin for PoliticsHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMenuState::InGame), despawn_politics_hud)
+            .add_systems(
+                Update,
+                (
+                    open_vote_dialog,
+                    close_vote_dialog,
+                    handle_vote_choice_clicks,
+                    (sync_monarch_crown, sync_goad_badges, update_vote_dialog)
+                        .run_if(resource_changed::<PoliticsSystem>),
+                )
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame)),
+            );
+    }
+}
@@ -0,0 +1,314 @@
+use bevy::prelude::*;
+
+use super::components::{
+    GoadBadge, MonarchCrown, VoteChoiceButton, VoteDialogRoot, VoteTallyText, VoterText,
+};
+use crate::camera::components::AppLayer;
+use crate::camera::z_order::UiLayer;
+use crate::game_engine::politics::{
+    PoliticsSystem, VoteCastEvent, VoteChoice, VoteCompletedEvent, VoteStartedEvent,
+};
+use crate::player::Player;
+use crate::player::playmat::PlayerPlaymat;
+
+const CROWN_COLOR: Color = Color::srgb(0.95, 0.78, 0.2);
+const GOAD_COLOR: Color = Color::srgb(0.9, 0.25, 0.2);
+const CHOICE_COLOR: Color = Color::srgba(0.22, 0.22, 0.26, 0.95);
+const HOVERED_CHOICE_COLOR: Color = Color::srgba(0.32, 0.32, 0.38, 0.95);
+
+/// Where the crown sits on a playmat, in the playmat's local space
+const CROWN_OFFSET: Vec3 = Vec3::new(0.0, 190.0, 5.0);
+
+/// Text of a vote choice button, including its current tally
+pub fn choice_label(choice: &VoteChoice, votes: u32) -> String {
+    format!(
+        "{} - {} vote{}",
+        choice.text,
+        votes,
+        if votes == 1 { "" } else { "s" }
+    )
+}
+
+/// Text naming the player whose vote is next
+pub fn voter_label(voter_name: Option<&str>) -> String {
+    match voter_name {
+        Some(name) => format!("{} to vote", name),
+        None => "Waiting for the vote to resolve".to_string(),
+    }
+}
+
+/// Keep a crown on the monarch's playmat
+pub fn sync_monarch_crown(
+    mut commands: Commands,
+    politics: Res<PoliticsSystem>,
+    asset_server: Res<AssetServer>,
+    crowns: Query<(Entity, &MonarchCrown)>,
+    playmats: Query<(Entity, &PlayerPlaymat)>,
+) {
+    let mut has_crown = false;
+    for (entity, crown) in crowns.iter() {
+        if politics.monarch == Some(crown.player) {
+            has_crown = true;
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    let Some(monarch) = politics.monarch else {
+        return;
+    };
+    if has_crown {
+        return;
+    }
+
+    let Some((playmat, _)) = playmats
+        .iter()
+        .find(|(_, playmat)| playmat.player_id == monarch)
+    else {
+        return;
+    };
+
+    let crown = commands
+        .spawn((
+            Text2d::new("♛"),
+            TextFont {
+                font: asset_server.load("fonts/DejaVuSans.ttf"),
+                font_size: 64.0,
+                ..default()
+            },
+            TextColor(CROWN_COLOR),
+            Transform::from_translation(CROWN_OFFSET),
+            MonarchCrown { player: monarch },
+            AppLayer::game_layers(),
+            Name::new("Monarch Crown"),
+        ))
+        .id();
+    commands.entity(playmat).add_child(crown);
+}
+
+/// Keep a badge on every goaded creature
+pub fn sync_goad_badges(
+    mut commands: Commands,
+    politics: Res<PoliticsSystem>,
+    badges: Query<(Entity, &GoadBadge)>,
+    creatures: Query<Option<&Sprite>>,
+) {
+    let is_goaded = |creature: Entity| {
+        politics
+            .goad_effects
+            .get(&creature)
+            .is_some_and(|effects| !effects.is_empty())
+    };
+
+    let mut badged = Vec::new();
+    for (entity, badge) in badges.iter() {
+        if is_goaded(badge.creature) {
+            badged.push(badge.creature);
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    for (&creature, effects) in &politics.goad_effects {
+        if effects.is_empty() || badged.contains(&creature) {
+            continue;
+        }
+        let Ok(sprite) = creatures.get(creature) else {
+            continue;
+        };
+
+        // Sit the badge on the top edge of the card
+        let top = sprite
+            .and_then(|sprite| sprite.custom_size)
+            .map_or(0.0, |size| size.y / 2.0);
+        let badge = commands
+            .spawn((
+                Text2d::new("GOADED"),
+                TextFont {
+                    font_size: 28.0,
+                    ..default()
+                },
+                TextColor(GOAD_COLOR),
+                Transform::from_xyz(0.0, top, 2.0),
+                GoadBadge { creature },
+                AppLayer::game_layers(),
+                Name::new("Goad Badge"),
+            ))
+            .id();
+        commands.entity(creature).add_child(badge);
+    }
+}
+
+/// Open the voting dialog when a vote starts
+pub fn open_vote_dialog(
+    mut commands: Commands,
+    mut vote_started_events: EventReader<VoteStartedEvent>,
+    dialogs: Query<Entity, With<VoteDialogRoot>>,
+) {
+    // Only the most recent vote is shown
+    let Some(event) = vote_started_events.read().last() else {
+        return;
+    };
+    for entity in dialogs.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let vote = &event.vote;
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            UiLayer::Dialog.global_z_index(),
+            VoteDialogRoot { vote_id: vote.id },
+            AppLayer::GameUI.layer(),
+            Name::new("Vote Dialog"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(460.0),
+                        padding: UiRect::all(Val::Px(16.0)),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(10.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.08, 0.1, 0.97)),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(vote.title.clone()),
+                        TextFont {
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                    parent.spawn((
+                        Text::new(voter_label(None)),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.75, 0.75, 0.75)),
+                        VoterText,
+                    ));
+
+                    for choice in &vote.choices {
+                        parent
+                            .spawn((
+                                Button,
+                                Node {
+                                    width: Val::Percent(100.0),
+                                    height: Val::Px(38.0),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                BackgroundColor(CHOICE_COLOR),
+                                VoteChoiceButton(choice.clone()),
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    Text::new(choice_label(choice, 0)),
+                                    TextFont {
+                                        font_size: 18.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::WHITE),
+                                    VoteTallyText(choice.id),
+                                ));
+                            });
+                    }
+                });
+        });
+}
+
+/// Close the voting dialog once its vote completes
+pub fn close_vote_dialog(
+    mut commands: Commands,
+    mut vote_completed_events: EventReader<VoteCompletedEvent>,
+    dialogs: Query<(Entity, &VoteDialogRoot)>,
+) {
+    for event in vote_completed_events.read() {
+        for (entity, dialog) in dialogs.iter() {
+            if dialog.vote_id == event.vote_id {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+/// Cast the next voter's vote for the clicked choice
+pub fn handle_vote_choice_clicks(
+    mut buttons: Query<
+        (&Interaction, &VoteChoiceButton, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    politics: Res<PoliticsSystem>,
+    mut vote_cast_events: EventWriter<VoteCastEvent>,
+) {
+    for (interaction, VoteChoiceButton(choice), mut background) in buttons.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                let (Some(vote), Some(player)) = (&politics.active_vote, politics.next_voter())
+                else {
+                    continue;
+                };
+                vote_cast_events.write(VoteCastEvent {
+                    vote_id: vote.id,
+                    player,
+                    choice: choice.clone(),
+                });
+            }
+            Interaction::Hovered => background.0 = HOVERED_CHOICE_COLOR,
+            Interaction::None => background.0 = CHOICE_COLOR,
+        }
+    }
+}
+
+/// Refresh the tallies and the next voter
+pub fn update_vote_dialog(
+    politics: Res<PoliticsSystem>,
+    players: Query<&Player>,
+    buttons: Query<&VoteChoiceButton>,
+    mut tallies: Query<(&VoteTallyText, &mut Text), Without<VoterText>>,
+    mut voter_texts: Query<&mut Text, With<VoterText>>,
+) {
+    let counts = politics.vote_counts();
+    for (VoteTallyText(choice_id), mut text) in tallies.iter_mut() {
+        if let Some(VoteChoiceButton(choice)) =
+            buttons.iter().find(|button| button.0.id == *choice_id)
+        {
+            text.0 = choice_label(choice, counts.get(choice_id).copied().unwrap_or(0));
+        }
+    }
+
+    let voter_name = politics
+        .next_voter()
+        .and_then(|voter| players.get(voter).ok())
+        .map(|player| player.name.as_str());
+    for mut text in voter_texts.iter_mut() {
+        text.0 = voter_label(voter_name);
+    }
+}
+
+/// Remove all politics indicators when leaving the game
+pub fn despawn_politics_hud(
+    mut commands: Commands,
+    crowns: Query<Entity, With<MonarchCrown>>,
+    badges: Query<Entity, With<GoadBadge>>,
+    dialogs: Query<Entity, With<VoteDialogRoot>>,
+) {
+    for entity in crowns.iter().chain(badges.iter()).chain(dialogs.iter()) {
+        commands.entity(entity).despawn();
+    }
+}
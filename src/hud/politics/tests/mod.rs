@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+
+use crate::game_engine::politics::{PoliticsSystem, Vote, VoteChoice};
+use crate::hud::politics::{choice_label, voter_label};
+
+#[test]
+fn test_choice_label_shows_tally() {
+    let choice = VoteChoice::new(0, "Grace");
+    assert_eq!(choice_label(&choice, 0), "Grace - 0 votes");
+    assert_eq!(choice_label(&choice, 1), "Grace - 1 vote");
+    assert_eq!(voter_label(Some("Player 2")), "Player 2 to vote");
+}
+
+#[test]
+fn test_voters_take_turns_and_tallies_count_weights() {
+    let mut world = World::new();
+    let first = world.spawn_empty().id();
+    let second = world.spawn_empty().id();
+    let source = world.spawn_empty().id();
+
+    let grace = VoteChoice::new(0, "Grace");
+    let condemnation = VoteChoice::new(1, "Condemnation");
+    let vote = Vote::builder("Council's Judgment", first, source)
+        .choices(vec![grace.clone(), condemnation.clone()])
+        .eligible_voters(vec![first, second])
+        .build();
+
+    let mut politics = PoliticsSystem {
+        active_vote: Some(vote),
+        ..Default::default()
+    };
+    politics.vote_weights.insert(first, 2);
+    assert_eq!(politics.next_voter(), Some(first));
+
+    politics.votes_cast.insert(first, grace);
+    assert_eq!(politics.next_voter(), Some(second));
+
+    politics.votes_cast.insert(second, condemnation);
+    assert_eq!(politics.next_voter(), None);
+
+    let counts = politics.vote_counts();
+    assert_eq!(counts.get(&0), Some(&2));
+    assert_eq!(counts.get(&1), Some(&1));
+}
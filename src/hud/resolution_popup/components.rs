@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use crate::game_engine::choices::ChoiceId;
+use crate::game_engine::stack::ResolvingItem;
+
+/// Seconds a resolved item stays shown
+pub const RESOLVED_POPUP_SECS: f32 = 3.0;
+
+/// Root node of the resolution popup
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ResolutionPopupRoot {
+    /// The stack item shown
+    pub item: Entity,
+    /// Whether it's waiting for a choice
+    pub waiting: bool,
+    /// Seconds it has been shown
+    pub shown_for: f32,
+}
+
+/// Button choosing an option for the item resolving
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolutionOptionButton {
+    pub player: Entity,
+    /// The choice asked of the item's controller
+    pub choice: ChoiceId,
+    pub option: usize,
+}
+
+/// The lines describing a resolving item, naming entities with `name_of`
+pub fn detail_lines(item: &ResolvingItem, name_of: impl Fn(Entity) -> String) -> Vec<String> {
+    let name = if item.details.name.is_empty() {
+        name_of(item.item)
+    } else {
+        item.details.name.clone()
+    };
+    let mut lines = vec![format!("Resolving: {}", name)];
+    if !item.details.modes.is_empty() {
+        lines.push(format!("Modes: {}", item.details.modes.join(", ")));
+    }
+    if let Some(x) = item.details.x_value {
+        lines.push(format!("X = {}", x));
+    }
+    if !item.targets.is_empty() {
+        let targets: Vec<String> = item.targets.iter().map(|&target| name_of(target)).collect();
+        lines.push(format!("Targets: {}", targets.join(", ")));
+    }
+    if let Some(choice) = item.choice.as_ref().filter(|_| item.is_waiting()) {
+        lines.push(format!(
+            "{} chooses: {}",
+            name_of(item.controller),
+            choice.prompt
+        ));
+    }
+    lines
+}
@@ -0,0 +1,19 @@
+//! Popup showing the stack item resolving
+//!
+//! As a spell or ability resolves, the popup names it along with its chosen
+//! modes, X value and targets, then fades after [`RESOLVED_POPUP_SECS`]. While
+//! resolution waits for a choice from the item's controller, the popup stays up
+//! with a button per option, each answering the choice with an
+//! [`AnswerChoiceEvent`](crate::game_engine::choices::AnswerChoiceEvent).
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{
+    RESOLVED_POPUP_SECS, ResolutionOptionButton, ResolutionPopupRoot, detail_lines,
+};
+pub use plugin::ResolutionPopupPlugin;
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use super::systems::{
+    despawn_resolution_popup, handle_resolution_option_clicks, sync_resolution_popup,
+    tick_resolution_popup,
+};
+use crate::game_engine::stack::StackResolution;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the popup showing what the stack is resolving
+pub struct ResolutionPopupPlugin;
+
+impl Plugin for ResolutionPopupPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMenuState::InGame), despawn_resolution_popup)
+            .add_systems(
+                Update,
+                (
+                    sync_resolution_popup.run_if(resource_changed::<StackResolution>),
+                    handle_resolution_option_clicks,
+                    tick_resolution_popup,
+                )
+                    .chain()
+                    .run_if(
+                        in_state(GameMenuState::InGame).and(resource_exists::<StackResolution>),
+                    ),
+            );
+    }
+}
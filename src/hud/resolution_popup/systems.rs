@@ -0,0 +1,177 @@
+use bevy::prelude::*;
+
+use super::components::{
+    RESOLVED_POPUP_SECS, ResolutionOptionButton, ResolutionPopupRoot, detail_lines,
+};
+use crate::camera::components::AppLayer;
+use crate::camera::z_order::UiLayer;
+use crate::cards::Card;
+use crate::game_engine::choices::{AnswerChoiceEvent, ChoiceAnswer};
+use crate::game_engine::stack::StackResolution;
+use crate::player::Player;
+
+const OPTION_COLOR: Color = Color::srgba(0.2, 0.35, 0.55, 0.95);
+
+/// Show the item waiting for a choice, or else the item just resolved
+pub fn sync_resolution_popup(
+    mut commands: Commands,
+    resolution: Res<StackResolution>,
+    roots: Query<(Entity, &ResolutionPopupRoot)>,
+    names: Query<&Name>,
+    cards: Query<&Card>,
+    players: Query<&Player>,
+    mut last_shown: Local<Option<(Entity, bool)>>,
+) {
+    let showing = match (&resolution.current, &resolution.last_resolved) {
+        (Some(current), _) => Some(current),
+        // A resolved item is only shown once
+        (None, Some(resolved)) if *last_shown != Some((resolved.item, false)) => Some(resolved),
+        _ => None,
+    };
+    let shown = roots
+        .iter()
+        .next()
+        .map(|(_, root)| (root.item, root.waiting));
+    let Some(item) = showing else {
+        // Keep a resolved item up until it fades
+        if shown.is_some_and(|(_, waiting)| waiting) {
+            for (root, _) in roots.iter() {
+                commands.entity(root).despawn();
+            }
+        }
+        return;
+    };
+    if shown == Some((item.item, item.is_waiting())) {
+        return;
+    }
+    for (root, _) in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    *last_shown = Some((item.item, item.is_waiting()));
+
+    let name_of = |entity: Entity| {
+        players
+            .get(entity)
+            .map(|player| player.name.clone())
+            .or_else(|_| cards.get(entity).map(|card| card.name.name.clone()))
+            .or_else(|_| names.get(entity).map(|name| name.to_string()))
+            .unwrap_or_else(|_| format!("{:?}", entity))
+    };
+    let lines = detail_lines(item, name_of);
+    let options = item
+        .choice
+        .as_ref()
+        .zip(item.choice_id)
+        .filter(|_| item.is_waiting())
+        .map(|(choice, id)| (id, choice.options.clone()));
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(120.0),
+                right: Val::Px(24.0),
+                width: Val::Px(320.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.06, 0.06, 0.1, 0.92)),
+            UiLayer::Dialog.global_z_index(),
+            ResolutionPopupRoot {
+                item: item.item,
+                waiting: item.is_waiting(),
+                shown_for: 0.0,
+            },
+            AppLayer::GameUI.layer(),
+            Name::new("Resolution Popup"),
+        ))
+        .with_children(|parent| {
+            for line in lines {
+                parent.spawn((
+                    Text::new(line),
+                    TextFont {
+                        font_size: 15.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            }
+            let Some((choice, options)) = options else {
+                return;
+            };
+            for (option, label) in options.into_iter().enumerate() {
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Px(30.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(OPTION_COLOR),
+                        ResolutionOptionButton {
+                            player: item.controller,
+                            choice,
+                            option,
+                        },
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            Text::new(label),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                    });
+            }
+        });
+}
+
+/// Send the option clicked
+pub fn handle_resolution_option_clicks(
+    buttons: Query<(&Interaction, &ResolutionOptionButton), Changed<Interaction>>,
+    mut answers: EventWriter<AnswerChoiceEvent>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            answers.write(AnswerChoiceEvent {
+                player: button.player,
+                id: button.choice,
+                answer: ChoiceAnswer::Option(button.option),
+            });
+        }
+    }
+}
+
+/// Remove a resolved item's popup once it has been shown long enough
+pub fn tick_resolution_popup(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut roots: Query<(Entity, &mut ResolutionPopupRoot)>,
+) {
+    for (entity, mut root) in roots.iter_mut() {
+        if root.waiting {
+            continue;
+        }
+        root.shown_for += time.delta_secs();
+        if root.shown_for >= RESOLVED_POPUP_SECS {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Despawn the resolution popup
+pub fn despawn_resolution_popup(
+    mut commands: Commands,
+    roots: Query<Entity, With<ResolutionPopupRoot>>,
+) {
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+}
@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+
+use crate::game_engine::choices::ChoiceId;
+use crate::game_engine::stack::{
+    ResolutionChoice, ResolutionDetails, ResolvingItem, StackResolution,
+};
+use crate::hud::resolution_popup::systems::sync_resolution_popup;
+use crate::hud::resolution_popup::{ResolutionOptionButton, ResolutionPopupRoot, detail_lines};
+
+fn resolving(item: Entity, controller: Entity, choice: bool) -> ResolvingItem {
+    ResolvingItem {
+        item,
+        controller,
+        targets: vec![Entity::from_raw(7)],
+        details: ResolutionDetails {
+            name: "Fireball".to_string(),
+            modes: Vec::new(),
+            x_value: Some(4),
+        },
+        choice: choice.then(|| ResolutionChoice {
+            prompt: "Split the damage?".to_string(),
+            options: vec!["Yes".to_string(), "No".to_string()],
+        }),
+        answer: None,
+        choice_id: choice.then_some(ChoiceId(0)),
+    }
+}
+
+#[test]
+fn test_detail_lines() {
+    let item = resolving(Entity::from_raw(1), Entity::from_raw(2), true);
+    let name_of = |entity: Entity| match entity.index() {
+        2 => "Alice".to_string(),
+        7 => "Bob".to_string(),
+        _ => "?".to_string(),
+    };
+    assert_eq!(
+        detail_lines(&item, name_of),
+        vec![
+            "Resolving: Fireball",
+            "X = 4",
+            "Targets: Bob",
+            "Alice chooses: Split the damage?"
+        ]
+    );
+}
+
+#[test]
+fn test_popup_offers_options_while_waiting() {
+    let mut app = App::new();
+    app.init_resource::<StackResolution>()
+        .add_systems(Update, sync_resolution_popup);
+    let [item, alice] = [1, 2].map(Entity::from_raw);
+    let buttons = |app: &mut App| {
+        app.world_mut()
+            .query::<&ResolutionOptionButton>()
+            .iter(app.world())
+            .count()
+    };
+    let roots = |app: &mut App| {
+        app.world_mut()
+            .query::<&ResolutionPopupRoot>()
+            .iter(app.world())
+            .copied()
+            .collect::<Vec<_>>()
+    };
+
+    app.world_mut().resource_mut::<StackResolution>().current = Some(resolving(item, alice, true));
+    app.update();
+    assert_eq!(buttons(&mut app), 2);
+    assert!(roots(&mut app)[0].waiting);
+
+    // Once chosen, the item is shown resolved without options
+    let mut resolved = resolving(item, alice, true);
+    resolved.answer = Some(1);
+    let mut resolution = app.world_mut().resource_mut::<StackResolution>();
+    resolution.current = None;
+    resolution.last_resolved = Some(resolved);
+    app.update();
+    assert_eq!(buttons(&mut app), 0);
+    let shown = roots(&mut app);
+    assert_eq!(shown.len(), 1);
+    assert!(!shown[0].waiting);
+}
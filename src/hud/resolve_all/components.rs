@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+/// Button resolving the local player's run of items on the stack
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolveAllButton {
+    /// The player whose items it resolves
+    pub player: Entity,
+    /// How many items it would resolve
+    pub run: usize,
+}
+
+/// The button's label for a run of `run` items
+pub fn resolve_all_label(run: usize) -> String {
+    format!("Resolve all mine ({})", run)
+}
@@ -0,0 +1,16 @@
+//! Button to resolve a run of the local player's own stack items
+//!
+//! While the local player holds priority with two or more of their own items on
+//! top of the stack that need no choices, like a pile of upkeep triggers, a
+//! button resolves them all in one click. Resolving stops by itself when
+//! another player gets priority or a choice has to be made.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{ResolveAllButton, resolve_all_label};
+pub use plugin::ResolveAllPlugin;
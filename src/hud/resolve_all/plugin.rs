@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+use super::systems::{
+    despawn_resolve_all_button, handle_resolve_all_clicks, update_resolve_all_button,
+};
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the button resolving a run of the local player's stack items
+pub struct ResolveAllPlugin;
+
+impl Plugin for ResolveAllPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMenuState::InGame), despawn_resolve_all_button)
+            .add_systems(
+                Update,
+                (update_resolve_all_button, handle_resolve_all_clicks)
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame)),
+            );
+    }
+}
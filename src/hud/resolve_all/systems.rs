@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+
+use super::components::{ResolveAllButton, resolve_all_label};
+use crate::camera::components::AppLayer;
+use crate::camera::presets::LOCAL_PLAYER_INDEX;
+use crate::camera::z_order::UiLayer;
+use crate::game_engine::stack::{ResolveAllEvent, ResolvingAll, resolvable_run};
+use crate::game_engine::{GameStack, PrioritySystem};
+use crate::player::Player;
+
+const BUTTON_COLOR: Color = Color::srgba(0.3, 0.25, 0.5, 0.95);
+
+/// Show the button while the local player holds priority with two or more of
+/// their items to resolve, and remove it otherwise
+pub fn update_resolve_all_button(
+    mut commands: Commands,
+    stack: Res<GameStack>,
+    priority: Res<PrioritySystem>,
+    resolving: Res<ResolvingAll>,
+    players: Query<(Entity, &Player)>,
+    mut buttons: Query<(Entity, &mut ResolveAllButton, &Children)>,
+    mut texts: Query<&mut Text>,
+) {
+    if !stack.is_changed() && !priority.is_changed() && !resolving.is_changed() {
+        return;
+    }
+
+    let shown = players
+        .iter()
+        .find(|(_, player)| player.player_index == LOCAL_PLAYER_INDEX)
+        .map(|(local, _)| local)
+        .filter(|&local| priority.has_priority(local) && resolving.player.is_none())
+        .map(|local| (local, resolvable_run(&stack, local)))
+        .filter(|&(_, run)| run >= 2);
+
+    let Some((player, run)) = shown else {
+        for (button, ..) in buttons.iter() {
+            commands.entity(button).despawn();
+        }
+        return;
+    };
+
+    if let Some((_, mut button, children)) = buttons.iter_mut().next() {
+        *button = ResolveAllButton { player, run };
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = resolve_all_label(run);
+            }
+        }
+        return;
+    }
+
+    commands
+        .spawn((
+            Button,
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(16.0),
+                bottom: Val::Percent(30.0),
+                padding: UiRect::axes(Val::Px(14.0), Val::Px(8.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(BUTTON_COLOR),
+            BorderColor(Color::srgb(0.7, 0.6, 1.0)),
+            UiLayer::Dialog.global_z_index(),
+            ResolveAllButton { player, run },
+            AppLayer::GameUI.layer(),
+            Name::new("Resolve All Button"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(resolve_all_label(run)),
+                TextFont {
+                    font_size: 15.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// Ask to resolve the player's items when the button is clicked
+pub fn handle_resolve_all_clicks(
+    buttons: Query<(&Interaction, &ResolveAllButton), Changed<Interaction>>,
+    mut requests: EventWriter<ResolveAllEvent>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            requests.write(ResolveAllEvent {
+                player: button.player,
+            });
+        }
+    }
+}
+
+/// Despawn the resolve all button
+pub fn despawn_resolve_all_button(
+    mut commands: Commands,
+    buttons: Query<Entity, With<ResolveAllButton>>,
+) {
+    for button in buttons.iter() {
+        commands.entity(button).despawn();
+    }
+}
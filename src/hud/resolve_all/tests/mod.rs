@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+
+use crate::game_engine::priority::PrioritySystemBuilder;
+use crate::game_engine::stack::{EffectSpec, ResolveAllEvent, ResolvingAll, StackItemSpec};
+use crate::game_engine::{GameStack, PrioritySystem};
+use crate::hud::resolve_all::systems::{handle_resolve_all_clicks, update_resolve_all_button};
+use crate::hud::resolve_all::{ResolveAllButton, resolve_all_label};
+use crate::player::Player;
+
+struct Table {
+    app: App,
+    player: Entity,
+    opponent: Entity,
+}
+
+impl Table {
+    /// The local player holding priority with an empty stack
+    fn new() -> Self {
+        let mut app = App::new();
+        app.add_event::<ResolveAllEvent>()
+            .init_resource::<GameStack>()
+            .init_resource::<ResolvingAll>()
+            .add_systems(
+                Update,
+                (update_resolve_all_button, handle_resolve_all_clicks).chain(),
+            );
+        let world = app.world_mut();
+        let player = world.spawn(Player::new("Alice")).id();
+        let opponent = world.spawn(Player::new("Bob").with_player_index(1)).id();
+        world.insert_resource(
+            PrioritySystemBuilder::new()
+                .active_player(player)
+                .priority_player(player)
+                .build(),
+        );
+        Self {
+            app,
+            player,
+            opponent,
+        }
+    }
+
+    /// Put a trigger controlled by `controller` on the stack
+    fn trigger(&mut self, controller: Entity) {
+        let entity = self.app.world_mut().spawn_empty().id();
+        let item = StackItemSpec {
+            spec: EffectSpec::Opaque {
+                name: "Phyrexian Arena".to_string(),
+                controller,
+                targets: Vec::new(),
+            },
+            has_split_second: false,
+            can_be_countered: true,
+        };
+        self.app
+            .world_mut()
+            .resource_mut::<GameStack>()
+            .push_spec(&item, entity);
+    }
+
+    fn button(&mut self) -> Option<(Entity, ResolveAllButton)> {
+        self.app.update();
+        self.app
+            .world_mut()
+            .query::<(Entity, &ResolveAllButton)>()
+            .iter(self.app.world())
+            .next()
+            .map(|(entity, button)| (entity, *button))
+    }
+}
+
+#[test]
+fn test_resolve_all_label() {
+    assert_eq!(resolve_all_label(3), "Resolve all mine (3)");
+}
+
+#[test]
+fn test_button_shows_for_a_run_of_the_local_players_items() {
+    let mut table = Table::new();
+    let (player, opponent) = (table.player, table.opponent);
+    table.trigger(opponent);
+    table.trigger(player);
+    assert!(table.button().is_none(), "one item needs no shortcut");
+
+    table.trigger(player);
+    let (_, button) = table.button().unwrap();
+    assert_eq!(button, ResolveAllButton { player, run: 2 });
+
+    // Gone while the items resolve, and while another player has priority
+    table.app.world_mut().resource_mut::<ResolvingAll>().player = Some(player);
+    assert!(table.button().is_none());
+    *table.app.world_mut().resource_mut::<ResolvingAll>() = ResolvingAll::default();
+    assert!(table.button().is_some());
+    table
+        .app
+        .world_mut()
+        .resource_mut::<PrioritySystem>()
+        .priority_player = opponent;
+    assert!(table.button().is_none());
+}
+
+#[test]
+fn test_clicking_asks_to_resolve_all() {
+    let mut table = Table::new();
+    let player = table.player;
+    table.trigger(player);
+    table.trigger(player);
+    let (button, _) = table.button().unwrap();
+
+    table
+        .app
+        .world_mut()
+        .entity_mut(button)
+        .insert(Interaction::Pressed);
+    table.app.update();
+    let requests: Vec<ResolveAllEvent> = table
+        .app
+        .world_mut()
+        .resource_mut::<Events<ResolveAllEvent>>()
+        .drain()
+        .collect();
+    assert_eq!(requests, vec![ResolveAllEvent { player }]);
+}
@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+
+/// Root node of the response prompt
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ResponsePromptRoot {
+    /// The player being prompted
+    pub player: Entity,
+    /// The item on top of the stack when the prompt was shown
+    pub top: Entity,
+    /// Seconds the prompt has waited for an answer
+    pub shown_for: f32,
+    /// Whether the player answered it, hiding the prompt
+    pub answered: bool,
+}
+
+/// What a response prompt button does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseAction {
+    /// Pass priority, letting the stack resolve
+    Resolve,
+    /// Hide the prompt to cast or activate a response
+    Respond,
+}
+
+/// Button answering the response prompt
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResponseButton(pub ResponseAction);
+
+/// Text listing the responses available
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ResponseListText;
+
+/// Text counting down to priority being passed
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ResponseCountdownText;
+
+/// The lines listing the responses available
+pub fn response_lines(responses: &[String]) -> String {
+    responses
+        .iter()
+        .map(|response| format!("- {}", response))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The countdown shown with `remaining` seconds left
+pub fn countdown_line(remaining: f32) -> String {
+    format!("Resolving in {}s", remaining.max(0.0).ceil() as u32)
+}
@@ -0,0 +1,21 @@
+//! Prompt to respond to what's on the stack
+//!
+//! When the local player gets priority with something on the stack and could
+//! cast an instant or flash spell or activate an ability, a prompt names the
+//! item on top and lists those responses, so the window to respond isn't
+//! missed. Resolve passes priority; Respond hides the prompt to cast or
+//! activate as usual. With a response timeout set in the gameplay settings,
+//! priority is passed once it runs out.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{
+    ResponseAction, ResponseButton, ResponseCountdownText, ResponseListText, ResponsePromptRoot,
+    countdown_line, response_lines,
+};
+pub use plugin::ResponsePromptPlugin;
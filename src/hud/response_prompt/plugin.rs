@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+use super::systems::{
+    despawn_response_prompt, handle_response_clicks, tick_response_prompt, update_response_prompt,
+};
+use crate::game_engine::zones::ZoneManager;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the prompt to respond to what's on the stack
+pub struct ResponsePromptPlugin;
+
+impl Plugin for ResponsePromptPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMenuState::InGame), despawn_response_prompt)
+            .add_systems(
+                Update,
+                (
+                    update_response_prompt,
+                    handle_response_clicks,
+                    tick_response_prompt,
+                )
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame).and(resource_exists::<ZoneManager>)),
+            );
+    }
+}
@@ -0,0 +1,330 @@
+use bevy::prelude::*;
+
+use super::components::{
+    ResponseAction, ResponseButton, ResponseCountdownText, ResponseListText, ResponsePromptRoot,
+    countdown_line, response_lines,
+};
+use crate::camera::components::AppLayer;
+use crate::camera::presets::LOCAL_PLAYER_INDEX;
+use crate::camera::z_order::UiLayer;
+use crate::cards::abilities::ActivatedAbility;
+use crate::cards::{Card, CardTypes};
+use crate::game_engine::permanent::{PermanentController, PermanentState};
+use crate::game_engine::zones::{Zone, ZoneManager};
+use crate::game_engine::{GameAction, GameStack, GameState, Phase, PrioritySystem};
+use crate::hud::action_hints::{ActionHint, HintContext};
+use crate::menu::settings::components::GameplaySettings;
+use crate::player::Player;
+
+const PROMPT_COLOR: Color = Color::srgb(1.0, 0.7, 0.2);
+const RESOLVE_COLOR: Color = Color::srgba(0.25, 0.25, 0.3, 0.95);
+const RESPOND_COLOR: Color = Color::srgba(0.2, 0.45, 0.25, 0.95);
+
+/// What the local player could respond with, if anything
+fn response_label(
+    context: &HintContext,
+    card: &Card,
+    zone: Zone,
+    state: Option<&PermanentState>,
+    ability: Option<&ActivatedAbility>,
+    player: &Player,
+) -> Option<String> {
+    match context.action_hint(card, zone, state, ability, player)? {
+        ActionHint::Play if !card.type_info.types.contains(CardTypes::LAND) => {
+            Some(format!("Cast {}", card.name.name))
+        }
+        ActionHint::Activate => Some(format!("Activate {}", card.name.name)),
+        _ => None,
+    }
+}
+
+/// Prompt the local player while they hold priority with something on the
+/// stack and have a response available, and remove the prompt once they don't
+///
+/// Like the action hints, responses are only worked out again when the game
+/// changes.
+#[allow(clippy::too_many_arguments)]
+pub fn update_response_prompt(
+    mut commands: Commands,
+    zones: Res<ZoneManager>,
+    game_state: Res<GameState>,
+    phase: Res<Phase>,
+    stack: Res<GameStack>,
+    priority: Res<PrioritySystem>,
+    players: Query<(Entity, &Player)>,
+    cards: Query<(
+        &Card,
+        Option<&PermanentState>,
+        Option<&PermanentController>,
+        Option<&ActivatedAbility>,
+    )>,
+    roots: Query<(Entity, &ResponsePromptRoot)>,
+    mut list: Query<&mut Text, With<ResponseListText>>,
+    changed_cards: Query<(), Or<(Changed<PermanentState>, Changed<PermanentController>)>>,
+    changed_players: Query<(), Changed<Player>>,
+) {
+    let changed = zones.is_changed()
+        || game_state.is_changed()
+        || phase.is_changed()
+        || stack.is_changed()
+        || priority.is_changed()
+        || !changed_cards.is_empty()
+        || !changed_players.is_empty();
+    if !changed {
+        return;
+    }
+
+    let local = players
+        .iter()
+        .find(|(_, player)| player.player_index == LOCAL_PLAYER_INDEX)
+        .filter(|(local, _)| priority.has_priority(*local));
+    let prompt = local
+        .zip(stack.items.last())
+        .and_then(|((local, player), top)| {
+            let context = HintContext {
+                player: local,
+                game_state: &game_state,
+                phase: &phase,
+                stack: &stack,
+                priority: &priority,
+            };
+            let hand = zones.hand(local).iter().map(|card| (*card, Zone::Hand));
+            let battlefield = zones
+                .battlefield()
+                .iter()
+                .map(|card| (*card, Zone::Battlefield));
+            let responses: Vec<String> = hand
+                .chain(battlefield)
+                .filter_map(|(entity, zone)| {
+                    let (card, state, controller, ability) = cards.get(entity).ok()?;
+                    let controlled = match controller {
+                        Some(controller) => controller.player == local,
+                        None => zones.get_card_owner(entity) == Some(local),
+                    };
+                    if !controlled {
+                        return None;
+                    }
+                    response_label(&context, card, zone, state, ability, player)
+                })
+                .collect();
+            (!responses.is_empty()).then_some((local, top, responses))
+        });
+
+    let Some((local, top, responses)) = prompt else {
+        for (root, _) in roots.iter() {
+            commands.entity(root).despawn();
+        }
+        return;
+    };
+
+    // Still the same window to respond in
+    if roots
+        .iter()
+        .any(|(_, root)| root.player == local && root.top == top.entity)
+    {
+        for mut text in list.iter_mut() {
+            text.0 = response_lines(&responses);
+        }
+        return;
+    }
+
+    for (root, _) in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    let controller = players
+        .get(top.controller)
+        .map(|(_, player)| player.name.clone())
+        .unwrap_or_else(|_| format!("{:?}", top.controller));
+    let on_stack = match top.spec.name() {
+        "" => format!("{} has something on the stack", controller),
+        name => format!("On the stack: {} ({})", name, controller),
+    };
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(12.0),
+                left: Val::Percent(50.0),
+                width: Val::Px(380.0),
+                margin: UiRect::left(Val::Px(-190.0)),
+                padding: UiRect::all(Val::Px(14.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.08, 0.06, 0.04, 0.94)),
+            BorderColor(PROMPT_COLOR),
+            UiLayer::Dialog.global_z_index(),
+            ResponsePromptRoot {
+                player: local,
+                top: top.entity,
+                shown_for: 0.0,
+                answered: false,
+            },
+            AppLayer::GameUI.layer(),
+            Name::new("Response Prompt"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Responses available"),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(PROMPT_COLOR),
+            ));
+            parent.spawn((
+                Text::new(on_stack),
+                TextFont {
+                    font_size: 15.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            parent.spawn((
+                Text::new(response_lines(&responses)),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.85, 0.9, 1.0)),
+                ResponseListText,
+            ));
+            parent.spawn((
+                Text::default(),
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.75, 0.75, 0.75)),
+                Visibility::Hidden,
+                ResponseCountdownText,
+            ));
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for (action, label, color) in [
+                        (ResponseAction::Resolve, "Resolve", RESOLVE_COLOR),
+                        (ResponseAction::Respond, "Respond", RESPOND_COLOR),
+                    ] {
+                        parent
+                            .spawn((
+                                Button,
+                                Node {
+                                    flex_grow: 1.0,
+                                    height: Val::Px(32.0),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                BackgroundColor(color),
+                                ResponseButton(action),
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    Text::new(label),
+                                    TextFont {
+                                        font_size: 15.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::WHITE),
+                                ));
+                            });
+                    }
+                });
+        });
+}
+
+/// Hide the prompt once answered, passing priority if the player let the
+/// stack resolve
+///
+/// The prompt is kept until priority moves on, so it isn't shown again for
+/// the same item.
+fn answer_prompt(
+    root: &mut ResponsePromptRoot,
+    visibility: &mut Visibility,
+    action: ResponseAction,
+    actions: &mut EventWriter<GameAction>,
+) {
+    root.answered = true;
+    *visibility = Visibility::Hidden;
+    if action == ResponseAction::Resolve {
+        actions.write(GameAction::PassPriority {
+            player: root.player,
+        });
+    }
+}
+
+/// Answer the prompt with the button clicked
+pub fn handle_response_clicks(
+    buttons: Query<(&Interaction, &ResponseButton), Changed<Interaction>>,
+    mut roots: Query<(&mut ResponsePromptRoot, &mut Visibility)>,
+    mut actions: EventWriter<GameAction>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        for (mut root, mut visibility) in roots.iter_mut() {
+            if !root.answered {
+                answer_prompt(&mut root, &mut visibility, button.0, &mut actions);
+            }
+        }
+    }
+}
+
+/// Count down the prompt's timeout from the gameplay settings, passing
+/// priority when it runs out
+pub fn tick_response_prompt(
+    time: Res<Time>,
+    settings: Option<Res<GameplaySettings>>,
+    mut roots: Query<(&mut ResponsePromptRoot, &mut Visibility)>,
+    mut countdown: Query<
+        (&mut Text, &mut Visibility),
+        (With<ResponseCountdownText>, Without<ResponsePromptRoot>),
+    >,
+    mut actions: EventWriter<GameAction>,
+) {
+    let timeout = settings.map_or(0, |settings| settings.response_timeout_secs);
+    for (mut root, mut visibility) in roots.iter_mut() {
+        if root.answered {
+            continue;
+        }
+        if timeout == 0 {
+            for (_, mut visibility) in countdown.iter_mut() {
+                *visibility = Visibility::Hidden;
+            }
+            continue;
+        }
+
+        root.shown_for += time.delta_secs();
+        let remaining = timeout as f32 - root.shown_for;
+        for (mut text, mut visibility) in countdown.iter_mut() {
+            text.0 = countdown_line(remaining);
+            *visibility = Visibility::Inherited;
+        }
+        if remaining <= 0.0 {
+            answer_prompt(
+                &mut root,
+                &mut visibility,
+                ResponseAction::Resolve,
+                &mut actions,
+            );
+        }
+    }
+}
+
+/// Despawn the response prompt
+pub fn despawn_response_prompt(
+    mut commands: Commands,
+    roots: Query<Entity, With<ResponsePromptRoot>>,
+) {
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+}
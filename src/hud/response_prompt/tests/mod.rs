@@ -0,0 +1,327 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::cards::{Card, CardDetails, CardTypes};
+use crate::game_engine::actions::process_game_actions;
+use crate::game_engine::phase::{AutoStops, PrecombatStep};
+use crate::game_engine::priority::{PrioritySystemBuilder, priority_system};
+use crate::game_engine::stack::{EffectSpec, StackItemSpec, stack_resolution_system};
+use crate::game_engine::turns::TurnManager;
+use crate::game_engine::{
+    EffectCounteredEvent, GameAction, GameStack, GameState, NextPhaseEvent, PassPriorityEvent,
+    Phase, PrioritySystem, ResolveStackItemEvent, StackItemResolvedEvent, ZoneManager,
+};
+use crate::hud::response_prompt::systems::{
+    handle_response_clicks, tick_response_prompt, update_response_prompt,
+};
+use crate::hud::response_prompt::{
+    ResponseAction, ResponseButton, ResponseCountdownText, ResponseListText, ResponsePromptRoot,
+    countdown_line, response_lines,
+};
+use crate::mana::Mana;
+use crate::menu::settings::components::GameplaySettings;
+use crate::player::Player;
+
+fn card(name: &str, types: CardTypes) -> Card {
+    Card::builder(name)
+        .cost(Mana::default())
+        .types(types)
+        .details(CardDetails::Other)
+        .build_or_panic()
+}
+
+struct Table {
+    app: App,
+    player: Entity,
+    opponent: Entity,
+}
+
+impl Table {
+    /// The local player in their main phase, holding an instant and a sorcery
+    fn new(response_timeout_secs: u32) -> Self {
+        let mut app = App::new();
+        app.add_event::<GameAction>()
+            .init_resource::<Time>()
+            .init_resource::<ZoneManager>()
+            .init_resource::<GameStack>()
+            .insert_resource(Phase::Precombat(PrecombatStep::Main))
+            .insert_resource(GameplaySettings {
+                response_timeout_secs,
+                ..default()
+            })
+            .add_systems(
+                Update,
+                (
+                    update_response_prompt,
+                    handle_response_clicks,
+                    tick_response_prompt,
+                )
+                    .chain(),
+            );
+
+        let world = app.world_mut();
+        let player = world.spawn(Player::new("Alice")).id();
+        let opponent = world.spawn(Player::new("Bob").with_player_index(1)).id();
+        let shock = world.spawn(card("Shock", CardTypes::INSTANT)).id();
+        let divination = world.spawn(card("Divination", CardTypes::SORCERY)).id();
+        let mut zones = world.resource_mut::<ZoneManager>();
+        zones.init_player_zones(player);
+        zones.init_player_zones(opponent);
+        zones.add_to_hand(player, shock);
+        zones.add_to_hand(player, divination);
+
+        world.insert_resource(
+            GameState::builder()
+                .active_player(player)
+                .turn_number(2)
+                .build(),
+        );
+        let mut table = Self {
+            app,
+            player,
+            opponent,
+        };
+        table.give_priority(player);
+        table
+    }
+
+    fn give_priority(&mut self, player: Entity) {
+        self.app.world_mut().insert_resource(
+            PrioritySystemBuilder::new()
+                .active_player(self.player)
+                .priority_player(player)
+                .build(),
+        );
+    }
+
+    /// The opponent puts a spell on the stack
+    fn cast_opponent_spell(&mut self) {
+        let spell = self.app.world_mut().spawn_empty().id();
+        let item = StackItemSpec {
+            spec: EffectSpec::Opaque {
+                name: "Lightning Bolt".to_string(),
+                controller: self.opponent,
+                targets: vec![self.player],
+            },
+            has_split_second: false,
+            can_be_countered: true,
+        };
+        self.app
+            .world_mut()
+            .resource_mut::<GameStack>()
+            .push_spec(&item, spell);
+    }
+
+    fn prompt(&mut self) -> Option<(Entity, ResponsePromptRoot, Visibility)> {
+        self.app
+            .world_mut()
+            .query::<(Entity, &ResponsePromptRoot, &Visibility)>()
+            .iter(self.app.world())
+            .next()
+            .map(|(entity, root, visibility)| (entity, *root, *visibility))
+    }
+
+    fn text<T: Component>(&mut self) -> (String, Visibility) {
+        self.app
+            .world_mut()
+            .query_filtered::<(&Text, Option<&Visibility>), With<T>>()
+            .iter(self.app.world())
+            .next()
+            .map(|(text, visibility)| (text.0.clone(), visibility.copied().unwrap_or_default()))
+            .unwrap()
+    }
+
+    fn click(&mut self, action: ResponseAction) {
+        let button = self
+            .app
+            .world_mut()
+            .query::<(Entity, &ResponseButton)>()
+            .iter(self.app.world())
+            .find(|(_, button)| button.0 == action)
+            .map(|(entity, _)| entity)
+            .unwrap();
+        self.app
+            .world_mut()
+            .entity_mut(button)
+            .insert(Interaction::Pressed);
+        self.app.update();
+    }
+
+    fn wait(&mut self, secs: u64) {
+        self.app
+            .world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(secs));
+        self.app.update();
+    }
+
+    fn passed_priority(&mut self) -> bool {
+        let player = self.player;
+        self.app
+            .world_mut()
+            .resource_mut::<Events<GameAction>>()
+            .drain()
+            .any(|action| {
+                matches!(action, GameAction::PassPriority { player: passer } if passer == player)
+            })
+    }
+}
+
+#[test]
+fn test_response_text() {
+    let responses = [
+        "Cast Shock".to_string(),
+        "Activate Prodigal Sorcerer".to_string(),
+    ];
+    assert_eq!(
+        response_lines(&responses),
+        "- Cast Shock\n- Activate Prodigal Sorcerer"
+    );
+    assert_eq!(countdown_line(4.2), "Resolving in 5s");
+    assert_eq!(countdown_line(-1.0), "Resolving in 0s");
+}
+
+#[test]
+fn test_response_timeouts_cycle_back_to_off() {
+    let mut settings = GameplaySettings::default();
+    assert_eq!(settings.response_timeout_label(), "Off");
+    let mut seen = Vec::new();
+    for _ in GameplaySettings::RESPONSE_TIMEOUTS {
+        settings.response_timeout_secs = settings.next_response_timeout();
+        seen.push(settings.response_timeout_secs);
+    }
+    assert_eq!(seen, [5, 10, 20, 30, 0]);
+}
+
+#[test]
+fn test_prompt_lists_responses_while_holding_priority() {
+    let mut table = Table::new(0);
+    table.app.update();
+    assert!(table.prompt().is_none(), "nothing to respond to yet");
+
+    table.cast_opponent_spell();
+    table.app.update();
+    let (_, root, visibility) = table.prompt().unwrap();
+    assert_eq!(root.player, table.player);
+    assert!(!root.answered);
+    assert_ne!(visibility, Visibility::Hidden);
+    // Sorceries can't be cast in response
+    assert_eq!(table.text::<ResponseListText>().0, "- Cast Shock");
+    assert_eq!(
+        table.text::<ResponseCountdownText>().1,
+        Visibility::Hidden,
+        "no countdown without a timeout"
+    );
+
+    // Gone once priority moves on
+    let opponent = table.opponent;
+    table.give_priority(opponent);
+    table.app.update();
+    assert!(table.prompt().is_none());
+}
+
+#[test]
+fn test_resolve_passes_priority() {
+    let mut table = Table::new(0);
+    table.cast_opponent_spell();
+    table.app.update();
+    assert!(!table.passed_priority());
+
+    table.click(ResponseAction::Resolve);
+    assert!(table.passed_priority());
+    let (_, root, visibility) = table.prompt().unwrap();
+    assert!(root.answered);
+    assert_eq!(visibility, Visibility::Hidden);
+}
+
+#[test]
+fn test_timeout_passes_priority_unless_responding() {
+    let mut table = Table::new(5);
+    table.cast_opponent_spell();
+    table.app.update();
+
+    table.wait(3);
+    assert_eq!(
+        table.text::<ResponseCountdownText>(),
+        ("Resolving in 2s".to_string(), Visibility::Inherited)
+    );
+    assert!(!table.passed_priority());
+    table.wait(3);
+    assert!(table.passed_priority());
+
+    // A new window, answered by responding, doesn't time out
+    let opponent = table.opponent;
+    table.give_priority(opponent);
+    table.app.update();
+    table.cast_opponent_spell();
+    let player = table.player;
+    table.give_priority(player);
+    table.app.update();
+    let (_, root, _) = table.prompt().unwrap();
+    assert!(!root.answered);
+
+    table.click(ResponseAction::Respond);
+    table.wait(10);
+    assert!(!table.passed_priority());
+    let (_, root, visibility) = table.prompt().unwrap();
+    assert!(root.answered);
+    assert_eq!(visibility, Visibility::Hidden);
+}
+
+#[test]
+fn test_resolving_from_every_player_resolves_the_spell() {
+    let mut table = Table::new(0);
+    let (player, opponent) = (table.player, table.opponent);
+    table
+        .app
+        .add_event::<PassPriorityEvent>()
+        .add_event::<ResolveStackItemEvent>()
+        .add_event::<NextPhaseEvent>()
+        .add_event::<StackItemResolvedEvent>()
+        .add_event::<EffectCounteredEvent>()
+        .init_resource::<AutoStops>()
+        .insert_resource(
+            TurnManager::builder()
+                .active_player(player)
+                .player_order(vec![player, opponent])
+                .build(),
+        )
+        .add_systems(
+            Update,
+            (
+                process_game_actions,
+                stack_resolution_system,
+                priority_system,
+            )
+                .chain()
+                .after(tick_response_prompt),
+        );
+    let mut priority = PrioritySystem::default();
+    priority.initialize(&[player, opponent], player);
+    table.app.insert_resource(priority);
+    table.cast_opponent_spell();
+    table.app.update();
+
+    // The local player answers the prompt, and the opponent passes too
+    table.click(ResponseAction::Resolve);
+    table
+        .app
+        .world_mut()
+        .send_event(GameAction::PassPriority { player: opponent });
+    table.app.update();
+    table.app.update();
+
+    assert!(table.app.world().resource::<GameStack>().is_empty());
+    assert_eq!(
+        *table.app.world().resource::<Phase>(),
+        Phase::Precombat(PrecombatStep::Main)
+    );
+    assert!(
+        table
+            .app
+            .world()
+            .resource::<Events<NextPhaseEvent>>()
+            .is_empty()
+    );
+}
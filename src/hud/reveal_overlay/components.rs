@@ -0,0 +1,45 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::game_engine::reveal::RevealMessage;
+
+/// Seconds a reveal is shown before the next one replaces it
+pub const REVEAL_OVERLAY_SECS: f32 = 8.0;
+
+/// Root node of the reveal overlay
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RevealOverlayRoot;
+
+/// Button closing the reveal being shown
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RevealOverlayCloseButton;
+
+/// Reveals for the local player, shown one at a time
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct RevealOverlay {
+    /// The reveal being shown, if any
+    pub shown: Option<RevealMessage>,
+    /// Reveals waiting to be shown, oldest first
+    pub queue: VecDeque<RevealMessage>,
+    /// Seconds the current reveal has been shown
+    pub shown_for: f32,
+}
+
+impl RevealOverlay {
+    /// Show `message` once the reveals before it are done
+    pub fn push(&mut self, message: RevealMessage) {
+        if self.shown.is_none() {
+            self.shown = Some(message);
+            self.shown_for = 0.0;
+        } else {
+            self.queue.push_back(message);
+        }
+    }
+
+    /// Stop showing the current reveal and show the next one
+    pub fn dismiss(&mut self) {
+        self.shown = self.queue.pop_front();
+        self.shown_for = 0.0;
+    }
+}
@@ -0,0 +1,17 @@
+//! Overlay showing cards revealed to the local player
+//!
+//! Each [`RevealMessage`](crate::game_engine::reveal::RevealMessage) for the
+//! local player is shown in turn, naming the player who revealed the cards and
+//! listing them, until it is closed or [`REVEAL_OVERLAY_SECS`] have passed.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{
+    REVEAL_OVERLAY_SECS, RevealOverlay, RevealOverlayCloseButton, RevealOverlayRoot,
+};
+pub use plugin::RevealOverlayPlugin;
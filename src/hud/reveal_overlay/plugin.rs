@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use super::components::RevealOverlay;
+use super::systems::{
+    collect_reveal_messages, despawn_reveal_overlay, handle_reveal_close_clicks,
+    sync_reveal_overlay, tick_reveal_overlay,
+};
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the overlay showing revealed cards
+pub struct RevealOverlayPlugin;
+
+impl Plugin for RevealOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RevealOverlay>()
+            .add_systems(OnExit(GameMenuState::InGame), despawn_reveal_overlay)
+            .add_systems(
+                Update,
+                (
+                    collect_reveal_messages,
+                    handle_reveal_close_clicks,
+                    tick_reveal_overlay,
+                    sync_reveal_overlay.run_if(resource_changed::<RevealOverlay>),
+                )
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame)),
+            );
+    }
+}
@@ -0,0 +1,178 @@
+use bevy::prelude::*;
+
+use super::components::{
+    REVEAL_OVERLAY_SECS, RevealOverlay, RevealOverlayCloseButton, RevealOverlayRoot,
+};
+use crate::camera::components::AppLayer;
+use crate::game_engine::reveal::RevealMessage;
+use crate::player::Player;
+
+const CARD_COLOR: Color = Color::srgba(0.22, 0.22, 0.26, 0.95);
+const CLOSE_COLOR: Color = Color::srgba(0.3, 0.3, 0.35, 0.95);
+
+/// Queue the reveals meant for the local player
+pub fn collect_reveal_messages(
+    mut messages: EventReader<RevealMessage>,
+    players: Query<(Entity, &Player)>,
+    mut overlay: ResMut<RevealOverlay>,
+) {
+    // The local player is always index 0
+    let local = players
+        .iter()
+        .find(|(_, player)| player.player_index == 0)
+        .map(|(entity, _)| entity);
+    for message in messages.read() {
+        if Some(message.recipient) == local {
+            overlay.push(message.clone());
+        }
+    }
+}
+
+/// Move on to the next reveal once the current one has been shown long enough
+pub fn tick_reveal_overlay(time: Res<Time>, mut overlay: ResMut<RevealOverlay>) {
+    if overlay.shown.is_none() {
+        return;
+    }
+    if overlay.shown_for >= REVEAL_OVERLAY_SECS {
+        overlay.dismiss();
+    } else {
+        // Time passing isn't a change the overlay needs to redraw for
+        overlay.bypass_change_detection().shown_for += time.delta_secs();
+    }
+}
+
+/// Close the current reveal when its button is clicked
+pub fn handle_reveal_close_clicks(
+    buttons: Query<&Interaction, (Changed<Interaction>, With<RevealOverlayCloseButton>)>,
+    mut overlay: ResMut<RevealOverlay>,
+) {
+    if buttons
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        overlay.dismiss();
+    }
+}
+
+/// Show the current reveal, replacing the one shown before
+pub fn sync_reveal_overlay(
+    mut commands: Commands,
+    overlay: Res<RevealOverlay>,
+    players: Query<&Player>,
+    roots: Query<Entity, With<RevealOverlayRoot>>,
+) {
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    let Some(message) = &overlay.shown else {
+        return;
+    };
+    let revealer = players
+        .get(message.revealer)
+        .map_or_else(|_| "A player".to_string(), |player| player.name.clone());
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(80.0),
+                left: Val::Percent(50.0),
+                margin: UiRect::left(Val::Px(-300.0)),
+                width: Val::Px(600.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.9)),
+            RevealOverlayRoot,
+            AppLayer::GameUI.layer(),
+            Name::new("Reveal Overlay"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("{} reveals", revealer)),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        flex_wrap: FlexWrap::Wrap,
+                        justify_content: JustifyContent::Center,
+                        column_gap: Val::Px(6.0),
+                        row_gap: Val::Px(6.0),
+                        ..default()
+                    },
+                    Name::new("Revealed Cards"),
+                ))
+                .with_children(|parent| {
+                    for info in &message.cards {
+                        parent
+                            .spawn((
+                                Node {
+                                    min_width: Val::Px(110.0),
+                                    height: Val::Px(32.0),
+                                    padding: UiRect::horizontal(Val::Px(8.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                BackgroundColor(CARD_COLOR),
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    Text::new(info.name.clone()),
+                                    TextFont {
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::WHITE),
+                                ));
+                            });
+                    }
+                });
+
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(100.0),
+                        height: Val::Px(30.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(CLOSE_COLOR),
+                    RevealOverlayCloseButton,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("Close"),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+        });
+}
+
+/// Despawn the reveal overlay and forget waiting reveals
+pub fn despawn_reveal_overlay(
+    mut commands: Commands,
+    roots: Query<Entity, With<RevealOverlayRoot>>,
+    mut overlay: ResMut<RevealOverlay>,
+) {
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    *overlay = RevealOverlay::default();
+}
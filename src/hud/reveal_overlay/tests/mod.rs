@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+
+use crate::game_engine::reveal::{RevealMessage, RevealedCardInfo};
+use crate::hud::reveal_overlay::systems::collect_reveal_messages;
+use crate::hud::reveal_overlay::{REVEAL_OVERLAY_SECS, RevealOverlay};
+use crate::player::Player;
+
+fn message(recipient: Entity, revealer: Entity, name: &str) -> RevealMessage {
+    RevealMessage {
+        recipient,
+        revealer,
+        cards: vec![RevealedCardInfo {
+            card: Entity::from_raw(100),
+            name: name.to_string(),
+        }],
+    }
+}
+
+#[test]
+fn test_overlay_shows_reveals_one_at_a_time() {
+    let [alice, bob] = [1, 2].map(Entity::from_raw);
+    let mut overlay = RevealOverlay::default();
+    overlay.push(message(alice, bob, "Forest"));
+    overlay.push(message(alice, bob, "Island"));
+    assert_eq!(overlay.shown.as_ref().unwrap().cards[0].name, "Forest");
+    assert_eq!(overlay.queue.len(), 1);
+
+    overlay.shown_for = REVEAL_OVERLAY_SECS;
+    overlay.dismiss();
+    assert_eq!(overlay.shown.as_ref().unwrap().cards[0].name, "Island");
+    assert_eq!(overlay.shown_for, 0.0);
+    overlay.dismiss();
+    assert!(overlay.shown.is_none());
+}
+
+#[test]
+fn test_only_reveals_for_the_local_player_are_shown() {
+    let mut app = App::new();
+    app.add_event::<RevealMessage>()
+        .init_resource::<RevealOverlay>()
+        .add_systems(Update, collect_reveal_messages);
+    let alice = app.world_mut().spawn(Player::new("Alice")).id();
+    let bob = app
+        .world_mut()
+        .spawn(Player::new("Bob").with_player_index(1))
+        .id();
+
+    app.world_mut()
+        .send_event(message(bob, alice, "Lightning Bolt"));
+    app.world_mut()
+        .send_event(message(alice, bob, "Counterspell"));
+    app.update();
+    let overlay = app.world().resource::<RevealOverlay>();
+    assert_eq!(overlay.shown.as_ref().unwrap().recipient, alice);
+    assert!(overlay.queue.is_empty());
+}
@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+
+use crate::game_engine::sacrifice::{PermanentSelection, SacrificePurpose};
+
+/// Root node of the sacrifice picker
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct SacrificePickerRoot {
+    /// The player choosing
+    pub player: Entity,
+    /// The permanents shown
+    pub candidates: Vec<Entity>,
+}
+
+/// Text naming the player choosing, how many to choose and the time left
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SacrificePickerTitle;
+
+/// Button selecting or deselecting a permanent
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SacrificeCandidateButton(pub Entity);
+
+/// Button confirming the selected permanents
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SacrificeConfirmButton;
+
+/// The permanents selected in the picker so far
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SacrificeDraft {
+    /// Selected permanents, in the order they were clicked
+    pub selected: Vec<Entity>,
+}
+
+impl SacrificeDraft {
+    /// Select `permanent`, or deselect it if it was selected
+    ///
+    /// Nothing more can be selected once `count` are.
+    pub fn toggle(&mut self, permanent: Entity, count: usize) {
+        if let Some(index) = self.selected.iter().position(|&p| p == permanent) {
+            self.selected.remove(index);
+        } else if self.selected.len() < count {
+            self.selected.push(permanent);
+        }
+    }
+}
+
+/// The picker's title
+pub fn picker_title(player_name: &str, selection: &PermanentSelection, selected: usize) -> String {
+    let purpose = match selection.request.purpose {
+        SacrificePurpose::Cost => " to pay a cost",
+        SacrificePurpose::Effect => "",
+    };
+    format!(
+        "{}: sacrifice {}{} ({}/{} chosen, {:.0}s)",
+        player_name,
+        selection.request.count,
+        purpose,
+        selected,
+        selection.request.count,
+        selection.remaining_secs().ceil()
+    )
+}
@@ -0,0 +1,20 @@
+//! Picker for the permanents a player sacrifices
+//!
+//! While a player chooses permanents to sacrifice, a dialog lists the permanents
+//! they can choose. Clicking one selects or deselects it, and once the right
+//! number are selected the confirm button sends a
+//! [`ConfirmSelectionEvent`](crate::game_engine::sacrifice::ConfirmSelectionEvent).
+//! The engine chooses for the player itself after a timeout.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{
+    SacrificeCandidateButton, SacrificeConfirmButton, SacrificeDraft, SacrificePickerRoot,
+    SacrificePickerTitle, picker_title,
+};
+pub use plugin::SacrificePickerPlugin;
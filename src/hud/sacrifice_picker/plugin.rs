@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+use super::components::SacrificeDraft;
+use super::systems::{
+    despawn_sacrifice_picker, handle_sacrifice_candidate_clicks, handle_sacrifice_confirm_clicks,
+    sync_sacrifice_picker, update_sacrifice_picker,
+};
+use crate::game_engine::sacrifice::PermanentSelections;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the dialog choosing permanents to sacrifice
+pub struct SacrificePickerPlugin;
+
+impl Plugin for SacrificePickerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SacrificeDraft>()
+            .add_systems(OnExit(GameMenuState::InGame), despawn_sacrifice_picker)
+            .add_systems(
+                Update,
+                (
+                    sync_sacrifice_picker.run_if(resource_changed::<PermanentSelections>),
+                    handle_sacrifice_candidate_clicks,
+                    handle_sacrifice_confirm_clicks,
+                    update_sacrifice_picker,
+                )
+                    .chain()
+                    .run_if(
+                        in_state(GameMenuState::InGame).and(resource_exists::<PermanentSelections>),
+                    ),
+            );
+    }
+}
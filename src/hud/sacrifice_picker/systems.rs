@@ -0,0 +1,232 @@
+use bevy::prelude::*;
+
+use super::components::{
+    SacrificeCandidateButton, SacrificeConfirmButton, SacrificeDraft, SacrificePickerRoot,
+    SacrificePickerTitle, picker_title,
+};
+use crate::camera::components::AppLayer;
+use crate::camera::z_order::UiLayer;
+use crate::cards::Card;
+use crate::game_engine::sacrifice::{ConfirmSelectionEvent, PermanentSelections};
+use crate::player::Player;
+
+const CANDIDATE_COLOR: Color = Color::srgba(0.22, 0.22, 0.26, 0.95);
+const SELECTED_COLOR: Color = Color::srgba(0.6, 0.2, 0.2, 0.95);
+const CONFIRM_COLOR: Color = Color::srgba(0.2, 0.55, 0.25, 0.95);
+const DISABLED_CONFIRM_COLOR: Color = Color::srgba(0.2, 0.25, 0.2, 0.7);
+
+/// Show the picker while a player chooses what to sacrifice
+pub fn sync_sacrifice_picker(
+    mut commands: Commands,
+    selections: Res<PermanentSelections>,
+    cards: Query<&Card>,
+    roots: Query<(Entity, &SacrificePickerRoot)>,
+    mut draft: ResMut<SacrificeDraft>,
+) {
+    let choosing = selections.current.as_ref();
+    let shown = roots.iter().next().map(|(_, root)| root);
+    if choosing.map(|current| (current.request.player, &current.candidates))
+        == shown.map(|root| (root.player, &root.candidates))
+    {
+        return;
+    }
+    for (root, _) in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    *draft = SacrificeDraft::default();
+    let Some(current) = choosing else {
+        return;
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            UiLayer::Dialog.global_z_index(),
+            SacrificePickerRoot {
+                player: current.request.player,
+                candidates: current.candidates.clone(),
+            },
+            AppLayer::GameUI.layer(),
+            Name::new("Sacrifice Picker"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(420.0),
+                        padding: UiRect::all(Val::Px(16.0)),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(8.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.08, 0.1, 0.97)),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        SacrificePickerTitle,
+                    ));
+
+                    for &permanent in &current.candidates {
+                        let name = cards
+                            .get(permanent)
+                            .map_or("Permanent", |card| card.name.name.as_str());
+                        parent
+                            .spawn((
+                                Button,
+                                Node {
+                                    width: Val::Percent(100.0),
+                                    height: Val::Px(32.0),
+                                    padding: UiRect::horizontal(Val::Px(8.0)),
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                BackgroundColor(CANDIDATE_COLOR),
+                                SacrificeCandidateButton(permanent),
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    Text::new(name),
+                                    TextFont {
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::WHITE),
+                                ));
+                            });
+                    }
+
+                    parent
+                        .spawn((
+                            Button,
+                            Node {
+                                width: Val::Px(140.0),
+                                height: Val::Px(34.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            BackgroundColor(DISABLED_CONFIRM_COLOR),
+                            SacrificeConfirmButton,
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Text::new("Sacrifice"),
+                                TextFont {
+                                    font_size: 16.0,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                            ));
+                        });
+                });
+        });
+}
+
+/// Select or deselect the clicked permanent
+pub fn handle_sacrifice_candidate_clicks(
+    buttons: Query<(&Interaction, &SacrificeCandidateButton), Changed<Interaction>>,
+    selections: Res<PermanentSelections>,
+    mut draft: ResMut<SacrificeDraft>,
+) {
+    let Some(current) = selections.current.as_ref() else {
+        return;
+    };
+    for (interaction, SacrificeCandidateButton(permanent)) in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            draft.toggle(*permanent, current.request.count);
+        }
+    }
+}
+
+/// Confirm the selected permanents once the right number are selected
+pub fn handle_sacrifice_confirm_clicks(
+    buttons: Query<&Interaction, (Changed<Interaction>, With<SacrificeConfirmButton>)>,
+    selections: Res<PermanentSelections>,
+    draft: Res<SacrificeDraft>,
+    mut confirmations: EventWriter<ConfirmSelectionEvent>,
+) {
+    let Some(current) = selections.current.as_ref() else {
+        return;
+    };
+    if !current.is_valid(&draft.selected) {
+        return;
+    }
+    for interaction in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            confirmations.write(ConfirmSelectionEvent {
+                player: current.request.player,
+                permanents: draft.selected.clone(),
+            });
+        }
+    }
+}
+
+/// Highlight the selected permanents and count down the time left to choose
+pub fn update_sacrifice_picker(
+    selections: Res<PermanentSelections>,
+    draft: Res<SacrificeDraft>,
+    players: Query<&Player>,
+    mut candidates: Query<
+        (&SacrificeCandidateButton, &mut BackgroundColor),
+        Without<SacrificeConfirmButton>,
+    >,
+    mut confirms: Query<&mut BackgroundColor, With<SacrificeConfirmButton>>,
+    mut titles: Query<&mut Text, With<SacrificePickerTitle>>,
+) {
+    let Some(current) = selections.current.as_ref() else {
+        return;
+    };
+
+    for (SacrificeCandidateButton(permanent), mut background) in candidates.iter_mut() {
+        let color = if draft.selected.contains(permanent) {
+            SELECTED_COLOR
+        } else {
+            CANDIDATE_COLOR
+        };
+        background.set_if_neq(BackgroundColor(color));
+    }
+    let confirm_color = if current.is_valid(&draft.selected) {
+        CONFIRM_COLOR
+    } else {
+        DISABLED_CONFIRM_COLOR
+    };
+    for mut background in confirms.iter_mut() {
+        background.set_if_neq(BackgroundColor(confirm_color));
+    }
+
+    let name = players
+        .get(current.request.player)
+        .map_or("Player", |player| player.name.as_str());
+    let title = picker_title(name, current, draft.selected.len());
+    for mut text in titles.iter_mut() {
+        if text.0 != title {
+            text.0 = title.clone();
+        }
+    }
+}
+
+/// Despawn the sacrifice picker
+pub fn despawn_sacrifice_picker(
+    mut commands: Commands,
+    roots: Query<Entity, With<SacrificePickerRoot>>,
+    mut draft: ResMut<SacrificeDraft>,
+) {
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    *draft = SacrificeDraft::default();
+}
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+
+use crate::cards::CardTypes;
+use crate::game_engine::sacrifice::{
+    PermanentFilter, PermanentSelection, SacrificePurpose, SelectPermanentsEvent,
+};
+use crate::hud::sacrifice_picker::{SacrificeDraft, picker_title};
+
+fn selection(count: usize) -> PermanentSelection {
+    let player = Entity::from_raw(1);
+    PermanentSelection {
+        request: SelectPermanentsEvent {
+            player,
+            source: player,
+            count,
+            filter: PermanentFilter::of_type(CardTypes::CREATURE),
+            purpose: SacrificePurpose::Cost,
+        },
+        candidates: (10..13).map(Entity::from_raw).collect(),
+        waited: 0.0,
+    }
+}
+
+#[test]
+fn test_draft_selects_up_to_the_count() {
+    let [a, b, c] = [10, 11, 12].map(Entity::from_raw);
+    let mut draft = SacrificeDraft::default();
+    draft.toggle(a, 2);
+    draft.toggle(b, 2);
+    draft.toggle(c, 2);
+    assert_eq!(draft.selected, vec![a, b]);
+    assert!(selection(2).is_valid(&draft.selected));
+
+    draft.toggle(a, 2);
+    draft.toggle(c, 2);
+    assert_eq!(draft.selected, vec![b, c]);
+}
+
+#[test]
+fn test_picker_title() {
+    assert_eq!(
+        picker_title("Alice", &selection(1), 0),
+        "Alice: sacrifice 1 to pay a cost (0/1 chosen, 20s)"
+    );
+}
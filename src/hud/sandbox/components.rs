@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+/// Root node of the sandbox panel
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SandboxPanelRoot;
+
+/// Button changing a player's life total
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SandboxLifeButton {
+    pub player: Entity,
+    pub delta: i32,
+}
+
+/// Text showing a player's life total
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SandboxLifeText(pub Entity);
@@ -0,0 +1,12 @@
+//! Controls for sandbox games
+//!
+//! Unless the [`RulesEnforcementLevel`](crate::game_engine::enforcement::RulesEnforcementLevel)
+//! is `Full`, dropped cards go to the nearest zone, the card under the cursor is tapped with
+//! `T` and given +1/+1 counters with `=` and `-`, and a panel sets life totals.
+
+mod components;
+mod plugin;
+mod systems;
+
+pub use components::{SandboxLifeButton, SandboxLifeText, SandboxPanelRoot};
+pub use plugin::SandboxHudPlugin;
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+use super::systems::{
+    despawn_sandbox_panel, handle_sandbox_card_drops, handle_sandbox_hotkeys,
+    handle_sandbox_life_buttons, sync_sandbox_panel, update_sandbox_life_texts,
+};
+use crate::game_engine::enforcement::RulesEnforcementLevel;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the sandbox controls
+pub struct SandboxHudPlugin;
+
+impl Plugin for SandboxHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMenuState::InGame), despawn_sandbox_panel)
+            .add_systems(
+                Update,
+                (
+                    sync_sandbox_panel.run_if(resource_changed::<RulesEnforcementLevel>),
+                    handle_sandbox_card_drops,
+                    handle_sandbox_hotkeys,
+                    handle_sandbox_life_buttons,
+                    update_sandbox_life_texts,
+                )
+                    .chain()
+                    .run_if(
+                        in_state(GameMenuState::InGame)
+                            .and(resource_exists::<RulesEnforcementLevel>),
+                    ),
+            );
+    }
+}
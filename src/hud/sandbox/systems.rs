@@ -0,0 +1,263 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::components::{SandboxLifeButton, SandboxLifeText, SandboxPanelRoot};
+use crate::camera::components::{AppLayer, GameCamera};
+use crate::cards::Card;
+use crate::cards::systems::CardDroppedEvent;
+use crate::game_engine::enforcement::RulesEnforcementLevel;
+use crate::game_engine::sandbox::SandboxAction;
+use crate::game_engine::zones::Zone;
+use crate::hud::card_preview::card_at;
+use crate::menu::input_blocker::InteractionBlockState;
+use crate::player::Player;
+use crate::player::playmat::PlaymatZone;
+use crate::player::resources::PlayerConfig;
+use crate::tutorial::systems::nearest_zone;
+
+const PANEL_COLOR: Color = Color::srgba(0.05, 0.05, 0.08, 0.9);
+const BUTTON_COLOR: Color = Color::srgba(0.22, 0.22, 0.26, 0.95);
+
+/// Show the sandbox panel while manual changes are allowed
+pub fn sync_sandbox_panel(
+    mut commands: Commands,
+    enforcement: Res<RulesEnforcementLevel>,
+    players: Query<(Entity, &Player)>,
+    roots: Query<Entity, With<SandboxPanelRoot>>,
+) {
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    if !enforcement.allows_manual_changes() {
+        return;
+    }
+
+    let mut players: Vec<_> = players.iter().collect();
+    players.sort_by_key(|(_, player)| player.player_index);
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                left: Val::Px(16.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(PANEL_COLOR),
+            SandboxPanelRoot,
+            AppLayer::GameUI.layer(),
+            Name::new("Sandbox Panel"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Sandbox"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            for (entity, player) in players {
+                parent
+                    .spawn((
+                        Node {
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(6.0),
+                            ..default()
+                        },
+                        Name::new(format!("{} Life", player.name)),
+                    ))
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(player.name.clone()),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                            Node {
+                                width: Val::Px(90.0),
+                                ..default()
+                            },
+                        ));
+                        spawn_life_button(row, entity, -1);
+                        row.spawn((
+                            Text::new(player.life.to_string()),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                            Node {
+                                width: Val::Px(36.0),
+                                justify_content: JustifyContent::Center,
+                                ..default()
+                            },
+                            SandboxLifeText(entity),
+                        ));
+                        spawn_life_button(row, entity, 1);
+                    });
+            }
+        });
+}
+
+fn spawn_life_button(parent: &mut ChildSpawnerCommands, player: Entity, delta: i32) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(28.0),
+                height: Val::Px(28.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(BUTTON_COLOR),
+            SandboxLifeButton { player, delta },
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(if delta < 0 { "-" } else { "+" }),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// Despawn the sandbox panel
+pub fn despawn_sandbox_panel(mut commands: Commands, roots: Query<Entity, With<SandboxPanelRoot>>) {
+    for entity in roots.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Move dropped cards to the zone they were dropped on
+pub fn handle_sandbox_card_drops(
+    enforcement: Res<RulesEnforcementLevel>,
+    mut dropped_events: EventReader<CardDroppedEvent>,
+    zones: Query<(&PlaymatZone, &GlobalTransform)>,
+    mut actions: EventWriter<SandboxAction>,
+) {
+    if !enforcement.allows_manual_changes() {
+        dropped_events.clear();
+        return;
+    }
+    let zones: Vec<(Entity, Zone, Vec2)> = zones
+        .iter()
+        .map(|(zone, transform)| {
+            (
+                zone.player_id,
+                zone.zone_type,
+                transform.translation().truncate(),
+            )
+        })
+        .collect();
+
+    for event in dropped_events.read() {
+        if let Some((player, zone)) = nearest_zone(event.position, &zones) {
+            actions.write(SandboxAction::MoveCard {
+                card: event.card,
+                zone,
+                player,
+            });
+        }
+    }
+}
+
+/// Tap the card under the cursor with `T` and add or remove +1/+1 counters with `=` and `-`
+#[allow(clippy::too_many_arguments)]
+pub fn handle_sandbox_hotkeys(
+    enforcement: Res<RulesEnforcementLevel>,
+    keys: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_q: Query<(&Camera, &GlobalTransform), With<GameCamera>>,
+    cards: Query<(Entity, &GlobalTransform), With<Card>>,
+    player_config: Res<PlayerConfig>,
+    interaction_block: Res<InteractionBlockState>,
+    mut actions: EventWriter<SandboxAction>,
+) {
+    if !enforcement.allows_manual_changes() || interaction_block.should_block {
+        return;
+    }
+    let action: fn(Entity) -> SandboxAction = if keys.just_pressed(KeyCode::KeyT) {
+        |card| SandboxAction::ToggleTapped { card }
+    } else if keys.just_pressed(KeyCode::Equal) {
+        |card| SandboxAction::AdjustCounters {
+            card,
+            kind: "+1/+1".to_string(),
+            delta: 1,
+        }
+    } else if keys.just_pressed(KeyCode::Minus) {
+        |card| SandboxAction::AdjustCounters {
+            card,
+            kind: "+1/+1".to_string(),
+            delta: -1,
+        }
+    } else {
+        return;
+    };
+
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = camera_q.single() else {
+        return;
+    };
+    let Some(world_pos) = window.cursor_position().and_then(|cursor_pos| {
+        camera
+            .viewport_to_world_2d(camera_transform, cursor_pos)
+            .ok()
+    }) else {
+        return;
+    };
+
+    let hovered = card_at(
+        world_pos,
+        player_config.card_size,
+        cards
+            .iter()
+            .map(|(entity, transform)| (entity, transform.translation())),
+    );
+    if let Some(card) = hovered {
+        actions.write(action(card));
+    }
+}
+
+/// Change life totals when the panel's buttons are clicked
+pub fn handle_sandbox_life_buttons(
+    buttons: Query<(&Interaction, &SandboxLifeButton), Changed<Interaction>>,
+    players: Query<&Player>,
+    mut actions: EventWriter<SandboxAction>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok(player) = players.get(button.player) {
+            actions.write(SandboxAction::SetLife {
+                player: button.player,
+                life: player.life + button.delta,
+            });
+        }
+    }
+}
+
+/// Keep the panel's life totals up to date
+pub fn update_sandbox_life_texts(
+    players: Query<&Player, Changed<Player>>,
+    mut texts: Query<(&SandboxLifeText, &mut Text)>,
+) {
+    for (SandboxLifeText(player), mut text) in texts.iter_mut() {
+        if let Ok(player) = players.get(*player) {
+            text.0 = player.life.to_string();
+        }
+    }
+}
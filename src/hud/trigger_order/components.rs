@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+
+/// Root node of the trigger ordering prompt
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TriggerOrderRoot;
+
+/// Text naming the player choosing and the time left to choose
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TriggerOrderTitle;
+
+/// A row of the prompt, by its position from the top
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerOrderRow(pub usize);
+
+/// Text of the row at a position
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerOrderRowText(pub usize);
+
+/// Button confirming the order
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TriggerOrderConfirmButton;
+
+/// The order being chosen in the prompt
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TriggerOrderDraft {
+    /// The player choosing
+    pub player: Option<Entity>,
+    /// Indices of the player's triggers, first to resolve first
+    pub order: Vec<usize>,
+    /// Position of the row being dragged
+    pub dragging: Option<usize>,
+}
+
+impl TriggerOrderDraft {
+    /// A draft keeping the order the triggers triggered in
+    pub fn new(player: Entity, count: usize) -> Self {
+        Self {
+            player: Some(player),
+            order: (0..count).collect(),
+            dragging: None,
+        }
+    }
+
+    /// Move the trigger at position `from` to `to`, shifting the ones between
+    pub fn move_row(&mut self, from: usize, to: usize) {
+        if from == to || from >= self.order.len() || to >= self.order.len() {
+            return;
+        }
+        let trigger = self.order.remove(from);
+        self.order.insert(to, trigger);
+    }
+}
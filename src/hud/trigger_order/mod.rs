@@ -0,0 +1,20 @@
+//! Prompt for ordering simultaneous triggers
+//!
+//! When a player has several triggered abilities to put on the stack at once,
+//! they drag the rows into the order they want them to resolve, top first, and
+//! confirm, which sends an
+//! [`OrderTriggersEvent`](crate::game_engine::triggers::OrderTriggersEvent). If
+//! they don't, the engine orders the triggers itself after a timeout.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{
+    TriggerOrderConfirmButton, TriggerOrderDraft, TriggerOrderRoot, TriggerOrderRow,
+    TriggerOrderRowText, TriggerOrderTitle,
+};
+pub use plugin::TriggerOrderPlugin;
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+use super::components::TriggerOrderDraft;
+use super::systems::{
+    despawn_trigger_order_prompt, drag_trigger_rows, handle_trigger_order_confirm,
+    sync_trigger_order_prompt, update_trigger_order_display,
+};
+use crate::game_engine::triggers::TriggerQueue;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the simultaneous trigger ordering prompt
+pub struct TriggerOrderPlugin;
+
+impl Plugin for TriggerOrderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TriggerOrderDraft>()
+            .add_systems(OnExit(GameMenuState::InGame), despawn_trigger_order_prompt)
+            .add_systems(
+                Update,
+                (
+                    sync_trigger_order_prompt.run_if(resource_changed::<TriggerQueue>),
+                    drag_trigger_rows,
+                    handle_trigger_order_confirm,
+                    update_trigger_order_display,
+                )
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame)),
+            );
+    }
+}
@@ -0,0 +1,233 @@
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+use super::components::{
+    TriggerOrderConfirmButton, TriggerOrderDraft, TriggerOrderRoot, TriggerOrderRow,
+    TriggerOrderRowText, TriggerOrderTitle,
+};
+use crate::camera::components::AppLayer;
+use crate::game_engine::triggers::{OrderTriggersEvent, TriggerQueue};
+use crate::player::Player;
+
+const ROW_COLOR: Color = Color::srgba(0.22, 0.22, 0.26, 0.95);
+const DRAGGED_ROW_COLOR: Color = Color::srgba(0.3, 0.45, 0.7, 0.95);
+const CONFIRM_COLOR: Color = Color::srgba(0.2, 0.55, 0.25, 0.95);
+
+/// Show the prompt while a player orders their triggers and remove it afterwards
+pub fn sync_trigger_order_prompt(
+    mut commands: Commands,
+    queue: Res<TriggerQueue>,
+    roots: Query<Entity, With<TriggerOrderRoot>>,
+    mut draft: ResMut<TriggerOrderDraft>,
+) {
+    let choosing = queue
+        .choice
+        .as_ref()
+        .map(|choice| (choice.player, choice.triggers.len()));
+    let shown = draft.player.map(|player| (player, draft.order.len()));
+    if choosing == shown {
+        return;
+    }
+
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    *draft = TriggerOrderDraft::default();
+
+    let Some((player, count)) = choosing else {
+        return;
+    };
+    *draft = TriggerOrderDraft::new(player, count);
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(80.0),
+                right: Val::Px(20.0),
+                width: Val::Px(360.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.9)),
+            TriggerOrderRoot,
+            AppLayer::GameUI.layer(),
+            Name::new("Trigger Order Prompt"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TriggerOrderTitle,
+            ));
+
+            for position in 0..count {
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            height: Val::Px(32.0),
+                            padding: UiRect::horizontal(Val::Px(8.0)),
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(ROW_COLOR),
+                        RelativeCursorPosition::default(),
+                        TriggerOrderRow(position),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            Text::new(""),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                            TriggerOrderRowText(position),
+                        ));
+                    });
+            }
+
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(140.0),
+                        height: Val::Px(34.0),
+                        align_self: AlignSelf::Center,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(CONFIRM_COLOR),
+                    TriggerOrderConfirmButton,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("Confirm"),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+        });
+}
+
+/// Despawn the trigger ordering prompt
+pub fn despawn_trigger_order_prompt(
+    mut commands: Commands,
+    roots: Query<Entity, With<TriggerOrderRoot>>,
+    mut draft: ResMut<TriggerOrderDraft>,
+) {
+    for entity in roots.iter() {
+        commands.entity(entity).despawn();
+    }
+    *draft = TriggerOrderDraft::default();
+}
+
+/// Drag a pressed row to the row under the cursor
+///
+/// A pressed button stays pressed while the mouse is held, so the row under the
+/// cursor is found by position rather than by its interaction.
+pub fn drag_trigger_rows(
+    rows: Query<(&Interaction, &RelativeCursorPosition, &TriggerOrderRow)>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut draft: ResMut<TriggerOrderDraft>,
+) {
+    if !mouse.pressed(MouseButton::Left) {
+        if draft.dragging.is_some() {
+            draft.dragging = None;
+        }
+        return;
+    }
+
+    let Some(from) = draft.dragging else {
+        draft.dragging = rows
+            .iter()
+            .find(|(interaction, _, _)| **interaction == Interaction::Pressed)
+            .map(|(_, _, row)| row.0);
+        return;
+    };
+    let hovered = rows
+        .iter()
+        .find(|(_, cursor, _)| cursor.mouse_over())
+        .map(|(_, _, row)| row.0);
+    if let Some(to) = hovered.filter(|to| *to != from) {
+        draft.move_row(from, to);
+        draft.dragging = Some(to);
+    }
+}
+
+/// Send the chosen order
+pub fn handle_trigger_order_confirm(
+    buttons: Query<&Interaction, (Changed<Interaction>, With<TriggerOrderConfirmButton>)>,
+    draft: Res<TriggerOrderDraft>,
+    mut orders: EventWriter<OrderTriggersEvent>,
+) {
+    let Some(player) = draft.player else {
+        return;
+    };
+
+    for interaction in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            orders.write(OrderTriggersEvent {
+                player,
+                order: draft.order.clone(),
+            });
+        }
+    }
+}
+
+/// Show the triggers in the chosen order and the time left to choose
+pub fn update_trigger_order_display(
+    queue: Res<TriggerQueue>,
+    draft: Res<TriggerOrderDraft>,
+    players: Query<&Player>,
+    mut rows: Query<(&TriggerOrderRow, &mut BackgroundColor)>,
+    mut row_texts: Query<(&TriggerOrderRowText, &mut Text), Without<TriggerOrderTitle>>,
+    mut titles: Query<&mut Text, With<TriggerOrderTitle>>,
+) {
+    let Some(choice) = queue.choice.as_ref() else {
+        return;
+    };
+
+    if draft.is_changed() {
+        for (row, mut background) in rows.iter_mut() {
+            background.0 = if draft.dragging == Some(row.0) {
+                DRAGGED_ROW_COLOR
+            } else {
+                ROW_COLOR
+            };
+        }
+        for (TriggerOrderRowText(position), mut text) in row_texts.iter_mut() {
+            let description = draft
+                .order
+                .get(*position)
+                .and_then(|&index| choice.triggers.get(index))
+                .map_or("", |trigger| trigger.description.as_str());
+            text.0 = format!("{}. {}", position + 1, description);
+        }
+    }
+
+    let name = players
+        .get(choice.player)
+        .map_or("Player", |player| player.name.as_str());
+    let title = format!(
+        "{}: drag your triggers into the order they resolve, top first ({:.0}s)",
+        name,
+        choice.remaining_secs().ceil()
+    );
+    for mut text in titles.iter_mut() {
+        if text.0 != title {
+            text.0 = title.clone();
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+use crate::hud::trigger_order::TriggerOrderDraft;
+
+#[test]
+fn test_dragging_a_row_shifts_the_rows_between() {
+    let mut world = World::new();
+    let player = world.spawn_empty().id();
+    let mut draft = TriggerOrderDraft::new(player, 4);
+
+    draft.move_row(0, 2);
+    assert_eq!(draft.order, vec![1, 2, 0, 3]);
+    draft.move_row(3, 0);
+    assert_eq!(draft.order, vec![3, 1, 2, 0]);
+
+    // Moves outside the list are ignored
+    draft.move_row(1, 4);
+    assert_eq!(draft.order, vec![3, 1, 2, 0]);
+}
@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+use crate::game_engine::choices::ChoiceId;
+use crate::game_engine::turn_order::TurnOrderRoll;
+
+/// Seconds the dice tumble before showing what was rolled
+pub const ROLL_ANIMATION_SECS: f32 = 1.5;
+
+/// Seconds the overlay stays up once the first player is chosen
+pub const DECIDED_POPUP_SECS: f32 = 3.0;
+
+/// Root node of the turn order overlay
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default)]
+pub struct TurnOrderRollRoot {
+    /// Seconds it has been shown
+    pub shown_for: f32,
+    /// Seconds it has been shown since the first player was chosen
+    pub decided_for: Option<f32>,
+}
+
+/// A player's line of rolls
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct RollText {
+    pub name: String,
+    /// What they rolled, rerolls included
+    pub rolls: Vec<u32>,
+}
+
+/// The line naming the winner, then the first player
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RollStatusText;
+
+/// The winner's buttons, hidden while the dice tumble
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FirstPlayerOptions;
+
+/// Button choosing who goes first
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FirstPlayerButton {
+    /// The winner of the roll, who chooses
+    pub player: Entity,
+    pub choice: ChoiceId,
+    pub option: usize,
+}
+
+/// The line showing a player's rolls
+pub fn roll_line(name: &str, rolls: &[u32]) -> String {
+    let rolls: Vec<String> = rolls.iter().map(u32::to_string).collect();
+    format!("{} rolled {}", name, rolls.join(", then "))
+}
+
+/// The line naming who won the roll, or who goes first once chosen
+pub fn status_line(roll: &TurnOrderRoll, name_of: impl Fn(Entity) -> String) -> String {
+    match (roll.first_player, roll.winner) {
+        (Some(first), _) => format!("{} goes first", name_of(first)),
+        (None, Some(winner)) => format!(
+            "{} wins the roll and chooses who goes first",
+            name_of(winner)
+        ),
+        (None, None) => "Rolling for who goes first".to_string(),
+    }
+}
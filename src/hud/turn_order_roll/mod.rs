@@ -0,0 +1,21 @@
+//! Overlay showing the roll for who goes first
+//!
+//! As a new game starts, each player's die tumbles for [`ROLL_ANIMATION_SECS`]
+//! before landing on what they rolled, rerolls included. The winner then gets a
+//! button per player, each answering their choice of who goes first with an
+//! [`AnswerChoiceEvent`](crate::game_engine::choices::AnswerChoiceEvent). Once
+//! chosen, the overlay names the first player and fades after
+//! [`DECIDED_POPUP_SECS`].
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{
+    DECIDED_POPUP_SECS, FirstPlayerButton, FirstPlayerOptions, ROLL_ANIMATION_SECS, RollStatusText,
+    RollText, TurnOrderRollRoot, roll_line, status_line,
+};
+pub use plugin::TurnOrderRollPlugin;
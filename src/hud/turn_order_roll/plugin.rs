@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+use super::systems::{
+    animate_turn_order_roll, despawn_turn_order_roll, handle_first_player_clicks,
+    sync_turn_order_roll,
+};
+use crate::game_engine::turn_order::TurnOrderRoll;
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the overlay showing the roll for who goes first
+pub struct TurnOrderRollPlugin;
+
+impl Plugin for TurnOrderRollPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMenuState::InGame), despawn_turn_order_roll)
+            .add_systems(
+                Update,
+                (
+                    sync_turn_order_roll.run_if(resource_changed::<TurnOrderRoll>),
+                    handle_first_player_clicks,
+                    animate_turn_order_roll,
+                )
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame).and(resource_exists::<TurnOrderRoll>)),
+            );
+    }
+}
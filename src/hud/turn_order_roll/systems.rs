@@ -0,0 +1,246 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use super::components::{
+    DECIDED_POPUP_SECS, FirstPlayerButton, FirstPlayerOptions, ROLL_ANIMATION_SECS, RollStatusText,
+    RollText, TurnOrderRollRoot, roll_line, status_line,
+};
+use crate::camera::components::AppLayer;
+use crate::camera::z_order::UiLayer;
+use crate::game_engine::choices::{AnswerChoiceEvent, ChoiceAnswer};
+use crate::game_engine::turn_order::{TURN_ORDER_DIE_SIDES, TurnOrderRoll};
+use crate::player::Player;
+
+const OPTION_COLOR: Color = Color::srgba(0.2, 0.35, 0.55, 0.95);
+
+/// Show a new roll, and who goes first once the winner has chosen
+pub fn sync_turn_order_roll(
+    mut commands: Commands,
+    roll: Res<TurnOrderRoll>,
+    mut roots: Query<(Entity, &mut TurnOrderRollRoot)>,
+    mut status: Query<&mut Text, With<RollStatusText>>,
+    options: Query<Entity, With<FirstPlayerOptions>>,
+    players: Query<&Player>,
+) {
+    let name_of = |entity: Entity| {
+        players
+            .get(entity)
+            .map(|player| player.name.clone())
+            .unwrap_or_else(|_| format!("{:?}", entity))
+    };
+
+    if !roll.is_added() {
+        // The same roll, now decided
+        if roll.first_player.is_some() {
+            for (_, mut root) in roots.iter_mut() {
+                root.decided_for.get_or_insert(0.0);
+            }
+            for mut text in status.iter_mut() {
+                text.0 = status_line(&roll, name_of);
+            }
+            for entity in options.iter() {
+                commands.entity(entity).despawn();
+            }
+        }
+        return;
+    }
+
+    for (root, _) in roots.iter() {
+        commands.entity(root).despawn();
+    }
+    let rows: Vec<RollText> = roll
+        .seats
+        .iter()
+        .map(|&player| RollText {
+            name: name_of(player),
+            rolls: roll.rolls_of(player),
+        })
+        .collect();
+    let buttons: Vec<(FirstPlayerButton, String)> = roll
+        .winner
+        .zip(roll.choice)
+        .map(|(winner, choice)| {
+            roll.options()
+                .into_iter()
+                .enumerate()
+                .map(|(option, player)| {
+                    let button = FirstPlayerButton {
+                        player: winner,
+                        choice,
+                        option,
+                    };
+                    (button, name_of(player))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let status_text = status_line(&roll, name_of);
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(25.0),
+                left: Val::Percent(50.0),
+                width: Val::Px(360.0),
+                margin: UiRect::left(Val::Px(-180.0)),
+                padding: UiRect::all(Val::Px(16.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.06, 0.06, 0.1, 0.92)),
+            UiLayer::Dialog.global_z_index(),
+            TurnOrderRollRoot {
+                shown_for: 0.0,
+                decided_for: roll.first_player.map(|_| 0.0),
+            },
+            AppLayer::GameUI.layer(),
+            Name::new("Turn Order Roll"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!(
+                    "Rolling a d{} for who goes first",
+                    TURN_ORDER_DIE_SIDES
+                )),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            for row in rows {
+                parent.spawn((
+                    Text::new(row.name.clone()),
+                    TextFont {
+                        font_size: 15.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    row,
+                ));
+            }
+            parent.spawn((
+                Text::new(status_text),
+                TextFont {
+                    font_size: 15.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.85, 0.4)),
+                Visibility::Hidden,
+                RollStatusText,
+            ));
+            if buttons.is_empty() {
+                return;
+            }
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(6.0),
+                        ..default()
+                    },
+                    Visibility::Hidden,
+                    FirstPlayerOptions,
+                ))
+                .with_children(|parent| {
+                    for (button, name) in buttons {
+                        parent
+                            .spawn((
+                                Button,
+                                Node {
+                                    width: Val::Percent(100.0),
+                                    height: Val::Px(30.0),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                BackgroundColor(OPTION_COLOR),
+                                button,
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    Text::new(name),
+                                    TextFont {
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::WHITE),
+                                ));
+                            });
+                    }
+                });
+        });
+}
+
+/// Send the first player clicked
+pub fn handle_first_player_clicks(
+    buttons: Query<(&Interaction, &FirstPlayerButton), Changed<Interaction>>,
+    mut answers: EventWriter<AnswerChoiceEvent>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            answers.write(AnswerChoiceEvent {
+                player: button.player,
+                id: button.choice,
+                answer: ChoiceAnswer::Option(button.option),
+            });
+        }
+    }
+}
+
+/// Tumble the dice, then show the rolls and the winner's options, and remove
+/// the overlay once the first player has been shown long enough
+#[allow(clippy::type_complexity)]
+pub fn animate_turn_order_roll(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut roots: Query<(Entity, &mut TurnOrderRollRoot)>,
+    mut rows: Query<(&RollText, &mut Text)>,
+    mut revealed: Query<&mut Visibility, Or<(With<RollStatusText>, With<FirstPlayerOptions>)>>,
+) {
+    let Ok((entity, mut root)) = roots.single_mut() else {
+        return;
+    };
+    let rolling = root.shown_for < ROLL_ANIMATION_SECS;
+    root.shown_for += time.delta_secs();
+
+    if root.shown_for < ROLL_ANIMATION_SECS {
+        // The numbers shown while tumbling are only for show
+        let mut rng = rand::rng();
+        for (row, mut text) in rows.iter_mut() {
+            text.0 = format!(
+                "{} rolls {}",
+                row.name,
+                rng.random_range(1..=TURN_ORDER_DIE_SIDES)
+            );
+        }
+        return;
+    }
+    if rolling {
+        for (row, mut text) in rows.iter_mut() {
+            text.0 = roll_line(&row.name, &row.rolls);
+        }
+        for mut visibility in revealed.iter_mut() {
+            *visibility = Visibility::Inherited;
+        }
+    }
+
+    if let Some(decided_for) = root.decided_for.as_mut() {
+        *decided_for += time.delta_secs();
+        if *decided_for >= DECIDED_POPUP_SECS {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Despawn the turn order overlay
+pub fn despawn_turn_order_roll(
+    mut commands: Commands,
+    roots: Query<Entity, With<TurnOrderRollRoot>>,
+) {
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+}
@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+
+use crate::game_engine::choices::ChoiceId;
+use crate::game_engine::turn_order::{DieRoll, TurnOrderRoll};
+use crate::hud::turn_order_roll::systems::sync_turn_order_roll;
+use crate::hud::turn_order_roll::{
+    FirstPlayerButton, RollText, TurnOrderRollRoot, roll_line, status_line,
+};
+
+fn tied_roll(alice: Entity, bob: Entity) -> TurnOrderRoll {
+    TurnOrderRoll {
+        seats: vec![alice, bob],
+        rounds: vec![
+            vec![
+                DieRoll {
+                    player: alice,
+                    result: 12,
+                },
+                DieRoll {
+                    player: bob,
+                    result: 12,
+                },
+            ],
+            vec![
+                DieRoll {
+                    player: alice,
+                    result: 3,
+                },
+                DieRoll {
+                    player: bob,
+                    result: 18,
+                },
+            ],
+        ],
+        winner: Some(bob),
+        choice: Some(ChoiceId(0)),
+        first_player: None,
+    }
+}
+
+#[test]
+fn test_roll_and_status_lines() {
+    let [alice, bob] = [1, 2].map(Entity::from_raw);
+    let mut roll = tied_roll(alice, bob);
+    let name_of = |entity: Entity| match entity.index() {
+        1 => "Alice".to_string(),
+        _ => "Bob".to_string(),
+    };
+
+    assert_eq!(
+        roll_line("Alice", &roll.rolls_of(alice)),
+        "Alice rolled 12, then 3"
+    );
+    assert_eq!(
+        status_line(&roll, name_of),
+        "Bob wins the roll and chooses who goes first"
+    );
+    roll.first_player = Some(alice);
+    assert_eq!(status_line(&roll, name_of), "Alice goes first");
+}
+
+#[test]
+fn test_overlay_offers_the_winner_every_player() {
+    let mut app = App::new();
+    app.add_systems(
+        Update,
+        sync_turn_order_roll.run_if(resource_changed::<TurnOrderRoll>),
+    );
+    let [alice, bob] = [1, 2].map(Entity::from_raw);
+    app.insert_resource(tied_roll(alice, bob));
+    app.update();
+
+    let mut buttons: Vec<FirstPlayerButton> = app
+        .world_mut()
+        .query::<&FirstPlayerButton>()
+        .iter(app.world())
+        .copied()
+        .collect();
+    buttons.sort_by_key(|button| button.option);
+    assert_eq!(buttons.len(), 2);
+    assert!(buttons.iter().all(|button| button.player == bob));
+    let rows = app
+        .world_mut()
+        .query::<&RollText>()
+        .iter(app.world())
+        .count();
+    assert_eq!(rows, 2);
+
+    // Once chosen, the options go and the overlay counts down to fading
+    app.world_mut().resource_mut::<TurnOrderRoll>().first_player = Some(bob);
+    app.update();
+    let buttons = app
+        .world_mut()
+        .query::<&FirstPlayerButton>()
+        .iter(app.world())
+        .count();
+    assert_eq!(buttons, 0);
+    let root = app
+        .world_mut()
+        .query::<&TurnOrderRollRoot>()
+        .single(app.world())
+        .unwrap();
+    assert_eq!(root.decided_for, Some(0.0));
+}
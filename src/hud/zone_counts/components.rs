@@ -0,0 +1,52 @@
+use std::fmt;
+
+use bevy::prelude::*;
+
+use crate::game_engine::cleanup::MaximumHandSize;
+use crate::game_engine::zones::ZoneManager;
+
+/// Badge showing an opponent's zone sizes
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ZoneCountBadge {
+    /// The player whose zones are counted
+    pub player: Entity,
+}
+
+/// How many cards are in a player's hand, library and graveyard
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ZoneCounts {
+    pub hand: usize,
+    pub library: usize,
+    pub graveyard: usize,
+    /// Shown next to the hand count when an effect changed it
+    pub maximum_hand_size: MaximumHandSize,
+}
+
+impl ZoneCounts {
+    /// Count `player`'s zones
+    pub fn of(zones: &ZoneManager, player: Entity, maximum_hand_size: MaximumHandSize) -> Self {
+        Self {
+            hand: zones.hand(player).len(),
+            library: zones.library(player).len(),
+            graveyard: zones.graveyard(player).len(),
+            maximum_hand_size,
+        }
+    }
+}
+
+impl fmt::Display for ZoneCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Hand {}", self.hand)?;
+        if self.maximum_hand_size != MaximumHandSize::default() {
+            match self.maximum_hand_size.0 {
+                Some(maximum) => write!(f, " (max {})", maximum)?,
+                None => write!(f, " (no max)")?,
+            }
+        }
+        write!(
+            f,
+            "   Library {}   Graveyard {}",
+            self.library, self.graveyard
+        )
+    }
+}
@@ -0,0 +1,18 @@
+//! Hand, library and graveyard counts for opponents
+//!
+//! Each opponent's playmat carries a small badge with the size of their hidden
+//! and public zones. Badges are refreshed from
+//! [`ZoneMutationEvent`](crate::game_engine::zones::ZoneMutationEvent)s, so they
+//! only change when the [`ZoneManager`](crate::game_engine::zones::ZoneManager) does.
+//! A player whose maximum hand size an effect changed has it shown next to
+//! their hand count.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{ZoneCountBadge, ZoneCounts};
+pub use plugin::ZoneCountsPlugin;
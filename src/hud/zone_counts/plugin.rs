@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+use super::systems::{
+    despawn_zone_count_badges, spawn_zone_count_badges, update_zone_count_badges,
+};
+use crate::game_engine::zones::{ZoneManager, publish_zone_mutations};
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the opponent zone count badges
+pub struct ZoneCountsPlugin;
+
+impl Plugin for ZoneCountsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMenuState::InGame), despawn_zone_count_badges)
+            .add_systems(
+                Update,
+                (spawn_zone_count_badges, update_zone_count_badges)
+                    .chain()
+                    .after(publish_zone_mutations)
+                    .run_if(in_state(GameMenuState::InGame).and(resource_exists::<ZoneManager>)),
+            );
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use super::components::{ZoneCountBadge, ZoneCounts};
+use crate::camera::components::AppLayer;
+use crate::game_engine::cleanup::MaximumHandSize;
+use crate::game_engine::zones::{ZoneManager, ZoneMutationEvent};
+use crate::player::playmat::PlayerPlaymat;
+
+const BADGE_COLOR: Color = Color::srgba(0.9, 0.9, 0.9, 0.85);
+
+/// Where the badge sits on a playmat, in the playmat's local space, just past
+/// the monarch crown
+const BADGE_OFFSET: Vec3 = Vec3::new(0.0, 250.0, 5.0);
+
+/// Put a badge on every opponent's playmat that doesn't have one yet
+pub fn spawn_zone_count_badges(
+    mut commands: Commands,
+    zones: Res<ZoneManager>,
+    playmats: Query<(Entity, &PlayerPlaymat, &Transform)>,
+    badges: Query<&ZoneCountBadge>,
+    hand_sizes: Query<&MaximumHandSize>,
+) {
+    for (entity, playmat, transform) in playmats.iter() {
+        // The local player is always index 0 and sees their own zones
+        if playmat.player_index == 0 || badges.iter().any(|badge| badge.player == playmat.player_id)
+        {
+            continue;
+        }
+
+        // Undo the playmat's rotation so the text reads upright
+        let maximum_hand_size = hand_sizes
+            .get(playmat.player_id)
+            .copied()
+            .unwrap_or_default();
+        let counts = ZoneCounts::of(&zones, playmat.player_id, maximum_hand_size);
+        let badge = commands
+            .spawn((
+                Text2d::new(counts.to_string()),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(BADGE_COLOR),
+                Transform::from_translation(BADGE_OFFSET)
+                    .with_rotation(transform.rotation.inverse()),
+                ZoneCountBadge {
+                    player: playmat.player_id,
+                },
+                AppLayer::game_layers(),
+                Name::new("Zone Count Badge"),
+            ))
+            .id();
+        commands.entity(entity).add_child(badge);
+    }
+}
+
+/// Refresh the badges of players whose zones or maximum hand size changed
+pub fn update_zone_count_badges(
+    mut mutation_events: EventReader<ZoneMutationEvent>,
+    zones: Res<ZoneManager>,
+    mut badges: Query<(&ZoneCountBadge, &mut Text2d)>,
+    hand_sizes: Query<&MaximumHandSize>,
+    changed_hand_sizes: Query<Entity, Changed<MaximumHandSize>>,
+) {
+    let mut changed: HashSet<Entity> = changed_hand_sizes.iter().collect();
+    let mut cleared = false;
+    for event in mutation_events.read() {
+        match event {
+            ZoneMutationEvent::Inserted { location, .. }
+            | ZoneMutationEvent::Removed { location, .. } => {
+                changed.insert(location.owner);
+            }
+            // Shuffling doesn't change how many cards are in the library
+            ZoneMutationEvent::Shuffled { .. } => {}
+            ZoneMutationEvent::Cleared => cleared = true,
+        }
+    }
+
+    for (badge, mut text) in badges.iter_mut() {
+        if cleared || changed.contains(&badge.player) {
+            let maximum_hand_size = hand_sizes.get(badge.player).copied().unwrap_or_default();
+            let label = ZoneCounts::of(&zones, badge.player, maximum_hand_size).to_string();
+            if text.0 != label {
+                text.0 = label;
+            }
+        }
+    }
+}
+
+/// Remove the badges when leaving the game
+pub fn despawn_zone_count_badges(
+    mut commands: Commands,
+    badges: Query<Entity, With<ZoneCountBadge>>,
+) {
+    for badge in badges.iter() {
+        commands.entity(badge).despawn();
+    }
+}
@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+
+use crate::game_engine::cleanup::MaximumHandSize;
+use crate::game_engine::zones::{ZoneManager, ZoneMutationEvent, publish_zone_mutations};
+use crate::hud::zone_counts::systems::{spawn_zone_count_badges, update_zone_count_badges};
+use crate::hud::zone_counts::{ZoneCountBadge, ZoneCounts};
+use crate::player::playmat::PlayerPlaymat;
+
+#[test]
+fn test_zone_counts_label() {
+    let counts = ZoneCounts {
+        hand: 7,
+        library: 92,
+        graveyard: 0,
+        ..default()
+    };
+    assert_eq!(counts.to_string(), "Hand 7   Library 92   Graveyard 0");
+
+    let counts = ZoneCounts {
+        maximum_hand_size: MaximumHandSize(Some(9)),
+        ..counts
+    };
+    assert_eq!(
+        counts.to_string(),
+        "Hand 7 (max 9)   Library 92   Graveyard 0"
+    );
+    let counts = ZoneCounts {
+        maximum_hand_size: MaximumHandSize::NO_MAXIMUM,
+        ..counts
+    };
+    assert_eq!(
+        counts.to_string(),
+        "Hand 7 (no max)   Library 92   Graveyard 0"
+    );
+}
+
+fn badge_text(app: &mut App, player: Entity) -> Option<String> {
+    app.world_mut()
+        .query::<(&ZoneCountBadge, &Text2d)>()
+        .iter(app.world())
+        .find(|(badge, _)| badge.player == player)
+        .map(|(_, text)| text.0.clone())
+}
+
+#[test]
+fn test_only_opponents_get_badges_that_follow_their_zones() {
+    let mut app = App::new();
+    app.add_event::<ZoneMutationEvent>()
+        .init_resource::<ZoneManager>()
+        .add_systems(
+            Update,
+            (
+                publish_zone_mutations,
+                spawn_zone_count_badges,
+                update_zone_count_badges,
+            )
+                .chain(),
+        );
+
+    let local = app.world_mut().spawn_empty().id();
+    let opponent = app.world_mut().spawn_empty().id();
+    for (index, player) in [local, opponent].into_iter().enumerate() {
+        app.world_mut().spawn((
+            PlayerPlaymat {
+                player_id: player,
+                player_index: index,
+            },
+            Transform::default(),
+        ));
+        let cards: Vec<Entity> = (0..3).map(|_| app.world_mut().spawn_empty().id()).collect();
+        let mut zones = app.world_mut().resource_mut::<ZoneManager>();
+        zones.init_player_zones(player);
+        for card in cards {
+            zones.add_to_library(player, card);
+        }
+    }
+    app.update();
+
+    assert_eq!(badge_text(&mut app, local), None);
+    assert_eq!(
+        badge_text(&mut app, opponent).as_deref(),
+        Some("Hand 0   Library 3   Graveyard 0")
+    );
+
+    {
+        let mut zones = app.world_mut().resource_mut::<ZoneManager>();
+        let drawn = zones.draw_card(opponent).unwrap();
+        zones.add_to_graveyard(opponent, drawn);
+        zones.draw_card(opponent);
+    }
+    app.update();
+
+    assert_eq!(
+        badge_text(&mut app, opponent).as_deref(),
+        Some("Hand 1   Library 1   Graveyard 1")
+    );
+}
+
+#[test]
+fn test_badge_shows_a_changed_maximum_hand_size() {
+    let mut app = App::new();
+    app.add_event::<ZoneMutationEvent>()
+        .init_resource::<ZoneManager>()
+        .add_systems(
+            Update,
+            (
+                publish_zone_mutations,
+                spawn_zone_count_badges,
+                update_zone_count_badges,
+            )
+                .chain(),
+        );
+    let opponent = app.world_mut().spawn(MaximumHandSize::default()).id();
+    app.world_mut().spawn((
+        PlayerPlaymat {
+            player_id: opponent,
+            player_index: 1,
+        },
+        Transform::default(),
+    ));
+    app.world_mut()
+        .resource_mut::<ZoneManager>()
+        .init_player_zones(opponent);
+    app.update();
+    assert_eq!(
+        badge_text(&mut app, opponent).as_deref(),
+        Some("Hand 0   Library 0   Graveyard 0")
+    );
+
+    *app.world_mut()
+        .get_mut::<MaximumHandSize>(opponent)
+        .unwrap() = MaximumHandSize::NO_MAXIMUM;
+    app.update();
+    assert_eq!(
+        badge_text(&mut app, opponent).as_deref(),
+        Some("Hand 0 (no max)   Library 0   Graveyard 0")
+    );
+}
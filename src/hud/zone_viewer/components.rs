@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+use crate::game_engine::zones::Zone;
+
+/// How many lines of the game log are kept for its viewer
+pub const LOG_LINES: usize = 40;
+
+/// What a viewer lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewerContent {
+    /// Everything narrated in the game, newest last
+    GameLog,
+    /// The items on the stack, top first
+    Stack,
+    /// The cards `owner` has in a zone
+    Zone { owner: Entity, zone: Zone },
+}
+
+impl ViewerContent {
+    /// The viewer's title, with the name of the zone's owner
+    pub fn title(&self, owner: &str) -> String {
+        match self {
+            ViewerContent::GameLog => "Game Log".to_string(),
+            ViewerContent::Stack => "Stack".to_string(),
+            ViewerContent::Zone { zone, .. } => format!("{}'s {:?}", owner, zone),
+        }
+    }
+}
+
+/// Open a viewer, or close it if it's already open
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToggleViewerEvent {
+    pub content: ViewerContent,
+}
+
+/// Root node of an open viewer
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewerPanel {
+    pub content: ViewerContent,
+    /// The window and camera showing it while it's detached
+    pub detached: Option<(Entity, Entity)>,
+}
+
+/// What a viewer's header button does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewerAction {
+    /// Move the viewer into a window of its own, or back over the table
+    Detach,
+    /// Close the viewer
+    Close,
+}
+
+/// Button in a viewer's header
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewerButton {
+    pub panel: Entity,
+    pub action: ViewerAction,
+}
+
+/// Text listing what a viewer shows
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewerListText {
+    pub panel: Entity,
+}
+
+/// Toolbar button opening a viewer
+///
+/// Zone viewers show the focused player's zone, or the local player's when no
+/// zone is focused.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewerToggle {
+    GameLog,
+    Stack,
+    Zone(Zone),
+}
+
+impl ViewerToggle {
+    /// Every toolbar button, in order
+    pub const ALL: [ViewerToggle; 4] = [
+        ViewerToggle::GameLog,
+        ViewerToggle::Stack,
+        ViewerToggle::Zone(Zone::Graveyard),
+        ViewerToggle::Zone(Zone::Exile),
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ViewerToggle::GameLog => "Log",
+            ViewerToggle::Stack => "Stack",
+            ViewerToggle::Zone(Zone::Graveyard) => "Graveyard",
+            ViewerToggle::Zone(Zone::Exile) => "Exile",
+            ViewerToggle::Zone(_) => "Zone",
+        }
+    }
+}
+
+/// Root node of the viewer toolbar
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ViewerToolbar;
+
+/// The most recent lines of the game log
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct GameLogLines {
+    lines: VecDeque<String>,
+}
+
+impl GameLogLines {
+    /// Add a line, dropping the oldest past [`LOG_LINES`]
+    pub fn push(&mut self, line: impl Into<String>) {
+        if self.lines.len() == LOG_LINES {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line.into());
+    }
+
+    /// The lines kept, oldest first
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().map(String::as_str)
+    }
+
+    pub fn clear(&mut self) {
+        self.lines.clear();
+    }
+}
+
+/// The text of a viewer listing `entries`
+pub fn list_text(entries: &[String]) -> String {
+    if entries.is_empty() {
+        "(empty)".to_string()
+    } else {
+        entries.join("\n")
+    }
+}
+
+/// The label of a viewer's detach button
+pub fn detach_label(detached: bool) -> &'static str {
+    if detached { "Dock" } else { "Detach" }
+}
@@ -0,0 +1,20 @@
+//! Viewers for the game log, the stack and public zones
+//!
+//! A toolbar opens panels listing the game log, what's on the stack, and the
+//! focused player's graveyard or exiled cards. Each panel floats over the table
+//! and can be detached into a window of its own, so players on large monitors
+//! can keep a graveyard or the log in view beside the battlefield rather than
+//! on top of it. Closing a detached window closes its viewer.
+
+mod components;
+mod plugin;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{
+    GameLogLines, LOG_LINES, ToggleViewerEvent, ViewerAction, ViewerButton, ViewerContent,
+    ViewerListText, ViewerPanel, ViewerToggle, detach_label, list_text,
+};
+pub use plugin::ZoneViewerPlugin;
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use bevy::window::WindowClosed;
+
+use super::components::{GameLogLines, ToggleViewerEvent};
+use super::systems::{
+    close_viewers_in_closed_windows, despawn_viewers, handle_toolbar_clicks, handle_viewer_buttons,
+    record_game_log, spawn_viewer_toolbar, toggle_viewers, update_viewer_text,
+};
+use crate::game_engine::zones::ZoneManager;
+use crate::menu::state::GameMenuState;
+use crate::narration::NarrationEvent;
+
+/// Plugin for the game log, stack and zone viewers
+pub struct ZoneViewerPlugin;
+
+impl Plugin for ZoneViewerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ToggleViewerEvent>()
+            .add_event::<NarrationEvent>()
+            .add_event::<WindowClosed>()
+            .init_resource::<GameLogLines>()
+            .add_systems(OnEnter(GameMenuState::InGame), spawn_viewer_toolbar)
+            .add_systems(OnExit(GameMenuState::InGame), despawn_viewers)
+            .add_systems(
+                Update,
+                (
+                    record_game_log,
+                    handle_toolbar_clicks,
+                    handle_viewer_buttons,
+                    close_viewers_in_closed_windows,
+                    toggle_viewers,
+                    update_viewer_text,
+                )
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame).and(resource_exists::<ZoneManager>)),
+            );
+    }
+}
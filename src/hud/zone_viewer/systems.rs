@@ -0,0 +1,425 @@
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::ui::UiTargetCamera;
+use bevy::window::{WindowClosed, WindowRef, WindowResolution};
+
+use super::components::{
+    GameLogLines, ToggleViewerEvent, ViewerAction, ViewerButton, ViewerContent, ViewerListText,
+    ViewerPanel, ViewerToggle, ViewerToolbar, detach_label, list_text,
+};
+use crate::camera::components::AppLayer;
+use crate::camera::presets::LOCAL_PLAYER_INDEX;
+use crate::cards::Card;
+use crate::game_engine::GameStack;
+use crate::game_engine::zones::{Zone, ZoneManager};
+use crate::narration::NarrationEvent;
+use crate::player::Player;
+use crate::player::playmat::ZoneFocusState;
+
+const PANEL_COLOR: Color = Color::srgba(0.05, 0.05, 0.08, 0.92);
+const BUTTON_COLOR: Color = Color::srgba(0.22, 0.22, 0.26, 0.95);
+
+/// Where the `index`th viewer floats over the table, each a little below and
+/// to the right of the last
+fn docked_node(index: usize) -> Node {
+    let offset = 24.0 * index as f32;
+    Node {
+        position_type: PositionType::Absolute,
+        top: Val::Px(80.0 + offset),
+        left: Val::Px(16.0 + offset),
+        width: Val::Px(300.0),
+        max_height: Val::Px(380.0),
+        padding: UiRect::all(Val::Px(10.0)),
+        flex_direction: FlexDirection::Column,
+        row_gap: Val::Px(6.0),
+        overflow: Overflow::clip(),
+        ..default()
+    }
+}
+
+/// A viewer filling the window it was detached into
+fn detached_node() -> Node {
+    Node {
+        width: Val::Percent(100.0),
+        height: Val::Percent(100.0),
+        padding: UiRect::all(Val::Px(12.0)),
+        flex_direction: FlexDirection::Column,
+        row_gap: Val::Px(6.0),
+        overflow: Overflow::clip(),
+        ..default()
+    }
+}
+
+fn player_name(players: &Query<(Entity, &Player)>, entity: Entity) -> String {
+    players
+        .get(entity)
+        .map(|(_, player)| player.name.clone())
+        .unwrap_or_else(|_| format!("{:?}", entity))
+}
+
+fn local_player(players: &Query<(Entity, &Player)>) -> Option<Entity> {
+    players
+        .iter()
+        .find(|(_, player)| player.player_index == LOCAL_PLAYER_INDEX)
+        .map(|(entity, _)| entity)
+}
+
+/// Despawn a viewer and the window it was detached into, if any
+fn close_viewer(commands: &mut Commands, panel: Entity, viewer: &ViewerPanel) {
+    if let Some((window, camera)) = viewer.detached {
+        commands.entity(window).despawn();
+        commands.entity(camera).despawn();
+    }
+    commands.entity(panel).despawn();
+}
+
+/// Show the toolbar opening the viewers as a game starts
+pub fn spawn_viewer_toolbar(mut commands: Commands, toolbars: Query<Entity, With<ViewerToolbar>>) {
+    for toolbar in toolbars.iter() {
+        commands.entity(toolbar).despawn();
+    }
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(44.0),
+                left: Val::Px(16.0),
+                column_gap: Val::Px(6.0),
+                ..default()
+            },
+            ViewerToolbar,
+            AppLayer::GameUI.layer(),
+            Name::new("Viewer Toolbar"),
+        ))
+        .with_children(|parent| {
+            for toggle in ViewerToggle::ALL {
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(BUTTON_COLOR),
+                        toggle,
+                        Name::new(format!("{} Viewer Button", toggle.label())),
+                    ))
+                    .with_children(|button| {
+                        button.spawn((
+                            Text::new(toggle.label()),
+                            TextFont {
+                                font_size: 13.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                    });
+            }
+        });
+}
+
+/// Toggle the viewer of a toolbar button when it's clicked
+pub fn handle_toolbar_clicks(
+    buttons: Query<(&Interaction, &ViewerToggle), Changed<Interaction>>,
+    focus: Option<Res<ZoneFocusState>>,
+    players: Query<(Entity, &Player)>,
+    mut toggles: EventWriter<ToggleViewerEvent>,
+) {
+    for (interaction, toggle) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let content = match *toggle {
+            ViewerToggle::GameLog => ViewerContent::GameLog,
+            ViewerToggle::Stack => ViewerContent::Stack,
+            ViewerToggle::Zone(zone) => {
+                let focused = focus.as_ref().and_then(|focus| focus.focused_zone_owner);
+                let Some(owner) = focused.or_else(|| local_player(&players)) else {
+                    continue;
+                };
+                ViewerContent::Zone { owner, zone }
+            }
+        };
+        toggles.write(ToggleViewerEvent { content });
+    }
+}
+
+/// Open the viewers asked for, or close them if they're already open
+pub fn toggle_viewers(
+    mut commands: Commands,
+    mut toggles: EventReader<ToggleViewerEvent>,
+    panels: Query<(Entity, &ViewerPanel)>,
+    players: Query<(Entity, &Player)>,
+) {
+    let mut open = panels.iter().count();
+    for toggle in toggles.read() {
+        if let Some((panel, viewer)) = panels
+            .iter()
+            .find(|(_, viewer)| viewer.content == toggle.content)
+        {
+            close_viewer(&mut commands, panel, viewer);
+            continue;
+        }
+
+        let owner = match toggle.content {
+            ViewerContent::Zone { owner, .. } => player_name(&players, owner),
+            _ => String::new(),
+        };
+        let panel = commands
+            .spawn((
+                docked_node(open),
+                BackgroundColor(PANEL_COLOR),
+                ViewerPanel {
+                    content: toggle.content,
+                    detached: None,
+                },
+                AppLayer::GameUI.layer(),
+                Name::new(format!("{} Viewer", toggle.content.title(&owner))),
+            ))
+            .id();
+        open += 1;
+
+        commands.entity(panel).with_children(|parent| {
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(6.0),
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|header| {
+                    header.spawn((
+                        Text::new(toggle.content.title(&owner)),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        Node {
+                            flex_grow: 1.0,
+                            ..default()
+                        },
+                    ));
+                    for (action, label) in [
+                        (ViewerAction::Detach, detach_label(false)),
+                        (ViewerAction::Close, "Close"),
+                    ] {
+                        header
+                            .spawn((
+                                Button,
+                                Node {
+                                    padding: UiRect::axes(Val::Px(8.0), Val::Px(2.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(BUTTON_COLOR),
+                                ViewerButton { panel, action },
+                            ))
+                            .with_children(|button| {
+                                button.spawn((
+                                    Text::new(label),
+                                    TextFont {
+                                        font_size: 12.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::WHITE),
+                                ));
+                            });
+                    }
+                });
+            parent.spawn((
+                Text::default(),
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                ViewerListText { panel },
+            ));
+        });
+    }
+}
+
+/// Detach, dock or close a viewer from the buttons in its header
+///
+/// A detached viewer gets a window of its own and a camera rendering only the
+/// viewer into it.
+pub fn handle_viewer_buttons(
+    mut commands: Commands,
+    buttons: Query<(&Interaction, &ViewerButton, &Children), Changed<Interaction>>,
+    mut panels: Query<(&mut ViewerPanel, &mut Node, &Name)>,
+    mut texts: Query<&mut Text>,
+) {
+    for (interaction, button, children) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok((mut viewer, mut node, name)) = panels.get_mut(button.panel) else {
+            continue;
+        };
+
+        match button.action {
+            ViewerAction::Close => close_viewer(&mut commands, button.panel, &viewer),
+            ViewerAction::Detach => {
+                if let Some((window, camera)) = viewer.detached.take() {
+                    commands.entity(window).despawn();
+                    commands.entity(camera).despawn();
+                    commands.entity(button.panel).remove::<UiTargetCamera>();
+                    *node = docked_node(0);
+                } else {
+                    let window = commands
+                        .spawn(Window {
+                            title: format!("Rummage - {}", name.as_str()),
+                            resolution: WindowResolution::new(360.0, 520.0),
+                            ..default()
+                        })
+                        .id();
+                    let camera = commands
+                        .spawn((
+                            Camera2d,
+                            Camera {
+                                target: RenderTarget::Window(WindowRef::Entity(window)),
+                                clear_color: ClearColorConfig::Custom(PANEL_COLOR),
+                                ..default()
+                            },
+                            AppLayer::GameUI.layer(),
+                            Name::new(format!("{} Camera", name.as_str())),
+                        ))
+                        .id();
+                    commands.entity(button.panel).insert(UiTargetCamera(camera));
+                    viewer.detached = Some((window, camera));
+                    *node = detached_node();
+                }
+
+                for child in children.iter() {
+                    if let Ok(mut text) = texts.get_mut(child) {
+                        text.0 = detach_label(viewer.detached.is_some()).to_string();
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Close the viewers whose windows were closed
+pub fn close_viewers_in_closed_windows(
+    mut commands: Commands,
+    mut closed: EventReader<WindowClosed>,
+    panels: Query<(Entity, &ViewerPanel)>,
+) {
+    for event in closed.read() {
+        for (panel, viewer) in panels.iter() {
+            let Some((window, camera)) = viewer.detached else {
+                continue;
+            };
+            if window == event.window {
+                commands.entity(camera).despawn();
+                commands.entity(panel).despawn();
+            }
+        }
+    }
+}
+
+/// Keep the game log's most recent lines for its viewer
+pub fn record_game_log(mut narration: EventReader<NarrationEvent>, mut log: ResMut<GameLogLines>) {
+    for event in narration.read() {
+        log.push(event.text.clone());
+    }
+}
+
+/// List what each viewer shows, when it changes
+///
+/// Face-down exiled cards are only named in their owner's viewers.
+#[allow(clippy::too_many_arguments)]
+pub fn update_viewer_text(
+    zones: Res<ZoneManager>,
+    stack: Res<GameStack>,
+    log: Res<GameLogLines>,
+    panels: Query<&ViewerPanel>,
+    players: Query<(Entity, &Player)>,
+    cards: Query<&Card>,
+    mut lists: Query<(&mut Text, &ViewerListText)>,
+    added: Query<(), Added<ViewerListText>>,
+) {
+    if !zones.is_changed() && !stack.is_changed() && !log.is_changed() && added.is_empty() {
+        return;
+    }
+
+    let local = local_player(&players);
+    let card_name = |card: Entity| {
+        cards
+            .get(card)
+            .map(|card| card.name.name.clone())
+            .unwrap_or_else(|_| format!("{:?}", card))
+    };
+    for (mut text, list) in lists.iter_mut() {
+        let Ok(viewer) = panels.get(list.panel) else {
+            continue;
+        };
+        let entries: Vec<String> = match viewer.content {
+            ViewerContent::GameLog => log.lines().map(str::to_string).collect(),
+            ViewerContent::Stack => stack
+                .items
+                .iter()
+                .rev()
+                .map(|item| {
+                    format!(
+                        "{} ({})",
+                        item.spec.name(),
+                        player_name(&players, item.controller)
+                    )
+                })
+                .collect(),
+            ViewerContent::Zone { owner, zone } => {
+                let cards: Vec<Entity> = match zone {
+                    // Exile is shared, so only the owner's cards are picked out
+                    Zone::Exile => zones
+                        .exile()
+                        .iter()
+                        .copied()
+                        .filter(|&card| zones.get_card_owner(card) == Some(owner))
+                        .collect(),
+                    // Most recent first, as a graveyard is looked through
+                    _ => zones
+                        .get_player_zone(owner, zone)
+                        .unwrap_or(&[])
+                        .iter()
+                        .rev()
+                        .copied()
+                        .collect(),
+                };
+                cards
+                    .into_iter()
+                    .map(|card| {
+                        let hidden = zones
+                            .exiled_by(card)
+                            .is_some_and(|exiled_by| exiled_by.face_down);
+                        if hidden && local != Some(owner) {
+                            "Face-down card".to_string()
+                        } else {
+                            card_name(card)
+                        }
+                    })
+                    .collect()
+            }
+        };
+        text.0 = list_text(&entries);
+    }
+}
+
+/// Close every viewer and its window, and forget the game's log
+pub fn despawn_viewers(
+    mut commands: Commands,
+    panels: Query<(Entity, &ViewerPanel)>,
+    toolbars: Query<Entity, With<ViewerToolbar>>,
+    mut log: ResMut<GameLogLines>,
+) {
+    for (panel, viewer) in panels.iter() {
+        close_viewer(&mut commands, panel, viewer);
+    }
+    for toolbar in toolbars.iter() {
+        commands.entity(toolbar).despawn();
+    }
+    log.clear();
+}
@@ -0,0 +1,242 @@
+use bevy::prelude::*;
+use bevy::ui::UiTargetCamera;
+use bevy::window::WindowClosed;
+
+use crate::cards::{Card, CardDetails, CardTypes};
+use crate::game_engine::stack::{EffectSpec, StackItemSpec};
+use crate::game_engine::zones::{ExiledBy, Zone};
+use crate::game_engine::{GameStack, ZoneManager};
+use crate::hud::zone_viewer::systems::{
+    close_viewers_in_closed_windows, handle_viewer_buttons, toggle_viewers, update_viewer_text,
+};
+use crate::hud::zone_viewer::{
+    GameLogLines, LOG_LINES, ToggleViewerEvent, ViewerAction, ViewerButton, ViewerContent,
+    ViewerListText, ViewerPanel, detach_label, list_text,
+};
+use crate::mana::Mana;
+use crate::player::Player;
+
+fn card(name: &str) -> Card {
+    Card::builder(name)
+        .cost(Mana::default())
+        .types(CardTypes::INSTANT)
+        .details(CardDetails::Other)
+        .build_or_panic()
+}
+
+struct Table {
+    app: App,
+    player: Entity,
+    opponent: Entity,
+}
+
+impl Table {
+    fn new() -> Self {
+        let mut app = App::new();
+        app.add_event::<ToggleViewerEvent>()
+            .add_event::<WindowClosed>()
+            .init_resource::<ZoneManager>()
+            .init_resource::<GameStack>()
+            .init_resource::<GameLogLines>()
+            .add_systems(
+                Update,
+                (
+                    handle_viewer_buttons,
+                    close_viewers_in_closed_windows,
+                    toggle_viewers,
+                    update_viewer_text,
+                )
+                    .chain(),
+            );
+        let world = app.world_mut();
+        let player = world.spawn(Player::new("Alice")).id();
+        let opponent = world.spawn(Player::new("Bob").with_player_index(1)).id();
+        let mut zones = world.resource_mut::<ZoneManager>();
+        zones.init_player_zones(player);
+        zones.init_player_zones(opponent);
+        Self {
+            app,
+            player,
+            opponent,
+        }
+    }
+
+    fn toggle(&mut self, content: ViewerContent) {
+        self.app
+            .world_mut()
+            .send_event(ToggleViewerEvent { content });
+        self.app.update();
+    }
+
+    fn panel(&mut self) -> Option<(Entity, ViewerPanel)> {
+        self.app
+            .world_mut()
+            .query::<(Entity, &ViewerPanel)>()
+            .iter(self.app.world())
+            .next()
+            .map(|(entity, panel)| (entity, *panel))
+    }
+
+    fn list(&mut self) -> String {
+        self.app.update();
+        self.app
+            .world_mut()
+            .query_filtered::<&Text, With<ViewerListText>>()
+            .iter(self.app.world())
+            .next()
+            .map(|text| text.0.clone())
+            .unwrap()
+    }
+
+    fn click(&mut self, action: ViewerAction) {
+        let button = self
+            .app
+            .world_mut()
+            .query::<(Entity, &ViewerButton)>()
+            .iter(self.app.world())
+            .find(|(_, button)| button.action == action)
+            .map(|(entity, _)| entity)
+            .unwrap();
+        self.app
+            .world_mut()
+            .entity_mut(button)
+            .insert(Interaction::Pressed);
+        self.app.update();
+        self.app
+            .world_mut()
+            .entity_mut(button)
+            .insert(Interaction::None);
+    }
+}
+
+#[test]
+fn test_viewer_text() {
+    assert_eq!(list_text(&[]), "(empty)");
+    assert_eq!(
+        list_text(&["Shock".to_string(), "Opt".to_string()]),
+        "Shock\nOpt"
+    );
+    assert_eq!(detach_label(false), "Detach");
+    assert_eq!(detach_label(true), "Dock");
+    assert_eq!(ViewerContent::Stack.title(""), "Stack");
+    let graveyard = ViewerContent::Zone {
+        owner: Entity::PLACEHOLDER,
+        zone: Zone::Graveyard,
+    };
+    assert_eq!(graveyard.title("Bob"), "Bob's Graveyard");
+}
+
+#[test]
+fn test_game_log_keeps_the_latest_lines() {
+    let mut log = GameLogLines::default();
+    for line in 0..LOG_LINES + 2 {
+        log.push(line.to_string());
+    }
+    let lines: Vec<&str> = log.lines().collect();
+    assert_eq!(lines.len(), LOG_LINES);
+    assert_eq!(lines[0], "2");
+}
+
+#[test]
+fn test_zone_viewers_toggle_and_list_their_cards() {
+    let mut table = Table::new();
+    let (player, opponent) = (table.player, table.opponent);
+    let world = table.app.world_mut();
+    let shock = world.spawn(card("Shock")).id();
+    let opt = world.spawn(card("Opt")).id();
+    let ponder = world.spawn(card("Ponder")).id();
+    let hidden = world.spawn(card("Brainstorm")).id();
+    let mut zones = world.resource_mut::<ZoneManager>();
+    zones.add_to_graveyard(opponent, shock);
+    zones.add_to_graveyard(opponent, opt);
+    zones.add_to_graveyard(player, ponder);
+    zones.exile_with(
+        opponent,
+        hidden,
+        ExiledBy {
+            source: shock,
+            may_play_until: None,
+            face_down: true,
+        },
+    );
+
+    let graveyard = ViewerContent::Zone {
+        owner: opponent,
+        zone: Zone::Graveyard,
+    };
+    table.toggle(graveyard);
+    assert_eq!(table.panel().unwrap().1.content, graveyard);
+    // Most recent first, and only the owner's cards
+    assert_eq!(table.list(), "Opt\nShock");
+
+    table.toggle(graveyard);
+    assert!(table.panel().is_none());
+
+    // Face-down cards aren't named to other players
+    table.toggle(ViewerContent::Zone {
+        owner: opponent,
+        zone: Zone::Exile,
+    });
+    assert_eq!(table.list(), "Face-down card");
+}
+
+#[test]
+fn test_stack_viewer_lists_the_top_first() {
+    let mut table = Table::new();
+    for (name, controller) in [("Shock", table.player), ("Counterspell", table.opponent)] {
+        let entity = table.app.world_mut().spawn_empty().id();
+        let item = StackItemSpec {
+            spec: EffectSpec::Opaque {
+                name: name.to_string(),
+                controller,
+                targets: Vec::new(),
+            },
+            has_split_second: false,
+            can_be_countered: true,
+        };
+        table
+            .app
+            .world_mut()
+            .resource_mut::<GameStack>()
+            .push_spec(&item, entity);
+    }
+
+    table.toggle(ViewerContent::Stack);
+    assert_eq!(table.list(), "Counterspell (Bob)\nShock (Alice)");
+}
+
+#[test]
+fn test_viewers_detach_into_their_own_window() {
+    let mut table = Table::new();
+    table.toggle(ViewerContent::GameLog);
+    let (panel, _) = table.panel().unwrap();
+
+    table.click(ViewerAction::Detach);
+    let (window, camera) = table.panel().unwrap().1.detached.unwrap();
+    assert!(table.app.world().entity(window).contains::<Window>());
+    assert_eq!(
+        table.app.world().entity(panel).get::<UiTargetCamera>(),
+        Some(&UiTargetCamera(camera))
+    );
+
+    // Docking puts it back over the table
+    table.click(ViewerAction::Detach);
+    assert!(table.panel().unwrap().1.detached.is_none());
+    assert!(table.app.world().get_entity(window).is_err());
+    assert!(table.app.world().get_entity(camera).is_err());
+    assert!(!table.app.world().entity(panel).contains::<UiTargetCamera>());
+}
+
+#[test]
+fn test_closing_a_detached_window_closes_its_viewer() {
+    let mut table = Table::new();
+    table.toggle(ViewerContent::GameLog);
+    table.click(ViewerAction::Detach);
+    let (window, camera) = table.panel().unwrap().1.detached.unwrap();
+
+    table.app.world_mut().despawn(window);
+    table.app.world_mut().send_event(WindowClosed { window });
+    table.app.update();
+    assert!(table.panel().is_none());
+    assert!(table.app.world().get_entity(camera).is_err());
+}
@@ -0,0 +1,250 @@
+use bevy::input::gamepad::{Gamepad, GamepadButton};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// How far the left stick has to be pushed to count as a direction
+pub const STICK_THRESHOLD: f32 = 0.6;
+
+/// Something the player can do, whichever key or button they do it with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputAction {
+    /// Move the menu focus up
+    Up,
+    /// Move the menu focus down
+    Down,
+    /// Move the menu focus left, or turn a focused slider down
+    Left,
+    /// Move the menu focus right, or turn a focused slider up
+    Right,
+    /// Press the focused button
+    Confirm,
+    /// Leave the current menu
+    Cancel,
+    /// Open the pause menu during a game
+    Pause,
+    /// Pass priority during a game
+    PassPriority,
+    /// Focus the next player's board
+    NextPlayer,
+    /// Focus the previous player's board
+    PreviousPlayer,
+    /// Focus the next zone of the focused player
+    NextZone,
+    /// Focus the previous zone of the focused player
+    PreviousZone,
+}
+
+impl InputAction {
+    /// Every action, in the order they are listed in hints
+    pub const ALL: [InputAction; 12] = [
+        InputAction::Up,
+        InputAction::Down,
+        InputAction::Left,
+        InputAction::Right,
+        InputAction::Confirm,
+        InputAction::Cancel,
+        InputAction::Pause,
+        InputAction::PassPriority,
+        InputAction::NextPlayer,
+        InputAction::PreviousPlayer,
+        InputAction::NextZone,
+        InputAction::PreviousZone,
+    ];
+}
+
+/// Keys and gamepad buttons bound to an action
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputBinding {
+    pub keys: Vec<KeyCode>,
+    pub buttons: Vec<GamepadButton>,
+}
+
+impl InputBinding {
+    pub fn new(keys: &[KeyCode], buttons: &[GamepadButton]) -> Self {
+        Self {
+            keys: keys.to_vec(),
+            buttons: buttons.to_vec(),
+        }
+    }
+}
+
+/// The keys and gamepad buttons bound to each [`InputAction`]
+///
+/// Escape and B both cancel in menus, but in a game Escape pauses and B passes
+/// priority, since menus and games never read each other's actions.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct InputBindings {
+    pub bindings: HashMap<InputAction, InputBinding>,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        use GamepadButton as Button;
+
+        let bindings = [
+            (
+                InputAction::Up,
+                InputBinding::new(&[KeyCode::ArrowUp], &[Button::DPadUp]),
+            ),
+            (
+                InputAction::Down,
+                InputBinding::new(&[KeyCode::ArrowDown], &[Button::DPadDown]),
+            ),
+            (
+                InputAction::Left,
+                InputBinding::new(&[KeyCode::ArrowLeft], &[Button::DPadLeft]),
+            ),
+            (
+                InputAction::Right,
+                InputBinding::new(&[KeyCode::ArrowRight], &[Button::DPadRight]),
+            ),
+            (
+                InputAction::Confirm,
+                InputBinding::new(&[KeyCode::Enter], &[Button::South]),
+            ),
+            (
+                InputAction::Cancel,
+                InputBinding::new(&[KeyCode::Escape], &[Button::East]),
+            ),
+            (
+                InputAction::Pause,
+                InputBinding::new(&[KeyCode::Escape], &[Button::Start]),
+            ),
+            (
+                InputAction::PassPriority,
+                InputBinding::new(&[KeyCode::Space], &[Button::East]),
+            ),
+            (
+                InputAction::NextPlayer,
+                InputBinding::new(&[KeyCode::PageDown], &[Button::RightTrigger]),
+            ),
+            (
+                InputAction::PreviousPlayer,
+                InputBinding::new(&[KeyCode::PageUp], &[Button::LeftTrigger]),
+            ),
+            (
+                InputAction::NextZone,
+                InputBinding::new(&[KeyCode::Tab], &[Button::RightTrigger2]),
+            ),
+            (
+                InputAction::PreviousZone,
+                InputBinding::new(&[], &[Button::LeftTrigger2]),
+            ),
+        ];
+
+        Self {
+            bindings: bindings.into_iter().collect(),
+        }
+    }
+}
+
+impl InputBindings {
+    /// What's bound to an action
+    pub fn get(&self, action: InputAction) -> Option<&InputBinding> {
+        self.bindings.get(&action)
+    }
+
+    /// Bind an action to a key, on top of what's already bound to it
+    pub fn bind_key(&mut self, action: InputAction, key: KeyCode) {
+        let binding = self.bindings.entry(action).or_default();
+        if !binding.keys.contains(&key) {
+            binding.keys.push(key);
+        }
+    }
+
+    /// Bind an action to a gamepad button, on top of what's already bound to it
+    pub fn bind_button(&mut self, action: InputAction, button: GamepadButton) {
+        let binding = self.bindings.entry(action).or_default();
+        if !binding.buttons.contains(&button) {
+            binding.buttons.push(button);
+        }
+    }
+
+    /// The first gamepad button bound to an action, shown in hints
+    pub fn button_for(&self, action: InputAction) -> Option<GamepadButton> {
+        self.get(action)
+            .and_then(|binding| binding.buttons.first().copied())
+    }
+}
+
+/// Which actions are pressed this frame, from any keyboard or gamepad
+pub type ActionInput = ButtonInput<InputAction>;
+
+/// The kind of device the player last pressed something on
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LastInputDevice {
+    #[default]
+    KeyboardMouse,
+    Gamepad,
+}
+
+/// The navigation direction the left stick is pushed in, if it is pushed far enough
+pub fn stick_direction(stick: Vec2) -> Option<InputAction> {
+    if stick.length() < STICK_THRESHOLD {
+        return None;
+    }
+    Some(if stick.x.abs() > stick.y.abs() {
+        if stick.x > 0.0 {
+            InputAction::Right
+        } else {
+            InputAction::Left
+        }
+    } else if stick.y > 0.0 {
+        InputAction::Up
+    } else {
+        InputAction::Down
+    })
+}
+
+/// Press and release actions from the bound keys, gamepad buttons and left sticks
+///
+/// A stick counts as pressing a direction when it crosses [`STICK_THRESHOLD`],
+/// and releasing it when it falls back or turns, so holding it moves once.
+pub fn update_action_input(
+    bindings: Res<InputBindings>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
+    gamepads: Query<&Gamepad>,
+    mut actions: ResMut<ActionInput>,
+    mut device: ResMut<LastInputDevice>,
+) {
+    actions.clear();
+
+    let sticks: Vec<InputAction> = gamepads
+        .iter()
+        .filter_map(|gamepad| stick_direction(gamepad.left_stick()))
+        .collect();
+
+    for action in InputAction::ALL {
+        let Some(binding) = bindings.get(action) else {
+            actions.release(action);
+            continue;
+        };
+
+        let key_down = keys
+            .as_ref()
+            .is_some_and(|keys| keys.any_pressed(binding.keys.iter().copied()));
+        let button_down = sticks.contains(&action)
+            || gamepads
+                .iter()
+                .any(|gamepad| gamepad.any_pressed(binding.buttons.iter().copied()));
+
+        if key_down || button_down {
+            if !actions.pressed(action) {
+                device.set_if_neq(if button_down {
+                    LastInputDevice::Gamepad
+                } else {
+                    LastInputDevice::KeyboardMouse
+                });
+            }
+            actions.press(action);
+        } else {
+            actions.release(action);
+        }
+    }
+
+    // Clicking goes back to mouse hints
+    if mouse.is_some_and(|mouse| mouse.get_just_pressed().next().is_some()) {
+        device.set_if_neq(LastInputDevice::KeyboardMouse);
+    }
+}
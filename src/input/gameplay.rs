@@ -0,0 +1,165 @@
+use bevy::prelude::*;
+
+use super::bindings::{ActionInput, InputAction};
+use crate::camera::presets::{CameraPreset, CameraPresetEvent, LOCAL_PLAYER_INDEX};
+use crate::game_engine::actions::GameAction;
+use crate::game_engine::zones::Zone;
+use crate::player::Player;
+use crate::player::playmat::{PlaymatZone, ZoneFocusState};
+
+/// Zones in the order the zone buttons cycle through them
+pub const ZONE_CYCLE: [Zone; 6] = [
+    Zone::Hand,
+    Zone::Battlefield,
+    Zone::Library,
+    Zone::Graveyard,
+    Zone::Exile,
+    Zone::Command,
+];
+
+/// Index of the next item when cycling through `len` items, wrapping at either end
+///
+/// With nothing selected yet, cycling forward starts at the first item and
+/// backward at the last.
+pub fn cycle_index(len: usize, current: Option<usize>, forward: bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    Some(match (current, forward) {
+        (None, true) => 0,
+        (None, false) => len - 1,
+        (Some(index), true) => (index + 1) % len,
+        (Some(index), false) => (index + len - 1) % len,
+    })
+}
+
+/// Which way a cycling action was pressed this frame, `true` for forward
+fn cycle_direction(
+    actions: &ActionInput,
+    next: InputAction,
+    previous: InputAction,
+) -> Option<bool> {
+    if actions.just_pressed(next) {
+        Some(true)
+    } else if actions.just_pressed(previous) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// The playmat zone of a type owned by a player
+fn find_zone<'a>(
+    zones: impl IntoIterator<Item = (Entity, &'a PlaymatZone)>,
+    owner: Entity,
+    zone_type: &Zone,
+) -> Option<Entity> {
+    zones
+        .into_iter()
+        .find(|(_, zone)| zone.player_id == owner && zone.zone_type == *zone_type)
+        .map(|(entity, _)| entity)
+}
+
+/// Focus the next or previous player's board and move the camera to it
+///
+/// The same kind of zone stays focused, starting from the battlefield.
+pub fn cycle_focused_player(
+    actions: Res<ActionInput>,
+    players: Query<(Entity, &Player)>,
+    zones: Query<(Entity, &PlaymatZone)>,
+    mut focus: ResMut<ZoneFocusState>,
+    mut presets: EventWriter<CameraPresetEvent>,
+) {
+    let Some(forward) = cycle_direction(
+        &actions,
+        InputAction::NextPlayer,
+        InputAction::PreviousPlayer,
+    ) else {
+        return;
+    };
+
+    let mut seats: Vec<(Entity, usize)> = players
+        .iter()
+        .map(|(entity, player)| (entity, player.player_index))
+        .collect();
+    seats.sort_unstable_by_key(|(_, index)| *index);
+
+    let current = focus
+        .focused_zone_owner
+        .and_then(|owner| seats.iter().position(|(entity, _)| *entity == owner))
+        .or_else(|| {
+            seats
+                .iter()
+                .position(|(_, index)| *index == LOCAL_PLAYER_INDEX)
+        });
+    let Some(next) = cycle_index(seats.len(), current, forward) else {
+        return;
+    };
+    let (owner, player_index) = seats[next];
+
+    let zone_type = focus.focused_zone_type.unwrap_or(Zone::Battlefield);
+    focus.focused_zone = find_zone(zones.iter(), owner, &zone_type);
+    focus.focused_zone_type = Some(zone_type);
+    focus.focused_zone_owner = Some(owner);
+
+    presets.write(CameraPresetEvent(CameraPreset::FocusPlayer(player_index)));
+}
+
+/// Focus the next or previous zone of the focused player, or of the local player
+/// when no one is focused
+pub fn cycle_focused_zone(
+    actions: Res<ActionInput>,
+    players: Query<(Entity, &Player)>,
+    zones: Query<(Entity, &PlaymatZone)>,
+    mut focus: ResMut<ZoneFocusState>,
+) {
+    let Some(forward) = cycle_direction(&actions, InputAction::NextZone, InputAction::PreviousZone)
+    else {
+        return;
+    };
+
+    let Some(owner) = focus.focused_zone_owner.or_else(|| {
+        players
+            .iter()
+            .find(|(_, player)| player.player_index == LOCAL_PLAYER_INDEX)
+            .map(|(entity, _)| entity)
+    }) else {
+        return;
+    };
+
+    // Only the zones this player has a playmat area for
+    let available: Vec<Zone> = ZONE_CYCLE
+        .into_iter()
+        .filter(|zone_type| find_zone(zones.iter(), owner, zone_type).is_some())
+        .collect();
+    let current = focus
+        .focused_zone_type
+        .as_ref()
+        .and_then(|zone_type| available.iter().position(|zone| zone == zone_type));
+    let Some(next) = cycle_index(available.len(), current, forward) else {
+        return;
+    };
+
+    let zone_type = available[next];
+    focus.focused_zone = find_zone(zones.iter(), owner, &zone_type);
+    focus.focused_zone_type = Some(zone_type);
+    focus.focused_zone_owner = Some(owner);
+}
+
+/// Pass priority for the local player
+pub fn pass_priority_from_input(
+    actions: Res<ActionInput>,
+    players: Query<(Entity, &Player)>,
+    mut game_actions: EventWriter<GameAction>,
+) {
+    if !actions.just_pressed(InputAction::PassPriority) {
+        return;
+    }
+
+    if let Some((player, _)) = players
+        .iter()
+        .find(|(_, player)| player.player_index == LOCAL_PLAYER_INDEX)
+    {
+        game_actions.write(GameAction::PassPriority { player });
+    }
+}
@@ -0,0 +1,151 @@
+use bevy::input::gamepad::GamepadButton;
+use bevy::prelude::*;
+
+use super::bindings::{InputAction, InputBindings, LastInputDevice};
+use crate::camera::z_order::UiLayer;
+use crate::menu::state::AppState;
+
+/// Actions hinted at in menus, with what they do there
+pub const MENU_HINTS: &[(InputAction, &str)] = &[
+    (InputAction::Up, "Move"),
+    (InputAction::Confirm, "Select"),
+    (InputAction::Cancel, "Back"),
+];
+
+/// Actions hinted at during a game, with what they do there
+pub const GAME_HINTS: &[(InputAction, &str)] = &[
+    (InputAction::PassPriority, "Pass priority"),
+    (InputAction::PreviousPlayer, "Players"),
+    (InputAction::PreviousZone, "Zones"),
+    (InputAction::Pause, "Pause"),
+];
+
+/// The bar of button hints along the bottom of the screen
+#[derive(Component)]
+pub struct InputHintBar;
+
+/// The text of the hint bar
+#[derive(Component)]
+pub struct InputHintText;
+
+/// The label printed on a gamepad button, using Xbox names
+pub fn button_glyph(button: GamepadButton) -> &'static str {
+    match button {
+        GamepadButton::South => "A",
+        GamepadButton::East => "B",
+        GamepadButton::West => "X",
+        GamepadButton::North => "Y",
+        GamepadButton::LeftTrigger => "LB",
+        GamepadButton::RightTrigger => "RB",
+        GamepadButton::LeftTrigger2 => "LT",
+        GamepadButton::RightTrigger2 => "RT",
+        GamepadButton::Select => "View",
+        GamepadButton::Start => "Menu",
+        GamepadButton::LeftThumb => "LS",
+        GamepadButton::RightThumb => "RS",
+        GamepadButton::DPadUp
+        | GamepadButton::DPadDown
+        | GamepadButton::DPadLeft
+        | GamepadButton::DPadRight => "D-Pad",
+        _ => "?",
+    }
+}
+
+/// The action cycling the other way from `action`, hinted at together with it
+fn paired_action(action: InputAction) -> Option<InputAction> {
+    match action {
+        InputAction::PreviousPlayer => Some(InputAction::NextPlayer),
+        InputAction::PreviousZone => Some(InputAction::NextZone),
+        _ => None,
+    }
+}
+
+/// A line of hints like `[A] Select   [B] Back` for the bound buttons
+///
+/// Actions with no gamepad button bound are left out, and previous/next pairs
+/// share one hint as `[LB/RB] Players`.
+pub fn hint_line(bindings: &InputBindings, hints: &[(InputAction, &str)]) -> String {
+    hints
+        .iter()
+        .filter_map(|(action, label)| {
+            let mut glyph = button_glyph(bindings.button_for(*action)?).to_string();
+            if let Some(button) = paired_action(*action).and_then(|next| bindings.button_for(next))
+            {
+                glyph = format!("{}/{}", glyph, button_glyph(button));
+            }
+            Some(format!("[{}] {}", glyph, label))
+        })
+        .collect::<Vec<_>>()
+        .join("   ")
+}
+
+/// Spawns the hint bar, hidden until a gamepad is used
+pub fn spawn_input_hint_bar(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(8.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            UiLayer::Overlay.global_z_index(),
+            Visibility::Hidden,
+            InputHintBar,
+            Name::new("Input Hint Bar"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+                    Name::new("Input Hint Background"),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        InputHintText,
+                        Name::new("Input Hint Text"),
+                    ));
+                });
+        });
+}
+
+/// Shows the hints for the current screen while a gamepad is in use
+pub fn update_input_hint_bar(
+    device: Res<LastInputDevice>,
+    bindings: Res<InputBindings>,
+    app_state: Option<Res<State<AppState>>>,
+    mut bars: Query<&mut Visibility, With<InputHintBar>>,
+    mut texts: Query<&mut Text, With<InputHintText>>,
+) {
+    let state_changed = app_state.as_ref().is_some_and(|state| state.is_changed());
+    if !device.is_changed() && !bindings.is_changed() && !state_changed {
+        return;
+    }
+
+    let visibility = if *device == LastInputDevice::Gamepad {
+        Visibility::Visible
+    } else {
+        Visibility::Hidden
+    };
+    for mut bar in bars.iter_mut() {
+        bar.set_if_neq(visibility);
+    }
+
+    let in_game = app_state.is_some_and(|state| *state.get() == AppState::InGame);
+    let hints = if in_game { GAME_HINTS } else { MENU_HINTS };
+    let line = hint_line(&bindings, hints);
+    for mut text in texts.iter_mut() {
+        text.0 = line.clone();
+    }
+}
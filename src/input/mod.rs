@@ -0,0 +1,23 @@
+//! Keyboard and gamepad controls
+//!
+//! Keys and gamepad buttons are mapped to [`InputAction`]s through the
+//! [`InputBindings`] resource, and systems read the pressed actions from
+//! [`ActionInput`] instead of particular keys or buttons. In menus the D-pad,
+//! left stick or arrow keys move a focus outline between buttons, step focused
+//! sliders left and right, and A or Enter presses the focused button. During a
+//! game the bumpers cycle through players, the triggers through zones, and B
+//! passes priority. While a gamepad is in use a bar along the bottom of the
+//! screen shows which button does what.
+
+pub mod bindings;
+pub mod gameplay;
+pub mod hints;
+pub mod navigation;
+mod plugin;
+
+#[cfg(test)]
+mod tests;
+
+pub use bindings::{ActionInput, InputAction, InputBinding, InputBindings, LastInputDevice};
+pub use navigation::{MenuFocus, NavigationSlider, SliderStepEvent};
+pub use plugin::ActionInputPlugin;
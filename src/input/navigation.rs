@@ -0,0 +1,204 @@
+use bevy::prelude::*;
+
+use super::bindings::{ActionInput, InputAction};
+
+/// Outline drawn around the focused button
+const FOCUS_OUTLINE_COLOR: Color = Color::srgb(1.0, 0.84, 0.0);
+
+/// The menu button focused by keyboard or gamepad navigation, if any
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MenuFocus(pub Option<Entity>);
+
+/// Marks the button showing the focus outline
+#[derive(Component)]
+pub struct FocusOutline;
+
+/// Marks a button as a slider, so left and right step its value when it is
+/// focused instead of moving the focus
+#[derive(Component, Default)]
+pub struct NavigationSlider;
+
+/// A focused slider was stepped up or down with left or right
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SliderStepEvent {
+    pub slider: Entity,
+    /// `1` for a step up, `-1` for a step down
+    pub step: i32,
+}
+
+/// Screen direction of a navigation action, with y pointing down like UI nodes
+pub fn direction_of(action: InputAction) -> Option<Vec2> {
+    match action {
+        InputAction::Up => Some(Vec2::NEG_Y),
+        InputAction::Down => Some(Vec2::Y),
+        InputAction::Left => Some(Vec2::NEG_X),
+        InputAction::Right => Some(Vec2::X),
+        _ => None,
+    }
+}
+
+/// The button to move focus to from `from` in `direction`
+///
+/// Prefers the nearest button in that direction, weighing sideways distance
+/// double so focus stays in its row or column. With nothing in that direction,
+/// focus wraps around to the furthest button on the other side.
+pub fn next_focus(from: Vec2, candidates: &[(Entity, Vec2)], direction: Vec2) -> Option<Entity> {
+    let score = |position: Vec2| {
+        let offset = position - from;
+        let along = offset.dot(direction);
+        let across = (offset - direction * along).length();
+        (along, across)
+    };
+
+    let ahead = candidates
+        .iter()
+        .filter_map(|(entity, position)| {
+            let (along, across) = score(*position);
+            (along > 1.0).then_some((*entity, along + across * 2.0))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+    if let Some((entity, _)) = ahead {
+        return Some(entity);
+    }
+
+    candidates
+        .iter()
+        .filter_map(|(entity, position)| {
+            let (along, across) = score(*position);
+            (along < -1.0).then_some((*entity, along + across * 2.0))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity)
+}
+
+/// The button to focus first: the top one, leftmost on ties
+pub fn first_focus(candidates: &[(Entity, Vec2)]) -> Option<Entity> {
+    candidates
+        .iter()
+        .min_by(|a, b| a.1.y.total_cmp(&b.1.y).then(a.1.x.total_cmp(&b.1.x)))
+        .map(|(entity, _)| *entity)
+}
+
+/// Move the menu focus between visible buttons, or step a focused slider
+pub fn navigate_menu_focus(
+    actions: Res<ActionInput>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    buttons: Query<
+        (
+            Entity,
+            &GlobalTransform,
+            &ComputedNode,
+            &InheritedVisibility,
+        ),
+        With<Button>,
+    >,
+    sliders: Query<(), With<NavigationSlider>>,
+    mut focus: ResMut<MenuFocus>,
+    mut steps: EventWriter<SliderStepEvent>,
+) {
+    // Clicking hands the menu back to the mouse
+    if mouse.get_just_pressed().next().is_some() {
+        focus.set_if_neq(MenuFocus(None));
+        return;
+    }
+
+    let candidates: Vec<(Entity, Vec2)> = buttons
+        .iter()
+        .filter(|(_, _, node, visibility)| visibility.get() && node.size() != Vec2::ZERO)
+        .map(|(entity, transform, ..)| (entity, transform.translation().truncate()))
+        .collect();
+
+    // Drop the focus once its button is hidden or despawned
+    let focused = focus
+        .0
+        .and_then(|entity| candidates.iter().find(|(e, _)| *e == entity).copied());
+    if focused.is_none() && focus.0.is_some() {
+        focus.0 = None;
+    }
+
+    for action in [
+        InputAction::Up,
+        InputAction::Down,
+        InputAction::Left,
+        InputAction::Right,
+    ] {
+        if !actions.just_pressed(action) {
+            continue;
+        }
+        let Some(direction) = direction_of(action) else {
+            continue;
+        };
+
+        let Some((entity, position)) = focused else {
+            focus.0 = first_focus(&candidates);
+            return;
+        };
+
+        if direction.y == 0.0 && sliders.contains(entity) {
+            steps.write(SliderStepEvent {
+                slider: entity,
+                step: direction.x as i32,
+            });
+            return;
+        }
+
+        if let Some(next) = next_focus(position, &candidates, direction) {
+            focus.0 = Some(next);
+        }
+        return;
+    }
+}
+
+/// Press the focused button when Confirm is pressed
+///
+/// The button's [`Interaction`] is set to pressed for one frame after the UI
+/// has worked out mouse interactions, so menus handle it like a click.
+pub fn confirm_menu_focus(
+    actions: Res<ActionInput>,
+    focus: Res<MenuFocus>,
+    mut interactions: Query<&mut Interaction, With<Button>>,
+    mut pressed: Local<Option<Entity>>,
+) {
+    if let Some(entity) = pressed.take() {
+        if let Ok(mut interaction) = interactions.get_mut(entity) {
+            interaction.set_if_neq(Interaction::None);
+        }
+    }
+
+    if !actions.just_pressed(InputAction::Confirm) {
+        return;
+    }
+    let Some(entity) = focus.0 else {
+        return;
+    };
+    if let Ok(mut interaction) = interactions.get_mut(entity) {
+        *interaction = Interaction::Pressed;
+        *pressed = Some(entity);
+    }
+}
+
+/// Outline the focused button
+pub fn show_menu_focus(
+    mut commands: Commands,
+    focus: Res<MenuFocus>,
+    outlined: Query<Entity, With<FocusOutline>>,
+) {
+    if !focus.is_changed() {
+        return;
+    }
+
+    for entity in outlined.iter() {
+        if Some(entity) != focus.0 {
+            commands
+                .entity(entity)
+                .try_remove::<(Outline, FocusOutline)>();
+        }
+    }
+
+    if let Some(entity) = focus.0 {
+        commands.entity(entity).try_insert((
+            Outline::new(Val::Px(3.0), Val::Px(2.0), FOCUS_OUTLINE_COLOR),
+            FocusOutline,
+        ));
+    }
+}
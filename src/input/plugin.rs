@@ -0,0 +1,52 @@
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+use super::bindings::{ActionInput, InputBindings, LastInputDevice, update_action_input};
+use super::gameplay::{cycle_focused_player, cycle_focused_zone, pass_priority_from_input};
+use super::hints::{spawn_input_hint_bar, update_input_hint_bar};
+use super::navigation::{
+    MenuFocus, SliderStepEvent, confirm_menu_focus, navigate_menu_focus, show_menu_focus,
+};
+use crate::menu::state::{AppState, GameMenuState};
+
+/// Plugin mapping keyboard and gamepad input to [`InputAction`](super::InputAction)s
+pub struct ActionInputPlugin;
+
+impl Plugin for ActionInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputBindings>()
+            .init_resource::<ActionInput>()
+            .init_resource::<LastInputDevice>()
+            .init_resource::<MenuFocus>()
+            .add_event::<SliderStepEvent>()
+            .add_systems(Startup, spawn_input_hint_bar)
+            .add_systems(PreUpdate, update_action_input.after(InputSystem))
+            // Pressing the focused button has to come after the UI works out
+            // mouse interactions, or it would be reset straight away
+            .add_systems(
+                PreUpdate,
+                confirm_menu_focus
+                    .after(UiSystem::Focus)
+                    .after(update_action_input)
+                    .run_if(not(in_state(AppState::InGame))),
+            )
+            .add_systems(
+                Update,
+                (navigate_menu_focus, show_menu_focus)
+                    .chain()
+                    .run_if(not(in_state(AppState::InGame))),
+            )
+            .add_systems(
+                Update,
+                (
+                    cycle_focused_player,
+                    cycle_focused_zone,
+                    pass_priority_from_input,
+                )
+                    .run_if(in_state(AppState::InGame))
+                    .run_if(in_state(GameMenuState::InGame)),
+            )
+            .add_systems(Update, update_input_hint_bar);
+    }
+}
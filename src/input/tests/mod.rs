@@ -0,0 +1,222 @@
+use bevy::input::gamepad::GamepadButton;
+use bevy::prelude::*;
+
+use crate::camera::presets::{CameraPreset, CameraPresetEvent};
+use crate::game_engine::zones::Zone;
+use crate::input::bindings::{stick_direction, update_action_input};
+use crate::input::gameplay::{cycle_focused_player, cycle_focused_zone, cycle_index};
+use crate::input::hints::{GAME_HINTS, MENU_HINTS, hint_line};
+use crate::input::navigation::{first_focus, next_focus};
+use crate::input::{ActionInput, InputAction, InputBindings, LastInputDevice};
+use crate::player::Player;
+use crate::player::playmat::{PlaymatZone, ZoneFocusState};
+
+fn entity(index: u32) -> Entity {
+    Entity::from_raw(index)
+}
+
+#[test]
+fn test_stick_needs_to_pass_threshold() {
+    assert_eq!(stick_direction(Vec2::new(0.3, 0.2)), None);
+    assert_eq!(
+        stick_direction(Vec2::new(0.9, 0.1)),
+        Some(InputAction::Right)
+    );
+    assert_eq!(
+        stick_direction(Vec2::new(-0.9, 0.3)),
+        Some(InputAction::Left)
+    );
+    // Stick y points up, unlike UI coordinates
+    assert_eq!(stick_direction(Vec2::new(0.1, 0.8)), Some(InputAction::Up));
+    assert_eq!(
+        stick_direction(Vec2::new(0.2, -0.7)),
+        Some(InputAction::Down)
+    );
+}
+
+#[test]
+fn test_focus_moves_to_nearest_button_in_direction() {
+    // A column of three buttons with a fourth off to the right of the middle one
+    let buttons = [
+        (entity(1), Vec2::new(100.0, 100.0)),
+        (entity(2), Vec2::new(100.0, 200.0)),
+        (entity(3), Vec2::new(100.0, 300.0)),
+        (entity(4), Vec2::new(300.0, 210.0)),
+    ];
+
+    assert_eq!(first_focus(&buttons), Some(entity(1)));
+    assert_eq!(next_focus(buttons[0].1, &buttons, Vec2::Y), Some(entity(2)));
+    assert_eq!(next_focus(buttons[1].1, &buttons, Vec2::X), Some(entity(4)));
+    assert_eq!(
+        next_focus(buttons[1].1, &buttons, Vec2::NEG_Y),
+        Some(entity(1))
+    );
+}
+
+#[test]
+fn test_focus_wraps_around_at_the_edge() {
+    let buttons = [
+        (entity(1), Vec2::new(100.0, 100.0)),
+        (entity(2), Vec2::new(100.0, 200.0)),
+        (entity(3), Vec2::new(100.0, 300.0)),
+    ];
+
+    assert_eq!(next_focus(buttons[2].1, &buttons, Vec2::Y), Some(entity(1)));
+    assert_eq!(
+        next_focus(buttons[0].1, &buttons, Vec2::NEG_Y),
+        Some(entity(3))
+    );
+    // Nothing to the side, so focus stays put
+    assert_eq!(next_focus(buttons[0].1, &buttons, Vec2::X), None);
+}
+
+#[test]
+fn test_cycling_wraps_both_ways() {
+    assert_eq!(cycle_index(0, None, true), None);
+    assert_eq!(cycle_index(4, None, true), Some(0));
+    assert_eq!(cycle_index(4, None, false), Some(3));
+    assert_eq!(cycle_index(4, Some(3), true), Some(0));
+    assert_eq!(cycle_index(4, Some(0), false), Some(3));
+}
+
+#[test]
+fn test_hints_use_bound_buttons() {
+    let mut bindings = InputBindings::default();
+    assert_eq!(
+        hint_line(&bindings, MENU_HINTS),
+        "[D-Pad] Move   [A] Select   [B] Back"
+    );
+    assert_eq!(
+        hint_line(&bindings, GAME_HINTS),
+        "[B] Pass priority   [LB/RB] Players   [LT/RT] Zones   [Menu] Pause"
+    );
+
+    // Rebinding is reflected in the hints
+    bindings.bindings.clear();
+    bindings.bind_button(InputAction::Confirm, GamepadButton::East);
+    assert_eq!(hint_line(&bindings, MENU_HINTS), "[B] Select");
+}
+
+fn input_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<InputBindings>()
+        .init_resource::<ActionInput>()
+        .init_resource::<LastInputDevice>()
+        .init_resource::<ButtonInput<KeyCode>>()
+        .add_systems(Update, update_action_input);
+    app
+}
+
+#[test]
+fn test_keys_press_their_actions() {
+    let mut app = input_app();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::Escape);
+    app.update();
+
+    let actions = app.world().resource::<ActionInput>();
+    assert!(actions.just_pressed(InputAction::Cancel));
+    assert!(actions.just_pressed(InputAction::Pause));
+    assert!(!actions.pressed(InputAction::Confirm));
+
+    // Held keys stay pressed without being pressed again
+    app.update();
+    let actions = app.world().resource::<ActionInput>();
+    assert!(actions.pressed(InputAction::Cancel));
+    assert!(!actions.just_pressed(InputAction::Cancel));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(KeyCode::Escape);
+    app.update();
+    let actions = app.world().resource::<ActionInput>();
+    assert!(actions.just_released(InputAction::Cancel));
+    assert_eq!(
+        *app.world().resource::<LastInputDevice>(),
+        LastInputDevice::KeyboardMouse
+    );
+}
+
+fn table_app() -> (App, Vec<Entity>) {
+    let mut app = App::new();
+    app.init_resource::<ActionInput>()
+        .init_resource::<ZoneFocusState>()
+        .add_event::<CameraPresetEvent>()
+        .add_systems(Update, (cycle_focused_player, cycle_focused_zone));
+
+    let players: Vec<Entity> = (0..3)
+        .map(|index| {
+            let mut player = Player::new(&format!("Player {}", index + 1));
+            player.player_index = index;
+            let entity = app.world_mut().spawn(player).id();
+            for zone_type in [Zone::Hand, Zone::Battlefield, Zone::Graveyard] {
+                app.world_mut().spawn(PlaymatZone {
+                    player_id: entity,
+                    zone_type,
+                });
+            }
+            entity
+        })
+        .collect();
+    (app, players)
+}
+
+fn press(app: &mut App, action: InputAction) {
+    let mut actions = app.world_mut().resource_mut::<ActionInput>();
+    actions.clear();
+    actions.release_all();
+    actions.press(action);
+}
+
+#[test]
+fn test_bumpers_cycle_players_and_move_the_camera() {
+    let (mut app, players) = table_app();
+
+    press(&mut app, InputAction::NextPlayer);
+    app.update();
+
+    let focus = app.world().resource::<ZoneFocusState>();
+    assert_eq!(focus.focused_zone_owner, Some(players[1]));
+    assert_eq!(focus.focused_zone_type, Some(Zone::Battlefield));
+    assert!(focus.focused_zone.is_some());
+
+    let presets: Vec<CameraPreset> = app
+        .world_mut()
+        .resource_mut::<Events<CameraPresetEvent>>()
+        .drain()
+        .map(|event| event.0)
+        .collect();
+    assert_eq!(presets, vec![CameraPreset::FocusPlayer(1)]);
+
+    // Going back past the local player wraps around to the last seat
+    press(&mut app, InputAction::PreviousPlayer);
+    app.update();
+    press(&mut app, InputAction::PreviousPlayer);
+    app.update();
+    assert_eq!(
+        app.world().resource::<ZoneFocusState>().focused_zone_owner,
+        Some(players[2])
+    );
+}
+
+#[test]
+fn test_triggers_cycle_the_focused_players_zones() {
+    let (mut app, players) = table_app();
+
+    // With no one focused, zones cycle on the local player's board
+    press(&mut app, InputAction::NextZone);
+    app.update();
+    let focus = app.world().resource::<ZoneFocusState>();
+    assert_eq!(focus.focused_zone_owner, Some(players[0]));
+    assert_eq!(focus.focused_zone_type, Some(Zone::Hand));
+
+    // Zones without a playmat area are skipped
+    press(&mut app, InputAction::PreviousZone);
+    app.update();
+    assert_eq!(
+        app.world().resource::<ZoneFocusState>().focused_zone_type,
+        Some(Zone::Graveyard)
+    );
+}
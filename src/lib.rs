@@ -10,19 +10,28 @@
 /// - Player interactions
 /// - Rules enforcement
 /// - Full Commander game rules implementation
+pub mod audio;
+pub mod benchmarks;
 pub mod camera;
 pub mod cards;
+pub mod cosmetics;
 pub mod deck;
 pub mod game_engine;
+pub mod hud;
+pub mod input;
 pub mod mana;
 pub mod menu;
+pub mod narration;
 pub mod networking;
+pub mod platform;
 pub mod player;
 pub mod plugins;
 pub mod snapshot;
+pub mod stats;
 pub mod tests;
 pub mod text;
 pub mod tracing;
+pub mod tutorial;
 pub mod utils;
 pub mod wsl2;
 
@@ -1,34 +1,50 @@
 #![allow(dead_code)] // Allow dead code globally for now
 #![feature(trivial_bounds)]
 
+mod audio;
+mod benchmarks;
 mod camera;
 mod cards;
+mod cosmetics;
 mod deck;
 mod game_engine;
+mod hud;
+mod input;
 mod mana;
 mod menu;
+mod narration;
 mod networking;
+mod platform;
 mod player;
 mod plugins;
 mod snapshot;
+mod stats;
 mod text;
 mod tracing;
+mod tutorial;
 mod utils;
 mod wsl2;
 
+use audio::RummageAudioPlugin;
 use bevy::DefaultPlugins;
 use bevy::audio::AudioPlugin;
 use bevy::audio::Volume;
 use bevy::log::Level;
 use bevy::prelude::*;
 use bevy::time::Fixed;
-use bevy::window::{PresentMode /* , WindowTheme */};
 use camera::CameraPlugin;
+use cosmetics::CosmeticsPlugin;
+use hud::HudPlugin;
+use input::ActionInputPlugin;
 use menu::MenuPlugin;
+use narration::NarrationPlugin;
+use networking::NetworkingPlugin;
 use plugins::RummagePlugin;
 #[cfg(feature = "snapshot")]
 use snapshot::SnapshotDisabled;
+use stats::StatsPlugin;
 use tracing::DiagnosticsPlugin;
+use tutorial::TutorialPlugin;
 
 #[cfg(debug_assertions)]
 use bevy_inspector_egui::bevy_egui::EguiPlugin;
@@ -36,6 +52,13 @@ use bevy_inspector_egui::bevy_egui::EguiPlugin;
 use bevy_inspector_egui::quick::WorldInspectorPlugin;
 
 fn main() {
+    // `--benchmark` runs the benchmark suite instead of the game
+    let args: Vec<String> = std::env::args().collect();
+    if args.iter().any(|arg| arg == "--benchmark") {
+        let save_baseline = args.iter().any(|arg| arg == "--save-baseline");
+        std::process::exit(benchmarks::run_benchmark_mode(save_baseline));
+    }
+
     println!("Starting Rummage application...");
 
     let mut app = App::new();
@@ -46,41 +69,11 @@ fn main() {
     app.add_plugins(
         DefaultPlugins
             .set(WindowPlugin {
-                primary_window: Some(Window {
-                    title: "Rummage - Commander Card Game".to_string(),
-                    resolution: (1280.0, 720.0).into(),
-                    position: WindowPosition::Centered(MonitorSelection::Current),
-                    resizable: true,
-                    present_mode: PresentMode::AutoVsync,
-                    prevent_default_event_handling: false,
-                    enabled_buttons: bevy::window::EnabledButtons {
-                        maximize: false,
-                        ..default()
-                    },
-                    visible: true,
-                    ..default()
-                }),
-                ..default()
-            })
-            .set(bevy::render::RenderPlugin {
-                // Configure rendering to be more resilient in WSL2 environments
-                render_creation: bevy::render::settings::RenderCreation::Automatic(
-                    bevy::render::settings::WgpuSettings {
-                        // Prefer Vulkan backend for better WSL2 compatibility
-                        backends: Some(bevy::render::settings::Backends::VULKAN),
-                        // Use low power preference for better WSL2 compatibility
-                        // power_preference: bevy::render::settings::PowerPreference::LowPower,
-                        // Don't require all features, adapt to what's available in WSL2
-                        // features: bevy::render::settings::WgpuFeatures::empty(),
-                        // Add more conservative options for WSL2 compatibility
-                        // dx12_shader_compiler: bevy::render::settings::Dx12Compiler::Fxc,
-                        ..default()
-                    },
-                ),
-                // Don't wait for pipelines to compile, which can hang under certain conditions
-                synchronous_pipeline_compilation: false,
+                primary_window: Some(platform::primary_window()),
                 ..default()
             })
+            .set(platform::render_plugin())
+            .set(platform::asset_plugin())
             .set(bevy::log::LogPlugin {
                 level: Level::DEBUG,
                 filter: "wgpu=error,bevy_render=info,bevy_app=debug,rummage=debug,khronos_egl=warn"
@@ -96,7 +89,15 @@ fn main() {
     .add_plugins(DiagnosticsPlugin) // Add our diagnostics plugin
     .add_plugins(CameraPlugin) // Add the camera plugin which manages SnapshotEvent
     .add_plugins(MenuPlugin)
-    .add_plugins(RummagePlugin);
+    .add_plugins(ActionInputPlugin)
+    .add_plugins(RummageAudioPlugin)
+    .add_plugins(NarrationPlugin)
+    .add_plugins(RummagePlugin)
+    .add_plugins(HudPlugin)
+    .add_plugins(CosmeticsPlugin)
+    .add_plugins(StatsPlugin)
+    .add_plugins(TutorialPlugin)
+    .add_plugins(NetworkingPlugin);
     // Add debug logging for audio system
     info!("Audio system initialized with DefaultPlugins");
 
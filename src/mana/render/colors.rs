@@ -70,3 +70,100 @@ pub fn is_dark_background(symbol: &str, _color: &Color) -> bool {
         }
     }
 }
+
+/// Returns a colorblind-safe color for a mana symbol
+///
+/// Colored mana uses the Okabe-Ito palette, whose hues stay apart under the
+/// common kinds of color blindness. Other symbols keep their usual colors.
+pub fn get_colorblind_mana_symbol_color(symbol: &str) -> Color {
+    match symbol.trim() {
+        "{W}" => Color::srgb(0.94, 0.89, 0.26), // Yellow
+        "{U}" => Color::srgb(0.0, 0.45, 0.70),  // Blue
+        "{B}" => Color::srgb(0.0, 0.0, 0.0),    // Black
+        "{R}" => Color::srgb(0.84, 0.37, 0.0),  // Vermillion
+        "{G}" => Color::srgb(0.0, 0.62, 0.45),  // Bluish green
+        other => get_mana_symbol_color(other),
+    }
+}
+
+/// Returns the color for a mana symbol in the standard or colorblind palette
+pub fn mana_symbol_color(symbol: &str, colorblind: bool) -> Color {
+    if colorblind {
+        get_colorblind_mana_symbol_color(symbol)
+    } else {
+        get_mana_symbol_color(symbol)
+    }
+}
+
+/// Fill pattern telling colored mana apart without relying on color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManaPattern {
+    Dots,
+    HorizontalStripes,
+    Checkered,
+    VerticalStripes,
+    Grid,
+}
+
+/// Returns the pattern for a colored mana symbol, or `None` for other symbols
+pub fn get_mana_pattern(symbol: &str) -> Option<ManaPattern> {
+    match symbol.trim() {
+        "{W}" => Some(ManaPattern::Dots),
+        "{U}" => Some(ManaPattern::HorizontalStripes),
+        "{B}" => Some(ManaPattern::Checkered),
+        "{R}" => Some(ManaPattern::VerticalStripes),
+        "{G}" => Some(ManaPattern::Grid),
+        _ => None,
+    }
+}
+
+/// Rectangles drawing a pattern inside a square swatch, as `(center, size)`
+/// pairs relative to the center of the swatch
+pub fn mana_pattern_rects(pattern: ManaPattern, swatch: f32) -> Vec<(Vec2, Vec2)> {
+    let third = swatch / 3.0;
+    let line = swatch / 8.0;
+    let offsets = [-third, 0.0, third];
+
+    match pattern {
+        ManaPattern::Dots => offsets
+            .iter()
+            .flat_map(|&x| {
+                offsets
+                    .iter()
+                    .map(move |&y| (Vec2::new(x, y), Vec2::splat(line)))
+            })
+            .collect(),
+        ManaPattern::HorizontalStripes => offsets
+            .iter()
+            .map(|&y| (Vec2::new(0.0, y), Vec2::new(swatch, line)))
+            .collect(),
+        ManaPattern::VerticalStripes => offsets
+            .iter()
+            .map(|&x| (Vec2::new(x, 0.0), Vec2::new(line, swatch)))
+            .collect(),
+        ManaPattern::Checkered => {
+            let cell = swatch / 4.0;
+            (0..4)
+                .flat_map(|row| (0..4).map(move |column| (row, column)))
+                .filter(|(row, column)| (row + column) % 2 == 0)
+                .map(|(row, column)| {
+                    let center = Vec2::new(column as f32 + 0.5, row as f32 + 0.5) * cell
+                        - Vec2::splat(swatch / 2.0);
+                    (center, Vec2::splat(cell))
+                })
+                .collect()
+        }
+        ManaPattern::Grid => {
+            let quarter = swatch / 4.0;
+            [-quarter, quarter]
+                .into_iter()
+                .flat_map(|offset| {
+                    [
+                        (Vec2::new(0.0, offset), Vec2::new(swatch, line)),
+                        (Vec2::new(offset, 0.0), Vec2::new(line, swatch)),
+                    ]
+                })
+                .collect()
+        }
+    }
+}
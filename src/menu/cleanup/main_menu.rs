@@ -1,15 +1,14 @@
 use crate::menu::backgrounds::MenuBackground;
 use crate::menu::components::MenuItem;
 use crate::menu::components::MenuRoot;
-use crate::menu::main_menu::components::{MainMenuBackground, MainMenuMusic};
+use crate::menu::main_menu::components::MainMenuBackground;
 use bevy::prelude::*;
 
-/// Cleans up main menu entities including root, background, and music
+/// Cleans up main menu entities including root and background
 pub fn cleanup_main_menu(
     mut commands: Commands,
     menu_root_query: Query<Entity, With<MenuRoot>>,
     background_query: Query<Entity, With<MainMenuBackground>>,
-    music_query: Query<Entity, With<MainMenuMusic>>,
     _menu_items_query: Query<Entity, With<MenuItem>>,
     _menu_backgrounds_query: Query<Entity, With<MenuBackground>>,
 ) {
@@ -36,12 +35,6 @@ pub fn cleanup_main_menu(
         commands.entity(entity).despawn();
     }
 
-    // Despawn music entities
-    for entity in music_query.iter() {
-        info!("Despawning main menu music entity: {:?}", entity);
-        commands.entity(entity).despawn();
-    }
-
     // Log the completion of cleanup
     info!("Main menu cleanup complete");
 }
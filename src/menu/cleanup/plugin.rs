@@ -1,4 +1,3 @@
-use crate::menu::main_menu::pause_main_menu_music_on_settings_enter;
 use crate::menu::state::GameMenuState;
 use bevy::prelude::*;
 
@@ -11,16 +10,19 @@ impl Plugin for CleanupPlugin {
             OnExit(GameMenuState::MainMenu),
             super::main_menu::cleanup_main_menu,
         )
-        .add_systems(
-            OnEnter(GameMenuState::Settings),
-            pause_main_menu_music_on_settings_enter,
-        )
         .add_systems(
             OnExit(GameMenuState::PauseMenu),
             super::pause_menu::cleanup_pause_menu,
         )
+        // Pausing keeps the game; it is cleaned up once the player leaves it
         .add_systems(
             OnExit(GameMenuState::InGame),
+            (super::game::cleanup_game, ApplyDeferred)
+                .chain()
+                .run_if(not(in_state(GameMenuState::PauseMenu))),
+        )
+        .add_systems(
+            OnEnter(GameMenuState::MainMenu),
             (super::game::cleanup_game, ApplyDeferred).chain(),
         );
 
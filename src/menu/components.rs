@@ -33,6 +33,10 @@ pub struct GameCamera;
 pub enum MenuButtonAction {
     /// Start a new game session
     NewGame,
+    /// Choose a puzzle scenario
+    Scenarios,
+    /// Start the interactive tutorial
+    Tutorial,
     /// Continue a previously saved game
     Continue,
     /// Load a previously saved game
@@ -51,6 +55,12 @@ pub enum MenuButtonAction {
     MainMenu,
     /// Save the current game
     SaveGame,
+    /// Roll back to a turn checkpoint
+    RestoreCheckpoint,
+    /// Save a framed screenshot of the board
+    ShareBoard,
+    /// Concede the current game
+    Concede,
     /// Show credits screen
     Credits,
 }
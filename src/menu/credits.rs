@@ -1,3 +1,4 @@
+use crate::input::{ActionInput, InputAction};
 use crate::menu::state::GameMenuState;
 use bevy::prelude::*;
 
@@ -19,15 +20,15 @@ pub fn setup_credits(_commands: Commands, _asset_server: Res<AssetServer>) {
     // TODO: Implement credits screen content
 }
 
-/// Handle ESC key press in credits screen
+/// Handle the Cancel action (ESC or B) in credits screen
 pub fn handle_credits_esc_key(
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<ActionInput>,
     state: Res<State<GameMenuState>>,
     mut next_state: ResMut<NextState<GameMenuState>>,
 ) {
-    // Only handle ESC key in credits state
-    if *state.get() == GameMenuState::Credits && keys.just_pressed(KeyCode::Escape) {
-        info!("ESC pressed in credits screen - returning to main menu");
+    // Only handle Cancel in credits state
+    if *state.get() == GameMenuState::Credits && actions.just_pressed(InputAction::Cancel) {
+        info!("Cancel pressed in credits screen - returning to main menu");
         next_state.set(GameMenuState::MainMenu);
     }
 }
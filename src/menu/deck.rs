@@ -1,16 +1,35 @@
 use bevy::prelude::*;
 
+use crate::camera::components::AppLayer;
+use crate::cards::mtgjson::loader::{SetLoadProgress, SetLoader};
+use crate::menu::components::ZLayers;
+
 /// Plugin for handling deck management functionality
 pub struct DeckManagerPlugin;
 
 impl Plugin for DeckManagerPlugin {
-    fn build(&self, _app: &mut App) {
-        // Deck management related systems will be added here
-        // For now, this is a placeholder implementation
+    fn build(&self, app: &mut App) {
+        // Show a progress bar while the deck builder's sets load in the background
+        app.add_systems(
+            Update,
+            update_set_loading_bar.run_if(resource_exists::<SetLoader>),
+        );
         info!("DeckManagerPlugin initialized");
     }
 }
 
+/// Root of the set loading progress bar
+#[derive(Component)]
+pub struct SetLoadingBar;
+
+/// The filled part of the set loading progress bar
+#[derive(Component)]
+pub struct SetLoadingBarFill;
+
+/// Text describing what is being loaded
+#[derive(Component)]
+pub struct SetLoadingBarLabel;
+
 /// Set up the deck manager UI
 #[allow(dead_code)]
 pub fn setup_deck_manager(_commands: Commands) {
@@ -24,3 +43,108 @@ pub fn handle_deck_action() {
     // Implementation for deck action handling will go here
     // This is currently a placeholder function
 }
+
+/// Show the progress of set loads, removing the bar once every set has loaded
+pub fn update_set_loading_bar(
+    mut commands: Commands,
+    loader: Res<SetLoader>,
+    mut progress: EventReader<SetLoadProgress>,
+    bars: Query<Entity, With<SetLoadingBar>>,
+    mut fills: Query<&mut Node, With<SetLoadingBarFill>>,
+    mut labels: Query<&mut Text, With<SetLoadingBarLabel>>,
+) {
+    if loader.is_idle() {
+        progress.clear();
+        for bar in bars.iter() {
+            commands.entity(bar).despawn();
+        }
+        return;
+    }
+
+    if progress.is_empty() && !bars.is_empty() {
+        return;
+    }
+    progress.clear();
+
+    // Several sets can load at once; the bar shows their average progress
+    let statuses: Vec<_> = loader.statuses().collect();
+    let fraction = statuses
+        .iter()
+        .map(|(_, status)| status.fraction)
+        .sum::<f32>()
+        / statuses.len() as f32;
+    let label = match statuses.as_slice() {
+        [(set_code, status)] => {
+            format!("{} {}... {:.0}%", status.stage, set_code, fraction * 100.0)
+        }
+        _ => format!(
+            "Loading {} sets... {:.0}%",
+            statuses.len(),
+            fraction * 100.0
+        ),
+    };
+
+    if bars.is_empty() {
+        spawn_set_loading_bar(&mut commands, fraction, label);
+        return;
+    }
+    for mut fill in fills.iter_mut() {
+        fill.width = Val::Percent(fraction * 100.0);
+    }
+    for mut text in labels.iter_mut() {
+        text.0 = label.clone();
+    }
+}
+
+fn spawn_set_loading_bar(commands: &mut Commands, fraction: f32, label: String) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(20.0),
+                left: Val::Percent(25.0),
+                width: Val::Percent(50.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            SetLoadingBar,
+            AppLayer::Menu.layer(),
+            ZIndex::from(ZLayers::MenuContainer),
+            Name::new("Set Loading Bar"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                SetLoadingBarLabel,
+                AppLayer::Menu.layer(),
+            ));
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(12.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+                    AppLayer::Menu.layer(),
+                ))
+                .with_children(|track| {
+                    track.spawn((
+                        Node {
+                            width: Val::Percent(fraction * 100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.3, 0.6, 0.3)),
+                        SetLoadingBarFill,
+                        AppLayer::Menu.layer(),
+                    ));
+                });
+        });
+}
@@ -1,16 +1,33 @@
 use bevy::prelude::*;
 
+use crate::menu::state::AppState;
+
 /// Marker component for input blockers
 #[derive(Component, Debug, Reflect)]
 pub struct InputBlocker;
 
 /// Resource to track input blocking state
-#[derive(Resource, Default, Debug)]
+#[derive(Resource, Default, Debug, PartialEq, Eq)]
 pub struct InteractionBlockState {
     /// Whether interaction should be blocked
     pub should_block: bool,
 }
 
+/// Block interaction with the game while an input blocker is open or the game is paused
+///
+/// Runs before `Update` so that game input systems see the new state in the
+/// same frame the blocker appears.
+pub fn update_interaction_block(
+    blockers: Query<(), With<InputBlocker>>,
+    app_state: Option<Res<State<AppState>>>,
+    mut block_state: ResMut<InteractionBlockState>,
+) {
+    let paused = app_state.is_some_and(|state| *state.get() == AppState::Paused);
+    block_state.set_if_neq(InteractionBlockState {
+        should_block: paused || !blockers.is_empty(),
+    });
+}
+
 /// A simple plugin for handling input blocking
 #[derive(Default)]
 pub struct InputBlockerPlugin;
@@ -18,7 +35,8 @@ pub struct InputBlockerPlugin;
 impl Plugin for InputBlockerPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<InputBlocker>()
-            .init_resource::<InteractionBlockState>();
+            .init_resource::<InteractionBlockState>()
+            .add_systems(PreUpdate, update_interaction_block);
 
         info!("InputBlocker plugin registered");
     }
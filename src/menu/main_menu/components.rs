@@ -15,7 +15,3 @@ pub struct MainMenuBackground;
 /// Marker component for main menu buttons
 #[derive(Component, Debug, Clone)]
 pub struct MainMenuButton;
-
-/// Component to mark the main menu music entity
-#[derive(Component)]
-pub struct MainMenuMusic;
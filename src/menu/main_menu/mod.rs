@@ -7,6 +7,3 @@ pub mod systems;
 
 // Export the plugin for use in the menu system
 pub use plugin::MainMenuPlugin;
-
-// Export components needed by other modules
-pub use cleanup::{/* cleanup_main_menu, */ pause_main_menu_music_on_settings_enter};
@@ -1,8 +1,8 @@
 use crate::menu::{
     components::MenuButtonAction, save_load::SaveLoadUiContext, save_load::SaveLoadUiState,
     settings::state::SettingsMenuState,
-    settings::systems::state_transitions::handle_settings_enter, state::AppState,
-    state::GameMenuState, state::StateTransitionContext,
+    settings::systems::state_transitions::handle_settings_enter, state::GameMenuState,
+    state::StateTransitionContext,
 };
 use bevy::prelude::*;
 
@@ -22,7 +22,6 @@ type MainMenuButtonInteractionQuery<'w, 's> = Query<
 pub fn handle_main_menu_interactions(
     mut interaction_query: MainMenuButtonInteractionQuery,
     mut next_state: ResMut<NextState<GameMenuState>>,
-    mut app_state: ResMut<NextState<AppState>>,
     mut settings_state: ResMut<NextState<SettingsMenuState>>,
     mut context: ResMut<StateTransitionContext>,
     mut exit: EventWriter<bevy::app::AppExit>,
@@ -36,8 +35,15 @@ pub fn handle_main_menu_interactions(
                 match action {
                     MenuButtonAction::NewGame => {
                         info!("New Game button pressed");
-                        next_state.set(GameMenuState::InGame);
-                        app_state.set(AppState::InGame);
+                        next_state.set(GameMenuState::NewGame);
+                    }
+                    MenuButtonAction::Scenarios => {
+                        info!("Scenarios button pressed");
+                        next_state.set(GameMenuState::Scenarios);
+                    }
+                    MenuButtonAction::Tutorial => {
+                        // The lesson itself is started by the tutorial plugin
+                        info!("Tutorial button pressed");
                     }
                     MenuButtonAction::LoadGame => {
                         info!("Load Game button pressed");
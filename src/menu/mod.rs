@@ -8,9 +8,11 @@ pub mod decorations;
 pub mod input_blocker;
 pub mod logo;
 pub mod main_menu;
+pub mod new_game;
 pub mod pause;
 pub mod plugin;
 pub mod save_load;
+pub mod scenarios;
 pub mod settings;
 pub mod star_of_david;
 pub mod state;
@@ -20,5 +22,8 @@ pub mod systems;
 pub mod ui;
 pub mod visibility;
 
+#[cfg(test)]
+mod tests;
+
 pub use plugin::MenuPlugin;
 pub use state::*;
@@ -0,0 +1,172 @@
+use bevy::prelude::*;
+
+use crate::deck::{COMMANDER_BAN_LIST_NAME, random_precons};
+use crate::game_engine::setup::{GameSetupConfig, MAX_PLAYERS, MIN_PLAYERS};
+
+/// Life gained or lost per press of the starting life buttons
+pub const STARTING_LIFE_STEP: i32 = 5;
+/// Highest starting life offered on the new game screen
+pub const MAX_STARTING_LIFE: i32 = 100;
+/// Highest commander damage threshold offered on the new game screen
+pub const MAX_COMMANDER_DAMAGE_THRESHOLD: u32 = 40;
+
+/// Marker for every entity belonging to the new game screen
+#[derive(Component, Debug, Clone, Copy)]
+pub struct NewGameScreen;
+
+/// Option shown on the new game screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetupField {
+    /// Number of players
+    PlayerCount,
+    /// Starting life total
+    StartingLife,
+    /// Commander damage threshold
+    CommanderDamageThreshold,
+    /// Commander damage house rule
+    CommanderDamage,
+    /// Mana outside a commander's color identity becoming colorless house rule
+    ColorlessOutsideIdentity,
+    /// Whether the player going first draws on their first turn
+    FirstTurnDraw,
+    /// How strictly the rules are enforced
+    RulesEnforcement,
+    /// Ban list decks are checked against
+    BanList,
+    /// Default decks or bundled precons
+    Decks,
+}
+
+impl SetupField {
+    /// Text describing the current value of this option
+    pub fn value_text(&self, config: &GameSetupConfig) -> String {
+        let on_off = |value: bool| if value { "On" } else { "Off" }.to_string();
+        match self {
+            SetupField::PlayerCount => config.player_count.to_string(),
+            SetupField::StartingLife => config.starting_life.to_string(),
+            SetupField::CommanderDamageThreshold => config.commander_damage_threshold.to_string(),
+            SetupField::CommanderDamage => on_off(config.house_rules.commander_damage),
+            SetupField::ColorlessOutsideIdentity => {
+                on_off(config.house_rules.colorless_outside_identity)
+            }
+            SetupField::FirstTurnDraw => config.first_turn_draw.label().to_string(),
+            SetupField::RulesEnforcement => config.rules_enforcement.label().to_string(),
+            SetupField::BanList => config
+                .ban_list
+                .as_deref()
+                .unwrap_or(COMMANDER_BAN_LIST_NAME)
+                .to_string(),
+            SetupField::Decks => if config.precons.is_empty() {
+                "Default"
+            } else {
+                "Random Precons"
+            }
+            .to_string(),
+        }
+    }
+}
+
+/// Text showing the current value of a setup option
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetupValueText(pub SetupField);
+
+/// Text listing the cards in each player's deck that the selected ban list forbids
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeckCheckText;
+
+/// Buttons on the new game screen
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewGameAction {
+    /// Remove a player
+    DecreasePlayers,
+    /// Add a player
+    IncreasePlayers,
+    /// Lower the starting life
+    DecreaseLife,
+    /// Raise the starting life
+    IncreaseLife,
+    /// Lower the commander damage threshold
+    DecreaseThreshold,
+    /// Raise the commander damage threshold
+    IncreaseThreshold,
+    /// Turn commander damage on or off
+    ToggleCommanderDamage,
+    /// Turn colorless mana outside color identity on or off
+    ToggleColorlessOutsideIdentity,
+    /// Switch to the next rule for drawing on the first turn
+    CycleFirstTurnDraw,
+    /// Switch to the next rules enforcement level
+    CycleRulesEnforcement,
+    /// Switch to the next ban list, which depends on the house lists in the settings
+    CycleBanList,
+    /// Give every player a random precon
+    RandomDecks,
+    /// Give every player the default deck
+    DefaultDecks,
+    /// Start the game with the chosen options
+    StartGame,
+    /// Test the first player's deck alone, see [`crate::game_engine::goldfish`]
+    Goldfish,
+    /// Return to the main menu
+    Back,
+}
+
+impl NewGameAction {
+    /// Apply this action to the setup options
+    ///
+    /// Returns `false` for actions that do not change the options.
+    pub fn apply(&self, config: &mut GameSetupConfig) -> bool {
+        match self {
+            NewGameAction::DecreasePlayers | NewGameAction::IncreasePlayers => {
+                config.player_count = if *self == NewGameAction::DecreasePlayers {
+                    config.player_count.saturating_sub(1).max(MIN_PLAYERS)
+                } else {
+                    (config.player_count + 1).min(MAX_PLAYERS)
+                };
+            }
+            NewGameAction::DecreaseLife => {
+                config.starting_life =
+                    (config.starting_life - STARTING_LIFE_STEP).max(STARTING_LIFE_STEP);
+            }
+            NewGameAction::IncreaseLife => {
+                config.starting_life =
+                    (config.starting_life + STARTING_LIFE_STEP).min(MAX_STARTING_LIFE);
+            }
+            NewGameAction::DecreaseThreshold => {
+                config.commander_damage_threshold =
+                    config.commander_damage_threshold.saturating_sub(1).max(1);
+            }
+            NewGameAction::IncreaseThreshold => {
+                config.commander_damage_threshold =
+                    (config.commander_damage_threshold + 1).min(MAX_COMMANDER_DAMAGE_THRESHOLD);
+            }
+            NewGameAction::ToggleCommanderDamage => {
+                config.house_rules.commander_damage = !config.house_rules.commander_damage;
+            }
+            NewGameAction::ToggleColorlessOutsideIdentity => {
+                config.house_rules.colorless_outside_identity =
+                    !config.house_rules.colorless_outside_identity;
+            }
+            NewGameAction::CycleFirstTurnDraw => {
+                config.first_turn_draw = config.first_turn_draw.next();
+            }
+            NewGameAction::CycleRulesEnforcement => {
+                config.rules_enforcement = config.rules_enforcement.next();
+            }
+            NewGameAction::RandomDecks => {
+                // Every seat gets one, so changing the player count keeps them
+                config.precons = random_precons(MAX_PLAYERS);
+            }
+            NewGameAction::DefaultDecks => {
+                config.precons.clear();
+            }
+            NewGameAction::CycleBanList
+            | NewGameAction::StartGame
+            | NewGameAction::Goldfish
+            | NewGameAction::Back => {
+                return false;
+            }
+        }
+        true
+    }
+}
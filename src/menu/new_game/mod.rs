@@ -0,0 +1,19 @@
+//! New game screen
+//!
+//! Shown between the main menu and the game. Lets the player choose the player
+//! count, starting life, commander damage threshold, house rules and ban
+//! list, which are stored in [`GameSetupConfig`](crate::game_engine::setup::GameSetupConfig)
+//! and read when the game starts. Players get the default deck unless the Random
+//! Deck button picks one of the bundled [precons](crate::deck::PRECON_DECKS) for
+//! each of them. Each player's deck is checked against the selected ban list and
+//! any violations are listed below the options.
+
+pub mod components;
+pub mod plugin;
+pub mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{DeckCheckText, NewGameAction, NewGameScreen, SetupField, SetupValueText};
+pub use plugin::NewGamePlugin;
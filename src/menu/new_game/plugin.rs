@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+use crate::game_engine::setup::GameSetupConfig;
+use crate::menu::camera::setup::{cleanup_menu_camera, setup_menu_camera};
+use crate::menu::settings::systems::despawn_screen;
+use crate::menu::state::GameMenuState;
+
+use super::components::NewGameScreen;
+use super::systems::{
+    handle_new_game_esc_key, new_game_button_interaction, setup_new_game_screen,
+    update_deck_check_text, update_setup_value_texts,
+};
+
+/// Plugin for the new game screen
+pub struct NewGamePlugin;
+
+impl Plugin for NewGamePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameSetupConfig>()
+            .add_systems(
+                OnEnter(GameMenuState::NewGame),
+                (setup_menu_camera, setup_new_game_screen),
+            )
+            .add_systems(
+                OnExit(GameMenuState::NewGame),
+                (despawn_screen::<NewGameScreen>, cleanup_menu_camera),
+            )
+            .add_systems(
+                Update,
+                (
+                    new_game_button_interaction,
+                    update_setup_value_texts.run_if(resource_changed::<GameSetupConfig>),
+                    update_deck_check_text,
+                    handle_new_game_esc_key,
+                )
+                    .chain()
+                    .run_if(in_state(GameMenuState::NewGame)),
+            );
+
+        info!("NewGamePlugin initialized");
+    }
+}
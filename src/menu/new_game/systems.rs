@@ -0,0 +1,448 @@
+use bevy::prelude::*;
+use bevy::text::JustifyText;
+
+use crate::camera::components::AppLayer;
+use crate::deck::{PreconDeck, get_player_deck};
+use crate::game_engine::goldfish::PendingGoldfish;
+use crate::game_engine::setup::GameSetupConfig;
+use crate::input::{ActionInput, InputAction};
+use crate::menu::components::ZLayers;
+use crate::menu::settings::components::BanListSettings;
+use crate::menu::state::{AppState, GameMenuState};
+
+use super::components::{DeckCheckText, NewGameAction, NewGameScreen, SetupField, SetupValueText};
+
+const BUTTON_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+const HOVERED_BUTTON_COLOR: Color = Color::srgb(0.25, 0.25, 0.25);
+const PRESSED_BUTTON_COLOR: Color = Color::srgb(0.35, 0.35, 0.35);
+
+/// Most banned cards listed per deck before the rest are summarized
+const MAX_LISTED_BANNED_CARDS: usize = 5;
+
+/// Spawns the new game screen showing the current setup options
+pub fn setup_new_game_screen(
+    mut commands: Commands,
+    config: Res<GameSetupConfig>,
+    ban_lists: Res<BanListSettings>,
+) {
+    info!("Setting up new game screen");
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            NewGameScreen,
+            AppLayer::Menu.layer(),
+            ZIndex::from(ZLayers::Background),
+            Name::new("New Game Screen"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("New Game"),
+                TextFont {
+                    font_size: 35.0,
+                    ..default()
+                },
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextColor(Color::WHITE),
+                AppLayer::Menu.layer(),
+                Name::new("New Game Title"),
+            ));
+
+            spawn_stepper_row(
+                parent,
+                &config,
+                "Players",
+                SetupField::PlayerCount,
+                NewGameAction::DecreasePlayers,
+                NewGameAction::IncreasePlayers,
+            );
+            spawn_stepper_row(
+                parent,
+                &config,
+                "Starting Life",
+                SetupField::StartingLife,
+                NewGameAction::DecreaseLife,
+                NewGameAction::IncreaseLife,
+            );
+            spawn_stepper_row(
+                parent,
+                &config,
+                "Commander Damage",
+                SetupField::CommanderDamageThreshold,
+                NewGameAction::DecreaseThreshold,
+                NewGameAction::IncreaseThreshold,
+            );
+            spawn_toggle_row(
+                parent,
+                &config,
+                "Commander Damage Losses",
+                SetupField::CommanderDamage,
+                NewGameAction::ToggleCommanderDamage,
+            );
+            spawn_toggle_row(
+                parent,
+                &config,
+                "Legacy Identity Mana",
+                SetupField::ColorlessOutsideIdentity,
+                NewGameAction::ToggleColorlessOutsideIdentity,
+            );
+            spawn_toggle_row(
+                parent,
+                &config,
+                "First Turn Draw",
+                SetupField::FirstTurnDraw,
+                NewGameAction::CycleFirstTurnDraw,
+            );
+            spawn_toggle_row(
+                parent,
+                &config,
+                "Rules Enforcement",
+                SetupField::RulesEnforcement,
+                NewGameAction::CycleRulesEnforcement,
+            );
+            spawn_toggle_row(
+                parent,
+                &config,
+                "Ban List",
+                SetupField::BanList,
+                NewGameAction::CycleBanList,
+            );
+            spawn_row(parent, "Decks", |row| {
+                spawn_value_text(row, &config, SetupField::Decks);
+                spawn_button(row, "Default", NewGameAction::DefaultDecks, 110.0);
+                spawn_button(row, "Random Deck", NewGameAction::RandomDecks, 150.0);
+            });
+
+            parent.spawn((
+                Text::new(deck_check_summary(&config, &ban_lists)),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextColor(Color::srgb(0.9, 0.75, 0.4)),
+                Node {
+                    max_width: Val::Px(520.0),
+                    ..default()
+                },
+                DeckCheckText,
+                AppLayer::Menu.layer(),
+                Name::new("Deck Check Text"),
+            ));
+
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(20.0),
+                        margin: UiRect::top(Val::Px(20.0)),
+                        ..default()
+                    },
+                    AppLayer::Menu.layer(),
+                    Name::new("New Game Actions"),
+                ))
+                .with_children(|row| {
+                    spawn_button(row, "Back", NewGameAction::Back, 150.0);
+                    spawn_button(row, "Goldfish", NewGameAction::Goldfish, 150.0);
+                    spawn_button(row, "Start Game", NewGameAction::StartGame, 150.0);
+                });
+        });
+}
+
+/// Spawns a labelled row with - and + buttons around the current value
+fn spawn_stepper_row(
+    parent: &mut ChildSpawnerCommands,
+    config: &GameSetupConfig,
+    label: &str,
+    field: SetupField,
+    decrease: NewGameAction,
+    increase: NewGameAction,
+) {
+    spawn_row(parent, label, |row| {
+        spawn_button(row, "-", decrease, 40.0);
+        spawn_value_text(row, config, field);
+        spawn_button(row, "+", increase, 40.0);
+    });
+}
+
+/// Spawns a labelled row with a single button showing the current value
+fn spawn_toggle_row(
+    parent: &mut ChildSpawnerCommands,
+    config: &GameSetupConfig,
+    label: &str,
+    field: SetupField,
+    toggle: NewGameAction,
+) {
+    spawn_row(parent, label, |row| {
+        row.spawn((
+            Button,
+            Node {
+                width: Val::Px(200.0),
+                height: Val::Px(40.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(BUTTON_COLOR),
+            toggle,
+            AppLayer::Menu.layer(),
+            ZIndex::from(ZLayers::MenuButtons),
+            Name::new(format!("{} Button", label)),
+        ))
+        .with_children(|button| spawn_value_text(button, config, field));
+    });
+}
+
+fn spawn_row(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    controls: impl FnOnce(&mut ChildSpawnerCommands),
+) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(520.0),
+                height: Val::Px(50.0),
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            AppLayer::Menu.layer(),
+            ZIndex::from(ZLayers::MenuContainer),
+            Name::new(format!("{} Row", label)),
+        ))
+        .with_children(|row| {
+            row.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                AppLayer::Menu.layer(),
+            ));
+            row.spawn((
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                },
+                AppLayer::Menu.layer(),
+            ))
+            .with_children(controls);
+        });
+}
+
+fn spawn_value_text(
+    parent: &mut ChildSpawnerCommands,
+    config: &GameSetupConfig,
+    field: SetupField,
+) {
+    parent.spawn((
+        Text::new(field.value_text(config)),
+        TextFont {
+            font_size: 22.0,
+            ..default()
+        },
+        TextLayout::new_with_justify(JustifyText::Center),
+        TextColor(Color::WHITE),
+        SetupValueText(field),
+        AppLayer::Menu.layer(),
+        ZIndex::from(ZLayers::MenuButtonText),
+    ));
+}
+
+fn spawn_button(parent: &mut ChildSpawnerCommands, text: &str, action: NewGameAction, width: f32) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(width),
+                height: Val::Px(40.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(BUTTON_COLOR),
+            action,
+            AppLayer::Menu.layer(),
+            ZIndex::from(ZLayers::MenuButtons),
+            Name::new(format!("{} Button", text)),
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(text),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                AppLayer::Menu.layer(),
+                ZIndex::from(ZLayers::MenuButtonText),
+            ));
+        });
+}
+
+/// Type alias for the query used in `new_game_button_interaction`.
+type NewGameButtonInteractionQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Interaction,
+        &'static NewGameAction,
+        &'static mut BackgroundColor,
+    ),
+    (Changed<Interaction>, With<Button>),
+>;
+
+/// Handles presses on the new game screen buttons
+pub fn new_game_button_interaction(
+    mut commands: Commands,
+    mut interaction_query: NewGameButtonInteractionQuery,
+    mut config: ResMut<GameSetupConfig>,
+    ban_lists: Res<BanListSettings>,
+    mut next_state: ResMut<NextState<GameMenuState>>,
+    mut app_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, action, mut background_color) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                match action {
+                    NewGameAction::StartGame => {
+                        info!("Starting game with setup {:?}", *config);
+                        *config = config.validated();
+                        next_state.set(GameMenuState::InGame);
+                        app_state.set(AppState::InGame);
+                    }
+                    NewGameAction::Goldfish => {
+                        let deck = get_player_deck(0, None);
+                        info!("Goldfishing {}", deck.name);
+                        *config = config.validated();
+                        commands.insert_resource(PendingGoldfish(deck));
+                        next_state.set(GameMenuState::InGame);
+                        app_state.set(AppState::InGame);
+                    }
+                    NewGameAction::Back => {
+                        next_state.set(GameMenuState::MainMenu);
+                    }
+                    NewGameAction::CycleBanList => {
+                        config.ban_list = ban_lists.next_after(config.ban_list.as_deref());
+                    }
+                    _ => {
+                        action.apply(&mut config);
+                    }
+                }
+            }
+            Interaction::Hovered => *background_color = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_color = BUTTON_COLOR.into(),
+        }
+    }
+}
+
+/// Refreshes the value texts after the setup options change
+pub fn update_setup_value_texts(
+    config: Res<GameSetupConfig>,
+    mut texts: Query<(&SetupValueText, &mut Text)>,
+) {
+    for (value_text, mut text) in texts.iter_mut() {
+        text.0 = value_text.0.value_text(&config);
+    }
+}
+
+/// Describe which precon each player plays and which cards in each player's
+/// deck the selected ban list forbids
+///
+/// Violations are only reported; the game can still be started with them.
+pub fn deck_check_summary(config: &GameSetupConfig, ban_lists: &BanListSettings) -> String {
+    let ban_list = ban_lists.resolve(config.ban_list.as_deref());
+    let player_count = config.validated().player_count;
+    let precons: Vec<String> = (0..player_count)
+        .filter_map(|player_index| {
+            config
+                .precon_for(player_index)
+                .map(|precon| format!("Player {}: {}", player_index + 1, precon))
+        })
+        .collect();
+    let violations: Vec<String> = (0..player_count)
+        .filter_map(|player_index| {
+            let (name, banned) = match config.precon_for(player_index).and_then(PreconDeck::find) {
+                Some(precon) => (precon.name.to_string(), precon.banned_cards(&ban_list)),
+                None => {
+                    let deck = get_player_deck(player_index, None);
+                    let banned = deck.banned_cards(&ban_list);
+                    (deck.name, banned)
+                }
+            };
+            if banned.is_empty() {
+                return None;
+            }
+
+            let mut listed = banned
+                .iter()
+                .take(MAX_LISTED_BANNED_CARDS)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ");
+            if banned.len() > MAX_LISTED_BANNED_CARDS {
+                listed.push_str(&format!(
+                    " and {} more",
+                    banned.len() - MAX_LISTED_BANNED_CARDS
+                ));
+            }
+            Some(format!(
+                "{}: Banned on the {} list: {}",
+                name, ban_list.name, listed
+            ))
+        })
+        .collect();
+
+    let check = if violations.is_empty() {
+        format!("All decks pass the {} ban list", ban_list.name)
+    } else {
+        violations.join("\n")
+    };
+    if precons.is_empty() {
+        check
+    } else {
+        format!("{}\n{}", precons.join(", "), check)
+    }
+}
+
+/// Refreshes the deck check after the setup options or house ban lists change
+pub fn update_deck_check_text(
+    config: Res<GameSetupConfig>,
+    ban_lists: Res<BanListSettings>,
+    mut texts: Query<&mut Text, With<DeckCheckText>>,
+) {
+    if !(config.is_changed() || ban_lists.is_changed()) {
+        return;
+    }
+
+    let summary = deck_check_summary(&config, &ban_lists);
+    for mut text in texts.iter_mut() {
+        text.0 = summary.clone();
+    }
+}
+
+/// Returns to the main menu when Cancel (Escape or B) is pressed on the new game screen
+pub fn handle_new_game_esc_key(
+    actions: Res<ActionInput>,
+    mut next_state: ResMut<NextState<GameMenuState>>,
+) {
+    if actions.just_pressed(InputAction::Cancel) {
+        info!("Cancel pressed in new game screen - returning to main menu");
+        next_state.set(GameMenuState::MainMenu);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::deck::BanList;
+use crate::game_engine::setup::{GameSetupConfig, MAX_PLAYERS, MIN_PLAYERS};
+use crate::menu::new_game::systems::deck_check_summary;
+use crate::menu::new_game::{NewGameAction, SetupField};
+use crate::menu::settings::components::BanListSettings;
+
+#[test]
+fn test_player_count_buttons_stay_in_range() {
+    let mut config = GameSetupConfig {
+        player_count: MAX_PLAYERS,
+        ..Default::default()
+    };
+    assert!(NewGameAction::IncreasePlayers.apply(&mut config));
+    assert_eq!(config.player_count, MAX_PLAYERS);
+
+    config.player_count = MIN_PLAYERS;
+    NewGameAction::DecreasePlayers.apply(&mut config);
+    assert_eq!(config.player_count, MIN_PLAYERS);
+}
+
+#[test]
+fn test_house_rule_toggles_update_value_text() {
+    let mut config = GameSetupConfig::default();
+    assert_eq!(SetupField::CommanderDamage.value_text(&config), "On");
+
+    NewGameAction::ToggleCommanderDamage.apply(&mut config);
+    assert_eq!(SetupField::CommanderDamage.value_text(&config), "Off");
+
+    assert_eq!(
+        SetupField::FirstTurnDraw.value_text(&config),
+        "By Player Count"
+    );
+    NewGameAction::CycleFirstTurnDraw.apply(&mut config);
+    assert_eq!(
+        SetupField::FirstTurnDraw.value_text(&config),
+        "Everyone Draws"
+    );
+}
+
+#[test]
+fn test_start_and_back_leave_config_unchanged() {
+    let mut config = GameSetupConfig::default();
+    assert!(!NewGameAction::StartGame.apply(&mut config));
+    assert!(!NewGameAction::Goldfish.apply(&mut config));
+    assert!(!NewGameAction::Back.apply(&mut config));
+    assert_eq!(config, GameSetupConfig::default());
+}
+
+#[test]
+fn test_deck_check_follows_selected_ban_list() {
+    let ban_lists = BanListSettings {
+        house_lists: vec![BanList {
+            name: "Anything Goes".to_string(),
+            banned: Vec::new(),
+            unbanned: Vec::new(),
+            include_commander: false,
+        }],
+    };
+    let mut config = GameSetupConfig::default();
+    assert_eq!(SetupField::BanList.value_text(&config), "Commander");
+
+    config.ban_list = ban_lists.next_after(config.ban_list.as_deref());
+    assert_eq!(SetupField::BanList.value_text(&config), "Anything Goes");
+    assert_eq!(
+        deck_check_summary(&config, &ban_lists),
+        "All decks pass the Anything Goes ban list"
+    );
+
+    config.ban_list = ban_lists.next_after(config.ban_list.as_deref());
+    assert_eq!(config.ban_list, None);
+}
+
+#[test]
+fn test_random_decks_pick_a_precon_for_every_seat() {
+    let mut config = GameSetupConfig::default();
+    assert_eq!(SetupField::Decks.value_text(&config), "Default");
+
+    assert!(NewGameAction::RandomDecks.apply(&mut config));
+    assert_eq!(config.precons.len(), MAX_PLAYERS);
+    assert_eq!(SetupField::Decks.value_text(&config), "Random Precons");
+    let summary = deck_check_summary(&config, &BanListSettings::default());
+    assert!(summary.starts_with(&format!("Player 1: {}", config.precons[0])));
+    assert!(summary.ends_with("All decks pass the Commander ban list"));
+
+    assert!(NewGameAction::DefaultDecks.apply(&mut config));
+    assert_eq!(config.precon_for(0), None);
+}
@@ -7,6 +7,7 @@ use bevy::prelude::*;
 
 use super::systems::pause_menu::{
     // Correct path for systems
+    game_time::{clear_pause_context, pause_game_time, resume_game_time},
     input_handler::{esc_key_system, handle_pause_trigger},
     interactions::pause_menu_action,
     // setup::setup_pause_menu, // Removed unused import
@@ -31,6 +32,10 @@ impl Plugin for PauseMenuPlugin {
             )
             // System to *trigger* the pause menu from the game
             .add_systems(Update, handle_pause_trigger.run_if(in_state(AppState::InGame)))
+            // Game time stands still while paused, which also stops FixedUpdate
+            .add_systems(OnEnter(AppState::Paused), pause_game_time)
+            .add_systems(OnExit(AppState::Paused), resume_game_time)
+            .add_systems(OnEnter(GameMenuState::MainMenu), clear_pause_context)
             // Exit pause menu cleanup - Commented out
             // .add_systems(
             //     OnExit(GameMenuState::PauseMenu),
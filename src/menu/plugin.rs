@@ -15,8 +15,10 @@ use crate::{
             MainMenuPlugin,
             systems::{interactions::handle_main_menu_interactions, setup::setup_main_menu},
         },
+        new_game::NewGamePlugin,
         pause::PauseMenuPlugin,
         save_load::SaveLoadUiPlugin,
+        scenarios::ScenarioPickerPlugin,
         settings::SettingsPlugin,
         star_of_david::StarOfDavidPlugin,
         state::StateTransitionContext,
@@ -51,6 +53,8 @@ impl Plugin for MenuPlugin {
                 MainMenuPlugin,
                 PauseMenuPlugin,
                 CreditsPlugin,
+                NewGamePlugin,
+                ScenarioPickerPlugin,
                 DeckManagerPlugin,
                 SaveLoadUiPlugin,
                 InputBlockerPlugin,
@@ -16,6 +16,14 @@ pub struct LoadGamePanel;
 #[derive(Component)]
 pub struct SaveSlotButton;
 
+/// Container holding one row per save slot
+#[derive(Component)]
+pub struct SaveSlotList;
+
+/// Text showing the description or slot name being typed
+#[derive(Component)]
+pub struct SlotTextEntryText;
+
 /// Button actions specific to save/load UI
 #[derive(Component, Clone, Debug)]
 pub enum SaveLoadButtonAction {
@@ -25,6 +33,20 @@ pub enum SaveLoadButtonAction {
     LoadFromSlot(String),
     /// Create a new save slot
     CreateSaveSlot,
+    /// Delete a save slot
+    DeleteSlot(String),
+    /// Start renaming a save slot
+    RenameSlot(String),
+    /// Roll back to a turn checkpoint
+    RestoreCheckpoint(String),
     /// Cancel and close the save/load dialog
     Cancel,
 }
+
+/// Dialog explaining why a save couldn't be loaded
+#[derive(Component)]
+pub struct SaveErrorDialog;
+
+/// Button that closes the save error dialog
+#[derive(Component)]
+pub struct SaveErrorDismissButton;
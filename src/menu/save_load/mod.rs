@@ -3,5 +3,8 @@ pub mod plugin;
 pub mod resources;
 pub mod systems;
 
+#[cfg(test)]
+mod tests;
+
 pub use plugin::SaveLoadUiPlugin;
 pub use resources::*;
@@ -1,7 +1,9 @@
 use bevy::prelude::*;
+use bevy_persistent::prelude::*;
 
 use super::resources::*;
 use super::systems::*;
+use crate::game_engine::save::SaveMetadata;
 
 /// Plugin that adds save/load UI functionality to the game
 pub struct SaveLoadUiPlugin;
@@ -11,18 +13,34 @@ impl Plugin for SaveLoadUiPlugin {
         app.init_state::<SaveLoadUiState>()
             .init_resource::<SaveLoadUiContext>()
             .init_resource::<SaveExists>()
+            .init_resource::<SlotTextEntry>()
             // Setup UI when entering the appropriate SaveLoadUiState
             .add_systems(OnEnter(SaveLoadUiState::SaveGame), setup_save_dialog)
             .add_systems(OnEnter(SaveLoadUiState::LoadGame), setup_load_dialog)
+            .add_systems(
+                OnEnter(SaveLoadUiState::Checkpoints),
+                setup_checkpoint_dialog,
+            )
             // Clean up UI when exiting the SaveLoadUiState
             .add_systems(OnExit(SaveLoadUiState::SaveGame), cleanup_save_load_ui)
             .add_systems(OnExit(SaveLoadUiState::LoadGame), cleanup_save_load_ui)
+            .add_systems(OnExit(SaveLoadUiState::Checkpoints), cleanup_save_load_ui)
+            // Failed loads can happen after the dialog has closed
+            .add_systems(Update, (show_save_error_dialog, dismiss_save_error_dialog))
             // Button interaction system
             .add_systems(
                 Update,
-                handle_save_load_buttons.run_if(|state: Res<State<SaveLoadUiState>>| {
-                    *state.get() != SaveLoadUiState::Hidden
-                }),
+                (
+                    handle_save_load_buttons,
+                    handle_slot_text_entry,
+                    refresh_slot_list
+                        .run_if(resource_exists_and_changed::<Persistent<SaveMetadata>>),
+                    update_slot_text_entry_display.run_if(resource_changed::<SlotTextEntry>),
+                )
+                    .chain()
+                    .run_if(|state: Res<State<SaveLoadUiState>>| {
+                        *state.get() != SaveLoadUiState::Hidden
+                    }),
             );
 
         info!("Save/Load UI plugin registered with SaveExists resource");
@@ -10,6 +10,8 @@ pub enum SaveLoadUiState {
     SaveGame,
     /// Showing load game dialog
     LoadGame,
+    /// Showing the turn checkpoints that can be restored
+    Checkpoints,
 }
 
 /// Resource to track the current state of the save/load UI
@@ -23,6 +25,71 @@ pub struct SaveLoadUiContext {
     pub selected_slot: Option<String>,
 }
 
+/// What the save/load text entry is currently editing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextEntryTarget {
+    /// The description of a new save
+    NewSave,
+    /// The new name of an existing save slot
+    Rename(String),
+}
+
+/// Text typed into the save/load dialog
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SlotTextEntry {
+    /// What the text is for, if anything is being edited
+    pub target: Option<TextEntryTarget>,
+    /// The text typed so far
+    pub buffer: String,
+}
+
+impl SlotTextEntry {
+    /// Longest text accepted for names and descriptions
+    pub const MAX_LENGTH: usize = 48;
+
+    /// Start editing, with some initial text
+    pub fn begin(&mut self, target: TextEntryTarget, initial: &str) {
+        self.target = Some(target);
+        self.buffer = initial.to_string();
+    }
+
+    /// Append typed text, ignoring control characters and anything past the limit
+    pub fn push_str(&mut self, text: &str) {
+        for c in text.chars().filter(|c| !c.is_control()) {
+            if self.buffer.chars().count() >= Self::MAX_LENGTH {
+                break;
+            }
+            self.buffer.push(c);
+        }
+    }
+
+    /// Remove the last character
+    pub fn backspace(&mut self) {
+        self.buffer.pop();
+    }
+
+    /// Finish editing, returning the target and the trimmed text
+    pub fn take(&mut self) -> Option<(TextEntryTarget, String)> {
+        let target = self.target.take()?;
+        let text = std::mem::take(&mut self.buffer).trim().to_string();
+        Some((target, text))
+    }
+
+    /// Prompt shown above the typed text
+    pub fn label(&self) -> String {
+        match &self.target {
+            Some(TextEntryTarget::NewSave) => format!("Description: {}_", self.buffer),
+            Some(TextEntryTarget::Rename(slot)) => {
+                format!(
+                    "Rename \"{}\": {}_ (Enter to confirm, Esc to cancel)",
+                    slot, self.buffer
+                )
+            }
+            None => String::new(),
+        }
+    }
+}
+
 /// Resource to track whether a save exists
 #[derive(Resource, Default)]
 pub struct SaveExists(pub bool);
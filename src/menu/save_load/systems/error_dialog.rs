@@ -0,0 +1,149 @@
+use crate::camera::components::AppLayer;
+use crate::camera::z_order::UiLayer;
+use crate::game_engine::save::{CURRENT_SAVE_FORMAT, SaveIncompatibility, SaveIncompatibleEvent};
+use crate::menu::input_blocker::InputBlocker;
+use crate::menu::save_load::components::*;
+use bevy::prelude::*;
+use bevy::text::JustifyText;
+
+/// Explains which versions were involved when a save couldn't be loaded
+pub fn incompatibility_summary(incompatibility: &SaveIncompatibility) -> String {
+    let written_by = match (&incompatibility.game_version, incompatibility.found_version) {
+        (Some(game), Some(format)) => {
+            format!(
+                "This save was written by version {} (save format {}).",
+                game, format
+            )
+        }
+        (None, Some(format)) => format!("This save uses save format {}.", format),
+        _ => "This file isn't a save this game can read.".to_string(),
+    };
+    format!(
+        "{} This version reads save format {} and older.",
+        written_by, CURRENT_SAVE_FORMAT
+    )
+}
+
+/// Opens an error dialog listing why a save couldn't be loaded
+pub fn show_save_error_dialog(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut events: EventReader<SaveIncompatibleEvent>,
+    dialogs: Query<Entity, With<SaveErrorDialog>>,
+) {
+    // Only the latest failure is shown
+    let Some(event) = events.read().last() else {
+        return;
+    };
+    for entity in dialogs.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            UiLayer::Overlay.global_z_index(),
+            AppLayer::Menu.layer(),
+            InputBlocker,
+            SaveErrorDialog,
+            Name::new("Save Error Dialog"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(560.0),
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(20.0)),
+                        row_gap: Val::Px(10.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 1.0)),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(format!("Can't load \"{}\"", event.slot_name)),
+                        TextFont {
+                            font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(1.0, 0.5, 0.5)),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                    ));
+
+                    parent.spawn((
+                        Text::new(incompatibility_summary(&event.incompatibility)),
+                        TextFont {
+                            font: asset_server.load("fonts/FiraSans-Regular.ttf"),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+
+                    for problem in &event.incompatibility.problems {
+                        parent.spawn((
+                            Text::new(format!("- {}", problem)),
+                            TextFont {
+                                font: asset_server.load("fonts/FiraSans-Regular.ttf"),
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                        ));
+                    }
+
+                    parent
+                        .spawn((
+                            Button,
+                            Node {
+                                min_width: Val::Px(96.0),
+                                height: Val::Px(30.0),
+                                align_self: AlignSelf::Center,
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+                            SaveErrorDismissButton,
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Text::new("OK"),
+                                TextFont {
+                                    font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                                    font_size: 16.0,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                            ));
+                        });
+                });
+        });
+}
+
+/// Closes the save error dialog when its button is pressed
+pub fn dismiss_save_error_dialog(
+    mut commands: Commands,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<SaveErrorDismissButton>)>,
+    dialogs: Query<Entity, With<SaveErrorDialog>>,
+) {
+    if !buttons
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        return;
+    }
+    for entity in dialogs.iter() {
+        commands.entity(entity).despawn();
+    }
+}
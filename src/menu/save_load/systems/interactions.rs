@@ -1,9 +1,18 @@
-use crate::game_engine::save::events::{LoadGameEvent, SaveGameEvent};
-use crate::menu::save_load::components::SaveLoadButtonAction;
-use crate::menu::save_load::resources::{SaveLoadUiContext, SaveLoadUiState};
-use crate::menu::state::{AppState, GameMenuState};
+use crate::game_engine::save::events::{
+    DeleteSaveEvent, LoadGameEvent, RenameSaveEvent, RollbackEvent, SaveGameEvent,
+};
+use crate::game_engine::save::resources::SaveMetadata;
+use crate::menu::save_load::components::{SaveLoadButtonAction, SlotTextEntryText};
+use crate::menu::save_load::resources::{
+    SaveLoadUiContext, SaveLoadUiState, SlotTextEntry, TextEntryTarget,
+};
+use crate::menu::state::GameMenuState;
 use crate::menu::styles::{HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+use bevy::ecs::system::SystemParam;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
 use bevy::prelude::*;
+use bevy_persistent::prelude::*;
 
 /// Type alias for the query used in `handle_save_load_buttons`.
 type SaveLoadButtonInteractionQuery<'w, 's> = Query<
@@ -17,15 +26,84 @@ type SaveLoadButtonInteractionQuery<'w, 's> = Query<
     (Changed<Interaction>, With<Button>),
 >;
 
+/// Events and state used to act on the save/load dialog
+#[derive(SystemParam)]
+pub struct SaveLoadActions<'w> {
+    save_load_state: ResMut<'w, NextState<SaveLoadUiState>>,
+    game_state: ResMut<'w, NextState<GameMenuState>>,
+    context: Res<'w, SaveLoadUiContext>,
+    mode: Res<'w, State<SaveLoadUiState>>,
+    save_metadata: Option<Res<'w, Persistent<SaveMetadata>>>,
+    save_events: EventWriter<'w, SaveGameEvent>,
+    rename_events: EventWriter<'w, RenameSaveEvent>,
+}
+
+impl SaveLoadActions<'_> {
+    /// Close the dialog and return to the menu it was opened from
+    fn close(&mut self) {
+        self.save_load_state.set(SaveLoadUiState::Hidden);
+        if self.context.from_pause_menu {
+            self.game_state.set(GameMenuState::PauseMenu);
+        } else {
+            self.game_state.set(GameMenuState::MainMenu);
+        }
+    }
+
+    /// Save the game to a slot and close the dialog
+    fn save(&mut self, slot_name: String, description: Option<String>) {
+        info!("Save game requested for slot: {}", slot_name);
+        self.save_events.write(SaveGameEvent {
+            slot_name,
+            description: description.or_else(|| {
+                Some(format!(
+                    "Save from {}",
+                    chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+                ))
+            }),
+            with_snapshot: true,
+        });
+        self.close();
+    }
+
+    /// Act on finished text entry
+    fn submit(&mut self, text_entry: &mut SlotTextEntry) {
+        let Some((target, text)) = text_entry.take() else {
+            return;
+        };
+        match target {
+            TextEntryTarget::NewSave => {
+                let slot_name = self.save_metadata.as_deref().map_or_else(
+                    || "Save 1".to_string(),
+                    |metadata| metadata.next_slot_name(),
+                );
+                self.save(slot_name, (!text.is_empty()).then_some(text));
+            }
+            TextEntryTarget::Rename(slot_name) => {
+                self.rename_events.write(RenameSaveEvent {
+                    slot_name,
+                    new_name: text,
+                });
+                self.restore_text_entry(text_entry);
+            }
+        }
+    }
+
+    /// Go back to editing the new save description, if saving
+    fn restore_text_entry(&self, text_entry: &mut SlotTextEntry) {
+        if *self.mode.get() == SaveLoadUiState::SaveGame && text_entry.target.is_none() {
+            text_entry.begin(TextEntryTarget::NewSave, "");
+        }
+    }
+}
+
 /// Handles button interactions in the save/load UI
 pub fn handle_save_load_buttons(
     mut interaction_query: SaveLoadButtonInteractionQuery,
-    mut save_load_state: ResMut<NextState<SaveLoadUiState>>,
-    mut game_state: ResMut<NextState<GameMenuState>>,
-    mut _app_state: ResMut<NextState<AppState>>,
-    mut save_events: EventWriter<SaveGameEvent>,
+    mut actions: SaveLoadActions,
     mut load_events: EventWriter<LoadGameEvent>,
-    context: ResMut<SaveLoadUiContext>,
+    mut delete_events: EventWriter<DeleteSaveEvent>,
+    mut rollback_events: EventWriter<RollbackEvent>,
+    mut text_entry: ResMut<SlotTextEntry>,
 ) {
     // Process button interactions
     for (interaction, action, mut bg_color) in interaction_query.iter_mut() {
@@ -36,27 +114,7 @@ pub fn handle_save_load_buttons(
 
                 match action {
                     SaveLoadButtonAction::SaveToSlot(slot_name) => {
-                        info!("Save game requested for slot: {}", slot_name);
-
-                        // Send save game event with the slot name
-                        save_events.write(SaveGameEvent {
-                            slot_name: slot_name.clone(),
-                            description: Some(format!(
-                                "Save from {}",
-                                chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
-                            )),
-                            with_snapshot: false,
-                        });
-
-                        // Clear the UI
-                        save_load_state.set(SaveLoadUiState::Hidden);
-
-                        // Return to appropriate state
-                        if context.from_pause_menu {
-                            game_state.set(GameMenuState::PauseMenu);
-                        } else {
-                            game_state.set(GameMenuState::MainMenu);
-                        }
+                        actions.save(slot_name.clone(), None);
                     }
                     SaveLoadButtonAction::LoadFromSlot(slot_name) => {
                         info!("Load game requested for slot: {}", slot_name);
@@ -67,27 +125,38 @@ pub fn handle_save_load_buttons(
                         });
 
                         // Clear the UI
-                        save_load_state.set(SaveLoadUiState::Hidden);
+                        actions.save_load_state.set(SaveLoadUiState::Hidden);
 
                         // Set game state to Loading (will transition to InGame after load)
-                        game_state.set(GameMenuState::Loading);
-                        // Set AppState to InGame immediately? Or wait?
-                        // Let's try setting AppState later, after loading is confirmed.
+                        actions.game_state.set(GameMenuState::Loading);
+                    }
+                    SaveLoadButtonAction::RestoreCheckpoint(slot_name) => {
+                        info!("Restoring checkpoint: {}", slot_name);
+                        rollback_events.write(RollbackEvent {
+                            checkpoint_name: Some(slot_name.clone()),
+                        });
+                        actions.save_load_state.set(SaveLoadUiState::Hidden);
+                        actions.game_state.set(GameMenuState::Loading);
+                    }
+                    SaveLoadButtonAction::CreateSaveSlot => {
+                        if text_entry.target != Some(TextEntryTarget::NewSave) {
+                            text_entry.begin(TextEntryTarget::NewSave, "");
+                        }
+                        actions.submit(&mut text_entry);
+                    }
+                    SaveLoadButtonAction::DeleteSlot(slot_name) => {
+                        info!("Delete requested for slot: {}", slot_name);
+                        delete_events.write(DeleteSaveEvent {
+                            slot_name: slot_name.clone(),
+                        });
+                    }
+                    SaveLoadButtonAction::RenameSlot(slot_name) => {
+                        text_entry.begin(TextEntryTarget::Rename(slot_name.clone()), slot_name);
                     }
                     SaveLoadButtonAction::Cancel => {
                         info!("Cancelling save/load operation");
-
-                        // Clear the UI
-                        save_load_state.set(SaveLoadUiState::Hidden);
-
-                        // Return to appropriate state
-                        if context.from_pause_menu {
-                            game_state.set(GameMenuState::PauseMenu);
-                        } else {
-                            game_state.set(GameMenuState::MainMenu);
-                        }
+                        actions.close();
                     }
-                    _ => {}
                 }
             }
             Interaction::Hovered => {
@@ -101,3 +170,40 @@ pub fn handle_save_load_buttons(
         }
     }
 }
+
+/// Handles typing descriptions and slot names
+pub fn handle_slot_text_entry(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut text_entry: ResMut<SlotTextEntry>,
+    mut actions: SaveLoadActions,
+) {
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed || text_entry.target.is_none() {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Character(text) => text_entry.push_str(text),
+            Key::Space => text_entry.push_str(" "),
+            Key::Backspace => text_entry.backspace(),
+            Key::Enter => actions.submit(&mut text_entry),
+            Key::Escape => {
+                if matches!(text_entry.target, Some(TextEntryTarget::Rename(_))) {
+                    *text_entry = SlotTextEntry::default();
+                    actions.restore_text_entry(&mut text_entry);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Shows the text being typed
+pub fn update_slot_text_entry_display(
+    text_entry: Res<SlotTextEntry>,
+    mut texts: Query<&mut Text, With<SlotTextEntryText>>,
+) {
+    for mut text in texts.iter_mut() {
+        text.0 = text_entry.label();
+    }
+}
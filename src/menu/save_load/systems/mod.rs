@@ -1,9 +1,15 @@
 mod cleanup;
+mod error_dialog;
 mod interactions;
 mod load_dialog;
 mod save_dialog;
+mod slot_browser;
 
 pub use cleanup::*;
+pub use error_dialog::*;
 pub use interactions::*;
 pub use load_dialog::*;
 pub use save_dialog::*;
+pub use slot_browser::{
+    format_save_timestamp, player_names_label, refresh_slot_list, slot_details,
+};
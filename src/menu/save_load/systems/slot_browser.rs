@@ -0,0 +1,413 @@
+use crate::camera::components::AppLayer;
+use crate::game_engine::save::SaveInfo;
+use crate::game_engine::save::resources::SaveMetadata;
+use crate::menu::input_blocker::InputBlocker;
+use crate::menu::save_load::components::*;
+use crate::menu::save_load::resources::*;
+use bevy::prelude::*;
+use bevy::text::JustifyText;
+use bevy_persistent::prelude::*;
+
+const ROW_COLOR: Color = Color::srgb(0.18, 0.18, 0.18);
+const BUTTON_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+const THUMBNAIL_SIZE: Vec2 = Vec2::new(128.0, 72.0);
+
+/// Formats a save timestamp (seconds since the Unix epoch) in local time
+pub fn format_save_timestamp(timestamp: u64) -> String {
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|time| {
+            time.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_else(|| "Unknown date".to_string())
+}
+
+/// Turn number and save time shown under a slot name
+pub fn slot_details(save: &SaveInfo) -> String {
+    format!(
+        "Turn {} - {}",
+        save.turn_number,
+        format_save_timestamp(save.timestamp)
+    )
+}
+
+/// Players shown for a slot, falling back to a count for older saves
+pub fn player_names_label(save: &SaveInfo) -> String {
+    if save.player_names.is_empty() {
+        format!("{} players", save.player_count)
+    } else {
+        format!("Players: {}", save.player_names.join(", "))
+    }
+}
+
+/// Spawns the slot browser shared by the save and load dialogs
+pub(crate) fn spawn_slot_browser(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    metadata: Option<&SaveMetadata>,
+    mode: &SaveLoadUiState,
+) {
+    let title = match mode {
+        SaveLoadUiState::SaveGame => "Save Game",
+        SaveLoadUiState::Checkpoints => "Restore Checkpoint",
+        _ => "Load Game",
+    };
+
+    // First, create a full-screen transparent input blocker
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        AppLayer::Menu.layer(),
+        InputBlocker,
+        SaveLoadUi,
+        Name::new(format!("{} Input Blocker", title)),
+    ));
+
+    // Create a semi-transparent background overlay
+    let mut overlay = commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+        SaveLoadUi,
+        AppLayer::Menu.layer(),
+        Name::new(title),
+    ));
+    match mode {
+        SaveLoadUiState::SaveGame => overlay.insert(SaveGamePanel),
+        _ => overlay.insert(LoadGamePanel),
+    };
+
+    overlay.with_children(|parent| {
+        // Main dialog panel
+        parent
+            .spawn((
+                Node {
+                    width: Val::Px(720.0),
+                    height: Val::Px(580.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(20.0)),
+                    row_gap: Val::Px(12.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 1.0)),
+            ))
+            .with_children(|parent| {
+                // Dialog title
+                parent.spawn((
+                    Text::new(title),
+                    TextFont {
+                        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                        font_size: 28.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    TextLayout::new_with_justify(JustifyText::Center),
+                ));
+
+                // Slot list
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Px(380.0),
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(8.0),
+                            overflow: Overflow::scroll_y(),
+                            ..default()
+                        },
+                        SaveSlotList,
+                    ))
+                    .with_children(|parent| {
+                        spawn_slot_rows(parent, asset_server, metadata, mode);
+                    });
+
+                // Text entry for descriptions and renames
+                parent.spawn((
+                    Text::new(""),
+                    TextFont {
+                        font: asset_server.load("fonts/FiraSans-Regular.ttf"),
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 0.6)),
+                    SlotTextEntryText,
+                ));
+
+                // Button row
+                parent
+                    .spawn(Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(44.0),
+                        justify_content: JustifyContent::SpaceEvenly,
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        if *mode == SaveLoadUiState::SaveGame {
+                            spawn_dialog_button(
+                                parent,
+                                asset_server,
+                                "New Save",
+                                SaveLoadButtonAction::CreateSaveSlot,
+                            );
+                        }
+                        spawn_dialog_button(
+                            parent,
+                            asset_server,
+                            "Cancel",
+                            SaveLoadButtonAction::Cancel,
+                        );
+                    });
+            });
+    });
+}
+
+/// Spawns one row per save, newest first
+fn spawn_slot_rows(
+    parent: &mut ChildSpawnerCommands,
+    asset_server: &AssetServer,
+    metadata: Option<&SaveMetadata>,
+    mode: &SaveLoadUiState,
+) {
+    let saves = match (metadata, mode) {
+        (Some(metadata), SaveLoadUiState::Checkpoints) => {
+            let mut checkpoints: Vec<&SaveInfo> = metadata.checkpoints.iter().collect();
+            checkpoints.sort_by(|a, b| b.turn_number.cmp(&a.turn_number));
+            checkpoints
+        }
+        (Some(metadata), _) => metadata.newest_first(),
+        (None, _) => Vec::new(),
+    };
+    if saves.is_empty() {
+        let message = match mode {
+            SaveLoadUiState::Checkpoints => "No checkpoints yet",
+            _ => "No saved games found",
+        };
+        parent.spawn((
+            Text::new(message),
+            TextFont {
+                font: asset_server.load("fonts/FiraSans-Regular.ttf"),
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            TextLayout::new_with_justify(JustifyText::Center),
+        ));
+        return;
+    }
+
+    for save in saves {
+        let primary = match mode {
+            SaveLoadUiState::SaveGame => (
+                "Overwrite",
+                SaveLoadButtonAction::SaveToSlot(save.slot_name.clone()),
+            ),
+            SaveLoadUiState::Checkpoints => (
+                "Restore",
+                SaveLoadButtonAction::RestoreCheckpoint(save.slot_name.clone()),
+            ),
+            _ => (
+                "Load",
+                SaveLoadButtonAction::LoadFromSlot(save.slot_name.clone()),
+            ),
+        };
+        // Checkpoints are managed by the retention policy
+        let manage_slot = *mode != SaveLoadUiState::Checkpoints;
+
+        parent
+            .spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    min_height: Val::Px(92.0),
+                    padding: UiRect::all(Val::Px(10.0)),
+                    column_gap: Val::Px(12.0),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(ROW_COLOR),
+                SaveSlotButton,
+                Name::new(format!("Save Slot {}", save.slot_name)),
+            ))
+            .with_children(|parent| {
+                spawn_thumbnail(parent, asset_server, save);
+
+                // Slot information
+                parent
+                    .spawn(Node {
+                        flex_grow: 1.0,
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(2.0),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        for (text, font, size, color) in [
+                            (
+                                save.slot_name.clone(),
+                                "fonts/FiraSans-Bold.ttf",
+                                20.0,
+                                Color::WHITE,
+                            ),
+                            (
+                                save.description.clone(),
+                                "fonts/FiraSans-Regular.ttf",
+                                15.0,
+                                Color::srgba(0.8, 0.8, 0.8, 1.0),
+                            ),
+                            (
+                                slot_details(save),
+                                "fonts/FiraSans-Regular.ttf",
+                                14.0,
+                                Color::srgba(0.6, 0.8, 0.6, 1.0),
+                            ),
+                            (
+                                player_names_label(save),
+                                "fonts/FiraSans-Regular.ttf",
+                                14.0,
+                                Color::srgba(0.7, 0.7, 0.9, 1.0),
+                            ),
+                        ] {
+                            parent.spawn((
+                                Text::new(text),
+                                TextFont {
+                                    font: asset_server.load(font),
+                                    font_size: size,
+                                    ..default()
+                                },
+                                TextColor(color),
+                                TextLayout::new_with_justify(JustifyText::Left),
+                            ));
+                        }
+                    });
+
+                // Slot actions
+                parent
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(4.0),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        spawn_dialog_button(parent, asset_server, primary.0, primary.1);
+                        if manage_slot {
+                            spawn_dialog_button(
+                                parent,
+                                asset_server,
+                                "Rename",
+                                SaveLoadButtonAction::RenameSlot(save.slot_name.clone()),
+                            );
+                            spawn_dialog_button(
+                                parent,
+                                asset_server,
+                                "Delete",
+                                SaveLoadButtonAction::DeleteSlot(save.slot_name.clone()),
+                            );
+                        }
+                    });
+            });
+    }
+}
+
+/// Spawns the board snapshot for a save, or a placeholder when there is none
+fn spawn_thumbnail(parent: &mut ChildSpawnerCommands, asset_server: &AssetServer, save: &SaveInfo) {
+    let mut frame = parent.spawn((
+        Node {
+            width: Val::Px(THUMBNAIL_SIZE.x),
+            height: Val::Px(THUMBNAIL_SIZE.y),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.08, 0.08, 0.08)),
+    ));
+
+    match &save.thumbnail {
+        Some(thumbnail) => {
+            frame.with_children(|parent| {
+                parent.spawn((
+                    ImageNode::new(asset_server.load(thumbnail.clone())),
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                ));
+            });
+        }
+        None => {
+            frame.with_children(|parent| {
+                parent.spawn((
+                    Text::new("No preview"),
+                    TextFont {
+                        font: asset_server.load("fonts/FiraSans-Regular.ttf"),
+                        font_size: 13.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.5, 0.5, 0.5)),
+                ));
+            });
+        }
+    }
+}
+
+/// Spawns a small labelled dialog button
+fn spawn_dialog_button(
+    parent: &mut ChildSpawnerCommands,
+    asset_server: &AssetServer,
+    label: &str,
+    action: SaveLoadButtonAction,
+) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                min_width: Val::Px(96.0),
+                height: Val::Px(26.0),
+                padding: UiRect::horizontal(Val::Px(10.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(BUTTON_COLOR),
+            action,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(label),
+                TextFont {
+                    font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+        });
+}
+
+/// Rebuilds the slot list when saves are added, renamed or deleted
+pub fn refresh_slot_list(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    save_metadata: Res<Persistent<SaveMetadata>>,
+    mode: Res<State<SaveLoadUiState>>,
+    lists: Query<Entity, With<SaveSlotList>>,
+) {
+    for list in lists.iter() {
+        commands
+            .entity(list)
+            .despawn_related::<Children>()
+            .with_children(|parent| {
+                spawn_slot_rows(parent, &asset_server, Some(&**save_metadata), mode.get());
+            });
+    }
+}
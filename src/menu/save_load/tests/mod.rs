@@ -0,0 +1,75 @@
+use crate::game_engine::save::{CURRENT_SAVE_FORMAT, SaveIncompatibility, SaveInfo};
+use crate::menu::save_load::systems::{incompatibility_summary, player_names_label, slot_details};
+use crate::menu::save_load::{SlotTextEntry, TextEntryTarget};
+
+fn save_info(player_names: Vec<String>) -> SaveInfo {
+    SaveInfo {
+        slot_name: "Save 1".to_string(),
+        timestamp: 0,
+        description: "Before the board wipe".to_string(),
+        turn_number: 7,
+        player_count: 4,
+        player_names,
+        thumbnail: None,
+    }
+}
+
+#[test]
+fn test_slot_labels() {
+    let save = save_info(vec!["Alice".to_string(), "Bob".to_string()]);
+    assert!(slot_details(&save).starts_with("Turn 7 - "));
+    assert_eq!(player_names_label(&save), "Players: Alice, Bob");
+
+    // Saves made before player names were recorded only know the count
+    assert_eq!(player_names_label(&save_info(Vec::new())), "4 players");
+}
+
+#[test]
+fn test_text_entry_edits_and_submits() {
+    let mut entry = SlotTextEntry::default();
+    entry.push_str("ignored");
+    assert!(entry.take().is_none());
+
+    entry.begin(TextEntryTarget::Rename("Save 1".to_string()), "Save 1");
+    entry.backspace();
+    entry.push_str("A\n");
+    assert_eq!(entry.buffer, "Save A");
+
+    assert_eq!(
+        entry.take(),
+        Some((
+            TextEntryTarget::Rename("Save 1".to_string()),
+            "Save A".to_string()
+        ))
+    );
+    assert!(entry.target.is_none());
+    assert!(entry.buffer.is_empty());
+}
+
+#[test]
+fn test_text_entry_length_is_limited() {
+    let mut entry = SlotTextEntry::default();
+    entry.begin(TextEntryTarget::NewSave, "");
+    entry.push_str(&"x".repeat(SlotTextEntry::MAX_LENGTH + 10));
+    assert_eq!(entry.buffer.len(), SlotTextEntry::MAX_LENGTH);
+}
+
+/// Tests the version summary shown when a save can't be loaded.
+#[test]
+fn test_incompatibility_summary() {
+    let newer = SaveIncompatibility {
+        found_version: Some(CURRENT_SAVE_FORMAT + 1),
+        game_version: Some("9.0.0".to_string()),
+        problems: vec!["newer format".to_string()],
+    };
+    let summary = incompatibility_summary(&newer);
+    assert!(summary.contains("written by version 9.0.0"));
+    assert!(summary.contains(&format!("reads save format {}", CURRENT_SAVE_FORMAT)));
+
+    let unknown = SaveIncompatibility {
+        found_version: None,
+        game_version: None,
+        problems: vec!["not a save".to_string()],
+    };
+    assert!(incompatibility_summary(&unknown).starts_with("This file isn't a save"));
+}
@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+use crate::game_engine::scenario::Scenario;
+
+/// Marker for every entity belonging to the scenario picker
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ScenarioScreen;
+
+/// Buttons on the scenario picker
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScenarioAction {
+    /// Play the scenario at this index in the library
+    Play(usize),
+    /// Return to the main menu
+    Back,
+}
+
+/// Text shown under a scenario's name in the picker
+pub fn scenario_summary(scenario: &Scenario) -> String {
+    if scenario.description.is_empty() {
+        scenario.goal.label()
+    } else {
+        format!("{} - {}", scenario.goal.label(), scenario.description)
+    }
+}
@@ -0,0 +1,14 @@
+//! Scenario picker
+//!
+//! Lists the puzzles in the [`ScenarioLibrary`](crate::game_engine::scenario::ScenarioLibrary)
+//! with their goals. Choosing one starts a game set up from the scenario's board.
+
+pub mod components;
+pub mod plugin;
+pub mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::{ScenarioAction, ScenarioScreen};
+pub use plugin::ScenarioPickerPlugin;
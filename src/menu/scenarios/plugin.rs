@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+use crate::menu::camera::setup::{cleanup_menu_camera, setup_menu_camera};
+use crate::menu::settings::systems::despawn_screen;
+use crate::menu::state::GameMenuState;
+
+use super::components::ScenarioScreen;
+use super::systems::{handle_scenario_esc_key, scenario_button_interaction, setup_scenario_screen};
+
+/// Plugin for the scenario picker
+pub struct ScenarioPickerPlugin;
+
+impl Plugin for ScenarioPickerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(GameMenuState::Scenarios),
+            (setup_menu_camera, setup_scenario_screen),
+        )
+        .add_systems(
+            OnExit(GameMenuState::Scenarios),
+            (despawn_screen::<ScenarioScreen>, cleanup_menu_camera),
+        )
+        .add_systems(
+            Update,
+            (scenario_button_interaction, handle_scenario_esc_key)
+                .chain()
+                .run_if(in_state(GameMenuState::Scenarios)),
+        );
+
+        info!("ScenarioPickerPlugin initialized");
+    }
+}
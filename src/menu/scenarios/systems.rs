@@ -0,0 +1,196 @@
+use bevy::prelude::*;
+use bevy::text::JustifyText;
+
+use crate::camera::components::AppLayer;
+use crate::game_engine::scenario::{PendingScenario, ScenarioLibrary};
+use crate::input::{ActionInput, InputAction};
+use crate::menu::components::ZLayers;
+use crate::menu::state::{AppState, GameMenuState};
+
+use super::components::{ScenarioAction, ScenarioScreen, scenario_summary};
+
+const BUTTON_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+const HOVERED_BUTTON_COLOR: Color = Color::srgb(0.25, 0.25, 0.25);
+const PRESSED_BUTTON_COLOR: Color = Color::srgb(0.35, 0.35, 0.35);
+
+/// Spawns the scenario picker listing every available scenario
+pub fn setup_scenario_screen(mut commands: Commands, library: Option<Res<ScenarioLibrary>>) {
+    info!("Setting up scenario picker");
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            ScenarioScreen,
+            AppLayer::Menu.layer(),
+            ZIndex::from(ZLayers::Background),
+            Name::new("Scenario Screen"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Scenarios"),
+                TextFont {
+                    font_size: 35.0,
+                    ..default()
+                },
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextColor(Color::WHITE),
+                AppLayer::Menu.layer(),
+                Name::new("Scenario Title"),
+            ));
+
+            let scenarios = library
+                .as_ref()
+                .map(|library| library.scenarios.as_slice())
+                .unwrap_or_default();
+            if scenarios.is_empty() {
+                parent.spawn((
+                    Text::new("No scenarios found"),
+                    TextFont {
+                        font_size: 22.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                    AppLayer::Menu.layer(),
+                ));
+            }
+
+            for (index, scenario) in scenarios.iter().enumerate() {
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(520.0),
+                            padding: UiRect::all(Val::Px(10.0)),
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(4.0),
+                            ..default()
+                        },
+                        BackgroundColor(BUTTON_COLOR),
+                        ScenarioAction::Play(index),
+                        AppLayer::Menu.layer(),
+                        ZIndex::from(ZLayers::MenuButtons),
+                        Name::new(format!("{} Scenario Button", scenario.name)),
+                    ))
+                    .with_children(|button| {
+                        button.spawn((
+                            Text::new(scenario.name.clone()),
+                            TextFont {
+                                font_size: 24.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                            AppLayer::Menu.layer(),
+                            ZIndex::from(ZLayers::MenuButtonText),
+                        ));
+                        button.spawn((
+                            Text::new(scenario_summary(scenario)),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                            AppLayer::Menu.layer(),
+                            ZIndex::from(ZLayers::MenuButtonText),
+                        ));
+                    });
+            }
+
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(150.0),
+                        height: Val::Px(40.0),
+                        margin: UiRect::top(Val::Px(20.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(BUTTON_COLOR),
+                    ScenarioAction::Back,
+                    AppLayer::Menu.layer(),
+                    ZIndex::from(ZLayers::MenuButtons),
+                    Name::new("Back Button"),
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        Text::new("Back"),
+                        TextFont {
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        AppLayer::Menu.layer(),
+                        ZIndex::from(ZLayers::MenuButtonText),
+                    ));
+                });
+        });
+}
+
+/// Type alias for the query used in `scenario_button_interaction`.
+type ScenarioButtonInteractionQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Interaction,
+        &'static ScenarioAction,
+        &'static mut BackgroundColor,
+    ),
+    (Changed<Interaction>, With<Button>),
+>;
+
+/// Handles presses on the scenario picker buttons
+pub fn scenario_button_interaction(
+    mut commands: Commands,
+    mut interaction_query: ScenarioButtonInteractionQuery,
+    library: Option<Res<ScenarioLibrary>>,
+    mut next_state: ResMut<NextState<GameMenuState>>,
+    mut app_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, action, mut background_color) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                match action {
+                    ScenarioAction::Play(index) => {
+                        let Some(scenario) = library
+                            .as_ref()
+                            .and_then(|library| library.scenarios.get(*index))
+                        else {
+                            continue;
+                        };
+                        info!("Scenario \"{}\" chosen", scenario.name);
+                        commands.insert_resource(PendingScenario(scenario.clone()));
+                        next_state.set(GameMenuState::InGame);
+                        app_state.set(AppState::InGame);
+                    }
+                    ScenarioAction::Back => {
+                        next_state.set(GameMenuState::MainMenu);
+                    }
+                }
+            }
+            Interaction::Hovered => *background_color = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_color = BUTTON_COLOR.into(),
+        }
+    }
+}
+
+/// Returns to the main menu when Cancel (Escape or B) is pressed on the scenario picker
+pub fn handle_scenario_esc_key(
+    actions: Res<ActionInput>,
+    mut next_state: ResMut<NextState<GameMenuState>>,
+) {
+    if actions.just_pressed(InputAction::Cancel) {
+        info!("Cancel pressed in scenario picker - returning to main menu");
+        next_state.set(GameMenuState::MainMenu);
+    }
+}
@@ -0,0 +1,29 @@
+use crate::game_engine::save::board::BoardDescription;
+use crate::game_engine::scenario::{Scenario, ScenarioGoal};
+use crate::menu::scenarios::components::scenario_summary;
+
+fn scenario(description: &str, goal: ScenarioGoal) -> Scenario {
+    Scenario {
+        name: "Test".to_string(),
+        description: description.to_string(),
+        player: "You".to_string(),
+        goal,
+        hand: Vec::new(),
+        board: BoardDescription::default(),
+    }
+}
+
+#[test]
+fn test_scenario_summary_shows_goal_and_description() {
+    let with_description = scenario("Finish them off.", ScenarioGoal::WinThisTurn);
+    assert_eq!(
+        scenario_summary(&with_description),
+        "Win this turn - Finish them off."
+    );
+
+    let without_description = scenario("", ScenarioGoal::SurviveUntilTurn { turn: 8 });
+    assert_eq!(
+        scenario_summary(&without_description),
+        "Survive until turn 8"
+    );
+}
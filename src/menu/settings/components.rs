@@ -1,6 +1,9 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::cosmetics::{DEFAULT_CARD_BACK, DEFAULT_PLAYMAT, ThemeId};
+use crate::deck::BanList;
+
 /// Marker component for settings menu entities
 #[derive(Component)]
 pub struct SettingsMenuItem;
@@ -25,6 +28,14 @@ pub struct GameplaySettingsScreen;
 #[derive(Component)]
 pub struct ControlsSettingsScreen;
 
+/// Marker component for accessibility settings screen
+#[derive(Component)]
+pub struct AccessibilitySettingsScreen;
+
+/// Marker component for appearance settings screen
+#[derive(Component)]
+pub struct AppearanceSettingsScreen;
+
 /// Settings button actions for navigating between settings screens
 #[derive(Component, Clone, Copy, Debug)]
 pub enum SettingsButtonAction {
@@ -36,6 +47,10 @@ pub enum SettingsButtonAction {
     NavigateToGameplay,
     /// Navigate to controls settings
     NavigateToControls,
+    /// Navigate to accessibility settings
+    NavigateToAccessibility,
+    /// Navigate to appearance settings
+    NavigateToAppearance,
     /// Navigate to main settings
     NavigateToMain,
     /// Exit settings menu
@@ -46,8 +61,113 @@ pub enum SettingsButtonAction {
 #[derive(Component, Debug, Clone, PartialEq, Eq)]
 pub struct QualityButton(pub GraphicsQuality);
 
+/// Button that opens and closes the resolution dropdown
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ResolutionDropdownButton;
+
+/// Container holding the resolution options, hidden while the dropdown is closed
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ResolutionDropdownList;
+
+/// Text showing the selected resolution
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ResolutionValueText;
+
+/// A selectable resolution in the dropdown
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolutionOption(pub [u32; 2]);
+
+/// Button selecting a window display mode
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayModeButton(pub WindowDisplayMode);
+
+/// Button toggling vertical sync
+#[derive(Component, Debug, Clone, Copy)]
+pub struct VsyncToggleButton;
+
+/// Button toggling legal action hints
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ActionHintsToggleButton;
+
+/// Button cycling through the response prompt timeouts
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ResponseTimeoutButton;
+
+/// Button cycling through the ways the table can be rotated
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TableRotationButton;
+
+/// Button toggling whether cards are turned to read upright
+#[derive(Component, Debug, Clone, Copy)]
+pub struct UprightCardsToggleButton;
+
+/// Button toggling procedurally rendered card frames
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ProceduralFramesToggleButton;
+
+/// Slider controlling the UI scale
+#[derive(Component, Debug, Clone, Copy)]
+pub struct UiScaleSlider;
+
+/// Fill node of the UI scale slider
+#[derive(Component, Debug, Clone, Copy)]
+pub struct UiScaleSliderFill;
+
+/// Text showing the current UI scale
+#[derive(Component, Debug, Clone, Copy)]
+pub struct UiScaleValueText;
+
+/// An on/off option on the accessibility settings page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessibilityOption {
+    /// Colorblind-safe mana colors with patterns
+    ColorblindMana,
+    /// Bold zone outlines
+    HighContrast,
+    /// No card animations
+    ReducedMotion,
+}
+
+/// Button toggling an accessibility option
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AccessibilityToggleButton(pub AccessibilityOption);
+
+/// Button cycling through the narration verbosity levels
+#[derive(Component, Debug, Clone, Copy)]
+pub struct NarrationVerbosityButton;
+
+/// Slider controlling the text scale
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TextScaleSlider;
+
+/// Fill node of the text scale slider
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TextScaleSliderFill;
+
+/// Text showing the current text scale
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TextScaleValueText;
+
+/// A theme chosen on the appearance settings page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeSlot {
+    /// The back of the local player's cards
+    CardBack,
+    /// The table and playmats
+    Playmat,
+}
+
+/// Button cycling through the themes for a slot
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ThemeButton(pub ThemeSlot);
+
+/// Preview of the theme chosen for a slot
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ThemePreview(pub ThemeSlot);
+
 /// Volume settings resource
-#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct VolumeSettings {
     /// Master volume level (0.0 - 1.0)
     pub master: f32,
@@ -85,8 +205,71 @@ impl Default for GraphicsQuality {
     }
 }
 
+/// How the primary window is displayed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum WindowDisplayMode {
+    /// Regular decorated window
+    #[default]
+    Windowed,
+    /// Borderless window covering the whole monitor
+    Borderless,
+    /// Exclusive fullscreen
+    Fullscreen,
+}
+
+impl WindowDisplayMode {
+    /// Label shown in the video settings menu
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Windowed => "Windowed",
+            Self::Borderless => "Borderless",
+            Self::Fullscreen => "Fullscreen",
+        }
+    }
+}
+
+/// Resolutions offered in the video settings menu
+pub const SUPPORTED_RESOLUTIONS: &[[u32; 2]] = &[
+    [1280, 720],
+    [1600, 900],
+    [1920, 1080],
+    [2560, 1440],
+    [3840, 2160],
+];
+
+/// Smallest UI scale offered by the UI scale slider
+pub const MIN_UI_SCALE: f32 = 0.5;
+/// Largest UI scale offered by the UI scale slider
+pub const MAX_UI_SCALE: f32 = 2.0;
+
+/// Video settings resource
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VideoSettings {
+    /// Window resolution in logical pixels
+    pub resolution: [u32; 2],
+    /// Windowed, borderless or fullscreen
+    pub display_mode: WindowDisplayMode,
+    /// Whether presentation waits for vertical sync
+    pub vsync: bool,
+    /// Global UI scale factor
+    pub ui_scale: f32,
+}
+
+impl Default for VideoSettings {
+    fn default() -> Self {
+        Self {
+            resolution: [1280, 720],
+            display_mode: WindowDisplayMode::Windowed,
+            vsync: true,
+            ui_scale: 1.0,
+        }
+    }
+}
+
 /// Gameplay settings resource
-#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct GameplaySettings {
     /// Enable auto-pass priority when no valid actions
     pub auto_pass: bool,
@@ -94,6 +277,17 @@ pub struct GameplaySettings {
     pub show_tooltips: bool,
     /// Animation speed multiplier
     pub animation_speed: f32,
+    /// Highlight cards that can be played, activated or attack with
+    pub show_action_hints: bool,
+    /// Seconds the response prompt waits before passing priority, or 0 to
+    /// wait until the player answers
+    pub response_timeout_secs: u32,
+    /// How the table is rotated on screen
+    pub table_rotation: TableRotation,
+    /// Turn every card to read upright on screen, wherever its owner sits
+    pub upright_cards: bool,
+    /// Draw card frames in-engine instead of plain cards with spawned text
+    pub procedural_frames: bool,
 }
 
 impl Default for GameplaySettings {
@@ -102,30 +296,300 @@ impl Default for GameplaySettings {
             auto_pass: true,
             show_tooltips: true,
             animation_speed: 1.0,
+            show_action_hints: true,
+            response_timeout_secs: 0,
+            table_rotation: TableRotation::default(),
+            upright_cards: false,
+            procedural_frames: false,
         }
     }
 }
 
+impl GameplaySettings {
+    /// Response prompt timeouts, in the order the settings button cycles
+    /// through them
+    pub const RESPONSE_TIMEOUTS: [u32; 5] = [0, 5, 10, 20, 30];
+
+    /// The response prompt timeout after the current one, wrapping back to
+    /// no timeout
+    pub fn next_response_timeout(&self) -> u32 {
+        let index = Self::RESPONSE_TIMEOUTS
+            .iter()
+            .position(|&secs| secs == self.response_timeout_secs)
+            .map_or(0, |index| index + 1);
+        Self::RESPONSE_TIMEOUTS[index % Self::RESPONSE_TIMEOUTS.len()]
+    }
+
+    /// Label shown on the response timeout button
+    pub fn response_timeout_label(&self) -> String {
+        match self.response_timeout_secs {
+            0 => "Off".to_string(),
+            secs => format!("{}s", secs),
+        }
+    }
+}
+
+/// How the table is rotated on screen
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TableRotation {
+    /// Turn the table so the local player sits at the bottom, whatever their seat
+    #[default]
+    LocalAtBottom,
+    /// Keep the first seat at the bottom
+    FixedSeats,
+    /// Turn the table freely with Q and E, as a spectator might
+    Free,
+}
+
+impl TableRotation {
+    /// All rotations, in the order the settings button cycles through them
+    pub const ALL: [TableRotation; 3] = [
+        TableRotation::LocalAtBottom,
+        TableRotation::FixedSeats,
+        TableRotation::Free,
+    ];
+
+    /// The rotation after this one, wrapping back to the first
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|rotation| *rotation == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Label shown on the settings button
+    pub fn label(self) -> &'static str {
+        match self {
+            TableRotation::LocalAtBottom => "Me at Bottom",
+            TableRotation::FixedSeats => "Fixed",
+            TableRotation::Free => "Free",
+        }
+    }
+}
+
+/// Smallest text scale offered by the text scale slider
+pub const MIN_TEXT_SCALE: f32 = 0.75;
+/// Largest text scale offered by the text scale slider
+pub const MAX_TEXT_SCALE: f32 = 2.0;
+
+/// How much of the game is narrated on the ticker and to the screen reader
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum NarrationVerbosity {
+    /// No narration
+    #[default]
+    Off,
+    /// Turns, spells and life totals
+    Minimal,
+    /// Also lands, attacks and cards leaving the battlefield
+    Normal,
+    /// Every narrated event, including draws, abilities and priority passes
+    Verbose,
+}
+
+impl NarrationVerbosity {
+    /// All levels, in the order the settings button cycles through them
+    pub const ALL: [NarrationVerbosity; 4] = [
+        NarrationVerbosity::Off,
+        NarrationVerbosity::Minimal,
+        NarrationVerbosity::Normal,
+        NarrationVerbosity::Verbose,
+    ];
+
+    /// Whether a line needing `level` is narrated at this verbosity
+    pub fn allows(self, level: NarrationVerbosity) -> bool {
+        self != NarrationVerbosity::Off && level <= self
+    }
+
+    /// The level after this one, wrapping back to off
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|level| *level == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Label shown on the settings button
+    pub fn label(self) -> &'static str {
+        match self {
+            NarrationVerbosity::Off => "Off",
+            NarrationVerbosity::Minimal => "Minimal",
+            NarrationVerbosity::Normal => "Normal",
+            NarrationVerbosity::Verbose => "Verbose",
+        }
+    }
+}
+
+/// Accessibility settings resource
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccessibilitySettings {
+    /// Tell mana colors apart by symbol and pattern, using a colorblind-safe palette
+    pub colorblind_mana: bool,
+    /// Scale applied to UI text, including the card preview
+    pub text_scale: f32,
+    /// Draw bold, high-contrast outlines around playmat zones
+    pub high_contrast: bool,
+    /// Move cards between zones without animating them
+    pub reduced_motion: bool,
+    /// How much of the game is narrated
+    pub narration: NarrationVerbosity,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self {
+            colorblind_mana: false,
+            text_scale: 1.0,
+            high_contrast: false,
+            reduced_motion: false,
+            narration: NarrationVerbosity::Off,
+        }
+    }
+}
+
+impl AccessibilitySettings {
+    /// Whether an on/off option is enabled
+    pub fn get(&self, option: AccessibilityOption) -> bool {
+        match option {
+            AccessibilityOption::ColorblindMana => self.colorblind_mana,
+            AccessibilityOption::HighContrast => self.high_contrast,
+            AccessibilityOption::ReducedMotion => self.reduced_motion,
+        }
+    }
+
+    /// Flip an on/off option, returning its new value
+    pub fn toggle(&mut self, option: AccessibilityOption) -> bool {
+        let value = match option {
+            AccessibilityOption::ColorblindMana => &mut self.colorblind_mana,
+            AccessibilityOption::HighContrast => &mut self.high_contrast,
+            AccessibilityOption::ReducedMotion => &mut self.reduced_motion,
+        };
+        *value = !*value;
+        *value
+    }
+}
+
+/// Card back and playmat themes, by id
+///
+/// Ids of themes that aren't registered fall back to the defaults, so a
+/// settings file naming a user theme that's gone still loads.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CosmeticSettings {
+    /// Back shown on the local player's cards, to every player
+    pub card_back: ThemeId,
+    /// Table and playmat look
+    pub playmat: ThemeId,
+}
+
+impl Default for CosmeticSettings {
+    fn default() -> Self {
+        Self {
+            card_back: ThemeId::new(DEFAULT_CARD_BACK),
+            playmat: ThemeId::new(DEFAULT_PLAYMAT),
+        }
+    }
+}
+
+impl CosmeticSettings {
+    /// The theme chosen for a slot
+    pub fn get(&self, slot: ThemeSlot) -> &ThemeId {
+        match slot {
+            ThemeSlot::CardBack => &self.card_back,
+            ThemeSlot::Playmat => &self.playmat,
+        }
+    }
+}
+
+/// House ban lists the lobby can check decks against
+///
+/// Lists are defined in the settings file, for example:
+///
+/// ```toml
+/// [[ban_lists.house_lists]]
+/// name = "Kitchen Table"
+/// banned = ["Cyclonic Rift"]
+/// unbanned = ["Biorhythm"]
+/// ```
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BanListSettings {
+    /// User-defined ban lists, in the order the lobby cycles through them
+    pub house_lists: Vec<BanList>,
+}
+
+impl BanListSettings {
+    /// Find a house list by name, ignoring case
+    pub fn find(&self, name: &str) -> Option<&BanList> {
+        self.house_lists
+            .iter()
+            .find(|list| list.name.eq_ignore_ascii_case(name))
+    }
+
+    /// The list selected by `name`, falling back to the Commander banned list
+    pub fn resolve(&self, name: Option<&str>) -> BanList {
+        name.and_then(|name| self.find(name))
+            .cloned()
+            .unwrap_or_else(BanList::commander)
+    }
+
+    /// The list after `name` when cycling from the Commander banned list
+    /// (`None`) through each house list and back
+    pub fn next_after(&self, name: Option<&str>) -> Option<String> {
+        let next = match name.and_then(|name| {
+            self.house_lists
+                .iter()
+                .position(|list| list.name.eq_ignore_ascii_case(name))
+        }) {
+            Some(index) => index + 1,
+            None => 0,
+        };
+        self.house_lists.get(next).map(|list| list.name.clone())
+    }
+}
+
 /// Combined settings that will be saved to TOML
-#[derive(Resource, Debug, Clone, Serialize, Deserialize, Default)]
+///
+/// Missing fields fall back to their defaults so files written by older
+/// versions still load; see [`persistence`](super::persistence) for migrations.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct RummageSettings {
+    /// Settings file format version, 0 for files written before versioning
+    #[serde(default)]
+    pub version: u32,
     /// Volume settings
     pub volume: VolumeSettings,
     /// Graphics settings
     pub graphics: GraphicsQuality,
+    /// Window and display settings
+    pub video: VideoSettings,
     /// Gameplay settings
     pub gameplay: GameplaySettings,
+    /// Accessibility settings
+    pub accessibility: AccessibilitySettings,
+    /// House ban lists
+    pub ban_lists: BanListSettings,
+    /// Card back and playmat themes
+    pub cosmetics: CosmeticSettings,
 }
 
-/* impl Default for RummageSettings {
+impl Default for RummageSettings {
     fn default() -> Self {
         Self {
+            version: super::persistence::SETTINGS_VERSION,
             volume: VolumeSettings::default(),
             graphics: GraphicsQuality::default(),
+            video: VideoSettings::default(),
             gameplay: GameplaySettings::default(),
+            accessibility: AccessibilitySettings::default(),
+            ban_lists: BanListSettings::default(),
+            cosmetics: CosmeticSettings::default(),
         }
     }
-} */
+}
 
 // --- Marker Components for Settings Screens ---
 
@@ -143,3 +607,9 @@ pub struct OnGameplaySettingsMenu;
 
 #[derive(Component, Debug, Clone, Copy)]
 pub struct OnControlsSettingsMenu;
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct OnAccessibilitySettingsMenu;
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct OnAppearanceSettingsMenu;
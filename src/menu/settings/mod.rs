@@ -5,11 +5,16 @@
 //! - Audio settings
 //! - Gameplay settings
 //! - Control settings
+//! - Persistence of all settings to the platform config directory
 
 pub mod components;
+pub mod persistence;
 pub mod plugin;
 pub mod state;
 pub mod systems;
 
+#[cfg(test)]
+mod tests;
+
 pub use plugin::SettingsPlugin;
 pub use state::*;
@@ -0,0 +1,220 @@
+//! Persistent storage for user settings
+//!
+//! Settings are stored as TOML in the platform config directory (for example
+//! `~/.config/rummage/settings.toml` on Linux). They are loaded while the settings
+//! plugin is built, before any menu is spawned, and written back whenever one of the
+//! settings resources changes. Files written by older versions are migrated to the
+//! current [`SETTINGS_VERSION`] on load.
+
+use bevy::prelude::*;
+use bevy_persistent::prelude::*;
+use std::path::{Path, PathBuf};
+
+use super::components::{
+    AccessibilitySettings, BanListSettings, CosmeticSettings, GameplaySettings, MAX_TEXT_SCALE,
+    MAX_UI_SCALE, MIN_TEXT_SCALE, MIN_UI_SCALE, RummageSettings, VideoSettings, VolumeSettings,
+};
+use super::plugin::CurrentGraphicsQuality;
+
+/// Current version of the settings file format
+pub const SETTINGS_VERSION: u32 = 4;
+
+/// Settings file name inside the config directory
+const SETTINGS_FILE_NAME: &str = "settings.toml";
+
+/// Location used by versions that stored settings next to the working directory
+const LEGACY_SETTINGS_PATH: &str = "settings/settings.toml";
+
+/// Path of the settings file in the platform config directory
+///
+/// In the browser, `bevy-persistent` keeps `/local/` paths in `localStorage`.
+pub fn settings_path() -> PathBuf {
+    #[cfg(target_arch = "wasm32")]
+    {
+        PathBuf::from("/local/rummage").join(SETTINGS_FILE_NAME)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        dirs::config_dir()
+            .map(|dir| dir.join("rummage"))
+            .unwrap_or_else(|| PathBuf::from("settings"))
+            .join(SETTINGS_FILE_NAME)
+    }
+}
+
+/// Copy a settings file from the legacy location if none exists at `path` yet
+fn migrate_legacy_settings_file(path: &Path) {
+    let legacy = Path::new(LEGACY_SETTINGS_PATH);
+    if path.exists() || !legacy.exists() || legacy == path {
+        return;
+    }
+
+    if let Some(parent) = path.parent() {
+        if let Err(e) = std::fs::create_dir_all(parent) {
+            warn!("Could not create settings directory {:?}: {}", parent, e);
+            return;
+        }
+    }
+
+    match std::fs::copy(legacy, path) {
+        Ok(_) => info!("Migrated settings from {:?} to {:?}", legacy, path),
+        Err(e) => warn!("Could not migrate legacy settings file: {}", e),
+    }
+}
+
+/// Upgrade settings loaded from an older file format
+///
+/// Returns the migrated settings and whether anything changed.
+pub fn migrate_settings(mut settings: RummageSettings) -> (RummageSettings, bool) {
+    if settings.version >= SETTINGS_VERSION {
+        return (settings, false);
+    }
+
+    // Version 0 files were written before the version field existed and
+    // did not validate slider values.
+    if settings.version < 1 {
+        settings.volume.master = settings.volume.master.clamp(0.0, 1.0);
+        settings.volume.music = settings.volume.music.clamp(0.0, 1.0);
+        settings.volume.sfx = settings.volume.sfx.clamp(0.0, 1.0);
+        if settings.gameplay.animation_speed <= 0.0 {
+            settings.gameplay.animation_speed = GameplaySettings::default().animation_speed;
+        }
+    }
+
+    // Version 1 files had no video section; serde fills in the defaults, so
+    // only out-of-range values need fixing.
+    if settings.version < 2 {
+        settings.video.ui_scale = settings.video.ui_scale.clamp(MIN_UI_SCALE, MAX_UI_SCALE);
+    }
+
+    // Version 2 files had no accessibility section
+    if settings.version < 3 {
+        settings.accessibility.text_scale = settings
+            .accessibility
+            .text_scale
+            .clamp(MIN_TEXT_SCALE, MAX_TEXT_SCALE);
+    }
+
+    // Version 3 files had no cosmetics section; serde fills in the default
+    // themes, and unknown theme ids fall back to them when looked up.
+
+    info!(
+        "Migrated settings from version {} to {}",
+        settings.version, SETTINGS_VERSION
+    );
+    settings.version = SETTINGS_VERSION;
+    (settings, true)
+}
+
+/// Load the persistent settings, migrating older files
+pub fn load_persistent_settings() -> Option<Persistent<RummageSettings>> {
+    let path = settings_path();
+    migrate_legacy_settings_file(&path);
+
+    let mut persistent = match Persistent::<RummageSettings>::builder()
+        .name("rummage_settings")
+        .format(StorageFormat::Toml)
+        .path(path)
+        .default(RummageSettings::default())
+        .revertible(true)
+        .revert_to_default_on_deserialization_errors(true)
+        .build()
+    {
+        Ok(persistent) => persistent,
+        Err(e) => {
+            error!("Failed to initialize persistent settings: {:?}", e);
+            return None;
+        }
+    };
+
+    let (migrated, changed) = migrate_settings(persistent.get().clone());
+    if changed {
+        if let Err(e) = persistent.set(migrated) {
+            error!("Failed to save migrated settings: {:?}", e);
+        }
+    }
+
+    Some(persistent)
+}
+
+/// Copy loaded settings into the individual settings resources
+pub fn insert_settings_resources(app: &mut App, settings: &RummageSettings) {
+    app.insert_resource(settings.volume.clone())
+        .insert_resource(settings.video.clone())
+        .insert_resource(settings.gameplay.clone())
+        .insert_resource(settings.accessibility.clone())
+        .insert_resource(settings.ban_lists.clone())
+        .insert_resource(settings.cosmetics.clone())
+        .insert_resource(CurrentGraphicsQuality {
+            quality: settings.graphics,
+        });
+}
+
+/// Combine the individual settings resources into the persisted form
+pub fn collect_settings(
+    volume: &VolumeSettings,
+    video: &VideoSettings,
+    gameplay: &GameplaySettings,
+    accessibility: &AccessibilitySettings,
+    ban_lists: &BanListSettings,
+    cosmetics: &CosmeticSettings,
+    graphics: &CurrentGraphicsQuality,
+) -> RummageSettings {
+    RummageSettings {
+        version: SETTINGS_VERSION,
+        volume: volume.clone(),
+        graphics: graphics.quality,
+        video: video.clone(),
+        gameplay: gameplay.clone(),
+        accessibility: accessibility.clone(),
+        ban_lists: ban_lists.clone(),
+        cosmetics: cosmetics.clone(),
+    }
+}
+
+/// Write settings to disk whenever one of the settings resources changes
+#[allow(clippy::too_many_arguments)]
+pub fn persist_settings_on_change(
+    volume: Res<VolumeSettings>,
+    video: Res<VideoSettings>,
+    gameplay: Res<GameplaySettings>,
+    accessibility: Res<AccessibilitySettings>,
+    ban_lists: Res<BanListSettings>,
+    cosmetics: Res<CosmeticSettings>,
+    graphics: Res<CurrentGraphicsQuality>,
+    persistent: Option<ResMut<Persistent<RummageSettings>>>,
+) {
+    let Some(mut persistent) = persistent else {
+        return;
+    };
+
+    if !(volume.is_changed()
+        || video.is_changed()
+        || gameplay.is_changed()
+        || accessibility.is_changed()
+        || ban_lists.is_changed()
+        || cosmetics.is_changed()
+        || graphics.is_changed())
+    {
+        return;
+    }
+
+    let settings = collect_settings(
+        &volume,
+        &video,
+        &gameplay,
+        &accessibility,
+        &ban_lists,
+        &cosmetics,
+        &graphics,
+    );
+    if *persistent.get() == settings {
+        return;
+    }
+
+    match persistent.set(settings) {
+        Ok(()) => debug!("Settings saved"),
+        Err(e) => error!("Failed to save settings: {:?}", e),
+    }
+}
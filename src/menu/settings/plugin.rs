@@ -1,37 +1,48 @@
+use crate::cosmetics::CosmeticThemes;
 use crate::menu::{
     camera::setup::MenuCamera, settings::state::SettingsMenuState, state::GameMenuState,
 };
-use bevy::prelude::SystemSet;
 use bevy::prelude::*;
-use bevy_persistent::prelude::*;
+use bevy::ui::UiSystem;
 
 use super::components::*;
 use super::components::{
-    OnAudioSettingsMenu, OnControlsSettingsMenu, OnGameplaySettingsMenu, OnMainSettingsMenu,
-    OnVideoSettingsMenu,
+    OnAccessibilitySettingsMenu, OnAppearanceSettingsMenu, OnAudioSettingsMenu,
+    OnControlsSettingsMenu, OnGameplaySettingsMenu, OnMainSettingsMenu, OnVideoSettingsMenu,
+};
+use super::persistence::{
+    insert_settings_resources, load_persistent_settings, persist_settings_on_change,
 };
 use super::systems::{
+    accessibility::{
+        accessibility_toggle_interaction, apply_text_scale, narration_verbosity_interaction,
+        refresh_card_frames_on_palette_change, setup_accessibility_settings,
+        step_text_scale_slider, text_scale_slider_interaction,
+    },
+    appearance::{setup_appearance_settings, theme_button_interaction, update_theme_previews},
     audio::{
-        VolumeUpdateRequests, apply_volume_updates, setup_audio_settings, volume_slider_interaction,
+        VolumeUpdateRequests, apply_volume_updates, setup_audio_settings, step_volume_sliders,
+        volume_slider_interaction,
     },
     controls::setup_controls_settings,
     despawn_screen,
-    gameplay::setup_gameplay_settings,
+    gameplay::{
+        action_hints_toggle_interaction, procedural_frames_toggle_interaction,
+        response_timeout_interaction, setup_gameplay_settings, table_rotation_interaction,
+        upright_cards_toggle_interaction,
+    },
     main::{handle_settings_back_input, settings_button_action, setup_main_settings},
     state_transitions::should_handle_settings_back,
-    video::{quality_button_interaction, setup_video_settings},
+    video::{
+        apply_video_settings, display_mode_button_interaction, quality_button_interaction,
+        resolution_dropdown_interaction, setup_video_settings, step_ui_scale_slider,
+        ui_scale_slider_interaction, vsync_toggle_interaction,
+    },
 };
 
 /// Plugin that sets up the settings menu system
 pub struct SettingsPlugin;
 
-// Define system sets for chaining on exit
-#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
-struct SaveSettingsSet;
-
-#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
-struct DespawnScreenSet;
-
 /// Despawns the menu camera when leaving the main settings state.
 fn cleanup_settings_menu_camera(
     mut commands: Commands,
@@ -52,31 +63,23 @@ impl Plugin for SettingsPlugin {
 
         // Initialize all settings resources first
         app.init_resource::<VolumeSettings>()
+            .init_resource::<VideoSettings>()
             .init_resource::<GameplaySettings>()
+            .init_resource::<AccessibilitySettings>()
+            .init_resource::<BanListSettings>()
+            .init_resource::<CosmeticSettings>()
+            .init_resource::<CosmeticThemes>()
             .init_resource::<CurrentGraphicsQuality>()
             .init_resource::<RummageSettings>()
             .init_resource::<VolumeUpdateRequests>();
 
         info!("Settings resources initialized");
 
-        // Set up persistent settings using TOML
-        match Persistent::<RummageSettings>::builder()
-            .name("rummage_settings")
-            .format(StorageFormat::Toml)
-            .path("settings/settings.toml")
-            .default(RummageSettings::default())
-            .revertible(true)
-            .revert_to_default_on_deserialization_errors(true)
-            .build()
-        {
-            Ok(persistent_settings) => {
-                // Store the persistent settings
-                app.insert_resource(persistent_settings);
-            }
-            Err(e) => {
-                error!("Failed to initialize persistent settings: {:?}", e);
-                // No need to fall back as we already initialized default resources above
-            }
+        // Load persistent settings before any menu is built so the menus
+        // start out showing the saved values
+        if let Some(persistent_settings) = load_persistent_settings() {
+            insert_settings_resources(app, persistent_settings.get());
+            app.insert_resource(persistent_settings);
         }
 
         // Register settings states
@@ -103,16 +106,57 @@ impl Plugin for SettingsPlugin {
                 OnEnter(SettingsMenuState::Controls),
                 setup_controls_settings,
             )
+            // Settings state - Accessibility settings
+            .add_systems(
+                OnEnter(SettingsMenuState::Accessibility),
+                setup_accessibility_settings,
+            )
+            // Settings state - Appearance settings
+            .add_systems(
+                OnEnter(SettingsMenuState::Appearance),
+                setup_appearance_settings,
+            )
             // Settings interaction system
             .add_systems(
                 Update,
                 (
                     settings_button_action,
                     volume_slider_interaction,
+                    step_volume_sliders.before(apply_volume_updates),
                     apply_volume_updates,
                     quality_button_interaction,
+                    resolution_dropdown_interaction,
+                    display_mode_button_interaction,
+                    vsync_toggle_interaction,
+                    ui_scale_slider_interaction,
+                    step_ui_scale_slider,
+                    action_hints_toggle_interaction,
+                    (
+                        response_timeout_interaction,
+                        table_rotation_interaction,
+                        upright_cards_toggle_interaction,
+                        procedural_frames_toggle_interaction,
+                    ),
+                    accessibility_toggle_interaction,
+                    narration_verbosity_interaction,
+                    text_scale_slider_interaction,
+                    step_text_scale_slider,
+                    refresh_card_frames_on_palette_change,
+                    theme_button_interaction,
+                    update_theme_previews
+                        .after(theme_button_interaction)
+                        .run_if(resource_changed::<CosmeticSettings>),
                 ),
             )
+            // Scale UI text once it is spawned, before it is laid out
+            .add_systems(PostUpdate, apply_text_scale.before(UiSystem::Prepare))
+            // Apply video settings to the window whenever they change
+            .add_systems(
+                Update,
+                apply_video_settings.run_if(resource_changed::<VideoSettings>),
+            )
+            // Write settings to disk whenever they change
+            .add_systems(Update, persist_settings_on_change)
             // Add handle_settings_back_input with condition using helper
             .add_systems(
                 Update,
@@ -120,38 +164,31 @@ impl Plugin for SettingsPlugin {
                     should_handle_settings_back(*state.get())
                 }),
             )
-            // Apply settings on startup
-            .add_systems(Startup, apply_settings)
-            // Save settings and cleanup when exiting any settings menu
-            // Use specific despawn systems for each state
+            // Clean up each settings screen when leaving it
             .add_systems(
                 OnExit(SettingsMenuState::Audio),
-                (
-                    save_settings.in_set(SaveSettingsSet),
-                    despawn_screen::<OnAudioSettingsMenu>.in_set(DespawnScreenSet),
-                )
-                    .chain(),
+                despawn_screen::<OnAudioSettingsMenu>,
             )
             .add_systems(
                 OnExit(SettingsMenuState::Video),
-                (
-                    save_settings.in_set(SaveSettingsSet),
-                    despawn_screen::<OnVideoSettingsMenu>.in_set(DespawnScreenSet),
-                )
-                    .chain(),
+                despawn_screen::<OnVideoSettingsMenu>,
             )
             .add_systems(
                 OnExit(SettingsMenuState::Gameplay),
-                (
-                    save_settings.in_set(SaveSettingsSet),
-                    despawn_screen::<OnGameplaySettingsMenu>.in_set(DespawnScreenSet),
-                )
-                    .chain(),
+                despawn_screen::<OnGameplaySettingsMenu>,
             )
             .add_systems(
                 OnExit(SettingsMenuState::Controls),
                 despawn_screen::<OnControlsSettingsMenu>,
             )
+            .add_systems(
+                OnExit(SettingsMenuState::Accessibility),
+                despawn_screen::<OnAccessibilitySettingsMenu>,
+            )
+            .add_systems(
+                OnExit(SettingsMenuState::Appearance),
+                despawn_screen::<OnAppearanceSettingsMenu>,
+            )
             .add_systems(
                 OnExit(SettingsMenuState::Main),
                 despawn_screen::<OnMainSettingsMenu>,
@@ -186,56 +223,3 @@ impl Default for CurrentGraphicsQuality {
         }
     }
 }
-
-/// Apply saved settings on startup
-fn apply_settings(
-    mut volume_settings: ResMut<VolumeSettings>,
-    mut gameplay_settings: ResMut<GameplaySettings>,
-    mut graphics_quality: ResMut<CurrentGraphicsQuality>,
-    persistent_settings: Res<Persistent<RummageSettings>>,
-) {
-    info!("Applying saved settings");
-
-    // Apply volume settings
-    volume_settings.master = persistent_settings.get().volume.master;
-    volume_settings.music = persistent_settings.get().volume.music;
-    volume_settings.sfx = persistent_settings.get().volume.sfx;
-
-    // Apply gameplay settings
-    gameplay_settings.auto_pass = persistent_settings.get().gameplay.auto_pass;
-    gameplay_settings.show_tooltips = persistent_settings.get().gameplay.show_tooltips;
-
-    // Apply graphics settings - now using Copy trait
-    graphics_quality.quality = persistent_settings.get().graphics;
-
-    info!("Settings applied successfully");
-}
-
-/// Save current settings to persistent storage
-fn save_settings(
-    volume_settings: Res<VolumeSettings>,
-    gameplay_settings: Res<GameplaySettings>,
-    graphics_quality: Res<CurrentGraphicsQuality>,
-    mut persistent_settings: ResMut<Persistent<RummageSettings>>,
-) {
-    info!("Saving current settings");
-
-    // Save volume settings
-    persistent_settings.get_mut().volume.master = volume_settings.master;
-    persistent_settings.get_mut().volume.music = volume_settings.music;
-    persistent_settings.get_mut().volume.sfx = volume_settings.sfx;
-
-    // Save gameplay settings
-    persistent_settings.get_mut().gameplay.auto_pass = gameplay_settings.auto_pass;
-    persistent_settings.get_mut().gameplay.show_tooltips = gameplay_settings.show_tooltips;
-
-    // Save graphics settings - now using Copy trait
-    persistent_settings.get_mut().graphics = graphics_quality.quality;
-
-    // Persist changes to disk
-    if let Err(e) = persistent_settings.persist() {
-        error!("Failed to save settings: {:?}", e);
-    } else {
-        info!("Settings saved successfully");
-    }
-}
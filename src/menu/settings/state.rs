@@ -13,6 +13,10 @@ pub enum SettingsMenuState {
     Gameplay,
     /// Controls settings submenu
     Controls,
+    /// Accessibility settings submenu
+    Accessibility,
+    /// Card back and playmat settings submenu
+    Appearance,
     /// Disabled state - no UI is shown
     #[default]
     Disabled,
@@ -28,6 +32,8 @@ impl SettingsMenuState {
             Self::Audio => "Audio Settings",
             Self::Gameplay => "Gameplay Settings",
             Self::Controls => "Controls Settings",
+            Self::Accessibility => "Accessibility Settings",
+            Self::Appearance => "Appearance Settings",
             Self::Disabled => "Settings Disabled",
         }
     }
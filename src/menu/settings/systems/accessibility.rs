@@ -0,0 +1,447 @@
+use super::common::{
+    SELECTED_COLOR, TEXT_COLOR, UNSELECTED_COLOR, spawn_settings_button, spawn_settings_container,
+    spawn_settings_root, spawn_settings_title,
+};
+use crate::cards::frame::{CardFrameRenderer, CardFrameTexture, ProceduralCardFrame};
+use crate::input::{NavigationSlider, SliderStepEvent};
+use crate::menu::components::*;
+use crate::menu::settings::components::{
+    AccessibilityOption, AccessibilitySettings, AccessibilityToggleButton, MAX_TEXT_SCALE,
+    MIN_TEXT_SCALE, NarrationVerbosity, NarrationVerbosityButton, OnAccessibilitySettingsMenu,
+    SettingsButtonAction, SettingsMenuItem, TextScaleSlider, TextScaleSliderFill,
+    TextScaleValueText,
+};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+/// Font size a UI text was spawned with, before the text scale is applied
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BaseFontSize(pub f32);
+
+/// Sets up the accessibility settings UI elements
+pub fn setup_accessibility_settings(mut commands: Commands, settings: Res<AccessibilitySettings>) {
+    info!("Setting up accessibility settings menu");
+
+    let root_entity = spawn_settings_root(
+        &mut commands,
+        Color::srgba(0.0, 0.0, 0.0, 0.7),
+        "Accessibility Settings",
+    );
+
+    // Add the marker component to the root entity
+    commands
+        .entity(root_entity)
+        .insert(OnAccessibilitySettingsMenu);
+
+    let mut container_entity = Entity::PLACEHOLDER;
+    commands.entity(root_entity).with_children(|parent| {
+        spawn_settings_title(parent, "Accessibility Settings");
+        container_entity = spawn_settings_container(parent);
+        spawn_settings_button(parent, "Back", SettingsButtonAction::NavigateToMain);
+    });
+
+    commands.entity(container_entity).with_children(|parent| {
+        create_accessibility_toggle(
+            parent,
+            "Colorblind Mana",
+            AccessibilityOption::ColorblindMana,
+            settings.colorblind_mana,
+        );
+        create_text_scale_setting(parent, settings.text_scale);
+        create_accessibility_toggle(
+            parent,
+            "High Contrast Zones",
+            AccessibilityOption::HighContrast,
+            settings.high_contrast,
+        );
+        create_accessibility_toggle(
+            parent,
+            "Reduced Motion",
+            AccessibilityOption::ReducedMotion,
+            settings.reduced_motion,
+        );
+        create_narration_setting(parent, settings.narration);
+    });
+}
+
+/// Spawns a labelled accessibility row and fills it with the given controls
+fn spawn_accessibility_row(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    controls: impl FnOnce(&mut ChildSpawnerCommands),
+) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(90.0),
+                height: Val::Px(50.0),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            MenuItem,
+            SettingsMenuItem,
+            Name::new(format!("{} Row", label)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                MenuItem,
+                SettingsMenuItem,
+                Name::new(format!("{} Label", label)),
+            ));
+            controls(parent);
+        });
+}
+
+/// Creates an on/off button for an accessibility option
+fn create_accessibility_toggle(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    option: AccessibilityOption,
+    enabled: bool,
+) {
+    spawn_accessibility_row(parent, label, |parent| {
+        parent
+            .spawn((
+                Button,
+                Node {
+                    width: Val::Px(80.0),
+                    height: Val::Px(36.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(if enabled {
+                    SELECTED_COLOR
+                } else {
+                    UNSELECTED_COLOR
+                }),
+                AccessibilityToggleButton(option),
+                MenuItem,
+                SettingsMenuItem,
+                Name::new(format!("{} Button", label)),
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Text::new(if enabled { "On" } else { "Off" }),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+    });
+}
+
+/// Creates the button cycling through narration verbosity levels
+fn create_narration_setting(parent: &mut ChildSpawnerCommands, verbosity: NarrationVerbosity) {
+    spawn_accessibility_row(parent, "Narration", |parent| {
+        parent
+            .spawn((
+                Button,
+                Node {
+                    width: Val::Px(100.0),
+                    height: Val::Px(36.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(if verbosity == NarrationVerbosity::Off {
+                    UNSELECTED_COLOR
+                } else {
+                    SELECTED_COLOR
+                }),
+                NarrationVerbosityButton,
+                MenuItem,
+                SettingsMenuItem,
+                Name::new("Narration Button"),
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Text::new(verbosity.label()),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+    });
+}
+
+/// Creates the text scale slider
+fn create_text_scale_setting(parent: &mut ChildSpawnerCommands, text_scale: f32) {
+    spawn_accessibility_row(parent, "Text Scale", |parent| {
+        parent
+            .spawn((
+                Node {
+                    width: Val::Px(150.0),
+                    height: Val::Px(20.0),
+                    ..default()
+                },
+                Button,
+                BackgroundColor(UNSELECTED_COLOR),
+                TextScaleSlider,
+                NavigationSlider,
+                MenuItem,
+                SettingsMenuItem,
+                Name::new("Text Scale Slider"),
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Node {
+                        width: Val::Percent(text_scale_to_percent(text_scale)),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::WHITE),
+                    TextScaleSliderFill,
+                    MenuItem,
+                    SettingsMenuItem,
+                    Name::new("Text Scale Slider Fill"),
+                ));
+            });
+
+        parent.spawn((
+            Text::new(format!("{:.0}%", text_scale * 100.0)),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(TEXT_COLOR),
+            TextScaleValueText,
+            MenuItem,
+            SettingsMenuItem,
+            Name::new("Text Scale Value"),
+        ));
+    });
+}
+
+/// Position of a text scale on the slider, as a percentage of its width
+pub(super) fn text_scale_to_percent(text_scale: f32) -> f32 {
+    ((text_scale - MIN_TEXT_SCALE) / (MAX_TEXT_SCALE - MIN_TEXT_SCALE)).clamp(0.0, 1.0) * 100.0
+}
+
+/// Text scale for a position on the slider, snapped to 5% steps
+pub(super) fn text_scale_from_fraction(fraction: f32) -> f32 {
+    let scale = MIN_TEXT_SCALE + fraction.clamp(0.0, 1.0) * (MAX_TEXT_SCALE - MIN_TEXT_SCALE);
+    (scale * 20.0).round() / 20.0
+}
+
+/// System to handle the accessibility on/off buttons
+pub fn accessibility_toggle_interaction(
+    mut interaction_query: Query<
+        (
+            &Interaction,
+            &AccessibilityToggleButton,
+            &Children,
+            &mut BackgroundColor,
+        ),
+        Changed<Interaction>,
+    >,
+    mut texts: Query<&mut Text>,
+    mut settings: ResMut<AccessibilitySettings>,
+) {
+    for (interaction, toggle, children, mut color) in interaction_query.iter_mut() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let enabled = settings.toggle(toggle.0);
+        info!("{:?} set to: {}", toggle.0, enabled);
+
+        color.0 = if enabled {
+            SELECTED_COLOR
+        } else {
+            UNSELECTED_COLOR
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = if enabled { "On" } else { "Off" }.to_string();
+            }
+        }
+    }
+}
+
+/// System to cycle the narration verbosity
+pub fn narration_verbosity_interaction(
+    mut interaction_query: Query<
+        (&Interaction, &Children, &mut BackgroundColor),
+        (Changed<Interaction>, With<NarrationVerbosityButton>),
+    >,
+    mut texts: Query<&mut Text>,
+    mut settings: ResMut<AccessibilitySettings>,
+) {
+    for (interaction, children, mut color) in interaction_query.iter_mut() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        settings.narration = settings.narration.next();
+        info!("Narration set to: {:?}", settings.narration);
+
+        color.0 = if settings.narration == NarrationVerbosity::Off {
+            UNSELECTED_COLOR
+        } else {
+            SELECTED_COLOR
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = settings.narration.label().to_string();
+            }
+        }
+    }
+}
+
+/// Show the new text scale on the slider
+fn show_text_scale(
+    scale: f32,
+    fills: &mut Query<&mut Node, With<TextScaleSliderFill>>,
+    value_text: &mut Query<&mut Text, With<TextScaleValueText>>,
+) {
+    for mut fill in fills.iter_mut() {
+        fill.width = Val::Percent(text_scale_to_percent(scale));
+    }
+    for mut text in value_text.iter_mut() {
+        text.0 = format!("{:.0}%", scale * 100.0);
+    }
+}
+
+/// System to drag the text scale slider
+///
+/// Like the UI scale, the text scale is applied when the mouse button is
+/// released, so the slider does not change size while it is being dragged.
+pub fn text_scale_slider_interaction(
+    sliders: Query<(&Interaction, &ComputedNode, &GlobalTransform), With<TextScaleSlider>>,
+    mut fills: Query<&mut Node, With<TextScaleSliderFill>>,
+    mut value_text: Query<&mut Text, With<TextScaleValueText>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    mut pending_scale: Local<Option<f32>>,
+    mut settings: ResMut<AccessibilitySettings>,
+) {
+    if mouse_input.just_released(MouseButton::Left) {
+        if let Some(scale) = pending_scale.take() {
+            if settings.text_scale != scale {
+                info!("Text scale set to: {:.2}", scale);
+                settings.text_scale = scale;
+            }
+        }
+        return;
+    }
+
+    if !mouse_input.pressed(MouseButton::Left) {
+        return;
+    }
+
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Some(cursor) = window.physical_cursor_position() else {
+        return;
+    };
+
+    for (interaction, node, transform) in sliders.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        // Node sizes and transforms are in physical pixels
+        let width = node.size().x.max(1.0);
+        let left = transform.translation().x - width / 2.0;
+        let scale = text_scale_from_fraction((cursor.x - left) / width);
+        *pending_scale = Some(scale);
+        show_text_scale(scale, &mut fills, &mut value_text);
+    }
+}
+
+/// System to step the focused text scale slider by 5% from a keyboard or gamepad
+pub fn step_text_scale_slider(
+    mut steps: EventReader<SliderStepEvent>,
+    sliders: Query<(), With<TextScaleSlider>>,
+    mut fills: Query<&mut Node, With<TextScaleSliderFill>>,
+    mut value_text: Query<&mut Text, With<TextScaleValueText>>,
+    mut settings: ResMut<AccessibilitySettings>,
+) {
+    for step in steps.read() {
+        if !sliders.contains(step.slider) {
+            continue;
+        }
+
+        let scale = ((settings.text_scale * 20.0).round() + step.step as f32) / 20.0;
+        let scale = scale.clamp(MIN_TEXT_SCALE, MAX_TEXT_SCALE);
+        if settings.text_scale == scale {
+            continue;
+        }
+        info!("Text scale set to: {:.2}", scale);
+        settings.text_scale = scale;
+        show_text_scale(scale, &mut fills, &mut value_text);
+    }
+}
+
+/// Scale UI text by the accessibility text scale
+///
+/// Each UI text remembers the size it was spawned with, so changing the scale
+/// never compounds. Text spans count as UI text when their parent is.
+pub fn apply_text_scale(
+    mut commands: Commands,
+    settings: Res<AccessibilitySettings>,
+    mut new_texts: Query<
+        (Entity, &mut TextFont, Option<&ChildOf>, Has<Text>),
+        (Or<(With<Text>, With<TextSpan>)>, Without<BaseFontSize>),
+    >,
+    mut scaled_texts: Query<(&BaseFontSize, &mut TextFont), With<BaseFontSize>>,
+    ui_texts: Query<(), With<Text>>,
+) {
+    let scale = settings.text_scale.clamp(MIN_TEXT_SCALE, MAX_TEXT_SCALE);
+
+    if settings.is_changed() {
+        for (base, mut font) in scaled_texts.iter_mut() {
+            font.font_size = base.0 * scale;
+        }
+    }
+
+    for (entity, mut font, parent, is_text) in new_texts.iter_mut() {
+        let is_ui_span = parent.is_some_and(|parent| ui_texts.contains(parent.parent()));
+        if !is_text && !is_ui_span {
+            continue;
+        }
+
+        commands
+            .entity(entity)
+            .try_insert(BaseFontSize(font.font_size));
+        font.font_size *= scale;
+    }
+}
+
+/// Redraw procedural card frames when the mana palette changes
+pub fn refresh_card_frames_on_palette_change(
+    mut commands: Commands,
+    settings: Res<AccessibilitySettings>,
+    mut colorblind: Local<Option<bool>>,
+    renderer: Option<ResMut<CardFrameRenderer>>,
+    cards: Query<Entity, (With<ProceduralCardFrame>, With<CardFrameTexture>)>,
+) {
+    let previous = colorblind.replace(settings.colorblind_mana);
+    if previous.is_none_or(|previous| previous == settings.colorblind_mana) {
+        return;
+    }
+
+    let Some(mut renderer) = renderer else {
+        return;
+    };
+
+    // Dropping the cached textures makes every card render its frame again
+    renderer.invalidate();
+    for entity in cards.iter() {
+        commands.entity(entity).remove::<CardFrameTexture>();
+    }
+}
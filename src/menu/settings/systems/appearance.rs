@@ -0,0 +1,214 @@
+use super::common::{
+    SELECTED_COLOR, TEXT_COLOR, spawn_settings_button, spawn_settings_container,
+    spawn_settings_root, spawn_settings_title,
+};
+use crate::cosmetics::CosmeticThemes;
+use crate::menu::components::*;
+use crate::menu::settings::components::{
+    CosmeticSettings, OnAppearanceSettingsMenu, SettingsButtonAction, SettingsMenuItem,
+    ThemeButton, ThemePreview, ThemeSlot,
+};
+use bevy::prelude::*;
+
+/// Name of the theme chosen for a slot
+fn theme_name<'a>(
+    themes: &'a CosmeticThemes,
+    settings: &CosmeticSettings,
+    slot: ThemeSlot,
+) -> &'a str {
+    match slot {
+        ThemeSlot::CardBack => &themes.card_back(Some(&settings.card_back)).name,
+        ThemeSlot::Playmat => &themes.playmat(&settings.playmat).name,
+    }
+}
+
+/// Image and color previewing the theme chosen for a slot
+fn theme_preview(
+    themes: &CosmeticThemes,
+    settings: &CosmeticSettings,
+    slot: ThemeSlot,
+) -> ImageNode {
+    let (image, color) = match slot {
+        ThemeSlot::CardBack => {
+            let theme = themes.card_back(Some(&settings.card_back));
+            (themes.image(theme.image.as_deref()), theme.color)
+        }
+        ThemeSlot::Playmat => {
+            let theme = themes.playmat(&settings.playmat);
+            (themes.image(theme.image.as_deref()), theme.mat_color)
+        }
+    };
+    ImageNode::new(image).with_color(color)
+}
+
+/// Sets up the appearance settings UI elements
+pub fn setup_appearance_settings(
+    mut commands: Commands,
+    settings: Res<CosmeticSettings>,
+    themes: Res<CosmeticThemes>,
+) {
+    info!("Setting up appearance settings menu");
+
+    let root_entity = spawn_settings_root(
+        &mut commands,
+        Color::srgba(0.0, 0.0, 0.0, 0.7),
+        "Appearance Settings",
+    );
+
+    // Add the marker component to the root entity
+    commands
+        .entity(root_entity)
+        .insert(OnAppearanceSettingsMenu);
+
+    let mut container_entity = Entity::PLACEHOLDER;
+    commands.entity(root_entity).with_children(|parent| {
+        spawn_settings_title(parent, "Appearance Settings");
+        container_entity = spawn_settings_container(parent);
+        spawn_settings_button(parent, "Back", SettingsButtonAction::NavigateToMain);
+    });
+
+    commands.entity(container_entity).with_children(|parent| {
+        create_theme_setting(parent, "Card Back", ThemeSlot::CardBack, &themes, &settings);
+        create_theme_setting(parent, "Playmat", ThemeSlot::Playmat, &themes, &settings);
+        create_theme_preview(parent, &themes, &settings);
+    });
+}
+
+/// Creates a labelled button cycling through the themes for a slot
+fn create_theme_setting(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    slot: ThemeSlot,
+    themes: &CosmeticThemes,
+    settings: &CosmeticSettings,
+) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(90.0),
+                height: Val::Px(50.0),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            MenuItem,
+            SettingsMenuItem,
+            Name::new(format!("{} Row", label)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                MenuItem,
+                SettingsMenuItem,
+                Name::new(format!("{} Label", label)),
+            ));
+
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(140.0),
+                        height: Val::Px(36.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(SELECTED_COLOR),
+                    ThemeButton(slot),
+                    MenuItem,
+                    SettingsMenuItem,
+                    Name::new(format!("{} Button", label)),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(theme_name(themes, settings, slot)),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+        });
+}
+
+/// Creates a small playmat with a card back on it, showing the chosen themes
+fn create_theme_preview(
+    parent: &mut ChildSpawnerCommands,
+    themes: &CosmeticThemes,
+    settings: &CosmeticSettings,
+) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(240.0),
+                height: Val::Px(140.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            theme_preview(themes, settings, ThemeSlot::Playmat),
+            ThemePreview(ThemeSlot::Playmat),
+            MenuItem,
+            SettingsMenuItem,
+            Name::new("Playmat Preview"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Px(72.0),
+                    height: Val::Px(100.0),
+                    ..default()
+                },
+                theme_preview(themes, settings, ThemeSlot::CardBack),
+                ThemePreview(ThemeSlot::CardBack),
+                MenuItem,
+                SettingsMenuItem,
+                Name::new("Card Back Preview"),
+            ));
+        });
+}
+
+/// System to cycle through the themes when their button is pressed
+pub fn theme_button_interaction(
+    mut interaction_query: Query<(&Interaction, &ThemeButton, &Children), Changed<Interaction>>,
+    mut texts: Query<&mut Text>,
+    themes: Res<CosmeticThemes>,
+    mut settings: ResMut<CosmeticSettings>,
+) {
+    for (interaction, button, children) in interaction_query.iter_mut() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        match button.0 {
+            ThemeSlot::CardBack => settings.card_back = themes.next_card_back(&settings.card_back),
+            ThemeSlot::Playmat => settings.playmat = themes.next_playmat(&settings.playmat),
+        }
+        info!("{:?} theme set to: {:?}", button.0, settings.get(button.0));
+
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = theme_name(&themes, &settings, button.0).to_string();
+            }
+        }
+    }
+}
+
+/// System to show the chosen themes on the preview
+pub fn update_theme_previews(
+    themes: Res<CosmeticThemes>,
+    settings: Res<CosmeticSettings>,
+    mut previews: Query<(&ThemePreview, &mut ImageNode)>,
+) {
+    for (preview, mut image) in previews.iter_mut() {
+        *image = theme_preview(&themes, &settings, preview.0);
+    }
+}
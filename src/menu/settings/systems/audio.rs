@@ -1,5 +1,6 @@
 use super::common::*;
 use crate::camera::components::AppLayer;
+use crate::input::{NavigationSlider, SliderStepEvent};
 use crate::menu::components::MenuItem;
 use crate::menu::settings::components::OnAudioSettingsMenu;
 use crate::menu::settings::components::*;
@@ -75,99 +76,121 @@ pub fn setup_audio_settings(mut commands: Commands, volume_settings: Res<VolumeS
 
         let _container = spawn_settings_container(parent);
 
-        // Volume slider
-        parent
-            .spawn((
-                Node {
-                    width: Val::Px(300.0),
-                    height: Val::Px(50.0),
-                    justify_content: JustifyContent::SpaceBetween,
-                    align_items: AlignItems::Center,
-                    margin: UiRect::all(Val::Px(10.0)),
+        spawn_volume_slider(
+            parent,
+            "Master Volume",
+            VolumeType::Master,
+            volume_settings.master,
+        );
+        spawn_volume_slider(
+            parent,
+            "Music Volume",
+            VolumeType::Music,
+            volume_settings.music,
+        );
+        spawn_volume_slider(parent, "SFX Volume", VolumeType::Sfx, volume_settings.sfx);
+
+        // Back button
+        spawn_settings_button(parent, "Back", SettingsButtonAction::NavigateToMain);
+    });
+}
+
+/// Spawns a labelled volume slider with its value text
+fn spawn_volume_slider(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    volume_type: VolumeType,
+    value: f32,
+) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(300.0),
+                height: Val::Px(50.0),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            MenuItem,
+            SettingsMenuItem,
+            AppLayer::Menu.layer(),
+            Visibility::Visible,
+            InheritedVisibility::VISIBLE,
+            Name::new(format!("{} Slider Container", label)),
+        ))
+        .with_children(|parent| {
+            // Label
+            parent.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 20.0,
                     ..default()
                 },
+                TextColor(TEXT_COLOR),
                 MenuItem,
                 SettingsMenuItem,
                 AppLayer::Menu.layer(),
                 Visibility::Visible,
                 InheritedVisibility::VISIBLE,
-                Name::new("Volume Slider Container"),
-            ))
-            .with_children(|parent| {
-                // Label
-                parent.spawn((
-                    Text::new("Master Volume"),
-                    TextFont {
-                        font_size: 20.0,
+                Name::new(format!("{} Label", label)),
+            ));
+
+            // Slider
+            let initial_volume_percent = (value * 100.0).round() as u32;
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(150.0),
+                        height: Val::Px(20.0),
                         ..default()
                     },
-                    TextColor(TEXT_COLOR),
+                    Button,
                     MenuItem,
                     SettingsMenuItem,
                     AppLayer::Menu.layer(),
                     Visibility::Visible,
                     InheritedVisibility::VISIBLE,
-                    Name::new("Volume Label"),
-                ));
-
-                // Slider
-                let initial_volume_percent = (volume_settings.master * 100.0).round() as u32;
-
-                parent
-                    .spawn((
+                    Name::new(format!("{} Slider", label)),
+                    VolumeSlider,
+                    NavigationSlider,
+                    volume_type,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
                         Node {
-                            width: Val::Px(150.0),
-                            height: Val::Px(20.0),
+                            width: Val::Percent(initial_volume_percent as f32),
+                            height: Val::Percent(100.0),
                             ..default()
                         },
-                        Button,
+                        BackgroundColor(Color::WHITE),
                         MenuItem,
                         SettingsMenuItem,
                         AppLayer::Menu.layer(),
                         Visibility::Visible,
                         InheritedVisibility::VISIBLE,
-                        Name::new("Volume Slider"),
-                        VolumeSlider,
-                        VolumeType::Master,
-                    ))
-                    .with_children(|parent| {
-                        parent.spawn((
-                            Node {
-                                width: Val::Percent(initial_volume_percent as f32),
-                                height: Val::Percent(100.0),
-                                ..default()
-                            },
-                            BackgroundColor(Color::WHITE),
-                            MenuItem,
-                            SettingsMenuItem,
-                            AppLayer::Menu.layer(),
-                            Visibility::Visible,
-                            InheritedVisibility::VISIBLE,
-                            Name::new("Volume Slider Fill"),
-                        ));
-                    });
-
-                // Value text
-                parent.spawn((
-                    Text::new(format!("{}%", initial_volume_percent)),
-                    TextFont {
-                        font_size: 20.0,
-                        ..default()
-                    },
-                    TextColor(TEXT_COLOR),
-                    MenuItem,
-                    SettingsMenuItem,
-                    AppLayer::Menu.layer(),
-                    Visibility::Visible,
-                    InheritedVisibility::VISIBLE,
-                    VolumeValueText(VolumeType::Master),
-                    Name::new("Volume Value"),
-                ));
-            });
+                        Name::new(format!("{} Slider Fill", label)),
+                    ));
+                });
 
-        // Back button
-        spawn_settings_button(parent, "Back", SettingsButtonAction::NavigateToMain);
-    });
+            // Value text
+            parent.spawn((
+                Text::new(format!("{}%", initial_volume_percent)),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                MenuItem,
+                SettingsMenuItem,
+                AppLayer::Menu.layer(),
+                Visibility::Visible,
+                InheritedVisibility::VISIBLE,
+                VolumeValueText(volume_type),
+                Name::new(format!("{} Value", label)),
+            ));
+        });
 }
 
 /// System to process slider interactions and queue volume updates
@@ -211,13 +234,36 @@ pub fn volume_slider_interaction(
     }
 }
 
+/// System to step focused volume sliders by 5% from a keyboard or gamepad
+pub fn step_volume_sliders(
+    mut steps: EventReader<SliderStepEvent>,
+    sliders: Query<&VolumeType, With<VolumeSlider>>,
+    volume_settings: Res<VolumeSettings>,
+    mut volume_requests: ResMut<VolumeUpdateRequests>,
+) {
+    for step in steps.read() {
+        let Ok(volume_type) = sliders.get(step.slider) else {
+            continue;
+        };
+
+        let current = match volume_type {
+            VolumeType::Master => volume_settings.master,
+            VolumeType::Music => volume_settings.music,
+            VolumeType::Sfx => volume_settings.sfx,
+        };
+        let clamped_value = ((current * 100.0).round() as i32 + step.step * 5).clamp(0, 100);
+        volume_requests
+            .requests
+            .push((*volume_type, clamped_value, clamped_value as f32 / 100.0));
+    }
+}
+
 /// System to apply volume changes and update UI
 pub fn apply_volume_updates(
     mut volume_requests: ResMut<VolumeUpdateRequests>,
     mut text_query: Query<(&mut Text, &VolumeValueText)>,
     mut volume_indicators: Query<(&mut Node, &ChildOf), Without<Button>>,
     volume_type_query: Query<&VolumeType>,
-    mut audio_players: Query<&mut bevy::audio::PlaybackSettings>,
     mut context: VolumeSettingsContext,
 ) {
     if volume_requests.requests.is_empty() {
@@ -232,16 +278,9 @@ pub fn apply_volume_updates(
                 context.volume_settings.master = volume_value;
                 context.global_volume.volume = Volume::Linear(volume_value);
             }
-            VolumeType::Music => {
-                context.volume_settings.music = volume_value;
-                for mut settings in audio_players.iter_mut() {
-                    settings.volume = Volume::Linear(volume_value);
-                }
-            }
-            VolumeType::Sfx => {
-                context.volume_settings.sfx = volume_value;
-                // NOTE: Need a way to apply SFX volume globally or per-sound
-            }
+            // Music and SFX volumes are applied by the audio plugin
+            VolumeType::Music => context.volume_settings.music = volume_value,
+            VolumeType::Sfx => context.volume_settings.sfx = volume_value,
         }
 
         // Update text display
@@ -9,6 +9,11 @@ use crate::menu::settings::components::*;
 /// Text color for settings menu
 pub const TEXT_COLOR: Color = Color::WHITE;
 
+/// Background of a selected option button
+pub const SELECTED_COLOR: Color = Color::srgba(0.4, 0.4, 0.8, 1.0);
+/// Background of an unselected option button
+pub const UNSELECTED_COLOR: Color = Color::srgba(0.2, 0.2, 0.2, 1.0);
+
 /// Creates a settings button with text
 pub fn spawn_settings_button(
     parent: &mut ChildSpawnerCommands,
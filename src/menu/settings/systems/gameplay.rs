@@ -1,6 +1,6 @@
 use super::common::{
-    TEXT_COLOR, create_toggle_setting, spawn_settings_button, spawn_settings_container,
-    spawn_settings_root, spawn_settings_title,
+    SELECTED_COLOR, TEXT_COLOR, UNSELECTED_COLOR, create_toggle_setting, spawn_settings_button,
+    spawn_settings_container, spawn_settings_root, spawn_settings_title,
 };
 use crate::menu::components::*;
 use crate::menu::settings::components::OnGameplaySettingsMenu;
@@ -34,6 +34,11 @@ pub fn setup_gameplay_settings(mut commands: Commands, settings: Res<GameplaySet
     container_children.with_children(|parent| {
         create_toggle_setting(parent, "Auto Pass", settings.auto_pass);
         create_toggle_setting(parent, "Show Tooltips", settings.show_tooltips);
+        create_action_hints_setting(parent, settings.show_action_hints);
+        create_response_timeout_setting(parent, &settings);
+        create_table_rotation_setting(parent, settings.table_rotation);
+        create_upright_cards_setting(parent, settings.upright_cards);
+        create_procedural_frames_setting(parent, settings.procedural_frames);
         // create_slider_setting(parent, "Animation Speed", settings.animation_speed);
     });
 
@@ -43,6 +48,476 @@ pub fn setup_gameplay_settings(mut commands: Commands, settings: Res<GameplaySet
     });
 }
 
+/// Creates the button toggling legal action hints
+fn create_action_hints_setting(parent: &mut ChildSpawnerCommands, enabled: bool) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(90.0),
+                height: Val::Px(50.0),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            MenuItem,
+            SettingsMenuItem,
+            Visibility::Visible,
+            InheritedVisibility::VISIBLE,
+            Name::new("Action Hints Row"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Highlight Legal Actions"),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                MenuItem,
+                SettingsMenuItem,
+                Name::new("Action Hints Label"),
+            ));
+
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(80.0),
+                        height: Val::Px(36.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(if enabled {
+                        SELECTED_COLOR
+                    } else {
+                        UNSELECTED_COLOR
+                    }),
+                    ActionHintsToggleButton,
+                    MenuItem,
+                    SettingsMenuItem,
+                    Name::new("Action Hints Button"),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(if enabled { "On" } else { "Off" }),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+        });
+}
+
+/// System to handle the action hints toggle
+pub fn action_hints_toggle_interaction(
+    mut interaction_query: Query<
+        (&Interaction, &Children, &mut BackgroundColor),
+        (Changed<Interaction>, With<ActionHintsToggleButton>),
+    >,
+    mut texts: Query<&mut Text>,
+    mut gameplay_settings: ResMut<GameplaySettings>,
+) {
+    for (interaction, children, mut color) in interaction_query.iter_mut() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        gameplay_settings.show_action_hints = !gameplay_settings.show_action_hints;
+        let enabled = gameplay_settings.show_action_hints;
+        info!("Action hints set to: {}", enabled);
+
+        color.0 = if enabled {
+            SELECTED_COLOR
+        } else {
+            UNSELECTED_COLOR
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = if enabled { "On" } else { "Off" }.to_string();
+            }
+        }
+    }
+}
+
+/// Creates the button cycling how long the response prompt waits
+fn create_response_timeout_setting(parent: &mut ChildSpawnerCommands, settings: &GameplaySettings) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(90.0),
+                height: Val::Px(50.0),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            MenuItem,
+            SettingsMenuItem,
+            Visibility::Visible,
+            InheritedVisibility::VISIBLE,
+            Name::new("Response Timeout Row"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Response Auto-Pass"),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                MenuItem,
+                SettingsMenuItem,
+                Name::new("Response Timeout Label"),
+            ));
+
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(80.0),
+                        height: Val::Px(36.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(if settings.response_timeout_secs == 0 {
+                        UNSELECTED_COLOR
+                    } else {
+                        SELECTED_COLOR
+                    }),
+                    ResponseTimeoutButton,
+                    MenuItem,
+                    SettingsMenuItem,
+                    Name::new("Response Timeout Button"),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(settings.response_timeout_label()),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+        });
+}
+
+/// System to cycle the response prompt timeout
+pub fn response_timeout_interaction(
+    mut interaction_query: Query<
+        (&Interaction, &Children, &mut BackgroundColor),
+        (Changed<Interaction>, With<ResponseTimeoutButton>),
+    >,
+    mut texts: Query<&mut Text>,
+    mut gameplay_settings: ResMut<GameplaySettings>,
+) {
+    for (interaction, children, mut color) in interaction_query.iter_mut() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        gameplay_settings.response_timeout_secs = gameplay_settings.next_response_timeout();
+        info!(
+            "Response timeout set to: {}",
+            gameplay_settings.response_timeout_label()
+        );
+
+        color.0 = if gameplay_settings.response_timeout_secs == 0 {
+            UNSELECTED_COLOR
+        } else {
+            SELECTED_COLOR
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = gameplay_settings.response_timeout_label();
+            }
+        }
+    }
+}
+
+/// Creates the button cycling how the table is rotated
+fn create_table_rotation_setting(parent: &mut ChildSpawnerCommands, rotation: TableRotation) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(90.0),
+                height: Val::Px(50.0),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            MenuItem,
+            SettingsMenuItem,
+            Visibility::Visible,
+            InheritedVisibility::VISIBLE,
+            Name::new("Table Rotation Row"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Table Rotation"),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                MenuItem,
+                SettingsMenuItem,
+                Name::new("Table Rotation Label"),
+            ));
+
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(140.0),
+                        height: Val::Px(36.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(SELECTED_COLOR),
+                    TableRotationButton,
+                    MenuItem,
+                    SettingsMenuItem,
+                    Name::new("Table Rotation Button"),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(rotation.label()),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+        });
+}
+
+/// System to cycle how the table is rotated
+pub fn table_rotation_interaction(
+    interaction_query: Query<
+        (&Interaction, &Children),
+        (Changed<Interaction>, With<TableRotationButton>),
+    >,
+    mut texts: Query<&mut Text>,
+    mut gameplay_settings: ResMut<GameplaySettings>,
+) {
+    for (interaction, children) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        gameplay_settings.table_rotation = gameplay_settings.table_rotation.next();
+        info!(
+            "Table rotation set to: {}",
+            gameplay_settings.table_rotation.label()
+        );
+
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = gameplay_settings.table_rotation.label().to_string();
+            }
+        }
+    }
+}
+
+/// Creates the button toggling upright cards
+fn create_upright_cards_setting(parent: &mut ChildSpawnerCommands, enabled: bool) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(90.0),
+                height: Val::Px(50.0),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            MenuItem,
+            SettingsMenuItem,
+            Visibility::Visible,
+            InheritedVisibility::VISIBLE,
+            Name::new("Upright Cards Row"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Upright Opponent Cards"),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                MenuItem,
+                SettingsMenuItem,
+                Name::new("Upright Cards Label"),
+            ));
+
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(80.0),
+                        height: Val::Px(36.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(if enabled {
+                        SELECTED_COLOR
+                    } else {
+                        UNSELECTED_COLOR
+                    }),
+                    UprightCardsToggleButton,
+                    MenuItem,
+                    SettingsMenuItem,
+                    Name::new("Upright Cards Button"),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(if enabled { "On" } else { "Off" }),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+        });
+}
+
+/// System to handle the upright cards toggle
+pub fn upright_cards_toggle_interaction(
+    mut interaction_query: Query<
+        (&Interaction, &Children, &mut BackgroundColor),
+        (Changed<Interaction>, With<UprightCardsToggleButton>),
+    >,
+    mut texts: Query<&mut Text>,
+    mut gameplay_settings: ResMut<GameplaySettings>,
+) {
+    for (interaction, children, mut color) in interaction_query.iter_mut() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        gameplay_settings.upright_cards = !gameplay_settings.upright_cards;
+        let enabled = gameplay_settings.upright_cards;
+        info!("Upright cards set to: {}", enabled);
+
+        color.0 = if enabled {
+            SELECTED_COLOR
+        } else {
+            UNSELECTED_COLOR
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = if enabled { "On" } else { "Off" }.to_string();
+            }
+        }
+    }
+}
+
+/// Creates the button toggling procedural card frames
+fn create_procedural_frames_setting(parent: &mut ChildSpawnerCommands, enabled: bool) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(90.0),
+                height: Val::Px(50.0),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            MenuItem,
+            SettingsMenuItem,
+            Visibility::Visible,
+            InheritedVisibility::VISIBLE,
+            Name::new("Procedural Frames Row"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Procedural Card Frames"),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                MenuItem,
+                SettingsMenuItem,
+                Name::new("Procedural Frames Label"),
+            ));
+
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(80.0),
+                        height: Val::Px(36.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(if enabled {
+                        SELECTED_COLOR
+                    } else {
+                        UNSELECTED_COLOR
+                    }),
+                    ProceduralFramesToggleButton,
+                    MenuItem,
+                    SettingsMenuItem,
+                    Name::new("Procedural Frames Button"),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(if enabled { "On" } else { "Off" }),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+        });
+}
+
+/// System to handle the procedural card frames toggle
+pub fn procedural_frames_toggle_interaction(
+    mut interaction_query: Query<
+        (&Interaction, &Children, &mut BackgroundColor),
+        (Changed<Interaction>, With<ProceduralFramesToggleButton>),
+    >,
+    mut texts: Query<&mut Text>,
+    mut gameplay_settings: ResMut<GameplaySettings>,
+) {
+    for (interaction, children, mut color) in interaction_query.iter_mut() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        gameplay_settings.procedural_frames = !gameplay_settings.procedural_frames;
+        let enabled = gameplay_settings.procedural_frames;
+        info!("Procedural frames set to: {}", enabled);
+
+        color.0 = if enabled {
+            SELECTED_COLOR
+        } else {
+            UNSELECTED_COLOR
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = if enabled { "On" } else { "Off" }.to_string();
+            }
+        }
+    }
+}
+
 /// Creates an animation speed setting display
 fn create_animation_speed_setting(parent: &mut ChildSpawnerCommands, speed: f32) {
     parent
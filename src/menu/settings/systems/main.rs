@@ -1,6 +1,7 @@
 use super::common::{
     spawn_settings_button, spawn_settings_container, spawn_settings_root, spawn_settings_title,
 };
+use crate::input::{ActionInput, InputAction};
 use crate::menu::settings::components::OnMainSettingsMenu;
 use crate::menu::settings::components::SettingsButtonAction;
 use crate::menu::settings::state::SettingsMenuState;
@@ -49,6 +50,16 @@ pub fn setup_main_settings(mut commands: Commands) {
         spawn_settings_button(parent, "Audio", SettingsButtonAction::NavigateToAudio);
         spawn_settings_button(parent, "Gameplay", SettingsButtonAction::NavigateToGameplay);
         spawn_settings_button(parent, "Controls", SettingsButtonAction::NavigateToControls);
+        spawn_settings_button(
+            parent,
+            "Accessibility",
+            SettingsButtonAction::NavigateToAccessibility,
+        );
+        spawn_settings_button(
+            parent,
+            "Appearance",
+            SettingsButtonAction::NavigateToAppearance,
+        );
         spawn_settings_button(parent, "Back", SettingsButtonAction::ExitSettings);
     });
 }
@@ -79,6 +90,12 @@ pub fn settings_button_action(
                 SettingsButtonAction::NavigateToControls => {
                     next_state.set(SettingsMenuState::Controls);
                 }
+                SettingsButtonAction::NavigateToAccessibility => {
+                    next_state.set(SettingsMenuState::Accessibility);
+                }
+                SettingsButtonAction::NavigateToAppearance => {
+                    next_state.set(SettingsMenuState::Appearance);
+                }
                 SettingsButtonAction::NavigateToMain => {
                     next_state.set(SettingsMenuState::Main);
                 }
@@ -94,15 +111,15 @@ pub fn settings_button_action(
     }
 }
 
-/// Handles the Escape key press to exit the settings menu
+/// Handles the Cancel action (Escape or B) to exit the settings menu
 pub fn handle_settings_back_input(
-    input: Res<ButtonInput<KeyCode>>,
+    actions: Res<ActionInput>,
     mut settings_menu_state: ResMut<NextState<SettingsMenuState>>,
     mut game_menu_state: ResMut<NextState<GameMenuState>>,
     mut context: ResMut<StateTransitionContext>,
 ) {
-    if input.just_pressed(KeyCode::Escape) {
-        info!("Escape key pressed, exiting settings menu");
+    if actions.just_pressed(InputAction::Cancel) {
+        info!("Cancel pressed, exiting settings menu");
         info!(
             "handle_settings_back_input: Context before exit: origin={:?}",
             context.settings_origin
@@ -1,3 +1,5 @@
+pub mod accessibility;
+pub mod appearance;
 pub mod audio;
 pub mod common;
 pub mod controls;
@@ -6,6 +8,9 @@ pub mod main;
 pub mod state_transitions;
 pub mod video;
 
+#[cfg(test)]
+mod tests;
+
 use crate::menu::settings::components::SettingsMenuItem;
 use crate::menu::settings::state::SettingsMenuState;
 use crate::menu::state::StateTransitionContext;
@@ -108,6 +108,8 @@ pub fn is_in_settings_submenu(settings_state: SettingsMenuState) -> bool {
             | SettingsMenuState::Audio
             | SettingsMenuState::Gameplay
             | SettingsMenuState::Controls
+            | SettingsMenuState::Accessibility
+            | SettingsMenuState::Appearance
     )
 }
 
@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+
+use crate::menu::settings::components::{AccessibilitySettings, MAX_TEXT_SCALE, MIN_TEXT_SCALE};
+use crate::menu::settings::systems::accessibility::{
+    apply_text_scale, text_scale_from_fraction, text_scale_to_percent,
+};
+
+#[test]
+fn test_text_scale_slider_round_trip() {
+    for scale in [MIN_TEXT_SCALE, 1.0, 1.5, MAX_TEXT_SCALE] {
+        let fraction = text_scale_to_percent(scale) / 100.0;
+        assert!((text_scale_from_fraction(fraction) - scale).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn test_text_scale_applies_to_ui_text_only() {
+    let mut app = App::new();
+    app.insert_resource(AccessibilitySettings {
+        text_scale: 1.5,
+        ..default()
+    })
+    .add_systems(Update, apply_text_scale);
+
+    let label = app
+        .world_mut()
+        .spawn((
+            Text::new("Label"),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+        ))
+        .id();
+    let card_name = app
+        .world_mut()
+        .spawn((
+            Text2d::new("Card"),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+        ))
+        .id();
+    app.update();
+
+    let font_size = |app: &App, entity| app.world().get::<TextFont>(entity).unwrap().font_size;
+    assert_eq!(font_size(&app, label), 30.0);
+    assert_eq!(font_size(&app, card_name), 20.0);
+
+    // Changing the scale starts again from the spawned size
+    app.world_mut()
+        .resource_mut::<AccessibilitySettings>()
+        .text_scale = 1.0;
+    app.update();
+    assert_eq!(font_size(&app, label), 20.0);
+}
@@ -0,0 +1,2 @@
+mod accessibility;
+mod video;
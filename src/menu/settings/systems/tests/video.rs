@@ -0,0 +1,30 @@
+use bevy::window::{PresentMode, WindowMode};
+
+use crate::menu::settings::components::{MAX_UI_SCALE, MIN_UI_SCALE, WindowDisplayMode};
+use crate::menu::settings::systems::video::{
+    present_mode_for, ui_scale_from_fraction, ui_scale_to_percent, window_mode_for,
+};
+
+#[test]
+fn test_ui_scale_slider_round_trip() {
+    for scale in [MIN_UI_SCALE, 1.0, 1.25, MAX_UI_SCALE] {
+        let fraction = ui_scale_to_percent(scale) / 100.0;
+        assert!((ui_scale_from_fraction(fraction) - scale).abs() < f32::EPSILON);
+    }
+}
+
+#[test]
+fn test_ui_scale_is_clamped() {
+    assert_eq!(ui_scale_from_fraction(-1.0), MIN_UI_SCALE);
+    assert_eq!(ui_scale_from_fraction(2.0), MAX_UI_SCALE);
+}
+
+#[test]
+fn test_window_settings_mapping() {
+    assert_eq!(
+        window_mode_for(WindowDisplayMode::Windowed),
+        WindowMode::Windowed
+    );
+    assert_eq!(present_mode_for(true), PresentMode::AutoVsync);
+    assert_eq!(present_mode_for(false), PresentMode::AutoNoVsync);
+}
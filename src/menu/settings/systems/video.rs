@@ -1,17 +1,26 @@
 use super::common::{
-    TEXT_COLOR, spawn_settings_button, spawn_settings_container, spawn_settings_root,
-    spawn_settings_title,
+    SELECTED_COLOR, TEXT_COLOR, UNSELECTED_COLOR, spawn_settings_button, spawn_settings_container,
+    spawn_settings_root, spawn_settings_title,
 };
+use crate::input::{NavigationSlider, SliderStepEvent};
 use crate::menu::components::*;
 use crate::menu::settings::components::OnVideoSettingsMenu;
 use crate::menu::settings::components::{
-    GraphicsQuality, QualityButton, SettingsButtonAction, SettingsMenuItem,
+    DisplayModeButton, GraphicsQuality, MAX_UI_SCALE, MIN_UI_SCALE, QualityButton,
+    ResolutionDropdownButton, ResolutionDropdownList, ResolutionOption, ResolutionValueText,
+    SUPPORTED_RESOLUTIONS, SettingsButtonAction, SettingsMenuItem, UiScaleSlider,
+    UiScaleSliderFill, UiScaleValueText, VideoSettings, VsyncToggleButton, WindowDisplayMode,
 };
 use crate::menu::settings::plugin::CurrentGraphicsQuality;
 use bevy::prelude::*;
+use bevy::window::{MonitorSelection, PresentMode, PrimaryWindow, VideoModeSelection, WindowMode};
 
 /// Sets up the video settings UI elements
-pub fn setup_video_settings(mut commands: Commands, graphics_quality: Res<CurrentGraphicsQuality>) {
+pub fn setup_video_settings(
+    mut commands: Commands,
+    graphics_quality: Res<CurrentGraphicsQuality>,
+    video_settings: Res<VideoSettings>,
+) {
     info!("Setting up video settings menu");
 
     let root_entity = spawn_settings_root(
@@ -47,9 +56,499 @@ pub fn setup_video_settings(mut commands: Commands, graphics_quality: Res<Curren
                 "Graphics Quality",
                 &graphics_quality.quality,
             );
+            create_resolution_setting(container_parent, video_settings.resolution);
+            create_display_mode_setting(container_parent, video_settings.display_mode);
+            create_vsync_setting(container_parent, video_settings.vsync);
+            create_ui_scale_setting(container_parent, video_settings.ui_scale);
+        });
+}
+
+/// Spawns a labelled settings row and fills it with the given controls
+fn spawn_video_setting_row(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    controls: impl FnOnce(&mut ChildSpawnerCommands),
+) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(90.0),
+                height: Val::Px(50.0),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            MenuItem,
+            SettingsMenuItem,
+            Visibility::Visible,
+            InheritedVisibility::VISIBLE,
+            Name::new(format!("{} Setting Row", label)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                MenuItem,
+                SettingsMenuItem,
+                Name::new(format!("{} Label", label)),
+            ));
+
+            controls(parent);
+        });
+}
+
+/// Spawns a small option button with a text label
+fn spawn_option_button(
+    parent: &mut ChildSpawnerCommands,
+    text: &str,
+    width: f32,
+    selected: bool,
+    bundle: impl Bundle,
+) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(width),
+                height: Val::Px(36.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                margin: UiRect::horizontal(Val::Px(5.0)),
+                ..default()
+            },
+            BackgroundColor(if selected {
+                SELECTED_COLOR
+            } else {
+                UNSELECTED_COLOR
+            }),
+            bundle,
+            MenuItem,
+            SettingsMenuItem,
+            Name::new(format!("{} Button", text)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(text),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
         });
 }
 
+/// Formats a resolution for display
+fn resolution_label(resolution: [u32; 2]) -> String {
+    format!("{}x{}", resolution[0], resolution[1])
+}
+
+/// Creates the resolution dropdown
+fn create_resolution_setting(parent: &mut ChildSpawnerCommands, current: [u32; 2]) {
+    spawn_video_setting_row(parent, "Resolution", |parent| {
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Column,
+                ..default()
+            })
+            .with_children(|parent| {
+                // Toggle button showing the current resolution
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(140.0),
+                            height: Val::Px(36.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(UNSELECTED_COLOR),
+                        ResolutionDropdownButton,
+                        MenuItem,
+                        SettingsMenuItem,
+                        Name::new("Resolution Dropdown Button"),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            Text::new(resolution_label(current)),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                            ResolutionValueText,
+                        ));
+                    });
+
+                // Option list, shown while the dropdown is open
+                parent
+                    .spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            top: Val::Px(38.0),
+                            flex_direction: FlexDirection::Column,
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.95)),
+                        GlobalZIndex(10),
+                        Visibility::Hidden,
+                        ResolutionDropdownList,
+                        MenuItem,
+                        SettingsMenuItem,
+                        Name::new("Resolution Dropdown List"),
+                    ))
+                    .with_children(|parent| {
+                        for &resolution in SUPPORTED_RESOLUTIONS {
+                            spawn_option_button(
+                                parent,
+                                &resolution_label(resolution),
+                                130.0,
+                                resolution == current,
+                                ResolutionOption(resolution),
+                            );
+                        }
+                    });
+            });
+    });
+}
+
+/// Creates the windowed/borderless/fullscreen switch
+fn create_display_mode_setting(parent: &mut ChildSpawnerCommands, current: WindowDisplayMode) {
+    spawn_video_setting_row(parent, "Display Mode", |parent| {
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                ..default()
+            })
+            .with_children(|parent| {
+                for mode in [
+                    WindowDisplayMode::Windowed,
+                    WindowDisplayMode::Borderless,
+                    WindowDisplayMode::Fullscreen,
+                ] {
+                    spawn_option_button(
+                        parent,
+                        mode.label(),
+                        100.0,
+                        mode == current,
+                        DisplayModeButton(mode),
+                    );
+                }
+            });
+    });
+}
+
+/// Creates the vsync toggle
+fn create_vsync_setting(parent: &mut ChildSpawnerCommands, vsync: bool) {
+    spawn_video_setting_row(parent, "VSync", |parent| {
+        spawn_option_button(
+            parent,
+            if vsync { "On" } else { "Off" },
+            80.0,
+            vsync,
+            VsyncToggleButton,
+        );
+    });
+}
+
+/// Creates the UI scale slider
+fn create_ui_scale_setting(parent: &mut ChildSpawnerCommands, ui_scale: f32) {
+    spawn_video_setting_row(parent, "UI Scale", |parent| {
+        parent
+            .spawn((
+                Node {
+                    width: Val::Px(150.0),
+                    height: Val::Px(20.0),
+                    ..default()
+                },
+                Button,
+                BackgroundColor(UNSELECTED_COLOR),
+                UiScaleSlider,
+                NavigationSlider,
+                MenuItem,
+                SettingsMenuItem,
+                Name::new("UI Scale Slider"),
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Node {
+                        width: Val::Percent(ui_scale_to_percent(ui_scale)),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::WHITE),
+                    UiScaleSliderFill,
+                    MenuItem,
+                    SettingsMenuItem,
+                    Name::new("UI Scale Slider Fill"),
+                ));
+            });
+
+        parent.spawn((
+            Text::new(format!("{:.0}%", ui_scale * 100.0)),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(TEXT_COLOR),
+            UiScaleValueText,
+            MenuItem,
+            SettingsMenuItem,
+            Name::new("UI Scale Value"),
+        ));
+    });
+}
+
+/// Position of a UI scale on the slider, as a percentage of its width
+pub(super) fn ui_scale_to_percent(ui_scale: f32) -> f32 {
+    ((ui_scale - MIN_UI_SCALE) / (MAX_UI_SCALE - MIN_UI_SCALE)).clamp(0.0, 1.0) * 100.0
+}
+
+/// UI scale for a position on the slider, snapped to 5% steps
+pub(super) fn ui_scale_from_fraction(fraction: f32) -> f32 {
+    let scale = MIN_UI_SCALE + fraction.clamp(0.0, 1.0) * (MAX_UI_SCALE - MIN_UI_SCALE);
+    (scale * 20.0).round() / 20.0
+}
+
+/// System to open and close the resolution dropdown and pick a resolution
+pub fn resolution_dropdown_interaction(
+    toggles: Query<&Interaction, (Changed<Interaction>, With<ResolutionDropdownButton>)>,
+    options: Query<(&Interaction, &ResolutionOption), Changed<Interaction>>,
+    mut option_colors: Query<(&ResolutionOption, &mut BackgroundColor)>,
+    mut lists: Query<&mut Visibility, With<ResolutionDropdownList>>,
+    mut value_text: Query<&mut Text, With<ResolutionValueText>>,
+    mut video_settings: ResMut<VideoSettings>,
+) {
+    for interaction in toggles.iter() {
+        if *interaction == Interaction::Pressed {
+            for mut visibility in lists.iter_mut() {
+                visibility.toggle_visible_hidden();
+            }
+        }
+    }
+
+    for (interaction, option) in options.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if video_settings.resolution != option.0 {
+            info!("Changing resolution to: {}", resolution_label(option.0));
+            video_settings.resolution = option.0;
+        }
+
+        for (button_option, mut color) in option_colors.iter_mut() {
+            color.0 = if button_option.0 == option.0 {
+                SELECTED_COLOR
+            } else {
+                UNSELECTED_COLOR
+            };
+        }
+        for mut text in value_text.iter_mut() {
+            text.0 = resolution_label(option.0);
+        }
+        for mut visibility in lists.iter_mut() {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
+
+/// System to handle the display mode buttons
+pub fn display_mode_button_interaction(
+    interaction_query: Query<(&Interaction, &DisplayModeButton), Changed<Interaction>>,
+    mut button_query: Query<(&DisplayModeButton, &mut BackgroundColor)>,
+    mut video_settings: ResMut<VideoSettings>,
+) {
+    for (interaction, clicked) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed || video_settings.display_mode == clicked.0 {
+            continue;
+        }
+
+        info!("Changing display mode to: {:?}", clicked.0);
+        video_settings.display_mode = clicked.0;
+
+        for (button, mut color) in button_query.iter_mut() {
+            color.0 = if button.0 == clicked.0 {
+                SELECTED_COLOR
+            } else {
+                UNSELECTED_COLOR
+            };
+        }
+    }
+}
+
+/// System to handle the vsync toggle
+pub fn vsync_toggle_interaction(
+    interaction_query: Query<
+        (&Interaction, &Children),
+        (Changed<Interaction>, With<VsyncToggleButton>),
+    >,
+    mut button_colors: Query<&mut BackgroundColor, With<VsyncToggleButton>>,
+    mut texts: Query<&mut Text>,
+    mut video_settings: ResMut<VideoSettings>,
+) {
+    for (interaction, children) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        video_settings.vsync = !video_settings.vsync;
+        info!("VSync set to: {}", video_settings.vsync);
+
+        for mut color in button_colors.iter_mut() {
+            color.0 = if video_settings.vsync {
+                SELECTED_COLOR
+            } else {
+                UNSELECTED_COLOR
+            };
+        }
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = if video_settings.vsync { "On" } else { "Off" }.to_string();
+            }
+        }
+    }
+}
+
+/// System to drag the UI scale slider
+///
+/// The scale is only applied when the mouse button is released so the slider
+/// does not move under the cursor while it is being dragged.
+pub fn ui_scale_slider_interaction(
+    sliders: Query<(&Interaction, &ComputedNode, &GlobalTransform), With<UiScaleSlider>>,
+    mut fills: Query<&mut Node, With<UiScaleSliderFill>>,
+    mut value_text: Query<&mut Text, With<UiScaleValueText>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    mut pending_scale: Local<Option<f32>>,
+    mut video_settings: ResMut<VideoSettings>,
+) {
+    if mouse_input.just_released(MouseButton::Left) {
+        if let Some(scale) = pending_scale.take() {
+            if video_settings.ui_scale != scale {
+                info!("UI scale set to: {:.2}", scale);
+                video_settings.ui_scale = scale;
+            }
+        }
+        return;
+    }
+
+    if !mouse_input.pressed(MouseButton::Left) {
+        return;
+    }
+
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Some(cursor) = window.physical_cursor_position() else {
+        return;
+    };
+
+    for (interaction, node, transform) in sliders.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        // Node sizes and transforms are in physical pixels
+        let width = node.size().x.max(1.0);
+        let left = transform.translation().x - width / 2.0;
+        let scale = ui_scale_from_fraction((cursor.x - left) / width);
+        *pending_scale = Some(scale);
+
+        for mut fill in fills.iter_mut() {
+            fill.width = Val::Percent(ui_scale_to_percent(scale));
+        }
+        for mut text in value_text.iter_mut() {
+            text.0 = format!("{:.0}%", scale * 100.0);
+        }
+    }
+}
+
+/// System to step the focused UI scale slider by 5% from a keyboard or gamepad
+pub fn step_ui_scale_slider(
+    mut steps: EventReader<SliderStepEvent>,
+    sliders: Query<(), With<UiScaleSlider>>,
+    mut fills: Query<&mut Node, With<UiScaleSliderFill>>,
+    mut value_text: Query<&mut Text, With<UiScaleValueText>>,
+    mut video_settings: ResMut<VideoSettings>,
+) {
+    for step in steps.read() {
+        if !sliders.contains(step.slider) {
+            continue;
+        }
+
+        let scale = ((video_settings.ui_scale * 20.0).round() + step.step as f32) / 20.0;
+        let scale = scale.clamp(MIN_UI_SCALE, MAX_UI_SCALE);
+        if video_settings.ui_scale == scale {
+            continue;
+        }
+        info!("UI scale set to: {:.2}", scale);
+        video_settings.ui_scale = scale;
+
+        for mut fill in fills.iter_mut() {
+            fill.width = Val::Percent(ui_scale_to_percent(scale));
+        }
+        for mut text in value_text.iter_mut() {
+            text.0 = format!("{:.0}%", scale * 100.0);
+        }
+    }
+}
+
+/// Window mode for a display mode setting
+pub fn window_mode_for(display_mode: WindowDisplayMode) -> WindowMode {
+    match display_mode {
+        WindowDisplayMode::Windowed => WindowMode::Windowed,
+        WindowDisplayMode::Borderless => {
+            WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+        }
+        WindowDisplayMode::Fullscreen => {
+            WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current)
+        }
+    }
+}
+
+/// Present mode for a vsync setting
+pub fn present_mode_for(vsync: bool) -> PresentMode {
+    if vsync {
+        PresentMode::AutoVsync
+    } else {
+        PresentMode::AutoNoVsync
+    }
+}
+
+/// System applying video settings to the primary window and the UI scale
+///
+/// Runs whenever [`VideoSettings`] changes, including the first frame, so saved
+/// settings are applied on startup.
+pub fn apply_video_settings(
+    video_settings: Res<VideoSettings>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    if let Ok(mut window) = windows.single_mut() {
+        let [width, height] = video_settings.resolution;
+        window.resolution.set(width as f32, height as f32);
+        window.mode = window_mode_for(video_settings.display_mode);
+        window.present_mode = present_mode_for(video_settings.vsync);
+    }
+
+    ui_scale.0 = video_settings.ui_scale.clamp(MIN_UI_SCALE, MAX_UI_SCALE);
+
+    info!(
+        "Applied video settings: {} {:?} vsync={} ui_scale={:.2}",
+        resolution_label(video_settings.resolution),
+        video_settings.display_mode,
+        video_settings.vsync,
+        video_settings.ui_scale
+    );
+}
+
 /// Creates a quality setting display with buttons
 fn create_quality_setting(
     parent: &mut ChildSpawnerCommands,
@@ -0,0 +1,99 @@
+use crate::menu::settings::components::{
+    AccessibilitySettings, CosmeticSettings, GameplaySettings, MAX_TEXT_SCALE, RummageSettings,
+    VideoSettings, VolumeSettings,
+};
+use crate::menu::settings::persistence::{SETTINGS_VERSION, migrate_settings};
+
+#[test]
+fn test_version_zero_settings_are_migrated() {
+    let mut settings = RummageSettings {
+        version: 0,
+        ..Default::default()
+    };
+    settings.volume.master = 3.0;
+    settings.gameplay.animation_speed = 0.0;
+
+    let (migrated, changed) = migrate_settings(settings);
+    assert!(changed);
+    assert_eq!(migrated.version, SETTINGS_VERSION);
+    assert_eq!(migrated.volume.master, 1.0);
+    assert_eq!(migrated.gameplay.animation_speed, 1.0);
+}
+
+#[test]
+fn test_version_one_settings_get_video_defaults() {
+    let settings: RummageSettings = toml::from_str("version = 1\ngraphics = \"High\"\n").unwrap();
+    let (migrated, changed) = migrate_settings(settings);
+    assert!(changed);
+    assert_eq!(migrated.video, VideoSettings::default());
+}
+
+#[test]
+fn test_version_two_settings_get_accessibility_defaults() {
+    let settings: RummageSettings = toml::from_str("version = 2\n").unwrap();
+    let (migrated, changed) = migrate_settings(settings);
+    assert!(changed);
+    assert_eq!(migrated.accessibility, AccessibilitySettings::default());
+
+    let settings: RummageSettings =
+        toml::from_str("version = 2\n[accessibility]\ntext_scale = 9.0\n").unwrap();
+    let (migrated, _) = migrate_settings(settings);
+    assert_eq!(migrated.accessibility.text_scale, MAX_TEXT_SCALE);
+}
+
+#[test]
+fn test_version_three_settings_get_default_themes() {
+    let settings: RummageSettings = toml::from_str("version = 3\n").unwrap();
+    let (migrated, changed) = migrate_settings(settings);
+    assert!(changed);
+    assert_eq!(migrated.cosmetics, CosmeticSettings::default());
+
+    let settings: RummageSettings =
+        toml::from_str("version = 4\n[cosmetics]\ncard_back = \"midnight\"\n").unwrap();
+    assert_eq!(settings.cosmetics.card_back.0, "midnight");
+    assert_eq!(
+        settings.cosmetics.playmat,
+        CosmeticSettings::default().playmat
+    );
+}
+
+#[test]
+fn test_current_settings_are_untouched() {
+    let settings = RummageSettings::default();
+    let (migrated, changed) = migrate_settings(settings.clone());
+    assert!(!changed);
+    assert_eq!(migrated, settings);
+}
+
+#[test]
+fn test_missing_fields_use_defaults() {
+    let settings: RummageSettings = toml::from_str("[volume]\nmaster = 0.2\n").unwrap();
+    assert_eq!(settings.version, 0);
+    assert_eq!(settings.volume.master, 0.2);
+    assert_eq!(settings.volume.music, VolumeSettings::default().music);
+    assert_eq!(settings.gameplay, GameplaySettings::default());
+}
+
+#[test]
+fn test_house_ban_lists_are_read() {
+    let settings: RummageSettings = toml::from_str(
+        "version = 3\n\
+         [[ban_lists.house_lists]]\n\
+         name = \"Kitchen Table\"\n\
+         banned = [\"Cyclonic Rift\"]\n",
+    )
+    .unwrap();
+    let list = settings.ban_lists.find("kitchen table").unwrap();
+    assert!(list.is_banned("Cyclonic Rift"));
+    assert!(list.is_banned("Black Lotus"));
+
+    assert_eq!(
+        settings.ban_lists.next_after(None).as_deref(),
+        Some("Kitchen Table")
+    );
+    assert_eq!(settings.ban_lists.next_after(Some("Kitchen Table")), None);
+    assert_eq!(
+        settings.ban_lists.resolve(Some("Missing")).name,
+        "Commander"
+    );
+}
@@ -22,6 +22,9 @@ pub enum MenuState {
     /// The state when a new game is started
     NewGame,
 
+    /// The state for choosing a puzzle scenario
+    Scenarios,
+
     /// The state when loading a saved game
     LoadGame,
 
@@ -56,3 +59,34 @@ pub struct StateTransitionContext {
     /// Whether transitioning from pause menu
     pub from_pause_menu: bool,
 }
+
+/// Run condition for systems that set up a new game when the game state is entered
+///
+/// Resuming from the pause menu re-enters the game states, but continues the
+/// game that was paused.
+pub fn starting_new_game(context: Option<Res<StateTransitionContext>>) -> bool {
+    !context.is_some_and(|context| context.from_pause_menu)
+}
+
+/// Run condition that holds once, on the `frames`th frame after entering the game
+///
+/// Lets diagnostics wait for the game to finish setting up. Pausing, and opening
+/// the settings from the pause menu, continue the same game, so the count carries
+/// on. Any other state, like loading a rematch or the main menu, starts it over,
+/// so it holds once per game.
+pub fn frames_after_entering_game(
+    frames: u32,
+) -> impl FnMut(Res<State<GameMenuState>>, Local<u32>) -> bool + Clone {
+    let frames = frames.max(1);
+    move |state: Res<State<GameMenuState>>, mut counted: Local<u32>| match state.get() {
+        GameMenuState::InGame => {
+            *counted = counted.saturating_add(1);
+            *counted == frames
+        }
+        GameMenuState::PauseMenu | GameMenuState::Settings => false,
+        _ => {
+            *counted = 0;
+            false
+        }
+    }
+}
@@ -192,6 +192,22 @@ pub fn create_main_menu_buttons(
                 asset_server,
             );
 
+            // Scenarios button
+            spawn_menu_button(
+                buttons_container_builder,
+                "Scenarios",
+                MenuButtonAction::Scenarios,
+                asset_server,
+            );
+
+            // Tutorial button
+            spawn_menu_button(
+                buttons_container_builder,
+                "Tutorial",
+                MenuButtonAction::Tutorial,
+                asset_server,
+            );
+
             // Load Game button - only show if save exists
             if save_exists {
                 spawn_menu_button(
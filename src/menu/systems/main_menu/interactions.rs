@@ -31,7 +31,15 @@ pub fn handle_main_menu_interactions(
                 match action {
                     MenuButtonAction::NewGame => {
                         info!("New Game button pressed");
-                        next_state.set(GameMenuState::InGame);
+                        next_state.set(GameMenuState::NewGame);
+                    }
+                    MenuButtonAction::Scenarios => {
+                        info!("Scenarios button pressed");
+                        next_state.set(GameMenuState::Scenarios);
+                    }
+                    MenuButtonAction::Tutorial => {
+                        // The lesson itself is started by the tutorial plugin
+                        info!("Tutorial button pressed");
                     }
                     MenuButtonAction::LoadGame => {
                         info!("Load Game button pressed");
@@ -0,0 +1,22 @@
+use crate::menu::state::StateTransitionContext;
+use bevy::prelude::*;
+
+/// Stops game time while the game is paused
+///
+/// Pausing virtual time stops [`FixedUpdate`], where the game engine runs, and
+/// gives every system reading [`Time`] a zero delta until the game resumes.
+pub fn pause_game_time(mut time: ResMut<Time<Virtual>>) {
+    info!("Pausing game time");
+    time.pause();
+}
+
+/// Starts game time again when the game is resumed or left
+pub fn resume_game_time(mut time: ResMut<Time<Virtual>>) {
+    info!("Resuming game time");
+    time.unpause();
+}
+
+/// Forgets the paused game once the main menu is reached, so the next game is set up from scratch
+pub fn clear_pause_context(mut context: ResMut<StateTransitionContext>) {
+    context.from_pause_menu = false;
+}
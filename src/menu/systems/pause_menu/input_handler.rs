@@ -1,3 +1,4 @@
+use crate::input::{ActionInput, InputAction};
 use crate::menu::{
     settings::SettingsMenuState,
     state::{AppState, GameMenuState, StateTransitionContext},
@@ -8,34 +9,43 @@ use bevy::prelude::*;
 // Define the system parameter struct for EscKeyState
 #[derive(SystemParam)]
 pub struct EscKeyStateParams<'w> {
-    keys: Res<'w, ButtonInput<KeyCode>>,
+    actions: Res<'w, ActionInput>,
     app_state: Res<'w, State<AppState>>,
     menu_state: Res<'w, State<GameMenuState>>,
     settings_state: Res<'w, State<SettingsMenuState>>,
     next_menu_state: ResMut<'w, NextState<GameMenuState>>,
     next_settings_state: ResMut<'w, NextState<SettingsMenuState>>,
     next_game_state: ResMut<'w, NextState<AppState>>,
+    context: ResMut<'w, StateTransitionContext>,
 }
 
-/// Handles keyboard input (ESC) while the game is actively running to trigger the pause menu.
+/// Handles the Pause action (ESC or Start) while the game is actively running to trigger the pause menu.
 /// Runs only when `AppState::InGame`.
+///
+/// Marks the transition as coming from the pause menu, so resuming continues the
+/// current game instead of setting up a new one.
 pub fn handle_pause_trigger(
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<ActionInput>,
     mut next_app_state: ResMut<NextState<AppState>>,
     mut next_menu_state: ResMut<NextState<GameMenuState>>,
+    mut context: ResMut<StateTransitionContext>,
 ) {
-    if keys.just_pressed(KeyCode::Escape) {
-        info!("ESC key pressed in AppState::InGame - Triggering Pause Menu");
+    if actions.just_pressed(InputAction::Pause) {
+        info!("Pause pressed in AppState::InGame - Triggering Pause Menu");
+        context.from_pause_menu = true;
         next_app_state.set(AppState::Paused);
         next_menu_state.set(GameMenuState::PauseMenu);
     }
 }
 
-/// Handles keyboard input (ESC) when the game is paused or in a menu state derived from pause.
+/// Handles the Cancel and Pause actions (ESC, B or Start) when the game is paused or in a menu state derived from pause.
 /// Toggles back to the game, navigates back within settings, or returns to the pause menu.
 /// Runs only when `AppState::Paused`.
 pub fn esc_key_system(mut params: EscKeyStateParams) {
-    if params.keys.just_pressed(KeyCode::Escape) {
+    if params
+        .actions
+        .any_just_pressed([InputAction::Cancel, InputAction::Pause])
+    {
         info!(
             "ESC key pressed - current app state: {:?}, menu state: {:?}, settings state: {:?}",
             params.app_state.get(),
@@ -45,6 +55,7 @@ pub fn esc_key_system(mut params: EscKeyStateParams) {
 
         if *params.app_state.get() == AppState::InGame {
             info!("Opening pause menu from game");
+            params.context.from_pause_menu = true;
             params.next_game_state.set(AppState::Paused);
             params.next_menu_state.set(GameMenuState::PauseMenu);
         } else if *params.app_state.get() == AppState::Paused {
@@ -1,3 +1,4 @@
+use crate::game_engine::commander::{EliminationReason, PlayerEliminatedEvent};
 use crate::menu::{
     components::{MenuButtonAction, MenuItem},
     save_load::{SaveLoadUiContext, SaveLoadUiState},
@@ -6,6 +7,8 @@ use crate::menu::{
     state::AppState,
     state::{GameMenuState, StateTransitionContext},
 };
+use crate::player::Player;
+use crate::snapshot::ShareBoardEvent;
 use bevy::{app::AppExit, prelude::*};
 
 const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
@@ -25,6 +28,7 @@ type PauseMenuButtonInteractionQuery<'w, 's> = Query<
 >;
 
 /// Handles button actions in the pause menu
+#[allow(clippy::too_many_arguments)]
 pub fn pause_menu_action(
     mut interaction_query: PauseMenuButtonInteractionQuery,
     mut game_menu_state: ResMut<NextState<GameMenuState>>,
@@ -34,6 +38,9 @@ pub fn pause_menu_action(
     mut app_exit_events: EventWriter<AppExit>,
     mut save_load_state: ResMut<NextState<SaveLoadUiState>>,
     mut save_load_context: ResMut<SaveLoadUiContext>,
+    mut share_events: EventWriter<ShareBoardEvent>,
+    mut eliminated_events: EventWriter<PlayerEliminatedEvent>,
+    players: Query<(Entity, &Player)>,
 ) {
     for (interaction, mut background_color, action) in &mut interaction_query {
         match *interaction {
@@ -59,6 +66,32 @@ pub fn pause_menu_action(
                         save_load_context.from_pause_menu = true;
                         save_load_state.set(SaveLoadUiState::LoadGame);
                     }
+                    MenuButtonAction::RestoreCheckpoint => {
+                        info!("Opening checkpoint dialog");
+                        save_load_context.from_pause_menu = true;
+                        save_load_state.set(SaveLoadUiState::Checkpoints);
+                    }
+                    MenuButtonAction::ShareBoard => {
+                        // Resume first so the menu isn't in the screenshot
+                        info!("Sharing board from pause menu");
+                        game_menu_state.set(GameMenuState::InGame);
+                        app_state.set(AppState::InGame);
+                        share_events.write(ShareBoardEvent);
+                    }
+                    MenuButtonAction::Concede => {
+                        // Resume so the game can show its results
+                        if let Some((player, _)) =
+                            players.iter().find(|(_, player)| player.player_index == 0)
+                        {
+                            info!("Conceding the game for player {:?}", player);
+                            eliminated_events.write(PlayerEliminatedEvent {
+                                player,
+                                reason: EliminationReason::Concede,
+                            });
+                        }
+                        game_menu_state.set(GameMenuState::InGame);
+                        app_state.set(AppState::InGame);
+                    }
                     MenuButtonAction::Settings => {
                         info!("Opening settings from pause menu");
                         handle_settings_enter(
@@ -69,8 +102,11 @@ pub fn pause_menu_action(
                         );
                     }
                     MenuButtonAction::MainMenu => {
-                        // Go back to the main menu
+                        // Leave the game for the main menu, which cleans it up
+                        info!("Exiting to the main menu from pause menu");
+                        context.from_pause_menu = false;
                         game_menu_state.set(GameMenuState::MainMenu);
+                        app_state.set(AppState::Menu);
                     }
                     MenuButtonAction::Quit => {
                         // Exit the game
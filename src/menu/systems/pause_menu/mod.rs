@@ -1,7 +1,11 @@
 pub mod buttons;
+pub mod game_time;
 pub mod input_handler;
 pub mod interactions;
 pub mod setup;
 pub mod ui_helpers;
 
 // Remove unused exports
+
+#[cfg(test)]
+mod tests;
@@ -53,7 +53,7 @@ pub fn setup_pause_menu(
                 .spawn((
                     Node {
                         width: Val::Px(300.0),
-                        height: Val::Px(450.0), // Adjusted height
+                        height: Val::Px(640.0), // Adjusted height
                         flex_direction: FlexDirection::Column,
                         justify_content: JustifyContent::Start, // Align content top-to-bottom inside
                         align_items: AlignItems::Center, // Center content horizontally inside
@@ -125,12 +125,30 @@ pub fn setup_pause_menu(
                                 MenuButtonAction::LoadGame,
                                 "Load Game Button",
                             );
+                            spawn_menu_button(
+                                button_parent,
+                                "Restore Checkpoint",
+                                MenuButtonAction::RestoreCheckpoint,
+                                "Restore Checkpoint Button",
+                            );
+                            spawn_menu_button(
+                                button_parent,
+                                "Share Board",
+                                MenuButtonAction::ShareBoard,
+                                "Share Board Button",
+                            );
                             spawn_menu_button(
                                 button_parent,
                                 "Settings",
                                 MenuButtonAction::Settings,
                                 "Settings Button",
                             );
+                            spawn_menu_button(
+                                button_parent,
+                                "Concede",
+                                MenuButtonAction::Concede,
+                                "Concede Button",
+                            );
                             spawn_menu_button(
                                 button_parent,
                                 "Exit to Main Menu",
@@ -0,0 +1,123 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+
+use crate::input::{ActionInput, InputAction};
+use crate::menu::input_blocker::{InputBlocker, InteractionBlockState, update_interaction_block};
+use crate::menu::state::{AppState, GameMenuState, StateTransitionContext, starting_new_game};
+use crate::menu::systems::pause_menu::game_time::{
+    clear_pause_context, pause_game_time, resume_game_time,
+};
+use crate::menu::systems::pause_menu::input_handler::handle_pause_trigger;
+
+fn pause_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin))
+        .init_state::<AppState>()
+        .init_state::<GameMenuState>()
+        .init_resource::<ActionInput>()
+        .init_resource::<StateTransitionContext>()
+        .init_resource::<InteractionBlockState>()
+        .add_systems(PreUpdate, update_interaction_block)
+        .add_systems(
+            Update,
+            handle_pause_trigger.run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(OnEnter(AppState::Paused), pause_game_time)
+        .add_systems(OnExit(AppState::Paused), resume_game_time)
+        .add_systems(OnEnter(GameMenuState::MainMenu), clear_pause_context);
+
+    app.world_mut()
+        .resource_mut::<NextState<AppState>>()
+        .set(AppState::InGame);
+    app.world_mut()
+        .resource_mut::<NextState<GameMenuState>>()
+        .set(GameMenuState::InGame);
+    app.update();
+    app
+}
+
+fn pause(app: &mut App) {
+    app.world_mut()
+        .resource_mut::<ActionInput>()
+        .press(InputAction::Pause);
+    app.update();
+    app.world_mut().resource_mut::<ActionInput>().clear();
+    app.world_mut().resource_mut::<ActionInput>().release_all();
+    // One frame to enter the pause states, one for the input block to follow
+    app.update();
+    app.update();
+}
+
+fn blocked(app: &App) -> bool {
+    app.world().resource::<InteractionBlockState>().should_block
+}
+
+#[test]
+fn test_pausing_stops_game_time_and_blocks_input() {
+    let mut app = pause_app();
+    assert!(!blocked(&app));
+
+    pause(&mut app);
+    assert_eq!(
+        *app.world().resource::<State<AppState>>().get(),
+        AppState::Paused
+    );
+    assert_eq!(
+        *app.world().resource::<State<GameMenuState>>().get(),
+        GameMenuState::PauseMenu
+    );
+    assert!(app.world().resource::<Time<Virtual>>().is_paused());
+    assert!(blocked(&app));
+    assert!(
+        app.world()
+            .resource::<StateTransitionContext>()
+            .from_pause_menu
+    );
+
+    // Resuming continues the paused game
+    app.world_mut()
+        .resource_mut::<NextState<AppState>>()
+        .set(AppState::InGame);
+    app.world_mut()
+        .resource_mut::<NextState<GameMenuState>>()
+        .set(GameMenuState::InGame);
+    app.update();
+    app.update();
+    assert!(!app.world().resource::<Time<Virtual>>().is_paused());
+    assert!(!blocked(&app));
+    assert!(!app.world_mut().run_system_once(starting_new_game).unwrap());
+}
+
+#[test]
+fn test_exiting_to_main_menu_forgets_the_paused_game() {
+    let mut app = pause_app();
+    pause(&mut app);
+
+    app.world_mut()
+        .resource_mut::<NextState<AppState>>()
+        .set(AppState::Menu);
+    app.world_mut()
+        .resource_mut::<NextState<GameMenuState>>()
+        .set(GameMenuState::MainMenu);
+    app.update();
+    assert!(!app.world().resource::<Time<Virtual>>().is_paused());
+    assert!(
+        !app.world()
+            .resource::<StateTransitionContext>()
+            .from_pause_menu
+    );
+    assert!(app.world_mut().run_system_once(starting_new_game).unwrap());
+}
+
+#[test]
+fn test_input_blockers_block_interaction() {
+    let mut app = pause_app();
+    let blocker = app.world_mut().spawn(InputBlocker).id();
+    app.update();
+    assert!(blocked(&app));
+
+    app.world_mut().despawn(blocker);
+    app.update();
+    assert!(!blocked(&app));
+}
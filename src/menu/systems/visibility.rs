@@ -80,7 +80,7 @@ pub fn debug_menu_visibility(
             info!("Menu camera {:?} visibility: {:?}", entity, visibility);
         }
 
-        log_state.last_update = std::time::Instant::now();
+        log_state.last_update = bevy::platform::time::Instant::now();
     }
 }
 
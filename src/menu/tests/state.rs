@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+
+use crate::menu::state::{GameMenuState, frames_after_entering_game};
+
+#[derive(Resource, Default)]
+struct Runs(u32);
+
+fn count_run(mut runs: ResMut<Runs>) {
+    runs.0 += 1;
+}
+
+fn run_frames(app: &mut App, state: GameMenuState, frames: u32) -> u32 {
+    app.insert_resource(State::new(state));
+    for _ in 0..frames {
+        app.update();
+    }
+    app.world().resource::<Runs>().0
+}
+
+#[test]
+fn test_frames_after_entering_game_holds_once_per_game() {
+    let mut app = App::new();
+    app.init_resource::<Runs>()
+        .add_systems(Update, count_run.run_if(frames_after_entering_game(3)));
+
+    assert_eq!(run_frames(&mut app, GameMenuState::MainMenu, 5), 0);
+    assert_eq!(run_frames(&mut app, GameMenuState::InGame, 2), 0);
+    assert_eq!(run_frames(&mut app, GameMenuState::InGame, 1), 1);
+    assert_eq!(run_frames(&mut app, GameMenuState::InGame, 10), 1);
+
+    // Pausing and resuming doesn't start the count over
+    run_frames(&mut app, GameMenuState::PauseMenu, 2);
+    assert_eq!(run_frames(&mut app, GameMenuState::InGame, 5), 1);
+
+    // A new game does
+    run_frames(&mut app, GameMenuState::MainMenu, 1);
+    assert_eq!(run_frames(&mut app, GameMenuState::InGame, 3), 2);
+}
+
+#[test]
+fn test_frames_after_entering_game_holds_again_after_a_rematch() {
+    let mut app = App::new();
+    app.init_resource::<Runs>()
+        .add_systems(Update, count_run.run_if(frames_after_entering_game(3)));
+
+    assert_eq!(run_frames(&mut app, GameMenuState::InGame, 5), 1);
+
+    // A rematch loads the next game without going through the main menu
+    run_frames(&mut app, GameMenuState::Loading, 1);
+    assert_eq!(run_frames(&mut app, GameMenuState::InGame, 2), 1);
+    assert_eq!(run_frames(&mut app, GameMenuState::InGame, 1), 2);
+}
@@ -17,7 +17,7 @@ pub struct MenuVisibilityLogState {
     pub last_item_count: usize,
     pub last_visible_items: usize,
     pub camera_states: std::collections::HashMap<Entity, Visibility>,
-    pub last_update: std::time::Instant,
+    pub last_update: bevy::platform::time::Instant,
 }
 
 impl Default for MenuVisibilityLogState {
@@ -26,7 +26,7 @@ impl Default for MenuVisibilityLogState {
             last_item_count: 0,
             last_visible_items: 0,
             camera_states: std::collections::HashMap::new(),
-            last_update: std::time::Instant::now(),
+            last_update: bevy::platform::time::Instant::now(),
         }
     }
 }
@@ -1,6 +1,6 @@
+use bevy::platform::time::Instant;
 use bevy::prelude::*;
 use std::collections::HashMap;
-use std::time::Instant;
 
 /// Resource to track previous window size
 #[derive(Component, Default, Reflect, Debug)]
@@ -206,7 +206,7 @@ pub fn debug_menu_visibility(
             );
         }
 
-        log_state.last_update = std::time::Instant::now();
+        log_state.last_update = bevy::platform::time::Instant::now();
     }
 }
 
@@ -0,0 +1,82 @@
+//! Plain-text descriptions of game events
+
+use crate::game_engine::zones::Zone;
+
+/// Join names into a list like "you, Player 3 and Grizzly Bears"
+pub fn join_names(names: &[String]) -> String {
+    match names {
+        [] => String::new(),
+        [only] => only.clone(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+/// Append " targeting ..." when there are targets
+fn with_targets(mut text: String, targets: &[String]) -> String {
+    if !targets.is_empty() {
+        text.push_str(" targeting ");
+        text.push_str(&join_names(targets));
+    }
+    text
+}
+
+/// "Player 2 casts Lightning Bolt targeting you"
+pub fn describe_cast(caster: &str, spell: &str, targets: &[String]) -> String {
+    with_targets(format!("{} casts {}", caster, spell), targets)
+}
+
+/// "Player 1 activates Prodigal Sorcerer targeting Player 3"
+pub fn describe_activation(player: &str, source: &str, targets: &[String]) -> String {
+    with_targets(format!("{} activates {}", player, source), targets)
+}
+
+/// "Player 1 plays Forest"
+pub fn describe_land(player: &str, land: &str) -> String {
+    format!("{} plays {}", player, land)
+}
+
+/// "Grizzly Bears attacks Player 2"
+pub fn describe_attack(attacker: &str, defender: &str) -> String {
+    format!("{} attacks {}", attacker, defender)
+}
+
+/// "Turn 3: Player 2's turn"
+pub fn describe_turn(turn_number: u32, player: &str) -> String {
+    format!("Turn {}: {}'s turn", turn_number, player)
+}
+
+/// "Player 2 loses 3 life, now at 37", or `None` when the total didn't change
+pub fn describe_life_change(player: &str, previous: i32, life: i32) -> Option<String> {
+    let change = life - previous;
+    let verb = match change.signum() {
+        1 => "gains",
+        -1 => "loses",
+        _ => return None,
+    };
+    Some(format!(
+        "{} {} {} life, now at {}",
+        player,
+        verb,
+        change.abs(),
+        life
+    ))
+}
+
+/// Describe a card changing zones, if the move is worth narrating
+///
+/// Draws don't name the card, since a hand is hidden from the other players.
+pub fn describe_zone_change(
+    card: &str,
+    owner: &str,
+    source: Zone,
+    destination: Zone,
+) -> Option<String> {
+    match (source, destination) {
+        (Zone::Library, Zone::Hand) => Some(format!("{} draws a card", owner)),
+        (_, Zone::Graveyard) => Some(format!("{} is put into {}'s graveyard", card, owner)),
+        (_, Zone::Exile) => Some(format!("{} is exiled", card)),
+        (_, Zone::Command) => Some(format!("{} returns to the command zone", card)),
+        (Zone::Battlefield, Zone::Hand) => Some(format!("{} returns to {}'s hand", card, owner)),
+        _ => None,
+    }
+}
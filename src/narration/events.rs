@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+use crate::menu::settings::components::NarrationVerbosity;
+
+/// A line of narration describing something that happened in the game
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct NarrationEvent {
+    /// What happened, e.g. "Player 2 casts Lightning Bolt targeting you"
+    pub text: String,
+    /// The lowest verbosity the line is narrated at
+    pub level: NarrationVerbosity,
+}
+
+impl NarrationEvent {
+    pub fn new(level: NarrationVerbosity, text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            level,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Narration of the game for players who can't follow it by sight
+//!
+//! Game events (turns, spells, lands, attacks, zone changes and life totals) are
+//! described in plain text as [`NarrationEvent`]s. The ones allowed by the
+//! narration verbosity in the accessibility settings scroll past on an on-screen
+//! ticker and, with the `screen_reader` feature, are spoken by the platform's
+//! screen reader or text-to-speech engine.
+
+pub mod describe;
+pub mod events;
+mod plugin;
+pub mod resources;
+pub mod systems;
+pub mod ticker;
+
+#[cfg(test)]
+mod tests;
+
+pub use events::NarrationEvent;
+pub use plugin::NarrationPlugin;
+pub use resources::NarrationLog;
@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+
+use super::events::NarrationEvent;
+use super::resources::NarrationLog;
+use super::systems::{
+    narrate_attacks, narrate_game_actions, narrate_life_changes, narrate_turns,
+    narrate_zone_changes, record_narration,
+};
+use super::ticker::{spawn_narration_ticker, update_narration_ticker};
+use crate::game_engine::actions::GameAction;
+use crate::game_engine::combat::AttackerDeclaredEvent;
+use crate::game_engine::turns::TurnStartEvent;
+use crate::game_engine::zones::ZoneChangeEvent;
+use crate::menu::settings::components::AccessibilitySettings;
+
+/// Plugin narrating the game on a ticker and to the screen reader
+pub struct NarrationPlugin;
+
+impl Plugin for NarrationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<NarrationEvent>()
+            .add_event::<GameAction>()
+            .add_event::<AttackerDeclaredEvent>()
+            .add_event::<TurnStartEvent>()
+            .add_event::<ZoneChangeEvent>()
+            .init_resource::<AccessibilitySettings>()
+            .init_resource::<NarrationLog>()
+            .add_systems(Startup, spawn_narration_ticker)
+            .add_systems(
+                Update,
+                (
+                    (
+                        narrate_turns,
+                        narrate_game_actions,
+                        narrate_attacks,
+                        narrate_zone_changes,
+                        narrate_life_changes,
+                    ),
+                    record_narration,
+                    update_narration_ticker,
+                )
+                    .chain(),
+            );
+
+        #[cfg(feature = "screen_reader")]
+        if let Some(screen_reader) = super::resources::ScreenReader::connect() {
+            app.insert_non_send_resource(screen_reader);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+/// How many lines the ticker shows at once
+pub const TICKER_LINES: usize = 4;
+
+/// How long a line stays on the ticker, in seconds
+pub const TICKER_LINE_SECONDS: f64 = 8.0;
+
+/// A narrated line and when it was narrated
+#[derive(Debug, Clone, PartialEq)]
+pub struct NarrationLine {
+    pub text: String,
+    /// Elapsed time in seconds when the line was narrated
+    pub at: f64,
+}
+
+/// The most recent narration, shown on the ticker
+#[derive(Resource, Debug, Default, Clone)]
+pub struct NarrationLog {
+    lines: VecDeque<NarrationLine>,
+}
+
+impl NarrationLog {
+    /// Add a line, dropping the oldest once the ticker is full
+    pub fn push(&mut self, text: impl Into<String>, at: f64) {
+        if self.lines.len() == TICKER_LINES {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(NarrationLine {
+            text: text.into(),
+            at,
+        });
+    }
+
+    /// Drop lines older than [`TICKER_LINE_SECONDS`], returning whether any were dropped
+    pub fn expire(&mut self, now: f64) -> bool {
+        let before = self.lines.len();
+        self.lines
+            .retain(|line| now - line.at < TICKER_LINE_SECONDS);
+        self.lines.len() != before
+    }
+
+    /// The lines on the ticker, oldest first
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().map(|line| line.text.as_str())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
+
+/// The platform's screen reader, or its text-to-speech engine when none is running
+///
+/// Speech backends aren't thread safe on every platform, so this is a non-send
+/// resource.
+#[cfg(feature = "screen_reader")]
+pub struct ScreenReader(pub tts::Tts);
+
+#[cfg(feature = "screen_reader")]
+impl ScreenReader {
+    /// Connect to the platform's speech backend
+    pub fn connect() -> Option<Self> {
+        match tts::Tts::default() {
+            Ok(tts) => Some(Self(tts)),
+            Err(error) => {
+                warn!("Screen reader unavailable, narration is on screen only: {error}");
+                None
+            }
+        }
+    }
+
+    /// Queue a line to be spoken after anything already being spoken
+    pub fn speak(&mut self, text: &str) {
+        if let Err(error) = self.0.speak(text, false) {
+            warn!("Failed to speak narration: {error}");
+        }
+    }
+}
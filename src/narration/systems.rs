@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use super::describe::{
+    describe_activation, describe_attack, describe_cast, describe_land, describe_life_change,
+    describe_turn, describe_zone_change,
+};
+use super::events::NarrationEvent;
+use super::resources::NarrationLog;
+#[cfg(feature = "screen_reader")]
+use super::resources::ScreenReader;
+use crate::camera::presets::LOCAL_PLAYER_INDEX;
+use crate::cards::Card;
+use crate::game_engine::actions::GameAction;
+use crate::game_engine::combat::AttackerDeclaredEvent;
+use crate::game_engine::turns::TurnStartEvent;
+use crate::game_engine::zones::{Zone, ZoneChangeEvent};
+use crate::menu::settings::components::{AccessibilitySettings, NarrationVerbosity};
+use crate::player::Player;
+
+/// Looks up the names narration refers to players and cards by
+#[derive(SystemParam)]
+pub struct NarrationNames<'w, 's> {
+    players: Query<'w, 's, &'static Player>,
+    cards: Query<'w, 's, &'static Card>,
+}
+
+impl NarrationNames<'_, '_> {
+    /// A player's name
+    pub fn player(&self, entity: Entity) -> String {
+        self.players
+            .get(entity)
+            .map_or_else(|_| "A player".to_string(), |player| player.name.clone())
+    }
+
+    /// A card's name
+    pub fn card(&self, entity: Entity) -> String {
+        self.cards
+            .get(entity)
+            .map_or_else(|_| "A card".to_string(), |card| card.name.name.clone())
+    }
+
+    /// A player or card being targeted or attacked, "you" for the local player
+    pub fn target(&self, entity: Entity) -> String {
+        if let Ok(player) = self.players.get(entity) {
+            return if player.player_index == LOCAL_PLAYER_INDEX {
+                "you".to_string()
+            } else {
+                player.name.clone()
+            };
+        }
+        self.card(entity)
+    }
+
+    fn targets(&self, entities: &[Entity]) -> Vec<String> {
+        entities.iter().map(|entity| self.target(*entity)).collect()
+    }
+}
+
+/// Narrate the start of each turn
+pub fn narrate_turns(
+    mut turns: EventReader<TurnStartEvent>,
+    names: NarrationNames,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    for event in turns.read() {
+        narration.write(NarrationEvent::new(
+            NarrationVerbosity::Minimal,
+            describe_turn(event.turn_number, &names.player(event.player)),
+        ));
+    }
+}
+
+/// Narrate spells, lands, abilities and priority passes
+pub fn narrate_game_actions(
+    mut actions: EventReader<GameAction>,
+    names: NarrationNames,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    for action in actions.read() {
+        let event = match action {
+            GameAction::CastSpell {
+                player,
+                spell_card,
+                targets,
+                ..
+            } => NarrationEvent::new(
+                NarrationVerbosity::Minimal,
+                describe_cast(
+                    &names.player(*player),
+                    &names.card(*spell_card),
+                    &names.targets(targets),
+                ),
+            ),
+            GameAction::PlayLand { player, land_card } => NarrationEvent::new(
+                NarrationVerbosity::Normal,
+                describe_land(&names.player(*player), &names.card(*land_card)),
+            ),
+            GameAction::ActivateAbility {
+                player,
+                source,
+                targets,
+                ..
+            } => NarrationEvent::new(
+                NarrationVerbosity::Verbose,
+                describe_activation(
+                    &names.player(*player),
+                    &names.card(*source),
+                    &names.targets(targets),
+                ),
+            ),
+            GameAction::PassPriority { player } => NarrationEvent::new(
+                NarrationVerbosity::Verbose,
+                format!("{} passes priority", names.player(*player)),
+            ),
+        };
+        narration.write(event);
+    }
+}
+
+/// Narrate each declared attacker
+pub fn narrate_attacks(
+    mut attackers: EventReader<AttackerDeclaredEvent>,
+    names: NarrationNames,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    for event in attackers.read() {
+        narration.write(NarrationEvent::new(
+            NarrationVerbosity::Normal,
+            describe_attack(&names.card(event.attacker), &names.target(event.defender)),
+        ));
+    }
+}
+
+/// Narrate cards being drawn, dying, exiled or returned
+///
+/// Draws are only narrated when verbose, since they happen every turn.
+pub fn narrate_zone_changes(
+    mut zone_changes: EventReader<ZoneChangeEvent>,
+    names: NarrationNames,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    for event in zone_changes.read() {
+        let Some(text) = describe_zone_change(
+            &names.card(event.card),
+            &names.player(event.owner),
+            event.source,
+            event.destination,
+        ) else {
+            continue;
+        };
+        let level = if (event.source, event.destination) == (Zone::Library, Zone::Hand) {
+            NarrationVerbosity::Verbose
+        } else {
+            NarrationVerbosity::Normal
+        };
+        narration.write(NarrationEvent::new(level, text));
+    }
+}
+
+/// Narrate life totals going up or down
+pub fn narrate_life_changes(
+    players: Query<(Entity, &Player), Changed<Player>>,
+    mut life_totals: Local<HashMap<Entity, i32>>,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    for (entity, player) in players.iter() {
+        let Some(previous) = life_totals.insert(entity, player.life) else {
+            continue;
+        };
+        if let Some(text) = describe_life_change(&player.name, previous, player.life) {
+            narration.write(NarrationEvent::new(NarrationVerbosity::Minimal, text));
+        }
+    }
+}
+
+/// Put narration allowed by the verbosity setting on the ticker and, with the
+/// `screen_reader` feature, hand it to the screen reader
+pub fn record_narration(
+    mut narration: EventReader<NarrationEvent>,
+    settings: Option<Res<AccessibilitySettings>>,
+    time: Res<Time>,
+    mut log: ResMut<NarrationLog>,
+    #[cfg(feature = "screen_reader")] mut screen_reader: Option<NonSendMut<ScreenReader>>,
+) {
+    let verbosity = settings.map_or(NarrationVerbosity::Off, |settings| settings.narration);
+
+    for event in narration.read() {
+        if !verbosity.allows(event.level) {
+            continue;
+        }
+
+        debug!("Narration: {}", event.text);
+        log.push(event.text.clone(), time.elapsed_secs_f64());
+        #[cfg(feature = "screen_reader")]
+        if let Some(screen_reader) = screen_reader.as_mut() {
+            screen_reader.speak(&event.text);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+
+use crate::game_engine::zones::Zone;
+use crate::menu::settings::components::{AccessibilitySettings, NarrationVerbosity};
+use crate::narration::NarrationEvent;
+use crate::narration::describe::{
+    describe_cast, describe_life_change, describe_zone_change, join_names,
+};
+use crate::narration::resources::{NarrationLog, TICKER_LINE_SECONDS, TICKER_LINES};
+use crate::narration::systems::record_narration;
+
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn test_cast_lists_its_targets() {
+    assert_eq!(
+        describe_cast("Player 2", "Lightning Bolt", &names(&["you"])),
+        "Player 2 casts Lightning Bolt targeting you"
+    );
+    assert_eq!(
+        describe_cast("Player 1", "Wrath of God", &[]),
+        "Player 1 casts Wrath of God"
+    );
+    assert_eq!(
+        join_names(&names(&["you", "Player 3", "Grizzly Bears"])),
+        "you, Player 3 and Grizzly Bears"
+    );
+}
+
+#[test]
+fn test_life_changes_read_both_ways() {
+    assert_eq!(
+        describe_life_change("Player 2", 40, 37).as_deref(),
+        Some("Player 2 loses 3 life, now at 37")
+    );
+    assert_eq!(
+        describe_life_change("Player 2", 37, 39).as_deref(),
+        Some("Player 2 gains 2 life, now at 39")
+    );
+    assert_eq!(describe_life_change("Player 2", 40, 40), None);
+}
+
+#[test]
+fn test_draws_keep_the_card_hidden() {
+    assert_eq!(
+        describe_zone_change("Sol Ring", "Player 1", Zone::Library, Zone::Hand).as_deref(),
+        Some("Player 1 draws a card")
+    );
+    assert_eq!(
+        describe_zone_change(
+            "Grizzly Bears",
+            "Player 1",
+            Zone::Battlefield,
+            Zone::Graveyard
+        )
+        .as_deref(),
+        Some("Grizzly Bears is put into Player 1's graveyard")
+    );
+    assert_eq!(
+        describe_zone_change("Forest", "Player 1", Zone::Hand, Zone::Battlefield),
+        None
+    );
+}
+
+#[test]
+fn test_verbosity_levels_include_the_ones_below() {
+    assert!(!NarrationVerbosity::Off.allows(NarrationVerbosity::Minimal));
+    assert!(NarrationVerbosity::Minimal.allows(NarrationVerbosity::Minimal));
+    assert!(!NarrationVerbosity::Minimal.allows(NarrationVerbosity::Normal));
+    assert!(NarrationVerbosity::Verbose.allows(NarrationVerbosity::Normal));
+    assert_eq!(NarrationVerbosity::Verbose.next(), NarrationVerbosity::Off);
+}
+
+#[test]
+fn test_log_keeps_recent_lines() {
+    let mut log = NarrationLog::default();
+    for index in 0..TICKER_LINES + 1 {
+        log.push(format!("line {}", index), index as f64);
+    }
+    assert_eq!(log.lines().count(), TICKER_LINES);
+    assert_eq!(log.lines().next(), Some("line 1"));
+
+    assert!(log.expire(TICKER_LINES as f64 + TICKER_LINE_SECONDS));
+    assert_eq!(log.lines().collect::<Vec<_>>(), vec!["line 4"]);
+}
+
+#[test]
+fn test_only_lines_allowed_by_the_verbosity_are_recorded() {
+    let mut app = App::new();
+    app.add_event::<NarrationEvent>()
+        .init_resource::<NarrationLog>()
+        .init_resource::<Time>()
+        .insert_resource(AccessibilitySettings {
+            narration: NarrationVerbosity::Normal,
+            ..default()
+        })
+        .add_systems(Update, record_narration);
+
+    for (level, text) in [
+        (NarrationVerbosity::Minimal, "Turn 2: Player 2's turn"),
+        (NarrationVerbosity::Verbose, "Player 2 draws a card"),
+        (NarrationVerbosity::Normal, "Player 2 plays Forest"),
+    ] {
+        app.world_mut().send_event(NarrationEvent::new(level, text));
+    }
+    app.update();
+
+    let log = app.world().resource::<NarrationLog>();
+    assert_eq!(
+        log.lines().collect::<Vec<_>>(),
+        vec!["Turn 2: Player 2's turn", "Player 2 plays Forest"]
+    );
+}
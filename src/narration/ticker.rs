@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+
+use super::resources::NarrationLog;
+use crate::camera::z_order::UiLayer;
+use crate::menu::settings::components::{AccessibilitySettings, NarrationVerbosity};
+
+/// The narration ticker in the top left corner of the screen
+#[derive(Component)]
+pub struct NarrationTicker;
+
+/// The text of the narration ticker
+#[derive(Component)]
+pub struct NarrationTickerText;
+
+/// Spawns the ticker, hidden until something is narrated
+pub fn spawn_narration_ticker(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(8.0),
+                left: Val::Px(8.0),
+                max_width: Val::Percent(40.0),
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            UiLayer::Overlay.global_z_index(),
+            Visibility::Hidden,
+            NarrationTicker,
+            Name::new("Narration Ticker"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                NarrationTickerText,
+                Name::new("Narration Ticker Text"),
+            ));
+        });
+}
+
+/// Expire old lines and show the rest on the ticker
+pub fn update_narration_ticker(
+    time: Res<Time>,
+    settings: Option<Res<AccessibilitySettings>>,
+    mut log: ResMut<NarrationLog>,
+    mut tickers: Query<&mut Visibility, With<NarrationTicker>>,
+    mut texts: Query<&mut Text, With<NarrationTickerText>>,
+) {
+    let expired = log.expire(time.elapsed_secs_f64());
+    if !expired && !log.is_changed() {
+        return;
+    }
+
+    let enabled = settings.is_some_and(|settings| settings.narration != NarrationVerbosity::Off);
+    let visibility = if enabled && !log.is_empty() {
+        Visibility::Visible
+    } else {
+        Visibility::Hidden
+    };
+    for mut ticker in tickers.iter_mut() {
+        ticker.set_if_neq(visibility);
+    }
+
+    let text = log.lines().collect::<Vec<_>>().join("\n");
+    for mut ticker_text in texts.iter_mut() {
+        ticker_text.0 = text.clone();
+    }
+}
@@ -0,0 +1,90 @@
+//! A peer's connection to whoever hosts its room
+
+use super::frame::{FramedStream, Packet};
+use super::transport::{NetError, PeerId, Recipient, TransportEvent};
+
+/// A peer's stream to the host or relay, and what it's been told so far
+#[derive(Debug)]
+pub struct HostConnection {
+    stream: FramedStream,
+    local_peer: Option<PeerId>,
+    disconnected: bool,
+}
+
+impl HostConnection {
+    pub fn new(stream: FramedStream) -> Self {
+        Self {
+            stream,
+            local_peer: None,
+            disconnected: false,
+        }
+    }
+
+    /// The id the host gave this peer, once it has
+    pub fn local_peer(&self) -> Option<PeerId> {
+        self.local_peer
+    }
+
+    /// Send a packet to the host or relay itself
+    pub fn send_packet(&mut self, packet: &Packet) -> Result<(), NetError> {
+        self.stream.send(packet)
+    }
+
+    /// Send game data to other peers
+    pub fn send(&mut self, to: Recipient, payload: Vec<u8>) -> Result<(), NetError> {
+        let from = self.local_peer.ok_or(NetError::Closed)?;
+        let to = match to {
+            Recipient::Peer(peer) => Some(peer),
+            Recipient::Everyone => None,
+        };
+        self.stream.send(&Packet::Data { from, to, payload })
+    }
+
+    /// Turn the packets received into events
+    pub fn poll(&mut self) -> Vec<TransportEvent> {
+        if self.disconnected {
+            return Vec::new();
+        }
+        let mut events = Vec::new();
+        let packets = match self.stream.receive() {
+            Ok(packets) => packets,
+            Err(e) => {
+                self.disconnect(&mut events, e.to_string());
+                return events;
+            }
+        };
+
+        for packet in packets {
+            match packet {
+                Packet::Welcome { peer } => {
+                    self.local_peer = Some(peer);
+                    events.push(TransportEvent::Connected { local_peer: peer });
+                }
+                Packet::RoomOpened { room } => {
+                    events.push(TransportEvent::RoomOpened { code: room });
+                }
+                Packet::PeerJoined { peer } => events.push(TransportEvent::PeerJoined(peer)),
+                Packet::PeerLeft { peer } => events.push(TransportEvent::PeerLeft(peer)),
+                Packet::Data { from, payload, .. } => {
+                    events.push(TransportEvent::Message { from, payload });
+                }
+                Packet::Rejected { reason } => {
+                    self.disconnect(&mut events, reason);
+                    return events;
+                }
+                Packet::Host | Packet::Join { .. } => {}
+            }
+        }
+
+        if self.stream.is_closed() {
+            self.disconnect(&mut events, "connection closed".to_string());
+        }
+        events
+    }
+
+    fn disconnect(&mut self, events: &mut Vec<TransportEvent>, reason: String) {
+        self.disconnected = true;
+        self.local_peer = None;
+        events.push(TransportEvent::Disconnected { reason });
+    }
+}
@@ -0,0 +1,218 @@
+//! Connecting straight to the host's address
+//!
+//! The host listens for connections and forwards each message to the peers
+//! it's for, so peers only need to reach the host. Behind NAT the host's
+//! port usually isn't reachable; use the [relay](super::relay) then.
+//!
+//! Joining connects on a worker thread, so a host that's slow to answer doesn't
+//! hold up the game; the connection is picked up by the next poll after it's made.
+
+use std::io;
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use bevy::log::{info, warn};
+
+use super::client::HostConnection;
+use super::frame::{FramedStream, Packet};
+use super::room::Room;
+use super::transport::{
+    HOST_PEER, NetError, NetTransport, PeerId, Recipient, TransportEvent, TransportKind,
+};
+
+/// How long joining waits for each of the host's addresses to answer
+pub const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The host's end of direct connections
+#[derive(Debug)]
+struct DirectHost {
+    listener: TcpListener,
+    room: Room,
+}
+
+#[derive(Debug)]
+enum DirectRole {
+    Host(DirectHost),
+    /// Joining, while the worker thread connects to the host
+    Connecting(JoinHandle<io::Result<TcpStream>>),
+    Peer(HostConnection),
+}
+
+/// Players connected straight to the host
+#[derive(Debug)]
+pub struct DirectTransport {
+    /// `None` once closed
+    role: Option<DirectRole>,
+    /// Events that happened outside a poll, like connecting
+    queued: Vec<TransportEvent>,
+}
+
+impl DirectTransport {
+    /// Host a game, listening on `addr`
+    pub fn host(addr: impl ToSocketAddrs) -> Result<Self, NetError> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        Ok(Self {
+            role: Some(DirectRole::Host(DirectHost {
+                listener,
+                room: Room::new(HOST_PEER + 1),
+            })),
+            queued: vec![TransportEvent::Connected {
+                local_peer: HOST_PEER,
+            }],
+        })
+    }
+
+    /// Join the game hosted at `addr`
+    ///
+    /// Returns straight away. [`TransportEvent::Connected`] follows once the host
+    /// answers, or [`TransportEvent::Disconnected`] if it can't be reached.
+    pub fn connect(addr: impl ToSocketAddrs + Send + 'static) -> Result<Self, NetError> {
+        let connecting = thread::Builder::new()
+            .name("direct-connect".to_string())
+            .spawn(move || connect_to_host(addr))?;
+        Ok(Self {
+            role: Some(DirectRole::Connecting(connecting)),
+            queued: Vec::new(),
+        })
+    }
+
+    /// Take the connection to the host once the worker thread has made it
+    fn finish_connecting(&mut self, events: &mut Vec<TransportEvent>) {
+        let connecting = match self.role.take() {
+            Some(DirectRole::Connecting(connecting)) if connecting.is_finished() => connecting,
+            role => {
+                self.role = role;
+                return;
+            }
+        };
+        let connected = match connecting.join() {
+            Ok(stream) => stream.and_then(FramedStream::new),
+            Err(_) => Err(io::Error::other("the connecting thread panicked")),
+        };
+        match connected {
+            Ok(stream) => self.role = Some(DirectRole::Peer(HostConnection::new(stream))),
+            Err(e) => {
+                warn!("Couldn't connect to the host: {}", e);
+                events.push(TransportEvent::Disconnected {
+                    reason: format!("couldn't connect to the host: {}", e),
+                });
+            }
+        }
+    }
+
+    /// The address a host is listening on, for others to connect to
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        match &self.role {
+            Some(DirectRole::Host(host)) => host.listener.local_addr().ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Connect to the first of the host's addresses that answers in time
+fn connect_to_host(addr: impl ToSocketAddrs) -> io::Result<TcpStream> {
+    let mut last_error = None;
+    for addr in addr.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT) {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(last_error
+        .unwrap_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no address to connect to")))
+}
+
+impl DirectHost {
+    fn poll(&mut self, events: &mut Vec<TransportEvent>) {
+        loop {
+            match self.listener.accept() {
+                Ok((stream, addr)) => match FramedStream::new(stream) {
+                    Ok(stream) => {
+                        let peer = self.room.add(stream);
+                        info!("Peer {} connected from {}", peer, addr);
+                        events.push(TransportEvent::PeerJoined(peer));
+                    }
+                    Err(e) => warn!("Couldn't accept a connection from {}: {}", addr, e),
+                },
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) => {
+                    warn!("Couldn't accept a connection: {}", e);
+                    break;
+                }
+            }
+        }
+
+        let update = self.room.poll();
+        for (from, packet) in update.packets {
+            if let Packet::Data { to, payload, .. } = packet {
+                self.room.route(from, to, &payload);
+                if to.is_none_or(|to| to == HOST_PEER) {
+                    events.push(TransportEvent::Message { from, payload });
+                }
+            }
+        }
+        self.room.remove(&update.left);
+        events.extend(update.left.into_iter().map(TransportEvent::PeerLeft));
+    }
+
+    fn send(&mut self, to: Recipient, payload: Vec<u8>) -> Result<(), NetError> {
+        match to {
+            Recipient::Peer(peer) => {
+                let packet = Packet::Data {
+                    from: HOST_PEER,
+                    to: Some(peer),
+                    payload,
+                };
+                if self.room.send_to(peer, &packet) {
+                    Ok(())
+                } else {
+                    Err(NetError::UnknownPeer(peer))
+                }
+            }
+            Recipient::Everyone => {
+                self.room.route(HOST_PEER, None, &payload);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl NetTransport for DirectTransport {
+    fn kind(&self) -> TransportKind {
+        TransportKind::Direct
+    }
+
+    fn local_peer(&self) -> Option<PeerId> {
+        match &self.role {
+            Some(DirectRole::Host(_)) => Some(HOST_PEER),
+            Some(DirectRole::Peer(connection)) => connection.local_peer(),
+            Some(DirectRole::Connecting(_)) | None => None,
+        }
+    }
+
+    fn send(&mut self, to: Recipient, payload: Vec<u8>) -> Result<(), NetError> {
+        match &mut self.role {
+            Some(DirectRole::Host(host)) => host.send(to, payload),
+            Some(DirectRole::Peer(connection)) => connection.send(to, payload),
+            Some(DirectRole::Connecting(_)) | None => Err(NetError::Closed),
+        }
+    }
+
+    fn poll(&mut self) -> Vec<TransportEvent> {
+        let mut events = std::mem::take(&mut self.queued);
+        self.finish_connecting(&mut events);
+        match &mut self.role {
+            Some(DirectRole::Host(host)) => host.poll(&mut events),
+            Some(DirectRole::Peer(connection)) => events.extend(connection.poll()),
+            Some(DirectRole::Connecting(_)) | None => {}
+        }
+        events
+    }
+
+    fn close(&mut self) {
+        // Dropping the listener and streams closes them
+        self.role = None;
+    }
+}
@@ -0,0 +1,150 @@
+//! Packets sent between peers and the relay, and how they're framed on the wire
+//!
+//! Each packet is bincode encoded and sent with its length as a big-endian
+//! `u32` in front of it. Streams are non-blocking, so partly received packets
+//! are kept until the rest arrives.
+
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+
+use serde::{Deserialize, Serialize};
+
+use super::transport::{NetError, PeerId};
+
+/// Largest packet accepted, so a bad length can't exhaust memory
+pub const MAX_PACKET_LEN: usize = 1 << 20;
+
+/// A packet between a peer and the host or the relay
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Packet {
+    /// Ask the relay to open a room
+    Host,
+    /// Ask the relay to join the room with a code
+    Join { room: String },
+    /// The relay opened a room for its host
+    RoomOpened { room: String },
+    /// Sent to a peer once it's connected, with the id it was given
+    Welcome { peer: PeerId },
+    /// Another peer joined
+    PeerJoined { peer: PeerId },
+    /// Another peer left
+    PeerLeft { peer: PeerId },
+    /// Game data; `to` is `None` for everyone else
+    Data {
+        from: PeerId,
+        to: Option<PeerId>,
+        payload: Vec<u8>,
+    },
+    /// The request was refused, like joining a room that doesn't exist
+    Rejected { reason: String },
+}
+
+/// A non-blocking stream sending and receiving whole packets
+#[derive(Debug)]
+pub struct FramedStream {
+    stream: TcpStream,
+    incoming: Vec<u8>,
+    outgoing: Vec<u8>,
+    closed: bool,
+}
+
+impl FramedStream {
+    /// Wrap a connected stream, making it non-blocking
+    pub fn new(stream: TcpStream) -> io::Result<Self> {
+        stream.set_nonblocking(true)?;
+        stream.set_nodelay(true)?;
+        Ok(Self {
+            stream,
+            incoming: Vec::new(),
+            outgoing: Vec::new(),
+            closed: false,
+        })
+    }
+
+    /// Whether the other end closed the connection or it failed
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Queue a packet and send as much as the connection takes
+    pub fn send(&mut self, packet: &Packet) -> Result<(), NetError> {
+        if self.closed {
+            return Err(NetError::Closed);
+        }
+        let bytes = bincode::serde::encode_to_vec(packet, bincode::config::standard())
+            .map_err(|e| NetError::Protocol(e.to_string()))?;
+        if bytes.len() > MAX_PACKET_LEN {
+            return Err(NetError::Protocol(format!(
+                "packet of {} bytes is too large",
+                bytes.len()
+            )));
+        }
+        self.outgoing
+            .extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+        self.outgoing.extend_from_slice(&bytes);
+        self.flush();
+        Ok(())
+    }
+
+    /// Send what's still queued
+    pub fn flush(&mut self) {
+        while !self.outgoing.is_empty() && !self.closed {
+            match self.stream.write(&self.outgoing) {
+                Ok(0) => self.closed = true,
+                Ok(written) => {
+                    self.outgoing.drain(..written);
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(_) => self.closed = true,
+            }
+        }
+    }
+
+    /// The packets received since the last call
+    pub fn receive(&mut self) -> Result<Vec<Packet>, NetError> {
+        self.flush();
+        let mut buffer = [0; 4096];
+        while !self.closed {
+            match self.stream.read(&mut buffer) {
+                Ok(0) => self.closed = true,
+                Ok(read) => self.incoming.extend_from_slice(&buffer[..read]),
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(_) => self.closed = true,
+            }
+        }
+
+        let mut packets = Vec::new();
+        while self.incoming.len() >= 4 {
+            let len = u32::from_be_bytes([
+                self.incoming[0],
+                self.incoming[1],
+                self.incoming[2],
+                self.incoming[3],
+            ]) as usize;
+            if len > MAX_PACKET_LEN {
+                self.closed = true;
+                return Err(NetError::Protocol(format!(
+                    "packet of {} bytes is too large",
+                    len
+                )));
+            }
+            if self.incoming.len() < 4 + len {
+                break;
+            }
+            let decoded = bincode::serde::decode_from_slice(
+                &self.incoming[4..4 + len],
+                bincode::config::standard(),
+            );
+            let (packet, _) = decoded.map_err(|e| {
+                // Nothing after a bad packet can be trusted
+                self.closed = true;
+                NetError::Protocol(e.to_string())
+            })?;
+            self.incoming.drain(..4 + len);
+            packets.push(packet);
+        }
+        Ok(packets)
+    }
+}
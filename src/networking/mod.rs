@@ -1 +1,65 @@
+//! Connections between the players of an online game
+//!
+//! How players are connected is hidden behind [`NetTransport`], so the game
+//! works the same over any of them:
+//!
+//! - [`DirectTransport`] connects straight to the host's address
+//! - [`RelayTransport`] connects through a [`RelayServer`] with a room code,
+//!   for players behind NAT
+//!
+//! The transport in use is the [`ActiveTransport`] resource. It's polled each
+//! frame, and what happened on it is sent as [`TransportEvent`]s.
+
+use bevy::prelude::*;
+
+mod client;
+pub mod direct;
+mod frame;
+pub mod relay;
+mod room;
+pub mod transport;
+
+pub use direct::DirectTransport;
+pub use relay::{RelayServer, RelayTransport};
+pub use transport::{
+    HOST_PEER, NetError, NetTransport, PeerId, Recipient, TransportEvent, TransportKind,
+};
+
+/// The connection to the other players, while in an online game
+#[derive(Resource)]
+pub struct ActiveTransport(pub Box<dyn NetTransport>);
+
+impl ActiveTransport {
+    pub fn new(transport: impl NetTransport) -> Self {
+        Self(Box::new(transport))
+    }
+}
+
+/// Send what happened on the active transport as events
+pub fn poll_transport(
+    transport: Option<ResMut<ActiveTransport>>,
+    mut events: EventWriter<TransportEvent>,
+) {
+    let Some(mut transport) = transport else {
+        return;
+    };
+    for event in transport.0.poll() {
+        if let TransportEvent::Disconnected { reason } = &event {
+            warn!("Disconnected from the game: {}", reason);
+        }
+        events.write(event);
+    }
+}
+
+/// Plugin polling the connection to the other players
+pub struct NetworkingPlugin;
+
+impl Plugin for NetworkingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TransportEvent>()
+            .add_systems(PreUpdate, poll_transport);
+    }
+}
+
+#[cfg(test)]
 mod tests;
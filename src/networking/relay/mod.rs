@@ -0,0 +1,103 @@
+//! Connecting through a relay server with room codes
+//!
+//! Hosts and peers both connect out to a relay, so neither needs a reachable
+//! address. The host asks the relay for a room and gets a short code to share;
+//! peers join with the code and the relay forwards messages within the room.
+//! [`RelayServer`] is the relay itself, small enough to run anywhere.
+
+use std::net::{TcpStream, ToSocketAddrs};
+
+use super::client::HostConnection;
+use super::frame::{FramedStream, Packet};
+use super::transport::{NetError, NetTransport, PeerId, Recipient, TransportEvent, TransportKind};
+
+mod server;
+pub use server::{ROOM_CODE_LEN, RelayServer};
+
+/// Port a relay listens on unless configured otherwise
+pub const DEFAULT_RELAY_PORT: u16 = 7878;
+
+/// Players connected through a relay server
+#[derive(Debug)]
+pub struct RelayTransport {
+    /// `None` once closed
+    connection: Option<HostConnection>,
+    room: Option<String>,
+}
+
+impl RelayTransport {
+    /// Open a room on the relay at `server`
+    ///
+    /// Its code arrives as a [`TransportEvent::RoomOpened`].
+    pub fn host(server: impl ToSocketAddrs) -> Result<Self, NetError> {
+        Self::open(server, &Packet::Host, None)
+    }
+
+    /// Join the room with `code` on the relay at `server`
+    pub fn join(server: impl ToSocketAddrs, code: &str) -> Result<Self, NetError> {
+        let room = normalize_room_code(code);
+        Self::open(server, &Packet::Join { room: room.clone() }, Some(room))
+    }
+
+    fn open(
+        server: impl ToSocketAddrs,
+        request: &Packet,
+        room: Option<String>,
+    ) -> Result<Self, NetError> {
+        let mut connection = HostConnection::new(FramedStream::new(TcpStream::connect(server)?)?);
+        connection.send_packet(request)?;
+        Ok(Self {
+            connection: Some(connection),
+            room,
+        })
+    }
+
+    /// The code of the room, once the relay opened it
+    pub fn room_code(&self) -> Option<&str> {
+        self.room.as_deref()
+    }
+}
+
+/// A room code as the relay knows it, whatever case or spacing it was typed in
+pub fn normalize_room_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+impl NetTransport for RelayTransport {
+    fn kind(&self) -> TransportKind {
+        TransportKind::Relay
+    }
+
+    fn local_peer(&self) -> Option<PeerId> {
+        self.connection
+            .as_ref()
+            .and_then(|connection| connection.local_peer())
+    }
+
+    fn send(&mut self, to: Recipient, payload: Vec<u8>) -> Result<(), NetError> {
+        match self.connection.as_mut() {
+            Some(connection) => connection.send(to, payload),
+            None => Err(NetError::Closed),
+        }
+    }
+
+    fn poll(&mut self) -> Vec<TransportEvent> {
+        let Some(connection) = self.connection.as_mut() else {
+            return Vec::new();
+        };
+        let events = connection.poll();
+        for event in &events {
+            if let TransportEvent::RoomOpened { code } = event {
+                self.room = Some(code.clone());
+            }
+        }
+        events
+    }
+
+    fn close(&mut self) {
+        self.connection = None;
+    }
+}
@@ -0,0 +1,146 @@
+//! The relay server players connect through
+
+use std::collections::HashMap;
+use std::io;
+use std::net::{SocketAddr, TcpListener, ToSocketAddrs};
+
+use bevy::log::{info, warn};
+use rand::Rng;
+
+use crate::networking::frame::{FramedStream, Packet};
+use crate::networking::room::Room;
+use crate::networking::transport::{HOST_PEER, NetError};
+
+/// Length of the codes rooms are joined with
+pub const ROOM_CODE_LEN: usize = 6;
+
+/// Letters and digits used in room codes, leaving out ones easily mixed up
+const ROOM_CODE_CHARS: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// A relay forwarding messages between the players of each room
+///
+/// It doesn't block: call [`RelayServer::poll`] in a loop, or each frame when
+/// a player runs it alongside their game.
+#[derive(Debug)]
+pub struct RelayServer {
+    listener: TcpListener,
+    /// Connections that haven't asked to host or join yet
+    pending: Vec<FramedStream>,
+    rooms: HashMap<String, Room>,
+}
+
+impl RelayServer {
+    /// Start a relay listening on `addr`
+    pub fn bind(addr: impl ToSocketAddrs) -> Result<Self, NetError> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        Ok(Self {
+            listener,
+            pending: Vec::new(),
+            rooms: HashMap::new(),
+        })
+    }
+
+    /// The address the relay listens on
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.listener.local_addr().ok()
+    }
+
+    /// The codes of the open rooms
+    pub fn rooms(&self) -> impl Iterator<Item = &str> {
+        self.rooms.keys().map(String::as_str)
+    }
+
+    /// Accept connections and forward what's been received
+    pub fn poll(&mut self) {
+        self.accept();
+
+        for mut stream in std::mem::take(&mut self.pending) {
+            let request = match stream.receive() {
+                Ok(packets) => packets.into_iter().next(),
+                Err(e) => {
+                    warn!("Dropping relay connection: {}", e);
+                    continue;
+                }
+            };
+            match request {
+                Some(Packet::Host) => self.open_room(stream),
+                Some(Packet::Join { room }) => self.join_room(stream, &room),
+                Some(_) => {
+                    let _ = stream.send(&Packet::Rejected {
+                        reason: "host or join a room first".to_string(),
+                    });
+                }
+                None if !stream.is_closed() => self.pending.push(stream),
+                None => {}
+            }
+        }
+
+        for room in self.rooms.values_mut() {
+            let update = room.poll();
+            for (from, packet) in update.packets {
+                if let Packet::Data { to, payload, .. } = packet {
+                    room.route(from, to, &payload);
+                }
+            }
+            room.remove(&update.left);
+        }
+        self.rooms.retain(|code, room| {
+            if room.is_empty() {
+                info!("Closing relay room {}", code);
+            }
+            !room.is_empty()
+        });
+    }
+
+    fn accept(&mut self) {
+        loop {
+            match self.listener.accept() {
+                Ok((stream, addr)) => match FramedStream::new(stream) {
+                    Ok(stream) => self.pending.push(stream),
+                    Err(e) => warn!("Couldn't accept a connection from {}: {}", addr, e),
+                },
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) => {
+                    warn!("Couldn't accept a connection: {}", e);
+                    break;
+                }
+            }
+        }
+    }
+
+    fn open_room(&mut self, stream: FramedStream) {
+        let code = loop {
+            let code = room_code(&mut rand::rng());
+            if !self.rooms.contains_key(&code) {
+                break code;
+            }
+        };
+        let mut room = Room::new(HOST_PEER);
+        room.add(stream);
+        room.send_to(HOST_PEER, &Packet::RoomOpened { room: code.clone() });
+        info!("Opened relay room {}", code);
+        self.rooms.insert(code, room);
+    }
+
+    fn join_room(&mut self, mut stream: FramedStream, code: &str) {
+        match self.rooms.get_mut(code) {
+            Some(room) => {
+                let peer = room.add(stream);
+                info!("Peer {} joined relay room {}", peer, code);
+            }
+            None => {
+                let _ = stream.send(&Packet::Rejected {
+                    reason: format!("there's no room {}", code),
+                });
+            }
+        }
+    }
+}
+
+/// A new random room code
+pub fn room_code(rng: &mut impl Rng) -> String {
+    (0..ROOM_CODE_LEN)
+        .map(|_| ROOM_CODE_CHARS[rng.random_range(0..ROOM_CODE_CHARS.len())] as char)
+        .collect()
+}
@@ -0,0 +1,105 @@
+//! The peers of one game, connected to whoever forwards their messages
+//!
+//! Both a direct host and the relay server keep a [`Room`] of the streams
+//! connected to them, forwarding each message to the peers it's for.
+
+use bevy::log::warn;
+
+use super::frame::{FramedStream, Packet};
+use super::transport::PeerId;
+
+/// What happened in a room since it was last polled
+#[derive(Debug, Default)]
+pub struct RoomUpdate {
+    /// Packets received, with the peer that sent them
+    pub packets: Vec<(PeerId, Packet)>,
+    /// Peers whose connection closed
+    pub left: Vec<PeerId>,
+}
+
+/// Peers connected to a host or relay
+#[derive(Debug)]
+pub struct Room {
+    members: Vec<(PeerId, FramedStream)>,
+    next_peer: PeerId,
+}
+
+impl Room {
+    /// An empty room whose first peer gets `first_peer`
+    pub fn new(first_peer: PeerId) -> Self {
+        Self {
+            members: Vec::new(),
+            next_peer: first_peer,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Add a peer, telling it its id and introducing it to the others
+    pub fn add(&mut self, mut stream: FramedStream) -> PeerId {
+        let peer = self.next_peer;
+        self.next_peer += 1;
+        // A failed send closes the stream, and the peer leaves on the next poll
+        let _ = stream.send(&Packet::Welcome { peer });
+        for (member, member_stream) in &mut self.members {
+            let _ = stream.send(&Packet::PeerJoined { peer: *member });
+            let _ = member_stream.send(&Packet::PeerJoined { peer });
+        }
+        self.members.push((peer, stream));
+        peer
+    }
+
+    /// Send a packet to one peer
+    pub fn send_to(&mut self, peer: PeerId, packet: &Packet) -> bool {
+        match self.members.iter_mut().find(|(member, _)| *member == peer) {
+            Some((_, stream)) => stream.send(packet).is_ok(),
+            None => false,
+        }
+    }
+
+    /// Forward data from `from` to `to`, or to everyone else if `None`
+    pub fn route(&mut self, from: PeerId, to: Option<PeerId>, payload: &[u8]) {
+        let packet = Packet::Data {
+            from,
+            to,
+            payload: payload.to_vec(),
+        };
+        for (member, stream) in &mut self.members {
+            if *member != from && to.is_none_or(|to| to == *member) {
+                let _ = stream.send(&packet);
+            }
+        }
+    }
+
+    /// Receive from every peer
+    ///
+    /// Peers whose connection closed stay in the room until they're
+    /// [removed](Room::remove), so what they sent last can still be forwarded.
+    pub fn poll(&mut self) -> RoomUpdate {
+        let mut update = RoomUpdate::default();
+        for (peer, stream) in &mut self.members {
+            match stream.receive() {
+                Ok(packets) => update
+                    .packets
+                    .extend(packets.into_iter().map(|packet| (*peer, packet))),
+                Err(e) => warn!("Dropping peer {}: {}", peer, e),
+            }
+            if stream.is_closed() {
+                update.left.push(*peer);
+            }
+        }
+        update
+    }
+
+    /// Remove peers that left, telling the others
+    pub fn remove(&mut self, left: &[PeerId]) {
+        self.members.retain(|(peer, _)| !left.contains(peer));
+        for peer in left {
+            for (_, stream) in &mut self.members {
+                let _ = stream.send(&Packet::PeerLeft { peer: *peer });
+            }
+        }
+    }
+}
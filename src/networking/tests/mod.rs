@@ -1 +1,209 @@
+use std::time::{Duration, Instant};
 
+use bevy::prelude::*;
+
+use crate::networking::relay::{ROOM_CODE_LEN, normalize_room_code};
+use crate::networking::{
+    ActiveTransport, DirectTransport, HOST_PEER, NetError, NetTransport, NetworkingPlugin,
+    Recipient, RelayServer, RelayTransport, TransportEvent,
+};
+
+/// Poll the transports, and the relay if there is one, until `done` holds
+fn poll_until(
+    relay: &mut Option<&mut RelayServer>,
+    transports: &mut [&mut dyn NetTransport],
+    received: &mut [Vec<TransportEvent>],
+    done: impl Fn(&[Vec<TransportEvent>]) -> bool,
+) {
+    let start = Instant::now();
+    while !done(received) {
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "timed out, got {:?}",
+            received
+        );
+        if let Some(relay) = relay.as_mut() {
+            relay.poll();
+        }
+        for (transport, events) in transports.iter_mut().zip(received.iter_mut()) {
+            events.extend(transport.poll());
+        }
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+fn message(from: u32, payload: &[u8]) -> TransportEvent {
+    TransportEvent::Message {
+        from,
+        payload: payload.to_vec(),
+    }
+}
+
+/// How many peers joined, by the events
+fn joined(events: &[TransportEvent]) -> usize {
+    events
+        .iter()
+        .filter(|event| matches!(event, TransportEvent::PeerJoined(_)))
+        .count()
+}
+
+#[test]
+fn test_direct_peers_reach_each_other_through_the_host() {
+    let mut host = DirectTransport::host("127.0.0.1:0").unwrap();
+    let addr = host.local_addr().unwrap();
+    let mut alice = DirectTransport::connect(addr).unwrap();
+    let mut bob = DirectTransport::connect(addr).unwrap();
+    let mut received = vec![Vec::new(), Vec::new(), Vec::new()];
+    poll_until(
+        &mut None,
+        &mut [&mut host, &mut alice, &mut bob],
+        &mut received,
+        |received| joined(&received[0]) == 2 && joined(&received[1]) + joined(&received[2]) == 2,
+    );
+    assert_eq!(host.local_peer(), Some(HOST_PEER));
+    let alice_id = alice.local_peer().unwrap();
+    let bob_id = bob.local_peer().unwrap();
+    assert_ne!(alice_id, bob_id);
+
+    host.send(Recipient::Everyone, b"untap".to_vec()).unwrap();
+    alice
+        .send(Recipient::Peer(bob_id), b"hello".to_vec())
+        .unwrap();
+    let mut received = vec![Vec::new(), Vec::new(), Vec::new()];
+    poll_until(
+        &mut None,
+        &mut [&mut host, &mut alice, &mut bob],
+        &mut received,
+        |received| received[1].len() == 1 && received[2].len() == 2,
+    );
+    assert_eq!(received[1], vec![message(HOST_PEER, b"untap")]);
+    assert!(received[2].contains(&message(HOST_PEER, b"untap")));
+    assert!(received[2].contains(&message(alice_id, b"hello")));
+    // Only addressed to Bob
+    assert!(received[0].is_empty());
+}
+
+#[test]
+fn test_direct_connections_that_fail_are_reported_by_poll() {
+    // A port nothing listens on any more
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let mut peer = DirectTransport::connect(addr).unwrap();
+    assert_eq!(peer.local_peer(), None);
+    assert_eq!(
+        peer.send(Recipient::Everyone, b"hello".to_vec()),
+        Err(NetError::Closed)
+    );
+
+    let mut received = vec![Vec::new()];
+    poll_until(&mut None, &mut [&mut peer], &mut received, |received| {
+        !received[0].is_empty()
+    });
+    assert!(matches!(
+        received[0].as_slice(),
+        [TransportEvent::Disconnected { .. }]
+    ));
+}
+
+#[test]
+fn test_relay_rooms_are_joined_with_their_code() {
+    let mut relay = RelayServer::bind("127.0.0.1:0").unwrap();
+    let addr = relay.local_addr().unwrap();
+    let mut host = RelayTransport::host(addr).unwrap();
+    let mut received = vec![Vec::new()];
+    poll_until(
+        &mut Some(&mut relay),
+        &mut [&mut host],
+        &mut received,
+        |received| received[0].len() == 2,
+    );
+    assert_eq!(
+        received[0][0],
+        TransportEvent::Connected {
+            local_peer: HOST_PEER
+        }
+    );
+    let code = host.room_code().unwrap().to_string();
+    assert_eq!(code.len(), ROOM_CODE_LEN);
+    assert_eq!(relay.rooms().collect::<Vec<_>>(), vec![code.as_str()]);
+
+    let mut guest = RelayTransport::join(addr, &code.to_lowercase()).unwrap();
+    let mut received = vec![Vec::new(), Vec::new()];
+    poll_until(
+        &mut Some(&mut relay),
+        &mut [&mut host, &mut guest],
+        &mut received,
+        |received| received[0].len() == 1 && received[1].len() == 2,
+    );
+    assert_eq!(received[0], vec![TransportEvent::PeerJoined(1)]);
+    assert_eq!(
+        received[1],
+        vec![
+            TransportEvent::Connected { local_peer: 1 },
+            TransportEvent::PeerJoined(HOST_PEER)
+        ]
+    );
+
+    guest
+        .send(Recipient::Peer(HOST_PEER), b"pass".to_vec())
+        .unwrap();
+    guest.close();
+    let mut received = vec![Vec::new()];
+    poll_until(
+        &mut Some(&mut relay),
+        &mut [&mut host],
+        &mut received,
+        |received| received[0].len() == 2,
+    );
+    assert_eq!(
+        received[0],
+        vec![message(1, b"pass"), TransportEvent::PeerLeft(1)]
+    );
+}
+
+#[test]
+fn test_joining_a_missing_room_disconnects() {
+    let mut relay = RelayServer::bind("127.0.0.1:0").unwrap();
+    let mut guest = RelayTransport::join(relay.local_addr().unwrap(), "NOPE42").unwrap();
+    let mut received = vec![Vec::new()];
+    poll_until(
+        &mut Some(&mut relay),
+        &mut [&mut guest],
+        &mut received,
+        |received| !received[0].is_empty(),
+    );
+    assert!(matches!(
+        received[0][0],
+        TransportEvent::Disconnected { .. }
+    ));
+    assert_eq!(guest.local_peer(), None);
+}
+
+#[test]
+fn test_room_codes_ignore_case_and_spacing() {
+    assert_eq!(normalize_room_code(" ab3-k9z "), "AB3K9Z");
+}
+
+#[test]
+fn test_the_active_transport_is_polled_into_events() {
+    let mut app = App::new();
+    app.add_plugins(NetworkingPlugin)
+        .insert_resource(ActiveTransport::new(
+            DirectTransport::host("127.0.0.1:0").unwrap(),
+        ));
+    app.update();
+
+    let events: Vec<TransportEvent> = app
+        .world_mut()
+        .resource_mut::<Events<TransportEvent>>()
+        .drain()
+        .collect();
+    assert_eq!(
+        events,
+        vec![TransportEvent::Connected {
+            local_peer: HOST_PEER
+        }]
+    );
+}
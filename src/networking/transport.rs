@@ -0,0 +1,107 @@
+//! The interface every way of connecting players implements
+
+use std::fmt;
+use std::io;
+
+use bevy::prelude::*;
+
+/// A player's connection in a game, unique within it
+///
+/// The host is always [`HOST_PEER`]; peers joining get the next free id.
+pub type PeerId = u32;
+
+/// The peer id of the player hosting the game
+pub const HOST_PEER: PeerId = 0;
+
+/// How players are connected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransportKind {
+    /// Straight to the host's address, which needs the host to be reachable
+    Direct,
+    /// Through a relay server, joining with a room code
+    Relay,
+}
+
+/// Who a message is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recipient {
+    Peer(PeerId),
+    /// Every other peer
+    Everyone,
+}
+
+/// Something that happened on a transport
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub enum TransportEvent {
+    /// Connected, as the peer `local_peer`
+    Connected {
+        local_peer: PeerId,
+    },
+    /// The relay opened a room others can join with its code
+    RoomOpened {
+        code: String,
+    },
+    PeerJoined(PeerId),
+    PeerLeft(PeerId),
+    Message {
+        from: PeerId,
+        payload: Vec<u8>,
+    },
+    /// The connection was lost or refused
+    Disconnected {
+        reason: String,
+    },
+}
+
+/// Why a transport couldn't do something
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetError {
+    /// Connecting or sending failed
+    Io(String),
+    /// Nothing is connected yet, or the connection was closed
+    Closed,
+    /// The peer isn't reachable over this transport
+    UnknownPeer(PeerId),
+    /// The other end sent something that isn't a valid packet
+    Protocol(String),
+}
+
+impl fmt::Display for NetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetError::Io(message) => write!(f, "connection failed: {}", message),
+            NetError::Closed => write!(f, "not connected"),
+            NetError::UnknownPeer(peer) => write!(f, "no connection to peer {}", peer),
+            NetError::Protocol(message) => write!(f, "invalid packet: {}", message),
+        }
+    }
+}
+
+impl From<io::Error> for NetError {
+    fn from(e: io::Error) -> Self {
+        NetError::Io(e.to_string())
+    }
+}
+
+/// A way of exchanging messages with the other players
+///
+/// Direct and relay connections, and later ones like Steam, all implement it
+/// so the game doesn't need to know how its players are connected. Transports
+/// never block: [`NetTransport::poll`] returns what happened since it was last
+/// called, and messages are sent in the order they're given.
+pub trait NetTransport: Send + Sync + 'static {
+    /// How this transport connects players
+    fn kind(&self) -> TransportKind;
+
+    /// This end's peer id, once connected
+    fn local_peer(&self) -> Option<PeerId>;
+
+    /// Send a message to one peer or everyone else
+    fn send(&mut self, to: Recipient, payload: Vec<u8>) -> Result<(), NetError>;
+
+    /// What happened since the last poll
+    fn poll(&mut self) -> Vec<TransportEvent>;
+
+    /// Close the connection
+    fn close(&mut self);
+}
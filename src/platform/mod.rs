@@ -0,0 +1,85 @@
+//! Window, rendering and asset setup for native and browser builds
+//!
+//! Native builds render through Vulkan, which copes best with WSL2. Wasm builds
+//! draw into the page's `#bevy` canvas through WebGL2, or WebGPU with the
+//! `webgpu` feature, and fetch assets over HTTP from the page's `assets/` folder.
+//!
+//! Build for the browser with
+//! `cargo build --target wasm32-unknown-unknown --no-default-features --features snapshot`,
+//! which leaves out the `native` feature and with it tokio, reqwest and every
+//! download. Saves and settings go to `localStorage`, see
+//! [`storage`](crate::game_engine::save::storage).
+
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::render::RenderPlugin;
+use bevy::render::settings::{Backends, RenderCreation, WgpuSettings};
+use bevy::window::{EnabledButtons, PresentMode};
+
+/// The game's main window
+pub fn primary_window() -> Window {
+    Window {
+        title: "Rummage - Commander Card Game".to_string(),
+        resolution: (1280.0, 720.0).into(),
+        position: WindowPosition::Centered(MonitorSelection::Current),
+        resizable: true,
+        present_mode: PresentMode::AutoVsync,
+        prevent_default_event_handling: false,
+        enabled_buttons: EnabledButtons {
+            maximize: false,
+            ..default()
+        },
+        visible: true,
+        // Render into the page's canvas, sized to its container
+        canvas: cfg!(target_arch = "wasm32").then(|| "#bevy".to_string()),
+        fit_canvas_to_parent: cfg!(target_arch = "wasm32"),
+        ..default()
+    }
+}
+
+/// Graphics backends to render with
+pub fn render_backends() -> Backends {
+    if cfg!(not(target_arch = "wasm32")) {
+        // Prefer Vulkan backend for better WSL2 compatibility
+        Backends::VULKAN
+    } else if cfg!(feature = "webgpu") {
+        Backends::BROWSER_WEBGPU
+    } else {
+        // WebGL2, which every current browser supports
+        Backends::GL
+    }
+}
+
+/// Rendering setup, resilient to WSL2 and browser limitations
+pub fn render_plugin() -> RenderPlugin {
+    RenderPlugin {
+        render_creation: RenderCreation::Automatic(WgpuSettings {
+            backends: Some(render_backends()),
+            // Use low power preference for better WSL2 compatibility
+            // power_preference: bevy::render::settings::PowerPreference::LowPower,
+            // Don't require all features, adapt to what's available in WSL2
+            // features: bevy::render::settings::WgpuFeatures::empty(),
+            // Add more conservative options for WSL2 compatibility
+            // dx12_shader_compiler: bevy::render::settings::Dx12Compiler::Fxc,
+            ..default()
+        }),
+        // Don't wait for pipelines to compile, which can hang under certain conditions
+        // and isn't possible in the browser
+        synchronous_pipeline_compilation: false,
+        ..default()
+    }
+}
+
+/// Asset loading setup
+pub fn asset_plugin() -> AssetPlugin {
+    AssetPlugin {
+        // Assets have no .meta files, and asking the web server for one per
+        // asset only fills the browser console with 404s
+        meta_check: if cfg!(target_arch = "wasm32") {
+            bevy::asset::AssetMetaCheck::Never
+        } else {
+            bevy::asset::AssetMetaCheck::default()
+        },
+        ..default()
+    }
+}
@@ -13,6 +13,8 @@ pub use components::Player;
 pub use playmat::plugin::PlayerPlaymatPlugin;
 pub use resources::PlayerConfig;
 pub use systems::debug::{PlayerPositionTracker, debug_draw_player_positions};
+pub use systems::layout::{apply_table_layout, fit_table_to_window};
+use systems::spawn::table::TableLayout;
 
 /// Plugin for player-related functionality
 pub struct PlayerPlugin;
@@ -22,6 +24,13 @@ impl Plugin for PlayerPlugin {
         app.init_resource::<PlayerConfig>()
             .init_resource::<PlayerPositionTracker>()
             .add_systems(FixedUpdate, debug_draw_player_positions)
+            // Keep the table in view as the window and UI scale change
+            .add_systems(
+                Update,
+                (fit_table_to_window, apply_table_layout)
+                    .chain()
+                    .run_if(resource_exists::<TableLayout>),
+            )
             .add_plugins(PlayerPlaymatPlugin);
     }
 }
@@ -1,15 +1,21 @@
 //! Battlefield zone implementation for the player playmat
 
+use std::collections::HashSet;
+
 use crate::camera::components::AppLayer;
+use crate::camera::z_order::ZBand;
+use crate::cards::animation::CardAnimation;
 use crate::game_engine::zones::Zone;
 use crate::player::components::Player;
 use crate::player::resources::PlayerConfig;
+use crate::tracing::CardUpdateCounts;
 use bevy::ecs::hierarchy::ChildOf;
 use bevy::input::keyboard::KeyCode;
 use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
 
 use super::PlaymatZone;
+use super::systems::place_card;
 
 /// Component for the battlefield zone specifically
 #[derive(Component, Debug)]
@@ -51,6 +57,13 @@ pub enum PermanentType {
     Token,
 }
 
+/// What the battlefield layout reads and writes on each card
+type CardPlacement = (
+    &'static mut Transform,
+    Option<&'static PermanentType>,
+    Option<&'static mut CardAnimation>,
+);
+
 /// Local struct for grouping card positioning parameters
 struct CardGroupPositioning {
     start_row: f32,
@@ -115,10 +128,21 @@ pub fn spawn_battlefield_zone(
 }
 
 /// Organize the cards on the battlefield in a grid layout
+///
+/// A battlefield is only laid out again when its cards, grouping or zoom change,
+/// a card's type changes, a card starts or ends an animation, or the window is
+/// resized, and only transforms that move are written, so a large idle
+/// battlefield costs next to nothing. Cards in flight are steered into their cell.
+#[allow(clippy::too_many_arguments)]
 pub fn organize_battlefield_cards(
-    battlefield_query: Query<(&BattlefieldZone, &Children)>,
-    mut card_query: Query<(&mut Transform, Option<&PermanentType>)>,
+    battlefield_query: Query<(Ref<BattlefieldZone>, Ref<Children>, &GlobalTransform)>,
+    mut card_query: Query<CardPlacement>,
+    changed_types: Query<(), Changed<PermanentType>>,
+    started: Query<(), Added<CardAnimation>>,
+    mut landed: RemovedComponents<CardAnimation>,
     windows: Query<&Window, With<bevy::window::PrimaryWindow>>,
+    mut last_window_size: Local<Option<Vec2>>,
+    counts: Option<ResMut<CardUpdateCounts>>,
 ) {
     // Safely get the window dimensions, defaulting to reasonable values if not available
     let (window_width, window_height) = if let Ok(window) = windows.single() {
@@ -127,8 +151,22 @@ pub fn organize_battlefield_cards(
         // Default to standard HD resolution if window can't be queried
         (1920.0, 1080.0)
     };
+    let window_size = Vec2::new(window_width, window_height);
+    let resized = last_window_size.replace(window_size) != Some(window_size);
+    let landed: HashSet<Entity> = landed.read().collect();
+    let mut updated = 0;
+
+    for (battlefield, children, zone) in battlefield_query.iter() {
+        let changed = resized
+            || battlefield.is_changed()
+            || children.is_changed()
+            || children.iter().any(|card| {
+                changed_types.contains(card) || started.contains(card) || landed.contains(&card)
+            });
+        if !changed {
+            continue;
+        }
 
-    for (battlefield, children) in battlefield_query.iter() {
         let card_count = children.len();
 
         // Skip if no cards on battlefield
@@ -158,7 +196,7 @@ pub fn organize_battlefield_cards(
 
             // Group cards by type
             for child_entity_ref in children.iter() {
-                if let Ok((_, permanent_type)) = card_query.get(child_entity_ref) {
+                if let Ok((_, permanent_type, _)) = card_query.get(child_entity_ref) {
                     match permanent_type {
                         Some(PermanentType::Creature) => creatures.push(child_entity_ref),
                         Some(PermanentType::Land) => lands.push(child_entity_ref),
@@ -176,8 +214,9 @@ pub fn organize_battlefield_cards(
             // Position each group in its own section
             let grid_width = battlefield.grid_columns as f32;
             let grid_height = battlefield.grid_rows as f32;
-            position_card_group(
+            updated += position_card_group(
                 &mut card_query,
+                zone,
                 &creatures,
                 CardGroupPositioning {
                     start_row: 0.0,
@@ -188,8 +227,9 @@ pub fn organize_battlefield_cards(
                     scale,
                 },
             );
-            position_card_group(
+            updated += position_card_group(
                 &mut card_query,
+                zone,
                 &lands,
                 CardGroupPositioning {
                     start_row: 0.0,
@@ -200,8 +240,9 @@ pub fn organize_battlefield_cards(
                     scale,
                 },
             );
-            position_card_group(
+            updated += position_card_group(
                 &mut card_query,
+                zone,
                 &artifacts,
                 CardGroupPositioning {
                     start_row: grid_height / 2.0,
@@ -212,8 +253,9 @@ pub fn organize_battlefield_cards(
                     scale,
                 },
             );
-            position_card_group(
+            updated += position_card_group(
                 &mut card_query,
+                zone,
                 &enchantments,
                 CardGroupPositioning {
                     start_row: grid_height / 2.0,
@@ -232,8 +274,9 @@ pub fn organize_battlefield_cards(
                 .chain(other.iter())
                 .copied()
                 .collect();
-            position_card_group(
+            updated += position_card_group(
                 &mut card_query,
+                zone,
                 &remaining_cards,
                 CardGroupPositioning {
                     start_row: 0.0,
@@ -250,19 +293,29 @@ pub fn organize_battlefield_cards(
             let start_y = -(grid_height * cell_size) / 2.0 + (cell_size / 2.0);
 
             for (i, child_entity_ref) in children.iter().enumerate() {
-                if let Ok((mut transform, _)) = card_query.get_mut(child_entity_ref) {
+                if let Ok((mut transform, _, animation)) = card_query.get_mut(child_entity_ref) {
                     let row = (i as u32) / battlefield.grid_columns;
                     let col = (i as u32) % battlefield.grid_columns;
 
                     let x = start_x + (col as f32 * cell_size);
                     let y = start_y + (row as f32 * cell_size);
 
-                    transform.translation = Vec3::new(x, y, i as f32 * 0.1);
-                    transform.scale = Vec3::splat(scale);
+                    let arranged = Transform {
+                        translation: Vec3::new(x, y, ZBand::Battlefield.z(i)),
+                        scale: Vec3::splat(scale),
+                        ..*transform
+                    };
+                    if place_card(&mut transform, animation, arranged, zone) {
+                        updated += 1;
+                    }
                 }
             }
         }
     }
+
+    if let Some(mut counts) = counts.filter(|_| updated > 0) {
+        counts.transforms += updated;
+    }
 }
 
 /// Calculate layout parameters for battlefield based on card count
@@ -298,19 +351,20 @@ fn calculate_battlefield_layout(
     (columns as f32, rows as f32, cell_size, scale)
 }
 
-/// Position a group of cards in a specified grid area
+/// Position a group of cards in a specified grid area, returning how many moved
 fn position_card_group(
-    card_query: &mut Query<(&mut Transform, Option<&PermanentType>)>,
+    card_query: &mut Query<CardPlacement>,
     cards: &[Entity],
+    zone: &GlobalTransform,
     positioning: CardGroupPositioning,
-) {
+) -> u32 {
     if cards.is_empty() {
-        return;
+        return 0;
     }
 
     let group_columns = ((positioning.end_col - positioning.start_col) * 2.0) as u32;
     if group_columns == 0 {
-        return;
+        return 0;
     }
 
     let start_x = (positioning.start_col * positioning.cell_size)
@@ -318,18 +372,26 @@ fn position_card_group(
     let start_y = (positioning.start_row * positioning.cell_size)
         - (((positioning.end_row - positioning.start_row) / 2.0) * positioning.cell_size);
 
+    let mut updated = 0;
     for (i, card_entity_ref) in cards.iter().enumerate() {
-        if let Ok((mut transform, _)) = card_query.get_mut(*card_entity_ref) {
+        if let Ok((mut transform, _, animation)) = card_query.get_mut(*card_entity_ref) {
             let local_row = (i as u32) / group_columns;
             let local_col = (i as u32) % group_columns;
 
             let x = start_x + (local_col as f32 * positioning.cell_size / 2.0);
             let y = start_y + (local_row as f32 * positioning.cell_size / 2.0);
 
-            transform.translation = Vec3::new(x, y, i as f32 * 0.1);
-            transform.scale = Vec3::splat(positioning.scale);
+            let arranged = Transform {
+                translation: Vec3::new(x, y, ZBand::Battlefield.z(i)),
+                scale: Vec3::splat(positioning.scale),
+                ..*transform
+            };
+            if place_card(&mut transform, animation, arranged, zone) {
+                updated += 1;
+            }
         }
     }
+    updated
 }
 
 /// System to toggle battlefield card grouping
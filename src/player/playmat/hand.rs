@@ -1,14 +1,20 @@
 //! Hand zone implementation for the player playmat
 
+use std::collections::HashSet;
+
 use crate::camera::components::AppLayer;
+use crate::cards::animation::CardAnimation;
 use crate::game_engine::zones::Zone;
 use crate::player::components::Player;
 use crate::player::resources::PlayerConfig;
+use crate::tracing::CardUpdateCounts;
 use bevy::ecs::hierarchy::ChildOf;
 use bevy::prelude::*;
 
-use super::PlaymatZone;
+use super::systems::place_card;
+use super::{PLAYMAT_SIZE, PlaymatZone};
 use crate::camera::components::GameCamera;
+use crate::player::systems::spawn::table::TableLayout;
 
 /// Component for the hand zone specifically
 #[derive(Component, Debug)]
@@ -88,20 +94,38 @@ pub fn spawn_hand_zone(
 }
 
 /// System to arrange cards in hand based on hand size
+///
+/// Hands are only laid out again when their cards or expansion change, a card
+/// starts or ends an animation, or the table is refitted to the window, and only
+/// transforms that move are written. Cards in flight are steered into their slot.
 pub fn arrange_cards_in_hand(
-    mut query: Query<(&HandZone, &Children, &mut Transform)>,
-    mut card_query: Query<&mut Transform, Without<HandZone>>,
-    windows: Query<&Window, With<bevy::window::PrimaryWindow>>,
+    query: Query<(Ref<HandZone>, Ref<Children>, &GlobalTransform)>,
+    mut card_query: Query<(&mut Transform, Option<&mut CardAnimation>), Without<HandZone>>,
+    started: Query<(), Added<CardAnimation>>,
+    mut landed: RemovedComponents<CardAnimation>,
+    table: Option<Res<TableLayout>>,
+    counts: Option<ResMut<CardUpdateCounts>>,
 ) {
-    // Safely get window width, defaulting to a reasonable value if not available
-    let window_width = if let Ok(window) = windows.single() {
-        window.width()
-    } else {
-        // Default to standard widescreen width if window can't be queried
-        1920.0
-    };
+    // Hands sit inside their playmat, so the width is in playmat units
+    let hand_width = table
+        .as_ref()
+        .map_or(PLAYMAT_SIZE.x, |table| table.hand_width());
+    let refitted = table.is_some_and(|table| table.is_changed());
+    // The animation left these cards wherever it ended, so put them back in their slot
+    let landed: HashSet<Entity> = landed.read().collect();
+    let mut updated = 0;
+
+    for (hand, children, hand_transform) in query.iter() {
+        let changed = refitted
+            || hand.is_changed()
+            || children.is_changed()
+            || children
+                .iter()
+                .any(|card| started.contains(card) || landed.contains(&card));
+        if !changed {
+            continue;
+        }
 
-    for (hand, children, _hand_transform) in query.iter_mut() {
         let card_count = children.len() as u32;
 
         // Skip if no cards in hand
@@ -115,7 +139,7 @@ pub fn arrange_cards_in_hand(
             hand.optimal_card_count,
             hand.max_overlap_percent,
             hand.is_expanded,
-            window_width,
+            hand_width,
         );
 
         // Arrange cards in an arc pattern
@@ -124,7 +148,7 @@ pub fn arrange_cards_in_hand(
         let start_x = -total_width / 2.0;
 
         for (i, child_ref) in children.iter().enumerate() {
-            if let Ok(mut card_transform) = card_query.get_mut(child_ref) {
+            if let Ok((mut card_transform, animation)) = card_query.get_mut(child_ref) {
                 let relative_pos = i as f32 / (card_count as f32 - 1.0).max(1.0);
                 let angle = std::f32::consts::PI * (0.4 - (0.8 * relative_pos));
 
@@ -135,12 +159,21 @@ pub fn arrange_cards_in_hand(
                 // Apply the calculated position and rotation
                 // Significantly increase z-index differences between cards to prevent z-fighting
                 let z = 10.0 + (i as f32 * 1.0); // Increased from 0.1 to 1.0 for clearer z separation
-                card_transform.translation = Vec3::new(x, y, z);
-                card_transform.rotation = Quat::from_rotation_z(rotation);
-                card_transform.scale = Vec3::splat(scale);
+                let arranged = Transform {
+                    translation: Vec3::new(x, y, z),
+                    rotation: Quat::from_rotation_z(rotation),
+                    scale: Vec3::splat(scale),
+                };
+                if place_card(&mut card_transform, animation, arranged, hand_transform) {
+                    updated += 1;
+                }
             }
         }
     }
+
+    if let Some(mut counts) = counts.filter(|_| updated > 0) {
+        counts.transforms += updated;
+    }
 }
 
 /// Calculate layout parameters for hand based on card count
@@ -149,12 +182,12 @@ fn calculate_hand_layout(
     optimal_count: u32,
     max_overlap: f32,
     is_expanded: bool,
-    window_width: f32,
+    hand_width: f32,
 ) -> (f32, f32, f32) {
     // Card dimensions with improved size multiplier
     let multiplier = crate::text::get_battlefield_card_size_multiplier();
     let card_width = 63.0 * multiplier;
-    let available_width = window_width * 0.8; // Use 80% of window width
+    let available_width = hand_width * 0.8; // Use 80% of the hand's width
 
     // Calculate spacing and scale
     let scale = if card_count <= optimal_count {
@@ -15,12 +15,15 @@ mod resources;
 mod systems;
 mod zones;
 
+#[cfg(test)]
+mod tests;
+
 // Re-export necessary items publicly
-pub use components::PlaymatZone;
+pub use components::{PlayerPlaymat, PlaymatZone};
 // Remove the specific re-export for the plugin as it's now accessible via the public module path
 // pub use plugin::PlayerPlaymatPlugin;
 // Only export resources/systems actually needed outside this parent module
-// pub use resources::{CurrentPhaseLayout, ZoneFocusState};
-pub use systems::spawn_player_playmat; // Assuming this is called from outside
+pub use resources::ZoneFocusState;
+pub use systems::{PLAYMAT_SIZE, playmat_placement, spawn_player_playmat};
 
 // No other code should be in this file.
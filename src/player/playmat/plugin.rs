@@ -2,13 +2,15 @@
 
 use bevy::prelude::*;
 
+use crate::cards::animation::CardAnimationSet;
+
 // Import resources and systems from the parent module's submodules
 use super::{
     battlefield, hand,
     resources::{CurrentPhaseLayout, PlaymatDebugState, ZoneFocusState},
     systems::{
-        adapt_zone_sizes, handle_zone_interactions, highlight_active_zones,
-        update_phase_based_layout,
+        adapt_zone_sizes, draw_high_contrast_zone_outlines, handle_zone_interactions,
+        highlight_active_zones, update_phase_based_layout,
     },
 };
 
@@ -48,9 +50,11 @@ impl Plugin for PlayerPlaymatPlugin {
                     highlight_active_zones,
                     adapt_zone_sizes,
                     update_phase_based_layout,
-                    // Systems from submodules need explicit path
-                    hand::arrange_cards_in_hand,
-                    battlefield::organize_battlefield_cards,
+                    // Systems from submodules need explicit path; cards are laid out
+                    // after their animations so a landing card goes to its slot
+                    hand::arrange_cards_in_hand.after(CardAnimationSet),
+                    battlefield::organize_battlefield_cards.after(CardAnimationSet),
+                    draw_high_contrast_zone_outlines,
                 )
                     .in_set(PlaymatSystemSet::Core)
                     .after(handle_zone_interactions),
@@ -1,7 +1,9 @@
 //! Systems related to player playmat interactions and layout.
 
 use crate::camera::components::{AppLayer, GameCamera};
+use crate::cards::animation::CardAnimation;
 use crate::game_engine::zones::Zone;
+use crate::menu::settings::components::AccessibilitySettings;
 use crate::player::components::Player;
 use crate::player::playmat::components::{PlayerPlaymat, PlaymatZone};
 use crate::player::playmat::resources::{
@@ -15,6 +17,29 @@ use bevy::prelude::*;
 
 use super::zones; // Import the zones module from the parent
 
+/// Put a zone's card in its arranged spot, returning whether its transform moved
+///
+/// A card still in flight keeps animating, but toward the arranged spot; only its
+/// scale is written until it lands.
+pub(super) fn place_card(
+    transform: &mut Mut<Transform>,
+    animation: Option<Mut<CardAnimation>>,
+    arranged: Transform,
+    zone: &GlobalTransform,
+) -> bool {
+    match animation {
+        Some(mut animation) => {
+            let landing = zone.transform_point(arranged.translation).truncate();
+            animation.retarget(landing, arranged.rotation);
+            transform.set_if_neq(Transform {
+                scale: arranged.scale,
+                ..**transform
+            })
+        }
+        None => transform.set_if_neq(arranged),
+    }
+}
+
 /// System to highlight active zones based on the current game phase
 pub fn highlight_active_zones(
     player_query: Query<(Entity, &Player)>,
@@ -204,6 +229,60 @@ pub fn adapt_zone_sizes(
     }
 }
 
+/// Color of zone outlines in high-contrast mode
+const ZONE_OUTLINE_COLOR: Color = Color::WHITE;
+/// Color of the focused zone's outline in high-contrast mode
+const FOCUSED_ZONE_OUTLINE_COLOR: Color = Color::srgb(1.0, 0.84, 0.0);
+/// Number of nested strokes making up a high-contrast outline
+const ZONE_OUTLINE_STROKES: usize = 3;
+/// Gap between the strokes of a high-contrast outline, in world units
+const ZONE_OUTLINE_STROKE_GAP: f32 = 1.5;
+
+/// Nominal size of a zone's area on the playmat, used for its outline
+pub fn zone_outline_size(zone_type: Zone) -> Vec2 {
+    match zone_type {
+        Zone::Battlefield => Vec2::new(240.0, 160.0),
+        Zone::Hand => Vec2::new(400.0, 90.0),
+        Zone::Library | Zone::Graveyard | Zone::Exile | Zone::Command | Zone::Stack => {
+            Vec2::new(80.0, 110.0)
+        }
+    }
+}
+
+/// Draw bold outlines around every playmat zone in high-contrast mode
+///
+/// The focused zone is outlined in yellow so it stands out from the rest.
+pub fn draw_high_contrast_zone_outlines(
+    mut gizmos: Gizmos,
+    settings: Option<Res<AccessibilitySettings>>,
+    zone_focus: Res<ZoneFocusState>,
+    zones: Query<(Entity, &PlaymatZone, &GlobalTransform)>,
+) {
+    if !settings.is_some_and(|settings| settings.high_contrast) {
+        return;
+    }
+
+    for (entity, zone, transform) in zones.iter() {
+        let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+        let isometry = Isometry2d::new(
+            translation.truncate(),
+            Rot2::radians(rotation.to_euler(EulerRot::ZYX).0),
+        );
+        let size = zone_outline_size(zone.zone_type) * scale.truncate();
+        let color = if zone_focus.focused_zone == Some(entity) {
+            FOCUSED_ZONE_OUTLINE_COLOR
+        } else {
+            ZONE_OUTLINE_COLOR
+        };
+
+        // Gizmo lines are one pixel wide, so nested strokes make the outline bold
+        for stroke in 0..ZONE_OUTLINE_STROKES {
+            let inset = Vec2::splat(stroke as f32 * ZONE_OUTLINE_STROKE_GAP * 2.0);
+            gizmos.rect_2d(isometry, size - inset, color);
+        }
+    }
+}
+
 /// Update layout based on the current game phase
 pub fn update_phase_based_layout(
     mut phase_layout: ResMut<CurrentPhaseLayout>,
@@ -272,35 +351,40 @@ pub fn update_phase_based_layout(
 }
 
 /// Spawns the visual representation of a player's playmat zones.
-pub fn spawn_player_playmat(
-    commands: &mut Commands,
-    asset_server: &Res<AssetServer>,
-    player_entity: Entity,
-    player: &Player,
-    config: &PlayerConfig,
-    mut player_position: Vec3,
-) -> Entity {
-    // Define the base layout for player 0 (bottom)
-    let playmat_size = Vec2::new(1800.0, 1200.0); // Tentative fixed size
-    let base_rotation = Quat::IDENTITY; // Player 0 has no rotation
-
-    // Calculate rotation and position adjustments based on player index
-    let (rotation, position_offset) = match player.player_index {
-        0 => (base_rotation, Vec3::new(0.0, -playmat_size.y / 2.0, 1.0)), // Bottom, Z=1.0
+/// Size of a playmat before the table is scaled to fit the window
+pub const PLAYMAT_SIZE: Vec2 = Vec2::new(1800.0, 1200.0);
+
+/// Rotation of a player's playmat and its offset from the player's position
+///
+/// Player 0 sits at the bottom; the others go around the table counter-clockwise.
+pub fn playmat_placement(player_index: usize) -> (Quat, Vec3) {
+    match player_index {
+        0 => (Quat::IDENTITY, Vec3::new(0.0, -PLAYMAT_SIZE.y / 2.0, 1.0)), // Bottom, Z=1.0
         1 => (
             Quat::from_rotation_z(-std::f32::consts::FRAC_PI_2),
-            Vec3::new(playmat_size.y / 2.0, 0.0, 1.0),
+            Vec3::new(PLAYMAT_SIZE.y / 2.0, 0.0, 1.0),
         ), // Right, Z=1.0
         2 => (
             Quat::from_rotation_z(std::f32::consts::PI),
-            Vec3::new(0.0, playmat_size.y / 2.0, 1.0),
+            Vec3::new(0.0, PLAYMAT_SIZE.y / 2.0, 1.0),
         ), // Top, Z=1.0
         3 => (
             Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
-            Vec3::new(-playmat_size.y / 2.0, 0.0, 1.0),
+            Vec3::new(-PLAYMAT_SIZE.y / 2.0, 0.0, 1.0),
         ), // Left, Z=1.0
         _ => unreachable!("Invalid player index"),
-    };
+    }
+}
+
+pub fn spawn_player_playmat(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    player_entity: Entity,
+    player: &Player,
+    config: &PlayerConfig,
+    mut player_position: Vec3,
+) -> Entity {
+    let (rotation, position_offset) = playmat_placement(player.player_index);
 
     // Adjust the main player position based on index
     player_position += position_offset;
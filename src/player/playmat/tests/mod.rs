@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+
+use crate::cards::animation::{CardAnimation, CardAnimationKind};
+use crate::player::playmat::battlefield::{BattlefieldZone, organize_battlefield_cards};
+use crate::player::playmat::hand::{HandZone, arrange_cards_in_hand};
+use crate::tracing::CardUpdateCounts;
+
+/// An app with a zone holding `cards` cards, counting the transforms written
+fn layout_app<Z: Component + Default>(cards: usize) -> (App, Entity) {
+    let mut app = App::new();
+    app.init_resource::<CardUpdateCounts>();
+    let zone = app
+        .world_mut()
+        .spawn((Z::default(), Transform::default()))
+        .id();
+    for _ in 0..cards {
+        let card = app.world_mut().spawn(Transform::default()).id();
+        app.world_mut().entity_mut(zone).add_child(card);
+    }
+    (app, zone)
+}
+
+/// A draw animation that has reached its end
+fn finished_draw() -> CardAnimation {
+    CardAnimation {
+        kind: CardAnimationKind::Draw,
+        from: Vec3::ZERO,
+        to: Vec3::ZERO,
+        arc_height: 0.0,
+        spin: 0.0,
+        base_rotation: Quat::IDENTITY,
+        elapsed: 1.0,
+        duration: 1.0,
+    }
+}
+
+/// Transforms written by the next frame
+fn transforms_written(app: &mut App) -> u32 {
+    app.update();
+    std::mem::take(
+        &mut app
+            .world_mut()
+            .resource_mut::<CardUpdateCounts>()
+            .transforms,
+    )
+}
+
+#[test]
+fn test_idle_hand_writes_no_transforms() {
+    let (mut app, hand) = layout_app::<HandZone>(5);
+    app.add_systems(Update, arrange_cards_in_hand);
+
+    assert_eq!(transforms_written(&mut app), 5);
+    assert_eq!(transforms_written(&mut app), 0);
+    assert_eq!(transforms_written(&mut app), 0);
+
+    app.world_mut()
+        .get_mut::<HandZone>(hand)
+        .unwrap()
+        .is_expanded = true;
+    assert_eq!(transforms_written(&mut app), 5);
+    assert_eq!(transforms_written(&mut app), 0);
+}
+
+#[test]
+fn test_idle_battlefield_writes_no_transforms() {
+    let (mut app, battlefield) = layout_app::<BattlefieldZone>(4);
+    app.add_systems(Update, organize_battlefield_cards);
+
+    assert!(transforms_written(&mut app) > 0);
+    assert_eq!(transforms_written(&mut app), 0);
+
+    let card = app.world_mut().spawn(Transform::default()).id();
+    app.world_mut().entity_mut(battlefield).add_child(card);
+    assert!(transforms_written(&mut app) > 0);
+    assert_eq!(transforms_written(&mut app), 0);
+}
+
+#[test]
+fn test_hand_is_laid_out_again_when_an_animation_ends() {
+    let (mut app, hand) = layout_app::<HandZone>(3);
+    app.add_systems(Update, arrange_cards_in_hand);
+    transforms_written(&mut app);
+
+    // The animation leaves the card wherever it ended
+    let card = app.world().get::<Children>(hand).unwrap()[1];
+    app.world_mut()
+        .entity_mut(card)
+        .insert(finished_draw())
+        .get_mut::<Transform>()
+        .unwrap()
+        .translation = Vec3::new(0.0, 400.0, 0.0);
+    assert_eq!(transforms_written(&mut app), 0);
+
+    app.world_mut().entity_mut(card).remove::<CardAnimation>();
+    assert_eq!(transforms_written(&mut app), 1);
+    assert_eq!(transforms_written(&mut app), 0);
+}
@@ -0,0 +1,88 @@
+//! Keeps the whole table in view as the window and UI scale change
+//!
+//! The table is laid out for a 1280x720 window. When the window is resized or the
+//! UI scale changes, [`fit_table_to_window`] refits the [`TableLayout`] resource
+//! and [`apply_table_layout`] moves the players, their playmats and the loose
+//! cards on the table to match. Cards inside a playmat's zones follow the playmat.
+
+use bevy::ecs::hierarchy::ChildOf;
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowResized};
+
+use crate::cards::Card;
+use crate::player::components::Player;
+use crate::player::playmat::PlayerPlaymat;
+use crate::player::systems::spawn::table::{TableLayout, visible_table_area};
+
+/// Refit the table when the window is resized or the UI scale changes
+pub fn fit_table_to_window(
+    mut resize_events: EventReader<WindowResized>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    mut table: ResMut<TableLayout>,
+) {
+    let resized = resize_events.read().count() > 0;
+    if !resized && !ui_scale.is_changed() && !table.is_added() {
+        return;
+    }
+    let Ok(window) = windows.single() else {
+        return;
+    };
+
+    let fitted = table
+        .clone()
+        .fit_to_view(visible_table_area(window.size(), ui_scale.0));
+    if fitted.scale != table.scale || fitted.view_size != table.view_size {
+        info!(
+            "Fitted table to {}x{} window at scale {:.2}",
+            window.width(),
+            window.height(),
+            fitted.scale
+        );
+        *table = fitted;
+    }
+}
+
+/// Move players, playmats and loose cards to the current table layout
+///
+/// Players and playmats are placed from the layout directly; cards on the table
+/// are scaled about its center by how much the table's scale changed, so cards
+/// the player moved stay where they were relative to the table.
+pub fn apply_table_layout(
+    table: Res<TableLayout>,
+    mut applied_scale: Local<Option<f32>>,
+    mut players: Query<(&Player, &mut Transform), (Without<PlayerPlaymat>, Without<Card>)>,
+    mut playmats: Query<(&PlayerPlaymat, &mut Transform), (Without<Player>, Without<Card>)>,
+    mut cards: Query<&mut Transform, (With<Card>, Without<ChildOf>, Without<Player>)>,
+) {
+    if !table.is_changed() {
+        return;
+    }
+    // A new table is laid out at scale 1
+    let previous = if table.is_added() {
+        1.0
+    } else {
+        applied_scale.unwrap_or(1.0)
+    };
+    *applied_scale = Some(table.scale);
+
+    for (player, mut transform) in players.iter_mut() {
+        transform.set_if_neq(table.get_player_position(player.player_index));
+    }
+    for (playmat, mut transform) in playmats.iter_mut() {
+        if playmat.player_index < 4 {
+            transform.set_if_neq(table.get_playmat_transform(playmat.player_index));
+        }
+    }
+
+    let ratio = table.scale / previous;
+    if ratio == 1.0 {
+        return;
+    }
+    for mut transform in cards.iter_mut() {
+        transform.translation.x *= ratio;
+        transform.translation.y *= ratio;
+        transform.scale.x *= ratio;
+        transform.scale.y *= ratio;
+    }
+}
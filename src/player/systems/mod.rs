@@ -6,7 +6,13 @@ pub mod spawn;
 // Make debug module public for external use (e.g., CameraPlugin)
 pub mod debug;
 
+// Refits the table to the window
+pub mod layout;
+
 // Other player systems can remain private for now
 // mod interactions; // Example: handle player clicks, etc.
 // mod movement; // Example: if players could move independently
 // ... add other player system modules here
+
+#[cfg(test)]
+mod tests;
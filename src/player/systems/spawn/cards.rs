@@ -1,15 +1,21 @@
 use super::table::TableLayout;
 use crate::camera::components::AppLayer;
+use crate::camera::z_order::ZBand;
 use crate::cards::components::card_entity::CardZone;
 use crate::cards::drag::Draggable;
+use crate::cards::pool::{CardVisualKey, CardVisualPool};
 use crate::cards::text::card_text::spawn_card_text_components;
 use crate::game_engine::zones::types::Zone;
 
 use bevy::prelude::*;
 
 /// Helper function to spawn visual card entities
+///
+/// Card visuals are taken from the pool where possible; recycled ones already have
+/// their text spawned.
 pub fn spawn_visual_cards(
     commands: &mut Commands,
+    pool: &mut CardVisualPool,
     card_size: &Vec2,
     spacing_multiplier: f32,
     player_index: usize,
@@ -84,38 +90,44 @@ pub fn spawn_visual_cards(
     for (i, card) in display_cards.into_iter().enumerate() {
         let card_clone = card.clone(); // Clone card to use later
 
-        // Calculate z-index based on position to ensure proper layering
-        // Use a smaller base z-value to ensure cards are closer to the camera
-        let z = 1.0 + (i as f32 * 0.1); // Drastically reduced z-index base
+        // Later cards in the hand overlap earlier ones
+        let z = ZBand::Hand.z(i);
 
-        // Calculate the position for this card
+        // Calculate the position for this card, shrunk with the rest of the table
         let position = Vec3::new(
-            start_pos.x + card_direction.x * i as f32,
-            start_pos.y + card_direction.y * i as f32,
+            (start_pos.x + card_direction.x * i as f32) * table.scale,
+            (start_pos.y + card_direction.y * i as f32) * table.scale,
             z,
         );
 
         // Draw cards at a much larger internal size for better text layout
         // but scale them down visually to fit in the playmat
         let internal_card_size = *card_size * 6.0; // Much larger internal size for text positioning
-        let display_scale = 2.5 / 6.0; // Scale factor to display correctly in the playmat
+        let display_scale = 2.5 / 6.0 * table.scale; // Scale factor to display correctly in the playmat
 
         // Create a card with a grayish white background for better readability
+        let visual = pool.spawn(
+            commands,
+            CardVisualKey::of(&card),
+            (
+                Sprite {
+                    color: Color::srgb(0.92, 0.92, 0.94), // Grayish white for better readability
+                    custom_size: Some(internal_card_size),
+                    ..default()
+                },
+                Transform {
+                    translation: position,
+                    scale: Vec3::splat(display_scale), // Scale down for display
+                    ..default()
+                },
+                GlobalTransform::default(),
+                Visibility::Visible,
+                InheritedVisibility::default(),
+                ViewVisibility::default(),
+            ),
+        );
         let card_entity = commands
-            .spawn(Sprite {
-                color: Color::srgb(0.92, 0.92, 0.94), // Grayish white for better readability
-                custom_size: Some(internal_card_size),
-                ..default()
-            })
-            .insert(Transform {
-                translation: position,
-                scale: Vec3::splat(display_scale), // Scale down for display
-                ..default()
-            })
-            .insert(GlobalTransform::default())
-            .insert(Visibility::Visible)
-            .insert(InheritedVisibility::default())
-            .insert(ViewVisibility::default())
+            .entity(visual.entity)
             .insert(card)
             .insert(Draggable {
                 dragging: false,
@@ -137,7 +149,7 @@ pub fn spawn_visual_cards(
         );
 
         // Spawn text components directly instead of just adding marker components
-        if let Some(game_asset_server) = asset_server_option {
+        if let Some(game_asset_server) = asset_server_option.filter(|_| !visual.recycled) {
             // Convert card::components::CardRulesText to text::components::CardRulesText
             let rules_text = crate::text::components::CardRulesText {
                 rules_text: card_clone.rules_text.rules_text.clone(),
@@ -6,6 +6,7 @@ pub mod position;
 pub mod table;
 
 use crate::camera::components::AppLayer;
+use crate::cards::pool::CardVisualPool;
 use crate::deck::{PlayerDeck, get_player_shuffled_deck};
 use crate::player::components::Player;
 use crate::player::playmat::spawn_player_playmat; // Import the new playmat function
@@ -24,6 +25,7 @@ use bevy::prelude::*;
 /// 5. Creates independent deck components for each player
 pub fn spawn_players<'w, 's>(
     commands: &mut Commands<'w, 's>,
+    pool: &mut CardVisualPool,
     asset_server: Res<AssetServer>,
     player_config: Option<Res<PlayerConfig>>,
 ) {
@@ -129,6 +131,7 @@ pub fn spawn_players<'w, 's>(
             // Remove context creation, call spawn_visual_cards directly
             cards::spawn_visual_cards(
                 commands, // Pass mutable commands directly
+                pool,
                 &config.card_size,
                 config.card_spacing_multiplier,
                 player_index,
@@ -1,8 +1,35 @@
 use bevy::prelude::*;
 use std::f32::consts::PI;
 
+use crate::camera::config::{INITIAL_ZOOM, VERTICAL_VIEW};
+use crate::player::playmat::{PLAYMAT_SIZE, playmat_placement};
+
+/// Window size the table's distances and sizes were designed for
+pub const REFERENCE_WINDOW_SIZE: Vec2 = Vec2::new(1280.0, 720.0);
+/// Pixels kept clear for the HUD along each window edge, at a UI scale of 1
+pub const HUD_MARGIN: f32 = 48.0;
+/// Smallest scale the table is shrunk to, however small the window
+pub const MIN_TABLE_SCALE: f32 = 0.25;
+
+/// World units of the table visible in a window, leaving room for the HUD
+///
+/// The game camera keeps [`VERTICAL_VIEW`] world units visible vertically, so
+/// only the window's aspect ratio and the space taken by UI change what fits.
+pub fn visible_table_area(window_size: Vec2, ui_scale: f32) -> Vec2 {
+    if window_size.y <= 0.0 {
+        return Vec2::ZERO;
+    }
+    let world_per_pixel = VERTICAL_VIEW * INITIAL_ZOOM / window_size.y;
+    let usable = (window_size - Vec2::splat(2.0 * HUD_MARGIN * ui_scale)).max(Vec2::ZERO);
+    usable * world_per_pixel
+}
+
 /// Calculates positions for players and cards around a table
-#[derive(Debug, Clone)]
+///
+/// Kept as a resource once the game is set up, and refitted by
+/// [`fit_table_to_window`](crate::player::systems::layout::fit_table_to_window)
+/// whenever the window or UI scale changes.
+#[derive(Resource, Debug, Clone)]
 pub struct TableLayout {
     /// Number of players at the table
     pub player_count: usize,
@@ -13,6 +40,10 @@ pub struct TableLayout {
     pub card_distance: f32,
     /// Standard dimensions for a playmat
     pub playmat_size: Vec2,
+    /// Scale applied to the whole table so it fits the view, never above 1
+    pub scale: f32,
+    /// World-space area the table was fitted into
+    pub view_size: Vec2,
 }
 
 impl TableLayout {
@@ -23,7 +54,56 @@ impl TableLayout {
             distance,
             card_distance: distance * 1.5, // Default card distance is 150% of player distance
             playmat_size: Vec2::new(430.0, 330.0), // Increased playmat size for larger cards
+            scale: 1.0,
+            view_size: visible_table_area(REFERENCE_WINDOW_SIZE, 1.0),
+        }
+    }
+
+    /// Scale the table down until every playmat fits in `view_size` world units
+    pub fn fit_to_view(mut self, view_size: Vec2) -> Self {
+        self.view_size = view_size;
+        self.scale = 1.0;
+        let extent = self.extent();
+        if extent.x > 0.0 && extent.y > 0.0 {
+            self.scale = (view_size.x / extent.x)
+                .min(view_size.y / extent.y)
+                .clamp(MIN_TABLE_SCALE, 1.0);
         }
+        self
+    }
+
+    /// Size of the area around the table's center covered by the playmats
+    pub fn extent(&self) -> Vec2 {
+        let half = (0..self.player_count.min(4))
+            .map(|player_index| {
+                let transform = self.get_playmat_transform(player_index);
+                let half_size = PLAYMAT_SIZE * self.scale / 2.0;
+                // Bounds of the rotated playmat
+                let axis = (transform.rotation * Vec3::X).truncate();
+                let (cos, sin) = (axis.x, axis.y);
+                let bounds = Vec2::new(
+                    cos.abs() * half_size.x + sin.abs() * half_size.y,
+                    sin.abs() * half_size.x + cos.abs() * half_size.y,
+                );
+                transform.translation.truncate().abs() + bounds
+            })
+            .fold(Vec2::ZERO, Vec2::max);
+        half * 2.0
+    }
+
+    /// Transform of a player's playmat, at the table's scale
+    pub fn get_playmat_transform(&self, player_index: usize) -> Transform {
+        let (rotation, offset) = playmat_placement(player_index);
+        let position = self.get_player_position(player_index).translation
+            + (offset.truncate() * self.scale).extend(offset.z);
+        Transform::from_translation(position)
+            .with_rotation(rotation)
+            .with_scale(Vec3::new(self.scale, self.scale, 1.0))
+    }
+
+    /// Width available to a hand, in the playmat's own units
+    pub fn hand_width(&self) -> f32 {
+        (self.view_size.min_element() / self.scale).min(PLAYMAT_SIZE.x)
     }
 
     /// Sets the card distance from center
@@ -86,8 +166,8 @@ impl TableLayout {
 
         // Calculate position based on angle and distance
         let position = Vec3::new(
-            polygon_distance * angle.sin(),
-            polygon_distance * angle.cos(),
+            polygon_distance * angle.sin() * self.scale,
+            polygon_distance * angle.cos() * self.scale,
             0.0,
         );
 
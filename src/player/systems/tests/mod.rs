@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+
+use crate::cards::{Card, CardTypes};
+use crate::mana::Mana;
+use crate::player::Player;
+use crate::player::playmat::PlayerPlaymat;
+use crate::player::systems::layout::apply_table_layout;
+use crate::player::systems::spawn::table::{
+    MIN_TABLE_SCALE, REFERENCE_WINDOW_SIZE, TableLayout, visible_table_area,
+};
+
+fn four_player_table() -> TableLayout {
+    TableLayout::new(4, 400.0)
+}
+
+#[test]
+fn test_table_fits_reference_window_unscaled() {
+    let table = four_player_table().fit_to_view(visible_table_area(REFERENCE_WINDOW_SIZE, 1.0));
+    assert_eq!(table.scale, 1.0);
+}
+
+#[test]
+fn test_narrow_windows_and_large_ui_shrink_the_table() {
+    let narrow = visible_table_area(Vec2::new(400.0, 1600.0), 1.0);
+    let table = four_player_table().fit_to_view(narrow);
+    assert!(table.scale < 1.0);
+    let extent = table.extent();
+    assert!(extent.x <= narrow.x + 0.01 && extent.y <= narrow.y + 0.01);
+
+    let window = Vec2::new(640.0, 360.0);
+    let normal_ui = four_player_table().fit_to_view(visible_table_area(window, 1.0));
+    let large_ui = four_player_table().fit_to_view(visible_table_area(window, 2.0));
+    assert!(large_ui.scale < normal_ui.scale);
+
+    let tiny = four_player_table().fit_to_view(Vec2::ZERO);
+    assert_eq!(tiny.scale, MIN_TABLE_SCALE);
+}
+
+#[test]
+fn test_refitting_moves_players_playmats_and_cards() {
+    let mut app = App::new();
+    app.add_systems(Update, apply_table_layout);
+
+    let player = app
+        .world_mut()
+        .spawn((Player::new("Alice"), Transform::default()))
+        .id();
+    let playmat = app
+        .world_mut()
+        .spawn((
+            PlayerPlaymat {
+                player_id: player,
+                player_index: 0,
+            },
+            Transform::default(),
+        ))
+        .id();
+    let card = Card::builder("Island")
+        .cost(Mana::default())
+        .types(CardTypes::LAND)
+        .build_or_panic();
+    let card = app
+        .world_mut()
+        .spawn((card, Transform::from_xyz(100.0, -200.0, 20.0)))
+        .id();
+
+    let table = four_player_table();
+    app.insert_resource(table.clone());
+    app.update();
+    assert_eq!(
+        app.world().get::<Transform>(playmat),
+        Some(&table.get_playmat_transform(0))
+    );
+    assert_eq!(
+        app.world().get::<Transform>(card).unwrap().translation,
+        Vec3::new(100.0, -200.0, 20.0)
+    );
+
+    app.world_mut().resource_mut::<TableLayout>().scale = 0.5;
+    let half = app.world().resource::<TableLayout>().clone();
+    app.update();
+    assert_eq!(
+        app.world().get::<Transform>(player),
+        Some(&half.get_player_position(0))
+    );
+    let card_transform = app.world().get::<Transform>(card).unwrap();
+    assert_eq!(card_transform.translation, Vec3::new(50.0, -100.0, 20.0));
+    assert_eq!(card_transform.scale, Vec3::new(0.5, 0.5, 1.0));
+}
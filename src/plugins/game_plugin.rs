@@ -4,7 +4,11 @@ use crate::camera::{
     config::CameraConfig,
     systems::{camera_movement, handle_window_resize, set_initial_zoom},
 };
-use crate::deck::{PlayerDeck, get_player_shuffled_deck};
+use crate::cards::CardDatabase;
+use crate::cards::mtgjson::index::CardIndex;
+use crate::cards::pool::CardVisualPool;
+use crate::deck::{PlayerDeck, player_starting_deck};
+use crate::game_engine::setup::GameSetupConfig;
 use crate::player::components::Player;
 use crate::player::playmat::spawn_player_playmat;
 use crate::player::systems::spawn::cards;
@@ -19,7 +23,7 @@ use bevy::render::view::RenderLayers;
 use std::collections::HashSet;
 
 // Add AppState import
-use crate::menu::state::AppState;
+use crate::menu::state::{AppState, starting_new_game};
 
 /// Marker component to trigger visual hand spawning for a player
 #[derive(Component)]
@@ -78,7 +82,8 @@ impl Plugin for RummagePlugin {
                     )
                         .chain(), // Chain snapshot logic
                 )
-                    .chain(), // Chain the core setup sequence
+                    .chain() // Chain the core setup sequence
+                    .run_if(starting_new_game), // Resuming continues the paused game
             )
             .add_systems(
                 Update,
@@ -202,8 +207,18 @@ fn spawn_game_camera(
 fn setup_game(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    player_config: Res<PlayerConfig>,
+    mut player_config: ResMut<PlayerConfig>,
+    setup_config: Option<Res<GameSetupConfig>>,
+    database: Option<Res<CardDatabase>>,
+    card_index: Option<Res<CardIndex>>,
 ) {
+    // Apply the options chosen on the new game screen before spawning anything
+    if let Some(setup_config) = setup_config.as_deref() {
+        setup_config.apply_to_player_config(&mut player_config);
+    }
+    // Precons are looked up in the built-in sets first, then the card index
+    let lookup = (database.as_deref(), card_index.as_deref());
+
     info!(
         "Setting up game state (players, playmats)... N={}",
         player_config.player_count
@@ -239,11 +254,11 @@ fn setup_game(
             player_transform.translation,
         );
 
-        let deck = get_player_shuffled_deck(
-            player_entity,
-            player_index,
-            Some(&format!("Player {} Deck", player_index + 1)),
-        );
+        let precon = setup_config
+            .as_deref()
+            .and_then(|setup_config| setup_config.precon_for(player_index));
+        let mut deck = player_starting_deck(player_index, precon, &lookup);
+        deck.shuffle();
         commands
             .entity(player_entity)
             .insert(PlayerDeck::new(deck.clone()));
@@ -257,12 +272,15 @@ fn setup_game(
             });
         }
     }
+    // Refitted to the window from here on
+    commands.insert_resource(table);
     info!("Player setup complete, markers added for visual hands.");
 }
 
 // System to handle spawning visual hands based on the marker
 fn spawn_player_visual_hands(
     mut commands: Commands,
+    mut pool: ResMut<CardVisualPool>,
     asset_server: Res<AssetServer>,
     game_cameras: Query<Entity, With<GameCamera>>,
     player_query: Query<&Player>,
@@ -305,6 +323,7 @@ fn spawn_player_visual_hands(
 
             cards::spawn_visual_cards(
                 &mut commands,
+                &mut pool,
                 &config.card_size,
                 config.card_spacing_multiplier,
                 player_index,
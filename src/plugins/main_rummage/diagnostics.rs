@@ -2,58 +2,23 @@ use bevy::prelude::*;
 
 use crate::cards::Card;
 use crate::game_engine::zones::{Zone, ZoneManager};
-use crate::menu::GameMenuState;
 use crate::player::components::Player;
 
+/// Frames to wait after entering the game before checking on the cards, so
+/// they've been spawned and put in their zones
+pub(super) const CARD_STATUS_DELAY_FRAMES: u32 = 30;
+
+/// Log where the cards, players and game camera ended up once a game has started
+///
+/// Runs once per game, [`CARD_STATUS_DELAY_FRAMES`] frames after entering it.
 pub(super) fn check_card_status(
     cards: Query<(Entity, &Transform, &Visibility), With<Card>>,
     player_query: Query<(Entity, &Player)>,
     game_camera_query: Query<Entity, With<crate::camera::components::GameCamera>>,
     zone_manager: Res<ZoneManager>,
-    mut has_run: Local<bool>,
-    game_state: Res<State<GameMenuState>>,
 ) {
-    // Only run when the game state is InGame
-    if *game_state.get() != GameMenuState::InGame {
-        return;
-    }
-
-    // Only run once
-    if *has_run {
-        return;
-    }
-
-    // Wait a few frames before checking
-    static mut FRAME_COUNT: u32 = 0;
-    unsafe {
-        FRAME_COUNT += 1;
-        if FRAME_COUNT < 30 {
-            return;
-        }
-    }
-
-    *has_run = true;
-
     // Count cards in all zones
-    let mut zone_manager_card_count = 0;
-
-    // Count cards in hands
-    for player_hand in zone_manager.hands.values() {
-        zone_manager_card_count += player_hand.len();
-    }
-
-    // Count cards in libraries
-    for player_library in zone_manager.libraries.values() {
-        zone_manager_card_count += player_library.len();
-    }
-
-    // Count cards in battlefield
-    zone_manager_card_count += zone_manager.battlefield.len();
-
-    // Count cards in graveyards
-    for player_graveyard in zone_manager.graveyards.values() {
-        zone_manager_card_count += player_graveyard.len();
-    }
+    let zone_manager_card_count = zone_manager.card_count();
 
     info!("Zone Manager contains {} cards", zone_manager_card_count);
 
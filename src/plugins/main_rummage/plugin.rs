@@ -4,12 +4,12 @@ use crate::cards::CardPlugin;
 use crate::cards::drag::DragPlugin;
 use crate::game_engine::save::SaveLoadPlugin;
 use crate::game_engine::zones::ZoneManager;
-use crate::menu::GameMenuState;
+use crate::menu::{GameMenuState, frames_after_entering_game};
 use crate::player::{PlayerPlugin, resources::PlayerConfig};
 use crate::text::DebugConfig;
 
 use super::camera::{GameCameraSetupSet, ensure_game_camera_visible, setup_game_camera};
-use super::diagnostics::check_card_status;
+use super::diagnostics::{CARD_STATUS_DELAY_FRAMES, check_card_status};
 use super::setup::setup_game;
 use super::visual_hand::spawn_player_visual_hands;
 use super::zones::{connect_cards_to_zones, register_unzoned_cards};
@@ -72,7 +72,7 @@ impl Plugin for MainRummagePlugin {
                 (
                     spawn_player_visual_hands,
                     connect_cards_to_zones,
-                    check_card_status,
+                    check_card_status.run_if(frames_after_entering_game(CARD_STATUS_DELAY_FRAMES)),
                     register_unzoned_cards.run_if(in_state(GameMenuState::InGame)),
                 ),
             );
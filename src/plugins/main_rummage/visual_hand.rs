@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
 use crate::camera::components::GameCamera;
+use crate::cards::pool::CardVisualPool;
 use crate::deck::PlayerDeck;
 use crate::player::components::Player;
 use crate::player::resources::PlayerConfig;
@@ -17,6 +18,7 @@ pub(super) struct SpawnVisualHand {
 
 pub(super) fn spawn_player_visual_hands(
     mut commands: Commands,
+    mut pool: ResMut<CardVisualPool>,
     asset_server: Res<AssetServer>,
     game_cameras: Query<Entity, With<GameCamera>>,
     player_query: Query<&Player>,
@@ -62,6 +64,7 @@ pub(super) fn spawn_player_visual_hands(
             // Remove context creation, call spawn_visual_cards directly
             cards::spawn_visual_cards(
                 &mut commands,
+                &mut pool,
                 &config.card_size,
                 config.card_spacing_multiplier,
                 player_index,
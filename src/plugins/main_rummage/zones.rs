@@ -37,25 +37,7 @@ pub(super) fn connect_cards_to_zones(
             );
 
             // Let's check if the zone manager already has any cards registered
-            let mut total_cards = 0;
-
-            // Count cards in hands
-            for player_hand in zone_manager.hands.values() {
-                total_cards += player_hand.len();
-            }
-
-            // Count cards in libraries
-            for player_library in zone_manager.libraries.values() {
-                total_cards += player_library.len();
-            }
-
-            // Count cards in battlefield
-            total_cards += zone_manager.battlefield.len();
-
-            // Count cards in graveyards
-            for player_graveyard in zone_manager.graveyards.values() {
-                total_cards += player_graveyard.len();
-            }
+            let total_cards = zone_manager.card_count();
 
             if total_cards > 0 {
                 info!(
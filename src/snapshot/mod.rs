@@ -2,6 +2,7 @@ pub mod components;
 pub mod examples;
 pub mod plugin;
 pub mod resources;
+pub mod share;
 pub mod systems;
 
 // Include tests module when running tests but not in normal builds
@@ -12,3 +13,4 @@ pub mod tests;
 pub use components::{CameraSnapshot, SaveGameSnapshot, SnapshotSettings};
 pub use plugin::SnapshotPlugin;
 pub use resources::{SnapshotConfig, SnapshotDisabled, SnapshotEvent};
+pub use share::ShareBoardEvent;
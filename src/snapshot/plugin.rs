@@ -6,6 +6,10 @@ use crate::menu::state::AppState;
 use crate::snapshot::resources::{
     SnapshotConfig, SnapshotDebugState, SnapshotDisabled, SnapshotEvent,
 };
+use crate::snapshot::share::{
+    PendingBoardShare, ShareBoardEvent, capture_shared_board, check_share_board_key_input,
+    handle_share_board_events, update_share_toasts,
+};
 use crate::snapshot::systems::{
     capture_replay_at_point, check_snapshot_key_input, handle_snapshot_events,
     process_pending_snapshots, snapshot_enabled, take_replay_snapshot, take_save_game_snapshot,
@@ -67,7 +71,21 @@ impl BevyPlugin for SnapshotPlugin {
             } else {
                 SnapshotDisabled::disabled()
             })
-            .add_event::<SnapshotEvent>();
+            .add_event::<SnapshotEvent>()
+            .add_event::<ShareBoardEvent>();
+
+        // Sharing the board is a player-facing feature, so it doesn't depend on
+        // debug snapshots being enabled
+        app.add_systems(
+            Update,
+            (
+                check_share_board_key_input.run_if(in_state(AppState::InGame)),
+                handle_share_board_events,
+                capture_shared_board.run_if(resource_exists::<PendingBoardShare>),
+                update_share_toasts,
+            )
+                .chain(),
+        );
 
         #[cfg(feature = "snapshot")]
         {
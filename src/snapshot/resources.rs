@@ -21,6 +21,8 @@ pub struct SnapshotConfig {
     pub auto_snapshot_enabled: bool,
     /// Whether to capture debug visualization in snapshots
     pub include_debug_by_default: bool,
+    /// Directory shared board screenshots are saved to
+    pub screenshots_dir: String,
 }
 
 impl SnapshotConfig {
@@ -64,6 +66,12 @@ impl SnapshotConfig {
         self.include_debug_by_default = include;
         self
     }
+
+    /// Set the directory shared board screenshots are saved to
+    pub fn with_screenshots_dir(mut self, dir: impl Into<String>) -> Self {
+        self.screenshots_dir = dir.into();
+        self
+    }
 }
 
 impl Default for SnapshotConfig {
@@ -75,6 +83,7 @@ impl Default for SnapshotConfig {
             resolution: Vec2::new(1920.0, 1080.0),
             auto_snapshot_enabled: false,
             include_debug_by_default: true,
+            screenshots_dir: "screenshots".to_string(),
         }
     }
 }
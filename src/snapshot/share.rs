@@ -0,0 +1,267 @@
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured, save_to_disk};
+use chrono::Local;
+
+use crate::camera::components::AppLayer;
+use crate::camera::z_order::UiLayer;
+use crate::game_engine::turns::TurnManager;
+use crate::player::Player;
+use crate::snapshot::resources::SnapshotConfig;
+
+/// How long the toast with the saved path stays on screen, in seconds
+const TOAST_SECONDS: f32 = 4.0;
+
+/// Frames to wait between framing the board and capturing it, so menus have
+/// closed and the frame has been laid out
+const CAPTURE_DELAY_FRAMES: u8 = 2;
+
+/// Event to save a framed screenshot of the board for sharing
+#[derive(Event, Debug, Clone, Copy, Default)]
+pub struct ShareBoardEvent;
+
+/// Text overlaid on a shared screenshot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardCaption {
+    pub turn_number: u32,
+    /// Name and life total of each player, in seating order
+    pub players: Vec<(String, i32)>,
+}
+
+impl BoardCaption {
+    /// Caption for the players and turn of the current game
+    pub fn new<'a>(turn_number: u32, players: impl IntoIterator<Item = &'a Player>) -> Self {
+        let mut players: Vec<&Player> = players.into_iter().collect();
+        players.sort_by_key(|player| player.player_index);
+        Self {
+            turn_number,
+            players: players
+                .into_iter()
+                .map(|player| (player.name.clone(), player.life))
+                .collect(),
+        }
+    }
+
+    /// Title shown above the board
+    pub fn title(&self) -> String {
+        format!("Rummage - Turn {}", self.turn_number)
+    }
+
+    /// Labels shown below the board, one per player
+    pub fn player_labels(&self) -> Vec<String> {
+        self.players
+            .iter()
+            .map(|(name, life)| format!("{}: {} life", name, life))
+            .collect()
+    }
+
+    /// File name for the screenshot, unique to the second it was taken
+    pub fn filename(&self) -> String {
+        format!(
+            "board_turn_{}{}.png",
+            self.turn_number,
+            Local::now().format("_%Y%m%d_%H%M%S")
+        )
+    }
+}
+
+/// Marker for the frame and caption drawn over the board while it is captured
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ShareBoardFrame;
+
+/// Toast telling the player where a shared screenshot was saved
+#[derive(Component, Debug, Clone)]
+pub struct ShareBoardToast {
+    pub timer: Timer,
+}
+
+/// A framed board waiting to be captured
+#[derive(Resource, Debug, Clone)]
+pub struct PendingBoardShare {
+    pub path: PathBuf,
+    pub frames_left: u8,
+}
+
+/// Shares the board when F8 is pressed
+pub fn check_share_board_key_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut share_events: EventWriter<ShareBoardEvent>,
+) {
+    if keyboard.just_pressed(KeyCode::F8) {
+        info!("Sharing board (F8 pressed)");
+        share_events.write(ShareBoardEvent);
+    }
+}
+
+/// Frames the board with player names, life totals and the turn number, and
+/// schedules its capture
+pub fn handle_share_board_events(
+    mut commands: Commands,
+    mut events: EventReader<ShareBoardEvent>,
+    config: Res<SnapshotConfig>,
+    pending: Option<Res<PendingBoardShare>>,
+    players: Query<&Player>,
+    turn_manager: Option<Res<TurnManager>>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+    if pending.is_some() {
+        info!("A board screenshot is already being taken");
+        return;
+    }
+
+    let turn_number = turn_manager.map_or(1, |turn_manager| turn_manager.turn_number);
+    let caption = BoardCaption::new(turn_number, players.iter());
+    let path = Path::new(&config.screenshots_dir).join(caption.filename());
+    spawn_share_frame(&mut commands, &caption);
+    commands.insert_resource(PendingBoardShare {
+        path,
+        frames_left: CAPTURE_DELAY_FRAMES,
+    });
+}
+
+fn spawn_share_frame(commands: &mut Commands, caption: &BoardCaption) {
+    let label = |text: String, font_size: f32| {
+        (
+            Text::new(text),
+            TextFont {
+                font_size,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        )
+    };
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            BorderColor(Color::srgb(0.55, 0.42, 0.2)),
+            ShareBoardFrame,
+            AppLayer::Overlay.layer(),
+            UiLayer::Overlay.global_z_index(),
+            Name::new("Share Board Frame"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        justify_content: JustifyContent::Center,
+                        padding: UiRect::all(Val::Px(8.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+                    Name::new("Share Board Title"),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(label(caption.title(), 28.0));
+                });
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        justify_content: JustifyContent::SpaceEvenly,
+                        padding: UiRect::all(Val::Px(8.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+                    Name::new("Share Board Players"),
+                ))
+                .with_children(|parent| {
+                    for player_label in caption.player_labels() {
+                        parent.spawn(label(player_label, 20.0));
+                    }
+                });
+        });
+}
+
+/// Captures the framed board once it has been drawn, saving it to the
+/// screenshots directory
+pub fn capture_shared_board(mut commands: Commands, mut pending: ResMut<PendingBoardShare>) {
+    if pending.frames_left > 0 {
+        pending.frames_left -= 1;
+        return;
+    }
+
+    let path = pending.path.clone();
+    commands.remove_resource::<PendingBoardShare>();
+    if let Some(dir) = path.parent() {
+        if let Err(e) = std::fs::create_dir_all(dir) {
+            error!("Failed to create screenshots directory {:?}: {}", dir, e);
+        }
+    }
+
+    info!("Capturing board screenshot to {:?}", path);
+    commands
+        .spawn(Screenshot::primary_window())
+        .observe(save_to_disk(path.clone()))
+        .observe(
+            move |_captured: Trigger<ScreenshotCaptured>,
+                  mut commands: Commands,
+                  frames: Query<Entity, With<ShareBoardFrame>>| {
+                for frame in frames.iter() {
+                    commands.entity(frame).despawn();
+                }
+                spawn_share_toast(&mut commands, &path);
+            },
+        );
+}
+
+fn spawn_share_toast(commands: &mut Commands, path: &Path) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(24.0),
+                left: Val::Percent(50.0),
+                margin: UiRect::left(Val::Px(-240.0)),
+                width: Val::Px(480.0),
+                justify_content: JustifyContent::Center,
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.9)),
+            ShareBoardToast {
+                timer: Timer::from_seconds(TOAST_SECONDS, TimerMode::Once),
+            },
+            AppLayer::Overlay.layer(),
+            UiLayer::Overlay.global_z_index(),
+            Name::new("Share Board Toast"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("Board saved to {}", path.display())),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// Removes share toasts once they have been shown long enough
+pub fn update_share_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut toasts: Query<(Entity, &mut ShareBoardToast)>,
+) {
+    for (entity, mut toast) in toasts.iter_mut() {
+        if toast.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -3,6 +3,7 @@ mod fixtures;
 mod integration_tests;
 mod plugin_tests;
 mod resources_tests;
+mod share_tests;
 mod systems_tests;
 
 // This test ensures that the re-exported items from the snapshot module are used
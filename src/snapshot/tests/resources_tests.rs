@@ -12,6 +12,7 @@ fn test_snapshot_config_default() {
     assert_eq!(config.resolution, Vec2::new(1920.0, 1080.0));
     assert_eq!(config.auto_snapshot_enabled, false);
     assert_eq!(config.include_debug_by_default, true);
+    assert_eq!(config.screenshots_dir, "screenshots");
 }
 
 #[test]
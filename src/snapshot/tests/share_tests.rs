@@ -0,0 +1,67 @@
+use std::path::Path;
+
+use crate::game_engine::turns::TurnManager;
+use crate::player::Player;
+use crate::snapshot::resources::SnapshotConfig;
+use crate::snapshot::share::{
+    BoardCaption, PendingBoardShare, ShareBoardEvent, ShareBoardFrame, handle_share_board_events,
+};
+use bevy::prelude::*;
+
+fn player(name: &str, life: i32, player_index: usize) -> Player {
+    let mut player = Player::new(name);
+    player.life = life;
+    player.player_index = player_index;
+    player
+}
+
+#[test]
+fn test_board_caption_lists_players_in_seating_order() {
+    let players = [player("Bob", 32, 1), player("Alice", 40, 0)];
+    let caption = BoardCaption::new(7, players.iter());
+
+    assert_eq!(caption.title(), "Rummage - Turn 7");
+    assert_eq!(
+        caption.player_labels(),
+        vec!["Alice: 40 life".to_string(), "Bob: 32 life".to_string()]
+    );
+    assert!(caption.filename().starts_with("board_turn_7_"));
+    assert!(caption.filename().ends_with(".png"));
+}
+
+#[test]
+fn test_share_board_event_frames_the_board() {
+    let mut app = App::new();
+    app.insert_resource(SnapshotConfig::new().with_screenshots_dir("shared"))
+        .insert_resource(TurnManager {
+            turn_number: 3,
+            ..default()
+        })
+        .add_event::<ShareBoardEvent>()
+        .add_systems(Update, handle_share_board_events);
+    app.world_mut().spawn(player("Alice", 40, 0));
+
+    app.world_mut().send_event(ShareBoardEvent);
+    app.update();
+
+    let pending = app.world().resource::<PendingBoardShare>();
+    assert_eq!(pending.path.parent(), Some(Path::new("shared")));
+    assert!(
+        pending
+            .path
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("board_turn_3_")
+    );
+
+    // A second request while one is pending doesn't add another frame
+    app.world_mut().send_event(ShareBoardEvent);
+    app.update();
+    let world = app.world_mut();
+    let frames = world
+        .query_filtered::<(), With<ShareBoardFrame>>()
+        .iter(world)
+        .count();
+    assert_eq!(frames, 1);
+}
@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// A game ended and its statistics were recorded
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameFinishedEvent {
+    /// The last player standing, if any
+    pub winner: Option<Entity>,
+}
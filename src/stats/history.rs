@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+use bevy_persistent::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use super::resources::{GameStats, PlayerGameStats};
+
+/// Statistics file name inside the config directory
+const STATS_FILE_NAME: &str = "stats.toml";
+
+/// Totals over every finished game played with a deck
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeckRecord {
+    pub games: u32,
+    pub wins: u32,
+    /// Turns over all games
+    pub turns: u32,
+    /// Seconds over all games
+    pub seconds: f64,
+    pub damage_dealt: u64,
+    pub cards_drawn: u64,
+    pub mana_spent: u64,
+    pub commander_casts: u64,
+}
+
+impl DeckRecord {
+    /// Add one finished game
+    pub fn record(&mut self, player: &PlayerGameStats, won: bool, turns: u32, seconds: f64) {
+        self.games += 1;
+        self.wins += u32::from(won);
+        self.turns += turns;
+        self.seconds += seconds;
+        self.damage_dealt += u64::from(player.damage_dealt);
+        self.cards_drawn += u64::from(player.cards_drawn);
+        self.mana_spent += player.mana_spent;
+        self.commander_casts += u64::from(player.commander_casts);
+    }
+
+    /// Share of games won, 0 before the first game
+    pub fn win_rate(&self) -> f32 {
+        if self.games == 0 {
+            0.0
+        } else {
+            self.wins as f32 / self.games as f32
+        }
+    }
+
+    /// Average game length in turns, 0 before the first game
+    pub fn average_turns(&self) -> f32 {
+        if self.games == 0 {
+            0.0
+        } else {
+            self.turns as f32 / self.games as f32
+        }
+    }
+}
+
+/// Statistics of every deck played, kept between sessions
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatsHistory {
+    /// Records by deck name
+    pub decks: BTreeMap<String, DeckRecord>,
+}
+
+impl StatsHistory {
+    /// Add a finished game to the record of every deck played in it
+    pub fn record_game(&mut self, stats: &GameStats) {
+        let Some(ended_at) = stats.ended_at else {
+            return;
+        };
+        let seconds = stats.duration_secs(ended_at);
+        for player in &stats.players {
+            if player.deck.is_empty() {
+                continue;
+            }
+            self.decks.entry(player.deck.clone()).or_default().record(
+                player,
+                stats.winner == Some(player.player),
+                stats.turns,
+                seconds,
+            );
+        }
+    }
+}
+
+/// Path of the statistics file in the platform config directory
+pub fn stats_history_path() -> PathBuf {
+    #[cfg(target_arch = "wasm32")]
+    {
+        PathBuf::from("/local/rummage").join(STATS_FILE_NAME)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        dirs::config_dir()
+            .map(|dir| dir.join("rummage"))
+            .unwrap_or_else(|| PathBuf::from("settings"))
+            .join(STATS_FILE_NAME)
+    }
+}
+
+/// Load the saved deck statistics
+pub fn load_persistent_history() -> Option<Persistent<StatsHistory>> {
+    match Persistent::<StatsHistory>::builder()
+        .name("rummage_stats")
+        .format(StorageFormat::Toml)
+        .path(stats_history_path())
+        .default(StatsHistory::default())
+        .revert_to_default_on_deserialization_errors(true)
+        .build()
+    {
+        Ok(persistent) => Some(persistent),
+        Err(e) => {
+            error!("Failed to initialize deck statistics: {:?}", e);
+            None
+        }
+    }
+}
+
+/// Write the deck statistics to disk whenever they change
+pub fn persist_stats_history(
+    history: Res<StatsHistory>,
+    persistent: Option<ResMut<Persistent<StatsHistory>>>,
+) {
+    let Some(mut persistent) = persistent else {
+        return;
+    };
+    if !history.is_changed() || *persistent.get() == *history {
+        return;
+    }
+
+    match persistent.set(history.clone()) {
+        Ok(()) => debug!("Deck statistics saved"),
+        Err(e) => error!("Failed to save deck statistics: {:?}", e),
+    }
+}
@@ -0,0 +1,23 @@
+//! Game statistics
+//!
+//! While a game runs, per-player metrics (damage dealt and taken, cards drawn,
+//! mana spent, spells and commanders cast, life totals each turn and the game's
+//! length) are collected from the engine's events into [`GameStats`]. When the
+//! game ends the totals are added to each deck's [`DeckRecord`], which is saved
+//! between sessions, and a results screen is shown with the winner, the order
+//! players were eliminated in, graphs of the game and a rematch button.
+
+pub mod events;
+pub mod history;
+mod plugin;
+pub mod resources;
+pub mod summary;
+pub mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use events::GameFinishedEvent;
+pub use history::{DeckRecord, StatsHistory};
+pub use plugin::StatsPlugin;
+pub use resources::{Elimination, GameStats, PlayerGameStats};
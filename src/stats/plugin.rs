@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use super::events::GameFinishedEvent;
+use super::history::{StatsHistory, load_persistent_history, persist_stats_history};
+use super::resources::GameStats;
+use super::summary::{despawn_game_summary, handle_game_summary_buttons, spawn_game_summary};
+use super::systems::{
+    finish_game, reset_game_stats, track_damage, track_game_actions, track_game_events, track_turns,
+};
+use crate::game_engine::actions::GameAction;
+use crate::game_engine::commander::CombatDamageEvent;
+use crate::game_engine::state::{GameEvent, GameOverEvent};
+use crate::game_engine::turns::TurnStartEvent;
+use crate::menu::state::GameMenuState;
+
+/// Plugin collecting game statistics and showing them when a game ends
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<GameFinishedEvent>()
+            .add_event::<GameAction>()
+            .add_event::<GameEvent>()
+            .add_event::<GameOverEvent>()
+            .add_event::<TurnStartEvent>()
+            .add_event::<CombatDamageEvent>()
+            .init_resource::<GameStats>()
+            .init_resource::<StatsHistory>();
+
+        if let Some(persistent) = load_persistent_history() {
+            app.insert_resource(persistent.get().clone());
+            app.insert_resource(persistent);
+        }
+
+        app.add_systems(OnEnter(GameMenuState::InGame), reset_game_stats)
+            .add_systems(OnExit(GameMenuState::InGame), despawn_game_summary)
+            .add_systems(
+                Update,
+                (
+                    (
+                        track_turns,
+                        track_game_events,
+                        track_game_actions,
+                        track_damage,
+                    ),
+                    finish_game,
+                    spawn_game_summary,
+                    handle_game_summary_buttons,
+                )
+                    .chain()
+                    .run_if(in_state(GameMenuState::InGame)),
+            )
+            .add_systems(Update, persist_stats_history);
+    }
+}
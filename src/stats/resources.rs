@@ -0,0 +1,103 @@
+use bevy::prelude::*;
+
+use crate::game_engine::commander::EliminationReason;
+
+/// When and why a player lost
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elimination {
+    /// Turn the player was eliminated on
+    pub turn: u32,
+    pub reason: EliminationReason,
+}
+
+/// What one player did during a game
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlayerGameStats {
+    pub player: Entity,
+    pub name: String,
+    /// Name of the deck the player played
+    pub deck: String,
+    /// Damage dealt by sources the player controls
+    pub damage_dealt: u32,
+    /// Damage dealt to the player
+    pub damage_taken: u32,
+    pub cards_drawn: u32,
+    /// Mana paid for spells and abilities
+    pub mana_spent: u64,
+    pub spells_cast: u32,
+    pub commander_casts: u32,
+    pub lands_played: u32,
+    /// Life total at the start of each turn
+    pub life_history: Vec<i32>,
+    /// How the player lost, if they did
+    pub elimination: Option<Elimination>,
+}
+
+impl PlayerGameStats {
+    pub fn new(player: Entity) -> Self {
+        Self {
+            player,
+            ..default()
+        }
+    }
+}
+
+/// Statistics of the game being played
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct GameStats {
+    /// Players in the order they were first seen
+    pub players: Vec<PlayerGameStats>,
+    /// Highest turn number reached
+    pub turns: u32,
+    /// Seconds since startup when the game began
+    pub started_at: f64,
+    /// Seconds since startup when the game ended, once it has
+    pub ended_at: Option<f64>,
+    pub winner: Option<Entity>,
+    /// Eliminated players, the first to be eliminated first
+    pub elimination_order: Vec<Entity>,
+}
+
+impl GameStats {
+    /// Empty statistics for a game starting at `started_at`
+    pub fn new(started_at: f64) -> Self {
+        Self {
+            started_at,
+            ..default()
+        }
+    }
+
+    /// The statistics of a player, added the first time they are needed
+    pub fn player_mut(&mut self, player: Entity) -> &mut PlayerGameStats {
+        let index = match self.players.iter().position(|stats| stats.player == player) {
+            Some(index) => index,
+            None => {
+                self.players.push(PlayerGameStats::new(player));
+                self.players.len() - 1
+            }
+        };
+        &mut self.players[index]
+    }
+
+    /// The statistics of a player, if they did anything yet
+    pub fn player(&self, player: Entity) -> Option<&PlayerGameStats> {
+        self.players.iter().find(|stats| stats.player == player)
+    }
+
+    /// Whether the game has ended
+    pub fn is_finished(&self) -> bool {
+        self.ended_at.is_some()
+    }
+
+    /// Length of the game in seconds, up to `now` while it is still running
+    pub fn duration_secs(&self, now: f64) -> f64 {
+        (self.ended_at.unwrap_or(now) - self.started_at).max(0.0)
+    }
+
+    /// Mark the game as over
+    pub fn finish(&mut self, winner: Option<Entity>, elimination_order: Vec<Entity>, now: f64) {
+        self.winner = winner;
+        self.elimination_order = elimination_order;
+        self.ended_at = Some(now);
+    }
+}
@@ -0,0 +1,379 @@
+//! Post-game results screen
+//!
+//! Shows the winner and the order players were eliminated in, each player's
+//! statistics, a bar graph of life totals by turn, a comparison of damage dealt
+//! and the running record of every deck played, with buttons for a rematch or
+//! returning to the main menu.
+
+use bevy::prelude::*;
+
+use super::events::GameFinishedEvent;
+use super::history::StatsHistory;
+use super::resources::{GameStats, PlayerGameStats};
+use crate::camera::components::AppLayer;
+use crate::camera::z_order::UiLayer;
+use crate::game_engine::commander::EliminationReason;
+use crate::menu::state::GameMenuState;
+
+const PANEL_COLOR: Color = Color::srgba(0.05, 0.05, 0.08, 0.95);
+const BUTTON_COLOR: Color = Color::srgba(0.22, 0.22, 0.26, 0.95);
+const GRAPH_TRACK_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.08);
+/// Colors telling players apart in the graphs, by seat
+const PLAYER_COLORS: [Color; 6] = [
+    Color::srgb(0.35, 0.6, 0.95),
+    Color::srgb(0.95, 0.45, 0.35),
+    Color::srgb(0.45, 0.85, 0.45),
+    Color::srgb(0.95, 0.8, 0.3),
+    Color::srgb(0.75, 0.45, 0.9),
+    Color::srgb(0.4, 0.85, 0.85),
+];
+/// Height of the tallest bar in the life graph
+const LIFE_GRAPH_HEIGHT: f32 = 48.0;
+/// Width of the damage bars when a player dealt the most damage
+const DAMAGE_GRAPH_WIDTH: f32 = 260.0;
+
+/// Root node of the summary screen
+#[derive(Component, Debug, Clone, Copy)]
+pub struct GameSummaryRoot;
+
+/// Buttons on the summary screen
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameSummaryAction {
+    /// Hide the summary and look at the final board
+    Close,
+    /// Play again with the same setup
+    Rematch,
+    /// Return to the main menu
+    MainMenu,
+}
+
+/// Length of a bar showing `value` out of `max`, from 0 to 1
+pub fn bar_fraction(value: f32, max: f32) -> f32 {
+    if max <= 0.0 {
+        0.0
+    } else {
+        (value / max).clamp(0.0, 1.0)
+    }
+}
+
+/// A game length as minutes and seconds
+pub fn format_duration(seconds: f64) -> String {
+    let seconds = seconds.max(0.0).round() as u64;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// One line of statistics for a player
+pub fn player_summary(stats: &PlayerGameStats) -> String {
+    format!(
+        "Damage {} dealt / {} taken  Drawn {}  Mana {}  Spells {}  Commander casts {}",
+        stats.damage_dealt,
+        stats.damage_taken,
+        stats.cards_drawn,
+        stats.mana_spent,
+        stats.spells_cast,
+        stats.commander_casts
+    )
+}
+
+/// Why a player was eliminated, as shown on the results screen
+pub fn elimination_reason_text(reason: EliminationReason) -> &'static str {
+    match reason {
+        EliminationReason::LifeLoss => "ran out of life",
+        EliminationReason::EmptyLibrary => "drew from an empty library",
+        EliminationReason::CommanderDamage(_) => "took lethal commander damage",
+        EliminationReason::Concede => "conceded",
+        EliminationReason::Poison => "got ten poison counters",
+        EliminationReason::CardEffect(_) => "lost to a card effect",
+    }
+}
+
+/// A line of the elimination order, such as "1. Bob conceded on turn 5"
+pub fn elimination_summary(place: usize, stats: &PlayerGameStats) -> String {
+    match stats.elimination {
+        Some(elimination) => format!(
+            "{}. {} {} on turn {}",
+            place,
+            stats.name,
+            elimination_reason_text(elimination.reason),
+            elimination.turn
+        ),
+        None => format!("{}. {}", place, stats.name),
+    }
+}
+
+fn label(text: impl Into<String>, font_size: f32, color: Color) -> impl Bundle {
+    (
+        Text::new(text),
+        TextFont {
+            font_size,
+            ..default()
+        },
+        TextColor(color),
+    )
+}
+
+/// Show the summary screen when a game ends
+pub fn spawn_game_summary(
+    mut commands: Commands,
+    mut finished: EventReader<GameFinishedEvent>,
+    stats: Res<GameStats>,
+    history: Res<StatsHistory>,
+    time: Res<Time>,
+    roots: Query<Entity, With<GameSummaryRoot>>,
+) {
+    let Some(event) = finished.read().last() else {
+        return;
+    };
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+
+    let winner = event
+        .winner
+        .and_then(|winner| stats.player(winner))
+        .map_or_else(
+            || "Nobody wins".to_string(),
+            |winner| format!("{} wins", winner.name),
+        );
+    let length = format!(
+        "{} turns, {}",
+        stats.turns,
+        format_duration(stats.duration_secs(time.elapsed_secs_f64()))
+    );
+    let max_life = stats
+        .players
+        .iter()
+        .flat_map(|player| player.life_history.iter().copied())
+        .max()
+        .unwrap_or(0) as f32;
+    let max_damage = stats
+        .players
+        .iter()
+        .map(|player| player.damage_dealt)
+        .max()
+        .unwrap_or(0) as f32;
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(15.0),
+                right: Val::Percent(15.0),
+                top: Val::Percent(8.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(PANEL_COLOR),
+            UiLayer::Dialog.global_z_index(),
+            GameSummaryRoot,
+            AppLayer::GameUI.layer(),
+            Name::new("Game Summary"),
+        ))
+        .with_children(|parent| {
+            parent.spawn(label("Game Over", 30.0, Color::WHITE));
+            parent.spawn(label(
+                format!("{} - {}", winner, length),
+                18.0,
+                Color::srgb(0.85, 0.85, 0.85),
+            ));
+
+            if !stats.elimination_order.is_empty() {
+                parent.spawn(label("Eliminated", 18.0, Color::WHITE));
+                for (place, player) in stats
+                    .elimination_order
+                    .iter()
+                    .filter_map(|player| stats.player(*player))
+                    .enumerate()
+                {
+                    parent.spawn(label(
+                        elimination_summary(place + 1, player),
+                        14.0,
+                        Color::srgb(0.85, 0.85, 0.85),
+                    ));
+                }
+            }
+
+            for (seat, player) in stats.players.iter().enumerate() {
+                let color = PLAYER_COLORS[seat % PLAYER_COLORS.len()];
+                parent.spawn(label(
+                    format!("{} ({})", player.name, player.deck),
+                    18.0,
+                    color,
+                ));
+                parent.spawn(label(player_summary(player), 14.0, Color::WHITE));
+            }
+
+            parent.spawn(label("Life by turn", 18.0, Color::WHITE));
+            for (seat, player) in stats.players.iter().enumerate() {
+                spawn_life_graph(
+                    parent,
+                    player,
+                    PLAYER_COLORS[seat % PLAYER_COLORS.len()],
+                    max_life,
+                );
+            }
+
+            parent.spawn(label("Damage dealt", 18.0, Color::WHITE));
+            for (seat, player) in stats.players.iter().enumerate() {
+                spawn_damage_bar(
+                    parent,
+                    player,
+                    PLAYER_COLORS[seat % PLAYER_COLORS.len()],
+                    max_damage,
+                );
+            }
+
+            parent.spawn(label("Deck records", 18.0, Color::WHITE));
+            for player in &stats.players {
+                let Some(record) = history.decks.get(&player.deck) else {
+                    continue;
+                };
+                parent.spawn(label(
+                    format!(
+                        "{}: {} games, {:.0}% won, {:.1} turns on average",
+                        player.deck,
+                        record.games,
+                        record.win_rate() * 100.0,
+                        record.average_turns()
+                    ),
+                    14.0,
+                    Color::srgb(0.85, 0.85, 0.85),
+                ));
+            }
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(12.0),
+                    margin: UiRect::top(Val::Px(8.0)),
+                    ..default()
+                })
+                .with_children(|row| {
+                    spawn_summary_button(row, "Close", GameSummaryAction::Close);
+                    spawn_summary_button(row, "Rematch", GameSummaryAction::Rematch);
+                    spawn_summary_button(row, "Main Menu", GameSummaryAction::MainMenu);
+                });
+        });
+}
+
+/// A row of bars, one per turn, as tall as the player's life total was
+fn spawn_life_graph(
+    parent: &mut ChildSpawnerCommands,
+    player: &PlayerGameStats,
+    color: Color,
+    max_life: f32,
+) {
+    parent
+        .spawn((
+            Node {
+                height: Val::Px(LIFE_GRAPH_HEIGHT),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::FlexEnd,
+                column_gap: Val::Px(2.0),
+                ..default()
+            },
+            BackgroundColor(GRAPH_TRACK_COLOR),
+            Name::new(format!("{} Life Graph", player.name)),
+        ))
+        .with_children(|graph| {
+            for life in &player.life_history {
+                graph.spawn((
+                    Node {
+                        width: Val::Px(8.0),
+                        height: Val::Px(LIFE_GRAPH_HEIGHT * bar_fraction(*life as f32, max_life)),
+                        ..default()
+                    },
+                    BackgroundColor(color),
+                ));
+            }
+        });
+}
+
+/// A bar as long as the damage the player dealt, relative to the most dealt
+fn spawn_damage_bar(
+    parent: &mut ChildSpawnerCommands,
+    player: &PlayerGameStats,
+    color: Color,
+    max_damage: f32,
+) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Node {
+                    width: Val::Px(
+                        DAMAGE_GRAPH_WIDTH * bar_fraction(player.damage_dealt as f32, max_damage),
+                    ),
+                    height: Val::Px(12.0),
+                    ..default()
+                },
+                BackgroundColor(color),
+            ));
+            row.spawn(label(player.damage_dealt.to_string(), 14.0, Color::WHITE));
+        });
+}
+
+fn spawn_summary_button(parent: &mut ChildSpawnerCommands, text: &str, action: GameSummaryAction) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(140.0),
+                height: Val::Px(36.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(BUTTON_COLOR),
+            action,
+            Name::new(format!("{} Button", text)),
+        ))
+        .with_children(|button| {
+            button.spawn(label(text, 16.0, Color::WHITE));
+        });
+}
+
+/// Close the summary, start a rematch or return to the main menu
+///
+/// A rematch goes through the loading state, which clears the finished game
+/// and sets up a new one from the same [`GameSetupConfig`].
+///
+/// [`GameSetupConfig`]: crate::game_engine::setup::GameSetupConfig
+pub fn handle_game_summary_buttons(
+    mut commands: Commands,
+    buttons: Query<(&Interaction, &GameSummaryAction), Changed<Interaction>>,
+    roots: Query<Entity, With<GameSummaryRoot>>,
+    mut next_state: ResMut<NextState<GameMenuState>>,
+) {
+    for (interaction, action) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match action {
+            GameSummaryAction::Close => {
+                for root in roots.iter() {
+                    commands.entity(root).despawn();
+                }
+            }
+            GameSummaryAction::Rematch => {
+                info!("Starting a rematch");
+                next_state.set(GameMenuState::Loading);
+            }
+            GameSummaryAction::MainMenu => next_state.set(GameMenuState::MainMenu),
+        }
+    }
+}
+
+/// Despawn the summary screen
+pub fn despawn_game_summary(mut commands: Commands, roots: Query<Entity, With<GameSummaryRoot>>) {
+    for entity in roots.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,169 @@
+use bevy::prelude::*;
+
+use super::events::GameFinishedEvent;
+use super::history::StatsHistory;
+use super::resources::{Elimination, GameStats};
+use crate::deck::PlayerDeck;
+use crate::game_engine::actions::GameAction;
+use crate::game_engine::commander::{CombatDamageEvent, Commander, EliminationReason};
+use crate::game_engine::permanent::{PermanentController, PermanentOwner};
+use crate::game_engine::state::{GameEvent, GameOverEvent};
+use crate::game_engine::turns::TurnStartEvent;
+use crate::menu::state::StateTransitionContext;
+use crate::player::Player;
+
+/// Start collecting statistics for a new game
+///
+/// Coming back from the pause menu continues the current game's statistics.
+pub fn reset_game_stats(
+    mut stats: ResMut<GameStats>,
+    context: Option<Res<StateTransitionContext>>,
+    time: Res<Time>,
+) {
+    if context.is_some_and(|context| context.from_pause_menu) {
+        return;
+    }
+    *stats = GameStats::new(time.elapsed_secs_f64());
+}
+
+/// Count turns and record every player's life total as each turn starts
+pub fn track_turns(
+    mut turns: EventReader<TurnStartEvent>,
+    players: Query<(Entity, &Player)>,
+    mut stats: ResMut<GameStats>,
+) {
+    for event in turns.read() {
+        stats.turns = stats.turns.max(event.turn_number);
+        for (entity, player) in players.iter() {
+            stats.player_mut(entity).life_history.push(player.life);
+        }
+    }
+}
+
+/// Count cards drawn and lands played, and note when players are eliminated or
+/// lose to another player's win
+pub fn track_game_events(mut events: EventReader<GameEvent>, mut stats: ResMut<GameStats>) {
+    for event in events.read() {
+        match event {
+            GameEvent::CardDrawn { player } => stats.player_mut(*player).cards_drawn += 1,
+            GameEvent::LandPlayed { player } => stats.player_mut(*player).lands_played += 1,
+            GameEvent::PlayerEliminated { player, reason } => {
+                let turn = stats.turns;
+                stats.player_mut(*player).elimination = Some(Elimination {
+                    turn,
+                    reason: *reason,
+                });
+            }
+            GameEvent::PlayerWon { player, source } => {
+                let turn = stats.turns;
+                for loser in stats
+                    .players
+                    .iter_mut()
+                    .filter(|stats| stats.player != *player && stats.elimination.is_none())
+                {
+                    loser.elimination = Some(Elimination {
+                        turn,
+                        reason: EliminationReason::CardEffect(*source),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Count spells and commanders cast and the mana paid for them and for abilities
+pub fn track_game_actions(
+    mut actions: EventReader<GameAction>,
+    commanders: Query<(), With<Commander>>,
+    mut stats: ResMut<GameStats>,
+) {
+    for action in actions.read() {
+        match action {
+            GameAction::CastSpell {
+                player,
+                spell_card,
+                mana_payment,
+                ..
+            } => {
+                let stats = stats.player_mut(*player);
+                stats.spells_cast += 1;
+                stats.mana_spent += mana_payment.total();
+                if commanders.contains(*spell_card) {
+                    stats.commander_casts += 1;
+                }
+            }
+            GameAction::ActivateAbility {
+                player,
+                mana_payment,
+                ..
+            } => stats.player_mut(*player).mana_spent += mana_payment.total(),
+            GameAction::PlayLand { .. } | GameAction::PassPriority { .. } => {}
+        }
+    }
+}
+
+/// Credit damage to the controller of its source and charge it to the player hit
+pub fn track_damage(
+    mut damage: EventReader<CombatDamageEvent>,
+    controllers: Query<&PermanentController>,
+    owners: Query<&PermanentOwner>,
+    players: Query<(), With<Player>>,
+    mut stats: ResMut<GameStats>,
+) {
+    for event in damage.read() {
+        let dealer = controllers
+            .get(event.source)
+            .map(|controller| controller.player)
+            .or_else(|_| owners.get(event.source).map(|owner| owner.player))
+            .ok()
+            .or_else(|| players.contains(event.source).then_some(event.source));
+        if let Some(dealer) = dealer {
+            stats.player_mut(dealer).damage_dealt += event.damage;
+        }
+        if players.contains(event.target) {
+            stats.player_mut(event.target).damage_taken += event.damage;
+        }
+    }
+}
+
+/// Record the game when the engine reports it is over
+///
+/// Games that never had an opponent, such as goldfish games, don't end this way.
+pub fn finish_game(
+    mut game_over: EventReader<GameOverEvent>,
+    time: Res<Time>,
+    players: Query<(Entity, &Player, Option<&PlayerDeck>)>,
+    mut stats: ResMut<GameStats>,
+    mut history: ResMut<StatsHistory>,
+    mut finished: EventWriter<GameFinishedEvent>,
+) {
+    let Some(event) = game_over.read().last() else {
+        return;
+    };
+    if stats.is_finished() {
+        return;
+    }
+
+    for (entity, player, deck) in players.iter() {
+        let player_stats = stats.player_mut(entity);
+        player_stats.name = player.name.clone();
+        player_stats.deck = deck.map_or_else(|| player.name.clone(), |deck| deck.deck.name.clone());
+        if player_stats.life_history.last() != Some(&player.life) {
+            player_stats.life_history.push(player.life);
+        }
+    }
+
+    let winner = event.winner;
+    stats.finish(
+        winner,
+        event.elimination_order.clone(),
+        time.elapsed_secs_f64(),
+    );
+    history.record_game(&stats);
+    info!(
+        "Game finished after {} turns, statistics recorded",
+        stats.turns
+    );
+    finished.write(GameFinishedEvent { winner });
+}
@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+
+use crate::game_engine::actions::GameAction;
+use crate::game_engine::commander::{CombatDamageEvent, Commander, EliminationReason};
+use crate::game_engine::permanent::PermanentController;
+use crate::game_engine::state::{GameEvent, GameOverEvent, GameOverReason};
+use crate::game_engine::turns::TurnStartEvent;
+use crate::mana::Mana;
+use crate::player::Player;
+use crate::stats::summary::{bar_fraction, elimination_summary, format_duration};
+use crate::stats::systems::{
+    finish_game, track_damage, track_game_actions, track_game_events, track_turns,
+};
+use crate::stats::{Elimination, GameFinishedEvent, GameStats, StatsHistory};
+
+fn stats_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<GameFinishedEvent>()
+        .add_event::<GameAction>()
+        .add_event::<GameEvent>()
+        .add_event::<GameOverEvent>()
+        .add_event::<TurnStartEvent>()
+        .add_event::<CombatDamageEvent>()
+        .init_resource::<GameStats>()
+        .init_resource::<StatsHistory>()
+        .add_systems(
+            Update,
+            (
+                (
+                    track_turns,
+                    track_game_events,
+                    track_game_actions,
+                    track_damage,
+                ),
+                finish_game,
+            )
+                .chain(),
+        );
+    app
+}
+
+#[test]
+fn test_metrics_are_collected_from_events() {
+    let mut app = stats_app();
+    let player = app
+        .world_mut()
+        .spawn(Player::new("Alice").with_life(40))
+        .id();
+    let opponent = app.world_mut().spawn(Player::new("Bob").with_life(40)).id();
+    let commander = app
+        .world_mut()
+        .spawn((
+            Commander {
+                owner: player,
+                ..default()
+            },
+            PermanentController::new(player),
+        ))
+        .id();
+
+    let world = app.world_mut();
+    world.send_event(TurnStartEvent::new(player, 1));
+    world.send_event(GameEvent::CardDrawn { player });
+    world.send_event(GameEvent::LandPlayed { player });
+    world.send_event(GameAction::CastSpell {
+        player,
+        spell_card: commander,
+        targets: Vec::new(),
+        mana_payment: Mana::new_with_colors(2, 0, 0, 0, 1, 0),
+    });
+    world.send_event(CombatDamageEvent {
+        source: commander,
+        target: opponent,
+        damage: 5,
+        is_combat_damage: true,
+        source_is_commander: true,
+    });
+    app.update();
+
+    let stats = app.world().resource::<GameStats>();
+    let alice = stats.player(player).unwrap();
+    assert_eq!(stats.turns, 1);
+    assert_eq!(alice.cards_drawn, 1);
+    assert_eq!(alice.lands_played, 1);
+    assert_eq!(alice.spells_cast, 1);
+    assert_eq!(alice.commander_casts, 1);
+    assert_eq!(alice.mana_spent, 3);
+    assert_eq!(alice.damage_dealt, 5);
+    assert_eq!(alice.life_history, vec![40]);
+    assert_eq!(stats.player(opponent).unwrap().damage_taken, 5);
+    assert!(!stats.is_finished());
+}
+
+#[test]
+fn test_finished_games_are_added_to_deck_records() {
+    let mut app = stats_app();
+    let player = app.world_mut().spawn(Player::new("Alice")).id();
+    let opponent = app.world_mut().spawn(Player::new("Bob")).id();
+    app.world_mut().send_event(TurnStartEvent::new(player, 4));
+    app.update();
+    assert!(!app.world().resource::<GameStats>().is_finished());
+
+    app.world_mut().send_event(GameEvent::PlayerEliminated {
+        player: opponent,
+        reason: EliminationReason::Concede,
+    });
+    app.world_mut().send_event(GameOverEvent {
+        winner: Some(player),
+        elimination_order: vec![opponent],
+        reason: GameOverReason::Elimination(EliminationReason::Concede),
+    });
+    app.update();
+
+    let stats = app.world().resource::<GameStats>();
+    assert!(stats.is_finished());
+    assert_eq!(stats.winner, Some(player));
+    assert_eq!(stats.elimination_order, vec![opponent]);
+    let bob = stats.player(opponent).unwrap();
+    assert_eq!(
+        bob.elimination,
+        Some(Elimination {
+            turn: 4,
+            reason: EliminationReason::Concede,
+        })
+    );
+    assert_eq!(elimination_summary(1, bob), "1. Bob conceded on turn 4");
+
+    // Players without a deck are recorded under their own name
+    let history = app.world().resource::<StatsHistory>();
+    let alice = &history.decks["Alice"];
+    assert_eq!((alice.games, alice.wins, alice.turns), (1, 1, 4));
+    assert_eq!(history.decks["Bob"].wins, 0);
+    assert_eq!(history.decks["Bob"].win_rate(), 0.0);
+
+    // Recording happens once per game
+    app.world_mut().send_event(GameOverEvent {
+        winner: Some(player),
+        elimination_order: vec![opponent],
+        reason: GameOverReason::Elimination(EliminationReason::Concede),
+    });
+    app.update();
+    assert_eq!(
+        app.world().resource::<StatsHistory>().decks["Alice"].games,
+        1
+    );
+}
+
+#[test]
+fn test_summary_helpers() {
+    assert_eq!(bar_fraction(20.0, 40.0), 0.5);
+    assert_eq!(bar_fraction(-3.0, 40.0), 0.0);
+    assert_eq!(bar_fraction(5.0, 0.0), 0.0);
+    assert_eq!(format_duration(754.4), "12:34");
+}
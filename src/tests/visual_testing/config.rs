@@ -36,7 +36,11 @@ impl Plugin for VisualTestingPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<VisualTestConfig>()
             .add_event::<ScreenshotEvent>()
-            .add_systems(Update, capture_screenshot_system);
+            .add_systems(Update, capture_screenshot_system)
+            .add_systems(
+                Startup,
+                apply_visual_test_args.run_if(resource_exists::<VisualTestArgs>),
+            );
 
         // Don't add render systems for now - we'll implement this properly later
         // This is causing a panic in the main app
@@ -72,6 +76,76 @@ pub struct VisualTestArgs {
     pub comparison_method: Option<ComparisonMethod>,
 }
 
+impl Default for VisualTestArgs {
+    fn default() -> Self {
+        Self {
+            run_visual_tests: true,
+            update_references: false,
+            reference_dir: None,
+            artifact_dir: None,
+            similarity_threshold: None,
+            comparison_method: None,
+        }
+    }
+}
+
+impl VisualTestArgs {
+    /// Parse visual testing flags, ignoring any other arguments
+    ///
+    /// `--update-references` regenerates reference images instead of comparing
+    /// against them. Directories, the threshold and the comparison method are
+    /// overridden with `--reference-dir=`, `--artifact-dir=`,
+    /// `--similarity-threshold=` and `--comparison-method=`.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Self {
+        let mut parsed = Self::default();
+        for arg in args {
+            let (flag, value) = match arg.split_once('=') {
+                Some((flag, value)) => (flag, Some(value)),
+                None => (arg.as_str(), None),
+            };
+            match (flag, value) {
+                ("--update-references", None) => parsed.update_references = true,
+                ("--skip-visual-tests", None) => parsed.run_visual_tests = false,
+                ("--reference-dir", Some(dir)) => parsed.reference_dir = Some(dir.to_string()),
+                ("--artifact-dir", Some(dir)) => parsed.artifact_dir = Some(dir.to_string()),
+                ("--similarity-threshold", Some(threshold)) => match threshold.parse() {
+                    Ok(threshold) => parsed.similarity_threshold = Some(threshold),
+                    Err(_) => warn!("Invalid similarity threshold: {}", threshold),
+                },
+                ("--comparison-method", Some(method)) => match ComparisonMethod::parse(method) {
+                    Some(method) => parsed.comparison_method = Some(method),
+                    None => warn!("Unknown comparison method: {}", method),
+                },
+                _ => {}
+            }
+        }
+        parsed
+    }
+
+    /// Flags from the command line, with the `GENERATE_REFERENCES` environment
+    /// variable also requesting regenerated references
+    pub fn from_env() -> Self {
+        let mut args = Self::from_args(std::env::args().skip(1));
+        if std::env::var("GENERATE_REFERENCES").is_ok() {
+            args.update_references = true;
+        }
+        args
+    }
+}
+
+impl ComparisonMethod {
+    /// Parse a method name as given on the command line
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "pixel" | "pixel-perfect" => Some(Self::PixelPerfect),
+            "phash" | "perceptual-hash" => Some(Self::PerceptualHash),
+            "ssim" => Some(Self::SSIM),
+            "combined" => Some(Self::Combined),
+            _ => None,
+        }
+    }
+}
+
 /// Apply command line arguments to the config
 pub fn apply_visual_test_args(mut config: ResMut<VisualTestConfig>, args: Res<VisualTestArgs>) {
     if args.update_references {
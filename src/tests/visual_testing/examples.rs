@@ -302,4 +302,111 @@ mod tests {
         let path = std::path::Path::new(&config.reference_dir).join(reference_name);
         path.exists()
     }
+
+    fn fixture_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(VisualTestingPlugin);
+        app
+    }
+
+    fn fixture_card(app: &mut App) -> Entity {
+        let world = app.world_mut();
+        world
+            .query_filtered::<Entity, With<VisualTestCard>>()
+            .single(world)
+            .expect("Fixture should spawn exactly one test card")
+    }
+
+    // Each card state fixture puts the card in the state it is named after
+    #[test]
+    fn test_card_state_fixtures() {
+        use crate::game_engine::combat::CombatState;
+        use crate::game_engine::permanent::PermanentState;
+
+        let mut app = fixture_app();
+
+        setup_card_state(&mut app, "card_tapped");
+        let card = fixture_card(&mut app);
+        assert!(app.world().get::<PermanentState>(card).unwrap().is_tapped);
+
+        setup_card_state(&mut app, "card_attacking");
+        let card = fixture_card(&mut app);
+        assert!(
+            app.world()
+                .resource::<CombatState>()
+                .attackers
+                .contains_key(&card)
+        );
+
+        setup_card_state(&mut app, "card_blocking");
+        let card = fixture_card(&mut app);
+        let combat = app.world().resource::<CombatState>();
+        assert!(
+            combat
+                .blockers
+                .values()
+                .any(|blockers| blockers == &vec![card])
+        );
+        assert!(combat.attackers.is_empty());
+
+        setup_card_state(&mut app, "card_with_counters");
+        let card = fixture_card(&mut app);
+        assert_eq!(
+            app.world()
+                .get::<PermanentState>(card)
+                .unwrap()
+                .counters
+                .plus_one_plus_one,
+            2
+        );
+
+        setup_card_state(&mut app, "card_with_attachments");
+        let card = fixture_card(&mut app);
+        assert_eq!(app.world().get::<Children>(card).unwrap().len(), 2);
+
+        // Fixtures from earlier states are cleaned up
+        setup_card_state(&mut app, "card_normal");
+        let card = fixture_card(&mut app);
+        assert!(!app.world().get::<PermanentState>(card).unwrap().is_tapped);
+        let world = app.world_mut();
+        let fixtures = world
+            .query_filtered::<(), With<VisualTestFixture>>()
+            .iter(world)
+            .count();
+        assert_eq!(fixtures, 1);
+    }
+
+    #[test]
+    fn test_visual_test_args_parsing() {
+        let args = VisualTestArgs::from_args(
+            [
+                "--update-references",
+                "--reference-dir=refs",
+                "--similarity-threshold=0.9",
+                "--comparison-method=ssim",
+                "--unrelated",
+            ]
+            .map(String::from),
+        );
+        assert!(args.update_references);
+        assert_eq!(args.reference_dir.as_deref(), Some("refs"));
+        assert_eq!(args.similarity_threshold, Some(0.9));
+        assert_eq!(args.comparison_method, Some(ComparisonMethod::SSIM));
+        assert!(!VisualTestArgs::from_args(Vec::new()).update_references);
+    }
+
+    // Regenerates every card state reference after an intentional rendering
+    // change: `cargo test regenerate_card_references -- --ignored`
+    #[test]
+    #[ignore]
+    fn regenerate_card_references() {
+        let mut app = fixture_app();
+        app.insert_resource(VisualTestArgs {
+            update_references: true,
+            ..VisualTestArgs::from_env()
+        });
+        app.update();
+        generate_reference_images(&mut app, CARD_TEST_STATES);
+    }
 }
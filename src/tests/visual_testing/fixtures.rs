@@ -1,6 +1,13 @@
-use crate::tests::visual_testing::capture::request_screenshot;
+use crate::camera::components::AppLayer;
+use crate::cards::{Card, CardDetails, CardTypes, CreatureCard, CreatureType};
+use crate::game_engine::combat::{BlockedStatus, CombatState};
+use crate::game_engine::permanent::PermanentState;
+use crate::hud::action_hints::ActionHint;
+use crate::mana::Mana;
+use crate::tests::visual_testing::CARD_TEST_STATES;
+use crate::tests::visual_testing::capture::{ScreenshotRequests, request_screenshot};
 use crate::tests::visual_testing::config::VisualTestConfig;
-use crate::tests::visual_testing::utils::ensure_test_directories;
+use crate::tests::visual_testing::utils::{ensure_test_directories, save_reference_image};
 use bevy::prelude::*;
 
 /// Sets up a basic test scene with camera
@@ -57,45 +64,185 @@ pub fn setup_animation_test(mut commands: Commands) {
     // with standard animation properties
 }
 
+/// Size the fixture cards are drawn at, matching battlefield cards
+const FIXTURE_CARD_SIZE: Vec2 = Vec2::new(157.5, 220.0);
+
+/// Marker for entities spawned by a card state fixture, despawned before the
+/// next state is set up
+#[derive(Component, Debug, Clone, Copy)]
+pub struct VisualTestFixture;
+
+/// Marker for the card a card state fixture is about
+#[derive(Component, Debug, Clone, Copy)]
+pub struct VisualTestCard;
+
+fn fixture_card(name: &str, types: CardTypes, rules_text: &str) -> Card {
+    let details = if types.contains(CardTypes::CREATURE) {
+        CardDetails::Creature(CreatureCard {
+            power: 2,
+            toughness: 2,
+            creature_type: CreatureType::NONE,
+        })
+    } else {
+        CardDetails::Other
+    };
+    Card::builder(name)
+        .cost(Mana::default())
+        .types(types)
+        .details(details)
+        .rules_text(rules_text)
+        .build_or_panic()
+}
+
+fn spawn_fixture_card(app: &mut App, card: Card, translation: Vec3) -> Entity {
+    app.world_mut()
+        .spawn((
+            Sprite {
+                color: Color::srgb(0.92, 0.92, 0.94),
+                custom_size: Some(FIXTURE_CARD_SIZE),
+                ..default()
+            },
+            Transform::from_translation(translation),
+            Visibility::Visible,
+            PermanentState::new(1),
+            Name::new(format!("Fixture Card: {}", card.name.name)),
+            card,
+            AppLayer::Cards.layer(),
+            VisualTestFixture,
+        ))
+        .id()
+}
+
+/// Spawn a sprite drawn just behind `card`, as the highlight and combat glows are
+fn spawn_glow(app: &mut App, card: Entity, color: Color) {
+    let translation = app.world().get::<Transform>(card).unwrap().translation;
+    app.world_mut().spawn((
+        Sprite {
+            color,
+            custom_size: Some(FIXTURE_CARD_SIZE * 1.08),
+            ..default()
+        },
+        Transform::from_translation(translation - Vec3::Z * 0.5),
+        AppLayer::Cards.layer(),
+        VisualTestFixture,
+        Name::new("Fixture Glow"),
+    ));
+}
+
 /// Set up card in a specific state for testing
+///
+/// Despawns the previous fixture and spawns a Grizzly Bears, marked with
+/// [`VisualTestCard`], in the requested state along with anything the state
+/// needs around it, such as the creature it blocks.
 pub fn setup_card_state(app: &mut App, state: &str) {
-    // Configure a card entity based on the requested state
-    // This would manipulate an existing card entity to put it in
-    // the specified state (tapped, highlighted, etc.)
+    let world = app.world_mut();
+    let previous: Vec<Entity> = world
+        .query_filtered::<Entity, With<VisualTestFixture>>()
+        .iter(world)
+        .collect();
+    for entity in previous {
+        world.entity_mut(entity).despawn();
+    }
+    app.init_resource::<CombatState>();
+    *app.world_mut().resource_mut::<CombatState>() = CombatState::default();
 
-    // Placeholder implementation - in the real version, this would
-    // modify the card entity's components based on the state
-    app.update(); // Ensure the card is properly rendered
+    let card = spawn_fixture_card(
+        app,
+        fixture_card("Grizzly Bears", CardTypes::CREATURE, ""),
+        Vec3::ZERO,
+    );
+    app.world_mut().entity_mut(card).insert(VisualTestCard);
 
     match state {
-        "card_normal" => {
-            // Set up a normal card
-        }
+        "card_normal" => {}
         "card_tapped" => {
-            // Set up a tapped card
+            let mut entity = app.world_mut().entity_mut(card);
+            entity.get_mut::<PermanentState>().unwrap().tap();
+            entity.get_mut::<Transform>().unwrap().rotation =
+                Quat::from_rotation_z(-std::f32::consts::FRAC_PI_2);
         }
         "card_highlighted" => {
-            // Set up a highlighted card
+            spawn_glow(app, card, ActionHint::Play.color());
         }
         "card_attacking" => {
-            // Set up an attacking card
+            // Attackers are tapped and pushed towards the defending player
+            let defender = app.world_mut().spawn(VisualTestFixture).id();
+            let mut entity = app.world_mut().entity_mut(card);
+            entity.get_mut::<PermanentState>().unwrap().tap();
+            let mut transform = entity.get_mut::<Transform>().unwrap();
+            transform.translation.y += FIXTURE_CARD_SIZE.y * 0.25;
+            transform.rotation = Quat::from_rotation_z(-std::f32::consts::FRAC_PI_2);
+            app.world_mut()
+                .resource_mut::<CombatState>()
+                .attackers
+                .insert(card, defender);
+            spawn_glow(app, card, ActionHint::Attack.color());
         }
         "card_blocking" => {
-            // Set up a blocking card
+            let attacker = spawn_fixture_card(
+                app,
+                fixture_card("Hill Giant", CardTypes::CREATURE, ""),
+                Vec3::new(0.0, FIXTURE_CARD_SIZE.y * 1.1, 0.0),
+            );
+            let mut combat = app.world_mut().resource_mut::<CombatState>();
+            combat.blockers.insert(attacker, vec![card]);
+            combat
+                .blocked_status
+                .insert(attacker, BlockedStatus::Blocked);
+            spawn_glow(app, card, Color::srgb(0.3, 0.5, 1.0));
         }
         "card_with_counters" => {
-            // Set up a card with counters
+            app.world_mut()
+                .get_mut::<PermanentState>(card)
+                .unwrap()
+                .counters
+                .plus_one_plus_one = 2;
+            let badge = app
+                .world_mut()
+                .spawn((
+                    Text2d::new("+2/+2"),
+                    TextFont {
+                        font_size: 28.0,
+                        ..default()
+                    },
+                    TextColor(Color::BLACK),
+                    Transform::from_xyz(0.0, -FIXTURE_CARD_SIZE.y * 0.35, 0.1),
+                    AppLayer::Cards.layer(),
+                    VisualTestFixture,
+                    Name::new("Fixture Counters"),
+                ))
+                .id();
+            app.world_mut().entity_mut(card).add_child(badge);
         }
         "card_with_attachments" => {
-            // Set up a card with attachments
+            // Attachments are fanned out above and behind what they're attached to
+            let equipment = spawn_fixture_card(
+                app,
+                fixture_card(
+                    "Bonesplitter",
+                    CardTypes::ARTIFACT,
+                    "Equipped creature gets +2/+0.\nEquip {1}",
+                ),
+                Vec3::new(0.0, FIXTURE_CARD_SIZE.y * 0.2, -0.1),
+            );
+            let aura = spawn_fixture_card(
+                app,
+                fixture_card("Rancor", CardTypes::ENCHANTMENT, "Enchant creature"),
+                Vec3::new(0.0, FIXTURE_CARD_SIZE.y * 0.1, -0.05),
+            );
+            app.world_mut()
+                .entity_mut(card)
+                .add_children(&[equipment, aura]);
         }
         "card_foil" => {
-            // Set up a foil card
+            // Foil rendering isn't implemented yet
         }
         _ => {
             warn!("Unknown card state: {}", state);
         }
     }
+
+    app.update(); // Ensure the card is properly rendered
 }
 
 /// Set up UI in a specific state for testing
@@ -163,20 +310,33 @@ pub fn setup_animation_keyframe(app: &mut App, animation: &str, _keyframe: i32)
     }
 }
 
+/// Set up a card or UI test state by name
+pub fn setup_state(app: &mut App, state: &str) {
+    if CARD_TEST_STATES.contains(&state) {
+        setup_card_state(app, state);
+    } else {
+        setup_ui_state(app, state);
+    }
+}
+
 /// Generate reference images for a set of test states
+///
+/// Each state is set up, captured and saved to the reference directory,
+/// replacing any existing reference.
 pub fn generate_reference_images(app: &mut App, test_states: &[&str]) {
     // Override the config to generate references
     {
         let mut config = app.world_mut().resource_mut::<VisualTestConfig>();
         config.update_references = true;
     }
+    app.init_resource::<ScreenshotRequests>();
 
     // Generate a reference image for each state
     for state in test_states {
         info!("Generating reference image for state: {}", state);
 
         // Set up the test scene with the specific state
-        setup_ui_state(app, state);
+        setup_state(app, state);
 
         // Queue a screenshot request using the event system
         app.world_mut().send_event(request_screenshot(
@@ -186,6 +346,18 @@ pub fn generate_reference_images(app: &mut App, test_states: &[&str]) {
 
         // Update to process the screenshot request
         app.update();
+
+        let captured: Vec<_> = app
+            .world_mut()
+            .resource_mut::<ScreenshotRequests>()
+            .requests
+            .drain(..)
+            .collect();
+        for (name, image) in captured {
+            if let Err(e) = save_reference_image(image, &name) {
+                error!("Failed to save reference {}: {}", name, e);
+            }
+        }
     }
 }
 
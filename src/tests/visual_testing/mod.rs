@@ -19,10 +19,11 @@ pub use capture::{
 };
 pub use ci::{configure_for_ci, is_ci_environment, setup_ci_visual_test};
 pub use comparison::{ComparisonResult, compare_images, save_difference_visualization};
-pub use config::{ComparisonMethod, VisualTestConfig, VisualTestingPlugin};
+pub use config::{ComparisonMethod, VisualTestArgs, VisualTestConfig, VisualTestingPlugin};
 pub use fixtures::{
-    generate_reference_images, setup_animation_keyframe, setup_animation_test, setup_card_state,
-    setup_test_scene, setup_ui_state, setup_ui_test_scene, setup_visual_test_fixtures,
+    VisualTestCard, VisualTestFixture, generate_reference_images, setup_animation_keyframe,
+    setup_animation_test, setup_card_state, setup_state, setup_test_scene, setup_ui_state,
+    setup_ui_test_scene, setup_visual_test_fixtures,
 };
 pub use utils::{load_reference_image, save_reference_image};
 
@@ -95,6 +95,48 @@ impl Default for CardTextLayout {
     }
 }
 
+impl CardTextLayout {
+    /// Local offset of the card name for a card of the given size
+    pub fn name_position(&self, card_size: Vec2) -> Vec2 {
+        card_size * Vec2::new(self.name_x_offset, self.name_y_offset)
+    }
+
+    /// Local offset of the mana cost for a card of the given size
+    pub fn mana_cost_position(&self, card_size: Vec2) -> Vec2 {
+        card_size * Vec2::new(self.mana_cost_x_offset, self.mana_cost_y_offset)
+    }
+
+    /// Local offset of the type line for a card of the given size
+    pub fn type_line_position(&self, card_size: Vec2) -> Vec2 {
+        card_size * Vec2::new(self.type_line_x_offset, self.type_line_y_offset)
+    }
+
+    /// Local offset of the center of the rules text box
+    pub fn text_box_position(&self, card_size: Vec2) -> Vec2 {
+        card_size * Vec2::new(0.0, self.text_box_y_offset)
+    }
+
+    /// Size of the rules text box with padding removed
+    pub fn text_box_inner_size(&self, card_size: Vec2) -> Vec2 {
+        calculate_text_size(
+            card_size,
+            self.text_box_width - (self.text_box_padding * 2.0),
+            self.text_box_height - (self.text_box_padding * 2.0),
+        )
+    }
+
+    /// Local offset of the top left corner of the padded rules text area
+    pub fn text_box_top_left(&self, card_size: Vec2) -> Vec2 {
+        let inner = self.text_box_inner_size(card_size);
+        self.text_box_position(card_size) + Vec2::new(-inner.x / 2.0, inner.y / 2.0)
+    }
+
+    /// Local offset of the power/toughness for a card of the given size
+    pub fn pt_position(&self, card_size: Vec2) -> Vec2 {
+        card_size * Vec2::new(self.pt_x_offset, self.pt_y_offset)
+    }
+}
+
 /// Calculate text size based on card size and percentage constraints
 pub fn calculate_text_size(card_size: Vec2, width_percentage: f32, height_percentage: f32) -> Vec2 {
     Vec2::new(
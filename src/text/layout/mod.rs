@@ -1,5 +1,9 @@
 //! Card text layout module for defining text positioning on cards
 
 mod card_text_layout;
+mod rules_text_layout;
+
+pub mod tests;
 
 pub use card_text_layout::*;
+pub use rules_text_layout::*;
@@ -0,0 +1,386 @@
+//! Rules text layout: measuring, word wrapping and shrink-to-fit
+//!
+//! Rules text is split into paragraphs, with parenthesized reminder text and flavor
+//! text marked as italic spans. The layout engine wraps the text to the card's text
+//! box and reduces the font size until everything fits or the minimum size is reached.
+//! Mana and tap symbols such as `{T}` or `{G}` are measured as single glyphs and
+//! emitted as their own spans so they can be drawn with the Mana font.
+
+use bevy::prelude::*;
+
+use crate::mana::symbols::is_valid_mana_symbol;
+
+/// Line height as a multiple of the font size
+pub const LINE_HEIGHT_FACTOR: f32 = 1.2;
+/// Space between rules text and flavor text as a multiple of the font size
+///
+/// Flavor text is separated from rules text by one blank line.
+pub const FLAVOR_SPACING_FACTOR: f32 = LINE_HEIGHT_FACTOR;
+/// Font size decrement used while shrinking text to fit
+const SHRINK_STEP: f32 = 0.5;
+/// Size of inline mana symbols relative to the surrounding text
+pub const MANA_SYMBOL_SCALE: f32 = 0.9;
+/// Advance width of an inline mana symbol relative to the font size
+const MANA_SYMBOL_WIDTH_FACTOR: f32 = 0.95 * MANA_SYMBOL_SCALE;
+
+/// Style of a run of rules text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RulesTextStyle {
+    /// Regular rules text
+    Regular,
+    /// Parenthesized reminder text, rendered in italics
+    Reminder,
+    /// Flavor text, rendered in italics below the rules text
+    Flavor,
+    /// A single mana or tap symbol such as `{T}`, rendered with the Mana font
+    ManaSymbol,
+}
+
+impl RulesTextStyle {
+    /// Whether this style is rendered in italics
+    pub fn is_italic(&self) -> bool {
+        matches!(self, RulesTextStyle::Reminder | RulesTextStyle::Flavor)
+    }
+}
+
+/// A run of text with a single style
+#[derive(Debug, Clone, PartialEq)]
+pub struct RulesTextSpan {
+    pub text: String,
+    pub style: RulesTextStyle,
+}
+
+/// A single laid out line
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RulesTextLine {
+    /// Styled runs making up the line
+    pub spans: Vec<RulesTextSpan>,
+    /// Measured width of the line
+    pub width: f32,
+    /// Whether this line starts a new paragraph
+    pub starts_paragraph: bool,
+    /// Whether this line starts the flavor text
+    pub starts_flavor: bool,
+}
+
+impl RulesTextLine {
+    /// Plain text content of the line
+    pub fn text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+}
+
+/// Result of laying out rules text inside a box
+#[derive(Debug, Clone, PartialEq)]
+pub struct RulesTextLayout {
+    /// Font size the text fits at
+    pub font_size: f32,
+    /// Wrapped lines
+    pub lines: Vec<RulesTextLine>,
+    /// Total height of the laid out text
+    pub height: f32,
+    /// True if the text still overflows at the minimum font size
+    pub overflow: bool,
+}
+
+impl RulesTextLayout {
+    /// Flatten the laid out lines into styled spans separated by line breaks
+    ///
+    /// Adjacent spans with the same style are merged, so the result can be rendered
+    /// directly as text spans. Each mana symbol becomes its own
+    /// [`RulesTextStyle::ManaSymbol`] span.
+    pub fn to_spans(&self) -> Vec<RulesTextSpan> {
+        let mut spans: Vec<RulesTextSpan> = Vec::new();
+
+        for (i, line) in self.lines.iter().enumerate() {
+            let break_text = match (i, line.starts_flavor) {
+                (0, _) => "",
+                (_, true) => "\n\n",
+                _ => "\n",
+            };
+
+            for (j, span) in line.spans.iter().enumerate() {
+                let text = if j == 0 {
+                    format!("{}{}", break_text, span.text)
+                } else {
+                    span.text.clone()
+                };
+
+                for (segment, is_symbol) in split_mana_symbols(&text) {
+                    let style = if is_symbol {
+                        RulesTextStyle::ManaSymbol
+                    } else {
+                        span.style
+                    };
+
+                    match spans.last_mut() {
+                        Some(last) if last.style == style && !is_symbol => {
+                            last.text.push_str(segment)
+                        }
+                        _ => spans.push(RulesTextSpan {
+                            text: segment.to_string(),
+                            style,
+                        }),
+                    }
+                }
+            }
+        }
+
+        spans
+    }
+}
+
+/// Split text into plain segments and valid mana symbols
+///
+/// Returns `(segment, is_symbol)` pairs in order. Braced text that is not a known
+/// mana symbol is left as plain text.
+pub fn split_mana_symbols(text: &str) -> Vec<(&str, bool)> {
+    let mut segments = Vec::new();
+    let mut plain_start = 0;
+    let mut search_from = 0;
+
+    while let Some(open) = text[search_from..].find('{').map(|i| i + search_from) {
+        let Some(close) = text[open..].find('}').map(|i| i + open) else {
+            break;
+        };
+
+        let symbol = &text[open..=close];
+        if is_valid_mana_symbol(symbol) {
+            if open > plain_start {
+                segments.push((&text[plain_start..open], false));
+            }
+            segments.push((symbol, true));
+            plain_start = close + 1;
+        }
+        search_from = close + 1;
+    }
+
+    if plain_start < text.len() {
+        segments.push((&text[plain_start..], false));
+    }
+
+    segments
+}
+
+/// Approximate advance width of a character relative to the font size
+///
+/// These factors approximate DejaVu Sans, which is used for card text.
+fn char_width_factor(c: char) -> f32 {
+    match c {
+        'i' | 'j' | 'l' | '.' | ',' | ':' | ';' | '\'' | '!' | '|' => 0.30,
+        ' ' => 0.32,
+        'f' | 't' | 'r' | '(' | ')' | '[' | ']' | '-' => 0.40,
+        'm' | 'w' => 0.90,
+        'M' | 'W' => 0.95,
+        '{' | '}' => 0.50,
+        c if c.is_ascii_uppercase() => 0.70,
+        c if c.is_ascii_digit() => 0.64,
+        _ => 0.58,
+    }
+}
+
+/// Measure the approximate rendered width of a string
+///
+/// Mana symbols are measured as a single glyph rather than their braced text.
+pub fn measure_text_width(text: &str, font_size: f32) -> f32 {
+    split_mana_symbols(text)
+        .into_iter()
+        .map(|(segment, is_symbol)| {
+            if is_symbol {
+                MANA_SYMBOL_WIDTH_FACTOR
+            } else {
+                segment.chars().map(char_width_factor).sum::<f32>()
+            }
+        })
+        .sum::<f32>()
+        * font_size
+}
+
+/// Split rules text into paragraphs of styled spans
+///
+/// Parenthesized text is treated as reminder text. Flavor text, if provided, is
+/// appended as its own paragraphs.
+pub fn parse_rules_text(rules_text: &str, flavor_text: Option<&str>) -> Vec<Vec<RulesTextSpan>> {
+    let mut paragraphs: Vec<Vec<RulesTextSpan>> = rules_text
+        .split('\n')
+        .map(str::trim)
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(split_reminder_text)
+        .collect();
+
+    if let Some(flavor) = flavor_text {
+        paragraphs.extend(
+            flavor
+                .split('\n')
+                .map(str::trim)
+                .filter(|paragraph| !paragraph.is_empty())
+                .map(|paragraph| {
+                    vec![RulesTextSpan {
+                        text: paragraph.to_string(),
+                        style: RulesTextStyle::Flavor,
+                    }]
+                }),
+        );
+    }
+
+    paragraphs
+}
+
+/// Split a paragraph into regular and reminder text spans
+fn split_reminder_text(paragraph: &str) -> Vec<RulesTextSpan> {
+    let mut spans = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+
+    for c in paragraph.chars() {
+        match c {
+            '(' => {
+                if depth == 0 && !current.is_empty() {
+                    spans.push(RulesTextSpan {
+                        text: std::mem::take(&mut current),
+                        style: RulesTextStyle::Regular,
+                    });
+                }
+                depth += 1;
+                current.push(c);
+            }
+            ')' if depth > 0 => {
+                current.push(c);
+                depth -= 1;
+                if depth == 0 {
+                    spans.push(RulesTextSpan {
+                        text: std::mem::take(&mut current),
+                        style: RulesTextStyle::Reminder,
+                    });
+                }
+            }
+            _ => current.push(c),
+        }
+    }
+
+    if !current.is_empty() {
+        let style = if depth > 0 {
+            RulesTextStyle::Reminder
+        } else {
+            RulesTextStyle::Regular
+        };
+        spans.push(RulesTextSpan {
+            text: current,
+            style,
+        });
+    }
+
+    spans
+}
+
+/// Wrap styled paragraphs to a maximum width at a given font size
+pub fn wrap_paragraphs(
+    paragraphs: &[Vec<RulesTextSpan>],
+    max_width: f32,
+    font_size: f32,
+) -> Vec<RulesTextLine> {
+    let space_width = measure_text_width(" ", font_size);
+    let mut lines = Vec::new();
+    let mut in_flavor = false;
+
+    for paragraph in paragraphs {
+        let is_flavor = paragraph
+            .first()
+            .is_some_and(|span| span.style == RulesTextStyle::Flavor);
+        let mut line = RulesTextLine {
+            starts_paragraph: true,
+            starts_flavor: is_flavor && !in_flavor,
+            ..default()
+        };
+        in_flavor |= is_flavor;
+
+        for span in paragraph {
+            for word in span.text.split_whitespace() {
+                let word_width = measure_text_width(word, font_size);
+                let needs_space = !line.spans.is_empty();
+                let extra = if needs_space { space_width } else { 0.0 };
+
+                if needs_space && line.width + extra + word_width > max_width {
+                    lines.push(std::mem::take(&mut line));
+                    push_word(&mut line, word, span.style, word_width, 0.0, false);
+                } else {
+                    push_word(&mut line, word, span.style, word_width, extra, needs_space);
+                }
+            }
+        }
+
+        if !line.spans.is_empty() {
+            lines.push(line);
+        }
+    }
+
+    lines
+}
+
+/// Append a word to a line, merging it into the last span when styles match
+fn push_word(
+    line: &mut RulesTextLine,
+    word: &str,
+    style: RulesTextStyle,
+    word_width: f32,
+    space_width: f32,
+    with_space: bool,
+) {
+    let separator = if with_space { " " } else { "" };
+    match line.spans.last_mut() {
+        Some(last) if last.style == style => {
+            last.text.push_str(separator);
+            last.text.push_str(word);
+        }
+        _ => line.spans.push(RulesTextSpan {
+            text: format!("{}{}", separator, word),
+            style,
+        }),
+    }
+    line.width += space_width + word_width;
+}
+
+/// Total height of wrapped lines at a font size
+pub fn measure_lines_height(lines: &[RulesTextLine], font_size: f32) -> f32 {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut height = font_size * LINE_HEIGHT_FACTOR;
+            if i > 0 && line.starts_flavor {
+                height += font_size * FLAVOR_SPACING_FACTOR;
+            }
+            height
+        })
+        .sum()
+}
+
+/// Lay out rules and flavor text inside a box, shrinking the font to fit
+pub fn layout_rules_text(
+    rules_text: &str,
+    flavor_text: Option<&str>,
+    box_size: Vec2,
+    base_font_size: f32,
+    min_font_size: f32,
+) -> RulesTextLayout {
+    let paragraphs = parse_rules_text(rules_text, flavor_text);
+    let min_font_size = min_font_size.min(base_font_size);
+    let mut font_size = base_font_size;
+
+    loop {
+        let lines = wrap_paragraphs(&paragraphs, box_size.x, font_size);
+        let height = measure_lines_height(&lines, font_size);
+        let fits = height <= box_size.y && lines.iter().all(|line| line.width <= box_size.x);
+
+        if fits || font_size <= min_font_size {
+            return RulesTextLayout {
+                font_size,
+                lines,
+                height,
+                overflow: !fits,
+            };
+        }
+
+        font_size = (font_size - SHRINK_STEP).max(min_font_size);
+    }
+}
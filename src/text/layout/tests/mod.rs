@@ -0,0 +1,102 @@
+// Tests for rules text layout
+#[cfg(test)]
+mod tests {
+    use crate::text::layout::{
+        RulesTextStyle, layout_rules_text, measure_text_width, parse_rules_text, split_mana_symbols,
+    };
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_measure_scales_with_font_size() {
+        let small = measure_text_width("Flying", 10.0);
+        let large = measure_text_width("Flying", 20.0);
+        assert!((large - small * 2.0).abs() < 0.001);
+        assert!(measure_text_width("mmmm", 10.0) > measure_text_width("iiii", 10.0));
+    }
+
+    #[test]
+    fn test_reminder_text_is_split_out() {
+        let paragraphs = parse_rules_text("Flying (This creature can't be blocked.)", None);
+        assert_eq!(paragraphs.len(), 1);
+        assert_eq!(paragraphs[0][0].style, RulesTextStyle::Regular);
+        assert_eq!(paragraphs[0][1].style, RulesTextStyle::Reminder);
+        assert_eq!(paragraphs[0][1].text, "(This creature can't be blocked.)");
+    }
+
+    #[test]
+    fn test_flavor_text_follows_rules_text() {
+        let layout = layout_rules_text(
+            "Draw a card.",
+            Some("Knowledge is power."),
+            Vec2::new(400.0, 200.0),
+            20.0,
+            8.0,
+        );
+        let flavor_line = layout.lines.iter().position(|line| line.starts_flavor);
+        assert_eq!(flavor_line, Some(1));
+        assert!(layout.to_spans()[1].text.starts_with("\n\n"));
+    }
+
+    #[test]
+    fn test_lines_wrap_within_box() {
+        let text = "Whenever another creature enters the battlefield under your control, \
+                    you gain 1 life and draw a card.";
+        let layout = layout_rules_text(text, None, Vec2::new(200.0, 500.0), 16.0, 8.0);
+        assert!(layout.lines.len() > 1);
+        assert!(layout.lines.iter().all(|line| line.width <= 200.0));
+        assert!(!layout.overflow);
+    }
+
+    #[test]
+    fn test_verbose_text_shrinks_to_fit() {
+        let text = "Target player draws three cards, then discards two cards. ".repeat(6);
+        let layout = layout_rules_text(&text, None, Vec2::new(300.0, 150.0), 24.0, 6.0);
+        assert!(layout.font_size < 24.0);
+        assert!(layout.height <= 150.0);
+        assert!(!layout.overflow);
+    }
+
+    #[test]
+    fn test_overflow_reported_at_minimum_size() {
+        let text = "word ".repeat(500);
+        let layout = layout_rules_text(&text, None, Vec2::new(100.0, 50.0), 20.0, 12.0);
+        assert_eq!(layout.font_size, 12.0);
+        assert!(layout.overflow);
+    }
+
+    #[test]
+    fn test_mana_symbols_are_tokenized() {
+        assert_eq!(
+            split_mana_symbols("{T}: Add {G}."),
+            vec![
+                ("{T}", true),
+                (": Add ", false),
+                ("{G}", true),
+                (".", false)
+            ]
+        );
+        // Unknown braced text stays literal
+        assert_eq!(split_mana_symbols("{FOO}"), vec![("{FOO}", false)]);
+    }
+
+    #[test]
+    fn test_mana_symbols_measure_as_single_glyphs() {
+        assert!(measure_text_width("{T}", 10.0) < measure_text_width("[T]", 10.0));
+        assert!(measure_text_width("{10}", 10.0) < measure_text_width("{FOO}", 10.0));
+    }
+
+    #[test]
+    fn test_symbols_become_their_own_spans() {
+        let layout = layout_rules_text("{T}: Add {G}.", None, Vec2::new(400.0, 100.0), 16.0, 8.0);
+        let styles: Vec<RulesTextStyle> = layout.to_spans().iter().map(|s| s.style).collect();
+        assert_eq!(
+            styles,
+            vec![
+                RulesTextStyle::ManaSymbol,
+                RulesTextStyle::Regular,
+                RulesTextStyle::ManaSymbol,
+                RulesTextStyle::Regular,
+            ]
+        );
+    }
+}
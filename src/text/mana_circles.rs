@@ -8,9 +8,14 @@ pub struct ManaCircle {
 }
 
 /// System to update the size and scale of mana circles to ensure they appear round
+///
+/// Only newly named or resized sprites are checked, rather than every sprite each frame.
 pub fn update_mana_circles(
     mut commands: Commands,
-    query: Query<(Entity, &Transform, &Sprite, &Name), Without<ManaCircle>>,
+    query: Query<
+        (Entity, &Transform, &Sprite, &Name),
+        (Without<ManaCircle>, Or<(Added<Name>, Changed<Sprite>)>),
+    >,
 ) {
     for (entity, _transform, sprite, name) in query.iter() {
         // Only process sprites with "Mana Circle" in their name
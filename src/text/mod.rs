@@ -24,14 +24,8 @@ pub struct TextPlugin {}
 
 impl Plugin for TextPlugin {
     fn build(&self, app: &mut App) {
-        // Only add the legacy text spawning system if requested
-        // Commenting this out as it's causing compilation errors
-        // We will need to properly define this as a system or function later
-        /*
-        if self.use_legacy_text_system {
-            app.add_systems(Update, crate::card::text::spawn_card_text);
-        }
-        */
+        // Card text itself is spawned by `crate::cards::text`, which replaced the
+        // old `crate::card` module
 
         app.add_systems(Update, mana_circles::update_mana_circles);
     }
@@ -0,0 +1,39 @@
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy::prelude::*;
+
+use crate::cards::pool::CardVisualPool;
+
+/// Card visuals waiting in the pool to be reused
+pub const CARD_POOL_FREE: DiagnosticPath = DiagnosticPath::const_new("cards/pool/free");
+
+/// Card visuals taken from the pool since startup
+pub const CARD_POOL_REUSED: DiagnosticPath = DiagnosticPath::const_new("cards/pool/reused");
+
+/// Card visuals spawned because none could be reused, since startup
+pub const CARD_POOL_SPAWNED: DiagnosticPath = DiagnosticPath::const_new("cards/pool/spawned");
+
+/// Released card visuals despawned because the pool was full, since startup
+pub const CARD_POOL_DISCARDED: DiagnosticPath = DiagnosticPath::const_new("cards/pool/discarded");
+
+/// Register the card visual pool diagnostics
+pub(super) fn register_card_pool_diagnostics(app: &mut App) {
+    app.register_diagnostic(Diagnostic::new(CARD_POOL_FREE))
+        .register_diagnostic(Diagnostic::new(CARD_POOL_REUSED))
+        .register_diagnostic(Diagnostic::new(CARD_POOL_SPAWNED))
+        .register_diagnostic(Diagnostic::new(CARD_POOL_DISCARDED))
+        .add_systems(Last, record_card_pool_diagnostics);
+}
+
+/// Add the pool's size and totals to the diagnostics
+pub fn record_card_pool_diagnostics(
+    mut diagnostics: Diagnostics,
+    pool: Option<Res<CardVisualPool>>,
+) {
+    let Some(pool) = pool else {
+        return;
+    };
+    diagnostics.add_measurement(&CARD_POOL_FREE, || pool.free_count() as f64);
+    diagnostics.add_measurement(&CARD_POOL_REUSED, || pool.stats.reused as f64);
+    diagnostics.add_measurement(&CARD_POOL_SPAWNED, || pool.stats.spawned as f64);
+    diagnostics.add_measurement(&CARD_POOL_DISCARDED, || pool.stats.discarded as f64);
+}
@@ -0,0 +1,43 @@
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy::prelude::*;
+
+/// Card transforms written by the layout systems in the last frame
+pub const CARD_TRANSFORM_UPDATES: DiagnosticPath =
+    DiagnosticPath::const_new("cards/transform_updates");
+
+/// Card sprites, images and overlays changed in the last frame
+pub const CARD_VISUAL_UPDATES: DiagnosticPath = DiagnosticPath::const_new("cards/visual_updates");
+
+/// Cards whose action hints were worked out again in the last frame
+pub const CARD_HINT_UPDATES: DiagnosticPath = DiagnosticPath::const_new("cards/hint_updates");
+
+/// Card updates made by the rendering sync systems during the current frame
+///
+/// Systems only touch cards whose state, layout or window changed, so these
+/// stay at zero while the table is idle, however many cards are on it.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CardUpdateCounts {
+    pub transforms: u32,
+    pub visuals: u32,
+    pub hints: u32,
+}
+
+/// Register the card update diagnostics and reset their counts every frame
+pub(super) fn register_card_update_diagnostics(app: &mut App) {
+    app.init_resource::<CardUpdateCounts>()
+        .register_diagnostic(Diagnostic::new(CARD_TRANSFORM_UPDATES))
+        .register_diagnostic(Diagnostic::new(CARD_VISUAL_UPDATES))
+        .register_diagnostic(Diagnostic::new(CARD_HINT_UPDATES))
+        .add_systems(Last, record_card_update_diagnostics);
+}
+
+/// Add the frame's counts to the diagnostics and start counting the next frame
+pub fn record_card_update_diagnostics(
+    mut diagnostics: Diagnostics,
+    mut counts: ResMut<CardUpdateCounts>,
+) {
+    let frame = std::mem::take(&mut *counts);
+    diagnostics.add_measurement(&CARD_TRANSFORM_UPDATES, || frame.transforms as f64);
+    diagnostics.add_measurement(&CARD_VISUAL_UPDATES, || frame.visuals as f64);
+    diagnostics.add_measurement(&CARD_HINT_UPDATES, || frame.hints as f64);
+}
@@ -0,0 +1,85 @@
+use bevy::log::tracing::Span;
+use bevy::log::tracing::span::Id;
+use bevy::prelude::*;
+
+use crate::game_engine::phase::Phase;
+use crate::game_engine::turns::TurnManager;
+
+/// Spans of the turn and step being played
+///
+/// The engine systems open their spans under the current step, so a trace reads
+/// `turn > step > system`. The spans are only entered by the systems beneath
+/// them, which keeps them open across frames without holding a guard.
+#[derive(Resource, Debug)]
+pub struct EngineSpans {
+    turn: Span,
+    step: Span,
+    /// The turn and step the spans were opened for
+    current: Option<(u32, Phase)>,
+}
+
+impl Default for EngineSpans {
+    fn default() -> Self {
+        Self {
+            turn: Span::none(),
+            step: Span::none(),
+            current: None,
+        }
+    }
+}
+
+impl EngineSpans {
+    /// The span of the turn being played
+    pub fn turn(&self) -> &Span {
+        &self.turn
+    }
+
+    /// The span of the step being played
+    pub fn step(&self) -> &Span {
+        &self.step
+    }
+
+    /// The parent for an engine system's span, if spans are being tracked
+    pub fn parent(spans: Option<&Self>) -> Option<Id> {
+        spans.and_then(|spans| spans.step.id())
+    }
+}
+
+/// Register the turn and step spans
+pub(super) fn register_engine_spans(app: &mut App) {
+    app.init_resource::<EngineSpans>()
+        .add_systems(First, update_engine_spans);
+}
+
+/// Open a new turn span when the turn changes and a new step span under it when
+/// the step changes, closing the previous ones
+pub fn update_engine_spans(
+    mut spans: ResMut<EngineSpans>,
+    turn_manager: Option<Res<TurnManager>>,
+    phase: Option<Res<Phase>>,
+) {
+    let (Some(turn_manager), Some(phase)) = (turn_manager, phase) else {
+        return;
+    };
+    let turn = turn_manager.turn_number;
+    let previous = spans.current;
+    if previous == Some((turn, *phase)) {
+        return;
+    }
+
+    if previous.map(|(previous_turn, _)| previous_turn) != Some(turn) {
+        // Replacing the spans drops the previous ones, which closes them
+        spans.step = Span::none();
+        let turn_span = info_span!(
+            parent: None,
+            "turn",
+            number = turn,
+            active_player = ?turn_manager.active_player,
+            extra = turn_manager.current_turn_is_extra
+        );
+        spans.turn = turn_span;
+    }
+    let step = info_span!(parent: &spans.turn, "step", phase = ?*phase);
+    spans.step = step;
+    spans.current = Some((turn, *phase));
+}
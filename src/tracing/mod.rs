@@ -1,6 +1,26 @@
+//! Logging, diagnostics and tracing spans
+//!
+//! The core engine systems open spans under a span per turn and per step, see
+//! [`EngineSpans`]. Build with the `trace_chrome` feature to write a Chrome trace
+//! (`trace-*.json`, viewable in Perfetto) or `trace_tracy` to stream to Tracy.
+
 use bevy::prelude::*;
 use std::panic;
 
+mod card_pool;
+mod card_updates;
+mod engine_spans;
+
+pub use card_pool::{
+    CARD_POOL_DISCARDED, CARD_POOL_FREE, CARD_POOL_REUSED, CARD_POOL_SPAWNED,
+    record_card_pool_diagnostics,
+};
+pub use card_updates::{
+    CARD_HINT_UPDATES, CARD_TRANSFORM_UPDATES, CARD_VISUAL_UPDATES, CardUpdateCounts,
+    record_card_update_diagnostics,
+};
+pub use engine_spans::{EngineSpans, update_engine_spans};
+
 /// Plugin that configures enhanced logging and diagnostics for the application
 pub struct DiagnosticsPlugin;
 
@@ -24,6 +44,15 @@ impl Plugin for DiagnosticsPlugin {
             .add_plugins(bevy::diagnostic::EntityCountDiagnosticsPlugin)
             .add_plugins(bevy::diagnostic::SystemInformationDiagnosticsPlugin);
 
+        // Count the cards the rendering sync systems touch each frame
+        card_updates::register_card_update_diagnostics(app);
+
+        // Report how well card visuals are being recycled
+        card_pool::register_card_pool_diagnostics(app);
+
+        // Group the engine systems' spans by turn and step
+        engine_spans::register_engine_spans(app);
+
         // Add startup diagnostic system
         app.add_systems(Startup, log_startup_info)
             .add_systems(Last, log_frame_completion);
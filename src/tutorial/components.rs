@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+/// Root node of the tutorial overlay
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TutorialOverlay;
+
+/// Text of the current tutorial step
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TutorialText;
+
+/// Button finishing the current step
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TutorialContinueButton;
+
+/// Button leaving the lesson
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TutorialSkipButton;
+
+/// Glow drawn behind the card a step points at
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TutorialHighlight {
+    pub card: Entity,
+}
+
+/// Sent to start a lesson
+#[derive(Event, Debug, Clone, PartialEq, Eq, Default)]
+pub struct StartLessonEvent {
+    /// Lesson to start, or the next unfinished one when `None`
+    pub lesson: Option<String>,
+}
@@ -0,0 +1,138 @@
+use crate::game_engine::phase::{
+    BeginningStep, CombatStep, EndingStep, Phase, PostcombatStep, PrecombatStep,
+};
+
+/// Card a tutorial step points at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardTarget {
+    /// Any card in the player's hand
+    AnyInHand,
+    /// A card with this name, preferring one in the player's hand
+    Named(String),
+}
+
+/// One step of a lesson
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TutorialStep {
+    /// Show a message until the player presses Continue
+    Explain { text: String },
+    /// Highlight a card and describe it until the player presses Continue
+    HighlightCard { target: CardTarget, text: String },
+    /// Highlight a card and wait for the player to drag it onto their battlefield
+    PlayCard { target: CardTarget, text: String },
+    /// Explain each step of the turn as the game reaches it, until `until`
+    PhaseTour { until: Phase },
+}
+
+impl TutorialStep {
+    /// Card highlighted during this step
+    pub fn target(&self) -> Option<&CardTarget> {
+        match self {
+            TutorialStep::HighlightCard { target, .. } | TutorialStep::PlayCard { target, .. } => {
+                Some(target)
+            }
+            TutorialStep::Explain { .. } | TutorialStep::PhaseTour { .. } => None,
+        }
+    }
+
+    /// Message shown in the tutorial overlay while the game is in `phase`
+    pub fn text(&self, phase: Phase) -> String {
+        match self {
+            TutorialStep::Explain { text }
+            | TutorialStep::HighlightCard { text, .. }
+            | TutorialStep::PlayCard { text, .. } => text.clone(),
+            TutorialStep::PhaseTour { .. } => {
+                format!("{}: {}", phase.label(), phase_explanation(phase))
+            }
+        }
+    }
+}
+
+/// A scripted lesson
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lesson {
+    /// Stable id stored in the tutorial progress
+    pub id: String,
+    pub title: String,
+    pub steps: Vec<TutorialStep>,
+}
+
+/// What happens during a step of the turn, for the phase tour
+pub fn phase_explanation(phase: Phase) -> &'static str {
+    match phase {
+        Phase::Beginning(BeginningStep::Untap) => "Your tapped permanents untap.",
+        Phase::Beginning(BeginningStep::Upkeep) => {
+            "Abilities that trigger \"at the beginning of your upkeep\" happen now."
+        }
+        Phase::Beginning(BeginningStep::Draw) => "You draw a card.",
+        Phase::Precombat(PrecombatStep::Main) => {
+            "You can play a land and cast creatures and other spells."
+        }
+        Phase::Combat(CombatStep::Beginning) => "Combat begins. Last chance to act before attacks.",
+        Phase::Combat(CombatStep::DeclareAttackers) => {
+            "Choose which creatures attack and which player each one attacks."
+        }
+        Phase::Combat(CombatStep::DeclareBlockers) => {
+            "The defending players choose which creatures block."
+        }
+        Phase::Combat(CombatStep::FirstStrikeDamage) => {
+            "Creatures with first strike or double strike deal their damage first."
+        }
+        Phase::Combat(CombatStep::CombatDamage) => "Creatures deal their combat damage.",
+        Phase::Combat(CombatStep::End) => "Combat is over.",
+        Phase::Postcombat(PostcombatStep::Main) => {
+            "A second main phase to cast spells after seeing how combat went."
+        }
+        Phase::Ending(EndingStep::End) => {
+            "Abilities that trigger \"at the beginning of the end step\" happen now."
+        }
+        Phase::Ending(EndingStep::Cleanup) => {
+            "Discard down to seven cards and damage wears off creatures."
+        }
+    }
+}
+
+/// Lessons offered to new players, in the order they should be taken
+pub fn builtin_lessons() -> Vec<Lesson> {
+    vec![
+        Lesson {
+            id: "basics".to_string(),
+            title: "The Basics".to_string(),
+            steps: vec![
+                TutorialStep::Explain {
+                    text: "Welcome to Rummage! This lesson walks you through your first turn."
+                        .to_string(),
+                },
+                TutorialStep::HighlightCard {
+                    target: CardTarget::AnyInHand,
+                    text: "This is a card in your hand. Only you can see your hand.".to_string(),
+                },
+                TutorialStep::PlayCard {
+                    target: CardTarget::AnyInHand,
+                    text: "Drag the highlighted card onto your battlefield to play it."
+                        .to_string(),
+                },
+                TutorialStep::Explain {
+                    text: "Cards on the battlefield are called permanents. Next, let's look at the parts of a turn."
+                        .to_string(),
+                },
+            ],
+        },
+        Lesson {
+            id: "turn_structure".to_string(),
+            title: "Turn Structure".to_string(),
+            steps: vec![
+                TutorialStep::Explain {
+                    text: "Every turn goes through the same steps. Watch them as they pass."
+                        .to_string(),
+                },
+                TutorialStep::PhaseTour {
+                    until: Phase::Ending(EndingStep::End),
+                },
+                TutorialStep::Explain {
+                    text: "That's a full turn. The next player now takes theirs.".to_string(),
+                },
+            ],
+        },
+    ]
+}
@@ -0,0 +1,20 @@
+//! Interactive tutorial
+//!
+//! Scripted lessons walk a new player through the game: an overlay explains each
+//! step, the card a step is about is highlighted, and while a lesson waits for the
+//! player to play that card every other card is locked in place. Finished lessons
+//! and the step reached in an unfinished one are saved between sessions.
+
+pub mod components;
+pub mod lessons;
+mod plugin;
+pub mod progress;
+pub mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub use components::StartLessonEvent;
+pub use lessons::{CardTarget, Lesson, TutorialStep, builtin_lessons};
+pub use plugin::TutorialPlugin;
+pub use progress::{ActiveLesson, TutorialProgress};
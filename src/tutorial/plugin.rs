@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use super::components::StartLessonEvent;
+use super::progress::{
+    ActiveLesson, TutorialProgress, load_persistent_progress, persist_tutorial_progress,
+};
+use super::systems::{
+    check_tutorial_card_played, clear_tutorial, follow_tutorial_highlight, handle_tutorial_buttons,
+    handle_tutorial_menu_button, start_lessons, sync_tutorial_overlay, track_phase_tour,
+    update_tutorial_target,
+};
+use crate::menu::state::GameMenuState;
+
+/// Plugin for the interactive tutorial
+pub struct TutorialPlugin;
+
+impl Plugin for TutorialPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TutorialProgress>()
+            .add_event::<StartLessonEvent>();
+
+        if let Some(persistent) = load_persistent_progress() {
+            app.insert_resource(persistent.get().clone());
+            app.insert_resource(persistent);
+        }
+
+        app.add_systems(
+            Update,
+            (
+                handle_tutorial_menu_button,
+                start_lessons,
+                persist_tutorial_progress,
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                track_phase_tour,
+                check_tutorial_card_played,
+                handle_tutorial_buttons,
+                update_tutorial_target,
+                follow_tutorial_highlight,
+                sync_tutorial_overlay,
+            )
+                .chain()
+                .run_if(in_state(GameMenuState::InGame))
+                .run_if(resource_exists::<ActiveLesson>),
+        )
+        .add_systems(
+            Update,
+            clear_tutorial.run_if(resource_removed::<ActiveLesson>),
+        )
+        .add_systems(OnExit(GameMenuState::InGame), clear_tutorial);
+    }
+}
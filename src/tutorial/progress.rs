@@ -0,0 +1,171 @@
+use bevy::prelude::*;
+use bevy_persistent::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use super::lessons::{Lesson, TutorialStep};
+
+/// Tutorial progress file name inside the config directory
+const PROGRESS_FILE_NAME: &str = "tutorial.toml";
+
+/// Where the player stopped in a lesson
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LessonProgress {
+    pub lesson: String,
+    pub step: usize,
+}
+
+/// Lessons the player has finished and the one in progress, kept between sessions
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TutorialProgress {
+    /// Ids of finished lessons
+    #[serde(default)]
+    pub completed: Vec<String>,
+    /// Lesson left part way through
+    #[serde(default)]
+    pub current: Option<LessonProgress>,
+}
+
+impl TutorialProgress {
+    /// Whether the lesson with this id has been finished
+    pub fn is_completed(&self, lesson: &str) -> bool {
+        self.completed.iter().any(|id| id == lesson)
+    }
+
+    /// Step to resume `lesson` from
+    pub fn resume_step(&self, lesson: &str) -> usize {
+        self.current
+            .as_ref()
+            .filter(|current| current.lesson == lesson)
+            .map_or(0, |current| current.step)
+    }
+
+    /// First lesson that hasn't been finished, or the first lesson once all are
+    pub fn next_lesson<'a>(&self, lessons: &'a [Lesson]) -> Option<&'a Lesson> {
+        lessons
+            .iter()
+            .find(|lesson| !self.is_completed(&lesson.id))
+            .or_else(|| lessons.first())
+    }
+
+    /// Record the step the player has reached
+    pub fn record_step(&mut self, lesson: &str, step: usize) {
+        self.current = Some(LessonProgress {
+            lesson: lesson.to_string(),
+            step,
+        });
+    }
+
+    /// Record a finished lesson
+    pub fn complete(&mut self, lesson: &str) {
+        if !self.is_completed(lesson) {
+            self.completed.push(lesson.to_string());
+        }
+        if self
+            .current
+            .as_ref()
+            .is_some_and(|current| current.lesson == lesson)
+        {
+            self.current = None;
+        }
+    }
+}
+
+/// The lesson being played
+#[derive(Resource, Debug, Clone)]
+pub struct ActiveLesson {
+    pub lesson: Lesson,
+    pub step: usize,
+    /// Card highlighted for the current step, once found
+    pub target: Option<Entity>,
+    /// Whether a phase tour has reached its last step
+    pub tour_finished: bool,
+}
+
+impl ActiveLesson {
+    pub fn new(lesson: Lesson, step: usize) -> Self {
+        let step = step.min(lesson.steps.len().saturating_sub(1));
+        Self {
+            lesson,
+            step,
+            target: None,
+            tour_finished: false,
+        }
+    }
+
+    /// The current step
+    pub fn current_step(&self) -> Option<&TutorialStep> {
+        self.lesson.steps.get(self.step)
+    }
+
+    /// Move to the next step
+    ///
+    /// Returns `true` once the lesson is finished.
+    pub fn advance(&mut self) -> bool {
+        self.step += 1;
+        self.target = None;
+        self.tour_finished = false;
+        self.step >= self.lesson.steps.len()
+    }
+
+    /// Whether the player can press Continue to finish the current step
+    pub fn can_continue(&self) -> bool {
+        match self.current_step() {
+            Some(TutorialStep::Explain { .. } | TutorialStep::HighlightCard { .. }) => true,
+            Some(TutorialStep::PhaseTour { .. }) => self.tour_finished,
+            Some(TutorialStep::PlayCard { .. }) | None => false,
+        }
+    }
+}
+
+/// Path of the tutorial progress file in the platform config directory
+pub fn tutorial_progress_path() -> PathBuf {
+    #[cfg(target_arch = "wasm32")]
+    {
+        PathBuf::from("/local/rummage").join(PROGRESS_FILE_NAME)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        dirs::config_dir()
+            .map(|dir| dir.join("rummage"))
+            .unwrap_or_else(|| PathBuf::from("settings"))
+            .join(PROGRESS_FILE_NAME)
+    }
+}
+
+/// Load the saved tutorial progress
+pub fn load_persistent_progress() -> Option<Persistent<TutorialProgress>> {
+    match Persistent::<TutorialProgress>::builder()
+        .name("rummage_tutorial")
+        .format(StorageFormat::Toml)
+        .path(tutorial_progress_path())
+        .default(TutorialProgress::default())
+        .revert_to_default_on_deserialization_errors(true)
+        .build()
+    {
+        Ok(persistent) => Some(persistent),
+        Err(e) => {
+            error!("Failed to initialize tutorial progress: {:?}", e);
+            None
+        }
+    }
+}
+
+/// Write the tutorial progress to disk whenever it changes
+pub fn persist_tutorial_progress(
+    progress: Res<TutorialProgress>,
+    persistent: Option<ResMut<Persistent<TutorialProgress>>>,
+) {
+    let Some(mut persistent) = persistent else {
+        return;
+    };
+    if !progress.is_changed() || *persistent.get() == *progress {
+        return;
+    }
+
+    match persistent.set(progress.clone()) {
+        Ok(()) => debug!("Tutorial progress saved"),
+        Err(e) => error!("Failed to save tutorial progress: {:?}", e),
+    }
+}
@@ -0,0 +1,405 @@
+use bevy::prelude::*;
+
+use super::components::{
+    StartLessonEvent, TutorialContinueButton, TutorialHighlight, TutorialOverlay,
+    TutorialSkipButton, TutorialText,
+};
+use super::lessons::{CardTarget, TutorialStep, builtin_lessons};
+use super::progress::{ActiveLesson, TutorialProgress};
+use crate::camera::components::AppLayer;
+use crate::camera::z_order::UiLayer;
+use crate::cards::components::DragLocked;
+use crate::cards::systems::CardDroppedEvent;
+use crate::cards::{Card, CardZone};
+use crate::game_engine::phase::Phase;
+use crate::game_engine::zones::Zone;
+use crate::menu::components::MenuButtonAction;
+use crate::menu::state::{AppState, GameMenuState};
+use crate::player::Player;
+use crate::player::playmat::PlaymatZone;
+use crate::player::resources::PlayerConfig;
+
+const PANEL_COLOR: Color = Color::srgba(0.05, 0.05, 0.08, 0.92);
+const BUTTON_COLOR: Color = Color::srgba(0.2, 0.45, 0.7, 0.95);
+const SKIP_COLOR: Color = Color::srgba(0.25, 0.25, 0.25, 0.95);
+const HIGHLIGHT_COLOR: Color = Color::srgba(1.0, 0.85, 0.2, 0.6);
+
+/// A card that a tutorial step could point at
+#[derive(Debug, Clone)]
+pub struct TargetCandidate {
+    pub card: Entity,
+    pub name: String,
+    /// Whether the card is in the local player's hand
+    pub in_hand: bool,
+}
+
+/// Pick the card a step points at
+///
+/// Cards in the local player's hand come first; among equals the oldest card wins
+/// so the choice stays the same from frame to frame.
+pub fn find_target(target: &CardTarget, candidates: &[TargetCandidate]) -> Option<Entity> {
+    candidates
+        .iter()
+        .filter(|candidate| match target {
+            CardTarget::AnyInHand => candidate.in_hand,
+            CardTarget::Named(name) => candidate.name == *name,
+        })
+        .min_by_key(|candidate| (!candidate.in_hand, candidate.card))
+        .map(|candidate| candidate.card)
+}
+
+/// Zone whose centre is closest to `position`, as `(player, zone)`
+pub fn nearest_zone(position: Vec2, zones: &[(Entity, Zone, Vec2)]) -> Option<(Entity, Zone)> {
+    zones
+        .iter()
+        .min_by(|(_, _, a), (_, _, b)| {
+            a.distance_squared(position)
+                .total_cmp(&b.distance_squared(position))
+        })
+        .map(|(player, zone, _)| (*player, *zone))
+}
+
+fn local_player(players: &Query<(Entity, &Player)>) -> Option<Entity> {
+    players
+        .iter()
+        .find(|(_, player)| player.player_index == 0)
+        .map(|(entity, _)| entity)
+}
+
+/// Move to the next step, finishing the lesson after the last one
+fn advance_lesson(
+    commands: &mut Commands,
+    active: &mut ActiveLesson,
+    progress: &mut TutorialProgress,
+) {
+    if active.advance() {
+        info!("Finished lesson \"{}\"", active.lesson.title);
+        progress.complete(&active.lesson.id);
+        commands.remove_resource::<ActiveLesson>();
+    } else {
+        progress.record_step(&active.lesson.id, active.step);
+    }
+}
+
+/// Start a lesson when the Tutorial button on the main menu is pressed
+pub fn handle_tutorial_menu_button(
+    buttons: Query<(&Interaction, &MenuButtonAction), Changed<Interaction>>,
+    mut start_events: EventWriter<StartLessonEvent>,
+    mut next_state: ResMut<NextState<GameMenuState>>,
+    mut app_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, action) in buttons.iter() {
+        if *interaction == Interaction::Pressed && matches!(action, MenuButtonAction::Tutorial) {
+            start_events.write(StartLessonEvent::default());
+            next_state.set(GameMenuState::InGame);
+            app_state.set(AppState::InGame);
+        }
+    }
+}
+
+/// Start the requested lesson, resuming where the player left it
+pub fn start_lessons(
+    mut commands: Commands,
+    mut start_events: EventReader<StartLessonEvent>,
+    mut progress: ResMut<TutorialProgress>,
+) {
+    let Some(event) = start_events.read().last().cloned() else {
+        return;
+    };
+
+    let lessons = builtin_lessons();
+    let lesson = match &event.lesson {
+        Some(id) => lessons.iter().find(|lesson| lesson.id == *id),
+        None => progress.next_lesson(&lessons),
+    };
+    let Some(lesson) = lesson.cloned() else {
+        warn!("No tutorial lesson {:?}", event.lesson);
+        return;
+    };
+
+    let step = progress.resume_step(&lesson.id);
+    let active = ActiveLesson::new(lesson, step);
+    info!(
+        "Starting lesson \"{}\" at step {}",
+        active.lesson.title,
+        active.step + 1
+    );
+    progress.record_step(&active.lesson.id, active.step);
+    commands.insert_resource(active);
+}
+
+/// Show the current step in the overlay, rebuilding it when the step or the game
+/// step changes
+pub fn sync_tutorial_overlay(
+    mut commands: Commands,
+    active: Res<ActiveLesson>,
+    phase: Option<Res<Phase>>,
+    roots: Query<Entity, With<TutorialOverlay>>,
+) {
+    let phase_changed = phase.as_ref().is_some_and(|phase| phase.is_changed());
+    if !active.is_changed() && !phase_changed && !roots.is_empty() {
+        return;
+    }
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+
+    let Some(step) = active.current_step() else {
+        return;
+    };
+    let phase = phase.map_or_else(Phase::default, |phase| *phase);
+    let heading = format!(
+        "{} ({}/{})",
+        active.lesson.title,
+        active.step + 1,
+        active.lesson.steps.len()
+    );
+    let can_continue = active.can_continue();
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                left: Val::Percent(50.0),
+                margin: UiRect::left(Val::Px(-260.0)),
+                width: Val::Px(520.0),
+                padding: UiRect::all(Val::Px(14.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+            BackgroundColor(PANEL_COLOR),
+            UiLayer::Dialog.global_z_index(),
+            TutorialOverlay,
+            AppLayer::GameUI.layer(),
+            Name::new("Tutorial Overlay"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(heading),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.75)),
+            ));
+            parent.spawn((
+                Text::new(step.text(phase)),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TutorialText,
+            ));
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::FlexEnd,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    spawn_overlay_button(row, "Skip", SKIP_COLOR, TutorialSkipButton);
+                    if can_continue {
+                        spawn_overlay_button(row, "Continue", BUTTON_COLOR, TutorialContinueButton);
+                    }
+                });
+        });
+}
+
+fn spawn_overlay_button(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    color: Color,
+    marker: impl Component,
+) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(110.0),
+                height: Val::Px(32.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(color),
+            marker,
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// Mark a phase tour as finished once the game reaches its last step
+pub fn track_phase_tour(mut active: ResMut<ActiveLesson>, phase: Option<Res<Phase>>) {
+    let Some(phase) = phase else {
+        return;
+    };
+    let reached = matches!(
+        active.current_step(),
+        Some(TutorialStep::PhaseTour { until }) if *until == *phase
+    );
+    if reached && !active.tour_finished {
+        active.tour_finished = true;
+    }
+}
+
+/// Find the card the current step points at, highlight it and stop every other
+/// card from being dragged
+pub fn update_tutorial_target(
+    mut commands: Commands,
+    mut active: ResMut<ActiveLesson>,
+    cards: Query<(Entity, &Card, Option<&CardZone>)>,
+    players: Query<(Entity, &Player)>,
+    locked: Query<(), With<DragLocked>>,
+    highlights: Query<(Entity, &TutorialHighlight)>,
+    player_config: Option<Res<PlayerConfig>>,
+) {
+    let target_exists = active.target.is_some_and(|card| cards.contains(card));
+    if !target_exists {
+        let target = active
+            .current_step()
+            .and_then(TutorialStep::target)
+            .and_then(|target| {
+                let local = local_player(&players);
+                let candidates: Vec<TargetCandidate> = cards
+                    .iter()
+                    .map(|(card, details, zone)| TargetCandidate {
+                        card,
+                        name: details.name.name.clone(),
+                        in_hand: zone.is_some_and(|zone| {
+                            zone.zone == Zone::Hand && zone.zone_owner == local
+                        }),
+                    })
+                    .collect();
+                find_target(target, &candidates)
+            });
+        if active.target != target {
+            active.target = target;
+        }
+    }
+    let target = active.target;
+
+    for (highlight, TutorialHighlight { card }) in highlights.iter() {
+        if Some(*card) != target {
+            commands.entity(highlight).despawn();
+        }
+    }
+    if let Some(card) = target.filter(|card| !highlights.iter().any(|(_, h)| h.card == *card)) {
+        let card_size = player_config.map_or(Vec2::new(63.0, 88.0), |config| config.card_size);
+        commands.spawn((
+            Sprite {
+                color: HIGHLIGHT_COLOR,
+                custom_size: Some(card_size * 2.5 * 1.1),
+                ..default()
+            },
+            Transform::default(),
+            TutorialHighlight { card },
+            AppLayer::Cards.layer(),
+            Name::new("Tutorial Highlight"),
+        ));
+    }
+
+    for (card, _, _) in cards.iter() {
+        let is_locked = locked.contains(card);
+        if Some(card) == target && is_locked {
+            commands.entity(card).remove::<DragLocked>();
+        } else if Some(card) != target && !is_locked {
+            commands.entity(card).insert(DragLocked);
+        }
+    }
+}
+
+/// Keep each highlight just behind its card
+pub fn follow_tutorial_highlight(
+    mut highlights: Query<(&TutorialHighlight, &mut Transform)>,
+    cards: Query<&GlobalTransform, With<Card>>,
+) {
+    for (highlight, mut transform) in highlights.iter_mut() {
+        if let Ok(card) = cards.get(highlight.card) {
+            transform.translation = card.translation() - Vec3::Z * 0.5;
+        }
+    }
+}
+
+/// Finish a play step when its card is dropped on the player's battlefield
+pub fn check_tutorial_card_played(
+    mut commands: Commands,
+    mut dropped_events: EventReader<CardDroppedEvent>,
+    mut active: ResMut<ActiveLesson>,
+    mut progress: ResMut<TutorialProgress>,
+    zones: Query<(&PlaymatZone, &GlobalTransform)>,
+    players: Query<(Entity, &Player)>,
+) {
+    if !matches!(active.current_step(), Some(TutorialStep::PlayCard { .. })) {
+        dropped_events.clear();
+        return;
+    }
+    let zones: Vec<(Entity, Zone, Vec2)> = zones
+        .iter()
+        .map(|(zone, transform)| {
+            (
+                zone.player_id,
+                zone.zone_type,
+                transform.translation().truncate(),
+            )
+        })
+        .collect();
+    let local = local_player(&players);
+
+    for event in dropped_events.read() {
+        if Some(event.card) != active.target {
+            continue;
+        }
+        let on_battlefield = nearest_zone(event.position, &zones)
+            .is_some_and(|(player, zone)| zone == Zone::Battlefield && Some(player) == local);
+        if on_battlefield {
+            advance_lesson(&mut commands, &mut active, &mut progress);
+            break;
+        }
+    }
+}
+
+/// Handle the Continue and Skip buttons
+pub fn handle_tutorial_buttons(
+    mut commands: Commands,
+    continue_buttons: Query<&Interaction, (Changed<Interaction>, With<TutorialContinueButton>)>,
+    skip_buttons: Query<&Interaction, (Changed<Interaction>, With<TutorialSkipButton>)>,
+    mut active: ResMut<ActiveLesson>,
+    mut progress: ResMut<TutorialProgress>,
+) {
+    if skip_buttons.iter().any(|i| *i == Interaction::Pressed) {
+        // The progress keeps the current step so the lesson can be resumed
+        info!("Skipped lesson \"{}\"", active.lesson.title);
+        commands.remove_resource::<ActiveLesson>();
+        return;
+    }
+    if continue_buttons.iter().any(|i| *i == Interaction::Pressed) && active.can_continue() {
+        advance_lesson(&mut commands, &mut active, &mut progress);
+    }
+}
+
+/// Remove the overlay and highlight and let every card be dragged again
+pub fn clear_tutorial(
+    mut commands: Commands,
+    tutorial_entities: Query<Entity, Or<(With<TutorialOverlay>, With<TutorialHighlight>)>>,
+    locked: Query<Entity, With<DragLocked>>,
+) {
+    for entity in tutorial_entities.iter() {
+        commands.entity(entity).despawn();
+    }
+    for card in locked.iter() {
+        commands.entity(card).remove::<DragLocked>();
+    }
+}
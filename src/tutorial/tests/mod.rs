@@ -0,0 +1,134 @@
+use bevy::prelude::*;
+
+use crate::game_engine::phase::{EndingStep, Phase, PrecombatStep};
+use crate::game_engine::zones::Zone;
+use crate::tutorial::progress::ActiveLesson;
+use crate::tutorial::systems::{TargetCandidate, find_target, nearest_zone};
+use crate::tutorial::{CardTarget, Lesson, TutorialProgress, TutorialStep, builtin_lessons};
+
+fn candidate(index: u32, name: &str, in_hand: bool) -> TargetCandidate {
+    TargetCandidate {
+        card: Entity::from_raw(index),
+        name: name.to_string(),
+        in_hand,
+    }
+}
+
+fn tour_lesson() -> Lesson {
+    Lesson {
+        id: "tour".to_string(),
+        title: "Tour".to_string(),
+        steps: vec![
+            TutorialStep::PhaseTour {
+                until: Phase::Ending(EndingStep::End),
+            },
+            TutorialStep::PlayCard {
+                target: CardTarget::AnyInHand,
+                text: "Play it".to_string(),
+            },
+        ],
+    }
+}
+
+#[test]
+fn test_find_target_prefers_cards_in_hand() {
+    let candidates = vec![
+        candidate(1, "Forest", false),
+        candidate(5, "Forest", true),
+        candidate(3, "Mountain", true),
+    ];
+
+    assert_eq!(
+        find_target(&CardTarget::Named("Forest".to_string()), &candidates),
+        Some(Entity::from_raw(5))
+    );
+    assert_eq!(
+        find_target(&CardTarget::AnyInHand, &candidates),
+        Some(Entity::from_raw(3))
+    );
+    assert_eq!(
+        find_target(&CardTarget::Named("Island".to_string()), &candidates),
+        None
+    );
+}
+
+#[test]
+fn test_nearest_zone_picks_closest_centre() {
+    let player = Entity::from_raw(1);
+    let zones = vec![
+        (player, Zone::Hand, Vec2::new(0.0, -300.0)),
+        (player, Zone::Battlefield, Vec2::new(0.0, -100.0)),
+        (
+            Entity::from_raw(2),
+            Zone::Battlefield,
+            Vec2::new(0.0, 200.0),
+        ),
+    ];
+
+    assert_eq!(
+        nearest_zone(Vec2::new(20.0, -80.0), &zones),
+        Some((player, Zone::Battlefield))
+    );
+    assert_eq!(nearest_zone(Vec2::ZERO, &[]), None);
+}
+
+#[test]
+fn test_progress_resumes_and_completes_lessons() {
+    let lessons = builtin_lessons();
+    let mut progress = TutorialProgress::default();
+    assert_eq!(progress.next_lesson(&lessons).unwrap().id, "basics");
+
+    progress.record_step("basics", 2);
+    assert_eq!(progress.resume_step("basics"), 2);
+    assert_eq!(progress.resume_step("turn_structure"), 0);
+
+    progress.complete("basics");
+    progress.complete("basics");
+    assert_eq!(progress.completed, vec!["basics".to_string()]);
+    assert!(progress.current.is_none());
+    assert_eq!(progress.next_lesson(&lessons).unwrap().id, "turn_structure");
+
+    progress.complete("turn_structure");
+    assert_eq!(progress.next_lesson(&lessons).unwrap().id, "basics");
+}
+
+#[test]
+fn test_progress_round_trips_through_toml() {
+    let mut progress = TutorialProgress::default();
+    progress.complete("basics");
+    progress.record_step("turn_structure", 1);
+
+    let text = toml::to_string(&progress).unwrap();
+    let loaded: TutorialProgress = toml::from_str(&text).unwrap();
+    assert_eq!(loaded, progress);
+}
+
+#[test]
+fn test_active_lesson_gates_continue() {
+    let mut active = ActiveLesson::new(tour_lesson(), 0);
+    assert!(!active.can_continue());
+    active.tour_finished = true;
+    assert!(active.can_continue());
+
+    active.target = Some(Entity::from_raw(4));
+    assert!(!active.advance());
+    assert_eq!(active.target, None);
+    assert!(!active.tour_finished);
+    assert!(!active.can_continue());
+    assert!(active.advance());
+}
+
+#[test]
+fn test_active_lesson_clamps_saved_step() {
+    let active = ActiveLesson::new(tour_lesson(), 10);
+    assert_eq!(active.step, 1);
+}
+
+#[test]
+fn test_phase_tour_explains_current_step() {
+    let step = TutorialStep::PhaseTour {
+        until: Phase::Ending(EndingStep::End),
+    };
+    let text = step.text(Phase::Precombat(PrecombatStep::Main));
+    assert!(text.starts_with(Phase::Precombat(PrecombatStep::Main).label()));
+}